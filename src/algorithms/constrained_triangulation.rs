@@ -0,0 +1,572 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::delaunay_triangulation::{triangulate, Triangulation, EMPTY};
+use algorithms::{incircle, orient2d};
+use std::collections::VecDeque;
+use structures::{DistanceMetric, KdTree, Point2D};
+
+/// Triangulates a set of 2D points such that each of the `constraints`, pairs of indices
+/// into `points`, is an edge of the triangulation, e.g. the breaklines of a TIN or the
+/// edges of a polygon. The triangulation is otherwise Delaunay, i.e. it is the
+/// constrained Delaunay triangulation of the points. The halfedges of the constrained
+/// edges are flagged in the `constrained` vector of the triangulation.
+///
+/// Points that the triangulation skips as near-duplicates of other points are replaced
+/// in the constraints by the points that they duplicate, and constraints that pass
+/// through other points are split at those points. Returns `None` if no triangulation
+/// exists for the points (e.g. all points are collinear) or if constraints cross one
+/// another other than at their end points.
+///
+/// # Example
+///
+/// ```rust
+/// let points = vec![
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(4.0, -1.0),
+///     Point2D::new(4.0, 1.0),
+///     Point2D::new(8.0, 0.0),
+/// ];
+/// // the Delaunay triangulation has the edge 1-2; the constraint replaces it with 0-3
+/// let result = constrained_triangulate(&points, &[(0, 3)]).expect("No triangulation exists.");
+/// ```
+pub fn constrained_triangulate(
+    points: &[Point2D],
+    constraints: &[(usize, usize)],
+) -> Option<Triangulation> {
+    let mut tri = triangulate(points)?;
+    tri.constrained = vec![false; tri.triangles.len()];
+    // an outgoing halfedge of each point, or EMPTY for points that were skipped
+    let mut vertex_edge = vec![EMPTY; points.len()];
+    for e in 0..tri.triangles.len() {
+        vertex_edge[tri.triangles[e]] = e;
+    }
+    let vertex_of = vertex_map(points, &vertex_edge);
+    for &(a, b) in constraints {
+        let (a, b) = (vertex_of[a], vertex_of[b]);
+        if a != b && !insert_constraint(&mut tri, points, &mut vertex_edge, a, b) {
+            return None;
+        }
+    }
+    Some(tri)
+}
+
+/// Triangulates a polygon, which may have holes, given the points of its rings and the
+/// index of the first point of each ring within `points` (i.e. the points and parts of a
+/// shapefile polygon). Rings may be closed, with their last point repeating their first,
+/// or open. Holes are identified by the nesting of the rings (the even-odd rule) rather
+/// than by their orientation. Returns the constrained Delaunay triangulation of the
+/// polygon's points, keeping only the triangles within the polygon, with the halfedges
+/// of the rings being constrained and the `hull` of the triangulation being empty.
+/// Returns `None` if no triangulation exists or if the rings cross one another.
+pub fn triangulate_polygon(points: &[Point2D], parts: &[usize]) -> Option<Triangulation> {
+    let mut constraints = vec![];
+    for i in 0..parts.len() {
+        let start = parts[i];
+        let end = if i < parts.len() - 1 {
+            parts[i + 1]
+        } else {
+            points.len()
+        };
+        if end < start + 2 {
+            continue;
+        }
+        for j in start..end - 1 {
+            constraints.push((j, j + 1));
+        }
+        if !points[end - 1].nearly_equals(&points[start]) {
+            constraints.push((end - 1, start));
+        }
+    }
+    let tri = constrained_triangulate(points, &constraints)?;
+
+    // find the number of rings crossed to reach each triangle from outside of the hull,
+    // which is odd for triangles within the polygon
+    let num_triangles = tri.len();
+    let mut depth = vec![usize::max_value(); num_triangles];
+    let mut queue = VecDeque::new();
+    for e in 0..tri.halfedges.len() {
+        if tri.halfedges[e] == EMPTY {
+            if tri.is_constrained(e) {
+                queue.push_back((tri.triangle_of_edge(e), 1));
+            } else {
+                queue.push_front((tri.triangle_of_edge(e), 0));
+            }
+        }
+    }
+    while let Some((t, d)) = queue.pop_front() {
+        if depth[t] <= d {
+            continue;
+        }
+        depth[t] = d;
+        for &e in tri.edges_of_triangle(t).iter() {
+            let twin = tri.halfedges[e];
+            if twin == EMPTY {
+                continue;
+            }
+            let nt = tri.triangle_of_edge(twin);
+            if tri.is_constrained(e) {
+                if d + 1 < depth[nt] {
+                    queue.push_back((nt, d + 1));
+                }
+            } else if d < depth[nt] {
+                queue.push_front((nt, d));
+            }
+        }
+    }
+
+    let mut index = vec![EMPTY; num_triangles];
+    let mut n = 0;
+    for t in 0..num_triangles {
+        if depth[t] != usize::max_value() && depth[t] % 2 == 1 {
+            index[t] = n;
+            n += 1;
+        }
+    }
+    let mut polygon = Triangulation {
+        triangles: Vec::with_capacity(n * 3),
+        halfedges: Vec::with_capacity(n * 3),
+        hull: vec![],
+        constrained: Vec::with_capacity(n * 3),
+    };
+    for t in 0..num_triangles {
+        if index[t] == EMPTY {
+            continue;
+        }
+        for &e in tri.edges_of_triangle(t).iter() {
+            polygon.triangles.push(tri.triangles[e]);
+            polygon.constrained.push(tri.constrained[e]);
+            let twin = tri.halfedges[e];
+            polygon
+                .halfedges
+                .push(if twin != EMPTY && index[twin / 3] != EMPTY {
+                    index[twin / 3] * 3 + twin % 3
+                } else {
+                    EMPTY
+                });
+        }
+    }
+    Some(polygon)
+}
+
+/// Returns the vertex of the triangulation representing each point, which is the point
+/// itself unless it was skipped as a near-duplicate, in which case it is the nearest vertex.
+fn vertex_map(points: &[Point2D], vertex_edge: &[usize]) -> Vec<usize> {
+    let mut vertex_of: Vec<usize> = (0..points.len()).collect();
+    if vertex_edge.iter().all(|&e| e != EMPTY) {
+        return vertex_of;
+    }
+    let mut coords = vec![];
+    let mut values = vec![];
+    for i in 0..points.len() {
+        if vertex_edge[i] != EMPTY {
+            coords.push(points[i].x);
+            coords.push(points[i].y);
+            values.push(i);
+        }
+    }
+    let tree = KdTree::new(2, coords, values, DistanceMetric::SquaredEuclidean);
+    for i in 0..points.len() {
+        if vertex_edge[i] == EMPTY {
+            if let Some((v, _)) = tree.nearest(&[points[i].x, points[i].y]) {
+                vertex_of[i] = v;
+            }
+        }
+    }
+    vertex_of
+}
+
+/// Inserts the constrained edge a-b, returning false if it crosses another constrained edge.
+fn insert_constraint(
+    tri: &mut Triangulation,
+    points: &[Point2D],
+    vertex_edge: &mut Vec<usize>,
+    a: usize,
+    b: usize,
+) -> bool {
+    let mut start = a;
+    while start != b {
+        // the edge is inserted in sections, ending at b or at any vertex lying on the edge
+        let (end, crossing) = match find_crossing_edges(tri, points, vertex_edge, start, b) {
+            Some(r) => r,
+            None => return false,
+        };
+        if !crossing.is_empty() {
+            let new_edges =
+                match remove_crossing_edges(tri, points, vertex_edge, start, end, crossing) {
+                    Some(edges) => edges,
+                    None => return false,
+                };
+            restore_delaunay(tri, points, vertex_edge, new_edges);
+        }
+        let e = find_edge(tri, vertex_edge, start, end);
+        if e == EMPTY {
+            return false;
+        }
+        tri.constrained[e] = true;
+        let twin = tri.halfedges[e];
+        if twin != EMPTY {
+            tri.constrained[twin] = true;
+        }
+        start = end;
+    }
+    true
+}
+
+/// Walks through the triangulation from vertex a towards vertex b, returning the vertex
+/// pairs of the edges crossed by the segment a-b, and the vertex at which the walk ends,
+/// which is either b or the first vertex lying on the segment. Returns None if a
+/// constrained edge is crossed.
+fn find_crossing_edges(
+    tri: &Triangulation,
+    points: &[Point2D],
+    vertex_edge: &[usize],
+    a: usize,
+    b: usize,
+) -> Option<(usize, Vec<(usize, usize)>)> {
+    let (pa, pb) = (&points[a], &points[b]);
+    for e in outgoing_edges(tri, vertex_edge[a]) {
+        let u = tri.triangles[tri.next_halfedge(e)];
+        let w = tri.triangles[tri.prev_halfedge(e)];
+        if u == b || w == b {
+            return Some((b, vec![]));
+        }
+        let (su, sw) = (side(pa, pb, &points[u]), side(pa, pb, &points[w]));
+        if su == 0 && is_ahead(pa, pb, &points[u]) {
+            return Some((u, vec![]));
+        }
+        if sw == 0 && is_ahead(pa, pb, &points[w]) {
+            return Some((w, vec![]));
+        }
+        // does the segment leave a through this triangle, crossing the edge u-w?
+        if su * sw >= 0 || side(&points[u], &points[w], pa) * side(&points[u], &points[w], pb) >= 0
+        {
+            continue;
+        }
+        let mut h = tri.next_halfedge(e);
+        let mut crossing = vec![];
+        loop {
+            let twin = tri.halfedges[h];
+            if twin == EMPTY || tri.is_constrained(h) {
+                return None;
+            }
+            crossing.push((tri.triangles[h], tri.triangles[twin]));
+            let x = tri.triangles[twin];
+            let v = tri.triangles[tri.prev_halfedge(twin)];
+            let sv = side(pa, pb, &points[v]);
+            if v == b || sv == 0 {
+                return Some((v, crossing));
+            }
+            // the segment leaves the triangle through the edge between v and whichever of
+            // the crossed edge's vertices lies on the other side of it
+            h = if sv == side(pa, pb, &points[x]) {
+                tri.next_halfedge(twin)
+            } else {
+                tri.prev_halfedge(twin)
+            };
+        }
+    }
+    None
+}
+
+/// Flips the edges crossing the segment a-b until none remain (Sloan, 1993), returning
+/// the vertex pairs of the edges created by the flips. Returns None if an edge cannot be
+/// removed.
+fn remove_crossing_edges(
+    tri: &mut Triangulation,
+    points: &[Point2D],
+    vertex_edge: &mut Vec<usize>,
+    a: usize,
+    b: usize,
+    crossing: Vec<(usize, usize)>,
+) -> Option<Vec<(usize, usize)>> {
+    let max_iterations = 10 * crossing.len() * crossing.len() + 100;
+    let mut queue: VecDeque<(usize, usize)> = crossing.into_iter().collect();
+    let mut new_edges = vec![];
+    let mut iterations = 0;
+    while let Some((p, q)) = queue.pop_front() {
+        iterations += 1;
+        if iterations > max_iterations {
+            return None;
+        }
+        let e = find_edge(tri, vertex_edge, p, q);
+        if e == EMPTY || tri.halfedges[e] == EMPTY {
+            return None;
+        }
+        let r = tri.triangles[tri.prev_halfedge(e)];
+        let s = tri.triangles[tri.prev_halfedge(tri.halfedges[e])];
+        // the edge can only be flipped if the quadrilateral of its triangles is convex
+        if side(&points[r], &points[s], &points[p]) * side(&points[r], &points[s], &points[q]) < 0
+        {
+            flip_edge(tri, vertex_edge, e);
+            if (r, s) == (a, b) || (s, r) == (a, b) {
+                continue;
+            }
+            if r != a && r != b && s != a && s != b && crosses(points, a, b, r, s) {
+                queue.push_back((r, s));
+            } else {
+                new_edges.push((r, s));
+            }
+        } else {
+            queue.push_back((p, q));
+        }
+    }
+    Some(new_edges)
+}
+
+/// Flips any of the given unconstrained edges that are not locally Delaunay, repeating
+/// until no more edges are flipped.
+fn restore_delaunay(
+    tri: &mut Triangulation,
+    points: &[Point2D],
+    vertex_edge: &mut Vec<usize>,
+    edges: Vec<(usize, usize)>,
+) {
+    let mut edges = edges;
+    for _ in 0..100 {
+        let mut flipped = false;
+        for i in 0..edges.len() {
+            let (p, q) = edges[i];
+            let e = find_edge(tri, vertex_edge, p, q);
+            if e == EMPTY || tri.is_constrained(e) || tri.halfedges[e] == EMPTY {
+                continue;
+            }
+            let r = tri.triangles[tri.prev_halfedge(e)];
+            let s = tri.triangles[tri.prev_halfedge(tri.halfedges[e])];
+            let (p, q) = (tri.triangles[e], tri.triangles[tri.next_halfedge(e)]);
+            if incircle(&points[r], &points[p], &points[q], &points[s]) < 0f64 {
+                flip_edge(tri, vertex_edge, e);
+                edges[i] = (r, s);
+                flipped = true;
+            }
+        }
+        if !flipped {
+            break;
+        }
+    }
+}
+
+/// Replaces the edge of halfedge a, which must have a twin, with the other diagonal of the
+/// quadrilateral formed by its two triangles (see `Triangulation::legalize`).
+fn flip_edge(tri: &mut Triangulation, vertex_edge: &mut Vec<usize>, a: usize) {
+    let b = tri.halfedges[a];
+    let ar = tri.prev_halfedge(a);
+    let al = tri.next_halfedge(a);
+    let bl = tri.prev_halfedge(b);
+    let br = tri.next_halfedge(b);
+
+    let p0 = tri.triangles[ar];
+    let p1 = tri.triangles[bl];
+    tri.triangles[a] = p1;
+    tri.triangles[b] = p0;
+
+    let hbl = tri.halfedges[bl];
+    let har = tri.halfedges[ar];
+    tri.halfedges[a] = hbl;
+    tri.halfedges[b] = har;
+    tri.halfedges[ar] = bl;
+    tri.halfedges[bl] = ar;
+    if hbl != EMPTY {
+        tri.halfedges[hbl] = a;
+    }
+    if har != EMPTY {
+        tri.halfedges[har] = b;
+    }
+
+    tri.constrained[a] = tri.constrained[bl];
+    tri.constrained[b] = tri.constrained[ar];
+    tri.constrained[ar] = false;
+    tri.constrained[bl] = false;
+
+    for &e in [a, al, ar, b, bl, br].iter() {
+        vertex_edge[tri.triangles[e]] = e;
+    }
+}
+
+/// Returns the halfedges leaving the vertex at which halfedge `start` begins.
+fn outgoing_edges(tri: &Triangulation, start: usize) -> Vec<usize> {
+    let mut result = vec![];
+    if start == EMPTY {
+        return result;
+    }
+    result.push(start);
+    let mut e = start;
+    loop {
+        let twin = tri.halfedges[tri.prev_halfedge(e)];
+        if twin == EMPTY {
+            break;
+        }
+        if twin == start {
+            return result;
+        }
+        result.push(twin);
+        e = twin;
+    }
+    // the vertex is on the hull; rotate the other way from the start
+    e = start;
+    loop {
+        let twin = tri.halfedges[e];
+        if twin == EMPTY {
+            break;
+        }
+        e = tri.next_halfedge(twin);
+        result.push(e);
+    }
+    result
+}
+
+/// Returns a halfedge joining vertices p and q, or EMPTY if there is none.
+fn find_edge(tri: &Triangulation, vertex_edge: &[usize], p: usize, q: usize) -> usize {
+    for e in outgoing_edges(tri, vertex_edge[p]) {
+        if tri.triangles[tri.next_halfedge(e)] == q {
+            return e;
+        }
+        if tri.triangles[tri.prev_halfedge(e)] == q {
+            return tri.prev_halfedge(e);
+        }
+    }
+    EMPTY
+}
+
+/// Returns the side of the line through a and b on which p lies: 1 (left), -1 (right)
+/// or 0 (on the line).
+fn side(a: &Point2D, b: &Point2D, p: &Point2D) -> i32 {
+    let o = orient2d(a, b, p);
+    if o > 0f64 {
+        1
+    } else if o < 0f64 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Returns true if p, which is on the line through a and b, lies beyond a towards b.
+fn is_ahead(a: &Point2D, b: &Point2D, p: &Point2D) -> bool {
+    (b.x - a.x) * (p.x - a.x) + (b.y - a.y) * (p.y - a.y) > 0f64
+}
+
+/// Returns true if the segments a-b and r-s cross at a point interior to both.
+fn crosses(points: &[Point2D], a: usize, b: usize, r: usize, s: usize) -> bool {
+    let (pa, pb, pr, ps) = (&points[a], &points[b], &points[r], &points[s]);
+    side(pa, pb, pr) * side(pa, pb, ps) < 0 && side(pr, ps, pa) * side(pr, ps, pb) < 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::{constrained_triangulate, triangulate_polygon};
+    use algorithms::delaunay_triangulation::{Triangulation, EMPTY};
+    use structures::Point2D;
+
+    fn has_edge(tri: &Triangulation, p: usize, q: usize) -> Option<usize> {
+        (0..tri.triangles.len()).find(|&e| {
+            tri.triangles[e] == p && tri.triangles[tri.next_halfedge(e)] == q
+                || tri.triangles[e] == q && tri.triangles[tri.next_halfedge(e)] == p
+        })
+    }
+
+    fn check_connectivity(tri: &Triangulation) {
+        for e in 0..tri.halfedges.len() {
+            let twin = tri.halfedges[e];
+            if twin != EMPTY {
+                assert_eq!(tri.halfedges[twin], e);
+                assert_eq!(tri.triangles[twin], tri.triangles[tri.next_halfedge(e)]);
+                assert_eq!(tri.is_constrained(e), tri.is_constrained(twin));
+            }
+        }
+    }
+
+    fn area(tri: &Triangulation, points: &[Point2D]) -> f64 {
+        (0..tri.len())
+            .map(|t| {
+                let p = tri.points_of_triangle(t);
+                let (a, b, c) = (points[p[0]], points[p[1]], points[p[2]]);
+                ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)).abs() / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_constrained_triangulate() {
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, -1.0),
+            Point2D::new(4.0, 1.0),
+            Point2D::new(8.0, 0.0),
+        ];
+        let tri = constrained_triangulate(&points, &[(0, 3)]).unwrap();
+        assert!(has_edge(&tri, 1, 2).is_none());
+        assert!(tri.is_constrained(has_edge(&tri, 0, 3).unwrap()));
+        check_connectivity(&tri);
+
+        // a 10 x 10 grid of points, with constraints passing through grid points
+        let mut points = vec![];
+        for row in 0..10 {
+            for col in 0..10 {
+                points.push(Point2D::new(col as f64, row as f64 * 2.0));
+            }
+        }
+        let constraints = [(10, 49), (50, 94), (0, 9)];
+        let tri = constrained_triangulate(&points, &constraints).unwrap();
+        check_connectivity(&tri);
+        assert!((area(&tri, &points) - 9.0 * 18.0).abs() < 1e-9);
+        // constraints are split at the grid points that they pass through
+        for &(p, q) in [(10, 23), (23, 36), (36, 49), (50, 61), (83, 94), (4, 5)].iter() {
+            assert!(tri.is_constrained(has_edge(&tri, p, q).unwrap()));
+        }
+        let num_constrained = tri.constrained.iter().filter(|&&c| c).count();
+        assert_eq!(num_constrained, 2 * 3 + 2 * 4 + 9); // the boundary edges have no twins
+
+        // constraints that cross one another
+        assert!(constrained_triangulate(&points, &[(0, 99), (9, 90)]).is_none());
+    }
+
+    #[test]
+    fn test_triangulate_polygon() {
+        // a square with a square hole, as closed rings
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 10.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 4.0),
+            Point2D::new(6.0, 4.0),
+            Point2D::new(6.0, 6.0),
+            Point2D::new(4.0, 6.0),
+            Point2D::new(4.0, 4.0),
+        ];
+        let tri = triangulate_polygon(&points, &[0, 5]).unwrap();
+        check_connectivity(&tri);
+        assert!((area(&tri, &points) - 96.0).abs() < 1e-9);
+        assert_eq!(tri.len(), 8);
+        assert!(tri.hull.is_empty());
+
+        // an open, concave ring (a U shape), whose notch lies within its convex hull
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(9.0, 0.0),
+            Point2D::new(9.0, 9.0),
+            Point2D::new(6.0, 9.0),
+            Point2D::new(6.0, 3.0),
+            Point2D::new(3.0, 3.0),
+            Point2D::new(3.0, 9.0),
+            Point2D::new(0.0, 9.0),
+        ];
+        let tri = triangulate_polygon(&points, &[0]).unwrap();
+        check_connectivity(&tri);
+        assert!((area(&tri, &points) - (81.0 - 18.0)).abs() < 1e-9);
+        assert_eq!(tri.len(), points.len() - 2);
+        for e in 0..tri.halfedges.len() {
+            // every boundary edge of the triangulation is an edge of the ring
+            if tri.halfedges[e] == EMPTY {
+                assert!(tri.is_constrained(e));
+            }
+        }
+    }
+}
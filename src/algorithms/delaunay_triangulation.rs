@@ -43,6 +43,7 @@ println!("{:?}", result.triangles); // [0, 2, 1, 0, 3, 2]
 ```
 */
 
+use algorithms::{incircle, orient2d};
 use std::f64;
 use structures::Point2D;
 
@@ -80,6 +81,11 @@ pub struct Triangulation {
     /// A vector of indices that reference points on the convex hull of the triangulation,
     /// counter-clockwise.
     pub hull: Vec<usize>,
+
+    /// A vector flagging the halfedges that are constrained edges, i.e. that must appear in
+    /// the triangulation and are never flipped. It is empty for unconstrained
+    /// triangulations (see `constrained_triangulate`).
+    pub constrained: Vec<bool>,
 }
 
 impl Triangulation {
@@ -90,6 +96,7 @@ impl Triangulation {
             triangles: Vec::with_capacity(max_triangles * 3),
             halfedges: Vec::with_capacity(max_triangles * 3),
             hull: Vec::new(),
+            constrained: Vec::new(),
         }
     }
 
@@ -116,6 +123,11 @@ impl Triangulation {
         }
     }
 
+    /// Returns true if a halfedge is a constrained edge.
+    pub fn is_constrained(&self, edge: usize) -> bool {
+        !self.constrained.is_empty() && self.constrained[edge]
+    }
+
     /// Returns the triangle of an edge.
     pub fn triangle_of_edge(&self, edge: usize) -> usize {
         edge / 3
@@ -242,7 +254,7 @@ impl Triangulation {
         let pl = self.triangles[al];
         let p1 = self.triangles[bl];
 
-        let illegal = (&points[p0]).in_circle(&points[pr], &points[pl], &points[p1]);
+        let illegal = incircle(&points[p0], &points[pr], &points[pl], &points[p1]) < 0f64;
         if illegal {
             self.triangles[a] = p1;
             self.triangles[b] = p0;
@@ -361,7 +373,7 @@ impl Hull {
         start = self.prev[start];
         let mut e = start;
 
-        while !p.orient(&points[e], &points[self.next[e]]) {
+        while orient2d(p, &points[e], &points[self.next[e]]) <= 0f64 {
             e = self.next[e];
             if e == start {
                 return (EMPTY, false);
@@ -433,7 +445,7 @@ fn find_seed_triangle(points: &[Point2D]) -> Option<(usize, usize, usize)> {
         None
     } else {
         // swap the order of the seed points for counter-clockwise orientation
-        Some(if p0.orient(p1, &points[i2]) {
+        Some(if orient2d(p0, p1, &points[i2]) > 0f64 {
             (i0, i2, i1)
         } else {
             (i0, i1, i2)
@@ -493,7 +505,7 @@ pub fn triangulate(points: &[Point2D]) -> Option<Triangulation> {
         let mut n = hull.next[e];
         loop {
             let q = hull.next[n];
-            if !p.orient(&points[n], &points[q]) {
+            if orient2d(p, &points[n], &points[q]) <= 0f64 {
                 break;
             }
             let t = triangulation.add_triangle(n, i, q, hull.tri[i], EMPTY, hull.tri[n]);
@@ -507,7 +519,7 @@ pub fn triangulate(points: &[Point2D]) -> Option<Triangulation> {
             loop {
                 let q = hull.prev[e];
 
-                if !p.orient(&points[q], &points[e]) {
+                if orient2d(p, &points[q], &points[e]) <= 0f64 {
                     break;
                 }
                 let t = triangulation.add_triangle(q, i, e, EMPTY, hull.tri[e], hull.tri[q]);
@@ -0,0 +1,144 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use na::Matrix3;
+
+/// The shape features of a neighbourhood of 3D points, derived from the eigenvalues
+/// (l1 >= l2 >= l3) and eigenvectors of the points' covariance matrix. The linearity,
+/// planarity, and sphericity sum to 1 and describe whether the points are distributed along
+/// a line, e.g. a wire, across a plane, e.g. a roof or the ground, or in all directions,
+/// e.g. vegetation.
+#[derive(Clone, Copy, Debug)]
+pub struct EigenFeatures {
+    /// The eigenvalues of the covariance matrix, in decreasing order.
+    pub eigenvalues: [f64; 3],
+    /// (l1 - l2) / l1
+    pub linearity: f64,
+    /// (l2 - l3) / l1
+    pub planarity: f64,
+    /// l3 / l1
+    pub sphericity: f64,
+    /// One minus the absolute vertical component of the normal vector, which is 0 for
+    /// horizontal surfaces and 1 for vertical surfaces.
+    pub verticality: f64,
+    /// The unit eigenvector of the smallest eigenvalue, i.e. the normal of the best-fit
+    /// plane, oriented such that its z component is non-negative.
+    pub normal: [f64; 3],
+}
+
+/// Calculates the covariance eigenvalue features of a set of 3D points. Returns None if
+/// there are fewer than three points or the points are coincident.
+pub fn eigen_features(points: &[[f64; 3]]) -> Option<EigenFeatures> {
+    let n = points.len();
+    if n < 3 {
+        return None;
+    }
+    let mut centroid = [0f64; 3];
+    for p in points {
+        for k in 0..3 {
+            centroid[k] += p[k];
+        }
+    }
+    for k in 0..3 {
+        centroid[k] /= n as f64;
+    }
+    let (mut xx, mut xy, mut xz, mut yy, mut yz, mut zz) = (0f64, 0f64, 0f64, 0f64, 0f64, 0f64);
+    for p in points {
+        let (dx, dy, dz) = (p[0] - centroid[0], p[1] - centroid[1], p[2] - centroid[2]);
+        xx += dx * dx;
+        xy += dx * dy;
+        xz += dx * dz;
+        yy += dy * dy;
+        yz += dy * dz;
+        zz += dz * dz;
+    }
+    let cov = Matrix3::new(xx, xy, xz, xy, yy, yz, xz, yz, zz) / n as f64;
+    let eigen = cov.symmetric_eigen();
+
+    // sort the eigenvalues, and their eigenvectors, in decreasing order
+    let mut order = [0usize, 1, 2];
+    order.sort_by(|&a, &b| {
+        eigen.eigenvalues[b]
+            .partial_cmp(&eigen.eigenvalues[a])
+            .unwrap()
+    });
+    let l1 = eigen.eigenvalues[order[0]];
+    if !(l1 > 0f64) {
+        return None;
+    }
+    // rounding errors may produce slightly negative values for degenerate neighbourhoods
+    let l2 = eigen.eigenvalues[order[1]].max(0f64);
+    let l3 = eigen.eigenvalues[order[2]].max(0f64);
+
+    let v = eigen.eigenvectors.column(order[2]);
+    let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    let sign = if v[2] < 0f64 { -1f64 } else { 1f64 };
+    let normal = [sign * v[0] / len, sign * v[1] / len, sign * v[2] / len];
+
+    Some(EigenFeatures {
+        eigenvalues: [l1, l2, l3],
+        linearity: (l1 - l2) / l1,
+        planarity: (l2 - l3) / l1,
+        sphericity: l3 / l1,
+        verticality: 1f64 - normal[2],
+        normal: normal,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::eigen_features;
+
+    #[test]
+    fn test_eigen_features_of_line() {
+        let points: Vec<[f64; 3]> = (0..10).map(|i| [i as f64, 2.0 * i as f64, 5.0]).collect();
+        let f = eigen_features(&points).unwrap();
+        assert!((f.linearity - 1.0).abs() < 1e-9);
+        assert!(f.planarity.abs() < 1e-9);
+        assert!(f.sphericity.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eigen_features_of_planes() {
+        // a horizontal plane
+        let mut points = vec![];
+        for i in 0..5 {
+            for j in 0..5 {
+                points.push([i as f64, j as f64, 10.0]);
+            }
+        }
+        let f = eigen_features(&points).unwrap();
+        assert!((f.planarity - 1.0).abs() < 1e-9);
+        assert!(f.sphericity.abs() < 1e-9);
+        assert!(f.verticality.abs() < 1e-9);
+        assert!((f.normal[2] - 1.0).abs() < 1e-9);
+
+        // a vertical wall, facing the x direction
+        let points: Vec<[f64; 3]> = points.iter().map(|p| [3.0, p[0], p[1]]).collect();
+        let f = eigen_features(&points).unwrap();
+        assert!((f.verticality - 1.0).abs() < 1e-9);
+        assert!((f.normal[0].abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eigen_features_of_cube() {
+        let mut points = vec![];
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    points.push([i as f64, j as f64, k as f64]);
+                }
+            }
+        }
+        let f = eigen_features(&points).unwrap();
+        assert!((f.sphericity - 1.0).abs() < 1e-9);
+        assert!((f.linearity + f.planarity + f.sphericity - 1.0).abs() < 1e-9);
+        assert!(eigen_features(&points[0..2]).is_none());
+        assert!(eigen_features(&[[1.0, 1.0, 1.0]; 4]).is_none());
+    }
+}
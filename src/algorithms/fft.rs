@@ -0,0 +1,293 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+use std::f64::consts::PI;
+
+/// Calculates the discrete Fourier transform of a complex sequence in place, with the real
+/// and imaginary parts of the sequence held in `re` and `im`. The forward transform uses the
+/// sign convention X(k) = sum x(n) exp(-2 pi i k n / N), and the inverse transform
+/// (`inverse`) is scaled by 1 / N, such that the inverse of the forward transform returns
+/// the original sequence.
+///
+/// Sequences with a power-of-two length are transformed with an iterative radix-2
+/// Cooley-Tukey FFT, and sequences of any other length with Bluestein's algorithm, which
+/// expresses the transform as a convolution that is calculated with radix-2 FFTs. Either way,
+/// the time taken is O(N log N).
+pub fn fft(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    assert_eq!(re.len(), im.len());
+    FftPlan::new(re.len()).transform(re, im, inverse);
+}
+
+/// Calculates the two-dimensional discrete Fourier transform of a grid of complex values
+/// in place. The real and imaginary parts of the grid are held, in row-major order, in `re`
+/// and `im`. The rows are transformed, followed by the columns, using the same conventions
+/// as `fft`.
+pub fn fft_2d(re: &mut [f64], im: &mut [f64], rows: usize, columns: usize, inverse: bool) {
+    assert_eq!(re.len(), rows * columns);
+    assert_eq!(im.len(), rows * columns);
+    if rows == 0 || columns == 0 {
+        return;
+    }
+    let row_plan = FftPlan::new(columns);
+    for row in 0..rows {
+        let range = row * columns..(row + 1) * columns;
+        row_plan.transform(&mut re[range.clone()], &mut im[range], inverse);
+    }
+    let column_plan = FftPlan::new(rows);
+    let mut column_re = vec![0f64; rows];
+    let mut column_im = vec![0f64; rows];
+    for col in 0..columns {
+        for row in 0..rows {
+            column_re[row] = re[row * columns + col];
+            column_im[row] = im[row * columns + col];
+        }
+        column_plan.transform(&mut column_re, &mut column_im, inverse);
+        for row in 0..rows {
+            re[row * columns + col] = column_re[row];
+            im[row * columns + col] = column_im[row];
+        }
+    }
+}
+
+/// The precalculated values needed to transform sequences of a given length, which allow
+/// the many rows and columns of a grid to be transformed without repeating this work.
+struct FftPlan {
+    n: usize,
+    bluestein: Option<Bluestein>,
+}
+
+/// The chirp of Bluestein's algorithm, w(k) = exp(-pi i k^2 / N), and the transform of the
+/// sequence that it is convolved with, of the power-of-two length `m`.
+struct Bluestein {
+    m: usize,
+    chirp_re: Vec<f64>,
+    chirp_im: Vec<f64>,
+    kernel_re: Vec<f64>,
+    kernel_im: Vec<f64>,
+}
+
+impl FftPlan {
+    fn new(n: usize) -> FftPlan {
+        if n <= 1 || n.is_power_of_two() {
+            return FftPlan { n, bluestein: None };
+        }
+        let m = (2 * n - 1).next_power_of_two();
+        let mut chirp_re = vec![0f64; n];
+        let mut chirp_im = vec![0f64; n];
+        for k in 0..n {
+            // k^2 mod 2N keeps the angle small, and therefore accurate, for long sequences
+            let k2 = ((k as u64 * k as u64) % (2 * n as u64)) as f64;
+            let angle = -PI * k2 / n as f64;
+            chirp_re[k] = angle.cos();
+            chirp_im[k] = angle.sin();
+        }
+        let mut kernel_re = vec![0f64; m];
+        let mut kernel_im = vec![0f64; m];
+        kernel_re[0] = chirp_re[0];
+        kernel_im[0] = -chirp_im[0];
+        for k in 1..n {
+            kernel_re[k] = chirp_re[k];
+            kernel_im[k] = -chirp_im[k];
+            kernel_re[m - k] = chirp_re[k];
+            kernel_im[m - k] = -chirp_im[k];
+        }
+        radix2(&mut kernel_re, &mut kernel_im, false);
+        FftPlan {
+            n,
+            bluestein: Some(Bluestein {
+                m,
+                chirp_re,
+                chirp_im,
+                kernel_re,
+                kernel_im,
+            }),
+        }
+    }
+
+    fn transform(&self, re: &mut [f64], im: &mut [f64], inverse: bool) {
+        let n = self.n;
+        if n <= 1 {
+            return;
+        }
+        // the inverse transform is the conjugate of the forward transform of the conjugate
+        if inverse {
+            for v in im.iter_mut() {
+                *v = -*v;
+            }
+        }
+        match self.bluestein {
+            None => radix2(re, im, false),
+            Some(ref b) => {
+                let mut a_re = vec![0f64; b.m];
+                let mut a_im = vec![0f64; b.m];
+                for k in 0..n {
+                    a_re[k] = re[k] * b.chirp_re[k] - im[k] * b.chirp_im[k];
+                    a_im[k] = re[k] * b.chirp_im[k] + im[k] * b.chirp_re[k];
+                }
+                radix2(&mut a_re, &mut a_im, false);
+                for k in 0..b.m {
+                    let (x, y) = (a_re[k], a_im[k]);
+                    a_re[k] = x * b.kernel_re[k] - y * b.kernel_im[k];
+                    a_im[k] = x * b.kernel_im[k] + y * b.kernel_re[k];
+                }
+                radix2(&mut a_re, &mut a_im, true);
+                for k in 0..n {
+                    re[k] = a_re[k] * b.chirp_re[k] - a_im[k] * b.chirp_im[k];
+                    im[k] = a_re[k] * b.chirp_im[k] + a_im[k] * b.chirp_re[k];
+                }
+            }
+        }
+        if inverse {
+            let scale = 1f64 / n as f64;
+            for k in 0..n {
+                re[k] *= scale;
+                im[k] *= -scale;
+            }
+        }
+    }
+}
+
+/// An iterative radix-2 FFT of a sequence with a power-of-two length; the inverse is scaled
+/// by 1 / N.
+fn radix2(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    if n <= 1 {
+        return;
+    }
+    // bit-reversal permutation
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+    let sign = if inverse { 1f64 } else { -1f64 };
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let twiddles: Vec<(f64, f64)> = (0..half)
+            .map(|k| {
+                let angle = sign * 2f64 * PI * k as f64 / len as f64;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        let mut start = 0;
+        while start < n {
+            for k in 0..half {
+                let (w_re, w_im) = twiddles[k];
+                let (a, b) = (start + k, start + k + half);
+                let t_re = re[b] * w_re - im[b] * w_im;
+                let t_im = re[b] * w_im + im[b] * w_re;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+    if inverse {
+        let scale = 1f64 / n as f64;
+        for k in 0..n {
+            re[k] *= scale;
+            im[k] *= scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fft, fft_2d};
+    use std::f64::consts::PI;
+
+    // the discrete Fourier transform, by its definition
+    fn dft(re: &[f64], im: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let n = re.len();
+        let mut out_re = vec![0f64; n];
+        let mut out_im = vec![0f64; n];
+        for k in 0..n {
+            for t in 0..n {
+                let angle = -2f64 * PI * (k * t) as f64 / n as f64;
+                out_re[k] += re[t] * angle.cos() - im[t] * angle.sin();
+                out_im[k] += re[t] * angle.sin() + im[t] * angle.cos();
+            }
+        }
+        (out_re, out_im)
+    }
+
+    fn sequence(n: usize) -> (Vec<f64>, Vec<f64>) {
+        let re = (0..n).map(|i| ((i * 7 + 3) % 11) as f64 - 4.5).collect();
+        let im = (0..n).map(|i| ((i * 5 + 1) % 13) as f64 * 0.25).collect();
+        (re, im)
+    }
+
+    #[test]
+    fn test_fft_matches_dft() {
+        for &n in &[1usize, 2, 3, 5, 8, 12, 16, 17, 30, 64, 100] {
+            let (re, im) = sequence(n);
+            let (expected_re, expected_im) = dft(&re, &im);
+            let (mut out_re, mut out_im) = (re.clone(), im.clone());
+            fft(&mut out_re, &mut out_im, false);
+            for k in 0..n {
+                assert!((out_re[k] - expected_re[k]).abs() < 1e-9, "n = {}", n);
+                assert!((out_im[k] - expected_im[k]).abs() < 1e-9, "n = {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_fft() {
+        for &n in &[4usize, 7, 32, 45] {
+            let (re, im) = sequence(n);
+            let (mut out_re, mut out_im) = (re.clone(), im.clone());
+            fft(&mut out_re, &mut out_im, false);
+            fft(&mut out_re, &mut out_im, true);
+            for k in 0..n {
+                assert!((out_re[k] - re[k]).abs() < 1e-9);
+                assert!((out_im[k] - im[k]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_2d() {
+        let (rows, columns) = (6, 5);
+        let (re, im) = sequence(rows * columns);
+        let (mut out_re, mut out_im) = (re.clone(), im.clone());
+        fft_2d(&mut out_re, &mut out_im, rows, columns, false);
+        for u in 0..rows {
+            for v in 0..columns {
+                let (mut sum_re, mut sum_im) = (0f64, 0f64);
+                for r in 0..rows {
+                    for c in 0..columns {
+                        let angle = -2f64
+                            * PI
+                            * ((u * r) as f64 / rows as f64 + (v * c) as f64 / columns as f64);
+                        let (x, y) = (re[r * columns + c], im[r * columns + c]);
+                        sum_re += x * angle.cos() - y * angle.sin();
+                        sum_im += x * angle.sin() + y * angle.cos();
+                    }
+                }
+                assert!((out_re[u * columns + v] - sum_re).abs() < 1e-9);
+                assert!((out_im[u * columns + v] - sum_im).abs() < 1e-9);
+            }
+        }
+        fft_2d(&mut out_re, &mut out_im, rows, columns, true);
+        for i in 0..rows * columns {
+            assert!((out_re[i] - re[i]).abs() < 1e-9);
+            assert!((out_im[i] - im[i]).abs() < 1e-9);
+        }
+    }
+}
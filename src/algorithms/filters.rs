@@ -0,0 +1,508 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+use std::collections::VecDeque;
+use std::f64;
+use std::ops::Range;
+use std::u32;
+
+/// The bin of a cell that does not contain a value, e.g. a nodata cell.
+pub const NO_BIN: u32 = u32::MAX;
+
+/// A statistic calculated by `rank_filter`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RankStatistic {
+    /// The lowest bin at which the cumulative count of the window reaches the percentile.
+    Percentile(f64),
+    /// The most frequent bin. Ties are resolved in favour of the centre cell's bin, if it
+    /// is one of the most frequent, and otherwise in favour of the lowest bin.
+    Mode,
+    /// The number of cells in the window with a lower bin than the centre cell, from which
+    /// the percentile rank of the centre cell can be found.
+    CountBelow,
+}
+
+/// Applies a rank filter with a rectangular window to a grid of binned values, i.e.
+/// integers in `0..num_bins`, stored in row-major order (`bins`), using the constant-time
+/// median filtering algorithm of Perreault and Hébert (2007). The filter is applied to
+/// the rows in `row_range`, and `output` is called with the index and the filtered bins
+/// of each of these rows, in order. The window extends `radius_x` columns and `radius_y`
+/// rows on each side of the centre cell. Cells with a bin of `NO_BIN` are excluded from
+/// the windows and are `NO_BIN` in the output, as are the cells with empty windows.
+///
+/// A histogram is kept for each column of the grid, covering the rows of the window, and
+/// is updated by one cell as the window moves down a row. The histogram of the window is
+/// updated by adding and subtracting the histograms of the columns that enter and leave
+/// it as it moves along a row. Each histogram has two tiers: a coarse histogram of
+/// segments of about `num_bins.sqrt()` bins, which is always kept up to date, and a fine
+/// histogram, whose segments are only brought up to date when they contain the
+/// percentile. The time taken per cell is therefore independent of the window size.
+///
+/// Perreault, S., and Hébert, P. (2007). Median filtering in constant time. IEEE
+/// Transactions on Image Processing, 16(9), 2389-2394.
+pub fn rank_filter<F>(
+    bins: &[u32],
+    rows: usize,
+    columns: usize,
+    radius_x: usize,
+    radius_y: usize,
+    num_bins: usize,
+    row_range: Range<usize>,
+    statistic: RankStatistic,
+    mut output: F,
+) where
+    F: FnMut(usize, Vec<u32>),
+{
+    if row_range.start >= row_range.end || columns == 0 || num_bins == 0 {
+        return;
+    }
+    let seg_size = ((num_bins as f64).sqrt().ceil() as usize).max(1);
+    let num_segs = (num_bins + seg_size - 1) / seg_size;
+    let (rx, ry) = (radius_x as isize, radius_y as isize);
+    let (rows_i, columns_i) = (rows as isize, columns as isize);
+
+    // the column histograms
+    let mut col_fine = vec![0u32; columns * num_bins];
+    let mut col_coarse = vec![0u32; columns * num_segs];
+    let mut col_n = vec![0u32; columns];
+    let update_column = |row: isize,
+                         sign: bool,
+                         col_fine: &mut Vec<u32>,
+                         col_coarse: &mut Vec<u32>,
+                         col_n: &mut Vec<u32>| {
+        if row < 0 || row >= rows_i {
+            return;
+        }
+        let offset = row as usize * columns;
+        for col in 0..columns {
+            let bin = bins[offset + col];
+            if bin != NO_BIN {
+                let b = bin as usize;
+                if sign {
+                    col_fine[col * num_bins + b] += 1;
+                    col_coarse[col * num_segs + b / seg_size] += 1;
+                    col_n[col] += 1;
+                } else {
+                    col_fine[col * num_bins + b] -= 1;
+                    col_coarse[col * num_segs + b / seg_size] -= 1;
+                    col_n[col] -= 1;
+                }
+            }
+        }
+    };
+    let first_row = row_range.start as isize;
+    for row in first_row - ry..first_row + ry + 1 {
+        update_column(row, true, &mut col_fine, &mut col_coarse, &mut col_n);
+    }
+
+    // the window histogram; each fine segment records the column at which it was updated
+    let mut win_fine = vec![0u32; num_bins];
+    let mut win_coarse = vec![0u32; num_segs];
+    let mut last_update = vec![0isize; num_segs];
+    let never = isize::min_value() / 2;
+    for row in row_range {
+        let r = row as isize;
+        if r > first_row {
+            update_column(r - ry - 1, false, &mut col_fine, &mut col_coarse, &mut col_n);
+            update_column(r + ry, true, &mut col_fine, &mut col_coarse, &mut col_n);
+        }
+
+        // the window initially contains the columns to the left of the first cell
+        for k in 0..num_segs {
+            win_coarse[k] = 0;
+            last_update[k] = never;
+        }
+        let mut n = 0u32;
+        for col in 0..rx.min(columns_i) {
+            let c = col as usize;
+            for k in 0..num_segs {
+                win_coarse[k] += col_coarse[c * num_segs + k];
+            }
+            n += col_n[c];
+        }
+
+        let mut data = vec![NO_BIN; columns];
+        for col in 0..columns_i {
+            // move the window one column to the right
+            if col + rx < columns_i {
+                let c = (col + rx) as usize;
+                for k in 0..num_segs {
+                    win_coarse[k] += col_coarse[c * num_segs + k];
+                }
+                n += col_n[c];
+            }
+            if col - rx - 1 >= 0 {
+                let c = (col - rx - 1) as usize;
+                for k in 0..num_segs {
+                    win_coarse[k] -= col_coarse[c * num_segs + k];
+                }
+                n -= col_n[c];
+            }
+            let centre_bin = bins[row * columns + col as usize];
+            if centre_bin == NO_BIN || n == 0 {
+                continue;
+            }
+
+            // brings a segment of the fine window histogram up to date
+            let mut update_segment = |k: usize, win_fine: &mut Vec<u32>| {
+                let (b0, b1) = (k * seg_size, ((k + 1) * seg_size).min(num_bins));
+                let last = last_update[k];
+                if last == col {
+                    return;
+                }
+                if col - last > 2 * rx + 1 {
+                    for b in b0..b1 {
+                        win_fine[b] = 0;
+                    }
+                    for c in (col - rx).max(0)..(col + rx + 1).min(columns_i) {
+                        let offset = c as usize * num_bins;
+                        for b in b0..b1 {
+                            win_fine[b] += col_fine[offset + b];
+                        }
+                    }
+                } else {
+                    for j in last + 1..col + 1 {
+                        if j + rx < columns_i {
+                            let offset = (j + rx) as usize * num_bins;
+                            for b in b0..b1 {
+                                win_fine[b] += col_fine[offset + b];
+                            }
+                        }
+                        if j - rx - 1 >= 0 {
+                            let offset = (j - rx - 1) as usize * num_bins;
+                            for b in b0..b1 {
+                                win_fine[b] -= col_fine[offset + b];
+                            }
+                        }
+                    }
+                }
+                last_update[k] = col;
+            };
+
+            data[col as usize] = match statistic {
+                RankStatistic::Percentile(p) => {
+                    let target = ((p / 100f64 * n as f64).ceil() as u32).max(1).min(n);
+                    let mut cumulative = 0u32;
+                    let mut k = 0;
+                    while cumulative + win_coarse[k] < target {
+                        cumulative += win_coarse[k];
+                        k += 1;
+                    }
+                    update_segment(k, &mut win_fine);
+                    let mut b = k * seg_size;
+                    while cumulative + win_fine[b] < target {
+                        cumulative += win_fine[b];
+                        b += 1;
+                    }
+                    b as u32
+                }
+                RankStatistic::Mode => {
+                    let mut mode = centre_bin as usize;
+                    for k in 0..num_segs {
+                        if win_coarse[k] > 0 {
+                            update_segment(k, &mut win_fine);
+                        }
+                    }
+                    for k in 0..num_segs {
+                        if win_coarse[k] > 0 {
+                            for b in k * seg_size..((k + 1) * seg_size).min(num_bins) {
+                                if win_fine[b] > win_fine[mode] {
+                                    mode = b;
+                                } else if win_fine[b] == win_fine[mode]
+                                    && mode != centre_bin as usize
+                                    && b < mode
+                                {
+                                    mode = b;
+                                }
+                            }
+                        }
+                    }
+                    mode as u32
+                }
+                RankStatistic::CountBelow => {
+                    let centre_seg = centre_bin as usize / seg_size;
+                    let mut count = 0u32;
+                    for k in 0..centre_seg {
+                        count += win_coarse[k];
+                    }
+                    update_segment(centre_seg, &mut win_fine);
+                    for b in centre_seg * seg_size..centre_bin as usize {
+                        count += win_fine[b];
+                    }
+                    count
+                }
+            };
+        }
+        output(row, data);
+    }
+}
+
+/// Calculates the minimum and maximum of the valid values within a rectangular window
+/// centred on each cell of a grid with `rows` rows and `columns` columns, where `value`
+/// returns the value of a cell, or `None` for cells that are excluded from the windows.
+/// The window extends `radius_x` columns and `radius_y` rows on each side of the centre
+/// cell. The minima and maxima are returned in row-major order; the windows that contain
+/// no valid values have a minimum of infinity and a maximum of negative infinity.
+///
+/// The window is separated into a horizontal and a vertical pass, each of which uses a
+/// monotonic queue of the candidate extreme values, such that the time taken per cell
+/// is independent of the window size.
+pub fn moving_extrema<F>(
+    rows: usize,
+    columns: usize,
+    radius_x: usize,
+    radius_y: usize,
+    value: F,
+) -> (Vec<f64>, Vec<f64>)
+where
+    F: Fn(usize, usize) -> Option<f64>,
+{
+    let mut min_vals = vec![f64::INFINITY; rows * columns];
+    let mut max_vals = vec![f64::NEG_INFINITY; rows * columns];
+    let mut line_min = vec![0f64; columns.max(rows)];
+    let mut line_max = vec![0f64; columns.max(rows)];
+    let mut queue = VecDeque::new();
+    for row in 0..rows {
+        let offset = row * columns;
+        for col in 0..columns {
+            if let Some(z) = value(row, col) {
+                min_vals[offset + col] = z;
+                max_vals[offset + col] = z;
+            }
+        }
+        let range = offset..offset + columns;
+        sliding_extreme(&min_vals[range.clone()], radius_x, false, &mut queue, &mut line_min);
+        sliding_extreme(&max_vals[range.clone()], radius_x, true, &mut queue, &mut line_max);
+        min_vals[range.clone()].copy_from_slice(&line_min[..columns]);
+        max_vals[range].copy_from_slice(&line_max[..columns]);
+    }
+    let mut column_vals = vec![0f64; rows];
+    for col in 0..columns {
+        for row in 0..rows {
+            column_vals[row] = min_vals[row * columns + col];
+        }
+        sliding_extreme(&column_vals, radius_y, false, &mut queue, &mut line_min);
+        for row in 0..rows {
+            column_vals[row] = max_vals[row * columns + col];
+        }
+        sliding_extreme(&column_vals, radius_y, true, &mut queue, &mut line_max);
+        for row in 0..rows {
+            min_vals[row * columns + col] = line_min[row];
+            max_vals[row * columns + col] = line_max[row];
+        }
+    }
+    (min_vals, max_vals)
+}
+
+/// Writes the minimum (or `maximum`) of `values` within `radius` of each position to
+/// `output`, using `queue` to hold the positions of the candidate values.
+fn sliding_extreme(
+    values: &[f64],
+    radius: usize,
+    maximum: bool,
+    queue: &mut VecDeque<usize>,
+    output: &mut [f64],
+) {
+    let n = values.len();
+    queue.clear();
+    let mut next = 0; // the next position to enter the window
+    for i in 0..n {
+        while next < n && next <= i + radius {
+            let z = values[next];
+            while let Some(&back) = queue.back() {
+                if (maximum && values[back] <= z) || (!maximum && values[back] >= z) {
+                    queue.pop_back();
+                } else {
+                    break;
+                }
+            }
+            queue.push_back(next);
+            next += 1;
+        }
+        while queue[0] + radius < i {
+            queue.pop_front();
+        }
+        output[i] = values[queue[0]];
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{moving_extrema, rank_filter, RankStatistic, NO_BIN};
+    use std::f64;
+
+    // returns the statistic of a window by brute force
+    fn brute_force(
+        bins: &[u32],
+        rows: isize,
+        columns: isize,
+        row: isize,
+        col: isize,
+        radius: isize,
+        statistic: RankStatistic,
+    ) -> u32 {
+        let centre = bins[(row * columns + col) as usize];
+        if centre == NO_BIN {
+            return NO_BIN;
+        }
+        let mut values = vec![];
+        for r in (row - radius).max(0)..(row + radius + 1).min(rows) {
+            for c in (col - radius).max(0)..(col + radius + 1).min(columns) {
+                let b = bins[(r * columns + c) as usize];
+                if b != NO_BIN {
+                    values.push(b);
+                }
+            }
+        }
+        values.sort();
+        match statistic {
+            RankStatistic::Percentile(p) => {
+                let n = values.len() as f64;
+                let target = ((p / 100f64 * n).ceil() as usize).max(1).min(values.len());
+                values[target - 1]
+            }
+            RankStatistic::Mode => {
+                let mut best = (0, centre);
+                let mut i = 0;
+                while i < values.len() {
+                    let mut j = i;
+                    while j < values.len() && values[j] == values[i] {
+                        j += 1;
+                    }
+                    let count = j - i;
+                    if count > best.0 || (count == best.0 && values[i] == centre) {
+                        best = (count, values[i]);
+                    }
+                    i = j;
+                }
+                best.1
+            }
+            RankStatistic::CountBelow => values.iter().filter(|&&b| b < centre).count() as u32,
+        }
+    }
+
+    fn test_grid(rows: usize, columns: usize, num_bins: u32) -> Vec<u32> {
+        // a simple deterministic pseudo-random grid with some nodata cells
+        let mut state = 12345u64;
+        (0..rows * columns)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let v = (state >> 33) as u32;
+                if v % 17 == 0 {
+                    NO_BIN
+                } else {
+                    v % num_bins
+                }
+            }).collect()
+    }
+
+    #[test]
+    fn test_rank_filter_ranks() {
+        let (rows, columns, num_bins) = (23, 31, 50);
+        let bins = test_grid(rows, columns, num_bins);
+        let statistics = [
+            RankStatistic::Percentile(0f64),
+            RankStatistic::Percentile(25f64),
+            RankStatistic::Percentile(50f64),
+            RankStatistic::Percentile(90f64),
+            RankStatistic::Percentile(100f64),
+            RankStatistic::CountBelow,
+        ];
+        for &stat in statistics.iter() {
+            for radius in 1..5 {
+                rank_filter(
+                    &bins,
+                    rows,
+                    columns,
+                    radius,
+                    radius,
+                    num_bins as usize,
+                    3..rows,
+                    stat,
+                    |row, data| {
+                        for col in 0..columns {
+                            let expected = brute_force(
+                                &bins,
+                                rows as isize,
+                                columns as isize,
+                                row as isize,
+                                col as isize,
+                                radius as isize,
+                                stat,
+                            );
+                            assert_eq!(data[col], expected);
+                        }
+                    },
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank_filter_mode() {
+        let (rows, columns, num_bins) = (19, 27, 6);
+        let bins = test_grid(rows, columns, num_bins);
+        for radius in 1..4 {
+            rank_filter(
+                &bins,
+                rows,
+                columns,
+                radius,
+                radius,
+                num_bins as usize,
+                0..rows,
+                RankStatistic::Mode,
+                |row, data| {
+                    for col in 0..columns {
+                        let expected = brute_force(
+                            &bins,
+                            rows as isize,
+                            columns as isize,
+                            row as isize,
+                            col as isize,
+                            radius as isize,
+                            RankStatistic::Mode,
+                        );
+                        assert_eq!(data[col], expected);
+                    }
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_moving_extrema() {
+        let (rows, columns) = (17, 12);
+        let bins = test_grid(rows, columns, 1000);
+        let value = |r: usize, c: usize| {
+            let b = bins[r * columns + c];
+            if b != NO_BIN && b % 5 != 0 {
+                Some(b as f64)
+            } else {
+                None
+            }
+        };
+        for &(radius_x, radius_y) in [(0, 0), (1, 2), (3, 1), (6, 9)].iter() {
+            let (min_vals, max_vals) = moving_extrema(rows, columns, radius_x, radius_y, &value);
+            for row in 0..rows {
+                for col in 0..columns {
+                    let (mut min_val, mut max_val) = (f64::INFINITY, f64::NEG_INFINITY);
+                    for r in row.saturating_sub(radius_y)..(row + radius_y + 1).min(rows) {
+                        for c in col.saturating_sub(radius_x)..(col + radius_x + 1).min(columns) {
+                            if let Some(z) = value(r, c) {
+                                min_val = min_val.min(z);
+                                max_val = max_val.max(z);
+                            }
+                        }
+                    }
+                    assert_eq!(min_vals[row * columns + col], min_val);
+                    assert_eq!(max_vals[row * columns + col], max_val);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::f64;
+
+/// The theoretical models that may be fitted to an experimental semivariogram.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VariogramModelType {
+    Spherical,
+    Exponential,
+    Gaussian,
+}
+
+impl VariogramModelType {
+    /// Parses a model type from its name, e.g. 'spherical', 'exponential' or 'gaussian'.
+    pub fn from_str(s: &str) -> Option<VariogramModelType> {
+        let s = s.to_lowercase();
+        if s.contains("sph") {
+            Some(VariogramModelType::Spherical)
+        } else if s.contains("exp") {
+            Some(VariogramModelType::Exponential)
+        } else if s.contains("gau") {
+            Some(VariogramModelType::Gaussian)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the semivariance of the model with a unit partial sill and range at lag `h`,
+    /// where `h` is expressed as a fraction of the range. The exponential and Gaussian
+    /// models are scaled such that the range is their practical (95% of sill) range.
+    fn unit_value(&self, h: f64) -> f64 {
+        match *self {
+            VariogramModelType::Spherical => {
+                if h >= 1f64 {
+                    1f64
+                } else {
+                    1.5 * h - 0.5 * h * h * h
+                }
+            }
+            VariogramModelType::Exponential => 1f64 - (-3f64 * h).exp(),
+            VariogramModelType::Gaussian => 1f64 - (-3f64 * h * h).exp(),
+        }
+    }
+}
+
+/// A theoretical semivariogram model, with a nugget, a partial sill (i.e. the sill less
+/// the nugget), and a range.
+#[derive(Clone, Copy, Debug)]
+pub struct VariogramModel {
+    pub model_type: VariogramModelType,
+    pub nugget: f64,
+    pub partial_sill: f64,
+    pub range: f64,
+}
+
+impl VariogramModel {
+    /// Returns the semivariance at a lag distance. The semivariance at a lag of zero is
+    /// zero, such that the nugget is a discontinuity at the origin.
+    pub fn semivariance(&self, h: f64) -> f64 {
+        if h <= 0f64 {
+            return 0f64;
+        }
+        self.nugget + self.partial_sill * self.model_type.unit_value(h / self.range)
+    }
+
+    /// Returns the sill, i.e. the nugget plus the partial sill.
+    pub fn sill(&self) -> f64 {
+        self.nugget + self.partial_sill
+    }
+}
+
+/// A lag bin of an experimental semivariogram.
+#[derive(Clone, Copy, Debug)]
+pub struct VariogramBin {
+    /// The mean distance between the pairs of points in the bin.
+    pub distance: f64,
+    /// The semivariance, i.e. half the mean squared difference between the pairs' values.
+    pub semivariance: f64,
+    pub num_pairs: usize,
+}
+
+/// Calculates the experimental (isotropic) semivariogram of a set of points, with `num_lags`
+/// bins of width `lag_size`. Pairs of points that are farther apart than the last bin are
+/// ignored, as are bins that contain no pairs.
+pub fn experimental_variogram(
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    lag_size: f64,
+    num_lags: usize,
+) -> Vec<VariogramBin> {
+    let mut sum_dist = vec![0f64; num_lags];
+    let mut sum_sq_diff = vec![0f64; num_lags];
+    let mut num_pairs = vec![0usize; num_lags];
+    let max_dist = lag_size * num_lags as f64;
+    let n = z.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            let dist = ((x[i] - x[j]) * (x[i] - x[j]) + (y[i] - y[j]) * (y[i] - y[j])).sqrt();
+            if dist >= max_dist {
+                continue;
+            }
+            let bin = (dist / lag_size) as usize;
+            sum_dist[bin] += dist;
+            sum_sq_diff[bin] += (z[i] - z[j]) * (z[i] - z[j]);
+            num_pairs[bin] += 1;
+        }
+    }
+    (0..num_lags)
+        .filter(|&b| num_pairs[b] > 0)
+        .map(|b| VariogramBin {
+            distance: sum_dist[b] / num_pairs[b] as f64,
+            semivariance: sum_sq_diff[b] / (2f64 * num_pairs[b] as f64),
+            num_pairs: num_pairs[b],
+        }).collect()
+}
+
+/// Fits a theoretical model to an experimental semivariogram by weighted least squares, with
+/// each bin weighted by its number of pairs. For each of a series of candidate ranges, up to
+/// one and a half times the greatest bin distance, the model is linear in the nugget and
+/// partial sill, which are solved for directly and constrained to be non-negative; the range
+/// with the smallest weighted squared error is retained.
+pub fn fit_variogram(bins: &[VariogramBin], model_type: VariogramModelType) -> VariogramModel {
+    let max_dist = bins.iter().fold(0f64, |m, b| m.max(b.distance));
+    let mut best = VariogramModel {
+        model_type: model_type,
+        nugget: bins.iter().fold(0f64, |m, b| m.max(b.semivariance)),
+        partial_sill: 0f64,
+        range: if max_dist > 0f64 { max_dist } else { 1f64 },
+    };
+    if bins.is_empty() || max_dist <= 0f64 {
+        return best;
+    }
+    let mut best_error = f64::INFINITY;
+    let num_ranges = 300;
+    for i in 1..num_ranges + 1 {
+        let range = 1.5 * max_dist * i as f64 / num_ranges as f64;
+        // weighted normal equations for gamma = nugget + partial_sill * f(h / range)
+        let (mut sw, mut swf, mut swff, mut swg, mut swfg) = (0f64, 0f64, 0f64, 0f64, 0f64);
+        for b in bins {
+            let w = b.num_pairs as f64;
+            let f = model_type.unit_value(b.distance / range);
+            sw += w;
+            swf += w * f;
+            swff += w * f * f;
+            swg += w * b.semivariance;
+            swfg += w * f * b.semivariance;
+        }
+        let det = sw * swff - swf * swf;
+        let (mut nugget, mut partial_sill) = if det.abs() > 1e-12 * sw * swff {
+            ((swff * swg - swf * swfg) / det, (sw * swfg - swf * swg) / det)
+        } else {
+            (0f64, if swff > 0f64 { swfg / swff } else { 0f64 })
+        };
+        if nugget < 0f64 {
+            nugget = 0f64;
+            partial_sill = if swff > 0f64 { swfg / swff } else { 0f64 };
+        }
+        if partial_sill < 0f64 {
+            partial_sill = 0f64;
+            nugget = swg / sw;
+        }
+        let model = VariogramModel {
+            model_type: model_type,
+            nugget: nugget,
+            partial_sill: partial_sill,
+            range: range,
+        };
+        let error = bins.iter().fold(0f64, |e, b| {
+            let d = model.semivariance(b.distance) - b.semivariance;
+            e + b.num_pairs as f64 * d * d
+        });
+        if error < best_error {
+            best_error = error;
+            best = model;
+        }
+    }
+    best
+}
+
+/// Estimates the value, and kriging variance, at a location (`x0`, `y0`) from a set of
+/// neighbouring points by ordinary kriging. The kriging weights, which are constrained to
+/// sum to one, are found by solving the kriging system, expressed in terms of the
+/// semivariances between the points and between the points and the location. Returns None
+/// if the system is singular, e.g. if two of the points are coincident.
+pub fn ordinary_kriging(
+    model: &VariogramModel,
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    x0: f64,
+    y0: f64,
+) -> Option<(f64, f64)> {
+    let n = z.len();
+    if n == 0 {
+        return None;
+    }
+    let dist = |xa: f64, ya: f64, xb: f64, yb: f64| -> f64 {
+        ((xa - xb) * (xa - xb) + (ya - yb) * (ya - yb)).sqrt()
+    };
+    // the augmented matrix of the (n + 1) x (n + 1) kriging system
+    let m = n + 1;
+    let gamma0: Vec<f64> = (0..n)
+        .map(|i| model.semivariance(dist(x[i], y[i], x0, y0)))
+        .collect();
+    let mut a = vec![0f64; m * (m + 1)];
+    for i in 0..n {
+        for j in 0..n {
+            a[i * (m + 1) + j] = model.semivariance(dist(x[i], y[i], x[j], y[j]));
+        }
+        a[i * (m + 1) + n] = 1f64;
+        a[n * (m + 1) + i] = 1f64;
+        a[i * (m + 1) + m] = gamma0[i];
+    }
+    a[n * (m + 1) + m] = 1f64;
+    let solution = solve_linear_system(&mut a, m)?;
+    let mut estimate = 0f64;
+    let mut variance = solution[n];
+    for i in 0..n {
+        estimate += solution[i] * z[i];
+        variance += solution[i] * gamma0[i];
+    }
+    Some((estimate, variance.max(0f64)))
+}
+
+/// Solves a system of `n` linear equations, stored as an n x (n + 1) augmented matrix in
+/// row-major order, by Gaussian elimination with partial pivoting. The matrix is overwritten.
+fn solve_linear_system(a: &mut [f64], n: usize) -> Option<Vec<f64>> {
+    let w = n + 1;
+    let scale = a.iter().fold(0f64, |m, v| m.max(v.abs()));
+    if scale == 0f64 {
+        return None;
+    }
+    for k in 0..n {
+        let mut pivot = k;
+        for i in k + 1..n {
+            if a[i * w + k].abs() > a[pivot * w + k].abs() {
+                pivot = i;
+            }
+        }
+        if a[pivot * w + k].abs() < 1e-12 * scale {
+            return None;
+        }
+        if pivot != k {
+            for j in 0..w {
+                a.swap(k * w + j, pivot * w + j);
+            }
+        }
+        for i in k + 1..n {
+            let factor = a[i * w + k] / a[k * w + k];
+            if factor != 0f64 {
+                for j in k..w {
+                    a[i * w + j] -= factor * a[k * w + j];
+                }
+            }
+        }
+    }
+    let mut solution = vec![0f64; n];
+    for k in (0..n).rev() {
+        let mut sum = a[k * w + n];
+        for j in k + 1..n {
+            sum -= a[k * w + j] * solution[j];
+        }
+        solution[k] = sum / a[k * w + k];
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        experimental_variogram, fit_variogram, ordinary_kriging, VariogramBin, VariogramModel,
+        VariogramModelType,
+    };
+
+    #[test]
+    fn test_variogram_models() {
+        let model = VariogramModel {
+            model_type: VariogramModelType::Spherical,
+            nugget: 1.0,
+            partial_sill: 4.0,
+            range: 10.0,
+        };
+        assert_eq!(model.semivariance(0.0), 0.0);
+        assert!((model.semivariance(5.0) - (1.0 + 4.0 * 0.6875)).abs() < 1e-12);
+        assert_eq!(model.semivariance(20.0), 5.0);
+        let model = VariogramModel {
+            model_type: VariogramModelType::Exponential,
+            ..model
+        };
+        // the practical range is at 95% of the partial sill
+        assert!((model.semivariance(10.0) - (1.0 + 4.0 * 0.950213)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_experimental_variogram() {
+        // points on a line, with values that alternate between 0 and 1
+        let x = vec![0.0, 1.0, 2.0, 3.0];
+        let y = vec![0.0; 4];
+        let z = vec![0.0, 1.0, 0.0, 1.0];
+        let bins = experimental_variogram(&x, &y, &z, 1.5, 2);
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].num_pairs, 3);
+        assert!((bins[0].distance - 1.0).abs() < 1e-12);
+        assert!((bins[0].semivariance - 0.5).abs() < 1e-12);
+        assert_eq!(bins[1].num_pairs, 2);
+        assert!((bins[1].semivariance - 0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fit_variogram() {
+        let model = VariogramModel {
+            model_type: VariogramModelType::Spherical,
+            nugget: 0.5,
+            partial_sill: 2.0,
+            range: 40.0,
+        };
+        let bins: Vec<VariogramBin> = (1..16)
+            .map(|i| VariogramBin {
+                distance: i as f64 * 5.0,
+                semivariance: model.semivariance(i as f64 * 5.0),
+                num_pairs: 100,
+            }).collect();
+        let fitted = fit_variogram(&bins, VariogramModelType::Spherical);
+        assert!((fitted.range - 40.0).abs() < 0.5);
+        assert!((fitted.nugget - 0.5).abs() < 0.05);
+        assert!((fitted.partial_sill - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_ordinary_kriging() {
+        let model = VariogramModel {
+            model_type: VariogramModelType::Exponential,
+            nugget: 0.0,
+            partial_sill: 1.0,
+            range: 10.0,
+        };
+        let x = vec![0.0, 4.0, 0.0, 4.0];
+        let y = vec![0.0, 0.0, 4.0, 4.0];
+        let z = vec![1.0, 2.0, 3.0, 4.0];
+        // without a nugget, kriging is an exact interpolator
+        let (estimate, variance) = ordinary_kriging(&model, &x, &y, &z, 4.0, 0.0).unwrap();
+        assert!((estimate - 2.0).abs() < 1e-9);
+        assert!(variance < 1e-9);
+        // the centre is equidistant from the points, which are weighted equally
+        let (estimate, variance) = ordinary_kriging(&model, &x, &y, &z, 2.0, 2.0).unwrap();
+        assert!((estimate - 2.5).abs() < 1e-9);
+        assert!(variance > 0.0 && variance < model.sill());
+        // coincident points make the system singular
+        let x = vec![1.0, 1.0];
+        let y = vec![1.0, 1.0];
+        assert!(ordinary_kriging(&model, &x, &y, &[1.0, 2.0], 0.0, 0.0).is_none());
+    }
+}
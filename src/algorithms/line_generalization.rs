@@ -0,0 +1,634 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::f64;
+use structures::Point2D;
+
+/// Simplifies a line using the Douglas-Peucker algorithm, retaining only those vertices
+/// that deviate from the simplified line by more than `tolerance`. The end points are
+/// always retained, such that a closed ring remains closed.
+pub fn douglas_peucker(points: &[Point2D], tolerance: f64) -> Vec<Point2D> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    let mut stack = vec![(0, n - 1)];
+    while let Some((a, b)) = stack.pop() {
+        let mut max_dist = 0f64;
+        let mut index = a;
+        for i in a + 1..b {
+            let dist = distance_to_segment(&points[i], &points[a], &points[b]);
+            if dist > max_dist {
+                max_dist = dist;
+                index = i;
+            }
+        }
+        if max_dist > tolerance {
+            keep[index] = true;
+            stack.push((a, index));
+            stack.push((index, b));
+        }
+    }
+    (0..n).filter(|&i| keep[i]).map(|i| points[i]).collect()
+}
+
+/// Simplifies a line using the Visvalingam-Whyatt algorithm, which repeatedly removes
+/// the vertex forming the triangle of least area with its neighbours, until all of the
+/// remaining vertices have effective areas of at least `min_area`. The end points are
+/// always retained, and a closed ring (with its last vertex equal to its first) retains
+/// at least three distinct vertices.
+pub fn visvalingam_whyatt(points: &[Point2D], min_area: f64) -> Vec<Point2D> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let min_remaining = if points[0] == points[n - 1] { 4 } else { 2 };
+    let mut prev: Vec<usize> = (0..n).map(|i| if i > 0 { i - 1 } else { 0 }).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+    let mut area = vec![f64::INFINITY; n];
+    let mut removed = vec![false; n];
+    let mut heap = BinaryHeap::new();
+    for i in 1..n - 1 {
+        area[i] = triangle_area(&points[i - 1], &points[i], &points[i + 1]);
+        heap.push(Vertex {
+            area: area[i],
+            index: i,
+        });
+    }
+    let mut num_remaining = n;
+    while let Some(v) = heap.pop() {
+        if removed[v.index] || v.area != area[v.index] {
+            continue; // a stale entry
+        }
+        if v.area >= min_area || num_remaining <= min_remaining {
+            break;
+        }
+        removed[v.index] = true;
+        num_remaining -= 1;
+        let (p, q) = (prev[v.index], next[v.index]);
+        next[p] = q;
+        prev[q] = p;
+        for &j in &[p, q] {
+            if j > 0 && j < n - 1 {
+                // effective areas never decrease, so that the removal order is monotonic
+                let a = triangle_area(&points[prev[j]], &points[j], &points[next[j]]);
+                area[j] = a.max(v.area);
+                heap.push(Vertex {
+                    area: area[j],
+                    index: j,
+                });
+            }
+        }
+    }
+    (0..n).filter(|&i| !removed[i]).map(|i| points[i]).collect()
+}
+
+/// Smooths a line using Chaikin's corner-cutting algorithm. Each iteration replaces every
+/// edge with two points, a quarter and three quarters of the way along it. The end points
+/// of an open line are retained, while a closed ring (`closed`, with its last vertex
+/// equal to its first) is cut at all of its corners and remains closed.
+pub fn chaikin_smoothing(points: &[Point2D], iterations: usize, closed: bool) -> Vec<Point2D> {
+    let mut line = points.to_vec();
+    for _ in 0..iterations {
+        let n = line.len();
+        if n < 3 {
+            break;
+        }
+        let mut smoothed = Vec::with_capacity(2 * n);
+        if !closed {
+            smoothed.push(line[0]);
+        }
+        for i in 0..n - 1 {
+            let (a, b) = (line[i], line[i + 1]);
+            smoothed.push(Point2D::new(0.75 * a.x + 0.25 * b.x, 0.75 * a.y + 0.25 * b.y));
+            smoothed.push(Point2D::new(0.25 * a.x + 0.75 * b.x, 0.25 * a.y + 0.75 * b.y));
+        }
+        if closed {
+            let p = smoothed[0];
+            smoothed.push(p);
+        } else {
+            smoothed.push(line[n - 1]);
+        }
+        line = smoothed;
+    }
+    line
+}
+
+/// Smooths a line using the Polynomial Approximation with Exponential Kernel (PAEK)
+/// method of Bodansky et al. (2002). The line is resampled at regular intervals along its
+/// length, and each sample is replaced by the value, at that sample, of a quadratic
+/// polynomial in the distance along the line, fitted to the samples within a window of
+/// length `tolerance` using Gaussian weights. Larger tolerances give smoother lines. The
+/// end points of an open line are retained, while the window wraps around a closed ring
+/// (`closed`, with its last vertex equal to its first).
+pub fn paek_smoothing(points: &[Point2D], tolerance: f64, closed: bool) -> Vec<Point2D> {
+    let n = points.len();
+    if n < 3 || tolerance <= 0f64 {
+        return points.to_vec();
+    }
+    let mut positions = vec![0f64; n];
+    for i in 1..n {
+        positions[i] = positions[i - 1] + points[i].distance(&points[i - 1]);
+    }
+    let length = positions[n - 1];
+    if length == 0f64 {
+        return points.to_vec();
+    }
+
+    // resample the line at intervals of a tenth of the tolerance
+    let num_samples = ((length / (tolerance / 10f64)).ceil() as usize).max(2);
+    let spacing = length / num_samples as f64;
+    let mut samples = Vec::with_capacity(num_samples + 1);
+    let mut segment = 0;
+    for k in 0..=num_samples {
+        let t = (k as f64 * spacing).min(length);
+        while segment < n - 2 && positions[segment + 1] < t {
+            segment += 1;
+        }
+        let (a, b) = (points[segment], points[segment + 1]);
+        let len = positions[segment + 1] - positions[segment];
+        let f = if len > 0f64 {
+            (t - positions[segment]) / len
+        } else {
+            0f64
+        };
+        samples.push(Point2D::new(a.x + f * (b.x - a.x), a.y + f * (b.y - a.y)));
+    }
+
+    let half_window = tolerance / 2f64;
+    let sigma = half_window / 2f64;
+    let reach = (half_window / spacing).floor() as isize;
+    let m = num_samples as isize; // the number of distinct samples on a closed ring
+    let mut smoothed = samples.clone();
+    for k in 0..=num_samples {
+        if !closed && (k == 0 || k == num_samples) {
+            continue;
+        }
+        // normal equations for a weighted least-squares quadratic in the scaled offset s
+        let mut sw = [0f64; 5]; // sums of w * s^p for p in 0..5
+        let mut sx = [0f64; 3];
+        let mut sy = [0f64; 3];
+        for d in -reach..=reach {
+            let mut j = k as isize + d;
+            if closed {
+                j = ((j % m) + m) % m;
+            } else if j < 0 || j > m {
+                continue;
+            }
+            let s = d as f64 * spacing / half_window;
+            let w = (-(d as f64 * spacing).powi(2) / (2f64 * sigma * sigma)).exp();
+            let p = samples[j as usize];
+            let mut sp = w;
+            for power in 0..5 {
+                sw[power] += sp;
+                if power < 3 {
+                    sx[power] += sp * p.x;
+                    sy[power] += sp * p.y;
+                }
+                sp *= s;
+            }
+        }
+        let a = [
+            [sw[0], sw[1], sw[2]],
+            [sw[1], sw[2], sw[3]],
+            [sw[2], sw[3], sw[4]],
+        ];
+        if let (Some(x), Some(y)) = (solve_constant_term(&a, &sx), solve_constant_term(&a, &sy)) {
+            smoothed[k] = Point2D::new(x, y);
+        }
+    }
+    if closed {
+        smoothed[num_samples] = smoothed[0];
+    } else {
+        smoothed[0] = points[0];
+        smoothed[num_samples] = points[n - 1];
+    }
+    smoothed
+}
+
+/// Smooths a line using a moving average of the positions of each vertex and its
+/// neighbours within a window of `filter` vertices. The end points of an open line are
+/// retained, while the window wraps around a closed ring (`closed`, with its last vertex
+/// equal to its first). Open lines of fewer than four vertices and rings of fewer than
+/// six are not smoothed.
+pub fn moving_average_smoothing(points: &[Point2D], filter: usize, closed: bool) -> Vec<Point2D> {
+    let n = points.len() as isize;
+    let half_filter = (filter / 2) as isize;
+    let mut smoothed = points.to_vec();
+    if closed && n > 5 {
+        // the last point, which duplicates the first, isn't included in the window
+        for i in 0..n - 1 {
+            let (mut x, mut y, mut num) = (0f64, 0f64, 0f64);
+            for j in (i - half_filter)..=(i + half_filter) {
+                let k = if j < 0 {
+                    n - 1 + j
+                } else if j > n - 1 {
+                    j - (n - 1)
+                } else {
+                    j
+                };
+                if k >= 0 && k <= n - 1 {
+                    num += 1f64;
+                    x += points[k as usize].x;
+                    y += points[k as usize].y;
+                }
+            }
+            smoothed[i as usize] = Point2D::new(x / num, y / num);
+        }
+        smoothed[n as usize - 1] = smoothed[0];
+    } else if !closed && n > 3 {
+        for i in 1..n - 1 {
+            let (mut x, mut y, mut num) = (0f64, 0f64, 0f64);
+            for j in (i - half_filter)..=(i + half_filter) {
+                if j >= 0 && j <= n - 1 {
+                    num += 1f64;
+                    x += points[j as usize].x;
+                    y += points[j as usize].y;
+                }
+            }
+            smoothed[i as usize] = Point2D::new(x / num, y / num);
+        }
+    }
+    smoothed
+}
+
+/// Applies a generalization `operation`, i.e. a simplification or smoothing method, to
+/// each of a set of lines, or of closed rings if `closed` is true, returning the
+/// generalized lines in the same order. The operation is given a line and whether it is a
+/// closed ring, in which case it must return a closed ring; otherwise it must retain the
+/// line's end points.
+///
+/// If `preserve_topology` is true, boundaries shared by several lines or rings, e.g. the
+/// common edge of two adjacent polygons, are generalized identically, so that no gaps or
+/// overlaps open up between them. The lines are split into arcs at nodes, i.e. the
+/// vertices where three or more arcs meet and the end points of open lines, and each arc
+/// is generalized once, with its end nodes fixed in place. Rings without nodes are
+/// generalized as a whole. Vertices are shared if they have identical coordinates.
+///
+/// Rings that would collapse to fewer than three distinct vertices are returned
+/// unaltered. Where a ring is generalized by arcs and the number of its vertices doesn't
+/// change, it retains its original starting vertex.
+pub fn generalize_lines<F>(
+    lines: &[Vec<Point2D>],
+    closed: bool,
+    preserve_topology: bool,
+    operation: F,
+) -> Vec<Vec<Point2D>>
+where
+    F: Fn(&[Point2D], bool) -> Vec<Point2D>,
+{
+    let lines: Vec<Vec<Point2D>> = lines
+        .iter()
+        .map(|line| {
+            let mut line = line.clone();
+            if closed && line.len() > 0 && line[0] != line[line.len() - 1] {
+                let p = line[0];
+                line.push(p);
+            }
+            line
+        })
+        .collect();
+
+    let mut nodes: HashSet<(u64, u64)> = HashSet::new();
+    if preserve_topology {
+        let mut neighbours: HashMap<(u64, u64), Vec<(u64, u64)>> = HashMap::new();
+        for line in &lines {
+            for i in 1..line.len() {
+                let (a, b) = (point_key(&line[i - 1]), point_key(&line[i]));
+                if a == b {
+                    continue;
+                }
+                for &(p, q) in &[(a, b), (b, a)] {
+                    let v = neighbours.entry(p).or_insert(vec![]);
+                    if !v.contains(&q) {
+                        v.push(q);
+                    }
+                }
+            }
+            if !closed && line.len() > 0 {
+                nodes.insert(point_key(&line[0]));
+                nodes.insert(point_key(&line[line.len() - 1]));
+            }
+        }
+        for (p, v) in &neighbours {
+            if v.len() != 2 {
+                nodes.insert(*p);
+            }
+        }
+    }
+
+    let mut arcs: HashMap<Vec<(u64, u64)>, Vec<Point2D>> = HashMap::new();
+    let mut ret = Vec::with_capacity(lines.len());
+    for line in &lines {
+        let n = line.len();
+        let min_points = if closed { 4 } else { 2 };
+        if n < min_points {
+            ret.push(line.clone());
+            continue;
+        }
+        let num_distinct = if closed { n - 1 } else { n };
+        let start = (0..num_distinct).find(|&i| nodes.contains(&point_key(&line[i])));
+        let generalized = match start {
+            None => operation(line, closed),
+            Some(s) => {
+                // a closed ring is rotated to begin at its first node
+                let line: Vec<Point2D> = if closed {
+                    line[s..n - 1].iter().chain(line[..=s].iter()).cloned().collect()
+                } else {
+                    line.clone()
+                };
+                let splits: Vec<usize> = (0..n)
+                    .filter(|&i| i == 0 || i == n - 1 || nodes.contains(&point_key(&line[i])))
+                    .collect();
+                let mut generalized = vec![line[0]];
+                for w in splits.windows(2) {
+                    let arc = &line[w[0]..=w[1]];
+                    let key: Vec<(u64, u64)> = arc.iter().map(point_key).collect();
+                    let reversed: Vec<(u64, u64)> = key.iter().rev().cloned().collect();
+                    let result = if reversed < key {
+                        let mut result = arcs
+                            .entry(reversed)
+                            .or_insert_with(|| {
+                                let arc: Vec<Point2D> = arc.iter().rev().cloned().collect();
+                                operation(&arc, false)
+                            })
+                            .clone();
+                        result.reverse();
+                        result
+                    } else {
+                        arcs.entry(key)
+                            .or_insert_with(|| operation(arc, false))
+                            .clone()
+                    };
+                    generalized.extend_from_slice(&result[1..]);
+                }
+                if closed && s > 0 && generalized.len() == n {
+                    // restore the original starting vertex
+                    generalized.pop();
+                    let offset = n - 1 - s;
+                    generalized.rotate_left(offset);
+                    let p = generalized[0];
+                    generalized.push(p);
+                }
+                generalized
+            }
+        };
+        if closed && count_distinct(&generalized) < 3 {
+            ret.push(line.clone());
+        } else {
+            ret.push(generalized);
+        }
+    }
+    ret
+}
+
+fn point_key(p: &Point2D) -> (u64, u64) {
+    (p.x.to_bits(), p.y.to_bits())
+}
+
+/// Counts the distinct vertices of a closed ring, excluding its last vertex.
+fn count_distinct(ring: &[Point2D]) -> usize {
+    let mut keys: Vec<(u64, u64)> = ring[..ring.len().saturating_sub(1)]
+        .iter()
+        .map(point_key)
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys.len()
+}
+
+fn distance_to_segment(p: &Point2D, a: &Point2D, b: &Point2D) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0f64 {
+        return p.distance(a);
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).max(0f64).min(1f64);
+    p.distance(&Point2D::new(a.x + t * dx, a.y + t * dy))
+}
+
+fn triangle_area(a: &Point2D, b: &Point2D, c: &Point2D) -> f64 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2f64
+}
+
+/// Solves the 3 x 3 system a * c = b by Cramer's rule, returning only the first
+/// coefficient, c[0], or None if the system is singular.
+fn solve_constant_term(a: &[[f64; 3]; 3], b: &[f64; 3]) -> Option<f64> {
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(a);
+    if d.abs() <= f64::EPSILON * (a[0][0] * a[1][1] * a[2][2]).abs() {
+        return None;
+    }
+    let mut m = *a;
+    for r in 0..3 {
+        m[r][0] = b[r];
+    }
+    Some(det(&m) / d)
+}
+
+/// A vertex in the Visvalingam-Whyatt heap, ordered such that the vertex with the least
+/// effective area is at the top of the heap.
+#[derive(Clone, Copy)]
+struct Vertex {
+    area: f64,
+    index: usize,
+}
+
+impl PartialEq for Vertex {
+    fn eq(&self, other: &Vertex) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Vertex {}
+
+impl PartialOrd for Vertex {
+    fn partial_cmp(&self, other: &Vertex) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Vertex {
+    fn cmp(&self, other: &Vertex) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        chaikin_smoothing, douglas_peucker, generalize_lines, moving_average_smoothing,
+        paek_smoothing, visvalingam_whyatt,
+    };
+    use structures::Point2D;
+
+    fn zigzag() -> Vec<Point2D> {
+        vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.1),
+            Point2D::new(2.0, -0.1),
+            Point2D::new(3.0, 5.0),
+            Point2D::new(4.0, 6.0),
+            Point2D::new(5.0, 7.0),
+            Point2D::new(6.0, 8.1),
+            Point2D::new(7.0, 9.0),
+        ]
+    }
+
+    #[test]
+    fn test_douglas_peucker() {
+        let line = zigzag();
+        let simplified = douglas_peucker(&line, 0.5);
+        assert_eq!(simplified, vec![line[0], line[2], line[3], line[7]]);
+        assert_eq!(douglas_peucker(&line, 100.0), vec![line[0], line[7]]);
+        // only the collinear vertex is removed with a zero tolerance
+        assert_eq!(douglas_peucker(&line, 0.0).len(), line.len() - 1);
+    }
+
+    #[test]
+    fn test_visvalingam_whyatt() {
+        let line = zigzag();
+        let simplified = visvalingam_whyatt(&line, 0.5);
+        assert_eq!(simplified[0], line[0]);
+        assert_eq!(simplified[simplified.len() - 1], line[7]);
+        assert!(simplified.contains(&line[3]));
+        assert!(!simplified.contains(&line[1]));
+        assert_eq!(visvalingam_whyatt(&line, 1000.0).len(), 2);
+
+        // a closed ring retains at least three distinct vertices
+        let ring = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ];
+        assert_eq!(visvalingam_whyatt(&ring, 1000.0).len(), 4);
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let line = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(4.0, 4.0),
+        ];
+        let smoothed = chaikin_smoothing(&line, 1, false);
+        assert_eq!(
+            smoothed,
+            vec![
+                Point2D::new(0.0, 0.0),
+                Point2D::new(1.0, 0.0),
+                Point2D::new(3.0, 0.0),
+                Point2D::new(4.0, 1.0),
+                Point2D::new(4.0, 3.0),
+                Point2D::new(4.0, 4.0),
+            ]
+        );
+        assert_eq!(chaikin_smoothing(&line, 3, false).len(), 24);
+
+        let mut ring = line.clone();
+        ring.push(line[0]);
+        let smoothed = chaikin_smoothing(&ring, 2, true);
+        assert_eq!(smoothed.len(), 13);
+        assert_eq!(smoothed[0], smoothed[12]);
+
+        // a straight line remains straight, with fixed end points
+        let straight: Vec<Point2D> = (0..11).map(|i| Point2D::new(i as f64, 2.0)).collect();
+        let smoothed = paek_smoothing(&straight, 3.0, false);
+        assert_eq!(smoothed[0], straight[0]);
+        assert_eq!(smoothed[smoothed.len() - 1], straight[10]);
+        for p in &smoothed {
+            assert!((p.y - 2.0).abs() < 1e-9);
+        }
+        // the corner of a square ring is rounded off
+        let square = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 10.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ];
+        let smoothed = paek_smoothing(&square, 4.0, true);
+        assert_eq!(smoothed[0], smoothed[smoothed.len() - 1]);
+        assert!(smoothed[0].x > 0.1 && smoothed[0].y > 0.1);
+
+        let smoothed = moving_average_smoothing(&straight, 3, false);
+        assert_eq!(smoothed, straight);
+        let smoothed = moving_average_smoothing(&zigzag(), 3, false);
+        assert!((smoothed[1].y - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generalize_lines_preserving_topology() {
+        // two squares sharing an edge with a wiggle in it, which should be simplified
+        // identically for both squares
+        let left = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 10.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(10.1, 5.0),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ];
+        let right = vec![
+            Point2D::new(10.0, 0.0),
+            Point2D::new(10.1, 5.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(20.0, 10.0),
+            Point2D::new(20.0, 0.0),
+            Point2D::new(10.0, 0.0),
+        ];
+        let lines = vec![left.clone(), right.clone()];
+        let simplified = generalize_lines(&lines, true, true, |l, _| douglas_peucker(l, 0.5));
+        assert_eq!(simplified[0].len(), 5);
+        assert!(!simplified[0].contains(&left[3]));
+        assert!(!simplified[1].contains(&left[3]));
+        for p in &[Point2D::new(10.0, 0.0), Point2D::new(10.0, 10.0)] {
+            assert!(simplified[0].contains(p) && simplified[1].contains(p));
+        }
+
+        // without topology, each ring is simplified from its own starting vertex
+        let simplified = generalize_lines(&lines, true, false, |l, _| douglas_peucker(l, 0.5));
+        assert_eq!(simplified[0][0], left[0]);
+        assert_eq!(simplified[1][0], right[0]);
+
+        // vertex-preserving operations retain the starting vertex of each ring
+        let smoothed = generalize_lines(&lines, true, true, |l, c| {
+            moving_average_smoothing(l, 3, c)
+        });
+        assert_eq!(smoothed[0].len(), left.len());
+        assert_eq!(smoothed[0][2], left[2]);
+        assert_eq!(smoothed[0][4], left[4]);
+        assert_eq!(smoothed[0][3], smoothed[1][1]);
+        assert!(smoothed[0][1] != left[1]);
+
+        // a ring collapsing to a line is returned unaltered
+        let thin = vec![vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(5.0, 0.1),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ]];
+        let simplified = generalize_lines(&thin, true, false, |l, _| douglas_peucker(l, 1.0));
+        assert_eq!(simplified[0], thin[0]);
+    }
+}
@@ -2,7 +2,7 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 15/10/2018
-Last Modified: 15/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -67,9 +67,20 @@ pub fn find_split_points_at_line_intersections(line1: &mut Polyline, line2: &mut
     }
 }
 
+/// Returns the point on the line segment from `a` to `b` that is nearest to `p`.
+pub fn nearest_point_on_segment(p: &Point2D, a: &Point2D, b: &Point2D) -> Point2D {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0f64 {
+        return *a;
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).max(0f64).min(1f64);
+    Point2D::new(a.x + t * dx, a.y + t * dy)
+}
+
 #[cfg(test)]
 mod test {
-    use super::find_line_intersections;
+    use super::{find_line_intersections, nearest_point_on_segment};
     use structures::{LineSegment, Point2D};
 
     #[test]
@@ -134,4 +145,17 @@ mod test {
         )];
         assert_eq!(intersections, intersections_should_be);
     }
+
+    #[test]
+    fn test_nearest_point_on_segment() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(10.0, 0.0);
+        assert_eq!(
+            nearest_point_on_segment(&Point2D::new(4.0, 3.0), &a, &b),
+            Point2D::new(4.0, 0.0)
+        );
+        assert_eq!(nearest_point_on_segment(&Point2D::new(-2.0, 1.0), &a, &b), a);
+        assert_eq!(nearest_point_on_segment(&Point2D::new(12.0, -1.0), &a, &b), b);
+        assert_eq!(nearest_point_on_segment(&Point2D::new(1.0, 1.0), &a, &a), a);
+    }
 }
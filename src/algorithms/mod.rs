@@ -6,25 +6,59 @@ Last Modified: 25/09/2018
 License: MIT
 */
 // private sub-module defined in other files
+mod constrained_triangulation;
 mod convex_hull;
 mod delaunay_triangulation;
+mod eigen_features;
+mod fft;
+mod filters;
+mod geostatistics;
 mod is_clockwise_order;
+mod line_generalization;
 mod line_ops;
 mod minimum_bounding_box;
+mod point_pattern;
 mod poly_area;
 mod poly_ops;
 mod poly_perimeter;
+mod polygon_overlay;
+mod robust_predicates;
 mod smallest_enclosing_circle;
+mod wavelet;
 
 // exports identifiers from private sub-modules in the current module namespace
+pub use self::constrained_triangulation::{constrained_triangulate, triangulate_polygon};
 pub use self::convex_hull::convex_hull;
 pub use self::delaunay_triangulation::{triangulate, Triangulation};
+pub use self::eigen_features::{eigen_features, EigenFeatures};
+pub use self::fft::{fft, fft_2d};
+pub use self::filters::{moving_extrema, rank_filter, RankStatistic, NO_BIN};
+pub use self::geostatistics::{
+    experimental_variogram, fit_variogram, ordinary_kriging, VariogramBin, VariogramModel,
+    VariogramModelType,
+};
 pub use self::is_clockwise_order::is_clockwise_order;
-pub use self::line_ops::{find_line_intersections, find_split_points_at_line_intersections};
+pub use self::line_generalization::{
+    chaikin_smoothing, douglas_peucker, generalize_lines, moving_average_smoothing,
+    paek_smoothing, visvalingam_whyatt,
+};
+pub use self::line_ops::{
+    find_line_intersections, find_split_points_at_line_intersections, nearest_point_on_segment,
+};
 pub use self::minimum_bounding_box::{minimum_bounding_box, MinimizationCriterion};
+pub use self::point_pattern::{
+    nearest_neighbour_index, quadrat_statistics, ripleys_k, ripleys_l, NearestNeighbourStatistics,
+    QuadratStatistics,
+};
 pub use self::poly_area::polygon_area;
 pub use self::poly_ops::{
     interior_point, point_in_poly, poly_in_poly, poly_is_convex, winding_number,
 };
 pub use self::poly_perimeter::polygon_perimeter;
+pub use self::polygon_overlay::{
+    clip_polyline, group_rings, orient_rings, point_in_rings, polygon_overlay, repair_rings,
+    OverlayOperation,
+};
+pub use self::robust_predicates::{incircle, orient2d, segment_intersection, SegmentIntersection};
 pub use self::smallest_enclosing_circle::smallest_enclosing_circle;
+pub use self::wavelet::{dwt_2d, idwt_2d};
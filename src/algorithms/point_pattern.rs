@@ -0,0 +1,259 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::f64;
+use std::f64::consts::PI;
+use structures::{DistanceMetric, KdTree, Point2D};
+
+/// The statistics of the Clark and Evans (1954) nearest-neighbour index of a point pattern.
+#[derive(Clone, Copy, Debug)]
+pub struct NearestNeighbourStatistics {
+    pub num_points: usize,
+    pub area: f64,
+    /// The mean distance from each point to its nearest neighbour.
+    pub observed_mean_distance: f64,
+    /// The expected mean nearest-neighbour distance of a random (Poisson) pattern.
+    pub expected_mean_distance: f64,
+    /// The ratio of the observed to the expected mean distance, i.e. the index, which is
+    /// less than 1 for clustered patterns and greater than 1 for dispersed patterns.
+    pub ratio: f64,
+    pub standard_error: f64,
+    /// The z-score of the observed mean distance under complete spatial randomness.
+    pub z_score: f64,
+}
+
+/// Calculates the nearest-neighbour index of a set of points within a study area of the
+/// given area. Returns None if there are fewer than two points or the area is not positive.
+pub fn nearest_neighbour_index(
+    points: &[Point2D],
+    area: f64,
+) -> Option<NearestNeighbourStatistics> {
+    let n = points.len();
+    if n < 2 || area <= 0f64 {
+        return None;
+    }
+    let tree = build_tree(points);
+    let mut total = 0f64;
+    for p in points {
+        // the nearest point is the point itself, or a coincident point
+        let ret = tree.knn_search(&[p.x, p.y], 2);
+        total += ret[1].1;
+    }
+    let density = n as f64 / area;
+    let observed = total / n as f64;
+    let expected = 0.5 / density.sqrt();
+    let standard_error = 0.26136 / (n as f64 * density).sqrt();
+    Some(NearestNeighbourStatistics {
+        num_points: n,
+        area: area,
+        observed_mean_distance: observed,
+        expected_mean_distance: expected,
+        ratio: observed / expected,
+        standard_error: standard_error,
+        z_score: (observed - expected) / standard_error,
+    })
+}
+
+/// Estimates Ripley's K function of a set of points within a study area of the given area,
+/// at each of the given distances. If the distance from each point to the boundary of the
+/// study area is given (`edge_distances`), the border (reduced-sample) edge correction is
+/// applied, i.e. at each distance, only the points that are at least that far from the
+/// boundary are used as centres; otherwise, no edge correction is applied. K is NaN at
+/// distances at which there are no such points.
+pub fn ripleys_k(
+    points: &[Point2D],
+    area: f64,
+    edge_distances: Option<&[f64]>,
+    distances: &[f64],
+) -> Vec<f64> {
+    let n = points.len();
+    let mut pair_counts = vec![0usize; distances.len()];
+    let mut centre_counts = vec![0usize; distances.len()];
+    let max_distance = distances.iter().cloned().fold(0f64, f64::max);
+    if n > 1 && max_distance > 0f64 {
+        let tree = build_tree(points);
+        for i in 0..n {
+            let mut neighbours: Vec<f64> = tree
+                .radius_search(&[points[i].x, points[i].y], max_distance)
+                .into_iter()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, d)| d)
+                .collect();
+            neighbours.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            for k in 0..distances.len() {
+                if let Some(edge) = edge_distances {
+                    if edge[i] < distances[k] {
+                        continue;
+                    }
+                }
+                centre_counts[k] += 1;
+                pair_counts[k] += count_within(&neighbours, distances[k]);
+            }
+        }
+    } else if n > 1 {
+        for k in 0..distances.len() {
+            centre_counts[k] = n;
+        }
+    }
+    let density = n as f64 / area;
+    (0..distances.len())
+        .map(|k| {
+            if centre_counts[k] == 0 || density <= 0f64 {
+                f64::NAN
+            } else {
+                pair_counts[k] as f64 / (centre_counts[k] as f64 * density)
+            }
+        })
+        .collect()
+}
+
+/// Returns Besag's L function, the variance-stabilized form of Ripley's K, which equals the
+/// distance for a random pattern.
+pub fn ripleys_l(k: f64) -> f64 {
+    (k / PI).sqrt()
+}
+
+/// The statistics of a set of quadrat counts.
+#[derive(Clone, Copy, Debug)]
+pub struct QuadratStatistics {
+    pub num_quadrats: usize,
+    pub mean: f64,
+    pub variance: f64,
+    /// The variance-to-mean ratio, which is 1 for a random pattern, greater than 1 for a
+    /// clustered pattern, and less than 1 for a dispersed pattern.
+    pub variance_mean_ratio: f64,
+    /// The chi-square statistic of the counts, i.e. (m - 1) times the variance-to-mean ratio.
+    pub chi_square: f64,
+    pub degrees_of_freedom: usize,
+}
+
+/// Calculates the variance-to-mean ratio test statistics of a set of quadrat counts. Returns
+/// None if there are fewer than two quadrats or no points.
+pub fn quadrat_statistics(counts: &[f64]) -> Option<QuadratStatistics> {
+    let m = counts.len();
+    if m < 2 {
+        return None;
+    }
+    let mean = counts.iter().sum::<f64>() / m as f64;
+    if mean <= 0f64 {
+        return None;
+    }
+    let sum_sqr_dev: f64 = counts.iter().map(|c| (c - mean) * (c - mean)).sum();
+    let variance = sum_sqr_dev / (m - 1) as f64;
+    Some(QuadratStatistics {
+        num_quadrats: m,
+        mean: mean,
+        variance: variance,
+        variance_mean_ratio: variance / mean,
+        chi_square: sum_sqr_dev / mean,
+        degrees_of_freedom: m - 1,
+    })
+}
+
+fn build_tree(points: &[Point2D]) -> KdTree<usize> {
+    let mut coords = Vec::with_capacity(2 * points.len());
+    for p in points {
+        coords.push(p.x);
+        coords.push(p.y);
+    }
+    KdTree::new(
+        2,
+        coords,
+        (0..points.len()).collect(),
+        DistanceMetric::Euclidean,
+    )
+}
+
+// returns the number of values in the sorted list that are less than or equal to value
+fn count_within(sorted: &[f64], value: f64) -> usize {
+    let (mut low, mut high) = (0, sorted.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if sorted[mid] <= value {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod test {
+    use super::{nearest_neighbour_index, quadrat_statistics, ripleys_k, ripleys_l};
+    use structures::Point2D;
+
+    fn grid(size: usize) -> Vec<Point2D> {
+        let mut points = vec![];
+        for row in 0..size {
+            for col in 0..size {
+                points.push(Point2D::new(col as f64 + 0.5, row as f64 + 0.5));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_nearest_neighbour_index() {
+        // a regular grid is highly dispersed
+        let stats = nearest_neighbour_index(&grid(10), 100.0).unwrap();
+        assert_eq!(stats.observed_mean_distance, 1.0);
+        assert_eq!(stats.expected_mean_distance, 0.5);
+        assert_eq!(stats.ratio, 2.0);
+        assert!(stats.z_score > 10.0);
+
+        // tight clusters are clustered
+        let mut points = vec![];
+        for p in grid(3) {
+            points.push(Point2D::new(p.x * 30.0, p.y * 30.0));
+            points.push(Point2D::new(p.x * 30.0 + 0.1, p.y * 30.0));
+        }
+        let stats = nearest_neighbour_index(&points, 8100.0).unwrap();
+        assert!(stats.ratio < 0.1);
+        assert!(stats.z_score < -3.0);
+
+        assert!(nearest_neighbour_index(&points[0..1], 100.0).is_none());
+    }
+
+    #[test]
+    fn test_ripleys_k() {
+        let points = grid(10);
+        let distances = vec![0.5, 1.0, 1.5];
+        // without edge correction, each interior point has 4 neighbours within a distance of
+        // 1, but those on the edges and corners have 3 and 2, respectively
+        let k = ripleys_k(&points, 100.0, None, &distances);
+        assert_eq!(k[0], 0.0);
+        assert!((k[1] - 360.0 / 100.0).abs() < 1e-12);
+
+        // with the border correction, only points at least 1 from the edge are centres
+        let edges: Vec<f64> = points
+            .iter()
+            .map(|p| p.x.min(p.y).min(10.0 - p.x).min(10.0 - p.y))
+            .collect();
+        let k = ripleys_k(&points, 100.0, Some(&edges), &distances);
+        assert!((k[1] - 4.0).abs() < 1e-12);
+        assert!((k[2] - 8.0).abs() < 1e-12);
+        assert_eq!(ripleys_l(k[1] * ::std::f64::consts::PI), 2.0);
+
+        // no points are 6 from the edge
+        let k = ripleys_k(&points, 100.0, Some(&edges), &[6.0]);
+        assert!(k[0].is_nan());
+    }
+
+    #[test]
+    fn test_quadrat_statistics() {
+        let stats = quadrat_statistics(&[2.0, 2.0, 2.0, 2.0]).unwrap();
+        assert_eq!(stats.variance_mean_ratio, 0.0);
+        let stats = quadrat_statistics(&[0.0, 4.0, 0.0, 4.0]).unwrap();
+        assert_eq!(stats.mean, 2.0);
+        assert_eq!(stats.variance, 16.0 / 3.0);
+        assert_eq!(stats.chi_square, 8.0);
+        assert_eq!(stats.degrees_of_freedom, 3);
+        assert!(quadrat_statistics(&[0.0, 0.0]).is_none());
+    }
+}
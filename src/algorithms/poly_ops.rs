@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 30/08/2018
-Last Modified: 30/08/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use algorithms::orient2d;
 use structures::Point2D;
 
 /// Tests if a point is Left|On|Right of an infinite line,
@@ -16,8 +17,10 @@ use structures::Point2D;
 /// Return: > 0 for p2 left of the line through p0 and p1
 ///         = 0 for p2 on the line through p0 and p1
 ///         < 0 for p2 right of the line through p0 and p1
+///
+/// The sign of the result is exact (see `orient2d`).
 fn is_left(p0: &Point2D, p1: &Point2D, p2: &Point2D) -> f64 {
-    (p1.x - p0.x) * (p2.y - p0.y) - (p2.x - p0.x) * (p1.y - p0.y)
+    orient2d(p0, p1, p2)
 }
 
 /// Tests whether a point is within in a polygon using the winding number (wn).
@@ -0,0 +1,806 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{point_in_poly, segment_intersection, SegmentIntersection};
+use std::collections::HashMap;
+use std::f64;
+use structures::{BoundingBox, Point2D, RTree};
+
+/// The set operation performed by `polygon_overlay`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverlayOperation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+/// Performs a boolean overlay of two polygons, each given as a set of closed rings
+/// (outer rings and holes) and interpreted using the even-odd rule. The edges of both
+/// polygons are split at their mutual intersections and each resulting edge piece is
+/// retained if it separates the interior of the result from its exterior. The retained
+/// pieces are then linked into closed rings. The output rings are oriented following the
+/// Shapefile convention, i.e. outer rings are clockwise and holes counter-clockwise.
+///
+/// Degenerate cases, i.e. edges that touch or overlap, vertices lying on the other
+/// polygon's edges, and identical polygons, are handled explicitly. Intersections are
+/// classified using exact predicates (see `segment_intersection`), and each piece is
+/// classified by which side of it its own polygon's interior lies on, found from the
+/// orientation and nesting of its ring, and by whether it lies within the other polygon
+/// or, for pieces shared by both polygons, on which side of it the other polygon lies.
+pub fn polygon_overlay(
+    subject: &[Vec<Point2D>],
+    clip: &[Vec<Point2D>],
+    operation: OverlayOperation,
+) -> Vec<Vec<Point2D>> {
+    let subject = close_rings(subject);
+    let clip = close_rings(clip);
+    if subject.is_empty() {
+        return match operation {
+            OverlayOperation::Union => orient_rings(&clip),
+            _ => vec![],
+        };
+    }
+    if clip.is_empty() {
+        return match operation {
+            OverlayOperation::Intersection => vec![],
+            _ => orient_rings(&subject),
+        };
+    }
+    let tolerance = get_tolerance(&[&subject, &clip]);
+
+    let subject_edges = get_edges(&subject);
+    let clip_edges = get_edges(&clip);
+    let (subject_pieces, clip_pieces) = split_edges(&subject_edges, &clip_edges, tolerance);
+    let subject_index = index_edges(&subject_edges, tolerance);
+    let clip_index = index_edges(&clip_edges, tolerance);
+    let subject_left = interior_on_left(&subject);
+    let clip_left = interior_on_left(&clip);
+
+    let in_result = |in_subject: bool, in_clip: bool| match operation {
+        OverlayOperation::Intersection => in_subject && in_clip,
+        OverlayOperation::Union => in_subject || in_clip,
+        OverlayOperation::Difference => in_subject && !in_clip,
+    };
+
+    // Retain the pieces that bound the result, directed such that the interior of
+    // the result is on the right. Pieces of the clip polygon that coincide with a
+    // subject edge are skipped because they duplicate a subject piece.
+    let mut directed: Vec<(Point2D, Point2D)> = vec![];
+    for &(p1, p2, e) in &subject_pieces {
+        let own_left = subject_left[subject_edges[e].2];
+        let (clip_on_left, clip_on_right) = match find_coincident_edge(
+            &p1,
+            &p2,
+            &clip_edges,
+            &clip_index,
+            tolerance,
+        ) {
+            Some((j, same_direction)) => {
+                let left = clip_left[clip_edges[j].2] == same_direction;
+                (left, !left)
+            }
+            None => {
+                let inside = point_in_rings(&Point2D::midpoint(&p1, &p2), &clip);
+                (inside, inside)
+            }
+        };
+        let in_left = in_result(own_left, clip_on_left);
+        let in_right = in_result(!own_left, clip_on_right);
+        if in_right && !in_left {
+            directed.push((p1, p2));
+        } else if in_left && !in_right {
+            directed.push((p2, p1));
+        }
+    }
+    for &(p1, p2, e) in &clip_pieces {
+        if find_coincident_edge(&p1, &p2, &subject_edges, &subject_index, tolerance).is_some() {
+            continue;
+        }
+        let own_left = clip_left[clip_edges[e].2];
+        let in_subject = point_in_rings(&Point2D::midpoint(&p1, &p2), &subject);
+        let in_left = in_result(in_subject, own_left);
+        let in_right = in_result(in_subject, !own_left);
+        if in_right && !in_left {
+            directed.push((p1, p2));
+        } else if in_left && !in_right {
+            directed.push((p2, p1));
+        }
+    }
+
+    link_pieces(&directed, tolerance)
+}
+
+/// Clips a polyline by a polygon, given as a set of closed rings interpreted using the
+/// even-odd rule. The portions of the line within the polygon are returned if
+/// `keep_inside` is true, otherwise the portions outside of the polygon are returned.
+pub fn clip_polyline(
+    line: &[Point2D],
+    polygon: &[Vec<Point2D>],
+    keep_inside: bool,
+) -> Vec<Vec<Point2D>> {
+    let polygon = close_rings(polygon);
+    if line.len() < 2 {
+        return vec![];
+    }
+    if polygon.is_empty() {
+        return if keep_inside { vec![] } else { vec![line.to_vec()] };
+    }
+    let tolerance = get_tolerance(&[&vec![line.to_vec()], &polygon]);
+    let line_edges: Vec<(Point2D, Point2D, usize)> = (0..line.len() - 1)
+        .filter(|&i| line[i] != line[i + 1])
+        .map(|i| (line[i], line[i + 1], 0))
+        .collect();
+    let (pieces, _) = split_edges(&line_edges, &get_edges(&polygon), tolerance);
+
+    let mut ret: Vec<Vec<Point2D>> = vec![];
+    let mut current: Vec<Point2D> = vec![];
+    for (p1, p2, _) in pieces {
+        let mid = Point2D::midpoint(&p1, &p2);
+        if point_in_rings(&mid, &polygon) == keep_inside {
+            if current.is_empty() {
+                current.push(p1);
+            } else if !current[current.len() - 1].nearly_equals(&p1) {
+                ret.push(current);
+                current = vec![p1];
+            }
+            current.push(p2);
+        } else if !current.is_empty() {
+            ret.push(current);
+            current = vec![];
+        }
+    }
+    if !current.is_empty() {
+        ret.push(current);
+    }
+    ret
+}
+
+/// Returns true if the point is within a polygon composed of a set of closed rings,
+/// using the even-odd rule, i.e. the point must fall within an odd number of rings.
+pub fn point_in_rings(p: &Point2D, rings: &[Vec<Point2D>]) -> bool {
+    let mut count = 0;
+    for ring in rings {
+        if ring.len() > 3 && point_in_poly(p, ring) {
+            count += 1;
+        }
+    }
+    count % 2 == 1
+}
+
+/// Orders a set of rings produced by `polygon_overlay` such that each hole directly
+/// follows the outer ring that contains it, as required by the Shapefile format.
+/// Returns the rings grouped by outer ring.
+pub fn group_rings(rings: &[Vec<Point2D>]) -> Vec<Vec<Vec<Point2D>>> {
+    let mut outers: Vec<usize> = vec![];
+    let mut holes: Vec<usize> = vec![];
+    for i in 0..rings.len() {
+        if signed_area(&rings[i]) < 0f64 {
+            outers.push(i);
+        } else {
+            holes.push(i);
+        }
+    }
+    let mut groups: Vec<Vec<Vec<Point2D>>> =
+        outers.iter().map(|&i| vec![rings[i].clone()]).collect();
+    for h in holes {
+        // the hole belongs to the smallest outer ring that contains it
+        let p = Point2D::midpoint(&rings[h][0], &rings[h][1]);
+        let mut best: Option<usize> = None;
+        let mut best_area = f64::INFINITY;
+        for (g, &o) in outers.iter().enumerate() {
+            let area = -signed_area(&rings[o]);
+            if area < best_area && point_in_poly(&p, &rings[o]) {
+                best = Some(g);
+                best_area = area;
+            }
+        }
+        if let Some(g) = best {
+            groups[g].push(rings[h].clone());
+        }
+    }
+    groups
+}
+
+/// Rebuilds a polygon whose rings may be self-intersecting, overlap one another, or be
+/// wrongly oriented, as a set of valid rings, interpreting the input using the even-odd
+/// rule. All edges are split at their mutual intersections, including those between
+/// edges of the same ring, and pieces traced an even number of times cancel. The
+/// remaining pieces each separate the interior from the exterior and are linked into
+/// closed rings oriented following the Shapefile convention, with outer rings clockwise
+/// and holes counter-clockwise. For example, a bow-tie ring becomes two triangles.
+pub fn repair_rings(rings: &[Vec<Point2D>]) -> Vec<Vec<Point2D>> {
+    // unlike close_rings, retain rings with no net area, e.g. bow-ties
+    let mut closed = vec![];
+    for ring in rings {
+        let mut r = ring.clone();
+        if r.len() > 0 && r[0] != r[r.len() - 1] {
+            let p = r[0];
+            r.push(p);
+        }
+        if r.len() > 3 {
+            closed.push(r);
+        }
+    }
+    let rings = closed;
+    if rings.is_empty() {
+        return vec![];
+    }
+    let tolerance = get_tolerance(&[&rings]);
+    let edges = get_edges(&rings);
+    let (pieces, _) = split_edges(&edges, &edges, tolerance);
+
+    // count the coincident copies of each piece, regardless of direction
+    let key = |p: &Point2D| {
+        (
+            (p.x / (tolerance * 10f64)).round() as i64,
+            (p.y / (tolerance * 10f64)).round() as i64,
+        )
+    };
+    let mut counts: HashMap<((i64, i64), (i64, i64)), (usize, usize)> = HashMap::new();
+    for (i, &(p1, p2, _)) in pieces.iter().enumerate() {
+        let (k1, k2) = (key(&p1), key(&p2));
+        if k1 == k2 {
+            continue;
+        }
+        let k = if k1 < k2 { (k1, k2) } else { (k2, k1) };
+        counts.entry(k).or_insert((i, 0)).1 += 1;
+    }
+    let mut boundary: Vec<(Point2D, Point2D)> = counts
+        .values()
+        .filter(|&&(_, count)| count % 2 == 1)
+        .map(|&(i, _)| (pieces[i].0, pieces[i].1))
+        .collect();
+    boundary.sort_by(|a, b| {
+        (a.0.x, a.0.y, a.1.x, a.1.y)
+            .partial_cmp(&(b.0.x, b.0.y, b.1.x, b.1.y))
+            .unwrap()
+    });
+    let index = RTree::new(
+        boundary
+            .iter()
+            .enumerate()
+            .map(|(i, &(a, b))| (BoundingBox::from_two_points(a, b), i))
+            .collect(),
+    );
+
+    // Each piece is directed such that the interior is on its right. The side that is
+    // interior is found by counting the crossings of the other pieces by a ray cast from
+    // the piece's midpoint, to the right (+x) of non-horizontal pieces, and upward (+y)
+    // from horizontal pieces.
+    let mut directed = vec![];
+    for i in 0..boundary.len() {
+        let (p1, p2) = boundary[i];
+        let m = Point2D::midpoint(&p1, &p2);
+        let horizontal = p1.y == p2.y;
+        let ray = if horizontal {
+            BoundingBox::new(m.x, m.x, m.y, f64::INFINITY)
+        } else {
+            BoundingBox::new(m.x, f64::INFINITY, m.y, m.y)
+        };
+        let mut crossings = 0;
+        for j in index.search(&ray) {
+            if j == i {
+                continue;
+            }
+            // swapping the axes of horizontal pieces allows a single test along +x
+            let (a, b, m) = if horizontal {
+                (swap(&boundary[j].0), swap(&boundary[j].1), swap(&m))
+            } else {
+                (boundary[j].0, boundary[j].1, m)
+            };
+            if (a.y > m.y) != (b.y > m.y) && a.x + (m.y - a.y) / (b.y - a.y) * (b.x - a.x) > m.x {
+                crossings += 1;
+            }
+        }
+        // whether the ray's side of the piece is the piece's right-hand side
+        let ray_on_right = if horizontal { p2.x < p1.x } else { p2.y > p1.y };
+        if (crossings % 2 == 1) == ray_on_right {
+            directed.push((p1, p2));
+        } else {
+            directed.push((p2, p1));
+        }
+    }
+    link_pieces(&directed, tolerance)
+}
+
+fn swap(p: &Point2D) -> Point2D {
+    Point2D::new(p.y, p.x)
+}
+
+/// Returns the rings, closed (the last vertex equals the first) and without
+/// degenerate rings.
+fn close_rings(rings: &[Vec<Point2D>]) -> Vec<Vec<Point2D>> {
+    let mut ret = vec![];
+    for ring in rings {
+        let mut r = ring.clone();
+        if r.len() > 0 && r[0] != r[r.len() - 1] {
+            let p = r[0];
+            r.push(p);
+        }
+        if r.len() > 3 && signed_area(&r) != 0f64 {
+            ret.push(r);
+        }
+    }
+    ret
+}
+
+/// Orients the rings of a valid polygon, i.e. one without intersecting rings, with
+/// outer rings clockwise and holes counter-clockwise, as required by the Shapefile format.
+pub fn orient_rings(rings: &[Vec<Point2D>]) -> Vec<Vec<Point2D>> {
+    let mut ret = vec![];
+    for i in 0..rings.len() {
+        let p = Point2D::midpoint(&rings[i][0], &rings[i][1]);
+        let mut depth = 0;
+        for j in 0..rings.len() {
+            if j != i && point_in_poly(&p, &rings[j]) {
+                depth += 1;
+            }
+        }
+        let mut r = rings[i].clone();
+        let clockwise = signed_area(&r) < 0f64;
+        if clockwise != (depth % 2 == 0) {
+            r.reverse();
+        }
+        ret.push(r);
+    }
+    ret
+}
+
+/// Twice the signed area of a closed ring; positive for counter-clockwise rings.
+fn signed_area(ring: &[Point2D]) -> f64 {
+    let mut area = 0f64;
+    for i in 0..ring.len() - 1 {
+        area += ring[i].x * ring[i + 1].y - ring[i + 1].x * ring[i].y;
+    }
+    area
+}
+
+/// The distance below which points are considered coincident, scaled to the extent
+/// of the data.
+fn get_tolerance(geometries: &[&Vec<Vec<Point2D>>]) -> f64 {
+    let mut bb = BoundingBox::default();
+    for g in geometries {
+        for part in g.iter() {
+            bb.expand_to(BoundingBox::from_points(part));
+        }
+    }
+    let size = bb
+        .get_width()
+        .max(bb.get_height())
+        .max(bb.max_x.abs().max(bb.min_x.abs()))
+        .max(bb.max_y.abs().max(bb.min_y.abs()));
+    if size > 0f64 {
+        size * 1e-10
+    } else {
+        1e-10
+    }
+}
+
+/// Returns the edges of a set of rings, with the index of the ring of each edge.
+fn get_edges(rings: &[Vec<Point2D>]) -> Vec<(Point2D, Point2D, usize)> {
+    let mut edges = vec![];
+    for (r, ring) in rings.iter().enumerate() {
+        for i in 0..ring.len() - 1 {
+            if ring[i] != ring[i + 1] {
+                edges.push((ring[i], ring[i + 1], r));
+            }
+        }
+    }
+    edges
+}
+
+fn index_edges(edges: &[(Point2D, Point2D, usize)], tolerance: f64) -> RTree<usize> {
+    RTree::new(
+        edges
+            .iter()
+            .enumerate()
+            .map(|(i, &(a, b, _))| {
+                let mut bb = BoundingBox::from_two_points(a, b);
+                bb.expand_by(tolerance);
+                (bb, i)
+            })
+            .collect(),
+    )
+}
+
+/// Returns, for each ring of a polygon, whether the polygon's interior lies to the left
+/// of the ring's edges, which is the case for counter-clockwise outer rings and
+/// clockwise holes.
+fn interior_on_left(rings: &[Vec<Point2D>]) -> Vec<bool> {
+    let mut ret = vec![];
+    for i in 0..rings.len() {
+        let p = Point2D::midpoint(&rings[i][0], &rings[i][1]);
+        let mut depth = 0;
+        for j in 0..rings.len() {
+            if j != i && point_in_poly(&p, &rings[j]) {
+                depth += 1;
+            }
+        }
+        ret.push((signed_area(&rings[i]) > 0f64) == (depth % 2 == 0));
+    }
+    ret
+}
+
+/// Finds an edge that a piece of the other polygon lies along, returning its index and
+/// whether it runs in the same direction as the piece.
+fn find_coincident_edge(
+    p1: &Point2D,
+    p2: &Point2D,
+    edges: &[(Point2D, Point2D, usize)],
+    index: &RTree<usize>,
+    tolerance: f64,
+) -> Option<(usize, bool)> {
+    let mid = Point2D::midpoint(p1, p2);
+    let mut candidates = index.search_point(mid.x, mid.y);
+    candidates.sort();
+    for j in candidates {
+        let (a, b, _) = edges[j];
+        if distance_to_segment(&mid, &a, &b) <= tolerance
+            && distance_to_segment(p1, &a, &b) <= tolerance
+            && distance_to_segment(p2, &a, &b) <= tolerance
+        {
+            let dot = (p2.x - p1.x) * (b.x - a.x) + (p2.y - p1.y) * (b.y - a.y);
+            return Some((j, dot > 0f64));
+        }
+    }
+    None
+}
+
+fn distance_to_segment(p: &Point2D, a: &Point2D, b: &Point2D) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0f64 {
+        return p.distance(a);
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).max(0f64).min(1f64);
+    p.distance(&Point2D::new(a.x + t * dx, a.y + t * dy))
+}
+
+/// Splits two sets of edges at their mutual intersections, including the end points
+/// of collinear overlapping sections and end points lying within the tolerance of an
+/// edge of the other set. Returns the pieces of each set of edges, in their original
+/// order and direction, with the index of the edge of each piece.
+fn split_edges(
+    edges1: &[(Point2D, Point2D, usize)],
+    edges2: &[(Point2D, Point2D, usize)],
+    tolerance: f64,
+) -> (
+    Vec<(Point2D, Point2D, usize)>,
+    Vec<(Point2D, Point2D, usize)>,
+) {
+    let mut splits1: Vec<Vec<(f64, Point2D)>> = vec![vec![]; edges1.len()];
+    let mut splits2: Vec<Vec<(f64, Point2D)>> = vec![vec![]; edges2.len()];
+    let index2 = index_edges(edges2, tolerance);
+    for i in 0..edges1.len() {
+        let (p1, p2, _) = edges1[i];
+        let mut candidates = index2.search(&BoundingBox::from_two_points(p1, p2));
+        candidates.sort();
+        for j in candidates {
+            let (q1, q2, _) = edges2[j];
+            match segment_intersection(&p1, &p2, &q1, &q2) {
+                SegmentIntersection::Point(p) => {
+                    // snap to the existing vertices where the intersection is near one
+                    let p = [p1, p2, q1, q2]
+                        .iter()
+                        .find(|v| v.distance(&p) <= tolerance)
+                        .map_or(p, |v| *v);
+                    splits1[i].push((position(&p, &p1, &p2), p));
+                    splits2[j].push((position(&p, &q1, &q2), p));
+                }
+                SegmentIntersection::Overlap(a, b) => {
+                    for p in &[a, b] {
+                        splits1[i].push((position(p, &p1, &p2), *p));
+                        splits2[j].push((position(p, &q1, &q2), *p));
+                    }
+                }
+                SegmentIntersection::None => {
+                    // the edges may still touch within the tolerance
+                    for q in &[q1, q2] {
+                        if distance_to_segment(q, &p1, &p2) <= tolerance {
+                            splits1[i].push((position(q, &p1, &p2), *q));
+                        }
+                    }
+                    for p in &[p1, p2] {
+                        if distance_to_segment(p, &q1, &q2) <= tolerance {
+                            splits2[j].push((position(p, &q1, &q2), *p));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    (
+        get_pieces(edges1, &mut splits1, tolerance),
+        get_pieces(edges2, &mut splits2, tolerance),
+    )
+}
+
+/// The position of the projection of p along the segment a-b, from 0 at a to 1 at b.
+fn position(p: &Point2D, a: &Point2D, b: &Point2D) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let t = ((p.x - a.x) * dx + (p.y - a.y) * dy) / (dx * dx + dy * dy);
+    t.max(0f64).min(1f64)
+}
+
+fn get_pieces(
+    edges: &[(Point2D, Point2D, usize)],
+    splits: &mut Vec<Vec<(f64, Point2D)>>,
+    tolerance: f64,
+) -> Vec<(Point2D, Point2D, usize)> {
+    let mut pieces = vec![];
+    for i in 0..edges.len() {
+        let s = &mut splits[i];
+        s.push((0f64, edges[i].0));
+        s.push((1f64, edges[i].1));
+        s.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut prev = s[0].1;
+        for k in 1..s.len() {
+            let p = s[k].1;
+            if p.distance(&prev) > tolerance {
+                pieces.push((prev, p, i));
+                prev = p;
+            }
+        }
+        // make sure the last piece ends at the edge's end point, for continuity
+        let n = pieces.len();
+        if n > 0 && pieces[n - 1].2 == i && pieces[n - 1].1.distance(&edges[i].1) <= tolerance {
+            pieces[n - 1].1 = edges[i].1;
+        }
+    }
+    pieces
+}
+
+/// Links directed pieces into closed rings by matching their end points. Where
+/// several pieces leave a node, the one making the sharpest right turn is followed,
+/// which keeps rings that touch at a single node separate.
+fn link_pieces(pieces: &[(Point2D, Point2D)], tolerance: f64) -> Vec<Vec<Point2D>> {
+    let key = |p: &Point2D| {
+        (
+            (p.x / (tolerance * 10f64)).round() as i64,
+            (p.y / (tolerance * 10f64)).round() as i64,
+        )
+    };
+    let mut starts: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for i in 0..pieces.len() {
+        starts.entry(key(&pieces[i].0)).or_insert(vec![]).push(i);
+    }
+    let mut used = vec![false; pieces.len()];
+    let mut rings = vec![];
+    for i in 0..pieces.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+        let start_key = key(&pieces[i].0);
+        let mut ring = vec![pieces[i].0, pieces[i].1];
+        let mut current = i;
+        loop {
+            let (from, to) = pieces[current];
+            if key(&to) == start_key {
+                let p = ring[0];
+                let n = ring.len();
+                ring[n - 1] = p;
+                break;
+            }
+            let mut next: Option<usize> = None;
+            let mut best_angle = f64::INFINITY;
+            let heading = (to.y - from.y).atan2(to.x - from.x);
+            if let Some(candidates) = starts.get(&key(&to)) {
+                for &c in candidates {
+                    if used[c] {
+                        continue;
+                    }
+                    let (a, b) = pieces[c];
+                    let h = (b.y - a.y).atan2(b.x - a.x);
+                    // turn angle measured clockwise from straight ahead, in (-pi, pi]
+                    let mut turn = heading - h;
+                    while turn <= -f64::consts::PI {
+                        turn += 2f64 * f64::consts::PI;
+                    }
+                    while turn > f64::consts::PI {
+                        turn -= 2f64 * f64::consts::PI;
+                    }
+                    let angle = -turn;
+                    if angle < best_angle {
+                        best_angle = angle;
+                        next = Some(c);
+                    }
+                }
+            }
+            match next {
+                Some(c) => {
+                    used[c] = true;
+                    ring.push(pieces[c].1);
+                    current = c;
+                }
+                None => break, // an open chain; shouldn't happen for valid input
+            }
+        }
+        if ring.len() > 3 && ring[0] == ring[ring.len() - 1] {
+            rings.push(remove_collinear_vertices(&ring));
+        }
+    }
+    rings
+}
+
+fn remove_collinear_vertices(ring: &[Point2D]) -> Vec<Point2D> {
+    let n = ring.len() - 1; // excluding the closing point
+    let mut ret: Vec<Point2D> = Vec::with_capacity(ring.len());
+    for i in 0..n {
+        let prev = ring[(i + n - 1) % n];
+        let next = ring[(i + 1) % n];
+        let p = ring[i];
+        let cross = (p.x - prev.x) * (next.y - p.y) - (p.y - prev.y) * (next.x - p.x);
+        let dot = (p.x - prev.x) * (next.x - p.x) + (p.y - prev.y) * (next.y - p.y);
+        if cross != 0f64 || dot < 0f64 {
+            ret.push(p);
+        }
+    }
+    if ret.len() < 3 {
+        return ring.to_vec();
+    }
+    let p = ret[0];
+    ret.push(p);
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::{clip_polyline, group_rings, polygon_overlay, repair_rings, OverlayOperation};
+    use algorithms::polygon_area;
+    use structures::Point2D;
+
+    fn square(x: f64, y: f64, size: f64) -> Vec<Point2D> {
+        vec![
+            Point2D::new(x, y),
+            Point2D::new(x, y + size),
+            Point2D::new(x + size, y + size),
+            Point2D::new(x + size, y),
+            Point2D::new(x, y),
+        ]
+    }
+
+    fn total_area(rings: &[Vec<Point2D>]) -> f64 {
+        let mut area = 0f64;
+        for g in group_rings(rings) {
+            area += polygon_area(&g[0]);
+            for h in 1..g.len() {
+                area -= polygon_area(&g[h]);
+            }
+        }
+        area
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let a = vec![square(0f64, 0f64, 2f64)];
+        let b = vec![square(1f64, 1f64, 2f64)];
+        let i = polygon_overlay(&a, &b, OverlayOperation::Intersection);
+        assert_eq!(i.len(), 1);
+        assert!((total_area(&i) - 1f64).abs() < 1e-9);
+        let u = polygon_overlay(&a, &b, OverlayOperation::Union);
+        assert_eq!(u.len(), 1);
+        assert!((total_area(&u) - 7f64).abs() < 1e-9);
+        let d = polygon_overlay(&a, &b, OverlayOperation::Difference);
+        assert_eq!(d.len(), 1);
+        assert!((total_area(&d) - 3f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hole_and_shared_edges() {
+        // a square with a hole, clipped by a square sharing one of its edges
+        let a = vec![square(0f64, 0f64, 4f64), square(1f64, 1f64, 1f64)];
+        let b = vec![square(0f64, 0f64, 2f64)];
+        let i = polygon_overlay(&a, &b, OverlayOperation::Intersection);
+        assert!((total_area(&i) - 3f64).abs() < 1e-9);
+        let d = polygon_overlay(&a, &b, OverlayOperation::Difference);
+        assert!((total_area(&d) - 12f64).abs() < 1e-9);
+
+        // the clip polygon falls entirely within the hole
+        let c = vec![square(1.25f64, 1.25f64, 0.5f64)];
+        assert!(polygon_overlay(&a, &c, OverlayOperation::Intersection).is_empty());
+        let d = polygon_overlay(&a, &c, OverlayOperation::Difference);
+        assert_eq!(group_rings(&d).len(), 1);
+        assert!((total_area(&d) - 15f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_degenerate_overlays() {
+        // identical polygons
+        let a = vec![square(0f64, 0f64, 2f64)];
+        let i = polygon_overlay(&a, &a, OverlayOperation::Intersection);
+        assert_eq!(i.len(), 1);
+        assert!((total_area(&i) - 4f64).abs() < 1e-9);
+        let u = polygon_overlay(&a, &a, OverlayOperation::Union);
+        assert_eq!(u.len(), 1);
+        assert!((total_area(&u) - 4f64).abs() < 1e-9);
+        assert!(polygon_overlay(&a, &a, OverlayOperation::Difference).is_empty());
+
+        // squares sharing all, and part, of an edge
+        for &y in &[0f64, 1f64] {
+            let b = vec![square(2f64, y, 2f64)];
+            assert!(polygon_overlay(&a, &b, OverlayOperation::Intersection).is_empty());
+            let u = polygon_overlay(&a, &b, OverlayOperation::Union);
+            assert_eq!(u.len(), 1);
+            assert!((total_area(&u) - 8f64).abs() < 1e-9);
+            let d = polygon_overlay(&a, &b, OverlayOperation::Difference);
+            assert!((total_area(&d) - 4f64).abs() < 1e-9);
+        }
+
+        // a triangle with a vertex on an edge of the square, and one within it
+        let t = vec![vec![
+            Point2D::new(2f64, 1f64),
+            Point2D::new(3f64, 3f64),
+            Point2D::new(1f64, 1.5f64),
+            Point2D::new(2f64, 1f64),
+        ]];
+        let i = polygon_overlay(&a, &t, OverlayOperation::Intersection);
+        let d = polygon_overlay(&t, &a, OverlayOperation::Difference);
+        let t_area = 1.25f64;
+        assert!((total_area(&i) + total_area(&d) - t_area).abs() < 1e-9);
+        let u = polygon_overlay(&a, &t, OverlayOperation::Union);
+        assert!((total_area(&u) - (4f64 + total_area(&d))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_repair_rings() {
+        // a bow-tie ring becomes two clockwise triangles
+        let bow_tie = vec![vec![
+            Point2D::new(0f64, 0f64),
+            Point2D::new(2f64, 2f64),
+            Point2D::new(2f64, 0f64),
+            Point2D::new(0f64, 2f64),
+            Point2D::new(0f64, 0f64),
+        ]];
+        let r = repair_rings(&bow_tie);
+        assert_eq!(r.len(), 2);
+        assert_eq!(group_rings(&r).len(), 2);
+        assert!((total_area(&r) - 2f64).abs() < 1e-9);
+
+        // a counter-clockwise, unclosed outer ring is closed and reversed
+        let mut ccw = square(0f64, 0f64, 2f64);
+        ccw.reverse();
+        ccw.pop();
+        let r = repair_rings(&vec![ccw]);
+        assert_eq!(r.len(), 1);
+        assert_eq!(r[0].len(), 5);
+        assert!((total_area(&r) - 4f64).abs() < 1e-9);
+
+        // overlapping rings are interpreted using the even-odd rule
+        let r = repair_rings(&vec![square(0f64, 0f64, 2f64), square(1f64, 1f64, 2f64)]);
+        assert_eq!(r.len(), 2);
+        assert!((total_area(&r) - 6f64).abs() < 1e-9);
+
+        // a ring with a spike, retracing an edge, loses the spike
+        let spike = vec![vec![
+            Point2D::new(0f64, 0f64),
+            Point2D::new(0f64, 2f64),
+            Point2D::new(2f64, 2f64),
+            Point2D::new(3f64, 3f64),
+            Point2D::new(2f64, 2f64),
+            Point2D::new(2f64, 0f64),
+            Point2D::new(0f64, 0f64),
+        ]];
+        let r = repair_rings(&spike);
+        assert_eq!(r.len(), 1);
+        assert_eq!(r[0].len(), 5);
+        assert!((total_area(&r) - 4f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clip_polyline() {
+        let line = vec![Point2D::new(-1f64, 0.5f64), Point2D::new(3f64, 0.5f64)];
+        let poly = vec![square(0f64, 0f64, 1f64)];
+        let inside = clip_polyline(&line, &poly, true);
+        assert_eq!(inside.len(), 1);
+        assert_eq!(inside[0][0], Point2D::new(0f64, 0.5f64));
+        assert_eq!(inside[0][1], Point2D::new(1f64, 0.5f64));
+        let outside = clip_polyline(&line, &poly, false);
+        assert_eq!(outside.len(), 2);
+    }
+}
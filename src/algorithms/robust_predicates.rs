@@ -0,0 +1,349 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! Robust geometric predicates, based on the adaptive-precision predicates of:
+//!
+//! Shewchuk, J.R. (1997) Adaptive Precision Floating-Point Arithmetic and Fast Robust
+//! Geometric Predicates. Discrete & Computational Geometry 18(3):305–363.
+//!
+//! Each predicate is first evaluated with ordinary floating-point arithmetic. Where the
+//! result is too close to zero for its sign to be trusted, given a bound on the rounding
+//! error, it is re-evaluated exactly, using expansions (sums of non-overlapping
+//! floating-point values) to represent intermediate values without rounding. The sign
+//! of the result, and whether it is zero, is therefore always correct, which is what
+//! triangulation, point-in-polygon and overlay algorithms depend upon; the exact
+//! evaluation is only rarely needed, for nearly degenerate inputs.
+
+use std::f64;
+use structures::Point2D;
+
+// half of the machine epsilon, i.e. the relative rounding error of an operation
+const EPSILON: f64 = f64::EPSILON / 2f64;
+const CCW_ERRBOUND: f64 = (3f64 + 16f64 * EPSILON) * EPSILON;
+const ICC_ERRBOUND: f64 = (10f64 + 96f64 * EPSILON) * EPSILON;
+// 2^27 + 1, used to split a value into two non-overlapping halves
+const SPLITTER: f64 = 134_217_729f64;
+
+/// Returns a positive value if the points a, b and c are in counter-clockwise order, a
+/// negative value if they are in clockwise order, and zero if they are collinear. The
+/// value approximates twice the signed area of the triangle abc, and its sign is exact.
+pub fn orient2d(a: &Point2D, b: &Point2D, c: &Point2D) -> f64 {
+    let det_left = (a.x - c.x) * (b.y - c.y);
+    let det_right = (a.y - c.y) * (b.x - c.x);
+    let det = det_left - det_right;
+    let det_sum = if det_left > 0f64 {
+        if det_right <= 0f64 {
+            return det;
+        }
+        det_left + det_right
+    } else if det_left < 0f64 {
+        if det_right >= 0f64 {
+            return det;
+        }
+        -det_left - det_right
+    } else {
+        return det;
+    };
+    let err_bound = CCW_ERRBOUND * det_sum;
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+    let left = mul(&two_diff(a.x, c.x), &two_diff(b.y, c.y));
+    let right = mul(&two_diff(a.y, c.y), &two_diff(b.x, c.x));
+    estimate(&sub(&left, &right))
+}
+
+/// Returns a positive value if the point d lies inside of the circle passing through the
+/// points a, b and c, a negative value if it lies outside, and zero if the four points are
+/// cocircular, where a, b and c are in counter-clockwise order (the sign is reversed if
+/// they are clockwise). The sign of the value is exact.
+pub fn incircle(a: &Point2D, b: &Point2D, c: &Point2D, d: &Point2D) -> f64 {
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+    let det = a_lift * (bdxcdy - cdxbdy) + b_lift * (cdxady - adxcdy) + c_lift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * a_lift
+        + (cdxady.abs() + adxcdy.abs()) * b_lift
+        + (adxbdy.abs() + bdxady.abs()) * c_lift;
+    let err_bound = ICC_ERRBOUND * permanent;
+    if det > err_bound || -det > err_bound {
+        return det;
+    }
+    let (adx, ady) = (two_diff(a.x, d.x), two_diff(a.y, d.y));
+    let (bdx, bdy) = (two_diff(b.x, d.x), two_diff(b.y, d.y));
+    let (cdx, cdy) = (two_diff(c.x, d.x), two_diff(c.y, d.y));
+    let a_lift = add(&mul(&adx, &adx), &mul(&ady, &ady));
+    let b_lift = add(&mul(&bdx, &bdx), &mul(&bdy, &bdy));
+    let c_lift = add(&mul(&cdx, &cdx), &mul(&cdy, &cdy));
+    let bc = sub(&mul(&bdx, &cdy), &mul(&cdx, &bdy));
+    let ca = sub(&mul(&cdx, &ady), &mul(&adx, &cdy));
+    let ab = sub(&mul(&adx, &bdy), &mul(&bdx, &ady));
+    let det = add(
+        &add(&mul(&a_lift, &bc), &mul(&b_lift, &ca)),
+        &mul(&c_lift, &ab),
+    );
+    estimate(&det)
+}
+
+/// The intersection of two line segments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SegmentIntersection {
+    /// The segments do not intersect.
+    None,
+    /// The segments intersect at a single point, which is an end point of one of the
+    /// segments if they touch, or else their computed crossing point.
+    Point(Point2D),
+    /// The segments are collinear and overlap between two points, which are ordered
+    /// in the direction of the first segment.
+    Overlap(Point2D, Point2D),
+}
+
+/// Finds the intersection of the segments p1-p2 and q1-q2. Whether, and how, the
+/// segments intersect is determined exactly, using `orient2d`; only the location of a
+/// crossing point, which is rounded, is computed approximately, and it is kept within the
+/// bounding boxes of both segments.
+pub fn segment_intersection(
+    p1: &Point2D,
+    p2: &Point2D,
+    q1: &Point2D,
+    q2: &Point2D,
+) -> SegmentIntersection {
+    let o1 = orient2d(p1, p2, q1);
+    let o2 = orient2d(p1, p2, q2);
+    let o3 = orient2d(q1, q2, p1);
+    let o4 = orient2d(q1, q2, p2);
+    if o1 == 0f64 && o2 == 0f64 && o3 == 0f64 && o4 == 0f64 {
+        return collinear_overlap(p1, p2, q1, q2);
+    }
+    if (o1 > 0f64 && o2 > 0f64)
+        || (o1 < 0f64 && o2 < 0f64)
+        || (o3 > 0f64 && o4 > 0f64)
+        || (o3 < 0f64 && o4 < 0f64)
+    {
+        return SegmentIntersection::None;
+    }
+    // the segments touch at an end point
+    if o1 == 0f64 {
+        return SegmentIntersection::Point(*q1);
+    }
+    if o2 == 0f64 {
+        return SegmentIntersection::Point(*q2);
+    }
+    if o3 == 0f64 {
+        return SegmentIntersection::Point(*p1);
+    }
+    if o4 == 0f64 {
+        return SegmentIntersection::Point(*p2);
+    }
+    // the segments cross; o3 and o4 are proportional to the distances of p1 and p2 from
+    // the line through q1 and q2
+    let t = o3 / (o3 - o4);
+    let x = p1.x + t * (p2.x - p1.x);
+    let y = p1.y + t * (p2.y - p1.y);
+    let min_x = p1.x.min(p2.x).max(q1.x.min(q2.x));
+    let max_x = p1.x.max(p2.x).min(q1.x.max(q2.x));
+    let min_y = p1.y.min(p2.y).max(q1.y.min(q2.y));
+    let max_y = p1.y.max(p2.y).min(q1.y.max(q2.y));
+    SegmentIntersection::Point(Point2D::new(
+        x.max(min_x).min(max_x),
+        y.max(min_y).min(max_y),
+    ))
+}
+
+fn collinear_overlap(
+    p1: &Point2D,
+    p2: &Point2D,
+    q1: &Point2D,
+    q2: &Point2D,
+) -> SegmentIntersection {
+    // measure positions along the axis in which the segments extend the most
+    let use_x = (p1.x - p2.x).abs().max((q1.x - q2.x).abs())
+        >= (p1.y - p2.y).abs().max((q1.y - q2.y).abs());
+    let pos = |p: &Point2D| if use_x { p.x } else { p.y };
+    let (p_min, p_max) = (pos(p1).min(pos(p2)), pos(p1).max(pos(p2)));
+    let (q_min, q_max) = (pos(q1).min(pos(q2)), pos(q1).max(pos(q2)));
+    let lo = p_min.max(q_min);
+    let hi = p_max.min(q_max);
+    if lo > hi {
+        return SegmentIntersection::None;
+    }
+    // the ends of the overlap are end points of the segments
+    let ends = [*p1, *p2, *q1, *q2];
+    let at = |v: f64| *ends.iter().find(|p| pos(*p) == v).unwrap();
+    let (mut start, mut end) = (at(lo), at(hi));
+    if lo == hi {
+        return SegmentIntersection::Point(start);
+    }
+    if pos(p1) > pos(p2) {
+        ::std::mem::swap(&mut start, &mut end);
+    }
+    SegmentIntersection::Overlap(start, end)
+}
+
+/// Returns the sum of an expansion, which has the sign of the exact value.
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().fold(0f64, |sum, &v| sum + v)
+}
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_diff(a: f64, b: f64) -> Vec<f64> {
+    let x = a - b;
+    let b_virtual = a - x;
+    let a_virtual = x + b_virtual;
+    let y = (a - a_virtual) + (b_virtual - b);
+    vec![y, x]
+}
+
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err = x - a_hi * b_hi - a_lo * b_hi - a_hi * b_lo;
+    (x, a_lo * b_lo - err)
+}
+
+/// Adds a value to an expansion (Shewchuk's Grow-Expansion), dropping zero components.
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut ret = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &v in e {
+        let (sum, err) = two_sum(q, v);
+        if err != 0f64 {
+            ret.push(err);
+        }
+        q = sum;
+    }
+    if q != 0f64 || ret.is_empty() {
+        ret.push(q);
+    }
+    ret
+}
+
+fn add(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |sum, &v| grow(&sum, v))
+}
+
+fn sub(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |sum, &v| grow(&sum, -v))
+}
+
+fn mul(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut ret = vec![0f64];
+    for &a in e {
+        for &b in f {
+            let (x, y) = two_product(a, b);
+            ret = grow(&grow(&ret, y), x);
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::{incircle, orient2d, segment_intersection, SegmentIntersection};
+    use std::f64;
+    use structures::Point2D;
+
+    #[test]
+    fn test_orient2d() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(1.0, 0.0);
+        assert!(orient2d(&a, &b, &Point2D::new(0.5, 1.0)) > 0.0);
+        assert!(orient2d(&a, &b, &Point2D::new(0.5, -1.0)) < 0.0);
+        assert_eq!(orient2d(&a, &b, &Point2D::new(7.0, 0.0)), 0.0);
+
+        // points very nearly on the line y = x, far from the origin, where the
+        // floating-point evaluation is unreliable
+        let a = Point2D::new(12.0, 12.0);
+        let b = Point2D::new(24.0, 24.0);
+        for i in 0..64 {
+            let x = 0.5 + i as f64 * f64::EPSILON;
+            for j in 0..64 {
+                let y = 0.5 + j as f64 * f64::EPSILON;
+                let o = orient2d(&Point2D::new(x, y), &a, &b);
+                // the exact sign is that of y - x, as the line passes through (0.5, 0.5)
+                assert_eq!(o > 0.0, j > i);
+                assert_eq!(o == 0.0, i == j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_incircle() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(1.0, 0.0);
+        let c = Point2D::new(0.0, 1.0);
+        assert!(incircle(&a, &b, &c, &Point2D::new(0.5, 0.5)) > 0.0);
+        assert!(incircle(&a, &b, &c, &Point2D::new(2.0, 2.0)) < 0.0);
+        assert_eq!(incircle(&a, &b, &c, &Point2D::new(1.0, 1.0)), 0.0);
+        // clockwise points reverse the sign
+        assert!(incircle(&a, &c, &b, &Point2D::new(0.5, 0.5)) < 0.0);
+
+        // cocircular points on a large, offset grid, and points displaced from them by
+        // the smallest possible amount
+        let (x0, y0) = (1e6, 1e6);
+        let a = Point2D::new(x0, y0);
+        let b = Point2D::new(x0 + 3.0, y0);
+        let c = Point2D::new(x0 + 3.0, y0 + 4.0);
+        let d = Point2D::new(x0, y0 + 4.0);
+        assert_eq!(incircle(&a, &b, &c, &d), 0.0);
+        let inside = Point2D::new(x0, y0 + 4.0 - 4.0 * f64::EPSILON * y0);
+        assert!(incircle(&a, &b, &c, &inside) > 0.0);
+        let outside = Point2D::new(x0, y0 + 4.0 + 4.0 * f64::EPSILON * y0);
+        assert!(incircle(&a, &b, &c, &outside) < 0.0);
+    }
+
+    #[test]
+    fn test_segment_intersection() {
+        let p = |x: f64, y: f64| Point2D::new(x, y);
+        assert_eq!(
+            segment_intersection(&p(0.0, 0.0), &p(2.0, 2.0), &p(0.0, 2.0), &p(2.0, 0.0)),
+            SegmentIntersection::Point(p(1.0, 1.0))
+        );
+        // touching at an end point
+        assert_eq!(
+            segment_intersection(&p(0.0, 0.0), &p(2.0, 0.0), &p(1.0, 0.0), &p(1.0, 3.0)),
+            SegmentIntersection::Point(p(1.0, 0.0))
+        );
+        assert_eq!(
+            segment_intersection(&p(0.0, 0.0), &p(2.0, 0.0), &p(1.0, 0.5), &p(1.0, 3.0)),
+            SegmentIntersection::None
+        );
+        // collinear segments
+        assert_eq!(
+            segment_intersection(&p(3.0, 0.0), &p(0.0, 0.0), &p(1.0, 0.0), &p(5.0, 0.0)),
+            SegmentIntersection::Overlap(p(3.0, 0.0), p(1.0, 0.0))
+        );
+        assert_eq!(
+            segment_intersection(&p(0.0, 0.0), &p(1.0, 1.0), &p(1.0, 1.0), &p(2.0, 2.0)),
+            SegmentIntersection::Point(p(1.0, 1.0))
+        );
+        assert_eq!(
+            segment_intersection(&p(0.0, 0.0), &p(1.0, 1.0), &p(2.0, 2.0), &p(3.0, 3.0)),
+            SegmentIntersection::None
+        );
+    }
+}
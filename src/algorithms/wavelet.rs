@@ -0,0 +1,154 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+/// The low-pass filter coefficients of the Daubechies D4 wavelet.
+fn d4_coefficients() -> ([f64; 4], [f64; 4]) {
+    let s3 = 3f64.sqrt();
+    let d = 4f64 * 2f64.sqrt();
+    let h = [(1f64 + s3) / d, (3f64 + s3) / d, (3f64 - s3) / d, (1f64 - s3) / d];
+    let g = [h[3], -h[2], h[1], -h[0]];
+    (h, g)
+}
+
+/// Calculates the multi-level 2D discrete wavelet transform of a grid, stored in row-major
+/// order, in place, using the orthogonal Daubechies D4 wavelet with periodic boundaries. At
+/// each level, the rows and then the columns of the approximation of the previous level are
+/// transformed, leaving its approximation in the top-left quadrant, the details that are
+/// high-pass along the rows (i.e. horizontal variation, such as vertical stripes) in the
+/// top-right quadrant, the details that are high-pass along the columns in the bottom-left
+/// quadrant, and the diagonal details in the bottom-right quadrant. The numbers of rows and
+/// columns must be divisible by 2^levels.
+pub fn dwt_2d(data: &mut [f64], rows: usize, columns: usize, levels: usize) {
+    check_dimensions(data, rows, columns, levels);
+    let (mut r, mut c) = (rows, columns);
+    for _ in 0..levels {
+        transform_level(data, columns, r, c, false);
+        r /= 2;
+        c /= 2;
+    }
+}
+
+/// Calculates the inverse of `dwt_2d`, in place.
+pub fn idwt_2d(data: &mut [f64], rows: usize, columns: usize, levels: usize) {
+    check_dimensions(data, rows, columns, levels);
+    for level in (0..levels).rev() {
+        transform_level(data, columns, rows >> level, columns >> level, true);
+    }
+}
+
+fn check_dimensions(data: &[f64], rows: usize, columns: usize, levels: usize) {
+    assert_eq!(data.len(), rows * columns);
+    assert!(
+        rows % (1 << levels) == 0 && columns % (1 << levels) == 0,
+        "The dimensions of the grid must be divisible by 2^levels."
+    );
+}
+
+/// Transforms, or inverse transforms, the rows and columns of the top-left `rows` by
+/// `columns` block of a grid with `stride` columns.
+fn transform_level(data: &mut [f64], stride: usize, rows: usize, columns: usize, inverse: bool) {
+    let mut line = vec![0f64; rows.max(columns)];
+    let transform_rows = |data: &mut [f64], line: &mut [f64]| {
+        for row in 0..rows {
+            let range = row * stride..row * stride + columns;
+            line[..columns].copy_from_slice(&data[range.clone()]);
+            transform_line(&mut line[..columns], inverse);
+            data[range].copy_from_slice(&line[..columns]);
+        }
+    };
+    let transform_columns = |data: &mut [f64], line: &mut [f64]| {
+        for col in 0..columns {
+            for row in 0..rows {
+                line[row] = data[row * stride + col];
+            }
+            transform_line(&mut line[..rows], inverse);
+            for row in 0..rows {
+                data[row * stride + col] = line[row];
+            }
+        }
+    };
+    if inverse {
+        transform_columns(data, &mut line);
+        transform_rows(data, &mut line);
+    } else {
+        transform_rows(data, &mut line);
+        transform_columns(data, &mut line);
+    }
+}
+
+/// A single level of the periodic D4 transform of a sequence of even length, which places
+/// the approximation in the first half of the sequence and the details in the second half.
+fn transform_line(x: &mut [f64], inverse: bool) {
+    let n = x.len();
+    if n < 2 {
+        return;
+    }
+    let (h, g) = d4_coefficients();
+    let half = n / 2;
+    let mut out = vec![0f64; n];
+    if inverse {
+        // the transform is orthogonal, and its inverse is therefore its transpose
+        for i in 0..half {
+            for j in 0..4 {
+                out[(2 * i + j) % n] += h[j] * x[i] + g[j] * x[half + i];
+            }
+        }
+    } else {
+        for i in 0..half {
+            for j in 0..4 {
+                let v = x[(2 * i + j) % n];
+                out[i] += h[j] * v;
+                out[half + i] += g[j] * v;
+            }
+        }
+    }
+    x.copy_from_slice(&out);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dwt_2d, idwt_2d};
+
+    #[test]
+    fn test_wavelet_reconstruction() {
+        let (rows, columns) = (16, 24);
+        let data: Vec<f64> = (0..rows * columns)
+            .map(|i| ((i * 37 + 11) % 101) as f64 * 0.1 - 3.0)
+            .collect();
+        let mut transformed = data.clone();
+        dwt_2d(&mut transformed, rows, columns, 3);
+        // the transform is orthogonal and preserves the sum of squares
+        let energy: f64 = data.iter().map(|v| v * v).sum();
+        let transformed_energy: f64 = transformed.iter().map(|v| v * v).sum();
+        assert!((energy - transformed_energy).abs() < 1e-9 * energy);
+        idwt_2d(&mut transformed, rows, columns, 3);
+        for i in 0..rows * columns {
+            assert!((transformed[i] - data[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_wavelet_vertical_stripes() {
+        // vertical stripes only have energy in the details that are high-pass along the rows
+        let (rows, columns) = (8, 8);
+        let mut data: Vec<f64> = (0..rows * columns)
+            .map(|i| if (i % columns) % 2 == 0 { 1f64 } else { -1f64 })
+            .collect();
+        dwt_2d(&mut data, rows, columns, 1);
+        for row in 0..rows {
+            for col in 0..columns {
+                let v = data[row * columns + col];
+                if row < rows / 2 && col >= columns / 2 {
+                    assert!(v.abs() > 1e-9);
+                } else {
+                    assert!(v.abs() < 1e-9);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+/*!
+The WhiteboxTools library. The `whitebox_tools` executable is a thin command-line
+front-end to this crate, and the same tools can be embedded within other Rust
+programs. Several tools expose their analysis as a core function that accepts and
+returns in-memory `Raster`, `Shapefile`, and `LasFile` objects, and that the tool itself
+calls between reading its inputs and writing its outputs, e.g.
+`tools::terrain_analysis::slope` and `tools::hydro_analysis::d8_flow_accumulation`:
+
+```
+extern crate whitebox_tools;
+
+use std::sync::Arc;
+use whitebox_tools::raster::Raster;
+use whitebox_tools::tools::hydro_analysis::{d8_flow_accumulation, d8_pointer};
+
+let dem = Arc::new(Raster::new("/path/to/DEM.tif", "r")?);
+let pntr: Raster = d8_pointer(dem.clone(), false, false);
+let mut flow_accum: Raster = d8_flow_accumulation(dem, "cells", false, false, false, false)?;
+flow_accum.set_file_name("/path/to/flow_accum.tif");
+flow_accum.write()?;
+```
+
+Any other tool may be run with in-memory datasets using a `ToolRunner`, which binds
+them to the tool's file parameters, such that no intermediate files are read from or
+written to disk:
+
+```
+let mut outputs = ToolRunner::new("FillDepressions")
+    .raster("dem", dem)
+    .output("output")
+    .run()?;
+let filled: Raster = outputs.take_raster("output")?;
+```
+*/
+
+extern crate byteorder;
+extern crate chrono;
+extern crate kdtree;
+extern crate libc;
+extern crate lzw;
+extern crate nalgebra as na;
+extern crate num_cpus;
+extern crate rand;
+extern crate serde;
+extern crate serde_json;
+extern crate statrs;
+// extern crate time;
+
+pub mod algorithms;
+pub mod lidar;
+pub mod raster;
+pub mod rendering;
+pub mod spatial_ref_system;
+pub mod structures;
+pub mod tools;
+pub mod utils;
+pub mod vector;
+
+#[macro_use]
+extern crate serde_derive;
+
+pub use tools::{Dataset, ToolManager, ToolOutputs, ToolRunner};
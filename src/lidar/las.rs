@@ -8,6 +8,8 @@ use self::zip::read::{ZipArchive, ZipFile};
 use self::zip::result::ZipResult;
 use self::zip::write::{FileOptions, ZipWriter};
 use self::zip::CompressionMethod;
+use utils::memory_store;
+use utils::report;
 use utils::{ByteOrderReader, Endianness};
 use lidar::header::LasHeader;
 use lidar::point_data::{ColourData, PointData, WaveformPacket};
@@ -69,6 +71,12 @@ impl LasFile {
             ..Default::default()
         };
         lf.file_mode = file_mode.to_lowercase();
+        if memory_store::is_memory_dataset(file_name) && lf.file_mode.starts_with("r") {
+            let mut lf = memory_store::get_lidar(file_name)?;
+            lf.file_name = file_name.to_string();
+            lf.file_mode = "r".to_string();
+            return Ok(lf);
+        }
         if lf.file_mode == "r" || lf.file_mode == "rh" {
             lf.read()?;
         } else {
@@ -108,6 +116,13 @@ impl LasFile {
         output
     }
 
+    /// Sets the name of the file to which the `LasFile` is written, e.g. that of a
+    /// `LasFile` returned by one of the tools' core functions.
+    pub fn set_file_name(&mut self, file_name: &str) {
+        self.file_name = file_name.to_string();
+        self.file_mode = "w".to_string();
+    }
+
     pub fn add_header(&mut self, header: LasHeader) {
         if self.file_mode == "r" {
             return;
@@ -1051,7 +1066,7 @@ impl LasFile {
 
         // Issue a warning if there are fewer than two points in the dataset. Many tools won't work correctly if this is the case.
         if self.header.number_of_points < 2 {
-            println!("WARNING: There are fewer than two points in the LAS file. This may cause some tools to fail when reading these data.");
+            report::warning("There are fewer than two points in the LAS file. This may cause some tools to fail when reading these data.");
         }
 
         self.header.x_offset = self.header.min_x;
@@ -1082,6 +1097,11 @@ impl LasFile {
             self.header.z_scale_factor = dec;
         }
 
+        if memory_store::is_memory_dataset(&self.file_name) {
+            memory_store::put_lidar(&self.file_name, self.clone());
+            return Ok(());
+        }
+
         if !self.file_name.to_lowercase().ends_with(".zip") {
             let f = File::create(&self.file_name)?;
             let mut writer = BufWriter::new(f);
@@ -1194,34 +1214,34 @@ impl LasFile {
             2u8 => 2u8,
             3u8 => 3u8,
             4u8 => {
-                println!(
-                    "Warning: Point Format 4 is not supported for output. Some data will be lost."
+                report::warning(
+                    "Point Format 4 is not supported for output. Some data will be lost.",
                 );
                 1u8
             }
             5u8 => {
-                println!(
-                    "Warning: Point Format 5 is not supported for output. Some data will be lost."
+                report::warning(
+                    "Point Format 5 is not supported for output. Some data will be lost.",
                 );
                 3u8
             }
             6u8 => 1u8,
             7u8 => 3u8,
             8u8 => {
-                println!(
-                    "Warning: Point Format 8 is not supported for output. Some data will be lost."
+                report::warning(
+                    "Point Format 8 is not supported for output. Some data will be lost.",
                 );
                 3u8
             }
             9u8 => {
-                println!(
-                    "Warning: Point Format 9 is not supported for output. Some data will be lost."
+                report::warning(
+                    "Point Format 9 is not supported for output. Some data will be lost.",
                 );
                 1u8
             }
             10u8 => {
-                println!(
-                    "Warning: Point Format 10 is not supported for output. Some data will be lost."
+                report::warning(
+                    "Point Format 10 is not supported for output. Some data will be lost.",
                 );
                 3u8
             }
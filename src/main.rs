@@ -18,10 +18,14 @@ by the WhiteboxTools library:
 | Command           | Description                                                                                       |
 | ----------------- | ------------------------------------------------------------------------------------------------- |
 | --cd, --wd        | Changes the working directory; used in conjunction with --run flag.                               |
+| --config          | Overrides settings for a run, e.g. --config="compress_rasters=true;default_nodata=-9999".         |
 | -h, --help        | Prints help information.                                                                          |
 | -l, --license     | Prints the whitebox-tools license.                                                                |
+| --json            | Reports tool progress, warnings, and outputs as JSON lines; used in conjunction with --run flag.  |
 | --listtools       | Lists all available tools, with tool descriptions. Keywords may also be used, --listtools slope.  |
 | -r, --run         | Runs a tool; used in conjunction with --cd flag; -r="LidarInfo".                                  |
+| --run_workflow    | Runs a workflow of tools from a JSON file; --run_workflow="workflow.json".                        |
+| --toolcatalog     | Prints a catalog of all tools, with keywords, data types, related tools, and parameters, as JSON. |
 | --toolbox         | Prints the toolbox associated with a tool; --toolbox=Slope.                                       |
 | --toolhelp        | Prints the help associated with a tool; --toolhelp="LidarInfo".                                   |
 | --toolparameters  | Prints the parameters (in json form) for a specific tool; --toolparameters=\"LidarInfo\".         |
@@ -31,35 +35,13 @@ by the WhiteboxTools library:
 
 */
 
-extern crate byteorder;
-extern crate chrono;
-extern crate kdtree;
-extern crate lzw;
-extern crate nalgebra as na;
-extern crate num_cpus;
-extern crate rand;
-extern crate serde;
-extern crate serde_json;
-extern crate statrs;
-// extern crate time;
-
-pub mod algorithms;
-pub mod lidar;
-pub mod raster;
-pub mod rendering;
-pub mod spatial_ref_system;
-pub mod structures;
-pub mod tools;
-pub mod utils;
-pub mod vector;
+extern crate whitebox_tools;
 
 use std::env;
 use std::io::Error;
 use std::path;
-use tools::ToolManager;
-
-#[macro_use]
-extern crate serde_derive;
+use whitebox_tools::tools::ToolManager;
+use whitebox_tools::utils::settings;
 
 /// WhiteboxTools is an advanced geospatial data analysis engine.
 ///
@@ -96,15 +78,20 @@ fn run() -> Result<(), Error> {
     let mut working_dir = String::new();
     let mut tool_name = String::new();
     let mut run_tool = false;
+    let mut run_workflow = false;
+    let mut json_mode = false;
+    let mut workflow_file = String::new();
     let mut tool_help = false;
     let mut tool_parameters = false;
     let mut toolbox = false;
+    let mut tool_catalog = false;
     let mut list_tools = false;
     let mut keywords: Vec<String> = vec![];
     let mut view_code = false;
     let mut tool_args_vec: Vec<String> = vec![];
     let mut verbose = false;
     let mut finding_working_dir = false;
+    let mut config_overrides: Vec<String> = vec![];
     let args: Vec<String> = env::args().collect();
     if args.len() <= 1 {
         version();
@@ -138,6 +125,27 @@ fn run() -> Result<(), Error> {
                 v.push_str(sep);
             }
             working_dir = v.to_string();
+        } else if arg.starts_with("-config") || arg.starts_with("--config") {
+            let mut v = arg
+                .replace("--config", "")
+                .replace("-config", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            config_overrides.push(v);
+        } else if arg.starts_with("-run_workflow") || arg.starts_with("--run_workflow") {
+            let mut v = arg
+                .replace("--run_workflow", "")
+                .replace("-run_workflow", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            workflow_file = v;
+            run_workflow = true;
         } else if arg.starts_with("-run") || arg.starts_with("--run") || arg.starts_with("-r") {
             let mut v = arg
                 .replace("--run", "")
@@ -172,6 +180,8 @@ fn run() -> Result<(), Error> {
             }
             tool_name = v;
             tool_parameters = true;
+        } else if arg.starts_with("-toolcatalog") || arg.starts_with("--toolcatalog") {
+            tool_catalog = true;
         } else if arg.starts_with("-toolbox") || arg.starts_with("--toolbox") {
             let mut v = arg
                 .replace("--toolbox", "")
@@ -221,6 +231,8 @@ fn run() -> Result<(), Error> {
             return Ok(());
         } else if arg.trim() == "-v" {
             verbose = true;
+        } else if arg.trim() == "--json" || arg.trim() == "-json" {
+            json_mode = true;
         } else if arg.starts_with("-") {
             // it's an arg to be fed to the tool
             // println!("arg: {}", arg); //temp
@@ -243,15 +255,36 @@ fn run() -> Result<(), Error> {
         }
     }
 
+    // the settings file next to the executable, followed by any overrides
+    let exe = env::current_exe()?;
+    if let Some(exe_dir) = exe.parent() {
+        settings::load_settings_file(exe_dir)?;
+    }
+    if !config_overrides.is_empty() {
+        let mut s = settings::get_settings();
+        for config in &config_overrides {
+            s.apply_overrides(config)?;
+        }
+        settings::set_settings(s);
+    }
+
     let sep = path::MAIN_SEPARATOR;
     if !working_dir.ends_with(sep) {
         working_dir.push_str(&(sep.to_string()));
     }
     let tm = ToolManager::new(&working_dir, &verbose)?;
-    if run_tool {
+    if run_workflow {
+        if workflow_file.is_empty() && keywords.len() > 0 {
+            workflow_file = keywords[0].clone();
+        }
+        return tm.run_workflow(workflow_file);
+    } else if run_tool {
         if tool_name.is_empty() && keywords.len() > 0 {
             tool_name = keywords[0].clone();
         }
+        if json_mode {
+            return tm.run_tool_json(tool_name, tool_args_vec);
+        }
         return tm.run_tool(tool_name, tool_args_vec);
     } else if tool_help {
         if tool_name.is_empty() && keywords.len() > 0 {
@@ -271,6 +304,8 @@ fn run() -> Result<(), Error> {
             tool_name = String::new();
         }
         return tm.toolbox(tool_name);
+    } else if tool_catalog {
+        return tm.tool_catalog();
     } else if list_tools {
         if keywords.len() == 0 {
             tm.list_tools();
@@ -299,10 +334,14 @@ fn help() {
 
 The following commands are recognized:
 --cd, --wd       Changes the working directory; used in conjunction with --run flag.
+--config         Overrides settings for a run; --config=\"compress_rasters=true;default_nodata=-9999\".
 -h, --help       Prints help information.
 -l, --license    Prints the whitebox-tools license.
+--json           Reports tool progress, warnings, and outputs as JSON lines; used with --run flag.
 --listtools      Lists all available tools. Keywords may also be used, --listtools slope.
 -r, --run        Runs a tool; used in conjuction with --wd flag; -r=\"LidarInfo\".
+--run_workflow   Runs a workflow of tools from a JSON file; --run_workflow=\"workflow.json\".
+--toolcatalog    Prints a catalog of all tools (keywords, data types, related tools, parameters) as JSON.
 --toolbox        Prints the toolbox associated with a tool; --toolbox=Slope.
 --toolhelp       Prints the help associated with a tool; --toolhelp=\"LidarInfo\".
 --toolparameters Prints the parameters (in json form) for a specific tool; --toolparameters=\"LidarInfo\".
@@ -1,4 +1,5 @@
 use std::io::Error;
+use std::io::ErrorKind;
 use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::prelude::*;
@@ -15,31 +16,37 @@ pub fn read_arcascii(file_name: &String, configs: &mut RasterConfigs, data: &mut
     let mut yllcenter: f64 = f64::NEG_INFINITY;
     let mut xllcorner: f64 = f64::NEG_INFINITY;
     let mut yllcorner: f64 = f64::NEG_INFINITY;
+    configs.photometric_interp = PhotometricInterpretation::Continuous;
+    // the nodata value is optional and defaults to -9999
+    configs.nodata = -9999f64;
     //let mut likely_float = false;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
-        let mut line_split = line_unwrapped.split(" ");
-        let mut vec = line_split.collect::<Vec<&str>>();
-        if vec.len() == 1 {
-            line_split = line_unwrapped.split("\t");
-            vec = line_split.collect::<Vec<&str>>();
+        let line_unwrapped = line?;
+        let vec = line_unwrapped.split_whitespace().collect::<Vec<&str>>();
+        if vec.is_empty() {
+            continue;
         }
-        if vec[0].to_lowercase().contains("nrows") {
+        let key = vec[0].to_lowercase();
+        if key.contains("nrows") {
             configs.rows = vec[vec.len()-1].trim().parse::<f32>().unwrap() as usize;
-        } else if vec[0].to_lowercase().contains("ncols") {
+        } else if key.contains("ncols") {
             configs.columns = vec[vec.len()-1].trim().parse::<f32>().unwrap() as usize;
-        } else if vec[0].to_lowercase().contains("xllcorner") {
-            xllcenter = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("yllcorner") {
-            yllcenter = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("xllcenter") {
+        } else if key.contains("xllcorner") {
             xllcorner = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("yllcenter") {
+        } else if key.contains("yllcorner") {
             yllcorner = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("cellsize") {
+        } else if key.contains("xllcenter") {
+            xllcenter = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
+        } else if key.contains("yllcenter") {
+            yllcenter = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
+        } else if key.contains("cellsize") {
+            configs.resolution_x = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
+            configs.resolution_y = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
+        } else if key == "dx" {
             configs.resolution_x = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
+        } else if key == "dy" {
             configs.resolution_y = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("nodata_value") {
+        } else if key.contains("nodata_value") {
             if vec[vec.len()-1].contains(".") {
                 //likely_float = true;
                 configs.data_type = DataType::F32;
@@ -49,13 +56,22 @@ pub fn read_arcascii(file_name: &String, configs: &mut RasterConfigs, data: &mut
             configs.nodata = vec[vec.len()-1].trim().to_string().parse::<f64>().unwrap();
         } else { // it's a data line
             for val in vec {
-                if !val.trim().to_string().is_empty() {
-                    data.push(val.trim().to_string().parse::<f64>().unwrap());
+                match val.parse::<f64>() {
+                    Ok(v) => data.push(v),
+                    Err(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("Invalid value '{}' in the ASCII raster {}.", val, file_name),
+                        ))
+                    }
                 }
             }
         }
     }
 
+    // the values are listed in row-major order, starting with the northern-most row
+    check_grid_size(file_name, configs, data.len())?;
+
     // set the North, East, South, and West coodinates
     if xllcorner != f64::NEG_INFINITY {
         //h.cellCornerMode = true
@@ -74,7 +90,18 @@ pub fn read_arcascii(file_name: &String, configs: &mut RasterConfigs, data: &mut
     Ok(())
 }
 
-pub fn write_arcascii<'a>(r: &'a mut Raster) -> Result<(), Error> {
+/// Writes an Esri ASCII raster, with floating-point values written to `precision`
+/// decimal places (see `format_ascii_value`). Nodata cells, including any NaN
+/// values, are written using the raster's nodata value, or -9999 if it is NaN.
+pub fn write_arcascii<'a>(r: &'a mut Raster, precision: Option<usize>) -> Result<(), Error> {
+    check_grid_size(&r.file_name, &r.configs, r.data.len())?;
+    let data_type = r.configs.data_type;
+    let nodata = r.configs.nodata;
+    let nodata_str = if nodata.is_nan() {
+        format_ascii_value(-9999f64, data_type, precision)
+    } else {
+        format_ascii_value(nodata, data_type, precision)
+    };
 
     // Save the file
     let f = File::create(&(r.file_name))?;
@@ -92,32 +119,43 @@ pub fn write_arcascii<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let s = format!("YLLCORNER {}\n", r.configs.south);
     writer.write_all(s.as_bytes())?;
 
-    let s = format!("CELLSIZE {}\n", (r.configs.resolution_x + r.configs.resolution_y) / 2.0);
-    writer.write_all(s.as_bytes())?;
+    // Non-square grid cells are not part of the format, but are commonly given using
+    // DX and DY in place of CELLSIZE.
+    let res_diff = (r.configs.resolution_x - r.configs.resolution_y).abs();
+    if res_diff > 1e-9 * r.configs.resolution_x.abs() {
+        let s = format!("DX {}\nDY {}\n", r.configs.resolution_x, r.configs.resolution_y);
+        writer.write_all(s.as_bytes())?;
+    } else {
+        let s = format!("CELLSIZE {}\n", r.configs.resolution_x);
+        writer.write_all(s.as_bytes())?;
+    }
 
-    let s = format!("NODATA_VALUE {}\n", &format!("{:.*} ", 2, r.configs.nodata));
+    let s = format!("NODATA_VALUE {}\n", nodata_str);
     writer.write_all(s.as_bytes())?;
 
-    // write the data
+    // write the data, one row per line, starting with the northern-most row
     let mut s2 = String::new();
     let num_cells: usize = r.configs.rows * r.configs.columns;
     let mut col = 0;
     for i in 0..num_cells {
-        if col < r.configs.columns - 1 {
-            s2 += &format!("{:.*} ", 2, r.data[i]);
+        let value = r.data[i];
+        if value == nodata || value.is_nan() {
+            s2 += &nodata_str;
         } else {
-            s2 += &format!("{:.*}\n", 2, r.data[i]);
+            s2 += &format_ascii_value(value, data_type, precision);
         }
         col += 1;
-        if col == r.configs.columns {
+        if col < r.configs.columns {
+            s2 += " ";
+        } else {
+            s2 += "\n";
             writer.write_all(s2.as_bytes())?;
-            s2 = String::new();
+            s2.clear();
             col = 0;
         }
-        //try!(writer.write_all(format!("{:.*}", 2, r.data[i]).as_bytes()));
     }
 
-    let _ = writer.flush();
+    writer.flush()?;
 
     Ok(())
 }
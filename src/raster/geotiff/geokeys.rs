@@ -122,32 +122,97 @@ impl GeoKeys {
 		ifd_map
 	}
 
+	/// Returns the EPSG code of the projected coordinate system (ProjectedCSTypeGeoKey),
+	/// or of the geographic coordinate system (GeographicTypeGeoKey) for unprojected data.
+	/// Zero is returned when neither key holds a registered code, e.g. for user-defined
+	/// (32767) coordinate systems.
 	pub fn find_epsg_code(&self) -> u16 {
-		if self.geo_key_directory.len() == 0 {
-			return 0u16;
+		for key in [3072u16, 2048u16].iter() {
+			match self.get_short_value(*key) {
+				Some(code) if code > 0 && code < 32767 => return code,
+				_ => {}
+			}
 		}
-		let keys = get_keys_map();
-		let number_of_keys = self.geo_key_directory[3];
+		0u16
+	}
 
-		let mut epsg_code = 0u16;
+	/// Returns the first non-empty citation among the PCSCitationGeoKey, GTCitationGeoKey,
+	/// and GeogCitationGeoKey, which for user-defined coordinate systems often contain a
+	/// description or WKT definition of the CRS.
+	pub fn find_citation(&self) -> Option<String> {
+		for key in [3073u16, 1026u16, 2049u16].iter() {
+			if let Some(citation) = self.get_ascii_value(*key) {
+				if !citation.trim().is_empty() {
+					return Some(citation.trim().to_string());
+				}
+			}
+		}
+		None
+	}
 
-		for i in 0..number_of_keys as usize {
-			let offset = 4 * (i + 1);
-			let key_id = self.geo_key_directory[offset];
-			let unknown_tag = TiffTag::new_unknown_tag();
-			let key = match keys.get(&key_id) {
-				Some(key) => key,
-				None => &unknown_tag,
-			};
+	/// Returns the name of the horizontal (ProjLinearUnitsGeoKey, or GeogAngularUnitsGeoKey
+	/// for unprojected data) and vertical (VerticalUnitsGeoKey) units, where specified.
+	pub fn find_units(&self) -> (Option<String>, Option<String>) {
+		let xy_units = match self.get_short_value(3076) {
+			Some(code) => units_name(code),
+			None => match self.get_short_value(2054) {
+				Some(code) => units_name(code),
+				None => None,
+			},
+		};
+		let z_units = match self.get_short_value(4099) {
+			Some(code) => units_name(code),
+			None => None,
+		};
+		(xy_units, z_units)
+	}
 
-			if key.code == 3072 || key.code == 2048 {
-				epsg_code = self.geo_key_directory[offset + 3];
-			} else if key.code == 2048 && epsg_code == 0u16 {
-				epsg_code = self.geo_key_directory[offset + 3];
+	/// Returns the value of a key that is stored directly in the key directory.
+	fn get_short_value(&self, key_id: u16) -> Option<u16> {
+		match self.find_key(key_id) {
+			Some(offset) if self.geo_key_directory[offset + 1] == 0 => {
+				Some(self.geo_key_directory[offset + 3])
 			}
+			_ => None,
 		}
+	}
 
-		epsg_code
+	/// Returns the value of a key that is stored in the GeoAsciiParamsTag, without the
+	/// terminating '|' character.
+	fn get_ascii_value(&self, key_id: u16) -> Option<String> {
+		match self.find_key(key_id) {
+			Some(offset) if self.geo_key_directory[offset + 1] == 34737 => {
+				let count = self.geo_key_directory[offset + 2] as usize;
+				let start = self.geo_key_directory[offset + 3] as usize;
+				let end = start + count;
+				if end > self.geo_ascii_params.len()
+					|| !self.geo_ascii_params.is_char_boundary(start)
+					|| !self.geo_ascii_params.is_char_boundary(end)
+				{
+					return None;
+				}
+				Some(self.geo_ascii_params[start..end].trim_right_matches('|').to_string())
+			}
+			_ => None,
+		}
+	}
+
+	/// Returns the position of a key's entry within the key directory.
+	fn find_key(&self, key_id: u16) -> Option<usize> {
+		if self.geo_key_directory.len() < 4 {
+			return None;
+		}
+		let number_of_keys = self.geo_key_directory[3] as usize;
+		for i in 0..number_of_keys {
+			let offset = 4 * (i + 1);
+			if offset + 3 >= self.geo_key_directory.len() {
+				break;
+			}
+			if self.geo_key_directory[offset] == key_id {
+				return Some(offset);
+			}
+		}
+		None
 	}
 
 	pub fn interpret_geokeys(&self) -> String {
@@ -228,6 +293,19 @@ impl GeoKeys {
 	}
 }
 
+/// Returns the name of an EPSG unit of measure code.
+fn units_name(code: u16) -> Option<String> {
+	let name = match code {
+		9001 => "metres",
+		9002 => "feet",
+		9003 => "US survey feet",
+		9101 => "radians",
+		9102 => "degrees",
+		_ => return None,
+	};
+	Some(name.to_string())
+}
+
 pub fn get_keys_map() -> HashMap<u16, TiffTag> {
 	let mut k = HashMap::new();
 	k.insert(
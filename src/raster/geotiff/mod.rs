@@ -22,7 +22,10 @@ use std::io::prelude::*;
 use std::io::BufWriter;
 use std::io::Error;
 use std::io::ErrorKind;
-use utils::{ByteOrderReader, Endianness};
+use std::io::{Seek, SeekFrom};
+use std::sync::Arc;
+use utils::settings;
+use utils::{ByteOrderReader, Endianness, MemoryMap};
 
 pub fn print_tags<'a>(file_name: &'a String) -> Result<(), Error> {
     let mut f = File::open(file_name.clone())?;
@@ -152,10 +155,21 @@ pub fn print_tags<'a>(file_name: &'a String) -> Result<(), Error> {
     Ok(())
 }
 
-pub fn read_geotiff<'a>(
+pub fn read_geotiff<'a, D: DecodedValues>(
     file_name: &'a String,
     configs: &'a mut RasterConfigs,
-    data: &'a mut Vec<f64>,
+    data: &'a mut D,
+) -> Result<(), Error> {
+    read_geotiff_band(file_name, configs, data, 0)
+}
+
+/// Reads a single band of a GeoTIFF, where `band` is the zero-based index of a sample of a
+/// multi-band grey-scale image. RGB and paletted images contain a single band.
+pub fn read_geotiff_band<'a, D: DecodedValues>(
+    file_name: &'a String,
+    configs: &'a mut RasterConfigs,
+    data: &'a mut D,
+    band: usize,
 ) -> Result<(), Error> {
     let mut f = File::open(file_name.clone())?;
 
@@ -177,8 +191,78 @@ pub fn read_geotiff<'a>(
         }
     }
 
+    read_geotiff_buffer(buffer, configs, data, band, BandRead::Values)?;
+    Ok(())
+}
+
+/// Reads the first band of a GeoTIFF, mapping the file into memory. If the image is an
+/// uncompressed, single-band grid of 64-bit floating-point values, stored in contiguous
+/// strips in the native byte order, the values are not read but rather refer directly to
+/// the mapped file. Otherwise, the values are read into `data` in the usual way and None
+/// is returned.
+pub fn read_geotiff_mapped<'a, D: DecodedValues>(
+    file_name: &'a String,
+    configs: &'a mut RasterConfigs,
+    data: &'a mut D,
+) -> Result<Option<RasterData>, Error> {
+    let map = match MemoryMap::open(file_name) {
+        Ok(map) => Arc::new(map),
+        Err(_) => {
+            read_geotiff_band(file_name, configs, data, 0)?;
+            return Ok(None);
+        }
+    };
+    if let Some(offset) = read_geotiff_buffer(&map[..], configs, data, 0, BandRead::Mapped)? {
+        let num_cells = configs.rows * configs.columns;
+        if let Some(values) = RasterData::from_map(map.clone(), offset, num_cells) {
+            return Ok(Some(values));
+        }
+        read_geotiff_buffer(&map[..], configs, data, 0, BandRead::Values)?;
+    }
+    Ok(None)
+}
+
+/// Reads the configurations of the first band of a GeoTIFF without decoding its values.
+/// The file is mapped into memory, such that only the parts of it that hold the image
+/// file directories are read.
+pub fn read_geotiff_header<'a>(
+    file_name: &'a String,
+    configs: &'a mut RasterConfigs,
+) -> Result<(), Error> {
+    let mut data: Vec<f64> = vec![];
+    match MemoryMap::open(file_name) {
+        Ok(map) => {
+            read_geotiff_buffer(&map[..], configs, &mut data, 0, BandRead::Header)?;
+        }
+        Err(_) => read_geotiff_band(file_name, configs, &mut data, 0)?,
+    }
+    Ok(())
+}
+
+// What is read of a band by `read_geotiff_buffer`.
+#[derive(Clone, Copy, PartialEq)]
+enum BandRead {
+    // the decoded values
+    Values,
+    // the offset of the values, if they can be mapped, or otherwise the decoded values
+    Mapped,
+    // only the configurations
+    Header,
+}
+
+/// Decodes a band of the GeoTIFF held in `buffer`. If `read` is `BandRead::Mapped` and
+/// the values of the band can be used as they are stored, the data are not decoded and
+/// the offset of the first value within the buffer is returned instead. If `read` is
+/// `BandRead::Header`, only the configurations are read.
+fn read_geotiff_buffer<B: AsRef<[u8]>, D: DecodedValues>(
+    buffer: B,
+    configs: &mut RasterConfigs,
+    data: &mut D,
+    band: usize,
+    read: BandRead,
+) -> Result<Option<usize>, Error> {
     //let byte_order = LittleEndian::read_u16(&buffer[0..2]);
-    match &buffer[0..2] {
+    match &buffer.as_ref()[0..2] {
         //byte_order { //LittleEndian::read_u16(&buffer[0..2]) {
         b"II" => configs.endian = Endianness::LittleEndian,
         b"MM" => configs.endian = Endianness::BigEndian,
@@ -295,12 +379,6 @@ pub fn read_geotiff<'a>(
         }
     };
 
-    //data = vec![0.0f64; configs.rows * configs.columns];
-    data.clear();
-    for _ in 0..configs.rows * configs.columns {
-        data.push(0.0f64);
-    }
-
     let bits_per_sample = match ifd_map.get(&258) {
         Some(ifd) => ifd.interpret_as_u16(),
         _ => {
@@ -352,10 +430,18 @@ pub fn read_geotiff<'a>(
         _ => 0,
     };
 
+    // unsigned integer data are the default when the SampleFormat tag is absent
     let sample_format = match ifd_map.get(&339) {
         Some(ifd) => ifd.interpret_as_u16(),
-        _ => [0].to_vec(),
+        _ => [1].to_vec(),
+    };
+
+    // PlanarConfiguration; 1 = chunky (interleaved samples), 2 = planar (separate sample planes)
+    let planar_config = match ifd_map.get(&TAG_PLANARCONFIGURATION) {
+        Some(ifd) => ifd.interpret_as_u16()[0],
+        _ => 1,
     };
+    let samples_per_pixel = bits_per_sample.len();
 
     configs.nodata = match ifd_map.get(&TAG_GDAL_NODATA) {
         Some(ifd) => ifd.interpret_as_ascii().parse::<f64>().unwrap(),
@@ -405,7 +491,26 @@ pub fn read_geotiff<'a>(
 
     // Get the EPSG code and WKT CRS
     configs.epsg_code = geokeys.find_epsg_code();
-    configs.coordinate_ref_system_wkt = esri_wkt_from_epsg(configs.epsg_code);
+    let wkt = esri_wkt_from_epsg(configs.epsg_code);
+    if wkt.contains("[") {
+        configs.coordinate_ref_system_wkt = wkt;
+    } else if let Some(citation) = geokeys.find_citation() {
+        // ESRI software stores the WKT of user-defined coordinate systems in the citation
+        let wkt_start = match citation.find("ESRI PE String = ") {
+            Some(idx) => idx + 17,
+            None => 0,
+        };
+        if citation[wkt_start..].contains("[") {
+            configs.coordinate_ref_system_wkt = citation[wkt_start..].trim().to_string();
+        }
+    }
+    let (xy_units, z_units) = geokeys.find_units();
+    if let Some(units) = xy_units {
+        configs.xy_units = units;
+    }
+    if let Some(units) = z_units {
+        configs.z_units = units;
+    }
     // if geokeys_map.contains_key(&2048) {
     //     // geographic coordinate system
     //     configs.epsg_code = geokeys_map.get(&2048).unwrap().interpret_as_u16()[0];
@@ -478,18 +583,24 @@ pub fn read_geotiff<'a>(
                 ))
             }
         };
+        if bits_per_sample[0] != 8 && bits_per_sample[0] != 16 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Only 8-bit and 16-bit paletted TIFFs are supported.",
+            ));
+        }
         let num_colors = color_map.len() / 3;
-        if color_map.len() % 3 != 0 || num_colors <= 0 || num_colors > 256 {
+        if color_map.len() % 3 != 0 || num_colors <= 0 || num_colors > 1 << bits_per_sample[0] {
             return Err(Error::new(ErrorKind::InvalidData, "bad ColorMap length"));
         }
         for i in 0..num_colors {
             // colours in the colour map are given in 16-bit channels
             // and need to be rescaled to an 8-bit format.
-            let red = (color_map[i] as f64 / 65535.0 * 255.0) as u32;
-            let green = (color_map[i + num_colors] as f64 / 65535.0 * 255.0) as u32;
-            let blue = (color_map[i + 2 * num_colors] as f64 / 65535.0 * 255.0) as u32;
+            let red = (color_map[i] as f64 / 257f64).round() as u32;
+            let green = (color_map[i + num_colors] as f64 / 257f64).round() as u32;
+            let blue = (color_map[i + 2 * num_colors] as f64 / 257f64).round() as u32;
             let a = 255u32;
-            let val = ((a << 24) | (red << 16) | (green << 8) | blue) as u32;
+            let val = ((a << 24) | (blue << 16) | (green << 8) | red) as u32;
             palette.push(val);
         }
     } else if photomet_str == "WhiteIsZero" {
@@ -505,6 +616,20 @@ pub fn read_geotiff<'a>(
         ));
     }
 
+    // The samples of grey-scale images with more than one sample per pixel are bands.
+    let multiband = samples_per_pixel > 1 && (mode == IM_GRAY || mode == IM_GRAYINVERT);
+    configs.bands = if multiband { samples_per_pixel as u8 } else { 1u8 };
+    if band >= configs.bands as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("The GeoTIFF does not contain band {}.", band + 1),
+        ));
+    }
+    if let Some(ifd) = ifd_map.get(&TAG_GDAL_METADATA) {
+        configs.band_names =
+            parse_band_descriptions(&ifd.interpret_as_ascii(), configs.bands as usize);
+    }
+
     let width = configs.columns;
     let height = configs.rows;
 
@@ -653,6 +778,173 @@ pub fn read_geotiff<'a>(
         };
     }
 
+    let predictor = match ifd_map.get(&317) {
+        Some(ifd) => ifd.interpret_as_u16()[0],
+        _ => 1,
+    };
+    let native_endian = if cfg!(target_endian = "little") {
+        Endianness::LittleEndian
+    } else {
+        Endianness::BigEndian
+    };
+    if read == BandRead::Mapped
+        && compression == COMPRESS_NONE
+        && !block_padding
+        && mode == IM_GRAY
+        && samples_per_pixel == 1
+        && sample_format[0] == 3
+        && bits_per_sample[0] == 64
+        && predictor != 2
+        && configs.endian == native_endian
+        && block_offsets.len() >= blocks_down
+        && block_counts.len() >= blocks_down
+    {
+        // the strips must follow one another without gaps
+        let first_offset = block_offsets[0] as usize;
+        let mut end = first_offset;
+        for j in 0..blocks_down {
+            if block_offsets[j] as usize != end {
+                end = 0;
+                break;
+            }
+            end += block_counts[j] as usize;
+        }
+        if end == first_offset + width * height * 8
+            && end <= th.buffer.as_ref().len()
+            && first_offset % 8 == 0
+        {
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+            configs.data_type = DataType::F64;
+            return Ok(Some(first_offset));
+        }
+    }
+
+    // the data type is needed to hold the decoded values in its storage type
+    match mode {
+        IM_GRAYINVERT | IM_GRAY => {
+            //ImageMode::GrayInvert | ImageMode::Gray => {
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+            match sample_format[0] {
+                1 => {
+                    // unsigned integer
+                    match bits_per_sample[0] {
+                        8 => {
+                            configs.data_type = DataType::U8;
+                        }
+                        16 => {
+                            configs.data_type = DataType::U16;
+                        }
+                        32 => {
+                            configs.data_type = DataType::U32;
+                        }
+                        64 => {
+                            configs.data_type = DataType::U64;
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "The raster was not read correctly",
+                            ))
+                        }
+                    }
+                }
+                2 => {
+                    // signed integer
+                    match bits_per_sample[0] {
+                        8 => {
+                            configs.data_type = DataType::I8;
+                        }
+                        16 => {
+                            configs.data_type = DataType::I16;
+                        }
+                        32 => {
+                            configs.data_type = DataType::I32;
+                        }
+                        64 => {
+                            configs.data_type = DataType::I64;
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "The raster was not read correctly",
+                            ))
+                        }
+                    }
+                }
+                3 => {
+                    // floating point
+                    match bits_per_sample[0] {
+                        32 => {
+                            configs.data_type = DataType::F32;
+                        }
+                        64 => {
+                            configs.data_type = DataType::F64;
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "The raster was not read correctly",
+                            ))
+                        }
+                    }
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "The raster was not read correctly",
+                    ))
+                }
+            }
+        }
+        IM_PALETTED => {
+            //ImageMode::Paletted => {
+            configs.photometric_interp = PhotometricInterpretation::Categorical;
+            configs.data_type = if bits_per_sample[0] == 16 {
+                DataType::U16
+            } else {
+                DataType::U8
+            };
+            configs.color_map = palette.clone();
+        }
+        IM_RGB => {
+            //ImageMode::RGB => {
+            // both 8-bit and 16-bit channels are read as packed 8-bit channels
+            configs.photometric_interp = PhotometricInterpretation::RGB;
+            if bits_per_sample[0] == 8 || bits_per_sample[0] == 16 {
+                configs.data_type = DataType::RGB24;
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The raster was not read correctly",
+                ));
+            }
+        }
+        IM_NRGBA | IM_RGBA => {
+            //ImageMode::NRGBA | ImageMode::RGBA => {
+            configs.photometric_interp = PhotometricInterpretation::RGB;
+            if bits_per_sample[0] == 8 || bits_per_sample[0] == 16 {
+                configs.data_type = DataType::RGBA32;
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The raster was not read correctly",
+                ));
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The raster was not read correctly",
+            ))
+        }
+    }
+
+    if read == BandRead::Header {
+        return Ok(None);
+    }
+
+    data.reset(configs.rows * configs.columns, configs.data_type);
+
     for i in 0..blocks_across {
         let mut blk_w = block_width;
         if !block_padding && i == blocks_across - 1 && width % block_width != 0 {
@@ -663,35 +955,54 @@ pub fn read_geotiff<'a>(
             if !block_padding && j == blocks_down - 1 && height % block_height != 0 {
                 blk_h = height % block_height;
             }
-            let offset = block_offsets[j * blocks_across + i] as usize;
-            let n = block_counts[j * blocks_across + i] as usize;
-            let mut buf: Vec<u8> = vec![];
-            match compression {
-                COMPRESS_NONE => {
-                    // no compression
-                    buf = th.buffer[offset..(offset + n)].to_vec();
-                }
-                COMPRESS_PACKBITS => {
-                    buf = packbits_decoder(th.buffer[offset..(offset + n)].to_vec());
+            let block = j * blocks_across + i;
+            let blocks_per_plane = blocks_across * blocks_down;
+            let bytes_per_sample = bits_per_sample[0] as usize / 8;
+            let mut buf = if multiband && planar_config == 2 {
+                // only the plane of the band being read is needed
+                let plane_block = band * blocks_per_plane + block;
+                decode_block(
+                    th.buffer.as_ref(),
+                    &block_offsets,
+                    &block_counts,
+                    plane_block,
+                    compression,
+                )?
+            } else {
+                decode_block(th.buffer.as_ref(), &block_offsets, &block_counts, block, compression)?
+            };
+            if multiband && planar_config != 2 {
+                // extract the band's samples from the interleaved pixels
+                let pixel_size = bytes_per_sample * samples_per_pixel;
+                let start = band * bytes_per_sample;
+                buf = buf
+                    .chunks(pixel_size)
+                    .filter(|px| px.len() == pixel_size)
+                    .flat_map(|px| px[start..start + bytes_per_sample].to_vec())
+                    .collect();
+            } else if !multiband && planar_config == 2 && samples_per_pixel > 1 {
+                // Each sample is stored in its own plane, with the blocks of the second plane
+                // following those of the first, and so on. Interleave the samples such that
+                // the block can be read in the same way as chunky data.
+                let mut planes = vec![buf];
+                for s in 1..samples_per_pixel {
+                    planes.push(decode_block(
+                        th.buffer.as_ref(),
+                        &block_offsets,
+                        &block_counts,
+                        s * blocks_per_plane + block,
+                        compression,
+                    )?);
                 }
-                COMPRESS_LZW => {
-                    let mut dec = lzw::DecoderEarlyChange::new(lzw::MsbReader::new(), 8u8);
-                    let mut compressed = &th.buffer[offset..(offset + n)];
-                    while compressed.len() > 0 {
-                        let (start, bytes) = dec.decode_bytes(&compressed).unwrap();
-                        compressed = &compressed[start..];
-                        buf.extend(bytes.iter().map(|&i| i));
+                let num_pixels = planes.iter().map(|p| p.len()).min().unwrap() / bytes_per_sample;
+                buf = Vec::with_capacity(num_pixels * bytes_per_sample * samples_per_pixel);
+                for px in 0..num_pixels {
+                    for plane in &planes {
+                        buf.extend_from_slice(
+                            &plane[px * bytes_per_sample..(px + 1) * bytes_per_sample],
+                        );
                     }
                 }
-                // COMPRESS_DEFLATE => {
-                //     let mut dec = GzDecoder::new(th.buffer[offset..(offset + n)].to_vec());
-                // }
-                _ => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        "The GeoTIFF decoder currently only supports PACKBITS and LZW compression.",
-                    ))
-                }
             }
             let mut bor = ByteOrderReader::new(buf, configs.endian);
 
@@ -717,7 +1028,7 @@ pub fn read_geotiff<'a>(
                                         for x in xmin..xmax {
                                             if off <= bor.len() {
                                                 let i = y * width + x;
-                                                data[i] = bor.read_u8() as f64; //buf[off] as f64;
+                                                data.set_value(i, bor.read_u8() as f64); //buf[off] as f64;
                                                 off += 1;
                                             }
                                         }
@@ -729,7 +1040,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_u16(); // g.ByteOrder.Uint16(g.buf[g.off : g.off+2])
                                                 let i = y * width + x;
-                                                data[i] = value as f64;
+                                                data.set_value(i, value as f64);
                                                 off += 2;
                                             }
                                         }
@@ -741,7 +1052,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_u32();
                                                 let i = y * width + x;
-                                                data[i] = value as f64;
+                                                data.set_value(i, value as f64);
                                                 off += 4;
                                             }
                                         }
@@ -753,7 +1064,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_u64();
                                                 let i = y * width + x;
-                                                data[i] = value as f64;
+                                                data.set_value(i, value as f64);
                                                 off += 8;
                                             }
                                         }
@@ -775,7 +1086,7 @@ pub fn read_geotiff<'a>(
                                         for x in xmin..xmax {
                                             if off <= bor.len() {
                                                 let i = y * width + x;
-                                                data[i] = bor.read_i8() as f64;
+                                                data.set_value(i, bor.read_i8() as f64);
                                                 off += 1;
                                             }
                                         }
@@ -787,7 +1098,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_i16();
                                                 let i = y * width + x;
-                                                data[i] = value as f64;
+                                                data.set_value(i, value as f64);
                                                 off += 2;
                                             }
                                         }
@@ -799,7 +1110,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_i32();
                                                 let i = y * width + x;
-                                                data[i] = value as f64;
+                                                data.set_value(i, value as f64);
                                                 off += 4;
                                             }
                                         }
@@ -811,7 +1122,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_i64();
                                                 let i = y * width + x;
-                                                data[i] = value as f64;
+                                                data.set_value(i, value as f64);
                                                 off += 8;
                                             }
                                         }
@@ -833,7 +1144,7 @@ pub fn read_geotiff<'a>(
                                         for x in xmin..xmax {
                                             let value = bor.read_f32();
                                             let i = y * width + x;
-                                            data[i] = value as f64;
+                                            data.set_value(i, value as f64);
                                             off += 4;
                                         }
                                     }
@@ -844,7 +1155,7 @@ pub fn read_geotiff<'a>(
                                             if off <= bor.len() {
                                                 let value = bor.read_f64();
                                                 let i = y * width + x;
-                                                data[i] = value;
+                                                data.set_value(i, value);
                                                 off += 8;
                                             }
                                         }
@@ -868,11 +1179,17 @@ pub fn read_geotiff<'a>(
                 }
                 IM_PALETTED => {
                     //ImageMode::Paletted => {
+                    // The category values are retained and the colour map is stored
+                    // with the raster configurations.
                     for y in ymin..ymax {
                         for x in xmin..xmax {
                             let i = y * width + x;
-                            let value = bor.read_u8() as usize;
-                            data[i] = palette[value] as f64;
+                            let value = if bits_per_sample[0] == 16 {
+                                bor.read_u16() as f64
+                            } else {
+                                bor.read_u8() as f64
+                            };
+                            data.set_value(i, value);
                         }
                     }
                 }
@@ -887,7 +1204,7 @@ pub fn read_geotiff<'a>(
                                 let a = 255u32;
                                 let value = (a << 24) | (blue << 16) | (green << 8) | red;
                                 let i = y * width + x;
-                                data[i] = value as f64;
+                                data.set_value(i, value as f64);
                             }
                         }
                     } else if bits_per_sample[0] == 16 {
@@ -903,7 +1220,7 @@ pub fn read_geotiff<'a>(
                                 let a = 255u32;
                                 let value = (a << 24) | (blue << 16) | (green << 8) | red;
                                 let i = y * width + x;
-                                data[i] = value as f64;
+                                data.set_value(i, value as f64);
                             }
                         }
                     } else {
@@ -924,7 +1241,7 @@ pub fn read_geotiff<'a>(
                                 let a = bor.read_u8() as u32;
                                 let value = (a << 24) | (blue << 16) | (green << 8) | red;
                                 let i = y * width + x;
-                                data[i] = value as f64;
+                                data.set_value(i, value as f64);
                             }
                         }
                     } else if bits_per_sample[0] == 16 {
@@ -940,125 +1257,9 @@ pub fn read_geotiff<'a>(
                                 let a = (bor.read_u16() as f64 / 65535f64 * 255f64) as u32;
                                 let value = (a << 24) | (blue << 16) | (green << 8) | red;
                                 let i = y * width + x;
-                                data[i] = value as f64;
-                            }
-                        }
-                    } else {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            "The raster was not read correctly",
-                        ));
-                    }
-                }
-                _ => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        "The raster was not read correctly",
-                    ))
-                }
-            }
-
-            match mode {
-                IM_GRAYINVERT | IM_GRAY => {
-                    //ImageMode::GrayInvert | ImageMode::Gray => {
-                    configs.photometric_interp = PhotometricInterpretation::Continuous;
-                    match sample_format[0] {
-                        1 => {
-                            // unsigned integer
-                            match bits_per_sample[0] {
-                                8 => {
-                                    configs.data_type = DataType::U8;
-                                }
-                                16 => {
-                                    configs.data_type = DataType::U16;
-                                }
-                                32 => {
-                                    configs.data_type = DataType::U32;
-                                }
-                                64 => {
-                                    configs.data_type = DataType::U64;
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        ErrorKind::InvalidData,
-                                        "The raster was not read correctly",
-                                    ))
-                                }
-                            }
-                        }
-                        2 => {
-                            // signed integer
-                            match bits_per_sample[0] {
-                                8 => {
-                                    configs.data_type = DataType::I8;
-                                }
-                                16 => {
-                                    configs.data_type = DataType::I16;
-                                }
-                                32 => {
-                                    configs.data_type = DataType::I32;
-                                }
-                                64 => {
-                                    configs.data_type = DataType::I64;
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        ErrorKind::InvalidData,
-                                        "The raster was not read correctly",
-                                    ))
-                                }
-                            }
-                        }
-                        3 => {
-                            // floating point
-                            match bits_per_sample[0] {
-                                32 => {
-                                    configs.data_type = DataType::F32;
-                                }
-                                64 => {
-                                    configs.data_type = DataType::F64;
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        ErrorKind::InvalidData,
-                                        "The raster was not read correctly",
-                                    ))
-                                }
+                                data.set_value(i, value as f64);
                             }
                         }
-                        _ => {
-                            return Err(Error::new(
-                                ErrorKind::InvalidData,
-                                "The raster was not read correctly",
-                            ))
-                        }
-                    }
-                }
-                IM_PALETTED => {
-                    //ImageMode::Paletted => {
-                    configs.photometric_interp = PhotometricInterpretation::Categorical;
-                    configs.data_type = DataType::U8;
-                }
-                IM_RGB => {
-                    //ImageMode::RGB => {
-                    configs.photometric_interp = PhotometricInterpretation::RGB;
-                    if bits_per_sample[0] == 8 {
-                        configs.data_type = DataType::U8;
-                    } else if bits_per_sample[0] == 16 {
-                        configs.data_type = DataType::U16;
-                    } else {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            "The raster was not read correctly",
-                        ));
-                    }
-                }
-                IM_NRGBA | IM_RGBA => {
-                    //ImageMode::NRGBA | ImageMode::RGBA => {
-                    if bits_per_sample[0] == 8 {
-                        configs.data_type = DataType::U32;
-                    } else if bits_per_sample[0] == 16 {
-                        configs.data_type = DataType::U64;
                     } else {
                         return Err(Error::new(
                             ErrorKind::InvalidData,
@@ -1087,7 +1288,8 @@ pub fn read_geotiff<'a>(
                     for col in 1..configs.columns {
                         //(0..configs.columns-1).rev() {
                         idx = row * configs.columns + col;
-                        data[idx] += data[idx - 1];
+                        let value = data.get_value(idx) + data.get_value(idx - 1);
+                        data.set_value(idx, value);
                     }
                 }
             }
@@ -1122,225 +1324,394 @@ pub fn read_geotiff<'a>(
     // println!("\nGeoKeys:\n");
     // println!("{}", geokeys.interpret_geokeys());
 
-    Ok(())
+    Ok(None)
 }
 
 pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
-    let f = File::create(r.file_name.clone())?;
-    let mut writer = BufWriter::new(f);
+    let num_bands = r.num_bands();
+    let mut writer = GeoTiffWriter::new(&r.file_name, &r.configs, num_bands)?;
+    r.configs.photometric_interp = writer.configs.photometric_interp;
+    let columns = r.configs.columns;
+    for band in Some(&r.data[..])
+        .into_iter()
+        .chain(r.extra_bands.iter().map(|b| &b[..]))
+    {
+        for row in 0..r.configs.rows {
+            writer.write_row(&band[row * columns..(row + 1) * columns])?;
+        }
+    }
+    writer.finish()
+}
 
-    // get the endianness of the raster
-    let little_endian = match r.configs.endian {
-        Endianness::LittleEndian => true,
-        Endianness::BigEndian => false,
-    };
+/// Writes a GeoTIFF one row at a time, so that the image data need not be held in memory.
+/// The image data directly follow the header and the IFD, the size of which depends on
+/// the (possibly compressed) size of the data, is written after them by `finish`. The
+/// rows of multi-band rasters are written band by band, i.e. all of the rows of the
+/// first band, then all of the rows of the second, and so on.
+pub struct GeoTiffWriter {
+    /// The configurations of the raster being written, with the photometric
+    /// interpretation adjusted to the one used in the file.
+    pub configs: RasterConfigs,
+    num_bands: usize,
+    strips: StripWriter<BufWriter<File>>,
+}
+
+impl GeoTiffWriter {
+    /// Creates the GeoTIFF file and writes its header.
+    pub fn new(
+        file_name: &str,
+        configs: &RasterConfigs,
+        num_bands: usize,
+    ) -> Result<GeoTiffWriter, Error> {
+        let mut configs = configs.clone();
+        let f = File::create(file_name)?;
+        let mut writer = BufWriter::new(f);
+
+        // Categorical and paletted rasters are written as colour-mapped images when they have
+        // a colour map that can be indexed by their values, and as grey-scale images otherwise.
+        if configs.photometric_interp == PhotometricInterpretation::Categorical
+            || configs.photometric_interp == PhotometricInterpretation::Paletted
+        {
+            let max_colors = match configs.data_type {
+                DataType::U8 => 256usize,
+                DataType::U16 => 65536usize,
+                _ => 0usize,
+            };
+            configs.photometric_interp =
+                if configs.color_map.is_empty() || configs.color_map.len() > max_colors {
+                    PhotometricInterpretation::Continuous
+                } else {
+                    PhotometricInterpretation::Categorical
+                };
+        }
+
+        // Multi-band rasters are written as grey-scale images with one sample per band, with
+        // each band stored in a separate plane.
+        if num_bands > 1 {
+            if configs.photometric_interp == PhotometricInterpretation::RGB {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Multi-band RGB rasters are currently unsupported for writing.",
+                ));
+            }
+            if configs.photometric_interp == PhotometricInterpretation::Categorical {
+                configs.photometric_interp = PhotometricInterpretation::Continuous;
+            }
+        }
 
-    if little_endian {
-        //////////////////////
-        // Write the header //
-        //////////////////////
-        writer.write_all("II".as_bytes())?;
-        // magic number
-        writer.write_u16::<LittleEndian>(42u16)?;
-        // offset to first IFD
-        let total_bytes_per_pixel = r.configs.data_type.get_data_size();
-        if total_bytes_per_pixel == 0 {
+        let total_bytes_per_pixel = configs.data_type.get_data_size();
+        if configs.endian == Endianness::LittleEndian {
+            writer.write_all("II".as_bytes())?;
+            // magic number
+            writer.write_u16::<LittleEndian>(42u16)?;
+            // offset to first IFD
+            if total_bytes_per_pixel == 0 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Unknown data type: {:?}. Photomet interp: {:?}",
+                        configs.data_type, configs.photometric_interp
+                    ),
+                ));
+            }
+            // (a placeholder, updated once the size of the image data is known)
+            writer.write_u32::<LittleEndian>(0u32)?;
+        } else {
+            writer.write_all("MM".as_bytes())?;
+            // magic number
+            writer.write_u16::<BigEndian>(42u16)?;
+            // offset to first IFD
+            if total_bytes_per_pixel == 0 {
+                return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
+            }
+            // (a placeholder, updated once the size of the image data is known)
+            writer.write_u32::<BigEndian>(0u32)?;
+        }
+        let compress = settings::get_settings().compress_rasters;
+        let strips = StripWriter::new(
+            writer,
+            8u32, // the image data follow the 8-byte header
+            configs.columns * total_bytes_per_pixel,
+            compress,
+        );
+        Ok(GeoTiffWriter {
+            configs: configs,
+            num_bands: num_bands,
+            strips: strips,
+        })
+    }
+
+    /// Writes the next row of image data, which must hold one value for each column.
+    pub fn write_row(&mut self, values: &[f64]) -> Result<(), Error> {
+        if values.len() != self.configs.columns {
             return Err(Error::new(
-                ErrorKind::InvalidData,
+                ErrorKind::InvalidInput,
                 format!(
-                    "Unknown data type: {:?}. Photomet interp: {:?}",
-                    r.configs.data_type, r.configs.photometric_interp
+                    "A row of {} values cannot be written to a raster of {} columns.",
+                    values.len(),
+                    self.configs.columns
                 ),
             ));
         }
-        let mut ifd_start = (8usize
-            + r.configs.rows as usize * r.configs.columns as usize * total_bytes_per_pixel)
-            as u32; // plus the 8-byte header
-        let mut ifd_start_needs_extra_byte = false;
-        if ifd_start % 2 == 1 {
-            ifd_start += 1;
-            ifd_start_needs_extra_byte = true;
-        }
-        writer.write_u32::<LittleEndian>(ifd_start)?;
-
-        // At the moment, categorical and paletted output is not supported.
-        if r.configs.photometric_interp == PhotometricInterpretation::Categorical
-            || r.configs.photometric_interp == PhotometricInterpretation::Paletted
-        {
-            r.configs.photometric_interp = PhotometricInterpretation::Continuous;
-        }
-
-        //////////////////////////
-        // Write the image data //
-        //////////////////////////
-        match r.configs.photometric_interp {
-            PhotometricInterpretation::Continuous
-            | PhotometricInterpretation::Categorical
-            | PhotometricInterpretation::Boolean => match r.configs.data_type {
-                DataType::F64 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_f64::<LittleEndian>(r.data[i])?;
+        let configs = &self.configs;
+        let strips = &mut self.strips;
+        if configs.endian == Endianness::LittleEndian {
+            match configs.photometric_interp {
+                PhotometricInterpretation::Continuous
+                | PhotometricInterpretation::Categorical
+                | PhotometricInterpretation::Boolean => match configs.data_type {
+                    DataType::F64 => {
+                        for col in 0..configs.columns {
+                            strips.write_f64::<LittleEndian>(values[col])?;
                         }
                     }
-                }
-                DataType::F32 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_f32::<LittleEndian>(r.data[i] as f32)?;
+                    DataType::F32 => {
+                        for col in 0..configs.columns {
+                            strips.write_f32::<LittleEndian>(values[col] as f32)?;
                         }
                     }
-                }
-                DataType::U64 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_u64::<LittleEndian>(r.data[i] as u64)?;
+                    DataType::U64 => {
+                        for col in 0..configs.columns {
+                            strips.write_u64::<LittleEndian>(values[col] as u64)?;
                         }
                     }
-                }
-                DataType::U32 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_u32::<LittleEndian>(r.data[i] as u32)?;
+                    DataType::U32 => {
+                        for col in 0..configs.columns {
+                            strips.write_u32::<LittleEndian>(values[col] as u32)?;
                         }
                     }
-                }
-                DataType::U16 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_u16::<LittleEndian>(r.data[i] as u16)?;
+                    DataType::U16 => {
+                        for col in 0..configs.columns {
+                            strips.write_u16::<LittleEndian>(values[col] as u16)?;
                         }
                     }
-                }
-                DataType::U8 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write(&[r.data[i] as u8])?;
+                    DataType::U8 => {
+                        for col in 0..configs.columns {
+                            strips.write(&[values[col] as u8])?;
                         }
                     }
-                }
-                DataType::I64 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_i64::<LittleEndian>(r.data[i] as i64)?;
+                    DataType::I64 => {
+                        for col in 0..configs.columns {
+                            strips.write_i64::<LittleEndian>(values[col] as i64)?;
                         }
                     }
-                }
-                DataType::I32 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_i32::<LittleEndian>(r.data[i] as i32)?;
+                    DataType::I32 => {
+                        for col in 0..configs.columns {
+                            strips.write_i32::<LittleEndian>(values[col] as i32)?;
                         }
                     }
-                }
-                DataType::I16 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_i16::<LittleEndian>(r.data[i] as i16)?;
+                    DataType::I16 => {
+                        for col in 0..configs.columns {
+                            strips.write_i16::<LittleEndian>(values[col] as i16)?;
                         }
                     }
-                }
-                DataType::I8 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write(&[r.data[i] as u8])?;
+                    DataType::I8 => {
+                        for col in 0..configs.columns {
+                            strips.write(&[values[col] as i8 as u8])?;
                         }
                     }
-                }
-                _ => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        format!(
-                            "Unknown data type: {:?}. Photomet interp: {:?}",
-                            r.configs.data_type, r.configs.photometric_interp
-                        ),
-                    ));
-                }
-            },
-            PhotometricInterpretation::RGB => {
-                match r.configs.data_type {
-                    DataType::RGB24 => {
-                        let mut bytes: [u8; 3] = [0u8; 3];
-                        let mut i: usize;
-                        for row in 0..r.configs.rows {
-                            for col in 0..r.configs.columns {
-                                i = row * r.configs.columns + col;
-                                // writer.write_u24::<LittleEndian>(r.data[i] as u32)?;
-                                let val = r.data[i] as u32;
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!(
+                                "Unknown data type: {:?}. Photomet interp: {:?}",
+                                configs.data_type, configs.photometric_interp
+                            ),
+                        ));
+                    }
+                },
+                PhotometricInterpretation::RGB => {
+                    match configs.data_type {
+                        DataType::RGB24 => {
+                            let mut bytes: [u8; 3] = [0u8; 3];
+                            for col in 0..configs.columns {
+                                // strips.write_u24::<LittleEndian>(values[col] as u32)?;
+                                let val = values[col] as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
-                                writer.write(&bytes)?;
+                                strips.write(&bytes)?;
                             }
                         }
-                    }
-                    DataType::RGBA32 | DataType::U32 => {
-                        let mut i: usize;
-                        let mut bytes: [u8; 4] = [0u8; 4];
-                        for row in 0..r.configs.rows {
-                            for col in 0..r.configs.columns {
-                                i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                        DataType::RGBA32 | DataType::U32 => {
+                            let mut bytes: [u8; 4] = [0u8; 4];
+                            for col in 0..configs.columns {
+                                let val = values[col] as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
                                 bytes[3] = ((val >> 24u32) & 0xFF) as u8; // a
-                                writer.write(&bytes)?;
+                                strips.write(&bytes)?;
                                 // let val2 = ((val << 24u32) & 0xFF) | ((val << 16u32) & 0xFF) | ((val << 8u32) & 0xFF) | (val & 0xFF);
-                                // writer.write_u32::<LittleEndian>(val2)?;
+                                // strips.write_u32::<LittleEndian>(val2)?;
                             }
                         }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                format!(
+                                    "Unknown data type: {:?}. Photomet interp: {:?}",
+                                    configs.data_type, configs.photometric_interp
+                                ),
+                            ));
+                        }
+                    }
+                }
+                PhotometricInterpretation::Paletted => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Paletted GeoTIFFs are currently unsupported for writing.",
+                    ));
+                }
+                PhotometricInterpretation::Unknown => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Error while writing GeoTIFF file.",
+                    ));
+                }
+            }
+        } else {
+            match configs.photometric_interp {
+                PhotometricInterpretation::Continuous
+                | PhotometricInterpretation::Categorical
+                | PhotometricInterpretation::Boolean => match configs.data_type {
+                    DataType::F64 => {
+                        for col in 0..configs.columns {
+                            strips.write_f64::<BigEndian>(values[col])?;
+                        }
+                    }
+                    DataType::F32 => {
+                        for col in 0..configs.columns {
+                            strips.write_f32::<BigEndian>(values[col] as f32)?;
+                        }
+                    }
+                    DataType::U64 => {
+                        for col in 0..configs.columns {
+                            strips.write_u64::<BigEndian>(values[col] as u64)?;
+                        }
+                    }
+                    DataType::U32 => {
+                        for col in 0..configs.columns {
+                            strips.write_u32::<BigEndian>(values[col] as u32)?;
+                        }
+                    }
+                    DataType::U16 => {
+                        for col in 0..configs.columns {
+                            strips.write_u16::<BigEndian>(values[col] as u16)?;
+                        }
+                    }
+                    DataType::U8 => {
+                        for col in 0..configs.columns {
+                            strips.write(&[values[col] as u8])?;
+                        }
+                    }
+                    DataType::I64 => {
+                        for col in 0..configs.columns {
+                            strips.write_i64::<BigEndian>(values[col] as i64)?;
+                        }
+                    }
+                    DataType::I32 => {
+                        for col in 0..configs.columns {
+                            strips.write_i32::<BigEndian>(values[col] as i32)?;
+                        }
+                    }
+                    DataType::I16 => {
+                        for col in 0..configs.columns {
+                            strips.write_i16::<BigEndian>(values[col] as i16)?;
+                        }
+                    }
+                    DataType::I8 => {
+                        for col in 0..configs.columns {
+                            strips.write(&[values[col] as i8 as u8])?;
+                        }
                     }
                     _ => {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            format!(
-                                "Unknown data type: {:?}. Photomet interp: {:?}",
-                                r.configs.data_type, r.configs.photometric_interp
-                            ),
-                        ));
+                        return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
+                    }
+                },
+                PhotometricInterpretation::RGB => {
+                    match configs.data_type {
+                        DataType::RGB24 => {
+                            let mut bytes: [u8; 3] = [0u8; 3];
+                            for col in 0..configs.columns {
+                                // strips.write_u24::<BigEndian>(values[col] as u32)?;
+                                let val = values[col] as u32;
+                                bytes[0] = ((val >> 16u32) & 0xFF) as u8; // red
+                                bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
+                                bytes[2] = (val & 0xFF) as u8; // blue
+                                strips.write(&bytes)?;
+                            }
+                        }
+                        DataType::RGBA32 => {
+                            for col in 0..configs.columns {
+                                let val = values[col] as u32;
+                                let val2 = ((val >> 24u32) & 0xFF)
+                                    | ((val >> 16u32) & 0xFF)
+                                    | ((val >> 8u32) & 0xFF)
+                                    | (val & 0xFF);
+                                strips.write_u32::<BigEndian>(val2)?;
+                            }
+                        }
+                        _ => {
+                            return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
+                        }
                     }
                 }
-            }
-            PhotometricInterpretation::Paletted => {
-                return Err(Error::new(
-                    ErrorKind::InvalidData,
-                    "Paletted GeoTIFFs are currently unsupported for writing.",
-                ));
-            }
-            PhotometricInterpretation::Unknown => {
-                return Err(Error::new(
-                    ErrorKind::InvalidData,
-                    "Error while writing GeoTIFF file.",
-                ));
+                PhotometricInterpretation::Paletted => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Paletted GeoTIFFs are currently unsupported for writing.",
+                    ));
+                }
+                PhotometricInterpretation::Unknown => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Error while writing GeoTIFF file.",
+                    ));
+                }
             }
         }
+        Ok(())
+    }
+
+    /// Writes the IFD following the image data and closes the file.
+    pub fn finish(self) -> Result<(), Error> {
+        let compress = self.strips.compress;
+        let (mut writer, strip_offsets, strip_byte_counts) = self.strips.finish()?;
+        write_ifd(
+            &mut writer,
+            &self.configs,
+            self.num_bands,
+            compress,
+            strip_offsets,
+            strip_byte_counts,
+        )
+    }
+}
 
-        // This is just because the IFD must start on a word (i.e. an even value). If the data are
-        // single bytes, then this may not be the case.
-        if ifd_start_needs_extra_byte {
+/// Writes the IFD of a GeoTIFF, which follows its image data, and the offset of the IFD.
+fn write_ifd(
+    writer: &mut BufWriter<File>,
+    configs: &RasterConfigs,
+    num_bands: usize,
+    compress: bool,
+    strip_offsets: Vec<u32>,
+    strip_byte_counts: Vec<u32>,
+) -> Result<(), Error> {
+    if configs.endian == Endianness::LittleEndian {
+        // The IFD must start on a word (i.e. an even value). If the data are single bytes,
+        // or compressed, then this may not be the case.
+        let mut ifd_start = match strip_offsets.last() {
+            Some(&offset) => offset + *strip_byte_counts.last().unwrap(),
+            None => 8u32,
+        };
+        if ifd_start % 2 == 1 {
             writer.write_u8(0u8)?;
+            ifd_start += 1;
         }
+        writer.seek(SeekFrom::Start(4))?;
+        writer.write_u32::<LittleEndian>(ifd_start)?;
+        writer.seek(SeekFrom::End(0))?;
 
         ////////////////////////////
         // Create the IFD entries //
@@ -1372,7 +1743,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             TAG_IMAGEWIDTH,
             DT_LONG,
             1u32,
-            r.configs.columns as u32,
+            configs.columns as u32,
         ));
 
         // ImageLength tag (257)
@@ -1380,10 +1751,10 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             TAG_IMAGELENGTH,
             DT_LONG,
             1u32,
-            r.configs.rows as u32,
+            configs.rows as u32,
         ));
 
-        let bits_per_sample = match r.configs.data_type {
+        let bits_per_sample = match configs.data_type {
             DataType::I8 | DataType::U8 => 8u16,
             DataType::I16 | DataType::U16 => 16u16,
             DataType::I32 | DataType::U32 | DataType::F32 => 32u16,
@@ -1396,11 +1767,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             }
         };
 
-        let samples_per_pixel = match r.configs.data_type {
-            DataType::I8 | DataType::U8 => 1u16,
-            DataType::I16 | DataType::U16 => 1u16,
-            DataType::I32 | DataType::U32 | DataType::F32 => 1u16,
-            DataType::I64 | DataType::U64 | DataType::F64 => 1u16,
+        let samples_per_pixel = match configs.data_type {
+            DataType::I8 | DataType::U8 => num_bands as u16,
+            DataType::I16 | DataType::U16 => num_bands as u16,
+            DataType::I32 | DataType::U32 | DataType::F32 => num_bands as u16,
+            DataType::I64 | DataType::U64 | DataType::F64 => num_bands as u16,
             DataType::RGB24 => 3u16,
             DataType::RGBA32 => 4u16,
             DataType::RGB48 => 3u16,
@@ -1410,13 +1781,13 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         };
 
         // BitsPerSample tag (258)
-        if r.configs.photometric_interp != PhotometricInterpretation::Boolean {
-            if samples_per_pixel == 1 {
+        if configs.photometric_interp != PhotometricInterpretation::Boolean {
+            if samples_per_pixel <= 2 {
                 ifd_entries.push(IfdEntry::new(
                     TAG_BITSPERSAMPLE,
                     DT_SHORT,
                     samples_per_pixel as u32,
-                    bits_per_sample as u32,
+                    inline_shorts(bits_per_sample, samples_per_pixel),
                 ));
             } else {
                 ifd_entries.push(IfdEntry::new(
@@ -1436,11 +1807,15 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             TAG_COMPRESSION,
             DT_SHORT,
             1u32,
-            COMPRESS_NONE as u32,
+            if compress {
+                COMPRESS_LZW as u32
+            } else {
+                COMPRESS_NONE as u32
+            },
         ));
 
         // PhotometricInterpretation tag (262)
-        let pi = match r.configs.photometric_interp {
+        let pi = match configs.photometric_interp {
             PhotometricInterpretation::Continuous => PI_BLACKISZERO,
             PhotometricInterpretation::Categorical | PhotometricInterpretation::Paletted => {
                 PI_PALETTED
@@ -1465,12 +1840,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ifd_entries.push(IfdEntry::new(
             TAG_STRIPOFFSETS,
             DT_LONG,
-            r.configs.rows as u32,
+            strip_offsets.len() as u32,
             larger_values_data.len() as u32,
         ));
-        let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel as u32;
-        for i in 0..r.configs.rows as u32 {
-            let _ = larger_values_data.write_u32::<LittleEndian>(8u32 + row_length_in_bytes * i);
+        for offset in &strip_offsets {
+            let _ = larger_values_data.write_u32::<LittleEndian>(*offset);
         }
 
         // SamplesPerPixel tag (277)
@@ -1488,24 +1862,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ifd_entries.push(IfdEntry::new(
             TAG_STRIPBYTECOUNTS,
             DT_LONG,
-            r.configs.rows as u32,
+            strip_byte_counts.len() as u32,
             larger_values_data.len() as u32,
-        ));
-        let total_bytes_per_pixel = match r.configs.data_type {
-            DataType::I8 | DataType::U8 => 1u32,
-            DataType::I16 | DataType::U16 => 2u32,
-            DataType::I32 | DataType::U32 | DataType::F32 => 4u32,
-            DataType::I64 | DataType::U64 | DataType::F64 => 8u32,
-            DataType::RGB24 => 3u32,
-            DataType::RGBA32 => 4u32,
-            DataType::RGB48 => 6u32,
-            _ => {
-                return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-            }
-        };
-        let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel;
-        for _ in 0..r.configs.rows as u32 {
-            let _ = larger_values_data.write_u32::<LittleEndian>(row_length_in_bytes);
+        ));
+        for count in &strip_byte_counts {
+            let _ = larger_values_data.write_u32::<LittleEndian>(*count);
         }
 
         // There is currently no support for storing the image resolution, so give a bogus value of 72x72 dpi.
@@ -1544,13 +1905,57 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ));
         let _ = larger_values_data.write_all(&soft_bytes);
 
-        if samples_per_pixel == 4 {
+        if configs.photometric_interp == PhotometricInterpretation::Categorical {
+            // ColorMap tag (320); all of the red values, then the green, then the blue,
+            // each rescaled to 16-bits.
+            let num_colors = 1usize << bits_per_sample;
+            ifd_entries.push(IfdEntry::new(
+                TAG_COLORMAP,
+                DT_SHORT,
+                3 * num_colors as u32,
+                larger_values_data.len() as u32,
+            ));
+            for shift in [0u32, 8u32, 16u32].iter() {
+                for i in 0..num_colors {
+                    let val = match configs.color_map.get(i) {
+                        Some(&c) => (c >> *shift) & 0xFF,
+                        None => 0u32,
+                    };
+                    let _ = larger_values_data.write_u16::<LittleEndian>((val * 257) as u16);
+                }
+            }
+        }
+
+        if configs.photometric_interp == PhotometricInterpretation::RGB
+            && samples_per_pixel == 4
+        {
             // ExtraSamples tag (338)
             ifd_entries.push(IfdEntry::new(TAG_EXTRASAMPLES, DT_SHORT, 1u32, 2u32));
         }
 
+        if num_bands > 1 {
+            // PlanarConfiguration tag (284); each band is stored in its own plane
+            ifd_entries.push(IfdEntry::new(TAG_PLANARCONFIGURATION, DT_SHORT, 1u32, 2u32));
+
+            // ExtraSamples tag (338); the samples of bands after the first are unspecified
+            let num_extra = num_bands as u32 - 1;
+            if num_extra <= 2 {
+                ifd_entries.push(IfdEntry::new(TAG_EXTRASAMPLES, DT_SHORT, num_extra, 0u32));
+            } else {
+                ifd_entries.push(IfdEntry::new(
+                    TAG_EXTRASAMPLES,
+                    DT_SHORT,
+                    num_extra,
+                    larger_values_data.len() as u32,
+                ));
+                for _ in 0..num_extra {
+                    let _ = larger_values_data.write_u16::<LittleEndian>(0u16);
+                }
+            }
+        }
+
         // SampleFormat tag (339)
-        let samples_format = match r.configs.data_type {
+        let samples_format = match configs.data_type {
             DataType::U8 | DataType::U16 | DataType::U32 | DataType::U64 => 1u16,
             DataType::I8 | DataType::I16 | DataType::I32 | DataType::I64 => 2u16,
             DataType::F32 | DataType::F64 => 3u16,
@@ -1559,12 +1964,12 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
             }
         };
-        if samples_per_pixel == 1 {
+        if samples_per_pixel <= 2 {
             ifd_entries.push(IfdEntry::new(
                 TAG_SAMPLEFORMAT,
                 DT_SHORT,
                 samples_per_pixel as u32,
-                samples_format as u32,
+                inline_shorts(samples_format, samples_per_pixel),
             ));
         } else {
             ifd_entries.push(IfdEntry::new(
@@ -1585,8 +1990,8 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             3u32,
             larger_values_data.len() as u32,
         ));
-        let _ = larger_values_data.write_f64::<LittleEndian>(r.configs.resolution_x);
-        let _ = larger_values_data.write_f64::<LittleEndian>(r.configs.resolution_y);
+        let _ = larger_values_data.write_f64::<LittleEndian>(configs.resolution_x);
+        let _ = larger_values_data.write_f64::<LittleEndian>(configs.resolution_y);
         let _ = larger_values_data.write_f64::<LittleEndian>(0f64);
 
         // ModelPixelScaleTag tag (33922)
@@ -1599,24 +2004,56 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         let _ = larger_values_data.write_f64::<LittleEndian>(0f64); // I
         let _ = larger_values_data.write_f64::<LittleEndian>(0f64); // J
         let _ = larger_values_data.write_f64::<LittleEndian>(0f64); // K
-        let _ = larger_values_data.write_f64::<LittleEndian>(r.configs.west); // X
-        let _ = larger_values_data.write_f64::<LittleEndian>(r.configs.north); // Y
+        let _ = larger_values_data.write_f64::<LittleEndian>(configs.west); // X
+        let _ = larger_values_data.write_f64::<LittleEndian>(configs.north); // Y
         let _ = larger_values_data.write_f64::<LittleEndian>(0f64); // Z
 
+        // TAG_GDAL_METADATA tag (42112); the band names
+        let band_names: Vec<String> =
+            configs.band_names.iter().take(num_bands).cloned().collect();
+        if band_names.iter().any(|name| !name.is_empty()) {
+            let mut metadata_bytes = band_descriptions_xml(&band_names).into_bytes();
+            metadata_bytes.push(0);
+            ifd_entries.push(IfdEntry::new(
+                TAG_GDAL_METADATA,
+                DT_ASCII,
+                metadata_bytes.len() as u32,
+                larger_values_data.len() as u32,
+            ));
+            if metadata_bytes.len() % 2 == 1 {
+                metadata_bytes.push(0);
+            }
+            let _ = larger_values_data.write_all(&metadata_bytes);
+        }
+
         // TAG_GDAL_NODATA tag (42113)
-        let nodata_str = format!("{}", r.configs.nodata);
+        let nodata_str = format!("{}", configs.nodata);
         let mut nodata_bytes = nodata_str.into_bytes();
         nodata_bytes.push(0);
-        ifd_entries.push(IfdEntry::new(
-            TAG_GDAL_NODATA,
-            DT_ASCII,
-            nodata_bytes.len() as u32,
-            larger_values_data.len() as u32,
-        ));
-        if nodata_bytes.len() % 2 == 1 {
-            nodata_bytes.push(0);
+        if nodata_bytes.len() <= 4 {
+            // values of four or fewer bytes are stored in the entry itself
+            let value = nodata_bytes
+                .iter()
+                .enumerate()
+                .fold(0u32, |v, (i, b)| v | (*b as u32) << (8 * i));
+            ifd_entries.push(IfdEntry::new(
+                TAG_GDAL_NODATA,
+                DT_ASCII,
+                nodata_bytes.len() as u32,
+                value,
+            ));
+        } else {
+            ifd_entries.push(IfdEntry::new(
+                TAG_GDAL_NODATA,
+                DT_ASCII,
+                nodata_bytes.len() as u32,
+                larger_values_data.len() as u32,
+            ));
+            if nodata_bytes.len() % 2 == 1 {
+                nodata_bytes.push(0);
+            }
+            let _ = larger_values_data.write_all(&nodata_bytes);
         }
-        let _ = larger_values_data.write_all(&nodata_bytes);
 
         let kw_map = get_keyword_map();
         let geographic_type_map = match kw_map.get(&2048u16) {
@@ -1642,7 +2079,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         let mut gk_entries: Vec<GeoKeyEntry> = vec![];
         let mut ascii_params = String::new(); //: Vec<u8> = vec![];
         let double_params: Vec<f64> = vec![];
-        if geographic_type_map.contains_key(&r.configs.epsg_code) {
+        if geographic_type_map.contains_key(&configs.epsg_code) {
             // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
                 tag: TAG_GTMODELTYPEGEOKEY,
@@ -1652,7 +2089,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             });
 
             // GTRasterTypeGeoKey (1025)
-            if r.configs.pixel_is_area {
+            if configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
                     tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
@@ -1671,7 +2108,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             // tGTCitationGeoKey (1026)
             let mut v = String::from(
                 geographic_type_map
-                    .get(&r.configs.epsg_code)
+                    .get(&configs.epsg_code)
                     .unwrap()
                     .clone(),
             );
@@ -1690,12 +2127,12 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 tag: TAG_GEOGRAPHICTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: r.configs.epsg_code,
+                value_offset: configs.epsg_code,
             });
 
-            if r.configs.z_units.to_lowercase() != "not specified" {
+            if configs.z_units.to_lowercase() != "not specified" {
                 // VerticalUnitsGeoKey (4099)
-                let units = r.configs.z_units.to_lowercase();
+                let units = configs.z_units.to_lowercase();
                 if units.contains("met") {
                     gk_entries.push(GeoKeyEntry {
                         tag: TAG_VERTICALUNITSGEOKEY,
@@ -1712,7 +2149,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     });
                 }
             }
-        } else if projected_cs_type_map.contains_key(&r.configs.epsg_code) {
+        } else if projected_cs_type_map.contains_key(&configs.epsg_code) {
             // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
                 tag: TAG_GTMODELTYPEGEOKEY,
@@ -1722,7 +2159,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             });
 
             // GTRasterTypeGeoKey (1025)
-            if r.configs.pixel_is_area {
+            if configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
                     tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
@@ -1743,13 +2180,13 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 tag: TAG_PROJECTEDCSTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: r.configs.epsg_code,
+                value_offset: configs.epsg_code,
             });
 
             // PCSCitationGeoKey (3073)
             let mut v = String::from(
                 projected_cs_type_map
-                    .get(&r.configs.epsg_code)
+                    .get(&configs.epsg_code)
                     .unwrap()
                     .clone(),
             );
@@ -1763,9 +2200,9 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             });
             ascii_params.push_str(&v);
 
-            if r.configs.xy_units.to_lowercase() != "not specified" {
+            if configs.xy_units.to_lowercase() != "not specified" {
                 // ProjLinearUnitsGeoKey (3076)
-                let units = r.configs.xy_units.to_lowercase();
+                let units = configs.xy_units.to_lowercase();
                 if units.contains("met") {
                     gk_entries.push(GeoKeyEntry {
                         tag: TAG_PROJLINEARUNITSGEOKEY,
@@ -1783,9 +2220,9 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 }
             }
 
-            if r.configs.z_units.to_lowercase() != "not specified" {
+            if configs.z_units.to_lowercase() != "not specified" {
                 // VerticalUnitsGeoKey (4099)
-                let units = r.configs.z_units.to_lowercase();
+                let units = configs.z_units.to_lowercase();
                 if units.contains("met") {
                     gk_entries.push(GeoKeyEntry {
                         tag: TAG_VERTICALUNITSGEOKEY,
@@ -1803,18 +2240,28 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 }
             }
         } else {
-            // we don't know much about the coordinate system used.
+            // we don't know much about the coordinate system used, other than possibly
+            // its WKT definition, which is stored in the citation in the same way as
+            // ESRI software does for user-defined coordinate systems.
+            let wkt = configs.coordinate_ref_system_wkt.trim().to_string();
+            let model_type = if wkt.to_uppercase().starts_with("PROJCS[") {
+                1u16
+            } else if wkt.to_uppercase().starts_with("GEOGCS[") {
+                2u16
+            } else {
+                0u16
+            };
 
             // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
                 tag: TAG_GTMODELTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: 0u16,
+                value_offset: model_type,
             });
 
             // GTRasterTypeGeoKey (1025)
-            if r.configs.pixel_is_area {
+            if configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
                     tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
@@ -1829,6 +2276,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     value_offset: 2u16,
                 });
             }
+
+            if model_type != 0 && wkt.len() < 65000 {
+                // tGTCitationGeoKey (1026)
+                let v = format!("ESRI PE String = {}|", wkt);
+                gk_entries.push(GeoKeyEntry {
+                    tag: TAG_GTCITATIONGEOKEY,
+                    location: 34737u16,
+                    count: v.len() as u16,
+                    value_offset: ascii_params.len() as u16,
+                });
+                ascii_params.push_str(&v);
+            }
         }
 
         // create the GeoKeyDirectoryTag tag (34735)
@@ -1904,6 +2363,9 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             } else if ifde.ifd_type == DT_LONG && ifde.num_values == 1 {
                 // it's a value
                 writer.write_u32::<LittleEndian>(ifde.offset)?;
+            } else if ifde.ifd_type == DT_ASCII && ifde.num_values <= 4 {
+                // it's a value
+                writer.write_u32::<LittleEndian>(ifde.offset)?;
             } else if ifde.ifd_type == DT_SHORT && ifde.num_values == 2 {
                 // I'm not really sure about this one. Two shorts will fit in the value_offset, but will they be interpreted correctly?
                 writer.write_u32::<LittleEndian>(ifde.offset)?; // Value
@@ -1983,183 +2445,19 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             - ResolutionUnit
         */
     } else {
-        //////////////////////
-        // Write the header //
-        //////////////////////
-        writer.write_all("II".as_bytes())?;
-        // magic number
-        writer.write_u16::<BigEndian>(42u16)?;
-        // offset to first IFD
-        let total_bytes_per_pixel = r.configs.data_type.get_data_size();
-        if total_bytes_per_pixel == 0 {
-            return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-        }
-        let mut ifd_start = (8usize
-            + r.configs.rows as usize * r.configs.columns as usize * total_bytes_per_pixel)
-            as u32; // plus the 8-byte header
-        let mut ifd_start_needs_extra_byte = false;
+        // The IFD must start on a word (i.e. an even value). If the data are single bytes,
+        // or compressed, then this may not be the case.
+        let mut ifd_start = match strip_offsets.last() {
+            Some(&offset) => offset + *strip_byte_counts.last().unwrap(),
+            None => 8u32,
+        };
         if ifd_start % 2 == 1 {
+            writer.write_u8(0u8)?;
             ifd_start += 1;
-            ifd_start_needs_extra_byte = true;
         }
+        writer.seek(SeekFrom::Start(4))?;
         writer.write_u32::<BigEndian>(ifd_start)?;
-
-        //////////////////////////////
-        // Write the image the data //
-        //////////////////////////////
-        match r.configs.photometric_interp {
-            PhotometricInterpretation::Continuous
-            | PhotometricInterpretation::Categorical
-            | PhotometricInterpretation::Boolean => match r.configs.data_type {
-                DataType::F64 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_f64::<BigEndian>(r.data[i])?;
-                        }
-                    }
-                }
-                DataType::F32 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_f32::<BigEndian>(r.data[i] as f32)?;
-                        }
-                    }
-                }
-                DataType::U64 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_u64::<BigEndian>(r.data[i] as u64)?;
-                        }
-                    }
-                }
-                DataType::U32 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_u32::<BigEndian>(r.data[i] as u32)?;
-                        }
-                    }
-                }
-                DataType::U16 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_u16::<BigEndian>(r.data[i] as u16)?;
-                        }
-                    }
-                }
-                DataType::U8 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write(&[r.data[i] as u8])?;
-                        }
-                    }
-                }
-                DataType::I64 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_i64::<BigEndian>(r.data[i] as i64)?;
-                        }
-                    }
-                }
-                DataType::I32 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_i32::<BigEndian>(r.data[i] as i32)?;
-                        }
-                    }
-                }
-                DataType::I16 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write_i16::<BigEndian>(r.data[i] as i16)?;
-                        }
-                    }
-                }
-                DataType::I8 => {
-                    let mut i: usize;
-                    for row in 0..r.configs.rows {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write(&[r.data[i] as u8])?;
-                        }
-                    }
-                }
-                _ => {
-                    return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-                }
-            },
-            PhotometricInterpretation::RGB => {
-                match r.configs.data_type {
-                    DataType::RGB24 => {
-                        let mut bytes: [u8; 3] = [0u8; 3];
-                        let mut i: usize;
-                        for row in 0..r.configs.rows {
-                            for col in 0..r.configs.columns {
-                                i = row * r.configs.columns + col;
-                                // writer.write_u24::<BigEndian>(r.data[i] as u32)?;
-                                let val = r.data[i] as u32;
-                                bytes[0] = ((val >> 16u32) & 0xFF) as u8; // red
-                                bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
-                                bytes[2] = (val & 0xFF) as u8; // blue
-                                writer.write(&bytes)?;
-                            }
-                        }
-                    }
-                    DataType::RGBA32 => {
-                        let mut i: usize;
-                        for row in 0..r.configs.rows {
-                            for col in 0..r.configs.columns {
-                                i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
-                                let val2 = ((val >> 24u32) & 0xFF)
-                                    | ((val >> 16u32) & 0xFF)
-                                    | ((val >> 8u32) & 0xFF)
-                                    | (val & 0xFF);
-                                writer.write_u32::<BigEndian>(val2)?;
-                            }
-                        }
-                    }
-                    _ => {
-                        return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-                    }
-                }
-            }
-            PhotometricInterpretation::Paletted => {
-                return Err(Error::new(
-                    ErrorKind::InvalidData,
-                    "Paletted GeoTIFFs are currently unsupported for writing.",
-                ));
-            }
-            PhotometricInterpretation::Unknown => {
-                return Err(Error::new(
-                    ErrorKind::InvalidData,
-                    "Error while writing GeoTIFF file.",
-                ));
-            }
-        }
-
-        // This is just because the IFD must start on a word (i.e. an even value). If the data are
-        // single bytes, then this may not be the case.
-        if ifd_start_needs_extra_byte {
-            writer.write_u8(0u8)?;
-        }
+        writer.seek(SeekFrom::End(0))?;
 
         ////////////////////////////
         // Create the IFD entries //
@@ -2191,7 +2489,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             TAG_IMAGEWIDTH,
             DT_LONG,
             1u32,
-            r.configs.columns as u32,
+            configs.columns as u32,
         ));
 
         // ImageLength tag (257)
@@ -2199,10 +2497,10 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             TAG_IMAGELENGTH,
             DT_LONG,
             1u32,
-            r.configs.rows as u32,
+            configs.rows as u32,
         ));
 
-        let bits_per_sample = match r.configs.data_type {
+        let bits_per_sample = match configs.data_type {
             DataType::I8 | DataType::U8 => 8u16,
             DataType::I16 | DataType::U16 => 16u16,
             DataType::I32 | DataType::U32 | DataType::F32 => 32u16,
@@ -2215,11 +2513,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             }
         };
 
-        let samples_per_pixel = match r.configs.data_type {
-            DataType::I8 | DataType::U8 => 1u16,
-            DataType::I16 | DataType::U16 => 1u16,
-            DataType::I32 | DataType::U32 | DataType::F32 => 1u16,
-            DataType::I64 | DataType::U64 | DataType::F64 => 1u16,
+        let samples_per_pixel = match configs.data_type {
+            DataType::I8 | DataType::U8 => num_bands as u16,
+            DataType::I16 | DataType::U16 => num_bands as u16,
+            DataType::I32 | DataType::U32 | DataType::F32 => num_bands as u16,
+            DataType::I64 | DataType::U64 | DataType::F64 => num_bands as u16,
             DataType::RGB24 => 3u16,
             DataType::RGBA32 => 4u16,
             DataType::RGB48 => 3u16,
@@ -2229,13 +2527,13 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         };
 
         // BitsPerSample tag (258)
-        if r.configs.photometric_interp != PhotometricInterpretation::Boolean {
-            if samples_per_pixel == 1 {
+        if configs.photometric_interp != PhotometricInterpretation::Boolean {
+            if samples_per_pixel <= 2 {
                 ifd_entries.push(IfdEntry::new(
                     TAG_BITSPERSAMPLE,
                     DT_SHORT,
                     samples_per_pixel as u32,
-                    bits_per_sample as u32,
+                    inline_shorts(bits_per_sample, samples_per_pixel),
                 ));
             } else {
                 ifd_entries.push(IfdEntry::new(
@@ -2255,11 +2553,15 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             TAG_COMPRESSION,
             DT_SHORT,
             1u32,
-            COMPRESS_NONE as u32,
+            if compress {
+                COMPRESS_LZW as u32
+            } else {
+                COMPRESS_NONE as u32
+            },
         ));
 
         // PhotometricInterpretation tag (262)
-        let pi = match r.configs.photometric_interp {
+        let pi = match configs.photometric_interp {
             PhotometricInterpretation::Continuous => PI_BLACKISZERO,
             PhotometricInterpretation::Categorical | PhotometricInterpretation::Paletted => {
                 PI_PALETTED
@@ -2284,12 +2586,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ifd_entries.push(IfdEntry::new(
             TAG_STRIPOFFSETS,
             DT_LONG,
-            r.configs.columns as u32,
+            strip_offsets.len() as u32,
             larger_values_data.len() as u32,
         ));
-        let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel as u32;
-        for i in 0..r.configs.rows as u32 {
-            let _ = larger_values_data.write_u32::<BigEndian>(8u32 + row_length_in_bytes * i);
+        for offset in &strip_offsets {
+            let _ = larger_values_data.write_u32::<BigEndian>(*offset);
         }
 
         // SamplesPerPixel tag (277)
@@ -2307,24 +2608,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ifd_entries.push(IfdEntry::new(
             TAG_STRIPBYTECOUNTS,
             DT_LONG,
-            r.configs.columns as u32,
+            strip_byte_counts.len() as u32,
             larger_values_data.len() as u32,
         ));
-        let total_bytes_per_pixel = match r.configs.data_type {
-            DataType::I8 | DataType::U8 => 1u32,
-            DataType::I16 | DataType::U16 => 2u32,
-            DataType::I32 | DataType::U32 | DataType::F32 => 4u32,
-            DataType::I64 | DataType::U64 | DataType::F64 => 8u32,
-            DataType::RGB24 => 3u32,
-            DataType::RGBA32 => 4u32,
-            DataType::RGB48 => 6u32,
-            _ => {
-                return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-            }
-        };
-        let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel;
-        for _ in 0..r.configs.rows as u32 {
-            let _ = larger_values_data.write_u32::<BigEndian>(row_length_in_bytes);
+        for count in &strip_byte_counts {
+            let _ = larger_values_data.write_u32::<BigEndian>(*count);
         }
 
         // There is currently no support for storing the image resolution, so give a bogus value of 72x72 dpi.
@@ -2363,8 +2651,50 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ));
         let _ = larger_values_data.write_all(&soft_bytes);
 
+        if configs.photometric_interp == PhotometricInterpretation::Categorical {
+            // ColorMap tag (320); all of the red values, then the green, then the blue,
+            // each rescaled to 16-bits.
+            let num_colors = 1usize << bits_per_sample;
+            ifd_entries.push(IfdEntry::new(
+                TAG_COLORMAP,
+                DT_SHORT,
+                3 * num_colors as u32,
+                larger_values_data.len() as u32,
+            ));
+            for shift in [0u32, 8u32, 16u32].iter() {
+                for i in 0..num_colors {
+                    let val = match configs.color_map.get(i) {
+                        Some(&c) => (c >> *shift) & 0xFF,
+                        None => 0u32,
+                    };
+                    let _ = larger_values_data.write_u16::<BigEndian>((val * 257) as u16);
+                }
+            }
+        }
+
+        if num_bands > 1 {
+            // PlanarConfiguration tag (284); each band is stored in its own plane
+            ifd_entries.push(IfdEntry::new(TAG_PLANARCONFIGURATION, DT_SHORT, 1u32, 2u32));
+
+            // ExtraSamples tag (338); the samples of bands after the first are unspecified
+            let num_extra = num_bands as u32 - 1;
+            if num_extra <= 2 {
+                ifd_entries.push(IfdEntry::new(TAG_EXTRASAMPLES, DT_SHORT, num_extra, 0u32));
+            } else {
+                ifd_entries.push(IfdEntry::new(
+                    TAG_EXTRASAMPLES,
+                    DT_SHORT,
+                    num_extra,
+                    larger_values_data.len() as u32,
+                ));
+                for _ in 0..num_extra {
+                    let _ = larger_values_data.write_u16::<BigEndian>(0u16);
+                }
+            }
+        }
+
         // SampleFormat tag (339)
-        let samples_format = match r.configs.data_type {
+        let samples_format = match configs.data_type {
             DataType::U8 | DataType::U16 | DataType::U32 | DataType::U64 => 1u16,
             DataType::I8 | DataType::I16 | DataType::I32 | DataType::I64 => 2u16,
             DataType::F32 | DataType::F64 => 3u16,
@@ -2373,12 +2703,12 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
             }
         };
-        if samples_per_pixel == 1 {
+        if samples_per_pixel <= 2 {
             ifd_entries.push(IfdEntry::new(
                 TAG_SAMPLEFORMAT,
                 DT_SHORT,
                 samples_per_pixel as u32,
-                samples_format as u32,
+                inline_shorts(samples_format, samples_per_pixel),
             ));
         } else {
             ifd_entries.push(IfdEntry::new(
@@ -2399,8 +2729,8 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             3u32,
             larger_values_data.len() as u32,
         ));
-        let _ = larger_values_data.write_f64::<BigEndian>(r.configs.resolution_x);
-        let _ = larger_values_data.write_f64::<BigEndian>(r.configs.resolution_y);
+        let _ = larger_values_data.write_f64::<BigEndian>(configs.resolution_x);
+        let _ = larger_values_data.write_f64::<BigEndian>(configs.resolution_y);
         let _ = larger_values_data.write_f64::<BigEndian>(0f64);
 
         // ModelPixelScaleTag tag (33922)
@@ -2413,24 +2743,56 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         let _ = larger_values_data.write_f64::<BigEndian>(0f64); // I
         let _ = larger_values_data.write_f64::<BigEndian>(0f64); // J
         let _ = larger_values_data.write_f64::<BigEndian>(0f64); // K
-        let _ = larger_values_data.write_f64::<BigEndian>(r.configs.west); // X
-        let _ = larger_values_data.write_f64::<BigEndian>(r.configs.north); // Y
+        let _ = larger_values_data.write_f64::<BigEndian>(configs.west); // X
+        let _ = larger_values_data.write_f64::<BigEndian>(configs.north); // Y
         let _ = larger_values_data.write_f64::<BigEndian>(0f64); // Z
 
+        // TAG_GDAL_METADATA tag (42112); the band names
+        let band_names: Vec<String> =
+            configs.band_names.iter().take(num_bands).cloned().collect();
+        if band_names.iter().any(|name| !name.is_empty()) {
+            let mut metadata_bytes = band_descriptions_xml(&band_names).into_bytes();
+            metadata_bytes.push(0);
+            ifd_entries.push(IfdEntry::new(
+                TAG_GDAL_METADATA,
+                DT_ASCII,
+                metadata_bytes.len() as u32,
+                larger_values_data.len() as u32,
+            ));
+            if metadata_bytes.len() % 2 == 1 {
+                metadata_bytes.push(0);
+            }
+            let _ = larger_values_data.write_all(&metadata_bytes);
+        }
+
         // TAG_GDAL_NODATA tag (42113)
-        let nodata_str = format!("{}", r.configs.nodata);
+        let nodata_str = format!("{}", configs.nodata);
         let mut nodata_bytes = nodata_str.into_bytes();
         nodata_bytes.push(0);
-        ifd_entries.push(IfdEntry::new(
-            TAG_GDAL_NODATA,
-            DT_ASCII,
-            nodata_bytes.len() as u32,
-            larger_values_data.len() as u32,
-        ));
-        if nodata_bytes.len() % 2 == 1 {
-            nodata_bytes.push(0);
+        if nodata_bytes.len() <= 4 {
+            // values of four or fewer bytes are stored in the entry itself
+            let value = nodata_bytes
+                .iter()
+                .enumerate()
+                .fold(0u32, |v, (i, b)| v | (*b as u32) << (24 - 8 * i));
+            ifd_entries.push(IfdEntry::new(
+                TAG_GDAL_NODATA,
+                DT_ASCII,
+                nodata_bytes.len() as u32,
+                value,
+            ));
+        } else {
+            ifd_entries.push(IfdEntry::new(
+                TAG_GDAL_NODATA,
+                DT_ASCII,
+                nodata_bytes.len() as u32,
+                larger_values_data.len() as u32,
+            ));
+            if nodata_bytes.len() % 2 == 1 {
+                nodata_bytes.push(0);
+            }
+            let _ = larger_values_data.write_all(&nodata_bytes);
         }
-        let _ = larger_values_data.write_all(&nodata_bytes);
 
         let kw_map = get_keyword_map();
         let geographic_type_map = match kw_map.get(&2048u16) {
@@ -2456,7 +2818,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         let mut gk_entries: Vec<GeoKeyEntry> = vec![];
         let mut ascii_params = String::new(); //: Vec<u8> = vec![];
         let double_params: Vec<f64> = vec![];
-        if geographic_type_map.contains_key(&r.configs.epsg_code) {
+        if geographic_type_map.contains_key(&configs.epsg_code) {
             // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
                 tag: TAG_GTMODELTYPEGEOKEY,
@@ -2466,7 +2828,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             });
 
             // GTRasterTypeGeoKey (1025)
-            if r.configs.pixel_is_area {
+            if configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
                     tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
@@ -2485,7 +2847,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             // tGTCitationGeoKey (1026)
             let mut v = String::from(
                 geographic_type_map
-                    .get(&r.configs.epsg_code)
+                    .get(&configs.epsg_code)
                     .unwrap()
                     .clone(),
             );
@@ -2504,12 +2866,12 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 tag: TAG_GEOGRAPHICTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: r.configs.epsg_code,
+                value_offset: configs.epsg_code,
             });
 
-            if r.configs.z_units.to_lowercase() != "not specified" {
+            if configs.z_units.to_lowercase() != "not specified" {
                 // VerticalUnitsGeoKey (4099)
-                let units = r.configs.z_units.to_lowercase();
+                let units = configs.z_units.to_lowercase();
                 if units.contains("met") {
                     gk_entries.push(GeoKeyEntry {
                         tag: TAG_VERTICALUNITSGEOKEY,
@@ -2526,7 +2888,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     });
                 }
             }
-        } else if projected_cs_type_map.contains_key(&r.configs.epsg_code) {
+        } else if projected_cs_type_map.contains_key(&configs.epsg_code) {
             // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
                 tag: TAG_GTMODELTYPEGEOKEY,
@@ -2536,7 +2898,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             });
 
             // GTRasterTypeGeoKey (1025)
-            if r.configs.pixel_is_area {
+            if configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
                     tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
@@ -2557,13 +2919,13 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 tag: TAG_PROJECTEDCSTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: r.configs.epsg_code,
+                value_offset: configs.epsg_code,
             });
 
             // PCSCitationGeoKey (3073)
             let mut v = String::from(
                 projected_cs_type_map
-                    .get(&r.configs.epsg_code)
+                    .get(&configs.epsg_code)
                     .unwrap()
                     .clone(),
             );
@@ -2577,9 +2939,9 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             });
             ascii_params.push_str(&v);
 
-            if r.configs.xy_units.to_lowercase() != "not specified" {
+            if configs.xy_units.to_lowercase() != "not specified" {
                 // ProjLinearUnitsGeoKey (3076)
-                let units = r.configs.xy_units.to_lowercase();
+                let units = configs.xy_units.to_lowercase();
                 if units.contains("met") {
                     gk_entries.push(GeoKeyEntry {
                         tag: TAG_PROJLINEARUNITSGEOKEY,
@@ -2597,9 +2959,9 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 }
             }
 
-            if r.configs.z_units.to_lowercase() != "not specified" {
+            if configs.z_units.to_lowercase() != "not specified" {
                 // VerticalUnitsGeoKey (4099)
-                let units = r.configs.z_units.to_lowercase();
+                let units = configs.z_units.to_lowercase();
                 if units.contains("met") {
                     gk_entries.push(GeoKeyEntry {
                         tag: TAG_VERTICALUNITSGEOKEY,
@@ -2617,18 +2979,28 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 }
             }
         } else {
-            // we don't know much about the coordinate system used.
+            // we don't know much about the coordinate system used, other than possibly
+            // its WKT definition, which is stored in the citation in the same way as
+            // ESRI software does for user-defined coordinate systems.
+            let wkt = configs.coordinate_ref_system_wkt.trim().to_string();
+            let model_type = if wkt.to_uppercase().starts_with("PROJCS[") {
+                1u16
+            } else if wkt.to_uppercase().starts_with("GEOGCS[") {
+                2u16
+            } else {
+                0u16
+            };
 
             // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
                 tag: TAG_GTMODELTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: 0u16,
+                value_offset: model_type,
             });
 
             // GTRasterTypeGeoKey (1025)
-            if r.configs.pixel_is_area {
+            if configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
                     tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
@@ -2643,6 +3015,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     value_offset: 2u16,
                 });
             }
+
+            if model_type != 0 && wkt.len() < 65000 {
+                // tGTCitationGeoKey (1026)
+                let v = format!("ESRI PE String = {}|", wkt);
+                gk_entries.push(GeoKeyEntry {
+                    tag: TAG_GTCITATIONGEOKEY,
+                    location: 34737u16,
+                    count: v.len() as u16,
+                    value_offset: ascii_params.len() as u16,
+                });
+                ascii_params.push_str(&v);
+            }
         }
 
         // create the GeoKeyDirectoryTag tag (34735)
@@ -2718,6 +3102,9 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             } else if ifde.ifd_type == DT_LONG && ifde.num_values == 1 {
                 // it's a value
                 writer.write_u32::<BigEndian>(ifde.offset)?;
+            } else if ifde.ifd_type == DT_ASCII && ifde.num_values <= 4 {
+                // it's a value
+                writer.write_u32::<BigEndian>(ifde.offset)?;
             } else if ifde.ifd_type == DT_SHORT && ifde.num_values == 2 {
                 // I'm not really sure about this one. Two shorts will fit in the value_offset, but will they be interpreted correctly?
                 writer.write_u32::<BigEndian>(ifde.offset)?; // Value
@@ -3603,6 +3990,125 @@ impl fmt::Display for IfdDirectory {
     }
 }
 
+/// Packs a SHORT value into the value of an IFD entry holding `count` (one or two)
+/// copies of it.
+fn inline_shorts(value: u16, count: u16) -> u32 {
+    if count == 2 {
+        ((value as u32) << 16) | value as u32
+    } else {
+        value as u32
+    }
+}
+
+/// Reads the band descriptions of a GDAL_METADATA tag, i.e. the contents of its
+/// `<Item name="DESCRIPTION" sample="n" role="description">` elements. Bands without a
+/// description are given empty names, and an empty list is returned if there are none.
+fn parse_band_descriptions(xml: &str, num_bands: usize) -> Vec<String> {
+    let mut names = vec![String::new(); num_bands];
+    let mut found = false;
+    for item in xml.split("<Item").skip(1) {
+        let (attributes, rest) = match item.find('>') {
+            Some(idx) => (&item[..idx], &item[idx + 1..]),
+            None => continue,
+        };
+        if !attributes.contains("role=\"description\"") {
+            continue;
+        }
+        let sample = match attributes.find("sample=\"") {
+            Some(idx) => attributes[idx + 8..]
+                .split('"')
+                .next()
+                .unwrap_or("")
+                .parse::<usize>()
+                .unwrap_or(num_bands),
+            None => 0,
+        };
+        if sample < num_bands {
+            let value = rest.split("</Item>").next().unwrap_or("");
+            names[sample] = value
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&amp;", "&");
+            found = true;
+        }
+    }
+    if !found {
+        names.clear();
+    }
+    names
+}
+
+/// Creates the contents of a GDAL_METADATA tag describing the names of a raster's bands.
+fn band_descriptions_xml(names: &[String]) -> String {
+    let mut xml = "<GDALMetadata>\n".to_string();
+    for (sample, name) in names.iter().enumerate() {
+        if !name.is_empty() {
+            let value = name
+                .replace("&", "&amp;")
+                .replace("<", "&lt;")
+                .replace(">", "&gt;")
+                .replace("\"", "&quot;");
+            xml.push_str(&format!(
+                "  <Item name=\"DESCRIPTION\" sample=\"{}\" role=\"description\">{}</Item>\n",
+                sample, value
+            ));
+        }
+    }
+    xml.push_str("</GDALMetadata>");
+    xml
+}
+
+/// Reads and decompresses the bytes of a strip or tile.
+fn decode_block(
+    buffer: &[u8],
+    block_offsets: &[u32],
+    block_counts: &[u32],
+    block: usize,
+    compression: u16,
+) -> Result<Vec<u8>, Error> {
+    if block >= block_offsets.len() || block >= block_counts.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "The TIFF file does not contain the expected number of strips or tiles.",
+        ));
+    }
+    let offset = block_offsets[block] as usize;
+    let n = block_counts[block] as usize;
+    if offset + n > buffer.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "A strip or tile extends beyond the end of the TIFF file.",
+        ));
+    }
+    let mut buf: Vec<u8> = vec![];
+    match compression {
+        COMPRESS_NONE => {
+            // no compression
+            buf = buffer[offset..(offset + n)].to_vec();
+        }
+        COMPRESS_PACKBITS => {
+            buf = packbits_decoder(buffer[offset..(offset + n)].to_vec());
+        }
+        COMPRESS_LZW => {
+            let mut dec = lzw::DecoderEarlyChange::new(lzw::MsbReader::new(), 8u8);
+            let mut compressed = &buffer[offset..(offset + n)];
+            while compressed.len() > 0 {
+                let (start, bytes) = dec.decode_bytes(&compressed).unwrap();
+                compressed = &compressed[start..];
+                buf.extend(bytes.iter().map(|&i| i));
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The GeoTIFF decoder currently only supports PACKBITS and LZW compression.",
+            ))
+        }
+    }
+    Ok(buf)
+}
+
 // An implimentation of a PackBits reader
 #[inline]
 pub fn packbits_decoder(input_data: Vec<u8>) -> Vec<u8> {
@@ -3627,6 +4133,136 @@ pub fn packbits_decoder(input_data: Vec<u8>) -> Vec<u8> {
     output_data
 }
 
+/// An implementation of a TIFF LZW encoder, i.e. MSB-first codes of 9-12 bits
+/// using the 'early change' convention, as read by `lzw::DecoderEarlyChange`.
+pub fn lzw_encoder(input_data: &[u8]) -> Vec<u8> {
+    const CLEAR_CODE: u16 = 256;
+    const EOI_CODE: u16 = 257;
+    const FIRST_CODE: u16 = 258;
+    const MAX_CODE: u16 = 4093; // the table is reset before a 13-bit code is required
+
+    let mut output_data = Vec::with_capacity(input_data.len() / 2 + 16);
+    let mut bit_buffer = 0u32;
+    let mut num_bits = 0u8;
+    let mut code_width = 9u8;
+    {
+        let mut put_code = |code: u16, width: u8| {
+            bit_buffer = (bit_buffer << width) | code as u32;
+            num_bits += width;
+            while num_bits >= 8 {
+                num_bits -= 8;
+                output_data.push((bit_buffer >> num_bits) as u8);
+            }
+            bit_buffer &= (1u32 << num_bits) - 1;
+        };
+        put_code(CLEAR_CODE, code_width);
+        if !input_data.is_empty() {
+            let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+            let mut next_code = FIRST_CODE;
+            let mut prefix = input_data[0] as u16;
+            for &k in &input_data[1..] {
+                if let Some(&code) = table.get(&(prefix, k)) {
+                    prefix = code;
+                    continue;
+                }
+                put_code(prefix, code_width);
+                table.insert((prefix, k), next_code);
+                next_code += 1;
+                if next_code > MAX_CODE {
+                    put_code(CLEAR_CODE, code_width);
+                    table.clear();
+                    next_code = FIRST_CODE;
+                    code_width = 9;
+                } else if next_code > (1u16 << code_width) - 1 {
+                    code_width += 1;
+                }
+                prefix = k as u16;
+            }
+            put_code(prefix, code_width);
+            // the decoder adds a table entry for the final code too
+            next_code += 1;
+            if next_code > (1u16 << code_width) - 1 && code_width < 12 {
+                code_width += 1;
+            }
+        }
+        put_code(EOI_CODE, code_width);
+    }
+    if num_bits > 0 {
+        output_data.push((bit_buffer << (8 - num_bits)) as u8);
+    }
+    output_data
+}
+
+/// Writes the image data of a GeoTIFF one strip (row) at a time, optionally
+/// compressing each strip, and records the offset and size of each strip.
+struct StripWriter<W: Write> {
+    writer: W,
+    offset: u32,
+    strip_length: usize,
+    compress: bool,
+    strip: Vec<u8>,
+    offsets: Vec<u32>,
+    byte_counts: Vec<u32>,
+}
+
+impl<W: Write> StripWriter<W> {
+    fn new(writer: W, offset: u32, strip_length: usize, compress: bool) -> StripWriter<W> {
+        StripWriter {
+            writer: writer,
+            offset: offset,
+            strip_length: strip_length,
+            compress: compress,
+            strip: Vec::with_capacity(strip_length),
+            offsets: vec![],
+            byte_counts: vec![],
+        }
+    }
+
+    fn write_strip(&mut self) -> Result<(), Error> {
+        let num_bytes = if self.compress {
+            let compressed = lzw_encoder(&self.strip);
+            self.writer.write_all(&compressed)?;
+            compressed.len() as u32
+        } else {
+            self.writer.write_all(&self.strip)?;
+            self.strip.len() as u32
+        };
+        self.offsets.push(self.offset);
+        self.byte_counts.push(num_bytes);
+        self.offset += num_bytes;
+        self.strip.clear();
+        Ok(())
+    }
+
+    /// Writes any partial final strip and returns the underlying writer, along with
+    /// the strip offsets and byte counts.
+    fn finish(mut self) -> Result<(W, Vec<u32>, Vec<u32>), Error> {
+        if !self.strip.is_empty() {
+            self.write_strip()?;
+        }
+        Ok((self.writer, self.offsets, self.byte_counts))
+    }
+}
+
+impl<W: Write> Write for StripWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        let mut remaining = buf;
+        while !remaining.is_empty() {
+            let n = (self.strip_length - self.strip.len()).min(remaining.len());
+            self.strip.extend_from_slice(&remaining[0..n]);
+            remaining = &remaining[n..];
+            if self.strip.len() >= self.strip_length {
+                self.write_strip()?;
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()
+    }
+}
+
 // const COMPRESS_NONE: u16 = 1;
 // const COMPRESS_CCITT: u16 = 2;
 // const COMPRESS_G3: u16 = 3; // Group 3 Fax.
@@ -3750,3 +4386,96 @@ pub fn packbits_decoder(input_data: Vec<u8>) -> Vec<u8> {
 // // const PI_CMYK: u16        = 5;
 // // const PI_YCBCR: u16       = 6;
 // // const PI_CIELAB: u16      = 8;
+
+#[cfg(test)]
+mod test {
+    use super::GeoTiffWriter;
+    use raster::{DataType, PhotometricInterpretation, Raster, RasterConfigs};
+    use std::io::ErrorKind;
+    use std::{env, fs, process};
+    use utils::settings;
+
+    fn configs(data_type: DataType) -> RasterConfigs {
+        RasterConfigs {
+            rows: 7,
+            columns: 5,
+            nodata: -32768f64,
+            north: 700f64,
+            south: 0f64,
+            east: 500f64,
+            west: 0f64,
+            resolution_x: 100f64,
+            resolution_y: 100f64,
+            photometric_interp: PhotometricInterpretation::Continuous,
+            data_type: data_type,
+            ..Default::default()
+        }
+    }
+
+    fn rows(configs: &RasterConfigs) -> Vec<Vec<f64>> {
+        (0..configs.rows)
+            .map(|row| {
+                (0..configs.columns)
+                    .map(|col| match (row * configs.columns + col) % 11 {
+                        0 => configs.nodata,
+                        v => (row as f64 - 3f64) * 10f64 + v as f64,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Streams the rows to a GeoTIFF and reads them back.
+    fn round_trip(file_name: &str, configs: &RasterConfigs, rows: &[Vec<f64>]) -> Raster {
+        let mut writer = GeoTiffWriter::new(file_name, configs, 1).unwrap();
+        for row in rows {
+            writer.write_row(row).unwrap();
+        }
+        writer.finish().unwrap();
+        Raster::new(file_name, "r").unwrap()
+    }
+
+    #[test]
+    fn test_geotiff_writer_round_trip() {
+        let dir = env::temp_dir().join(format!("wbt_geotiff_test_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let original_settings = settings::get_settings();
+        for &compress in &[false, true] {
+            let mut s = original_settings.clone();
+            s.compress_rasters = compress;
+            settings::set_settings(s);
+            for &data_type in &[DataType::F64, DataType::F32, DataType::I16, DataType::I32] {
+                let configs = configs(data_type);
+                let rows = rows(&configs);
+                let file_name = dir.join(format!("{:?}_{}.tif", data_type, compress));
+                let r = round_trip(&file_name.to_string_lossy(), &configs, &rows);
+                assert_eq!(r.configs.rows, configs.rows);
+                assert_eq!(r.configs.columns, configs.columns);
+                assert_eq!(r.configs.data_type, data_type);
+                assert_eq!(r.configs.north, configs.north);
+                assert_eq!(r.configs.west, configs.west);
+                for row in 0..configs.rows {
+                    assert_eq!(r.get_row_data(row as isize), rows[row]);
+                }
+            }
+        }
+        settings::set_settings(original_settings);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_geotiff_writer_row_length() {
+        let dir = env::temp_dir().join(format!("wbt_geotiff_row_test_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let configs = configs(DataType::F32);
+        let file_name = dir.join("short_row.tif");
+        let mut writer = GeoTiffWriter::new(&file_name.to_string_lossy(), &configs, 1).unwrap();
+        let err = writer.write_row(&[1f64, 2f64]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let err = writer
+            .write_row(&vec![1f64; configs.columns + 1])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 use std::io::Error;
+use std::io::ErrorKind;
 use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::prelude::*;
@@ -13,13 +14,37 @@ pub fn read_grass_raster(file_name: &String, configs: &mut RasterConfigs, data:
 
     //let mut likely_float = false;
     let mut multiplier = 1.0;
-    let mut null_str = String::from("");
-    let mut null_is_str = false;
+    let mut null_str = String::from("*");
+    let mut null_is_str = true;
+    configs.photometric_interp = PhotometricInterpretation::Continuous;
+    configs.nodata = -32768.0f64;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = try!(line);
         let line_split = line_unwrapped.split(":");
         let vec = line_split.collect::<Vec<&str>>();
-        if vec[0].to_lowercase().contains("rows") {
+        if vec.len() < 2 {
+            // it's a data line
+            for val in line_unwrapped.split_whitespace() {
+                if null_is_str && val == null_str {
+                    data.push(configs.nodata);
+                    continue;
+                }
+                let val_num = match val.parse::<f64>() {
+                    Ok(v) => v,
+                    Err(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("Invalid value '{}' in the ASCII raster {}.", val, file_name),
+                        ))
+                    }
+                };
+                if val_num != configs.nodata {
+                    data.push(val_num * multiplier);
+                } else {
+                    data.push(val_num);
+                }
+            }
+        } else if vec[0].to_lowercase().contains("rows") {
             configs.rows = vec[1].trim().parse::<f32>().unwrap() as usize;
         } else if vec[0].to_lowercase().contains("cols") {
             configs.columns = vec[1].trim().parse::<f32>().unwrap() as usize;
@@ -31,11 +56,9 @@ pub fn read_grass_raster(file_name: &String, configs: &mut RasterConfigs, data:
             configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
         } else if vec[0].to_lowercase().contains("west") {
             configs.west = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("cellsize") {
-            configs.resolution_x = vec[1].trim().to_string().parse::<f64>().unwrap();
-            configs.resolution_y = vec[1].trim().to_string().parse::<f64>().unwrap();
         } else if vec[0].to_lowercase().contains("null") {
             if is_number(vec[1].trim().to_string()) {
+                null_is_str = false;
                 configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
                 if vec[1].contains(".") {
                     //likely_float = true;
@@ -46,65 +69,57 @@ pub fn read_grass_raster(file_name: &String, configs: &mut RasterConfigs, data:
             } else {
                 null_is_str = true;
                 null_str = vec[1].trim().to_string();
-                configs.nodata = 32768.0f64;
             }
         } else if vec[0].to_lowercase().contains("type") {
             if vec[1].contains("float") {
                 //likely_float = true;
                 configs.data_type = DataType::F32;
-            } if vec[1].contains("double") {
+            } else if vec[1].contains("double") {
                 //likely_float = true;
                 configs.data_type = DataType::F64;
             } else {
                 configs.data_type = DataType::I32;
             }
-            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
         } else if vec[0].to_lowercase().contains("multiplier") {
             multiplier = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else { // it's a data line
-            if !null_is_str {
-                let mut val_num;
-                for val in vec {
-                    val_num = val.trim().to_string().parse::<f64>().unwrap();
-                    if val_num != configs.nodata {
-                        data.push(val_num * multiplier);
-                    } else {
-                        data.push(val_num);
-                    }
-                }
-            } else {
-                let mut val_string;
-                for val in vec {
-                    val_string = val.trim().to_string();
-                    if val_string != null_str {
-                        data.push(val.trim().to_string().parse::<f64>().unwrap() * multiplier);
-                    } else {
-                        data.push(configs.nodata);
-                    }
-                }
-            }
         }
     }
 
+    // the values are listed in row-major order, starting with the northern-most row
+    check_grid_size(file_name, configs, data.len())?;
+    configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
+    configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
+
     Ok(())
 }
 
-pub fn write_grass_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
+/// Writes a GRASS ASCII raster, with floating-point values written to `precision`
+/// decimal places (see `format_ascii_value`). Nodata cells, including any NaN
+/// values, are written using the raster's nodata value, or '*' if it is NaN.
+pub fn write_grass_raster<'a>(r: &'a mut Raster, precision: Option<usize>) -> Result<(), Error> {
+    check_grid_size(&r.file_name, &r.configs, r.data.len())?;
+    let data_type = r.configs.data_type;
+    let nodata = r.configs.nodata;
+    let nodata_str = if nodata.is_nan() {
+        "*".to_string()
+    } else {
+        format_ascii_value(nodata, data_type, precision)
+    };
 
     // Save the file
     let f = try!(File::create(&(r.file_name)));
     let mut writer = BufWriter::new(f);
 
-    let s = format!("north:                   {}\n", &format!("{:.*} ", 2, r.configs.north));
+    let s = format!("north:                   {}\n", r.configs.north);
     try!(writer.write_all(s.as_bytes()));
 
-    let s = format!("south:                   {}\n", &format!("{:.*} ", 2, r.configs.south));
+    let s = format!("south:                   {}\n", r.configs.south);
     try!(writer.write_all(s.as_bytes()));
 
-    let s = format!("east:                    {}\n", &format!("{:.*} ", 2, r.configs.east));
+    let s = format!("east:                    {}\n", r.configs.east);
     try!(writer.write_all(s.as_bytes()));
 
-    let s = format!("west:                    {}\n", &format!("{:.*} ", 2, r.configs.west));
+    let s = format!("west:                    {}\n", r.configs.west);
     try!(writer.write_all(s.as_bytes()));
 
     let s = format!("rows:                    {}\n", r.configs.rows);
@@ -113,18 +128,13 @@ pub fn write_grass_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let s = format!("cols:                    {}\n", r.configs.columns);
     try!(writer.write_all(s.as_bytes()));
 
-    if r.configs.data_type == DataType::F32 || r.configs.data_type == DataType::F64 {
-        let s = format!("null:                    {}\n", &format!("{:.*} ", 2, r.configs.nodata));
-        try!(writer.write_all(s.as_bytes()));
-    } else {
-        let s = format!("null:                    {}\n", &format!("{:.*} ", 0, r.configs.nodata));
-        try!(writer.write_all(s.as_bytes()));
-    }
+    let s = format!("null:                    {}\n", nodata_str);
+    try!(writer.write_all(s.as_bytes()));
 
     if r.configs.data_type == DataType::F32 {
         let s = format!("type:                    float\n");
         try!(writer.write_all(s.as_bytes()));
-    } else if r.configs.data_type == DataType::F64 {
+    } else if r.configs.data_type.is_float() {
         let s = format!("type:                    double\n");
         try!(writer.write_all(s.as_bytes()));
     } else {
@@ -132,43 +142,29 @@ pub fn write_grass_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
         try!(writer.write_all(s.as_bytes()));
     }
 
-    try!(writer.write_all("".as_bytes()));
-
-    // write the data
+    // write the data, one row per line, starting with the northern-most row
     let mut s2 = String::new();
     let num_cells: usize = r.configs.rows * r.configs.columns;
     let mut col = 0;
-    if r.configs.data_type == DataType::F32 || r.configs.data_type == DataType::F64 {
-        for i in 0..num_cells {
-            if col < r.configs.columns - 1 {
-                s2 += &format!("{:.*} ", 2, r.data[i]);
-            } else {
-                s2 += &format!("{:.*}\n", 2, r.data[i]);
-            }
-            col += 1;
-            if col == r.configs.columns {
-                try!(writer.write_all(s2.as_bytes()));
-                s2 = String::new();
-                col = 0;
-            }
+    for i in 0..num_cells {
+        let value = r.data[i];
+        if value == nodata || value.is_nan() {
+            s2 += &nodata_str;
+        } else {
+            s2 += &format_ascii_value(value, data_type, precision);
         }
-    } else {
-        for i in 0..num_cells {
-            if col < r.configs.columns - 1 {
-                s2 += &format!("{:.*} ", 0, r.data[i]);
-            } else {
-                s2 += &format!("{:.*}\n", 0, r.data[i]);
-            }
-            col += 1;
-            if col == r.configs.columns {
-                try!(writer.write_all(s2.as_bytes()));
-                s2 = String::new();
-                col = 0;
-            }
+        col += 1;
+        if col < r.configs.columns {
+            s2 += " ";
+        } else {
+            s2 += "\n";
+            try!(writer.write_all(s2.as_bytes()));
+            s2.clear();
+            col = 0;
         }
     }
 
-    let _ = writer.flush();
+    try!(writer.flush());
 
     Ok(())
 }
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 2, 2017
-Last Modified: July 7, 2017
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -18,6 +18,15 @@ pub mod surfer7_raster;
 pub mod surfer_ascii_raster;
 pub mod whitebox_raster;
 
+// private sub-module defined in other files
+mod raster_data;
+mod raster_writer;
+
+// exports identifiers from private sub-modules in the current module namespace
+pub use self::raster_data::{DecodedValues, RasterData};
+pub use self::raster_writer::RasterWriter;
+
+use self::raster_data::CompactValues;
 use raster::arcascii_raster::*;
 use raster::arcbinary_raster::*;
 use raster::geotiff::*;
@@ -27,6 +36,7 @@ use raster::saga_raster::*;
 use raster::surfer7_raster::*;
 use raster::surfer_ascii_raster::*;
 use raster::whitebox_raster::*;
+use spatial_ref_system::epsg_from_wkt;
 use std::cmp::Ordering::Equal;
 use std::default::Default;
 use std::f64;
@@ -41,6 +51,9 @@ use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
 use structures::Array2D;
+use utils::memory_store;
+use utils::report;
+use utils::settings;
 use utils::*;
 
 /// Raster is a common data structure that abstracts over several raster data formats,
@@ -63,7 +76,9 @@ pub struct Raster {
     pub file_mode: String,
     pub raster_type: RasterType,
     pub configs: RasterConfigs,
-    data: Vec<f64>,
+    data: RasterData,
+    // the data of the second and subsequent bands of a multi-band raster
+    extra_bands: Vec<RasterData>,
 }
 
 impl Index<(isize, isize)> for Raster {
@@ -122,11 +137,28 @@ impl Raster {
     /// read from an existing file (`file_name`; `file_mode` is 'r') or
     /// prepared for new file creation (`file_mode` is 'w') The raster format
     /// will be determined by the file extension of the `file_name` string.
+    /// File names using the `memory://` scheme refer to in-memory datasets
+    /// (see `utils::memory_store`) and are never read from or written to disk.
+    /// When the `memory_map_rasters` setting is enabled, the values of uncompressed 64-bit
+    /// floating-point GeoTIFF and Whitebox files are memory-mapped (see `RasterData`).
     ///
     /// To create a new `Raster` file, most applications should prefer the
     /// `initialize_using_config` or `initialize_using_file` functions instead.
     pub fn new<'a>(file_name: &'a str, file_mode: &'a str) -> Result<Raster, Error> {
+        Raster::open(file_name, file_mode, false)
+    }
+
+    // Creates a raster as `new` does, holding the values read from a file in a compact
+    // storage type if `compact` is true (see `new_compact`).
+    fn open<'a>(file_name: &'a str, file_mode: &'a str, compact: bool) -> Result<Raster, Error> {
         let fm: String = file_mode.to_lowercase();
+        if memory_store::is_memory_dataset(file_name) && fm.contains("r") {
+            let mut r = memory_store::get_raster(file_name)?;
+            r.file_name = file_name.to_string();
+            r.file_mode = fm;
+            r.extra_bands.clear();
+            return Ok(r);
+        }
         let mut r = Raster {
             file_name: file_name.to_string(),
             file_mode: fm.clone(),
@@ -134,48 +166,62 @@ impl Raster {
             ..Default::default()
         };
         if r.file_mode.contains("r") {
+            let mut data: Vec<f64> = vec![];
+            // the values of GeoTIFF and Whitebox files, if they are mapped or compactly stored
+            let mut values: Option<RasterData> = None;
             match get_raster_type_from_file(file_name.to_string(), fm) {
                 RasterType::ArcBinary => {
-                    let _ = read_arcbinary(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_arcbinary(&r.file_name, &mut r.configs, &mut data)?;
                 }
                 RasterType::ArcAscii => {
-                    let _ = read_arcascii(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_arcascii(&r.file_name, &mut r.configs, &mut data)?;
                 }
-                RasterType::GeoTiff => {
-                    let _ = read_geotiff(&r.file_name, &mut r.configs, &mut r.data)?;
-                    r.update_min_max();
-                    return Ok(r);
+                RasterType::GeoTiff | RasterType::Whitebox if compact => {
+                    // the values are decoded directly into their compact storage type
+                    let mut compact_data = CompactValues::new();
+                    values = match r.read_native(&mut compact_data)? {
+                        Some(mapped) => Some(mapped),
+                        None => Some(compact_data.into()),
+                    };
+                }
+                RasterType::GeoTiff | RasterType::Whitebox => {
+                    values = r.read_native(&mut data)?;
                 }
                 RasterType::GrassAscii => {
-                    let _ = read_grass_raster(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_grass_raster(&r.file_name, &mut r.configs, &mut data)?;
                 }
                 RasterType::IdrisiBinary => {
-                    let _ = read_idrisi(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_idrisi(&r.file_name, &mut r.configs, &mut data)?;
                 }
                 RasterType::SagaBinary => {
-                    let _ = read_saga(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_saga(&r.file_name, &mut r.configs, &mut data)?;
                 }
                 RasterType::Surfer7Binary => {
-                    let _ = read_surfer7(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_surfer7(&r.file_name, &mut r.configs, &mut data)?;
                 }
                 RasterType::SurferAscii => {
-                    let _ = read_surfer_ascii_raster(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
-                }
-                RasterType::Whitebox => {
-                    let _ = read_whitebox(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_surfer_ascii_raster(&r.file_name, &mut r.configs, &mut data)?;
                 }
                 RasterType::Unknown => {
                     return Err(Error::new(ErrorKind::Other, "Unrecognized raster type"));
                 }
             }
+            r.data = match values {
+                Some(values) => values,
+                None if compact => RasterData::with_data_type(data, r.configs.data_type),
+                None => data.into(),
+            };
+            match r.raster_type {
+                RasterType::ArcAscii | RasterType::GeoTiff | RasterType::GrassAscii => {
+                    r.update_min_max();
+                }
+                _ => {}
+            }
+            if !r.configs.coordinate_ref_system_wkt.contains("[") {
+                // formats that don't store the CRS may have a .prj sidecar file
+                r.read_prj_file();
+            }
+            return Ok(r);
         } else {
             // write
             return Ok(r);
@@ -183,15 +229,103 @@ impl Raster {
         // Err(Error::new(ErrorKind::Other, "Error creating raster"))
     }
 
+    // Reads the values of a GeoTIFF or Whitebox file into `data`, or returns them if they
+    // are memory-mapped.
+    fn read_native<D: DecodedValues>(&mut self, data: &mut D) -> Result<Option<RasterData>, Error> {
+        let map_data = settings::get_settings().memory_map_rasters;
+        if self.raster_type == RasterType::GeoTiff {
+            if map_data {
+                return read_geotiff_mapped(&self.file_name, &mut self.configs, data);
+            }
+            read_geotiff(&self.file_name, &mut self.configs, data)?;
+        } else {
+            if map_data {
+                return read_whitebox_mapped(&self.file_name, &mut self.configs, data);
+            }
+            read_whitebox(&self.file_name, &mut self.configs, data)?;
+        }
+        Ok(None)
+    }
+
+    /// Reads a single band of a multi-band raster file, where `band` is the zero-based
+    /// band index. The `bands` value of the returned raster's configurations gives the
+    /// number of bands in the file. Only GeoTIFFs can contain more than one band, and
+    /// `Raster::new` always reads the first band.
+    pub fn read_band<'a>(file_name: &'a str, band: usize) -> Result<Raster, Error> {
+        if memory_store::is_memory_dataset(file_name) {
+            let mut r = memory_store::get_raster(file_name)?;
+            r.file_name = file_name.to_string();
+            r.file_mode = "r".to_string();
+            if band > 0 {
+                if band > r.extra_bands.len() {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{} does not contain band {}.", file_name, band + 1),
+                    ));
+                }
+                r.data = r.extra_bands[band - 1].clone();
+            }
+            r.extra_bands.clear();
+            r.update_min_max();
+            return Ok(r);
+        }
+        let mut r = Raster {
+            file_name: file_name.to_string(),
+            file_mode: "r".to_string(),
+            raster_type: get_raster_type_from_file(file_name.to_string(), "r".to_string()),
+            ..Default::default()
+        };
+        if r.raster_type != RasterType::GeoTiff {
+            if band == 0 {
+                return Raster::new(file_name, "r");
+            }
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} does not contain band {}.", file_name, band + 1),
+            ));
+        }
+        let mut data: Vec<f64> = vec![];
+        let _ = read_geotiff_band(&r.file_name, &mut r.configs, &mut data, band)?;
+        r.data = data.into();
+        r.update_min_max();
+        if !r.configs.coordinate_ref_system_wkt.contains("[") {
+            r.read_prj_file();
+        }
+        Ok(r)
+    }
+
+    /// Reads the configurations of an existing raster file without reading its values, e.g.
+    /// to compare the grids of several rasters before reading them. Only the headers of
+    /// GeoTIFF and Whitebox files are read, and the values of in-memory rasters are not
+    /// copied, while files of other formats are read in full. The minimum and maximum
+    /// values are those recorded in the header, if any.
+    pub fn read_configs(file_name: &str) -> Result<RasterConfigs, Error> {
+        if memory_store::is_memory_dataset(file_name) {
+            return Ok(memory_store::get_raster(file_name)?.configs);
+        }
+        let mut r = Raster {
+            file_name: file_name.to_string(),
+            file_mode: "r".to_string(),
+            raster_type: get_raster_type_from_file(file_name.to_string(), "r".to_string()),
+            ..Default::default()
+        };
+        match r.raster_type {
+            RasterType::GeoTiff => read_geotiff_header(&r.file_name, &mut r.configs)?,
+            RasterType::Whitebox => read_whitebox_header(&r.file_name, &mut r.configs)?,
+            _ => return Ok(Raster::new(file_name, "r")?.configs),
+        }
+        if !r.configs.coordinate_ref_system_wkt.contains("[") {
+            r.read_prj_file();
+        }
+        Ok(r.configs)
+    }
+
     /// Creates a new in-memory `Raster` object with grid extent and location
     /// based on specified configurations contained within a `RasterConfigs`.
+    /// If `file_name` has no extension, the default raster format of the
+    /// current `utils::settings` is used, as is its default nodata value, if any.
     pub fn initialize_using_config<'a>(file_name: &'a str, configs: &'a RasterConfigs) -> Raster {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .tif
-            format!("{}.tif", file_name)
-        };
+        let new_file_name = get_output_file_name(file_name);
         let mut output = Raster {
             file_name: new_file_name.clone(),
             configs: configs.clone(),
@@ -199,59 +333,80 @@ impl Raster {
         };
         output.file_mode = "w".to_string();
         output.raster_type = get_raster_type_from_file(new_file_name.clone(), "w".to_string());
+        output.use_default_nodata();
 
-        output.data = vec![output.configs.nodata; output.configs.rows * output.configs.columns];
+        output.data =
+            vec![output.configs.nodata; output.configs.rows * output.configs.columns].into();
 
         output
     }
 
     /// Creates a new in-memory `Raster` object with grid extent and location based
-    /// on an existing `Raster` contained within `file_name`.
+    /// on an existing `Raster` contained within `file_name`. If `file_name`
+    /// has no extension, the default raster format of the current
+    /// `utils::settings` is used, as is its default nodata value, if any.
     pub fn initialize_using_file<'a>(file_name: &'a str, input: &'a Raster) -> Raster {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .tif
-            format!("{}.tif", file_name)
-        };
+        let new_file_name = get_output_file_name(file_name);
         let mut output = Raster {
             file_name: new_file_name.clone(),
             ..Default::default()
         };
         output.file_mode = "w".to_string();
         output.raster_type = get_raster_type_from_file(new_file_name.clone(), "w".to_string());
-        output.configs.rows = input.configs.rows;
-        output.configs.columns = input.configs.columns;
-        output.configs.north = input.configs.north;
-        output.configs.south = input.configs.south;
-        output.configs.east = input.configs.east;
-        output.configs.west = input.configs.west;
-        output.configs.resolution_x = input.configs.resolution_x;
-        output.configs.resolution_y = input.configs.resolution_y;
-        output.configs.nodata = input.configs.nodata;
-        output.configs.data_type = input.configs.data_type;
-        output.configs.photometric_interp = input.configs.photometric_interp;
-        output.configs.palette = input.configs.palette.clone();
-        output.configs.projection = input.configs.projection.clone();
-        output.configs.xy_units = input.configs.xy_units.clone();
-        output.configs.z_units = input.configs.z_units.clone();
-        output.configs.endian = input.configs.endian.clone();
-        // output.configs.palette_nonlinearity = input.configs.palette_nonlinearity;
-        output.configs.pixel_is_area = input.configs.pixel_is_area;
-        output.configs.epsg_code = input.configs.epsg_code;
-        output.configs.coordinate_ref_system_wkt = input.configs.coordinate_ref_system_wkt.clone();
-
-        if output.raster_type == RasterType::SurferAscii
-            || output.raster_type == RasterType::Surfer7Binary
-        {
-            output.configs.nodata = 1.71041e38;
-        }
+        output.configs = get_configs_using_file(&input.configs, &output.raster_type);
+        output.use_default_nodata();
+        output.data =
+            vec![output.configs.nodata; output.configs.rows * output.configs.columns].into();
 
-        output.data = vec![output.configs.nodata; output.configs.rows * output.configs.columns];
+        output
+    }
+
+    /// Creates a new in-memory `Raster` object with the grid extent and location of an
+    /// existing `Raster`, but without a file name or format, e.g. the output of one of the
+    /// tools' core functions. A file name may later be given using `set_file_name`.
+    pub fn initialize_using_raster(input: &Raster) -> Raster {
+        let mut output = Raster {
+            file_mode: "w".to_string(),
+            raster_type: RasterType::Unknown,
+            configs: get_configs_using_file(&input.configs, &RasterType::Unknown),
+            ..Default::default()
+        };
+        output.use_default_nodata();
+        output.data =
+            vec![output.configs.nodata; output.configs.rows * output.configs.columns].into();
 
         output
     }
 
+    /// Sets the name of the file to which the raster is written, determining its format.
+    /// If `file_name` has no extension, the default raster format of the current
+    /// `utils::settings` is used. The Surfer formats have a fixed nodata value, to which
+    /// any nodata cells are updated.
+    pub fn set_file_name(&mut self, file_name: &str) {
+        self.file_name = get_output_file_name(file_name);
+        self.file_mode = "w".to_string();
+        self.raster_type = get_raster_type_from_file(self.file_name.clone(), "w".to_string());
+        let nodata = get_configs_using_file(&self.configs, &self.raster_type).nodata;
+        if nodata != self.configs.nodata {
+            let old_nodata = self.configs.nodata;
+            for i in 0..self.data.len() {
+                if self.data.get(i) == old_nodata {
+                    self.data.set(i, nodata);
+                }
+            }
+            self.configs.nodata = nodata;
+        }
+    }
+
+    /// Replaces the nodata value of a new output raster with the default nodata value of the
+    /// current `utils::settings`, if there is one and it can be stored using the raster's
+    /// data type and format. Tools mark nodata cells using `configs.nodata`.
+    fn use_default_nodata(&mut self) {
+        if let Some(nodata) = get_default_nodata(&self.configs, &self.raster_type) {
+            self.configs.nodata = nodata;
+        }
+    }
+
     /// Returns the file name of the `Raster`, without the directory.
     pub fn get_short_filename(&self) -> String {
         let path = Path::new(&self.file_name);
@@ -276,7 +431,7 @@ impl Raster {
             let r: usize = row as usize;
 
             let idx: usize = r * self.configs.columns + c;
-            return self.data[idx];
+            return self.data.get(idx);
         }
 
         // it's not within the area of the data
@@ -337,7 +492,7 @@ impl Raster {
             let r: usize = row as usize;
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
-                self.data[idx] = value;
+                self.data.set(idx, value);
             }
         }
     }
@@ -348,10 +503,11 @@ impl Raster {
             let r: usize = row as usize;
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
-                if self.data[idx] != self.configs.nodata {
-                    self.data[idx] -= value;
+                let z = self.data.get(idx);
+                if z != self.configs.nodata {
+                    self.data.set(idx, z - value);
                 } else {
-                    self.data[idx] = value;
+                    self.data.set(idx, value);
                 }
             }
         }
@@ -363,10 +519,11 @@ impl Raster {
             let r: usize = row as usize;
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
-                if self.data[idx] != self.configs.nodata {
-                    self.data[idx] += value;
+                let z = self.data.get(idx);
+                if z != self.configs.nodata {
+                    self.data.set(idx, z + value);
                 } else {
-                    self.data[idx] = value;
+                    self.data.set(idx, value);
                 }
             }
         }
@@ -379,7 +536,7 @@ impl Raster {
                 let r: usize = row as usize;
                 if c < self.configs.columns && r < self.configs.rows {
                     let idx = r * self.configs.columns + c;
-                    self.data[idx] = values[c];
+                    self.data.set(idx, values[c]);
                 }
             }
         }
@@ -389,7 +546,7 @@ impl Raster {
         let mut values: Vec<f64> = vec![self.configs.nodata; self.configs.columns];
         if row >= 0 && row < self.configs.rows as isize {
             for column in 0..values.len() {
-                values[column] = self.data[row as usize * self.configs.columns + column];
+                values[column] = self.data.get(row as usize * self.configs.columns + column);
             }
         }
         values
@@ -402,7 +559,8 @@ impl Raster {
                 let r: usize = row as usize;
                 if c < self.configs.columns && r < self.configs.rows {
                     let idx = r * self.configs.columns + c;
-                    self.data[idx] += values[c];
+                    let z = self.data.get(idx);
+                    self.data.set(idx, z + values[c]);
                 }
             }
         }
@@ -415,12 +573,15 @@ impl Raster {
                 let r: usize = row as usize;
                 if c < self.configs.columns && r < self.configs.rows {
                     let idx = r * self.configs.columns + c;
-                    self.data[idx] -= values[c];
+                    let z = self.data.get(idx);
+                    self.data.set(idx, z - values[c]);
                 }
             }
         }
     }
 
+    /// Copies the values of `other`, which must have the same dimensions, into the raster.
+    /// The nodata cells of `other` are given the raster's own nodata value.
     pub fn set_data_from_raster(&mut self, other: &Raster) -> Result<(), Error> {
         if self.configs.rows != other.configs.rows || self.configs.columns != other.configs.columns
         {
@@ -429,8 +590,18 @@ impl Raster {
                 "Rasters must have the same dimensions and extent.",
             ));
         }
+        let nodata = self.configs.nodata;
+        let other_nodata = other.configs.nodata;
         for row in 0..self.configs.rows as isize {
-            self.set_row_data(row, other.get_row_data(row));
+            let mut values = other.get_row_data(row);
+            if nodata != other_nodata {
+                for v in values.iter_mut() {
+                    if *v == other_nodata {
+                        *v = nodata;
+                    }
+                }
+            }
+            self.set_row_data(row, values);
         }
         Ok(())
     }
@@ -448,8 +619,87 @@ impl Raster {
         data
     }
 
+    /// Changes the nodata value, updating any grid cells that contain the current nodata value.
+    pub fn set_nodata_value(&mut self, nodata: f64) {
+        if nodata == self.configs.nodata {
+            return;
+        }
+        let old_nodata = self.configs.nodata;
+        for v in self.data.iter_mut() {
+            if *v == old_nodata {
+                *v = nodata;
+            }
+        }
+        for band in self.extra_bands.iter_mut() {
+            for v in band.iter_mut() {
+                if *v == old_nodata {
+                    *v = nodata;
+                }
+            }
+        }
+        self.configs.nodata = nodata;
+    }
+
+    /// Reads an existing raster file in the same way as `Raster::new`, but holds its values
+    /// in a compact storage type matching the data type of the file, e.g. as `f32`s for a
+    /// F32 file or `i16`s for an I16 file, rather than as `f64`s (see `RasterData`). This
+    /// reduces the memory used by a large input raster by a half or more. The values of
+    /// GeoTIFF and Whitebox files are decoded directly into the compact storage type, while
+    /// those of other formats are converted to it after they are read. In-memory rasters
+    /// are held as `f64`s.
+    ///
+    /// The values of a compactly stored raster must be accessed using `get_value`,
+    /// `get_row_data` and the other accessor methods, which convert each value to and from
+    /// `f64`. Indexing the raster (`input[(row, col)]`) panics, while modifying the raster
+    /// with a value that the storage type cannot represent, or by mutable indexing, converts
+    /// the storage to `f64`.
+    pub fn new_compact<'a>(file_name: &'a str) -> Result<Raster, Error> {
+        Raster::open(file_name, "r", true)
+    }
+
+    /// Returns the type in which the raster's values are held in memory, which is F64
+    /// unless the raster was read using `new_compact`.
+    pub fn storage_type(&self) -> DataType {
+        self.data.storage_type()
+    }
+
+    /// Returns the number of bands held by the raster. This is one for rasters read from
+    /// files, which hold a single band even if the file contains several (see `read_band`).
+    pub fn num_bands(&self) -> usize {
+        1 + self.extra_bands.len()
+    }
+
+    /// Appends the data of another raster with the same dimensions as a new band, assigning
+    /// the nodata value of this raster to its nodata cells. Multi-band rasters can only be
+    /// written as GeoTIFFs.
+    pub fn add_band(&mut self, band: &Raster) -> Result<(), Error> {
+        if band.configs.rows != self.configs.rows || band.configs.columns != self.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "All bands of a raster must have the same number of rows and columns.",
+            ));
+        }
+        if self.num_bands() >= 255 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Rasters cannot contain more than 255 bands.",
+            ));
+        }
+        let (in_nodata, nodata) = (band.configs.nodata, self.configs.nodata);
+        self.extra_bands.push(
+            (0..band.data.len())
+                .map(|i| band.data.get(i))
+                .map(|v| if v == in_nodata { nodata } else { v })
+                .collect::<Vec<f64>>()
+                .into(),
+        );
+        self.configs.bands = self.num_bands() as u8;
+        Ok(())
+    }
+
     pub fn reinitialize_values(&mut self, value: f64) {
-        self.data = vec![value; self.configs.rows * self.configs.columns];
+        self.data = vec![value; self.configs.rows * self.configs.columns].into();
     }
 
     pub fn get_value_as_rgba(&self, row: isize, column: isize) -> (u8, u8, u8, u8) {
@@ -470,7 +720,7 @@ impl Raster {
             return (0, 0, 0, 0);
         }
         let idx: usize = r * self.configs.columns + c;
-        let z = self.data[idx];
+        let z = self.data.get(idx);
 
         let r = (z as u32 & 0xFF) as u8;
         let g = ((z as u32 >> 8) & 0xFF) as u8;
@@ -487,7 +737,8 @@ impl Raster {
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
                 let (r, g, b, a) = rgba;
-                self.data[idx] += ((a << 24) | (b << 16) | (g << 8) | r) as f64;
+                let z = self.data.get(idx);
+                self.data.set(idx, z + ((a << 24) | (b << 16) | (g << 8) | r) as f64);
             }
         }
     }
@@ -516,8 +767,11 @@ impl Raster {
     }
 
     pub fn clip_display_min_max(&mut self, percent: f64) {
+        if !settings::get_settings().clip_display_min_max {
+            return;
+        }
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in 0..d.len() {
@@ -543,8 +797,11 @@ impl Raster {
     }
 
     pub fn clip_display_min(&mut self, percent: f64) {
+        if !settings::get_settings().clip_display_min_max {
+            return;
+        }
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in 0..d.len() {
@@ -559,8 +816,11 @@ impl Raster {
     }
 
     pub fn clip_display_max(&mut self, percent: f64) {
+        if !settings::get_settings().clip_display_min_max {
+            return;
+        }
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in (0..d.len()).rev() {
@@ -612,7 +872,7 @@ impl Raster {
 
     pub fn clip_min_by_percent(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         let mut val = 0.0;
@@ -627,9 +887,9 @@ impl Raster {
         }
 
         for i in 0..self.data.len() {
-            if self.data[i] != self.configs.nodata {
-                if self.data[i] < val {
-                    self.data[i] = val;
+            if self.data.get(i) != self.configs.nodata {
+                if self.data.get(i) < val {
+                    self.data.set(i, val);
                 }
             }
         }
@@ -639,7 +899,7 @@ impl Raster {
 
     pub fn clip_max_by_percent(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         let mut val = 0.0;
@@ -654,9 +914,9 @@ impl Raster {
         }
 
         for i in 0..self.data.len() {
-            if self.data[i] != self.configs.nodata {
-                if self.data[i] > val {
-                    self.data[i] = val;
+            if self.data.get(i) != self.configs.nodata {
+                if self.data.get(i) > val {
+                    self.data.set(i, val);
                 }
             }
         }
@@ -666,7 +926,7 @@ impl Raster {
 
     pub fn clip_min_and_max_by_percent(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         let mut lower_val = 0.0;
@@ -693,11 +953,11 @@ impl Raster {
         }
 
         for i in 0..self.data.len() {
-            if self.data[i] != self.configs.nodata {
-                if self.data[i] < lower_val {
-                    self.data[i] = lower_val;
-                } else if self.data[i] > upper_val {
-                    self.data[i] = upper_val;
+            if self.data.get(i) != self.configs.nodata {
+                if self.data.get(i) < lower_val {
+                    self.data.set(i, lower_val);
+                } else if self.data.get(i) > upper_val {
+                    self.data.set(i, upper_val);
                 }
             }
         }
@@ -711,6 +971,7 @@ impl Raster {
         self.configs.maximum = f64::NEG_INFINITY;
         let num_procs = num_cpus::get();
         let nodata = self.configs.nodata;
+        // compactly stored values are shared, rather than copied
         let values = Arc::new(self.data.clone());
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
@@ -721,7 +982,7 @@ impl Raster {
                 let mut max_val = f64::NEG_INFINITY;
                 let mut value: f64;
                 for i in (0..values.len()).filter(|v| v % num_procs == tid) {
-                    value = values[i];
+                    value = values.get(i);
                     if value != nodata {
                         if value < min_val {
                             min_val = value;
@@ -771,7 +1032,7 @@ impl Raster {
             return 0usize;
         }
         let nodata = self.configs.nodata;
-        let values = Arc::new(self.data.clone());
+        let values = Arc::new(self.data.to_vec());
         let num_procs = num_cpus::get();
         let num_cells = self.num_cells();
         let (tx, rx) = mpsc::channel();
@@ -802,7 +1063,7 @@ impl Raster {
             return 0.0;
         }
         let nodata = self.configs.nodata;
-        let values = Arc::new(self.data.clone());
+        let values = Arc::new(self.data.to_vec());
         let num_procs = num_cpus::get();
         let num_cells = self.num_cells();
         let (tx, rx) = mpsc::channel();
@@ -840,7 +1101,7 @@ impl Raster {
 
         let mean = self.calculate_mean();
         let nodata = self.configs.nodata;
-        let values = Arc::new(self.data.clone());
+        let values = Arc::new(self.data.to_vec());
         let num_procs = num_cpus::get();
         let num_cells = self.num_cells();
         let (tx, rx) = mpsc::channel();
@@ -875,7 +1136,7 @@ impl Raster {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
         let mut lower_tail = f64::NEG_INFINITY;
         let mut upper_tail = f64::NEG_INFINITY;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in 0..d.len() {
@@ -902,6 +1163,7 @@ impl Raster {
         (lower_tail, upper_tail)
     }
 
+    /// Writes the raster to its file.
     pub fn write(&mut self) -> Result<(), Error> {
         if !self.file_mode.contains("w") {
             return Err(Error::new(
@@ -909,66 +1171,123 @@ impl Raster {
                 "Cannot write raster that is not created in write mmode ('w').",
             ));
         }
+        if self.file_name.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Cannot write a raster without a file name (see set_file_name).",
+            ));
+        }
+        // the writers, and the tools reading a stored raster, dereference its values
+        self.data.convert_to_f64();
+        for band in self.extra_bands.iter_mut() {
+            band.convert_to_f64();
+        }
+        if memory_store::is_memory_dataset(&self.file_name) {
+            // the stored raster shares its values with this raster until either is modified
+            self.data.share();
+            for band in self.extra_bands.iter_mut() {
+                band.share();
+            }
+            memory_store::put_raster(&self.file_name, self.clone());
+            return Ok(());
+        }
+        if self.raster_type == RasterType::Unknown {
+            // e.g. an in-memory raster that has since been given a file name
+            self.raster_type =
+                get_raster_type_from_file(self.file_name.clone(), self.file_mode.clone());
+        }
+        if !self.extra_bands.is_empty() && self.raster_type != RasterType::GeoTiff {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Multi-band rasters can only be written as GeoTIFFs.",
+            ));
+        }
         match self.raster_type {
             RasterType::ArcAscii => {
-                let _ = match write_arcascii(self) {
+                let _ = match write_arcascii(self, settings::get_settings().ascii_precision) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::ArcBinary => {
                 let _ = match write_arcbinary(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::GeoTiff => {
                 let _ = match write_geotiff(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::GrassAscii => {
-                let _ = match write_grass_raster(self) {
+                let precision = settings::get_settings().ascii_precision;
+                let _ = match write_grass_raster(self, precision) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::IdrisiBinary => {
                 let _ = match write_idrisi(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::SagaBinary => {
                 let _ = match write_saga(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::Surfer7Binary => {
                 let _ = match write_surfer7(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::SurferAscii => {
                 let _ = match write_surfer_ascii_raster(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::Whitebox => {
                 let _ = match write_whitebox(self) {
                     Ok(_) => (),
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => report::message(&format!("error while writing: {:?}", e)),
                 };
             }
             RasterType::Unknown => {
                 return Err(Error::new(ErrorKind::Other, "Unrecognized raster type"));
             }
         }
-        Ok(())
+        write_auxiliary_files(&self.file_name, &self.configs)
+    }
+
+    /// Returns the contents of a world file describing the georeferencing of the raster,
+    /// i.e. the cell sizes and rotation terms, followed by the coordinates of the centre
+    /// of the upper-left cell.
+    pub fn get_world_file_text(&self) -> String {
+        get_world_file_text(&self.configs)
+    }
+
+    /// Reads the coordinate reference system from a .prj file accompanying the raster,
+    /// if there is one.
+    fn read_prj_file(&mut self) {
+        let prj_file = Path::new(&self.file_name).with_extension("prj");
+        if !prj_file.exists() || prj_file == Path::new(&self.file_name) {
+            return;
+        }
+        let mut wkt = String::new();
+        if let Ok(mut f) = File::open(&prj_file) {
+            if f.read_to_string(&mut wkt).is_ok() && wkt.contains("[") {
+                self.configs.coordinate_ref_system_wkt = wkt.trim().to_string();
+                if self.configs.epsg_code == 0 {
+                    self.configs.epsg_code = epsg_from_wkt(&wkt);
+                }
+            }
+        }
     }
 
     pub fn add_metadata_entry(&mut self, value: String) {
@@ -1007,6 +1326,8 @@ pub struct RasterConfigs {
     pub rows: usize,
     pub columns: usize,
     pub bands: u8,
+    /// The names (descriptions) of the bands of a multi-band raster, if any.
+    pub band_names: Vec<String>,
     pub nodata: f64,
     pub north: f64,
     pub south: f64,
@@ -1019,6 +1340,10 @@ pub struct RasterConfigs {
     pub display_min: f64,
     pub display_max: f64,
     pub palette: String,
+    /// The colour table of a paletted (colour-mapped) raster, with one entry per
+    /// category value. Colours are packed as (alpha << 24) | (blue << 16) | (green << 8) | red,
+    /// i.e. the same way as RGB raster values.
+    pub color_map: Vec<u32>,
     pub projection: String,
     pub endian: Endianness,
     pub photometric_interp: PhotometricInterpretation,
@@ -1038,6 +1363,7 @@ impl Default for RasterConfigs {
         RasterConfigs {
             title: String::from(""),
             bands: 1,
+            band_names: vec![],
             rows: 0,
             columns: 0,
             nodata: -32768.0,
@@ -1052,6 +1378,7 @@ impl Default for RasterConfigs {
             display_min: f64::INFINITY,
             display_max: f64::NEG_INFINITY,
             palette: "not specified".to_string(),
+            color_map: vec![],
             projection: "not specified".to_string(),
             endian: Endianness::LittleEndian,
             photometric_interp: PhotometricInterpretation::Unknown,
@@ -1088,7 +1415,140 @@ impl Default for RasterType {
     }
 }
 
+/// Returns the configurations of a new raster of type `raster_type` with the grid extent,
+/// location, data type and coordinate reference system of an existing raster.
+fn get_configs_using_file(input: &RasterConfigs, raster_type: &RasterType) -> RasterConfigs {
+    let mut configs = RasterConfigs::default();
+    configs.rows = input.rows;
+    configs.columns = input.columns;
+    configs.north = input.north;
+    configs.south = input.south;
+    configs.east = input.east;
+    configs.west = input.west;
+    configs.resolution_x = input.resolution_x;
+    configs.resolution_y = input.resolution_y;
+    configs.nodata = input.nodata;
+    configs.data_type = input.data_type;
+    configs.photometric_interp = input.photometric_interp;
+    configs.palette = input.palette.clone();
+    configs.color_map = input.color_map.clone();
+    configs.projection = input.projection.clone();
+    configs.xy_units = input.xy_units.clone();
+    configs.z_units = input.z_units.clone();
+    configs.endian = input.endian.clone();
+    // configs.palette_nonlinearity = input.palette_nonlinearity;
+    configs.pixel_is_area = input.pixel_is_area;
+    configs.epsg_code = input.epsg_code;
+    configs.coordinate_ref_system_wkt = input.coordinate_ref_system_wkt.clone();
+
+    if *raster_type == RasterType::SurferAscii || *raster_type == RasterType::Surfer7Binary {
+        configs.nodata = 1.71041e38;
+    }
+    configs
+}
+
+/// Returns the default nodata value of the current `utils::settings`, if there is one and
+/// it can be stored using the data type of `configs` and format `raster_type`. The Surfer
+/// formats have a fixed nodata value.
+fn get_default_nodata(configs: &RasterConfigs, raster_type: &RasterType) -> Option<f64> {
+    match settings::get_settings().default_nodata {
+        Some(nodata)
+            if configs.data_type.can_represent(nodata)
+                && *raster_type != RasterType::SurferAscii
+                && *raster_type != RasterType::Surfer7Binary =>
+        {
+            Some(nodata)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the contents of a world file describing the georeferencing of a raster.
+fn get_world_file_text(configs: &RasterConfigs) -> String {
+    format!(
+        "{}\n0.0\n0.0\n{}\n{}\n{}\n",
+        configs.resolution_x,
+        -configs.resolution_y,
+        configs.west + configs.resolution_x / 2f64,
+        configs.north - configs.resolution_y / 2f64
+    )
+}
+
+/// Writes the .prj and .wld files that accompany a raster written to `file_name`, if the
+/// current settings call for them.
+fn write_auxiliary_files(file_name: &str, configs: &RasterConfigs) -> Result<(), Error> {
+    let settings = settings::get_settings();
+    if settings.write_prj_files && configs.coordinate_ref_system_wkt.contains("[") {
+        let mut f = File::create(Path::new(file_name).with_extension("prj"))?;
+        f.write_all(configs.coordinate_ref_system_wkt.trim().as_bytes())?;
+    }
+    if settings.write_world_files {
+        let mut f = File::create(Path::new(file_name).with_extension("wld"))?;
+        f.write_all(get_world_file_text(configs).as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Returns `file_name` with the default raster format extension appended if it has none.
+fn get_output_file_name(file_name: &str) -> String {
+    // in-memory datasets are identified by their full names and have no file format
+    if file_name.contains(".") || memory_store::is_memory_dataset(file_name) {
+        file_name.to_string()
+    } else {
+        // likely no extension provided; use the default format
+        format!(
+            "{}.{}",
+            file_name,
+            settings::get_settings().default_raster_format
+        )
+    }
+}
+
+/// Formats a cell value for the ASCII raster formats. Integer data are written without
+/// decimal places. Floating-point data are written with `precision` decimal places or,
+/// if `precision` is None, with as many digits as are needed to represent the value.
+fn format_ascii_value(value: f64, data_type: DataType, precision: Option<usize>) -> String {
+    if !data_type.is_float() {
+        return format!("{:.0}", value);
+    }
+    match precision {
+        Some(p) => format!("{:.*}", p, value),
+        // the debug format always includes a decimal point, which identifies the data
+        // as floating-point when the file is read
+        None if data_type == DataType::F32 => format!("{:?}", value as f32),
+        None => format!("{:?}", value),
+    }
+}
+
+/// Checks that the number of cells of a raster is consistent with its numbers of rows
+/// and columns, i.e. that the data can be read or written in row-major order.
+fn check_grid_size(
+    file_name: &str,
+    configs: &RasterConfigs,
+    num_cells: usize,
+) -> Result<(), Error> {
+    if configs.rows == 0 || configs.columns == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("The raster {} has no rows or columns.", file_name),
+        ));
+    }
+    if num_cells != configs.rows * configs.columns {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "The raster {} contains {} cells, but has {} rows and {} columns.",
+                file_name, num_cells, configs.rows, configs.columns
+            ),
+        ));
+    }
+    Ok(())
+}
+
 fn get_raster_type_from_file(file_name: String, file_mode: String) -> RasterType {
+    if memory_store::is_memory_dataset(&file_name) {
+        return RasterType::Unknown;
+    }
     // get the file extension
     let extension: String = match Path::new(&file_name).extension().unwrap().to_str() {
         Some(n) => n.to_string().to_lowercase(),
@@ -1183,6 +1643,63 @@ impl Default for DataType {
 }
 
 impl DataType {
+    /// Returns whether the data type is a floating-point type. Unknown data are
+    /// assumed to be floating-point.
+    pub fn is_float(&self) -> bool {
+        match *self {
+            DataType::F64 | DataType::F32 | DataType::Unknown => true,
+            _ => false,
+        }
+    }
+
+    /// Returns whether `value` can be stored exactly using the data type, e.g. whether
+    /// a raster of this type can hold a particular nodata value. RGB values are packed
+    /// into unsigned integers.
+    pub fn can_represent(&self, value: f64) -> bool {
+        if self.is_float() {
+            return *self != DataType::F32 || value.is_nan() || value as f32 as f64 == value;
+        }
+        if value.fract() != 0f64 || value.is_nan() {
+            return false;
+        }
+        let (min, max) = match *self {
+            DataType::RGBA32 => (0f64, 4294967295f64),
+            DataType::RGB24 => (0f64, 16777215f64),
+            DataType::RGB48 => (0f64, 281474976710655f64),
+            _ => self.integer_range().unwrap(),
+        };
+        value >= min && value <= max
+    }
+
+    /// Returns whether the data type is a signed or unsigned integer type.
+    pub fn is_integer(&self) -> bool {
+        self.integer_range().is_some()
+    }
+
+    /// Returns whether the data type is a signed integer type.
+    pub fn is_signed_integer(&self) -> bool {
+        match *self {
+            DataType::I64 | DataType::I32 | DataType::I16 | DataType::I8 => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the minimum and maximum values of an integer data type, or `None` for
+    /// floating-point and RGB data types.
+    pub fn integer_range(&self) -> Option<(f64, f64)> {
+        match *self {
+            DataType::I64 => Some((-9.223372036854776e18, 9.223372036854776e18)),
+            DataType::I32 => Some((-2147483648f64, 2147483647f64)),
+            DataType::I16 => Some((-32768f64, 32767f64)),
+            DataType::I8 => Some((-128f64, 127f64)),
+            DataType::U64 => Some((0f64, 1.8446744073709552e19)),
+            DataType::U32 => Some((0f64, 4294967295f64)),
+            DataType::U16 => Some((0f64, 65535f64)),
+            DataType::U8 => Some((0f64, 255f64)),
+            _ => None,
+        }
+    }
+
     pub fn get_data_size(&self) -> usize {
         match *self {
             DataType::F64 => 8usize,
@@ -0,0 +1,436 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::DataType;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+use std::sync::Arc;
+use std::{f32, i16, i32, u8};
+use utils::MemoryMap;
+
+/// The grid cell values of a raster band, stored in row-major order. The values are
+/// usually held in memory as `f64`s, but they may instead be:
+///
+/// - held in a compact storage type matching the data type of the file that they were
+///   read from (`f32`, `i32`, `i16` or `u8`), reducing memory use by a half or more
+///   (see `Raster::new_compact`); or
+/// - held in a memory-mapped file, if they were read with memory mapping enabled (see the
+///   `memory_map_rasters` setting of `utils::settings`), in which case they refer directly
+///   to the values of an uncompressed 64-bit floating-point file and are shared with any
+///   other process reading the same file.
+///
+/// Values are read and written individually, in any storage type, with `get` and `set`,
+/// which convert each value to and from the storage type. `f64` and mapped values may also
+/// be dereferenced as a slice of `f64`s, but compactly stored values cannot be, and
+/// dereferencing them panics. Mutably dereferencing the values, or setting a value that
+/// cannot be represented by the storage type, converts the storage to `f64`. Mapped values
+/// are read-only, and are likewise copied into memory when first modified.
+///
+/// `f64` values are modified in place. Compactly stored and mapped values, and values that
+/// have been moved into shared storage with `share` (e.g. by the rasters of
+/// `utils::memory_store`), are not copied when the data are cloned, but are instead shared
+/// by the clones until one of them is modified, at which point its values are copied once.
+#[derive(Clone)]
+pub struct RasterData {
+    // the values, if they are held as f64s by this data alone, which are modified in place
+    values: Vec<f64>,
+    // otherwise, the compactly stored, memory-mapped, or shared values
+    stored: Option<Arc<Values>>,
+}
+
+#[derive(Clone)]
+enum Values {
+    F64(Vec<f64>),
+    F32(Vec<f32>),
+    I32(Vec<i32>),
+    I16(Vec<i16>),
+    U8(Vec<u8>),
+    Mapped {
+        map: Arc<MemoryMap>,
+        offset: usize,
+        len: usize,
+    },
+}
+
+impl RasterData {
+    /// Returns data referring to the `len` values that begin `offset` bytes into a mapped
+    /// file, or None if the file is too short or the values are not suitably aligned.
+    pub fn from_map(map: Arc<MemoryMap>, offset: usize, len: usize) -> Option<RasterData> {
+        if map.get_slice::<f64>(offset, len).is_none() {
+            return None;
+        }
+        Some(RasterData::new(Values::Mapped {
+            map: map,
+            offset: offset,
+            len: len,
+        }))
+    }
+
+    /// Returns data holding `values` in the most compact storage type able to represent
+    /// every value of `data_type`, i.e. `f32` for F32 data, `i32` for I32 and U16 data,
+    /// `i16` for I16 and I8 data, and `u8` for U8 data. Other data types, and values that
+    /// cannot be represented by the storage type (e.g. a nodata value of -32768 in U8
+    /// data), are stored as `f64`s.
+    pub fn with_data_type(values: Vec<f64>, data_type: DataType) -> RasterData {
+        let compact = match data_type {
+            DataType::F32 if all_fit(&values, fits_f32) => {
+                Values::F32(values.iter().map(|&v| v as f32).collect())
+            }
+            DataType::I32 | DataType::U16
+                if all_fit(&values, |v| fits_int(v, i32::MIN as f64, i32::MAX as f64)) =>
+            {
+                Values::I32(values.iter().map(|&v| v as i32).collect())
+            }
+            DataType::I16 | DataType::I8
+                if all_fit(&values, |v| fits_int(v, i16::MIN as f64, i16::MAX as f64)) =>
+            {
+                Values::I16(values.iter().map(|&v| v as i16).collect())
+            }
+            DataType::U8 if all_fit(&values, |v| fits_int(v, 0f64, u8::MAX as f64)) => {
+                Values::U8(values.iter().map(|&v| v as u8).collect())
+            }
+            _ => Values::F64(values),
+        };
+        RasterData::new(compact)
+    }
+
+    fn new(values: Values) -> RasterData {
+        match values {
+            Values::F64(v) => RasterData::from(v),
+            values => RasterData {
+                values: vec![],
+                stored: Some(Arc::new(values)),
+            },
+        }
+    }
+
+    /// Moves the values into shared storage, such that clones of the data share them,
+    /// rather than copying them, until one of the clones is modified.
+    pub fn share(&mut self) {
+        if self.stored.is_none() {
+            let values = mem::replace(&mut self.values, vec![]);
+            self.stored = Some(Arc::new(Values::F64(values)));
+        }
+    }
+
+    /// Returns the type in which the values are stored; mapped values are F64.
+    pub fn storage_type(&self) -> DataType {
+        match self.stored {
+            None => DataType::F64,
+            Some(ref stored) => stored.storage_type(),
+        }
+    }
+
+    /// Converts compactly stored values to `f64`s, e.g. so that they may be dereferenced.
+    /// `f64` and mapped values are left unchanged.
+    pub fn convert_to_f64(&mut self) {
+        if self.storage_type() != DataType::F64 {
+            self.unshare();
+        }
+    }
+
+    /// Returns true if the values are held in a memory-mapped file.
+    pub fn is_mapped(&self) -> bool {
+        match self.stored {
+            Some(ref stored) => match **stored {
+                Values::Mapped { .. } => true,
+                _ => false,
+            },
+            None => false,
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        match self.stored {
+            None => self.values.len(),
+            Some(ref stored) => stored.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value at `index`. Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> f64 {
+        match self.stored {
+            None => self.values[index],
+            Some(ref stored) => stored.get(index),
+        }
+    }
+
+    /// Sets the value at `index`, converting the storage to `f64` if the value cannot be
+    /// represented by the storage type. Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: f64) {
+        if self.stored.is_none() {
+            self.values[index] = value;
+            return;
+        }
+        let is_set = match self.stored {
+            // shared and mapped f64 values are copied into `values` by deref_mut below
+            Some(ref mut stored) => match **stored {
+                Values::F64(_) | Values::Mapped { .. } => false,
+                _ => Arc::make_mut(stored).set(index, value),
+            },
+            None => unreachable!(),
+        };
+        if !is_set {
+            self.deref_mut()[index] = value;
+        }
+    }
+
+    /// Returns a copy of the values as `f64`s.
+    pub fn to_vec(&self) -> Vec<f64> {
+        match self.stored {
+            None => self.values.clone(),
+            Some(ref stored) => stored.to_vec(),
+        }
+    }
+
+    /// Returns the values as a vector, copying them if they are not held as `f64`s by
+    /// this data alone.
+    pub fn into_vec(mut self) -> Vec<f64> {
+        self.unshare();
+        self.values
+    }
+
+    // Moves stored values into `values`, as f64s, copying them unless they are f64s that
+    // are not shared with any other data.
+    fn unshare(&mut self) {
+        if let Some(stored) = self.stored.take() {
+            self.values = match Arc::try_unwrap(stored) {
+                Ok(Values::F64(v)) => v,
+                Ok(stored) => stored.to_vec(),
+                Err(stored) => stored.to_vec(),
+            };
+        }
+    }
+}
+
+impl Default for RasterData {
+    fn default() -> RasterData {
+        RasterData::from(vec![])
+    }
+}
+
+impl From<Vec<f64>> for RasterData {
+    fn from(values: Vec<f64>) -> RasterData {
+        RasterData {
+            values: values,
+            stored: None,
+        }
+    }
+}
+
+impl Deref for RasterData {
+    type Target = [f64];
+
+    fn deref(&self) -> &[f64] {
+        match self.stored {
+            None => &self.values,
+            Some(ref stored) => stored
+                .as_slice()
+                .expect("Compactly stored values must be read individually, using get."),
+        }
+    }
+}
+
+impl DerefMut for RasterData {
+    fn deref_mut(&mut self) -> &mut [f64] {
+        if self.stored.is_some() {
+            self.unshare();
+        }
+        &mut self.values
+    }
+}
+
+impl<'a> IntoIterator for &'a RasterData {
+    type Item = &'a f64;
+    type IntoIter = slice::Iter<'a, f64>;
+
+    fn into_iter(self) -> slice::Iter<'a, f64> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut RasterData {
+    type Item = &'a mut f64;
+    type IntoIter = slice::IterMut<'a, f64>;
+
+    fn into_iter(self) -> slice::IterMut<'a, f64> {
+        self.iter_mut()
+    }
+}
+
+impl Values {
+    // Returns `len` zeros held in the storage type of `data_type` (see `with_data_type`).
+    fn zeros(len: usize, data_type: DataType) -> Values {
+        match data_type {
+            DataType::F32 => Values::F32(vec![0f32; len]),
+            DataType::I32 | DataType::U16 => Values::I32(vec![0i32; len]),
+            DataType::I16 | DataType::I8 => Values::I16(vec![0i16; len]),
+            DataType::U8 => Values::U8(vec![0u8; len]),
+            _ => Values::F64(vec![0f64; len]),
+        }
+    }
+
+    fn storage_type(&self) -> DataType {
+        match *self {
+            Values::F64(_) | Values::Mapped { .. } => DataType::F64,
+            Values::F32(_) => DataType::F32,
+            Values::I32(_) => DataType::I32,
+            Values::I16(_) => DataType::I16,
+            Values::U8(_) => DataType::U8,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match *self {
+            Values::F64(ref v) => v.len(),
+            Values::F32(ref v) => v.len(),
+            Values::I32(ref v) => v.len(),
+            Values::I16(ref v) => v.len(),
+            Values::U8(ref v) => v.len(),
+            Values::Mapped { len, .. } => len,
+        }
+    }
+
+    fn get(&self, index: usize) -> f64 {
+        match *self {
+            Values::F32(ref v) => v[index] as f64,
+            Values::I32(ref v) => v[index] as f64,
+            Values::I16(ref v) => v[index] as f64,
+            Values::U8(ref v) => v[index] as f64,
+            _ => self.as_slice().unwrap()[index],
+        }
+    }
+
+    // Sets the value at `index`, returning false, without setting it, if it cannot be
+    // represented by the storage type or the values are mapped.
+    fn set(&mut self, index: usize, value: f64) -> bool {
+        match *self {
+            Values::F64(ref mut v) => v[index] = value,
+            Values::F32(ref mut v) if fits_f32(value) => v[index] = value as f32,
+            Values::I32(ref mut v) if fits_int(value, i32::MIN as f64, i32::MAX as f64) => {
+                v[index] = value as i32
+            }
+            Values::I16(ref mut v) if fits_int(value, i16::MIN as f64, i16::MAX as f64) => {
+                v[index] = value as i16
+            }
+            Values::U8(ref mut v) if fits_int(value, 0f64, u8::MAX as f64) => {
+                v[index] = value as u8
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    // Returns the values if they are stored as f64s.
+    fn as_slice(&self) -> Option<&[f64]> {
+        match *self {
+            Values::F64(ref v) => Some(v),
+            Values::Mapped {
+                ref map,
+                offset,
+                len,
+            } => map.get_slice::<f64>(offset, len), // checked by from_map
+            _ => None,
+        }
+    }
+
+    fn to_vec(&self) -> Vec<f64> {
+        match self.as_slice() {
+            Some(values) => values.to_vec(),
+            None => (0..self.len()).map(|i| self.get(i)).collect(),
+        }
+    }
+}
+
+/// The destination of the values decoded by a raster file reader, which holds them either
+/// as `f64`s (`Vec<f64>`) or in the compact storage type of the file's data type
+/// (`CompactValues`).
+pub trait DecodedValues {
+    /// Replaces the values with `len` zeros, which are to hold values of `data_type`.
+    fn reset(&mut self, len: usize, data_type: DataType);
+
+    /// Returns the value at `index`.
+    fn get_value(&self, index: usize) -> f64;
+
+    /// Sets the value at `index`.
+    fn set_value(&mut self, index: usize, value: f64);
+}
+
+impl DecodedValues for Vec<f64> {
+    fn reset(&mut self, len: usize, _data_type: DataType) {
+        self.clear();
+        self.resize(len, 0f64);
+    }
+
+    fn get_value(&self, index: usize) -> f64 {
+        self[index]
+    }
+
+    fn set_value(&mut self, index: usize, value: f64) {
+        self[index] = value;
+    }
+}
+
+/// Values that are decoded directly into the compact storage type of their data type (see
+/// `RasterData::with_data_type`), without first being read as `f64`s. The values are
+/// converted to `f64`s if one of them cannot be represented by the storage type.
+pub struct CompactValues {
+    values: Values,
+}
+
+impl CompactValues {
+    pub fn new() -> CompactValues {
+        CompactValues {
+            values: Values::F64(vec![]),
+        }
+    }
+}
+
+impl DecodedValues for CompactValues {
+    fn reset(&mut self, len: usize, data_type: DataType) {
+        self.values = Values::zeros(len, data_type);
+    }
+
+    fn get_value(&self, index: usize) -> f64 {
+        self.values.get(index)
+    }
+
+    fn set_value(&mut self, index: usize, value: f64) {
+        if !self.values.set(index, value) {
+            self.values = Values::F64(self.values.to_vec());
+            self.values.set(index, value);
+        }
+    }
+}
+
+impl From<CompactValues> for RasterData {
+    fn from(values: CompactValues) -> RasterData {
+        RasterData::new(values.values)
+    }
+}
+
+fn all_fit<F: Fn(f64) -> bool>(values: &[f64], fits: F) -> bool {
+    values.iter().all(|&v| fits(v))
+}
+
+fn fits_f32(value: f64) -> bool {
+    // out-of-range values are checked before casting
+    value.is_nan()
+        || value.is_infinite()
+        || value.abs() <= f32::MAX as f64 && (value as f32) as f64 == value
+}
+
+fn fits_int(value: f64, min: f64, max: f64) -> bool {
+    value >= min && value <= max && value.trunc() == value
+}
@@ -0,0 +1,209 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::{
+    check_grid_size, get_configs_using_file, get_default_nodata, get_output_file_name,
+    get_raster_type_from_file, write_auxiliary_files,
+};
+use raster::geotiff::GeoTiffWriter;
+use raster::{Raster, RasterConfigs, RasterType};
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use utils::memory_store;
+
+/// RasterWriter writes a new raster incrementally, a block of rows at a time, such that
+/// tools computing their output row by row need not hold the entire output grid in
+/// memory. A writer is created in the same way as a new `Raster`, its `configs` are
+/// modified as needed, and rows are then written between calls to `begin_write` and
+/// `finalize`.
+///
+/// Examples:
+///
+/// ```
+/// let mut output = RasterWriter::initialize_using_file(&output_file, &input);
+/// output.configs.data_type = DataType::F32;
+/// output.begin_write()?;
+/// for row in 0..rows {
+///     let (r, data) = rx.recv().unwrap(); // rows may arrive in any order
+///     output.write_rows(r, data)?;
+/// }
+/// output.add_metadata_entry("Created by whitebox_tools".to_string());
+/// output.finalize()?;
+/// ```
+///
+/// The configurations that determine how the data are stored, i.e. the grid dimensions,
+/// data type, nodata value, photometric interpretation and byte order, are fixed by
+/// `begin_write`. Others, such as the display minimum and maximum, palette, and metadata,
+/// may be modified until `finalize` is called.
+///
+/// GeoTIFFs are written to disk as their rows are received. Rows that are received ahead
+/// of the next row in the file are held in memory until all of the rows preceding them
+/// have been written, so memory use is lowest when rows are written in roughly ascending
+/// order, as they are by tools that distribute rows among threads. The other raster
+/// formats, and in-memory datasets, are buffered in a `Raster` that is written by
+/// `finalize`, which requires no less memory than writing a `Raster` directly.
+pub struct RasterWriter {
+    pub file_name: String,
+    pub raster_type: RasterType,
+    pub configs: RasterConfigs,
+    geotiff: Option<GeoTiffWriter>,
+    buffer: Option<Raster>,
+    // rows received ahead of the next row of the file, keyed by row
+    pending: BTreeMap<usize, Vec<f64>>,
+    next_row: usize,
+}
+
+impl RasterWriter {
+    /// Creates a `RasterWriter` for a new raster with the grid extent and location
+    /// specified by `configs`. If `file_name` has no extension, the default raster
+    /// format of the current `utils::settings` is used, as is its default nodata value,
+    /// if any.
+    pub fn initialize_using_config<'a>(
+        file_name: &'a str,
+        configs: &'a RasterConfigs,
+    ) -> RasterWriter {
+        let new_file_name = get_output_file_name(file_name);
+        let raster_type = get_raster_type_from_file(new_file_name.clone(), "w".to_string());
+        RasterWriter::new(new_file_name, raster_type, configs.clone())
+    }
+
+    /// Creates a `RasterWriter` for a new raster with the grid extent and location of an
+    /// existing `Raster`, in the same way as `Raster::initialize_using_file`.
+    pub fn initialize_using_file<'a>(file_name: &'a str, input: &'a Raster) -> RasterWriter {
+        let new_file_name = get_output_file_name(file_name);
+        let raster_type = get_raster_type_from_file(new_file_name.clone(), "w".to_string());
+        let configs = get_configs_using_file(&input.configs, &raster_type);
+        RasterWriter::new(new_file_name, raster_type, configs)
+    }
+
+    fn new(file_name: String, raster_type: RasterType, mut configs: RasterConfigs) -> RasterWriter {
+        if let Some(nodata) = get_default_nodata(&configs, &raster_type) {
+            configs.nodata = nodata;
+        }
+        RasterWriter {
+            file_name: file_name,
+            raster_type: raster_type,
+            configs: configs,
+            geotiff: None,
+            buffer: None,
+            pending: BTreeMap::new(),
+            next_row: 0,
+        }
+    }
+
+    /// Creates the output file, after which rows may be written. Cells of rows that are
+    /// never written are assigned the nodata value.
+    pub fn begin_write(&mut self) -> Result<(), Error> {
+        if self.geotiff.is_some() || self.buffer.is_some() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("Writing of the raster {} has already begun.", self.file_name),
+            ));
+        }
+        let num_cells = self.configs.rows * self.configs.columns;
+        check_grid_size(&self.file_name, &self.configs, num_cells)?;
+        if self.raster_type == RasterType::GeoTiff
+            && !memory_store::is_memory_dataset(&self.file_name)
+        {
+            self.geotiff = Some(GeoTiffWriter::new(&self.file_name, &self.configs, 1)?);
+        } else {
+            // keep the nodata value of the configs, which the tool may have changed since the
+            // writer was initialized
+            let mut buffer = Raster::initialize_using_config(&self.file_name, &self.configs);
+            buffer.configs.nodata = self.configs.nodata;
+            buffer.reinitialize_values(self.configs.nodata);
+            self.buffer = Some(buffer);
+        }
+        Ok(())
+    }
+
+    /// Writes a block of one or more complete rows, beginning with `first_row`. Blocks
+    /// may be written in any order, but each row may only be written once.
+    pub fn write_rows(&mut self, first_row: isize, values: Vec<f64>) -> Result<(), Error> {
+        if self.geotiff.is_none() && self.buffer.is_none() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("Writing of the raster {} has not begun.", self.file_name),
+            ));
+        }
+        let columns = self.configs.columns;
+        let num_rows = values.len() / columns;
+        if first_row < 0
+            || values.len() % columns != 0
+            || first_row as usize + num_rows > self.configs.rows
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Rows {} to {} do not lie within the raster {}.",
+                    first_row,
+                    first_row + num_rows as isize - 1,
+                    self.file_name
+                ),
+            ));
+        }
+        let first_row = first_row as usize;
+        if let Some(ref mut buffer) = self.buffer {
+            let start = first_row * columns;
+            buffer.data[start..start + values.len()].copy_from_slice(&values);
+            return Ok(());
+        }
+        for (i, row_values) in values.chunks(columns).enumerate() {
+            let row = first_row + i;
+            if row < self.next_row || self.pending.contains_key(&row) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Row {} of the raster {} is written twice.", row, self.file_name),
+                ));
+            }
+            self.pending.insert(row, row_values.to_vec());
+        }
+        while let Some(row_values) = self.pending.remove(&self.next_row) {
+            self.write_next_row(row_values)?;
+        }
+        Ok(())
+    }
+
+    pub fn add_metadata_entry(&mut self, value: String) {
+        self.configs.metadata.push(value);
+    }
+
+    /// Writes any remaining rows and completes the output file, along with its
+    /// accompanying .prj and .wld files where the current settings call for them.
+    pub fn finalize(mut self) -> Result<(), Error> {
+        if let Some(mut buffer) = self.buffer.take() {
+            buffer.configs = self.configs.clone();
+            return buffer.write();
+        }
+        if self.geotiff.is_none() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("Writing of the raster {} has not begun.", self.file_name),
+            ));
+        }
+        while self.next_row < self.configs.rows {
+            let row_values = match self.pending.remove(&self.next_row) {
+                Some(v) => v,
+                None => vec![self.configs.nodata; self.configs.columns],
+            };
+            self.write_next_row(row_values)?;
+        }
+        if let Some(geotiff) = self.geotiff.take() {
+            geotiff.finish()?;
+        }
+        write_auxiliary_files(&self.file_name, &self.configs)
+    }
+
+    fn write_next_row(&mut self, values: Vec<f64>) -> Result<(), Error> {
+        if let Some(ref mut geotiff) = self.geotiff {
+            geotiff.write_row(&values)?;
+        }
+        self.next_row += 1;
+        Ok(())
+    }
+}
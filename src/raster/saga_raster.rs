@@ -1,3 +1,4 @@
+use byteorder::{LittleEndian, WriteBytesExt};
 use raster::*;
 use std::f64;
 use std::fs::File;
@@ -7,403 +8,210 @@ use std::io::BufWriter;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::SeekFrom;
-use std::mem;
 use std::path::Path;
-use utils::Endianness;
-
+use utils::{ByteOrderReader, Endianness};
+
+/// The nodata value written to SAGA grids whose nodata value is NaN.
+const DEFAULT_SAGA_NODATA: f64 = -99999f64;
+
+/// Reads a SAGA binary grid (.sgrd header and .sdat data file).
+///
+/// Cell values are scaled by the header's Z_FACTOR and Z_OFFSET entries. The
+/// NODATA_VALUE entry, which newer versions of SAGA may write as a range of values
+/// (e.g. `-99999;-99990`), applies to the unscaled values, and any cell within the
+/// range is given the (first) nodata value. Note that SAGA's POSITION_XMIN and
+/// POSITION_YMIN are the coordinates of the centre of the lower-left grid cell.
 pub fn read_saga(
     file_name: &String,
     configs: &mut RasterConfigs,
     data: &mut Vec<f64>,
 ) -> Result<(), Error> {
     // read the header file
-    let header_file = file_name.replace(".sdat", ".sgrd");
-    let f = try!(File::open(header_file));
+    let header_file = Path::new(file_name).with_extension("sgrd");
+    let f = File::open(&header_file)?;
     let f = BufReader::new(f);
     let mut data_file_offset = 0u64;
     let mut top_to_bottom = false;
-    let mut z_factor = 1.0;
+    let mut z_factor = 1f64;
+    let mut z_offset = 0f64;
+    let mut nodata_range = (f64::NAN, f64::NAN);
+    let mut is_bit_grid = false;
+    let mut x_min = 0f64;
+    let mut y_min = 0f64;
+    configs.photometric_interp = PhotometricInterpretation::Continuous;
+    configs.endian = Endianness::LittleEndian;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
-        //let line_split = line_unwrapped.split("\t");
-        let line_split = line_unwrapped.split("=");
-        let vec = line_split.collect::<Vec<&str>>();
-        if vec[0].to_lowercase().contains("name") {
-            configs.title = vec[1].replace("=", "").trim().to_string();
-        } else if vec[0].to_lowercase().contains("description") {
-            if vec[1].replace("=", "").trim() != "" {
-                configs
-                    .metadata
-                    .push(vec[1].trim().replace("=", "").to_string());
-            }
-        } else if vec[0].to_lowercase().contains("unit") {
-            if vec[1].replace("=", "").trim() != "" {
-                configs.xy_units = vec[1].trim().replace("=", "").to_string();
-            }
-        } else if vec[0].to_lowercase().contains("datafile_offset") {
-            data_file_offset = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<u64>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("dataformat") {
-            let data_format = vec[1].replace("=", "").trim().to_lowercase().to_string();
-            match &data_format[..] {
-                "bit" => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "Reading of this kind of SAGA raster file is not currently supported",
-                    ))
+        let line_unwrapped = line?;
+        // header lines are of the form 'KEY = value'; values may contain '=' signs
+        let mut parts = line_unwrapped.splitn(2, '=');
+        let key = parts.next().unwrap().trim().to_uppercase();
+        let value = match parts.next() {
+            Some(v) => v.trim().to_string(),
+            None => continue,
+        };
+        match &key[..] {
+            "NAME" => configs.title = value,
+            "DESCRIPTION" => {
+                if !value.is_empty() {
+                    configs.metadata.push(value);
                 }
-                "byte_unsigned" => configs.data_type = DataType::U8,
-                "byte" => configs.data_type = DataType::U8,
-                "shortint_unsigned" => configs.data_type = DataType::U16,
-                "shortint" => configs.data_type = DataType::I16,
-                "integer_unsigned" => configs.data_type = DataType::U32,
-                "integer" => configs.data_type = DataType::I32,
-                "float" => configs.data_type = DataType::F32,
-                "double" => configs.data_type = DataType::F64,
-                _ => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "Reading of this kind of SAGA raster file is not currently supported",
-                    ))
+            }
+            "UNIT" => {
+                if !value.is_empty() {
+                    configs.z_units = value;
                 }
             }
-        } else if vec[0].to_lowercase().contains("byteorder_big") {
-            if vec[1].replace("=", "").trim().to_lowercase().contains("f") || vec[1]
-                .replace("=", "")
-                .trim()
-                .to_lowercase()
-                .contains("lsb")
-            {
-                configs.endian = Endianness::LittleEndian;
-            } else {
-                configs.endian = Endianness::BigEndian;
+            "DATAFILE_OFFSET" => {
+                // a negative offset indicates that there is no header in the data file
+                let offset = parse_header_value(&header_file, &key, &value)?;
+                data_file_offset = if offset > 0f64 { offset as u64 } else { 0u64 };
+            }
+            "DATAFORMAT" => {
+                configs.data_type = match &value.to_lowercase()[..] {
+                    "bit" => {
+                        is_bit_grid = true;
+                        DataType::U8
+                    }
+                    "byte_unsigned" => DataType::U8,
+                    "byte" => DataType::I8,
+                    "shortint_unsigned" => DataType::U16,
+                    "shortint" => DataType::I16,
+                    "integer_unsigned" => DataType::U32,
+                    "integer" => DataType::I32,
+                    "longint_unsigned" => DataType::U64,
+                    "longint" => DataType::I64,
+                    "float" => DataType::F32,
+                    "double" => DataType::F64,
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!(
+                                "The SAGA data format '{}' of {} is not currently supported.",
+                                value,
+                                header_file.display()
+                            ),
+                        ))
+                    }
+                };
+            }
+            "BYTEORDER_BIG" => {
+                let v = value.to_lowercase();
+                configs.endian = if v.starts_with('f') || v.contains("lsb") {
+                    Endianness::LittleEndian
+                } else {
+                    Endianness::BigEndian
+                };
             }
-        } else if vec[0].to_lowercase().contains("position_xmin") {
-            configs.west = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<f64>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("position_ymin") {
-            configs.south = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<f64>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("cellcount_x") {
-            configs.columns = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<usize>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("cellcount_y") {
-            configs.rows = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<usize>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("cellsize") {
-            configs.resolution_x = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<f64>()
-                .unwrap();
-            configs.resolution_y = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<f64>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("z_factor") {
-            z_factor = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<f64>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("nodata_value") {
-            configs.nodata = vec[1]
-                .replace("=", "")
-                .trim()
-                .to_string()
-                .parse::<f64>()
-                .unwrap();
-        } else if vec[0].to_lowercase().contains("toptobottom") {
-            top_to_bottom = vec[1].replace("=", "").trim().to_lowercase().contains("t")
+            "POSITION_XMIN" => x_min = parse_header_value(&header_file, &key, &value)?,
+            "POSITION_YMIN" => y_min = parse_header_value(&header_file, &key, &value)?,
+            "CELLCOUNT_X" => {
+                configs.columns = parse_header_value(&header_file, &key, &value)? as usize;
+            }
+            "CELLCOUNT_Y" => {
+                configs.rows = parse_header_value(&header_file, &key, &value)? as usize;
+            }
+            "CELLSIZE" => {
+                configs.resolution_x = parse_header_value(&header_file, &key, &value)?;
+                configs.resolution_y = configs.resolution_x;
+            }
+            "Z_FACTOR" => z_factor = parse_header_value(&header_file, &key, &value)?,
+            "Z_OFFSET" => z_offset = parse_header_value(&header_file, &key, &value)?,
+            "NODATA_VALUE" => {
+                let mut range = value.split(';');
+                let low = parse_header_value(&header_file, &key, range.next().unwrap())?;
+                let high = match range.next() {
+                    Some(v) => parse_header_value(&header_file, &key, v)?,
+                    None => low,
+                };
+                nodata_range = if low <= high { (low, high) } else { (high, low) };
+                configs.nodata = low;
+            }
+            "TOPTOBOTTOM" => top_to_bottom = value.to_lowercase().starts_with('t'),
+            _ => {}
         }
     }
 
+    check_grid_size(&header_file.to_string_lossy(), configs, configs.rows * configs.columns)?;
+
+    configs.west = x_min - configs.resolution_x / 2f64;
+    configs.south = y_min - configs.resolution_y / 2f64;
     configs.north = configs.south + configs.resolution_y * configs.rows as f64;
     configs.east = configs.west + configs.resolution_x * configs.columns as f64;
 
-    if z_factor < 0.0 && (configs.data_type == DataType::F32 || configs.data_type == DataType::F64)
-    {
+    // scaled integer values are generally not integers
+    let storage_type = configs.data_type;
+    let is_scaled = z_factor != 1f64 || z_offset != 0f64;
+    if is_scaled && configs.data_type != DataType::F32 && configs.data_type != DataType::F64 {
         configs.data_type = DataType::F32;
     }
 
-    let mut row_start = 0;
-    if !top_to_bottom {
-        row_start = configs.rows - 1;
-    }
-
     // read the data file
-    let data_file = file_name.replace(".sgrd", ".sdat");
-    let mut f = try!(File::open(data_file.clone()));
-    try!(f.seek(SeekFrom::Start(data_file_offset)));
-
-    let data_size = if configs.data_type == DataType::F64 {
-        8
-    } else if configs.data_type == DataType::F32
-        || configs.data_type == DataType::I32
-        || configs.data_type == DataType::U32
-    {
-        4
-    } else if configs.data_type == DataType::I16 || configs.data_type == DataType::U16 {
-        2
+    let data_file = Path::new(file_name).with_extension("sdat");
+    let mut f = File::open(&data_file)?;
+    f.seek(SeekFrom::Start(data_file_offset))?;
+
+    let rows = configs.rows;
+    let columns = configs.columns;
+    let row_size = if is_bit_grid {
+        // each row of a bit grid is packed into whole bytes, plus one extra byte
+        columns / 8 + 1
     } else {
-        // DataType::U8 or I8
-        1
+        columns * saga_value_size(storage_type)
     };
-
-    let num_cells = configs.rows * configs.columns;
-    //data = vec![configs.nodata; num_cells];
-    data.clear();
-    for _ in 0..num_cells {
-        data.push(configs.nodata);
+    let mut buffer = vec![0u8; row_size * rows];
+    if let Err(e) = f.read_exact(&mut buffer) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "The SAGA data file {} is too small for a grid of {} rows and {} columns ({}).",
+                data_file.display(),
+                rows,
+                columns,
+                e
+            ),
+        ));
     }
 
-    let buf_size = 1_000_000usize;
-    let mut j = 0;
-    let mut row = row_start;
-    let mut col = 0;
-    let mut k: usize;
-    while j < num_cells {
-        let mut buffer = vec![0; buf_size * data_size];
-
-        try!(f.read(&mut buffer));
-
-        let mut offset: usize;
-        match configs.data_type {
-            DataType::F64 => {
-                for i in 0..buf_size {
-                    offset = i * data_size;
-                    k = row * configs.columns + col;
-                    data[k] = unsafe {
-                        mem::transmute::<[u8; 8], f64>([
-                            buffer[offset],
-                            buffer[offset + 1],
-                            buffer[offset + 2],
-                            buffer[offset + 3],
-                            buffer[offset + 4],
-                            buffer[offset + 5],
-                            buffer[offset + 6],
-                            buffer[offset + 7],
-                        ])
-                    } * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j >= num_cells - 1 {
-                        break;
-                    }
-                }
-            }
-            DataType::F32 => {
-                for i in 0..buf_size {
-                    offset = i * data_size;
-                    k = row * configs.columns + col;
-                    data[k] = unsafe {
-                        mem::transmute::<[u8; 4], f32>([
-                            buffer[offset],
-                            buffer[offset + 1],
-                            buffer[offset + 2],
-                            buffer[offset + 3],
-                        ])
-                    } as f64
-                        * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
-                }
-            }
-            DataType::I32 => {
-                for i in 0..buf_size {
-                    offset = i * data_size;
-                    k = row * configs.columns + col;
-                    data[k] = unsafe {
-                        mem::transmute::<[u8; 4], i32>([
-                            buffer[offset],
-                            buffer[offset + 1],
-                            buffer[offset + 2],
-                            buffer[offset + 3],
-                        ])
-                    } as f64
-                        * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
-                }
-            }
-            DataType::U32 => {
-                for i in 0..buf_size {
-                    offset = i * data_size;
-                    k = row * configs.columns + col;
-                    data[k] = unsafe {
-                        mem::transmute::<[u8; 4], u32>([
-                            buffer[offset],
-                            buffer[offset + 1],
-                            buffer[offset + 2],
-                            buffer[offset + 3],
-                        ])
-                    } as f64
-                        * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
-                }
-            }
-            DataType::I16 => {
-                for i in 0..buf_size {
-                    offset = i * data_size;
-                    k = row * configs.columns + col;
-                    data[k] = unsafe {
-                        mem::transmute::<[u8; 2], i16>([buffer[offset], buffer[offset + 1]])
-                    } as f64
-                        * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
-                }
-            }
-            DataType::U16 => {
-                for i in 0..buf_size {
-                    offset = i * data_size;
-                    k = row * configs.columns + col;
-                    data[k] = unsafe {
-                        mem::transmute::<[u8; 2], u16>([buffer[offset], buffer[offset + 1]])
-                    } as f64
-                        * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
-                }
-            }
-            DataType::I8 => {
-                for i in 0..buf_size {
-                    k = row * configs.columns + col;
-                    data[k] = (buffer[i] as i8) as f64 * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
-                }
-            }
-            DataType::U8 => {
-                for i in 0..buf_size {
-                    k = row * configs.columns + col;
-                    data[k] = buffer[i] as f64 * z_factor;
-                    col += 1;
-                    if col >= configs.columns {
-                        col = 0;
-                        if !top_to_bottom {
-                            row -= 1;
-                        } else {
-                            row += 1;
-                        }
-                    }
-                    j += 1;
-                    if j == num_cells {
-                        break;
-                    }
+    let nodata = configs.nodata;
+    let mut bor = ByteOrderReader::new(buffer, configs.endian);
+    data.clear();
+    data.resize(rows * columns, nodata);
+    let mut value: f64;
+    for i in 0..rows {
+        // the rows are stored from the bottom of the grid by default
+        let row = if top_to_bottom { i } else { rows - 1 - i };
+        bor.seek(i * row_size);
+        for col in 0..columns {
+            value = if is_bit_grid {
+                let byte = bor.buffer[i * row_size + col / 8];
+                ((byte >> (col % 8)) & 1u8) as f64
+            } else {
+                match storage_type {
+                    DataType::F64 => bor.read_f64(),
+                    DataType::F32 => bor.read_f32() as f64,
+                    DataType::I64 => bor.read_i64() as f64,
+                    DataType::U64 => bor.read_u64() as f64,
+                    DataType::I32 => bor.read_i32() as f64,
+                    DataType::U32 => bor.read_u32() as f64,
+                    DataType::I16 => bor.read_i16() as f64,
+                    DataType::U16 => bor.read_u16() as f64,
+                    DataType::I8 => bor.read_i8() as f64,
+                    _ => bor.read_u8() as f64,
                 }
+            };
+            if value.is_nan() || (value >= nodata_range.0 && value <= nodata_range.1) {
+                continue; // the cell is already set to nodata
             }
-            _ => {
-                return Err(Error::new(
-                    ErrorKind::NotFound,
-                    "Raster data type is unknown.",
-                ));
-            }
+            data[row * columns + col] = value * z_factor + z_offset;
         }
     }
 
     Ok(())
 }
 
+/// Writes a SAGA binary grid, i.e. a .sgrd header file and a little-endian .sdat data
+/// file, with the rows ordered from the bottom of the grid. NaN values are written as
+/// nodata. Rasters with non-square cells cannot be written in this format.
 pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
@@ -425,58 +233,17 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
         r.configs.display_max = r.configs.maximum;
     }
 
-    // Save the header file
-    let header_file = r.file_name.replace(".sdat", ".sgrd");
-    let f = try!(File::create(header_file.clone()));
-    let mut writer = BufWriter::new(f);
-
-    // get the short file NAME
-    let short_name: String = match Path::new(&header_file).file_name().unwrap().to_str() {
-        Some(n) => n.to_string().to_lowercase(),
-        None => "".to_string(),
-    };
-
-    try!(writer.write_all(format!("NAME\t= {}\n", short_name).as_bytes()));
-
-    if r.configs.metadata.len() > 0 {
-        try!(writer.write_all(format!("DESCRIPTION\t= {}\n", r.configs.metadata[0]).as_bytes()));
-    } else {
-        try!(writer.write_all("DESCRIPTION\t=\n".as_bytes()));
-    }
-
-    if r.configs.xy_units != "not specified" {
-        try!(writer.write_all(format!("UNIT\t= {}\n", r.configs.xy_units).as_bytes()));
-    } else {
-        try!(writer.write_all("UNIT\t=\n".as_bytes()));
-    }
-
-    try!(writer.write_all("DATAFILE_OFFSET\t= 0\n".as_bytes()));
-
-    match r.configs.data_type {
-        DataType::F64 => {
-            try!(writer.write_all("DATAFORMAT\t= DOUBLE\n".as_bytes()));
-        }
-        DataType::F32 => {
-            try!(writer.write_all("DATAFORMAT\t= FLOAT\n".as_bytes()));
-        }
-        DataType::I32 => {
-            try!(writer.write_all("DATAFORMAT\t= INTEGER\n".as_bytes()));
-        }
-        DataType::U32 => {
-            try!(writer.write_all("DATAFORMAT\t= INTEGER_UNSIGNED\n".as_bytes()));
-        }
-        DataType::I16 => {
-            try!(writer.write_all("DATAFORMAT\t= SHORTINT\n".as_bytes()));
-        }
-        DataType::U16 => {
-            try!(writer.write_all("DATAFORMAT\t= SHORTINT_UNSIGNED\n".as_bytes()));
-        }
-        DataType::U8 => {
-            try!(writer.write_all("DATAFORMAT\t= BYTE_UNSIGNED\n".as_bytes()));
-        }
-        DataType::I8 => {
-            try!(writer.write_all("DATAFORMAT\t= BYTE\n".as_bytes()));
-        }
+    let data_format = match r.configs.data_type {
+        DataType::F64 => "DOUBLE",
+        DataType::F32 => "FLOAT",
+        DataType::I64 => "LONGINT",
+        DataType::U64 => "LONGINT_UNSIGNED",
+        DataType::I32 => "INTEGER",
+        DataType::U32 => "INTEGER_UNSIGNED",
+        DataType::I16 => "SHORTINT",
+        DataType::U16 => "SHORTINT_UNSIGNED",
+        DataType::I8 => "BYTE",
+        DataType::U8 => "BYTE_UNSIGNED",
         _ => {
             return Err(Error::new(
                 ErrorKind::NotFound,
@@ -486,127 +253,125 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 ),
             ));
         }
-    }
+    };
 
-    if r.configs.endian == Endianness::LittleEndian {
-        try!(writer.write_all("BYTEORDER_BIG\t= FALSE\n".as_bytes()));
-    } else {
-        try!(writer.write_all("BYTEORDER_BIG\t= TRUE\n".as_bytes()));
+    // SAGA grids have square cells
+    let res_diff = (r.configs.resolution_x - r.configs.resolution_y).abs();
+    if res_diff > 1e-9 * r.configs.resolution_x.abs() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "SAGA grids must have square cells, but the cells of {} are {} by {}.",
+                r.file_name, r.configs.resolution_x, r.configs.resolution_y
+            ),
+        ));
     }
 
-    try!(writer.write_all(format!("POSITION_XMIN\t= {}\n", r.configs.west).as_bytes()));
-
-    try!(writer.write_all(format!("POSITION_YMIN\t= {}\n", r.configs.south).as_bytes()));
-
-    try!(writer.write_all(format!("CELLCOUNT_X\t= {}\n", r.configs.columns).as_bytes()));
-
-    try!(writer.write_all(format!("CELLCOUNT_Y\t= {}\n", r.configs.rows).as_bytes()));
+    let nodata = if r.configs.nodata.is_nan() {
+        DEFAULT_SAGA_NODATA
+    } else {
+        r.configs.nodata
+    };
 
-    try!(
-        writer.write_all(
-            format!(
-                "CELLSIZE\t= {}\n",
-                (r.configs.resolution_x + r.configs.resolution_y) / 2.0
-            ).as_bytes()
-        )
-    );
+    // Save the header file
+    let header_file = Path::new(&r.file_name).with_extension("sgrd");
+    let f = File::create(&header_file)?;
+    let mut writer = BufWriter::new(f);
 
-    try!(writer.write_all("Z_FACTOR\t= 1.000000\n".as_bytes()));
+    // get the short file NAME
+    let short_name: String = match header_file.file_stem() {
+        Some(n) => n.to_string_lossy().to_string(),
+        None => "".to_string(),
+    };
 
-    try!(writer.write_all(format!("NODATA_VALUE\t= {}\n", r.configs.nodata).as_bytes()));
+    writer.write_all(format!("NAME\t= {}\n", short_name).as_bytes())?;
 
-    try!(writer.write_all("TOPTOBOTTOM\t= FALSE\n".as_bytes()));
+    if r.configs.metadata.len() > 0 {
+        writer.write_all(format!("DESCRIPTION\t= {}\n", r.configs.metadata[0]).as_bytes())?;
+    } else {
+        writer.write_all("DESCRIPTION\t=\n".as_bytes())?;
+    }
 
-    let _ = writer.flush();
+    if r.configs.z_units != "not specified" {
+        writer.write_all(format!("UNIT\t= {}\n", r.configs.z_units).as_bytes())?;
+    } else {
+        writer.write_all("UNIT\t=\n".as_bytes())?;
+    }
 
-    // write the data file
-    let data_file = r.file_name.replace(".sgrd", ".sdat");
-    let f = try!(File::create(&data_file));
+    writer.write_all("DATAFILE_OFFSET\t= 0\n".as_bytes())?;
+    writer.write_all(format!("DATAFORMAT\t= {}\n", data_format).as_bytes())?;
+    writer.write_all("BYTEORDER_BIG\t= FALSE\n".as_bytes())?;
+
+    // the grid is positioned by the centre of the lower-left cell
+    let cell_size = r.configs.resolution_x;
+    writer.write_all(
+        format!("POSITION_XMIN\t= {}\n", r.configs.west + cell_size / 2f64).as_bytes(),
+    )?;
+    writer.write_all(
+        format!("POSITION_YMIN\t= {}\n", r.configs.south + cell_size / 2f64).as_bytes(),
+    )?;
+    writer.write_all(format!("CELLCOUNT_X\t= {}\n", r.configs.columns).as_bytes())?;
+    writer.write_all(format!("CELLCOUNT_Y\t= {}\n", r.configs.rows).as_bytes())?;
+    writer.write_all(format!("CELLSIZE\t= {}\n", cell_size).as_bytes())?;
+    writer.write_all("Z_FACTOR\t= 1.000000\n".as_bytes())?;
+    writer.write_all(format!("NODATA_VALUE\t= {}\n", nodata).as_bytes())?;
+    writer.write_all("TOPTOBOTTOM\t= FALSE\n".as_bytes())?;
+
+    writer.flush()?;
+
+    // write the data file, starting with the bottom row
+    let data_file = Path::new(&r.file_name).with_extension("sdat");
+    let f = File::create(&data_file)?;
     let mut writer = BufWriter::new(f);
 
-    let mut u16_bytes: [u8; 2];
-    let mut u32_bytes: [u8; 4];
-    let mut u64_bytes: [u8; 8];
-    let mut i: usize;
-    match r.configs.data_type {
-        DataType::F64 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    u64_bytes = unsafe { mem::transmute(r.data[i]) };
-                    try!(writer.write(&u64_bytes));
-                }
-            }
-        }
-        DataType::F32 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
-                    try!(writer.write(&u32_bytes));
-                }
-            }
-        }
-        DataType::I32 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as i32) };
-                    try!(writer.write(&u32_bytes));
-                }
-            }
-        }
-        DataType::U32 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as u32) };
-                    try!(writer.write(&u32_bytes));
-                }
-            }
-        }
-        DataType::I16 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    u16_bytes = unsafe { mem::transmute(r.data[i] as i16) };
-                    try!(writer.write(&u16_bytes));
-                }
-            }
-        }
-        DataType::U16 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    u16_bytes = unsafe { mem::transmute(r.data[i] as u16) };
-                    try!(writer.write(&u16_bytes));
-                }
+    let mut value: f64;
+    for row in (0..r.configs.rows).rev() {
+        for col in 0..r.configs.columns {
+            value = r.data[row * r.configs.columns + col];
+            if value.is_nan() || value == r.configs.nodata {
+                value = nodata;
             }
-        }
-        DataType::U8 | DataType::I8 => {
-            for row in 0..r.configs.rows {
-                //(0..r.configs.rows).rev() {
-                for col in 0..r.configs.columns {
-                    i = row * r.configs.columns + col;
-                    try!(writer.write(&[r.data[i] as u8]));
-                }
+            match r.configs.data_type {
+                DataType::F64 => writer.write_f64::<LittleEndian>(value)?,
+                DataType::F32 => writer.write_f32::<LittleEndian>(value as f32)?,
+                DataType::I64 => writer.write_i64::<LittleEndian>(value as i64)?,
+                DataType::U64 => writer.write_u64::<LittleEndian>(value as u64)?,
+                DataType::I32 => writer.write_i32::<LittleEndian>(value as i32)?,
+                DataType::U32 => writer.write_u32::<LittleEndian>(value as u32)?,
+                DataType::I16 => writer.write_i16::<LittleEndian>(value as i16)?,
+                DataType::U16 => writer.write_u16::<LittleEndian>(value as u16)?,
+                DataType::I8 => writer.write_i8(value as i8)?,
+                _ => writer.write_u8(value as u8)?,
             }
         }
-        _ => {
-            return Err(Error::new(
-                ErrorKind::NotFound,
-                "Raster data type is unsupported.",
-            ));
-        }
     }
 
-    let _ = writer.flush();
+    writer.flush()?;
 
     Ok(())
 }
+
+/// Returns the number of bytes used to store each value of a SAGA grid.
+fn saga_value_size(data_type: DataType) -> usize {
+    match data_type {
+        DataType::F64 | DataType::I64 | DataType::U64 => 8,
+        DataType::F32 | DataType::I32 | DataType::U32 => 4,
+        DataType::I16 | DataType::U16 => 2,
+        _ => 1,
+    }
+}
+
+fn parse_header_value(header_file: &Path, key: &str, value: &str) -> Result<f64, Error> {
+    match value.trim().parse::<f64>() {
+        Ok(v) => Ok(v),
+        Err(_) => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Invalid {} value '{}' in the SAGA header file {}.",
+                key,
+                value.trim(),
+                header_file.display()
+            ),
+        )),
+    }
+}
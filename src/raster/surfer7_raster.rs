@@ -91,7 +91,7 @@ pub fn read_surfer7(file_name: &String, configs: &mut RasterConfigs, data: &mut
                     buffer[offset+3], buffer[offset+4], buffer[offset+5], buffer[offset+6], buffer[offset+7]]) };
     offset += 8;
     if rotation_value != 0.0f64 {
-        println!("Warning, non-zero rotation values are not currently supported.");
+        report::warning("Non-zero rotation values are not currently supported.");
     }
 
     configs.nodata =  unsafe { mem::transmute::<[u8; 8], f64>([buffer[offset], buffer[offset+1], buffer[offset+2],
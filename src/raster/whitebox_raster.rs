@@ -7,13 +7,11 @@ use std::io::Error;
 use std::io::ErrorKind;
 use std::io::{BufReader, BufWriter};
 use std::mem;
-use utils::Endianness;
+use std::sync::Arc;
+use utils::{Endianness, MemoryMap};
 
-pub fn read_whitebox(
-    file_name: &String,
-    configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
-) -> Result<(), Error> {
+/// Reads the configurations of a Whitebox raster from its header (.dep) file.
+pub fn read_whitebox_header(file_name: &String, configs: &mut RasterConfigs) -> Result<(), Error> {
     // read the header file
     let header_file = file_name.replace(".tas", ".dep");
     let f = File::open(header_file)?;
@@ -111,6 +109,16 @@ pub fn read_whitebox(
     configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
     configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
 
+    Ok(())
+}
+
+pub fn read_whitebox<D: DecodedValues>(
+    file_name: &String,
+    configs: &mut RasterConfigs,
+    data: &mut D,
+) -> Result<(), Error> {
+    read_whitebox_header(file_name, configs)?;
+
     // read the data file
     let data_file = file_name.replace(".dep", ".tas");
     let mut f = File::open(data_file.clone())?;
@@ -134,6 +142,7 @@ pub fn read_whitebox(
     };
 
     let num_cells = configs.rows * configs.columns;
+    data.reset(num_cells, configs.data_type);
     let buf_size = 1_000_000usize;
     let mut j = 0;
     while j < num_cells {
@@ -151,7 +160,7 @@ pub fn read_whitebox(
             DataType::F64 => {
                 for i in 0..buf_size {
                     offset = i * data_size;
-                    data.push(unsafe {
+                    data.set_value(j, unsafe {
                         mem::transmute::<[u8; 8], f64>([
                             buffer[offset],
                             buffer[offset + 1],
@@ -173,7 +182,7 @@ pub fn read_whitebox(
                 let mut bor = ByteOrderReader::new(buffer, configs.endian);
                 bor.pos = 0;
                 for _ in 0..buf_size {
-                    data.push(bor.read_f32() as f64);
+                    data.set_value(j, bor.read_f32() as f64);
                     j += 1;
                     if j == num_cells {
                         break;
@@ -196,7 +205,7 @@ pub fn read_whitebox(
             DataType::I32 => {
                 for i in 0..buf_size {
                     offset = i * data_size;
-                    data.push(unsafe {
+                    data.set_value(j, unsafe {
                         mem::transmute::<[u8; 4], i32>([
                             buffer[offset],
                             buffer[offset + 1],
@@ -213,7 +222,7 @@ pub fn read_whitebox(
             DataType::I16 => {
                 for i in 0..buf_size {
                     offset = i * data_size;
-                    data.push(unsafe {
+                    data.set_value(j, unsafe {
                         mem::transmute::<[u8; 2], i16>([buffer[offset], buffer[offset + 1]])
                     } as f64);
                     j += 1;
@@ -224,7 +233,7 @@ pub fn read_whitebox(
             }
             DataType::U8 => {
                 for i in 0..buf_size {
-                    data.push(buffer[i] as f64);
+                    data.set_value(j, buffer[i] as f64);
                     j += 1;
                     if j == num_cells {
                         break;
@@ -235,7 +244,7 @@ pub fn read_whitebox(
                 let mut bor = ByteOrderReader::new(buffer, configs.endian);
                 bor.pos = 0;
                 for _ in 0..buf_size {
-                    data.push(bor.read_f32() as i32 as u32 as f64);
+                    data.set_value(j, bor.read_f32() as i32 as u32 as f64);
                     j += 1;
                     if j == num_cells {
                         break;
@@ -278,6 +287,30 @@ pub fn read_whitebox(
     Ok(())
 }
 
+/// Reads a Whitebox raster, mapping the values of its data file into memory rather than
+/// reading them, if they are stored as 64-bit floating-point values. Otherwise, the
+/// values are read into `data` in the usual way and None is returned.
+pub fn read_whitebox_mapped<D: DecodedValues>(
+    file_name: &String,
+    configs: &mut RasterConfigs,
+    data: &mut D,
+) -> Result<Option<RasterData>, Error> {
+    let mut header = configs.clone();
+    read_whitebox_header(file_name, &mut header)?;
+    if header.data_type == DataType::F64 {
+        // the values are stored in the native byte order, as they are read by read_whitebox
+        if let Ok(map) = MemoryMap::open(&file_name.replace(".dep", ".tas")) {
+            let num_cells = header.rows * header.columns;
+            if let Some(values) = RasterData::from_map(Arc::new(map), 0, num_cells) {
+                *configs = header;
+                return Ok(Some(values));
+            }
+        }
+    }
+    read_whitebox(file_name, configs, data)?;
+    Ok(None)
+}
+
 pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
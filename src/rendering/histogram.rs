@@ -37,6 +37,7 @@ impl Histogram {
         let mut s = String::new();
         s.push_str(&format!(r#"
     <script>
+    (function() {{
       var data = {};
       var cumulative = {};
       var xAxisLabel = "{}";
@@ -618,6 +619,7 @@ impl Histogram {
       }
 
       update(null);
+    })();
     </script>"#);
         
         s
@@ -21,6 +21,7 @@ impl LineGraph {
         let mut s = String::new();
         s.push_str(&format!("
     <script>
+    (function() {{
       var plot = {{
         dataX: {},
         dataY: {},
@@ -183,21 +184,21 @@ impl LineGraph {
           font-size: 85%;
           fill: ${showValueClr};
         }
-        #context-menu {
+        .context-menu {
           position:absolute;
           display:none;
         }
-        #context-menu ul {
+        .context-menu ul {
           list-style:none;
           margin:0;
           padding:0;
           background: #EFEFEF;
           opacity: 0.90;
         }
-        #context-menu {
+        .context-menu {
           border:solid 1px #CCC;
         }
-        #context-menu li {
+        .context-menu li {
           font-family:Sans,Arial;
           font-size: 75%;
           text-align: left;
@@ -206,10 +207,10 @@ impl LineGraph {
           padding:5px 15px;
           border-bottom:solid 1px #CCC;
         }
-        #context-menu li:last-child {
+        .context-menu li:last-child {
           border:none;
         }
-        #context-menu li:hover {
+        .context-menu li:hover {
           background:#007AFF;
           color:#FFF;
         }
@@ -269,7 +270,7 @@ impl LineGraph {
         }
         style.innerHTML = styleString;
         svg.appendChild(style);
-        svg.id = "plotSvg";
+        svg.id = `${plot.parentId}Svg`;
 
         // background
         var background = document.createElementNS(svgns, "rect");
@@ -639,7 +640,7 @@ impl LineGraph {
 
         // Add an invisible context menu to the parentId.
         var cm = document.createElement('div');
-        cm.id = 'context-menu';
+        cm.id = `${plot.parentId}ContextMenu`;
         cm.className = 'context-menu';
         var list = document.createElement('ul');
 
@@ -717,12 +718,12 @@ impl LineGraph {
         cm.appendChild(list);
         document.getElementById(plot.parentId).appendChild(cm);
 
-        var menu = document.getElementById('context-menu');
-        document.onclick = function () {
+        var menu = cm;
+        document.addEventListener('click', function () {
             menu.style.display = 'none';
-        };
+        }, false);
 
-        document.getElementById('plotSvg').oncontextmenu = function (evt) {
+        svg.oncontextmenu = function (evt) {
             evt = (evt) ? evt : ((event) ? event : null);
             var posnX = (evt.pageX) ? evt.pageX : ((evt.offsetX) ? evt.offsetX + 10 : null);
             var posnY = (evt.pageY) ? evt.pageY : ((evt.offsetY) ? evt.offsetY + 10 : null);
@@ -749,6 +750,7 @@ impl LineGraph {
       }
 
       update(null);
+    })();
     </script>"#);
         
         s
@@ -21,6 +21,7 @@ impl Scattergram {
         let mut s = String::new();
         s.push_str(&format!("
     <script>
+    (function() {{
       var plot = {{
         dataX: {},
         dataY: {},
@@ -114,7 +115,7 @@ impl Scattergram {
               svg.removeChild(svg.lastChild);
           }
         }
-        svg.id = "plotSvg";
+        svg.id = `${plot.parentId}Svg`;
         svg.setAttribute('width', `${plot.width}`);
         svg.setAttribute('height', `${plot.height}`);
         var div = document.getElementById(plot.parentId);
@@ -165,21 +166,21 @@ impl Scattergram {
           font-size: 85%;
           fill: ${showValueClr};
         }
-        #context-menu {
+        .context-menu {
           position:absolute;
           display:none;
         }
-        #context-menu ul {
+        .context-menu ul {
           list-style:none;
           margin:0;
           padding:0;
           background: #EFEFEF;
           opacity: 0.90;
         }
-        #context-menu {
+        .context-menu {
           border:solid 1px #CCC;
         }
-        #context-menu li {
+        .context-menu li {
           font-family:Sans,Arial;
           font-size: 75%;
           text-align: left;
@@ -188,10 +189,10 @@ impl Scattergram {
           padding:5px 15px;
           border-bottom:solid 1px #CCC;
         }
-        #context-menu li:last-child {
+        .context-menu li:last-child {
           border:none;
         }
-        #context-menu li:hover {
+        .context-menu li:hover {
           background:#007AFF;
           color:#FFF;
         }
@@ -490,7 +491,7 @@ impl Scattergram {
               for (s2 = 0; s2 < numSeries; s2++) {
                 if (s2 != s) {
                   if (plot.drawTrendline) {
-                    document.getElementById(`seriesTrendline${s2}`).style.opacity = deselectedOpacity;
+                    document.getElementById(`${plot.parentId}Trendline${s2}`).style.opacity = deselectedOpacity;
                   }
                   var i;
                   x = document.getElementsByClassName(`dataPoint${s2}`);
@@ -508,7 +509,7 @@ impl Scattergram {
               for (s2 = 0; s2 < numSeries; s2++) {
                 if (s2 != s) {
                   if (plot.drawTrendline) {
-                    document.getElementById(`seriesTrendline${s2}`).style.opacity = 1.0;
+                    document.getElementById(`${plot.parentId}Trendline${s2}`).style.opacity = 1.0;
                   }
                   var i;
                   x = document.getElementsByClassName(`dataPoint${s2}`);
@@ -562,15 +563,15 @@ impl Scattergram {
             line.setAttribute('x2', (maxX - xMin) / xRange * plotWidth);
             yHat = -(((slope * maxX + intercept) - yMin) / yRange * plotHeight);
             line.setAttribute('y2', yHat);
-            line.setAttribute('class', `seriesTrendline${s}`);
-            line.setAttribute('id', `seriesTrendline${s}`);
+            line.setAttribute('class', `${plot.parentId}Trendline${s}`);
+            line.setAttribute('id', `${plot.parentId}Trendline${s}`);
             // line.setAttribute('style', 'stroke:black');
 
             line.addEventListener('mouseover', function() {
               var s2;
               for (s2 = 0; s2 < numSeries; s2++) {
                 if (s2 != s) {
-                  document.getElementById(`seriesTrendline${s2}`).style.opacity = deselectedOpacity;
+                  document.getElementById(`${plot.parentId}Trendline${s2}`).style.opacity = deselectedOpacity;
                   var i;
                   x = document.getElementsByClassName(`dataPoint${s2}`);
                   for (i = 0; i < x.length; i++) {
@@ -584,7 +585,7 @@ impl Scattergram {
               var s2;
               for (s2 = 0; s2 < numSeries; s2++) {
                 if (s2 != s) {
-                  document.getElementById(`seriesTrendline${s2}`).style.opacity = 1.0;
+                  document.getElementById(`${plot.parentId}Trendline${s2}`).style.opacity = 1.0;
                   var i;
                   x = document.getElementsByClassName(`dataPoint${s2}`);
                   for (i = 0; i < x.length; i++) {
@@ -644,7 +645,7 @@ impl Scattergram {
 
         // Add an invisible context menu to the parentId.
         var cm = document.createElement('div');
-        cm.id = 'context-menu';
+        cm.id = `${plot.parentId}ContextMenu`;
         cm.className = 'context-menu';
         var list = document.createElement('ul');
 
@@ -722,12 +723,12 @@ impl Scattergram {
         cm.appendChild(list);
         document.getElementById(plot.parentId).appendChild(cm);
 
-        var menu = document.getElementById('context-menu');
-        document.onclick = function () {
+        var menu = cm;
+        document.addEventListener('click', function () {
             menu.style.display = 'none';
-        };
+        }, false);
 
-        document.getElementById('plotSvg').oncontextmenu = function (evt) {
+        svg.oncontextmenu = function (evt) {
             evt = (evt) ? evt : ((event) ? event : null);
             var posnX = (evt.pageX) ? evt.pageX : ((evt.offsetX) ? evt.offsetX + 10 : null);
             var posnY = (evt.pageY) ? evt.pageY : ((evt.offsetY) ? evt.offsetY + 10 : null);
@@ -754,6 +755,7 @@ impl Scattergram {
       }
 
       update(null);
+    })();
     </script>"#);
         
         s
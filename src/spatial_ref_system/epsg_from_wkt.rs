@@ -0,0 +1,52 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+/// Returns the EPSG code of a coordinate reference system from the top-level
+/// AUTHORITY["EPSG",code] element of its WKT definition, or zero if there is none.
+pub fn epsg_from_wkt(wkt: &str) -> u16 {
+    let upper = wkt.to_uppercase();
+    let bytes = upper.as_bytes();
+    let mut depth = 0isize;
+    let mut i = 0usize;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'[' | b'(' => depth += 1,
+            b']' | b')' => depth -= 1,
+            b'A' if depth == 1 && upper[i..].starts_with("AUTHORITY[") => {
+                let start = i + "AUTHORITY[".len();
+                let end = match upper[start..].find(']') {
+                    Some(e) => start + e,
+                    None => return 0u16,
+                };
+                let parts: Vec<&str> = upper[start..end].split(',').collect();
+                if parts.len() == 2 && parts[0].trim().trim_matches('"') == "EPSG" {
+                    return parts[1].trim().trim_matches('"').parse::<u16>().unwrap_or(0u16);
+                }
+                return 0u16;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    0u16
+}
+
+#[cfg(test)]
+mod test {
+    use super::epsg_from_wkt;
+
+    #[test]
+    fn test_epsg_from_wkt() {
+        let wkt = "PROJCS[\"NAD_1983_UTM_Zone_17N\",GEOGCS[\"GCS_North_American_1983\",DATUM[\"D_North_American_1983\",SPHEROID[\"GRS_1980\",6378137.0,298.257222101]],PRIMEM[\"Greenwich\",0.0],UNIT[\"Degree\",0.0174532925199433],AUTHORITY[\"EPSG\",4269]],PROJECTION[\"Transverse_Mercator\"],UNIT[\"Meter\",1.0],AUTHORITY[\"EPSG\",26917]]";
+        assert_eq!(epsg_from_wkt(wkt), 26917);
+        let wkt = "PROJCS[\"Custom\",GEOGCS[\"GCS_WGS_1984\",AUTHORITY[\"EPSG\",\"4326\"]],UNIT[\"Meter\",1.0]]";
+        assert_eq!(epsg_from_wkt(wkt), 0);
+        assert_eq!(epsg_from_wkt("GEOGCS[\"WGS 84\",AUTHORITY[\"EPSG\",\"4326\"]]"), 4326);
+        assert_eq!(epsg_from_wkt("not specified"), 0);
+    }
+}
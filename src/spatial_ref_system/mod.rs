@@ -1,3 +1,5 @@
+mod epsg_from_wkt;
 mod epsg_to_wkt;
 
+pub use self::epsg_from_wkt::epsg_from_wkt;
 pub use self::epsg_to_wkt::esri_wkt_from_epsg;
@@ -0,0 +1,125 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::io::{Error, ErrorKind};
+
+/// A 2-dimensional array of boolean values, packed 64 to a word, for the visited and
+/// flag grids of flood-fill style algorithms, which would otherwise use a byte or more
+/// per grid cell. As with `Array2D`, cells may be addressed beyond the extent of the
+/// array, where they have the value `nodata` and cannot be set. Because the values are
+/// packed, they are accessed with `get_value` and `set_value` rather than by indexing.
+///
+/// ## Example
+///     let mut visited = BitArray2D::new(rows, columns, false, true)?;
+///     visited.set_value(row, col, true);
+///     assert!(visited.get_value(row, col));
+///     assert!(visited.get_value(-1, col)); // outside of the array
+#[derive(Debug, Clone)]
+pub struct BitArray2D {
+    pub columns: isize,
+    pub rows: isize,
+    data: Vec<u64>,
+    pub nodata: bool,
+}
+
+impl BitArray2D {
+    pub fn new(
+        rows: isize,
+        columns: isize,
+        initial_value: bool,
+        nodata: bool,
+    ) -> Result<BitArray2D, Error> {
+        if rows < 0 || columns < 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Only non-negative rows and columns values accepted.",
+            ));
+        }
+        let num_words = ((rows * columns) as usize + 63) / 64;
+        let word = if initial_value { !0u64 } else { 0u64 };
+        Ok(BitArray2D {
+            columns: columns,
+            rows: rows,
+            data: vec![word; num_words],
+            nodata: nodata,
+        })
+    }
+
+    pub fn set_value(&mut self, row: isize, column: isize, value: bool) {
+        if row >= 0 && column >= 0 && row < self.rows && column < self.columns {
+            let idx = (row * self.columns + column) as usize;
+            if value {
+                self.data[idx / 64] |= 1u64 << (idx % 64);
+            } else {
+                self.data[idx / 64] &= !(1u64 << (idx % 64));
+            }
+        }
+    }
+
+    pub fn get_value(&self, row: isize, column: isize) -> bool {
+        if row < 0 || column < 0 || row >= self.rows || column >= self.columns {
+            return self.nodata;
+        }
+        let idx = (row * self.columns + column) as usize;
+        self.data[idx / 64] & (1u64 << (idx % 64)) != 0
+    }
+
+    /// Sets every cell of the array to `value`.
+    pub fn reinitialize_values(&mut self, value: bool) {
+        let word = if value { !0u64 } else { 0u64 };
+        for w in self.data.iter_mut() {
+            *w = word;
+        }
+    }
+
+    pub fn columns(&self) -> isize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> isize {
+        self.rows
+    }
+
+    pub fn nodata(&self) -> bool {
+        self.nodata
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BitArray2D;
+
+    #[test]
+    fn test_bit_array2d_values() {
+        let mut a = BitArray2D::new(3, 50, false, true).unwrap();
+        a.set_value(1, 20, true); // bit 70, in the second word
+        a.set_value(2, 49, true); // the last cell
+        a.set_value(0, 0, true);
+        a.set_value(0, 0, false);
+        for row in 0..3 {
+            for col in 0..50 {
+                let expected = (row, col) == (1, 20) || (row, col) == (2, 49);
+                assert_eq!(a.get_value(row, col), expected);
+            }
+        }
+        a.reinitialize_values(true);
+        assert!(a.get_value(0, 0) && a.get_value(2, 49));
+    }
+
+    #[test]
+    fn test_bit_array2d_outside() {
+        let mut a = BitArray2D::new(2, 2, false, true).unwrap();
+        a.set_value(-1, 0, false);
+        a.set_value(0, 2, false);
+        assert!(a.get_value(-1, 0));
+        assert!(a.get_value(0, 2));
+        assert!(a.get_value(2, 0));
+        assert!(!a.get_value(1, 1));
+        assert!(BitArray2D::new(-1, 2, false, false).is_err());
+    }
+}
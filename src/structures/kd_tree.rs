@@ -0,0 +1,388 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::f64;
+use structures::DistanceMetric;
+
+/// A k-d tree spatial index of 2-D or 3-D (or higher-dimensional) points, each holding a
+/// value of type T, e.g. the index of a point within a LiDAR file or a record number of
+/// a vector file. The tree is built once from all of its points, and is balanced, with
+/// each node splitting its points at their median along the dimension of greatest
+/// spread. Unlike `FixedRadiusSearch2D` and `FixedRadiusSearch3D`, which bin points
+/// using a fixed radius, the tree supports searches of any radius and exact k-nearest
+/// neighbour searches, and its performance does not depend on the density of points.
+/// The tree is read-only once built and may be shared among threads within an `Arc`.
+///
+/// Distances are Euclidean or squared Euclidean, according to the tree's `DistanceMetric`.
+///
+/// ## Example
+///     // points (x, y) are given as a flat list of coordinates
+///     let coords = vec![40.0, 32.0, 25.0, 65.0, 42.0, 35.0];
+///     let tree = KdTree::new(2, coords, vec![1, 2, 3], DistanceMetric::Euclidean);
+///     let near = tree.radius_search(&[41.0, 33.0], 2.5); // [(1, 1.414..), (3, 2.236..)]
+///     let nearest = tree.knn_search(&[26.0, 64.0], 1); // [(2, 1.414..)]
+pub struct KdTree<T: Copy> {
+    dimensions: usize,
+    // the coordinates and values of the points, in tree order, such that the node of each
+    // subtree, which spans a range of points, is the point at the middle of its range
+    coords: Vec<f64>,
+    values: Vec<T>,
+    split_dims: Vec<u8>,
+    is_distance_squared: bool,
+}
+
+impl<T: Copy> KdTree<T> {
+    /// Builds a tree of `dimensions`-dimensional points, the coordinates of which are given
+    /// point by point in `coordinates`, e.g. x1, y1, x2, y2, ... for 2-D points, and whose
+    /// values are given by `values`. Panics if the numbers of coordinates and values differ.
+    pub fn new(
+        dimensions: usize,
+        coordinates: Vec<f64>,
+        values: Vec<T>,
+        metric: DistanceMetric,
+    ) -> KdTree<T> {
+        assert!(dimensions > 0 && dimensions <= 255);
+        assert_eq!(coordinates.len(), dimensions * values.len());
+        let n = values.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        let mut split_dims = vec![0u8; n];
+        build(&coordinates, dimensions, &mut order, 0, &mut split_dims);
+        let mut coords = Vec::with_capacity(coordinates.len());
+        let mut tree_values = Vec::with_capacity(n);
+        for &i in &order {
+            coords.extend_from_slice(&coordinates[i * dimensions..(i + 1) * dimensions]);
+            tree_values.push(values[i]);
+        }
+        KdTree {
+            dimensions: dimensions,
+            coords: coords,
+            values: tree_values,
+            split_dims: split_dims,
+            is_distance_squared: match metric {
+                DistanceMetric::Euclidean => false,
+                DistanceMetric::SquaredEuclidean => true,
+            },
+        }
+    }
+
+    /// Returns the value and distance of every point within `radius` of `point`, in no
+    /// particular order.
+    pub fn radius_search(&self, point: &[f64], radius: f64) -> Vec<(T, f64)> {
+        let mut ret = vec![];
+        self.search_within(point, radius * radius, 0, self.values.len(), &mut ret);
+        if !self.is_distance_squared {
+            for r in ret.iter_mut() {
+                r.1 = r.1.sqrt();
+            }
+        }
+        ret
+    }
+
+    /// Returns the values and distances of the `k` points nearest to `point`, ordered from
+    /// the nearest to the farthest. Fewer than `k` points are returned if the tree holds
+    /// fewer than `k` points.
+    pub fn knn_search(&self, point: &[f64], k: usize) -> Vec<(T, f64)> {
+        if k == 0 {
+            return vec![];
+        }
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search_nearest(point, k, 0, self.values.len(), &mut heap);
+        let mut ret: Vec<(T, f64)> = heap
+            .into_sorted_vec()
+            .iter()
+            .map(|n| (self.values[n.index], n.dist))
+            .collect();
+        if !self.is_distance_squared {
+            for r in ret.iter_mut() {
+                r.1 = r.1.sqrt();
+            }
+        }
+        ret
+    }
+
+    /// Returns the value and distance of the point nearest to `point`, or None if the tree
+    /// is empty.
+    pub fn nearest(&self, point: &[f64]) -> Option<(T, f64)> {
+        self.knn_search(point, 1).pop()
+    }
+
+    pub fn set_distance_metric(&mut self, metric: DistanceMetric) {
+        match metric {
+            DistanceMetric::Euclidean => self.is_distance_squared = false,
+            DistanceMetric::SquaredEuclidean => self.is_distance_squared = true,
+        }
+    }
+
+    pub fn get_distance_metric(&self) -> DistanceMetric {
+        match self.is_distance_squared {
+            false => DistanceMetric::Euclidean,
+            true => DistanceMetric::SquaredEuclidean,
+        }
+    }
+
+    /// Returns the number of points in the tree.
+    pub fn size(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn sqr_dist(&self, point: &[f64], index: usize) -> f64 {
+        let p = &self.coords[index * self.dimensions..(index + 1) * self.dimensions];
+        let mut dist = 0f64;
+        for d in 0..self.dimensions {
+            dist += (point[d] - p[d]) * (point[d] - p[d]);
+        }
+        dist
+    }
+
+    fn search_within(
+        &self,
+        point: &[f64],
+        r_sqr: f64,
+        lo: usize,
+        hi: usize,
+        ret: &mut Vec<(T, f64)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let dist = self.sqr_dist(point, mid);
+        if dist <= r_sqr {
+            ret.push((self.values[mid], dist));
+        }
+        let dim = self.split_dims[mid] as usize;
+        let diff = point[dim] - self.coords[mid * self.dimensions + dim];
+        if diff <= 0f64 || diff * diff <= r_sqr {
+            self.search_within(point, r_sqr, lo, mid, ret);
+        }
+        if diff >= 0f64 || diff * diff <= r_sqr {
+            self.search_within(point, r_sqr, mid + 1, hi, ret);
+        }
+    }
+
+    fn search_nearest(
+        &self,
+        point: &[f64],
+        k: usize,
+        lo: usize,
+        hi: usize,
+        heap: &mut BinaryHeap<Neighbour>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let dist = self.sqr_dist(point, mid);
+        if heap.len() < k {
+            heap.push(Neighbour {
+                index: mid,
+                dist: dist,
+            });
+        } else if dist < heap.peek().unwrap().dist {
+            heap.pop();
+            heap.push(Neighbour {
+                index: mid,
+                dist: dist,
+            });
+        }
+        let dim = self.split_dims[mid] as usize;
+        let diff = point[dim] - self.coords[mid * self.dimensions + dim];
+        // search the side of the split containing the point first
+        let (first, second) = if diff < 0f64 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.search_nearest(point, k, first.0, first.1, heap);
+        if heap.len() < k || diff * diff < heap.peek().unwrap().dist {
+            self.search_nearest(point, k, second.0, second.1, heap);
+        }
+    }
+}
+
+/// Arranges the points of `order` such that the middle point is the median along the
+/// dimension of greatest spread, with points that are no greater before it and points
+/// that are no less after it, and then arranges each half in the same way.
+fn build(coords: &[f64], dimensions: usize, order: &mut [usize], offset: usize, split: &mut [u8]) {
+    if order.len() <= 1 {
+        return;
+    }
+    let mut dim = 0;
+    let mut max_spread = f64::NEG_INFINITY;
+    for d in 0..dimensions {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for &i in order.iter() {
+            let v = coords[i * dimensions + d];
+            min = min.min(v);
+            max = max.max(v);
+        }
+        if max - min > max_spread {
+            max_spread = max - min;
+            dim = d;
+        }
+    }
+    let mid = order.len() / 2;
+    select_nth(order, mid, |i| coords[i * dimensions + dim]);
+    split[offset + mid] = dim as u8;
+    let (left, right) = order.split_at_mut(mid);
+    build(coords, dimensions, left, offset, split);
+    build(coords, dimensions, &mut right[1..], offset + mid + 1, split);
+}
+
+/// Partially sorts `items` by `key` such that the item at `n` is the one that would be
+/// there were the items sorted (quickselect).
+fn select_nth<F: Fn(usize) -> f64>(items: &mut [usize], n: usize, key: F) {
+    let (mut lo, mut hi) = (0, items.len() - 1);
+    while lo < hi {
+        // median-of-three pivot, moved to hi
+        let mid = lo + (hi - lo) / 2;
+        if key(items[mid]) < key(items[lo]) {
+            items.swap(mid, lo);
+        }
+        if key(items[hi]) < key(items[lo]) {
+            items.swap(hi, lo);
+        }
+        if key(items[mid]) < key(items[hi]) {
+            items.swap(mid, hi);
+        }
+        let pivot = key(items[hi]);
+        let mut store = lo;
+        for i in lo..hi {
+            if key(items[i]) < pivot {
+                items.swap(i, store);
+                store += 1;
+            }
+        }
+        items.swap(store, hi);
+        if store == n {
+            return;
+        } else if store < n {
+            lo = store + 1;
+        } else {
+            hi = store - 1;
+        }
+    }
+}
+
+/// A candidate neighbour in a k-nearest neighbour search; the heap of candidates is
+/// ordered by distance such that the farthest is at its top.
+#[derive(Clone, Copy)]
+struct Neighbour {
+    index: usize,
+    dist: f64,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Neighbour) -> bool {
+        self.dist == other.dist && self.index == other.index
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Neighbour) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Neighbour) -> Ordering {
+        self.dist
+            .partial_cmp(&other.dist)
+            .unwrap_or(Ordering::Equal)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::KdTree;
+    use structures::DistanceMetric;
+
+    fn brute_force(coords: &[f64], dims: usize, point: &[f64]) -> Vec<(usize, f64)> {
+        let mut d: Vec<(usize, f64)> = (0..coords.len() / dims)
+            .map(|i| {
+                let mut dist = 0f64;
+                for j in 0..dims {
+                    dist += (coords[i * dims + j] - point[j]).powi(2);
+                }
+                (i, dist)
+            })
+            .collect();
+        d.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+        d
+    }
+
+    fn test_points(n: usize, dims: usize) -> Vec<f64> {
+        // a deterministic scattering of points, including duplicates
+        (0..n * dims)
+            .map(|i| ((i * 7919 + 13) % 1009) as f64 / 10.0 + (i % 3) as f64)
+            .collect()
+    }
+
+    #[test]
+    fn test_kd_tree_radius_search() {
+        let coords = vec![40.0, 32.0, 25.0, 65.0, 42.0, 35.0, 40.0, 31.0, 24.0, 68.0];
+        let tree = KdTree::new(2, coords, vec![1, 2, 3, 4, 5], DistanceMetric::SquaredEuclidean);
+        let mut s = tree.radius_search(&[40.5, 31.5], 0.75);
+        s.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(s, vec![(1, 0.5), (4, 0.5)]);
+        assert!(tree.radius_search(&[0.0, 0.0], 10.0).is_empty());
+
+        for &dims in &[2usize, 3] {
+            let coords = test_points(500, dims);
+            let values: Vec<usize> = (0..500).collect();
+            let tree = KdTree::new(dims, coords.clone(), values, DistanceMetric::Euclidean);
+            let point = vec![50.0; dims];
+            let mut found: Vec<usize> =
+                tree.radius_search(&point, 25.0).iter().map(|r| r.0).collect();
+            found.sort();
+            let expected: Vec<usize> = brute_force(&coords, dims, &point)
+                .iter()
+                .filter(|r| r.1 <= 625.0)
+                .map(|r| r.0)
+                .collect::<Vec<usize>>();
+            let mut expected = expected;
+            expected.sort();
+            assert!(!expected.is_empty());
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_knn_search() {
+        for &dims in &[2usize, 3] {
+            let coords = test_points(800, dims);
+            let values: Vec<usize> = (0..800).collect();
+            let tree = KdTree::new(dims, coords.clone(), values, DistanceMetric::SquaredEuclidean);
+            for q in 0..5 {
+                let point: Vec<f64> = (0..dims).map(|d| (q * 23 + d * 11) as f64).collect();
+                let knn = tree.knn_search(&point, 10);
+                let expected = brute_force(&coords, dims, &point);
+                assert_eq!(knn.len(), 10);
+                for i in 0..10 {
+                    // ties may be returned in either order, but the distances must match
+                    assert_eq!(knn[i].1, expected[i].1);
+                }
+                assert_eq!(tree.nearest(&point).unwrap().1, expected[0].1);
+            }
+        }
+        let tree: KdTree<usize> = KdTree::new(2, vec![], vec![], DistanceMetric::Euclidean);
+        assert!(tree.nearest(&[0.0, 0.0]).is_none());
+        let tree = KdTree::new(2, vec![1.0, 1.0], vec![7], DistanceMetric::Euclidean);
+        assert_eq!(tree.knn_search(&[4.0, 5.0], 3), vec![(7, 5.0)]);
+    }
+}
@@ -0,0 +1,322 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use structures::Point2D;
+
+/// A directed network of line features, such as a vector stream network, in which
+/// each feature flows from a starting node to an ending node. Nodes are identified
+/// either by matching feature end-points or from user-supplied node identifiers.
+#[derive(Default, Clone, Debug)]
+pub struct LineNetwork {
+    pub num_nodes: usize,
+    pub from_node: Vec<usize>,
+    pub to_node: Vec<usize>,
+    pub node_points: Vec<Point2D>,
+    outflowing: Vec<Vec<usize>>,
+    inflowing: Vec<Vec<usize>>,
+}
+
+impl LineNetwork {
+    /// Builds a network from the first (`start_points`) and last (`end_points`) vertices
+    /// of each feature. End-points that are within `snap_distance` of an existing node
+    /// are assigned to that node; a `snap_distance` of zero requires exact matches.
+    /// Non-finite end-points are each assigned their own, unconnected node.
+    pub fn from_endpoints(
+        start_points: &[Point2D],
+        end_points: &[Point2D],
+        snap_distance: f64,
+    ) -> LineNetwork {
+        let mut node_points: Vec<Point2D> = vec![];
+        let mut exact: HashMap<(u64, u64), usize> = HashMap::new();
+        let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        let mut find_node = |p: Point2D, node_points: &mut Vec<Point2D>| -> usize {
+            if !p.x.is_finite() || !p.y.is_finite() {
+                // e.g. null shapes; these are isolated from the rest of the network
+                node_points.push(p);
+                return node_points.len() - 1;
+            }
+            if snap_distance <= 0f64 {
+                return match exact.entry((p.x.to_bits(), p.y.to_bits())) {
+                    Occupied(entry) => *entry.get(),
+                    Vacant(entry) => {
+                        node_points.push(p);
+                        *entry.insert(node_points.len() - 1)
+                    }
+                };
+            }
+            let i = (p.x / snap_distance).floor() as i64;
+            let j = (p.y / snap_distance).floor() as i64;
+            let mut nearest = None;
+            let mut min_dist = snap_distance;
+            for a in -1..2 {
+                for b in -1..2 {
+                    if let Some(nodes) = grid.get(&(i + a, j + b)) {
+                        for &n in nodes {
+                            let dist = p.distance(&node_points[n]);
+                            if dist <= min_dist {
+                                min_dist = dist;
+                                nearest = Some(n);
+                            }
+                        }
+                    }
+                }
+            }
+            match nearest {
+                Some(n) => n,
+                None => {
+                    node_points.push(p);
+                    let n = node_points.len() - 1;
+                    grid.entry((i, j)).or_insert(vec![]).push(n);
+                    n
+                }
+            }
+        };
+
+        let mut from_node = Vec::with_capacity(start_points.len());
+        let mut to_node = Vec::with_capacity(end_points.len());
+        for i in 0..start_points.len() {
+            from_node.push(find_node(start_points[i], &mut node_points));
+            to_node.push(find_node(end_points[i], &mut node_points));
+        }
+        let mut network = LineNetwork::build(from_node, to_node, node_points.len());
+        network.node_points = node_points;
+        network
+    }
+
+    /// Builds a network from node identifiers, e.g. the from- and to-node attributes
+    /// of a hydrography dataset.
+    pub fn from_node_ids<T: Eq + Hash + Clone>(from_ids: &[T], to_ids: &[T]) -> LineNetwork {
+        let mut ids: HashMap<T, usize> = HashMap::new();
+        let mut from_node = Vec::with_capacity(from_ids.len());
+        let mut to_node = Vec::with_capacity(to_ids.len());
+        for i in 0..from_ids.len() {
+            let n = ids.len();
+            from_node.push(*ids.entry(from_ids[i].clone()).or_insert(n));
+            let n = ids.len();
+            to_node.push(*ids.entry(to_ids[i].clone()).or_insert(n));
+        }
+        let num_nodes = ids.len();
+        LineNetwork::build(from_node, to_node, num_nodes)
+    }
+
+    fn build(from_node: Vec<usize>, to_node: Vec<usize>, num_nodes: usize) -> LineNetwork {
+        let mut outflowing = vec![vec![]; num_nodes];
+        let mut inflowing = vec![vec![]; num_nodes];
+        for i in 0..from_node.len() {
+            outflowing[from_node[i]].push(i);
+            inflowing[to_node[i]].push(i);
+        }
+        LineNetwork {
+            num_nodes: num_nodes,
+            from_node: from_node,
+            to_node: to_node,
+            node_points: vec![],
+            outflowing: outflowing,
+            inflowing: inflowing,
+        }
+    }
+
+    /// Returns the number of features in the network.
+    pub fn num_features(&self) -> usize {
+        self.from_node.len()
+    }
+
+    /// Returns the features that begin at a node.
+    pub fn features_leaving(&self, node: usize) -> &[usize] {
+        &self.outflowing[node]
+    }
+
+    /// Returns the features that end at a node.
+    pub fn features_entering(&self, node: usize) -> &[usize] {
+        &self.inflowing[node]
+    }
+
+    /// Returns the features immediately upstream of a feature.
+    pub fn upstream_neighbours(&self, feature: usize) -> &[usize] {
+        &self.inflowing[self.from_node[feature]]
+    }
+
+    /// Returns the features immediately downstream of a feature.
+    pub fn downstream_neighbours(&self, feature: usize) -> &[usize] {
+        &self.outflowing[self.to_node[feature]]
+    }
+
+    /// Returns the sorted list of features that are upstream of, or equal to, any of
+    /// the `features`.
+    pub fn upstream(&self, features: &[usize]) -> Vec<usize> {
+        self.trace(features, true, false)
+    }
+
+    /// Returns the sorted list of features that are downstream of, or equal to, any of
+    /// the `features`.
+    pub fn downstream(&self, features: &[usize]) -> Vec<usize> {
+        self.trace(features, false, true)
+    }
+
+    /// Returns the sorted list of features that are connected to any of the `features`,
+    /// regardless of flow direction.
+    pub fn connected(&self, features: &[usize]) -> Vec<usize> {
+        self.trace(features, true, true)
+    }
+
+    fn trace(&self, features: &[usize], upstream: bool, downstream: bool) -> Vec<usize> {
+        let mut visited = vec![false; self.num_features()];
+        let mut stack = vec![];
+        for &f in features {
+            if f < visited.len() && !visited[f] {
+                visited[f] = true;
+                stack.push(f);
+            }
+        }
+        while let Some(f) = stack.pop() {
+            let mut nodes = vec![];
+            if upstream {
+                nodes.push(self.from_node[f]);
+            }
+            if downstream {
+                nodes.push(self.to_node[f]);
+            }
+            for n in nodes {
+                for list in &[&self.inflowing[n], &self.outflowing[n]] {
+                    for &f2 in list.iter() {
+                        if visited[f2] {
+                            continue;
+                        }
+                        // only follow features in the direction of the trace
+                        let in_direction = if upstream && downstream {
+                            true
+                        } else if upstream {
+                            self.to_node[f2] == n
+                        } else {
+                            self.from_node[f2] == n
+                        };
+                        if in_direction {
+                            visited[f2] = true;
+                            stack.push(f2);
+                        }
+                    }
+                }
+            }
+        }
+        (0..visited.len()).filter(|&f| visited[f]).collect()
+    }
+
+    /// Returns the number of feature ends at a node, i.e. the node's valence or degree.
+    /// A feature that both begins and ends at the node is counted twice.
+    pub fn node_valence(&self, node: usize) -> usize {
+        self.outflowing[node].len() + self.inflowing[node].len()
+    }
+
+    /// Returns the connected component, numbered from zero in the order of the features,
+    /// that each feature belongs to, and the number of components. Features belong to the
+    /// same component if they are connected, regardless of flow direction.
+    pub fn components(&self) -> (Vec<usize>, usize) {
+        let n = self.num_features();
+        let mut component = vec![usize::max_value(); n];
+        let mut num_components = 0;
+        for f in 0..n {
+            if component[f] != usize::max_value() {
+                continue;
+            }
+            component[f] = num_components;
+            let mut stack = vec![f];
+            while let Some(f1) = stack.pop() {
+                for &node in &[self.from_node[f1], self.to_node[f1]] {
+                    for list in &[&self.inflowing[node], &self.outflowing[node]] {
+                        for &f2 in list.iter() {
+                            if component[f2] == usize::max_value() {
+                                component[f2] = num_components;
+                                stack.push(f2);
+                            }
+                        }
+                    }
+                }
+            }
+            num_components += 1;
+        }
+        (component, num_components)
+    }
+
+    /// Returns the features ordered from upstream to downstream, such that every
+    /// feature is preceded by all of the features that are upstream of it. Features
+    /// that are part of a flow loop cannot be ordered and are excluded.
+    pub fn topological_order(&self) -> Vec<usize> {
+        let n = self.num_features();
+        let mut num_upstream: Vec<usize> =
+            (0..n).map(|f| self.upstream_neighbours(f).len()).collect();
+        let mut queue: VecDeque<usize> = (0..n).filter(|&f| num_upstream[f] == 0).collect();
+        let mut ret = Vec::with_capacity(n);
+        while let Some(f) = queue.pop_front() {
+            ret.push(f);
+            for &f2 in self.downstream_neighbours(f) {
+                num_upstream[f2] -= 1;
+                if num_upstream[f2] == 0 {
+                    queue.push_back(f2);
+                }
+            }
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LineNetwork;
+    use structures::Point2D;
+
+    // A simple Y-shaped network: features 0 and 1 join and flow into feature 2.
+    fn y_network(offset: f64) -> LineNetwork {
+        let start_points = vec![
+            Point2D::new(0.0, 10.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(5.0 + offset, 5.0),
+        ];
+        let end_points = vec![
+            Point2D::new(5.0, 5.0),
+            Point2D::new(5.0, 5.0 + offset),
+            Point2D::new(5.0, 0.0),
+        ];
+        LineNetwork::from_endpoints(&start_points, &end_points, offset * 2.0)
+    }
+
+    #[test]
+    fn test_line_network_from_endpoints() {
+        let network = y_network(0.0);
+        assert_eq!(network.num_nodes, 4);
+        assert_eq!(network.to_node[0], network.from_node[2]);
+        assert_eq!(network.to_node[1], network.from_node[2]);
+        let network = y_network(0.01);
+        assert_eq!(network.num_nodes, 4);
+        assert_eq!(network.upstream_neighbours(2), &[0, 1]);
+    }
+
+    #[test]
+    fn test_line_network_trace() {
+        let network = LineNetwork::from_node_ids(&[1, 2, 3, 5], &[3, 3, 4, 4]);
+        assert_eq!(network.upstream(&[2]), vec![0, 1, 2]);
+        assert_eq!(network.downstream(&[0]), vec![0, 2]);
+        assert_eq!(network.connected(&[0]), vec![0, 1, 2, 3]);
+        let order = network.topological_order();
+        assert_eq!(order.len(), 4);
+        assert!(order.iter().position(|&f| f == 2) > order.iter().position(|&f| f == 1));
+    }
+
+    #[test]
+    fn test_line_network_components() {
+        let network = LineNetwork::from_node_ids(&[1, 2, 3, 6, 8], &[3, 3, 4, 7, 8]);
+        assert_eq!(network.node_valence(1), 3); // node 3
+        assert_eq!(network.node_valence(0), 1);
+        assert_eq!(network.node_valence(6), 2); // a closed loop
+        let (component, num_components) = network.components();
+        assert_eq!(num_components, 3);
+        assert_eq!(component, vec![0, 0, 0, 1, 2]);
+    }
+}
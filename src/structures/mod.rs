@@ -1,20 +1,34 @@
 // private sub-module defined in other files
 mod array2d;
+mod bit_array2d;
 mod bounding_box;
 mod circle;
 mod fixed_radius_search;
+mod kd_tree;
+mod line_network;
 mod line_segment;
 mod n_minimizer;
+mod nibble_array2d;
 mod point2d;
 mod polyline;
+mod r_tree;
+mod summed_area_table;
+mod union_find;
 
 // exports identifiers from private sub-modules in the current module namespace
 pub use self::array2d::Array2D;
+pub use self::bit_array2d::BitArray2D;
 pub use self::bounding_box::BoundingBox;
 pub use self::circle::Circle;
 pub use self::fixed_radius_search::{DistanceMetric, FixedRadiusSearch2D, FixedRadiusSearch3D};
+pub use self::kd_tree::KdTree;
+pub use self::line_network::LineNetwork;
 pub use self::line_segment::LineSegment;
 pub use self::n_minimizer::NMinimizer;
+pub use self::nibble_array2d::NibbleArray2D;
 pub use self::point2d::Direction;
 pub use self::point2d::Point2D;
 pub use self::polyline::Polyline;
+pub use self::r_tree::RTree;
+pub use self::summed_area_table::SummedAreaTable;
+pub use self::union_find::ConcurrentUnionFind;
@@ -0,0 +1,124 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::io::{Error, ErrorKind};
+
+/// A 2-dimensional array of 4-bit unsigned values (0-15), packed two to a byte, e.g. for
+/// grids of D8 flow directions or other small codes that would otherwise use an `i8` per
+/// grid cell. As with `Array2D`, cells may be addressed beyond the extent of the array,
+/// where they have the value `nodata` and cannot be set. Because the values are packed,
+/// they are accessed with `get_value` and `set_value` rather than by indexing.
+///
+/// ## Example
+///     // flow directions 0-7, with 15 marking cells that have none
+///     let mut flow_dir = NibbleArray2D::new(rows, columns, 15, 15)?;
+///     flow_dir.set_value(row, col, 3);
+///     assert_eq!(flow_dir.get_value(row, col), 3);
+#[derive(Debug, Clone)]
+pub struct NibbleArray2D {
+    pub columns: isize,
+    pub rows: isize,
+    data: Vec<u8>,
+    pub nodata: u8,
+}
+
+impl NibbleArray2D {
+    /// Creates a new array. Returns an error if `initial_value` or `nodata` exceed 15.
+    pub fn new(
+        rows: isize,
+        columns: isize,
+        initial_value: u8,
+        nodata: u8,
+    ) -> Result<NibbleArray2D, Error> {
+        if rows < 0 || columns < 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Only non-negative rows and columns values accepted.",
+            ));
+        }
+        if initial_value > 15 || nodata > 15 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The values of a NibbleArray2D must be in the range 0-15.",
+            ));
+        }
+        let num_bytes = ((rows * columns) as usize + 1) / 2;
+        Ok(NibbleArray2D {
+            columns: columns,
+            rows: rows,
+            data: vec![initial_value | (initial_value << 4); num_bytes],
+            nodata: nodata,
+        })
+    }
+
+    /// Sets the value of a cell. Only the lowest four bits of `value` are stored.
+    pub fn set_value(&mut self, row: isize, column: isize, value: u8) {
+        if row >= 0 && column >= 0 && row < self.rows && column < self.columns {
+            let idx = (row * self.columns + column) as usize;
+            let shift = (idx % 2) * 4;
+            let byte = &mut self.data[idx / 2];
+            *byte = (*byte & !(0x0Fu8 << shift)) | ((value & 0x0F) << shift);
+        }
+    }
+
+    pub fn get_value(&self, row: isize, column: isize) -> u8 {
+        if row < 0 || column < 0 || row >= self.rows || column >= self.columns {
+            return self.nodata;
+        }
+        let idx = (row * self.columns + column) as usize;
+        (self.data[idx / 2] >> ((idx % 2) * 4)) & 0x0F
+    }
+
+    pub fn columns(&self) -> isize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> isize {
+        self.rows
+    }
+
+    pub fn nodata(&self) -> u8 {
+        self.nodata
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NibbleArray2D;
+
+    #[test]
+    fn test_nibble_array2d_values() {
+        let mut a = NibbleArray2D::new(3, 5, 15, 15).unwrap();
+        for row in 0..3 {
+            for col in 0..5 {
+                a.set_value(row, col, ((row * 5 + col) % 16) as u8);
+            }
+        }
+        // neighbouring cells share a byte and must not overwrite one another
+        a.set_value(1, 2, 0);
+        for row in 0..3 {
+            for col in 0..5 {
+                let expected = if (row, col) == (1, 2) { 0 } else { (row * 5 + col) % 16 };
+                assert_eq!(a.get_value(row, col), expected as u8);
+            }
+        }
+        a.set_value(0, 0, 0x1F); // only the low four bits are stored
+        assert_eq!(a.get_value(0, 0), 15);
+        assert_eq!(a.get_value(0, 1), 1);
+    }
+
+    #[test]
+    fn test_nibble_array2d_outside() {
+        let mut a = NibbleArray2D::new(2, 3, 0, 9).unwrap();
+        a.set_value(2, 0, 4);
+        assert_eq!(a.get_value(2, 0), 9);
+        assert_eq!(a.get_value(0, -1), 9);
+        assert_eq!(a.get_value(1, 2), 0);
+        assert!(NibbleArray2D::new(2, 3, 16, 0).is_err());
+    }
+}
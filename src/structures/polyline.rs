@@ -6,6 +6,7 @@ Last Modified: 18/10/2018
 License: MIT
 */
 
+use std::f64;
 use std::ops::Index;
 use structures::{BoundingBox, Point2D};
 
@@ -166,6 +167,79 @@ impl Polyline {
     pub fn get_bounding_box(&self) -> BoundingBox {
         BoundingBox::from_points(&self.vertices)
     }
+
+    /// Returns points located at regular `interval` distances along the polyline,
+    /// each paired with its distance from the first vertex. The first and last
+    /// vertices are always included. A non-positive `interval` returns the vertices.
+    pub fn points_at_interval(&self, interval: f64) -> Vec<(f64, Point2D)> {
+        let mut ret = vec![];
+        if self.is_empty() {
+            return ret;
+        }
+        ret.push((0f64, self.vertices[0]));
+        let mut dist = 0f64;
+        let mut next = interval;
+        let mut seg_length: f64;
+        let mut t: f64;
+        for a in 0..self.len() - 1 {
+            seg_length = self[a].distance(&self[a + 1]);
+            if interval > 0f64 && seg_length > 0f64 {
+                while next <= dist + seg_length {
+                    t = (next - dist) / seg_length;
+                    ret.push((
+                        next,
+                        Point2D::new(
+                            self[a].x + t * (self[a + 1].x - self[a].x),
+                            self[a].y + t * (self[a + 1].y - self[a].y),
+                        ),
+                    ));
+                    next += interval;
+                }
+            }
+            dist += seg_length;
+            if interval <= 0f64 {
+                ret.push((dist, self[a + 1]));
+            }
+        }
+        if interval > 0f64 && dist - ret[ret.len() - 1].0 > 1e-10f64 {
+            ret.push((dist, self.last_vertex()));
+        }
+        ret
+    }
+
+    /// Finds the location on the polyline that is nearest to `point`, returning
+    /// a tuple of the distance along the line to this location, measured from the
+    /// first vertex, and the distance between `point` and the line.
+    pub fn nearest_position(&self, point: &Point2D) -> (f64, f64) {
+        let mut along = 0f64;
+        let mut min_dist = f64::INFINITY;
+        let mut dist = 0f64;
+        let (mut dx, mut dy, mut seg_length, mut t): (f64, f64, f64, f64);
+        let mut p: Point2D;
+        if self.len() == 1 {
+            return (0f64, point.distance(&self[0]));
+        }
+        for a in 0..self.len() - 1 {
+            dx = self[a + 1].x - self[a].x;
+            dy = self[a + 1].y - self[a].y;
+            seg_length = (dx * dx + dy * dy).sqrt();
+            t = if seg_length > 0f64 {
+                (((point.x - self[a].x) * dx + (point.y - self[a].y) * dy)
+                    / (seg_length * seg_length))
+                    .max(0f64)
+                    .min(1f64)
+            } else {
+                0f64
+            };
+            p = Point2D::new(self[a].x + t * dx, self[a].y + t * dy);
+            if point.distance(&p) < min_dist {
+                min_dist = point.distance(&p);
+                along = dist + t * seg_length;
+            }
+            dist += seg_length;
+        }
+        (along, min_dist)
+    }
 }
 
 #[cfg(test)]
@@ -202,4 +276,40 @@ mod test {
         ];
         assert_eq!(new_polylines, new_polyline_should_be);
     }
+
+    #[test]
+    fn test_polyline_points_at_interval() {
+        let pl = Polyline::new(
+            &vec![
+                Point2D::new(0.0, 0.0),
+                Point2D::new(10.0, 0.0),
+                Point2D::new(10.0, 5.0),
+            ],
+            1,
+        );
+        let points = pl.points_at_interval(4.0);
+        let points_should_be = vec![
+            (0.0, Point2D::new(0.0, 0.0)),
+            (4.0, Point2D::new(4.0, 0.0)),
+            (8.0, Point2D::new(8.0, 0.0)),
+            (12.0, Point2D::new(10.0, 2.0)),
+            (15.0, Point2D::new(10.0, 5.0)),
+        ];
+        assert_eq!(points, points_should_be);
+    }
+
+    #[test]
+    fn test_polyline_nearest_position() {
+        let pl = Polyline::new(
+            &vec![
+                Point2D::new(0.0, 0.0),
+                Point2D::new(10.0, 0.0),
+                Point2D::new(10.0, 5.0),
+            ],
+            1,
+        );
+        assert_eq!(pl.nearest_position(&Point2D::new(4.0, 3.0)), (4.0, 3.0));
+        assert_eq!(pl.nearest_position(&Point2D::new(12.0, 4.0)), (14.0, 2.0));
+        assert_eq!(pl.nearest_position(&Point2D::new(-3.0, 4.0)), (0.0, 5.0));
+    }
 }
@@ -0,0 +1,326 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use structures::BoundingBox;
+
+const NODE_CAPACITY: usize = 16;
+
+/// An R-tree spatial index of rectangles, e.g. the bounding boxes of the features of a
+/// vector file, each holding a value of type T, such as a record number. The tree is
+/// bulk-loaded once from all of its entries using the Sort-Tile-Recursive (STR) method,
+/// and supports searches for the entries that overlap a box or contain a point, and
+/// nearest-neighbour searches, either by the distance to the entries' rectangles or by an
+/// exact distance to the features that they bound. The tree is read-only once built and
+/// may be shared among threads within an `Arc`.
+///
+/// ## Example
+///     let entries = vec![
+///         (BoundingBox::new(0.0, 10.0, 0.0, 10.0), 0),
+///         (BoundingBox::new(20.0, 30.0, 5.0, 8.0), 1),
+///     ];
+///     let tree = RTree::new(entries);
+///     let found = tree.search(&BoundingBox::new(8.0, 12.0, 8.0, 12.0)); // [0]
+///     let nearest = tree.knn_search(15.0, 6.0, 1); // [(0, 5.0)]
+///     // the nearest feature, by the distance to the feature itself
+///     let nearest = tree.knn_search_by(15.0, 6.0, 1, |_, fid| distance_to(fid, 15.0, 6.0));
+pub struct RTree<T: Copy> {
+    // entries in tree order, such that the entries of each leaf node are contiguous
+    entries: Vec<(BoundingBox, T)>,
+    // nodes from the leaves up, with the root last
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Copy)]
+struct Node {
+    bb: BoundingBox,
+    // the range of entries (for leaf nodes) or nodes (otherwise) that the node holds
+    start: usize,
+    end: usize,
+    is_leaf: bool,
+}
+
+impl<T: Copy> RTree<T> {
+    pub fn new(entries: Vec<(BoundingBox, T)>) -> RTree<T> {
+        let order = str_order(&entries.iter().map(|e| centre(&e.0)).collect::<Vec<_>>());
+        let entries: Vec<(BoundingBox, T)> = order.iter().map(|&i| entries[i]).collect();
+        let mut level: Vec<Node> = (0..entries.len())
+            .step_by(NODE_CAPACITY)
+            .map(|start| {
+                let end = (start + NODE_CAPACITY).min(entries.len());
+                Node {
+                    bb: union(entries[start..end].iter().map(|e| e.0)),
+                    start: start,
+                    end: end,
+                    is_leaf: true,
+                }
+            })
+            .collect();
+        let mut nodes = vec![];
+        while level.len() > 1 {
+            let order = str_order(&level.iter().map(|n| centre(&n.bb)).collect::<Vec<_>>());
+            let base = nodes.len();
+            nodes.extend(order.iter().map(|&i| level[i]));
+            let num_nodes = nodes.len();
+            level = (base..num_nodes)
+                .step_by(NODE_CAPACITY)
+                .map(|start| {
+                    let end = (start + NODE_CAPACITY).min(num_nodes);
+                    Node {
+                        bb: union(nodes[start..end].iter().map(|n| n.bb)),
+                        start: start,
+                        end: end,
+                        is_leaf: false,
+                    }
+                })
+                .collect();
+        }
+        nodes.extend(level);
+        RTree {
+            entries: entries,
+            nodes: nodes,
+        }
+    }
+
+    /// Returns the values of the entries whose rectangles overlap, or touch, `bb`.
+    pub fn search(&self, bb: &BoundingBox) -> Vec<T> {
+        let mut ret = vec![];
+        if self.nodes.is_empty() {
+            return ret;
+        }
+        let mut stack = vec![self.nodes.len() - 1];
+        while let Some(n) = stack.pop() {
+            let node = &self.nodes[n];
+            if !node.bb.overlaps(*bb) {
+                continue;
+            }
+            if node.is_leaf {
+                for e in &self.entries[node.start..node.end] {
+                    if e.0.overlaps(*bb) {
+                        ret.push(e.1);
+                    }
+                }
+            } else {
+                stack.extend(node.start..node.end);
+            }
+        }
+        ret
+    }
+
+    /// Returns the values of the entries whose rectangles contain the point (x, y),
+    /// including entries with the point on their boundaries.
+    pub fn search_point(&self, x: f64, y: f64) -> Vec<T> {
+        self.search(&BoundingBox::new(x, x, y, y))
+    }
+
+    /// Returns the values of the `k` entries whose rectangles are nearest to the point
+    /// (x, y), and the distances to those rectangles, ordered from the nearest to the
+    /// farthest. The distance to a rectangle containing the point is zero.
+    pub fn knn_search(&self, x: f64, y: f64, k: usize) -> Vec<(T, f64)> {
+        self.knn_search_by(x, y, k, |bb, _| box_distance(bb, x, y))
+    }
+
+    /// Returns the values of the `k` entries nearest to the point (x, y), and their
+    /// distances, ordered from the nearest to the farthest, where `distance` gives the
+    /// distance from the point to an entry, e.g. to the feature bounded by its rectangle.
+    /// The distance to an entry must be no less than the distance to its rectangle, which
+    /// allows the distance to be calculated only for entries that may be among the nearest.
+    pub fn knn_search_by<F>(&self, x: f64, y: f64, k: usize, distance: F) -> Vec<(T, f64)>
+    where
+        F: Fn(&BoundingBox, T) -> f64,
+    {
+        let mut ret = vec![];
+        if self.nodes.is_empty() || k == 0 {
+            return ret;
+        }
+        // a best-first search, ordered by the least possible distance of each candidate
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            dist: 0f64,
+            kind: CandidateKind::Node,
+            index: self.nodes.len() - 1,
+        });
+        while let Some(c) = heap.pop() {
+            match c.kind {
+                CandidateKind::Node => {
+                    let node = &self.nodes[c.index];
+                    for i in node.start..node.end {
+                        let (bb, kind) = if node.is_leaf {
+                            (&self.entries[i].0, CandidateKind::Entry)
+                        } else {
+                            (&self.nodes[i].bb, CandidateKind::Node)
+                        };
+                        heap.push(Candidate {
+                            dist: box_distance(bb, x, y),
+                            kind: kind,
+                            index: i,
+                        });
+                    }
+                }
+                CandidateKind::Entry => {
+                    let e = &self.entries[c.index];
+                    heap.push(Candidate {
+                        dist: distance(&e.0, e.1),
+                        kind: CandidateKind::Resolved,
+                        index: c.index,
+                    });
+                }
+                CandidateKind::Resolved => {
+                    ret.push((self.entries[c.index].1, c.dist));
+                    if ret.len() == k {
+                        break;
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    /// Returns the number of entries in the tree.
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Returns the distance from the point (x, y) to the nearest point of `bb`.
+fn box_distance(bb: &BoundingBox, x: f64, y: f64) -> f64 {
+    let dx = (bb.min_x - x).max(x - bb.max_x).max(0f64);
+    let dy = (bb.min_y - y).max(y - bb.max_y).max(0f64);
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn centre(bb: &BoundingBox) -> (f64, f64) {
+    ((bb.min_x + bb.max_x) / 2f64, (bb.min_y + bb.max_y) / 2f64)
+}
+
+fn union<I: Iterator<Item = BoundingBox>>(boxes: I) -> BoundingBox {
+    let mut bb = BoundingBox::default();
+    for b in boxes {
+        bb.expand_to(b);
+    }
+    bb
+}
+
+/// Returns the order in which to place items, given their centres, such that each
+/// consecutive group of `NODE_CAPACITY` items forms a compact tile: the items are sorted
+/// by x into vertical slices of about the square root of the number of groups, and each
+/// slice is sorted by y.
+fn str_order(centres: &[(f64, f64)]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..centres.len()).collect();
+    let num_groups = (centres.len() + NODE_CAPACITY - 1) / NODE_CAPACITY;
+    let num_slices = (num_groups as f64).sqrt().ceil().max(1f64) as usize;
+    let slice_size = ((num_groups + num_slices - 1) / num_slices).max(1) * NODE_CAPACITY;
+    order.sort_by(|&a, &b| cmp_f64(centres[a].0, centres[b].0));
+    for slice in order.chunks_mut(slice_size) {
+        slice.sort_by(|&a, &b| cmp_f64(centres[a].1, centres[b].1));
+    }
+    order
+}
+
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CandidateKind {
+    // ordered such that, at equal distances, entries with known distances are taken first
+    Resolved,
+    Entry,
+    Node,
+}
+
+/// A node or entry in a nearest-neighbour search; the heap of candidates is ordered by
+/// distance such that the nearest is at its top.
+#[derive(Clone, Copy)]
+struct Candidate {
+    dist: f64,
+    kind: CandidateKind,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        cmp_f64(other.dist, self.dist)
+            .then(other.kind.cmp(&self.kind))
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RTree;
+    use structures::BoundingBox;
+
+    fn test_boxes() -> Vec<(BoundingBox, usize)> {
+        // a 20 x 20 grid of unit squares, spaced 2 units apart
+        (0..400)
+            .map(|i| {
+                let (x, y) = ((i % 20) as f64 * 2.0, (i / 20) as f64 * 2.0);
+                (BoundingBox::new(x, x + 1.0, y, y + 1.0), i)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_r_tree_search() {
+        let tree = RTree::new(test_boxes());
+        assert_eq!(tree.size(), 400);
+        let mut found = tree.search(&BoundingBox::new(3.5, 6.0, 0.5, 2.5));
+        found.sort();
+        assert_eq!(found, vec![2, 3, 22, 23]); // including boxes touching the edge
+        assert_eq!(tree.search_point(4.5, 2.5), vec![22]);
+        assert!(tree.search_point(5.5, 2.5).is_empty());
+        assert!(tree.search(&BoundingBox::new(50.0, 60.0, 0.0, 10.0)).is_empty());
+        let mut all = tree.search(&BoundingBox::new(-1.0, 40.0, -1.0, 40.0));
+        all.sort();
+        assert_eq!(all, (0..400).collect::<Vec<usize>>());
+
+        let empty: RTree<usize> = RTree::new(vec![]);
+        assert!(empty.search_point(0.0, 0.0).is_empty());
+        assert!(empty.knn_search(0.0, 0.0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_r_tree_knn_search() {
+        let boxes = test_boxes();
+        let tree = RTree::new(boxes.clone());
+        let knn = tree.knn_search(4.5, 4.5, 1);
+        assert_eq!(knn, vec![(42, 0.0)]);
+        let knn = tree.knn_search(-3.0, 0.5, 2);
+        assert_eq!(knn, vec![(0, 3.0), (20, 1.5f64.hypot(3.0))]);
+
+        // distances to the centres of the boxes, which are never less than to the boxes
+        let centre_dist = |i: usize, x: f64, y: f64| {
+            let bb = boxes[i].0;
+            ((bb.min_x + bb.max_x) / 2.0 - x).hypot((bb.min_y + bb.max_y) / 2.0 - y)
+        };
+        let (x, y) = (17.2, 23.9);
+        let knn = tree.knn_search_by(x, y, 5, |_, i| centre_dist(i, x, y));
+        let mut expected: Vec<f64> = (0..400).map(|i| centre_dist(i, x, y)).collect();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(knn.len(), 5);
+        for i in 0..5 {
+            assert_eq!(knn[i].1, expected[i]);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+/// A summed-area table, or integral image, of a grid of values. The sum, count, mean, and
+/// variance of the valid values within any rectangular window of the grid can be
+/// calculated from four lookups, so that the time needed to evaluate a window does not
+/// depend on its size.
+///
+/// The tables are stored with an extra leading row and column of zeros, such that the
+/// windows that overlap the grid edges are evaluated over their full extent within the
+/// grid. The values are accumulated relative to the first valid value of the grid, which
+/// reduces the loss of precision in the sums of squares used by `variance`.
+pub struct SummedAreaTable {
+    rows: isize,
+    columns: isize,
+    shift: f64,
+    sum: Vec<f64>,
+    sum_sqr: Vec<f64>,
+    count: Vec<u32>,
+}
+
+impl SummedAreaTable {
+    /// Creates the summed-area table of a grid with `rows` rows and `columns` columns,
+    /// where `value` returns the value of a cell, or `None` for cells, e.g. nodata cells,
+    /// that are excluded from the windows. The table of squared values, which is needed
+    /// by `variance` and `std_dev`, is only created if `squares` is true.
+    pub fn new<F>(rows: isize, columns: isize, squares: bool, value: F) -> SummedAreaTable
+    where
+        F: Fn(isize, isize) -> Option<f64>,
+    {
+        let width = columns as usize + 1;
+        let size = (rows as usize + 1) * width;
+        let mut sum = vec![0f64; size];
+        let mut sum_sqr = if squares { vec![0f64; size] } else { vec![] };
+        let mut count = vec![0u32; size];
+        let mut shift: Option<f64> = None;
+        let (mut row_sum, mut row_sum_sqr, mut row_count): (f64, f64, u32);
+        let (mut i, mut above): (usize, usize);
+        for row in 0..rows {
+            row_sum = 0f64;
+            row_sum_sqr = 0f64;
+            row_count = 0;
+            for col in 0..columns {
+                if let Some(mut z) = value(row, col) {
+                    if shift.is_none() {
+                        shift = Some(z);
+                    }
+                    z -= shift.unwrap();
+                    row_sum += z;
+                    row_sum_sqr += z * z;
+                    row_count += 1;
+                }
+                i = (row as usize + 1) * width + col as usize + 1;
+                above = i - width;
+                sum[i] = sum[above] + row_sum;
+                if squares {
+                    sum_sqr[i] = sum_sqr[above] + row_sum_sqr;
+                }
+                count[i] = count[above] + row_count;
+            }
+        }
+
+        SummedAreaTable {
+            rows: rows,
+            columns: columns,
+            shift: shift.unwrap_or(0f64),
+            sum: sum,
+            sum_sqr: sum_sqr,
+            count: count,
+        }
+    }
+
+    pub fn rows(&self) -> isize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> isize {
+        self.columns
+    }
+
+    /// Returns the table indices of the corners of a window, clipped to the grid, or `None`
+    /// if the window lies entirely outside of the grid.
+    fn corners(
+        &self,
+        top: isize,
+        left: isize,
+        bottom: isize,
+        right: isize,
+    ) -> Option<(usize, usize, usize, usize)> {
+        let (top, left) = (top.max(0), left.max(0));
+        let (bottom, right) = (bottom.min(self.rows - 1), right.min(self.columns - 1));
+        if top > bottom || left > right {
+            return None;
+        }
+        let width = self.columns as usize + 1;
+        let (y1, y2) = (top as usize * width, (bottom as usize + 1) * width);
+        let (x1, x2) = (left as usize, right as usize + 1);
+        Some((y1 + x1, y1 + x2, y2 + x1, y2 + x2))
+    }
+
+    fn window_total<T>(table: &[T], corners: (usize, usize, usize, usize)) -> T
+    where
+        T: Copy + ::std::ops::Add<Output = T> + ::std::ops::Sub<Output = T>,
+    {
+        let (top_left, top_right, bottom_left, bottom_right) = corners;
+        table[bottom_right] + table[top_left] - table[top_right] - table[bottom_left]
+    }
+
+    /// Returns the number of valid cells within the window spanning rows `top` to `bottom`
+    /// and columns `left` to `right`, inclusive.
+    pub fn count(&self, top: isize, left: isize, bottom: isize, right: isize) -> usize {
+        match self.corners(top, left, bottom, right) {
+            Some(c) => SummedAreaTable::window_total(&self.count, c) as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns the sum of the valid values within a window.
+    pub fn sum(&self, top: isize, left: isize, bottom: isize, right: isize) -> f64 {
+        match self.corners(top, left, bottom, right) {
+            Some(c) => {
+                let n = SummedAreaTable::window_total(&self.count, c);
+                SummedAreaTable::window_total(&self.sum, c) + n as f64 * self.shift
+            }
+            None => 0f64,
+        }
+    }
+
+    /// Returns the mean of the valid values within a window, or `None` if it contains no
+    /// valid values.
+    pub fn mean(&self, top: isize, left: isize, bottom: isize, right: isize) -> Option<f64> {
+        let c = self.corners(top, left, bottom, right)?;
+        let n = SummedAreaTable::window_total(&self.count, c);
+        if n == 0 {
+            return None;
+        }
+        Some(SummedAreaTable::window_total(&self.sum, c) / n as f64 + self.shift)
+    }
+
+    /// Returns the (population) variance of the valid values within a window, or `None` if
+    /// it contains no valid values or the table was created without squared values.
+    pub fn variance(&self, top: isize, left: isize, bottom: isize, right: isize) -> Option<f64> {
+        if self.sum_sqr.is_empty() {
+            return None;
+        }
+        let c = self.corners(top, left, bottom, right)?;
+        let n = SummedAreaTable::window_total(&self.count, c);
+        if n == 0 {
+            return None;
+        }
+        let n = n as f64;
+        let sum = SummedAreaTable::window_total(&self.sum, c);
+        let sum_sqr = SummedAreaTable::window_total(&self.sum_sqr, c);
+        Some(((sum_sqr - sum * sum / n) / n).max(0f64))
+    }
+
+    /// Returns the (population) standard deviation of the valid values within a window.
+    pub fn std_dev(&self, top: isize, left: isize, bottom: isize, right: isize) -> Option<f64> {
+        self.variance(top, left, bottom, right).map(|v| v.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SummedAreaTable;
+
+    fn test_grid(rows: isize, columns: isize) -> Vec<Option<f64>> {
+        (0..rows * columns)
+            .map(|i| {
+                if i % 7 == 3 {
+                    None
+                } else {
+                    Some(1000f64 + ((i * 37) % 23) as f64 * 0.5)
+                }
+            }).collect()
+    }
+
+    #[test]
+    fn test_summed_area_table_windows() {
+        let (rows, columns) = (9isize, 13isize);
+        let grid = test_grid(rows, columns);
+        let sat =
+            SummedAreaTable::new(rows, columns, true, |r, c| grid[(r * columns + c) as usize]);
+        for &(top, left, bottom, right) in
+            [(0, 0, 0, 0), (-2, -3, 2, 1), (3, 4, 8, 12), (5, 10, 12, 20), (0, 0, 8, 12)].iter()
+        {
+            let mut values = vec![];
+            for r in top.max(0)..(bottom + 1).min(rows) {
+                for c in left.max(0)..(right + 1).min(columns) {
+                    if let Some(z) = grid[(r * columns + c) as usize] {
+                        values.push(z);
+                    }
+                }
+            }
+            let n = values.len() as f64;
+            let sum: f64 = values.iter().sum();
+            let mean = sum / n;
+            let variance = values.iter().map(|z| (z - mean) * (z - mean)).sum::<f64>() / n;
+            assert_eq!(sat.count(top, left, bottom, right), values.len());
+            assert!((sat.sum(top, left, bottom, right) - sum).abs() < 1e-6);
+            assert!((sat.mean(top, left, bottom, right).unwrap() - mean).abs() < 1e-9);
+            assert!((sat.variance(top, left, bottom, right).unwrap() - variance).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_summed_area_table_empty_windows() {
+        let sat = SummedAreaTable::new(4, 4, false, |r, c| if r == c { Some(1f64) } else { None });
+        assert_eq!(sat.count(0, 1, 0, 3), 0);
+        assert_eq!(sat.mean(0, 1, 0, 3), None);
+        assert_eq!(sat.mean(5, 5, 9, 9), None);
+        assert_eq!(sat.sum(-3, -3, 3, 3), 4f64);
+        assert_eq!(sat.variance(0, 0, 3, 3), None);
+    }
+}
@@ -0,0 +1,136 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A disjoint-set (union-find) forest over the elements `0..n` that may be shared
+/// among threads, e.g. within an `Arc`, and updated concurrently without locks. Sets are
+/// always linked so that the root of each set is its smallest element, which makes the
+/// final partition, and the roots that represent its sets, independent of the order in
+/// which the unions are performed. Paths are shortened by path halving during `find`.
+///
+/// ## Example
+///     let sets = ConcurrentUnionFind::new(5);
+///     sets.union(3, 1);
+///     sets.union(4, 3);
+///     assert_eq!(sets.find(4), 1);
+pub struct ConcurrentUnionFind {
+    parent: Vec<AtomicUsize>,
+}
+
+impl ConcurrentUnionFind {
+    /// Creates a new forest in which each of the `n` elements is in its own set.
+    pub fn new(n: usize) -> ConcurrentUnionFind {
+        let mut parent = Vec::with_capacity(n);
+        for i in 0..n {
+            parent.push(AtomicUsize::new(i));
+        }
+        ConcurrentUnionFind { parent: parent }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns the root, i.e. the smallest element, of the set containing `x`. The root
+    /// is only final once all concurrent unions have completed.
+    pub fn find(&self, x: usize) -> usize {
+        let mut x = x;
+        loop {
+            let p = self.parent[x].load(Ordering::Acquire);
+            if p == x {
+                return x;
+            }
+            let gp = self.parent[p].load(Ordering::Acquire);
+            if gp != p {
+                // path halving; if another thread has already updated the link, the
+                // grandparent is an ancestor of its new parent and nothing is lost.
+                let _ = self.parent[x].compare_exchange(
+                    p,
+                    gp,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                );
+            }
+            x = gp;
+        }
+    }
+
+    /// Merges the sets containing `a` and `b`, returning `false` if they were already
+    /// the same set.
+    pub fn union(&self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (a, b);
+        loop {
+            a = self.find(a);
+            b = self.find(b);
+            if a == b {
+                return false;
+            }
+            // the larger root is linked to the smaller one, which prevents cycles
+            let (low, high) = if a < b { (a, b) } else { (b, a) };
+            if self.parent[high]
+                .compare_exchange(high, low, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return true;
+            }
+            // another thread linked the root first; retry from the new roots
+        }
+    }
+
+    /// Returns `true` if `a` and `b` are in the same set.
+    pub fn same_set(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConcurrentUnionFind;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_union_find_sets() {
+        let sets = ConcurrentUnionFind::new(8);
+        assert!(sets.union(5, 2));
+        assert!(sets.union(7, 5));
+        assert!(sets.union(6, 3));
+        assert!(!sets.union(2, 7));
+        assert_eq!(sets.find(7), 2);
+        assert_eq!(sets.find(6), 3);
+        assert!(sets.same_set(5, 7));
+        assert!(!sets.same_set(3, 5));
+        assert_eq!(sets.find(0), 0);
+    }
+
+    #[test]
+    fn test_union_find_concurrent() {
+        // each thread links a different subset of a chain of elements
+        let n = 10000;
+        let sets = Arc::new(ConcurrentUnionFind::new(n));
+        let mut handles = vec![];
+        for tid in 0..4 {
+            let sets = sets.clone();
+            handles.push(thread::spawn(move || {
+                for i in (1..n).filter(|i| i % 4 == tid) {
+                    if i % 100 != 0 {
+                        sets.union(i, i - 1);
+                    }
+                }
+            }));
+        }
+        for h in handles {
+            h.join().unwrap();
+        }
+        for i in 0..n {
+            assert_eq!(sets.find(i), i / 100 * 100);
+        }
+    }
+}
@@ -0,0 +1,489 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! Batch (multi-file) processing for tools with a single raster input.
+//!
+//! When the value of a tool's raster input parameter contains a wildcard pattern
+//! (e.g. `-i="*.tif"` or `--dem="/data/tile_??.tif"`) or a semicolon-separated list of
+//! files, the tool is run once for each matching file, with several files processed
+//! in parallel. The output file name is used as a template; the placeholder `{name}`
+//! is replaced with the base name of each input file (e.g. `-o="{name}_slope.tif"`),
+//! or, if the placeholder is absent, the input base name is appended to the output
+//! base name (e.g. `-o=slope.tif` yields `slope_tile01.tif`, `slope_tile02.tif`, ...).
+
+use num_cpus;
+use serde_json;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{self, Path};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+use tools::{
+    get_output_parameter_flags, is_flag, join_quoted_args, unquote, ToolManager, WhiteboxTool,
+};
+use utils::get_formatted_elapsed_time;
+use utils::report;
+
+/// Returns the input file and argument list of each individual run if `args` requests
+/// a batch run of the tool, or `None` otherwise.
+pub fn get_batch_jobs<'a>(
+    tm: &ToolManager,
+    tool: &Box<WhiteboxTool + 'a>,
+    args: &[String],
+) -> Result<Option<Vec<(String, Vec<String>)>>, Error> {
+    let input_flags = get_raster_input_flags(tool);
+    let output_flags = get_output_parameter_flags(tool);
+    if input_flags.is_empty() || output_flags.is_empty() {
+        return Ok(None);
+    }
+
+    let pairs = get_flag_value_pairs(args);
+    let is_batch_value = |v: &str| v.contains("*") || v.contains("?") || v.contains(";");
+    let mut batch_input = None;
+    let mut output = None;
+    let mut num_outputs = 0;
+    for j in 0..pairs.len() {
+        let flag = pairs[j].0.to_lowercase().replace("--", "-");
+        if let Some(ref v) = pairs[j].1 {
+            if input_flags.contains(&flag) && is_batch_value(v) {
+                if batch_input.is_some() {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Only one input parameter may contain a wildcard pattern or file list.",
+                    ));
+                }
+                batch_input = Some(j);
+            } else if output_flags.contains(&flag) {
+                num_outputs += 1;
+                output = Some(j);
+            }
+        }
+    }
+    let batch_input = match batch_input {
+        Some(j) => j,
+        None => return Ok(None),
+    };
+    if num_outputs > 1 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Batch processing is only supported for tools with a single output file.",
+        ));
+    }
+    let output = match output {
+        Some(j) => j,
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "An output file name template must be specified for batch processing.",
+            ))
+        }
+    };
+
+    let files = expand_file_pattern(pairs[batch_input].1.as_ref().unwrap(), &tm.working_dir)?;
+    if files.is_empty() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "No files match the input pattern '{}'.",
+                pairs[batch_input].1.as_ref().unwrap()
+            ),
+        ));
+    }
+    let template = pairs[output].1.clone().unwrap();
+    let mut jobs = Vec::with_capacity(files.len());
+    let mut output_names: Vec<String> = vec![];
+    for file in files {
+        let stem = match Path::new(&file).file_stem() {
+            Some(s) => s.to_string_lossy().to_string(),
+            None => file.clone(),
+        };
+        let out_file = get_templated_file_name(&template, &stem);
+        if output_names.contains(&out_file) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "More than one input file results in the output file '{}'.",
+                    out_file
+                ),
+            ));
+        }
+        output_names.push(out_file.clone());
+        let mut job_args = vec![];
+        for j in 0..pairs.len() {
+            let val = if j == batch_input {
+                Some(file.clone())
+            } else if j == output {
+                Some(out_file.clone())
+            } else {
+                pairs[j].1.clone()
+            };
+            match val {
+                Some(v) => job_args.push(format!("{}={}", pairs[j].0, v)),
+                None => job_args.push(pairs[j].0.clone()),
+            }
+        }
+        jobs.push((file, job_args));
+    }
+    Ok(Some(jobs))
+}
+
+/// Normalizes a tool's arguments to (flag, value) pairs, such that the arguments can be
+/// modified and then re-assembled in the form `flag=value`. Arguments are tokenized in the
+/// same way as by `ToolArgs`, i.e. quoted values may contain spaces and negative numbers
+/// are values rather than flags.
+pub fn get_flag_value_pairs(args: &[String]) -> Vec<(String, Option<String>)> {
+    let mut pairs: Vec<(String, Option<String>)> = vec![];
+    let mut i = 0;
+    while i < args.len() {
+        let (arg, next) = join_quoted_args(args, i);
+        i = next;
+        if !is_flag(&arg) {
+            // a value without a flag is passed on unchanged
+            pairs.push((arg, None));
+            continue;
+        }
+        let mut parts = arg.splitn(2, '=');
+        let flag = parts.next().unwrap().to_string();
+        let val = match parts.next() {
+            Some(v) => Some(unquote(v)),
+            None if i < args.len() => {
+                let (next_arg, next) = join_quoted_args(args, i);
+                if is_flag(&next_arg) {
+                    None
+                } else {
+                    i = next;
+                    Some(unquote(&next_arg))
+                }
+            }
+            None => None,
+        };
+        pairs.push((flag, val));
+    }
+    pairs
+}
+
+/// Runs a tool once for each job, processing several jobs in parallel.
+pub fn run_batch(
+    tm: &ToolManager,
+    tool_name: String,
+    jobs: Vec<(String, Vec<String>)>,
+) -> Result<(), Error> {
+    let start = Instant::now();
+    let num_jobs = jobs.len();
+    if tm.verbose {
+        report::message(&format!("Batch processing {} files with {}...", num_jobs, tool_name));
+    }
+
+    // Most tools are themselves multi-threaded; a few concurrent jobs keep the
+    // processors busy during the (single-threaded) reading and writing of files.
+    let num_workers = (num_cpus::get() / 2).max(1).min(num_jobs);
+    let jobs = Arc::new(jobs);
+    let next_job = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = mpsc::channel();
+    for _ in 0..num_workers {
+        let jobs = jobs.clone();
+        let next_job = next_job.clone();
+        let tx = tx.clone();
+        let working_dir = tm.working_dir.clone();
+        let tool_name = tool_name.clone();
+        thread::spawn(move || {
+            let tm = ToolManager::new(&working_dir, &false).unwrap();
+            loop {
+                let j = next_job.fetch_add(1, Ordering::SeqCst);
+                if j >= jobs.len() {
+                    break;
+                }
+                let result = match tm.get_tool(&tool_name) {
+                    Some(tool) => tool
+                        .run(jobs[j].1.clone(), &working_dir, false)
+                        .map_err(|e| e.to_string()),
+                    None => Err(format!("Unrecognized tool name {}.", tool_name)),
+                };
+                tx.send((j, result)).unwrap();
+            }
+        });
+    }
+    drop(tx);
+
+    let mut failures = vec![];
+    let mut num_completed = 0;
+    for (j, result) in rx.iter() {
+        num_completed += 1;
+        match result {
+            Ok(()) => {
+                if tm.verbose {
+                    report::message(&format!(
+                        "Processed {} ({} of {})",
+                        jobs[j].0, num_completed, num_jobs
+                    ));
+                }
+            }
+            Err(e) => {
+                if tm.verbose {
+                    report::message(&format!("Error processing {}: {}", jobs[j].0, e));
+                }
+                failures.push(format!("{}: {}", jobs[j].0, e));
+            }
+        }
+        if tm.verbose {
+            report::progress(
+                "Progress",
+                (num_completed as f64 / num_jobs as f64 * 100f64) as usize,
+            );
+        }
+    }
+    if num_completed < num_jobs {
+        // a worker thread panicked
+        failures.push(format!(
+            "{} of {} files were not processed.",
+            num_jobs - num_completed,
+            num_jobs
+        ));
+    }
+
+    if tm.verbose {
+        report::message(&format!(
+            "Elapsed Time (batch of {} files): {}",
+            num_jobs,
+            get_formatted_elapsed_time(start)
+        ));
+    }
+    if !failures.is_empty() {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "Batch processing failed for {} of {} files:\n{}",
+                failures.len(),
+                num_jobs,
+                failures.join("\n")
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the flags of a tool's (single file) raster input parameters.
+fn get_raster_input_flags<'a>(wt: &Box<WhiteboxTool + 'a>) -> Vec<String> {
+    let mut flags = vec![];
+    if let Ok(p) = serde_json::from_str::<serde_json::Value>(&wt.get_tool_parameters()) {
+        if let Some(params) = p["parameters"].as_array() {
+            for param in params {
+                if param["parameter_type"]["ExistingFile"] == "Raster" {
+                    if let Some(f) = param["flags"].as_array() {
+                        for flag in f {
+                            if let Some(flag) = flag.as_str() {
+                                flags.push(flag.to_lowercase().replace("--", "-"));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    flags
+}
+
+/// Expands a file name containing wildcards ('*' and '?') within its final component,
+/// or a semicolon-separated list of file names, into a sorted list of files.
+fn expand_file_pattern(pattern: &str, working_dir: &str) -> Result<Vec<String>, Error> {
+    let sep = path::MAIN_SEPARATOR.to_string();
+    let mut files = vec![];
+    for p in pattern.split(";") {
+        let p = p.trim();
+        if p.is_empty() {
+            continue;
+        }
+        let p = if !p.contains(&sep) && !p.contains("/") {
+            format!("{}{}", working_dir, p)
+        } else {
+            p.to_string()
+        };
+        if !p.contains("*") && !p.contains("?") {
+            files.push(p);
+            continue;
+        }
+        let path = Path::new(&p);
+        let dir = match path.parent() {
+            Some(d) if !d.as_os_str().is_empty() => d.to_path_buf(),
+            _ => Path::new(".").to_path_buf(),
+        };
+        let name_pattern = match path.file_name() {
+            Some(n) => n.to_string_lossy().to_string(),
+            None => continue,
+        };
+        let mut matches = vec![];
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            if wildcard_match(&name_pattern, &name) {
+                matches.push(entry.path().to_string_lossy().to_string());
+            }
+        }
+        matches.sort();
+        files.extend(matches);
+    }
+    Ok(files)
+}
+
+/// Matches a file name against a pattern in which '*' matches any sequence of
+/// characters and '?' matches any single character.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let n: Vec<char> = name.chars().collect();
+    let (mut pi, mut ni) = (0usize, 0usize);
+    let mut star: Option<usize> = None;
+    let mut star_ni = 0usize;
+    while ni < n.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == n[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some(pi);
+            star_ni = ni;
+            pi += 1;
+        } else if let Some(s) = star {
+            // backtrack; let the last '*' absorb one more character
+            pi = s + 1;
+            star_ni += 1;
+            ni = star_ni;
+        } else {
+            return false;
+        }
+    }
+    while pi < p.len() && p[pi] == '*' {
+        pi += 1;
+    }
+    pi == p.len()
+}
+
+/// Returns the output file name for the input with base name `stem`.
+pub fn get_templated_file_name(template: &str, stem: &str) -> String {
+    if template.contains("{name}") {
+        return template.replace("{name}", stem);
+    }
+    let path = Path::new(template);
+    let template_stem = match path.file_stem() {
+        Some(s) => s.to_string_lossy().to_string(),
+        None => String::new(),
+    };
+    let file_name = match path.extension() {
+        Some(e) => format!("{}_{}.{}", template_stem, stem, e.to_string_lossy()),
+        None => format!("{}_{}", template_stem, stem),
+    };
+    match path.parent() {
+        Some(d) if !d.as_os_str().is_empty() => d.join(file_name).to_string_lossy().to_string(),
+        _ => file_name,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        expand_file_pattern, get_flag_value_pairs, get_templated_file_name, wildcard_match,
+    };
+    use std::env;
+    use std::fs;
+    use std::path::MAIN_SEPARATOR;
+    use std::process;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.tif", "DEM.tif"));
+        assert!(wildcard_match("*.tif", ".tif"));
+        assert!(!wildcard_match("*.tif", "DEM.tif.aux"));
+        assert!(wildcard_match("tile_?.tif", "tile_1.tif"));
+        assert!(!wildcard_match("tile_?.tif", "tile_10.tif"));
+        assert!(!wildcard_match("tile_?.tif", "tile_.tif"));
+        assert!(wildcard_match("t*_*0.t?f", "tile_10.tif"));
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("DEM.tif", "DEM.tif"));
+        assert!(!wildcard_match("DEM.tif", "DEM.tiff"));
+    }
+
+    #[test]
+    fn test_expand_file_pattern() {
+        let sep = MAIN_SEPARATOR.to_string();
+        let dir = env::temp_dir().join(format!("wbt_batch_test_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in &["tile_2.tif", "tile_1.tif", "tile_10.tif", "notes.txt"] {
+            fs::write(dir.join(name), b"").unwrap();
+        }
+        let wd = format!("{}{}", dir.to_string_lossy(), sep);
+        let path = |name: &str| format!("{}{}", wd, name);
+
+        // '*' and '?', sorted
+        assert_eq!(
+            expand_file_pattern("tile_*.tif", &wd).unwrap(),
+            vec![path("tile_1.tif"), path("tile_10.tif"), path("tile_2.tif")]
+        );
+        assert_eq!(
+            expand_file_pattern("tile_?.tif", &wd).unwrap(),
+            vec![path("tile_1.tif"), path("tile_2.tif")]
+        );
+
+        // no matches
+        assert!(expand_file_pattern("*.dep", &wd).unwrap().is_empty());
+
+        // literal paths without wildcards are returned as given, whether or not they exist
+        assert_eq!(
+            expand_file_pattern("notes.txt; missing.tif", &wd).unwrap(),
+            vec![path("notes.txt"), path("missing.tif")]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_get_flag_value_pairs() {
+        let args: Vec<String> = [
+            "-i=\"my",
+            "tiles/*.tif\"",
+            "--zfactor",
+            "-2",
+            "--fill",
+            "-o",
+            "out.tif",
+        ]
+        .iter()
+        .map(|a| a.to_string())
+        .collect();
+        assert_eq!(
+            get_flag_value_pairs(&args),
+            vec![
+                ("-i".to_string(), Some("my tiles/*.tif".to_string())),
+                ("--zfactor".to_string(), Some("-2".to_string())),
+                ("--fill".to_string(), None),
+                ("-o".to_string(), Some("out.tif".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_templated_file_name() {
+        let sep = MAIN_SEPARATOR.to_string();
+        assert_eq!(get_templated_file_name("{name}_slope.tif", "DEM"), "DEM_slope.tif");
+        assert_eq!(
+            get_templated_file_name("out/{name}/{name}.tif", "DEM"),
+            "out/DEM/DEM.tif"
+        );
+
+        // without a placeholder, the input name is appended to the template's stem
+        assert_eq!(get_templated_file_name("slope.tif", "DEM"), "slope_DEM.tif");
+        assert_eq!(get_templated_file_name("slope", "DEM"), "slope_DEM");
+        assert_eq!(
+            get_templated_file_name(&format!("out{}slope.tif", sep), "DEM"),
+            format!("out{}slope_DEM.tif", sep)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! A machine-readable catalog of all of the available tools.
+//!
+//! The catalog is a single JSON document containing, for each tool, its name,
+//! description, toolbox, keywords, the data types of its input and output files,
+//! related tools, parameters, and example usage, as well as the list of toolboxes.
+//! It is intended to drive user interfaces, which would otherwise need to call
+//! `--toolhelp` or `--toolparameters` for every tool.
+
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::io::Error;
+use tools::ToolManager;
+
+/// The maximum number of inferred related tools.
+const MAX_RELATED_TOOLS: usize = 5;
+
+/// Words that are too general to indicate that two tools are related.
+const STOP_WORDS: [&'static str; 14] = [
+    "to", "from", "of", "by", "and", "with", "in", "on", "raster", "vector", "file", "files",
+    "tool", "tools",
+];
+
+struct CatalogEntry {
+    name: String,
+    toolbox: String,
+    keywords: Vec<String>,
+    related_tools: Vec<String>,
+    entry: Map<String, Value>,
+}
+
+impl ToolManager {
+    /// Prints the catalog of all tools as a JSON document.
+    pub fn tool_catalog(&self) -> Result<(), Error> {
+        println!("{}", self.get_tool_catalog());
+        Ok(())
+    }
+
+    /// Returns the catalog of all tools.
+    pub fn get_tool_catalog(&self) -> Value {
+        let mut entries: Vec<CatalogEntry> = Vec::with_capacity(self.tool_names.len());
+        for name in &self.tool_names {
+            let tool = match self.get_tool(name) {
+                Some(t) => t,
+                None => continue,
+            };
+            let parameters = match serde_json::from_str::<Value>(&tool.get_tool_parameters()) {
+                Ok(p) => p["parameters"].clone(),
+                Err(_) => Value::Array(vec![]),
+            };
+            let mut input_types: Vec<String> = vec![];
+            let mut output_types: Vec<String> = vec![];
+            if let Some(params) = parameters.as_array() {
+                for p in params {
+                    let pt = &p["parameter_type"];
+                    for &(key, is_output) in &[
+                        ("ExistingFile", false),
+                        ("ExistingFileOrFloat", false),
+                        ("FileList", false),
+                        ("NewFile", true),
+                    ] {
+                        if let Some(ft) = pt.get(key) {
+                            let t = get_file_type_name(ft);
+                            let types = if is_output {
+                                &mut output_types
+                            } else {
+                                &mut input_types
+                            };
+                            if !types.contains(&t) {
+                                types.push(t);
+                            }
+                        }
+                    }
+                }
+            }
+            let toolbox = tool.get_toolbox();
+            let keywords = tool.get_keywords();
+            let mut entry = Map::new();
+            entry.insert("name".to_string(), Value::from(tool.get_tool_name()));
+            entry.insert(
+                "description".to_string(),
+                Value::from(tool.get_tool_description()),
+            );
+            entry.insert("toolbox".to_string(), Value::from(toolbox.clone()));
+            entry.insert("keywords".to_string(), Value::from(keywords.clone()));
+            entry.insert("input_types".to_string(), Value::from(input_types));
+            entry.insert("output_types".to_string(), Value::from(output_types));
+            entry.insert("parameters".to_string(), parameters);
+            entry.insert(
+                "example_usage".to_string(),
+                Value::from(tool.get_example_usage()),
+            );
+            entries.push(CatalogEntry {
+                name: tool.get_tool_name(),
+                toolbox: toolbox,
+                keywords: keywords,
+                related_tools: tool.get_related_tools(),
+                entry: entry,
+            });
+        }
+
+        // infer related tools from the keywords shared by tools in the same toolbox
+        let mut related: Vec<Vec<String>> = Vec::with_capacity(entries.len());
+        for i in 0..entries.len() {
+            if !entries[i].related_tools.is_empty() {
+                related.push(entries[i].related_tools.clone());
+                continue;
+            }
+            let toolbox_words: Vec<String> = entries[i]
+                .toolbox
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .map(|w| w.to_lowercase())
+                .collect();
+            let significant: Vec<&String> = entries[i]
+                .keywords
+                .iter()
+                .filter(|k| !STOP_WORDS.contains(&k.as_str()) && !toolbox_words.contains(k))
+                .collect();
+            let mut scores: Vec<(usize, &String)> = vec![];
+            for j in 0..entries.len() {
+                if i == j || entries[j].toolbox != entries[i].toolbox {
+                    continue;
+                }
+                let n = significant
+                    .iter()
+                    .filter(|k| entries[j].keywords.contains(k))
+                    .count();
+                if n > 0 {
+                    scores.push((n, &entries[j].name));
+                }
+            }
+            scores.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+            related.push(
+                scores
+                    .iter()
+                    .take(MAX_RELATED_TOOLS)
+                    .map(|&(_, n)| n.clone())
+                    .collect(),
+            );
+        }
+
+        let mut toolboxes: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut tools: Vec<Value> = Vec::with_capacity(entries.len());
+        for (e, r) in entries.into_iter().zip(related.into_iter()) {
+            toolboxes
+                .entry(e.toolbox.clone())
+                .or_insert(vec![])
+                .push(e.name.clone());
+            let mut entry = e.entry;
+            entry.insert("related_tools".to_string(), Value::from(r));
+            tools.push(Value::Object(entry));
+        }
+        let toolboxes: Vec<Value> = toolboxes
+            .into_iter()
+            .map(|(name, tools)| {
+                let mut m = Map::new();
+                m.insert("name".to_string(), Value::from(name));
+                m.insert("tools".to_string(), Value::from(tools));
+                Value::Object(m)
+            })
+            .collect();
+
+        const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
+        let mut catalog = Map::new();
+        catalog.insert(
+            "version".to_string(),
+            Value::from(VERSION.unwrap_or("unknown")),
+        );
+        catalog.insert("num_tools".to_string(), Value::from(tools.len()));
+        catalog.insert("toolboxes".to_string(), Value::Array(toolboxes));
+        catalog.insert("tools".to_string(), Value::Array(tools));
+        Value::Object(catalog)
+    }
+}
+
+/// Returns a name for a serialized `ParameterFileType`, e.g. 'Raster' or 'Vector:Point'.
+fn get_file_type_name(ft: &Value) -> String {
+    match ft {
+        &Value::String(ref s) => s.clone(),
+        &Value::Object(ref m) => match m.iter().next() {
+            Some((k, v)) => match v.as_str() {
+                Some("Any") | None => k.clone(),
+                Some(g) => format!("{}:{}", k, g),
+            },
+            None => "Any".to_string(),
+        },
+        _ => "Any".to_string(),
+    }
+}
@@ -0,0 +1,339 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool adds a new field (`--field`) to the attribute table of a vector file
+/// (`--input`). The field type (`--field_type`) may be 'Integer', 'Float', 'Text', or
+/// 'Boolean'. The field width (`--length`) and, for Float fields, the number of decimal
+/// places (`--decimals`) may also be specified; otherwise, defaults suited to the field
+/// type are used. Field names are limited to 10 characters by the dBase format. The new
+/// field is initialized with the value `--value`, or left empty (null) if this is not
+/// specified; the `CalculateField` tool may be used to assign values derived from the
+/// other fields.
+///
+/// If an output file (`--output`) is specified, the modified vector is written to it
+/// and the input file is left unaltered; otherwise, the input file is modified in place.
+///
+/// # See Also
+/// `DeleteField`, `CalculateField`, `ReinitializeAttributeTable`
+pub struct AddField {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl AddField {
+    pub fn new() -> AddField {
+        // public constructor
+        let name = "AddField".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description = "Adds a field to the attribute table of a vector file.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Name of the new field (up to 10 characters).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Type".to_owned(),
+            flags: vec!["--field_type".to_owned()],
+            description: "Field data type.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "Integer".to_owned(),
+                "Float".to_owned(),
+                "Text".to_owned(),
+                "Boolean".to_owned(),
+            ]),
+            default_value: Some("Float".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Length".to_owned(),
+            flags: vec!["--length".to_owned()],
+            description: "Field width, in characters (optional).".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Decimal Places".to_owned(),
+            flags: vec!["--decimals".to_owned()],
+            description: "Number of decimal places of Float fields (optional).".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Initial Value".to_owned(),
+            flags: vec!["--value".to_owned()],
+            description: "Initial value of the field; null by default (optional).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file; the input is modified in place if not specified."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp --field=AREA_KM2 --field_type=Float --decimals=3",
+            short_exe, name
+        ).replace("*", &sep);
+
+        AddField {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for AddField {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut field_name = String::new();
+        let mut field_type = "float".to_string();
+        let mut length: Option<usize> = None;
+        let mut decimals: Option<usize> = None;
+        let mut initial_value: Option<String> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--field_type"])? {
+            field_type = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_usize(&["--length"])? {
+            length = Some(v);
+        }
+        if let Some(v) = tool_args.get_usize(&["--decimals"])? {
+            decimals = Some(v);
+        }
+        if let Some(v) = tool_args.get_string(&["--value"])? {
+            initial_value = Some(v);
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if output_file.is_empty() {
+            output_file = input_file.clone();
+        } else if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if field_name.is_empty() || field_name.len() > 10 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Field names must contain between 1 and 10 characters.",
+            ));
+        }
+
+        let start = Instant::now();
+        let mut vector = Shapefile::read(&input_file)?;
+        if vector.attributes.get_field_num(&field_name).is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("The attribute table already contains a field named {}.", field_name),
+            ));
+        }
+
+        let field = if field_type.contains("int") {
+            AttributeField::new(
+                &field_name,
+                FieldDataType::Int,
+                length.unwrap_or(10).min(254) as u8,
+                0u8,
+            )
+        } else if field_type.contains("text") || field_type.contains("str") {
+            AttributeField::new(
+                &field_name,
+                FieldDataType::Text,
+                length.unwrap_or(50).min(254) as u8,
+                0u8,
+            )
+        } else if field_type.contains("bool") {
+            AttributeField::new(&field_name, FieldDataType::Bool, 1u8, 0u8)
+        } else {
+            let decimals = decimals.unwrap_or(4).min(15);
+            AttributeField::new(
+                &field_name,
+                FieldDataType::Real,
+                length.unwrap_or(12).max(decimals + 2).min(254) as u8,
+                decimals as u8,
+            )
+        };
+        vector.attributes.add_field(&field);
+
+        if let Some(value) = initial_value {
+            let value = parse_field_value(&value, &field)?;
+            for record_num in 0..vector.num_records {
+                vector
+                    .attributes
+                    .set_value(record_num, &field_name, value.clone());
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        vector.file_name = output_file;
+        vector.file_mode = "w".to_string();
+        let _ = match vector.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a string to a value of a field's data type.
+fn parse_field_value(value: &str, field: &AttributeField) -> Result<FieldData, Error> {
+    let v = value.trim();
+    if v.is_empty() || v.to_lowercase() == "null" {
+        return Ok(FieldData::Null);
+    }
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid value '{}' for field {}.", value, field.name),
+        )
+    };
+    match field.field_type {
+        'N' if field.decimal_count == 0 => match v.parse::<f64>() {
+            Ok(x) => Ok(FieldData::Int(x.round() as i32)),
+            Err(_) => Err(invalid()),
+        },
+        'N' | 'F' => match v.parse::<f64>() {
+            Ok(x) => Ok(FieldData::Real(x)),
+            Err(_) => Err(invalid()),
+        },
+        'L' => match v.to_lowercase().as_ref() {
+            "true" | "t" | "yes" | "y" | "1" => Ok(FieldData::Bool(true)),
+            "false" | "f" | "no" | "n" | "0" => Ok(FieldData::Bool(false)),
+            _ => Err(invalid()),
+        },
+        _ => Ok(FieldData::Text(value.to_string())),
+    }
+}
@@ -0,0 +1,399 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use spatial_ref_system::{epsg_from_wkt, esri_wkt_from_epsg};
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+use vector::*;
+
+/// This tool assigns a coordinate reference system (CRS) to a raster or vector file
+/// (`--input`) whose projection information is missing, e.g. after processing in software
+/// that does not retain it. The data are not reprojected; only the CRS definition stored
+/// with the file is set. Use `ModifyProjection` to replace or edit a CRS definition that
+/// is already present; by default, this tool will not overwrite an existing definition
+/// unless the `--overwrite` flag is specified.
+///
+/// The CRS is specified either using an EPSG code (`--epsg`), which is looked up in the
+/// database of EPSG definitions embedded in WhiteboxTools, or using a WKT definition
+/// (`--wkt`), given either as text or as the name of a .prj file. When both are
+/// specified, the WKT definition is stored with the EPSG code.
+///
+/// The result is saved to `--output` if specified; otherwise, the input file is updated.
+/// For vectors, the CRS is stored in the Shapefile's .prj file. For rasters, the horizontal
+/// units of the raster are also set from the units of the CRS.
+///
+/// # See Also
+/// `ModifyProjection`
+pub struct AssignProjection {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl AssignProjection {
+    pub fn new() -> AssignProjection {
+        // public constructor
+        let name = "AssignProjection".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Assigns a coordinate reference system to a raster or vector without reprojecting it."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Raster or Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster or vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output file; the input file is updated if unspecified.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "EPSG Code".to_owned(),
+            flags: vec!["--epsg".to_owned()],
+            description: "EPSG code of the coordinate reference system.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "WKT Definition".to_owned(),
+            flags: vec!["--wkt".to_owned()],
+            description: "WKT definition of the coordinate reference system, or a .prj file."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Overwrite an existing CRS definition?".to_owned(),
+            flags: vec!["--overwrite".to_owned()],
+            description: "Replace the CRS definition of inputs that already have one."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --epsg=26917
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=roads.shp --wkt=roads_crs.prj", short_exe, name).replace("*", &sep);
+
+        AssignProjection {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for AssignProjection {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut epsg: Option<isize> = None;
+        let mut wkt: Option<String> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--epsg"])? {
+            epsg = Some(v);
+        }
+        if let Some(v) = tool_args.get_string(&["--wkt"])? {
+            wkt = Some(v);
+        }
+        let overwrite = tool_args.get_flag(&["--overwrite"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.is_empty() && !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let (epsg_code, wkt) = match read_crs_definition(epsg, wkt, working_directory)? {
+            Some(crs) => crs,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "A coordinate reference system must be specified using --epsg or --wkt.",
+                ))
+            }
+        };
+
+        if !overwrite && get_crs_wkt(&input_file)?.contains("[") {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input already has a coordinate reference system; use the --overwrite flag or the ModifyProjection tool to replace it.",
+            ));
+        }
+
+        let xy_units = units_from_wkt(&wkt);
+        write_crs(
+            &input_file,
+            &output_file,
+            epsg_code,
+            Some(wkt),
+            xy_units,
+            None,
+            &format!("Created by whitebox_tools\' {} tool", self.get_tool_name()),
+        )?;
+
+        if verbose {
+            report::message(&format!(
+                "Coordinate reference system assigned (EPSG code {})",
+                epsg_code
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether a file is a vector (Shapefile).
+fn is_vector(file_name: &str) -> bool {
+    file_name.to_lowercase().ends_with(".shp")
+}
+
+/// Reads a CRS definition from an EPSG code and/or a WKT definition, which may be the name
+/// of a .prj file. Returns the EPSG code (zero if unknown) and WKT definition, or `None` if
+/// neither was specified.
+pub fn read_crs_definition(
+    epsg: Option<isize>,
+    wkt: Option<String>,
+    working_directory: &str,
+) -> Result<Option<(u16, String)>, Error> {
+    let epsg = match epsg {
+        Some(code) if code <= 0 || code >= 32767 => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} is not a valid EPSG code.", code),
+            ))
+        }
+        Some(code) => Some(code as u16),
+        None => None,
+    };
+    let wkt = match wkt {
+        Some(ref w) if !w.trim().is_empty() => {
+            let w = w.trim();
+            if w.contains("[") {
+                Some(w.to_string())
+            } else {
+                // the name of a .prj file
+                let mut prj_file = w.to_string();
+                if !Path::new(&prj_file).exists() {
+                    prj_file = format!("{}{}", working_directory, w);
+                }
+                let mut contents = String::new();
+                File::open(&prj_file)?.read_to_string(&mut contents)?;
+                if !contents.contains("[") {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("{} does not contain a WKT definition.", prj_file),
+                    ));
+                }
+                Some(contents.trim().to_string())
+            }
+        }
+        _ => None,
+    };
+    match (epsg, wkt) {
+        (None, None) => Ok(None),
+        (Some(code), Some(w)) => Ok(Some((code, w))),
+        (None, Some(w)) => Ok(Some((epsg_from_wkt(&w), w))),
+        (Some(code), None) => {
+            let w = esri_wkt_from_epsg(code);
+            if !w.contains("[") {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "EPSG code {} was not found in the EPSG database; specify the CRS using --wkt instead.",
+                        code
+                    ),
+                ));
+            }
+            Ok(Some((code, w)))
+        }
+    }
+}
+
+/// Returns the name of the horizontal units of a WKT CRS definition, i.e. degrees for a
+/// geographic CRS and the last UNIT element of a projected CRS.
+pub fn units_from_wkt(wkt: &str) -> Option<String> {
+    let upper = wkt.trim().to_uppercase();
+    if upper.starts_with("GEOGCS[") {
+        return Some("degrees".to_string());
+    }
+    if !upper.starts_with("PROJCS[") {
+        return None;
+    }
+    let start = match upper.rfind("UNIT[\"") {
+        Some(idx) => idx + 6,
+        None => return None,
+    };
+    let name = match upper[start..].find('"') {
+        Some(end) => upper[start..start + end].to_string(),
+        None => return None,
+    };
+    if name.contains("MET") {
+        Some("metres".to_string())
+    } else if name.contains("US") && (name.contains("FOOT") || name.contains("FEET")) {
+        Some("US survey feet".to_string())
+    } else if name.contains("FOOT") || name.contains("FEET") {
+        Some("feet".to_string())
+    } else {
+        None
+    }
+}
+
+/// Returns the WKT CRS definition of a raster or vector file, which is empty or
+/// 'not specified' if the file has none.
+pub fn get_crs_wkt(file_name: &str) -> Result<String, Error> {
+    if is_vector(file_name) {
+        Ok(Shapefile::read(file_name)?.projection)
+    } else {
+        Ok(Raster::new(file_name, "r")?.configs.coordinate_ref_system_wkt)
+    }
+}
+
+/// Sets the CRS definition and, for rasters, the units of a raster or vector file without
+/// altering its data. The result is written to `output_file`, or the input file is updated
+/// if `output_file` is empty. Properties that are `None` are left unchanged.
+pub fn write_crs(
+    input_file: &str,
+    output_file: &str,
+    epsg_code: u16,
+    wkt: Option<String>,
+    xy_units: Option<String>,
+    z_units: Option<String>,
+    metadata_entry: &str,
+) -> Result<(), Error> {
+    let output_file = if output_file.is_empty() {
+        input_file
+    } else {
+        output_file
+    };
+    if is_vector(input_file) {
+        let mut vector = Shapefile::read(input_file)?;
+        if let Some(w) = wkt {
+            vector.projection = w;
+        }
+        vector.file_name = output_file.to_string();
+        vector.file_mode = "w".to_string();
+        return vector.write();
+    }
+
+    let input = Raster::new(input_file, "r")?;
+    let mut output = Raster::initialize_using_file(output_file, &input);
+    output.set_data_from_raster(&input)?;
+    output.configs.metadata = input.configs.metadata.clone();
+    if let Some(w) = wkt {
+        output.configs.epsg_code = epsg_code;
+        output.configs.coordinate_ref_system_wkt = w;
+    }
+    if let Some(units) = xy_units {
+        output.configs.xy_units = units;
+    }
+    if let Some(units) = z_units {
+        output.configs.z_units = units;
+    }
+    output.add_metadata_entry(metadata_entry.to_string());
+    output.write()
+}
@@ -0,0 +1,295 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use serde_json;
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool exports the attribute table of a vector file (`--input`) to a comma
+/// separated values (CSV) or JavaScript Object Notation (JSON) file (`--output`), for use
+/// in reporting pipelines and other software. The format is determined by the extension of
+/// the output file: files ending in `.json` are written as an array of records, each of
+/// which is an object keyed by field name, and all other files are written as CSV, with a
+/// header row of field names.
+///
+/// Unlike `ExportTableToCsv`, CSV values are quoted only when necessary, i.e. when they
+/// contain commas, quotes, or line breaks, with any quotes doubled, and null values are
+/// written as empty values (or `null` in JSON). Numbers are written to the precision of
+/// their fields. The output may therefore be read by spreadsheets and by the
+/// `JoinTableToVector` tool without loss. A comma-separated list of fields to export
+/// (`--fields`) may optionally be specified; by default, all fields are exported.
+///
+/// # See Also
+/// `ExportTableToCsv`, `JoinTableToVector`, `RasterSummaryStats`
+pub struct AttributesToCsv {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl AttributesToCsv {
+    pub fn new() -> AttributesToCsv {
+        // public constructor
+        let name = "AttributesToCsv".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Exports a vector's attribute table to a CSV or JSON file.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV (.csv) or JSON (.json) file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Exported Fields".to_owned(),
+            flags: vec!["--fields".to_owned()],
+            description: "Comma-separated fields to export; all are exported by default (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp -o=basins.csv
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp -o=basins.json --fields=\"VALUE,AREA\"",
+            short_exe, name
+        ).replace("*", &sep);
+
+        AttributesToCsv {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for AttributesToCsv {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut field_names: Vec<String> = vec![];
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--fields"])? {
+            field_names = v
+                .split(|c| c == ',' || c == ';')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        let mut fields: Vec<usize> = vec![];
+        if field_names.is_empty() {
+            fields = (0..input.attributes.get_num_fields()).collect();
+        } else {
+            for name in &field_names {
+                match input.attributes.get_field_num(name) {
+                    Some(i) => fields.push(i),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("The field {} was not located in the attribute table.", name),
+                        ))
+                    }
+                }
+            }
+        }
+        let fields: Vec<AttributeField> = fields
+            .iter()
+            .map(|&i| input.attributes.get_field(i).clone())
+            .collect();
+        let indices: Vec<usize> = fields
+            .iter()
+            .map(|f| input.attributes.get_field_num(&f.name).unwrap())
+            .collect();
+
+        let f = File::create(&output_file)?;
+        let mut writer = BufWriter::new(f);
+
+        if output_file.to_lowercase().ends_with(".json") {
+            let mut records = Vec::with_capacity(input.num_records);
+            for record_num in 0..input.num_records {
+                let rec = input.attributes.get_record(record_num);
+                let mut obj = serde_json::Map::new();
+                for (field, &i) in fields.iter().zip(indices.iter()) {
+                    obj.insert(field.name.clone(), field_data_to_json(&rec[i], field));
+                }
+                records.push(serde_json::Value::Object(obj));
+            }
+            let s = match serde_json::to_string_pretty(&serde_json::Value::Array(records)) {
+                Ok(s) => s,
+                Err(e) => return Err(Error::new(ErrorKind::Other, format!("{}", e))),
+            };
+            writer.write_all(s.as_bytes())?;
+            writer.write_all(b"\n")?;
+        } else {
+            let header: Vec<String> = fields.iter().map(|f| csv_value(&f.name)).collect();
+            writer.write_all(format!("{}\n", header.join(",")).as_bytes())?;
+            for record_num in 0..input.num_records {
+                let rec = input.attributes.get_record(record_num);
+                let values: Vec<String> = fields
+                    .iter()
+                    .zip(indices.iter())
+                    .map(|(field, &i)| csv_value(&field_data_to_string(&rec[i], field)))
+                    .collect();
+                writer.write_all(format!("{}\n", values.join(",")).as_bytes())?;
+            }
+        }
+        writer.flush()?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::message("Output file written");
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the text of a value, with numbers written to the precision of their field.
+fn field_data_to_string(value: &FieldData, field: &AttributeField) -> String {
+    match *value {
+        FieldData::Int(v) => v.to_string(),
+        FieldData::Real(v) => format!("{:.*}", field.decimal_count as usize, v),
+        FieldData::Text(ref v) => v.clone(),
+        FieldData::Date(ref v) => v.to_string(),
+        FieldData::Bool(v) => v.to_string(),
+        FieldData::Null => String::new(),
+    }
+}
+
+fn field_data_to_json(value: &FieldData, field: &AttributeField) -> serde_json::Value {
+    match *value {
+        FieldData::Int(v) => serde_json::Value::from(v),
+        FieldData::Real(v) => {
+            let m = 10f64.powi(field.decimal_count as i32);
+            serde_json::Value::from((v * m).round() / m)
+        }
+        FieldData::Bool(v) => serde_json::Value::from(v),
+        FieldData::Null => serde_json::Value::Null,
+        _ => serde_json::Value::from(field_data_to_string(value, field)),
+    }
+}
+
+/// Quotes a CSV value, if it contains a delimiter, quote, or line break.
+fn csv_value(s: &str) -> String {
+    if s.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", s.replace("\"", "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -0,0 +1,1038 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool assigns the values of an expression (`--expression`), evaluated for each
+/// record of the attribute table of a vector file (`--input`), to a field (`--field`).
+/// If the field does not exist, it is created, using the type specified by `--field_type`
+/// ('Integer', 'Float', 'Text', or 'Boolean') or, if this is not specified, the type of
+/// the expression's values. Existing fields retain their type and the values are
+/// converted to it, e.g. numbers are rounded when assigned to an Integer field.
+///
+/// Expressions may refer to the values of the other fields by name (e.g. `AREA`), or
+/// with the name in square brackets when it is not a simple identifier (e.g. `[MY-FIELD]`).
+/// Text literals are enclosed in single or double quotes. The following are supported:
+///
+/// - Arithmetic: `+`, `-`, `*`, `/`, `%` (remainder), and `^` (power).
+/// - Text concatenation: `+`, when either of the operands is text, e.g. `NAME + '_' + FID`.
+/// - Comparison: `==` (or `=`), `!=` (or `<>`), `<`, `<=`, `>`, and `>=`.
+/// - Logic: `and` (`&&`), `or` (`||`), and `not` (`!`), and the constants `true`,
+///   `false`, and `null`.
+/// - Conditionals: `if(condition, value_if_true, value_if_false)`.
+/// - Functions: `abs`, `sqrt`, `exp`, `ln`, `log10`, `sin`, `cos`, `tan`, `asin`, `acos`,
+///   `atan`, `floor`, `ceil`, `round(x)` or `round(x, decimals)`, `pow(x, y)`,
+///   `min(...)`, `max(...)`, `upper`, `lower`, `trim`, `len`, `substr(text, start, length)`
+///   (with zero-based start), `str` (converts to text), `num` (converts to a number), and
+///   `isnull`.
+///
+/// Null (empty) values propagate through arithmetic and text operations; for example,
+/// `A + B` is null for records where either field is null. Use `if(isnull(A), 0, A)` to
+/// substitute a value for nulls.
+///
+/// If an output file (`--output`) is specified, the modified vector is written to it
+/// and the input file is left unaltered; otherwise, the input file is modified in place.
+///
+/// # See Also
+/// `AddField`, `DeleteField`
+pub struct CalculateField {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl CalculateField {
+    pub fn new() -> CalculateField {
+        // public constructor
+        let name = "CalculateField".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Calculates the values of an attribute field using an expression of the other fields."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Name of the calculated field, which is created if it does not exist."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Expression".to_owned(),
+            flags: vec!["--expression".to_owned()],
+            description: "Expression used to calculate the field values.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Type".to_owned(),
+            flags: vec!["--field_type".to_owned()],
+            description: "Data type of a new field; based on the values by default (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "Integer".to_owned(),
+                "Float".to_owned(),
+                "Text".to_owned(),
+                "Boolean".to_owned(),
+            ]),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file; the input is modified in place if not specified."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp --field=DENSITY --expression=\"if(AREA > 0, LENGTH / AREA, null)\"",
+            short_exe, name
+        ).replace("*", &sep);
+
+        CalculateField {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for CalculateField {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut field_name = String::new();
+        let mut expression = String::new();
+        let mut field_type = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--expression"])? {
+            expression = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field_type"])? {
+            field_type = v.to_lowercase();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if output_file.is_empty() {
+            output_file = input_file.clone();
+        } else if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let start = Instant::now();
+        let mut vector = Shapefile::read(&input_file)?;
+
+        let field_names: Vec<String> = vector
+            .attributes
+            .get_fields()
+            .iter()
+            .map(|f| f.name.clone())
+            .collect();
+        let expr = match Parser::parse(&expression, &field_names) {
+            Ok(e) => e,
+            Err(msg) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Error in expression: {}", msg),
+                ))
+            }
+        };
+
+        // evaluate the expression for each record
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        let mut values: Vec<Value> = Vec::with_capacity(vector.num_records);
+        for record_num in 0..vector.num_records {
+            let record: Vec<Value> = vector
+                .attributes
+                .get_record(record_num)
+                .iter()
+                .map(|v| Value::from_field_data(v))
+                .collect();
+            match expr.evaluate(&record) {
+                Ok(v) => values.push(v),
+                Err(msg) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Error evaluating the expression for record {}: {}",
+                            record_num + 1,
+                            msg
+                        ),
+                    ))
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / vector.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // create the field, if needed
+        if vector.attributes.get_field_num(&field_name).is_none() {
+            if field_name.is_empty() || field_name.len() > 10 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Field names must contain between 1 and 10 characters.",
+                ));
+            }
+            let field = new_field(&field_name, &field_type, &values);
+            vector.attributes.add_field(&field);
+        }
+        let field_index = vector.attributes.get_field_num(&field_name).unwrap();
+        let field = vector.attributes.get_field(field_index).clone();
+        for record_num in 0..values.len() {
+            match values[record_num].to_field_data(&field) {
+                Ok(v) => vector.attributes.set_value(record_num, &field_name, v),
+                Err(msg) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Error assigning the value of record {}: {}",
+                            record_num + 1,
+                            msg
+                        ),
+                    ))
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        vector.file_name = output_file;
+        vector.file_mode = "w".to_string();
+        let _ = match vector.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates a field of the specified type or, if no type is specified, of the type of
+/// the first non-null value.
+fn new_field(name: &str, field_type: &str, values: &[Value]) -> AttributeField {
+    let field_type = if !field_type.is_empty() {
+        field_type.to_string()
+    } else {
+        match values.iter().find(|v| **v != Value::Null) {
+            Some(&Value::Text(_)) => "text".to_string(),
+            Some(&Value::Bool(_)) => "boolean".to_string(),
+            _ => "float".to_string(),
+        }
+    };
+    if field_type.contains("int") {
+        AttributeField::new(name, FieldDataType::Int, 10u8, 0u8)
+    } else if field_type.contains("text") || field_type.contains("str") {
+        let mut length = 1;
+        for v in values {
+            if let Value::Text(ref s) = *v {
+                length = length.max(s.len());
+            }
+        }
+        AttributeField::new(name, FieldDataType::Text, length.min(254) as u8, 0u8)
+    } else if field_type.contains("bool") {
+        AttributeField::new(name, FieldDataType::Bool, 1u8, 0u8)
+    } else {
+        AttributeField::new(name, FieldDataType::Real, 18u8, 6u8)
+    }
+}
+
+/// A value of an expression.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    fn from_field_data(data: &FieldData) -> Value {
+        match *data {
+            FieldData::Int(v) => Value::Number(v as f64),
+            FieldData::Real(v) => Value::Number(v),
+            FieldData::Text(ref v) => Value::Text(v.clone()),
+            FieldData::Bool(v) => Value::Bool(v),
+            FieldData::Date(ref v) => Value::Text(format!("{}{:02}{:02}", v.year, v.month, v.day)),
+            FieldData::Null => Value::Null,
+        }
+    }
+
+    /// Converts the value to the data type of a field.
+    fn to_field_data(&self, field: &AttributeField) -> Result<FieldData, String> {
+        if *self == Value::Null {
+            return Ok(FieldData::Null);
+        }
+        match field.field_type {
+            'N' | 'F' | 'I' | 'O' => {
+                let x = match self.to_number() {
+                    Some(x) if x.is_finite() => x,
+                    Some(_) => return Ok(FieldData::Null),
+                    None => return Err(format!("'{}' is not a number", self.to_text())),
+                };
+                if field.decimal_count == 0 {
+                    if x.round() < i32::min_value() as f64 || x.round() > i32::max_value() as f64 {
+                        return Err(format!("{} is too large for an integer field", x));
+                    }
+                    Ok(FieldData::Int(x.round() as i32))
+                } else {
+                    Ok(FieldData::Real(x))
+                }
+            }
+            'L' => Ok(FieldData::Bool(self.is_true())),
+            'D' => {
+                let s = self.to_text();
+                if s.len() == 8 && s.chars().all(|c| c.is_digit(10)) {
+                    Ok(FieldData::Date(DateData {
+                        year: s[0..4].parse::<u16>().unwrap(),
+                        month: s[4..6].parse::<u8>().unwrap(),
+                        day: s[6..8].parse::<u8>().unwrap(),
+                    }))
+                } else {
+                    Err(format!("'{}' is not a date (YYYYMMDD)", s))
+                }
+            }
+            _ => Ok(FieldData::Text(self.to_text())),
+        }
+    }
+
+    fn to_number(&self) -> Option<f64> {
+        match *self {
+            Value::Number(x) => Some(x),
+            Value::Bool(b) => Some(if b { 1f64 } else { 0f64 }),
+            Value::Text(ref s) => s.trim().parse::<f64>().ok(),
+            Value::Null => None,
+        }
+    }
+
+    fn to_text(&self) -> String {
+        match *self {
+            Value::Number(x) => {
+                if x == x.trunc() && x.abs() < 1e15 {
+                    format!("{}", x as i64)
+                } else {
+                    format!("{}", x)
+                }
+            }
+            Value::Text(ref s) => s.clone(),
+            Value::Bool(b) => format!("{}", b),
+            Value::Null => String::new(),
+        }
+    }
+
+    fn is_true(&self) -> bool {
+        match *self {
+            Value::Number(x) => x != 0f64 && !x.is_nan(),
+            Value::Text(ref s) => !s.is_empty(),
+            Value::Bool(b) => b,
+            Value::Null => false,
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match *self {
+            Value::Number(_) => "number",
+            Value::Text(_) => "text",
+            Value::Bool(_) => "Boolean",
+            Value::Null => "null",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Ident(String),
+    Field(String),
+    Op(String),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_digit(10) || (c == '.' && i + 1 < chars.len() && chars[i + 1].is_digit(10))
+        {
+            let start = i;
+            while i < chars.len() && (chars[i].is_digit(10) || chars[i] == '.') {
+                i += 1;
+            }
+            // exponent
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].is_digit(10) {
+                    i = j;
+                    while i < chars.len() && chars[i].is_digit(10) {
+                        i += 1;
+                    }
+                }
+            }
+            let s: String = chars[start..i].iter().collect();
+            match s.parse::<f64>() {
+                Ok(x) => tokens.push(Token::Number(x)),
+                Err(_) => return Err(format!("invalid number '{}'", s)),
+            }
+        } else if c == '\'' || c == '"' {
+            // a quoted text literal; a doubled quote represents the quote character
+            let mut s = String::new();
+            i += 1;
+            loop {
+                if i >= chars.len() {
+                    return Err("unterminated text literal".to_string());
+                }
+                if chars[i] == c {
+                    if i + 1 < chars.len() && chars[i + 1] == c {
+                        s.push(c);
+                        i += 2;
+                        continue;
+                    }
+                    i += 1;
+                    break;
+                }
+                s.push(chars[i]);
+                i += 1;
+            }
+            tokens.push(Token::Text(s));
+        } else if c == '[' {
+            let start = i + 1;
+            while i < chars.len() && chars[i] != ']' {
+                i += 1;
+            }
+            if i >= chars.len() {
+                return Err("unterminated field name".to_string());
+            }
+            tokens.push(Token::Field(chars[start..i].iter().collect()));
+            i += 1;
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c == '(' {
+            tokens.push(Token::LeftParen);
+            i += 1;
+        } else if c == ')' {
+            tokens.push(Token::RightParen);
+            i += 1;
+        } else if c == ',' {
+            tokens.push(Token::Comma);
+            i += 1;
+        } else {
+            let two: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+            let op = match two.as_ref() {
+                "==" | "!=" | "<>" | "<=" | ">=" | "&&" | "||" => two.clone(),
+                _ => match c {
+                    '+' | '-' | '*' | '/' | '%' | '^' | '<' | '>' | '!' => c.to_string(),
+                    '=' => "==".to_string(),
+                    _ => return Err(format!("unexpected character '{}'", c)),
+                },
+            };
+            i += if op.len() == 2 && two == op { 2 } else { 1 };
+            tokens.push(Token::Op(op));
+        }
+    }
+    Ok(tokens)
+}
+
+/// A parsed expression.
+#[derive(Clone, Debug)]
+enum Expr {
+    Literal(Value),
+    Field(usize),
+    Unary(String, Box<Expr>),
+    Binary(String, Box<Expr>, Box<Expr>),
+    Function(String, Vec<Expr>),
+}
+
+impl Expr {
+    fn evaluate(&self, record: &[Value]) -> Result<Value, String> {
+        match *self {
+            Expr::Literal(ref v) => Ok(v.clone()),
+            Expr::Field(i) => Ok(record[i].clone()),
+            Expr::Unary(ref op, ref e) => {
+                let v = e.evaluate(record)?;
+                if op == "not" {
+                    return Ok(Value::Bool(!v.is_true()));
+                }
+                match v {
+                    Value::Null => Ok(Value::Null),
+                    Value::Number(x) => Ok(Value::Number(-x)),
+                    _ => Err(format!("cannot negate a {} value", v.type_name())),
+                }
+            }
+            Expr::Binary(ref op, ref a, ref b) => {
+                // the logical operators only evaluate their second operand if needed
+                if op == "and" {
+                    let v = a.evaluate(record)?.is_true() && b.evaluate(record)?.is_true();
+                    return Ok(Value::Bool(v));
+                } else if op == "or" {
+                    let v = a.evaluate(record)?.is_true() || b.evaluate(record)?.is_true();
+                    return Ok(Value::Bool(v));
+                }
+                let (a, b) = (a.evaluate(record)?, b.evaluate(record)?);
+                evaluate_binary(op, &a, &b)
+            }
+            Expr::Function(ref name, ref args) => {
+                if name == "if" {
+                    return if args[0].evaluate(record)?.is_true() {
+                        args[1].evaluate(record)
+                    } else {
+                        args[2].evaluate(record)
+                    };
+                }
+                let mut values = Vec::with_capacity(args.len());
+                for a in args {
+                    values.push(a.evaluate(record)?);
+                }
+                evaluate_function(name, &values)
+            }
+        }
+    }
+}
+
+fn evaluate_binary(op: &str, a: &Value, b: &Value) -> Result<Value, String> {
+    match op {
+        "==" => return Ok(Value::Bool(values_equal(a, b))),
+        "!=" | "<>" => return Ok(Value::Bool(!values_equal(a, b))),
+        _ => {}
+    }
+    if *a == Value::Null || *b == Value::Null {
+        return Ok(Value::Null);
+    }
+    match op {
+        "<" | "<=" | ">" | ">=" => {
+            let ordering = match (a, b) {
+                (&Value::Text(ref x), &Value::Text(ref y)) => x.partial_cmp(y),
+                _ => match (a.to_number(), b.to_number()) {
+                    (Some(x), Some(y)) => x.partial_cmp(&y),
+                    _ => {
+                        return Err(format!(
+                            "cannot compare a {} value with a {} value",
+                            a.type_name(),
+                            b.type_name()
+                        ))
+                    }
+                },
+            };
+            let ordering = match ordering {
+                Some(o) => o,
+                None => return Ok(Value::Null),
+            };
+            let result = match op {
+                "<" => ordering == ::std::cmp::Ordering::Less,
+                "<=" => ordering != ::std::cmp::Ordering::Greater,
+                ">" => ordering == ::std::cmp::Ordering::Greater,
+                _ => ordering != ::std::cmp::Ordering::Less,
+            };
+            Ok(Value::Bool(result))
+        }
+        "+" if is_text(a) || is_text(b) => {
+            Ok(Value::Text(format!("{}{}", a.to_text(), b.to_text())))
+        }
+        _ => {
+            let (x, y) = match (a, b) {
+                (&Value::Number(x), &Value::Number(y)) => (x, y),
+                _ => {
+                    return Err(format!(
+                        "the '{}' operator cannot be applied to {} and {} values",
+                        op,
+                        a.type_name(),
+                        b.type_name()
+                    ))
+                }
+            };
+            let z = match op {
+                "+" => x + y,
+                "-" => x - y,
+                "*" => x * y,
+                "/" => x / y,
+                "%" => x % y,
+                _ => x.powf(y),
+            };
+            Ok(Value::Number(z))
+        }
+    }
+}
+
+fn is_text(v: &Value) -> bool {
+    match *v {
+        Value::Text(_) => true,
+        _ => false,
+    }
+}
+
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (&Value::Text(ref x), &Value::Text(ref y)) => x == y,
+        (&Value::Null, _) | (_, &Value::Null) => *a == *b,
+        (&Value::Text(_), _) | (_, &Value::Text(_)) => a.to_text() == b.to_text(),
+        _ => a.to_number() == b.to_number(),
+    }
+}
+
+/// The names of the supported functions and their minimum and maximum numbers of
+/// arguments.
+const FUNCTIONS: [(&'static str, usize, usize); 27] = [
+    ("if", 3, 3),
+    ("abs", 1, 1),
+    ("sqrt", 1, 1),
+    ("exp", 1, 1),
+    ("ln", 1, 1),
+    ("log10", 1, 1),
+    ("sin", 1, 1),
+    ("cos", 1, 1),
+    ("tan", 1, 1),
+    ("asin", 1, 1),
+    ("acos", 1, 1),
+    ("atan", 1, 1),
+    ("floor", 1, 1),
+    ("ceil", 1, 1),
+    ("round", 1, 2),
+    ("pow", 2, 2),
+    ("min", 1, usize::max_value()),
+    ("max", 1, usize::max_value()),
+    ("upper", 1, 1),
+    ("lower", 1, 1),
+    ("trim", 1, 1),
+    ("len", 1, 1),
+    ("substr", 2, 3),
+    ("str", 1, 1),
+    ("num", 1, 1),
+    ("isnull", 1, 1),
+    ("concat", 1, usize::max_value()),
+];
+
+fn evaluate_function(name: &str, args: &[Value]) -> Result<Value, String> {
+    match name {
+        "isnull" => return Ok(Value::Bool(args[0] == Value::Null)),
+        "str" => {
+            return Ok(match args[0] {
+                Value::Null => Value::Null,
+                ref v => Value::Text(v.to_text()),
+            })
+        }
+        "num" => {
+            return Ok(match args[0].to_number() {
+                Some(x) => Value::Number(x),
+                None => Value::Null,
+            })
+        }
+        "concat" => {
+            let mut s = String::new();
+            for a in args {
+                s.push_str(&a.to_text());
+            }
+            return Ok(Value::Text(s));
+        }
+        _ => {}
+    }
+    if args.iter().any(|a| *a == Value::Null) {
+        return Ok(Value::Null);
+    }
+    match name {
+        "upper" | "lower" | "trim" | "len" | "substr" => {
+            let s = args[0].to_text();
+            Ok(match name {
+                "upper" => Value::Text(s.to_uppercase()),
+                "lower" => Value::Text(s.to_lowercase()),
+                "trim" => Value::Text(s.trim().to_string()),
+                "len" => Value::Number(s.chars().count() as f64),
+                _ => {
+                    let start = number_arg(name, &args[1])?.max(0f64) as usize;
+                    let length = if args.len() > 2 {
+                        number_arg(name, &args[2])?.max(0f64) as usize
+                    } else {
+                        usize::max_value()
+                    };
+                    Value::Text(s.chars().skip(start).take(length).collect())
+                }
+            })
+        }
+        "min" | "max" => {
+            let mut z = number_arg(name, &args[0])?;
+            for a in &args[1..] {
+                let x = number_arg(name, a)?;
+                z = if name == "min" { z.min(x) } else { z.max(x) };
+            }
+            Ok(Value::Number(z))
+        }
+        "round" => {
+            let x = number_arg(name, &args[0])?;
+            if args.len() > 1 {
+                let m = 10f64.powi(number_arg(name, &args[1])? as i32);
+                Ok(Value::Number((x * m).round() / m))
+            } else {
+                Ok(Value::Number(x.round()))
+            }
+        }
+        "pow" => Ok(Value::Number(
+            number_arg(name, &args[0])?.powf(number_arg(name, &args[1])?),
+        )),
+        _ => {
+            let x = number_arg(name, &args[0])?;
+            let z = match name {
+                "abs" => x.abs(),
+                "sqrt" => x.sqrt(),
+                "exp" => x.exp(),
+                "ln" => x.ln(),
+                "log10" => x.log10(),
+                "sin" => x.sin(),
+                "cos" => x.cos(),
+                "tan" => x.tan(),
+                "asin" => x.asin(),
+                "acos" => x.acos(),
+                "atan" => x.atan(),
+                "floor" => x.floor(),
+                _ => x.ceil(),
+            };
+            Ok(Value::Number(z))
+        }
+    }
+}
+
+fn number_arg(function: &str, v: &Value) -> Result<f64, String> {
+    match *v {
+        Value::Number(x) => Ok(x),
+        _ => Err(format!(
+            "the {} function requires a number but was given a {} value",
+            function,
+            v.type_name()
+        )),
+    }
+}
+
+/// A recursive-descent parser of expressions.
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    field_names: &'a [String],
+}
+
+impl<'a> Parser<'a> {
+    fn parse(expression: &str, field_names: &'a [String]) -> Result<Expr, String> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            pos: 0,
+            field_names: field_names,
+        };
+        if parser.tokens.is_empty() {
+            return Err("the expression is empty".to_string());
+        }
+        let expr = parser.parse_or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(format!("unexpected {:?}", parser.tokens[parser.pos]));
+        }
+        Ok(expr)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    /// Consumes the next token if it is one of the operators (or keywords) `ops`,
+    /// returning the operator's canonical name.
+    fn next_op(&mut self, ops: &[&str]) -> Option<String> {
+        let op = match self.peek() {
+            Some(&Token::Op(ref op)) => op.clone(),
+            Some(&Token::Ident(ref name)) => name.to_lowercase(),
+            _ => return None,
+        };
+        if ops.contains(&op.as_ref()) {
+            self.pos += 1;
+            let op = match op.as_ref() {
+                "&&" => "and".to_string(),
+                "||" => "or".to_string(),
+                "!" => "not".to_string(),
+                _ => op,
+            };
+            Some(op)
+        } else {
+            None
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut e = self.parse_and()?;
+        while let Some(op) = self.next_op(&["or", "||"]) {
+            e = Expr::Binary(op, Box::new(e), Box::new(self.parse_and()?));
+        }
+        Ok(e)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut e = self.parse_not()?;
+        while let Some(op) = self.next_op(&["and", "&&"]) {
+            e = Expr::Binary(op, Box::new(e), Box::new(self.parse_not()?));
+        }
+        Ok(e)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, String> {
+        if let Some(op) = self.next_op(&["not", "!"]) {
+            return Ok(Expr::Unary(op, Box::new(self.parse_not()?)));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, String> {
+        let e = self.parse_additive()?;
+        if let Some(op) = self.next_op(&["==", "!=", "<>", "<", "<=", ">", ">="]) {
+            return Ok(Expr::Binary(op, Box::new(e), Box::new(self.parse_additive()?)));
+        }
+        Ok(e)
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, String> {
+        let mut e = self.parse_multiplicative()?;
+        while let Some(op) = self.next_op(&["+", "-"]) {
+            e = Expr::Binary(op, Box::new(e), Box::new(self.parse_multiplicative()?));
+        }
+        Ok(e)
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, String> {
+        let mut e = self.parse_unary()?;
+        while let Some(op) = self.next_op(&["*", "/", "%"]) {
+            e = Expr::Binary(op, Box::new(e), Box::new(self.parse_unary()?));
+        }
+        Ok(e)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, String> {
+        if let Some(op) = self.next_op(&["-", "+"]) {
+            let e = self.parse_unary()?;
+            return Ok(if op == "-" {
+                Expr::Unary(op, Box::new(e))
+            } else {
+                e
+            });
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> Result<Expr, String> {
+        let e = self.parse_primary()?;
+        if let Some(op) = self.next_op(&["^"]) {
+            // right-associative, and binding more tightly than unary minus on its left
+            return Ok(Expr::Binary(op, Box::new(e), Box::new(self.parse_unary()?)));
+        }
+        Ok(e)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        let token = match self.peek() {
+            Some(t) => t.clone(),
+            None => return Err("unexpected end of expression".to_string()),
+        };
+        self.pos += 1;
+        match token {
+            Token::Number(x) => Ok(Expr::Literal(Value::Number(x))),
+            Token::Text(s) => Ok(Expr::Literal(Value::Text(s))),
+            Token::Field(name) => self.field(&name),
+            Token::LeftParen => {
+                let e = self.parse_or()?;
+                self.expect(Token::RightParen)?;
+                Ok(e)
+            }
+            Token::Ident(name) => {
+                if self.peek() == Some(&Token::LeftParen) {
+                    self.pos += 1;
+                    return self.parse_function(&name);
+                }
+                match name.to_lowercase().as_ref() {
+                    "true" => Ok(Expr::Literal(Value::Bool(true))),
+                    "false" => Ok(Expr::Literal(Value::Bool(false))),
+                    "null" => Ok(Expr::Literal(Value::Null)),
+                    _ => self.field(&name),
+                }
+            }
+            t => Err(format!("unexpected {:?}", t)),
+        }
+    }
+
+    fn parse_function(&mut self, name: &str) -> Result<Expr, String> {
+        let name = name.to_lowercase();
+        let (min_args, max_args) = match FUNCTIONS.iter().find(|f| f.0 == name) {
+            Some(f) => (f.1, f.2),
+            None => return Err(format!("unknown function '{}'", name)),
+        };
+        let mut args = vec![];
+        if self.peek() != Some(&Token::RightParen) {
+            loop {
+                args.push(self.parse_or()?);
+                if self.peek() == Some(&Token::Comma) {
+                    self.pos += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+        self.expect(Token::RightParen)?;
+        if args.len() < min_args || args.len() > max_args {
+            return Err(format!(
+                "wrong number of arguments ({}) for the {} function",
+                args.len(),
+                name
+            ));
+        }
+        Ok(Expr::Function(name, args))
+    }
+
+    fn field(&self, name: &str) -> Result<Expr, String> {
+        // exact matches take precedence over case-insensitive matches
+        if let Some(i) = self.field_names.iter().position(|f| f == name) {
+            return Ok(Expr::Field(i));
+        }
+        let lower = name.to_lowercase();
+        match self.field_names.iter().position(|f| f.to_lowercase() == lower) {
+            Some(i) => Ok(Expr::Field(i)),
+            None => Err(format!("unknown field '{}'", name)),
+        }
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), String> {
+        if self.peek() == Some(&token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!("expected {:?}", token))
+        }
+    }
+}
@@ -123,34 +123,16 @@ impl WhiteboxTool for ConvertNodataToZero {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -202,7 +184,7 @@ impl WhiteboxTool for ConvertNodataToZero {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -217,22 +199,19 @@ impl WhiteboxTool for ConvertNodataToZero {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
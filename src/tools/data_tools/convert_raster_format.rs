@@ -126,34 +126,16 @@ impl WhiteboxTool for ConvertRasterFormat {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -166,14 +148,14 @@ impl WhiteboxTool for ConvertRasterFormat {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
-        println!("Initializing the output raster...");
+        report::message("Initializing the output raster...");
         match output.set_data_from_raster(&input) {
             Ok(_) => (), // do nothings
             Err(err) => return Err(err),
@@ -196,22 +178,19 @@ impl WhiteboxTool for ConvertRasterFormat {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,424 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::csv_points_to_vector::read_csv_points;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use tools::*;
+
+/// Used to give the in-memory points of concurrent runs unique names.
+static RUN_COUNTER: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// This tool interpolates a raster (`--output`) directly from a delimited text file of
+/// x, y, value points (`--input`), such as a CSV file of survey data or an XYZ file,
+/// without first creating a vector. The text file is read in the same way as by the
+/// `CsvPointsToVector` tool, i.e. values may be separated by commas, semicolons, tabs, or
+/// spaces, a header row is optional, and the coordinate fields may be specified
+/// (`--x_field` and `--y_field`) or located by their conventional names. The interpolated
+/// field (`--field`) is, by default, the field named Z, or the third field of the file.
+///
+/// Three interpolation methods (`--method`) are available:
+///
+/// - `nearest`: each cell is assigned the value of the nearest point, optionally within a
+///   maximum search distance (`--max_dist`); see `NearestNeighbourGridding`.
+/// - `idw`: inverse-distance weighting, with a weight exponent (`--weight`), search
+///   radius (`--radius`), and minimum number of points (`--min_points`); see
+///   `IdwInterpolation`. This is the default.
+/// - `tin`: linear interpolation within the triangles of a Delaunay triangulation of the
+///   points; see `TINGridding`.
+///
+/// The output grid is defined by a cell size (`--cell_size`), or, for the nearest and
+/// IDW methods, alternatively by a base raster (`--base`) whose grid is used. The TIN
+/// method requires a cell size.
+///
+/// # See Also
+/// `CsvPointsToVector`, `NearestNeighbourGridding`, `IdwInterpolation`, `TINGridding`
+pub struct CsvPointsToRaster {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl CsvPointsToRaster {
+    pub fn new() -> CsvPointsToRaster {
+        // public constructor
+        let name = "CsvPointsToRaster".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Interpolates a raster from a CSV or XYZ text file of point values.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Text File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input CSV or XYZ text file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Value Field".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Name of the field of interpolated values (optional).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "X Field".to_owned(),
+            flags: vec!["--x_field".to_owned()],
+            description: "Name of the field containing x coordinates (optional).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Y Field".to_owned(),
+            flags: vec!["--y_field".to_owned()],
+            description: "Name of the field containing y coordinates (optional).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Interpolation Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Interpolation method.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "nearest".to_owned(),
+                "idw".to_owned(),
+                "tin".to_owned(),
+            ]),
+            default_value: Some("idw".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cell Size (optional)".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Cell size of the output raster; required by the TIN method.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Base Raster File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Base raster defining the output grid; not used with a cell size."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "IDW Weight (Exponent) Value".to_owned(),
+            flags: vec!["--weight".to_owned()],
+            description: "IDW weight value.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Radius".to_owned(),
+            flags: vec!["--radius".to_owned()],
+            description: "IDW search radius (optional).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Min. Number of Points".to_owned(),
+            flags: vec!["--min_points".to_owned()],
+            description: "IDW minimum number of points (optional).".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Search Distance".to_owned(),
+            flags: vec!["--max_dist".to_owned()],
+            description: "Nearest neighbour maximum search distance (optional).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=survey.xyz -o=surface.tif --method=tin --cell_size=2.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=survey.csv -o=surface.tif --field=ELEV --method=idw --base=DEM.tif --radius=50.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        CsvPointsToRaster {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for CsvPointsToRaster {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut field_name = String::new();
+        let mut x_field = String::new();
+        let mut y_field = String::new();
+        let mut method = "idw".to_string();
+        let mut cell_size: Option<f64> = None;
+        let mut base_file = String::new();
+        let mut weight: Option<f64> = None;
+        let mut radius: Option<f64> = None;
+        let mut min_points: Option<usize> = None;
+        let mut max_dist: Option<f64> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--x_field"])? {
+            x_field = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--y_field"])? {
+            y_field = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            cell_size = Some(v);
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--weight"])? {
+            weight = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            radius = Some(v);
+        }
+        if let Some(v) = tool_args.get_usize(&["--min_points"])? {
+            min_points = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_dist"])? {
+            max_dist = Some(v);
+        }
+
+        let tool_name = if method.contains("near") {
+            "NearestNeighbourGridding"
+        } else if method.contains("tin") {
+            "TINGridding"
+        } else if method.contains("idw") {
+            "IdwInterpolation"
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized interpolation method '{}'.", method),
+            ));
+        };
+        if cell_size.is_none() && (base_file.is_empty() || tool_name == "TINGridding") {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                if tool_name == "TINGridding" {
+                    "The TIN method requires a cell size (--cell_size)."
+                } else {
+                    "Either a cell size (--cell_size) or a base raster (--base) must be specified."
+                },
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !base_file.is_empty() && !base_file.contains(&sep) && !base_file.contains("/") {
+            base_file = format!("{}{}", working_directory, base_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let start = Instant::now();
+        let run_id = RUN_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let points_name = format!("memory://csv_points_{}.shp", run_id);
+        let (points, num_skipped) =
+            read_csv_points(&input_file, &points_name, &x_field, &y_field)?;
+
+        // the interpolated field
+        let fields = points.attributes.get_fields().clone();
+        let field_index = if !field_name.is_empty() {
+            fields.iter().position(|f| f.name == field_name)
+        } else {
+            match fields.iter().position(|f| f.name.to_lowercase() == "z") {
+                Some(i) => Some(i),
+                None if fields.len() > 2 => Some(2),
+                None => None,
+            }
+        };
+        let field_index = match field_index {
+            Some(i) => i,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The field of interpolated values was not located in the input file.",
+                ))
+            }
+        };
+        if fields[field_index].field_type != 'N' && fields[field_index].field_type != 'F' {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("The field {} is not numeric.", fields[field_index].name),
+            ));
+        }
+        field_name = fields[field_index].name.clone();
+
+        if verbose {
+            report::message(&format!(
+                "Interpolating the {} values of {} points...",
+                field_name, points.num_records
+            ));
+            if num_skipped > 0 {
+                report::warning(&format!(
+                    "{} rows with missing or invalid coordinates were skipped.",
+                    num_skipped
+                ));
+            }
+        }
+
+        // the points are passed to the gridding tool in memory
+        let mut runner = ToolRunner::new(tool_name)
+            .vector("input", points)
+            .arg("field", &field_name)
+            .arg("output", &output_file)
+            .verbose(verbose);
+        if tool_name == "TINGridding" {
+            runner = runner.arg("resolution", cell_size.unwrap());
+        } else if let Some(v) = cell_size {
+            runner = runner.arg("cell_size", v);
+        } else {
+            runner = runner.arg("base", &base_file);
+        }
+        if tool_name == "IdwInterpolation" {
+            if let Some(v) = weight {
+                runner = runner.arg("weight", v);
+            }
+            if let Some(v) = radius {
+                runner = runner.arg("radius", v);
+            }
+            if let Some(v) = min_points {
+                runner = runner.arg("min_points", v);
+            }
+        } else if tool_name == "NearestNeighbourGridding" {
+            if let Some(v) = max_dist {
+                runner = runner.arg("max_dist", v);
+            }
+        }
+        runner.run()?;
+
+        if verbose {
+            let elapsed_time = get_formatted_elapsed_time(start);
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
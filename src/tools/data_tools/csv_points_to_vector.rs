@@ -0,0 +1,336 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::join_table_to_vector::{get_unique_field_name, read_csv_table};
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufReader, Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool converts a delimited text file of point coordinates (`--input`), such as a
+/// CSV file of survey data or an XYZ file, into a vector file of POINT ShapeType
+/// (`--output`). Each row of the file becomes a point feature and all of the file's
+/// columns, including the coordinates, become fields of the output attribute table. The
+/// data type of each field (Integer, Float, Boolean, or Text) is inferred from its values.
+///
+/// Values may be separated by commas, semicolons, tabs, or spaces. The file normally
+/// contains a header row of field names; if every value of the first row is a number, the
+/// file is assumed to have no header and the columns are named X, Y, Z, FIELD4, and so on.
+/// The fields containing the x and y coordinates may be specified (`--x_field` and
+/// `--y_field`); otherwise, the fields named X/EASTING/LON/LONGITUDE and
+/// Y/NORTHING/LAT/LATITUDE (in any case), or failing these the first two fields, are used.
+/// Rows with missing or non-numeric coordinates are skipped and their number is reported.
+///
+/// The coordinate system of the points may be specified by a projection file (`--prj`),
+/// e.g. the .prj file of another vector in the same coordinate system, which is copied to
+/// the output.
+///
+/// # See Also
+/// `CsvPointsToRaster`, `JoinTableToVector`, `AttributesToCsv`
+pub struct CsvPointsToVector {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl CsvPointsToVector {
+    pub fn new() -> CsvPointsToVector {
+        // public constructor
+        let name = "CsvPointsToVector".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Converts a CSV or XYZ text file of point coordinates into a point vector.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Text File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input CSV or XYZ text file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "X Field".to_owned(),
+            flags: vec!["--x_field".to_owned()],
+            description: "Name of the field containing x coordinates (optional).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Y Field".to_owned(),
+            flags: vec!["--y_field".to_owned()],
+            description: "Name of the field containing y coordinates (optional).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Projection File".to_owned(),
+            flags: vec!["--prj".to_owned()],
+            description: "Projection (.prj) file of the points' coordinate system (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Any),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=survey.csv -o=survey.shp --x_field=EASTING --y_field=NORTHING --prj=DEM.prj",
+            short_exe, name
+        ).replace("*", &sep);
+
+        CsvPointsToVector {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for CsvPointsToVector {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut x_field = String::new();
+        let mut y_field = String::new();
+        let mut prj_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--x_field"])? {
+            x_field = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--y_field"])? {
+            y_field = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--prj"])? {
+            prj_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !prj_file.is_empty() && !prj_file.contains(&sep) && !prj_file.contains("/") {
+            prj_file = format!("{}{}", working_directory, prj_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let start = Instant::now();
+        let (mut output, num_skipped) =
+            read_csv_points(&input_file, &output_file, &x_field, &y_field)?;
+        if !prj_file.is_empty() {
+            let mut projection = String::new();
+            BufReader::new(File::open(&prj_file)?).read_to_string(&mut projection)?;
+            output.projection = projection;
+        }
+
+        if verbose {
+            report::message(&format!("{} points were read.", output.num_records));
+            if num_skipped > 0 {
+                report::warning(&format!(
+                    "{} rows with missing or invalid coordinates were skipped.",
+                    num_skipped
+                ));
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a delimited text file of point coordinates into a point vector named
+/// `output_file`, returning the vector and the number of rows that were skipped because
+/// their coordinates were missing or invalid. The coordinate fields are located by name,
+/// if specified, and otherwise by their conventional names or positions.
+pub fn read_csv_points(
+    input_file: &str,
+    output_file: &str,
+    x_field: &str,
+    y_field: &str,
+) -> Result<(Shapefile, usize), Error> {
+    let (mut fields, records) = read_csv_table(input_file)?;
+    if fields.len() < 2 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The input file must contain at least two fields (x and y coordinates).",
+        ));
+    }
+    let x_index = find_field(&fields, x_field, &["x", "easting", "lon", "longitude"], 0)?;
+    let y_index = find_field(&fields, y_field, &["y", "northing", "lat", "latitude"], 1)?;
+
+    // field names are limited to 10 characters by the dBase format
+    let mut names: Vec<String> = vec![];
+    for field in &mut fields {
+        field.name = get_unique_field_name(&field.name, &names);
+        names.push(field.name.clone());
+    }
+    let mut output = Shapefile::new(output_file, ShapeType::Point)?;
+    output.attributes.add_fields(&fields);
+    let mut num_skipped = 0;
+    for record in records {
+        let x = match record[x_index] {
+            FieldData::Int(v) => v as f64,
+            FieldData::Real(v) => v,
+            _ => {
+                num_skipped += 1;
+                continue;
+            }
+        };
+        let y = match record[y_index] {
+            FieldData::Int(v) => v as f64,
+            FieldData::Real(v) => v,
+            _ => {
+                num_skipped += 1;
+                continue;
+            }
+        };
+        output.add_point_record(x, y);
+        output.attributes.add_record(record, false);
+    }
+    if output.num_records == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The input file does not contain any points with valid coordinates.",
+        ));
+    }
+    Ok((output, num_skipped))
+}
+
+fn find_field(
+    fields: &[AttributeField],
+    name: &str,
+    conventional_names: &[&str],
+    default_index: usize,
+) -> Result<usize, Error> {
+    if !name.is_empty() {
+        return match fields.iter().position(|f| f.name == name) {
+            Some(i) => Ok(i),
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("The field {} was not located in the input file.", name),
+            )),
+        };
+    }
+    for n in conventional_names {
+        if let Some(i) = fields.iter().position(|f| f.name.to_lowercase() == *n) {
+            return Ok(i);
+        }
+    }
+    Ok(default_index)
+}
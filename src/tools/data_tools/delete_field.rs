@@ -0,0 +1,226 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool removes one or more fields (`--field`) from the attribute table of a
+/// vector file (`--input`). Several fields may be removed at once by separating their
+/// names with commas or semicolons. At least one field must remain in the table.
+///
+/// If an output file (`--output`) is specified, the modified vector is written to it
+/// and the input file is left unaltered; otherwise, the input file is modified in place.
+///
+/// # See Also
+/// `AddField`, `CalculateField`
+pub struct DeleteField {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DeleteField {
+    pub fn new() -> DeleteField {
+        // public constructor
+        let name = "DeleteField".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description = "Deletes one or more fields from the attribute table of a vector file.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Names".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Name of the field(s) to delete, separated by commas.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file; the input is modified in place if not specified."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp --field='TEMP,COUNT' -o=output.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        DeleteField {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DeleteField {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut field_names = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_names = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if output_file.is_empty() {
+            output_file = input_file.clone();
+        } else if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let start = Instant::now();
+        let mut vector = Shapefile::read(&input_file)?;
+        let delimiter = if field_names.contains(";") { ";" } else { "," };
+        let mut num_deleted = 0;
+        for name in field_names.split(delimiter).map(|f| f.trim()).filter(|f| !f.is_empty()) {
+            match vector.attributes.get_field_num(name) {
+                Some(i) => {
+                    if vector.attributes.get_num_fields() == 1 {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "The attribute table must contain at least one field.",
+                        ));
+                    }
+                    vector.attributes.remove_field(i);
+                    num_deleted += 1;
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Attribute {} not found in table.", name),
+                    ));
+                }
+            }
+        }
+        if num_deleted == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "No field names were specified.",
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        vector.file_name = output_file;
+        vector.file_mode = "w".to_string();
+        let _ = match vector.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
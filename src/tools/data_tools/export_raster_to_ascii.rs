@@ -0,0 +1,273 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::arcascii_raster::write_arcascii;
+use raster::grass_raster::write_grass_raster;
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+use utils::settings;
+
+/// This tool exports a raster (`--input`) to an ASCII grid (`--output`), either in the
+/// Esri ASCII format (`.asc`) or the GRASS ASCII format (`.txt`), for use with models
+/// and software that only read these formats. The format is specified using `--format`
+/// ('Esri' or 'GRASS'); when it is not specified, it is based on the output file extension.
+///
+/// Floating-point values are written with `--precision` decimal places. By default, they
+/// are written with as many digits as are needed to represent each value exactly (or with
+/// the number of decimal places set by the `ascii_precision` setting). Integer data are
+/// always written without decimal places.
+///
+/// Many models expect a particular nodata value (e.g. -9999), which can be specified using
+/// `--nodata`; otherwise, the nodata value of the input raster is used. NaN values are
+/// treated as nodata.
+///
+/// # See Also
+/// `ConvertRasterFormat`
+pub struct ExportRasterToAscii {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ExportRasterToAscii {
+    pub fn new() -> ExportRasterToAscii {
+        // public constructor
+        let name = "ExportRasterToAscii".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Exports a raster to an Esri ASCII or GRASS ASCII grid with a specified precision."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output ASCII raster file (.asc or .txt).".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "ASCII Format".to_owned(),
+            flags: vec!["--format".to_owned()],
+            description: "ASCII raster format; based on the output file extension by default."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "Esri".to_owned(),
+                "GRASS".to_owned(),
+            ]),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Decimal Places".to_owned(),
+            flags: vec!["--precision".to_owned()],
+            description: "Number of decimal places of floating-point values (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output NoData Value".to_owned(),
+            flags: vec!["--nodata".to_owned()],
+            description: "Output nodata value; the input nodata value is used by default."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=DEM.asc --precision=3 --nodata=-9999", short_exe, name).replace("*", &sep);
+
+        ExportRasterToAscii {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ExportRasterToAscii {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut format = String::new();
+        let mut precision = settings::get_settings().ascii_precision;
+        let mut out_nodata: Option<f64> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--format"])? {
+            format = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_usize(&["--precision"])? {
+            precision = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--nodata"])? {
+            out_nodata = Some(v);
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        // determine the format, and the output file extension
+        let extension = match Path::new(&output_file).extension() {
+            Some(ext) => ext.to_string_lossy().to_lowercase(),
+            None => String::new(),
+        };
+        let grass_format = if format.is_empty() {
+            extension == "txt"
+        } else if format.contains("grass") {
+            true
+        } else if format.contains("esri") || format.contains("arc") {
+            false
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized ASCII format '{}'; use 'Esri' or 'GRASS'.", format),
+            ));
+        };
+        if extension.is_empty() {
+            output_file.push_str(if grass_format { ".txt" } else { ".asc" });
+        } else if extension != "asc" && extension != "txt" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The output file must have a .asc or .txt extension.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        let start = Instant::now();
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.raster_type = if grass_format {
+            RasterType::GrassAscii
+        } else {
+            RasterType::ArcAscii
+        };
+        output.set_data_from_raster(&input)?;
+        if let Some(nodata) = out_nodata {
+            output.set_nodata_value(nodata);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        if grass_format {
+            write_grass_raster(&mut output, precision)?;
+        } else {
+            write_arcascii(&mut output, precision)?;
+        }
+        if verbose {
+            report::message("Output file written")
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
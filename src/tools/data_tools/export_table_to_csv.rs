@@ -139,37 +139,19 @@ impl WhiteboxTool for ExportTableToCsv {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-headers" {
-                headers = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--headers"])? {
+            headers = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -185,7 +167,7 @@ impl WhiteboxTool for ExportTableToCsv {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -245,7 +227,7 @@ impl WhiteboxTool for ExportTableToCsv {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Writing attributes: {}%", progress);
+                    report::progress("Writing attributes", progress);
                     old_progress = progress;
                 }
             }
@@ -253,10 +235,7 @@ impl WhiteboxTool for ExportTableToCsv {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,321 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool pads a raster (`--input`) with nodata cells, such that the output raster
+/// (`--output`) covers a larger extent. The target extent may be specified using any of
+/// the `--north`, `--south`, `--east`, and `--west` coordinates, and/or a number of
+/// cells (`--cells`) with which to pad each of the edges of the grid. The target extent
+/// is snapped outwards to the grid of the input raster, such that the output raster has
+/// the same cell size and grid alignment as the input and the values of the input cells
+/// are unaltered. Edges of the target extent that fall within the extent of the input
+/// raster are ignored, i.e. the raster is never cropped by this tool.
+///
+/// # See Also
+/// `Shrink`, `SetExtentFromReference`, `Resample`
+pub struct Extend {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Extend {
+    pub fn new() -> Extend {
+        // public constructor
+        let name = "Extend".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description = "Pads a raster with nodata cells to a larger extent.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "North Coordinate".to_owned(),
+            flags: vec!["--north".to_owned()],
+            description: "Northern edge of the target extent (optional).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "South Coordinate".to_owned(),
+            flags: vec!["--south".to_owned()],
+            description: "Southern edge of the target extent (optional).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "East Coordinate".to_owned(),
+            flags: vec!["--east".to_owned()],
+            description: "Eastern edge of the target extent (optional).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "West Coordinate".to_owned(),
+            flags: vec!["--west".to_owned()],
+            description: "Western edge of the target extent (optional).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Cells".to_owned(),
+            flags: vec!["--cells".to_owned()],
+            description: "Number of nodata cells added to each edge of the grid (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --north=4890000.0 --west=660000.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --cells=10", short_exe, name).replace("*", &sep);
+
+        Extend {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Extend {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut north = f64::NEG_INFINITY;
+        let mut south = f64::INFINITY;
+        let mut east = f64::NEG_INFINITY;
+        let mut west = f64::INFINITY;
+        let mut num_cells = 0usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--north"])? {
+            north = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--south"])? {
+            south = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--east"])? {
+            east = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--west"])? {
+            west = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--cells"])? {
+            num_cells = v;
+        }
+
+        if north.is_infinite()
+            && south.is_infinite()
+            && east.is_infinite()
+            && west.is_infinite()
+            && num_cells == 0
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A target extent (--north, --south, --east, --west) or a number of cells (--cells) must be specified.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        let start = Instant::now();
+
+        // the number of cells added to each edge, snapping the target extent outwards
+        let res_x = input.configs.resolution_x;
+        let res_y = input.configs.resolution_y;
+        let cells_beyond = |distance: f64, resolution: f64| {
+            if distance > 0f64 {
+                (distance / resolution - 1e-6).ceil().max(0f64) as usize
+            } else {
+                0usize
+            }
+        };
+        let top = cells_beyond(north - input.configs.north, res_y).max(num_cells);
+        let bottom = cells_beyond(input.configs.south - south, res_y).max(num_cells);
+        let right = cells_beyond(east - input.configs.east, res_x).max(num_cells);
+        let left = cells_beyond(input.configs.west - west, res_x).max(num_cells);
+
+        let mut configs = input.configs.clone();
+        configs.rows += top + bottom;
+        configs.columns += left + right;
+        configs.north += top as f64 * res_y;
+        configs.south -= bottom as f64 * res_y;
+        configs.east += right as f64 * res_x;
+        configs.west -= left as f64 * res_x;
+        configs.metadata.clear();
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let out_nodata = output.configs.nodata;
+        let (top, left) = (top as isize, left as isize);
+        let mut z: f64;
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != nodata {
+                    output.set_value(row + top, col + left, z);
+                } else {
+                    output.set_value(row + top, col + left, out_nodata);
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message(&format!(
+                "Output grid: {} rows x {} columns (input: {} x {})",
+                output.configs.rows, output.configs.columns, rows, columns
+            ));
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
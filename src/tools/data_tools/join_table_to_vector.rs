@@ -0,0 +1,678 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufReader, Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool joins the columns of a table (`--table`) to the attribute table of a vector
+/// file (`--input`), matching each feature to the table record whose *foreign key*
+/// (`--fkey`) equals the feature's *primary key* (`--pkey`). It may be used, for example,
+/// to map the outputs of a hydrological model, tabulated by basin ID, back onto the basin
+/// polygons. The table may be either a comma separated values (CSV) text file or a dBase
+/// (.dbf) file, such as the attribute table of another vector. CSV files must contain a
+/// header row of field names; the fields may be separated by commas, semicolons, or tabs,
+/// and values containing the delimiter may be enclosed in double quotes.
+///
+/// The data type of each CSV column is inferred from all of its values: columns in which
+/// every non-empty value is an integer become Integer fields, those containing decimal
+/// numbers become Float fields (with the greatest precision of the values), columns of
+/// `true`/`false` values become Boolean fields, and all others become Text fields. Empty
+/// values are null. By default, all table fields other than the foreign key are joined;
+/// alternatively, a comma-separated list of the fields to join may be given (`--import`).
+/// Joined fields whose names conflict with existing fields, or exceed the 10-character
+/// limit of the dBase format, are renamed.
+///
+/// Keys are compared as text, after trimming spaces, with numeric values written in their
+/// shortest form, such that an integer primary key of 12 matches a foreign key of `12`
+/// or `12.0`, but not `012`. Matching is case sensitive. The join supports one-to-one and
+/// many-to-one relations; if several table records share a foreign key, the first is used.
+/// Features without a matching table record are either retained with null values in the
+/// joined fields (`--unmatched=keep`, the default) or omitted from the output
+/// (`--unmatched=drop`). The numbers of unmatched features and duplicated keys are
+/// reported.
+///
+/// If an output file (`--output`) is specified, the joined vector is written to it and
+/// the input file is left unaltered; otherwise, the input file is modified in place.
+///
+/// # See Also
+/// `MergeTableWithCsv`, `JoinTables`, `ExportTableToCsv`
+pub struct JoinTableToVector {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl JoinTableToVector {
+    pub fn new() -> JoinTableToVector {
+        // public constructor
+        let name = "JoinTableToVector".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Joins the fields of a CSV or dBase table to a vector's attribute table using a key field."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Primary Key Field".to_owned(),
+            flags: vec!["--pkey".to_owned()],
+            description: "Primary key field of the vector's attribute table.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Table File".to_owned(),
+            flags: vec!["--table".to_owned()],
+            description: "Input CSV (.csv, .txt) or dBase (.dbf) table file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Any),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Foreign Key Field".to_owned(),
+            flags: vec!["--fkey".to_owned()],
+            description: "Foreign key field of the table.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Imported Fields".to_owned(),
+            flags: vec!["--import".to_owned()],
+            description: "Comma-separated table fields to join; all are joined by default (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Unmatched Features".to_owned(),
+            flags: vec!["--unmatched".to_owned()],
+            description: "Keep features without a matching record, with null values, or drop them."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec!["keep".to_owned(), "drop".to_owned()]),
+            default_value: Some("keep".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file; the input is modified in place if not specified."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp --pkey=VALUE --table=model_output.csv --fkey=BASIN_ID -o=joined.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp --pkey=VALUE --table=model_output.csv --fkey=BASIN_ID --import=\"RUNOFF,SED_YIELD\" --unmatched=drop",
+            short_exe, name
+        ).replace("*", &sep);
+
+        JoinTableToVector {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for JoinTableToVector {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut primary_key = String::new();
+        let mut table_file = String::new();
+        let mut foreign_key = String::new();
+        let mut import_fields: Vec<String> = vec![];
+        let mut drop_unmatched = false;
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--pkey", "--primary_key"])? {
+            primary_key = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--table"])? {
+            table_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--fkey", "--foreign_key"])? {
+            foreign_key = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--import"])? {
+            import_fields = v
+                .split(|c| c == ',' || c == ';')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+        if let Some(v) = tool_args.get_string(&["--unmatched"])? {
+            drop_unmatched = v.to_lowercase().contains("drop");
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !table_file.contains(&sep) && !table_file.contains("/") {
+            table_file = format!("{}{}", working_directory, table_file);
+        }
+        if output_file.is_empty() {
+            output_file = input_file.clone();
+        } else if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Shapefile::read(&input_file)?;
+        let pkey_index = match input.attributes.get_field_num(&primary_key) {
+            Some(i) => i,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The primary key field {} was not located in the attribute table.",
+                        primary_key
+                    ),
+                ))
+            }
+        };
+
+        let table = if table_file.to_lowercase().ends_with(".dbf") {
+            let attributes = ShapefileAttributes::read(&table_file)?;
+            let mut records = Vec::with_capacity(attributes.header.num_records as usize);
+            for i in 0..attributes.header.num_records as usize {
+                records.push(attributes.get_record(i));
+            }
+            (attributes.get_fields().clone(), records)
+        } else {
+            read_csv_table(&table_file)?
+        };
+        let (table_fields, table_records) = table;
+
+        let fkey_index = match table_fields.iter().position(|f| f.name == foreign_key) {
+            Some(i) => i,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The foreign key field {} was not located in the table.",
+                        foreign_key
+                    ),
+                ))
+            }
+        };
+
+        // which of the table fields are joined?
+        let mut joined: Vec<usize> = vec![];
+        if import_fields.is_empty() {
+            joined = (0..table_fields.len()).filter(|&i| i != fkey_index).collect();
+        } else {
+            for name in &import_fields {
+                match table_fields.iter().position(|f| &f.name == name) {
+                    Some(i) => {
+                        if !joined.contains(&i) {
+                            joined.push(i);
+                        }
+                    }
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("The field {} was not located in the table.", name),
+                        ))
+                    }
+                }
+            }
+        }
+        if joined.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The table does not contain any fields to join.",
+            ));
+        }
+
+        let start = Instant::now();
+
+        // map the foreign key values to table records; the first of any duplicates is used
+        let mut key_map: HashMap<String, usize> = HashMap::new();
+        let mut num_duplicates = 0;
+        for (i, record) in table_records.iter().enumerate() {
+            if let Some(key) = key_string(&record[fkey_index]) {
+                if key_map.contains_key(&key) {
+                    num_duplicates += 1;
+                } else {
+                    key_map.insert(key, i);
+                }
+            }
+        }
+
+        // create the output file, with the joined fields
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        let mut field_names: Vec<String> = input
+            .attributes
+            .get_fields()
+            .iter()
+            .map(|f| f.name.clone())
+            .collect();
+        for &i in &joined {
+            let mut field = table_fields[i].clone();
+            field.name = get_unique_field_name(&field.name, &field_names);
+            if verbose && field.name != table_fields[i].name {
+                report::message(&format!(
+                    "The table field {} was renamed {}.",
+                    table_fields[i].name, field.name
+                ));
+            }
+            field_names.push(field.name.clone());
+            output.attributes.add_field(&field);
+        }
+
+        let mut num_unmatched = 0;
+        for record_num in 0..input.num_records {
+            let mut atts = input.attributes.get_record(record_num);
+            let table_record = match key_string(&atts[pkey_index]) {
+                Some(key) => key_map.get(&key),
+                None => None,
+            };
+            match table_record {
+                Some(&t) => {
+                    for &i in &joined {
+                        atts.push(table_records[t][i].clone());
+                    }
+                }
+                None => {
+                    num_unmatched += 1;
+                    if drop_unmatched {
+                        continue;
+                    }
+                    for _ in 0..joined.len() {
+                        atts.push(FieldData::Null);
+                    }
+                }
+            }
+            output.add_record(input.get_record(record_num).clone());
+            output.attributes.add_record(atts, false);
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message(&format!(
+                "{} of {} features were matched to table records.",
+                input.num_records - num_unmatched,
+                input.num_records
+            ));
+            if num_duplicates > 0 {
+                report::warning(&format!(
+                    "{} table records had duplicate foreign keys and were ignored.",
+                    num_duplicates
+                ));
+            }
+        }
+
+        if output.num_records == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "None of the features were matched to table records; no output was created.",
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the text used to match key values, or `None` for null values.
+fn key_string(value: &FieldData) -> Option<String> {
+    match *value {
+        FieldData::Int(v) => Some(v.to_string()),
+        FieldData::Real(v) => {
+            if v == v.trunc() && v.abs() < 1e15 {
+                Some((v as i64).to_string())
+            } else {
+                Some(v.to_string())
+            }
+        }
+        FieldData::Text(ref v) => {
+            let v = v.trim();
+            match v.parse::<f64>() {
+                // numeric text is written in the same form as numbers, e.g. '12.0' as '12'
+                Ok(x) if !v.starts_with('0') || v.starts_with("0.") || v == "0" => {
+                    key_string(&FieldData::Real(x))
+                }
+                _ => Some(v.to_string()),
+            }
+        }
+        FieldData::Date(ref v) => Some(v.to_string()),
+        FieldData::Bool(v) => Some(v.to_string()),
+        FieldData::Null => None,
+    }
+}
+
+/// Returns a field name, of no more than 10 characters, that doesn't conflict with the
+/// names of the existing fields.
+pub fn get_unique_field_name(name: &str, existing: &[String]) -> String {
+    let base: String = name.chars().take(10).collect();
+    if !existing.contains(&base) {
+        return base;
+    }
+    let mut n = 1;
+    loop {
+        let suffix = format!("_{}", n);
+        let name: String = base
+            .chars()
+            .take(10 - suffix.len())
+            .chain(suffix.chars())
+            .collect();
+        if !existing.contains(&name) {
+            return name;
+        }
+        n += 1;
+    }
+}
+
+/// Reads a delimited text table, inferring the data type of each column from its values.
+/// Values may be separated by commas, semicolons, tabs, or, if none of these occur in the
+/// first line, spaces. If every value of the first line is a number, the table is assumed
+/// to have no header row (e.g. an XYZ file) and the fields are named X, Y, Z, FIELD4, ...
+pub fn read_csv_table(
+    file_name: &str,
+) -> Result<(Vec<AttributeField>, Vec<Vec<FieldData>>), Error> {
+    let f = BufReader::new(File::open(file_name)?);
+    let mut headers: Vec<String> = vec![];
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut delimiter = ',';
+    for (line_num, line) in f.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if headers.is_empty() {
+            // the delimiter is the most frequent of the candidates in the header row
+            let count = |c: char| line.chars().filter(|&x| x == c).count();
+            delimiter = ',';
+            for &c in &[';', '\t'] {
+                if count(c) > count(delimiter) {
+                    delimiter = c;
+                }
+            }
+            if count(delimiter) == 0 {
+                delimiter = ' ';
+            }
+            headers = split_csv_line(&line, delimiter);
+            if headers.iter().all(|v| v.parse::<f64>().is_ok()) {
+                let names = ["X", "Y", "Z"];
+                let num_fields = headers.len();
+                headers = (0..num_fields)
+                    .map(|i| match names.get(i) {
+                        Some(n) => n.to_string(),
+                        None => format!("FIELD{}", i + 1),
+                    })
+                    .collect();
+            } else {
+                continue;
+            }
+        }
+        let values = split_csv_line(&line, delimiter);
+        if values.len() != headers.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Line {} of the table contains {} values but there are {} fields.",
+                    line_num + 1,
+                    values.len(),
+                    headers.len()
+                ),
+            ));
+        }
+        rows.push(values);
+    }
+    if headers.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The table does not contain a header row.",
+        ));
+    }
+
+    let mut fields = Vec::with_capacity(headers.len());
+    for col in 0..headers.len() {
+        let values: Vec<&str> = rows
+            .iter()
+            .map(|r| r[col].as_ref())
+            .filter(|v: &&str| !v.is_empty())
+            .collect();
+        let length = values.iter().map(|v| v.chars().count()).max().unwrap_or(1);
+        let field = if !values.is_empty() && values.iter().all(|v| v.parse::<i32>().is_ok()) {
+            AttributeField::new(&headers[col], FieldDataType::Int, length.min(18) as u8, 0u8)
+        } else if !values.is_empty() && values.iter().all(|v| v.parse::<f64>().is_ok()) {
+            let decimals = values
+                .iter()
+                .map(|v| match v.find('.') {
+                    Some(p) => v.len() - p - 1,
+                    None => 0,
+                })
+                .max()
+                .unwrap_or(0)
+                .max(1)
+                .min(15);
+            AttributeField::new(
+                &headers[col],
+                FieldDataType::Real,
+                (length + decimals + 2).min(30) as u8,
+                decimals as u8,
+            )
+        } else if !values.is_empty() && values.iter().all(|v| v.parse::<bool>().is_ok()) {
+            AttributeField::new(&headers[col], FieldDataType::Bool, 1u8, 0u8)
+        } else {
+            AttributeField::new(&headers[col], FieldDataType::Text, length.min(254) as u8, 0u8)
+        };
+        fields.push(field);
+    }
+
+    let mut records = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut record = Vec::with_capacity(row.len());
+        for col in 0..row.len() {
+            let v = &row[col];
+            record.push(if v.is_empty() {
+                FieldData::Null
+            } else {
+                match fields[col].field_type {
+                    'N' => FieldData::Int(v.parse::<i32>().unwrap()),
+                    'F' => FieldData::Real(v.parse::<f64>().unwrap()),
+                    'L' => FieldData::Bool(v.parse::<bool>().unwrap()),
+                    _ => FieldData::Text(v.clone()),
+                }
+            });
+        }
+        records.push(record);
+    }
+    Ok((fields, records))
+}
+
+/// Splits a line of a CSV file into its trimmed values. Values may be enclosed in double
+/// quotes, within which delimiters are ignored and a doubled quote represents a quote.
+/// A space delimiter matches any run of whitespace.
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    if delimiter == ' ' {
+        let line = line.trim();
+        let mut values = vec![];
+        let mut value = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '"' {
+                if in_quotes && chars.peek() == Some(&'"') {
+                    value.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = !in_quotes;
+                }
+            } else if c.is_whitespace() && !in_quotes {
+                if chars.peek().map_or(false, |c| !c.is_whitespace()) {
+                    values.push(value);
+                    value = String::new();
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        values.push(value);
+        return values;
+    }
+    let mut values = vec![];
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    value.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                value.push(c);
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == delimiter {
+            values.push(value.trim().to_string());
+            value = String::new();
+        } else {
+            value.push(c);
+        }
+    }
+    values.push(value.trim().to_string());
+    values
+}
@@ -194,53 +194,25 @@ impl WhiteboxTool for JoinTables {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i1" || flag_val == "-input1" {
-                input1_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-primary_key" || flag_val == "-pkey" {
-                primary_key = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-i2" || flag_val == "-input2" {
-                input2_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-foreign_key" || flag_val == "-fkey" {
-                foreign_key = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-import" {
-                import_field = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--primary_key", "--pkey"])? {
+            primary_key = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--foreign_key", "--fkey"])? {
+            foreign_key = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--import"])? {
+            import_field = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -256,7 +228,7 @@ impl WhiteboxTool for JoinTables {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input1 = Shapefile::read(&input1_file)?;
         let input2 = Shapefile::read(&input2_file)?;
@@ -365,18 +337,18 @@ impl WhiteboxTool for JoinTables {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input1.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -384,7 +356,7 @@ impl WhiteboxTool for JoinTables {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
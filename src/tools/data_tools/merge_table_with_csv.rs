@@ -198,53 +198,25 @@ impl WhiteboxTool for MergeTableWithCsv {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-primary_key" || flag_val == "-pkey" {
-                primary_key = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-csv" {
-                csv_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-foreign_key" || flag_val == "-fkey" {
-                foreign_key = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-import" {
-                import_field = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--primary_key", "--pkey"])? {
+            primary_key = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--csv"])? {
+            csv_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--foreign_key", "--fkey"])? {
+            foreign_key = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--import"])? {
+            import_field = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -260,7 +232,7 @@ impl WhiteboxTool for MergeTableWithCsv {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Shapefile::read(&input_file)?;
 
@@ -425,18 +397,18 @@ impl WhiteboxTool for MergeTableWithCsv {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -444,7 +416,7 @@ impl WhiteboxTool for MergeTableWithCsv {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -147,29 +147,12 @@ impl WhiteboxTool for MergeVectors {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-inputs" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -179,9 +162,7 @@ impl WhiteboxTool for MergeVectors {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !output_file.contains(&sep) && !output_file.contains("/") {
@@ -227,7 +208,7 @@ impl WhiteboxTool for MergeVectors {
                 in_files.push(input_file.clone());
 
                 if verbose {
-                    println!("Reading '{}'", input_file);
+                    report::message(&format!("Reading '{}'", input_file));
                 };
 
                 let input = Shapefile::read(&input_file)?;
@@ -310,7 +291,7 @@ impl WhiteboxTool for MergeVectors {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -318,11 +299,11 @@ impl WhiteboxTool for MergeVectors {
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -330,7 +311,7 @@ impl WhiteboxTool for MergeVectors {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
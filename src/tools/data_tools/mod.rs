@@ -1,12 +1,23 @@
 // private sub-module defined in other files
+mod add_field;
 mod add_point_coordinates_to_table;
+mod assign_projection;
+mod attributes_to_csv;
+mod calculate_field;
 mod convert_nodata_to_zero;
 mod convert_raster_format;
+mod csv_points_to_raster;
+mod csv_points_to_vector;
+mod delete_field;
+mod export_raster_to_ascii;
 mod export_table_to_csv;
+mod extend;
+mod join_table_to_vector;
 mod join_tables;
 mod lines_to_polygons;
 mod merge_table_with_csv;
 mod merge_vectors;
+mod modify_projection;
 mod multipart_to_singlepart;
 mod new_raster;
 mod polygons_to_lines;
@@ -15,30 +26,46 @@ mod raster_to_vector_lines;
 mod raster_to_vector_points;
 mod reinitialize_attribute_table;
 mod remove_polygon_holes;
+mod set_extent_from_reference;
 mod set_nodata_value;
+mod shrink;
 mod singlepart_to_multipart;
 mod vector_lines_to_raster;
 mod vector_points_to_raster;
 mod vector_polygons_to_raster;
 
 // exports identifiers from private sub-modules in the current module namespace
+pub use self::add_field::AddField;
 pub use self::add_point_coordinates_to_table::AddPointCoordinatesToTable;
+pub use self::assign_projection::AssignProjection;
+pub use self::attributes_to_csv::AttributesToCsv;
+pub use self::calculate_field::CalculateField;
 pub use self::convert_nodata_to_zero::ConvertNodataToZero;
 pub use self::convert_raster_format::ConvertRasterFormat;
+pub use self::csv_points_to_raster::CsvPointsToRaster;
+pub use self::csv_points_to_vector::CsvPointsToVector;
+pub use self::csv_points_to_vector::read_csv_points;
+pub use self::delete_field::DeleteField;
+pub use self::export_raster_to_ascii::ExportRasterToAscii;
 pub use self::export_table_to_csv::ExportTableToCsv;
+pub use self::extend::Extend;
+pub use self::join_table_to_vector::JoinTableToVector;
 pub use self::join_tables::JoinTables;
 pub use self::lines_to_polygons::LinesToPolygons;
 pub use self::merge_table_with_csv::MergeTableWithCsv;
 pub use self::merge_vectors::MergeVectors;
+pub use self::modify_projection::ModifyProjection;
 pub use self::multipart_to_singlepart::MultiPartToSinglePart;
 pub use self::new_raster::NewRasterFromBase;
 pub use self::polygons_to_lines::PolygonsToLines;
 pub use self::print_geotiff_tags::PrintGeoTiffTags;
 pub use self::raster_to_vector_lines::RasterToVectorLines;
-pub use self::raster_to_vector_points::RasterToVectorPoints;
+pub use self::raster_to_vector_points::{raster_to_vector_points, RasterToVectorPoints};
 pub use self::reinitialize_attribute_table::ReinitializeAttributeTable;
 pub use self::remove_polygon_holes::RemovePolygonHoles;
+pub use self::set_extent_from_reference::SetExtentFromReference;
 pub use self::set_nodata_value::SetNodataValue;
+pub use self::shrink::Shrink;
 pub use self::singlepart_to_multipart::SinglePartToMultiPart;
 pub use self::vector_lines_to_raster::VectorLinesToRaster;
 pub use self::vector_points_to_raster::VectorPointsToRaster;
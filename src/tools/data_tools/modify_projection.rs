@@ -0,0 +1,272 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::assign_projection::{get_crs_wkt, read_crs_definition, units_from_wkt, write_crs};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool modifies the coordinate reference system (CRS) definition of a raster or
+/// vector file (`--input`), e.g. to correct a CRS that was assigned incorrectly. As with
+/// `AssignProjection`, the data are not reprojected. The new CRS may be specified using an
+/// EPSG code (`--epsg`) or a WKT definition (`--wkt`), given as text or as the name of a
+/// .prj file. For rasters, the horizontal (`--xy_units`) and vertical (`--z_units`) units
+/// may also be modified, either along with the CRS or on their own; when a new CRS is
+/// specified without `--xy_units`, the horizontal units are taken from the CRS. At least
+/// one modification must be specified.
+///
+/// The result is saved to `--output` if specified; otherwise, the input file is updated.
+///
+/// # See Also
+/// `AssignProjection`
+pub struct ModifyProjection {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ModifyProjection {
+    pub fn new() -> ModifyProjection {
+        // public constructor
+        let name = "ModifyProjection".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Modifies the coordinate reference system definition of a raster or vector."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Raster or Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster or vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output file; the input file is updated if unspecified.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "EPSG Code".to_owned(),
+            flags: vec!["--epsg".to_owned()],
+            description: "EPSG code of the new coordinate reference system.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "WKT Definition".to_owned(),
+            flags: vec!["--wkt".to_owned()],
+            description:
+                "WKT definition of the new coordinate reference system, or a .prj file."
+                    .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Horizontal Units (Rasters)".to_owned(),
+            flags: vec!["--xy_units".to_owned()],
+            description: "New horizontal units of a raster, e.g. metres.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Vertical Units (Rasters)".to_owned(),
+            flags: vec!["--z_units".to_owned()],
+            description: "New vertical units of a raster, e.g. metres.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --epsg=32617 --z_units=metres
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=roads.shp --wkt=roads_crs.prj", short_exe, name).replace("*", &sep);
+
+        ModifyProjection {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ModifyProjection {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut epsg: Option<isize> = None;
+        let mut wkt: Option<String> = None;
+        let mut xy_units: Option<String> = None;
+        let mut z_units: Option<String> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--epsg"])? {
+            epsg = Some(v);
+        }
+        if let Some(v) = tool_args.get_string(&["--wkt"])? {
+            wkt = Some(v);
+        }
+        if let Some(v) = tool_args.get_string(&["--xy_units"])? {
+            if !v.trim().is_empty() {
+                xy_units = Some(v.trim().to_string());
+            }
+        }
+        if let Some(v) = tool_args.get_string(&["--z_units"])? {
+            if !v.trim().is_empty() {
+                z_units = Some(v.trim().to_string());
+            }
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.is_empty() && !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let is_vector = input_file.to_lowercase().ends_with(".shp");
+        if is_vector && (xy_units.is_some() || z_units.is_some()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The --xy_units and --z_units parameters only apply to raster inputs.",
+            ));
+        }
+
+        let (epsg_code, wkt) = match read_crs_definition(epsg, wkt, working_directory)? {
+            Some((code, w)) => (code, Some(w)),
+            None => (0u16, None),
+        };
+        if wkt.is_none() && xy_units.is_none() && z_units.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "No modifications were specified; use --epsg, --wkt, --xy_units, or --z_units.",
+            ));
+        }
+        if verbose && wkt.is_some() && !get_crs_wkt(&input_file)?.contains("[") {
+            report::warning("The input did not previously have a coordinate reference system.");
+        }
+        if xy_units.is_none() {
+            if let Some(ref w) = wkt {
+                xy_units = units_from_wkt(w);
+            }
+        }
+
+        let modified_crs = wkt.is_some();
+        write_crs(
+            &input_file,
+            &output_file,
+            epsg_code,
+            wkt,
+            xy_units,
+            z_units,
+            &format!("Modified by whitebox_tools\' {} tool", self.get_tool_name()),
+        )?;
+
+        if verbose {
+            if modified_crs {
+                report::message(&format!(
+                    "Coordinate reference system modified (EPSG code {})",
+                    epsg_code
+                ));
+            } else {
+                report::message("Units modified");
+            }
+        }
+
+        Ok(())
+    }
+}
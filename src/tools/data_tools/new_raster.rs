@@ -143,52 +143,22 @@ impl WhiteboxTool for NewRasterFromBase {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-base"
-                || vec[0].to_lowercase() == "--base"
-                || vec[0].to_lowercase() == "-i"
-            {
-                if keyval {
-                    base_file = vec[1].to_string();
-                } else {
-                    base_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-value" || vec[0].to_lowercase() == "--value" {
-                if keyval {
-                    out_val_str = vec[1].to_string();
-                } else {
-                    out_val_str = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-data_type"
-                || vec[0].to_lowercase() == "--data_type"
-                || vec[0].to_lowercase() == "--datatype"
-            {
-                if keyval {
-                    data_type = vec[1].to_string();
-                } else {
-                    data_type = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--base", "-i"])? {
+            base_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--value"])? {
+            out_val_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--data_type", "--datatype"])? {
+            data_type = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -234,22 +204,19 @@ impl WhiteboxTool for NewRasterFromBase {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -114,34 +114,13 @@ impl WhiteboxTool for PrintGeoTiffTags {
             return Err(Error::new(ErrorKind::InvalidInput,
                                   "Tool run with no paramters."));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            // } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-            //     if keyval {
-            //         output_file = vec[1].to_string();
-            //     } else {
-            //         output_file = args[i + 1].to_string();
-            //     }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -144,35 +144,16 @@ impl WhiteboxTool for RasterToVectorLines {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -188,7 +169,7 @@ impl WhiteboxTool for RasterToVectorLines {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -250,14 +231,14 @@ impl WhiteboxTool for RasterToVectorLines {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Tracing raster lines...");
+            report::message("Tracing raster lines...");
         }
         let (mut row, mut col): (isize, isize);
         let (mut row_n, mut col_n): (isize, isize);
@@ -339,7 +320,7 @@ impl WhiteboxTool for RasterToVectorLines {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -348,7 +329,7 @@ impl WhiteboxTool for RasterToVectorLines {
         // The above procedure will not catch closed loops that are disconnected from any line end.
         // Pass over the raster looking for any untraced lines.
         if verbose {
-            println!("Searching for closed loops...");
+            report::message("Searching for closed loops...");
         }
         let (mut row2, mut col2): (isize, isize);
         for row in 0..rows {
@@ -423,7 +404,7 @@ impl WhiteboxTool for RasterToVectorLines {
                                 / (num_cells - 1) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
@@ -435,20 +416,17 @@ impl WhiteboxTool for RasterToVectorLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
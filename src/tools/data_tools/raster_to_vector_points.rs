@@ -132,38 +132,16 @@ impl WhiteboxTool for RasterToVectorPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
-
-        let mut progress: usize;
-        let mut old_progress: usize = 1;
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -176,75 +154,86 @@ impl WhiteboxTool for RasterToVectorPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
 
         let start = Instant::now();
-        let rows = input.configs.rows as isize;
-        let columns = input.configs.columns as isize;
-        let nodata = input.configs.nodata;
-
-        let mut output = Shapefile::new(&output_file, ShapeType::Point)?;
-
-        // set the projection information
-        output.projection = input.configs.coordinate_ref_system_wkt.clone();
-
-        // add the attributes
-        output
-            .attributes
-            .add_field(&AttributeField::new("FID", FieldDataType::Int, 2u8, 0u8));
-        output.attributes.add_field(&AttributeField::new(
-            "VALUE",
-            FieldDataType::Real,
-            12u8,
-            4u8,
-        ));
-
-        let mut rec_num = 1i32;
-        let (mut x, mut y): (f64, f64);
-        let mut z: f64;
-        for row in 0..rows {
-            for col in 0..columns {
-                z = input.get_value(row, col);
-                if z != 0.0f64 && z != nodata {
-                    x = input.get_x_from_column(col);
-                    y = input.get_y_from_row(row);
-                    output.add_point_record(x, y);
-                    output
-                        .attributes
-                        .add_record(vec![FieldData::Int(rec_num), FieldData::Real(z)], false);
-                    rec_num += 1i32;
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+
+        let mut output = raster_to_vector_points(&input, verbose)?;
+        output.set_file_name(&output_file);
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// Converts the grid cells of a raster containing non-zero, non-NoData values into a
+/// vector of points located at the cell centres, with the cell values held in a 'VALUE'
+/// attribute field.
+pub fn raster_to_vector_points(input: &Raster, verbose: bool) -> Result<Shapefile, Error> {
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let nodata = input.configs.nodata;
+
+    let mut output = Shapefile::new("", ShapeType::Point)?;
+
+    // set the projection information
+    output.projection = input.configs.coordinate_ref_system_wkt.clone();
+
+    // add the attributes
+    output
+        .attributes
+        .add_field(&AttributeField::new("FID", FieldDataType::Int, 2u8, 0u8));
+    output.attributes.add_field(&AttributeField::new(
+        "VALUE",
+        FieldDataType::Real,
+        12u8,
+        4u8,
+    ));
+
+    let mut rec_num = 1i32;
+    let (mut x, mut y): (f64, f64);
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input.get_value(row, col);
+            if z != 0.0f64 && z != nodata {
+                x = input.get_x_from_column(col);
+                y = input.get_y_from_row(row);
+                output.add_point_record(x, y);
+                output
+                    .attributes
+                    .add_record(vec![FieldData::Int(rec_num), FieldData::Real(z)], false);
+                rec_num += 1i32;
+            }
+        }
+        if verbose {
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Progress", progress);
+                old_progress = progress;
+            }
+        }
+    }
+
+    Ok(output)
+}
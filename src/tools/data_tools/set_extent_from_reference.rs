@@ -0,0 +1,248 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool forces a raster (`--input`) onto the grid of a reference raster
+/// (`--reference`), i.e. the output raster (`--output`) has the same number of rows and
+/// columns, extent, and cell size as the reference raster. The input is cropped where it
+/// extends beyond the reference extent and padded with nodata cells where it does not
+/// cover the reference extent. Each output cell is assigned the value of the input cell
+/// that contains its centre, which, when the two grids are aligned and share the same
+/// cell size, leaves the input values unaltered. The output raster retains the data type
+/// and nodata value of the input. Use the `Resample` tool (`--base`) for bilinear or cubic
+/// convolution resampling onto a reference grid.
+///
+/// # See Also
+/// `Extend`, `Shrink`, `Resample`
+pub struct SetExtentFromReference {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SetExtentFromReference {
+    pub fn new() -> SetExtentFromReference {
+        // public constructor
+        let name = "SetExtentFromReference".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description = "Forces a raster onto the grid of a reference raster, cropping or padding it as needed.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Reference File".to_owned(),
+            flags: vec!["--reference".to_owned()],
+            description: "Input reference raster file, defining the output grid.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif --reference=DEM.tif -o=output.tif", short_exe, name).replace("*", &sep);
+
+        SetExtentFromReference {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SetExtentFromReference {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut reference_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--reference"])? {
+            reference_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !reference_file.contains(&sep) && !reference_file.contains("/") {
+            reference_file = format!("{}{}", working_directory, reference_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        let reference = Raster::new(&reference_file, "r")?;
+        let start = Instant::now();
+
+        let mut configs = input.configs.clone();
+        configs.rows = reference.configs.rows;
+        configs.columns = reference.configs.columns;
+        configs.north = reference.configs.north;
+        configs.south = reference.configs.south;
+        configs.east = reference.configs.east;
+        configs.west = reference.configs.west;
+        configs.resolution_x = reference.configs.resolution_x;
+        configs.resolution_y = reference.configs.resolution_y;
+        configs.metadata.clear();
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+
+        // each output cell takes the value of the input cell containing its centre
+        let rows = configs.rows as isize;
+        let columns = configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let out_nodata = output.configs.nodata;
+        let input_columns: Vec<isize> = (0..columns)
+            .map(|col| input.get_column_from_x(output.get_x_from_column(col)))
+            .collect();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let input_row = input.get_row_from_y(output.get_y_from_row(row));
+            if input_row >= 0 && input_row < input.configs.rows as isize {
+                for col in 0..columns {
+                    let z = input.get_value(input_row, input_columns[col as usize]);
+                    if z != nodata {
+                        output.set_value(row, col, z);
+                    } else {
+                        output.set_value(row, col, out_nodata);
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Reference file: {}", reference_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
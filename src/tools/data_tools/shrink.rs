@@ -0,0 +1,265 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool trims the rows and columns along the edges of a raster (`--input`) that
+/// contain only nodata cells, such that the output raster (`--output`) has the smallest
+/// extent that contains all of the valid cells of the input. Optionally, a number of
+/// cells (`--padding`) may be retained around the valid data; the output extent never
+/// exceeds that of the input. The output raster has the same cell size and grid
+/// alignment as the input and the values of the retained cells are unaltered. This is
+/// useful for removing the large nodata margins that are often left after clipping or
+/// reprojecting data.
+///
+/// # See Also
+/// `Extend`, `SetExtentFromReference`, `ClipRasterToPolygon`
+pub struct Shrink {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Shrink {
+    pub fn new() -> Shrink {
+        // public constructor
+        let name = "Shrink".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description = "Trims the rows and columns of nodata cells from the edges of a raster.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Padding Cells".to_owned(),
+            flags: vec!["--padding".to_owned()],
+            description: "Number of nodata cells retained around the valid data (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=clipped.tif -o=output.tif --padding=2", short_exe, name).replace("*", &sep);
+
+        Shrink {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Shrink {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut padding = 0usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--padding"])? {
+            padding = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        let start = Instant::now();
+
+        // find the extent of the valid data
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let (mut min_row, mut max_row) = (rows, -1isize);
+        let (mut min_col, mut max_col) = (columns, -1isize);
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            for col in 0..columns {
+                let z = input.get_value(row, col);
+                if z != nodata && !z.is_nan() {
+                    min_row = min_row.min(row);
+                    max_row = max_row.max(row);
+                    min_col = min_col.min(col);
+                    max_col = max_col.max(col);
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        if max_row < 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster does not contain any valid (non-nodata) cells.",
+            ));
+        }
+        let padding = padding as isize;
+        let min_row = (min_row - padding).max(0);
+        let max_row = (max_row + padding).min(rows - 1);
+        let min_col = (min_col - padding).max(0);
+        let max_col = (max_col + padding).min(columns - 1);
+
+        let res_x = input.configs.resolution_x;
+        let res_y = input.configs.resolution_y;
+        let mut configs = input.configs.clone();
+        configs.rows = (max_row - min_row + 1) as usize;
+        configs.columns = (max_col - min_col + 1) as usize;
+        configs.north = input.configs.north - min_row as f64 * res_y;
+        configs.south = configs.north - configs.rows as f64 * res_y;
+        configs.west = input.configs.west + min_col as f64 * res_x;
+        configs.east = configs.west + configs.columns as f64 * res_x;
+        configs.metadata.clear();
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+        let out_nodata = output.configs.nodata;
+        for row in min_row..=max_row {
+            for col in min_col..=max_col {
+                let z = input.get_value(row, col);
+                if z != nodata {
+                    output.set_value(row - min_row, col - min_col, z);
+                } else {
+                    output.set_value(row - min_row, col - min_col, out_nodata);
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message(&format!(
+                "Output grid: {} rows x {} columns (input: {} x {})",
+                output.configs.rows, output.configs.columns, rows, columns
+            ));
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -160,36 +160,16 @@ impl WhiteboxTool for SinglePartToMultiPart {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                use_field = true;
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+            use_field = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,9 +179,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -268,7 +246,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
                                 / input.num_records as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
@@ -292,11 +270,11 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => return Err(e),
                     };
@@ -343,7 +321,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
                                 / input.num_records as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
@@ -355,11 +333,11 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => return Err(e),
                     };
@@ -443,7 +421,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
                                 / input.num_records as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
@@ -455,11 +433,11 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => return Err(e),
                     };
@@ -488,9 +466,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
             // Is the field numeric?
             if !input.attributes.is_field_numeric(field_index) {
                 if input.attributes.fields[field_index].decimal_count > 0 {
-                    println!(
-                        "WARNING: The attribute field does not appear to be categorical. This may produce unexpected results."
-                    )
+                    report::warning("The attribute field does not appear to be categorical. This may produce unexpected results.")
                 }
             }
 
@@ -601,18 +577,18 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         if verbose {
                             progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => return Err(e),
                     };
@@ -706,18 +682,18 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         if verbose {
                             progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => return Err(e),
                     };
@@ -843,18 +819,18 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         if verbose {
                             progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => return Err(e),
                     };
@@ -872,7 +848,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -173,61 +173,31 @@ impl WhiteboxTool for VectorPointsToRaster {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-cell_size" {
-                cell_size = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-base" {
-                base_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-nodata" {
-                background_val = nodata;
-            } else if flag_val == "-assign" {
-                assign_op = if keyval {
-                    vec[1].to_lowercase()
-                } else {
-                    args[i + 1].to_lowercase()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            cell_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+        if tool_args.get_flag(&["--nodata"])? {
+            background_val = nodata;
+        }
+        if let Some(v) = tool_args.get_string(&["--assign"])? {
+            assign_op = v.to_lowercase();
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -243,7 +213,7 @@ impl WhiteboxTool for VectorPointsToRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -263,7 +233,7 @@ impl WhiteboxTool for VectorPointsToRaster {
             None => {
                 // Field not found use FID
                 if verbose {
-                    println!("Warning: Attribute not found in table. FID will be used instead.");
+                    report::warning("Attribute not found in table. FID will be used instead.");
                 }
                 field_name = "FID".to_string();
                 0
@@ -274,7 +244,9 @@ impl WhiteboxTool for VectorPointsToRaster {
         if !vector_data.attributes.is_field_numeric(field_index) {
             // Warn user of non-numeric
             if verbose {
-                println!("Warning: Non-numeric attributes cannot be rasterized. FID will be used instead.");
+                report::warning(
+                    "Non-numeric attributes cannot be rasterized. FID will be used instead.",
+                );
             }
             field_name = "FID".to_string(); // Can't use non-numeric field; use FID instead.
         }
@@ -317,7 +289,9 @@ impl WhiteboxTool for VectorPointsToRaster {
             Raster::initialize_using_config(&output_file, &configs)
         };
 
-        if background_val != nodata {
+        if background_val == nodata {
+            background_val = output.configs.nodata;
+        } else {
             output.reinitialize_values(background_val);
         }
 
@@ -351,7 +325,7 @@ impl WhiteboxTool for VectorPointsToRaster {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
+                    report::progress("Reading attributes", progress);
                     old_progress = progress;
                 }
             }
@@ -374,11 +348,9 @@ impl WhiteboxTool for VectorPointsToRaster {
                 if verbose {
                     progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
+                        report::progress(
+                            &format!("Rasterizing {} of {}", record_num + 1, num_records),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -400,11 +372,9 @@ impl WhiteboxTool for VectorPointsToRaster {
                 if verbose {
                     progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
+                        report::progress(
+                            &format!("Rasterizing {} of {}", record_num + 1, num_records),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -426,11 +396,9 @@ impl WhiteboxTool for VectorPointsToRaster {
                 if verbose {
                     progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
+                        report::progress(
+                            &format!("Rasterizing {} of {}", record_num + 1, num_records),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -452,11 +420,9 @@ impl WhiteboxTool for VectorPointsToRaster {
                 if verbose {
                     progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
+                        report::progress(
+                            &format!("Rasterizing {} of {}", record_num + 1, num_records),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -480,11 +446,9 @@ impl WhiteboxTool for VectorPointsToRaster {
                 if verbose {
                     progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
+                        report::progress(
+                            &format!("Rasterizing {} of {}", record_num + 1, num_records),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -501,20 +465,17 @@ impl WhiteboxTool for VectorPointsToRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
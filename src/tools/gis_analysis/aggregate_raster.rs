@@ -146,53 +146,26 @@ impl WhiteboxTool for AggregateRaster {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-agg_factor" {
-                if keyval {
-                    agg_factor = vec[1].to_string().parse::<isize>().unwrap();
-                } else {
-                    agg_factor = args[i + 1].to_string().parse::<isize>().unwrap();
-                }
-                if agg_factor < 2isize {
-                    println!(
-                        "WARNING: Aggregation factor cannot be less than 2. It has been modified."
-                    );
-                    agg_factor = 2isize;
-                }
-            } else if flag_val == "-type" {
-                if keyval {
-                    agg_type = vec[1].to_string();
-                } else {
-                    agg_type = args[i + 1].to_string();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--agg_factor"])? {
+            agg_factor = v;
+            if agg_factor < 2isize {
+                report::warning("Aggregation factor cannot be less than 2. It has been modified.");
+                agg_factor = 2isize;
             }
         }
+        if let Some(v) = tool_args.get_string(&["--type"])? {
+            agg_type = v;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -208,7 +181,7 @@ impl WhiteboxTool for AggregateRaster {
         }
 
         if verbose {
-            println!("Reading input data...")
+            report::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -242,6 +215,7 @@ impl WhiteboxTool for AggregateRaster {
         configs.palette = input.configs.palette.clone();
 
         let mut output = Raster::initialize_using_config(&output_file, &configs);
+        let out_nodata = output.configs.nodata;
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -258,7 +232,7 @@ impl WhiteboxTool for AggregateRaster {
                         let mut stat: f64;
                         let mut count: f64;
                         for row in (0..rows_out).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns_out as usize];
+                            let mut data = vec![out_nodata; columns_out as usize];
                             for col in 0..columns_out {
                                 row_in = row * agg_factor;
                                 col_in = col * agg_factor;
@@ -289,7 +263,7 @@ impl WhiteboxTool for AggregateRaster {
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -306,7 +280,7 @@ impl WhiteboxTool for AggregateRaster {
                         let mut stat: f64;
                         let mut count: f64;
                         for row in (0..rows_out).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns_out as usize];
+                            let mut data = vec![out_nodata; columns_out as usize];
                             for col in 0..columns_out {
                                 row_in = row * agg_factor;
                                 col_in = col * agg_factor;
@@ -336,7 +310,7 @@ impl WhiteboxTool for AggregateRaster {
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -353,7 +327,7 @@ impl WhiteboxTool for AggregateRaster {
                         let mut stat: f64;
                         let mut count: f64;
                         for row in (0..rows_out).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns_out as usize];
+                            let mut data = vec![out_nodata; columns_out as usize];
                             for col in 0..columns_out {
                                 row_in = row * agg_factor;
                                 col_in = col * agg_factor;
@@ -385,7 +359,7 @@ impl WhiteboxTool for AggregateRaster {
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -402,7 +376,7 @@ impl WhiteboxTool for AggregateRaster {
                         let mut stat: f64;
                         let mut count: f64;
                         for row in (0..rows_out).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns_out as usize];
+                            let mut data = vec![out_nodata; columns_out as usize];
                             for col in 0..columns_out {
                                 row_in = row * agg_factor;
                                 col_in = col * agg_factor;
@@ -434,7 +408,7 @@ impl WhiteboxTool for AggregateRaster {
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -452,7 +426,7 @@ impl WhiteboxTool for AggregateRaster {
                         let mut min_val: f64;
                         let mut count: f64;
                         for row in (0..rows_out).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns_out as usize];
+                            let mut data = vec![out_nodata; columns_out as usize];
                             for col in 0..columns_out {
                                 row_in = row * agg_factor;
                                 col_in = col * agg_factor;
@@ -488,7 +462,7 @@ impl WhiteboxTool for AggregateRaster {
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -510,20 +484,17 @@ impl WhiteboxTool for AggregateRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -119,35 +119,16 @@ impl WhiteboxTool for AverageOverlay {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" || flag_val == "-input" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -188,7 +169,7 @@ impl WhiteboxTool for AverageOverlay {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    report::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -201,17 +182,17 @@ impl WhiteboxTool for AverageOverlay {
                     read_first_file = true;
                     rows = input.configs.rows as isize;
                     columns = input.configs.columns as isize;
-                    out_nodata = in_nodata;
 
                     // initialize the output file and n
                     output = Raster::initialize_using_file(&output_file, &input);
+                    out_nodata = output.configs.nodata;
                     n = Array2D::new(rows, columns, 0i16, i16::MIN)?;
                 }
                 // check to ensure that all inputs have the same rows and columns
                 if input.configs.rows as isize != rows || input.configs.columns as isize != columns
                 {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
+                                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
 
                 for row in 0..rows {
@@ -230,7 +211,10 @@ impl WhiteboxTool for AverageOverlay {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files + 1, progress);
+                            report::progress(
+                                &format!("Progress (loop {} of {})", i, num_files + 1),
+                                progress,
+                            );
                             old_progress = progress;
                         }
                     }
@@ -253,11 +237,9 @@ impl WhiteboxTool for AverageOverlay {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Progress (loop {} of {}): {}%",
-                        num_files + 1,
-                        num_files + 1,
-                        progress
+                    report::progress(
+                        &format!("Progress (loop {} of {})", num_files + 1, num_files + 1),
+                        progress,
                     );
                     old_progress = progress;
                 }
@@ -272,20 +254,17 @@ impl WhiteboxTool for AverageOverlay {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
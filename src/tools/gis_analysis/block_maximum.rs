@@ -161,55 +161,28 @@ impl WhiteboxTool for BlockMaximumGridding {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-use_z" {
-                use_z = true;
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-cell_size" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-base" {
-                base_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if tool_args.get_flag(&["--use_z"])? {
+            use_z = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -225,7 +198,7 @@ impl WhiteboxTool for BlockMaximumGridding {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -283,7 +256,7 @@ impl WhiteboxTool for BlockMaximumGridding {
         let columns = output.configs.columns as isize;
         let west = output.configs.west;
         let north = output.configs.north;
-        output.configs.nodata = nodata; // in case a base image is used with a different nodata value.
+        let nodata = output.configs.nodata; // may differ from the input nodata
 
         // let half_grid_res = grid_res / 2f64;
         let ew_range = output.configs.east - west;
@@ -341,7 +314,7 @@ impl WhiteboxTool for BlockMaximumGridding {
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -378,7 +351,7 @@ impl WhiteboxTool for BlockMaximumGridding {
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -394,20 +367,17 @@ impl WhiteboxTool for BlockMaximumGridding {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,349 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool smooths the boundaries between the classes of a categorical raster
+/// (`--input`), such as a classified image or a landform classification, by expanding and
+/// then shrinking the classes. Each class is assigned a priority and, during the
+/// expansion, each cell takes on the value of the highest-priority class among itself and
+/// its eight neighbours. During the subsequent shrinking, each cell takes on the value of
+/// the lowest-priority class in its neighbourhood within the expanded raster. The effect
+/// is to fill narrow inlets and small gaps within the higher-priority classes, and to
+/// straighten ragged boundaries, while leaving straight boundaries unchanged.
+///
+/// By default (`--sort=descending`), classes that cover larger areas have higher
+/// priorities. Smaller classes may instead be given higher priorities
+/// (`--sort=ascending`), or the priorities may follow the class values, with larger
+/// values having higher priorities (`--sort=none`). If `--two_way` is specified, the
+/// expansion and shrinking are repeated with the priorities reversed, which also removes
+/// the small isolated groups of cells of the higher-priority classes. NoData cells are
+/// never modified and are ignored within neighbourhoods.
+///
+/// # See Also
+/// `GeneralizeClassifiedRaster`, `MajorityFilter`, `Closing`
+pub struct BoundaryClean {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl BoundaryClean {
+    pub fn new() -> BoundaryClean {
+        // public constructor
+        let name = "BoundaryClean".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Smooths the boundaries between the classes of a categorical raster by expanding and shrinking them."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input categorical raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Class Priority".to_owned(),
+            flags: vec!["--sort".to_owned()],
+            description: "Class priority order; options are 'descending' (larger classes first), 'ascending' (smaller classes first), and 'none' (larger values first).".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "descending".to_owned(),
+                "ascending".to_owned(),
+                "none".to_owned(),
+            ]),
+            default_value: Some("descending".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Repeat with reversed priorities?".to_owned(),
+            flags: vec!["--two_way".to_owned()],
+            description:
+                "Flag indicating whether to repeat the expansion and shrinking with the priorities reversed."
+                    .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=classes.tif -o=output.tif --sort=descending --two_way",
+            short_exe, name
+        ).replace("*", &sep);
+
+        BoundaryClean {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for BoundaryClean {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut sort = "descending".to_string();
+        let mut two_way = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--sort"])? {
+            sort = v.to_lowercase();
+            if sort.contains("asc") {
+                sort = "ascending".to_string();
+            } else if sort.contains("desc") {
+                sort = "descending".to_string();
+            } else if sort.contains("none") {
+                sort = "none".to_string();
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The sort option must be 'descending', 'ascending', or 'none'.",
+                ));
+            }
+        }
+        if tool_args.get_flag(&["--two_way"])? {
+            two_way = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+
+        // find the classes and their areas
+        let mut class_index: HashMap<u64, usize> = HashMap::new();
+        let mut classes: Vec<(f64, usize)> = vec![];
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input[(row, col)];
+                if z != nodata {
+                    let i = *class_index.entry(z.to_bits()).or_insert(classes.len());
+                    if i == classes.len() {
+                        classes.push((z, 0));
+                    }
+                    classes[i].1 += 1;
+                }
+            }
+        }
+
+        // rank the classes by increasing priority and replace each cell with its rank
+        let mut order: Vec<usize> = (0..classes.len()).collect();
+        match sort.as_ref() {
+            "ascending" => order.sort_by(|&a, &b| {
+                classes[b]
+                    .1
+                    .cmp(&classes[a].1)
+                    .then(classes[a].0.partial_cmp(&classes[b].0).unwrap())
+            }),
+            "descending" => order.sort_by(|&a, &b| {
+                classes[a]
+                    .1
+                    .cmp(&classes[b].1)
+                    .then(classes[a].0.partial_cmp(&classes[b].0).unwrap())
+            }),
+            _ => order.sort_by(|&a, &b| classes[a].0.partial_cmp(&classes[b].0).unwrap()),
+        }
+        let mut rank_of_class = vec![0usize; classes.len()];
+        for (rank, &c) in order.iter().enumerate() {
+            rank_of_class[c] = rank;
+        }
+        let no_rank = usize::max_value();
+        let mut ranks = vec![no_rank; (rows * columns) as usize];
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input[(row, col)];
+                if z != nodata {
+                    ranks[(row * columns + col) as usize] =
+                        rank_of_class[class_index[&z.to_bits()]];
+                }
+            }
+        }
+
+        let num_passes = if two_way { 4 } else { 2 };
+        for pass in 0..num_passes {
+            // expand (take the highest rank) and then shrink (take the lowest rank); in
+            // the second round, the ranks are reversed.
+            let reversed = pass >= 2;
+            let expand = pass % 2 == 0;
+            ranks = neighbourhood_rank(&ranks, rows, columns, expand != reversed);
+            if verbose {
+                report::message(&format!(
+                    "{} ({} of {})",
+                    if expand { "Expanding" } else { "Shrinking" },
+                    pass + 1,
+                    num_passes
+                ));
+            }
+        }
+
+        for row in 0..rows {
+            let mut data = vec![out_nodata; columns as usize];
+            for col in 0..columns {
+                let rank = ranks[(row * columns + col) as usize];
+                if rank != no_rank {
+                    data[col as usize] = classes[order[rank]].0;
+                }
+            }
+            output.set_row_data(row, data);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Sort: {}", sort));
+        output.add_metadata_entry(format!("Two way: {}", two_way));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces each valid cell's rank with the highest (`maximum`) or lowest rank among the
+/// valid cells of its 3 x 3 neighbourhood.
+fn neighbourhood_rank(ranks: &[usize], rows: isize, columns: isize, maximum: bool) -> Vec<usize> {
+    let no_rank = usize::max_value();
+    let mut result = vec![no_rank; ranks.len()];
+    let (mut rn, mut best): (usize, usize);
+    for row in 0..rows {
+        for col in 0..columns {
+            best = ranks[(row * columns + col) as usize];
+            if best == no_rank {
+                continue;
+            }
+            for r in (row - 1).max(0)..(row + 2).min(rows) {
+                for c in (col - 1).max(0)..(col + 2).min(columns) {
+                    rn = ranks[(r * columns + c) as usize];
+                    if rn != no_rank && ((maximum && rn > best) || (!maximum && rn < best)) {
+                        best = rn;
+                    }
+                }
+            }
+            result[(row * columns + col) as usize] = best;
+        }
+    }
+    result
+}
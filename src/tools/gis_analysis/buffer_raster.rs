@@ -142,44 +142,22 @@ impl WhiteboxTool for BufferRaster {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-size" || vec[0].to_lowercase() == "--size" {
-                if keyval {
-                    buffer_size = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    buffer_size = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-gridcells"
-                || vec[0].to_lowercase() == "--gridcells"
-            {
-                grid_cell_units = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--size"])? {
+            buffer_size = v;
+        }
+        if tool_args.get_flag(&["--gridcells"])? {
+            grid_cell_units = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -195,7 +173,7 @@ impl WhiteboxTool for BufferRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -209,6 +187,7 @@ impl WhiteboxTool for BufferRaster {
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let mut h: f64;
         let mut which_cell: usize;
@@ -232,7 +211,7 @@ impl WhiteboxTool for BufferRaster {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    report::progress("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -248,7 +227,7 @@ impl WhiteboxTool for BufferRaster {
                         x = col + d_x[i];
                         y = row + d_y[i];
                         z2 = output[(y, x)];
-                        if z2 != nodata {
+                        if z2 != out_nodata {
                             h = match i {
                                 0 => 2.0 * r_x[(y, x)] + 1.0,
                                 1 => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0),
@@ -274,7 +253,7 @@ impl WhiteboxTool for BufferRaster {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
+                    report::progress("Progress (1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -290,7 +269,7 @@ impl WhiteboxTool for BufferRaster {
                         x = col + d_x[i];
                         y = row + d_y[i];
                         z2 = output[(y, x)];
-                        if z2 != nodata {
+                        if z2 != out_nodata {
                             h = match i {
                                 5 => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0),
                                 4 => 2.0 * r_x[(y, x)] + 1.0,
@@ -316,7 +295,7 @@ impl WhiteboxTool for BufferRaster {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
+                    report::progress("Progress (2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -339,13 +318,13 @@ impl WhiteboxTool for BufferRaster {
                         output[(row, col)] = 0.0;
                     }
                 } else {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    report::progress("Progress (3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -364,20 +343,17 @@ impl WhiteboxTool for BufferRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
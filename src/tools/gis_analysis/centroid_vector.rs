@@ -139,29 +139,12 @@ impl WhiteboxTool for CentroidVector {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -171,9 +154,7 @@ impl WhiteboxTool for CentroidVector {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -211,7 +192,7 @@ impl WhiteboxTool for CentroidVector {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -227,11 +208,11 @@ impl WhiteboxTool for CentroidVector {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -269,18 +250,18 @@ impl WhiteboxTool for CentroidVector {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -289,7 +270,7 @@ impl WhiteboxTool for CentroidVector {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
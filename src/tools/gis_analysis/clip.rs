@@ -0,0 +1,362 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{
+    clip_polyline, group_rings, point_in_rings, polygon_overlay, OverlayOperation,
+};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, Point2D};
+use tools::*;
+use vector::*;
+
+/// This tool extracts the portions of the features in an input vector file (`--input`)
+/// that fall within the polygons of a clip vector file (`--clip`). The input may be of
+/// POINT, MULTIPOINT, POLYLINE, or POLYGON ShapeType and the output file (`--output`)
+/// will share this geometry type; the clip file must be of POLYGON ShapeType. Lines and
+/// polygons are cut at the boundaries of the clip polygons, while points are retained
+/// if they fall within a clip polygon. Overlapping clip polygons are merged before
+/// clipping. Input features that fall entirely outside of the clip polygons are not
+/// included in the output.
+///
+/// The output attribute table contains all of the fields and values of the input
+/// file. Z and M values are retained for point features only.
+///
+/// # See Also
+/// `Erase`, `SplitByPolygon`, `ClipRasterToPolygon`
+pub struct Clip {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Clip {
+    pub fn new() -> Clip {
+        // public constructor
+        let name = "Clip".to_string();
+        let toolbox = "GIS Analysis/Overlay Tools".to_string();
+        let description =
+            "Extract all the features, or parts of features, that overlap with the features of the clip vector."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Clip Polygon File".to_owned(),
+            flags: vec!["--clip".to_owned()],
+            description: "Input clip polygon vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=lines.shp --clip=clip_poly.shp -o=output.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        Clip {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Clip {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file: String = "".to_string();
+        let mut clip_file: String = "".to_string();
+        let mut output_file: String = "".to_string();
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--clip"])? {
+            clip_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !clip_file.contains(&sep) && !clip_file.contains("/") {
+            clip_file = format!("{}{}", working_directory, clip_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let input = Shapefile::read(&input_file)?;
+        let clip = Shapefile::read(&clip_file)?;
+
+        // make sure the clip vector file is of polygon type
+        if clip.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The clip vector data must be of POLYGON base shape type.",
+            ));
+        }
+
+        if verbose {
+            report::message("Merging clip polygons...");
+        }
+        let records: Vec<usize> = (0..clip.num_records).collect();
+        let region = get_polygon_union(&clip, &records);
+        let region_box = get_rings_bounding_box(&region);
+
+        // create output file
+        let output_type = get_clipped_shape_type(input.header.shape_type);
+        let mut output = Shapefile::initialize_using_file(&output_file, &input, output_type, true)?;
+
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            if let Some(geometry) =
+                clip_record(record, &region, region_box, false, output_type)
+            {
+                output.add_record(geometry);
+                output
+                    .attributes
+                    .add_record(input.attributes.get_record(record_num), false);
+            }
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if output.num_records == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "None of the input features overlap the clip polygons; no output was created.",
+            ));
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the rings of a polygon record.
+pub fn get_polygon_rings(record: &ShapefileGeometry) -> Vec<Vec<Point2D>> {
+    let mut rings = Vec::with_capacity(record.num_parts as usize);
+    for part in 0..record.num_parts as usize {
+        let first_point_in_part = record.parts[part] as usize;
+        let last_point_in_part = if part < record.num_parts as usize - 1 {
+            record.parts[part + 1] as usize - 1
+        } else {
+            record.num_points as usize - 1
+        };
+        rings.push(record.points[first_point_in_part..=last_point_in_part].to_vec());
+    }
+    rings
+}
+
+/// Returns the union of a set of the polygon records in a file, as a set of rings.
+pub fn get_polygon_union(polygons: &Shapefile, records: &[usize]) -> Vec<Vec<Point2D>> {
+    let mut region: Vec<Vec<Point2D>> = vec![];
+    for &record_num in records {
+        let rings = get_polygon_rings(polygons.get_record(record_num));
+        region = polygon_overlay(&region, &rings, OverlayOperation::Union);
+    }
+    region
+}
+
+pub fn get_rings_bounding_box(rings: &[Vec<Point2D>]) -> BoundingBox {
+    let mut bb = BoundingBox::default();
+    for ring in rings {
+        bb.expand_to(BoundingBox::from_points(ring));
+    }
+    bb
+}
+
+/// Returns the ShapeType of clipped features. Points are copied unaltered, but
+/// clipped lines and polygons do not retain Z and M values.
+pub fn get_clipped_shape_type(input_type: ShapeType) -> ShapeType {
+    if input_type.base_shape_type() == ShapeType::Point {
+        input_type
+    } else {
+        input_type.base_shape_type()
+    }
+}
+
+/// Clips a record by a polygonal region, retaining the parts within the region, or
+/// outside of it when `erase` is true. Returns `None` if nothing of the record remains.
+pub fn clip_record(
+    record: &ShapefileGeometry,
+    region: &[Vec<Point2D>],
+    region_box: BoundingBox,
+    erase: bool,
+    output_type: ShapeType,
+) -> Option<ShapefileGeometry> {
+    if record.num_points == 0 {
+        return None;
+    }
+    // there's no need to test features that don't overlap the region
+    let empty = vec![];
+    let region = if record.get_bounding_box().overlaps(region_box) {
+        region
+    } else {
+        &empty
+    };
+    let mut geometry = ShapefileGeometry::new(output_type);
+    match record.shape_type.base_shape_type() {
+        ShapeType::Point => {
+            if point_in_rings(&record.points[0], region) == erase {
+                return None;
+            }
+            geometry = record.clone();
+        }
+        ShapeType::MultiPoint => {
+            for p in &record.points {
+                if point_in_rings(p, region) != erase {
+                    geometry.add_point(*p);
+                }
+            }
+        }
+        ShapeType::PolyLine => for part in get_polygon_rings(record) {
+            for line in clip_polyline(&part, region, !erase) {
+                geometry.add_part(&line);
+            }
+        },
+        _ => {
+            let operation = if erase {
+                OverlayOperation::Difference
+            } else {
+                OverlayOperation::Intersection
+            };
+            let rings = polygon_overlay(&get_polygon_rings(record), region, operation);
+            for group in group_rings(&rings) {
+                for ring in group {
+                    geometry.add_part(&ring);
+                }
+            }
+        }
+    }
+    if geometry.num_points == 0 {
+        return None;
+    }
+    Some(geometry)
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 25/04/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTES: This tool differs from the Whitebox GAT tool in that it only takes a single raster input.
@@ -13,11 +13,24 @@ use raster::*;
 use std::env;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::BoundingBox;
-use structures::Point2D;
+use structures::{Array2D, Point2D};
 use tools::*;
 use vector::{ShapeType, Shapefile};
 
+/// This tool clips a raster (`--input`) to one or more vector polygons (`--polygons`).
+/// Grid cells whose centres are outside of the polygons, or within polygon holes, are
+/// assigned the NoData value in the output raster (`--output`). By default, the output
+/// raster is cropped to the grid cells that overlap with the bounding box of the
+/// polygons; the `--maintain_dimensions` flag retains the rows and columns of the input
+/// raster instead. The `--invert` flag erases the areas within the polygons, rather
+/// than those outside of them, and always maintains the input raster dimensions.
+///
+/// The clipped areas are assigned the NoData value of the input raster. When the input
+/// raster has an integer data type that cannot hold this value (e.g. an 8-bit unsigned
+/// raster with a NoData value of -32768), the output raster is given a wider data type.
+///
+/// # See Also
+/// `ErasePolygonFromRaster`
 pub struct ClipRasterToPolygon {
     name: String,
     description: String,
@@ -72,6 +85,16 @@ impl ClipRasterToPolygon {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Invert (erase the areas within the polygons)?".to_owned(),
+            flags: vec!["--invert".to_owned()],
+            description: "Erase the areas within the polygons, rather than those outside of them?"
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_string()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -133,6 +156,7 @@ impl WhiteboxTool for ClipRasterToPolygon {
         let mut polygons_file = String::new();
         let mut output_file = String::new();
         let mut maintain_dimensions = false;
+        let mut invert = false;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -140,43 +164,25 @@ impl WhiteboxTool for ClipRasterToPolygon {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-polygon" || flag_val == "-polygons" {
-                polygons_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-maintain_dimensions" {
-                maintain_dimensions = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--polygon", "--polygons"])? {
+            polygons_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--maintain_dimensions"])? {
+            maintain_dimensions = true;
+        }
+        if tool_args.get_flag(&["--invert"])? {
+            invert = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -195,18 +201,18 @@ impl WhiteboxTool for ClipRasterToPolygon {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
         let start = Instant::now();
-        let rows = input.configs.rows as isize;
-        let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let res_x = input.configs.resolution_x;
+        let res_y = input.configs.resolution_y;
 
         let polygons = Shapefile::read(&polygons_file)?;
 
-        // make sure the input vector file is of points type
+        // make sure the input vector file is of polygon type
         if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
@@ -214,392 +220,146 @@ impl WhiteboxTool for ClipRasterToPolygon {
             ));
         }
 
-        if maintain_dimensions {
-            // Output raster has same dimensions as the input
-            let mut output = Raster::initialize_using_file(&output_file, &input);
-
-            let mut start_point_in_part: usize;
-            let mut end_point_in_part: usize;
-            let (mut row, mut col): (isize, isize);
-            let (mut x, mut y): (f64, f64);
-            let (mut starting_row, mut ending_row, mut starting_col, mut ending_col): (
-                isize,
-                isize,
-                isize,
-                isize,
-            );
-            let num_records = polygons.num_records;
-            for record_num in 0..polygons.num_records {
-                let record = polygons.get_record(record_num);
-
-                let mut part_num = 1;
-                for part in 0..record.num_parts as usize {
-                    if !record.is_hole(part as i32) {
-                        // Add these cells in
-
-                        start_point_in_part = record.parts[part] as usize;
-                        end_point_in_part = if part < record.num_parts as usize - 1 {
-                            record.parts[part + 1] as usize - 1
-                        } else {
-                            record.num_points as usize - 1
-                        };
-
-                        // First, figure out the minimum and maximum row and column for the polygon part
-                        starting_row = rows;
-                        ending_row = 0;
-                        starting_col = columns;
-                        ending_col = 0;
-                        for p in start_point_in_part..end_point_in_part + 1 {
-                            row = input.get_row_from_y(record.points[p].y);
-                            col = input.get_column_from_x(record.points[p].x);
-                            if row < starting_row {
-                                starting_row = row;
-                            }
-                            if row > ending_row {
-                                ending_row = row;
-                            }
-                            if col < starting_col {
-                                starting_col = col;
-                            }
-                            if col > ending_col {
-                                ending_col = col;
-                            }
-                        }
-
-                        for r in starting_row..ending_row {
-                            y = input.get_y_from_row(r);
-                            for c in starting_col..ending_col {
-                                x = input.get_x_from_column(c);
-                                if point_in_poly(
-                                    &Point2D { x: x, y: y },
-                                    &record.points[start_point_in_part..end_point_in_part + 1],
-                                ) {
-                                    output.set_value(r, c, input.get_value(r, c));
-                                }
-                            }
-                            if verbose {
-                                progress = (100.0_f64 * r as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
-                            }
-                        }
-                        part_num += 1;
-                    }
-                }
-
-                for part in 0..record.num_parts as usize {
-                    if record.is_hole(part as i32) {
-                        // Erase these cells
-
-                        start_point_in_part = record.parts[part] as usize;
-                        end_point_in_part = if part < record.num_parts as usize - 1 {
-                            record.parts[part + 1] as usize - 1
-                        } else {
-                            record.num_points as usize - 1
-                        };
-
-                        // First, figure out the minimum and maximum row and column for the polygon part
-                        starting_row = rows;
-                        ending_row = 0;
-                        starting_col = columns;
-                        ending_col = 0;
-                        for p in start_point_in_part..end_point_in_part + 1 {
-                            row = input.get_row_from_y(record.points[p].y);
-                            col = input.get_column_from_x(record.points[p].x);
-                            if row < starting_row {
-                                starting_row = row;
-                            }
-                            if row > ending_row {
-                                ending_row = row;
-                            }
-                            if col < starting_col {
-                                starting_col = col;
-                            }
-                            if col > ending_col {
-                                ending_col = col;
-                            }
-                        }
-
-                        for r in starting_row..ending_row {
-                            y = input.get_y_from_row(r);
-                            for c in starting_col..ending_col {
-                                x = input.get_x_from_column(c);
-                                if point_in_poly(
-                                    &Point2D { x: x, y: y },
-                                    &record.points[start_point_in_part..end_point_in_part + 1],
-                                ) {
-                                    output.set_value(r, c, nodata);
-                                }
-                            }
-                            if verbose {
-                                progress = (100.0_f64 * r as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
-                            }
-                        }
-                        part_num += 1;
-                    }
-                }
+        // The output grid is aligned with the input grid. Unless the input dimensions are
+        // maintained, it is cropped to the cells overlapping the polygons' bounding box.
+        // The erased areas of an inverted clip are within the polygons, and so the input
+        // dimensions are always maintained.
+        let (mut row_offset, mut col_offset) = (0isize, 0isize);
+        let mut configs = input.configs.clone();
+        if !maintain_dimensions && !invert {
+            row_offset = ((input.configs.north - polygons.header.y_max) / res_y).floor() as isize;
+            let last_row =
+                ((input.configs.north - polygons.header.y_min) / res_y).ceil() as isize - 1;
+            col_offset = ((polygons.header.x_min - input.configs.west) / res_x).floor() as isize;
+            let last_col =
+                ((polygons.header.x_max - input.configs.west) / res_x).ceil() as isize - 1;
+            row_offset = row_offset.max(0);
+            col_offset = col_offset.max(0);
+            let last_row = last_row.min(input.configs.rows as isize - 1);
+            let last_col = last_col.min(input.configs.columns as isize - 1);
+            if last_row < row_offset || last_col < col_offset {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input polygons do not overlap with the input raster.",
+                ));
             }
+            configs.rows = (last_row - row_offset + 1) as usize;
+            configs.columns = (last_col - col_offset + 1) as usize;
+            configs.north = input.configs.north - row_offset as f64 * res_y;
+            configs.south = configs.north - configs.rows as f64 * res_y;
+            configs.west = input.configs.west + col_offset as f64 * res_x;
+            configs.east = configs.west + configs.columns as f64 * res_x;
+        }
 
-            let elapsed_time = get_formatted_elapsed_time(start);
-            output.add_metadata_entry(format!(
-                "Created by whitebox_tools\' {} tool",
-                self.get_tool_name()
-            ));
-            output.add_metadata_entry(format!("Input file: {}", input_file));
-            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
-
-            if verbose {
-                println!("Saving data...")
-            };
-            let _ = match output.write() {
-                Ok(_) => if verbose {
-                    println!("Output file written")
-                },
-                Err(e) => return Err(e),
+        // The clipped areas are assigned the input nodata value; an integer data type
+        // that cannot hold this value is replaced by a wider type.
+        if !configs.data_type.can_represent(nodata) {
+            configs.data_type = match configs.data_type {
+                DataType::U8 | DataType::I8 | DataType::U16 | DataType::I16
+                    if DataType::I32.can_represent(nodata) =>
+                {
+                    DataType::I32
+                }
+                _ => DataType::F64,
             };
-
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                report::warning(&format!(
+                    "The output data type is {:?} because the input data type cannot hold the nodata value ({}).",
+                    configs.data_type, nodata
+                ));
             }
-        } else {
-            // we'll need to trim the raster to the extent of the polygons.
-            let vec_bb = BoundingBox::new(
-                polygons.header.x_min,
-                polygons.header.x_max,
-                polygons.header.y_min,
-                polygons.header.y_max,
-            );
-            let mut rast_bb = BoundingBox::new(
-                input.configs.west,
-                input.configs.east,
-                input.configs.south,
-                input.configs.north,
-            );
-            rast_bb.contract_to(vec_bb);
-
-            let west: f64 = rast_bb.min_x;
-            let north: f64 = rast_bb.max_y;
-            let rows: isize =
-                (((north - rast_bb.min_y) / input.configs.resolution_y).ceil()) as isize;
-            let columns: isize =
-                (((rast_bb.max_x - west) / input.configs.resolution_x).ceil()) as isize;
-            let south: f64 = north - rows as f64 * input.configs.resolution_y;
-            let east = west + columns as f64 * input.configs.resolution_x;
-
-            let mut configs = RasterConfigs {
-                ..Default::default()
-            };
-            configs.rows = rows as usize;
-            configs.columns = columns as usize;
-            configs.north = north;
-            configs.south = south;
-            configs.east = east;
-            configs.west = west;
-            configs.resolution_x = input.configs.resolution_x;
-            configs.resolution_y = input.configs.resolution_y;
-            configs.nodata = nodata;
-            configs.data_type = input.configs.data_type;
-            configs.photometric_interp = input.configs.photometric_interp;
-            configs.palette = input.configs.palette.clone();
-
-            let mut output = Raster::initialize_using_config(&output_file, &configs);
-
-            let mut start_point_in_part: usize;
-            let mut end_point_in_part: usize;
-            let (mut row, mut col): (isize, isize);
-            let (mut row_in, mut col_in): (isize, isize);
-            let (mut x, mut y): (f64, f64);
-            let (mut starting_row, mut ending_row, mut starting_col, mut ending_col): (
-                isize,
-                isize,
-                isize,
-                isize,
-            );
-            let num_records = polygons.num_records;
-            for record_num in 0..polygons.num_records {
-                let record = polygons.get_record(record_num);
-
-                let mut part_num = 1;
-                for part in 0..record.num_parts as usize {
-                    if !record.is_hole(part as i32) {
-                        // Add these cells in
+        }
 
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+        let rows = configs.rows as isize;
+        let columns = configs.columns as isize;
+
+        // find the output cells whose centres are within the polygons
+        let mut inside: Array2D<u8> = Array2D::new(rows, columns, 0u8, 0u8)?;
+        let mut start_point_in_part: usize;
+        let mut end_point_in_part: usize;
+        let mut num_containing_parts: usize;
+        let (mut x, mut y): (f64, f64);
+        let num_records = polygons.num_records;
+        for record_num in 0..polygons.num_records {
+            let record = polygons.get_record(record_num);
+            let starting_row = output.get_row_from_y(record.y_max).max(0);
+            let ending_row = output.get_row_from_y(record.y_min).min(rows - 1);
+            let starting_col = output.get_column_from_x(record.x_min).max(0);
+            let ending_col = output.get_column_from_x(record.x_max).min(columns - 1);
+            for row in starting_row..ending_row + 1 {
+                y = output.get_y_from_row(row);
+                for col in starting_col..ending_col + 1 {
+                    x = output.get_x_from_column(col);
+                    // A cell is within the polygon if it is within an odd number of its
+                    // parts, which excludes the cells within holes.
+                    num_containing_parts = 0;
+                    for part in 0..record.num_parts as usize {
                         start_point_in_part = record.parts[part] as usize;
                         end_point_in_part = if part < record.num_parts as usize - 1 {
                             record.parts[part + 1] as usize - 1
                         } else {
                             record.num_points as usize - 1
                         };
-
-                        // First, figure out the minimum and maximum row and column for the polygon part
-                        starting_row = rows;
-                        ending_row = 0;
-                        starting_col = columns;
-                        ending_col = 0;
-                        for p in start_point_in_part..end_point_in_part + 1 {
-                            row = output.get_row_from_y(record.points[p].y);
-                            col = output.get_column_from_x(record.points[p].x);
-                            if row < starting_row {
-                                starting_row = row;
-                            }
-                            if row > ending_row {
-                                ending_row = row;
-                            }
-                            if col < starting_col {
-                                starting_col = col;
-                            }
-                            if col > ending_col {
-                                ending_col = col;
-                            }
-                        }
-
-                        for r in starting_row..ending_row {
-                            y = output.get_y_from_row(r);
-                            for c in starting_col..ending_col {
-                                x = output.get_x_from_column(c);
-                                if point_in_poly(
-                                    &Point2D { x: x, y: y },
-                                    &record.points[start_point_in_part..end_point_in_part + 1],
-                                ) {
-                                    row_in = input.get_row_from_y(y);
-                                    col_in = input.get_column_from_x(x);
-                                    output.set_value(r, c, input.get_value(row_in, col_in));
-                                }
-                            }
-                            if verbose {
-                                progress = (100.0_f64 * r as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
-                            }
+                        if point_in_poly(
+                            &Point2D { x: x, y: y },
+                            &record.points[start_point_in_part..end_point_in_part + 1],
+                        ) {
+                            num_containing_parts += 1;
                         }
-                        part_num += 1;
+                    }
+                    if num_containing_parts % 2 == 1 {
+                        inside.set_value(row, col, 1u8);
                     }
                 }
+            }
 
-                for part in 0..record.num_parts as usize {
-                    if record.is_hole(part as i32) {
-                        // Erase these cells
-
-                        start_point_in_part = record.parts[part] as usize;
-                        end_point_in_part = if part < record.num_parts as usize - 1 {
-                            record.parts[part + 1] as usize - 1
-                        } else {
-                            record.num_points as usize - 1
-                        };
-
-                        // First, figure out the minimum and maximum row and column for the polygon part
-                        starting_row = rows;
-                        ending_row = 0;
-                        starting_col = columns;
-                        ending_col = 0;
-                        for p in start_point_in_part..end_point_in_part + 1 {
-                            row = output.get_row_from_y(record.points[p].y);
-                            col = output.get_column_from_x(record.points[p].x);
-                            if row < starting_row {
-                                starting_row = row;
-                            }
-                            if row > ending_row {
-                                ending_row = row;
-                            }
-                            if col < starting_col {
-                                starting_col = col;
-                            }
-                            if col > ending_col {
-                                ending_col = col;
-                            }
-                        }
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
 
-                        for r in starting_row..ending_row {
-                            y = output.get_y_from_row(r);
-                            for c in starting_col..ending_col {
-                                x = output.get_x_from_column(c);
-                                if point_in_poly(
-                                    &Point2D { x: x, y: y },
-                                    &record.points[start_point_in_part..end_point_in_part + 1],
-                                ) {
-                                    output.set_value(r, c, nodata);
-                                }
-                            }
-                            if verbose {
-                                progress = (100.0_f64 * r as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
-                            }
-                        }
-                        part_num += 1;
+        let retained = if invert { 0u8 } else { 1u8 };
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                if inside.get_value(row, col) == retained {
+                    z = input.get_value(row + row_offset, col + col_offset);
+                    if z != nodata {
+                        output.set_value(row, col, z);
                     }
                 }
             }
+        }
 
-            let elapsed_time = get_formatted_elapsed_time(start);
-            output.add_metadata_entry(format!(
-                "Created by whitebox_tools\' {} tool",
-                self.get_tool_name()
-            ));
-            output.add_metadata_entry(format!("Input file: {}", input_file));
-            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Polygons file: {}", polygons_file));
+        if invert {
+            output.add_metadata_entry("Inverted: the areas within the polygons are erased".to_string());
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
-            if verbose {
-                println!("Saving data...")
-            };
-            let _ = match output.write() {
-                Ok(_) => if verbose {
-                    println!("Output file written")
-                },
-                Err(e) => return Err(e),
-            };
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
 
-            if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
-            }
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -2,17 +2,40 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 22/06/2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use num_cpus;
 use raster::*;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::ConcurrentUnionFind;
 use tools::*;
 
+/// This tool re-categorizes the data in a raster image by grouping cells that form
+/// physically discrete areas (i.e. connected regions of cells with the same value) and
+/// assigning each region a unique identifier. Regions may be connected through the four
+/// cardinal neighbours of each cell or, if `--diag` is specified, through all eight
+/// neighbours. If `--zero_back` is specified, cells with a value of zero are treated as
+/// background, and are not grouped into regions. Regions containing fewer cells than an
+/// optional minimum size (`--min_size`) are assigned the NoData value in the output.
+/// Regions are numbered consecutively from one, in the order of their first cell when the
+/// raster is scanned by rows.
+///
+/// The regions are identified using a two-pass connected-component labelling, in which
+/// the raster is divided into strips of rows that are processed concurrently, with each
+/// cell being joined to the regions of its previously scanned neighbours within a shared
+/// union-find structure. The tool therefore scales with the number of processors.
+///
+/// # See Also
+/// `FindPatchOrClassEdgeCells`, `Reclass`
 pub struct Clump {
     name: String,
     description: String,
@@ -69,6 +92,15 @@ impl Clump {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Minimum Region Size (cells)".to_owned(),
+            flags: vec!["--min_size".to_owned()],
+            description: "Minimum number of cells in an output region; smaller regions are assigned NoData (optional).".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -133,6 +165,7 @@ impl WhiteboxTool for Clump {
         let mut output_file = String::new();
         let mut diag = false;
         let mut zero_back = false;
+        let mut min_size = 0usize;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -140,39 +173,25 @@ impl WhiteboxTool for Clump {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-diag" {
-                diag = true;
-            } else if flag_val == "-zero_back" {
-                zero_back = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--diag"])? {
+            diag = true;
+        }
+        if tool_args.get_flag(&["--zero_back"])? {
+            zero_back = true;
+        }
+        if let Some(v) = tool_args.get_usize(&["--min_size"])? {
+            min_size = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -188,88 +207,216 @@ impl WhiteboxTool for Clump {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
-        let input = Raster::new(&input_file, "r")?;
+        let input = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         let nodata = input.configs.nodata;
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
+        let back_val = if zero_back { 0f64 } else { f64::NEG_INFINITY };
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
-        output.configs.data_type = DataType::I32;
+        // Each thread processes a strip of contiguous rows. Cells are identified by their
+        // row-major index, so the root of each region is its first cell in scan order.
+        let num_procs = num_cpus::get() as isize;
+        let strip_size = (rows + num_procs - 1) / num_procs;
+        let regions = Arc::new(ConcurrentUnionFind::new((rows * columns) as usize));
 
-        let mut dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let mut dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-        let mut num_neighbours = 8;
-        if !diag {
-            dx = [0, 1, 0, -1, 0, 0, 0, 0];
-            dy = [-1, 0, 1, 0, 0, 0, 0, 0];
-            num_neighbours = 4;
+        // Loop 1: join each cell to its previously scanned neighbours of the same value,
+        // including those in the last row of the preceding strip.
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let regions = regions.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut dx = vec![-1, 0];
+                let mut dy = vec![0, -1];
+                if diag {
+                    dx = vec![-1, -1, 0, 1];
+                    dy = vec![0, -1, -1, -1];
+                }
+                let mut z: f64;
+                for row in (tid * strip_size)..((tid + 1) * strip_size).min(rows) {
+                    for col in 0..columns {
+                        z = input[(row, col)];
+                        if z != nodata && z != back_val {
+                            for n in 0..dx.len() {
+                                // cells outside of the raster are nodata and never match
+                                if input[(row + dy[n], col + dx[n])] == z {
+                                    regions.union(
+                                        (row * columns + col) as usize,
+                                        ((row + dy[n]) * columns + col + dx[n]) as usize,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    tx.send(row).unwrap();
+                }
+            });
         }
-        let mut back_val = f64::NEG_INFINITY;
-        if zero_back {
-            back_val = 0f64;
+
+        for r in 0..rows {
+            rx.recv().unwrap();
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 1 of 2)", progress);
+                    old_progress = progress;
+                }
+            }
         }
-        let (mut zin, mut zout, mut zn): (f64, f64, f64);
-        let (mut r, mut c): (isize, isize);
-        let mut fid = 0f64;
-        let mut num_solved_cells = 0;
-        let num_cells = rows * columns;
-        let mut stack = Vec::with_capacity((rows * columns) as usize);
-        let mut count: usize; // this is just used to update the progress after every 1000 cells solved.
-        for row in 0..rows {
-            for col in 0..columns {
-                zin = input[(row, col)];
-                zout = output[(row, col)];
-                if zin != nodata && zin != back_val && zout == nodata {
-                    fid += 1f64;
-                    output[(row, col)] = fid;
-                    num_solved_cells += 1;
-                    stack.push((row, col));
-                    count = 0;
-                    while !stack.is_empty() {
-                        let cell = stack.pop().unwrap();
-                        r = cell.0;
-                        c = cell.1;
-                        count += 1;
-                        if count == 1000 {
-                            count = 0;
-                            if verbose {
-                                progress = (100.0_f64 * num_solved_cells as f64
-                                    / (num_cells - 1) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!("Performing analysis: {}%", progress);
-                                    old_progress = progress;
-                                }
+
+        // count the cells in each region, if small regions are to be removed. The
+        // counts are stored with the region roots and are later replaced by their labels.
+        let labels: Arc<Vec<AtomicUsize>> = Arc::new(
+            (0..(rows * columns) as usize)
+                .map(|_| AtomicUsize::new(0))
+                .collect(),
+        );
+        if min_size > 1 {
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let input = input.clone();
+                let regions = regions.clone();
+                let labels = labels.clone();
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    let mut z: f64;
+                    for row in (tid * strip_size)..((tid + 1) * strip_size).min(rows) {
+                        for col in 0..columns {
+                            z = input[(row, col)];
+                            if z != nodata && z != back_val {
+                                let root = regions.find((row * columns + col) as usize);
+                                labels[root].fetch_add(1, Ordering::Relaxed);
                             }
                         }
-                        for i in 0..num_neighbours {
-                            zn = input[(r + dy[i], c + dx[i])];
-                            zout = output[(r + dy[i], c + dx[i])];
-                            if zn == zin && zout == nodata {
-                                output[(r + dy[i], c + dx[i])] = fid;
-                                num_solved_cells += 1;
-                                stack.push((r + dy[i], c + dx[i]));
+                    }
+                    tx.send(tid).unwrap();
+                });
+            }
+            for _ in 0..num_procs {
+                rx.recv().unwrap();
+            }
+        }
+
+        // count the retained regions whose roots are in each strip, so that the
+        // regions may be labelled consecutively in scan order.
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let regions = regions.clone();
+            let labels = labels.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut z: f64;
+                let mut i: usize;
+                let mut num_roots = 0usize;
+                for row in (tid * strip_size)..((tid + 1) * strip_size).min(rows) {
+                    for col in 0..columns {
+                        z = input[(row, col)];
+                        i = (row * columns + col) as usize;
+                        if z != nodata
+                            && z != back_val
+                            && regions.find(i) == i
+                            && (min_size <= 1 || labels[i].load(Ordering::Relaxed) >= min_size)
+                        {
+                            num_roots += 1;
+                        }
+                    }
+                }
+                tx.send((tid, num_roots)).unwrap();
+            });
+        }
+        let mut first_label = vec![0usize; num_procs as usize + 1];
+        for _ in 0..num_procs {
+            let (tid, num_roots) = rx.recv().unwrap();
+            first_label[tid as usize + 1] = num_roots;
+        }
+        first_label[0] = 1;
+        for tid in 1..first_label.len() {
+            first_label[tid] += first_label[tid - 1];
+        }
+        let num_regions = first_label[num_procs as usize] - 1;
+
+        // label the retained regions; the roots of removed regions are labelled zero
+        let first_label = Arc::new(first_label);
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let regions = regions.clone();
+            let labels = labels.clone();
+            let first_label = first_label.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut z: f64;
+                let mut i: usize;
+                let mut label = first_label[tid as usize];
+                for row in (tid * strip_size)..((tid + 1) * strip_size).min(rows) {
+                    for col in 0..columns {
+                        z = input[(row, col)];
+                        i = (row * columns + col) as usize;
+                        if z != nodata && z != back_val && regions.find(i) == i {
+                            if min_size <= 1 || labels[i].load(Ordering::Relaxed) >= min_size {
+                                labels[i].store(label, Ordering::Relaxed);
+                                label += 1;
+                            } else {
+                                labels[i].store(0, Ordering::Relaxed);
                             }
                         }
                     }
-                } else if zin == nodata {
-                    num_solved_cells += 1;
-                } else if zin == back_val {
-                    num_solved_cells += 1;
-                    output[(row, col)] = back_val;
                 }
-            }
+                tx.send(tid).unwrap();
+            });
+        }
+        for _ in 0..num_procs {
+            rx.recv().unwrap();
+        }
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+
+        // Loop 2: assign each cell the label of its region
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let regions = regions.clone();
+            let labels = labels.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut z: f64;
+                let mut label: usize;
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![out_nodata; columns as usize];
+                    for col in 0..columns {
+                        z = input[(row, col)];
+                        if z == back_val {
+                            data[col as usize] = back_val;
+                        } else if z != nodata {
+                            label = labels[regions.find((row * columns + col) as usize)]
+                                .load(Ordering::Relaxed);
+                            if label > 0 {
+                                data[col as usize] = label as f64;
+                            }
+                        }
+                    }
+                    tx.send((row, data)).unwrap();
+                }
+            });
+        }
+
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        output.configs.data_type = DataType::I32;
+        for r in 0..rows {
+            let (row, data) = rx.recv().unwrap();
+            output.set_row_data(row, data);
             if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -283,23 +430,25 @@ impl WhiteboxTool for Clump {
         ));
         output.add_metadata_entry(format!("Input file: {}", input_file));
         output.add_metadata_entry(format!("Diagonal connectivity: {}", diag));
+        output.add_metadata_entry(format!("Background zero values: {}", zero_back));
+        if min_size > 1 {
+            output.add_metadata_entry(format!("Minimum region size: {}", min_size));
+        }
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Number of regions", &num_regions.to_string());
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
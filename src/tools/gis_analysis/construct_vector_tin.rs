@@ -2,23 +2,36 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 21/09/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
 use self::na::Vector3;
-use algorithms::triangulate;
+use super::clip::{get_polygon_union, get_rings_bounding_box};
+use algorithms::{polygon_overlay, triangulate, OverlayOperation};
 use na;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::Point2D;
+use structures::{BoundingBox, Point2D};
 use tools::*;
 use vector::ShapefileGeometry;
 use vector::*;
 
 /// This tool creates a vector triangular irregular network (TIN) for a set of vector points.
+/// The TIN is the Delaunay triangulation of the points. Each output triangle records the
+/// feature IDs (PNT1, PNT2, and PNT3) of the points at its vertices, so that the attributes of
+/// these points can be joined to the triangles. If an attribute field (`--field`) or the points'
+/// z values (`--use_z`) are specified, the centroid height and hillshade value of each
+/// triangle are also output.
+///
+/// The TIN may optionally be clipped to a boundary polygon (`--boundary`), in which case
+/// the triangles lying entirely outside of the boundary, which is the union of the
+/// boundary file's polygons, are removed and those crossing it are clipped.
+///
+/// # See Also
+/// `VoronoiDiagram`, `TINGridding`, `LidarConstructVectorTIN`
 pub struct ConstructVectorTIN {
     name: String,
     description: String,
@@ -82,6 +95,18 @@ impl ConstructVectorTIN {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input Boundary Polygon File (optional)".to_owned(),
+            flags: vec!["--boundary".to_owned()],
+            description: "Optional input vector polygon file to which the triangles are clipped."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -95,7 +120,7 @@ impl ConstructVectorTIN {
         }
         let usage = format!(
             ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp --field=HEIGHT -o=tin.shp
->>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp --use_z -o=tin.shp",
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp --use_z -o=tin.shp --boundary=site.shp",
             short_exe, name
         ).replace("*", &sep);
 
@@ -155,6 +180,7 @@ impl WhiteboxTool for ConstructVectorTIN {
         let mut use_z = false;
         let mut use_field = false;
         let mut output_file: String = "".to_string();
+        let mut boundary_file = String::new();
 
         // read the arguments
         if args.len() == 0 {
@@ -163,38 +189,22 @@ impl WhiteboxTool for ConstructVectorTIN {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                use_field = true;
-            } else if flag_val.contains("use_z") {
-                use_z = true;
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+            use_field = true;
+        }
+        if tool_args.get_flag(&["--use_z"])? {
+            use_z = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--boundary"])? {
+            boundary_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -204,9 +214,7 @@ impl WhiteboxTool for ConstructVectorTIN {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -255,6 +263,33 @@ impl WhiteboxTool for ConstructVectorTIN {
             }
         }
 
+        // read the boundary, if one is specified
+        let mut boundary: Vec<Vec<Point2D>> = vec![];
+        if !boundary_file.is_empty() {
+            if !boundary_file.contains(&sep) && !boundary_file.contains("/") {
+                boundary_file = format!("{}{}", working_directory, boundary_file);
+            }
+            let polygons = Shapefile::read(&boundary_file)?;
+            if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The boundary vector data must be of POLYGON base shape type.",
+                ));
+            }
+            if verbose {
+                report::message("Reading boundary...");
+            }
+            let records: Vec<usize> = (0..polygons.num_records).collect();
+            boundary = get_polygon_union(&polygons, &records);
+            if boundary.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The boundary vector does not contain any polygons.",
+                ));
+            }
+        }
+        let boundary_box = get_rings_bounding_box(&boundary);
+
         let azimuth = (315f64 - 90f64).to_radians();
         let altitude = 30f64.to_radians();
         let sin_theta = altitude.sin();
@@ -287,13 +322,21 @@ impl WhiteboxTool for ConstructVectorTIN {
             ));
         }
 
+        for name in &["PNT1", "PNT2", "PNT3"] {
+            output
+                .attributes
+                .add_field(&AttributeField::new(name, FieldDataType::Int, 7u8, 0u8));
+        }
+
         let mut points: Vec<Point2D> = vec![];
+        let mut point_records: Vec<usize> = vec![]; // the record containing each point
         let mut z_values: Vec<f64> = vec![];
 
         for record_num in 0..input.num_records {
             let record = input.get_record(record_num);
             for i in 0..record.num_points as usize {
                 points.push(Point2D::new(record.points[i].x, record.points[i].y));
+                point_records.push(record_num);
                 if use_z {
                     z_values.push(record.z_array[i]);
                 } else if use_field {
@@ -316,14 +359,14 @@ impl WhiteboxTool for ConstructVectorTIN {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    report::progress("Reading points", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Performing triangulation...");
+            report::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let result = triangulate(&points).expect("No triangulation exists.");
@@ -347,9 +390,27 @@ impl WhiteboxTool for ConstructVectorTIN {
             tri_points.push(points[p1].clone());
 
             let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
-            sfg.add_part(&tri_points);
+            if boundary.is_empty() {
+                sfg.add_part(&tri_points);
+            } else {
+                if !BoundingBox::from_points(&tri_points).overlaps(boundary_box) {
+                    continue;
+                }
+                let rings = polygon_overlay(
+                    &[tri_points.clone()],
+                    &boundary,
+                    OverlayOperation::Intersection,
+                );
+                if rings.is_empty() {
+                    continue;
+                }
+                for ring in &rings {
+                    sfg.add_part(ring);
+                }
+            }
             output.add_record(sfg);
 
+            let mut atts = vec![FieldData::Int(rec_num)];
             if use_field || use_z {
                 // calculate the hillshade value
                 let a = Vector3::new(tri_points[0].x, tri_points[0].y, z_values[p1]);
@@ -381,37 +442,31 @@ impl WhiteboxTool for ConstructVectorTIN {
                     }
                 }
 
-                output.attributes.add_record(
-                    vec![
-                        FieldData::Int(rec_num),
-                        FieldData::Real(centroid.z),
-                        FieldData::Int(hillshade as i32),
-                    ],
-                    false,
-                );
-            } else {
-                output
-                    .attributes
-                    .add_record(vec![FieldData::Int(rec_num)], false);
+                atts.push(FieldData::Real(centroid.z));
+                atts.push(FieldData::Int(hillshade as i32));
+            }
+            for &p in &[p1, p2, p3] {
+                atts.push(FieldData::Int(point_records[p] as i32 + 1i32));
             }
+            output.attributes.add_record(atts, false);
 
             rec_num += 1i32;
 
             if verbose {
                 progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating polygons: {}%", progress);
+                    report::progress("Creating polygons", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -419,7 +474,7 @@ impl WhiteboxTool for ConstructVectorTIN {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,283 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::f64;
+use std::io::Error;
+use structures::Array2D;
+
+/// The neighbours of a cell, as (column, row) offsets.
+const DX: [isize; 8] = [1, 1, 0, -1, -1, -1, 0, 1];
+const DY: [isize; 8] = [0, 1, 1, 1, 0, -1, -1, -1];
+
+/// Returns the cost of travelling between two neighbouring cells, which is the mean of their
+/// costs multiplied by the distance between their centres, as in the `CostDistance` tool.
+pub fn step_cost(cost1: f64, cost2: f64, dx: isize, dy: isize, res_x: f64, res_y: f64) -> f64 {
+    let (a, b) = (dx as f64 * res_x, dy as f64 * res_y);
+    (cost1 + cost2) / 2f64 * (a * a + b * b).sqrt()
+}
+
+/// Calculates the accumulated cost of travelling from the nearest of a set of source cells
+/// to each cell of a cost grid, using Dijkstra's algorithm. Cells with nodata or negative
+/// costs are impassable, and cells that cannot be reached are assigned infinity.
+pub fn accumulate_cost(
+    cost: &Array2D<f64>,
+    sources: &[(isize, isize)],
+    res_x: f64,
+    res_y: f64,
+) -> Result<Array2D<f64>, Error> {
+    let rows = cost.rows();
+    let columns = cost.columns();
+    let nodata = cost.nodata();
+    let passable = |row: isize, col: isize| -> bool {
+        let z = cost.get_value(row, col);
+        z != nodata && z >= 0f64
+    };
+    let mut accum: Array2D<f64> = Array2D::new(rows, columns, f64::INFINITY, f64::INFINITY)?;
+    let mut done: Array2D<u8> = Array2D::new(rows, columns, 0, 1)?;
+    let mut queue = BinaryHeap::new();
+    for &(row, col) in sources {
+        if passable(row, col) && accum.get_value(row, col) > 0f64 {
+            accum.set_value(row, col, 0f64);
+            queue.push(QueueCell {
+                row: row,
+                col: col,
+                priority: 0f64,
+            });
+        }
+    }
+    while let Some(cell) = queue.pop() {
+        let (row, col) = (cell.row, cell.col);
+        if done.get_value(row, col) == 1 {
+            continue;
+        }
+        done.set_value(row, col, 1);
+        let cost1 = cost.get_value(row, col);
+        for n in 0..8 {
+            let (row_n, col_n) = (row + DY[n], col + DX[n]);
+            if done.get_value(row_n, col_n) == 1 || !passable(row_n, col_n) {
+                continue;
+            }
+            let accum_n = cell.priority
+                + step_cost(cost1, cost.get_value(row_n, col_n), DX[n], DY[n], res_x, res_y);
+            if accum_n < accum.get_value(row_n, col_n) {
+                accum.set_value(row_n, col_n, accum_n);
+                queue.push(QueueCell {
+                    row: row_n,
+                    col: col_n,
+                    priority: accum_n,
+                });
+            }
+        }
+    }
+    Ok(accum)
+}
+
+/// Finds the `k` least-cost paths from any of a set of source cells to a target cell, using
+/// Yen's k-shortest paths algorithm, where the sources are treated as the neighbours of a
+/// single virtual origin. Returns the cost and cells of each path, in order of increasing
+/// cost, with the cells in order from the source. No path visits a cell more than once, and
+/// fewer than `k` paths are returned if fewer exist.
+pub fn least_cost_paths(
+    cost: &Array2D<f64>,
+    sources: &[(isize, isize)],
+    target: (isize, isize),
+    k: usize,
+    res_x: f64,
+    res_y: f64,
+) -> Result<Vec<(f64, Vec<(isize, isize)>)>, Error> {
+    // since step costs are symmetric, the cost of travelling from each cell to the target
+    // is the cost accumulated from the target, which guides the searches for spur paths
+    let to_target = accumulate_cost(cost, &[target], res_x, res_y)?;
+    let mut paths = vec![];
+    let first = find_path(
+        cost,
+        sources,
+        target,
+        &to_target,
+        &HashSet::new(),
+        &HashSet::new(),
+        res_x,
+        res_y,
+    );
+    match first {
+        Some(path) => paths.push(path),
+        None => return Ok(paths),
+    }
+    let mut candidates: Vec<(f64, Vec<(isize, isize)>)> = vec![];
+    while paths.len() < k {
+        let prev = paths[paths.len() - 1].1.clone();
+        // The spur path leaves the previous path after its first i cells, its root; with an
+        // empty root, it leaves the virtual origin for a different source cell.
+        let mut root_cost = 0f64;
+        for i in 0..prev.len() {
+            let mut blocked_cells = HashSet::new();
+            let mut blocked_steps = HashSet::new();
+            let starts = if i == 0 {
+                sources
+                    .iter()
+                    .filter(|s| !paths.iter().any(|p| p.1[0] == **s))
+                    .cloned()
+                    .collect()
+            } else {
+                if i > 1 {
+                    let ((row1, col1), (row2, col2)) = (prev[i - 2], prev[i - 1]);
+                    root_cost += step_cost(
+                        cost.get_value(row1, col1),
+                        cost.get_value(row2, col2),
+                        col2 - col1,
+                        row2 - row1,
+                        res_x,
+                        res_y,
+                    );
+                }
+                // the root's other cells and the steps that earlier paths with the same
+                // root take from its last cell are excluded
+                for &cell in &prev[..i - 1] {
+                    blocked_cells.insert(cell);
+                }
+                for p in &paths {
+                    if p.1.len() > i && p.1[..i] == prev[..i] {
+                        blocked_steps.insert((prev[i - 1], p.1[i]));
+                    }
+                }
+                vec![prev[i - 1]]
+            };
+            let spur = find_path(
+                cost,
+                &starts,
+                target,
+                &to_target,
+                &blocked_cells,
+                &blocked_steps,
+                res_x,
+                res_y,
+            );
+            if let Some((spur_cost, spur_path)) = spur {
+                let mut path = if i > 0 { prev[..i - 1].to_vec() } else { vec![] };
+                path.extend(spur_path);
+                if !paths.iter().chain(candidates.iter()).any(|p| p.1 == path) {
+                    candidates.push((root_cost + spur_cost, path));
+                }
+            }
+        }
+        if candidates.is_empty() {
+            break;
+        }
+        let mut best = 0;
+        for j in 1..candidates.len() {
+            if candidates[j].0 < candidates[best].0 {
+                best = j;
+            }
+        }
+        paths.push(candidates.swap_remove(best));
+    }
+    // the costs of later paths may be lower than those of earlier ones by a rounding error
+    paths.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    Ok(paths)
+}
+
+/// Finds the least-cost path from the cheapest of a set of start cells to a target cell,
+/// avoiding a set of blocked cells and of blocked steps between neighbouring cells, using A*
+/// search. `to_target` holds the cost of travelling from each cell to the target without
+/// any blocked cells or steps, which is a lower bound on the cost when some are blocked.
+/// Returns the cost and cells of the path, or None if the target cannot be reached.
+fn find_path(
+    cost: &Array2D<f64>,
+    starts: &[(isize, isize)],
+    target: (isize, isize),
+    to_target: &Array2D<f64>,
+    blocked_cells: &HashSet<(isize, isize)>,
+    blocked_steps: &HashSet<((isize, isize), (isize, isize))>,
+    res_x: f64,
+    res_y: f64,
+) -> Option<(f64, Vec<(isize, isize)>)> {
+    let nodata = cost.nodata();
+    let passable = |cell: (isize, isize)| -> bool {
+        let z = cost.get_value(cell.0, cell.1);
+        z != nodata
+            && z >= 0f64
+            && to_target.get_value(cell.0, cell.1).is_finite()
+            && !blocked_cells.contains(&cell)
+    };
+    // the search only visits the cells near the path, which are held in maps
+    let mut accum: HashMap<(isize, isize), f64> = HashMap::new();
+    let mut backlink: HashMap<(isize, isize), (isize, isize)> = HashMap::new();
+    let mut done: HashSet<(isize, isize)> = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    for &(row, col) in starts {
+        if passable((row, col)) && !accum.contains_key(&(row, col)) {
+            accum.insert((row, col), 0f64);
+            queue.push(QueueCell {
+                row: row,
+                col: col,
+                priority: to_target.get_value(row, col),
+            });
+        }
+    }
+    while let Some(cell) = queue.pop() {
+        let (row, col) = (cell.row, cell.col);
+        if !done.insert((row, col)) {
+            continue;
+        }
+        let accum1 = accum[&(row, col)];
+        if (row, col) == target {
+            let mut path = vec![target];
+            while let Some(&prev) = backlink.get(&path[path.len() - 1]) {
+                path.push(prev);
+            }
+            path.reverse();
+            return Some((accum1, path));
+        }
+        let cost1 = cost.get_value(row, col);
+        for n in 0..8 {
+            let (row_n, col_n) = (row + DY[n], col + DX[n]);
+            if done.contains(&(row_n, col_n))
+                || !passable((row_n, col_n))
+                || blocked_steps.contains(&((row, col), (row_n, col_n)))
+            {
+                continue;
+            }
+            let accum_n = accum1
+                + step_cost(cost1, cost.get_value(row_n, col_n), DX[n], DY[n], res_x, res_y);
+            if accum.get(&(row_n, col_n)).map_or(true, |&a| accum_n < a) {
+                accum.insert((row_n, col_n), accum_n);
+                backlink.insert((row_n, col_n), (row, col));
+                queue.push(QueueCell {
+                    row: row_n,
+                    col: col_n,
+                    priority: accum_n + to_target.get_value(row_n, col_n),
+                });
+            }
+        }
+    }
+    None
+}
+
+#[derive(PartialEq, Debug)]
+struct QueueCell {
+    row: isize,
+    col: isize,
+    priority: f64,
+}
+
+impl Eq for QueueCell {}
+
+impl PartialOrd for QueueCell {
+    fn partial_cmp(&self, other: &QueueCell) -> Option<Ordering> {
+        // reversed, such that the binary heap pops the lowest accumulated cost first
+        other.priority.partial_cmp(&self.priority)
+    }
+}
+
+impl Ord for QueueCell {
+    fn cmp(&self, other: &QueueCell) -> Ordering {
+        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+    }
+}
@@ -127,40 +127,19 @@ impl WhiteboxTool for CostAllocation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-backlink" || vec[0].to_lowercase() == "--backlink" {
-                if keyval {
-                    d8_file = vec[1].to_string();
-                } else {
-                    d8_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-source" || vec[0].to_lowercase() == "--source" {
-                if keyval {
-                    pourpts_file = vec[1].to_string();
-                } else {
-                    pourpts_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--backlink"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--source"])? {
+            pourpts_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -179,7 +158,7 @@ impl WhiteboxTool for CostAllocation {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -199,7 +178,7 @@ impl WhiteboxTool for CostAllocation {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -208,6 +187,7 @@ impl WhiteboxTool for CostAllocation {
 
         let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
         let mut output = Raster::initialize_using_file(&output_file, &pourpts);
+        let out_nodata = output.configs.nodata;
         output.configs.palette = palette;
         output.configs.photometric_interp = pourpts.configs.photometric_interp;
         let low_value = f64::MIN;
@@ -240,7 +220,7 @@ impl WhiteboxTool for CostAllocation {
                         flow_dir[(row, col)] = -1i8;
                     }
                 } else {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
                 z = pourpts[(row, col)];
                 if z != nodata && z > 0.0 {
@@ -250,7 +230,7 @@ impl WhiteboxTool for CostAllocation {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing: {}%", progress);
+                    report::progress("Initializing", progress);
                     old_progress = progress;
                 }
             }
@@ -267,7 +247,7 @@ impl WhiteboxTool for CostAllocation {
                     flag = false;
                     x = col;
                     y = row;
-                    outlet_id = nodata;
+                    outlet_id = out_nodata;
                     while !flag {
                         // find its downslope neighbour
                         dir = flow_dir[(y, x)];
@@ -313,7 +293,7 @@ impl WhiteboxTool for CostAllocation {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -329,20 +309,17 @@ impl WhiteboxTool for CostAllocation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
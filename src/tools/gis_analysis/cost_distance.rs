@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 4, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTES: Add anisotropy option.
@@ -138,50 +138,22 @@ impl WhiteboxTool for CostDistance {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-source" || vec[0].to_lowercase() == "--source" {
-                if keyval {
-                    source_file = vec[1].to_string();
-                } else {
-                    source_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-cost" || vec[0].to_lowercase() == "--cost" {
-                if keyval {
-                    cost_file = vec[1].to_string();
-                } else {
-                    cost_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_accum"
-                || vec[0].to_lowercase() == "--out_accum"
-            {
-                if keyval {
-                    accum_file = vec[1].to_string();
-                } else {
-                    accum_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_backlink"
-                || vec[0].to_lowercase() == "--out_backlink"
-            {
-                if keyval {
-                    backlink_file = vec[1].to_string();
-                } else {
-                    backlink_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--source"])? {
+            source_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--cost"])? {
+            cost_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_accum"])? {
+            accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_backlink"])? {
+            backlink_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,12 +175,12 @@ impl WhiteboxTool for CostDistance {
         }
 
         if verbose {
-            println!("Reading source data...")
+            report::message("Reading source data...")
         };
         let source = Raster::new(&source_file, "r")?;
 
         if verbose {
-            println!("Reading cost data...")
+            report::message("Reading cost data...")
         };
         let cost = Raster::new(&cost_file, "r")?;
 
@@ -218,7 +190,7 @@ impl WhiteboxTool for CostDistance {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -228,6 +200,7 @@ impl WhiteboxTool for CostDistance {
         let nodata = cost.configs.nodata;
 
         let mut output = Raster::initialize_using_file(&accum_file, &cost);
+        let out_nodata = output.configs.nodata;
         let background_val = (i32::max_value() - 1) as f64;
         output.reinitialize_values(background_val);
 
@@ -239,13 +212,13 @@ impl WhiteboxTool for CostDistance {
                     output[(row, col)] = 0.0;
                     backlink[(row, col)] = -1.0;
                 } else if cost[(row, col)] == nodata {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing: {}%", progress);
+                    report::progress("Initializing", progress);
                     old_progress = progress;
                 }
             }
@@ -259,7 +232,6 @@ impl WhiteboxTool for CostDistance {
         let cell_size_y = source.configs.resolution_y;
         let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
         let dist = [
-            diag_cell_size,
             cell_size_x,
             diag_cell_size,
             cell_size_y,
@@ -267,6 +239,7 @@ impl WhiteboxTool for CostDistance {
             cell_size_x,
             diag_cell_size,
             cell_size_y,
+            diag_cell_size,
         ];
         let dx = [1, 1, 0, -1, -1, -1, 0, 1];
         let dy = [0, 1, 1, 1, 0, -1, -1, -1];
@@ -281,12 +254,15 @@ impl WhiteboxTool for CostDistance {
             for row in 0..rows {
                 for col in 0..columns {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -299,7 +275,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -314,12 +290,15 @@ impl WhiteboxTool for CostDistance {
             for row in (0..rows).rev() {
                 for col in (0..columns).rev() {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -332,7 +311,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -347,12 +326,15 @@ impl WhiteboxTool for CostDistance {
             for row in 0..rows {
                 for col in (0..columns).rev() {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -365,7 +347,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -380,12 +362,15 @@ impl WhiteboxTool for CostDistance {
             for row in (0..rows).rev() {
                 for col in 0..columns {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -398,7 +383,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -415,12 +400,15 @@ impl WhiteboxTool for CostDistance {
             for col in 0..columns {
                 for row in 0..rows {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -433,7 +421,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -448,12 +436,15 @@ impl WhiteboxTool for CostDistance {
             for col in (0..columns).rev() {
                 for row in (0..rows).rev() {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -466,7 +457,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -481,12 +472,15 @@ impl WhiteboxTool for CostDistance {
             for col in (0..columns).rev() {
                 for row in 0..rows {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -499,7 +493,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -514,12 +508,15 @@ impl WhiteboxTool for CostDistance {
             for col in 0..columns {
                 for row in (0..rows).rev() {
                     accum_val = output[(row, col)];
-                    if accum_val < background_val && accum_val != nodata {
+                    if accum_val < background_val && accum_val != out_nodata {
                         cost1 = cost[(row, col)];
                         for n in 0..8 {
                             col_n = col + dx[n];
                             row_n = row + dy[n];
                             cost2 = cost[(row_n, col_n)];
+                            if cost2 == nodata {
+                                continue;
+                            }
                             new_cost = accum_val + (cost1 + cost2) / 2.0 * dist[n];
                             if new_cost < output[(row_n, col_n)] {
                                 output.set_value(row_n, col_n, new_cost);
@@ -532,7 +529,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -606,11 +603,11 @@ impl WhiteboxTool for CostDistance {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -626,16 +623,13 @@ impl WhiteboxTool for CostDistance {
         backlink.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         let _ = match backlink.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
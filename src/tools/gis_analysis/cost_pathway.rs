@@ -139,46 +139,22 @@ impl WhiteboxTool for CostPathway {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-destination" || vec[0].to_lowercase() == "--destination" {
-                if keyval {
-                    destination_file = vec[1].to_string();
-                } else {
-                    destination_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-backlink" || vec[0].to_lowercase() == "--backlink"
-            {
-                if keyval {
-                    backlink_file = vec[1].to_string();
-                } else {
-                    backlink_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                background_val = 0f64;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--destination"])? {
+            destination_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--backlink"])? {
+            backlink_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--zero_background", "--esri_style"])? {
+            background_val = 0f64;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -197,12 +173,12 @@ impl WhiteboxTool for CostPathway {
         }
 
         if verbose {
-            println!("Reading destination data...")
+            report::message("Reading destination data...")
         };
         let destination = Raster::new(&destination_file, "r")?;
 
         if verbose {
-            println!("Reading backlink data...")
+            report::message("Reading backlink data...")
         };
         let backlink = Raster::new(&backlink_file, "r")?;
 
@@ -212,7 +188,7 @@ impl WhiteboxTool for CostPathway {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -220,11 +196,12 @@ impl WhiteboxTool for CostPathway {
         let rows = destination.configs.rows as isize;
         let columns = destination.configs.columns as isize;
         let nodata = backlink.configs.nodata;
-        if background_val == f64::NEG_INFINITY {
-            background_val = nodata;
-        }
 
         let mut output = Raster::initialize_using_file(&output_file, &destination);
+        let out_nodata = output.configs.nodata;
+        if background_val == f64::NEG_INFINITY {
+            background_val = out_nodata;
+        }
         output.reinitialize_values(background_val);
 
         let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -266,13 +243,13 @@ impl WhiteboxTool for CostPathway {
                         }
                     }
                 } else if backlink[(row, col)] == nodata {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -291,20 +268,17 @@ impl WhiteboxTool for CostPathway {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -125,41 +125,19 @@ impl WhiteboxTool for CountIf {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-value" {
-                comparison_value = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--value"])? {
+            comparison_value = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -204,7 +182,7 @@ impl WhiteboxTool for CountIf {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    report::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -217,16 +195,16 @@ impl WhiteboxTool for CountIf {
                     read_first_file = true;
                     rows = input.configs.rows as isize;
                     columns = input.configs.columns as isize;
-                    out_nodata = in_nodata;
 
                     // initialize the output file and n
                     output = Raster::initialize_using_file(&output_file, &input);
+                    out_nodata = output.configs.nodata;
                 }
                 // check to ensure that all inputs have the same rows and columns
                 if input.configs.rows as isize != rows || input.configs.columns as isize != columns
                 {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
+                                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
 
                 for row in 0..rows {
@@ -243,7 +221,10 @@ impl WhiteboxTool for CountIf {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files, progress);
+                            report::progress(
+                                &format!("Progress (loop {} of {})", i, num_files),
+                                progress,
+                            );
                             old_progress = progress;
                         }
                     }
@@ -260,20 +241,17 @@ impl WhiteboxTool for CountIf {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,232 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::f64;
+use std::io::Error;
+use structures::Array2D;
+use utils::report;
+
+/// The neighbours visited by the forward (top-left to bottom-right) pass of the distance
+/// transforms, as (column, row) offsets; the backward pass visits their reflections.
+const FORWARD_DX: [isize; 4] = [-1, -1, 0, 1];
+const FORWARD_DY: [isize; 4] = [0, -1, -1, -1];
+
+/// The nearest source cell of each cell in a raster, found with a two-pass Euclidean
+/// distance transform (Shih and Wu, 2004), in which the location of the nearest source cell
+/// is propagated from cell to cell, first from the top-left of the grid and then from the
+/// bottom-right. The source cells are the valid, non-zero cells of the input raster; nodata
+/// cells are not sources but do not block the propagation.
+///
+/// Reference:
+///
+/// Shih FY and Wu Y-T (2004), Fast Euclidean distance transformation in two scans using a
+/// 3 x 3 neighborhood, *Computer Vision and Image Understanding*, 93: 195-205.
+pub struct EuclideanTransform {
+    source_row: Array2D<isize>,
+    source_col: Array2D<isize>,
+    resolution_x: f64,
+    resolution_y: f64,
+}
+
+impl EuclideanTransform {
+    pub fn new(input: &Raster, verbose: bool) -> Result<EuclideanTransform, Error> {
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let mut source_row: Array2D<isize> = Array2D::new(rows, columns, -1, -1)?;
+        let mut source_col: Array2D<isize> = Array2D::new(rows, columns, -1, -1)?;
+        let mut sq_distance: Array2D<f64> = Array2D::new(rows, columns, f64::INFINITY, -1f64)?;
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != 0f64 && z != nodata {
+                    source_row.set_value(row, col, row);
+                    source_col.set_value(row, col, col);
+                    sq_distance.set_value(row, col, 0f64);
+                }
+            }
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        let (mut x, mut y, mut sr, mut sc): (isize, isize, isize, isize);
+        let mut d: f64;
+        for pass in 0..2 {
+            let sign = if pass == 0 { 1isize } else { -1isize };
+            for r in 0..rows {
+                let row = if pass == 0 { r } else { rows - 1 - r };
+                for c in 0..columns {
+                    let col = if pass == 0 { c } else { columns - 1 - c };
+                    if sq_distance.get_value(row, col) == 0f64 {
+                        continue;
+                    }
+                    for i in 0..4 {
+                        x = col + sign * FORWARD_DX[i];
+                        y = row + sign * FORWARD_DY[i];
+                        sr = source_row.get_value(y, x);
+                        if sr < 0 {
+                            continue;
+                        }
+                        sc = source_col.get_value(y, x);
+                        d = ((row - sr) * (row - sr) + (col - sc) * (col - sc)) as f64;
+                        if d < sq_distance.get_value(row, col) {
+                            sq_distance.set_value(row, col, d);
+                            source_row.set_value(row, col, sr);
+                            source_col.set_value(row, col, sc);
+                        }
+                    }
+                }
+                if verbose {
+                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress(
+                            &format!("Distance transform (pass {} of 2)", pass + 1),
+                            progress,
+                        );
+                        old_progress = progress;
+                    }
+                }
+            }
+        }
+
+        Ok(EuclideanTransform {
+            source_row: source_row,
+            source_col: source_col,
+            resolution_x: input.configs.resolution_x,
+            resolution_y: input.configs.resolution_y,
+        })
+    }
+
+    /// Returns the row and column of the nearest source cell to a cell, or None if the
+    /// raster does not contain any source cells.
+    pub fn nearest_source(&self, row: isize, col: isize) -> Option<(isize, isize)> {
+        let sr = self.source_row.get_value(row, col);
+        if sr < 0 {
+            return None;
+        }
+        Some((sr, self.source_col.get_value(row, col)))
+    }
+
+    /// Returns the distance, in map units, from a cell to its nearest source cell.
+    pub fn distance(&self, row: isize, col: isize) -> Option<f64> {
+        self.nearest_source(row, col).map(|(sr, sc)| {
+            let dx = (col - sc) as f64 * self.resolution_x;
+            let dy = (row - sr) as f64 * self.resolution_y;
+            (dx * dx + dy * dy).sqrt()
+        })
+    }
+
+    /// Returns the direction from a cell to its nearest source cell, as an azimuth in degrees
+    /// clockwise from north, from just above 0 to 360 (north), or 0 for source cells.
+    pub fn direction(&self, row: isize, col: isize) -> Option<f64> {
+        self.nearest_source(row, col).map(|(sr, sc)| {
+            if sr == row && sc == col {
+                return 0f64;
+            }
+            let dx = (sc - col) as f64 * self.resolution_x;
+            let dy = (row - sr) as f64 * self.resolution_y;
+            let azimuth = dx.atan2(dy).to_degrees();
+            if azimuth <= 0f64 {
+                azimuth + 360f64
+            } else {
+                azimuth
+            }
+        })
+    }
+}
+
+/// Calculates the geodesic distance, in map units, from each passable cell of a grid to the
+/// nearest source cell, travelling only through passable cells. The distance is
+/// approximated with a 5 x 5 chamfer transform, whose steps to the 16 neighbours within two
+/// cells (excluding those that are aligned with closer neighbours) have their Euclidean
+/// lengths, which limits the error to about 2% of the distance. The longer, knight's-move
+/// steps may only pass between two passable cells. Forward and backward passes of the
+/// transform are repeated until the distances no longer change, such that distances follow
+/// winding corridors. Impassable and unreachable cells are assigned infinity.
+pub fn geodesic_distance(
+    is_source: &Array2D<u8>,
+    is_passable: &Array2D<u8>,
+    resolution_x: f64,
+    resolution_y: f64,
+    verbose: bool,
+) -> Result<Array2D<f64>, Error> {
+    let rows = is_source.rows();
+    let columns = is_source.columns();
+    // the forward half of the neighbourhood, as (column, row) offsets
+    let dx: [isize; 8] = [-1, -1, 0, 1, -1, 1, -2, 2];
+    let dy: [isize; 8] = [0, -1, -1, -1, -2, -2, -1, -1];
+    let mut step = [0f64; 8];
+    for i in 0..8 {
+        let (a, b) = (dx[i] as f64 * resolution_x, dy[i] as f64 * resolution_y);
+        step[i] = (a * a + b * b).sqrt();
+    }
+    let mut distance: Array2D<f64> = Array2D::new(rows, columns, f64::INFINITY, f64::INFINITY)?;
+    for row in 0..rows {
+        for col in 0..columns {
+            if is_source.get_value(row, col) == 1 && is_passable.get_value(row, col) == 1 {
+                distance.set_value(row, col, 0f64);
+            }
+        }
+    }
+
+    let passable = |row: isize, col: isize| -> bool { is_passable.get_value(row, col) == 1 };
+    let mut iteration = 0;
+    loop {
+        iteration += 1;
+        let mut changed = false;
+        for pass in 0..2 {
+            let sign = if pass == 0 { 1isize } else { -1isize };
+            for r in 0..rows {
+                let row = if pass == 0 { r } else { rows - 1 - r };
+                for c in 0..columns {
+                    let col = if pass == 0 { c } else { columns - 1 - c };
+                    if !passable(row, col) {
+                        continue;
+                    }
+                    let mut d = distance.get_value(row, col);
+                    if d == 0f64 {
+                        continue;
+                    }
+                    for i in 0..8 {
+                        let (ox, oy) = (sign * dx[i], sign * dy[i]);
+                        if !passable(row + oy, col + ox) {
+                            continue;
+                        }
+                        // a knight's move must pass between the two cells that it straddles
+                        if ox.abs() == 2 && !(passable(row, col + ox / 2)
+                            && passable(row + oy, col + ox / 2))
+                        {
+                            continue;
+                        }
+                        if oy.abs() == 2 && !(passable(row + oy / 2, col)
+                            && passable(row + oy / 2, col + ox))
+                        {
+                            continue;
+                        }
+                        let dn = distance.get_value(row + oy, col + ox) + step[i];
+                        if dn < d {
+                            d = dn;
+                            changed = true;
+                        }
+                    }
+                    distance.set_value(row, col, d);
+                }
+            }
+        }
+        if verbose {
+            report::message(&format!("Geodesic distance transform: iteration {}", iteration));
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    Ok(distance)
+}
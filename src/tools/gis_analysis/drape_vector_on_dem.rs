@@ -0,0 +1,359 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::extract_values_to_points::{interpolate, nearest};
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Point2D;
+use tools::*;
+use vector::*;
+
+/// This tool drapes a 2D vector (`--input`) of points, lines, or polygons onto a digital
+/// elevation model (`--dem`), creating 3D features. The output vector (`--output`) is of the
+/// Z variant of the input's shape type, e.g. PolyLineZ for a PolyLine input, and the z-value
+/// of each vertex is sampled from the DEM, either from the grid cell containing the vertex
+/// (`--method=nearest`) or by bilinear interpolation between the centres of the four
+/// nearest cells (`--method=bilinear`, the default). If the input already has z-values,
+/// they are replaced. Measures (M values) of the input are retained, and are zero otherwise.
+///
+/// The vertices of lines and polygons may be spaced far apart relative to the DEM's grid
+/// resolution, in which case the draped features will not follow the terrain between their
+/// vertices. Vertices can be added along each segment, at a spacing of no more than
+/// `--densify` map units, so that the draped features follow the terrain more closely;
+/// the measures of added vertices are interpolated linearly. Vertices that fall outside of
+/// the DEM, or on NoData cells, are assigned the DEM's NoData value, and a warning is
+/// reported.
+///
+/// # See Also
+/// `LineLength3D`, `VertexZStatistics`, `ExtractValuesToPoints`
+pub struct DrapeVectorOnDem {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DrapeVectorOnDem {
+    pub fn new() -> DrapeVectorOnDem {
+        // public constructor
+        let name = "DrapeVectorOnDem".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Drapes a 2D vector onto a DEM, creating 3D features with z-values sampled from the DEM."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points, lines, or polygon file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output 3D vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sampling Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "DEM sampling method; options are 'nearest' and 'bilinear'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "nearest".to_owned(),
+                "bilinear".to_owned(),
+            ]),
+            default_value: Some("bilinear".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Densification Distance (optional)".to_owned(),
+            flags: vec!["--densify".to_owned()],
+            description: "Optional maximum spacing of the vertices of lines and polygons, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=roads.shp --dem=dem.tif -o=roads_3d.shp --densify=10.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        DrapeVectorOnDem {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DrapeVectorOnDem {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut dem_file = String::new();
+        let mut output_file = String::new();
+        let mut bilinear = true;
+        let mut densify = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            bilinear = v.to_lowercase().contains("bi");
+        }
+        if let Some(v) = tool_args.get_f64(&["--densify"])? {
+            densify = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Shapefile::read(&input_file)?;
+        let dem = Raster::new(&dem_file, "r")?;
+
+        let start = Instant::now();
+
+        let base_shape_type = input.header.shape_type.base_shape_type();
+        let output_shape_type = match base_shape_type {
+            ShapeType::Point => ShapeType::PointZ,
+            ShapeType::MultiPoint => ShapeType::MultiPointZ,
+            ShapeType::PolyLine => ShapeType::PolyLineZ,
+            ShapeType::Polygon => ShapeType::PolygonZ,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input vector data contains an unsupported shape type.",
+                ))
+            }
+        };
+        let has_measures = input.header.shape_type.dimension() != ShapeTypeDimension::XY;
+        let densify = if base_shape_type == ShapeType::PolyLine
+            || base_shape_type == ShapeType::Polygon
+        {
+            densify
+        } else {
+            0f64
+        };
+
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, output_shape_type, true)?;
+
+        let nodata = dem.configs.nodata;
+        let mut num_missing = 0;
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let mut geometry = ShapefileGeometry::new(output_shape_type);
+            let num_parts = (record.num_parts as usize).max(1);
+            for part in 0..num_parts {
+                let part_start = if record.num_parts > 0 {
+                    record.parts[part] as usize
+                } else {
+                    0
+                };
+                let part_end = if part < num_parts - 1 {
+                    record.parts[part + 1] as usize
+                } else {
+                    record.num_points as usize
+                };
+                let mut points: Vec<Point2D> = vec![];
+                let mut measures: Vec<f64> = vec![];
+                for i in part_start..part_end {
+                    let m = if has_measures { record.m_array[i] } else { 0f64 };
+                    if densify > 0f64 && i > part_start {
+                        // add vertices along the segment from the previous vertex
+                        let (p1, p2) = (record.points[i - 1], record.points[i]);
+                        let m1 = measures[measures.len() - 1];
+                        let n = (p1.distance(&p2) / densify).ceil() as usize;
+                        for k in 1..n {
+                            let t = k as f64 / n as f64;
+                            points.push(Point2D::new(
+                                p1.x + t * (p2.x - p1.x),
+                                p1.y + t * (p2.y - p1.y),
+                            ));
+                            measures.push(m1 + t * (m - m1));
+                        }
+                    }
+                    points.push(record.points[i]);
+                    measures.push(m);
+                }
+                if points.is_empty() {
+                    continue;
+                }
+                let z_values: Vec<f64> = points
+                    .iter()
+                    .map(|p| {
+                        let z = if bilinear {
+                            interpolate(&dem, p.x, p.y)
+                        } else {
+                            nearest(&dem, p.x, p.y)
+                        };
+                        match z {
+                            Some(z) => z,
+                            None => {
+                                num_missing += 1;
+                                nodata
+                            }
+                        }
+                    })
+                    .collect();
+                if record.num_parts > 0 {
+                    geometry.add_partz(&points, &measures, &z_values);
+                } else {
+                    for i in 0..points.len() {
+                        geometry.add_pointz(points[i], measures[i], z_values[i]);
+                    }
+                }
+            }
+            output.add_record(geometry);
+            output
+                .attributes
+                .add_record(input.attributes.get_record(record_num), false);
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if num_missing > 0 {
+            report::warning(&format!(
+                "{} vertices are outside of the DEM or on NoData cells and were assigned the NoData value.",
+                num_missing
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
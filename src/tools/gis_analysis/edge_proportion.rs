@@ -132,38 +132,19 @@ impl WhiteboxTool for EdgeProportion {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-output_text"
-                || vec[0].to_lowercase() == "--output_text"
-            {
-                output_text = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--output_text"])? {
+            output_text = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -179,7 +160,7 @@ impl WhiteboxTool for EdgeProportion {
         }
 
         if verbose {
-            println!("Reading input data...")
+            report::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -238,13 +219,15 @@ impl WhiteboxTool for EdgeProportion {
             if verbose {
                 progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    report::progress("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        let mut edge_props = vec![nodata; max_val + 1];
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        let mut edge_props = vec![out_nodata; max_val + 1];
         for bin in 0..max_val + 1 {
             if num_cells[bin] > 0 {
                 edge_props[bin] = num_edge_cells[bin] as f64 / num_cells[bin] as f64;
@@ -261,7 +244,7 @@ impl WhiteboxTool for EdgeProportion {
                 let mut z: f64;
                 let mut bin: usize;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input[(row, col)];
                         if z > 0f64 && z != nodata {
@@ -274,7 +257,6 @@ impl WhiteboxTool for EdgeProportion {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         output.configs.data_type = DataType::F32;
         output.configs.palette = "spectrum.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Continuous;
@@ -285,7 +267,7 @@ impl WhiteboxTool for EdgeProportion {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -300,29 +282,26 @@ impl WhiteboxTool for EdgeProportion {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if output_text {
-            println!("Edge Proportion\nPatch ID\tValue");
+            report::message("Edge Proportion\nPatch ID\tValue");
             for bin in 0..max_val + 1 {
-                if edge_props[bin] > 0f64 && edge_props[bin] != nodata {
-                    println!("{}\t{}", bin, edge_props[bin]);
+                if edge_props[bin] > 0f64 && edge_props[bin] != out_nodata {
+                    report::message(&format!("{}\t{}", bin, edge_props[bin]));
                 }
             }
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
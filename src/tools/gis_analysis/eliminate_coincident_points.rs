@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 16/09/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -10,7 +10,7 @@ use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::{DistanceMetric, FixedRadiusSearch2D};
+use structures::{DistanceMetric, KdTree};
 use tools::*;
 use vector::*;
 
@@ -147,35 +147,15 @@ impl WhiteboxTool for EliminateCoincidentPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val.contains("tol") {
-                tolerance = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--tolerance"])? {
+            tolerance = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -185,9 +165,7 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if tolerance <= 0f64 {
@@ -223,36 +201,41 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         let mut output =
             Shapefile::initialize_using_file(&output_file, &input, ShapeType::Point, true)?;
 
-        let mut frs: FixedRadiusSearch2D<usize> =
-            FixedRadiusSearch2D::new(tolerance * 10f64, DistanceMetric::SquaredEuclidean);
-
-        tolerance *= tolerance; // square distance threshold.
+        let tolerance_sqr = tolerance * tolerance; // square distance threshold.
 
-        // first fill the FRS with the hex centre points
+        // first build a spatial index of the points
+        let mut coords = Vec::with_capacity(num_points * 2);
         for record_num in 0..num_points as usize {
             let record = input.get_record(record_num);
             x = record.points[0].x;
             y = record.points[0].y;
-            frs.insert(x, y, record_num);
+            coords.extend_from_slice(&[x, y]);
 
             if verbose {
                 progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Building fixed-radius search: {}%", progress);
+                    report::progress("Reading points", progress);
                     old_progress = progress;
                 }
             }
         }
 
+        let tree = KdTree::new(
+            2,
+            coords,
+            (0..num_points).collect(),
+            DistanceMetric::SquaredEuclidean,
+        );
+
         let mut excluded = vec![false; num_points];
         for record_num in 0..num_points as usize {
             let record = input.get_record(record_num);
             x = record.points[0].x;
             y = record.points[0].y;
-            let ret = frs.search(x, y);
+            let ret = tree.radius_search(&[x, y], tolerance);
             if ret.len() > 0 {
                 for p in ret {
-                    if p.1 < tolerance && record_num > p.0 && !excluded[p.0] {
+                    if p.1 < tolerance_sqr && record_num > p.0 && !excluded[p.0] {
                         excluded[record_num] = true;
                     }
                 }
@@ -265,18 +248,18 @@ impl WhiteboxTool for EliminateCoincidentPoints {
             if verbose {
                 progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -284,7 +267,7 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
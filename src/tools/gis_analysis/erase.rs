@@ -0,0 +1,259 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::{
+    clip_record, get_clipped_shape_type, get_polygon_union, get_rings_bounding_box,
+};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool removes the portions of the features in an input vector file (`--input`)
+/// that fall within the polygons of an erase vector file (`--erase`). It is the
+/// complement of the `Clip` tool. The input may be of POINT, MULTIPOINT, POLYLINE, or
+/// POLYGON ShapeType and the output file (`--output`) will share this geometry type;
+/// the erase file must be of POLYGON ShapeType. Lines and polygons are cut at the
+/// boundaries of the erase polygons, while points are removed if they fall within an
+/// erase polygon. Input features that fall entirely within the erase polygons are not
+/// included in the output.
+///
+/// The output attribute table contains all of the fields and values of the input
+/// file. Z and M values are retained for point features only.
+///
+/// # See Also
+/// `Clip`, `ErasePolygonFromRaster`
+pub struct Erase {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Erase {
+    pub fn new() -> Erase {
+        // public constructor
+        let name = "Erase".to_string();
+        let toolbox = "GIS Analysis/Overlay Tools".to_string();
+        let description =
+            "Removes all the features, or parts of features, that overlap with the features of the erase vector polygon."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Erase Polygon File".to_owned(),
+            flags: vec!["--erase".to_owned()],
+            description: "Input erase polygon vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=lines.shp --erase=erase_poly.shp -o=output.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        Erase {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Erase {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file: String = "".to_string();
+        let mut erase_file: String = "".to_string();
+        let mut output_file: String = "".to_string();
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--erase"])? {
+            erase_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !erase_file.contains(&sep) && !erase_file.contains("/") {
+            erase_file = format!("{}{}", working_directory, erase_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let input = Shapefile::read(&input_file)?;
+        let erase = Shapefile::read(&erase_file)?;
+
+        // make sure the erase vector file is of polygon type
+        if erase.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The erase vector data must be of POLYGON base shape type.",
+            ));
+        }
+
+        if verbose {
+            report::message("Merging erase polygons...");
+        }
+        let records: Vec<usize> = (0..erase.num_records).collect();
+        let region = get_polygon_union(&erase, &records);
+        let region_box = get_rings_bounding_box(&region);
+
+        // create output file
+        let output_type = get_clipped_shape_type(input.header.shape_type);
+        let mut output = Shapefile::initialize_using_file(&output_file, &input, output_type, true)?;
+
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            if let Some(geometry) =
+                clip_record(record, &region, region_box, true, output_type)
+            {
+                output.add_record(geometry);
+                output
+                    .attributes
+                    .add_record(input.attributes.get_record(record_num), false);
+            }
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if output.num_records == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "All of the input features fall within the erase polygons; no output was created.",
+            ));
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -127,41 +127,19 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-polygon" || flag_val == "-polygons" {
-                polygons_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--polygon", "--polygons"])? {
+            polygons_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -180,7 +158,7 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -200,10 +178,12 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         match output.set_data_from_raster(&input) {
             Ok(_) => (), // do nothings
             Err(err) => return Err(err),
         }
+        let mut z: f64;
 
         let mut start_point_in_part: usize;
         let mut end_point_in_part: usize;
@@ -261,19 +241,21 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                                 &Point2D { x: x, y: y },
                                 &record.points[start_point_in_part..end_point_in_part + 1],
                             ) {
-                                output.set_value(r, c, nodata);
+                                output.set_value(r, c, out_nodata);
                             }
                         }
                         if verbose {
                             progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Progress (rec {} of {} part {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    part_num,
-                                    progress
+                                report::progress(
+                                    &format!(
+                                        "Progress (rec {} of {} part {})",
+                                        record_num + 1,
+                                        num_records,
+                                        part_num
+                                    ),
+                                    progress,
                                 );
                                 old_progress = progress;
                             }
@@ -324,19 +306,26 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                                 &Point2D { x: x, y: y },
                                 &record.points[start_point_in_part..end_point_in_part + 1],
                             ) {
-                                output.set_value(r, c, input.get_value(r, c));
+                                z = input.get_value(r, c);
+                                if z != nodata {
+                                    output.set_value(r, c, z);
+                                } else {
+                                    output.set_value(r, c, out_nodata);
+                                }
                             }
                         }
                         if verbose {
                             progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Progress (rec {} of {} part {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    part_num,
-                                    progress
+                                report::progress(
+                                    &format!(
+                                        "Progress (rec {} of {} part {})",
+                                        record_num + 1,
+                                        num_records,
+                                        part_num
+                                    ),
+                                    progress,
                                 );
                                 old_progress = progress;
                             }
@@ -356,20 +345,17 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
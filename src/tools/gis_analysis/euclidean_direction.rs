@@ -0,0 +1,223 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::distance_transform::EuclideanTransform;
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool calculates the direction from each grid cell to the nearest target cell in
+/// the input image, which is the companion of the distance calculated by
+/// `EuclideanDistance`. Target cells are all valid, non-zero cells in the input. The
+/// direction is measured as an azimuth in degrees clockwise from north, from just above 0
+/// to 360 for cells whose nearest target is due north; target cells are assigned 0. Nodata
+/// cells in the input are assigned nodata in the output.
+///
+/// The nearest target cells are found with the same two-pass distance transform as
+/// `EuclideanDistance` and `EuclideanAllocation` (Shih and Wu, 2004), and the three tools
+/// therefore agree on the nearest target of each cell.
+///
+/// # Reference
+/// Shih FY and Wu Y-T (2004), Fast Euclidean distance transformation in two scans using a
+/// 3 x 3 neighborhood, *Computer Vision and Image Understanding*, 93: 195-205.
+///
+/// # See Also
+/// `EuclideanDistance`, `EuclideanAllocation`, `GeodesicDistance`
+pub struct EuclideanDirection {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl EuclideanDirection {
+    pub fn new() -> EuclideanDirection {
+        // public constructor
+        let name = "EuclideanDirection".to_string();
+        let toolbox = "GIS Analysis/Distance Tools".to_string();
+        let description =
+            "Calculates the direction, as an azimuth, from each cell to the nearest target cell."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=targets.tif -o=direction.tif",
+            short_exe, name
+        ).replace("*", &sep);
+
+        EuclideanDirection {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for EuclideanDirection {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+
+        let start = Instant::now();
+
+        let transform = EuclideanTransform::new(&input, verbose)?;
+
+        output.configs.data_type = DataType::F32;
+        for row in 0..rows {
+            let mut data = vec![out_nodata; columns as usize];
+            for col in 0..columns {
+                if input.get_value(row, col) != nodata {
+                    if let Some(d) = transform.direction(row, col) {
+                        data[col as usize] = d;
+                    }
+                }
+            }
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "circular_bw.plt".to_string();
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
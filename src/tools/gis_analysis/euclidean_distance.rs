@@ -2,16 +2,15 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 22 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::distance_transform::EuclideanTransform;
 use raster::*;
 use std::env;
-use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::Array2D;
 use tools::*;
 
 pub struct EuclideanDistance {
@@ -118,34 +117,16 @@ impl WhiteboxTool for EuclideanDistance {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -161,147 +142,36 @@ impl WhiteboxTool for EuclideanDistance {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
 
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
-        let mut r_x: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
-        let mut r_y: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
 
         let start = Instant::now();
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-
-        let mut h: f64;
-        let mut which_cell: usize;
-        let inf_val = f64::INFINITY;
-        let d_x = [-1, -1, 0, 1, 1, 1, 0, -1];
-        let d_y = [0, -1, -1, -1, 0, 1, 1, 1];
-        let g_x = [1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0];
-        let g_y = [0.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0];
-        let (mut x, mut y): (isize, isize);
-        let (mut z, mut z2, mut z_min): (f64, f64, f64);
+        let transform = EuclideanTransform::new(&input, verbose)?;
 
+        output.configs.data_type = DataType::F32;
         for row in 0..rows {
+            let mut data = vec![out_nodata; columns as usize];
             for col in 0..columns {
-                z = input[(row, col)];
-                if z != 0.0 {
-                    output[(row, col)] = 0.0;
-                } else {
-                    output[(row, col)] = inf_val;
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        for row in 0..rows {
-            for col in 0..columns {
-                z = output[(row, col)];
-                if z != 0.0 {
-                    z_min = inf_val;
-                    which_cell = 0;
-                    for i in 0..4 {
-                        x = col + d_x[i];
-                        y = row + d_y[i];
-                        z2 = output[(y, x)];
-                        if z2 != nodata {
-                            h = match i {
-                                0 => 2.0 * r_x[(y, x)] + 1.0,
-                                1 => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0),
-                                2 => 2.0 * r_y[(y, x)] + 1.0,
-                                _ => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0), // 3
-                            };
-                            z2 += h;
-                            if z2 < z_min {
-                                z_min = z2;
-                                which_cell = i;
-                            }
-                        }
-                    }
-                    if z_min < z {
-                        output[(row, col)] = z_min;
-                        x = col + d_x[which_cell];
-                        y = row + d_y[which_cell];
-                        r_x[(row, col)] = r_x[(y, x)] + g_x[which_cell];
-                        r_y[(row, col)] = r_y[(y, x)] + g_y[which_cell];
+                if input.get_value(row, col) != nodata {
+                    if let Some(d) = transform.distance(row, col) {
+                        data[col as usize] = d;
                     }
                 }
             }
+            output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        for row in (0..rows).rev() {
-            for col in (0..columns).rev() {
-                z = output[(row, col)];
-                if z != 0.0 {
-                    z_min = inf_val;
-                    which_cell = 0;
-                    for i in 4..8 {
-                        x = col + d_x[i];
-                        y = row + d_y[i];
-                        z2 = output[(y, x)];
-                        if z2 != nodata {
-                            h = match i {
-                                5 => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0),
-                                4 => 2.0 * r_x[(y, x)] + 1.0,
-                                6 => 2.0 * r_y[(y, x)] + 1.0,
-                                _ => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0), // 7
-                            };
-                            z2 += h;
-                            if z2 < z_min {
-                                z_min = z2;
-                                which_cell = i;
-                            }
-                        }
-                    }
-                    if z_min < z {
-                        output[(row, col)] = z_min;
-                        x = col + d_x[which_cell];
-                        y = row + d_y[which_cell];
-                        r_x[(row, col)] = r_x[(y, x)] + g_x[which_cell];
-                        r_y[(row, col)] = r_y[(y, x)] + g_y[which_cell];
-                    }
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        let cell_size = (input.configs.resolution_x + input.configs.resolution_y) / 2.0;
-        for row in 0..rows {
-            for col in 0..columns {
-                z = input[(row, col)];
-                if z != nodata {
-                    output[(row, col)] = output[(row, col)].sqrt() * cell_size;
-                } else {
-                    output[(row, col)] = nodata;
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -317,20 +187,17 @@ impl WhiteboxTool for EuclideanDistance {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -156,54 +156,31 @@ impl WhiteboxTool for ExtendVectorLines {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-dist" {
-                dist = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val.contains("extend") {
-                let extend_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                extend = if extend_str.to_lowercase().contains("bo") {
-                    // both
-                    0
-                } else if extend_str.to_lowercase().contains("st") {
-                    // line start
-                    1
-                } else if extend_str.to_lowercase().contains("end") {
-                    // line end
-                    2
-                } else {
-                    // in the event that the flag is not recognized, default to both ends
-                    0
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--dist"])? {
+            dist = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--extend"])? {
+            let extend_str = v.to_lowercase();
+            extend = if extend_str.contains("bo") {
+                // both
+                0
+            } else if extend_str.contains("st") {
+                // line start
+                1
+            } else if extend_str.contains("end") {
+                // line end
+                2
+            } else {
+                // in the event that the flag is not recognized, default to both ends
+                0
+            };
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -213,9 +190,7 @@ impl WhiteboxTool for ExtendVectorLines {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -307,18 +282,18 @@ impl WhiteboxTool for ExtendVectorLines {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -326,7 +301,7 @@ impl WhiteboxTool for ExtendVectorLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
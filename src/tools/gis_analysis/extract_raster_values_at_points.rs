@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 17/06/2018
-Last Modified: 17/06/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -14,7 +14,12 @@ use std::path;
 use tools::*;
 use vector::*;
 
-/// Extracts the values of raster(s) at vector point locations.
+/// Extracts the values of raster(s) at vector point locations. The values are written to
+/// the attribute table of the input points file itself, in fields named VALUE1, VALUE2,
+/// etc.
+///
+/// # See Also
+/// `ExtractValuesToPoints`
 pub struct ExtractRasterValuesAtPoints {
     name: String,
     description: String,
@@ -124,38 +129,19 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--inputs" {
-                if keyval {
-                    input_files = vec[1].to_string();
-                } else {
-                    input_files = args[i + 1].to_string();
-                }
-            } else if flag_val == "-points" {
-                points_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--points"])? {
+            points_file = v;
         }
 
         // let mut progress: usize;
         // let mut old_progress: usize = 1;
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -209,7 +195,7 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    report::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -339,19 +325,16 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match points.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,380 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool samples one or more rasters (`--inputs`) at the locations of a set of vector
+/// points (`--points`) and writes a copy of the points, including their existing attributes,
+/// to an output vector file (`--output`) with one additional attribute for each raster.
+/// It can be used to build the calibration and validation datasets of statistical and
+/// machine-learning models from a stack of predictor rasters.
+///
+/// Each new attribute is named after its raster file, converted to upper case and truncated
+/// to the ten characters that are allowed in a field name; where this would duplicate the
+/// name of another field, the name is shortened further and a number is appended. Rasters
+/// are sampled either at the grid cell containing each point (`--method=nearest`, the
+/// default) or by bilinear interpolation between the centres of the four nearest cells
+/// (`--method=bilinear`). Where any of these four cells is nodata, the value of the cell
+/// containing the point is used instead. Points that fall outside of a raster or on a
+/// nodata cell are assigned a null value for that raster.
+///
+/// Unlike `ExtractRasterValuesAtPoints`, this tool does not modify the input points file.
+///
+/// # See Also
+/// `ExtractRasterValuesAtPoints`, `RandomPoints`, `StratifiedRandomPoints`
+pub struct ExtractValuesToPoints {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ExtractValuesToPoints {
+    pub fn new() -> ExtractValuesToPoints {
+        // public constructor
+        let name = "ExtractValuesToPoints".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description = "Samples one or more rasters at point locations, appending an attribute for each raster to a copy of the points.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Raster Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input raster files.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Points File".to_owned(),
+            flags: vec!["--points".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Points File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sampling Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Raster sampling method; options are 'nearest' and 'bilinear'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "nearest".to_owned(),
+                "bilinear".to_owned(),
+            ]),
+            default_value: Some("nearest".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='dem.tif;slope.tif;ndvi.tif' --points=sites.shp -o=samples.shp --method=bilinear",
+            short_exe, name
+        ).replace("*", &sep);
+
+        ExtractValuesToPoints {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ExtractValuesToPoints {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_files = String::new();
+        let mut points_file = String::new();
+        let mut output_file = String::new();
+        let mut bilinear = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--points"])? {
+            points_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            bilinear = v.to_lowercase().contains("bi");
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !points_file.contains(&sep) && !points_file.contains("/") {
+            points_file = format!("{}{}", working_directory, points_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let mut cmd = input_files.split(";");
+        let mut vec = cmd.collect::<Vec<&str>>();
+        if vec.len() == 1 {
+            cmd = input_files.split(",");
+            vec = cmd.collect::<Vec<&str>>();
+        }
+        let raster_files: Vec<String> = vec
+            .iter()
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", working_directory, f)
+                } else {
+                    f.to_owned()
+                }
+            }).collect();
+        if raster_files.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one input raster is required to operate this tool.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let points = Shapefile::read(&points_file)?;
+        if points.header.shape_type.base_shape_type() != ShapeType::Point {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of Point base shape type.",
+            ));
+        }
+        let num_records = points.num_records;
+
+        let start = Instant::now();
+
+        // sample each of the rasters in turn, so that only one is held in memory at a time
+        let mut samples: Vec<Vec<FieldData>> = vec![vec![]; num_records];
+        let mut field_names: Vec<String> = points
+            .attributes
+            .get_fields()
+            .iter()
+            .map(|f| f.name.to_uppercase())
+            .collect();
+        let mut new_fields = vec![];
+        for (i, raster_file) in raster_files.iter().enumerate() {
+            let input = Raster::new(raster_file, "r")?;
+            let name = unique_field_name(&input.get_short_filename(), &field_names);
+            field_names.push(name.clone());
+            new_fields.push(AttributeField::new(&name, FieldDataType::Real, 12u8, 4u8));
+            for record_num in 0..num_records {
+                let p = points.get_record(record_num).points[0];
+                let z = if bilinear {
+                    interpolate(&input, p.x, p.y)
+                } else {
+                    nearest(&input, p.x, p.y)
+                };
+                samples[record_num].push(match z {
+                    Some(z) => FieldData::Real(z),
+                    None => FieldData::Null,
+                });
+            }
+            if verbose {
+                report::message(&format!(
+                    "Sampled raster {} of {} ({})",
+                    i + 1,
+                    raster_files.len(),
+                    name
+                ));
+            }
+        }
+
+        let mut output = Shapefile::initialize_using_file(
+            &output_file,
+            &points,
+            points.header.shape_type,
+            true,
+        )?;
+        for field in &new_fields {
+            output.attributes.add_field(field);
+        }
+        for record_num in 0..num_records {
+            output.add_record(points.get_record(record_num).clone());
+            let mut atts = points.attributes.get_record(record_num);
+            atts.append(&mut samples[record_num]);
+            output.attributes.add_record(atts, false);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns a field name, of up to ten characters, derived from a raster's file name and
+/// distinct from the names of the existing fields.
+fn unique_field_name(file_stem: &str, existing: &[String]) -> String {
+    let base: String = file_stem
+        .to_uppercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let base = if base.is_empty() || base.chars().next().unwrap().is_ascii_digit() {
+        format!("R{}", base)
+    } else {
+        base
+    };
+    let name: String = base.chars().take(10).collect();
+    if !existing.contains(&name) {
+        return name;
+    }
+    let mut n = 1;
+    loop {
+        let suffix = format!("{}", n);
+        let name = format!(
+            "{}{}",
+            base.chars().take(10 - suffix.len()).collect::<String>(),
+            suffix
+        );
+        if !existing.contains(&name) {
+            return name;
+        }
+        n += 1;
+    }
+}
+
+/// Returns the value of the grid cell containing a location, or None if the location is
+/// outside of the raster or on a nodata cell.
+pub fn nearest(input: &Raster, x: f64, y: f64) -> Option<f64> {
+    let row = input.get_row_from_y(y);
+    let col = input.get_column_from_x(x);
+    if row < 0
+        || col < 0
+        || row >= input.configs.rows as isize
+        || col >= input.configs.columns as isize
+    {
+        return None;
+    }
+    let z = input.get_value(row, col);
+    if z == input.configs.nodata {
+        return None;
+    }
+    Some(z)
+}
+
+/// Returns the value of a raster at a location, interpolated bilinearly between the centres
+/// of the surrounding cells, or the value of the containing cell where any of the
+/// surrounding cells are nodata.
+pub fn interpolate(input: &Raster, x: f64, y: f64) -> Option<f64> {
+    let z = match nearest(input, x, y) {
+        Some(z) => z,
+        None => return None,
+    };
+    let nodata = input.configs.nodata;
+    let fx = (x - input.configs.west) / input.configs.resolution_x - 0.5;
+    let fy = (input.configs.north - y) / input.configs.resolution_y - 0.5;
+    let (c0, r0) = (fx.floor() as isize, fy.floor() as isize);
+    let (tx, ty) = (fx - c0 as f64, fy - r0 as f64);
+    let z00 = input.get_value(r0, c0);
+    let z01 = input.get_value(r0, c0 + 1);
+    let z10 = input.get_value(r0 + 1, c0);
+    let z11 = input.get_value(r0 + 1, c0 + 1);
+    if z00 == nodata || z01 == nodata || z10 == nodata || z11 == nodata {
+        return Some(z);
+    }
+    Some(
+        z00 * (1f64 - tx) * (1f64 - ty)
+            + z01 * tx * (1f64 - ty)
+            + z10 * (1f64 - tx) * ty
+            + z11 * tx * ty,
+    )
+}
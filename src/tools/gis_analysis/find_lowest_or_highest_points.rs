@@ -142,43 +142,23 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_type"])? {
+            out_type = v.to_lowercase();
+            if out_type.contains("low") {
+                out_type = "lowest".to_owned();
             }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_type" {
-                out_type = if keyval {
-                    vec[1].to_lowercase()
-                } else {
-                    args[i + 1].to_lowercase()
-                };
-                if out_type.contains("low") {
-                    out_type = "lowest".to_owned();
-                }
-                if out_type.contains("hi") {
-                    out_type = "highest".to_owned();
-                }
-                if out_type.contains("b") {
-                    out_type = "both".to_owned();
-                }
+            if out_type.contains("hi") {
+                out_type = "highest".to_owned();
+            }
+            if out_type.contains("b") {
+                out_type = "both".to_owned();
             }
         }
 
@@ -186,9 +166,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         // let mut old_progress: usize = 1;
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -201,7 +179,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -263,7 +241,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
                         progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                         let mut p = old_progress.lock().unwrap();
                         if progress != *p {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             *p = progress;
                         }
                     }
@@ -320,19 +298,16 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
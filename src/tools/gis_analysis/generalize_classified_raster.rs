@@ -0,0 +1,388 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::mem;
+use std::path;
+use structures::ConcurrentUnionFind;
+use tools::*;
+
+/// This tool generalizes a categorical raster (`--input`), such as a classified image or
+/// the output of the `PennockLandformClass` tool, by eliminating regions that contain
+/// fewer than a minimum number of cells (`--min_size`). A region is a connected group of
+/// cells with the same value, where cells are connected through their four cardinal
+/// neighbours or, if `--diag` is specified, through all eight neighbours. Each small
+/// region is merged into the largest of its neighbouring regions, taking on its value.
+///
+/// Regions are eliminated in order of increasing size, and the sizes of the regions are
+/// updated as they are merged, such that a cluster of several small regions may together
+/// form a region of sufficient size. Regions that are completely surrounded by NoData
+/// cells, and therefore have no neighbours, are left unchanged. NoData cells are never
+/// modified.
+///
+/// # See Also
+/// `Clump`, `MajorityFilter`, `BoundaryClean`
+pub struct GeneralizeClassifiedRaster {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl GeneralizeClassifiedRaster {
+    pub fn new() -> GeneralizeClassifiedRaster {
+        // public constructor
+        let name = "GeneralizeClassifiedRaster".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Eliminates small regions from a categorical raster by merging them into their largest neighbours."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input categorical raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Region Size (cells)".to_owned(),
+            flags: vec!["--min_size".to_owned()],
+            description: "Minimum number of cells in an output region.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("5".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Include diagonal connections?".to_owned(),
+            flags: vec!["--diag".to_owned()],
+            description: "Flag indicating whether diagonal connections should be considered."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=landforms.tif -o=output.tif --min_size=25 --diag",
+            short_exe, name
+        ).replace("*", &sep);
+
+        GeneralizeClassifiedRaster {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for GeneralizeClassifiedRaster {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut min_size = 5usize;
+        let mut diag = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--min_size"])? {
+            min_size = v;
+        }
+        if tool_args.get_flag(&["--diag"])? {
+            diag = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let nodata = input.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+
+        // the previously scanned neighbours, which are sufficient to find all adjacencies
+        let (dx, dy) = if diag {
+            (vec![-1, -1, 0, 1], vec![0, -1, -1, -1])
+        } else {
+            (vec![-1, 0], vec![0, -1])
+        };
+
+        // identify the regions of connected cells with the same value
+        let cells = ConcurrentUnionFind::new((rows * columns) as usize);
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input[(row, col)];
+                if z != nodata {
+                    for n in 0..dx.len() {
+                        if input[(row + dy[n], col + dx[n])] == z {
+                            cells.union(
+                                (row * columns + col) as usize,
+                                ((row + dy[n]) * columns + col + dx[n]) as usize,
+                            );
+                        }
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Identifying regions", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // number the regions and find their sizes, values, and neighbours
+        let no_region = usize::max_value();
+        let mut region = vec![no_region; (rows * columns) as usize];
+        let mut size: Vec<usize> = vec![];
+        let mut value: Vec<f64> = vec![];
+        let mut neighbours: Vec<HashSet<usize>> = vec![];
+        let (mut i, mut j, mut root): (usize, usize, usize);
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input[(row, col)];
+                if z == nodata {
+                    continue;
+                }
+                i = (row * columns + col) as usize;
+                root = cells.find(i);
+                if root == i {
+                    region[i] = size.len();
+                    size.push(0);
+                    value.push(z);
+                    neighbours.push(HashSet::new());
+                } else {
+                    region[i] = region[root];
+                }
+                size[region[i]] += 1;
+                for n in 0..dx.len() {
+                    if input[(row + dy[n], col + dx[n])] != nodata {
+                        j = ((row + dy[n]) * columns + col + dx[n]) as usize;
+                        if region[j] != region[i] {
+                            let (a, b) = (region[i], region[j]);
+                            neighbours[a].insert(b);
+                            neighbours[b].insert(a);
+                        }
+                    }
+                }
+            }
+        }
+        drop(cells);
+        let num_regions = size.len();
+
+        // merge the small regions, smallest first, into their largest neighbours
+        let regions = ConcurrentUnionFind::new(num_regions);
+        let mut queue = BinaryHeap::new();
+        for r in 0..num_regions {
+            if size[r] < min_size {
+                queue.push(Reverse((size[r], r)));
+            }
+        }
+        let mut num_eliminated = 0usize;
+        while let Some(Reverse((s, r))) = queue.pop() {
+            if regions.find(r) != r || size[r] != s {
+                continue; // this entry is out of date
+            }
+            let adjacent: HashSet<usize> = neighbours[r]
+                .iter()
+                .map(|&n| regions.find(n))
+                .filter(|&n| n != r)
+                .collect();
+            let mut target = no_region;
+            for &n in &adjacent {
+                if target == no_region
+                    || size[n] > size[target]
+                    || (size[n] == size[target] && n < target)
+                {
+                    target = n;
+                }
+            }
+            if target == no_region {
+                continue; // the region is isolated by nodata cells
+            }
+            // the region takes the value of its largest neighbour, with which it is merged,
+            // along with any other neighbours with the same value that it now connects.
+            let new_value = value[target];
+            let mut merged = vec![r];
+            for &n in &adjacent {
+                if value[n] == new_value {
+                    merged.push(n);
+                }
+            }
+            // the neighbour sets of the others are added to the largest, which may hold
+            // merged regions; these are resolved to their current roots when read.
+            let mut largest = r;
+            for &m in &merged {
+                if neighbours[m].len() > neighbours[largest].len() {
+                    largest = m;
+                }
+            }
+            let mut new_neighbours = mem::replace(&mut neighbours[largest], HashSet::new());
+            let mut new_size = 0usize;
+            for &m in &merged {
+                new_size += size[m];
+                new_neighbours.extend(mem::replace(&mut neighbours[m], HashSet::new()));
+                regions.union(m, r);
+            }
+            root = regions.find(r);
+            size[root] = new_size;
+            value[root] = new_value;
+            neighbours[root] = new_neighbours;
+            if new_size < min_size {
+                queue.push(Reverse((new_size, root)));
+            }
+            num_eliminated += 1;
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        for row in 0..rows {
+            let mut data = vec![out_nodata; columns as usize];
+            for col in 0..columns {
+                i = (row * columns + col) as usize;
+                if region[i] != no_region {
+                    data[col as usize] = value[regions.find(region[i])];
+                }
+            }
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Generalizing", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Minimum region size: {}", min_size));
+        output.add_metadata_entry(format!("Diagonal connectivity: {}", diag));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message(&format!(
+                "{} of {} regions were eliminated.",
+                num_eliminated, num_regions
+            ));
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
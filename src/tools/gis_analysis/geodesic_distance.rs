@@ -0,0 +1,273 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::distance_transform::geodesic_distance;
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool calculates the geodesic distance from each cell to the nearest target cell,
+/// i.e. the length of the shortest path between them that is confined to a mask of passable
+/// cells (`--mask`), such as the distance along a river corridor, through a habitat patch,
+/// or around the buildings of a city block. Target cells are the valid, non-zero cells of
+/// the input image (`--input`) and passable cells are the valid, non-zero cells of the mask,
+/// which must have the same dimensions as the input. Unlike `EuclideanDistance`, which
+/// measures straight-line distances across any intervening cells, the paths measured by
+/// this tool bend around impassable cells. Distances are measured in map units.
+///
+/// The distance is calculated with a chamfer distance transform over the 5 x 5
+/// neighbourhood of each cell, in which the steps to neighbouring cells have their Euclidean
+/// lengths, and which is accurate to within about 2% of the straight-line distance in open
+/// areas. The forward and backward passes of the transform are repeated until the distances
+/// no longer change, such that the paths may follow corridors that wind in any direction.
+/// Target cells outside of the mask are ignored. Impassable cells, nodata cells, and cells
+/// that cannot be reached from any target are assigned nodata in the output.
+///
+/// # See Also
+/// `EuclideanDistance`, `CostDistance`, `EuclideanDirection`
+pub struct GeodesicDistance {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl GeodesicDistance {
+    pub fn new() -> GeodesicDistance {
+        // public constructor
+        let name = "GeodesicDistance".to_string();
+        let toolbox = "GIS Analysis/Distance Tools".to_string();
+        let description =
+            "Calculates the distance to the nearest target cell along paths confined to a mask."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Mask File".to_owned(),
+            flags: vec!["--mask".to_owned()],
+            description: "Input raster of passable cells, which have valid, non-zero values."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=targets.tif --mask=corridor.tif -o=distance.tif",
+            short_exe, name
+        ).replace("*", &sep);
+
+        GeodesicDistance {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for GeodesicDistance {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut mask_file = String::new();
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--mask"])? {
+            mask_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !mask_file.contains(&sep) && !mask_file.contains("/") {
+            mask_file = format!("{}{}", working_directory, mask_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+        let mask = Raster::new(&mask_file, "r")?;
+
+        let nodata = input.configs.nodata;
+        let mask_nodata = mask.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        if mask.configs.rows as isize != rows || mask.configs.columns as isize != columns {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input and mask files must have the same number of rows and columns.",
+            ));
+        }
+
+        let start = Instant::now();
+
+        let mut is_source: Array2D<u8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut is_passable: Array2D<u8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != 0f64 && z != nodata {
+                    is_source.set_value(row, col, 1);
+                }
+                z = mask.get_value(row, col);
+                if z != 0f64 && z != mask_nodata && input.get_value(row, col) != nodata {
+                    is_passable.set_value(row, col, 1);
+                }
+            }
+        }
+
+        let distance = geodesic_distance(
+            &is_source,
+            &is_passable,
+            input.configs.resolution_x,
+            input.configs.resolution_y,
+            verbose,
+        )?;
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        output.configs.data_type = DataType::F32;
+        for row in 0..rows {
+            let mut data = vec![out_nodata; columns as usize];
+            for col in 0..columns {
+                z = distance.get_value(row, col);
+                if z.is_finite() {
+                    data[col as usize] = z;
+                }
+            }
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "spectrum.plt".to_string();
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Mask file: {}", mask_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -208,79 +208,37 @@ impl WhiteboxTool for IdwInterpolation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-use_z" {
-                use_z = true;
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-cell_size" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-base" {
-                base_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-weight" {
-                weight = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-radius" {
-                radius = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-min_points" {
-                min_points = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap() as usize
-                };
-                // } else if flag_val == "-max_dist" {
-                //     max_dist = if keyval {
-                //         vec[1].to_string().parse::<f64>().unwrap()
-                //     } else {
-                //         args[i+1].to_string().parse::<f64>().unwrap()
-                //     };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if tool_args.get_flag(&["--use_z"])? {
+            use_z = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--weight"])? {
+            weight = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            radius = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--min_points"])? {
+            min_points = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -302,7 +260,7 @@ impl WhiteboxTool for IdwInterpolation {
         // }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -460,7 +418,7 @@ impl WhiteboxTool for IdwInterpolation {
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
+                        report::progress("Creating search structure", progress);
                         old_progress = progress;
                     }
                 }
@@ -497,7 +455,7 @@ impl WhiteboxTool for IdwInterpolation {
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
+                        report::progress("Creating search structure", progress);
                         old_progress = progress;
                     }
                 }
@@ -550,7 +508,7 @@ impl WhiteboxTool for IdwInterpolation {
         let columns = output.configs.columns as isize;
         let west = output.configs.west;
         let north = output.configs.north;
-        output.configs.nodata = nodata; // in case a base image is used with a different nodata value.
+        let nodata = output.configs.nodata; // may differ from the input nodata
 
         // let kdtree = Arc::new(kdtree); // wrap FRS in an Arc
         let frs = Arc::new(frs);
@@ -671,7 +629,7 @@ impl WhiteboxTool for IdwInterpolation {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -686,20 +644,17 @@ impl WhiteboxTool for IdwInterpolation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
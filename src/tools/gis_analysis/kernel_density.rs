@@ -0,0 +1,657 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::{get_polygon_union, get_rings_bounding_box};
+use algorithms::point_in_rings;
+use raster::*;
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, DistanceMetric, KdTree, Point2D};
+use tools::*;
+use vector::*;
+
+/// This tool creates a heat map, i.e. a kernel density estimate, of a set of vector points.
+/// Each point is replaced by a kernel, a smooth bump centred on the point whose volume equals
+/// the point's weight, and the kernels of all points are summed. The output raster
+/// (`--output`) therefore gives the density of points, or the density of the weights of the
+/// points if a numeric weight field (`--field`) is specified, in points (or weight) per
+/// squared map unit. Multiplying the density by the grid cell area gives the expected number
+/// of points in each cell.
+///
+/// Three kernel functions (`--kernel`) are available: `quartic` (the default, also known as
+/// the biweight kernel), `epanechnikov`, and `gaussian`. The quartic and Epanechnikov kernels
+/// fall to zero at a distance of one bandwidth from the point, while the Gaussian kernel has a
+/// standard deviation of one bandwidth and is truncated at four bandwidths. The bandwidth
+/// (`--bandwidth`), in map units, controls the smoothness of the surface. If it is not
+/// specified, it is estimated using Scott's rule, `h = s n^(-1/6)`, where `s` is the standard
+/// distance of the points. Alternatively, an adaptive bandwidth may be used by specifying a
+/// number of neighbours (`--num_neighbours`), in which case the bandwidth of each point is the
+/// distance to its k-th nearest neighbour (or `--bandwidth`, if greater), so that the kernels
+/// are narrow where points are dense and wide where points are sparse.
+///
+/// The study area may be specified by a polygon vector (`--boundary`), the union of whose
+/// polygons is used; points outside of the study area are ignored and grid cells outside of
+/// it are assigned NoData. Because no points can occur outside of the study area, kernels
+/// that extend beyond its edge lose part of their volume, and densities near the edge are
+/// underestimated. If `--edge_correction` is specified, the kernel of each point is rescaled
+/// such that its volume within the study area equals the point's weight (Jones, 1993). The
+/// study area is the extent of the output grid if no boundary is specified.
+///
+/// The output grid is specified either by a grid cell size (`--cell_size`), in which case the
+/// grid covers the study area, or by the extent of the points expanded by the bandwidth, or by
+/// a base raster (`--base`).
+///
+/// # Reference
+/// Jones, M. C. (1993). Simple boundary correction for kernel density estimation. Statistics
+/// and Computing, 3(3), 135-146.
+///
+/// # See Also
+/// `QuadratCount`, `LidarPointDensity`, `NearestNeighbourGridding`
+pub struct KernelDensity {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl KernelDensity {
+    pub fn new() -> KernelDensity {
+        // public constructor
+        let name = "KernelDensity".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Creates a kernel density estimate (heat map) raster from a set of vector points."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Weight Field Name (optional)".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Optional numeric attribute field containing the weight of each point."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Kernel Function".to_owned(),
+            flags: vec!["--kernel".to_owned()],
+            description: "Kernel function; options include 'quartic' (default), 'epanechnikov', and 'gaussian'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "quartic".to_owned(),
+                "epanechnikov".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("quartic".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Bandwidth (optional)".to_owned(),
+            flags: vec!["--bandwidth".to_owned()],
+            description: "Kernel bandwidth, in map units; estimated from the points if unspecified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Neighbours for Adaptive Bandwidth (optional)".to_owned(),
+            flags: vec!["--num_neighbours".to_owned()],
+            description: "Number of nearest neighbours used to set each point's adaptive bandwidth; 0 for a fixed bandwidth.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Study Area Polygon File (optional)".to_owned(),
+            flags: vec!["--boundary".to_owned()],
+            description: "Optional input vector polygon file of the study area.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Apply edge correction?".to_owned(),
+            flags: vec!["--edge_correction".to_owned()],
+            description: "Correct the underestimation of densities near the edge of the study area.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Grid Cell Size (optional)".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Optionally specified cell size of output raster. Not used when base raster is specified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Base Raster File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Optionally specified input base raster file. Not used when a cell size is specified.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=incidents.shp -o=heat.tif --bandwidth=500.0 --cell_size=25.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=sightings.shp --field=COUNT -o=heat.tif --kernel=gaussian --num_neighbours=10 --boundary=park.shp --edge_correction --cell_size=50.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        KernelDensity {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for KernelDensity {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut field_name = String::new();
+        let mut output_file = String::new();
+        let mut kernel_name = String::from("quartic");
+        let mut bandwidth = 0f64;
+        let mut num_neighbours = 0usize;
+        let mut boundary_file = String::new();
+        let mut grid_res = 0f64;
+        let mut base_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--kernel"])? {
+            kernel_name = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--bandwidth"])? {
+            bandwidth = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_neighbours", "--num_neighbors"])? {
+            num_neighbours = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--boundary"])? {
+            boundary_file = v;
+        }
+        let edge_correction = tool_args.get_flag(&["--edge_correction"])?;
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+
+        let kernel = match Kernel::from_str(&kernel_name) {
+            Some(k) => k,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized kernel; options include 'quartic', 'epanechnikov', and 'gaussian'.",
+                ))
+            }
+        };
+        if grid_res <= 0f64 && base_file.trim().is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a cell size (--cell_size) or a base raster (--base) must be specified.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let start = Instant::now();
+
+        let base_shape_type = input.header.shape_type.base_shape_type();
+        if base_shape_type != ShapeType::Point && base_shape_type != ShapeType::MultiPoint {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT base shape type.",
+            ));
+        }
+        if !field_name.is_empty() {
+            match input.attributes.get_field_num(&field_name) {
+                Some(i) => if !input.attributes.is_field_numeric(i) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The specified weight field is non-numeric.",
+                    ));
+                },
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The specified field name does not exist in input shapefile.",
+                    ))
+                }
+            }
+        }
+
+        // read the study area, if one is specified
+        let mut study_area: Vec<Vec<Point2D>> = vec![];
+        if !boundary_file.is_empty() {
+            if !boundary_file.contains(&sep) && !boundary_file.contains("/") {
+                boundary_file = format!("{}{}", working_directory, boundary_file);
+            }
+            let polygons = Shapefile::read(&boundary_file)?;
+            if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The boundary vector data must be of POLYGON base shape type.",
+                ));
+            }
+            let records: Vec<usize> = (0..polygons.num_records).collect();
+            study_area = get_polygon_union(&polygons, &records);
+            if study_area.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The boundary vector does not contain any polygons.",
+                ));
+            }
+        }
+        let study_box = get_rings_bounding_box(&study_area);
+
+        // read the points and their weights
+        let mut points: Vec<Point2D> = vec![];
+        let mut weights: Vec<f64> = vec![];
+        for record_num in 0..input.num_records {
+            let weight = if field_name.is_empty() {
+                1f64
+            } else {
+                match input.attributes.get_value(record_num, &field_name) {
+                    FieldData::Int(v) => v as f64,
+                    FieldData::Real(v) => v,
+                    _ => continue, // likely a null field
+                }
+            };
+            let record = input.get_record(record_num);
+            for p in &record.points {
+                let p = Point2D::new(p.x, p.y);
+                if study_area.is_empty()
+                    || (study_box.is_point_in_box(p.x, p.y) && point_in_rings(&p, &study_area))
+                {
+                    points.push(p);
+                    weights.push(weight);
+                }
+            }
+        }
+        let n = points.len();
+        if n == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "There are no points within the study area.",
+            ));
+        }
+
+        // determine the bandwidth of each point
+        if bandwidth <= 0f64 && num_neighbours == 0 {
+            bandwidth = scotts_bandwidth(&points);
+            if bandwidth <= 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The bandwidth cannot be estimated from coincident points; please specify a bandwidth.",
+                ));
+            }
+            if verbose {
+                report::metadata("Estimated bandwidth", &bandwidth.to_string());
+            }
+        }
+        let mut bandwidths = vec![bandwidth; n];
+        if num_neighbours > 0 {
+            if num_neighbours >= n {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The number of neighbours must be less than the number of points.",
+                ));
+            }
+            let mut coords = Vec::with_capacity(2 * n);
+            for p in &points {
+                coords.push(p.x);
+                coords.push(p.y);
+            }
+            let tree = KdTree::new(2, coords, (0..n).collect(), DistanceMetric::Euclidean);
+            for i in 0..n {
+                // the first neighbour found is the point itself
+                let ret = tree.knn_search(&[points[i].x, points[i].y], num_neighbours + 1);
+                bandwidths[i] = ret[num_neighbours].1.max(bandwidth);
+            }
+        }
+        let max_bandwidth = bandwidths.iter().cloned().fold(0f64, f64::max);
+
+        // create the output raster
+        let nodata = -32768.0f64;
+        let mut output = if !base_file.trim().is_empty() && grid_res <= 0f64 {
+            if !base_file.contains(&sep) && !base_file.contains("/") {
+                base_file = format!("{}{}", working_directory, base_file);
+            }
+            let base = Raster::new(&base_file, "r")?;
+            Raster::initialize_using_file(&output_file, &base)
+        } else {
+            let extent = if study_area.is_empty() {
+                let mut bb = BoundingBox::from_points(&points);
+                bb.expand_by(max_bandwidth * kernel.support());
+                bb
+            } else {
+                study_box
+            };
+            let west = extent.min_x;
+            let north = extent.max_y;
+            let rows = (((north - extent.min_y) / grid_res).ceil() as usize).max(1);
+            let columns = (((extent.max_x - west) / grid_res).ceil() as usize).max(1);
+
+            let mut configs = RasterConfigs {
+                ..Default::default()
+            };
+            configs.rows = rows;
+            configs.columns = columns;
+            configs.north = north;
+            configs.south = north - rows as f64 * grid_res;
+            configs.east = west + columns as f64 * grid_res;
+            configs.west = west;
+            configs.resolution_x = grid_res;
+            configs.resolution_y = grid_res;
+            configs.nodata = nodata;
+            configs.data_type = DataType::F32;
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+            configs.projection = input.projection.clone();
+
+            Raster::initialize_using_config(&output_file, &configs)
+        };
+        let nodata = output.configs.nodata; // may differ from the input nodata
+        let rows = output.configs.rows as isize;
+        let columns = output.configs.columns as isize;
+        let west = output.configs.west;
+        let north = output.configs.north;
+        let res_x = output.configs.resolution_x;
+        let res_y = output.configs.resolution_y;
+        let cell_area = res_x * res_y;
+
+        // the grid cells within the study area
+        let mut in_study_area = vec![true; (rows * columns) as usize];
+        if !study_area.is_empty() {
+            for row in 0..rows {
+                let y = north - (row as f64 + 0.5) * res_y;
+                for col in 0..columns {
+                    let x = west + (col as f64 + 0.5) * res_x;
+                    in_study_area[(row * columns + col) as usize] =
+                        point_in_rings(&Point2D::new(x, y), &study_area);
+                }
+            }
+        }
+
+        // Add the kernel of each point to the grid. Each kernel is evaluated at the cell
+        // centres and scaled such that its discrete volume, over all cells or, if the edge
+        // correction is applied, over the cells within the study area, equals the weight.
+        let mut density = vec![0f64; (rows * columns) as usize];
+        let mut values: Vec<(usize, f64)> = vec![];
+        for i in 0..n {
+            let h = bandwidths[i];
+            let radius = h * kernel.support();
+            let col_start = ((points[i].x - radius - west) / res_x).floor() as isize;
+            let col_end = ((points[i].x + radius - west) / res_x).floor() as isize;
+            let row_start = ((north - points[i].y - radius) / res_y).floor() as isize;
+            let row_end = ((north - points[i].y + radius) / res_y).floor() as isize;
+            let mut volume = 0f64;
+            values.clear();
+            for row in row_start..row_end + 1 {
+                let dy = north - (row as f64 + 0.5) * res_y - points[i].y;
+                for col in col_start..col_end + 1 {
+                    let dx = west + (col as f64 + 0.5) * res_x - points[i].x;
+                    let k = kernel.value((dx * dx + dy * dy).sqrt(), h);
+                    if k <= 0f64 {
+                        continue;
+                    }
+                    let in_grid = row >= 0 && row < rows && col >= 0 && col < columns;
+                    let index = (row * columns + col) as usize;
+                    if !edge_correction || (in_grid && in_study_area[index]) {
+                        volume += k * cell_area;
+                    }
+                    if in_grid {
+                        values.push((index, k));
+                    }
+                }
+            }
+            if volume <= 0f64 {
+                // the kernel is narrower than a grid cell; assign the weight to its cell
+                let row = ((north - points[i].y) / res_y).floor() as isize;
+                let col = ((points[i].x - west) / res_x).floor() as isize;
+                if row >= 0 && row < rows && col >= 0 && col < columns {
+                    density[(row * columns + col) as usize] += weights[i] / cell_area;
+                }
+            } else {
+                for &(index, k) in &values {
+                    density[index] += weights[i] * k / volume;
+                }
+            }
+
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / n as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        for row in 0..rows {
+            let mut data = vec![nodata; columns as usize];
+            for col in 0..columns {
+                let index = (row * columns + col) as usize;
+                if in_study_area[index] {
+                    data[col as usize] = density[index];
+                }
+            }
+            output.set_row_data(row, data);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Kernel: {}", kernel_name));
+        if num_neighbours > 0 {
+            output.add_metadata_entry(format!("Adaptive bandwidth neighbours: {}", num_neighbours));
+        } else {
+            output.add_metadata_entry(format!("Bandwidth: {}", bandwidth));
+        }
+        output.add_metadata_entry(format!("Edge correction: {}", edge_correction));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Kernel {
+    Quartic,
+    Epanechnikov,
+    Gaussian,
+}
+
+impl Kernel {
+    fn from_str(s: &str) -> Option<Kernel> {
+        if s.contains("quar") || s.contains("biw") {
+            Some(Kernel::Quartic)
+        } else if s.contains("epa") {
+            Some(Kernel::Epanechnikov)
+        } else if s.contains("gau") {
+            Some(Kernel::Gaussian)
+        } else {
+            None
+        }
+    }
+
+    /// The radius, in bandwidths, beyond which the kernel is zero.
+    fn support(&self) -> f64 {
+        match *self {
+            Kernel::Gaussian => 4f64,
+            _ => 1f64,
+        }
+    }
+
+    /// The value of the kernel, with a unit volume, at a distance d for a bandwidth h.
+    fn value(&self, d: f64, h: f64) -> f64 {
+        let u = d / h;
+        if u >= self.support() {
+            return 0f64;
+        }
+        match *self {
+            Kernel::Quartic => 3f64 / (PI * h * h) * (1f64 - u * u) * (1f64 - u * u),
+            Kernel::Epanechnikov => 2f64 / (PI * h * h) * (1f64 - u * u),
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2f64 * PI * h * h),
+        }
+    }
+}
+
+/// Estimates a bandwidth using Scott's rule, from the standard distance of the points.
+fn scotts_bandwidth(points: &[Point2D]) -> f64 {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.y).sum::<f64>() / n;
+    let sum_sqr_dist: f64 = points
+        .iter()
+        .map(|p| (p.x - mean_x) * (p.x - mean_x) + (p.y - mean_y) * (p.y - mean_y))
+        .sum();
+    let standard_distance = (sum_sqr_dist / (2f64 * n)).sqrt();
+    standard_distance * n.powf(-1f64 / 6f64)
+}
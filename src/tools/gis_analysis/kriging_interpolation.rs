@@ -0,0 +1,677 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{
+    experimental_variogram, fit_variogram, ordinary_kriging, VariogramModel, VariogramModelType,
+};
+use kdtree::distance::squared_euclidean;
+use kdtree::KdTree;
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use tools::*;
+use vector::{FieldData, ShapeType, Shapefile};
+
+/// This tool interpolates vector points into a raster surface by ordinary kriging. Each grid
+/// cell is estimated as a weighted average of a number of its nearest points
+/// (`--num_points`, default 16), with weights that are derived from a semivariogram model,
+/// such that the estimate is unbiased and its variance is minimized. The kriging variance,
+/// i.e. the expected squared error of the estimate, may optionally be output (`--variance`);
+/// it increases with the distance to the points and is a useful measure of the reliability
+/// of the interpolated surface.
+///
+/// The values to be interpolated are taken either from a numeric attribute field (`--field`)
+/// or from the z-coordinates of the points (`--use_z`). Points with identical coordinates are
+/// merged, and assigned their mean value, since the kriging system cannot otherwise be solved.
+///
+/// The semivariogram model is either spherical, exponential, or Gaussian (`--model`). If the
+/// nugget (`--nugget`), sill (`--sill`), and range (`--range`) are all specified, they are
+/// used directly; otherwise, the model is fitted to the experimental semivariogram of the
+/// points, calculated with a number of lag bins (`--num_lags`, default 15) of a specified size
+/// (`--lag_size`), which by default spans half of the diagonal of the points' extent. If
+/// there are more than 2000 points, a systematic sample of them is used for fitting. The
+/// `Semivariogram` tool may be used to inspect the experimental semivariogram and choose a
+/// suitable model prior to interpolation. The fitted model is reported when the tool is run
+/// in verbose mode and is stored in the output's metadata.
+///
+/// The output raster is either based on a specified cell size (`--cell_size`) and the extent
+/// of the points, or on the extent and grid resolution of an existing base raster (`--base`).
+///
+/// # See Also
+/// `Semivariogram`, `IdwInterpolation`, `SibsonInterpolation`, `TINGridding`
+pub struct KrigingInterpolation {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl KrigingInterpolation {
+    pub fn new() -> KrigingInterpolation {
+        // public constructor
+        let name = "KrigingInterpolation".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Interpolates vector points into a raster surface using ordinary kriging.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector Points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Input field name in attribute table.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Use z-coordinate instead of field?".to_owned(),
+            flags: vec!["--use_z".to_owned()],
+            description: "Use z-coordinate instead of field?".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Kriging Variance File (optional)".to_owned(),
+            flags: vec!["--variance".to_owned()],
+            description: "Optional output kriging variance raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Model Type".to_owned(),
+            flags: vec!["--model".to_owned()],
+            description: "Semivariogram model; options include 'spherical', 'exponential', and 'gaussian'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "spherical".to_owned(),
+                "exponential".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("spherical".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Nugget (optional)".to_owned(),
+            flags: vec!["--nugget".to_owned()],
+            description: "Semivariogram nugget; fitted to the points if unspecified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sill (optional)".to_owned(),
+            flags: vec!["--sill".to_owned()],
+            description: "Semivariogram sill, including the nugget; fitted to the points if unspecified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Range (optional)".to_owned(),
+            flags: vec!["--range".to_owned()],
+            description: "Semivariogram range; fitted to the points if unspecified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Lags".to_owned(),
+            flags: vec!["--num_lags".to_owned()],
+            description: "Number of lag bins used to fit the semivariogram model.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("15".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Lag Size (optional)".to_owned(),
+            flags: vec!["--lag_size".to_owned()],
+            description: "Width of the lag bins used to fit the semivariogram model.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Neighbouring Points".to_owned(),
+            flags: vec!["--num_points".to_owned()],
+            description: "Number of nearest points used to estimate each cell.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("16".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cell Size (optional)".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Optionally specified cell size of output raster. Not used when base raster is specified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Base Raster File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Optionally specified input base raster file. Not used when a cell size is specified.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp --field=ELEV -o=output.tif --variance=variance.tif --model=exponential --cell_size=10.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp --use_z -o=output.tif --nugget=0.5 --sill=4.0 --range=250.0 --num_points=24 --base=existing_raster.tif",
+            short_exe, name
+        ).replace("*", &sep);
+
+        KrigingInterpolation {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for KrigingInterpolation {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut field_name = String::new();
+        let mut use_z = false;
+        let mut output_file = String::new();
+        let mut variance_file = String::new();
+        let mut model_name = String::from("spherical");
+        let mut nugget: Option<f64> = None;
+        let mut sill: Option<f64> = None;
+        let mut range: Option<f64> = None;
+        let mut num_lags = 15usize;
+        let mut lag_size = 0f64;
+        let mut num_points = 16usize;
+        let mut grid_res = 0f64;
+        let mut base_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if tool_args.get_flag(&["--use_z"])? {
+            use_z = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--variance"])? {
+            variance_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--model"])? {
+            model_name = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--nugget"])? {
+            nugget = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--sill"])? {
+            sill = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--range"])? {
+            range = Some(v);
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_lags"])? {
+            num_lags = v.max(1);
+        }
+        if let Some(v) = tool_args.get_f64(&["--lag_size"])? {
+            lag_size = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_points"])? {
+            num_points = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+
+        let model_type = match VariogramModelType::from_str(&model_name) {
+            Some(m) => m,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized model type; options include 'spherical', 'exponential', and 'gaussian'.",
+                ))
+            }
+        };
+        if num_points < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of neighbouring points must be at least one.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !variance_file.is_empty() && !variance_file.contains(&sep) && !variance_file.contains("/")
+        {
+            variance_file = format!("{}{}", working_directory, variance_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let vector_data = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of points type
+        if vector_data.header.shape_type.base_shape_type() != ShapeType::Point {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of point base shape type.",
+            ));
+        }
+
+        let mut points: Vec<(f64, f64, f64)> = vec![];
+        if !use_z {
+            let field_index = match vector_data.attributes.get_field_num(&field_name) {
+                Some(i) => i,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Attribute not found in table.",
+                    ));
+                }
+            };
+            if !vector_data.attributes.is_field_numeric(field_index) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Non-numeric attributes cannot be interpolated.",
+                ));
+            }
+            for record_num in 0..vector_data.num_records {
+                let record = vector_data.get_record(record_num);
+                match vector_data.attributes.get_value(record_num, &field_name) {
+                    FieldData::Int(val) => {
+                        points.push((record.points[0].x, record.points[0].y, val as f64));
+                    }
+                    FieldData::Real(val) => {
+                        points.push((record.points[0].x, record.points[0].y, val));
+                    }
+                    _ => {
+                        // do nothing; likely due to null value for record.
+                    }
+                }
+            }
+        } else {
+            if vector_data.header.shape_type != ShapeType::PointZ
+                && vector_data.header.shape_type != ShapeType::MultiPointZ
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input vector data must be of PointZ or MultiPointZ shape type.",
+                ));
+            }
+            for record_num in 0..vector_data.num_records {
+                let record = vector_data.get_record(record_num);
+                for i in 0..record.z_array.len() {
+                    points.push((record.points[i].x, record.points[i].y, record.z_array[i]));
+                }
+            }
+        }
+
+        // merge coincident points, which would make the kriging system singular
+        points.sort_by(|a, b| {
+            (a.0, a.1)
+                .partial_cmp(&(b.0, b.1))
+                .unwrap_or(::std::cmp::Ordering::Equal)
+        });
+        let (mut px, mut py, mut pz): (Vec<f64>, Vec<f64>, Vec<f64>) = (vec![], vec![], vec![]);
+        let mut i = 0;
+        while i < points.len() {
+            let (x, y) = (points[i].0, points[i].1);
+            let (mut sum, mut n) = (0f64, 0f64);
+            while i < points.len() && points[i].0 == x && points[i].1 == y {
+                sum += points[i].2;
+                n += 1f64;
+                i += 1;
+            }
+            px.push(x);
+            py.push(y);
+            pz.push(sum / n);
+        }
+        let num_pts = pz.len();
+        if num_pts < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least two distinct points are needed for kriging.",
+            ));
+        }
+
+        // the semivariogram model
+        let model = match (nugget, sill, range) {
+            (Some(nugget), Some(sill), Some(range)) => {
+                if nugget < 0f64 || sill < nugget || range <= 0f64 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The nugget must be non-negative and no larger than the sill, and the range must be positive.",
+                    ));
+                }
+                VariogramModel {
+                    model_type: model_type,
+                    nugget: nugget,
+                    partial_sill: sill - nugget,
+                    range: range,
+                }
+            }
+            _ => {
+                if verbose {
+                    report::message("Fitting the semivariogram model...");
+                }
+                let step = (num_pts + 1999) / 2000;
+                let sx: Vec<f64> = px.iter().step_by(step).cloned().collect();
+                let sy: Vec<f64> = py.iter().step_by(step).cloned().collect();
+                let sz: Vec<f64> = pz.iter().step_by(step).cloned().collect();
+                if lag_size <= 0f64 {
+                    let width = vector_data.header.x_max - vector_data.header.x_min;
+                    let height = vector_data.header.y_max - vector_data.header.y_min;
+                    lag_size = (width * width + height * height).sqrt() / 2f64 / num_lags as f64;
+                }
+                let bins = experimental_variogram(&sx, &sy, &sz, lag_size, num_lags);
+                fit_variogram(&bins, model_type)
+            }
+        };
+        if model.sill() <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The semivariogram model has a sill of zero; the points may all have the same value.",
+            ));
+        }
+        if verbose {
+            report::message(&format!(
+                "Semivariogram model: {:?}, nugget = {}, sill = {}, range = {}",
+                model.model_type,
+                model.nugget,
+                model.sill(),
+                model.range
+            ));
+        }
+
+        let mut tree = KdTree::new_with_capacity(2, 64);
+        for i in 0..num_pts {
+            if tree.add([px[i], py[i]], i).is_err() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input points contain non-finite coordinates.",
+                ));
+            }
+        }
+
+        // Create the output raster. The process of doing this will
+        // depend on whether a cell size or a base raster were specified.
+        // If both are specified, the base raster takes priority.
+
+        let nodata = -32768.0f64;
+
+        let mut output = if !base_file.trim().is_empty() || grid_res == 0f64 {
+            if !base_file.contains(&sep) && !base_file.contains("/") {
+                base_file = format!("{}{}", working_directory, base_file);
+            }
+            let base = Raster::new(&base_file, "r")?;
+            Raster::initialize_using_file(&output_file, &base)
+        } else {
+            // base the output raster on the grid_res and the
+            // extent of the input vector.
+            let west: f64 = vector_data.header.x_min;
+            let north: f64 = vector_data.header.y_max;
+            let rows: isize = (((north - vector_data.header.y_min) / grid_res).ceil()) as isize;
+            let columns: isize = (((vector_data.header.x_max - west) / grid_res).ceil()) as isize;
+            let south: f64 = north - rows as f64 * grid_res;
+            let east = west + columns as f64 * grid_res;
+
+            let mut configs = RasterConfigs {
+                ..Default::default()
+            };
+            configs.rows = rows as usize;
+            configs.columns = columns as usize;
+            configs.north = north;
+            configs.south = south;
+            configs.east = east;
+            configs.west = west;
+            configs.resolution_x = grid_res;
+            configs.resolution_y = grid_res;
+            configs.nodata = nodata;
+            configs.data_type = DataType::F32;
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+
+            Raster::initialize_using_config(&output_file, &configs)
+        };
+
+        let rows = output.configs.rows as isize;
+        let columns = output.configs.columns as isize;
+        let nodata = output.configs.nodata; // may differ from the input nodata
+        output.configs.data_type = DataType::F32;
+        let mut variance_output = if variance_file.is_empty() {
+            None
+        } else {
+            let mut r = Raster::initialize_using_file(&variance_file, &output);
+            r.configs.nodata = nodata;
+            r.configs.data_type = DataType::F32;
+            Some(r)
+        };
+
+        let tree = Arc::new(tree);
+        let px = Arc::new(px);
+        let py = Arc::new(py);
+        let pz = Arc::new(pz);
+        let west = output.configs.west;
+        let north = output.configs.north;
+        let res_x = output.configs.resolution_x;
+        let res_y = output.configs.resolution_y;
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let tree = tree.clone();
+            let (px, py, pz) = (px.clone(), py.clone(), pz.clone());
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let (mut x, mut y): (f64, f64);
+                let mut nx: Vec<f64> = Vec::with_capacity(num_points);
+                let mut ny: Vec<f64> = Vec::with_capacity(num_points);
+                let mut nz: Vec<f64> = Vec::with_capacity(num_points);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![nodata; columns as usize];
+                    let mut variances = vec![nodata; columns as usize];
+                    for col in 0..columns {
+                        x = west + (col as f64 + 0.5) * res_x;
+                        y = north - (row as f64 + 0.5) * res_y;
+                        nx.clear();
+                        ny.clear();
+                        nz.clear();
+                        if let Ok(ret) = tree.nearest(&[x, y], num_points, &squared_euclidean) {
+                            for &(_, &i) in &ret {
+                                nx.push(px[i]);
+                                ny.push(py[i]);
+                                nz.push(pz[i]);
+                            }
+                        }
+                        if let Some((z, v)) = ordinary_kriging(&model, &nx, &ny, &nz, x, y) {
+                            data[col as usize] = z;
+                            variances[col as usize] = v;
+                        }
+                    }
+                    tx.send((row, data, variances)).unwrap();
+                }
+            });
+        }
+
+        for r in 0..rows {
+            let (row, data, variances) = rx.recv().unwrap();
+            output.set_row_data(row, data);
+            if let Some(ref mut v) = variance_output {
+                v.set_row_data(row, variances);
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        let model_description = format!(
+            "Semivariogram model: {:?}, nugget: {}, sill: {}, range: {}",
+            model.model_type,
+            model.nugget,
+            model.sill(),
+            model.range
+        );
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(model_description.clone());
+        output.add_metadata_entry(format!("Number of neighbouring points: {}", num_points));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if let Some(mut v) = variance_output {
+            v.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            v.add_metadata_entry(format!("Input file: {}", input_file));
+            v.add_metadata_entry(model_description);
+            let _ = match v.write() {
+                Ok(_) => if verbose {
+                    report::message("Kriging variance file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
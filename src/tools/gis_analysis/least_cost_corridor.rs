@@ -0,0 +1,308 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::cmp::Ordering::Equal;
+use std::env;
+use std::i32;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool maps the least-cost corridor between two sets of source areas, such as two
+/// habitat patches in a wildlife connectivity analysis. The inputs are the two cost
+/// accumulation surfaces (`--accum1` and `--accum2`) created by running the `CostDistance`
+/// tool on the same cost surface from each set of sources. Their sum is, at each cell, the
+/// total cost of the least-cost route between the two sets of sources that passes through the
+/// cell; its minimum is the cost of the least-cost path itself, and the cells of the path
+/// share this value.
+///
+/// By default, the output contains the corridor value of every cell. When a percentage is
+/// specified (`--percent`), only the cheapest cells are retained, i.e. those with corridor
+/// values within the lowest `--percent` percent of all corridor values, and the remaining
+/// cells are assigned nodata, which outlines a corridor of routes that are nearly as cheap as
+/// the least-cost path. Cells that are nodata in either input, or that could not be reached
+/// from either set of sources, are assigned nodata.
+///
+/// # See Also
+/// `CostDistance`, `LeastCostPaths`, `CostPathway`
+pub struct LeastCostCorridor {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LeastCostCorridor {
+    pub fn new() -> LeastCostCorridor {
+        // public constructor
+        let name = "LeastCostCorridor".to_string();
+        let toolbox = "GIS Analysis/Distance Tools".to_string();
+        let description =
+            "Sums two cost accumulation surfaces to map the least-cost corridor between two sets of sources."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input First Cost Accumulation File".to_owned(),
+            flags: vec!["--accum1".to_owned()],
+            description: "Input cost accumulation raster file for the first set of sources."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Second Cost Accumulation File".to_owned(),
+            flags: vec!["--accum2".to_owned()],
+            description: "Input cost accumulation raster file for the second set of sources."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cheapest Percentage of Cells".to_owned(),
+            flags: vec!["--percent".to_owned()],
+            description: "Optional percentage (0-100) of cells with the lowest corridor values to retain.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" --accum1=accum_patch1.tif --accum2=accum_patch2.tif -o=corridor.tif --percent=5.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LeastCostCorridor {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LeastCostCorridor {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut accum1_file = String::new();
+        let mut accum2_file = String::new();
+        let mut output_file = String::new();
+        let mut percent: Option<f64> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--accum1"])? {
+            accum1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--accum2"])? {
+            accum2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--percent"])? {
+            if v <= 0f64 || v > 100f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The percentage of cells must be greater than 0 and no greater than 100.",
+                ));
+            }
+            percent = Some(v);
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !accum1_file.contains(&sep) && !accum1_file.contains("/") {
+            accum1_file = format!("{}{}", working_directory, accum1_file);
+        }
+        if !accum2_file.contains(&sep) && !accum2_file.contains("/") {
+            accum2_file = format!("{}{}", working_directory, accum2_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let accum1 = Raster::new(&accum1_file, "r")?;
+        let accum2 = Raster::new(&accum2_file, "r")?;
+
+        // make sure the input files have the same size
+        if accum1.configs.rows != accum2.configs.rows
+            || accum1.configs.columns != accum2.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent.",
+            ));
+        }
+
+        let start = Instant::now();
+        let rows = accum1.configs.rows as isize;
+        let columns = accum1.configs.columns as isize;
+        let nodata1 = accum1.configs.nodata;
+        let nodata2 = accum2.configs.nodata;
+        // CostDistance assigns this value to cells that cannot be reached from any source
+        let background_val = (i32::max_value() - 1) as f64;
+
+        let mut output = Raster::initialize_using_file(&output_file, &accum1);
+        output.configs.data_type = DataType::F32;
+        let nodata = output.configs.nodata;
+        let mut values = vec![];
+        let (mut z1, mut z2): (f64, f64);
+        for row in 0..rows {
+            let mut data = vec![nodata; columns as usize];
+            for col in 0..columns {
+                z1 = accum1.get_value(row, col);
+                z2 = accum2.get_value(row, col);
+                if z1 != nodata1
+                    && z2 != nodata2
+                    && z1 < background_val
+                    && z2 < background_val
+                {
+                    data[col as usize] = z1 + z2;
+                    if percent.is_some() {
+                        values.push(z1 + z2);
+                    }
+                }
+            }
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if let Some(p) = percent {
+            if !values.is_empty() {
+                values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
+                let n = ((p / 100f64 * values.len() as f64).ceil() as usize).max(1);
+                let threshold = values[n.min(values.len()) - 1];
+                if verbose {
+                    report::metadata("Least-cost path cost", &values[0].to_string());
+                    report::metadata("Corridor threshold", &threshold.to_string());
+                }
+                let mut z: f64;
+                for row in 0..rows {
+                    for col in 0..columns {
+                        z = output.get_value(row, col);
+                        if z != nodata && z > threshold {
+                            output.set_value(row, col, nodata);
+                        }
+                    }
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "spectrum.plt".to_string();
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("First cost accumulation file: {}", accum1_file));
+        output.add_metadata_entry(format!("Second cost accumulation file: {}", accum2_file));
+        if let Some(p) = percent {
+            output.add_metadata_entry(format!("Cheapest percentage of cells: {}", p));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
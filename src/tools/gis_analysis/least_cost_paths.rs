@@ -0,0 +1,417 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::cost_accumulation::least_cost_paths;
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Point2D;
+use tools::*;
+use vector::*;
+
+/// This tool extracts the least-cost paths across a cost (friction) surface (`--cost`)
+/// between a set of source points (`--sources`) and each of a set of destination points
+/// (`--destinations`), and outputs them as vector polylines (`--output`). It is intended for
+/// connectivity analyses, such as identifying the routes that wildlife are likely to use to
+/// move between habitat patches. Each path starts at whichever source point is cheapest to
+/// reach the destination from, and connects the centres of the grid cells along the path.
+/// The cost of moving between two neighbouring cells is the mean of their costs multiplied
+/// by the distance between their centres, as in the `CostDistance` tool; cells with nodata or
+/// negative costs are impassable.
+///
+/// In addition to the least-cost path, the tool can extract the k least-cost paths to each
+/// destination (`--num_paths`), which is useful because animals rarely follow a single
+/// optimal route. The paths are found using Yen's k-shortest paths algorithm, and none of
+/// them visits a grid cell more than once. Note that the k least-cost paths across a grid
+/// commonly differ from one another by only a few cells, since a path can usually be
+/// diverted around any one of its cells at little extra cost. Fewer than k paths are
+/// output for a destination if fewer exist, e.g. where it lies at the end of a narrow,
+/// single-cell passage.
+///
+/// The attribute table of the output contains the ID of each path's destination and source
+/// (their record numbers, starting at 1), its rank among the paths to its destination,
+/// from 1 for the least-cost path, its total accumulated cost (`COST`), and its length in
+/// map units (`LENGTH`). Points that lie outside of the cost raster or on impassable cells
+/// are skipped, as are destinations without a point and those that cannot be reached from
+/// any source.
+///
+/// # See Also
+/// `CostDistance`, `CostPathway`, `LeastCostCorridor`
+pub struct LeastCostPaths {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LeastCostPaths {
+    pub fn new() -> LeastCostPaths {
+        // public constructor
+        let name = "LeastCostPaths".to_string();
+        let toolbox = "GIS Analysis/Distance Tools".to_string();
+        let description =
+            "Extracts the k least-cost paths between source and destination points as vector lines."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Cost (Friction) File".to_owned(),
+            flags: vec!["--cost".to_owned()],
+            description: "Input cost (friction) raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Source Points File".to_owned(),
+            flags: vec!["--sources".to_owned()],
+            description: "Input vector source points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Destination Points File".to_owned(),
+            flags: vec!["--destinations".to_owned()],
+            description: "Input vector destination points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Lines File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector lines file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Paths per Destination".to_owned(),
+            flags: vec!["--num_paths".to_owned()],
+            description: "Number of least-cost paths (k) to extract for each destination.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" --cost=friction.tif --sources=patch1.shp --destinations=patch2.shp -o=paths.shp --num_paths=5",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LeastCostPaths {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LeastCostPaths {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut cost_file = String::new();
+        let mut sources_file = String::new();
+        let mut destinations_file = String::new();
+        let mut output_file = String::new();
+        let mut num_paths = 1usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--cost"])? {
+            cost_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--sources"])? {
+            sources_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--destinations"])? {
+            destinations_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--num_paths"])? {
+            if v < 1 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The number of paths must be at least one.",
+                ));
+            }
+            num_paths = v as usize;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !cost_file.contains(&sep) && !cost_file.contains("/") {
+            cost_file = format!("{}{}", working_directory, cost_file);
+        }
+        if !sources_file.contains(&sep) && !sources_file.contains("/") {
+            sources_file = format!("{}{}", working_directory, sources_file);
+        }
+        if !destinations_file.contains(&sep) && !destinations_file.contains("/") {
+            destinations_file = format!("{}{}", working_directory, destinations_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let cost = Raster::new(&cost_file, "r")?;
+        let sources = Shapefile::read(&sources_file)?;
+        let destinations = Shapefile::read(&destinations_file)?;
+
+        let start = Instant::now();
+
+        if sources.header.shape_type.base_shape_type() != ShapeType::Point
+            || destinations.header.shape_type.base_shape_type() != ShapeType::Point
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input source and destination files must be of point base shape type.",
+            ));
+        }
+
+        let rows = cost.configs.rows as isize;
+        let columns = cost.configs.columns as isize;
+        let nodata = cost.configs.nodata;
+        let res_x = cost.configs.resolution_x;
+        let res_y = cost.configs.resolution_y;
+        let original_cost = cost.get_data_as_array2d();
+        let is_passable = |row: isize, col: isize| -> bool {
+            let z = original_cost.get_value(row, col);
+            row >= 0 && col >= 0 && row < rows && col < columns && z != nodata && z >= 0f64
+        };
+
+        // locate the source cells, keeping the first source point in each cell
+        let mut source_cells = vec![];
+        let mut source_ids: HashMap<(isize, isize), usize> = HashMap::new();
+        for record_num in 0..sources.num_records {
+            let record = sources.get_record(record_num);
+            for p in &record.points {
+                let cell = (cost.get_row_from_y(p.y), cost.get_column_from_x(p.x));
+                if is_passable(cell.0, cell.1) && !source_ids.contains_key(&cell) {
+                    source_ids.insert(cell, record_num + 1);
+                    source_cells.push(cell);
+                }
+            }
+        }
+        if source_cells.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "None of the source points are located on passable cells of the cost raster.",
+            ));
+        }
+
+        let mut output = Shapefile::new(&output_file, ShapeType::PolyLine)?;
+        output.projection = cost.configs.coordinate_ref_system_wkt.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("DEST_ID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("SOURCE_ID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("RANK", FieldDataType::Int, 4u8, 0u8));
+        output.attributes.add_field(&AttributeField::new(
+            "COST",
+            FieldDataType::Real,
+            16u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "LENGTH",
+            FieldDataType::Real,
+            14u8,
+            4u8,
+        ));
+
+        let mut fid = 1i32;
+        let mut num_skipped = 0;
+        for record_num in 0..destinations.num_records {
+            let record = destinations.get_record(record_num);
+            if record.points.is_empty() {
+                num_skipped += 1;
+                continue;
+            }
+            let p = record.points[0];
+            let target = (cost.get_row_from_y(p.y), cost.get_column_from_x(p.x));
+            if !is_passable(target.0, target.1) {
+                num_skipped += 1;
+                continue;
+            }
+            let paths = least_cost_paths(
+                &original_cost,
+                &source_cells,
+                target,
+                num_paths,
+                res_x,
+                res_y,
+            )?;
+            if paths.is_empty() {
+                num_skipped += 1;
+                continue;
+            }
+
+            // the paths are in order of increasing cost
+            for (rank, &(total_cost, ref path)) in paths.iter().enumerate() {
+                let mut points = Vec::with_capacity(path.len());
+                let mut length = 0f64;
+                for i in 0..path.len() {
+                    let (row, col) = path[i];
+                    points.push(Point2D::new(
+                        cost.get_x_from_column(col),
+                        cost.get_y_from_row(row),
+                    ));
+                    if i > 0 {
+                        let (row1, col1) = path[i - 1];
+                        let (dx, dy) = (col - col1, row - row1);
+                        let (a, b) = (dx as f64 * res_x, dy as f64 * res_y);
+                        length += (a * a + b * b).sqrt();
+                    }
+                }
+                if points.len() == 1 {
+                    // the destination is a source cell; output a degenerate two-point line
+                    let p = points[0];
+                    points.push(p);
+                }
+                let source_id = match source_ids.get(&path[0]) {
+                    Some(id) => *id as i32,
+                    None => 0i32,
+                };
+                let mut sfg = ShapefileGeometry::new(ShapeType::PolyLine);
+                sfg.add_part(&points);
+                output.add_record(sfg);
+                output.attributes.add_record(
+                    vec![
+                        FieldData::Int(fid),
+                        FieldData::Int(record_num as i32 + 1),
+                        FieldData::Int(source_id),
+                        FieldData::Int(rank as i32 + 1),
+                        FieldData::Real(total_cost),
+                        FieldData::Real(length),
+                    ],
+                    false,
+                );
+                fid += 1;
+            }
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / destinations.num_records as f64)
+                    as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if num_skipped > 0 {
+            report::warning(&format!(
+                "{} destination points were empty, lay on impassable cells, or could not be \
+                 reached from any source, and were skipped.",
+                num_skipped
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -158,35 +158,15 @@ impl WhiteboxTool for LineIntersections {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i1" || flag_val == "-input1" {
-                input1_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-i2" || flag_val == "-input2" {
-                input2_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,9 +176,7 @@ impl WhiteboxTool for LineIntersections {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input1_file.contains(path::MAIN_SEPARATOR) && !input1_file.contains("/") {
@@ -426,9 +404,9 @@ impl WhiteboxTool for LineIntersections {
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / input1.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Progress ({} intersections found): {}%",
-                        num_intersections, progress
+                    report::progress(
+                        &format!("Progress ({} intersections found)", num_intersections),
+                        progress,
                     );
                     old_progress = progress;
                 }
@@ -436,19 +414,21 @@ impl WhiteboxTool for LineIntersections {
         }
 
         if print_warning {
-            println!("Warning: Some of the input line features intersect at line segments rather than points.")
+            report::warning(
+                "Some of the input line features intersect at line segments rather than points.",
+            )
         }
 
         if fid == 1 {
-            println!("Warning: No intersections were found between the input features.")
+            report::warning("No intersections were found between the input features.")
         } else {
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -457,7 +437,7 @@ impl WhiteboxTool for LineIntersections {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,247 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool calculates the planimetric (2D) and surface (3D) lengths of the features in a
+/// 3D vector lines or polygons file (PolyLineZ or PolygonZ), adding the results to the
+/// vector's attribute table (LENGTH_2D and LENGTH_3D fields). The 3D length accounts for the
+/// change in the z-values between each pair of consecutive vertices, and is therefore always
+/// at least as large as the 2D length; for polygons, the lengths are those of the polygon
+/// perimeters, including the boundaries of any holes. The vector should be in a projected
+/// coordinate system, with z-values in the same units as the x and y coordinates.
+///
+/// 3D vectors can be created from 2D vectors and a digital elevation model using the
+/// `DrapeVectorOnDem` tool. Notice that vertices that were assigned the DEM's NoData value
+/// will result in unrealistic 3D lengths; the `VertexZStatistics` tool can be used to
+/// identify these features.
+///
+/// # See Also
+/// `DrapeVectorOnDem`, `VertexZStatistics`, `PolygonPerimeter`
+pub struct LineLength3D {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LineLength3D {
+    pub fn new() -> LineLength3D {
+        // public constructor
+        let name = "LineLength3D".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Calculates the 2D and 3D lengths of the features in a 3D vector lines or polygons file."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input 3D vector lines or polygon file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input=roads_3d.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LineLength3D {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LineLength3D {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of a 3D lines or polygons type
+        if (input.header.shape_type.base_shape_type() != ShapeType::PolyLine
+            && input.header.shape_type.base_shape_type() != ShapeType::Polygon)
+            || input.header.shape_type.dimension() != ShapeTypeDimension::Z
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POLYLINEZ or POLYGONZ shape type.",
+            ));
+        }
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&input_file, &input, input.header.shape_type, true)?;
+
+        // add the attributes
+        output.attributes.add_field(&AttributeField::new(
+            "LENGTH_2D",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "LENGTH_3D",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+
+        let mut part_start: usize;
+        let mut part_end: usize;
+        let (mut length_2d, mut length_3d): (f64, f64);
+        let (mut dxy, mut dz): (f64, f64);
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            length_2d = 0f64;
+            length_3d = 0f64;
+            for part in 0..record.num_parts as usize {
+                part_start = record.parts[part] as usize;
+                part_end = if part < record.num_parts as usize - 1 {
+                    record.parts[part + 1] as usize
+                } else {
+                    record.num_points as usize
+                };
+                for i in part_start + 1..part_end {
+                    dxy = record.points[i].distance(&record.points[i - 1]);
+                    dz = record.z_array[i] - record.z_array[i - 1];
+                    length_2d += dxy;
+                    length_3d += (dxy * dxy + dz * dz).sqrt();
+                }
+            }
+            let record_out = record.clone();
+            output.add_record(record_out);
+
+            let mut atts = input.attributes.get_record(record_num);
+            atts.push(FieldData::Real(length_2d));
+            atts.push(FieldData::Real(length_3d));
+            output.attributes.add_record(atts, false);
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
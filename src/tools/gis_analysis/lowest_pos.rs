@@ -114,34 +114,16 @@ impl WhiteboxTool for LowestPosition {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--inputs" {
-                if keyval {
-                    input_files = vec[1].to_string();
-                } else {
-                    input_files = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -180,7 +162,7 @@ impl WhiteboxTool for LowestPosition {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    report::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -202,7 +184,7 @@ impl WhiteboxTool for LowestPosition {
                 if input.configs.rows as isize != rows || input.configs.columns as isize != columns
                 {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
+                                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
 
                 for row in 0..rows {
@@ -218,7 +200,10 @@ impl WhiteboxTool for LowestPosition {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files, progress);
+                            report::progress(
+                                &format!("Progress (loop {} of {})", i, num_files),
+                                progress,
+                            );
                             old_progress = progress;
                         }
                     }
@@ -235,20 +220,17 @@ impl WhiteboxTool for LowestPosition {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -162,46 +162,27 @@ impl WhiteboxTool for MinimumBoundingBox {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-criterion" {
-                let criteria_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                min_criterion = if criteria_str.contains("len") {
-                    MinimizationCriterion::Length
-                } else if criteria_str.contains("wi") {
-                    MinimizationCriterion::Width
-                } else if criteria_str.contains("per") {
-                    MinimizationCriterion::Perimeter
-                } else {
-                    MinimizationCriterion::Area
-                };
-            } else if flag_val == "-features" || flag_val == "-feature" {
-                individual_feature_hulls = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--criterion"])? {
+            let criteria_str = v;
+            min_criterion = if criteria_str.contains("len") {
+                MinimizationCriterion::Length
+            } else if criteria_str.contains("wi") {
+                MinimizationCriterion::Width
+            } else if criteria_str.contains("per") {
+                MinimizationCriterion::Perimeter
+            } else {
+                MinimizationCriterion::Area
+            };
+        }
+        if tool_args.get_flag(&["--features", "--feature"])? {
+            individual_feature_hulls = true;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -211,9 +192,7 @@ impl WhiteboxTool for MinimumBoundingBox {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -259,18 +238,18 @@ impl WhiteboxTool for MinimumBoundingBox {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -294,14 +273,14 @@ impl WhiteboxTool for MinimumBoundingBox {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Reading points: {}%", progress);
+                        report::progress("Reading points", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Finding convex hull...");
+                report::message("Finding convex hull...");
             }
             let mut mbb_points = minimum_bounding_box(&mut points, min_criterion);
             // now add a last point same as the first.
@@ -316,11 +295,11 @@ impl WhiteboxTool for MinimumBoundingBox {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -329,7 +308,7 @@ impl WhiteboxTool for MinimumBoundingBox {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,635 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{
+    convex_hull, minimum_bounding_box, polygon_area, polygon_perimeter,
+    smallest_enclosing_circle, MinimizationCriterion,
+};
+use lidar::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Point2D;
+use tools::*;
+use vector::ShapefileGeometry;
+use vector::*;
+
+/// This tool delineates the minimum bounding geometry around groups of vector features or LiDAR
+/// point clouds. Three types of bounding geometry (`--geometry`) are available:
+///
+/// - `convex_hull` (the default), the smallest convex polygon enclosing the group;
+/// - `rectangle`, the minimum bounding rectangle, which may be rotated and which minimizes the
+///   rectangle's area, length, width, or perimeter (`--criterion`); and
+/// - `circle`, the smallest enclosing circle.
+///
+/// The vertices of the input features can be grouped (`--group_by`) by `feature` (the default),
+/// in which case a bounding geometry is created around each feature and the feature's attributes
+/// are copied to the output; by `field`, in which case a bounding geometry is created around all
+/// of the features sharing the same value of an attribute field (`--field`); or as a single group
+/// (`all`) enclosing the entire vector. If the input is a LiDAR (LAS) file, or if no input is
+/// specified, in which case the tool works with all of the LAS files contained within the working
+/// directory, a bounding geometry is created around the points of each LAS file, providing an
+/// outline of each tile.
+///
+/// The following size and orientation attributes are added to the output polygons:
+///
+/// | Field     | Description                                                                   |
+/// |-----------|-------------------------------------------------------------------------------|
+/// | AREA      | The area of the bounding geometry.                                            |
+/// | PERIMETER | The perimeter of the bounding geometry.                                       |
+/// | LENGTH    | The length of the rectangle, the diameter of the circle, or the diameter of   |
+/// |           | the hull, i.e. the greatest distance between any two of its vertices.         |
+/// | WIDTH     | The width of the rectangle, the diameter of the circle, or the minimum width  |
+/// |           | of the hull.                                                                  |
+/// | ORIENT    | The orientation of the rectangle's long axis or the hull's diameter, in       |
+/// |           | degrees clockwise from north (0-180). Circles have no orientation.            |
+///
+/// Groups with fewer than three vertices are not enclosed.
+///
+/// # See Also
+/// `MinimumBoundingBox`, `MinimumBoundingCircle`, `MinimumConvexHull`, `MinimumBoundingEnvelope`,
+/// `LidarTileFootprint`, `PolygonGeometryAttributes`
+pub struct MinimumBoundingGeometry {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl MinimumBoundingGeometry {
+    pub fn new() -> MinimumBoundingGeometry {
+        // public constructor
+        let name = "MinimumBoundingGeometry".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description = "Creates convex hulls, minimum bounding rectangles, or minimum bounding circles around groups of vector features or LiDAR tiles.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector or LiDAR File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector or LiDAR file; if unspecified, all LAS files in the working directory are used.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Any),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Polygon File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector polygon file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Bounding Geometry".to_owned(),
+            flags: vec!["--geometry".to_owned()],
+            description: "Bounding geometry type; options include 'convex_hull' (default), 'rectangle', and 'circle'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "convex_hull".to_owned(),
+                "rectangle".to_owned(),
+                "circle".to_owned(),
+            ]),
+            default_value: Some("convex_hull".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Group By".to_owned(),
+            flags: vec!["--group_by".to_owned()],
+            description: "Grouping of vector features; options include 'feature' (default), 'field', and 'all'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "feature".to_owned(),
+                "field".to_owned(),
+                "all".to_owned(),
+            ]),
+            default_value: Some("feature".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Group Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Attribute field used to group features when grouping by field.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Rectangle Minimization Criterion".to_owned(),
+            flags: vec!["--criterion".to_owned()],
+            description: "Minimization criterion for rectangles; options include 'area' (default), 'length', 'width', and 'perimeter'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "area".to_owned(),
+                "length".to_owned(),
+                "width".to_owned(),
+                "perimeter".to_owned(),
+            ]),
+            default_value: Some("area".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.shp -o=outfile.shp --geometry=rectangle --criterion=width
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.shp -o=outfile.shp --geometry=circle --group_by=field --field=CLASS
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -o=tiles.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        MinimumBoundingGeometry {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for MinimumBoundingGeometry {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut geometry = String::from("convex_hull");
+        let mut group_by = String::from("feature");
+        let mut field_name = String::new();
+        let mut min_criterion = MinimizationCriterion::Area;
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--geometry"])? {
+            geometry = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_string(&["--group_by"])? {
+            group_by = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--criterion"])? {
+            let criteria_str = v.to_lowercase();
+            min_criterion = if criteria_str.contains("len") {
+                MinimizationCriterion::Length
+            } else if criteria_str.contains("wi") {
+                MinimizationCriterion::Width
+            } else if criteria_str.contains("per") {
+                MinimizationCriterion::Perimeter
+            } else {
+                MinimizationCriterion::Area
+            };
+        }
+
+        if geometry != "convex_hull" && geometry != "rectangle" && geometry != "circle" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized bounding geometry; options are 'convex_hull', 'rectangle', and 'circle'.",
+            ));
+        }
+        if group_by != "feature" && group_by != "field" && group_by != "all" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized grouping; options are 'feature', 'field', and 'all'.",
+            ));
+        }
+        if group_by == "field" && field_name.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A group field name (--field) must be specified when grouping by field.",
+            ));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let mut inputs = vec![];
+        if input_file.is_empty() {
+            if working_directory.is_empty() {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                    "This tool must be run by specifying either an individual input file or a working directory."));
+            }
+            match fs::read_dir(working_directory) {
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
+                Ok(paths) => for path in paths {
+                    let s = format!("{:?}", path.unwrap().path());
+                    if s.replace("\"", "").to_lowercase().ends_with(".las") {
+                        inputs.push(s.replace("\"", ""));
+                    }
+                },
+            }
+            if inputs.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "No input file was specified and the working directory contains no LAS files.",
+                ));
+            }
+        } else {
+            if !input_file.contains(&sep) && !input_file.contains("/") {
+                input_file = format!("{}{}", working_directory, input_file);
+            }
+            inputs.push(input_file.clone());
+        }
+
+        // Gather the groups of points to be enclosed, along with their attributes.
+        let mut groups: Vec<Vec<Point2D>> = vec![];
+        let mut group_atts: Vec<Vec<FieldData>> = vec![];
+        let mut output = Shapefile::new(&output_file, ShapeType::Polygon)?;
+        if inputs[0].to_lowercase().ends_with(".las") {
+            output
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 6u8, 0u8));
+            output
+                .attributes
+                .add_field(&AttributeField::new("LAS_NM", FieldDataType::Text, 25u8, 4u8));
+            output
+                .attributes
+                .add_field(&AttributeField::new("NUM_PNTS", FieldDataType::Int, 9u8, 0u8));
+            let num_tiles = inputs.len();
+            for tile in 0..num_tiles {
+                let short_filename = path::Path::new(&inputs[tile])
+                    .file_stem()
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+                    .to_string();
+                if verbose && num_tiles > 1 {
+                    report::message(&format!(
+                        "Reading {} ({} of {})",
+                        short_filename,
+                        tile + 1,
+                        num_tiles
+                    ));
+                } else if verbose {
+                    report::message("Reading input LAS file...");
+                }
+                let mut input = match LasFile::new(&inputs[tile], "r") {
+                    Ok(lf) => lf,
+                    Err(err) => {
+                        report::message(&format!("Error reading file {}:\n{}", inputs[tile], err));
+                        continue;
+                    }
+                };
+                let n_points = input.header.number_of_points as usize;
+                let mut points: Vec<Point2D> = Vec::with_capacity(n_points);
+                for i in 0..n_points {
+                    let p: PointData = input.get_point_info(i);
+                    points.push(Point2D::new(p.x, p.y));
+                }
+                if output.projection.is_empty() {
+                    let wkt = input.get_wkt();
+                    if wkt != "Unknown EPSG Code" {
+                        output.projection = wkt;
+                    }
+                }
+                groups.push(points);
+                group_atts.push(vec![
+                    FieldData::Int(0i32),
+                    FieldData::Text(short_filename),
+                    FieldData::Int(n_points as i32),
+                ]);
+            }
+        } else {
+            let input = Shapefile::read(&inputs[0])?;
+            output.projection = input.projection.clone();
+
+            let mut field_index = 0usize;
+            if group_by == "field" {
+                field_index = match input.attributes.get_field_num(&field_name) {
+                    Some(i) => i,
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "The specified group field name does not exist in the input file.",
+                        ));
+                    }
+                };
+            }
+            if group_by == "feature" {
+                output
+                    .attributes
+                    .add_fields(&input.attributes.get_fields().clone());
+            } else {
+                output
+                    .attributes
+                    .add_field(&AttributeField::new("FID", FieldDataType::Int, 6u8, 0u8));
+                if group_by == "field" {
+                    output
+                        .attributes
+                        .add_field(&input.attributes.get_field_info(field_index));
+                }
+                output
+                    .attributes
+                    .add_field(&AttributeField::new("COUNT", FieldDataType::Int, 9u8, 0u8));
+            }
+
+            let mut group_nums: HashMap<String, usize> = HashMap::new();
+            for record_num in 0..input.num_records {
+                let record = input.get_record(record_num);
+                let points = record
+                    .points
+                    .iter()
+                    .map(|p| Point2D::new(p.x, p.y))
+                    .collect::<Vec<Point2D>>();
+                if group_by == "feature" {
+                    groups.push(points);
+                    group_atts.push(input.attributes.get_record(record_num));
+                } else {
+                    let value = if group_by == "field" {
+                        input.attributes.get_value(record_num, &field_name)
+                    } else {
+                        FieldData::Null
+                    };
+                    let key = format!("{}", value);
+                    let group = match group_nums.get(&key) {
+                        Some(&g) => g,
+                        None => {
+                            groups.push(vec![]);
+                            group_atts.push(if group_by == "field" {
+                                vec![FieldData::Int(0i32), value, FieldData::Int(0i32)]
+                            } else {
+                                vec![FieldData::Int(0i32), FieldData::Int(0i32)]
+                            });
+                            groups.len() - 1
+                        }
+                    };
+                    group_nums.insert(key, group);
+                    groups[group].extend(points);
+                    let count = group_atts[group].len() - 1;
+                    if let FieldData::Int(ref mut n) = group_atts[group][count] {
+                        *n += 1;
+                    }
+                }
+
+                if verbose {
+                    progress =
+                        (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Reading features", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+        }
+
+        for name in &["AREA", "PERIMETER", "LENGTH", "WIDTH", "ORIENT"] {
+            output
+                .attributes
+                .add_field(&AttributeField::new(name, FieldDataType::Real, 16u8, 4u8));
+        }
+
+        if verbose {
+            report::message("Finding bounding geometries...");
+        }
+        let mut num_skipped = 0;
+        let mut fid = 1i32;
+        let num_groups = groups.len();
+        for group in 0..num_groups {
+            if groups[group].len() < 3 {
+                num_skipped += 1;
+                continue;
+            }
+            let (ring, measures) = match &geometry as &str {
+                "rectangle" => bounding_rectangle(&mut groups[group], min_criterion),
+                "circle" => bounding_circle(&groups[group]),
+                _ => bounding_hull(&mut groups[group]),
+            };
+            let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+            sfg.add_part(&ring);
+            output.add_record(sfg);
+
+            let mut atts = group_atts[group].clone();
+            if group_by != "feature" || inputs[0].to_lowercase().ends_with(".las") {
+                atts[0] = FieldData::Int(fid);
+            }
+            for v in measures {
+                atts.push(match v {
+                    Some(v) => FieldData::Real(v),
+                    None => FieldData::Null,
+                });
+            }
+            output.attributes.add_record(atts, false);
+            fid += 1;
+
+            if verbose {
+                progress = (100.0_f64 * (group + 1) as f64 / num_groups as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        if num_skipped > 0 {
+            report::warning(&format!(
+                "{} group(s) with fewer than three vertices were not enclosed.",
+                num_skipped
+            ));
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the closed, clockwise ring of the convex hull of a group of points, along with its
+/// area, perimeter, diameter, minimum width, and the orientation of its diameter.
+fn bounding_hull(points: &mut Vec<Point2D>) -> (Vec<Point2D>, Vec<Option<f64>>) {
+    let mut hull = convex_hull(points);
+    // convex_hull returns points in a counter-clockwise order but we need it to be clockwise for a shapefile poly.
+    hull.reverse();
+
+    let (mut a, mut b) = (hull[0], hull[0]);
+    let mut diameter = 0f64;
+    for i in 0..hull.len() {
+        for j in i + 1..hull.len() {
+            let d = hull[i].distance(&hull[j]);
+            if d > diameter {
+                diameter = d;
+                a = hull[i];
+                b = hull[j];
+            }
+        }
+    }
+    let mbb = minimum_bounding_box(&mut hull.clone(), MinimizationCriterion::Width);
+    let width = mbb[0].distance(&mbb[1]).min(mbb[1].distance(&mbb[2]));
+    let measures = vec![
+        Some(polygon_area(&hull)),
+        Some(polygon_perimeter(&hull)),
+        Some(diameter),
+        Some(width),
+        Some(orientation(a, b)),
+    ];
+
+    // now add a last point same as the first.
+    let p = hull[0];
+    hull.push(p);
+    (hull, measures)
+}
+
+/// Returns the closed ring of the minimum bounding rectangle of a group of points, along with
+/// its area, perimeter, length, width, and the orientation of its long axis.
+fn bounding_rectangle(
+    points: &mut Vec<Point2D>,
+    min_criterion: MinimizationCriterion,
+) -> (Vec<Point2D>, Vec<Option<f64>>) {
+    let mut mbb = minimum_bounding_box(points, min_criterion);
+    let (side1, side2) = (mbb[0].distance(&mbb[1]), mbb[1].distance(&mbb[2]));
+    let long_axis = if side1 >= side2 {
+        orientation(mbb[0], mbb[1])
+    } else {
+        orientation(mbb[1], mbb[2])
+    };
+    let measures = vec![
+        Some(side1 * side2),
+        Some(2f64 * (side1 + side2)),
+        Some(side1.max(side2)),
+        Some(side1.min(side2)),
+        Some(long_axis),
+    ];
+
+    // now add a last point same as the first.
+    let p = mbb[0];
+    mbb.push(p);
+    (mbb, measures)
+}
+
+/// Returns the closed ring of the smallest enclosing circle of a group of points, along with its
+/// area, circumference, and diameter (as both length and width).
+fn bounding_circle(points: &[Point2D]) -> (Vec<Point2D>, Vec<Option<f64>>) {
+    let mbc = smallest_enclosing_circle(points);
+    let num_circle_vertices = 128usize;
+    let angular_resolution = 2f64 * PI / num_circle_vertices as f64;
+    let mut ring: Vec<Point2D> = Vec::with_capacity(num_circle_vertices + 1);
+    for i in 0..=num_circle_vertices {
+        let slope = (i % num_circle_vertices) as f64 * angular_resolution;
+        ring.push(Point2D::new(
+            mbc.center.x + mbc.radius * slope.sin(),
+            mbc.center.y + mbc.radius * slope.cos(),
+        ));
+    }
+    let measures = vec![
+        Some(PI * mbc.radius * mbc.radius),
+        Some(2f64 * PI * mbc.radius),
+        Some(2f64 * mbc.radius),
+        Some(2f64 * mbc.radius),
+        None,
+    ];
+    (ring, measures)
+}
+
+/// Returns the orientation of the line from a to b, in degrees clockwise from north, within the
+/// range [0, 180).
+fn orientation(a: Point2D, b: Point2D) -> f64 {
+    let mut azimuth = (b.x - a.x).atan2(b.y - a.y).to_degrees();
+    while azimuth < 0f64 {
+        azimuth += 180f64;
+    }
+    while azimuth >= 180f64 {
+        azimuth -= 180f64;
+    }
+    azimuth
+}
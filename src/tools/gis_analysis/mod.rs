@@ -3,13 +3,16 @@ mod aggregate_raster;
 mod average_overlay;
 mod block_maximum;
 mod block_minimum;
+mod boundary_clean;
 mod buffer_raster;
 mod centroid;
 mod centroid_vector;
+mod clip;
 mod clip_raster_to_polygon;
 mod clump;
 mod compactness_ratio;
 mod construct_vector_tin;
+mod cost_accumulation;
 mod cost_allocation;
 mod cost_distance;
 mod cost_pathway;
@@ -17,22 +20,34 @@ mod count_if;
 mod create_hexagonal_vector_grid;
 mod create_plane;
 mod create_rectangular_vector_grid;
+mod distance_transform;
+mod drape_vector_on_dem;
 mod edge_proportion;
 mod eliminate_coincident_points;
 mod elongation_ratio;
+mod erase;
 mod erase_polygon_from_raster;
 mod euclidean_allocation;
+mod euclidean_direction;
 mod euclidean_distance;
 mod extend_vector_lines;
 mod extract_nodes;
 mod extract_raster_values_at_points;
+mod extract_values_to_points;
 mod find_lowest_or_highest_points;
 mod find_patch_edge_cells;
+mod generalize_classified_raster;
+mod geodesic_distance;
 mod highest_pos;
 mod hole_proportion;
 mod idw_interpolation;
+mod kernel_density;
+mod kriging_interpolation;
 mod layer_footprint;
+mod least_cost_corridor;
+mod least_cost_paths;
 mod line_intersections;
+mod line_length_3d;
 mod linearity_index;
 mod lowest_pos;
 mod max_abs_overlay;
@@ -43,32 +58,47 @@ mod min_overlay;
 mod minimum_bounding_box;
 mod minimum_bounding_circle;
 mod minimum_bounding_envelope;
+mod minimum_bounding_geometry;
 mod minimum_convex_hull;
 mod nearest_neighbour_gridding;
+mod nearest_neighbour_index;
 mod patch_orientation;
 mod percent_equal_to;
 mod percent_greater_than;
 mod percent_less_than;
 mod perimeter_area_ratio;
 mod pick_from_list;
+mod point_sampling;
 mod polygon_area;
+mod polygon_geometry_attributes;
 mod polygon_long_axis;
 mod polygon_perimeter;
 mod polygon_short_axis;
 mod polygonize;
+mod quadrat_count;
 mod radius_of_gyration;
+mod random_points;
 mod raster_cell_assignment;
 mod reclass;
 mod reclass_equal_interval;
 mod reclass_from_file;
+mod regular_grid_points;
 mod related_circumscribing_circle;
+mod ripleys_k;
 mod shape_complexity_index;
 // mod sibson_interpolation;
+mod simplify_vectors;
 mod smooth_vectors;
+mod snap_points_to_lines;
+mod split_by_attribute;
+mod split_by_polygon;
 mod split_with_lines;
+mod stratified_random_points;
 mod sum_overlay;
 mod tin_gridding;
+mod validate_polygons;
 mod vector_hex_bin;
+mod vertex_z_statistics;
 mod voronoi_diagram;
 mod weighted_overlay;
 mod weighted_sum;
@@ -78,9 +108,11 @@ pub use self::aggregate_raster::AggregateRaster;
 pub use self::average_overlay::AverageOverlay;
 pub use self::block_maximum::BlockMaximumGridding;
 pub use self::block_minimum::BlockMinimumGridding;
+pub use self::boundary_clean::BoundaryClean;
 pub use self::buffer_raster::BufferRaster;
 pub use self::centroid::Centroid;
 pub use self::centroid_vector::CentroidVector;
+pub use self::clip::Clip;
 pub use self::clip_raster_to_polygon::ClipRasterToPolygon;
 pub use self::clump::Clump;
 pub use self::compactness_ratio::CompactnessRatio;
@@ -92,22 +124,33 @@ pub use self::count_if::CountIf;
 pub use self::create_hexagonal_vector_grid::CreateHexagonalVectorGrid;
 pub use self::create_plane::CreatePlane;
 pub use self::create_rectangular_vector_grid::CreateRectangularVectorGrid;
+pub use self::drape_vector_on_dem::DrapeVectorOnDem;
 pub use self::edge_proportion::EdgeProportion;
 pub use self::eliminate_coincident_points::EliminateCoincidentPoints;
 pub use self::elongation_ratio::ElongationRatio;
+pub use self::erase::Erase;
 pub use self::erase_polygon_from_raster::ErasePolygonFromRaster;
 pub use self::euclidean_allocation::EuclideanAllocation;
+pub use self::euclidean_direction::EuclideanDirection;
 pub use self::euclidean_distance::EuclideanDistance;
 pub use self::extend_vector_lines::ExtendVectorLines;
 pub use self::extract_nodes::ExtractNodes;
 pub use self::extract_raster_values_at_points::ExtractRasterValuesAtPoints;
+pub use self::extract_values_to_points::ExtractValuesToPoints;
 pub use self::find_lowest_or_highest_points::FindLowestOrHighestPoints;
 pub use self::find_patch_edge_cells::FindPatchOrClassEdgeCells;
+pub use self::generalize_classified_raster::GeneralizeClassifiedRaster;
+pub use self::geodesic_distance::GeodesicDistance;
 pub use self::highest_pos::HighestPosition;
 pub use self::hole_proportion::HoleProportion;
 pub use self::idw_interpolation::IdwInterpolation;
+pub use self::kernel_density::KernelDensity;
+pub use self::kriging_interpolation::KrigingInterpolation;
 pub use self::layer_footprint::LayerFootprint;
+pub use self::least_cost_corridor::LeastCostCorridor;
+pub use self::least_cost_paths::LeastCostPaths;
 pub use self::line_intersections::LineIntersections;
+pub use self::line_length_3d::LineLength3D;
 pub use self::linearity_index::LinearityIndex;
 pub use self::lowest_pos::LowestPosition;
 pub use self::max_abs_overlay::MaxAbsoluteOverlay;
@@ -118,8 +161,10 @@ pub use self::min_overlay::MinOverlay;
 pub use self::minimum_bounding_box::MinimumBoundingBox;
 pub use self::minimum_bounding_circle::MinimumBoundingCircle;
 pub use self::minimum_bounding_envelope::MinimumBoundingEnvelope;
+pub use self::minimum_bounding_geometry::MinimumBoundingGeometry;
 pub use self::minimum_convex_hull::MinimumConvexHull;
 pub use self::nearest_neighbour_gridding::NearestNeighbourGridding;
+pub use self::nearest_neighbour_index::NearestNeighbourIndex;
 pub use self::patch_orientation::PatchOrientation;
 pub use self::percent_equal_to::PercentEqualTo;
 pub use self::percent_greater_than::PercentGreaterThan;
@@ -127,23 +172,35 @@ pub use self::percent_less_than::PercentLessThan;
 pub use self::perimeter_area_ratio::PerimeterAreaRatio;
 pub use self::pick_from_list::PickFromList;
 pub use self::polygon_area::PolygonArea;
+pub use self::polygon_geometry_attributes::PolygonGeometryAttributes;
 pub use self::polygon_long_axis::PolygonLongAxis;
 pub use self::polygon_perimeter::PolygonPerimeter;
 pub use self::polygon_short_axis::PolygonShortAxis;
 pub use self::polygonize::Polygonize;
+pub use self::quadrat_count::QuadratCount;
 pub use self::radius_of_gyration::RadiusOfGyration;
+pub use self::random_points::RandomPoints;
 pub use self::raster_cell_assignment::RasterCellAssignment;
 pub use self::reclass::Reclass;
 pub use self::reclass_equal_interval::ReclassEqualInterval;
 pub use self::reclass_from_file::ReclassFromFile;
+pub use self::regular_grid_points::RegularGridPoints;
 pub use self::related_circumscribing_circle::RelatedCircumscribingCircle;
+pub use self::ripleys_k::RipleysK;
 pub use self::shape_complexity_index::ShapeComplexityIndex;
 // pub use self::sibson_interpolation::SibsonInterpolation;
+pub use self::simplify_vectors::SimplifyVectors;
 pub use self::smooth_vectors::SmoothVectors;
+pub use self::snap_points_to_lines::SnapPointsToLines;
+pub use self::split_by_attribute::SplitByAttribute;
+pub use self::split_by_polygon::SplitByPolygon;
 pub use self::split_with_lines::SplitWithLines;
+pub use self::stratified_random_points::StratifiedRandomPoints;
 pub use self::sum_overlay::SumOverlay;
 pub use self::tin_gridding::TINGridding;
+pub use self::validate_polygons::ValidatePolygons;
 pub use self::vector_hex_bin::VectorHexBinning;
+pub use self::vertex_z_statistics::VertexZStatistics;
 pub use self::voronoi_diagram::VoronoiDiagram;
 pub use self::weighted_overlay::WeightedOverlay;
 pub use self::weighted_sum::WeightedSum;
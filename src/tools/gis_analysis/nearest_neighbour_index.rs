@@ -0,0 +1,420 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use self::statrs::distribution::{Normal, Univariate};
+use super::clip::{get_polygon_union, get_rings_bounding_box};
+use algorithms::{is_clockwise_order, nearest_neighbour_index, point_in_rings, polygon_area};
+use rendering::html::*;
+use statrs;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::process::Command;
+use structures::{BoundingBox, Point2D};
+use tools::*;
+use vector::*;
+
+/// This tool calculates the nearest-neighbour index (Clark and Evans, 1954) of a set of
+/// vector points within a study area, to test whether the points are clustered, randomly
+/// distributed, or dispersed. The index is the ratio of the observed mean distance between
+/// each point and its nearest neighbour to the mean distance expected of a random (Poisson)
+/// pattern of the same density, `0.5 / sqrt(n / A)`, where `n` is the number of points and
+/// `A` the area of the study area. Ratios less than 1 indicate clustering and ratios greater
+/// than 1 dispersion. The significance of the departure from complete spatial randomness is
+/// given by the z-score of the observed mean distance and its two-tailed p-value.
+///
+/// The study area is specified by an optional polygon vector (`--boundary`), the union of
+/// whose polygons is used; points outside of the study area are ignored. If no boundary is
+/// specified, the bounding box of the points is used. Because the points near the edge of the
+/// study area have no neighbours beyond it, their nearest-neighbour distances tend to be
+/// overestimated, biasing the index towards dispersion, particularly for small numbers of
+/// points; the bounding box is the smallest possible study area and is therefore the most
+/// affected. The study area should reflect the area within which points could occur.
+///
+/// The results are written to an HTML report (`--output`).
+///
+/// # Reference
+/// Clark, P. J., and Evans, F. C. (1954). Distance to nearest neighbor as a measure of spatial
+/// relationships in populations. Ecology, 35(4), 445-453.
+///
+/// # See Also
+/// `RipleysK`, `QuadratCount`
+pub struct NearestNeighbourIndex {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl NearestNeighbourIndex {
+    pub fn new() -> NearestNeighbourIndex {
+        // public constructor
+        let name = "NearestNeighbourIndex".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description = "Calculates the nearest-neighbour index of a set of vector points within a study area.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Study Area Polygon File (optional)".to_owned(),
+            flags: vec!["--boundary".to_owned()],
+            description: "Optional input vector polygon file of the study area.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output HTML File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output HTML report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=trees.shp --boundary=stand.shp -o=report.html",
+            short_exe, name
+        ).replace("*", &sep);
+
+        NearestNeighbourIndex {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for NearestNeighbourIndex {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut boundary_file = String::new();
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--boundary"])? {
+            boundary_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !boundary_file.is_empty() && !boundary_file.contains(&sep) && !boundary_file.contains("/")
+        {
+            boundary_file = format!("{}{}", working_directory, boundary_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !output_file.ends_with(".html") {
+            output_file.push_str(".html");
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let start = Instant::now();
+        let (points, study_area) = read_study_area_points(&input, &boundary_file)?;
+        let area = rings_area(&study_area);
+
+        let stats = match nearest_neighbour_index(&points, area) {
+            Some(s) => s,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "At least two points within a study area of non-zero area are required.",
+                ))
+            }
+        };
+        let distribution = Normal::new(0.0, 1.0).unwrap();
+        let p_value = 2f64 * (1f64 - distribution.cdf(stats.z_score.abs()));
+        let pattern = if p_value >= 0.05 {
+            "random"
+        } else if stats.ratio < 1f64 {
+            "clustered"
+        } else {
+            "dispersed"
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+
+        writer.write_all(&r#"<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+        <html>
+            <head>
+                <meta content=\"text/html; charset=iso-8859-1\" http-equiv=\"content-type\">
+                <title>Nearest-Neighbour Index Report</title>"#.as_bytes())?;
+
+        // get the style sheet
+        writer.write_all(&get_css().as_bytes())?;
+
+        writer.write_all(
+            &r#"
+            </head>
+            <body>
+                <h1>Nearest-Neighbour Index Report</h1>
+                "#.as_bytes(),
+        )?;
+
+        writer.write_all((format!("<p><strong>Input</strong>: {}</p>", input_file)).as_bytes())?;
+        writer.write_all(
+            (format!(
+                "<p><strong>Study area</strong>: {}</p>",
+                if boundary_file.is_empty() {
+                    "bounding box of the points"
+                } else {
+                    &boundary_file
+                }
+            )).as_bytes(),
+        )?;
+
+        writer.write_all("<p><table>".as_bytes())?;
+        writer.write_all("<tr><th>Statistic</th><th>Value</th></tr>".as_bytes())?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Number of points</td><td class=\"numberCell\">{}</td></tr>",
+                stats.num_points
+            ).as_bytes(),
+        )?;
+        for &(label, value) in &[
+            ("Study area", stats.area),
+            ("Observed mean distance", stats.observed_mean_distance),
+            ("Expected mean distance", stats.expected_mean_distance),
+            ("Nearest-neighbour index", stats.ratio),
+            ("Standard error", stats.standard_error),
+            ("Z-score", stats.z_score),
+            ("P-value (two-tailed)", p_value),
+        ] {
+            writer.write_all(
+                &format!(
+                    "<tr><td>{}</td><td class=\"numberCell\">{:.*}</td></tr>",
+                    label, 5, value
+                ).as_bytes(),
+            )?;
+        }
+        writer.write_all("</table></p>".as_bytes())?;
+        writer.write_all(
+            &format!(
+                "<p>The pattern is <strong>{}</strong> at the 95% confidence level.</p>",
+                pattern
+            ).as_bytes(),
+        )?;
+
+        writer.write_all("</body>".as_bytes())?;
+
+        let _ = writer.flush();
+
+        if verbose {
+            if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+                let output = Command::new("open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "windows") {
+                let output = Command::new("explorer.exe")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "linux") {
+                let output = Command::new("xdg-open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            }
+
+            report::message(&format!("Please see {} for output report.", output_file));
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the points of a point pattern and its study area, which is the union of the
+/// polygons in the boundary file or, if no boundary file is given, the bounding box of the
+/// points. Points outside of the study area are excluded.
+pub fn read_study_area_points(
+    input: &Shapefile,
+    boundary_file: &str,
+) -> Result<(Vec<Point2D>, Vec<Vec<Point2D>>), Error> {
+    let base_shape_type = input.header.shape_type.base_shape_type();
+    if base_shape_type != ShapeType::Point && base_shape_type != ShapeType::MultiPoint {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The input vector data must be of POINT base shape type.",
+        ));
+    }
+    let mut points = vec![];
+    for record_num in 0..input.num_records {
+        let record = input.get_record(record_num);
+        for p in &record.points {
+            points.push(Point2D::new(p.x, p.y));
+        }
+    }
+
+    if boundary_file.is_empty() {
+        let bb = BoundingBox::from_points(&points);
+        let study_area = vec![vec![
+            Point2D::new(bb.min_x, bb.min_y),
+            Point2D::new(bb.min_x, bb.max_y),
+            Point2D::new(bb.max_x, bb.max_y),
+            Point2D::new(bb.max_x, bb.min_y),
+            Point2D::new(bb.min_x, bb.min_y),
+        ]];
+        return Ok((points, study_area));
+    }
+
+    let polygons = Shapefile::read(boundary_file)?;
+    if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The boundary vector data must be of POLYGON base shape type.",
+        ));
+    }
+    let records: Vec<usize> = (0..polygons.num_records).collect();
+    let study_area = get_polygon_union(&polygons, &records);
+    let bb = get_rings_bounding_box(&study_area);
+    points.retain(|p| bb.is_point_in_box(p.x, p.y) && point_in_rings(p, &study_area));
+    Ok((points, study_area))
+}
+
+/// Returns the area of a set of rings, oriented such that outer rings are clockwise and
+/// holes counter-clockwise.
+pub fn rings_area(rings: &[Vec<Point2D>]) -> f64 {
+    let mut area = 0f64;
+    for ring in rings {
+        if ring.len() > 3 {
+            if is_clockwise_order(ring) {
+                area += polygon_area(ring);
+            } else {
+                area -= polygon_area(ring);
+            }
+        }
+    }
+    area
+}
+
+/// Returns the distance from a point to the nearest edge of a set of closed rings.
+pub fn distance_to_rings(p: &Point2D, rings: &[Vec<Point2D>]) -> f64 {
+    let mut min_dist = f64::INFINITY;
+    for ring in rings {
+        for i in 0..ring.len().saturating_sub(1) {
+            let (a, b) = (ring[i], ring[i + 1]);
+            let (dx, dy) = (b.x - a.x, b.y - a.y);
+            let len_sqr = dx * dx + dy * dy;
+            let t = if len_sqr > 0f64 {
+                (((p.x - a.x) * dx + (p.y - a.y) * dy) / len_sqr).max(0f64).min(1f64)
+            } else {
+                0f64
+            };
+            let d = p.distance(&Point2D::new(a.x + t * dx, a.y + t * dy));
+            if d < min_dist {
+                min_dist = d;
+            }
+        }
+    }
+    min_dist
+}
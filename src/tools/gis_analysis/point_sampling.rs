@@ -0,0 +1,131 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::{get_polygon_rings, get_rings_bounding_box};
+use algorithms::point_in_rings;
+use rand::Rng;
+use raster::Raster;
+use std::io::Error;
+use structures::{BoundingBox, Point2D};
+use vector::*;
+
+/// The maximum number of attempts made to place each random point within a polygon mask.
+const MAX_ATTEMPTS: usize = 100_000;
+
+/// A set of polygons within which points are generated.
+pub struct PolygonMask {
+    rings: Vec<Vec<Vec<Point2D>>>,
+    boxes: Vec<BoundingBox>,
+    // the running total of the areas of the polygons' bounding boxes
+    cumulative_area: Vec<f64>,
+}
+
+impl PolygonMask {
+    pub fn new(polygons: &Shapefile) -> PolygonMask {
+        let mut rings = Vec::with_capacity(polygons.num_records);
+        let mut boxes = Vec::with_capacity(polygons.num_records);
+        let mut cumulative_area = Vec::with_capacity(polygons.num_records);
+        let mut total = 0f64;
+        for record_num in 0..polygons.num_records {
+            let r = get_polygon_rings(polygons.get_record(record_num));
+            let bb = get_rings_bounding_box(&r);
+            if r.len() > 0 {
+                total += bb.get_width() * bb.get_height();
+            }
+            rings.push(r);
+            boxes.push(bb);
+            cumulative_area.push(total);
+        }
+        PolygonMask {
+            rings: rings,
+            boxes: boxes,
+            cumulative_area: cumulative_area,
+        }
+    }
+
+    /// Returns the bounding box of all of the polygons.
+    pub fn extent(&self) -> BoundingBox {
+        let mut bb = BoundingBox::default();
+        for i in 0..self.rings.len() {
+            if self.rings[i].len() > 0 {
+                bb.expand_to(self.boxes[i]);
+            }
+        }
+        bb
+    }
+
+    /// Returns the index of the first polygon that contains a point, if any.
+    pub fn containing_polygon(&self, x: f64, y: f64) -> Option<usize> {
+        let p = Point2D::new(x, y);
+        (0..self.rings.len())
+            .find(|&i| self.boxes[i].is_point_in_box(x, y) && point_in_rings(&p, &self.rings[i]))
+    }
+
+    /// Returns a point that is uniformly distributed over the area of the polygons, with the
+    /// index of the polygon that contains it, or None if a point could not be placed. A
+    /// polygon is chosen with a probability proportional to the area of its bounding box
+    /// and a point within the box is accepted if the polygon is the first that contains it,
+    /// such that areas where polygons overlap are not sampled more densely.
+    pub fn random_point<R: Rng>(&self, rng: &mut R) -> Option<(Point2D, usize)> {
+        let total = match self.cumulative_area.last() {
+            Some(&t) if t > 0f64 => t,
+            _ => return None,
+        };
+        for _ in 0..MAX_ATTEMPTS {
+            let a = rng.gen_range(0f64, total);
+            let i = match self.cumulative_area.iter().position(|&c| c > a) {
+                Some(i) => i,
+                None => continue,
+            };
+            let bb = self.boxes[i];
+            let x = rng.gen_range(bb.min_x, bb.max_x);
+            let y = rng.gen_range(bb.min_y, bb.max_y);
+            if self.containing_polygon(x, y) == Some(i) {
+                return Some((Point2D::new(x, y), i));
+            }
+        }
+        None
+    }
+}
+
+/// Returns the (row, column) of each of the valid, non-zero cells of a raster mask.
+pub fn mask_cells(mask: &Raster) -> Vec<(isize, isize)> {
+    let nodata = mask.configs.nodata;
+    let mut cells = vec![];
+    for row in 0..mask.configs.rows as isize {
+        for col in 0..mask.configs.columns as isize {
+            let z = mask.get_value(row, col);
+            if z != nodata && z != 0f64 {
+                cells.push((row, col));
+            }
+        }
+    }
+    cells
+}
+
+/// Creates a points file with a set of attribute fields.
+pub fn create_points_file(
+    file_name: &str,
+    projection: String,
+    fields: &[AttributeField],
+) -> Result<Shapefile, Error> {
+    let mut output = Shapefile::new(file_name, ShapeType::Point)?;
+    output.projection = projection;
+    for field in fields {
+        output.attributes.add_field(field);
+    }
+    Ok(output)
+}
+
+/// Adds a point and its attributes to a points file.
+pub fn add_point(output: &mut Shapefile, x: f64, y: f64, attributes: Vec<FieldData>) {
+    let mut sfg = ShapefileGeometry::new(ShapeType::Point);
+    sfg.add_point(Point2D::new(x, y));
+    output.add_record(sfg);
+    output.attributes.add_record(attributes, false);
+}
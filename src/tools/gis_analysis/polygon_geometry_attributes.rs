@@ -0,0 +1,423 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::get_polygon_rings;
+use algorithms::{
+    convex_hull, is_clockwise_order, minimum_bounding_box, polygon_area, polygon_perimeter,
+    MinimizationCriterion,
+};
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, Point2D};
+use tools::*;
+use vector::*;
+
+/// This tool calculates several geometric attributes of vector polygons, adding them to the
+/// vector's attribute table as the following new fields:
+///
+/// | Field      | Description                                                                 |
+/// |------------|-----------------------------------------------------------------------------|
+/// | AREA       | The polygon's area, excluding any holes.                                    |
+/// | PERIMETER  | The total length of the polygon's rings, including those of holes.          |
+/// | COMPACT    | The compactness ratio, `A / P`, as calculated by `CompactnessRatio`.        |
+/// | ISO_QUOT   | The isoperimetric quotient, `4 pi A / P^2`, which is 1 for a circle.        |
+/// | COMPLEXITY | The shape complexity index, `1 - A / Ah`, as in `ShapeComplexityIndex`.     |
+/// | MBB_LENGTH | The long-axis length of the minimum (area) bounding box.                    |
+/// | MBB_WIDTH  | The short-axis length of the minimum bounding box.                          |
+/// | ELONGATION | The elongation ratio, `1 - W / L`, as calculated by `ElongationRatio`.      |
+/// | ORIENT     | The orientation of the bounding box's long axis, in degrees from north.     |
+///
+/// Where `A` is the polygon's area, `P` its perimeter, `Ah` the area of its convex hull, and
+/// `L` and `W` the length and width of its minimum bounding box. The dominant orientation
+/// (ORIENT) is measured clockwise from north and ranges from 0 to 180 degrees, since it is an
+/// orientation rather than a direction.
+///
+/// Areas and lengths are planar and in the units of the vector's coordinate system, and so
+/// the vector should be in a projected coordinate system. If the vector is in geographic
+/// coordinates, i.e. longitude and latitude in degrees, the equal-area option
+/// (`--equal_area`) should be used. Each polygon is then projected, using a Lambert azimuthal
+/// equal-area projection of the WGS84 ellipsoid centred on the polygon, before its attributes
+/// are calculated. Areas are then measured in square metres, and are exact, while lengths are
+/// measured in metres and are highly accurate for all but continental-scale polygons. A
+/// warning is issued if the vector's projection file indicates a geographic coordinate
+/// system and the equal-area option has not been used.
+///
+/// # See Also
+/// `PolygonArea`, `PolygonPerimeter`, `CompactnessRatio`, `ShapeComplexityIndex`,
+/// `ElongationRatio`, `PatchOrientation`, `MinimumBoundingBox`
+pub struct PolygonGeometryAttributes {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl PolygonGeometryAttributes {
+    pub fn new() -> PolygonGeometryAttributes {
+        // public constructor
+        let name = "PolygonGeometryAttributes".to_string();
+        let toolbox = "GIS Analysis/Patch Shape Tools".to_string();
+        let description = "Calculates the area, perimeter, shape indices, bounding box dimensions, and orientation of vector polygons.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Polygon File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector polygon file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Use equal-area projection for geographic coordinates?".to_owned(),
+            flags: vec!["--equal_area".to_owned()],
+            description: "Project geographic (longitude, latitude) coordinates to an equal-area projection before measuring.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input=polygons.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input=lakes_wgs84.shp --equal_area",
+            short_exe, name
+        ).replace("*", &sep);
+
+        PolygonGeometryAttributes {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for PolygonGeometryAttributes {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        let equal_area = tool_args.get_flag(&["--equal_area"])?;
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of polygon type
+        if input.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POLYGON base shape type.",
+            ));
+        }
+
+        if equal_area {
+            let extent = BoundingBox::new(
+                input.header.x_min,
+                input.header.x_max,
+                input.header.y_min,
+                input.header.y_max,
+            );
+            if !BoundingBox::new(-180f64, 360f64, -90f64, 90f64).entirely_contains(extent) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The equal-area option requires geographic coordinates (longitude and latitude in degrees), but the vector's extent falls outside of the valid range.",
+                ));
+            }
+        } else {
+            let projection = input.projection.to_uppercase();
+            if projection.contains("GEOGCS[") && !projection.contains("PROJCS[") {
+                report::warning("The vector appears to be in geographic coordinates; areas and lengths will be in squared degrees and degrees. Consider using the --equal_area option.");
+            }
+        }
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&input_file, &input, input.header.shape_type, true)?;
+
+        // add the attributes
+        for &(name, width, decimals) in &[
+            ("AREA", 18u8, 4u8),
+            ("PERIMETER", 16u8, 4u8),
+            ("COMPACT", 14u8, 6u8),
+            ("ISO_QUOT", 10u8, 6u8),
+            ("COMPLEXITY", 10u8, 6u8),
+            ("MBB_LENGTH", 16u8, 4u8),
+            ("MBB_WIDTH", 16u8, 4u8),
+            ("ELONGATION", 10u8, 6u8),
+            ("ORIENT", 10u8, 4u8),
+        ] {
+            output.attributes.add_field(&AttributeField::new(
+                name,
+                FieldDataType::Real,
+                width,
+                decimals,
+            ));
+        }
+
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let mut rings = get_polygon_rings(record);
+            if equal_area {
+                let bb = record.get_bounding_box();
+                let projection = LambertEqualArea::new(
+                    (bb.min_x + bb.max_x) / 2f64,
+                    (bb.min_y + bb.max_y) / 2f64,
+                );
+                for ring in rings.iter_mut() {
+                    for p in ring.iter_mut() {
+                        *p = projection.forward(p.x, p.y);
+                    }
+                }
+            }
+            let atts = calculate_attributes(&rings);
+
+            let record_out = record.clone();
+            output.add_record(record_out);
+
+            let mut rec = input.attributes.get_record(record_num);
+            for v in &atts {
+                rec.push(match *v {
+                    Some(v) => FieldData::Real(v),
+                    None => FieldData::Null,
+                });
+            }
+            output.attributes.add_record(rec, false);
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Calculates the attributes of a polygon, given its rings, in the order in which the
+/// fields are added to the attribute table. Attributes that are undefined, e.g. the
+/// ratios of a polygon without area, are None.
+fn calculate_attributes(rings: &[Vec<Point2D>]) -> Vec<Option<f64>> {
+    let mut area = 0f64;
+    let mut perimeter = 0f64;
+    let mut hull_area = 0f64;
+    let mut points = vec![];
+    for ring in rings {
+        // the rings are closed, and the area and perimeter functions close them too
+        if ring.len() < 4 {
+            continue;
+        }
+        let ring = &ring[..ring.len() - 1];
+        if is_clockwise_order(ring) {
+            area += polygon_area(ring);
+            hull_area += polygon_area(&convex_hull(&mut ring.to_vec()));
+            points.extend_from_slice(ring);
+        } else {
+            area -= polygon_area(ring);
+        }
+        perimeter += polygon_perimeter(ring);
+    }
+    if points.len() < 3 || area <= 0f64 {
+        let mut atts = vec![Some(area.max(0f64)), Some(perimeter)];
+        atts.extend(vec![None; 7]);
+        return atts;
+    }
+
+    let mbb = minimum_bounding_box(&mut points, MinimizationCriterion::Area);
+    let (side1, side2) = (mbb[0].distance(&mbb[1]), mbb[1].distance(&mbb[2]));
+    let (length, width) = (side1.max(side2), side1.min(side2));
+    let (a, b) = if side1 >= side2 {
+        (mbb[0], mbb[1])
+    } else {
+        (mbb[1], mbb[2])
+    };
+    // the azimuth of the long axis, folded into the range [0, 180)
+    let mut orientation = (b.x - a.x).atan2(b.y - a.y).to_degrees();
+    while orientation < 0f64 {
+        orientation += 180f64;
+    }
+    while orientation >= 180f64 {
+        orientation -= 180f64;
+    }
+
+    vec![
+        Some(area),
+        Some(perimeter),
+        Some(area / perimeter),
+        Some(4f64 * PI * area / (perimeter * perimeter)),
+        Some(1f64 - area / hull_area),
+        Some(length),
+        Some(width),
+        Some(1f64 - width / length),
+        Some(orientation),
+    ]
+}
+
+/// The Lambert azimuthal equal-area projection of the WGS84 ellipsoid, centred on a given
+/// longitude and latitude. Geodetic latitudes are converted to authalic latitudes on a
+/// sphere of equal surface area, which is then projected, such that areas are preserved
+/// exactly (Snyder, 1987, p. 187).
+struct LambertEqualArea {
+    lon0: f64,
+    sin_beta0: f64,
+    cos_beta0: f64,
+    e: f64,
+    qp: f64,
+    rq: f64,
+}
+
+impl LambertEqualArea {
+    fn new(lon0: f64, lat0: f64) -> LambertEqualArea {
+        let a = 6378137f64;
+        let f = 1f64 / 298.257223563f64;
+        let e = (f * (2f64 - f)).sqrt();
+        let qp = authalic_q(1f64, e);
+        let beta0 = (authalic_q(lat0.to_radians().sin(), e) / qp).max(-1f64).min(1f64).asin();
+        LambertEqualArea {
+            lon0: lon0.to_radians(),
+            sin_beta0: beta0.sin(),
+            cos_beta0: beta0.cos(),
+            e: e,
+            qp: qp,
+            rq: a * (qp / 2f64).sqrt(),
+        }
+    }
+
+    /// Projects a longitude and latitude, in degrees, to planar coordinates in metres.
+    fn forward(&self, lon: f64, lat: f64) -> Point2D {
+        let beta = (authalic_q(lat.to_radians().sin(), self.e) / self.qp)
+            .max(-1f64)
+            .min(1f64)
+            .asin();
+        let (sin_beta, cos_beta) = beta.sin_cos();
+        let dlon = lon.to_radians() - self.lon0;
+        let (sin_dlon, cos_dlon) = dlon.sin_cos();
+        let denom = 1f64 + self.sin_beta0 * sin_beta + self.cos_beta0 * cos_beta * cos_dlon;
+        let k = if denom > 0f64 {
+            (2f64 / denom).sqrt()
+        } else {
+            0f64 // the antipode of the centre, which cannot be projected
+        };
+        Point2D::new(
+            self.rq * k * cos_beta * sin_dlon,
+            self.rq * k * (self.cos_beta0 * sin_beta - self.sin_beta0 * cos_beta * cos_dlon),
+        )
+    }
+}
+
+/// The function q of the sine of the geodetic latitude, used to calculate authalic latitudes
+/// (Snyder, 1987, eq. 3-12).
+fn authalic_q(sin_lat: f64, e: f64) -> f64 {
+    let e_sin = e * sin_lat;
+    (1f64 - e * e)
+        * (sin_lat / (1f64 - e_sin * e_sin)
+            - (1f64 / (2f64 * e)) * ((1f64 - e_sin) / (1f64 + e_sin)).ln())
+}
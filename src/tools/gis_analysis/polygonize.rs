@@ -148,29 +148,12 @@ impl WhiteboxTool for Polygonize {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val.contains("-input") {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -180,9 +163,7 @@ impl WhiteboxTool for Polygonize {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !output_file.contains(&sep) && !output_file.contains("/") {
@@ -207,7 +188,7 @@ impl WhiteboxTool for Polygonize {
         let mut bb: Vec<BoundingBox> = vec![];
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let mut projection = String::new();
         for value in vec {
@@ -294,7 +275,7 @@ impl WhiteboxTool for Polygonize {
                 progress =
                     (100.0_f64 * (record_num1 + 1) as f64 / in_polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding line intersections: {}%", progress);
+                    report::progress("Finding line intersections", progress);
                     old_progress = progress;
                 }
             }
@@ -318,7 +299,7 @@ impl WhiteboxTool for Polygonize {
         let mut p2: Point2D;
         let mut p3: Point2D;
         let mut p4: Point2D;
-        println!("Creating tree...");
+        report::message("Creating tree...");
         for i in 0..polylines.len() {
             p1 = polylines[i].first_vertex();
             kdtree.add([p1.x, p1.y], first_node_id(i)).unwrap();
@@ -329,7 +310,7 @@ impl WhiteboxTool for Polygonize {
 
         // Find the neighbours of each endnode and check for dangling arcs
         // and self-closing arcs which form single-line polys.
-        println!("Finding node vertices...");
+        report::message("Finding node vertices...");
         let mut is_acyclic_arc = vec![false; polylines.len()];
         let mut node_angles: Vec<Vec<f64>> = vec![vec![]; num_endnodes];
         let mut heading: f64;
@@ -455,7 +436,7 @@ impl WhiteboxTool for Polygonize {
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding acyclic arcs: {}%", progress);
+                    report::progress("Finding acyclic arcs", progress);
                     old_progress = progress;
                 }
             }
@@ -774,7 +755,7 @@ impl WhiteboxTool for Polygonize {
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding polygons: {}%", progress);
+                    report::progress("Finding polygons", progress);
                     old_progress = progress;
                 }
             }
@@ -1178,11 +1159,11 @@ impl WhiteboxTool for Polygonize {
         // }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -1190,7 +1171,7 @@ impl WhiteboxTool for Polygonize {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
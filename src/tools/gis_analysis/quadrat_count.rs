@@ -0,0 +1,347 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use self::statrs::distribution::{ChiSquared, Univariate};
+use super::clip::get_rings_bounding_box;
+use super::nearest_neighbour_index::read_study_area_points;
+use algorithms::{point_in_rings, quadrat_statistics};
+use raster::*;
+use statrs;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Point2D;
+use tools::*;
+use vector::*;
+
+/// This tool counts the number of vector points falling within each of a grid of square
+/// quadrats covering a study area, outputting the counts as a raster (`--output`), the grid
+/// cells of which are the quadrats (`--cell_size`). If `--density` is specified, the counts are
+/// divided by the quadrat area, giving the density of points in points per squared map unit.
+/// The study area is specified by an optional polygon vector (`--boundary`), the union of
+/// whose polygons is used; points outside of the study area are ignored. If no boundary is
+/// specified, the bounding box of the points is used. The grid of quadrats starts at the
+/// north-west corner of the study area. If a boundary is specified, the quadrats whose centres
+/// lie outside of it are assigned NoData and are not included in the statistics.
+///
+/// The tool also tests the counts for departure from complete spatial randomness using the
+/// variance-to-mean ratio (VMR) of the counts, which is 1 for a random (Poisson) pattern,
+/// greater than 1 for a clustered pattern, and less than 1 for a dispersed pattern. The
+/// chi-square statistic, `(m - 1) VMR`, where `m` is the number of quadrats, is compared to
+/// the chi-square distribution with `m - 1` degrees of freedom to give a two-tailed p-value.
+/// The result of the test depends on the quadrat size, which should generally be such that
+/// the mean count is at least one or two points. The statistics are reported and are
+/// stored in the output raster's metadata.
+///
+/// # See Also
+/// `NearestNeighbourIndex`, `RipleysK`, `VectorPointsToRaster`
+pub struct QuadratCount {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl QuadratCount {
+    pub fn new() -> QuadratCount {
+        // public constructor
+        let name = "QuadratCount".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description = "Counts vector points within a grid of quadrats and tests the counts for randomness.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Study Area Polygon File (optional)".to_owned(),
+            flags: vec!["--boundary".to_owned()],
+            description: "Optional input vector polygon file of the study area.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Quadrat Size".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Quadrat (grid cell) size.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output density?".to_owned(),
+            flags: vec!["--density".to_owned()],
+            description: "Output the density of points, rather than the counts.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=trees.shp --boundary=stand.shp -o=counts.tif --cell_size=25.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        QuadratCount {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for QuadratCount {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut boundary_file = String::new();
+        let mut output_file = String::new();
+        let mut cell_size = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--boundary"])? {
+            boundary_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            cell_size = v;
+        }
+        let density = tool_args.get_flag(&["--density"])?;
+        if cell_size <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A positive quadrat size (--cell_size) must be specified.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !boundary_file.is_empty() && !boundary_file.contains(&sep) && !boundary_file.contains("/")
+        {
+            boundary_file = format!("{}{}", working_directory, boundary_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let start = Instant::now();
+        let (points, study_area) = read_study_area_points(&input, &boundary_file)?;
+
+        // base the output raster on the cell size and the extent of the study area
+        let bb = get_rings_bounding_box(&study_area);
+        let west = bb.min_x;
+        let north = bb.max_y;
+        let rows = (((north - bb.min_y) / cell_size).ceil() as usize).max(1);
+        let columns = (((bb.max_x - west) / cell_size).ceil() as usize).max(1);
+        let nodata = -32768f64;
+
+        let mut configs = RasterConfigs {
+            ..Default::default()
+        };
+        configs.rows = rows;
+        configs.columns = columns;
+        configs.north = north;
+        configs.south = north - rows as f64 * cell_size;
+        configs.east = west + columns as f64 * cell_size;
+        configs.west = west;
+        configs.resolution_x = cell_size;
+        configs.resolution_y = cell_size;
+        configs.nodata = nodata;
+        configs.data_type = DataType::F32;
+        configs.photometric_interp = PhotometricInterpretation::Continuous;
+        configs.projection = input.projection.clone();
+
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+        let out_nodata = output.configs.nodata;
+
+        let mut counts = vec![0f64; rows * columns];
+        for p in &points {
+            let row = (((north - p.y) / cell_size).floor() as usize).min(rows - 1);
+            let col = (((p.x - west) / cell_size).floor() as usize).min(columns - 1);
+            counts[row * columns + col] += 1f64;
+        }
+
+        let quadrat_area = cell_size * cell_size;
+        let mut quadrat_counts = vec![];
+        let mut num_points_counted = 0f64;
+        for row in 0..rows {
+            let y = north - (row as f64 + 0.5) * cell_size;
+            let mut data = vec![out_nodata; columns];
+            for col in 0..columns {
+                let x = west + (col as f64 + 0.5) * cell_size;
+                if boundary_file.is_empty() || point_in_rings(&Point2D::new(x, y), &study_area) {
+                    let count = counts[row * columns + col];
+                    quadrat_counts.push(count);
+                    num_points_counted += count;
+                    data[col] = if density { count / quadrat_area } else { count };
+                }
+            }
+            output.set_row_data(row as isize, data);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Quadrat size: {}", cell_size));
+
+        report::metadata("Number of quadrats", &quadrat_counts.len().to_string());
+        report::message(&format!(
+            "Number of points in quadrats: {} of {}",
+            num_points_counted,
+            points.len()
+        ));
+        match quadrat_statistics(&quadrat_counts) {
+            Some(stats) => {
+                let distribution = ChiSquared::new(stats.degrees_of_freedom as f64).unwrap();
+                let cdf = distribution.cdf(stats.chi_square);
+                let p_value = (2f64 * cdf.min(1f64 - cdf)).min(1f64);
+                let pattern = if p_value >= 0.05 {
+                    "random"
+                } else if stats.variance_mean_ratio > 1f64 {
+                    "clustered"
+                } else {
+                    "dispersed"
+                };
+                for &(label, value) in &[
+                    ("Mean count", stats.mean),
+                    ("Variance", stats.variance),
+                    ("Variance-to-mean ratio", stats.variance_mean_ratio),
+                    ("Chi-square", stats.chi_square),
+                    ("P-value (two-tailed)", p_value),
+                ] {
+                    report::message(&format!("{}: {:.5}", label, value));
+                    output.add_metadata_entry(format!("{}: {}", label, value));
+                }
+                report::metadata("Degrees of freedom", &stats.degrees_of_freedom.to_string());
+                report::message(&format!(
+                    "The pattern is {} at the 95% confidence level.",
+                    pattern
+                ));
+            }
+            None => report::warning("At least two quadrats and one point are required to test the pattern."),
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
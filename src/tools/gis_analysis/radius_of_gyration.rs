@@ -141,37 +141,19 @@ impl WhiteboxTool for RadiusOfGyration {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-text_output" {
-                text_output = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--text_output"])? {
+            text_output = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -187,7 +169,7 @@ impl WhiteboxTool for RadiusOfGyration {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -245,7 +227,7 @@ impl WhiteboxTool for RadiusOfGyration {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
+                    report::progress("Progress (Loop 1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -298,7 +280,7 @@ impl WhiteboxTool for RadiusOfGyration {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
+                    report::progress("Progress (Loop 2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -319,24 +301,24 @@ impl WhiteboxTool for RadiusOfGyration {
                 if z > 0f64 && z != nodata {
                     a = (z - min_val as f64) as usize;
                     output.set_value(row, col, gyradius[a]);
-                } else {
+                } else if z != nodata {
                     output.set_value(row, col, z);
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
+                    report::progress("Progress (Loop 3 of 3)", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if text_output {
-            println!("Patch Radius of Gyration\nPatch ID\tValue");
+            report::message("Patch Radius of Gyration\nPatch ID\tValue");
             for a in 0..range + 1 {
                 if total_n[a] > 0 {
-                    println!("{:.0}\t{:.4}", (a + min_val), gyradius[a]);
+                    report::message(&format!("{:.0}\t{:.4}", (a + min_val), gyradius[a]));
                 }
             }
         }
@@ -352,20 +334,17 @@ impl WhiteboxTool for RadiusOfGyration {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,277 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::point_sampling::{add_point, create_points_file, mask_cells, PolygonMask};
+use rand::prelude::*;
+use rand::seq::sample_indices;
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool generates randomly located points within a mask, which may be either a raster
+/// or a vector polygon file (`--input`). The output vector points file (`--output`) contains
+/// the specified number of points (`--num_points`), which is useful for creating the
+/// training and validation data of classification models and for field sampling designs.
+///
+/// If the mask is a raster, points are placed at the centres of randomly selected grid cells,
+/// sampled without replacement from the valid, non-zero cells of the mask, and the value of
+/// each point's cell is recorded in a VALUE attribute. The number of points may therefore
+/// not exceed the number of such cells. If the mask is a polygon file, points are uniformly
+/// distributed over the area of the polygons, and the record number of the polygon that
+/// contains each point is recorded in a POLY_ID attribute.
+///
+/// # See Also
+/// `StratifiedRandomPoints`, `RegularGridPoints`, `RandomSample`
+pub struct RandomPoints {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RandomPoints {
+    pub fn new() -> RandomPoints {
+        // public constructor
+        let name = "RandomPoints".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Generates randomly located points within a raster or polygon mask.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Raster or Polygon Mask File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster or vector polygon mask file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Points File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Points".to_owned(),
+            flags: vec!["--num_points".to_owned()],
+            description: "Number of random points.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("100".to_owned()),
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=mask.tif -o=points.shp --num_points=500
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=study_area.shp -o=points.shp --num_points=500",
+            short_exe, name
+        ).replace("*", &sep);
+
+        RandomPoints {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RandomPoints {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut num_points = 100usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_points"])? {
+            num_points = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let mut rng = thread_rng();
+        let fid = AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8);
+        let start: Instant;
+        let mut output: Shapefile;
+        if input_file.to_lowercase().ends_with(".shp") {
+            let polygons = Shapefile::read(&input_file)?;
+            start = Instant::now();
+            if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input vector data must be of polygon base shape type.",
+                ));
+            }
+            let mask = PolygonMask::new(&polygons);
+            output = create_points_file(
+                &output_file,
+                polygons.projection.clone(),
+                &[
+                    fid,
+                    AttributeField::new("POLY_ID", FieldDataType::Int, 7u8, 0u8),
+                ],
+            )?;
+            for i in 0..num_points {
+                match mask.random_point(&mut rng) {
+                    Some((p, poly)) => add_point(
+                        &mut output,
+                        p.x,
+                        p.y,
+                        vec![
+                            FieldData::Int(i as i32 + 1),
+                            FieldData::Int(poly as i32 + 1),
+                        ],
+                    ),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "Random points could not be placed within the input polygons.",
+                        ))
+                    }
+                }
+            }
+        } else {
+            let mask = Raster::new(&input_file, "r")?;
+            start = Instant::now();
+            let cells = mask_cells(&mask);
+            if num_points > cells.len() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The number of points exceeds the number of valid, non-zero cells in the mask ({}).",
+                        cells.len()
+                    ),
+                ));
+            }
+            output = create_points_file(
+                &output_file,
+                mask.configs.coordinate_ref_system_wkt.clone(),
+                &[
+                    fid,
+                    AttributeField::new("VALUE", FieldDataType::Real, 12u8, 4u8),
+                ],
+            )?;
+            for (i, c) in sample_indices(&mut rng, cells.len(), num_points)
+                .into_iter()
+                .enumerate()
+            {
+                let (row, col) = cells[c];
+                add_point(
+                    &mut output,
+                    mask.get_x_from_column(col),
+                    mask.get_y_from_row(row),
+                    vec![
+                        FieldData::Int(i as i32 + 1),
+                        FieldData::Real(mask.get_value(row, col)),
+                    ],
+                );
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -139,46 +139,22 @@ impl WhiteboxTool for Reclass {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-reclass_vals"
-                || vec[0].to_lowercase() == "--reclass_vals"
-            {
-                if keyval {
-                    reclass_str = vec[1].to_string();
-                } else {
-                    reclass_str = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-assign_mode"
-                || vec[0].to_lowercase() == "--assign_mode"
-            {
-                assign_mode = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--reclass_vals"])? {
+            reclass_str = v;
+        }
+        if tool_args.get_flag(&["--assign_mode"])? {
+            assign_mode = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let mut v: Vec<&str> = reclass_str.split(";").collect();
@@ -219,7 +195,7 @@ impl WhiteboxTool for Reclass {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -232,6 +208,7 @@ impl WhiteboxTool for Reclass {
         let (tx, rx) = mpsc::channel();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         if !assign_mode {
             for tid in 0..num_procs {
@@ -241,7 +218,7 @@ impl WhiteboxTool for Reclass {
                 thread::spawn(move || {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         let mut prev_idx: usize = num_ranges;
                         for col in 0..columns {
                             z = input[(row, col)];
@@ -284,7 +261,7 @@ impl WhiteboxTool for Reclass {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -314,7 +291,7 @@ impl WhiteboxTool for Reclass {
                 thread::spawn(move || {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z = input[(row, col)];
                             if z != nodata {
@@ -339,7 +316,7 @@ impl WhiteboxTool for Reclass {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -356,20 +333,17 @@ impl WhiteboxTool for Reclass {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -148,55 +148,25 @@ impl WhiteboxTool for ReclassEqualInterval {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-interval" || vec[0].to_lowercase() == "--interval"
-            {
-                if keyval {
-                    interval_size = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    interval_size = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-start_val"
-                || vec[0].to_lowercase() == "--start_val"
-            {
-                if keyval {
-                    start_val = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    start_val = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-end_val" || vec[0].to_lowercase() == "--end_val" {
-                if keyval {
-                    end_val = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    end_val = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--interval"])? {
+            interval_size = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--start_val"])? {
+            start_val = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--end_val"])? {
+            end_val = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -212,7 +182,7 @@ impl WhiteboxTool for ReclassEqualInterval {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -220,6 +190,8 @@ impl WhiteboxTool for ReclassEqualInterval {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         if start_val == f64::NEG_INFINITY {
             start_val = input.configs.minimum;
         }
@@ -235,7 +207,7 @@ impl WhiteboxTool for ReclassEqualInterval {
             thread::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input[(row, col)];
                         if z != nodata {
@@ -250,7 +222,6 @@ impl WhiteboxTool for ReclassEqualInterval {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
@@ -258,7 +229,7 @@ impl WhiteboxTool for ReclassEqualInterval {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -273,20 +244,17 @@ impl WhiteboxTool for ReclassEqualInterval {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
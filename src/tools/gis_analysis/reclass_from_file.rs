@@ -134,42 +134,19 @@ impl WhiteboxTool for ReclassFromFile {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-reclass_file"
-                || vec[0].to_lowercase() == "--reclass_file"
-            {
-                if keyval {
-                    reclass_file = vec[1].to_string();
-                } else {
-                    reclass_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--reclass_file"])? {
+            reclass_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -228,7 +205,7 @@ impl WhiteboxTool for ReclassFromFile {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -241,6 +218,7 @@ impl WhiteboxTool for ReclassFromFile {
         let (tx, rx) = mpsc::channel();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         if !assign_mode {
             for tid in 0..num_procs {
@@ -250,7 +228,7 @@ impl WhiteboxTool for ReclassFromFile {
                 thread::spawn(move || {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         let mut prev_idx: usize = num_ranges;
                         for col in 0..columns {
                             z = input[(row, col)];
@@ -293,7 +271,7 @@ impl WhiteboxTool for ReclassFromFile {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -323,7 +301,7 @@ impl WhiteboxTool for ReclassFromFile {
                 thread::spawn(move || {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z = input[(row, col)];
                             if z != nodata {
@@ -348,7 +326,7 @@ impl WhiteboxTool for ReclassFromFile {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -365,20 +343,17 @@ impl WhiteboxTool for ReclassFromFile {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,283 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::point_sampling::{add_point, create_points_file, PolygonMask};
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool generates points on a regular grid within a mask, which may be either a raster
+/// or a vector polygon file (`--input`), for use as systematic sampling locations. The grid
+/// spacing (`--spacing`), in map units, is the same in the x and y directions, and the first
+/// point lies half of the spacing from the north-west corner of the mask's extent. Points
+/// are only retained where they overlie the mask, i.e. a valid, non-zero grid cell of a raster
+/// mask or one of the polygons of a vector mask.
+///
+/// Each point in the output vector points file (`--output`) records the row and column of
+/// its position in the grid (ROW and COLUMN) and either the value of the underlying raster
+/// cell (VALUE) or the record number of the polygon that contains it (POLY_ID).
+///
+/// # See Also
+/// `RandomPoints`, `StratifiedRandomPoints`, `CreateRectangularVectorGrid`
+pub struct RegularGridPoints {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RegularGridPoints {
+    pub fn new() -> RegularGridPoints {
+        // public constructor
+        let name = "RegularGridPoints".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Generates points on a regular grid within a raster or polygon mask.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Raster or Polygon Mask File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster or vector polygon mask file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Points File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Grid Spacing".to_owned(),
+            flags: vec!["--spacing".to_owned()],
+            description: "Spacing between grid points, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=study_area.shp -o=points.shp --spacing=250.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        RegularGridPoints {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RegularGridPoints {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut spacing = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--spacing"])? {
+            spacing = v;
+        }
+        if spacing <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The grid spacing must be greater than zero.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let fields = vec![
+            AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8),
+            AttributeField::new("ROW", FieldDataType::Int, 7u8, 0u8),
+            AttributeField::new("COLUMN", FieldDataType::Int, 7u8, 0u8),
+        ];
+        let start: Instant;
+        let mut output: Shapefile;
+        // Returns the value that is recorded for a grid point, if it overlies the mask.
+        let mask_value: Box<Fn(f64, f64) -> Option<FieldData>>;
+        let (west, north, east, south): (f64, f64, f64, f64);
+        if input_file.to_lowercase().ends_with(".shp") {
+            let polygons = Shapefile::read(&input_file)?;
+            start = Instant::now();
+            if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input vector data must be of polygon base shape type.",
+                ));
+            }
+            let mut f = fields.clone();
+            f.push(AttributeField::new("POLY_ID", FieldDataType::Int, 7u8, 0u8));
+            output = create_points_file(&output_file, polygons.projection.clone(), &f)?;
+            let mask = PolygonMask::new(&polygons);
+            let extent = mask.extent();
+            west = extent.min_x;
+            north = extent.max_y;
+            east = extent.max_x;
+            south = extent.min_y;
+            mask_value = Box::new(move |x: f64, y: f64| {
+                mask.containing_polygon(x, y)
+                    .map(|i| FieldData::Int(i as i32 + 1))
+            });
+        } else {
+            let mask = Raster::new(&input_file, "r")?;
+            start = Instant::now();
+            let mut f = fields.clone();
+            f.push(AttributeField::new("VALUE", FieldDataType::Real, 12u8, 4u8));
+            output = create_points_file(
+                &output_file,
+                mask.configs.coordinate_ref_system_wkt.clone(),
+                &f,
+            )?;
+            west = mask.configs.west;
+            north = mask.configs.north;
+            east = mask.configs.east;
+            south = mask.configs.south;
+            mask_value = Box::new(move |x: f64, y: f64| {
+                let z = mask.get_value(mask.get_row_from_y(y), mask.get_column_from_x(x));
+                if z != mask.configs.nodata && z != 0f64 {
+                    Some(FieldData::Real(z))
+                } else {
+                    None
+                }
+            });
+        }
+
+        let rows = ((north - south) / spacing).ceil().max(1f64) as usize;
+        let columns = ((east - west) / spacing).ceil().max(1f64) as usize;
+        let mut fid = 1;
+        for row in 0..rows {
+            let y = north - (row as f64 + 0.5) * spacing;
+            for col in 0..columns {
+                let x = west + (col as f64 + 0.5) * spacing;
+                if let Some(value) = mask_value(x, y) {
+                    add_point(
+                        &mut output,
+                        x,
+                        y,
+                        vec![
+                            FieldData::Int(fid),
+                            FieldData::Int(row as i32),
+                            FieldData::Int(col as i32),
+                            value,
+                        ],
+                    );
+                    fid += 1;
+                }
+            }
+        }
+        if verbose {
+            report::metadata("Number of points", &(fid - 1).to_string());
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
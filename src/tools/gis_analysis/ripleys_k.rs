@@ -0,0 +1,360 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::get_rings_bounding_box;
+use super::nearest_neighbour_index::{distance_to_rings, read_study_area_points, rings_area};
+use algorithms::{point_in_rings, ripleys_k, ripleys_l};
+use rand::prelude::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+use std::path;
+use structures::Point2D;
+use tools::*;
+use vector::*;
+
+/// This tool estimates Ripley's K function, and Besag's L function, of a set of vector points
+/// within a study area. The K function, `K(d)`, is the expected number of further points within
+/// a distance `d` of a typical point, divided by the density of points. Unlike the
+/// nearest-neighbour index (`NearestNeighbourIndex`), which only measures the spacing of
+/// neighbouring points, the K function describes the pattern at a range of scales; a pattern
+/// may, for example, be dispersed at short distances and clustered at longer distances. For a
+/// random (Poisson) pattern, `K(d)` is `pi d^2` and the L function, `L(d) = sqrt(K(d) / pi)`,
+/// equals `d`. Values of `L(d) - d` greater than zero therefore indicate clustering at the
+/// distance `d`, and values less than zero indicate dispersion.
+///
+/// The functions are estimated at a number of equally spaced distances (`--num_distances`) up
+/// to a maximum distance (`--max_distance`), which defaults to a quarter of the shorter side of
+/// the study area's bounding box. The border edge correction is applied, i.e. at each distance
+/// only those points that are at least that distance from the edge of the study area are used
+/// as the centres about which neighbouring points are counted. The study area is specified by
+/// an optional polygon vector (`--boundary`), the union of whose polygons is used; points
+/// outside of the study area are ignored. If no boundary is specified, the bounding box of the
+/// points is used.
+///
+/// The significance of departures from complete spatial randomness may be assessed using
+/// simulation envelopes. If a number of simulations (`--num_sims`) is specified, that number
+/// of random patterns, each with the same number of points as the input pattern, are generated
+/// within the study area, and the minimum and maximum values of the L function of the random
+/// patterns are reported at each distance. For 99 simulations, an observed L function
+/// falling outside of the envelope indicates a significant departure from randomness at the
+/// 0.01 level (one-tailed) at that distance.
+///
+/// The output is a CSV file (`--output`), with the fields DISTANCE, K, L, and L_MINUS_D, and,
+/// if simulations are performed, L_MIN and L_MAX.
+///
+/// # Reference
+/// Ripley, B. D. (1977). Modelling spatial patterns. Journal of the Royal Statistical Society,
+/// Series B, 39(2), 172-212.
+///
+/// # See Also
+/// `NearestNeighbourIndex`, `QuadratCount`
+pub struct RipleysK {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RipleysK {
+    pub fn new() -> RipleysK {
+        // public constructor
+        let name = "RipleysK".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Estimates Ripley's K and L functions of a set of vector points within a study area."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Study Area Polygon File (optional)".to_owned(),
+            flags: vec!["--boundary".to_owned()],
+            description: "Optional input vector polygon file of the study area.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Distance (optional)".to_owned(),
+            flags: vec!["--max_distance".to_owned()],
+            description: "Maximum distance; defaults to a quarter of the shorter side of the study area's bounding box.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Distances".to_owned(),
+            flags: vec!["--num_distances".to_owned()],
+            description: "Number of distances at which the functions are estimated.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("20".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Simulations".to_owned(),
+            flags: vec!["--num_sims".to_owned()],
+            description: "Number of random patterns simulated to calculate envelopes; 0 for none."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=trees.shp --boundary=stand.shp -o=k.csv --max_distance=50.0 --num_sims=99",
+            short_exe, name
+        ).replace("*", &sep);
+
+        RipleysK {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RipleysK {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut boundary_file = String::new();
+        let mut output_file = String::new();
+        let mut max_distance = 0f64;
+        let mut num_distances = 20usize;
+        let mut num_sims = 0usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--boundary"])? {
+            boundary_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_distance"])? {
+            max_distance = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_distances"])? {
+            num_distances = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_sims"])? {
+            num_sims = v;
+        }
+        if num_distances < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of distances must be at least one.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !boundary_file.is_empty() && !boundary_file.contains(&sep) && !boundary_file.contains("/")
+        {
+            boundary_file = format!("{}{}", working_directory, boundary_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let start = Instant::now();
+        let (points, study_area) = read_study_area_points(&input, &boundary_file)?;
+        let area = rings_area(&study_area);
+        if points.len() < 2 || area <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least two points within a study area of non-zero area are required.",
+            ));
+        }
+
+        let bb = get_rings_bounding_box(&study_area);
+        if max_distance <= 0f64 {
+            max_distance = bb.get_width().min(bb.get_height()) / 4f64;
+        }
+        let distances: Vec<f64> = (1..num_distances + 1)
+            .map(|i| max_distance * i as f64 / num_distances as f64)
+            .collect();
+
+        if verbose {
+            report::message("Calculating the K function...");
+        }
+        let edge_distances: Vec<f64> = points
+            .iter()
+            .map(|p| distance_to_rings(p, &study_area))
+            .collect();
+        let k = ripleys_k(&points, area, Some(&edge_distances), &distances);
+
+        let mut l_min = vec![f64::INFINITY; num_distances];
+        let mut l_max = vec![f64::NEG_INFINITY; num_distances];
+        if num_sims > 0 {
+            let mut rng = thread_rng();
+            let mut progress: usize;
+            let mut old_progress: usize = 1;
+            for sim in 0..num_sims {
+                let mut random_points = Vec::with_capacity(points.len());
+                while random_points.len() < points.len() {
+                    let p = Point2D::new(
+                        rng.gen_range(bb.min_x, bb.max_x),
+                        rng.gen_range(bb.min_y, bb.max_y),
+                    );
+                    if point_in_rings(&p, &study_area) {
+                        random_points.push(p);
+                    }
+                }
+                let edge_distances: Vec<f64> = random_points
+                    .iter()
+                    .map(|p| distance_to_rings(p, &study_area))
+                    .collect();
+                let sim_k = ripleys_k(&random_points, area, Some(&edge_distances), &distances);
+                for i in 0..num_distances {
+                    let l = ripleys_l(sim_k[i]);
+                    if l < l_min[i] {
+                        l_min[i] = l;
+                    }
+                    if l > l_max[i] {
+                        l_max[i] = l;
+                    }
+                }
+
+                if verbose {
+                    progress = (100.0_f64 * (sim + 1) as f64 / num_sims as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Simulating random patterns", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+        }
+
+        let f = File::create(&output_file)?;
+        let mut writer = BufWriter::new(f);
+        if num_sims > 0 {
+            writer.write_all("DISTANCE,K,L,L_MINUS_D,L_MIN,L_MAX\n".as_bytes())?;
+        } else {
+            writer.write_all("DISTANCE,K,L,L_MINUS_D\n".as_bytes())?;
+        }
+        for i in 0..num_distances {
+            let l = ripleys_l(k[i]);
+            let mut line = format!("{},{},{},{}", distances[i], k[i], l, l - distances[i]);
+            if num_sims > 0 {
+                line.push_str(&format!(",{},{}", l_min[i], l_max[i]));
+            }
+            line.push_str("\n");
+            writer.write_all(line.as_bytes())?;
+        }
+        let _ = writer.flush();
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Number of points", &points.len().to_string());
+            report::message("Output file written");
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -189,44 +189,22 @@ impl WhiteboxTool for SibsonInterpolation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                use_field = true;
-            } else if flag_val.contains("use_z") {
-                use_z = true;
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+            use_field = true;
+        }
+        if tool_args.get_flag(&["--use_z"])? {
+            use_z = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -236,9 +214,7 @@ impl WhiteboxTool for SibsonInterpolation {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -348,7 +324,7 @@ impl WhiteboxTool for SibsonInterpolation {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    report::progress("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -396,7 +372,7 @@ impl WhiteboxTool for SibsonInterpolation {
 
         // this is where the heavy-lifting is
         if verbose {
-            println!("Performing triangulation...");
+            report::message("Performing triangulation...");
         }
         let delaunay = triangulate(&points).expect("No triangulation exists.");
         let num_triangles = delaunay.len();
@@ -609,7 +585,7 @@ impl WhiteboxTool for SibsonInterpolation {
             if verbose {
                 progress = (100.0_f64 * triangle as f64 / (num_triangles - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -628,11 +604,11 @@ impl WhiteboxTool for SibsonInterpolation {
         );
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -641,10 +617,7 @@ impl WhiteboxTool for SibsonInterpolation {
         let elapsed_time = end - start;
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time: {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,320 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::get_polygon_rings;
+use algorithms::{douglas_peucker, generalize_lines, visvalingam_whyatt};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool simplifies the lines of a vector coverage of either a POLYLINE or POLYGON base
+/// ShapeType, reducing the number of vertices while retaining the general shape of each line.
+/// Two simplification methods (`--method`) are available:
+///
+/// - `douglas_peucker` (the default) uses the Douglas-Peucker algorithm, which retains only those
+///   vertices that deviate from the simplified line by more than the tolerance (`--tolerance`),
+///   a distance in map units.
+/// - `visvalingam` uses the Visvalingam-Whyatt algorithm, which repeatedly removes the vertex
+///   that forms the triangle of least area with its two neighbours. Vertices are removed until
+///   each remaining vertex has an effective area of at least the tolerance, which is in this
+///   case an area in squared map units. This method tends to produce smoother, more natural
+///   looking lines than the Douglas-Peucker algorithm.
+///
+/// The end points of polylines are always retained, and polygon rings that would collapse to
+/// fewer than three vertices are left unsimplified. If `--preserve_topology` is specified,
+/// boundaries that are shared by several features, e.g. the common boundary of two adjacent
+/// basins, are simplified identically so that no gaps or overlaps develop between the
+/// features, and the nodes where three or more boundaries meet are retained. Vertices are
+/// considered to be shared if they have identical coordinates. Notice that large tolerances
+/// may still cause simplified lines to cross one another.
+///
+/// This tool is commonly used to reduce the stair-stepped lines and polygons produced by
+/// raster-to-vector conversion to a manageable number of vertices, e.g. before applying
+/// `SmoothVectors`. Z and M values are not retained.
+///
+/// # See Also
+/// `SmoothVectors`, `RasterToVectorLines`
+pub struct SimplifyVectors {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SimplifyVectors {
+    pub fn new() -> SimplifyVectors {
+        // public constructor
+        let name = "SimplifyVectors".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Simplifies the lines of a vector coverage of either a POLYLINE or POLYGON base ShapeType."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector POLYLINE or POLYGON file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Simplification Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Simplification method; options are 'douglas_peucker' (default) and 'visvalingam'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "douglas_peucker".to_owned(),
+                "visvalingam".to_owned(),
+            ]),
+            default_value: Some("douglas_peucker".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Simplification Tolerance".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Tolerance; a distance for the Douglas-Peucker method, an area for the Visvalingam method."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Preserve topology between shared boundaries?".to_owned(),
+            flags: vec!["--preserve_topology".to_owned()],
+            description: "Simplify the boundaries shared by features identically.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp -o=out_file.shp --tolerance=5.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp -o=out_file.shp --method=visvalingam --tolerance=100.0 --preserve_topology",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SimplifyVectors {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SimplifyVectors {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file: String = "".to_string();
+        let mut output_file: String = "".to_string();
+        let mut method = String::from("douglas_peucker");
+        let mut tolerance = -1f64;
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--tolerance"])? {
+            tolerance = v;
+        }
+        let preserve_topology = tool_args.get_flag(&["--preserve_topology"])?;
+
+        if tolerance < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A non-negative simplification tolerance (--tolerance) must be specified.",
+            ));
+        }
+        if method != "douglas_peucker" && method != "visvalingam" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized simplification method; options are 'douglas_peucker' and 'visvalingam'.",
+            ));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let input = Shapefile::read(&input_file)?;
+
+        // make sure the input vector file is of PolyLine or Polygon type
+        let base_shape_type = input.header.shape_type.base_shape_type();
+        if base_shape_type != ShapeType::PolyLine && base_shape_type != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POLYLINE or POLYGON base shape type.",
+            ));
+        }
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, base_shape_type, true)?;
+
+        let closed = base_shape_type == ShapeType::Polygon;
+        let mut lines = vec![];
+        for record_num in 0..input.num_records {
+            lines.extend(get_polygon_rings(input.get_record(record_num)));
+        }
+        if verbose {
+            report::message("Simplifying lines...");
+        }
+        let simplified = generalize_lines(&lines, closed, preserve_topology, |line, _| {
+            if method == "visvalingam" {
+                visvalingam_whyatt(line, tolerance)
+            } else {
+                douglas_peucker(line, tolerance)
+            }
+        });
+
+        let mut part_num = 0;
+        let mut num_vertices = 0;
+        for record_num in 0..input.num_records {
+            let in_record = input.get_record(record_num);
+            let mut out_record = ShapefileGeometry::new(base_shape_type);
+            for part in 0..in_record.num_parts as usize {
+                out_record.add_part(&simplified[part_num + part]);
+            }
+            part_num += in_record.num_parts as usize;
+            num_vertices += out_record.num_points as usize;
+
+            output.add_record(out_record);
+
+            let atts = input.attributes.get_record(record_num);
+            output.attributes.add_record(atts.clone(), false);
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            let num_input_vertices: usize = lines.iter().map(|l| l.len()).sum();
+            report::message(&format!(
+                "Number of vertices reduced from {} to {}",
+                num_input_vertices, num_vertices
+            ));
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -2,20 +2,49 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 01/10/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::clip::get_polygon_rings;
+use algorithms::{chaikin_smoothing, generalize_lines, moving_average_smoothing, paek_smoothing};
 use std::env;
 use std::io::{Error, ErrorKind};
 use std::path;
 use tools::*;
 use vector::*;
 
-/// This tool smooths a vector coverage of either a POLYLINE or POLYGON base ShapeType. The algorithm
-/// uses a simple moving average method for smoothing, where the size of the averaging window is specified
-/// by the user. The default filter size is 3 and can be any odd integer larger than or equal to 3. The
-/// larger the averaging window, the greater the degree of line smoothing.
+/// This tool smooths a vector coverage of either a POLYLINE or POLYGON base ShapeType. Three
+/// smoothing methods (`--method`) are available:
+///
+/// - `moving_average` (the default) uses a simple moving average method for smoothing, where the
+///   size of the averaging window is specified by the user. The default filter size (`--filter`)
+///   is 3 and can be any odd integer larger than or equal to 3. The larger the averaging window,
+///   the greater the degree of line smoothing.
+/// - `chaikin` uses Chaikin's corner-cutting algorithm, which replaces each line segment with two
+///   points a quarter and three quarters of the way along it. This is repeated a number of times
+///   (`--iterations`, 3 by default), with each iteration doubling the number of vertices.
+/// - `paek` uses the Polynomial Approximation with Exponential Kernel method of Bodansky et al.
+///   (2002), which resamples each line at regular intervals and fits a locally weighted
+///   polynomial to the samples within a kernel of length `--tolerance`, in map units. Larger
+///   tolerances give smoother lines. This method is well suited to smoothing the stair-stepped
+///   lines and polygons produced by raster-to-vector conversion.
+///
+/// The end points of polylines are not moved. If `--preserve_topology` is specified, boundaries
+/// that are shared by several features, e.g. the common boundary of two adjacent basins, are
+/// smoothed identically so that no gaps or overlaps develop between the features, and the nodes
+/// where three or more boundaries meet are not moved. Vertices are considered to be shared if
+/// they have identical coordinates.
+///
+/// Z and M values are retained by the moving average method only.
+///
+/// # Reference
+/// Bodansky, E., Gribov, A., and Pilouk, M. (2002). Smoothing and compression of lines obtained
+/// by raster-to-vector conversion. In *Graphics Recognition Algorithms and Applications*, LNCS
+/// 2390, 256-265.
+///
+/// # See Also
+/// `SimplifyVectors`, `RasterToVectorLines`
 pub struct SmoothVectors {
     name: String,
     description: String,
@@ -55,6 +84,20 @@ impl SmoothVectors {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Smoothing Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Smoothing method; options are 'moving_average' (default), 'chaikin', and 'paek'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "moving_average".to_owned(),
+                "chaikin".to_owned(),
+                "paek".to_owned(),
+            ]),
+            default_value: Some("moving_average".to_owned()),
+            optional: true,
+        });
+
         parameters.push(ToolParameter {
             name: "Filter Size".to_owned(),
             flags: vec!["--filter".to_owned()],
@@ -66,6 +109,33 @@ impl SmoothVectors {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Number of Iterations".to_owned(),
+            flags: vec!["--iterations".to_owned()],
+            description: "Number of iterations of the Chaikin method; default is 3.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some(String::from("3")),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Smoothing Tolerance".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Kernel length of the PAEK method, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Preserve topology between shared boundaries?".to_owned(),
+            flags: vec!["--preserve_topology".to_owned()],
+            description: "Smooth the boundaries shared by features identically.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -78,7 +148,8 @@ impl SmoothVectors {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=in_file.shp -o=out_file.shp --filter=9",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=in_file.shp -o=out_file.shp --filter=9
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp -o=out_file.shp --method=paek --tolerance=50.0 --preserve_topology",
             short_exe, name
         ).replace("*", &sep);
 
@@ -135,7 +206,10 @@ impl WhiteboxTool for SmoothVectors {
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
+        let mut method = String::from("moving_average");
         let mut filter: usize = 3;
+        let mut iterations: usize = 3;
+        let mut tolerance = 0f64;
 
         // read the arguments
         if args.len() == 0 {
@@ -144,36 +218,26 @@ impl WhiteboxTool for SmoothVectors {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-filter" {
-                filter = if keyval {
-                    vec[1].to_string().parse::<usize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<usize>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
         }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--iterations"])? {
+            iterations = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--tolerance"])? {
+            tolerance = v;
+        }
+        let preserve_topology = tool_args.get_flag(&["--preserve_topology"])?;
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let mut progress: usize;
@@ -182,9 +246,7 @@ impl WhiteboxTool for SmoothVectors {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if filter < 3 {
@@ -195,8 +257,6 @@ impl WhiteboxTool for SmoothVectors {
             filter += 1;
         }
 
-        let half_filter = (filter / 2) as i32;
-
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
@@ -217,133 +277,94 @@ impl WhiteboxTool for SmoothVectors {
             ));
         }
 
-        // create output file
-        let mut output =
-            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
-
-        let (mut x, mut y): (f64, f64);
-        let mut n: f64;
-        let (mut start_point_in_part, mut end_point_in_part): (i32, i32);
-
-        if input.header.shape_type.base_shape_type() == ShapeType::PolyLine {
-            for record_num in 0..input.num_records {
-                let in_record = input.get_record(record_num);
-                let mut out_record = in_record.clone();
-
-                for part in 0..in_record.num_parts as usize {
-                    start_point_in_part = in_record.parts[part];
-                    end_point_in_part = if (part as i32) < in_record.num_parts - 1 {
-                        in_record.parts[part + 1] - 1
-                    } else {
-                        in_record.num_points - 1
-                    };
-                    if end_point_in_part - start_point_in_part > 2 {
-                        // Notice, we are pinning the starting and ending points positions.
-                        // This is because the edge effects of the filter will have the effect
-                        // of shortening the polyline otherwise.
-                        for i in start_point_in_part + 1..end_point_in_part {
-                            n = 0f64;
-                            x = 0f64;
-                            y = 0f64;
-                            for j in (i - half_filter)..=(i + half_filter) {
-                                if j >= start_point_in_part && j <= end_point_in_part {
-                                    n += 1f64;
-                                    x += in_record.points[j as usize].x;
-                                    y += in_record.points[j as usize].y;
-                                }
-                            }
-                            if n > 0f64 {
-                                out_record.points[i as usize].x = x / n;
-                                out_record.points[i as usize].y = y / n;
-                            }
-                        }
-                    }
-                }
+        if method != "moving_average" && method != "chaikin" && method != "paek" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized smoothing method; options are 'moving_average', 'chaikin', and 'paek'.",
+            ));
+        }
+        if method == "paek" && tolerance <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A positive smoothing tolerance (--tolerance) is required by the PAEK method.",
+            ));
+        }
 
-                output.add_record(out_record);
+        // Only the moving average method retains the number of vertices, and with them the
+        // Z and M values of the input.
+        let output_type = if method == "moving_average" {
+            input.header.shape_type
+        } else {
+            input.header.shape_type.base_shape_type()
+        };
 
-                let atts = input.attributes.get_record(record_num);
-                output.attributes.add_record(atts.clone(), false);
+        // create output file
+        let mut output = Shapefile::initialize_using_file(&output_file, &input, output_type, true)?;
 
-                if verbose {
-                    progress =
-                        (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
+        let closed = input.header.shape_type.base_shape_type() == ShapeType::Polygon;
+        let mut lines = vec![];
+        for record_num in 0..input.num_records {
+            lines.extend(get_polygon_rings(input.get_record(record_num)));
+        }
+        if verbose {
+            report::message("Smoothing lines...");
+        }
+        let smoothed = generalize_lines(&lines, closed, preserve_topology, |line, closed| {
+            match method.as_str() {
+                "chaikin" => chaikin_smoothing(line, iterations, closed),
+                "paek" => paek_smoothing(line, tolerance, closed),
+                _ => moving_average_smoothing(line, filter, closed),
             }
-        } else {
-            // Polygon
-            let mut k: i32;
-            for record_num in 0..input.num_records {
-                let in_record = input.get_record(record_num);
-                let mut out_record = in_record.clone();
+        });
 
-                for part in 0..in_record.num_parts as usize {
-                    start_point_in_part = in_record.parts[part];
-                    end_point_in_part = if (part as i32) < in_record.num_parts - 1 {
-                        in_record.parts[part + 1] - 1
+        let mut part_num = 0;
+        for record_num in 0..input.num_records {
+            let in_record = input.get_record(record_num);
+            let num_parts = in_record.num_parts as usize;
+            let out_record = if method == "moving_average" {
+                let mut out_record = in_record.clone();
+                for part in 0..num_parts {
+                    let start_point_in_part = in_record.parts[part] as usize;
+                    let end_point_in_part = if part < num_parts - 1 {
+                        in_record.parts[part + 1] as usize
                     } else {
-                        in_record.num_points - 1
+                        in_record.num_points as usize
                     };
-                    if end_point_in_part - start_point_in_part > 4 {
-                        // We won't smooth the last point in the part. This
-                        // will be set to the same position as the first
-                        // after the smoothing operation.
-                        for i in start_point_in_part..end_point_in_part {
-                            n = 0f64;
-                            x = 0f64;
-                            y = 0f64;
-                            for j in (i - half_filter)..=(i + half_filter) {
-                                k = j;
-                                if j < start_point_in_part {
-                                    k = end_point_in_part - (start_point_in_part - j);
-                                }
-                                if j > end_point_in_part {
-                                    k = start_point_in_part + (j - end_point_in_part);
-                                }
-                                if k >= start_point_in_part && k <= end_point_in_part {
-                                    n += 1f64;
-                                    x += in_record.points[k as usize].x;
-                                    y += in_record.points[k as usize].y;
-                                }
-                            }
-                            if n > 0f64 {
-                                out_record.points[i as usize].x = x / n;
-                                out_record.points[i as usize].y = y / n;
-                            }
-                        }
-                        out_record.points[end_point_in_part as usize].x =
-                            out_record.points[start_point_in_part as usize].x;
-                        out_record.points[end_point_in_part as usize].y =
-                            out_record.points[start_point_in_part as usize].y;
+                    let points = &mut out_record.points[start_point_in_part..end_point_in_part];
+                    for (p, q) in points.iter_mut().zip(smoothed[part_num + part].iter()) {
+                        *p = *q;
                     }
                 }
+                out_record
+            } else {
+                let mut out_record = ShapefileGeometry::new(output_type);
+                for part in 0..num_parts {
+                    out_record.add_part(&smoothed[part_num + part]);
+                }
+                out_record
+            };
+            part_num += num_parts;
 
-                output.add_record(out_record);
+            output.add_record(out_record);
 
-                let atts = input.attributes.get_record(record_num);
-                output.attributes.add_record(atts.clone(), false);
+            let atts = input.attributes.get_record(record_num);
+            output.attributes.add_record(atts.clone(), false);
 
-                if verbose {
-                    progress =
-                        (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -351,7 +372,7 @@ impl WhiteboxTool for SmoothVectors {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
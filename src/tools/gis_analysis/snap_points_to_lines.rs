@@ -0,0 +1,387 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::nearest_point_on_segment;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, DistanceMetric, KdTree, Point2D, RTree};
+use tools::*;
+use vector::*;
+
+/// This tool moves each of a set of vector points (`--input`) to the nearest location on the
+/// features of a vector line layer (`--lines`), e.g. to move stream gauging stations onto a
+/// vector stream network before delineating their watersheds, or to conflate observations
+/// with a road network. If `--to_vertex` is specified, the points are instead moved to the
+/// nearest vertex of the lines. Polygon layers may also be used, in which case the points are
+/// snapped to the polygons' boundaries.
+///
+/// Points are only moved if the nearest location is within the snap distance (`--snap_dist`),
+/// in map units; points farther from the lines are output at their original locations. The
+/// output vector (`--output`) contains all of the input points, in their original order and
+/// with their attributes, Z and M values, and two additional fields: `SNAP_DIST`, the distance
+/// that each point was moved, and `LINE_FID`, the record number (starting at 1) of the line
+/// feature that it was snapped to. Both fields are null for points that were not snapped.
+///
+/// # See Also
+/// `JensonSnapPourPoints`, `SnapPourPoints`, `ExtractNodes`
+pub struct SnapPointsToLines {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SnapPointsToLines {
+    pub fn new() -> SnapPointsToLines {
+        // public constructor
+        let name = "SnapPointsToLines".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Moves vector points to the nearest location, or vertex, on a vector line layer."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Vector Lines File".to_owned(),
+            flags: vec!["--lines".to_owned()],
+            description: "Input vector lines file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap_dist".to_owned()],
+            description: "Maximum snap distance, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap to nearest vertex?".to_owned(),
+            flags: vec!["--to_vertex".to_owned()],
+            description: "Snap points to the nearest line vertex rather than the nearest location on the lines.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=gauges.shp --lines=streams.shp -o=snapped.shp --snap_dist=50.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SnapPointsToLines {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SnapPointsToLines {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut lines_file = String::new();
+        let mut output_file = String::new();
+        let mut snap_dist = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--lines"])? {
+            lines_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--snap_dist"])? {
+            snap_dist = v;
+        }
+        let to_vertex = tool_args.get_flag(&["--to_vertex"])?;
+        if snap_dist <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A positive snap distance (--snap_dist) must be specified.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !lines_file.contains(&sep) && !lines_file.contains("/") {
+            lines_file = format!("{}{}", working_directory, lines_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let lines = Shapefile::read(&lines_file)?;
+        let start = Instant::now();
+
+        if input.header.shape_type.base_shape_type() != ShapeType::Point {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT base shape type.",
+            ));
+        }
+        if lines.header.shape_type.base_shape_type() != ShapeType::PolyLine
+            && lines.header.shape_type.base_shape_type() != ShapeType::Polygon
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The lines vector data must be of either a polyline or polygon base shape type.",
+            ));
+        }
+
+        // index the line segments, or vertices, and the records that they belong to
+        let mut segments: Vec<(Point2D, Point2D, usize)> = vec![];
+        let mut vertices: Vec<(Point2D, usize)> = vec![];
+        for record_num in 0..lines.num_records {
+            let record = lines.get_record(record_num);
+            for part in 0..record.num_parts as usize {
+                let part_start = record.parts[part] as usize;
+                let part_end = if part < record.num_parts as usize - 1 {
+                    record.parts[part + 1] as usize
+                } else {
+                    record.num_points as usize
+                };
+                for i in part_start..part_end {
+                    if to_vertex {
+                        vertices.push((record.points[i], record_num));
+                    } else if i < part_end - 1 {
+                        segments.push((record.points[i], record.points[i + 1], record_num));
+                    } else if part_end - part_start == 1 {
+                        // a single-vertex part
+                        segments.push((record.points[i], record.points[i], record_num));
+                    }
+                }
+            }
+        }
+        if segments.is_empty() && vertices.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The lines vector does not contain any features.",
+            ));
+        }
+        let mut coords = Vec::with_capacity(2 * vertices.len());
+        for v in &vertices {
+            coords.push(v.0.x);
+            coords.push(v.0.y);
+        }
+        let vertex_tree = KdTree::new(
+            2,
+            coords,
+            (0..vertices.len()).collect(),
+            DistanceMetric::Euclidean,
+        );
+        let segment_tree = RTree::new(
+            segments
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (BoundingBox::from_two_points(s.0, s.1), i))
+                .collect(),
+        );
+
+        // create the output file
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        output.attributes.add_field(&AttributeField::new(
+            "SNAP_DIST",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output
+            .attributes
+            .add_field(&AttributeField::new("LINE_FID", FieldDataType::Int, 8u8, 0u8));
+
+        let mut num_snapped = 0;
+        for record_num in 0..input.num_records {
+            let mut geometry = input.get_record(record_num).clone();
+            let mut atts = input.attributes.get_record(record_num);
+            let mut snapped = false;
+            if geometry.num_points > 0 {
+                let p = geometry.points[0];
+                let nearest = if to_vertex {
+                    vertex_tree
+                        .knn_search(&[p.x, p.y], 1)
+                        .first()
+                        .map(|&(i, d)| (d, vertices[i]))
+                } else {
+                    segment_tree
+                        .knn_search_by(p.x, p.y, 1, |_, i| {
+                            p.distance(&nearest_point_on_segment(
+                                &p,
+                                &segments[i].0,
+                                &segments[i].1,
+                            ))
+                        })
+                        .first()
+                        .map(|&(i, d)| {
+                            let q = nearest_point_on_segment(&p, &segments[i].0, &segments[i].1);
+                            (d, (q, segments[i].2))
+                        })
+                };
+                if let Some((d, (q, line_record))) = nearest {
+                    if d <= snap_dist {
+                        geometry.points[0] = q;
+                        geometry.x_min = q.x;
+                        geometry.x_max = q.x;
+                        geometry.y_min = q.y;
+                        geometry.y_max = q.y;
+                        atts.push(FieldData::Real(d));
+                        atts.push(FieldData::Int(line_record as i32 + 1i32));
+                        snapped = true;
+                        num_snapped += 1;
+                    }
+                }
+            }
+            if !snapped {
+                atts.push(FieldData::Null);
+                atts.push(FieldData::Null);
+            }
+            output.add_record(geometry);
+            output.attributes.add_record(atts, false);
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message(&format!(
+                "Snapped {} of {} points; {} points are beyond the snap distance.",
+                num_snapped,
+                input.num_records,
+                input.num_records - num_snapped
+            ));
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::collections::HashMap;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::batch::get_templated_file_name;
+use tools::*;
+use vector::*;
+
+/// This tool splits an input vector file (`--input`) into one output file per unique
+/// value of an attribute field (`--field`). Each output file contains all of the
+/// features that share the value, along with their attributes, and retains the
+/// ShapeType of the input.
+///
+/// The names of the output files are derived from the output file name (`--output`)
+/// and the attribute values. If the output name contains the `{name}` placeholder,
+/// e.g. `basin_{name}.shp`, it is replaced by the attribute value; otherwise, the value
+/// is appended to the output name, e.g. `basins.shp` becomes `basins_1.shp`, `basins_2.shp`,
+/// etc. Characters that are not permitted in file names are replaced by underscores.
+///
+/// # See Also
+/// `SplitByPolygon`, `Clip`
+pub struct SplitByAttribute {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SplitByAttribute {
+    pub fn new() -> SplitByAttribute {
+        // public constructor
+        let name = "SplitByAttribute".to_string();
+        let toolbox = "GIS Analysis/Overlay Tools".to_string();
+        let description =
+            "Splits a vector file into one file per unique value of an attribute field."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Input field name in attribute table.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description:
+                "Output vector file name, which may contain a {name} placeholder for the attribute value."
+                    .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=basins.shp --field=BASIN_ID -o=basin_{{name}}.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SplitByAttribute {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SplitByAttribute {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file: String = "".to_string();
+        let mut field_name: String = "".to_string();
+        let mut output_file: String = "".to_string();
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let input = Shapefile::read(&input_file)?;
+
+        let field_index = match input.attributes.get_field_num(&field_name) {
+            Some(i) => i,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Attribute not found in table.",
+                ));
+            }
+        };
+
+        // group the records by attribute value, in order of first appearance
+        let mut groups: Vec<(String, Vec<usize>)> = vec![];
+        let mut group_of_value: HashMap<String, usize> = HashMap::new();
+        for record_num in 0..input.num_records {
+            let value = field_data_to_string(&input.attributes.get_record(record_num)[field_index]);
+            let g = match group_of_value.get(&value) {
+                Some(&g) => g,
+                None => {
+                    groups.push((value.clone(), vec![]));
+                    groups.len() - 1
+                }
+            };
+            group_of_value.insert(value, g);
+            groups[g].1.push(record_num);
+        }
+
+        let mut file_names = OutputFileNames::new(&output_file);
+        for g in 0..groups.len() {
+            let file_name = file_names.get(&groups[g].0);
+            let mut output = Shapefile::initialize_using_file(
+                &file_name,
+                &input,
+                input.header.shape_type,
+                true,
+            )?;
+            for &record_num in &groups[g].1 {
+                output.add_record(input.get_record(record_num).clone());
+                output
+                    .attributes
+                    .add_record(input.attributes.get_record(record_num), false);
+            }
+            output.write()?;
+
+            if verbose {
+                progress = (100.0_f64 * (g + 1) as f64 / groups.len() as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message(&format!("{} output files written", groups.len()));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns an attribute value as a string, for use in file names.
+pub fn field_data_to_string(value: &FieldData) -> String {
+    match *value {
+        FieldData::Int(v) => format!("{}", v),
+        FieldData::Real(v) => format!("{}", v),
+        FieldData::Text(ref v) => v.trim().to_string(),
+        FieldData::Bool(v) => format!("{}", v),
+        FieldData::Date(ref v) => format!("{}{:02}{:02}", v.year, v.month, v.day),
+        FieldData::Null => "null".to_string(),
+    }
+}
+
+/// Generates unique output file names from an output file name template.
+pub struct OutputFileNames {
+    template: String,
+    used: HashMap<String, usize>,
+}
+
+impl OutputFileNames {
+    pub fn new(template: &str) -> OutputFileNames {
+        OutputFileNames {
+            template: template.to_string(),
+            used: HashMap::new(),
+        }
+    }
+
+    /// Returns the file name for `value`. Characters that are not permitted in file
+    /// names are replaced by underscores and a numeric suffix is added if the
+    /// resulting name has already been used.
+    pub fn get(&mut self, value: &str) -> String {
+        let mut stem: String = value
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        if stem.is_empty() {
+            stem = "_".to_string();
+        }
+        let count = self.used.entry(stem.clone()).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            stem = format!("{}_{}", stem, count);
+        }
+        get_templated_file_name(&self.template, &stem)
+    }
+}
@@ -0,0 +1,294 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::{
+    clip_record, get_clipped_shape_type, get_polygon_union, get_rings_bounding_box,
+};
+use super::split_by_attribute::{field_data_to_string, OutputFileNames};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool partitions an input vector file (`--input`) using the polygons of a split
+/// vector file (`--polygons`), writing one output file for each split polygon. Each
+/// output file contains the features of the input, or the parts of features, that fall
+/// within the split polygon, in the same way as the `Clip` tool. The input may be of
+/// POINT, MULTIPOINT, POLYLINE, or POLYGON ShapeType; the split file must be of POLYGON
+/// ShapeType. Split polygons that do not overlap any input features do not produce an
+/// output file. This is useful, for example, for partitioning a large stream network
+/// by watershed.
+///
+/// The names of the output files are derived from the output file name (`--output`)
+/// and either the values of an attribute of the split polygons (`--field`) or, if no
+/// field is specified, the record numbers (starting at 1) of the split polygons. If the
+/// output name contains the `{name}` placeholder, e.g. `streams_{name}.shp`, it is
+/// replaced by this value; otherwise, the value is appended to the output name.
+///
+/// # See Also
+/// `SplitByAttribute`, `Clip`
+pub struct SplitByPolygon {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SplitByPolygon {
+    pub fn new() -> SplitByPolygon {
+        // public constructor
+        let name = "SplitByPolygon".to_string();
+        let toolbox = "GIS Analysis/Overlay Tools".to_string();
+        let description =
+            "Splits a vector file into one file per polygon of a split vector, clipping the features to each polygon."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Split Polygon File".to_owned(),
+            flags: vec!["--polygons".to_owned()],
+            description: "Input split polygon vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Name Field".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Optional split polygon attribute used to name the output files."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--polygons".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description:
+                "Output vector file name, which may contain a {name} placeholder for the polygon name."
+                    .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp --polygons=basins.shp --field=BASIN_ID -o=streams_{{name}}.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SplitByPolygon {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SplitByPolygon {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file: String = "".to_string();
+        let mut polygons_file: String = "".to_string();
+        let mut field_name: String = "".to_string();
+        let mut output_file: String = "".to_string();
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--polygons"])? {
+            polygons_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !polygons_file.contains(&sep) && !polygons_file.contains("/") {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let input = Shapefile::read(&input_file)?;
+        let polygons = Shapefile::read(&polygons_file)?;
+
+        // make sure the split vector file is of polygon type
+        if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The split vector data must be of POLYGON base shape type.",
+            ));
+        }
+
+        let field_index = if field_name.is_empty() {
+            None
+        } else {
+            match polygons.attributes.get_field_num(&field_name) {
+                Some(i) => Some(i),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Attribute not found in table.",
+                    ));
+                }
+            }
+        };
+
+        let output_type = get_clipped_shape_type(input.header.shape_type);
+        let mut file_names = OutputFileNames::new(&output_file);
+        let mut num_files = 0;
+        for polygon_num in 0..polygons.num_records {
+            let region = get_polygon_union(&polygons, &[polygon_num]);
+            let region_box = get_rings_bounding_box(&region);
+            let polygon_name = match field_index {
+                Some(i) => field_data_to_string(&polygons.attributes.get_record(polygon_num)[i]),
+                None => format!("{}", polygon_num + 1),
+            };
+            let file_name = file_names.get(&polygon_name);
+            let mut output =
+                Shapefile::initialize_using_file(&file_name, &input, output_type, true)?;
+            for record_num in 0..input.num_records {
+                let record = input.get_record(record_num);
+                if !record.get_bounding_box().overlaps(region_box) {
+                    continue;
+                }
+                if let Some(geometry) =
+                    clip_record(record, &region, region_box, false, output_type)
+                {
+                    output.add_record(geometry);
+                    output
+                        .attributes
+                        .add_record(input.attributes.get_record(record_num), false);
+                }
+            }
+            if output.num_records > 0 {
+                output.write()?;
+                num_files += 1;
+            }
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (polygon_num + 1) as f64 / polygons.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message(&format!("{} output files written", num_files));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -158,35 +158,15 @@ impl WhiteboxTool for SplitWithLines {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input1_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-split" {
-                input2_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--split"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,9 +176,7 @@ impl WhiteboxTool for SplitWithLines {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input1_file.contains(path::MAIN_SEPARATOR) && !input1_file.contains("/") {
@@ -361,7 +339,7 @@ impl WhiteboxTool for SplitWithLines {
                     progress =
                         (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -369,11 +347,11 @@ impl WhiteboxTool for SplitWithLines {
 
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -421,7 +399,7 @@ impl WhiteboxTool for SplitWithLines {
                     progress =
                         (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -460,7 +438,7 @@ impl WhiteboxTool for SplitWithLines {
                 if verbose {
                     progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Creating tree: {}%", progress);
+                        report::progress("Creating tree", progress);
                         old_progress = progress;
                     }
                 }
@@ -504,7 +482,7 @@ impl WhiteboxTool for SplitWithLines {
                 if verbose {
                     progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Finding node vertices: {}%", progress);
+                        report::progress("Finding node vertices", progress);
                         old_progress = progress;
                     }
                 }
@@ -656,7 +634,7 @@ impl WhiteboxTool for SplitWithLines {
                 if verbose {
                     progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Finding polygons: {}%", progress);
+                        report::progress("Finding polygons", progress);
                         old_progress = progress;
                     }
                 }
@@ -664,11 +642,11 @@ impl WhiteboxTool for SplitWithLines {
 
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -677,7 +655,7 @@ impl WhiteboxTool for SplitWithLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
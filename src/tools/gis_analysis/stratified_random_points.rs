@@ -0,0 +1,303 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::point_sampling::{add_point, create_points_file};
+use rand::prelude::*;
+use rand::seq::sample_indices;
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool generates stratified random points from a categorical raster (`--input`), such
+/// as a land-cover map, with each of the raster's classes (i.e. unique valid values) as a
+/// stratum. It is commonly used to create the training and validation data of
+/// classification models and to design the sampling of accuracy assessments, in which rare
+/// classes must be represented.
+///
+/// By default, the specified number of points (`--num_points`) is generated within each
+/// class. If the `--proportional` flag is used, the number of points is instead the total,
+/// which is allocated among the classes in proportion to their areas (by the largest remainder
+/// method). Points are placed at the centres of grid cells that are sampled without
+/// replacement from each class; if a class contains fewer cells than its allocation, every
+/// cell of the class is used and a warning is issued. The output vector points file
+/// (`--output`) records each point's class in a CLASS attribute.
+///
+/// # See Also
+/// `RandomPoints`, `RegularGridPoints`, `RandomSample`
+pub struct StratifiedRandomPoints {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl StratifiedRandomPoints {
+    pub fn new() -> StratifiedRandomPoints {
+        // public constructor
+        let name = "StratifiedRandomPoints".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Generates stratified random points within the classes of a categorical raster."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Categorical Raster File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input categorical raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Points File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Points".to_owned(),
+            flags: vec!["--num_points".to_owned()],
+            description: "Number of points per class, or in total if proportional.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("50".to_owned()),
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Allocate points in proportion to class area?".to_owned(),
+            flags: vec!["--proportional".to_owned()],
+            description: "Allocate the total number of points in proportion to class area?"
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=landcover.tif -o=points.shp --num_points=50
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=landcover.tif -o=points.shp --num_points=1000 --proportional",
+            short_exe, name
+        ).replace("*", &sep);
+
+        StratifiedRandomPoints {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for StratifiedRandomPoints {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut num_points = 50usize;
+        let mut proportional = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_points"])? {
+            num_points = v;
+        }
+        if tool_args.get_flag(&["--proportional"])? {
+            proportional = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+
+        // the cells of each class, in order of class value
+        let mut class_index: HashMap<u64, usize> = HashMap::new();
+        let mut classes: Vec<(f64, Vec<(isize, isize)>)> = vec![];
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != nodata {
+                    let i = *class_index.entry(z.to_bits()).or_insert_with(|| {
+                        classes.push((z, vec![]));
+                        classes.len() - 1
+                    });
+                    classes[i].1.push((row, col));
+                }
+            }
+        }
+        if classes.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster does not contain any valid cells.",
+            ));
+        }
+        classes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let allocation: Vec<usize> = if proportional {
+            // the largest remainder method
+            let total_cells = classes.iter().fold(0usize, |n, c| n + c.1.len()) as f64;
+            let quotas: Vec<f64> = classes
+                .iter()
+                .map(|c| num_points as f64 * c.1.len() as f64 / total_cells)
+                .collect();
+            let mut allocation: Vec<usize> = quotas.iter().map(|q| q.floor() as usize).collect();
+            let mut order: Vec<usize> = (0..classes.len()).collect();
+            order.sort_by(|&a, &b| {
+                (quotas[b] - quotas[b].floor())
+                    .partial_cmp(&(quotas[a] - quotas[a].floor()))
+                    .unwrap()
+            });
+            let remaining = num_points - allocation.iter().sum::<usize>();
+            for &i in order.iter().take(remaining) {
+                allocation[i] += 1;
+            }
+            allocation
+        } else {
+            vec![num_points; classes.len()]
+        };
+
+        let mut output = create_points_file(
+            &output_file,
+            input.configs.coordinate_ref_system_wkt.clone(),
+            &[
+                AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8),
+                AttributeField::new("CLASS", FieldDataType::Real, 12u8, 4u8),
+            ],
+        )?;
+        let mut rng = thread_rng();
+        let mut fid = 1;
+        for (c, &(value, ref cells)) in classes.iter().enumerate() {
+            let n = if allocation[c] > cells.len() {
+                report::warning(&format!(
+                    "Class {} contains only {} cells, fewer than the {} points allocated to it.",
+                    value, cells.len(), allocation[c]
+                ));
+                cells.len()
+            } else {
+                allocation[c]
+            };
+            if verbose {
+                report::message(&format!("Class {}: {} points", value, n));
+            }
+            for i in sample_indices(&mut rng, cells.len(), n) {
+                let (row, col) = cells[i];
+                add_point(
+                    &mut output,
+                    input.get_x_from_column(col),
+                    input.get_y_from_row(row),
+                    vec![FieldData::Int(fid), FieldData::Real(value)],
+                );
+                fid += 1;
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
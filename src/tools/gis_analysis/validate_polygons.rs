@@ -0,0 +1,448 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::clip::get_polygon_rings;
+use algorithms::{
+    group_rings, orient_rings, polygon_area, repair_rings, segment_intersection,
+    SegmentIntersection,
+};
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, Point2D, RTree};
+use tools::*;
+use vector::*;
+
+/// This tool checks the geometry of each feature in a polygon vector file (`--input`)
+/// for the following problems:
+///
+/// - Unclosed rings, i.e. rings whose last vertex is not the same as their first.
+/// - Duplicate vertices, i.e. consecutive vertices with identical coordinates.
+/// - Degenerate rings, having fewer than three distinct vertices.
+/// - Self-intersections, where the edges of a feature's rings cross, overlap, or touch
+///   other than at shared vertices. Rings touching at a single shared vertex are allowed.
+/// - Misoriented rings. Following the Shapefile convention, outer rings must be
+///   clockwise and holes counter-clockwise.
+/// - Slivers, i.e. parts (outer rings and their holes) with an area less than the
+///   minimum area threshold (`--min_area`), in squared map units. By default, with a
+///   threshold of zero, slivers are not identified.
+///
+/// A CSV report (`--report`) is output with one row per feature, containing the feature
+/// identifier (FID), the number of each type of problem found, and the feature's status,
+/// which is either 'valid', 'invalid', 'repaired', or 'removed'.
+///
+/// If an output file (`--output`) is specified, the features are also repaired: rings are
+/// closed, duplicate vertices and degenerate rings are removed, self-intersecting
+/// features are rebuilt (for example, a bow-tie shaped ring becomes two triangles),
+/// rings are re-oriented, and slivers are removed. Self-intersecting features are
+/// rebuilt using the even-odd rule, such that areas enclosed by overlapping rings of a
+/// feature become holes. Features with no remaining parts are not included in the
+/// output and are reported as 'removed'. The output attribute table contains all of the
+/// fields and values of the input file. Z and M values are not retained.
+///
+/// # See Also
+/// `Clip`, `Erase`, `PolygonArea`
+pub struct ValidatePolygons {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ValidatePolygons {
+    pub fn new() -> ValidatePolygons {
+        // public constructor
+        let name = "ValidatePolygons".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Detects, and optionally repairs, invalid polygon geometries, e.g. self-intersections and unclosed rings."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Polygon File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector polygon file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Report File".to_owned(),
+            flags: vec!["--report".to_owned()],
+            description: "Output CSV report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Repaired Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Optional output vector polygon file of repaired features.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Area".to_owned(),
+            flags: vec!["--min_area".to_owned()],
+            description: "Minimum area of polygon parts; smaller parts are slivers.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=polygons.shp --report=report.csv -o=repaired.shp --min_area=10.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        ValidatePolygons {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ValidatePolygons {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file: String = "".to_string();
+        let mut report_file: String = "".to_string();
+        let mut output_file: String = "".to_string();
+        let mut min_area = 0f64;
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--report"])? {
+            report_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--min_area"])? {
+            min_area = v;
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !report_file.contains(&sep) && !report_file.contains("/") {
+            report_file = format!("{}{}", working_directory, report_file);
+        }
+        let repair = !output_file.is_empty();
+        if repair && !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let input = Shapefile::read(&input_file)?;
+
+        // make sure the input vector file is of polygon type
+        if input.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POLYGON base shape type.",
+            ));
+        }
+
+        let mut output = if repair {
+            Some(Shapefile::initialize_using_file(
+                &output_file,
+                &input,
+                ShapeType::Polygon,
+                true,
+            )?)
+        } else {
+            None
+        };
+
+        let f = File::create(&report_file)?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all(
+            "FID,UNCLOSED_RINGS,DUPLICATE_VERTICES,DEGENERATE_RINGS,SELF_INTERSECTIONS,MISORIENTED_RINGS,SLIVER_PARTS,STATUS\n"
+                .as_bytes(),
+        )?;
+
+        let mut num_invalid = 0;
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let (parts, issues) = validate_polygon(record, min_area);
+            let status = if issues.count() == 0 {
+                "valid"
+            } else if !repair {
+                "invalid"
+            } else if parts.is_empty() {
+                "removed"
+            } else {
+                "repaired"
+            };
+            if issues.count() > 0 {
+                num_invalid += 1;
+            }
+            if let Some(ref mut output) = output {
+                if !parts.is_empty() {
+                    let mut geometry = ShapefileGeometry::new(ShapeType::Polygon);
+                    for part in parts {
+                        for ring in part {
+                            geometry.add_part(&ring);
+                        }
+                    }
+                    output.add_record(geometry);
+                    output
+                        .attributes
+                        .add_record(input.attributes.get_record(record_num), false);
+                }
+            }
+            writer.write_all(
+                format!(
+                    "{},{},{},{},{},{},{},{}\n",
+                    record_num + 1,
+                    issues.unclosed_rings,
+                    issues.duplicate_vertices,
+                    issues.degenerate_rings,
+                    issues.self_intersections,
+                    issues.misoriented_rings,
+                    issues.sliver_parts,
+                    status
+                ).as_bytes(),
+            )?;
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        writer.flush()?;
+
+        if verbose {
+            report::message(&format!(
+                "{} of {} features had invalid geometries",
+                num_invalid, input.num_records
+            ));
+        }
+
+        if let Some(mut output) = output {
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// The numbers of each type of problem found in a feature's geometry.
+#[derive(Default)]
+struct Issues {
+    unclosed_rings: usize,
+    duplicate_vertices: usize,
+    degenerate_rings: usize,
+    self_intersections: usize,
+    misoriented_rings: usize,
+    sliver_parts: usize,
+}
+
+impl Issues {
+    fn count(&self) -> usize {
+        self.unclosed_rings
+            + self.duplicate_vertices
+            + self.degenerate_rings
+            + self.self_intersections
+            + self.misoriented_rings
+            + self.sliver_parts
+    }
+}
+
+/// Checks the geometry of a polygon record, returning the problems found and the
+/// repaired geometry, as a set of parts each containing an outer ring followed by its
+/// holes.
+fn validate_polygon(record: &ShapefileGeometry, min_area: f64) -> (Vec<Vec<Vec<Point2D>>>, Issues) {
+    let mut issues = Issues::default();
+    let mut rings = vec![];
+    if record.num_points > 0 {
+        for ring in get_polygon_rings(record) {
+            let mut r: Vec<Point2D> = Vec::with_capacity(ring.len() + 1);
+            for p in ring {
+                if r.last() == Some(&p) {
+                    issues.duplicate_vertices += 1;
+                } else {
+                    r.push(p);
+                }
+            }
+            if r.len() > 1 && r[0] != r[r.len() - 1] {
+                issues.unclosed_rings += 1;
+                let p = r[0];
+                r.push(p);
+            }
+            if r.len() < 4 {
+                issues.degenerate_rings += 1;
+            } else {
+                rings.push(r);
+            }
+        }
+    }
+
+    issues.self_intersections = count_self_intersections(&rings);
+    let rings = if issues.self_intersections > 0 {
+        repair_rings(&rings)
+    } else {
+        let oriented = orient_rings(&rings);
+        issues.misoriented_rings = (0..rings.len()).filter(|&i| oriented[i] != rings[i]).count();
+        oriented
+    };
+
+    let mut parts = vec![];
+    for part in group_rings(&rings) {
+        let mut area = polygon_area(&part[0]);
+        for hole in &part[1..] {
+            area -= polygon_area(hole);
+        }
+        if area < min_area {
+            issues.sliver_parts += 1;
+        } else {
+            parts.push(part);
+        }
+    }
+    (parts, issues)
+}
+
+/// Counts the intersections between the edges of a set of closed rings, other than
+/// those of edges that share a vertex at that vertex. Collinear overlapping edges,
+/// including consecutive edges of a ring that double back on themselves, also count.
+fn count_self_intersections(rings: &[Vec<Point2D>]) -> usize {
+    let mut edges: Vec<(Point2D, Point2D)> = vec![];
+    for ring in rings {
+        for i in 0..ring.len() - 1 {
+            edges.push((ring[i], ring[i + 1]));
+        }
+    }
+    let index = RTree::new(
+        edges
+            .iter()
+            .enumerate()
+            .map(|(i, &(a, b))| (BoundingBox::from_two_points(a, b), i))
+            .collect(),
+    );
+    let mut count = 0;
+    for i in 0..edges.len() {
+        let (p1, p2) = edges[i];
+        for j in index.search(&BoundingBox::from_two_points(p1, p2)) {
+            if j <= i {
+                continue;
+            }
+            let (q1, q2) = edges[j];
+            match segment_intersection(&p1, &p2, &q1, &q2) {
+                SegmentIntersection::Point(p) => {
+                    let shared_vertex = (p == p1 || p == p2) && (p == q1 || p == q2);
+                    if !shared_vertex {
+                        count += 1;
+                    }
+                }
+                SegmentIntersection::Overlap(_, _) => count += 1,
+                SegmentIntersection::None => {}
+            }
+        }
+    }
+    count
+}
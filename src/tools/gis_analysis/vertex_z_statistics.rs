@@ -0,0 +1,280 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+use vector::*;
+
+/// This tool summarizes the z-values of the vertices of each feature in a 3D vector file
+/// (PointZ, MultiPointZ, PolyLineZ, or PolygonZ), adding the results to the vector's attribute
+/// table. The added fields are the minimum (Z_MIN), maximum (Z_MAX), mean (Z_MEAN), range
+/// (Z_RANGE), and standard deviation (Z_STDEV) of the vertex z-values; for line features, the
+/// z-values of the first (Z_START) and last (Z_END) vertices are also added, which can be used
+/// to determine the direction of a line relative to the terrain, e.g. for stream networks.
+///
+/// Vertices with a z-value equal to the optional NoData value (`--nodata`), e.g. the vertices
+/// of features created with the `DrapeVectorOnDem` tool that fell on NoData cells, are
+/// excluded from the statistics. Fields are null for features without any valid z-values.
+///
+/// # See Also
+/// `DrapeVectorOnDem`, `LineLength3D`
+pub struct VertexZStatistics {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl VertexZStatistics {
+    pub fn new() -> VertexZStatistics {
+        // public constructor
+        let name = "VertexZStatistics".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Calculates summary statistics of the vertex z-values of the features in a 3D vector."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input 3D vector file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "NoData Value (optional)".to_owned(),
+            flags: vec!["--nodata".to_owned()],
+            description: "Optional z-value to exclude from the statistics.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input=streams_3d.shp --nodata=-32768.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        VertexZStatistics {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for VertexZStatistics {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut nodata: Option<f64> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--nodata"])? {
+            nodata = Some(v);
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of a Z shape type
+        if input.header.shape_type.dimension() != ShapeTypeDimension::Z {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of a Z shape type (e.g. POINTZ or POLYLINEZ).",
+            ));
+        }
+        let is_line = input.header.shape_type.base_shape_type() == ShapeType::PolyLine;
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&input_file, &input, input.header.shape_type, true)?;
+
+        // add the attributes
+        let mut field_names = vec!["Z_MIN", "Z_MAX", "Z_MEAN", "Z_RANGE", "Z_STDEV"];
+        if is_line {
+            field_names.push("Z_START");
+            field_names.push("Z_END");
+        }
+        for name in &field_names {
+            output
+                .attributes
+                .add_field(&AttributeField::new(name, FieldDataType::Real, 12u8, 4u8));
+        }
+
+        let is_valid = |z: f64| match nodata {
+            Some(nd) => z != nd && z.is_finite(),
+            None => z.is_finite(),
+        };
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let z_values: Vec<f64> = record
+                .z_array
+                .iter()
+                .cloned()
+                .filter(|&z| is_valid(z))
+                .collect();
+
+            let mut atts = input.attributes.get_record(record_num);
+            if z_values.len() > 0 {
+                let n = z_values.len() as f64;
+                let mut z_min = f64::INFINITY;
+                let mut z_max = f64::NEG_INFINITY;
+                let mut sum = 0f64;
+                let mut sq_sum = 0f64;
+                for &z in &z_values {
+                    if z < z_min {
+                        z_min = z;
+                    }
+                    if z > z_max {
+                        z_max = z;
+                    }
+                    sum += z;
+                    sq_sum += z * z;
+                }
+                let mean = sum / n;
+                let variance = (sq_sum / n - mean * mean).max(0f64);
+                atts.push(FieldData::Real(z_min));
+                atts.push(FieldData::Real(z_max));
+                atts.push(FieldData::Real(mean));
+                atts.push(FieldData::Real(z_max - z_min));
+                atts.push(FieldData::Real(variance.sqrt()));
+            } else {
+                for _ in 0..5 {
+                    atts.push(FieldData::Null);
+                }
+            }
+            if is_line {
+                // the first and last vertices of the feature, regardless of their parts
+                for z in &[record.z_array.first(), record.z_array.last()] {
+                    match *z {
+                        Some(&z) if is_valid(z) => atts.push(FieldData::Real(z)),
+                        _ => atts.push(FieldData::Null),
+                    }
+                }
+            }
+
+            let record_out = record.clone();
+            output.add_record(record_out);
+            output.attributes.add_record(atts, false);
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
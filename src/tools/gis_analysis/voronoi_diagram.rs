@@ -2,11 +2,12 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 03/10/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
-use algorithms::triangulate;
+use super::clip::{get_polygon_union, get_rings_bounding_box};
+use algorithms::{polygon_overlay, triangulate, OverlayOperation};
 use std::collections::HashMap;
 use std::env;
 use std::f64;
@@ -33,6 +34,13 @@ use vector::*;
 /// unacceptable for application, clipping the Voronoi diagram to the convex
 /// hull may be a better alternative.
 ///
+/// Alternatively, the diagram can be clipped to a boundary polygon (`--boundary`),
+/// e.g. a watershed when calculating the Thiessen polygon weights of a set of rain
+/// gauges. The frame of ghost points is then placed around both the input points and
+/// the boundary, and each cell is clipped to the boundary, which is the union of the
+/// boundary file's polygons. Cells lying entirely outside of the boundary are not
+/// output. Each cell carries the attributes of the point that generated it.
+///
 /// This tool works on vector input data only. If a Voronoi diagram is needed
 /// to tesselate regions associated with a set of raster points, use the
 /// `EuclideanAllocation` tool instead. To use Voronoi diagrams for gridding
@@ -79,6 +87,18 @@ impl VoronoiDiagram {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input Boundary Polygon File (optional)".to_owned(),
+            flags: vec!["--boundary".to_owned()],
+            description: "Optional input vector polygon file to which the cells are clipped."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -91,7 +111,8 @@ impl VoronoiDiagram {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp -o=tin.shp",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp -o=tin.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=gauges.shp -o=thiessen.shp --boundary=watershed.shp",
             short_exe, name
         ).replace("*", &sep);
 
@@ -148,6 +169,7 @@ impl WhiteboxTool for VoronoiDiagram {
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
+        let mut boundary_file = String::new();
 
         // read the arguments
         if args.len() == 0 {
@@ -156,29 +178,15 @@ impl WhiteboxTool for VoronoiDiagram {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--boundary"])? {
+            boundary_file = v;
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -188,9 +196,7 @@ impl WhiteboxTool for VoronoiDiagram {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -213,6 +219,32 @@ impl WhiteboxTool for VoronoiDiagram {
             ));
         }
 
+        // read the boundary, if one is specified
+        let mut boundary: Vec<Vec<Point2D>> = vec![];
+        if !boundary_file.is_empty() {
+            if !boundary_file.contains(&sep) && !boundary_file.contains("/") {
+                boundary_file = format!("{}{}", working_directory, boundary_file);
+            }
+            let polygons = Shapefile::read(&boundary_file)?;
+            if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The boundary vector data must be of POLYGON base shape type.",
+                ));
+            }
+            if verbose {
+                report::message("Reading boundary...");
+            }
+            let records: Vec<usize> = (0..polygons.num_records).collect();
+            boundary = get_polygon_union(&polygons, &records);
+            if boundary.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The boundary vector does not contain any polygons.",
+                ));
+            }
+        }
+
         // create output file
         let mut output = Shapefile::new(&output_file, ShapeType::Polygon)?;
 
@@ -225,18 +257,20 @@ impl WhiteboxTool for VoronoiDiagram {
 
         // Read the points in
         let mut points: Vec<Point2D> = vec![];
+        let mut point_records: Vec<usize> = vec![]; // the record containing each point
 
         for record_num in 0..input.num_records {
             let record = input.get_record(record_num);
             for i in 0..record.num_points as usize {
                 points.push(Point2D::new(record.points[i].x, record.points[i].y));
+                point_records.push(record_num);
             }
 
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    report::progress("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -250,10 +284,15 @@ impl WhiteboxTool for VoronoiDiagram {
             input.header.y_max,
         );
         // expand the box by a factor of the average point spacing.
+        let num_points = points.len();
         let expansion = ((input.header.x_max - input.header.x_min)
             * (input.header.y_max - input.header.y_min)
-            / input.num_records as f64)
+            / num_points as f64)
             .sqrt();
+        if !boundary.is_empty() {
+            // the cells must extend beyond the boundary before they are clipped
+            ghost_box.expand_to(get_rings_bounding_box(&boundary));
+        }
         ghost_box.expand_by(2.0 * expansion);
 
         let gap = expansion / 3f64; // One-third the average point spacing
@@ -283,13 +322,13 @@ impl WhiteboxTool for VoronoiDiagram {
 
         // Do the Delaunay triangulation
         if verbose {
-            println!("Performing triangulation...");
+            report::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let delaunay = triangulate(&points).expect("No triangulation exists.");
 
         if verbose {
-            println!("Creating point-halfedge mapping...");
+            report::message("Creating point-halfedge mapping...");
         }
         let mut point_edge_map = HashMap::new(); // point id to half-edge id
         for edge in 0..delaunay.triangles.len() {
@@ -301,7 +340,7 @@ impl WhiteboxTool for VoronoiDiagram {
                 progress =
                     (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -309,7 +348,7 @@ impl WhiteboxTool for VoronoiDiagram {
 
         // Now create the Voronoi cells
         const EMPTY: usize = usize::max_value();
-        for p in 0..input.num_records {
+        for p in 0..num_points {
             // get the edge that is incoming to 'p'
             let edge = match point_edge_map.get(&p) {
                 Some(e) => *e,
@@ -333,32 +372,42 @@ impl WhiteboxTool for VoronoiDiagram {
                     // delaunay.edges_around_point has been modified:
                     // } else if incoming == start {
                     //       result.push(incoming);
-                    let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
-                    sfg.add_part(&vertices);
-                    output.add_record(sfg);
-
-                    // now get the attributes of the parent point.
-                    output
-                        .attributes
-                        .add_record(input.attributes.get_record(p).clone(), false);
+                    let rings = if boundary.is_empty() {
+                        vec![vertices]
+                    } else {
+                        polygon_overlay(&[vertices], &boundary, OverlayOperation::Intersection)
+                    };
+                    if !rings.is_empty() {
+                        let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+                        for ring in &rings {
+                            sfg.add_part(ring);
+                        }
+                        output.add_record(sfg);
+
+                        // now get the attributes of the parent point.
+                        output.attributes.add_record(
+                            input.attributes.get_record(point_records[p]).clone(),
+                            false,
+                        );
+                    }
                 }
             }
 
             if verbose {
-                progress = (100.0_f64 * p as f64 / (input.num_records - 1) as f64) as usize;
+                progress = (100.0_f64 * (p + 1) as f64 / num_points as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating Voronoi cells: {}%", progress);
+                    report::progress("Creating Voronoi cells", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -366,7 +415,7 @@ impl WhiteboxTool for VoronoiDiagram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -177,59 +177,28 @@ impl WhiteboxTool for WeightedOverlay {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-factors" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-w" || flag_val == "-weights" {
-                weights_list = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-cost" {
-                cost_list = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-constraints" {
-                constraint_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-scale_max" {
-                scale_max = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--factors"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-w", "--weights"])? {
+            weights_list = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--cost"])? {
+            cost_list = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--constraints"])? {
+            constraint_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--scale_max"])? {
+            scale_max = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -329,7 +298,7 @@ impl WhiteboxTool for WeightedOverlay {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    report::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_string();
@@ -342,17 +311,17 @@ impl WhiteboxTool for WeightedOverlay {
                     read_first_file = true;
                     rows = input.configs.rows as isize;
                     columns = input.configs.columns as isize;
-                    out_nodata = in_nodata;
 
                     // initialize the output file and low_val
                     output = Raster::initialize_using_file(&output_file, &input);
+                    out_nodata = output.configs.nodata;
                     output.reinitialize_values(0.0);
                 }
                 // check to ensure that all inputs have the same rows and columns
                 if input.configs.rows as isize != rows || input.configs.columns as isize != columns
                 {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
+                                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
 
                 min_val = input.configs.minimum;
@@ -377,9 +346,9 @@ impl WhiteboxTool for WeightedOverlay {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Processing Factors (loop {} of {}): {}%",
-                                i, num_files, progress
+                            report::progress(
+                                &format!("Processing Factors (loop {} of {})", i, num_files),
+                                progress,
                             );
                             old_progress = progress;
                         }
@@ -403,7 +372,7 @@ impl WhiteboxTool for WeightedOverlay {
         for value in constraint_file_names {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    report::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_string();
@@ -416,7 +385,7 @@ impl WhiteboxTool for WeightedOverlay {
                 if input.configs.rows as isize != rows || input.configs.columns as isize != columns
                 {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
+                                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
 
                 for row in 0..rows {
@@ -433,9 +402,12 @@ impl WhiteboxTool for WeightedOverlay {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Processing Constraints (loop {} of {}): {}%",
-                                i, num_constraints, progress
+                            report::progress(
+                                &format!(
+                                    "Processing Constraints (loop {} of {})",
+                                    i, num_constraints
+                                ),
+                                progress,
                             );
                             old_progress = progress;
                         }
@@ -454,20 +426,17 @@ impl WhiteboxTool for WeightedOverlay {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,289 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! On-the-fly grid harmonization for tools with several raster inputs.
+//!
+//! Most tools with more than one raster input require that the inputs share the same
+//! grid, i.e. the same number of rows and columns and the same extent. When a tool is
+//! run with the `--harmonize` flag, input rasters that do not share a grid are
+//! resampled to a common grid before the tool is run. The common grid is aligned with
+//! the grid (and cell size) of the first raster input and covers the intersection of
+//! the input extents. Rasters with integer data types are resampled using the nearest
+//! neighbour method and floating-point rasters using bilinear interpolation. The
+//! harmonized rasters are held in memory and the input files are not modified.
+
+use raster::*;
+use serde_json;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tools::batch::get_flag_value_pairs;
+use tools::{ToolManager, WhiteboxTool};
+use utils::memory_store;
+use utils::report;
+
+/// The flag that requests harmonization of a tool's raster inputs.
+const HARMONIZE_FLAG: &'static str = "harmonize";
+
+/// Used to give the in-memory rasters of each harmonized run unique names.
+static NEXT_RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Removes the `--harmonize` flag from `args`, returning true if it was present.
+pub fn take_harmonize_flag(args: &mut Vec<String>) -> bool {
+    let len = args.len();
+    args.retain(|a| {
+        let flag = a.trim().trim_left_matches('-').to_lowercase();
+        flag != HARMONIZE_FLAG && flag != format!("{}=true", HARMONIZE_FLAG)
+    });
+    args.len() != len
+}
+
+/// Resamples the raster inputs of a tool run to a common grid, if they do not already
+/// share one. Returns the arguments of the run, in which the inputs are replaced by
+/// the names of the harmonized in-memory rasters, along with these names, which should
+/// be passed to `remove_harmonized_rasters` once the tool has run.
+pub fn harmonize_inputs<'a>(
+    tm: &ToolManager,
+    tool: &Box<WhiteboxTool + 'a>,
+    args: &[String],
+) -> Result<(Vec<String>, Vec<String>), Error> {
+    let (file_flags, list_flags) = get_raster_input_flags(tool);
+    let mut pairs = get_flag_value_pairs(args);
+
+    // find the raster inputs, in the order given
+    let mut input_files: Vec<String> = vec![];
+    let mut input_locations: Vec<(usize, usize)> = vec![]; // (pair, index within a list)
+    for j in 0..pairs.len() {
+        let flag = pairs[j].0.to_lowercase().replace("--", "-");
+        if let Some(ref v) = pairs[j].1 {
+            // constant values may be given in place of some raster inputs
+            if file_flags.contains(&flag) && !v.trim().is_empty() && v.trim().parse::<f64>().is_err()
+            {
+                input_files.push(get_file_path(v.trim(), &tm.working_dir));
+                input_locations.push((j, 0));
+            } else if list_flags.contains(&flag) {
+                let sep = if v.contains(";") { ";" } else { "," };
+                for (k, f) in v.split(sep).enumerate() {
+                    if !f.trim().is_empty() {
+                        input_files.push(get_file_path(f.trim(), &tm.working_dir));
+                        input_locations.push((j, k));
+                    }
+                }
+            }
+        }
+    }
+    if input_files.len() < 2 {
+        return Ok((args.to_vec(), vec![]));
+    }
+
+    // only the headers are read to compare the grids
+    let mut input_configs: Vec<RasterConfigs> = Vec::with_capacity(input_files.len());
+    for f in &input_files {
+        input_configs.push(Raster::read_configs(f)?);
+    }
+    if input_configs.iter().all(|c| same_grid(c, &input_configs[0])) {
+        return Ok((args.to_vec(), vec![]));
+    }
+
+    let configs = get_common_grid(&input_configs)?;
+    if tm.verbose {
+        report::warning(&format!(
+            "The input rasters do not share the same grid and have been resampled to a common grid of {} rows and {} columns.",
+            configs.rows, configs.columns
+        ));
+    }
+
+    // inputs that already have the common grid are read by the tool as they are
+    let run_id = NEXT_RUN_ID.fetch_add(1, Ordering::SeqCst);
+    let mut names: Vec<String> = Vec::with_capacity(input_files.len());
+    let mut harmonized_locations: Vec<(usize, usize)> = vec![];
+    for i in 0..input_files.len() {
+        if same_grid(&input_configs[i], &configs) {
+            continue;
+        }
+        let input = Raster::new(&input_files[i], "r")?;
+        let name = format!("memory://harmonized_{}_{}", run_id, i);
+        let harmonized = resample_to_grid(&input, &name, &configs);
+        memory_store::put_raster(&name, harmonized);
+        names.push(name);
+        harmonized_locations.push(input_locations[i]);
+    }
+
+    // substitute the harmonized rasters for the inputs
+    for i in 0..harmonized_locations.len() {
+        let (j, k) = harmonized_locations[i];
+        let value = pairs[j].1.clone().unwrap();
+        if list_flags.contains(&pairs[j].0.to_lowercase().replace("--", "-")) {
+            let sep = if value.contains(";") { ";" } else { "," };
+            let mut files: Vec<String> = value.split(sep).map(|s| s.to_string()).collect();
+            files[k] = names[i].clone();
+            pairs[j].1 = Some(files.join(";"));
+        } else {
+            pairs[j].1 = Some(names[i].clone());
+        }
+    }
+    let new_args = pairs
+        .iter()
+        .map(|&(ref flag, ref value)| match *value {
+            Some(ref v) => format!("{}={}", flag, v),
+            None => flag.clone(),
+        })
+        .collect();
+
+    Ok((new_args, names))
+}
+
+/// Frees the memory used by the harmonized rasters of a run.
+pub fn remove_harmonized_rasters(names: &[String]) {
+    for name in names {
+        memory_store::remove(name);
+    }
+}
+
+/// Returns the flags of a tool's raster input parameters, separated into those of
+/// single-file parameters and those of file-list parameters.
+fn get_raster_input_flags<'a>(wt: &Box<WhiteboxTool + 'a>) -> (Vec<String>, Vec<String>) {
+    let mut file_flags = vec![];
+    let mut list_flags = vec![];
+    if let Ok(p) = serde_json::from_str::<serde_json::Value>(&wt.get_tool_parameters()) {
+        if let Some(params) = p["parameters"].as_array() {
+            for param in params {
+                let flags = if param["parameter_type"]["ExistingFile"] == "Raster"
+                    || param["parameter_type"]["ExistingFileOrFloat"] == "Raster"
+                {
+                    &mut file_flags
+                } else if param["parameter_type"]["FileList"] == "Raster" {
+                    &mut list_flags
+                } else {
+                    continue;
+                };
+                if let Some(f) = param["flags"].as_array() {
+                    for flag in f {
+                        if let Some(flag) = flag.as_str() {
+                            flags.push(flag.to_lowercase().replace("--", "-"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    (file_flags, list_flags)
+}
+
+fn get_file_path(file_name: &str, working_dir: &str) -> String {
+    let sep = path::MAIN_SEPARATOR.to_string();
+    if !file_name.contains(&sep) && !file_name.contains("/") {
+        format!("{}{}", working_dir, file_name)
+    } else {
+        file_name.to_string()
+    }
+}
+
+/// Returns true if two grids have the same rows, columns, and (within a small fraction
+/// of a grid cell) extent.
+fn same_grid(a: &RasterConfigs, b: &RasterConfigs) -> bool {
+    let tolerance = 1e-6 * a.resolution_x.min(a.resolution_y);
+    a.rows == b.rows
+        && a.columns == b.columns
+        && (a.north - b.north).abs() <= tolerance
+        && (a.south - b.south).abs() <= tolerance
+        && (a.east - b.east).abs() <= tolerance
+        && (a.west - b.west).abs() <= tolerance
+}
+
+/// Returns the grid that is aligned with the first input and covers the intersection
+/// of the input extents.
+fn get_common_grid(inputs: &[RasterConfigs]) -> Result<RasterConfigs, Error> {
+    let mut north = f64::INFINITY;
+    let mut south = f64::NEG_INFINITY;
+    let mut east = f64::INFINITY;
+    let mut west = f64::NEG_INFINITY;
+    for input in inputs {
+        north = north.min(input.north);
+        south = south.max(input.south);
+        east = east.min(input.east);
+        west = west.max(input.west);
+    }
+
+    // snap the intersection inwards to the edges of the cells of the first input
+    let first = &inputs[0];
+    let (res_x, res_y) = (first.resolution_x, first.resolution_y);
+    let eps = 1e-6;
+    let first_col = ((west - first.west) / res_x - eps).ceil();
+    let last_col = ((east - first.west) / res_x + eps).floor();
+    let first_row = ((first.north - north) / res_y - eps).ceil();
+    let last_row = ((first.north - south) / res_y + eps).floor();
+    if last_col <= first_col || last_row <= first_row {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The input rasters cannot be harmonized because they do not overlap.",
+        ));
+    }
+
+    let mut configs = first.clone();
+    configs.columns = (last_col - first_col) as usize;
+    configs.rows = (last_row - first_row) as usize;
+    configs.west = first.west + first_col * res_x;
+    configs.east = first.west + last_col * res_x;
+    configs.north = first.north - first_row * res_y;
+    configs.south = first.north - last_row * res_y;
+    Ok(configs)
+}
+
+/// Resamples `input` to the grid described by `configs`, retaining the data type and
+/// other properties of `input`. Nodata cells take the output's nodata value.
+fn resample_to_grid(input: &Raster, name: &str, configs: &RasterConfigs) -> Raster {
+    let mut output_configs = input.configs.clone();
+    output_configs.rows = configs.rows;
+    output_configs.columns = configs.columns;
+    output_configs.north = configs.north;
+    output_configs.south = configs.south;
+    output_configs.east = configs.east;
+    output_configs.west = configs.west;
+    output_configs.resolution_x = configs.resolution_x;
+    output_configs.resolution_y = configs.resolution_y;
+    let mut output = Raster::initialize_using_config(name, &output_configs);
+    let use_nearest = !input.configs.data_type.is_float();
+    let nodata = input.configs.nodata;
+    for row in 0..configs.rows as isize {
+        let y = output.get_y_from_row(row);
+        for col in 0..configs.columns as isize {
+            let x = output.get_x_from_column(col);
+            let nearest = input.get_value(input.get_row_from_y(y), input.get_column_from_x(x));
+            if nearest == nodata {
+                continue;
+            }
+            if use_nearest {
+                output.set_value(row, col, nearest);
+                continue;
+            }
+            // bilinear interpolation, relative to the centres of the input cells
+            let row_src = (input.configs.north - y) / input.configs.resolution_y - 0.5;
+            let col_src = (x - input.configs.west) / input.configs.resolution_x - 0.5;
+            let (r0, c0) = (row_src.floor(), col_src.floor());
+            let (dy, dx) = (row_src - r0, col_src - c0);
+            let (r0, c0) = (r0 as isize, c0 as isize);
+            let z00 = input.get_value(r0, c0);
+            let z01 = input.get_value(r0, c0 + 1);
+            let z10 = input.get_value(r0 + 1, c0);
+            let z11 = input.get_value(r0 + 1, c0 + 1);
+            if z00 == nodata || z01 == nodata || z10 == nodata || z11 == nodata {
+                output.set_value(row, col, nearest);
+            } else {
+                let z = (z00 * (1f64 - dx) + z01 * dx) * (1f64 - dy)
+                    + (z10 * (1f64 - dx) + z11 * dx) * dy;
+                output.set_value(row, col, z);
+            }
+        }
+    }
+    output.update_min_max();
+    output.configs.display_min = output.configs.minimum;
+    output.configs.display_max = output.configs.maximum;
+    output
+}
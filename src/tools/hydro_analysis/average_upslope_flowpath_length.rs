@@ -123,37 +123,16 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -169,7 +148,7 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -258,7 +237,7 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    report::progress("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -301,6 +280,7 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         //output.reinitialize_values(1.0);
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
@@ -320,7 +300,7 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    report::progress("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -353,7 +333,7 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
                 row_n = row + d_y[dir as usize];
                 col_n = col + d_x[dir as usize];
                 length = output[(row, col)] + grid_lengths[dir as usize];
-                if output[(row_n, col_n)] == nodata {
+                if output[(row_n, col_n)] == out_nodata {
                     output[(row_n, col_n)] = length;
                 } else {
                     output.increment(row_n, col_n, length);
@@ -373,7 +353,7 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flowpath tracing: {}%", progress);
+                    report::progress("Flowpath tracing", progress);
                     old_progress = progress;
                 }
             }
@@ -390,26 +370,21 @@ impl WhiteboxTool for AverageUpslopeFlowpathLength {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
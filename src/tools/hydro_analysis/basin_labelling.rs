@@ -0,0 +1,175 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use num_cpus;
+use raster::*;
+use std::io::{Error, ErrorKind};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use structures::{Array2D, NibbleArray2D};
+use utils::report;
+
+/// The number of labelled cells that a thread accumulates before sending them to be written.
+const BATCH_SIZE: usize = 65_536;
+
+/// Converts a D8 flow pointer raster into flow directions, where each cell contains the
+/// index (0-7) of its downslope neighbour in the offsets dx = [1, 1, 1, 0, -1, -1, -1, 0]
+/// and dy = [-1, 0, 1, 1, 1, 0, -1, -1], -1 where it has no downslope neighbour, and -2 where
+/// the pointer is nodata.
+pub fn d8_flow_directions(pntr: &Raster, esri_style: bool) -> Result<Array2D<i8>, Error> {
+    let rows = pntr.configs.rows as isize;
+    let columns = pntr.configs.columns as isize;
+    let nodata = pntr.configs.nodata;
+
+    // Create a mapping from the pointer values to cells offsets.
+    let mut pntr_matches: [i8; 129] = [-3i8; 129];
+    let pointer_values = [1usize, 2, 4, 8, 16, 32, 64, 128];
+    for i in 0..8 {
+        pntr_matches[pointer_values[i]] = if !esri_style {
+            i as i8
+        } else {
+            ((i + 1) % 8) as i8
+        };
+    }
+
+    let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = pntr[(row, col)];
+            if z != nodata {
+                if z > 0.0 {
+                    if z > 128.0 || pntr_matches[z as usize] == -3i8 {
+                        return Err(Error::new(ErrorKind::InvalidInput,
+                            "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
+                    }
+                    flow_dir[(row, col)] = pntr_matches[z as usize];
+                } else {
+                    flow_dir[(row, col)] = -1i8;
+                }
+            }
+        }
+    }
+    Ok(flow_dir)
+}
+
+/// Assigns each unlabelled cell of `labels` (those containing `unlabelled`) the label of the
+/// first labelled cell that is encountered along its downslope flowpath, as given by the
+/// flow directions (see `d8_flow_directions`). Labelled cells, other than nodata cells,
+/// are the seeds from which labels are propagated upslope through the cells that drain to
+/// them. Because every cell drains to at most one seed, the seeds' upslope areas are
+/// disjoint and are traced concurrently. Cells that do not drain to a seed, including
+/// those whose flowpaths end at nodata cells or at unlabelled outlets, are assigned nodata.
+pub fn label_upslope_cells(
+    flow_dir: &Array2D<i8>,
+    labels: &mut Raster,
+    unlabelled: f64,
+    verbose: bool,
+) {
+    let rows = labels.configs.rows as isize;
+    let columns = labels.configs.columns as isize;
+    let nodata = labels.configs.nodata;
+
+    // The flow directions of the unlabelled cells, through which labels are propagated;
+    // all other cells are given a value (15) that never matches an inflowing direction.
+    let mut seeds = vec![];
+    let mut upslope_dir = NibbleArray2D::new(rows, columns, 15, 15).unwrap();
+    let mut num_unlabelled = 0usize;
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = labels[(row, col)];
+            if z == unlabelled {
+                if flow_dir[(row, col)] >= 0 {
+                    upslope_dir.set_value(row, col, flow_dir[(row, col)] as u8);
+                }
+                num_unlabelled += 1;
+            } else if z != nodata {
+                seeds.push((row, col, z));
+            }
+        }
+    }
+    let seeds = Arc::new(seeds);
+    let upslope_dir = Arc::new(upslope_dir);
+
+    let next_seed = Arc::new(Mutex::new(0usize));
+    let num_procs = num_cpus::get();
+    let (tx, rx) = mpsc::channel();
+    for _ in 0..num_procs {
+        let upslope_dir = upslope_dir.clone();
+        let seeds = seeds.clone();
+        let next_seed = next_seed.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+            let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let inflowing_vals: [u8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
+            let mut stack = vec![];
+            let (mut row_n, mut col_n): (isize, isize);
+            loop {
+                let s = {
+                    let mut next = next_seed.lock().unwrap();
+                    *next += 1;
+                    *next - 1
+                };
+                if s >= seeds.len() {
+                    break;
+                }
+                let (row, col, label) = seeds[s];
+                let mut cells = vec![];
+                stack.push((row, col));
+                while let Some((r, c)) = stack.pop() {
+                    for i in 0..8 {
+                        row_n = r + dy[i];
+                        col_n = c + dx[i];
+                        if upslope_dir.get_value(row_n, col_n) == inflowing_vals[i] {
+                            stack.push((row_n, col_n));
+                            cells.push((row_n, col_n));
+                        }
+                    }
+                    if cells.len() >= BATCH_SIZE {
+                        tx.send((label, cells)).unwrap();
+                        cells = vec![];
+                    }
+                }
+                if !cells.is_empty() {
+                    tx.send((label, cells)).unwrap();
+                }
+            }
+        });
+    }
+    drop(tx);
+
+    let mut num_solved = 0usize;
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+    for (label, cells) in rx {
+        num_solved += cells.len();
+        for (row, col) in cells {
+            labels[(row, col)] = label;
+        }
+        if verbose && num_unlabelled > 0 {
+            progress = (100.0_f64 * num_solved as f64 / num_unlabelled as f64) as usize;
+            if progress != old_progress {
+                report::progress("Labelling basins", progress);
+                old_progress = progress;
+            }
+        }
+    }
+
+    if num_solved < num_unlabelled {
+        for row in 0..rows {
+            for col in 0..columns {
+                if labels[(row, col)] == unlabelled {
+                    labels[(row, col)] = nodata;
+                }
+            }
+        }
+    }
+}
@@ -2,16 +2,16 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 1, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::basin_labelling::{d8_flow_directions, label_upslope_cells};
 use raster::*;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::Array2D;
 use tools::*;
 
 pub struct Basins {
@@ -127,37 +127,19 @@ impl WhiteboxTool for Basins {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-d8_pntr" {
-                d8_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -173,7 +155,7 @@ impl WhiteboxTool for Basins {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -184,134 +166,38 @@ impl WhiteboxTool for Basins {
         let columns = pntr.configs.columns as isize;
         let nodata = pntr.configs.nodata;
 
-        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-
-        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
+        let flow_dir = d8_flow_directions(&pntr, esri_style)?;
         let mut output = Raster::initialize_using_file(&output_file, &pntr);
+        let out_nodata = output.configs.nodata;
         output.configs.data_type = DataType::F32;
         output.configs.palette = "qual.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
         let low_value = f64::MIN;
         output.reinitialize_values(low_value);
 
-        // Create a mapping from the pointer values to cells offsets.
-        // This may seem wasteful, using only 8 of 129 values in the array,
-        // but the mapping method is far faster than calculating z.ln() / ln(2.0).
-        // It's also a good way of allowing for different point styles.
-        let mut pntr_matches: [i8; 129] = [0i8; 129];
-        if !esri_style {
-            // This maps Whitebox-style D8 pointer values
-            // onto the cell offsets in d_x and d_y.
-            pntr_matches[1] = 0i8;
-            pntr_matches[2] = 1i8;
-            pntr_matches[4] = 2i8;
-            pntr_matches[8] = 3i8;
-            pntr_matches[16] = 4i8;
-            pntr_matches[32] = 5i8;
-            pntr_matches[64] = 6i8;
-            pntr_matches[128] = 7i8;
-        } else {
-            // This maps Esri-style D8 pointer values
-            // onto the cell offsets in d_x and d_y.
-            pntr_matches[1] = 1i8;
-            pntr_matches[2] = 2i8;
-            pntr_matches[4] = 3i8;
-            pntr_matches[8] = 4i8;
-            pntr_matches[16] = 5i8;
-            pntr_matches[32] = 6i8;
-            pntr_matches[64] = 7i8;
-            pntr_matches[128] = 0i8;
-        }
-
+        // each cell without a downslope neighbour is the outlet of a basin
         let mut basin_id = 0f64;
-        let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                z = pntr[(row, col)];
-                if z != nodata {
-                    if z > 0.0 {
-                        flow_dir[(row, col)] = pntr_matches[z as usize];
-                    } else {
-                        flow_dir[(row, col)] = -1i8;
+                if pntr[(row, col)] != nodata {
+                    if flow_dir[(row, col)] == -1i8 {
                         basin_id += 1f64;
                         output[(row, col)] = basin_id;
                     }
                 } else {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing: {}%", progress);
+                    report::progress("Initializing", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        let mut flag: bool;
-        let (mut x, mut y): (isize, isize);
-        let mut dir: i8;
-        let mut outlet_id: f64;
-        for row in 0..rows {
-            for col in 0..columns {
-                if output[(row, col)] == low_value {
-                    // && flow_dir[(row, col)] != -2i8 {
-                    flag = false;
-                    x = col;
-                    y = row;
-                    outlet_id = nodata;
-                    while !flag {
-                        // find its downslope neighbour
-                        dir = flow_dir[(y, x)];
-                        if dir >= 0 {
-                            // move x and y accordingly
-                            x += dx[dir as usize];
-                            y += dy[dir as usize];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
-                            if z != low_value {
-                                outlet_id = z;
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                    }
-
-                    flag = false;
-                    x = col;
-                    y = row;
-                    output[(y, x)] = outlet_id;
-                    while !flag {
-                        // find its downslope neighbour
-                        dir = flow_dir[(y, x)];
-                        if dir >= 0 {
-                            // move x and y accordingly
-                            x += dx[dir as usize];
-                            y += dy[dir as usize];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                        output[(y, x)] = outlet_id;
-                    }
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        label_upslope_cells(&flow_dir, &mut output, low_value, verbose);
 
         let elapsed_time = get_formatted_elapsed_time(start);
         output.add_metadata_entry(format!(
@@ -322,19 +208,16 @@ impl WhiteboxTool for Basins {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -147,55 +147,24 @@ impl WhiteboxTool for BreachDepressions {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-max_depth"
-                || vec[0].to_lowercase() == "--max_depth"
-            {
-                if keyval {
-                    max_depth = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_depth = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                constrained_mode = true;
-            } else if vec[0].to_lowercase() == "-max_length"
-                || vec[0].to_lowercase() == "--max_length"
-            {
-                if keyval {
-                    max_length = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_length = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                constrained_mode = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_depth"])? {
+            max_depth = v;
+            constrained_mode = true;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_length"])? {
+            max_length = v;
+            constrained_mode = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -211,11 +180,11 @@ impl WhiteboxTool for BreachDepressions {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         if verbose && constrained_mode {
-            println!("Breaching in constrained mode...");
+            report::message("Breaching in constrained mode...");
         }
 
         let input = Raster::new(&input_file, "r")?;
@@ -232,6 +201,7 @@ impl WhiteboxTool for BreachDepressions {
         let small_num = 1.0 / elev_multiplier as f64;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let background_val = (i32::min_value() + 1) as f64;
         output.reinitialize_values(background_val);
 
@@ -289,7 +259,7 @@ impl WhiteboxTool for BreachDepressions {
                 zout_n = output[(row_n, col_n)];
                 if zout_n == background_val {
                     if zin_n == nodata {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         queue.push_back((row_n, col_n));
                     } else {
                         output[(row_n, col_n)] = zin_n;
@@ -307,7 +277,7 @@ impl WhiteboxTool for BreachDepressions {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("progress: {}%", progress);
+                    report::progress("progress", progress);
                     old_progress = progress;
                 }
             }
@@ -380,7 +350,7 @@ impl WhiteboxTool for BreachDepressions {
                             }
                         } else {
                             // Interior nodata cells are still treated as nodata and are not filled.
-                            output[(row_n, col_n)] = nodata;
+                            output[(row_n, col_n)] = out_nodata;
                             num_solved_cells += 1;
                         }
                     }
@@ -391,7 +361,7 @@ impl WhiteboxTool for BreachDepressions {
                     progress =
                         (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -475,7 +445,7 @@ impl WhiteboxTool for BreachDepressions {
                             }
                         } else {
                             // Interior nodata cells are still treated as nodata and are not filled.
-                            output[(row_n, col_n)] = nodata;
+                            output[(row_n, col_n)] = out_nodata;
                             num_solved_cells += 1;
                         }
                     }
@@ -486,14 +456,14 @@ impl WhiteboxTool for BreachDepressions {
                     progress =
                         (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if unresolved_pits && verbose {
-                println!("There were unbreached depressions. The result should be filled to remove additional depressions.");
+                report::message("There were unbreached depressions. The result should be filled to remove additional depressions.");
             }
         }
 
@@ -508,19 +478,16 @@ impl WhiteboxTool for BreachDepressions {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
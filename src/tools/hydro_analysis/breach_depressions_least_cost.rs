@@ -0,0 +1,707 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+const MAX_PASSES: usize = 10;
+
+/// This tool removes the depressions in a digital elevation model (DEM) by breaching, i.e.
+/// carving channels through the barriers that enclose them, along least-cost paths. The
+/// `BreachDepressions` tool breaches each depression along the path that a priority-flood
+/// operation first reaches it by, which can sometimes produce long, deep trenches that are
+/// unrealistic. This tool instead searches outward from each pit cell, i.e. a cell without
+/// lower neighbours, for the breach channel that requires the least modification of the DEM
+/// (Lindsay and Dhun, 2015). Breach channels are constrained to a maximum length in grid
+/// cells (`--max_length`).
+///
+/// Depressions are often best removed using a combination of breaching and filling (Lindsay,
+/// 2016). Once the least-cost breach path has been found, the tool therefore also considers
+/// raising the water level in the depression, i.e. partially filling it, before breaching
+/// at the higher level, which reduces the depth of the breach channel. The level that
+/// minimizes the total modification volume, i.e. the volume of the fill plus the volume of
+/// the cut, is chosen. The breach solution may be constrained by a maximum breach channel
+/// depth (`--max_depth`) and a maximum total modification volume (`--max_cost`). Pits that
+/// cannot be resolved within the constraints are left unaltered, unless the `--fill` flag
+/// is specified, in which case all remaining depressions are filled after breaching.
+///
+/// Breach channels, and filled depressions, are given a small gradient (`--flat_increment`)
+/// to ensure that they drain. If unspecified, the increment is determined from the
+/// elevation range of the DEM.
+///
+/// # References
+/// Lindsay, J. B., & Dhun, K. (2015). Modelling surface drainage patterns in altered
+/// landscapes using LiDAR. *International Journal of Geographical Information Science*,
+/// 29(3), 397-411.
+///
+/// Lindsay, J. B. (2016). Efficient hybrid breaching-filling sink removal methods for
+/// flow path enforcement in digital elevation models. *Hydrological Processes*, 30(6),
+/// 846-857.
+///
+/// # See Also
+/// `BreachDepressions`, `FillDepressions`, `BreachSingleCellPits`
+pub struct BreachDepressionsLeastCost {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl BreachDepressionsLeastCost {
+    pub fn new() -> BreachDepressionsLeastCost {
+        // public constructor
+        let name = "BreachDepressionsLeastCost".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description = "Breaches the depressions in a DEM along least-cost paths, partially filling depressions where this reduces the total modification.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Breach Channel Length (grid cells)".to_owned(),
+            flags: vec!["--max_length".to_owned()],
+            description: "Maximum breach channel length, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("100".to_owned()),
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Breach Depth (z units)".to_owned(),
+            flags: vec!["--max_depth".to_owned()],
+            description: "Optional maximum breach channel depth (default is Inf).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Modification Volume".to_owned(),
+            flags: vec!["--max_cost".to_owned()],
+            description: "Optional maximum fill plus cut volume of a breach (default is Inf)."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flat Increment Value (z units)".to_owned(),
+            flags: vec!["--flat_increment".to_owned()],
+            description: "Optional elevation increment applied to breach channels and filled areas."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Fill unbreached depressions?".to_owned(),
+            flags: vec!["--fill".to_owned()],
+            description: "Optional flag indicating whether to fill any remaining unbreached depressions.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --max_length=50 --max_depth=3.0 --fill",
+            short_exe, name
+        ).replace("*", &sep);
+
+        BreachDepressionsLeastCost {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for BreachDepressionsLeastCost {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut max_length = 100isize;
+        let mut max_depth = f64::INFINITY;
+        let mut max_cost = f64::INFINITY;
+        let mut flat_increment = f64::NAN;
+        let mut fill = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--max_length"])? {
+            max_length = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_depth"])? {
+            max_depth = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_cost"])? {
+            max_cost = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--flat_increment"])? {
+            flat_increment = v;
+        }
+        if tool_args.get_flag(&["--fill"])? {
+            fill = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if max_length < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The maximum breach channel length must be at least one grid cell.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let cell_area = input.configs.resolution_x * input.configs.resolution_y;
+
+        let small_num = if flat_increment.is_finite() && flat_increment > 0f64 {
+            flat_increment
+        } else {
+            let min_val = input.configs.minimum;
+            let elev_digits = ((input.configs.maximum - min_val) as i64).to_string().len();
+            let elev_multiplier = 10.0_f64.powi((5 - elev_digits) as i32);
+            1.0 / elev_multiplier as f64
+        };
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let mut dem: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        for row in 0..rows {
+            dem.set_row_data(row, input.get_row_data(row));
+        }
+
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+
+        let (mut z, mut z_n): (f64, f64);
+        let mut is_pit: bool;
+        let mut num_pits = 0;
+        let mut num_breached = 0;
+        let mut num_hybrid = 0;
+        let mut num_unresolved: usize;
+        let mut pass = 0;
+        loop {
+            /*
+            Find the pits, i.e. interior cells without a lower neighbour. Breaching
+            may occasionally leave behind small pits, e.g. where a filled area is
+            graded above a neighbouring cell, and so the search is repeated until
+            no further pits can be resolved.
+            */
+            let mut pits = vec![];
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = dem.get_value(row, col);
+                    if z != nodata {
+                        is_pit = true;
+                        for n in 0..8 {
+                            z_n = dem.get_value(row + dy[n], col + dx[n]);
+                            if z_n == nodata || z_n < z {
+                                is_pit = false;
+                                break;
+                            }
+                        }
+                        if is_pit {
+                            pits.push((z, row, col));
+                        }
+                    }
+                }
+                if verbose {
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Finding pits", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+            // lower pits are solved first, since higher pits may drain to them
+            pits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            if pits.is_empty() {
+                num_unresolved = 0;
+                break;
+            }
+            if pass == 0 {
+                num_pits = pits.len();
+            }
+            let num_resolved = num_breached + num_hybrid;
+            num_unresolved = 0;
+            for p in 0..pits.len() {
+                let (_, row, col) = pits[p];
+                z = dem.get_value(row, col);
+                // the pit may have been resolved by an earlier breach
+                is_pit = true;
+                for n in 0..8 {
+                    z_n = dem.get_value(row + dy[n], col + dx[n]);
+                    if z_n == nodata || z_n < z {
+                        is_pit = false;
+                        break;
+                    }
+                }
+                if !is_pit {
+                    continue;
+                }
+
+                let path = match find_breach_path(
+                    &dem, row, col, max_length, max_cost, small_num, cell_area, nodata,
+                ) {
+                    Some(p) => p,
+                    None => {
+                        num_unresolved += 1;
+                        continue;
+                    }
+                };
+                let path_z: Vec<f64> = path.iter().map(|&(r, c)| dem.get_value(r, c)).collect();
+
+                // Choose the breach level, between the pit elevation and the level at which
+                // no cutting is needed, that minimizes the fill plus cut volume.
+                let mut levels = vec![z];
+                for i in 1..path.len() {
+                    let level = path_z[i] + i as f64 * small_num;
+                    if level > z {
+                        levels.push(level);
+                    }
+                }
+                levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                levels.dedup();
+                let max_region = ((2 * max_length + 1) * (2 * max_length + 1)) as usize;
+                let mut best: Option<(f64, f64, Vec<(isize, isize, usize)>)> = None;
+                for &level in &levels {
+                    let mut cut = 0f64;
+                    let mut max_cut = 0f64;
+                    for i in 1..path.len() {
+                        let d = path_z[i] - (level - i as f64 * small_num);
+                        if d > 0f64 {
+                            cut += d * cell_area;
+                            if d > max_cut {
+                                max_cut = d;
+                            }
+                        }
+                    }
+                    if max_cut > max_depth {
+                        continue;
+                    }
+                    let (region, fill_vol) =
+                        match flood_region(&dem, row, col, level, max_region, nodata) {
+                            Some(v) => v,
+                            None => break, // higher levels will also spill out of the region
+                        };
+                    let total = cut + fill_vol;
+                    if total > max_cost {
+                        continue;
+                    }
+                    let replace = match best {
+                        Some((c, _, _)) => total < c,
+                        None => true,
+                    };
+                    if replace {
+                        best = Some((total, level, region));
+                    }
+                }
+
+                let (level, region) = match best {
+                    Some((_, level, region)) => (level, region),
+                    None => {
+                        num_unresolved += 1;
+                        continue;
+                    }
+                };
+
+                // Fill the depression up to the breach level, grading the filled area towards
+                // the pit, and then carve the breach channel.
+                for &(r, c, d) in &region {
+                    dem.set_value(r, c, level + d as f64 * small_num);
+                }
+                dem.set_value(row, col, level);
+                for i in 1..path.len() {
+                    let (r, c) = path[i];
+                    let level_i = level - i as f64 * small_num;
+                    if dem.get_value(r, c) > level_i {
+                        dem.set_value(r, c, level_i);
+                    }
+                }
+                if region.len() > 1 {
+                    num_hybrid += 1;
+                } else {
+                    num_breached += 1;
+                }
+
+                if verbose {
+                    progress = (100.0_f64 * p as f64 / (pits.len() - 1).max(1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Breaching", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+
+            pass += 1;
+            if num_breached + num_hybrid == num_resolved || pass == MAX_PASSES {
+                break;
+            }
+        }
+
+        // Fill any remaining depressions using a priority-flood operation.
+        if fill && num_unresolved > 0 {
+            let mut solved: Array2D<i8> = Array2D::new(rows, columns, 0, 1)?;
+            let mut minheap = BinaryHeap::new();
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = dem.get_value(row, col);
+                    if z == nodata {
+                        solved.set_value(row, col, 1);
+                    } else if is_edge(&dem, row, col, nodata) {
+                        solved.set_value(row, col, 1);
+                        minheap.push(CostCell {
+                            row: row,
+                            column: col,
+                            priority: z,
+                        });
+                    }
+                }
+            }
+            while let Some(cell) = minheap.pop() {
+                z = dem.get_value(cell.row, cell.column);
+                for n in 0..8 {
+                    let (row_n, col_n) = (cell.row + dy[n], cell.column + dx[n]);
+                    if solved.get_value(row_n, col_n) == 0 {
+                        solved.set_value(row_n, col_n, 1);
+                        z_n = dem.get_value(row_n, col_n);
+                        if z_n <= z {
+                            z_n = z + small_num;
+                            dem.set_value(row_n, col_n, z_n);
+                        }
+                        minheap.push(CostCell {
+                            row: row_n,
+                            column: col_n,
+                            priority: z_n,
+                        });
+                    }
+                }
+            }
+        }
+
+        let out_nodata = output.configs.nodata;
+        for row in 0..rows {
+            let mut data = dem.get_row_data(row);
+            for z in data.iter_mut() {
+                if *z == nodata {
+                    *z = out_nodata;
+                }
+            }
+            output.set_row_data(row, data);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.display_min = input.configs.display_min;
+        output.configs.display_max = input.configs.display_max;
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Maximum breach length: {}", max_length));
+        output.add_metadata_entry(format!("Maximum breach depth: {}", max_depth));
+        output.add_metadata_entry(format!("Maximum breach cost: {}", max_cost));
+        output.add_metadata_entry(format!("Flat increment: {}", small_num));
+        output.add_metadata_entry(format!("Fill unbreached depressions: {}", fill));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::metadata("Number of pits", &num_pits.to_string());
+            report::metadata("Number of breached pits", &num_breached.to_string());
+            report::metadata(
+                "Number of partially filled and breached pits",
+                &num_hybrid.to_string(),
+            );
+            if fill {
+                report::metadata("Number of filled pits", &num_unresolved.to_string());
+            } else {
+                report::metadata("Number of unresolved pits", &num_unresolved.to_string());
+            }
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns true if a cell is adjacent to a nodata cell or the edge of the grid.
+pub fn is_edge(dem: &Array2D<f64>, row: isize, col: isize, nodata: f64) -> bool {
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    for n in 0..8 {
+        if dem.get_value(row + dy[n], col + dx[n]) == nodata {
+            return true;
+        }
+    }
+    false
+}
+
+/// Searches outward from a pit for the least-cost breach path, with the cost of a cell
+/// being the volume of cut required to lower it to the breach channel, which descends from
+/// the pit elevation by `small_num` per cell. The path ends at the first cell that is lower than
+/// the breach channel would be at that point, or that is at the edge of the data, and is
+/// returned from the pit to this outlet. Returns None if no such path exists within
+/// `max_length` cells and `max_cost`.
+pub fn find_breach_path(
+    dem: &Array2D<f64>,
+    row: isize,
+    col: isize,
+    max_length: isize,
+    max_cost: f64,
+    small_num: f64,
+    cell_area: f64,
+    nodata: f64,
+) -> Option<Vec<(isize, isize)>> {
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let z = dem.get_value(row, col);
+    let mut z_n: f64;
+    let mut visited: HashMap<(isize, isize), (f64, isize, (isize, isize))> = HashMap::new();
+    let mut minheap = BinaryHeap::new();
+    visited.insert((row, col), (0f64, 0, (row, col)));
+    minheap.push(CostCell {
+        row: row,
+        column: col,
+        priority: 0f64,
+    });
+    let mut outlet = None;
+    while let Some(cell) = minheap.pop() {
+        let (cost, steps, _) = visited[&(cell.row, cell.column)];
+        if cost < cell.priority {
+            continue; // a cheaper path to this cell was already found
+        }
+        if steps > 0 {
+            z_n = dem.get_value(cell.row, cell.column);
+            // the outlet must be lower than the breach channel would be at this point
+            if z_n < z - steps as f64 * small_num || is_edge(dem, cell.row, cell.column, nodata) {
+                outlet = Some((cell.row, cell.column));
+                break;
+            }
+        }
+        if steps == max_length {
+            continue;
+        }
+        for n in 0..8 {
+            let (row_n, col_n) = (cell.row + dy[n], cell.column + dx[n]);
+            z_n = dem.get_value(row_n, col_n);
+            if z_n == nodata {
+                continue;
+            }
+            let new_cost = cost
+                + (z_n - (z - (steps + 1) as f64 * small_num)).max(0f64) * cell_area;
+            let better = match visited.get(&(row_n, col_n)) {
+                Some(&(c, _, _)) => new_cost < c,
+                None => true,
+            };
+            if better && new_cost <= max_cost {
+                visited.insert((row_n, col_n), (new_cost, steps + 1, (cell.row, cell.column)));
+                minheap.push(CostCell {
+                    row: row_n,
+                    column: col_n,
+                    priority: new_cost,
+                });
+            }
+        }
+    }
+
+    let outlet = match outlet {
+        Some(o) => o,
+        None => return None,
+    };
+
+    // Retrieve the path, from the pit to the outlet.
+    let mut path = vec![outlet];
+    let mut cell = outlet;
+    while cell != (row, col) {
+        cell = visited[&cell].2;
+        path.push(cell);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Finds the cells that are connected to a pit and lower than `level`, along with their
+/// distance from the pit in cells, and the volume needed to fill them to `level`. Returns
+/// None if the flooded region exceeds `max_cells`, reaches the edge of the data, or spills
+/// into cells that are lower than the pit.
+pub fn flood_region(
+    dem: &Array2D<f64>,
+    row: isize,
+    col: isize,
+    level: f64,
+    max_cells: usize,
+    nodata: f64,
+) -> Option<(Vec<(isize, isize, usize)>, f64)> {
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let mut region = vec![];
+    let mut in_region: HashMap<(isize, isize), bool> = HashMap::new();
+    let mut queue = VecDeque::new();
+    let mut volume = 0f64;
+    let z_pit = dem.get_value(row, col);
+    queue.push_back((row, col, 0usize));
+    in_region.insert((row, col), true);
+    while let Some((r, c, d)) = queue.pop_front() {
+        volume += level - dem.get_value(r, c);
+        region.push((r, c, d));
+        if region.len() > max_cells {
+            return None;
+        }
+        for n in 0..8 {
+            let (r_n, c_n) = (r + dy[n], c + dx[n]);
+            let z_n = dem.get_value(r_n, c_n);
+            if z_n == nodata || z_n < z_pit {
+                return None;
+            }
+            if z_n < level && !in_region.contains_key(&(r_n, c_n)) {
+                in_region.insert((r_n, c_n), true);
+                queue.push_back((r_n, c_n, d + 1));
+            }
+        }
+    }
+    Some((region, volume))
+}
+
+#[derive(PartialEq, Debug)]
+pub struct CostCell {
+    pub row: isize,
+    pub column: isize,
+    pub priority: f64,
+}
+
+impl Eq for CostCell {}
+
+impl PartialOrd for CostCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.priority.partial_cmp(&self.priority)
+    }
+}
+
+impl Ord for CostCell {
+    fn cmp(&self, other: &CostCell) -> Ordering {
+        let ord = self.partial_cmp(other).unwrap();
+        match ord {
+            Ordering::Greater => Ordering::Less,
+            Ordering::Less => Ordering::Greater,
+            Ordering::Equal => ord,
+        }
+    }
+}
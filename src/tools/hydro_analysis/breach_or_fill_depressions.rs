@@ -0,0 +1,739 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::breach_depressions_least_cost::{find_breach_path, flood_region, is_edge, CostCell};
+use raster::*;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+const MAX_PASSES: usize = 10;
+
+/// This tool removes the depressions in a digital elevation model (DEM) by choosing, for
+/// each depression, whether to fill it, to breach it, or to partially fill and then breach
+/// it, according to an objective that is weighted by the user. Where `FillDepressions` and
+/// `BreachDepressions` apply a single method to every depression, and
+/// `BreachDepressionsLeastCost` minimizes the modification volume alone, this tool gives
+/// explicit control of the trade-off between the different ways in which conditioning
+/// alters a DEM.
+///
+/// Depressions are solved from their pits, i.e. cells without lower neighbours, in order of
+/// increasing elevation. For each pit, the tool considers filling the depression up to its
+/// spill elevation, and breaching it along the least-cost breach channel, which is found
+/// within a maximum length in grid cells (`--max_length`), at each of the water levels
+/// between the pit elevation and the level at which no cutting is needed (see
+/// `BreachDepressionsLeastCost`). Each candidate solution is measured by the volume of
+/// the DEM that it alters (fill plus cut), the number of cells that it alters, and the
+/// maximum modification of any one cell. The solution that minimizes the weighted sum of
+/// these measures, each relative to that of filling the depression, is applied:
+///
+/// > objective = (*w_v* *V* / *V_fill* + *w_n* *N* / *N_fill* + *w_m* *M* / *M_fill*) /
+/// (*w_v* + *w_n* + *w_m*)
+///
+/// where the weights *w_v*, *w_n*, and *w_m* are set by the `--volume_weight`,
+/// `--cells_weight`, and `--max_mod_weight` parameters. Filling therefore always has an
+/// objective of 1.0. Weighting volume alone favours the least total modification;
+/// weighting the number of cells favours narrow breach channels over broad fills; and
+/// weighting the maximum modification favours shallow fills over deep breach channels.
+/// Filling is always possible, and so all depressions are removed. Breach channels, and
+/// filled depressions, are given a small gradient (`--flat_increment`) to ensure that
+/// they drain. If unspecified, the increment is determined from the elevation range of the
+/// DEM.
+///
+/// Optionally, a CSV report of the modifications (`--report`) may be output, with one row
+/// per solved depression containing its pit location and elevation, the chosen method
+/// (fill, breach, or hybrid, i.e. partial filling and breaching), the water level, the
+/// volume, number of cells, and maximum modification, and the objective of the chosen
+/// solution and of the best breach solution, if one was found.
+///
+/// # Reference
+/// Lindsay, J. B. (2016). Efficient hybrid breaching-filling sink removal methods for
+/// flow path enforcement in digital elevation models. *Hydrological Processes*, 30(6),
+/// 846-857.
+///
+/// # See Also
+/// `BreachDepressionsLeastCost`, `FillDepressions`, `BreachDepressions`
+pub struct BreachOrFillDepressions {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl BreachOrFillDepressions {
+    pub fn new() -> BreachOrFillDepressions {
+        // public constructor
+        let name = "BreachOrFillDepressions".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description = "Removes the depressions in a DEM by filling, breaching, or partially filling and breaching each, minimizing a user-weighted objective.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Breach Channel Length (grid cells)".to_owned(),
+            flags: vec!["--max_length".to_owned()],
+            description: "Maximum breach channel length, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("100".to_owned()),
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Modification Volume Weight".to_owned(),
+            flags: vec!["--volume_weight".to_owned()],
+            description: "Weight of the volume of the DEM altered in the objective.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Modified Cells Weight".to_owned(),
+            flags: vec!["--cells_weight".to_owned()],
+            description: "Weight of the number of cells altered in the objective.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Modification Weight".to_owned(),
+            flags: vec!["--max_mod_weight".to_owned()],
+            description: "Weight of the maximum modification of a cell in the objective."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flat Increment Value (z units)".to_owned(),
+            flags: vec!["--flat_increment".to_owned()],
+            description: "Optional elevation increment applied to breach channels and filled areas."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Modification Report File (optional)".to_owned(),
+            flags: vec!["--report".to_owned()],
+            description: "Optional output CSV report of the modification of each depression."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --max_length=50 --volume_weight=1.0 --cells_weight=0.5 --max_mod_weight=0.5 --report=report.csv",
+            short_exe, name
+        ).replace("*", &sep);
+
+        BreachOrFillDepressions {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for BreachOrFillDepressions {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut max_length = 100isize;
+        let mut volume_weight = 1f64;
+        let mut cells_weight = 0f64;
+        let mut max_mod_weight = 0f64;
+        let mut flat_increment = f64::NAN;
+        let mut report_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--max_length"])? {
+            max_length = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--volume_weight"])? {
+            volume_weight = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cells_weight"])? {
+            cells_weight = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_mod_weight"])? {
+            max_mod_weight = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--flat_increment"])? {
+            flat_increment = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--report"])? {
+            report_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !report_file.is_empty() && !report_file.contains(&sep) && !report_file.contains("/") {
+            report_file = format!("{}{}", working_directory, report_file);
+        }
+        if max_length < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The maximum breach channel length must be at least one grid cell.",
+            ));
+        }
+        let weights = [volume_weight, cells_weight, max_mod_weight];
+        if weights.iter().any(|w| !(*w >= 0f64)) || weights.iter().sum::<f64>() <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The objective weights must be non-negative and at least one must be positive.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let cell_area = input.configs.resolution_x * input.configs.resolution_y;
+
+        let small_num = if flat_increment.is_finite() && flat_increment > 0f64 {
+            flat_increment
+        } else {
+            let min_val = input.configs.minimum;
+            let elev_digits = ((input.configs.maximum - min_val) as i64).to_string().len();
+            let elev_multiplier = 10.0_f64.powi((5 - elev_digits) as i32);
+            1.0 / elev_multiplier as f64
+        };
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let mut dem: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        for row in 0..rows {
+            dem.set_row_data(row, input.get_row_data(row));
+        }
+
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let is_pit = |dem: &Array2D<f64>, row: isize, col: isize| -> bool {
+            let z = dem.get_value(row, col);
+            if z == nodata {
+                return false;
+            }
+            for n in 0..8 {
+                let z_n = dem.get_value(row + dy[n], col + dx[n]);
+                if z_n == nodata || z_n < z {
+                    return false;
+                }
+            }
+            true
+        };
+
+        let max_region = ((2 * max_length + 1) * (2 * max_length + 1)) as usize;
+        let mut z: f64;
+        let mut num_pits = 0;
+        let mut num_filled = 0;
+        let mut num_breached = 0;
+        let mut num_hybrid = 0;
+        let mut total = Modification::default();
+        let mut report: Vec<ReportEntry> = vec![];
+        let mut pass = 0;
+        loop {
+            /*
+            Find the pits. Grading filled areas and breach channels may occasionally
+            leave behind small pits, and so the search is repeated until none remain.
+            */
+            let mut pits = vec![];
+            for row in 0..rows {
+                for col in 0..columns {
+                    if is_pit(&dem, row, col) {
+                        pits.push((dem.get_value(row, col), row, col));
+                    }
+                }
+                if verbose {
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Finding pits", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+            // lower pits are solved first, since higher pits may drain to them
+            pits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            if pits.is_empty() {
+                break;
+            }
+            if pass == 0 {
+                num_pits = pits.len();
+            }
+            for p in 0..pits.len() {
+                let (_, row, col) = pits[p];
+                // the pit may have been resolved by an earlier solution
+                if !is_pit(&dem, row, col) {
+                    continue;
+                }
+                z = dem.get_value(row, col);
+
+                // Filling is always possible and is the reference for the objective.
+                let (fill_cells, fill_level) = fill_depression(&dem, row, col, small_num, nodata);
+                let reference = Modification::measure(&dem, &fill_cells, cell_area);
+                let objective = |m: &Modification| -> f64 {
+                    let ratio = |a: f64, b: f64| if b > 0f64 { a / b } else { a };
+                    (volume_weight * ratio(m.volume, reference.volume)
+                        + cells_weight * ratio(m.num_cells as f64, reference.num_cells as f64)
+                        + max_mod_weight * ratio(m.max_mod, reference.max_mod))
+                        / (volume_weight + cells_weight + max_mod_weight)
+                };
+
+                // Evaluate breaching along the least-cost path at each level between the pit
+                // elevation and the level at which no cutting is needed.
+                let mut best_breach: Option<(f64, f64, Vec<(isize, isize, f64)>, bool)> = None;
+                if let Some(path) = find_breach_path(
+                    &dem, row, col, max_length, f64::INFINITY, small_num, cell_area, nodata,
+                ) {
+                    let path_z: Vec<f64> =
+                        path.iter().map(|&(r, c)| dem.get_value(r, c)).collect();
+                    let mut levels = vec![z];
+                    for i in 1..path.len() {
+                        let level = path_z[i] + i as f64 * small_num;
+                        if level > z {
+                            levels.push(level);
+                        }
+                    }
+                    levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    levels.dedup();
+                    for &level in &levels {
+                        let region = match flood_region(&dem, row, col, level, max_region, nodata)
+                        {
+                            Some((region, _)) => region,
+                            None => break, // higher levels will also spill out of the region
+                        };
+                        let mut new_z: HashMap<(isize, isize), f64> = HashMap::new();
+                        for &(r, c, d) in &region {
+                            new_z.insert((r, c), level + d as f64 * small_num);
+                        }
+                        new_z.insert((row, col), level);
+                        for i in 1..path.len() {
+                            let level_i = level - i as f64 * small_num;
+                            let z_i = match new_z.get(&path[i]) {
+                                Some(&v) => v,
+                                None => path_z[i],
+                            };
+                            if z_i > level_i {
+                                new_z.insert(path[i], level_i);
+                            }
+                        }
+                        let cells: Vec<(isize, isize, f64)> =
+                            new_z.into_iter().map(|((r, c), v)| (r, c, v)).collect();
+                        let obj = objective(&Modification::measure(&dem, &cells, cell_area));
+                        let replace = match best_breach {
+                            Some((o, _, _, _)) => obj < o,
+                            None => true,
+                        };
+                        if replace {
+                            best_breach = Some((obj, level, cells, region.len() > 1));
+                        }
+                    }
+                }
+
+                let breach_objective = match best_breach {
+                    Some((o, _, _, _)) => Some(o),
+                    None => None,
+                };
+                if breach_objective.unwrap_or(f64::INFINITY) >= objective(&reference) {
+                    best_breach = None;
+                }
+                let (method, level, cells) = match best_breach {
+                    Some((_, level, cells, hybrid)) => {
+                        if hybrid {
+                            num_hybrid += 1;
+                            ("hybrid", level, cells)
+                        } else {
+                            num_breached += 1;
+                            ("breach", level, cells)
+                        }
+                    }
+                    None => {
+                        num_filled += 1;
+                        ("fill", fill_level, fill_cells)
+                    }
+                };
+                let modification = Modification::measure(&dem, &cells, cell_area);
+                for &(r, c, v) in &cells {
+                    dem.set_value(r, c, v);
+                }
+                total.volume += modification.volume;
+                total.num_cells += modification.num_cells;
+                if modification.max_mod > total.max_mod {
+                    total.max_mod = modification.max_mod;
+                }
+                if !report_file.is_empty() {
+                    report.push(ReportEntry {
+                        x: input.get_x_from_column(col),
+                        y: input.get_y_from_row(row),
+                        z: z,
+                        method: method,
+                        level: level,
+                        objective: objective(&modification),
+                        breach_objective: breach_objective,
+                        modification: modification,
+                    });
+                }
+
+                if verbose {
+                    progress = (100.0_f64 * p as f64 / (pits.len() - 1).max(1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Solving depressions", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+
+            pass += 1;
+            if pass == MAX_PASSES {
+                break;
+            }
+        }
+
+        let out_nodata = output.configs.nodata;
+        for row in 0..rows {
+            let mut data = dem.get_row_data(row);
+            for z in data.iter_mut() {
+                if *z == nodata {
+                    *z = out_nodata;
+                }
+            }
+            output.set_row_data(row, data);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.display_min = input.configs.display_min;
+        output.configs.display_max = input.configs.display_max;
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Maximum breach length: {}", max_length));
+        output.add_metadata_entry(format!("Volume weight: {}", volume_weight));
+        output.add_metadata_entry(format!("Cells weight: {}", cells_weight));
+        output.add_metadata_entry(format!("Maximum modification weight: {}", max_mod_weight));
+        output.add_metadata_entry(format!("Flat increment: {}", small_num));
+        output.add_metadata_entry(format!("Modified volume: {}", total.volume));
+        output.add_metadata_entry(format!("Modified cells: {}", total.num_cells));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::metadata("Number of pits", &num_pits.to_string());
+            report::metadata("Number of filled depressions", &num_filled.to_string());
+            report::metadata("Number of breached depressions", &num_breached.to_string());
+            report::metadata(
+                "Number of partially filled and breached depressions",
+                &num_hybrid.to_string(),
+            );
+            report::metadata("Modified volume", &format!("{:.3}", total.volume));
+            report::metadata("Modified cells", &total.num_cells.to_string());
+            report::metadata("Maximum modification", &format!("{:.3}", total.max_mod));
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if !report_file.is_empty() {
+            let f = File::create(report_file.clone())?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all(
+                "ID,X,Y,PIT_Z,METHOD,LEVEL,VOLUME,NUM_CELLS,MAX_MOD,OBJECTIVE,BREACH_OBJ\n"
+                    .as_bytes(),
+            )?;
+            for (i, entry) in report.iter().enumerate() {
+                let breach_objective = match entry.breach_objective {
+                    Some(v) => v.to_string(),
+                    None => String::new(),
+                };
+                writer.write_all(
+                    format!(
+                        "{},{},{},{},{},{},{},{},{},{},{}\n",
+                        i + 1,
+                        entry.x,
+                        entry.y,
+                        entry.z,
+                        entry.method,
+                        entry.level,
+                        entry.modification.volume,
+                        entry.modification.num_cells,
+                        entry.modification.max_mod,
+                        entry.objective,
+                        breach_objective
+                    ).as_bytes(),
+                )?;
+            }
+            if verbose {
+                report::message("Modification report written")
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds the elevations needed to fill the depression containing a pit so that it drains,
+/// returning the modified cells and the spill elevation. The depression is flooded from the
+/// pit, in order of elevation, until a cell is reached that is lower than the water level
+/// or that is at the edge of the data. This outlet is then used to seed a priority-flood
+/// of the flooded cells, which raises them to drain towards the outlet with a gradient of
+/// `small_num` per cell.
+fn fill_depression(
+    dem: &Array2D<f64>,
+    row: isize,
+    col: isize,
+    small_num: f64,
+    nodata: f64,
+) -> (Vec<(isize, isize, f64)>, f64) {
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let mut region: HashSet<(isize, isize)> = HashSet::new();
+    let mut queued: HashSet<(isize, isize)> = HashSet::new();
+    let mut minheap = BinaryHeap::new();
+    let mut level = f64::NEG_INFINITY;
+    let mut outlet = (row, col);
+    queued.insert((row, col));
+    minheap.push(CostCell {
+        row: row,
+        column: col,
+        priority: dem.get_value(row, col),
+    });
+    while let Some(cell) = minheap.pop() {
+        if cell.priority < level || is_edge(dem, cell.row, cell.column, nodata) {
+            outlet = (cell.row, cell.column);
+            if cell.priority > level {
+                level = cell.priority;
+            }
+            break;
+        }
+        level = cell.priority;
+        region.insert((cell.row, cell.column));
+        for n in 0..8 {
+            let (row_n, col_n) = (cell.row + dy[n], cell.column + dx[n]);
+            let z_n = dem.get_value(row_n, col_n);
+            if z_n != nodata && !queued.contains(&(row_n, col_n)) {
+                queued.insert((row_n, col_n));
+                minheap.push(CostCell {
+                    row: row_n,
+                    column: col_n,
+                    priority: z_n,
+                });
+            }
+        }
+    }
+
+    /*
+    Raise the flooded cells so that they drain to the outlet. Raising a cell may leave a
+    neighbouring cell that drained to it without a lower neighbour, and so any such cells
+    are added to the region and the grading is repeated.
+    */
+    let z_o = dem.get_value(outlet.0, outlet.1);
+    let mut cells: HashMap<(isize, isize), f64>;
+    loop {
+        cells = HashMap::new();
+        let mut done: HashSet<(isize, isize)> = HashSet::new();
+        minheap.clear();
+        minheap.push(CostCell {
+            row: outlet.0,
+            column: outlet.1,
+            priority: z_o,
+        });
+        while let Some(cell) = minheap.pop() {
+            for n in 0..8 {
+                let (row_n, col_n) = (cell.row + dy[n], cell.column + dx[n]);
+                if region.contains(&(row_n, col_n)) && done.insert((row_n, col_n)) {
+                    let mut z_n = dem.get_value(row_n, col_n);
+                    if z_n <= cell.priority {
+                        z_n = cell.priority + small_num;
+                        cells.insert((row_n, col_n), z_n);
+                    }
+                    minheap.push(CostCell {
+                        row: row_n,
+                        column: col_n,
+                        priority: z_n,
+                    });
+                }
+            }
+        }
+        let elev = |r: isize, c: isize| match cells.get(&(r, c)) {
+            Some(&v) => v,
+            None => dem.get_value(r, c),
+        };
+        let mut orphans = vec![];
+        for &(r, c) in cells.keys() {
+            for n in 0..8 {
+                let (row_n, col_n) = (r + dy[n], c + dx[n]);
+                let z_n = dem.get_value(row_n, col_n);
+                if z_n == nodata
+                    || (row_n, col_n) == outlet
+                    || region.contains(&(row_n, col_n))
+                    || is_edge(dem, row_n, col_n, nodata)
+                {
+                    continue;
+                }
+                let drained = (0..8).any(|m| dem.get_value(row_n + dy[m], col_n + dx[m]) < z_n);
+                if drained && (0..8).all(|m| elev(row_n + dy[m], col_n + dx[m]) >= z_n) {
+                    orphans.push((row_n, col_n));
+                }
+            }
+        }
+        if orphans.is_empty() {
+            break;
+        }
+        region.extend(orphans);
+    }
+    let cells = cells.into_iter().map(|((r, c), v)| (r, c, v)).collect();
+    (cells, level)
+}
+
+/// The alteration of a DEM by a depression solution.
+#[derive(Default)]
+struct Modification {
+    volume: f64,
+    num_cells: usize,
+    max_mod: f64,
+}
+
+impl Modification {
+    fn measure(dem: &Array2D<f64>, cells: &[(isize, isize, f64)], cell_area: f64) -> Self {
+        let mut m = Modification::default();
+        for &(r, c, v) in cells {
+            let d = (v - dem.get_value(r, c)).abs();
+            if d > 0f64 {
+                m.volume += d * cell_area;
+                m.num_cells += 1;
+                if d > m.max_mod {
+                    m.max_mod = d;
+                }
+            }
+        }
+        m
+    }
+}
+
+struct ReportEntry {
+    x: f64,
+    y: f64,
+    z: f64,
+    method: &'static str,
+    level: f64,
+    modification: Modification,
+    objective: f64,
+    breach_objective: Option<f64>,
+}
@@ -0,0 +1,557 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool conditions the elevations of stream cells in a DEM (`--dem`) so that they
+/// decrease monotonically in the downstream direction along the D8 flow paths of a raster
+/// stream network (`--streams` and `--d8_pntr`). DEM noise, bridges, and vegetation often
+/// create artificial steps and reverse gradients along channels, which cause problems for
+/// applications like height above nearest drainage (HAND) and flood inundation mapping.
+/// Cells that are not part of the stream network are unaltered in the output DEM
+/// (`--output`).
+///
+/// The tool works through the network one stream link at a time and supports three methods
+/// (`--method`) for removing reverse gradients:
+///
+/// - 'carve' lowers each stream cell to no higher than the cells upstream of it, which
+///   removes obstructions (e.g. road embankments) by cutting through them;
+/// - 'fill' raises each stream cell to no lower than the cell downstream of it, which
+///   removes pits in the profile; and
+/// - 'average' (default) takes the mean of the carved and filled profiles, which is also
+///   monotonic and minimizes the overall change to the profile.
+///
+/// Before the monotonic constraint is enforced, the long profiles can optionally be smoothed
+/// with a moving average filter that extends `--smooth` cells upstream and downstream of
+/// each stream cell along the flow path. Where several tributaries join, the upstream
+/// direction follows the tributary with the greatest number of upstream stream cells.
+/// A minimum elevation drop between consecutive stream cells can also be imposed
+/// (`--min_drop`), which prevents flat sections of channel.
+///
+/// # See Also
+/// `FillBurn`, `BreachDepressions`, `ElevationAboveStream`, `LongProfile`
+pub struct ConditionStreamProfiles {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ConditionStreamProfiles {
+    pub fn new() -> ConditionStreamProfiles {
+        // public constructor
+        let name = "ConditionStreamProfiles".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description = "Smooths stream long profiles and enforces a monotonic downstream decrease in stream cell elevations.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Streams File".to_owned(),
+            flags: vec!["--streams".to_owned()],
+            description: "Input raster streams file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Conditioning Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Conditioning method; one of 'carve', 'fill', and 'average' (default)."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "carve".to_owned(),
+                "fill".to_owned(),
+                "average".to_owned(),
+            ]),
+            default_value: Some("average".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Smoothing Half-Window (cells)".to_owned(),
+            flags: vec!["--smooth".to_owned()],
+            description: "Number of cells upstream and downstream used to smooth the profiles; zero for no smoothing.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Drop".to_owned(),
+            flags: vec!["--min_drop".to_owned()],
+            description: "Minimum elevation drop between consecutive stream cells.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --d8_pntr=D8.tif --streams=streams.tif -o=output.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --d8_pntr=D8.tif --streams=streams.tif -o=output.tif --method=carve --smooth=3 --min_drop=0.001", short_exe, name).replace("*", &sep);
+
+        ConditionStreamProfiles {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ConditionStreamProfiles {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut dem_file = String::new();
+        let mut d8_file = String::new();
+        let mut streams_file = String::new();
+        let mut output_file = String::new();
+        let mut method = String::from("average");
+        let mut smooth = 0isize;
+        let mut min_drop = 0f64;
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            let val = v.to_lowercase();
+            method = if val.contains("carve") || val.contains("breach") {
+                "carve".to_string()
+            } else if val.contains("fill") {
+                "fill".to_string()
+            } else {
+                "average".to_string()
+            };
+        }
+        if let Some(v) = tool_args.get_isize(&["--smooth"])? {
+            smooth = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--min_drop"])? {
+            min_drop = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !streams_file.contains(&sep) && !streams_file.contains("/") {
+            streams_file = format!("{}{}", working_directory, streams_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if smooth < 0 {
+            smooth = 0;
+        }
+        if min_drop < 0f64 {
+            min_drop = 0f64;
+        }
+
+        if verbose {
+            report::message("Reading DEM data...")
+        };
+        let dem = Raster::new(&dem_file, "r")?;
+        if verbose {
+            report::message("Reading pointer data...")
+        };
+        let pntr = Raster::new(&d8_file, "r")?;
+        if verbose {
+            report::message("Reading streams data...")
+        };
+        let streams = Raster::new(&streams_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = dem.configs.rows as isize;
+        let columns = dem.configs.columns as isize;
+        let nodata = dem.configs.nodata;
+        let streams_nodata = streams.configs.nodata;
+
+        // make sure the input files have the same size
+        if streams.configs.rows != dem.configs.rows
+            || streams.configs.columns != dem.configs.columns
+            || pntr.configs.rows != dem.configs.rows
+            || pntr.configs.columns != dem.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        let mut inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+            inflowing_vals = [8f64, 16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64];
+        }
+
+        let is_stream = |row: isize, col: isize| -> bool {
+            streams.get_value(row, col) > 0f64
+                && streams.get_value(row, col) != streams_nodata
+                && dem.get_value(row, col) != nodata
+        };
+
+        // Returns the downstream stream cell, if there is one.
+        let downstream = |row: isize, col: isize| -> Option<(isize, isize)> {
+            let p = pntr.get_value(row, col);
+            if p > 0f64 && p <= 128f64 {
+                let dir = pntr_matches[p as usize];
+                if dir < 8 && is_stream(row + d_y[dir], col + d_x[dir]) {
+                    return Some((row + d_y[dir], col + d_x[dir]));
+                }
+            }
+            None
+        };
+
+        // Find a topological ordering of the stream cells, from upstream to downstream,
+        // and count the number of upstream stream cells for each cell.
+        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
+        let mut num_upstream: Array2D<f64> = Array2D::new(rows, columns, 1f64, -1f64)?;
+        let mut stack = vec![];
+        let mut count: i8;
+        for row in 0..rows {
+            for col in 0..columns {
+                if is_stream(row, col) {
+                    count = 0i8;
+                    for i in 0..8 {
+                        if is_stream(row + d_y[i], col + d_x[i])
+                            && pntr.get_value(row + d_y[i], col + d_x[i]) == inflowing_vals[i]
+                        {
+                            count += 1;
+                        }
+                    }
+                    num_inflowing.set_value(row, col, count);
+                    if count == 0 {
+                        stack.push((row, col));
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 1 of 3)", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut order = vec![];
+        let mut n: f64;
+        while let Some((row, col)) = stack.pop() {
+            order.push((row, col));
+            if let Some((row_n, col_n)) = downstream(row, col) {
+                n = num_upstream.get_value(row, col);
+                num_upstream.increment(row_n, col_n, n);
+                num_inflowing.decrement(row_n, col_n, 1);
+                if num_inflowing.get_value(row_n, col_n) == 0 {
+                    stack.push((row_n, col_n));
+                }
+            }
+        }
+
+        // Returns the upstream trunk cell, i.e. the inflowing stream cell with the
+        // largest number of upstream stream cells.
+        let upstream = |row: isize, col: isize| -> Option<(isize, isize)> {
+            let mut ret = None;
+            let mut max_n = 0f64;
+            for i in 0..8 {
+                let (row_n, col_n) = (row + d_y[i], col + d_x[i]);
+                if is_stream(row_n, col_n)
+                    && pntr.get_value(row_n, col_n) == inflowing_vals[i]
+                    && num_upstream.get_value(row_n, col_n) > max_n
+                {
+                    max_n = num_upstream.get_value(row_n, col_n);
+                    ret = Some((row_n, col_n));
+                }
+            }
+            ret
+        };
+
+        // Smooth the profiles.
+        let mut z: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut sum: f64;
+        let mut num: f64;
+        let (mut r, mut c): (isize, isize);
+        for i in 0..order.len() {
+            let (row, col) = order[i];
+            sum = dem.get_value(row, col);
+            num = 1f64;
+            r = row;
+            c = col;
+            for _ in 0..smooth {
+                match downstream(r, c) {
+                    Some((rn, cn)) => {
+                        r = rn;
+                        c = cn;
+                        sum += dem.get_value(r, c);
+                        num += 1f64;
+                    }
+                    None => break,
+                }
+            }
+            r = row;
+            c = col;
+            for _ in 0..smooth {
+                match upstream(r, c) {
+                    Some((rn, cn)) => {
+                        r = rn;
+                        c = cn;
+                        sum += dem.get_value(r, c);
+                        num += 1f64;
+                    }
+                    None => break,
+                }
+            }
+            z.set_value(row, col, sum / num);
+            if verbose {
+                progress = (100.0_f64 * i as f64 / (order.len() - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 2 of 3)", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Carving works downstream from the channel heads; each cell is lowered to no
+        // higher than the cells draining to it.
+        let mut carved: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        if method != "fill" {
+            let mut zn: f64;
+            for &(row, col) in &order {
+                zn = z.get_value(row, col);
+                for i in 0..8 {
+                    let (row_n, col_n) = (row + d_y[i], col + d_x[i]);
+                    if is_stream(row_n, col_n) && pntr.get_value(row_n, col_n) == inflowing_vals[i]
+                    {
+                        zn = zn.min(carved.get_value(row_n, col_n) - min_drop);
+                    }
+                }
+                carved.set_value(row, col, zn);
+            }
+        }
+
+        // Filling works upstream from the outlets; each cell is raised to no lower than
+        // the cell that it drains to.
+        let mut filled: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        if method != "carve" {
+            let mut zn: f64;
+            for &(row, col) in order.iter().rev() {
+                zn = z.get_value(row, col);
+                if let Some((row_n, col_n)) = downstream(row, col) {
+                    zn = zn.max(filled.get_value(row_n, col_n) + min_drop);
+                }
+                filled.set_value(row, col, zn);
+            }
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &dem);
+        let mut num_modified = 0usize;
+        let mut z_out: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                if z.get_value(row, col) != nodata {
+                    z_out = if method == "carve" {
+                        carved.get_value(row, col)
+                    } else if method == "fill" {
+                        filled.get_value(row, col)
+                    } else {
+                        (carved.get_value(row, col) + filled.get_value(row, col)) / 2f64
+                    };
+                    if z_out != dem.get_value(row, col) {
+                        num_modified += 1;
+                    }
+                    output.set_value(row, col, z_out);
+                } else if dem.get_value(row, col) != nodata {
+                    output.set_value(row, col, dem.get_value(row, col));
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 3 of 3)", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input DEM file: {}", dem_file));
+        output.add_metadata_entry(format!("Input d8 pointer file: {}", d8_file));
+        output.add_metadata_entry(format!("Input streams file: {}", streams_file));
+        output.add_metadata_entry(format!("Method: {}", method));
+        output.add_metadata_entry(format!("Smoothing half-window: {}", smooth));
+        output.add_metadata_entry(format!("Minimum drop: {}", min_drop));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::metadata("Number of modified stream cells", &num_modified.to_string());
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,452 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::subbasin_parameters::{lookup_parameter, read_parameter_lookup};
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use tools::*;
+
+/// This tool estimates the depth of direct runoff generated by a storm using the Soil
+/// Conservation Service (SCS, now NRCS) curve number method. The curve number (CN) of each
+/// grid cell is read from a lookup table (`--lookup`) using the values of a land-cover
+/// raster (`--landcover`) and a hydrologic soil group raster (`--soils`). The lookup table
+/// is a text file containing one record per line, with fields separated by commas,
+/// semicolons, spaces, or tabs. Each record contains a land-cover value, a soil value, and
+/// the curve number for that combination, e.g. `41,B,55`. Hydrologic soil groups may be
+/// given either as numeric codes or as the letters A, B, C, and D, which correspond to soil
+/// raster values of 1, 2, 3, and 4 respectively. Records with only two fields, i.e. a
+/// land-cover value and a curve number, apply to all soil groups, and are used when a
+/// land-cover and soil combination has no record of its own. Lines that do not begin with
+/// a number, such as a header line, are ignored. Because the lookup table is an ordinary
+/// text file, the curve numbers can readily be edited to suit local conditions or the
+/// antecedent moisture condition of the storm.
+///
+/// The runoff depth (Q) for the storm rainfall depth (P; `--rainfall`) is:
+///
+/// > Q = (P - I<sub>a</sub>)<sup>2</sup> / (P - I<sub>a</sub> + S), for P > I<sub>a</sub>
+///
+/// and zero otherwise, where S = 1000 / CN - 10 is the potential maximum retention, in
+/// inches, and I<sub>a</sub> = λS is the initial abstraction. The initial abstraction ratio
+/// (λ; `--ia_ratio`) is 0.2 by default, although values of 0.05 have also been suggested.
+/// The rainfall depth and output runoff depths are measured in either millimetres or
+/// inches (`--units`). The curve numbers may optionally be output as a raster (`--out_cn`).
+/// Cells with NoData land-cover or soil values, or with combinations that are missing from
+/// the lookup table, are assigned NoData in the outputs.
+///
+/// The output runoff raster may be used as the loading (`--loading`) of the
+/// `WeightedFlowAccumulation` tool to estimate the amount of runoff draining to each
+/// location in a landscape.
+///
+/// # Reference
+/// USDA-NRCS (1986). Urban hydrology for small watersheds. Technical Release 55,
+/// United States Department of Agriculture, Natural Resources Conservation Service.
+///
+/// # See Also
+/// `SubbasinParameters`, `WeightedFlowAccumulation`, `D8MassFlux`
+pub struct CurveNumberRunoff {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl CurveNumberRunoff {
+    pub fn new() -> CurveNumberRunoff {
+        // public constructor
+        let name = "CurveNumberRunoff".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Calculates SCS curve numbers from land-cover and soils rasters, and the runoff depth of a storm."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Land-Cover File".to_owned(),
+            flags: vec!["--landcover".to_owned()],
+            description: "Input raster land-cover file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Soils File".to_owned(),
+            flags: vec!["--soils".to_owned()],
+            description: "Input raster hydrologic soil group file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Lookup Table".to_owned(),
+            flags: vec!["--lookup".to_owned()],
+            description: "Input lookup table of curve numbers for each land-cover and soil combination.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Runoff File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster runoff depth file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Curve Number File (optional)".to_owned(),
+            flags: vec!["--out_cn".to_owned()],
+            description: "Output raster curve number file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Storm Rainfall Depth".to_owned(),
+            flags: vec!["--rainfall".to_owned()],
+            description: "Rainfall depth of the storm.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Depth Units".to_owned(),
+            flags: vec!["--units".to_owned()],
+            description: "Units of the rainfall and runoff depths; options are 'mm' and 'inches'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec!["mm".to_owned(), "inches".to_owned()]),
+            default_value: Some("mm".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Initial Abstraction Ratio".to_owned(),
+            flags: vec!["--ia_ratio".to_owned()],
+            description: "Ratio of the initial abstraction to the potential maximum retention."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.2".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --landcover=landcover.tif --soils=hsg.tif --lookup=cn.csv --rainfall=75.0 -o=runoff.tif --out_cn=cn.tif",
+            short_exe, name
+        ).replace("*", &sep);
+
+        CurveNumberRunoff {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for CurveNumberRunoff {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut landcover_file = String::new();
+        let mut soils_file = String::new();
+        let mut lookup_file = String::new();
+        let mut output_file = String::new();
+        let mut cn_file = String::new();
+        let mut rainfall = f64::NAN;
+        let mut units = "mm".to_string();
+        let mut ia_ratio = 0.2f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--landcover"])? {
+            landcover_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--soils"])? {
+            soils_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--lookup"])? {
+            lookup_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_cn"])? {
+            cn_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--rainfall"])? {
+            rainfall = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--units"])? {
+            units = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--ia_ratio"])? {
+            ia_ratio = v;
+        }
+
+        if !(rainfall >= 0f64) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A non-negative storm rainfall depth (--rainfall) must be specified.",
+            ));
+        }
+        if ia_ratio < 0f64 || ia_ratio > 1f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The initial abstraction ratio (--ia_ratio) must be between 0 and 1.",
+            ));
+        }
+        // the potential maximum retention, S, is calculated in inches
+        let depth_to_inches = if units.starts_with("in") {
+            1f64
+        } else if units == "mm" {
+            1f64 / 25.4
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The depth units (--units) must be either 'mm' or 'inches'.",
+            ));
+        };
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !landcover_file.contains(&sep) && !landcover_file.contains("/") {
+            landcover_file = format!("{}{}", working_directory, landcover_file);
+        }
+        if !soils_file.contains(&sep) && !soils_file.contains("/") {
+            soils_file = format!("{}{}", working_directory, soils_file);
+        }
+        if !lookup_file.contains(&sep) && !lookup_file.contains("/") {
+            lookup_file = format!("{}{}", working_directory, lookup_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !cn_file.is_empty() && !cn_file.contains(&sep) && !cn_file.contains("/") {
+            cn_file = format!("{}{}", working_directory, cn_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let landcover = Arc::new(Raster::new(&landcover_file, "r")?);
+        let soils = Arc::new(Raster::new(&soils_file, "r")?);
+        let lookup = Arc::new(read_parameter_lookup(&lookup_file)?);
+        for cn in lookup.values() {
+            if *cn <= 0f64 || *cn > 100f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The lookup table contains an invalid curve number ({}); curve numbers must be greater than 0 and no more than 100.", cn),
+                ));
+            }
+        }
+
+        let start = Instant::now();
+
+        let rows = landcover.configs.rows as isize;
+        let columns = landcover.configs.columns as isize;
+        let nodata = -32768f64;
+
+        if soils.configs.rows as isize != rows || soils.configs.columns as isize != columns {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let landcover = landcover.clone();
+            let soils = soils.clone();
+            let lookup = lookup.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let lc_nodata = landcover.configs.nodata;
+                let soil_nodata = soils.configs.nodata;
+                let p = rainfall * depth_to_inches;
+                let (mut lc, mut soil): (f64, f64);
+                let (mut s, mut ia): (f64, f64);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut cn_data = vec![nodata; columns as usize];
+                    let mut runoff_data = vec![nodata; columns as usize];
+                    let mut num_unmatched = 0usize;
+                    for col in 0..columns {
+                        lc = landcover[(row, col)];
+                        soil = soils[(row, col)];
+                        if lc != lc_nodata && soil != soil_nodata {
+                            match lookup_parameter(&lookup, lc, Some(soil)) {
+                                Some(cn) => {
+                                    s = 1000f64 / cn - 10f64;
+                                    ia = ia_ratio * s;
+                                    cn_data[col as usize] = cn;
+                                    runoff_data[col as usize] = if p > ia {
+                                        (p - ia) * (p - ia) / (p - ia + s) / depth_to_inches
+                                    } else {
+                                        0f64
+                                    };
+                                }
+                                None => num_unmatched += 1,
+                            }
+                        }
+                    }
+                    tx.send((row, cn_data, runoff_data, num_unmatched)).unwrap();
+                }
+            });
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &landcover);
+        output.configs.nodata = nodata;
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.palette = "blueyellow.plt".to_string();
+        let mut cn_output = if !cn_file.is_empty() {
+            let mut r = Raster::initialize_using_file(&cn_file, &landcover);
+            r.configs.nodata = nodata;
+            r.configs.data_type = DataType::F32;
+            r.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            Some(r)
+        } else {
+            None
+        };
+        let mut num_unmatched = 0usize;
+        for r in 0..rows {
+            let (row, cn_data, runoff_data, n) = rx.recv().unwrap();
+            output.set_row_data(row, runoff_data);
+            if let Some(ref mut cn_output) = cn_output {
+                cn_output.set_row_data(row, cn_data);
+            }
+            num_unmatched += n;
+
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        if verbose && num_unmatched > 0 {
+            report::warning(&format!(
+                "{} cells have land-cover and soil values that are missing from the lookup table.",
+                num_unmatched
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Land-cover file: {}", landcover_file));
+        output.add_metadata_entry(format!("Soils file: {}", soils_file));
+        output.add_metadata_entry(format!("Lookup table: {}", lookup_file));
+        output.add_metadata_entry(format!("Rainfall depth: {} {}", rainfall, units));
+        output.add_metadata_entry(format!("Initial abstraction ratio: {}", ia_ratio));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if let Some(mut cn_output) = cn_output {
+            cn_output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            cn_output.add_metadata_entry(format!("Land-cover file: {}", landcover_file));
+            cn_output.add_metadata_entry(format!("Soils file: {}", soils_file));
+            cn_output.add_metadata_entry(format!("Lookup table: {}", lookup_file));
+            let _ = match cn_output.write() {
+                Ok(_) => if verbose {
+                    report::message("Curve number file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -2,12 +2,13 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 26, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
 use num_cpus;
 use raster::*;
+use std::collections::HashMap;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
@@ -79,6 +80,15 @@ impl D8FlowAccumulation {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Route flow across internal NoData gaps?".to_owned(),
+            flags: vec!["--route_gaps".to_owned()],
+            description: "Optional flag to route flow across internal NoData gaps, e.g. removed lakes or missing tiles.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -91,7 +101,8 @@ impl D8FlowAccumulation {
             short_exe += ".exe";
         }
         let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.dtifep --out_type='cells'
->>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --out_type='specific catchment area' --log --clip", short_exe, name).replace("*", &sep);
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --out_type='specific catchment area' --log --clip
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --route_gaps", short_exe, name).replace("*", &sep);
 
         D8FlowAccumulation {
             name: name,
@@ -142,6 +153,7 @@ impl WhiteboxTool for D8FlowAccumulation {
         let mut out_type = String::from("sca");
         let mut log_transform = false;
         let mut clip_max = false;
+        let mut route_gaps = false;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -149,62 +161,32 @@ impl WhiteboxTool for D8FlowAccumulation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_type" || vec[0].to_lowercase() == "--out_type"
-            {
-                if keyval {
-                    out_type = vec[1].to_lowercase();
-                } else {
-                    out_type = args[i + 1].to_lowercase();
-                }
-                if out_type.contains("specific") || out_type.contains("sca") {
-                    out_type = String::from("sca");
-                } else if out_type.contains("cells") {
-                    out_type = String::from("cells");
-                } else {
-                    out_type = String::from("ca");
-                }
-            } else if vec[0].to_lowercase() == "-log" || vec[0].to_lowercase() == "--log" {
-                log_transform = true;
-            } else if vec[0].to_lowercase() == "-clip" || vec[0].to_lowercase() == "--clip" {
-                clip_max = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_type"])? {
+            out_type = v;
+        }
+        if tool_args.get_flag(&["--log"])? {
+            log_transform = true;
+        }
+        if tool_args.get_flag(&["--clip"])? {
+            clip_max = true;
+        }
+        if tool_args.get_flag(&["--route_gaps"])? {
+            route_gaps = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
 
-        let mut progress: usize;
-        let mut old_progress: usize = 1;
-
         if !input_file.contains(&sep) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
@@ -213,298 +195,482 @@ impl WhiteboxTool for D8FlowAccumulation {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
-        let input = Arc::new(Raster::new(&input_file, "r")?);
+        let input = Arc::new(Raster::new_compact(&input_file)?);
 
-        // calculate the flow direction
         let start = Instant::now();
-        let rows = input.configs.rows as isize;
-        let columns = input.configs.columns as isize;
-        let num_cells = rows * columns;
-        let nodata = input.configs.nodata;
-        let cell_size_x = input.configs.resolution_x;
-        let cell_size_y = input.configs.resolution_y;
-        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
-
-        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
-        let num_procs = num_cpus::get() as isize;
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let input = input.clone();
-            let tx = tx.clone();
-            thread::spawn(move || {
-                let nodata = input.configs.nodata;
-                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-                let grid_lengths = [
-                    diag_cell_size,
-                    cell_size_x,
-                    diag_cell_size,
-                    cell_size_y,
-                    diag_cell_size,
-                    cell_size_x,
-                    diag_cell_size,
-                    cell_size_y,
-                ];
-                let (mut z, mut z_n): (f64, f64);
-                let (mut max_slope, mut slope): (f64, f64);
-                let mut dir: i8;
-                let mut neighbouring_nodata: bool;
-                let mut interior_pit_found = false;
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<i8> = vec![-1i8; columns as usize];
-                    for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != nodata {
-                            dir = 0i8;
-                            max_slope = f64::MIN;
-                            neighbouring_nodata = false;
-                            for i in 0..8 {
-                                z_n = input[(row + dy[i], col + dx[i])];
-                                if z_n != nodata {
-                                    slope = (z - z_n) / grid_lengths[i];
-                                    if slope > max_slope && slope > 0f64 {
-                                        max_slope = slope;
-                                        dir = i as i8;
-                                    }
-                                } else {
-                                    neighbouring_nodata = true;
+
+        let mut output = d8_flow_accumulation(
+            input,
+            &out_type,
+            log_transform,
+            clip_max,
+            route_gaps,
+            verbose,
+        )?;
+        output.set_file_name(&output_file);
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Calculates the D8 flow accumulation of each grid cell of a DEM. The output type
+/// (`out_type`) is the number of upslope cells ("cells"), the upslope contributing area
+/// ("ca"), or the specific contributing area ("sca"), optionally log-transformed. If
+/// `route_gaps` is true, cells bordering internal NoData gaps may drain across them.
+pub fn d8_flow_accumulation(
+    input: Arc<Raster>,
+    out_type: &str,
+    log_transform: bool,
+    clip_max: bool,
+    route_gaps: bool,
+    verbose: bool,
+) -> Result<Raster, Error> {
+    let mut out_type = out_type.to_lowercase();
+    if out_type.contains("specific") || out_type.contains("sca") {
+        out_type = String::from("sca");
+    } else if out_type.contains("cells") {
+        out_type = String::from("cells");
+    } else {
+        out_type = String::from("ca");
+    }
+
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+
+    // calculate the flow direction
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let num_cells = rows * columns;
+    let nodata = input.configs.nodata;
+    let cell_size_x = input.configs.resolution_x;
+    let cell_size_y = input.configs.resolution_y;
+    let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+
+    let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let nodata = input.configs.nodata;
+            let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+            let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let grid_lengths = [
+                diag_cell_size,
+                cell_size_x,
+                diag_cell_size,
+                cell_size_y,
+                diag_cell_size,
+                cell_size_x,
+                diag_cell_size,
+                cell_size_y,
+            ];
+            let (mut z, mut z_n): (f64, f64);
+            let (mut max_slope, mut slope): (f64, f64);
+            let mut dir: i8;
+            let mut neighbouring_nodata: bool;
+            let mut interior_pit_found = false;
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data: Vec<i8> = vec![-1i8; columns as usize];
+                for col in 0..columns {
+                    z = input.get_value(row, col);
+                    if z != nodata {
+                        dir = 0i8;
+                        max_slope = f64::MIN;
+                        neighbouring_nodata = false;
+                        for i in 0..8 {
+                            z_n = input.get_value(row + dy[i], col + dx[i]);
+                            if z_n != nodata {
+                                slope = (z - z_n) / grid_lengths[i];
+                                if slope > max_slope && slope > 0f64 {
+                                    max_slope = slope;
+                                    dir = i as i8;
                                 }
-                            }
-                            if max_slope >= 0f64 {
-                                data[col as usize] = dir;
                             } else {
-                                data[col as usize] = -1i8;
-                                if !neighbouring_nodata {
-                                    interior_pit_found = true;
-                                }
+                                neighbouring_nodata = true;
                             }
+                        }
+                        if max_slope >= 0f64 {
+                            data[col as usize] = dir;
                         } else {
                             data[col as usize] = -1i8;
+                            if !neighbouring_nodata {
+                                interior_pit_found = true;
+                            }
                         }
+                    } else {
+                        data[col as usize] = -1i8;
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
                 }
-            });
+                tx.send((row, data, interior_pit_found)).unwrap();
+            }
+        });
+    }
+
+    let mut interior_pit_found = false;
+    for r in 0..rows {
+        let (row, data, pit) = rx.recv().unwrap();
+        flow_dir.set_row_data(row, data); //(data.0, data.1);
+        if pit {
+            interior_pit_found = true;
+        }
+        if verbose {
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Flow directions", progress);
+                old_progress = progress;
+            }
         }
+    }
 
-        let mut interior_pit_found = false;
-        for r in 0..rows {
-            let (row, data, pit) = rx.recv().unwrap();
-            flow_dir.set_row_data(row, data); //(data.0, data.1);
-            if pit {
-                interior_pit_found = true;
+    // Cells that do not have a downslope neighbour and that border an internal NoData
+    // gap, i.e. one that is not connected to the grid edges, may drain across the gap.
+    // Flow is routed to the first valid cell along the straight-line continuation of
+    // the direction into the gap, provided that it is lower, and where there are
+    // several such cells, to the one with the steepest slope. Failing this, the gap is
+    // treated as a conveyance, e.g. a lake, and flow is routed to the gap's lowest
+    // bordering cell, i.e. its outlet, if it is lower.
+    let mut gap_receivers: HashMap<isize, (isize, isize)> = HashMap::new();
+    if route_gaps {
+        // label the NoData gaps; the gaps that are connected to the grid edges, and
+        // the cells beyond the edges, are labelled 0.
+        let mut gap_id: Array2D<i32> = Array2D::new(rows, columns, -1, 0)?;
+        let mut gap_outlets: Vec<(isize, isize)> = vec![(-1, -1)];
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut stack = vec![];
+        let (mut row_n, mut col_n): (isize, isize);
+        let (mut z, mut z_n): (f64, f64);
+        for row in 0..rows {
+            for col in 0..columns {
+                if input.get_value(row, col) == nodata
+                    && (row == 0 || col == 0 || row == rows - 1 || col == columns - 1)
+                {
+                    gap_id[(row, col)] = 0;
+                    stack.push((row, col));
+                }
             }
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
-                    old_progress = progress;
+        }
+        while let Some((r, c)) = stack.pop() {
+            for i in 0..8 {
+                row_n = r + dy[i];
+                col_n = c + dx[i];
+                if input.get_value(row_n, col_n) == nodata && gap_id[(row_n, col_n)] == -1 {
+                    gap_id[(row_n, col_n)] = 0;
+                    stack.push((row_n, col_n));
                 }
             }
         }
-
-        // calculate the number of inflowing cells
-        let flow_dir = Arc::new(flow_dir);
-        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
-
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let input = input.clone();
-            let flow_dir = flow_dir.clone();
-            let tx = tx.clone();
-            thread::spawn(move || {
-                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-                let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
-                let mut z: f64;
-                let mut count: i8;
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<i8> = vec![-1i8; columns as usize];
-                    for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != nodata {
-                            count = 0i8;
-                            for i in 0..8 {
-                                if flow_dir[(row + dy[i], col + dx[i])] == inflowing_vals[i] {
-                                    count += 1;
-                                }
+        for row in 0..rows {
+            for col in 0..columns {
+                if input.get_value(row, col) != nodata || gap_id[(row, col)] != -1 {
+                    continue;
+                }
+                let id = gap_outlets.len() as i32;
+                let mut outlet = (-1, -1);
+                let mut outlet_z = f64::INFINITY;
+                gap_id[(row, col)] = id;
+                stack.push((row, col));
+                while let Some((r, c)) = stack.pop() {
+                    for i in 0..8 {
+                        row_n = r + dy[i];
+                        col_n = c + dx[i];
+                        z_n = input.get_value(row_n, col_n);
+                        if z_n == nodata {
+                            if gap_id[(row_n, col_n)] == -1 {
+                                gap_id[(row_n, col_n)] = id;
+                                stack.push((row_n, col_n));
                             }
-                            data[col as usize] = count;
-                        } else {
-                            data[col as usize] = -1i8;
+                        } else if z_n < outlet_z {
+                            outlet_z = z_n;
+                            outlet = (row_n, col_n);
                         }
                     }
-                    tx.send((row, data)).unwrap();
                 }
-            });
+                gap_outlets.push(outlet);
+            }
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-        output.reinitialize_values(1.0);
-        let mut stack = Vec::with_capacity((rows * columns) as usize);
-        let mut num_solved_cells = 0;
-        for r in 0..rows {
-            let (row, data) = rx.recv().unwrap();
-            num_inflowing.set_row_data(row, data);
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+        let (mut max_slope, mut slope): (f64, f64);
+        let mut k: isize;
+        let mut id: i32;
+        for row in 0..rows {
             for col in 0..columns {
-                if num_inflowing[(row, col)] == 0i8 {
-                    stack.push((row, col));
-                } else if num_inflowing[(row, col)] == -1i8 {
-                    num_solved_cells += 1;
+                z = input.get_value(row, col);
+                if z == nodata || flow_dir[(row, col)] >= 0 {
+                    continue;
+                }
+                max_slope = 0f64;
+                let mut receiver = None;
+                let mut outlet = None;
+                for i in 0..8 {
+                    id = gap_id[(row + dy[i], col + dx[i])];
+                    if input.get_value(row + dy[i], col + dx[i]) != nodata || id <= 0 {
+                        continue;
+                    }
+                    k = 2;
+                    loop {
+                        row_n = row + k * dy[i];
+                        col_n = col + k * dx[i];
+                        if row_n < 0 || row_n >= rows || col_n < 0 || col_n >= columns {
+                            break;
+                        }
+                        z_n = input.get_value(row_n, col_n);
+                        if z_n != nodata {
+                            slope = (z - z_n) / (k as f64 * grid_lengths[i]);
+                            if slope > max_slope {
+                                max_slope = slope;
+                                receiver = Some((row_n, col_n));
+                            }
+                            break;
+                        }
+                        k += 1;
+                    }
+                    let (row_o, col_o) = gap_outlets[id as usize];
+                    if input.get_value(row_o, col_o) < z {
+                        outlet = Some((row_o, col_o));
+                    }
+                }
+                if let Some(cell) = receiver.or(outlet) {
+                    gap_receivers.insert(row * columns + col, cell);
                 }
             }
-
             if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    report::progress("Routing across gaps", progress);
                     old_progress = progress;
                 }
             }
         }
+        if verbose {
+            report::metadata(
+                "Number of cells draining across gaps",
+                &gap_receivers.len().to_string(),
+            );
+        }
+    }
 
-        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-        let (mut row, mut col): (isize, isize);
-        let (mut row_n, mut col_n): (isize, isize);
-        // let mut cell: (isize, isize);
-        let mut dir: i8;
-        let mut fa: f64;
-        while !stack.is_empty() {
-            let cell = stack.pop().unwrap();
-            row = cell.0;
-            col = cell.1;
-            fa = output[(row, col)];
-            num_inflowing.decrement(row, col, 1i8);
-            dir = flow_dir[(row, col)];
-            if dir >= 0 {
-                row_n = row + dy[dir as usize];
-                col_n = col + dx[dir as usize];
-                output.increment(row_n, col_n, fa);
-                num_inflowing.decrement(row_n, col_n, 1i8);
-                if num_inflowing[(row_n, col_n)] == 0i8 {
-                    stack.push((row_n, col_n));
+    // calculate the number of inflowing cells
+    let flow_dir = Arc::new(flow_dir);
+    let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let flow_dir = flow_dir.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+            let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
+            let mut z: f64;
+            let mut count: i8;
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data: Vec<i8> = vec![-1i8; columns as usize];
+                for col in 0..columns {
+                    z = input.get_value(row, col);
+                    if z != nodata {
+                        count = 0i8;
+                        for i in 0..8 {
+                            if flow_dir[(row + dy[i], col + dx[i])] == inflowing_vals[i] {
+                                count += 1;
+                            }
+                        }
+                        data[col as usize] = count;
+                    } else {
+                        data[col as usize] = -1i8;
+                    }
                 }
+                tx.send((row, data)).unwrap();
             }
+        });
+    }
 
-            if verbose {
+    let mut output = Raster::initialize_using_raster(&input);
+    output.reinitialize_values(1.0);
+    let out_nodata = output.configs.nodata;
+    let mut stack = Vec::with_capacity((rows * columns) as usize);
+    let mut num_solved_cells = 0;
+    for r in 0..rows {
+        let (row, data) = rx.recv().unwrap();
+        num_inflowing.set_row_data(row, data);
+
+        if verbose {
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Num. inflowing neighbours", progress);
+                old_progress = progress;
+            }
+        }
+    }
+    for &(row_n, col_n) in gap_receivers.values() {
+        num_inflowing.increment(row_n, col_n, 1i8);
+    }
+    for row in 0..rows {
+        for col in 0..columns {
+            if num_inflowing[(row, col)] == 0i8 {
+                stack.push((row, col));
+            } else if num_inflowing[(row, col)] == -1i8 {
                 num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
-                    old_progress = progress;
-                }
             }
         }
+    }
 
-        let mut cell_area = cell_size_x * cell_size_y;
-        //let diag = (input.configs.resolution_x + input.configs.resolution_y).sqrt();
-        let mut flow_widths = [
-            diag_cell_size,
-            cell_size_y,
-            diag_cell_size,
-            cell_size_x,
-            diag_cell_size,
-            cell_size_y,
-            diag_cell_size,
-            cell_size_x,
-        ];
-        if out_type == "cells" {
-            cell_area = 1.0;
-            flow_widths = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-        } else if out_type == "ca" {
-            flow_widths = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let (mut row, mut col): (isize, isize);
+    let (mut row_n, mut col_n): (isize, isize);
+    // let mut cell: (isize, isize);
+    let mut dir: i8;
+    let mut fa: f64;
+    while !stack.is_empty() {
+        let cell = stack.pop().unwrap();
+        row = cell.0;
+        col = cell.1;
+        fa = output[(row, col)];
+        num_inflowing.decrement(row, col, 1i8);
+        dir = flow_dir[(row, col)];
+        if dir >= 0 {
+            row_n = row + dy[dir as usize];
+            col_n = col + dx[dir as usize];
+            output.increment(row_n, col_n, fa);
+            num_inflowing.decrement(row_n, col_n, 1i8);
+            if num_inflowing[(row_n, col_n)] == 0i8 {
+                stack.push((row_n, col_n));
+            }
+        } else if let Some(&(row_n, col_n)) = gap_receivers.get(&(row * columns + col)) {
+            output.increment(row_n, col_n, fa);
+            num_inflowing.decrement(row_n, col_n, 1i8);
+            if num_inflowing[(row_n, col_n)] == 0i8 {
+                stack.push((row_n, col_n));
+            }
         }
 
-        if log_transform {
-            for row in 0..rows {
-                for col in 0..columns {
-                    if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+        if verbose {
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Flow accumulation", progress);
+                old_progress = progress;
+            }
+        }
+    }
+
+    let mut cell_area = cell_size_x * cell_size_y;
+    //let diag = (input.configs.resolution_x + input.configs.resolution_y).sqrt();
+    let mut flow_widths = [
+        diag_cell_size,
+        cell_size_y,
+        diag_cell_size,
+        cell_size_x,
+        diag_cell_size,
+        cell_size_y,
+        diag_cell_size,
+        cell_size_x,
+    ];
+    if out_type == "cells" {
+        cell_area = 1.0;
+        flow_widths = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    } else if out_type == "ca" {
+        flow_widths = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    }
+
+    if log_transform {
+        for row in 0..rows {
+            for col in 0..columns {
+                if input.get_value(row, col) == nodata {
+                    output[(row, col)] = out_nodata;
+                } else {
+                    let dir = flow_dir[(row, col)];
+                    if dir >= 0 {
+                        output[(row, col)] =
+                            (output[(row, col)] * cell_area / flow_widths[dir as usize]).ln();
                     } else {
-                        let dir = flow_dir[(row, col)];
-                        if dir >= 0 {
-                            output[(row, col)] =
-                                (output[(row, col)] * cell_area / flow_widths[dir as usize]).ln();
-                        } else {
-                            output[(row, col)] =
-                                (output[(row, col)] * cell_area / flow_widths[3]).ln();
-                        }
+                        output[(row, col)] =
+                            (output[(row, col)] * cell_area / flow_widths[3]).ln();
                     }
                 }
+            }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
-                        old_progress = progress;
-                    }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Correcting values", progress);
+                    old_progress = progress;
                 }
             }
-        } else {
-            for row in 0..rows {
-                for col in 0..columns {
-                    if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+        }
+    } else {
+        for row in 0..rows {
+            for col in 0..columns {
+                if input.get_value(row, col) == nodata {
+                    output[(row, col)] = out_nodata;
+                } else {
+                    let dir = flow_dir[(row, col)];
+                    if dir >= 0 {
+                        output[(row, col)] =
+                            output[(row, col)] * cell_area / flow_widths[dir as usize];
                     } else {
-                        let dir = flow_dir[(row, col)];
-                        if dir >= 0 {
-                            output[(row, col)] =
-                                output[(row, col)] * cell_area / flow_widths[dir as usize];
-                        } else {
-                            output[(row, col)] = output[(row, col)] * cell_area / flow_widths[3];
-                        }
+                        output[(row, col)] = output[(row, col)] * cell_area / flow_widths[3];
                     }
                 }
+            }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
-                        old_progress = progress;
-                    }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Correcting values", progress);
+                    old_progress = progress;
                 }
             }
         }
+    }
 
-        output.configs.palette = "blueyellow.plt".to_string();
-        if clip_max {
-            output.clip_display_max(1.0);
-        }
-        let elapsed_time = get_formatted_elapsed_time(start);
-        output.add_metadata_entry(format!(
-            "Created by whitebox_tools\' {} tool",
-            self.get_tool_name()
-        ));
-        output.add_metadata_entry(format!("Input file: {}", input_file));
-        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
-
-        if verbose {
-            println!("Saving data...")
-        };
-        let _ = match output.write() {
-            Ok(_) => if verbose {
-                println!("Output file written")
-            },
-            Err(e) => return Err(e),
-        };
-        if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
-        }
-        if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
-            running this tool.");
-            println!("**********************************************************************************");
-        }
-
-        Ok(())
+    output.configs.palette = "blueyellow.plt".to_string();
+    if clip_max {
+        output.clip_display_max(1.0);
     }
+    if interior_pit_found {
+        report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+        DEM needs to be processed to remove topographic depressions and flats prior to \
+        running this tool.");
+    }
+
+    Ok(output)
 }
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 16, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -130,49 +130,23 @@ impl WhiteboxTool for D8Pointer {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-esri_pntr"
-                || vec[0].to_lowercase() == "--esri_pntr"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
 
-        let mut progress: usize;
-        let mut old_progress: usize = 1;
-
         if !input_file.contains(&sep) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
@@ -181,91 +155,17 @@ impl WhiteboxTool for D8Pointer {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
-        let input = Arc::new(Raster::new(&input_file, "r")?);
+        let input = Arc::new(Raster::new_compact(&input_file)?);
 
         let start = Instant::now();
-        let cell_size_x = input.configs.resolution_x;
-        let cell_size_y = input.configs.resolution_y;
-        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
-
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-        let rows = input.configs.rows as isize;
-        let nodata = input.configs.nodata;
-        let columns = input.configs.columns as isize;
-
-        let num_procs = num_cpus::get() as isize;
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let input = input.clone();
-            let tx1 = tx.clone();
-            thread::spawn(move || {
-                let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
-                let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
-                let grid_lengths = [
-                    diag_cell_size,
-                    cell_size_x,
-                    diag_cell_size,
-                    cell_size_y,
-                    diag_cell_size,
-                    cell_size_x,
-                    diag_cell_size,
-                    cell_size_y,
-                ];
-                let out_vals = match esri_style {
-                    true => [128f64, 1f64, 2f64, 4f64, 8f64, 16f64, 32f64, 64f64],
-                    false => [1f64, 2f64, 4f64, 8f64, 16f64, 32f64, 64f64, 128f64],
-                };
-                let (mut z, mut z_n, mut slope): (f64, f64, f64);
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
-                    for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != nodata {
-                            let mut dir = 0;
-                            let mut max_slope = f64::MIN;
-                            for i in 0..8 {
-                                z_n = input[(row + d_y[i], col + d_x[i])];
-                                if z_n != nodata {
-                                    slope = (z - z_n) / grid_lengths[i];
-                                    if slope > max_slope && slope > 0f64 {
-                                        max_slope = slope;
-                                        dir = i;
-                                    }
-                                }
-                            }
-                            if max_slope >= 0f64 {
-                                data[col as usize] = out_vals[dir]; //(1 << dir) as f64;
-                            } else {
-                                data[col as usize] = 0f64;
-                            }
-                        } else {
-                            data[col as usize] = nodata;
-                        }
-                    }
-                    tx1.send((row, data)).unwrap();
-                }
-            });
-        }
-
-        for row in 0..rows {
-            let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        let mut output = d8_pointer(input, esri_style, verbose);
+        output.set_file_name(&output_file);
 
         let elapsed_time = get_formatted_elapsed_time(start);
-        output.configs.palette = "qual.plt".to_string();
-        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
         output.add_metadata_entry(format!(
             "Created by whitebox_tools\' {} tool",
             self.get_tool_name()
@@ -279,21 +179,107 @@ impl WhiteboxTool for D8Pointer {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// Calculates the D8 flow pointer (flow direction) of each grid cell of a DEM, i.e. the
+/// direction of steepest descent to one of its eight neighbours, encoded using either the
+/// Whitebox or, if `esri_style` is true, the Esri base-2 numbering scheme.
+pub fn d8_pointer(input: Arc<Raster>, esri_style: bool, verbose: bool) -> Raster {
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+
+    let cell_size_x = input.configs.resolution_x;
+    let cell_size_y = input.configs.resolution_y;
+    let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+
+    let mut output = Raster::initialize_using_raster(&input);
+    let rows = input.configs.rows as isize;
+    let nodata = input.configs.nodata;
+    let out_nodata = output.configs.nodata;
+    let columns = input.configs.columns as isize;
+
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let tx1 = tx.clone();
+        thread::spawn(move || {
+            let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+            let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let grid_lengths = [
+                diag_cell_size,
+                cell_size_x,
+                diag_cell_size,
+                cell_size_y,
+                diag_cell_size,
+                cell_size_x,
+                diag_cell_size,
+                cell_size_y,
+            ];
+            let out_vals = match esri_style {
+                true => [128f64, 1f64, 2f64, 4f64, 8f64, 16f64, 32f64, 64f64],
+                false => [1f64, 2f64, 4f64, 8f64, 16f64, 32f64, 64f64, 128f64],
+            };
+            let (mut z, mut z_n, mut slope): (f64, f64, f64);
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data = vec![out_nodata; columns as usize];
+                for col in 0..columns {
+                    z = input.get_value(row, col);
+                    if z != nodata {
+                        let mut dir = 0;
+                        let mut max_slope = f64::MIN;
+                        for i in 0..8 {
+                            z_n = input.get_value(row + d_y[i], col + d_x[i]);
+                            if z_n != nodata {
+                                slope = (z - z_n) / grid_lengths[i];
+                                if slope > max_slope && slope > 0f64 {
+                                    max_slope = slope;
+                                    dir = i;
+                                }
+                            }
+                        }
+                        if max_slope >= 0f64 {
+                            data[col as usize] = out_vals[dir]; //(1 << dir) as f64;
+                        } else {
+                            data[col as usize] = 0f64;
+                        }
+                    } else {
+                        data[col as usize] = out_nodata;
+                    }
+                }
+                tx1.send((row, data)).unwrap();
+            }
+        });
+    }
+
+    for row in 0..rows {
+        let data = rx.recv().unwrap();
+        output.set_row_data(data.0, data.1);
+
+        if verbose {
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Progress", progress);
+                old_progress = progress;
+            }
+        }
+    }
+    output.configs.palette = "qual.plt".to_string();
+    output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+
+    output
+}
@@ -128,42 +128,19 @@ impl WhiteboxTool for DepthInSink {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                zero_background = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--zero_background", "--esri_style"])? {
+            zero_background = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -179,7 +156,7 @@ impl WhiteboxTool for DepthInSink {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -191,6 +168,7 @@ impl WhiteboxTool for DepthInSink {
         let nodata = input.configs.nodata;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let mut background_val = (i32::min_value() + 1) as f64;
         output.reinitialize_values(background_val);
 
@@ -246,7 +224,7 @@ impl WhiteboxTool for DepthInSink {
                 zout_n = output[(row_n, col_n)];
                 if zout_n == background_val {
                     if zin_n == nodata {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         queue.push_back((row_n, col_n));
                     } else {
                         output[(row_n, col_n)] = zin_n;
@@ -264,7 +242,7 @@ impl WhiteboxTool for DepthInSink {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("progress: {}%", progress);
+                    report::progress("progress", progress);
                     old_progress = progress;
                 }
             }
@@ -294,7 +272,7 @@ impl WhiteboxTool for DepthInSink {
                         });
                     } else {
                         // Interior nodata cells are still treated as nodata and are not filled.
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         num_solved_cells += 1;
                     }
                 }
@@ -304,32 +282,32 @@ impl WhiteboxTool for DepthInSink {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    report::progress("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        background_val = nodata;
+        background_val = out_nodata;
         if zero_background {
             background_val = 0f64;
         }
         for row in 0..rows {
             for col in 0..columns {
-                if output[(row, col)] > input[(row, col)] {
+                if input[(row, col)] != nodata && output[(row, col)] > input[(row, col)] {
                     output[(row, col)] = output[(row, col)] - input[(row, col)];
                 } else {
                     if input[(row, col)] != nodata {
                         output[(row, col)] = background_val;
                     } else {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     }
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -347,19 +325,16 @@ impl WhiteboxTool for DepthInSink {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -152,53 +152,25 @@ impl WhiteboxTool for DInfMassFlux {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-dem" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-loading" {
-                if keyval {
-                    loading_file = vec[1].to_string();
-                } else {
-                    loading_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-efficiency" {
-                if keyval {
-                    efficiency_file = vec[1].to_string();
-                } else {
-                    efficiency_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-absorption" {
-                if keyval {
-                    absorption_file = vec[1].to_string();
-                } else {
-                    absorption_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--loading"])? {
+            loading_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--efficiency"])? {
+            efficiency_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--absorption"])? {
+            absorption_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -223,7 +195,7 @@ impl WhiteboxTool for DInfMassFlux {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let start = Instant::now();
@@ -242,7 +214,7 @@ impl WhiteboxTool for DInfMassFlux {
             || efficiency.configs.columns as isize != columns
         {
             return Err(Error::new(ErrorKind::InvalidInput,
-                "All input images must share the same dimensions (rows and columns) and spatial extent."));
+                "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
         }
         let efficiency_multiplier = if efficiency.configs.maximum > 1f64 {
             0.01f64 // assumpted to be percent...need proportion
@@ -255,7 +227,7 @@ impl WhiteboxTool for DInfMassFlux {
             || absorption.configs.columns as isize != columns
         {
             return Err(Error::new(ErrorKind::InvalidInput,
-                "All input images must share the same dimensions (rows and columns) and spatial extent."));
+                "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
         }
 
         // calculate the flow directions
@@ -393,7 +365,7 @@ impl WhiteboxTool for DInfMassFlux {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    report::progress("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -458,7 +430,7 @@ impl WhiteboxTool for DInfMassFlux {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    report::progress("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -466,16 +438,17 @@ impl WhiteboxTool for DInfMassFlux {
 
         // Create the output image
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         // read in the loading file and initialize output with these data.
         let loading = Raster::new(&loading_file, "r")?; // the loading raster
         if loading.configs.rows as isize != rows || loading.configs.columns as isize != columns {
             return Err(Error::new(ErrorKind::InvalidInput,
-                "All input images must share the same dimensions (rows and columns) and spatial extent."));
+                "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
         }
         let load_nodata = absorption.configs.nodata;
 
-        if load_nodata == nodata {
+        if load_nodata == out_nodata {
             output.set_data_from_raster(&loading)?;
         // let _ = match output.set_data_from_raster(&loading) {
         //     Ok(_) => // do nothing,
@@ -489,13 +462,13 @@ impl WhiteboxTool for DInfMassFlux {
                     if load != load_nodata {
                         output.set_value(row, col, load);
                     } else {
-                        output.set_value(row, col, nodata);
+                        output.set_value(row, col, out_nodata);
                     }
                 }
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Initializing output raster: {}%", progress);
+                        report::progress("Initializing output raster", progress);
                         old_progress = progress;
                     }
                 }
@@ -602,7 +575,7 @@ impl WhiteboxTool for DInfMassFlux {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    report::progress("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -611,14 +584,14 @@ impl WhiteboxTool for DInfMassFlux {
         for row in 0..rows {
             for col in 0..columns {
                 if input.get_value(row, col) == nodata {
-                    output.set_value(row, col, nodata);
+                    output.set_value(row, col, out_nodata);
                 }
             }
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Correcting values: {}%", progress);
+                    report::progress("Correcting values", progress);
                     old_progress = progress;
                 }
             }
@@ -637,26 +610,21 @@ impl WhiteboxTool for DInfMassFlux {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
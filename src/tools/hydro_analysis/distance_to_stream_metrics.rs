@@ -0,0 +1,561 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::i32;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::Array2D;
+use tools::*;
+
+/// This tool measures, in a single pass, the relations between each grid cell in a DEM
+/// (`--dem`) and the stream cell that receives its flow, i.e. the first cell of an input
+/// streams raster (`--streams`) that is encountered along its D8 flowpath. These measures
+/// are commonly needed together in riparian analysis. The output file (`--output`) contains
+/// the distance to the receiving stream cell measured along the flowpath, as calculated by
+/// the `DownslopeDistanceToStream` tool. The following optional outputs may also be created:
+///
+/// - `--out_euclidean`: the straight-line (Euclidean) distance to the receiving stream cell;
+/// - `--out_drop`: the elevation drop to the receiving stream cell, i.e. the height above
+///   the stream, as calculated by the `ElevationAboveStream` tool;
+/// - `--out_slope`: the average slope gradient (degrees) of the flowpath to the stream,
+///   i.e. the arctangent of the ratio of the elevation drop to the flowpath distance; and
+/// - `--out_link`: the identifier of the stream link containing the receiving stream cell.
+///
+/// The stream links are identified from the D8 flow directions within the stream network,
+/// with each link extending downstream from a channel head or a confluence, and each link
+/// is assigned a unique positive identifier. Stream cells receive their own flow and are
+/// assigned distances, drops, and slopes of zero.
+///
+/// The DEM should be hydrologically conditioned, e.g. using the `BreachDepressions` or
+/// `FillDepressions` tools, before running this tool. Cells whose flowpaths end at a pit
+/// or an edge without encountering a stream are assigned NoData in all of the outputs.
+///
+/// # See Also
+/// `DownslopeDistanceToStream`, `ElevationAboveStream`, `ElevationAboveStreamEuclidean`,
+/// `StreamLinkIdentifier`
+pub struct DistanceToStreamMetrics {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DistanceToStreamMetrics {
+    pub fn new() -> DistanceToStreamMetrics {
+        // public constructor
+        let name = "DistanceToStreamMetrics".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Measures the flowpath and Euclidean distances, elevation drop, and slope to the receiving stream cell, and its link ID."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Streams File".to_owned(),
+            flags: vec!["--streams".to_owned()],
+            description: "Input raster streams file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Flowpath Distance File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file of the distance to the stream along the flowpath."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Euclidean Distance File (optional)".to_owned(),
+            flags: vec!["--out_euclidean".to_owned()],
+            description: "Output raster file of the Euclidean distance to the receiving stream cell.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Elevation Drop File (optional)".to_owned(),
+            flags: vec!["--out_drop".to_owned()],
+            description: "Output raster file of the elevation drop to the receiving stream cell."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Average Slope File (optional)".to_owned(),
+            flags: vec!["--out_slope".to_owned()],
+            description: "Output raster file of the average flowpath slope (degrees) to the stream."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Stream Link ID File (optional)".to_owned(),
+            flags: vec!["--out_link".to_owned()],
+            description: "Output raster file of the ID of the receiving stream link.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem='dem.tif' --streams='streams.tif' -o='flow_dist.tif' --out_euclidean='euclid_dist.tif' --out_drop='drop.tif' --out_slope='slope.tif' --out_link='link.tif'", short_exe, name).replace("*", &sep);
+
+        DistanceToStreamMetrics {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DistanceToStreamMetrics {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut dem_file = String::new();
+        let mut streams_file = String::new();
+        let mut output_file = String::new();
+        let mut euclidean_file = String::new();
+        let mut drop_file = String::new();
+        let mut slope_file = String::new();
+        let mut link_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_euclidean"])? {
+            euclidean_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_drop"])? {
+            drop_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_slope"])? {
+            slope_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_link"])? {
+            link_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        for file in [
+            &mut dem_file,
+            &mut streams_file,
+            &mut output_file,
+            &mut euclidean_file,
+            &mut drop_file,
+            &mut slope_file,
+            &mut link_file,
+        ].iter_mut()
+        {
+            if !file.is_empty() && !file.contains(&sep) && !file.contains("/") {
+                **file = format!("{}{}", working_directory, file);
+            }
+        }
+
+        if verbose {
+            report::message("Reading DEM data...")
+        };
+        let dem = Arc::new(Raster::new(&dem_file, "r")?);
+        if verbose {
+            report::message("Reading streams data...")
+        };
+        let streams = Raster::new(&streams_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = dem.configs.rows as isize;
+        let columns = dem.configs.columns as isize;
+        let nodata = dem.configs.nodata;
+        let streams_nodata = streams.configs.nodata;
+        let cell_size_x = dem.configs.resolution_x;
+        let cell_size_y = dem.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+        let flow_nodata = -2i8;
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let inflowing_vals = [4i8, 5i8, 6i8, 7i8, 0i8, 1i8, 2i8, 3i8];
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+
+        // make sure the input files have the same size
+        if dem.configs.rows != streams.configs.rows
+            || dem.configs.columns != streams.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let dem = dem.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let (mut z, mut z_n): (f64, f64);
+                let (mut max_slope, mut slope): (f64, f64);
+                let mut dir: i8;
+                let mut neighbouring_nodata: bool;
+                let mut interior_pit_found = false;
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data: Vec<i8> = vec![flow_nodata; columns as usize];
+                    for col in 0..columns {
+                        z = dem[(row, col)];
+                        if z != nodata {
+                            dir = 0i8;
+                            max_slope = f64::MIN;
+                            neighbouring_nodata = false;
+                            for i in 0..8 {
+                                z_n = dem[(row + dy[i], col + dx[i])];
+                                if z_n != nodata {
+                                    slope = (z - z_n) / grid_lengths[i];
+                                    if slope > max_slope && slope > 0f64 {
+                                        max_slope = slope;
+                                        dir = i as i8;
+                                    }
+                                } else {
+                                    neighbouring_nodata = true;
+                                }
+                            }
+                            if max_slope >= 0f64 {
+                                data[col as usize] = dir;
+                            } else {
+                                data[col as usize] = -1i8;
+                                if !neighbouring_nodata {
+                                    interior_pit_found = true;
+                                }
+                            }
+                        }
+                    }
+                    tx.send((row, data, interior_pit_found)).unwrap();
+                }
+            });
+        }
+
+        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, flow_nodata, flow_nodata)?;
+        let mut interior_pit_found = false;
+        for r in 0..rows {
+            let (row, data, pit) = rx.recv().unwrap();
+            flow_dir.set_row_data(row, data);
+            if pit {
+                interior_pit_found = true;
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Flow directions", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let is_stream = |row: isize, col: isize| -> bool {
+            streams[(row, col)] > 0f64
+                && streams[(row, col)] != streams_nodata
+                && dem[(row, col)] != nodata
+        };
+
+        // identify the stream links; a link begins at each channel head and at each
+        // confluence, i.e. at the stream cells that do not have exactly one inflowing
+        // stream neighbour.
+        let mut link_id: Array2D<i32> = Array2D::new(rows, columns, 0, 0)?;
+        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+        let mut stack = vec![];
+        let mut current_id = 0i32;
+        let mut count: i8;
+        for row in 0..rows {
+            for col in 0..columns {
+                if is_stream(row, col) {
+                    count = 0i8;
+                    for n in 0..8 {
+                        if is_stream(row + dy[n], col + dx[n])
+                            && flow_dir[(row + dy[n], col + dx[n])] == inflowing_vals[n]
+                        {
+                            count += 1;
+                        }
+                    }
+                    num_inflowing[(row, col)] = count;
+                    if count == 0 {
+                        current_id += 1;
+                        link_id[(row, col)] = current_id;
+                        stack.push((row, col));
+                    }
+                }
+            }
+        }
+        let (mut row_n, mut col_n): (isize, isize);
+        while let Some((row, col)) = stack.pop() {
+            let dir = flow_dir[(row, col)];
+            if dir < 0 {
+                continue;
+            }
+            row_n = row + dy[dir as usize];
+            col_n = col + dx[dir as usize];
+            if is_stream(row_n, col_n) {
+                num_inflowing.decrement(row_n, col_n, 1i8);
+                if num_inflowing[(row_n, col_n)] == 0 {
+                    if link_id[(row_n, col_n)] == -1 {
+                        // a confluence
+                        current_id += 1;
+                        link_id[(row_n, col_n)] = current_id;
+                    } else {
+                        link_id[(row_n, col_n)] = link_id[(row, col)];
+                    }
+                    stack.push((row_n, col_n));
+                } else {
+                    // flag the cell as a confluence
+                    link_id[(row_n, col_n)] = -1;
+                }
+            }
+        }
+
+        // find the receiving stream cell of each grid cell by working upslope from the
+        // streams; cells that do not drain to a stream are left unsolved (-1).
+        let mut receiving_cell: Array2D<isize> = Array2D::new(rows, columns, -1, -1)?;
+        let mut flow_dist: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut num_solved_cells = 0;
+        for row in 0..rows {
+            for col in 0..columns {
+                if is_stream(row, col) {
+                    receiving_cell[(row, col)] = row * columns + col;
+                    flow_dist[(row, col)] = 0f64;
+                    stack.push((row, col));
+                }
+            }
+        }
+        let num_cells = dem.num_cells();
+        while let Some((row, col)) = stack.pop() {
+            for n in 0..8 {
+                row_n = row + dy[n];
+                col_n = col + dx[n];
+                if flow_dir[(row_n, col_n)] == inflowing_vals[n]
+                    && receiving_cell[(row_n, col_n)] == -1
+                {
+                    receiving_cell[(row_n, col_n)] = receiving_cell[(row, col)];
+                    flow_dist[(row_n, col_n)] = flow_dist[(row, col)] + grid_lengths[n];
+                    stack.push((row_n, col_n));
+                }
+            }
+            if verbose {
+                num_solved_cells += 1;
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Tracing flowpaths", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // create the outputs
+        let mut output = Raster::initialize_using_file(&output_file, &dem);
+        let out_nodata = output.configs.nodata;
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        let new_output = |file: &str, data_type: DataType| -> Option<Raster> {
+            if file.is_empty() {
+                return None;
+            }
+            let mut r = Raster::initialize_using_file(file, &dem);
+            r.configs.data_type = data_type;
+            r.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            Some(r)
+        };
+        let mut out_euclidean = new_output(&euclidean_file, DataType::F32);
+        let mut out_drop = new_output(&drop_file, DataType::F32);
+        let mut out_slope = new_output(&slope_file, DataType::F32);
+        let mut out_link = new_output(&link_file, DataType::I32);
+        if let Some(ref mut r) = out_link {
+            r.configs.nodata = i32::MIN as f64;
+            r.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        }
+        let link_nodata = i32::MIN as f64;
+
+        let (mut row_s, mut col_s): (isize, isize);
+        let (mut x, mut y, mut drop, mut dist): (f64, f64, f64, f64);
+        for row in 0..rows {
+            let mut dist_data = vec![out_nodata; columns as usize];
+            let mut euclidean_data = vec![out_nodata; columns as usize];
+            let mut drop_data = vec![out_nodata; columns as usize];
+            let mut slope_data = vec![out_nodata; columns as usize];
+            let mut link_data = vec![link_nodata; columns as usize];
+            for col in 0..columns {
+                if receiving_cell[(row, col)] >= 0 && dem[(row, col)] != nodata {
+                    row_s = receiving_cell[(row, col)] / columns;
+                    col_s = receiving_cell[(row, col)] % columns;
+                    dist = flow_dist[(row, col)];
+                    dist_data[col as usize] = dist;
+                    x = (col - col_s) as f64 * cell_size_x;
+                    y = (row - row_s) as f64 * cell_size_y;
+                    euclidean_data[col as usize] = (x * x + y * y).sqrt();
+                    drop = dem[(row, col)] - dem[(row_s, col_s)];
+                    drop_data[col as usize] = drop;
+                    slope_data[col as usize] = if dist > 0f64 {
+                        (drop / dist).atan().to_degrees()
+                    } else {
+                        0f64
+                    };
+                    link_data[col as usize] = link_id[(row_s, col_s)] as f64;
+                }
+            }
+            output.set_row_data(row, dist_data);
+            if let Some(ref mut r) = out_euclidean {
+                r.set_row_data(row, euclidean_data);
+            }
+            if let Some(ref mut r) = out_drop {
+                r.set_row_data(row, drop_data);
+            }
+            if let Some(ref mut r) = out_slope {
+                r.set_row_data(row, slope_data);
+            }
+            if let Some(ref mut r) = out_link {
+                r.set_row_data(row, link_data);
+            }
+
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Calculating metrics", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::message("Saving data...")
+        };
+        let mut outputs = vec![Some(output), out_euclidean, out_drop, out_slope, out_link];
+        for r in outputs.iter_mut() {
+            if let Some(ref mut r) = *r {
+                r.add_metadata_entry(format!(
+                    "Created by whitebox_tools\' {} tool",
+                    self.get_tool_name()
+                ));
+                r.add_metadata_entry(format!("DEM file: {}", dem_file));
+                r.add_metadata_entry(format!("Streams file: {}", streams_file));
+                r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+                let _ = match r.write() {
+                    Ok(_) => if verbose {
+                        report::metadata("Output file written", &r.file_name)
+                    },
+                    Err(e) => return Err(e),
+                };
+            }
+        }
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        if interior_pit_found {
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
+            running this tool.");
+        }
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 9, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -128,40 +128,19 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-dem" || vec[0].to_lowercase() == "--dem" {
-                if keyval {
-                    dem_file = vec[1].to_string();
-                } else {
-                    dem_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
-                if keyval {
-                    streams_file = vec[1].to_string();
-                } else {
-                    streams_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -180,11 +159,11 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            report::message("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -208,7 +187,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -273,6 +252,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, flow_nodata, flow_nodata)?;
         let mut interior_pit_found = false;
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        let out_nodata = output.configs.nodata;
         let background_value = f64::MIN;
         output.reinitialize_values(background_value);
         let mut stack = Vec::with_capacity((rows * columns) as usize);
@@ -286,16 +266,16 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             for col in 0..columns {
                 if streams[(row, col)] > 0f64 && streams[(row, col)] != streams_nodata {
                     output[(row, col)] = 0f64;
-                    stack.push((row, col, dem[(row, col)]));
+                    stack.push((row, col, 0f64));
                 }
                 if dem[(row, col)] == nodata {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                     num_solved_cells += 1;
                 }
                 if flow_dir[(row, col)] == -1 {
                     if output[(row, col)] != 0f64 {
                         stack.push((row, col, nodata));
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                         num_solved_cells += 1;
                     }
                 }
@@ -303,7 +283,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    report::progress("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -340,7 +320,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                         output[(row_n, col_n)] = dist;
                         stack.push((row_n, col_n, dist));
                     } else {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         stack.push((row_n, col_n, nodata));
                     }
                 }
@@ -349,7 +329,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -365,27 +345,22 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
@@ -146,49 +146,25 @@ impl WhiteboxTool for DownslopeFlowpathLength {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-d8_pntr" {
-                d8_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-watersheds" {
-                watersheds_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-weights" {
-                weights_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--watersheds"])? {
+            watersheds_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--weights"])? {
+            weights_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -222,7 +198,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            report::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let rows = pntr.configs.rows as isize;
@@ -233,7 +209,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
 
         if verbose {
-            println!("Initializing watershed data...")
+            report::message("Initializing watershed data...")
         };
         let watersheds: Array2D<f64> = match use_watersheds {
             false => Array2D::new(1, 1, 1f64, 1f64)?,
@@ -242,7 +218,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
                 let r = Raster::new(&watersheds_file, "r")?;
                 if r.configs.rows != rows as usize || r.configs.columns != columns as usize {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                        "The input files must have the same number of rows and columns and spatial extent."));
+                                        "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
                 r.get_data_as_array2d()
             }
@@ -250,7 +226,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         // let watershed_nodata = watersheds.nodata;
 
         if verbose {
-            println!("Initializing weights data...")
+            report::message("Initializing weights data...")
         };
         let weights: Array2D<f64> = match use_weights {
             false => Array2D::new(1, 1, 1f64, 1f64)?,
@@ -259,7 +235,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
                 let r = Raster::new(&weights_file, "r")?;
                 if r.configs.rows != rows as usize || r.configs.columns != columns as usize {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                        "The input files must have the same number of rows and columns and spatial extent."));
+                                        "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
                 r.get_data_as_array2d()
             }
@@ -383,7 +359,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -407,19 +383,16 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,442 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use num_cpus;
+use raster::*;
+use std::collections::VecDeque;
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::Array2D;
+use tools::*;
+
+/// This tool identifies the grid cells of a DEM (`--dem`) whose contributing areas may
+/// be truncated by the edges of the DEM or by NoData regions, and for which the values
+/// produced by the flow accumulation tools may therefore be underestimated. This edge
+/// contamination is a common problem when working with DEM tiles, or with DEMs that have
+/// been clipped to an area of interest rather than to the boundary of a watershed. Each
+/// valid cell that lies on the edge of the grid or that neighbours a NoData cell may
+/// receive flow from beyond the data, and so the cells that receive flow from these edge
+/// cells, following the flow-routing method (`--flow_type`) used for flow accumulation,
+/// are contaminated. The flow-routing methods are `d8`, as used by the `D8FlowAccumulation`
+/// tool, `dinf`, as used by `DInfFlowAccumulation`, and `fd8`, as used by
+/// `FD8FlowAccumulation`, in which flow is dispersed to all downslope neighbours.
+///
+/// The output raster (`--output`) is a Boolean mask in which contaminated cells have a
+/// value of 1 and the remaining valid cells have a value of 0. The mask may be used to
+/// exclude unreliable flow accumulation values from further analysis, e.g. when
+/// extracting streams, or to determine how much larger than an area of interest a DEM
+/// must be for the flow accumulation within that area to be complete.
+///
+/// The DEM should be hydrologically conditioned, e.g. using the `BreachDepressions` or
+/// `FillDepressions` tools, before running this tool, as for the flow accumulation tools.
+///
+/// # See Also
+/// `D8FlowAccumulation`, `DInfFlowAccumulation`, `FD8FlowAccumulation`
+pub struct EdgeContamination {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl EdgeContamination {
+    pub fn new() -> EdgeContamination {
+        // public constructor
+        let name = "EdgeContamination".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Identifies cells whose contributing areas may be truncated by the edges of a DEM or NoData regions."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flow Type".to_owned(),
+            flags: vec!["--flow_type".to_owned()],
+            description: "Flow-routing method; options are 'd8', 'dinf', and 'fd8'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "d8".to_owned(),
+                "dinf".to_owned(),
+                "fd8".to_owned(),
+            ]),
+            default_value: Some("d8".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem='DEM.tif' -o='edge_mask.tif' --flow_type='dinf'", short_exe, name).replace("*", &sep);
+
+        EdgeContamination {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for EdgeContamination {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut flow_type = "d8".to_string();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--flow_type"])? {
+            flow_type = v.to_lowercase();
+        }
+        // 0 = D8, 1 = D-infinity, 2 = FD8
+        let method = if flow_type.contains("inf") {
+            1
+        } else if flow_type.contains("fd8") || flow_type.contains("mfd") {
+            2
+        } else if flow_type.contains("d8") {
+            0
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The flow type (--flow_type) must be one of 'd8', 'dinf', and 'fd8'.",
+            ));
+        };
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let cell_size_x = input.configs.resolution_x;
+        let cell_size_y = input.configs.resolution_y;
+
+        // find the receivers of each cell; the receivers are stored as a bit field, in
+        // which bit i is set if flow is directed to the neighbour in direction i.
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+                let diag_cell_size =
+                    (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+                let grid_lengths = [
+                    diag_cell_size,
+                    cell_size_x,
+                    diag_cell_size,
+                    cell_size_y,
+                    diag_cell_size,
+                    cell_size_x,
+                    diag_cell_size,
+                    cell_size_y,
+                ];
+                let (mut z, mut z_n, mut slope, mut max_slope): (f64, f64, f64, f64);
+                let mut receivers: u8;
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![0u8; columns as usize];
+                    for col in 0..columns {
+                        z = input[(row, col)];
+                        if z == nodata {
+                            continue;
+                        }
+                        receivers = 0u8;
+                        if method == 1 {
+                            if let Some(dir) =
+                                dinf_direction(&input, row, col, cell_size_x, cell_size_y)
+                            {
+                                // the D-infinity direction is measured clockwise from north,
+                                // and lies between the directions of two neighbours
+                                let sector = (dir / 45f64).floor();
+                                let first = (sector as usize + 7) % 8;
+                                receivers |= 1 << first;
+                                if dir > sector * 45f64 {
+                                    receivers |= 1 << ((first + 1) % 8);
+                                }
+                            }
+                        } else {
+                            max_slope = 0f64;
+                            for i in 0..8 {
+                                z_n = input[(row + dy[i], col + dx[i])];
+                                if z_n != nodata && z_n < z {
+                                    if method == 2 {
+                                        receivers |= 1 << i;
+                                    } else {
+                                        slope = (z - z_n) / grid_lengths[i];
+                                        if slope > max_slope {
+                                            max_slope = slope;
+                                            receivers = 1 << i;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        data[col as usize] = receivers;
+                    }
+                    tx.send((row, data)).unwrap();
+                }
+            });
+        }
+
+        let mut receivers: Array2D<u8> = Array2D::new(rows, columns, 0u8, 0u8)?;
+        for r in 0..rows {
+            let (row, data) = rx.recv().unwrap();
+            receivers.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Flow directions", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // the edge cells are contaminated, and so is every cell that receives flow from a
+        // contaminated cell.
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.nodata = -32768f64;
+        output.configs.data_type = DataType::I16;
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        output.configs.palette = "qual.plt".to_string();
+        let out_nodata = -32768f64;
+        output.reinitialize_values(out_nodata);
+        let mut queue = VecDeque::new();
+        let (mut row_n, mut col_n): (isize, isize);
+        for row in 0..rows {
+            for col in 0..columns {
+                if input[(row, col)] != nodata {
+                    output[(row, col)] = 0f64;
+                    for i in 0..8 {
+                        row_n = row + dy[i];
+                        col_n = col + dx[i];
+                        if input[(row_n, col_n)] == nodata {
+                            // this also includes the cells beyond the grid edges
+                            output[(row, col)] = 1f64;
+                            queue.push_back((row, col));
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        let num_cells = input.num_cells();
+        let mut num_solved_cells = 0;
+        while let Some((row, col)) = queue.pop_front() {
+            let r = receivers[(row, col)];
+            for i in 0..8 {
+                if r & (1 << i) != 0 {
+                    row_n = row + dy[i];
+                    col_n = col + dx[i];
+                    if output[(row_n, col_n)] == 0f64 {
+                        output[(row_n, col_n)] = 1f64;
+                        queue.push_back((row_n, col_n));
+                    }
+                }
+            }
+            if verbose {
+                num_solved_cells += 1;
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Tracing contamination", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Flow type: {}", flow_type));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the D-infinity flow direction of a cell (Tarboton, 1997), in degrees clockwise
+/// from north, or `None` if the cell has no downslope neighbours. The calculation is the
+/// same as that used by the `DInfPointer` and `DInfFlowAccumulation` tools.
+fn dinf_direction(
+    input: &Raster,
+    row: isize,
+    col: isize,
+    cell_size_x: f64,
+    cell_size_y: f64,
+) -> Option<f64> {
+    let nodata = input.configs.nodata;
+    let grid_res = (cell_size_x + cell_size_y) / 2.0;
+    let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+    let ac_vals = [0f64, 1f64, 1f64, 2f64, 2f64, 3f64, 3f64, 4f64];
+    let af_vals = [1f64, -1f64, 1f64, -1f64, 1f64, -1f64, 1f64, -1f64];
+    let e1_col = [1, 0, 0, -1, -1, 0, 0, 1];
+    let e1_row = [0, -1, -1, 0, 0, 1, 1, 0];
+    let e2_col = [1, 1, -1, -1, -1, -1, 1, 1];
+    let e2_row = [-1, -1, -1, -1, 1, 1, 1, 1];
+    let atanof1 = 1.0f64.atan();
+    let e0 = input[(row, col)];
+    let mut dir = 360.0;
+    let mut max_slope = f64::MIN;
+    let (mut e1, mut e2, mut r, mut s, mut s1, mut s2): (f64, f64, f64, f64, f64, f64);
+    for i in 0..8 {
+        e1 = input[(row + e1_row[i], col + e1_col[i])];
+        e2 = input[(row + e2_row[i], col + e2_col[i])];
+        if e1 == nodata || e2 == nodata {
+            continue;
+        }
+        if e0 > e1 && e0 > e2 {
+            s1 = (e0 - e1) / grid_res;
+            s2 = (e1 - e2) / grid_res;
+            r = (s2 / s1).atan();
+            s = (s1 * s1 + s2 * s2).sqrt();
+            if r < 0.0 {
+                r = 0.0;
+                s = s1;
+            } else if r > atanof1 {
+                r = atanof1;
+                s = (e0 - e2) / diag_cell_size;
+            }
+        } else if e0 > e1 {
+            r = 0.0;
+            s = (e0 - e1) / grid_res;
+        } else if e0 > e2 {
+            r = atanof1;
+            s = (e0 - e2) / diag_cell_size;
+        } else {
+            continue;
+        }
+        if s >= max_slope {
+            max_slope = s;
+            dir = af_vals[i] * r + ac_vals[i] * (PI / 2.0);
+        }
+    }
+    if max_slope > 0f64 {
+        dir = 360.0 - dir.to_degrees() + 90.0;
+        if dir >= 360.0 {
+            dir -= 360.0;
+        }
+        Some(dir)
+    } else {
+        None
+    }
+}
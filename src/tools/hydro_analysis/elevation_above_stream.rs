@@ -130,41 +130,19 @@ impl WhiteboxTool for ElevationAboveStream {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-dem" || flag_val == "-i" {
-                dem_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-streams" {
-                streams_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem", "-i"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -183,11 +161,11 @@ impl WhiteboxTool for ElevationAboveStream {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            report::message("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -211,7 +189,7 @@ impl WhiteboxTool for ElevationAboveStream {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -276,6 +254,7 @@ impl WhiteboxTool for ElevationAboveStream {
         let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, flow_nodata, flow_nodata)?;
         let mut interior_pit_found = false;
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        let out_nodata = output.configs.nodata;
         let background_value = f64::MIN;
         output.reinitialize_values(background_value);
         let mut stack = Vec::with_capacity((rows * columns) as usize);
@@ -292,13 +271,13 @@ impl WhiteboxTool for ElevationAboveStream {
                     stack.push((row, col, dem[(row, col)]));
                 }
                 if dem[(row, col)] == nodata {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                     num_solved_cells += 1;
                 }
                 if flow_dir[(row, col)] == -1 {
                     if output[(row, col)] != 0f64 {
                         stack.push((row, col, nodata));
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                         num_solved_cells += 1;
                     }
                 }
@@ -306,7 +285,7 @@ impl WhiteboxTool for ElevationAboveStream {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    report::progress("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -331,7 +310,7 @@ impl WhiteboxTool for ElevationAboveStream {
                     if stream_elev != nodata {
                         output[(row_n, col_n)] = dem[(row_n, col_n)] - stream_elev;
                     } else {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                     }
                 }
             }
@@ -339,7 +318,7 @@ impl WhiteboxTool for ElevationAboveStream {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -428,27 +407,22 @@ impl WhiteboxTool for ElevationAboveStream {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
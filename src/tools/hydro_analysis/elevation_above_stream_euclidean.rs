@@ -126,41 +126,19 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-dem" {
-                dem_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-streams" {
-                streams_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -179,7 +157,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let dem = Raster::new(&dem_file, "r")?;
@@ -202,6 +180,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
         let start = Instant::now();
 
         let mut allocation = Raster::initialize_using_file(&output_file, &dem);
+        let out_nodata = allocation.configs.nodata;
 
         let mut h: f64;
         let mut which_cell: usize;
@@ -227,7 +206,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    report::progress("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -270,7 +249,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
+                    report::progress("Progress (1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -313,7 +292,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
+                    report::progress("Progress (2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -323,7 +302,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
             for col in 0..columns {
                 z = input[(row, col)];
                 if z == nodata {
-                    allocation[(row, col)] = nodata;
+                    allocation[(row, col)] = out_nodata;
                 } else {
                     allocation[(row, col)] = dem[(row, col)] - allocation[(row, col)];
                 }
@@ -331,7 +310,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    report::progress("Progress (3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -348,20 +327,17 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
         allocation.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match allocation.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
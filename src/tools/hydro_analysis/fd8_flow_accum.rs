@@ -172,70 +172,38 @@ impl WhiteboxTool for FD8FlowAccumulation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_type" || vec[0].to_lowercase() == "--out_type"
-            {
-                if keyval {
-                    out_type = vec[1].to_lowercase();
-                } else {
-                    out_type = args[i + 1].to_lowercase();
-                }
-                if out_type.contains("specific") || out_type.contains("sca") {
-                    out_type = String::from("sca");
-                } else if out_type.contains("cells") {
-                    out_type = String::from("cells");
-                } else {
-                    out_type = String::from("ca");
-                }
-            } else if vec[0].to_lowercase() == "-exponent" || vec[0].to_lowercase() == "--exponent"
-            {
-                if keyval {
-                    exponent = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    exponent = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-threshold"
-                || vec[0].to_lowercase() == "--threshold"
-            {
-                if keyval {
-                    convergence_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    convergence_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-log" || vec[0].to_lowercase() == "--log" {
-                log_transform = true;
-            } else if vec[0].to_lowercase() == "-clip" || vec[0].to_lowercase() == "--clip" {
-                clip_max = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_type"])? {
+            out_type = v.to_lowercase();
+            if out_type.contains("specific") || out_type.contains("sca") {
+                out_type = String::from("sca");
+            } else if out_type.contains("cells") {
+                out_type = String::from("cells");
+            } else {
+                out_type = String::from("ca");
             }
         }
+        if let Some(v) = tool_args.get_f64(&["--exponent"])? {
+            exponent = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--threshold"])? {
+            convergence_threshold = v;
+        }
+        if tool_args.get_flag(&["--log"])? {
+            log_transform = true;
+        }
+        if tool_args.get_flag(&["--clip"])? {
+            clip_max = true;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -251,7 +219,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -302,6 +270,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
         output.reinitialize_values(1.0);
+        let out_nodata = output.configs.nodata;
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
         let mut interior_pit_found = false;
@@ -322,7 +291,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    report::progress("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -410,7 +379,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    report::progress("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -429,7 +398,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
             for row in 0..rows {
                 for col in 0..columns {
                     if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     } else {
                         output[(row, col)] = (output[(row, col)] * cell_area / avg_cell_size).ln();
                     }
@@ -438,7 +407,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        report::progress("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -447,7 +416,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
             for row in 0..rows {
                 for col in 0..columns {
                     if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     } else {
                         output[(row, col)] = output[(row, col)] * cell_area / avg_cell_size;
                     }
@@ -456,7 +425,7 @@ impl WhiteboxTool for FD8FlowAccumulation {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        report::progress("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -476,26 +445,21 @@ impl WhiteboxTool for FD8FlowAccumulation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 01/04/2018
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 Notes: This tool uses the algorithm described in:
@@ -28,7 +28,7 @@ use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
-use structures::{Array2D, BoundingBox};
+use structures::{Array2D, BitArray2D, BoundingBox};
 use tools::*;
 use vector::{ShapeType, Shapefile};
 
@@ -152,41 +152,19 @@ impl WhiteboxTool for FillBurn {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-dem" {
-                dem_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-streams" {
-                streams_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -205,12 +183,12 @@ impl WhiteboxTool for FillBurn {
         }
 
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Shapefile::read(&streams_file)?;
 
         if verbose {
-            println!("Reading DEM data...")
+            report::message("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
         let rows = dem.configs.rows as isize;
@@ -343,7 +321,7 @@ impl WhiteboxTool for FillBurn {
             if verbose {
                 progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
+                    report::progress("Rasterizing Streams", progress);
                     old_progress = progress;
                 }
             }
@@ -409,7 +387,7 @@ impl WhiteboxTool for FillBurn {
                 if verbose {
                     progress = (100.0_f64 * a as f64 / 7.0) as usize;
                     if progress != old_progress {
-                        println!("Line Thinning {}: {}%", loop_num, progress);
+                        report::progress(&format!("Line Thinning {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -418,6 +396,8 @@ impl WhiteboxTool for FillBurn {
 
         // Make a copy of the DEM where each stream cell
         //  has been lowered by 10,000 elevation units.
+        let mut output = Raster::initialize_using_file(&output_file, &dem);
+        let out_nodata = output.configs.nodata;
         let raster_streams = Arc::new(raster_streams);
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -428,7 +408,7 @@ impl WhiteboxTool for FillBurn {
             thread::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = dem.get_value(row, col);
                         if raster_streams.get_value(row, col) == 0u8 && z != nodata {
@@ -442,7 +422,6 @@ impl WhiteboxTool for FillBurn {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &dem);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
@@ -450,14 +429,14 @@ impl WhiteboxTool for FillBurn {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
+                    report::progress("Initializing output", progress);
                     old_progress = progress;
                 }
             }
         }
 
         // Fill the streams-decremented DEM.
-        let mut in_queue: Array2D<u8> = Array2D::new(rows, columns, 0u8, 2u8)?;
+        let mut in_queue = BitArray2D::new(rows, columns, false, true)?;
 
         /*
         Find the data edges. This is complicated by the fact that DEMs frequently
@@ -507,7 +486,7 @@ impl WhiteboxTool for FillBurn {
             for n in 0..8 {
                 row_n = row + dy[n];
                 col_n = col + dx[n];
-                if in_queue.get_value(row_n, col_n) == 0u8 {
+                if !in_queue.get_value(row_n, col_n) {
                     if dem.get_value(row_n, col_n) == nodata {
                         queue.push_back((row_n, col_n));
                     } else {
@@ -518,7 +497,7 @@ impl WhiteboxTool for FillBurn {
                             priority: output.get_value(row_n, col_n),
                         });
                     }
-                    in_queue.set_value(row_n, col_n, 1u8);
+                    in_queue.set_value(row_n, col_n, true);
                     num_solved_cells += 1;
                 }
             }
@@ -526,7 +505,7 @@ impl WhiteboxTool for FillBurn {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -547,10 +526,10 @@ impl WhiteboxTool for FillBurn {
             for n in 0..8 {
                 row_n = row + dy[n];
                 col_n = col + dx[n];
-                if in_queue.get_value(row_n, col_n) == 0u8 {
+                if !in_queue.get_value(row_n, col_n) {
                     zout_n = output.get_value(row_n, col_n);
                     // zin_n = input[(row_n, col_n)];
-                    if zout_n != nodata {
+                    if zout_n != out_nodata {
                         if zout_n < (zout + small_num) {
                             zout_n = zout + small_num;
                         } // We're in a depression. Raise the elevation.
@@ -561,7 +540,7 @@ impl WhiteboxTool for FillBurn {
                             priority: zout_n,
                         });
                     }
-                    in_queue.set_value(row_n, col_n, 1u8);
+                    in_queue.set_value(row_n, col_n, true);
                 }
             }
 
@@ -569,7 +548,7 @@ impl WhiteboxTool for FillBurn {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Filling DEM: {}%", progress);
+                    report::progress("Filling DEM", progress);
                     old_progress = progress;
                 }
             }
@@ -592,7 +571,7 @@ impl WhiteboxTool for FillBurn {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Updating Stream Elevations: {}%", progress);
+                    report::progress("Updating Stream Elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -610,7 +589,7 @@ impl WhiteboxTool for FillBurn {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Updating Stream Elevations: {}%", progress);
+                    report::progress("Updating Stream Elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -627,19 +606,16 @@ impl WhiteboxTool for FillBurn {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
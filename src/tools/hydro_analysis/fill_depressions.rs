@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 28, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -12,9 +12,9 @@ use std::collections::BinaryHeap;
 use std::collections::VecDeque;
 use std::env;
 use std::f64;
-use std::i32;
 use std::io::{Error, ErrorKind};
 use std::path;
+use structures::BitArray2D;
 use tools::*;
 
 pub struct FillDepressions {
@@ -132,41 +132,19 @@ impl WhiteboxTool for FillDepressions {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-fix_flats"
-                || vec[0].to_lowercase() == "--fix_flats"
-            {
-                fix_flats = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--fix_flats"])? {
+            fix_flats = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -182,7 +160,7 @@ impl WhiteboxTool for FillDepressions {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -202,8 +180,10 @@ impl WhiteboxTool for FillDepressions {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
-        let background_val = (i32::min_value() + 1) as f64;
-        output.reinitialize_values(background_val);
+        let out_nodata = output.configs.nodata;
+        // cells are solved once their output value is assigned; cells beyond the edges of
+        // the raster are treated as solved
+        let mut solved = BitArray2D::new(rows, columns, false, true)?;
 
         /*
         Find the data edges. This is complicated by the fact that DEMs frequently
@@ -241,7 +221,6 @@ impl WhiteboxTool for FillDepressions {
         let mut num_solved_cells = 0;
         let mut zin_n: f64; // value of neighbour of row, col in input raster
         let mut zout: f64; // value of row, col in output raster
-        let mut zout_n: f64; // value of neighbour of row, col in output raster
         let dx = [1, 1, 1, 0, -1, -1, -1, 0];
         let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
         let (mut row, mut col): (isize, isize);
@@ -254,10 +233,10 @@ impl WhiteboxTool for FillDepressions {
                 row_n = row + dy[n];
                 col_n = col + dx[n];
                 zin_n = input[(row_n, col_n)];
-                zout_n = output[(row_n, col_n)];
-                if zout_n == background_val {
+                if !solved.get_value(row_n, col_n) {
+                    solved.set_value(row_n, col_n, true);
                     if zin_n == nodata {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         queue.push_back((row_n, col_n));
                     } else {
                         output[(row_n, col_n)] = zin_n;
@@ -275,7 +254,7 @@ impl WhiteboxTool for FillDepressions {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("progress: {}%", progress);
+                    report::progress("progress", progress);
                     old_progress = progress;
                 }
             }
@@ -295,8 +274,8 @@ impl WhiteboxTool for FillDepressions {
             for n in 0..8 {
                 row_n = row + dy[n];
                 col_n = col + dx[n];
-                zout_n = output[(row_n, col_n)];
-                if zout_n == background_val {
+                if !solved.get_value(row_n, col_n) {
+                    solved.set_value(row_n, col_n, true);
                     zin_n = input[(row_n, col_n)];
                     if zin_n != nodata {
                         if zin_n < (zout + small_num) {
@@ -310,7 +289,7 @@ impl WhiteboxTool for FillDepressions {
                         });
                     } else {
                         // Interior nodata cells are still treated as nodata and are not filled.
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         num_solved_cells += 1;
                     }
                 }
@@ -320,7 +299,7 @@ impl WhiteboxTool for FillDepressions {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -557,19 +536,16 @@ impl WhiteboxTool for FillDepressions {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
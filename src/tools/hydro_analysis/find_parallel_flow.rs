@@ -133,43 +133,22 @@ impl WhiteboxTool for FindParallelFlow {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-d8_pntr" || vec[0].to_lowercase() == "--d8_pntr" {
-                if keyval {
-                    d8_file = vec[1].to_string();
-                } else {
-                    d8_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
-                if keyval {
-                    streams_file = vec[1].to_string();
-                } else {
-                    streams_file = args[i + 1].to_string();
-                }
-                if !streams_file.is_empty() {
-                    use_streams = true;
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+            if !streams_file.is_empty() {
+                use_streams = true;
             }
         }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,12 +178,12 @@ impl WhiteboxTool for FindParallelFlow {
             }
             true => {
                 if verbose {
-                    println!("Reading streams data...")
+                    report::message("Reading streams data...")
                 };
                 let r = Raster::new(&streams_file, "r")?;
                 if r.configs.rows != rows as usize || r.configs.columns != columns as usize {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                        "The input files must have the same number of rows and columns and spatial extent."));
+                                        "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
                 streams_nodata = r.configs.nodata;
                 r.get_data_as_array2d()
@@ -212,6 +191,7 @@ impl WhiteboxTool for FindParallelFlow {
         };
 
         let mut output = Raster::initialize_using_file(&output_file, &pntr);
+        let out_nodata = output.configs.nodata;
         let streams = Arc::new(streams);
 
         let num_procs = num_cpus::get() as isize;
@@ -231,7 +211,7 @@ impl WhiteboxTool for FindParallelFlow {
                 let inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
                 let outflowing_vals = [1f64, 2f64, 4f64, 8f64, 16f64, 32f64, 64f64, 128f64];
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = pntr[(row, col)];
                         stream_val = streams[(row, col)];
@@ -269,7 +249,7 @@ impl WhiteboxTool for FindParallelFlow {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -287,22 +267,19 @@ impl WhiteboxTool for FindParallelFlow {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
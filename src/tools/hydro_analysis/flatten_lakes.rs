@@ -133,41 +133,19 @@ impl WhiteboxTool for FlattenLakes {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-lakes" {
-                polygons_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--lakes"])? {
+            polygons_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -186,7 +164,7 @@ impl WhiteboxTool for FlattenLakes {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -323,7 +301,7 @@ impl WhiteboxTool for FlattenLakes {
             if verbose {
                 progress = (100.0_f64 * count / (polygons.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding lake elevations: {}%", progress);
+                    report::progress("Finding lake elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -388,11 +366,13 @@ impl WhiteboxTool for FlattenLakes {
                             progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Updating lake elevations ({} of {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    progress
+                                report::progress(
+                                    &format!(
+                                        "Updating lake elevations ({} of {})",
+                                        record_num + 1,
+                                        num_records
+                                    ),
+                                    progress,
                                 );
                                 old_progress = progress;
                             }
@@ -449,11 +429,13 @@ impl WhiteboxTool for FlattenLakes {
                             progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Updating lake elevations ({} of {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    progress
+                                report::progress(
+                                    &format!(
+                                        "Updating lake elevations ({} of {})",
+                                        record_num + 1,
+                                        num_records
+                                    ),
+                                    progress,
                                 );
                                 old_progress = progress;
                             }
@@ -472,20 +454,17 @@ impl WhiteboxTool for FlattenLakes {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -190,75 +190,41 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_dem" || vec[0].to_lowercase() == "--out_dem" {
-                if keyval {
-                    outdem_file = vec[1].to_string();
-                } else {
-                    outdem_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_pntr" || vec[0].to_lowercase() == "--out_pntr"
-            {
-                if keyval {
-                    pntr_file = vec[1].to_string();
-                } else {
-                    pntr_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_accum"
-                || vec[0].to_lowercase() == "--out_accum"
-            {
-                if keyval {
-                    accum_file = vec[1].to_string();
-                } else {
-                    accum_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_type" || vec[0].to_lowercase() == "--out_type"
-            {
-                if keyval {
-                    out_type = vec[1].to_lowercase();
-                } else {
-                    out_type = args[i + 1].to_lowercase();
-                }
-                if out_type.contains("specific") || out_type.contains("sca") {
-                    out_type = String::from("sca");
-                } else if out_type.contains("cells") {
-                    out_type = String::from("cells");
-                } else {
-                    out_type = String::from("ca");
-                }
-            } else if vec[0].to_lowercase() == "-log" || vec[0].to_lowercase() == "--log" {
-                log_transform = true;
-            } else if vec[0].to_lowercase() == "-clip" || vec[0].to_lowercase() == "--clip" {
-                clip_max = true;
-            } else if vec[0].to_lowercase() == "-esri_style"
-                || vec[0].to_lowercase() == "--esri_style"
-                || vec[0].to_lowercase() == "--esri_pntr"
-            {
-                esri_style = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_dem"])? {
+            outdem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_pntr"])? {
+            pntr_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_accum"])? {
+            accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_type"])? {
+            out_type = v.to_lowercase();
+            if out_type.contains("specific") || out_type.contains("sca") {
+                out_type = String::from("sca");
+            } else if out_type.contains("cells") {
+                out_type = String::from("cells");
+            } else {
+                out_type = String::from("ca");
             }
         }
+        if tool_args.get_flag(&["--log"])? {
+            log_transform = true;
+        }
+        if tool_args.get_flag(&["--clip"])? {
+            clip_max = true;
+        }
+        if tool_args.get_flag(&["--esri_style", "--esri_pntr"])? {
+            esri_style = true;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -280,7 +246,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -355,7 +321,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating aspect: {}%", progress);
+                    report::progress("Calculating aspect", progress);
                     old_progress = progress;
                 }
             }
@@ -367,6 +333,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         let small_num = 1.0 / elev_multiplier as f64;
 
         let mut output = Raster::initialize_using_file(&outdem_file, &input);
+        let out_nodata = output.configs.nodata;
         let background_val = (i32::min_value() + 1) as f64;
         output.reinitialize_values(background_val);
 
@@ -426,7 +393,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 zout_n = output[(row_n, col_n)];
                 if zout_n == background_val {
                     if zin_n == nodata {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         queue.push_back((row_n, col_n));
                     } else {
                         // see if it's the lowest of its neighbours
@@ -457,7 +424,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("progress: {}%", progress);
+                    report::progress("progress", progress);
                     old_progress = progress;
                 }
             }
@@ -519,10 +486,13 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                         }
                     } else {
                         // Interior nodata cells are still treated as nodata and are not filled.
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         num_solved_cells += 1;
                     }
-                } else if zout_n > zout && zout_n != nodata && aspect[(row_n, col_n)] != nodata {
+                } else if zout_n > zout
+                    && zout_n != out_nodata
+                    && aspect[(row_n, col_n)] != nodata
+                {
                     /* Check to see if the flow direction could be improved; if so, capture its flow.
                     This is the main logic for the flow direction calculation. Basically,
                     we link cells to the neighbour that has the closest flow direction to the
@@ -562,7 +532,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -581,11 +551,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         ));
 
         if verbose {
-            println!("Saving DEM data...")
+            report::message("Saving DEM data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -644,7 +614,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    report::progress("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -673,7 +643,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    report::progress("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -707,7 +677,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             for row in 0..rows {
                 for col in 0..columns {
                     if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     } else {
                         let dir = flow_dir[(row, col)];
                         if dir >= 0 {
@@ -725,7 +695,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        report::progress("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -734,7 +704,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             for row in 0..rows {
                 for col in 0..columns {
                     if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     } else {
                         let dir = flow_dir[(row, col)];
                         if dir >= 0 {
@@ -751,7 +721,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        report::progress("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -770,11 +740,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         pntr.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving flow pointer data...")
+            report::message("Saving flow pointer data...")
         };
         let _ = match pntr.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -791,19 +761,16 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving accumulation data...")
+            report::message("Saving accumulation data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -2,18 +2,41 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 16, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::basin_labelling::{d8_flow_directions, label_upslope_cells};
+use super::impoundment_storage_curve::trace_region_boundaries;
 use raster::*;
+use std::collections::HashMap;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
 use structures::Array2D;
 use tools::*;
-
+use vector::*;
+
+/// This tool identifies the hillslopes that drain to each link in a stream network,
+/// given a D8 flow pointer raster (`--d8_pntr`) and a streams raster (`--streams`). The
+/// catchment of each link is divided into the areas that drain to its left and right
+/// banks and, for first-order links, the area that drains to its channel head. Each
+/// hillslope is assigned a unique identifier in the output raster (`--output`) and
+/// stream cells are assigned zero.
+///
+/// Optionally, the hillslopes may also be output as a vector polygon file
+/// (`--out_vector`), which requires an input DEM (`--dem`). Each polygon carries the
+/// attributes needed to define hydrological response units: the hillslope identifier
+/// (`HS_ID`), the identifier of the stream link that it drains to (`LINK_ID`, using the
+/// numbering of the `StreamLinkIdentifier` tool), the bank side (`SIDE`; `left`, `right`,
+/// or `head`, looking downstream), the area (`AREA`), the mean slope gradient in degrees
+/// (`SLOPE`), and the mean flow distance to the stream (`FLOW_DIST`), measured along the
+/// D8 flow paths. The bank side of a hillslope is determined by the positions of the
+/// cells that drain directly into the stream relative to its flow direction.
+///
+/// # See Also
+/// `Subbasins`, `StreamLinkIdentifier`, `DownslopeDistanceToStream`
 pub struct Hillslopes {
     name: String,
     description: String,
@@ -68,6 +91,28 @@ impl Hillslopes {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input DEM File (optional)".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file, used for the hillslope slope attribute."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Hillslopes File (optional)".to_owned(),
+            flags: vec!["--out_vector".to_owned()],
+            description: "Output vector polygon file of hillslopes and their attributes."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -79,7 +124,8 @@ impl Hillslopes {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr='d8pntr.tif' --streams='streams.tif' -o='output.tif'", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr='d8pntr.tif' --streams='streams.tif' -o='output.tif'
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr='d8pntr.tif' --streams='streams.tif' -o='output.tif' --dem='dem.tif' --out_vector='hillslopes.shp'", short_exe, name).replace("*", &sep);
 
         Hillslopes {
             name: name,
@@ -129,6 +175,8 @@ impl WhiteboxTool for Hillslopes {
         let mut streams_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
+        let mut dem_file = String::new();
+        let mut vector_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -136,45 +184,34 @@ impl WhiteboxTool for Hillslopes {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-d8_pntr" || vec[0].to_lowercase() == "--d8_pntr" {
-                if keyval {
-                    d8_file = vec[1].to_string();
-                } else {
-                    d8_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
-                if keyval {
-                    streams_file = vec[1].to_string();
-                } else {
-                    streams_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-esri_pntr"
-                || vec[0].to_lowercase() == "--esri_pntr"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_vector"])? {
+            vector_file = v;
+        }
+        if !vector_file.is_empty() && dem_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "An input DEM (--dem) is required for the vector hillslopes output.",
+            ));
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -191,13 +228,32 @@ impl WhiteboxTool for Hillslopes {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !vector_file.is_empty() && !vector_file.contains(&sep) && !vector_file.contains("/") {
+            vector_file = format!("{}{}", working_directory, vector_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
         let streams = Raster::new(&streams_file, "r")?;
+        let dem = if !vector_file.is_empty() {
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+                ));
+            }
+            Some(dem)
+        } else {
+            None
+        };
 
         let start = Instant::now();
 
@@ -213,7 +269,7 @@ impl WhiteboxTool for Hillslopes {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -264,7 +320,7 @@ impl WhiteboxTool for Hillslopes {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
+                    report::progress("Processing streams", progress);
                     old_progress = progress;
                 }
             }
@@ -337,23 +393,26 @@ impl WhiteboxTool for Hillslopes {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
+                    report::progress("Processing streams", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        // Assign a new unique id to each channel head
+        // Assign a new unique id to each channel head, recording the id of its link
+        let mut head_links: HashMap<u64, f64> = HashMap::new();
         while !heads.is_empty() {
             let cell = heads.pop().unwrap();
             row = cell.0;
             col = cell.1;
             current_id += 1f64;
+            head_links.insert(current_id.to_bits(), pourpts[(row, col)]);
             pourpts[(row, col)] = current_id;
         }
 
         // Now perform the watershedding operation
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        let out_nodata = output.configs.nodata;
         output.configs.data_type = DataType::F32;
         output.configs.palette = "qual.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -364,7 +423,7 @@ impl WhiteboxTool for Hillslopes {
         for row in 0..rows {
             for col in 0..columns {
                 if pntr[(row, col)] == pntr_nodata {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
                 z = pourpts[(row, col)];
                 if z != nodata && z > 0.0 {
@@ -374,71 +433,16 @@ impl WhiteboxTool for Hillslopes {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
+                    report::progress("Watershedding (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        let mut flag: bool;
-        let (mut x, mut y): (isize, isize);
-        let mut outlet_id: f64;
-        for row in 0..rows {
-            for col in 0..columns {
-                if output[(row, col)] == low_value {
-                    flag = false;
-                    x = col;
-                    y = row;
-                    outlet_id = nodata;
-                    while !flag {
-                        dir = pntr[(y, x)] as usize;
-                        if dir > 0 {
-                            c = pntr_matches[dir];
-                            y += dy[c];
-                            x += dx[c];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
-                            if z != low_value {
-                                outlet_id = z;
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                    }
+        let flow_dir = d8_flow_directions(&pntr, esri_style)?;
+        label_upslope_cells(&flow_dir, &mut output, low_value, verbose);
 
-                    flag = false;
-                    x = col;
-                    y = row;
-                    output[(y, x)] = outlet_id;
-                    while !flag {
-                        // find its downslope neighbour
-                        dir = pntr[(y, x)] as usize;
-                        if dir > 0 {
-                            c = pntr_matches[dir];
-                            y += dy[c];
-                            x += dx[c];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                        output[(y, x)] = outlet_id;
-                    }
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        let (mut x, mut y): (isize, isize);
 
         // Replace all stream cells with 0's
         for row in 0..rows {
@@ -450,7 +454,7 @@ impl WhiteboxTool for Hillslopes {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
+                    report::progress("Watershedding (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -471,6 +475,7 @@ impl WhiteboxTool for Hillslopes {
         let (mut row2, mut col2): (isize, isize);
         current_id = 0f64;
         let mut old_id: f64;
+        let mut watershed_ids = vec![0f64]; // the catchment id of each hillslope
         for row in 0..rows {
             for col in 0..columns {
                 if visited[(row, col)] > 0
@@ -479,6 +484,7 @@ impl WhiteboxTool for Hillslopes {
                 {
                     current_id += 1f64;
                     old_id = output[(row, col)];
+                    watershed_ids.push(old_id);
                     stack.push((row, col));
                     while !stack.is_empty() {
                         let cell = stack.pop().unwrap();
@@ -513,12 +519,215 @@ impl WhiteboxTool for Hillslopes {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Grouping hillslopes: {}%", progress);
+                    report::progress("Grouping hillslopes", progress);
                     old_progress = progress;
                 }
             }
         }
 
+        let mut hillslopes = vec![];
+        if let Some(ref dem) = dem {
+            // find the cells of each hillslope
+            let num_hillslopes = current_id as usize;
+            let mut label: Array2D<i32> = Array2D::new(rows, columns, -1, -1)?;
+            let mut cells: Vec<Vec<(isize, isize)>> = vec![vec![]; num_hillslopes + 1];
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = output[(row, col)];
+                    if z > 0f64 && z != out_nodata && pntr[(row, col)] != pntr_nodata {
+                        label[(row, col)] = z as i32;
+                        cells[z as usize].push((row, col));
+                    }
+                }
+            }
+
+            // the downstream neighbour of a cell, if it has a valid flow direction
+            let receiver = |row: isize, col: isize| -> Option<usize> {
+                let dir = pntr[(row, col)];
+                if dir <= 0f64 || dir == pntr_nodata || dir > 128f64 {
+                    return None;
+                }
+                let c = pntr_matches[dir as usize];
+                if c == 999 || row + dy[c] < 0 || row + dy[c] >= rows {
+                    return None;
+                }
+                if col + dx[c] < 0 || col + dx[c] >= columns {
+                    return None;
+                }
+                Some(c)
+            };
+            let is_stream = |row: isize, col: isize| -> bool {
+                streams[(row, col)] > 0f64 && streams[(row, col)] != nodata
+            };
+
+            // calculate the flow distance of each cell to the stream, and find the cell at
+            // which its flow path enters the stream; unsolved cells have a distance of -1
+            // and the cells that do not drain to a stream have a distance of -2.
+            let res_x = pntr.configs.resolution_x;
+            let res_y = pntr.configs.resolution_y;
+            let diag_length = (res_x * res_x + res_y * res_y).sqrt();
+            let lengths = [
+                diag_length,
+                res_x,
+                diag_length,
+                res_y,
+                diag_length,
+                res_x,
+                diag_length,
+                res_y,
+            ];
+            let mut flow_dist: Array2D<f64> = Array2D::new(rows, columns, -1f64, -1f64)?;
+            let mut entry: Array2D<i32> = Array2D::new(rows, columns, -1, -1)?;
+            let mut path = vec![];
+            let mut dist: f64;
+            let mut entry_cell: i32;
+            for hs in 1..num_hillslopes + 1 {
+                for &(row, col) in &cells[hs] {
+                    if flow_dist[(row, col)] != -1f64 {
+                        continue;
+                    }
+                    path.clear();
+                    y = row;
+                    x = col;
+                    loop {
+                        if is_stream(y, x) {
+                            dist = 0f64;
+                            let &(py, px) = path.last().unwrap();
+                            entry_cell = (py * columns + px) as i32;
+                            break;
+                        }
+                        if flow_dist[(y, x)] != -1f64 {
+                            dist = flow_dist[(y, x)];
+                            entry_cell = entry[(y, x)];
+                            break;
+                        }
+                        path.push((y, x));
+                        match receiver(y, x) {
+                            Some(c) => {
+                                y += dy[c];
+                                x += dx[c];
+                            }
+                            None => {
+                                dist = -2f64;
+                                entry_cell = -1;
+                                break;
+                            }
+                        }
+                    }
+                    for &(py, px) in path.iter().rev() {
+                        if dist >= 0f64 {
+                            dist += lengths[receiver(py, px).unwrap()];
+                        }
+                        flow_dist[(py, px)] = dist;
+                        entry[(py, px)] = entry_cell;
+                    }
+                }
+            }
+
+            // tally the bank sides at which the cells' flow paths enter the stream, and
+            // calculate the attributes of each hillslope
+            let dem_nodata = dem.configs.nodata;
+            let mut slope: f64;
+            let mut n = [0f64; 8];
+            for hs in 1..num_hillslopes + 1 {
+                if cells[hs].is_empty() {
+                    continue;
+                }
+                let link_id = match head_links.get(&watershed_ids[hs].to_bits()) {
+                    Some(&id) => id,
+                    None => watershed_ids[hs],
+                };
+                let mut votes = [0usize; 3]; // left, right, head
+                let (mut slope_sum, mut num_slopes) = (0f64, 0usize);
+                let (mut dist_sum, mut num_dists) = (0f64, 0usize);
+                for &(row, col) in &cells[hs] {
+                    if entry[(row, col)] >= 0 {
+                        let row_e = entry[(row, col)] as isize / columns;
+                        let col_e = entry[(row, col)] as isize % columns;
+                        let c = receiver(row_e, col_e).unwrap();
+                        let (row_s, col_s) = (row_e + dy[c], col_e + dx[c]);
+                        if head_links.contains_key(&pourpts[(row_s, col_s)].to_bits()) {
+                            votes[2] += 1;
+                        } else {
+                            // the stream's flow direction at the entry point, which, at an
+                            // outlet, is taken from the upstream stream cell
+                            let mut flow_dir = receiver(row_s, col_s).map(|cs| (dx[cs], dy[cs]));
+                            if flow_dir.is_none() {
+                                for i in 0..8 {
+                                    let (row_u, col_u) = (row_s + dy[i], col_s + dx[i]);
+                                    if is_stream(row_u, col_u)
+                                        && receiver(row_u, col_u) == Some((i + 4) % 8)
+                                    {
+                                        flow_dir = Some((-dx[i], -dy[i]));
+                                        break;
+                                    }
+                                }
+                            }
+                            if let Some((fx, fy)) = flow_dir {
+                                // the cross product of the stream's flow direction and the
+                                // direction from the stream to the entry cell (y is north)
+                                let cross = fx as f64 * -(row_e - row_s) as f64
+                                    - -fy as f64 * (col_e - col_s) as f64;
+                                if cross > 0f64 {
+                                    votes[0] += 1;
+                                } else if cross < 0f64 {
+                                    votes[1] += 1;
+                                }
+                            }
+                        }
+                    }
+                    if flow_dist[(row, col)] >= 0f64 {
+                        dist_sum += flow_dist[(row, col)];
+                        num_dists += 1;
+                    }
+                    z = dem[(row, col)];
+                    if z != dem_nodata {
+                        for i in 0..8 {
+                            n[i] = dem[(row + dy[i], col + dx[i])];
+                            if n[i] == dem_nodata {
+                                n[i] = z;
+                            }
+                        }
+                        // Horn's method; the neighbours are NE, E, SE, S, SW, W, NW, N
+                        let fx = (n[0] + 2f64 * n[1] + n[2] - n[6] - 2f64 * n[5] - n[4])
+                            / (8f64 * res_x);
+                        let fy = (n[6] + 2f64 * n[7] + n[0] - n[4] - 2f64 * n[3] - n[2])
+                            / (8f64 * res_y);
+                        slope = (fx * fx + fy * fy).sqrt().atan().to_degrees();
+                        slope_sum += slope;
+                        num_slopes += 1;
+                    }
+                }
+                let side = if votes[0] + votes[1] + votes[2] == 0 {
+                    "none"
+                } else if votes[2] >= votes[0] && votes[2] >= votes[1] {
+                    "head"
+                } else if votes[0] >= votes[1] {
+                    "left"
+                } else {
+                    "right"
+                };
+                let rings = trace_region_boundaries(&cells[hs], &label, hs as i32, &pntr);
+                hillslopes.push((
+                    hs,
+                    link_id,
+                    side,
+                    cells[hs].len() as f64 * res_x * res_y,
+                    if num_slopes > 0 {
+                        slope_sum / num_slopes as f64
+                    } else {
+                        0f64
+                    },
+                    if num_dists > 0 {
+                        dist_sum / num_dists as f64
+                    } else {
+                        0f64
+                    },
+                    rings,
+                ));
+            }
+        }
+
         let elapsed_time = get_formatted_elapsed_time(start);
         output.add_metadata_entry(format!(
             "Created by whitebox_tools\' {} tool",
@@ -529,19 +738,78 @@ impl WhiteboxTool for Hillslopes {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
+
+        if !vector_file.is_empty() {
+            let mut vector = Shapefile::new(&vector_file, ShapeType::Polygon)?;
+            vector.projection = pntr.configs.coordinate_ref_system_wkt.clone();
+            vector
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+            vector
+                .attributes
+                .add_field(&AttributeField::new("HS_ID", FieldDataType::Int, 7u8, 0u8));
+            vector
+                .attributes
+                .add_field(&AttributeField::new("LINK_ID", FieldDataType::Int, 7u8, 0u8));
+            vector
+                .attributes
+                .add_field(&AttributeField::new("SIDE", FieldDataType::Text, 5u8, 0u8));
+            vector.attributes.add_field(&AttributeField::new(
+                "AREA",
+                FieldDataType::Real,
+                14u8,
+                2u8,
+            ));
+            vector.attributes.add_field(&AttributeField::new(
+                "SLOPE",
+                FieldDataType::Real,
+                10u8,
+                4u8,
+            ));
+            vector.attributes.add_field(&AttributeField::new(
+                "FLOW_DIST",
+                FieldDataType::Real,
+                12u8,
+                2u8,
+            ));
+            for (fid, hillslope) in hillslopes.into_iter().enumerate() {
+                let (hs, link_id, side, area, mean_slope, mean_dist, rings) = hillslope;
+                let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+                for ring in &rings {
+                    sfg.add_part(ring);
+                }
+                vector.add_record(sfg);
+                vector.attributes.add_record(
+                    vec![
+                        FieldData::Int(fid as i32 + 1),
+                        FieldData::Int(hs as i32),
+                        FieldData::Int(link_id as i32),
+                        FieldData::Text(side.to_string()),
+                        FieldData::Real(area),
+                        FieldData::Real(mean_slope),
+                        FieldData::Real(mean_dist),
+                    ],
+                    false,
+                );
+            }
+            let _ = match vector.write() {
+                Ok(_) => if verbose {
+                    report::message("Output vector file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -154,57 +154,32 @@ impl WhiteboxTool for ImpoundmentIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_type" {
-                let val = if keyval {
-                    vec[1].to_lowercase()
-                } else {
-                    args[i + 1].to_lowercase()
-                };
-                out_type = if val.contains("v") {
-                    1
-                } else if val.contains("depth") {
-                    2
-                } else {
-                    0 // area
-                };
-            } else if flag_val == "-damlength" {
-                dam_length = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_type"])? {
+            let val = v.to_lowercase();
+            out_type = if val.contains("v") {
+                1
+            } else if val.contains("depth") {
+                2
+            } else {
+                0 // area
+            };
+        }
+        if let Some(v) = tool_args.get_f64(&["--damlength"])? {
+            dam_length = v;
         }
 
         let mut progress: usize;
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -234,7 +209,7 @@ impl WhiteboxTool for ImpoundmentIndex {
         */
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         // let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -375,7 +350,7 @@ impl WhiteboxTool for ImpoundmentIndex {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating dam heights: {}%", progress);
+                    report::progress("Calculating dam heights", progress);
                     old_progress = progress;
                 }
             }
@@ -474,7 +449,7 @@ impl WhiteboxTool for ImpoundmentIndex {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating flow directions: {}%", progress);
+                    report::progress("Calculating flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -528,7 +503,7 @@ impl WhiteboxTool for ImpoundmentIndex {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating flow directions: {}%", progress);
+                    report::progress("Calculating flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -608,7 +583,7 @@ impl WhiteboxTool for ImpoundmentIndex {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating index: {}%", progress);
+                    report::progress("Calculating index", progress);
                     old_progress = progress;
                 }
             }
@@ -636,7 +611,7 @@ impl WhiteboxTool for ImpoundmentIndex {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Outputting dam heights: {}%", progress);
+                    report::progress("Outputting dam heights", progress);
                     old_progress = progress;
                 }
             }
@@ -661,11 +636,11 @@ impl WhiteboxTool for ImpoundmentIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving index data...")
+            report::message("Saving index data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -685,20 +660,17 @@ impl WhiteboxTool for ImpoundmentIndex {
         output_hgt.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving dam height data...")
+            report::message("Saving dam height data...")
         };
         let _ = match output_hgt.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,679 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::is_clockwise_order;
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{Array2D, Point2D};
+use tools::*;
+use vector::*;
+
+/// This tool models the reservoirs that would be impounded by dams placed at a set of
+/// user-specified locations (`--dams`) within a digital elevation model (DEM). Whereas the
+/// `ImpoundmentIndex` tool estimates the impoundment size for a dam of a fixed length placed
+/// at every grid cell, this tool characterizes specific dam sites in detail. The dam height
+/// at each site is either read from a numeric field in the dams attribute table
+/// (`--height_field`) or set to a constant value (`--dam_height`).
+///
+/// The crest elevation of each dam is the DEM elevation at the dam site plus the dam height.
+/// The inundated area is made up of the grid cells that drain to the dam site, based on the
+/// input D8 flow pointer raster (`--d8_pntr`), and that are lower than the crest elevation.
+/// The DEM should be hydrologically conditioned (e.g. using `BreachDepressions` or
+/// `FillDepressions`) and the dam sites should be located on the flow paths of interest,
+/// e.g. by using `SnapPourPoints` with a flow accumulation raster.
+///
+/// The tool outputs a vector polygon of the inundated area for each dam (`--output`), with
+/// attributes for the dam height, base and crest elevations, and the flooded area, storage
+/// volume, and mean depth of the full reservoir. Optionally, the stage-storage-area curve of
+/// each reservoir can be written to a CSV table (`--out_table`), with one row per stage
+/// increment (`--interval`) from the dam base to the crest, and the water depth of the full
+/// reservoirs can be output as a raster (`--out_depth`). Where reservoirs overlap, the
+/// depth raster contains the greater of the depths.
+///
+/// # See Also
+/// `ImpoundmentIndex`, `Watershed`, `SnapPourPoints`, `DepthInSink`
+pub struct ImpoundmentStorageCurve {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ImpoundmentStorageCurve {
+    pub fn new() -> ImpoundmentStorageCurve {
+        // public constructor
+        let name = "ImpoundmentStorageCurve".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description = "Calculates the inundated area, storage volume, and stage-storage-area curve of reservoirs impounded by dams at specified sites.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Dam Sites File".to_owned(),
+            flags: vec!["--dams".to_owned()],
+            description: "Input vector points file of dam locations.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Dam Height Field Name".to_owned(),
+            flags: vec!["--height_field".to_owned()],
+            description: "Optional numeric field in the dams attribute table containing dam heights.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--dams".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Dam Height".to_owned(),
+            flags: vec!["--dam_height".to_owned()],
+            description: "Dam height (in elevation units), used when no height field is specified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("10.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Reservoirs File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector polygon file of inundated areas.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Stage-Storage Table File".to_owned(),
+            flags: vec!["--out_table".to_owned()],
+            description: "Optional output CSV file of stage-storage-area curves.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Stage Interval".to_owned(),
+            flags: vec!["--interval".to_owned()],
+            description: "Stage increment used in the stage-storage-area curves.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Depth File".to_owned(),
+            flags: vec!["--out_depth".to_owned()],
+            description: "Optional output raster file of reservoir water depths.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --d8_pntr=D8.tif --dams=dams.shp --dam_height=15.0 -o=reservoirs.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --d8_pntr=D8.tif --dams=dams.shp --height_field=HEIGHT -o=reservoirs.shp --out_table=curves.csv --interval=0.5 --out_depth=depth.tif", short_exe, name).replace("*", &sep);
+
+        ImpoundmentStorageCurve {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ImpoundmentStorageCurve {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut dem_file = String::new();
+        let mut d8_file = String::new();
+        let mut dams_file = String::new();
+        let mut height_field = String::new();
+        let mut dam_height = 10f64;
+        let mut output_file = String::new();
+        let mut table_file = String::new();
+        let mut interval = 1f64;
+        let mut depth_file = String::new();
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dams"])? {
+            dams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--height_field"])? {
+            height_field = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--dam_height"])? {
+            dam_height = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_table"])? {
+            table_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--interval"])? {
+            interval = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_depth"])? {
+            depth_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !dams_file.contains(&sep) && !dams_file.contains("/") {
+            dams_file = format!("{}{}", working_directory, dams_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !table_file.is_empty() && !table_file.contains(&sep) && !table_file.contains("/") {
+            table_file = format!("{}{}", working_directory, table_file);
+        }
+        if !depth_file.is_empty() && !depth_file.contains(&sep) && !depth_file.contains("/") {
+            depth_file = format!("{}{}", working_directory, depth_file);
+        }
+        if interval <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The stage interval must be greater than zero.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let dem = Raster::new(&dem_file, "r")?;
+        let pntr = Raster::new(&d8_file, "r")?;
+        let dams = Shapefile::read(&dams_file)?;
+
+        let start = Instant::now();
+
+        let rows = dem.configs.rows as isize;
+        let columns = dem.configs.columns as isize;
+        let nodata = dem.configs.nodata;
+        let cell_area = dem.configs.resolution_x * dem.configs.resolution_y;
+
+        // make sure the input files have the same size
+        if pntr.configs.rows != dem.configs.rows || pntr.configs.columns != dem.configs.columns {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        // make sure the input vector file is of points type
+        if dams.header.shape_type.base_shape_type() != ShapeType::Point {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input dams vector data must be of point base shape type.",
+            ));
+        }
+
+        if !height_field.is_empty() && dams.attributes.get_field_num(&height_field).is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The field {} does not exist in the attribute table.",
+                    height_field
+                ),
+            ));
+        }
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
+        if esri_style {
+            inflowing_vals = [8f64, 16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64];
+        }
+
+        let mut output = Shapefile::new(&output_file, ShapeType::Polygon)?;
+        output.projection = dem.configs.coordinate_ref_system_wkt.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("DAM_ID", FieldDataType::Int, 7u8, 0u8));
+        output.attributes.add_field(&AttributeField::new(
+            "HEIGHT",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "BASE_ELEV",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "CREST_ELEV",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "AREA",
+            FieldDataType::Real,
+            16u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "VOLUME",
+            FieldDataType::Real,
+            16u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "MEAN_DEPTH",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+
+        let mut table = String::from("DAM_ID,STAGE,ELEV,AREA,VOLUME\n");
+
+        let mut depth: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        // cells are labelled with the ID of the dam currently being processed
+        let mut label: Array2D<i32> = Array2D::new(rows, columns, 0, -1)?;
+        let mut num_skipped = 0;
+        let mut fid = 1i32;
+        let (mut z, mut z_n, mut base_z, mut crest_z, mut h): (f64, f64, f64, f64, f64);
+        let (mut row_n, mut col_n): (isize, isize);
+        for record_num in 0..dams.num_records {
+            let dam_id = record_num as i32 + 1;
+            let record = dams.get_record(record_num);
+            let row = dem.get_row_from_y(record.points[0].y);
+            let col = dem.get_column_from_x(record.points[0].x);
+            base_z = dem.get_value(row, col);
+            h = if !height_field.is_empty() {
+                match dams.attributes.get_value(record_num, &height_field) {
+                    FieldData::Int(val) => val as f64,
+                    FieldData::Real(val) => val,
+                    _ => -1f64, // likely due to null value for record.
+                }
+            } else {
+                dam_height
+            };
+            if base_z == nodata || h <= 0f64 {
+                num_skipped += 1;
+                continue;
+            }
+            crest_z = base_z + h;
+
+            // Find the upslope cells that are lower than the crest.
+            let mut cells = vec![(row, col)];
+            let mut elevations = vec![base_z];
+            label.set_value(row, col, dam_id);
+            let mut i = 0;
+            while i < cells.len() {
+                let (r, c) = cells[i];
+                for n in 0..8 {
+                    row_n = r + d_y[n];
+                    col_n = c + d_x[n];
+                    if pntr.get_value(row_n, col_n) == inflowing_vals[n]
+                        && label.get_value(row_n, col_n) != dam_id
+                    {
+                        z_n = dem.get_value(row_n, col_n);
+                        if z_n != nodata && z_n < crest_z {
+                            label.set_value(row_n, col_n, dam_id);
+                            cells.push((row_n, col_n));
+                            elevations.push(z_n);
+                        }
+                    }
+                }
+                i += 1;
+            }
+
+            let num_cells = cells.len() as f64;
+            let mut volume = 0f64;
+            for &(r, c) in &cells {
+                z = crest_z - dem.get_value(r, c);
+                volume += z * cell_area;
+                if z > depth.get_value(r, c) || depth.get_value(r, c) == nodata {
+                    depth.set_value(r, c, z);
+                }
+            }
+
+            // Stage-storage-area curve
+            if !table_file.is_empty() {
+                elevations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let mut j = 0;
+                let mut sum_z = 0f64;
+                let mut stage = 0f64;
+                loop {
+                    stage += interval;
+                    if stage > h {
+                        stage = h;
+                    }
+                    let level = base_z + stage;
+                    while j < elevations.len() && elevations[j] < level {
+                        sum_z += elevations[j];
+                        j += 1;
+                    }
+                    table.push_str(&format!(
+                        "{},{},{},{},{}\n",
+                        dam_id,
+                        stage,
+                        level,
+                        j as f64 * cell_area,
+                        (j as f64 * level - sum_z) * cell_area
+                    ));
+                    if stage >= h {
+                        break;
+                    }
+                }
+            }
+
+            let rings = trace_region_boundaries(&cells, &label, dam_id, &dem);
+            let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+            for ring in &rings {
+                sfg.add_part(ring);
+            }
+            output.add_record(sfg);
+            output.attributes.add_record(
+                vec![
+                    FieldData::Int(fid),
+                    FieldData::Int(dam_id),
+                    FieldData::Real(h),
+                    FieldData::Real(base_z),
+                    FieldData::Real(crest_z),
+                    FieldData::Real(num_cells * cell_area),
+                    FieldData::Real(volume),
+                    FieldData::Real(volume / (num_cells * cell_area)),
+                ],
+                false,
+            );
+            fid += 1;
+
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / dams.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if num_skipped > 0 {
+            report::warning(&format!(
+                "{} dam sites were located outside of the DEM or had invalid heights and were skipped.",
+                num_skipped
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if !table_file.is_empty() {
+            let f = File::create(table_file.clone())?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all(table.as_bytes())?;
+            let _ = writer.flush();
+            if verbose {
+                report::message("Stage-storage table written")
+            };
+        }
+
+        if !depth_file.is_empty() {
+            let mut output_depth = Raster::initialize_using_file(&depth_file, &dem);
+            output_depth.configs.data_type = DataType::F32;
+            let out_nodata = output_depth.configs.nodata;
+            for row in 0..rows {
+                let mut data = depth.get_row_data(row);
+                for z in data.iter_mut() {
+                    if *z == nodata {
+                        *z = out_nodata;
+                    }
+                }
+                output_depth.set_row_data(row, data);
+            }
+            output_depth.configs.palette = "blueyellow.plt".to_string();
+            output_depth.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_depth.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output_depth.add_metadata_entry(format!("Input DEM file: {}", dem_file));
+            output_depth.add_metadata_entry(format!("Input d8 pointer file: {}", d8_file));
+            output_depth.add_metadata_entry(format!("Input dams file: {}", dams_file));
+            output_depth
+                .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+            let _ = match output_depth.write() {
+                Ok(_) => if verbose {
+                    report::message("Depth file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Traces the outlines of a region of grid cells, i.e. the cells in `label` with the value
+/// `id`, along the cell edges. Hulls are returned in clockwise order, followed by holes
+/// in counter-clockwise order.
+pub fn trace_region_boundaries(
+    cells: &[(isize, isize)],
+    label: &Array2D<i32>,
+    id: i32,
+    raster: &Raster,
+) -> Vec<Vec<Point2D>> {
+    // Cell corners are indexed by (row, column), with corner (r, c) located at the
+    // north-west corner of cell (r, c). Edges are directed so that the region is on
+    // their right-hand side; directions are east, south, west, and north.
+    let d_row = [0isize, 1, 0, -1];
+    let d_col = [1isize, 0, -1, 0];
+    let mut edges: HashMap<(isize, isize), [bool; 4]> = HashMap::new();
+    for &(row, col) in cells {
+        if label.get_value(row - 1, col) != id {
+            edges.entry((row, col)).or_insert([false; 4])[0] = true;
+        }
+        if label.get_value(row, col + 1) != id {
+            edges.entry((row, col + 1)).or_insert([false; 4])[1] = true;
+        }
+        if label.get_value(row + 1, col) != id {
+            edges.entry((row + 1, col + 1)).or_insert([false; 4])[2] = true;
+        }
+        if label.get_value(row, col - 1) != id {
+            edges.entry((row + 1, col)).or_insert([false; 4])[3] = true;
+        }
+    }
+
+    let west = raster.configs.west;
+    let north = raster.configs.north;
+    let res_x = raster.configs.resolution_x;
+    let res_y = raster.configs.resolution_y;
+    let mut hulls = vec![];
+    let mut holes = vec![];
+    let mut starts: Vec<(isize, isize)> = edges.keys().cloned().collect();
+    starts.sort();
+    for start in starts {
+        loop {
+            let mut dir = match edges.get(&start) {
+                Some(e) => match e.iter().position(|&v| v) {
+                    Some(d) => d,
+                    None => break,
+                },
+                None => break,
+            };
+            let mut corner = start;
+            let mut ring = vec![];
+            let mut prev_dir = 4;
+            loop {
+                if dir != prev_dir {
+                    // only corners where the direction changes are vertices
+                    ring.push(Point2D::new(
+                        west + corner.1 as f64 * res_x,
+                        north - corner.0 as f64 * res_y,
+                    ));
+                }
+                edges.get_mut(&corner).unwrap()[dir] = false;
+                corner = (corner.0 + d_row[dir], corner.1 + d_col[dir]);
+                prev_dir = dir;
+                if corner == start {
+                    break;
+                }
+                // Where the region touches itself diagonally, there are two outgoing
+                // edges; turning right keeps the ring from crossing itself.
+                let e = edges[&corner];
+                dir = if e[(dir + 1) % 4] {
+                    (dir + 1) % 4
+                } else if e[dir] {
+                    dir
+                } else {
+                    (dir + 3) % 4
+                };
+            }
+            let p = ring[0];
+            ring.push(p);
+            if is_clockwise_order(&ring) {
+                hulls.push(ring);
+            } else {
+                holes.push(ring);
+            }
+        }
+    }
+    hulls.append(&mut holes);
+    hulls
+}
@@ -2,12 +2,15 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: December 3, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::basin_labelling::label_upslope_cells;
+use super::impoundment_storage_curve::trace_region_boundaries;
 use num_cpus;
 use raster::*;
+use std::collections::HashSet;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
@@ -17,7 +20,49 @@ use std::sync::Arc;
 use std::thread;
 use structures::Array2D;
 use tools::*;
-
+use vector::*;
+
+/// This tool divides a landscape into a group of nearly equal-sized watersheds, known as
+/// *isobasins*. The user must specify the target size of the isobasins (`--size`), in grid
+/// cells. The input DEM (`--dem`) should be hydrologically corrected to remove all spurious
+/// depressions and flat areas, e.g. using the `BreachDepressions` or `FillDepressions`
+/// tools. The output is a raster (`--output`) in which each basin is assigned a unique
+/// identifier.
+///
+/// Basin outlets are identified by accumulating the contributing areas of the cells in
+/// downstream order. A basin is closed at the first cell at which its area reaches the
+/// target size. Where tributaries join, the area can jump well beyond the target. The
+/// optional `--tolerance` parameter gives the allowable deviation from the target size,
+/// as a proportion of it (e.g. 0.1 for 10%). When the area at a confluence exceeds the
+/// target by more than the tolerance, the tributaries that are closest to the target
+/// size are made basins of their own. A tributary is only split off when its area
+/// deviates from the target less than the confluence's does. This continues until the
+/// remaining area is within the tolerance. Basins that drain to the DEM edges, or that
+/// are forced by seeds, may still be smaller than the target.
+///
+/// Optionally, outlets may be specified using an input vector points file (`--seeds`).
+/// A basin outlet is always placed at the cell containing each seed point, and the areas
+/// upstream of a seed are divided into isobasins as usual. Seeds that fall outside of the
+/// DEM or on NoData cells are ignored.
+///
+/// By default, each cell that borders a NoData gap in the DEM and that has no downslope
+/// neighbour becomes a basin outlet. Along the shore of a removed lake, this can result in
+/// many small basins. When the `--route_gaps` flag is specified, internal NoData gaps
+/// (those not connected to the grid edges) are treated as conveyances. These cells drain
+/// through the gap to its outlet, which is the gap's lowest bordering cell. The areas on
+/// either side of a gap that drain through it are therefore kept together in one basin.
+/// The gap cells themselves remain NoData in the output.
+///
+/// The basins may also be output as a vector polygon file (`--out_vector`). Each polygon
+/// has the following attributes:
+///
+/// - `BASIN_ID`: the basin identifier
+/// - `NUM_CELLS`: the number of grid cells
+/// - `AREA`: the area, in map units
+/// - `AREA_DEV`: the percent deviation of the basin's size from the target size
+///
+/// # See Also
+/// `Basins`, `Watershed`, `Subbasins`, `StrahlerOrderBasins`
 pub struct Isobasins {
     name: String,
     description: String,
@@ -63,6 +108,47 @@ impl Isobasins {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Basin Size Tolerance (proportion)".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Optional allowable deviation of basin sizes from the target size, as a proportion of the target size (e.g. 0.1).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Seed Outlets File (optional)".to_owned(),
+            flags: vec!["--seeds".to_owned()],
+            description: "Optional input vector points file of outlets at which basins are always created.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Route flow through internal NoData gaps?".to_owned(),
+            flags: vec!["--route_gaps".to_owned()],
+            description: "Optional flag to route flow through internal NoData gaps, e.g. removed lakes, to their outlets.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Basins File (optional)".to_owned(),
+            flags: vec!["--out_vector".to_owned()],
+            description: "Optional output vector polygon file of basins and their areas."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -74,10 +160,8 @@ impl Isobasins {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --size=1000",
-            short_exe, name
-        ).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --size=1000
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --size=1000 --tolerance=0.1 --seeds=outlets.shp --route_gaps --out_vector=isobasins.shp", short_exe, name).replace("*", &sep);
 
         Isobasins {
             name: name,
@@ -126,6 +210,10 @@ impl WhiteboxTool for Isobasins {
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut target_size = -1;
+        let mut tolerance = 0f64;
+        let mut seeds_file = String::new();
+        let mut route_gaps = false;
+        let mut vector_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -133,43 +221,31 @@ impl WhiteboxTool for Isobasins {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-size" || vec[0].to_lowercase() == "--size" {
-                if keyval {
-                    target_size = vec[1].to_string().parse::<isize>().unwrap();
-                } else {
-                    target_size = args[i + 1].to_string().parse::<isize>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--size"])? {
+            target_size = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--tolerance"])? {
+            tolerance = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--seeds"])? {
+            seeds_file = v;
+        }
+        if tool_args.get_flag(&["--route_gaps"])? {
+            route_gaps = true;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_vector"])? {
+            vector_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if target_size == -1 {
@@ -178,6 +254,18 @@ impl WhiteboxTool for Isobasins {
                 "Target basin size (--size) not specified.",
             ));
         }
+        if target_size < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The target basin size (--size) must be at least one grid cell.",
+            ));
+        }
+        if tolerance < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The basin size tolerance (--tolerance) must not be negative.",
+            ));
+        }
 
         let target_fa = target_size as usize;
 
@@ -192,13 +280,33 @@ impl WhiteboxTool for Isobasins {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !seeds_file.is_empty() && !seeds_file.contains(&sep) && !seeds_file.contains("/") {
+            seeds_file = format!("{}{}", working_directory, seeds_file);
+        }
+        if !vector_file.is_empty() && !vector_file.contains(&sep) && !vector_file.contains("/") {
+            vector_file = format!("{}{}", working_directory, vector_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
+        let seeds = if !seeds_file.is_empty() {
+            let seeds = Shapefile::read(&seeds_file)?;
+            // make sure the input vector file is of points type
+            if seeds.header.shape_type.base_shape_type() != ShapeType::Point {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input seeds file must be of point base shape type.",
+                ));
+            }
+            Some(seeds)
+        } else {
+            None
+        };
+
         //////////////////////////////////
         // Calculate the flow direction //
         //////////////////////////////////
@@ -283,21 +391,121 @@ impl WhiteboxTool for Isobasins {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    report::progress("Flow directions", progress);
                     old_progress = progress;
                 }
             }
         }
 
+        // Internal NoData gaps, i.e. those that are not connected to the grid edges, are
+        // treated as conveyances that drain to their outlets, the lowest bordering cells.
+        // The gap cells are given flow directions leading to the outlet, and the bordering
+        // cells that do not have a downslope neighbour, other than the outlets, drain
+        // into the gap. An outlet's own flowpath descends below each of the gap's
+        // bordering cells, so that no cycles are created.
+        let mut gap: Array2D<u8> = Array2D::new(rows, columns, 0, 0)?;
+        if route_gaps {
+            // label the NoData gaps; the gaps that are connected to the grid edges, and
+            // the cells beyond the edges, are labelled 0.
+            let mut gap_id: Array2D<i32> = Array2D::new(rows, columns, -1, 0)?;
+            let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+            let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let mut stack = vec![];
+            let (mut row_n, mut col_n): (isize, isize);
+            let mut z_n: f64;
+            for row in 0..rows {
+                for col in 0..columns {
+                    if input[(row, col)] == nodata
+                        && (row == 0 || col == 0 || row == rows - 1 || col == columns - 1)
+                    {
+                        gap_id[(row, col)] = 0;
+                        stack.push((row, col));
+                    }
+                }
+            }
+            while let Some((r, c)) = stack.pop() {
+                for i in 0..8 {
+                    row_n = r + dy[i];
+                    col_n = c + dx[i];
+                    if input[(row_n, col_n)] == nodata && gap_id[(row_n, col_n)] == -1 {
+                        gap_id[(row_n, col_n)] = 0;
+                        stack.push((row_n, col_n));
+                    }
+                }
+            }
+            let mut num_gaps = 0;
+            let mut outlets = HashSet::new();
+            let mut shore_cells = vec![];
+            for row in 0..rows {
+                for col in 0..columns {
+                    if input[(row, col)] != nodata || gap_id[(row, col)] != -1 {
+                        continue;
+                    }
+                    num_gaps += 1;
+                    let id = num_gaps;
+                    let mut outlet = (-1, -1);
+                    let mut outlet_z = f64::INFINITY;
+                    gap_id[(row, col)] = id;
+                    stack.push((row, col));
+                    while let Some((r, c)) = stack.pop() {
+                        for i in 0..8 {
+                            row_n = r + dy[i];
+                            col_n = c + dx[i];
+                            z_n = input[(row_n, col_n)];
+                            if z_n == nodata {
+                                if gap_id[(row_n, col_n)] == -1 {
+                                    gap_id[(row_n, col_n)] = id;
+                                    stack.push((row_n, col_n));
+                                }
+                            } else {
+                                if z_n < outlet_z {
+                                    outlet_z = z_n;
+                                    outlet = (row_n, col_n);
+                                }
+                                if flow_dir[(row_n, col_n)] == -1i8 {
+                                    shore_cells.push((row_n, col_n, ((i + 4) % 8) as i8));
+                                }
+                            }
+                        }
+                    }
+
+                    // direct the gap cells towards the outlet
+                    outlets.insert(outlet);
+                    stack.push(outlet);
+                    while let Some((r, c)) = stack.pop() {
+                        for i in 0..8 {
+                            row_n = r + dy[i];
+                            col_n = c + dx[i];
+                            if gap_id[(row_n, col_n)] == id && gap[(row_n, col_n)] == 0 {
+                                gap[(row_n, col_n)] = 1;
+                                flow_dir[(row_n, col_n)] = ((i + 4) % 8) as i8;
+                                stack.push((row_n, col_n));
+                            }
+                        }
+                    }
+                }
+            }
+            for (row, col, dir) in shore_cells {
+                if flow_dir[(row, col)] == -1i8 && !outlets.contains(&(row, col)) {
+                    flow_dir[(row, col)] = dir;
+                }
+            }
+            if verbose {
+                report::metadata("Number of internal NoData gaps", &num_gaps.to_string());
+            }
+        }
+
         /////////////////////////////////////////////
         // Calculate the number of inflowing cells //
         /////////////////////////////////////////////
         let flow_dir = Arc::new(flow_dir);
+        let gap = Arc::new(gap);
         let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let input = input.clone();
             let flow_dir = flow_dir.clone();
+            let gap = gap.clone();
             let tx = tx.clone();
             thread::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -309,7 +517,7 @@ impl WhiteboxTool for Isobasins {
                     let mut data: Vec<i8> = vec![-1i8; columns as usize];
                     for col in 0..columns {
                         z = input[(row, col)];
-                        if z != nodata {
+                        if z != nodata || gap[(row, col)] == 1 {
                             count = 0i8;
                             for i in 0..8 {
                                 if flow_dir[(row + dy[i], col + dx[i])] == inflowing_vals[i] {
@@ -342,7 +550,7 @@ impl WhiteboxTool for Isobasins {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    report::progress("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -351,10 +559,44 @@ impl WhiteboxTool for Isobasins {
         /////////////////////////////////
         // Find and ID the pour points //
         /////////////////////////////////
+        let mut is_seed: Array2D<u8> = Array2D::new(rows, columns, 0, 0)?;
+        if let Some(ref seeds) = seeds {
+            let mut num_ignored = 0;
+            for record_num in 0..seeds.num_records {
+                let record = seeds.get_record(record_num);
+                for p in &record.points {
+                    let row = input.get_row_from_y(p.y);
+                    let col = input.get_column_from_x(p.x);
+                    if input.get_value(row, col) != nodata {
+                        is_seed[(row, col)] = 1;
+                    } else {
+                        num_ignored += 1;
+                    }
+                }
+            }
+            if verbose && num_ignored > 0 {
+                report::warning(&format!(
+                    "{} seed points outside of the DEM or on NoData cells were ignored.",
+                    num_ignored
+                ));
+            }
+        }
+
+        // The accumulated area of each cell, excluding the areas that have been assigned to
+        // basins, is the area that it passes to its downslope neighbour. Gap cells have no
+        // area of their own.
         let mut accum: Array2D<usize> = Array2D::new(rows, columns, 1, 0)?;
+        for row in 0..rows {
+            for col in 0..columns {
+                if gap[(row, col)] == 1 {
+                    accum[(row, col)] = 0;
+                }
+            }
+        }
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let mut outlet_id = 1f64;
-        // output.reinitialize_values(1.0);
+        let max_fa = (target_fa as f64 * (1f64 + tolerance)).floor() as usize;
         let dx = [1, 1, 1, 0, -1, -1, -1, 0];
         let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
         let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
@@ -362,48 +604,50 @@ impl WhiteboxTool for Isobasins {
         let (mut row_n, mut col_n): (isize, isize);
         let mut dir: i8;
         let mut fa: usize;
-        let mut inla_index: usize;
-        let mut inla_mag: usize;
+        let mut is_outlet: bool;
+        let mut tributaries = vec![];
+        let deviation = |a: usize| (a as isize - target_fa as isize).abs();
         while !stack.is_empty() {
             let cell = stack.pop().unwrap();
             row = cell.0;
             col = cell.1;
             fa = accum.get_value(row, col);
-            if fa >= target_fa {
-                // find the index of the inflowing neighbour with the largest accumulation
-                inla_mag = 0;
-                inla_index = 8;
-                for i in 0..8 {
-                    row_n = row + dy[i];
-                    col_n = col + dx[i];
-                    if flow_dir.get_value(row_n, col_n) == inflowing_vals[i] {
-                        if accum.get_value(row_n, col_n) > inla_mag {
-                            inla_mag = accum.get_value(row_n, col_n);
-                            inla_index = i;
+            is_outlet = false;
+            if gap[(row, col)] == 0 && (fa >= target_fa || is_seed[(row, col)] == 1) {
+                if fa > max_fa {
+                    // Split off the inflowing tributaries that are closest to the target size,
+                    // provided that they deviate from it less than the confluence does.
+                    tributaries.clear();
+                    for i in 0..8 {
+                        row_n = row + dy[i];
+                        col_n = col + dx[i];
+                        if flow_dir.get_value(row_n, col_n) == inflowing_vals[i]
+                            && gap[(row_n, col_n)] == 0
+                            && accum.get_value(row_n, col_n) > 0
+                        {
+                            tributaries.push((accum.get_value(row_n, col_n), row_n, col_n));
                         }
                     }
-                }
-                if (target_fa - inla_mag) < (fa - target_fa) {
-                    if inla_index < 8 {
-                        row_n = row + dy[inla_index];
-                        col_n = col + dx[inla_index];
-                        accum.decrement(row, col, inla_mag);
-                        fa -= inla_mag;
-                        output.set_value(row_n, col_n, outlet_id);
-                        outlet_id += 1f64;
-                    } else {
-                        accum.set_value(row, col, 1);
-                        fa = 1;
-                        output.set_value(row, col, outlet_id);
-                        outlet_id += 1f64;
+                    tributaries.sort_by_key(|t| deviation(t.0));
+                    for &(a, row_n, col_n) in &tributaries {
+                        if fa <= max_fa {
+                            break;
+                        }
+                        if deviation(a) < deviation(fa) {
+                            output.set_value(row_n, col_n, outlet_id);
+                            outlet_id += 1f64;
+                            fa -= a;
+                        }
                     }
-                } else {
-                    accum.set_value(row, col, 1);
-                    fa = 1;
+                }
+                if fa >= target_fa || is_seed[(row, col)] == 1 {
                     output.set_value(row, col, outlet_id);
                     outlet_id += 1f64;
+                    is_outlet = true;
+                    fa = 0;
                 }
             }
+            accum.set_value(row, col, fa);
             num_inflowing.decrement(row, col, 1i8);
             dir = flow_dir[(row, col)];
             if dir >= 0 {
@@ -414,7 +658,7 @@ impl WhiteboxTool for Isobasins {
                 if num_inflowing[(row_n, col_n)] == 0i8 {
                     stack.push((row_n, col_n));
                 }
-            } else {
+            } else if !is_outlet {
                 output.set_value(row, col, outlet_id);
                 outlet_id += 1f64;
             }
@@ -423,7 +667,7 @@ impl WhiteboxTool for Isobasins {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding pour points: {}%", progress);
+                    report::progress("Finding pour points", progress);
                     old_progress = progress;
                 }
             }
@@ -432,69 +676,50 @@ impl WhiteboxTool for Isobasins {
         //////////////////////////////////////////
         // Trace flowpaths to their pour points //
         //////////////////////////////////////////
-        let mut flag: bool;
+        label_upslope_cells(&flow_dir, &mut output, out_nodata, verbose);
+
+        // find the size of each basin; gap cells are not part of any basin
+        let num_basins = outlet_id as usize - 1;
+        let mut basin_cells: Vec<Vec<(isize, isize)>> = vec![vec![]; num_basins + 1];
         let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                z = input.get_value(row, col);
-                if z != nodata && output.get_value(row, col) == nodata {
-                    // trace the flowpath from this cell until you find an outlet ID in the output
-                    outlet_id = nodata;
-                    flag = true;
-                    col_n = col;
-                    row_n = row;
-                    while flag {
-                        // find its downslope neighbour
-                        dir = flow_dir.get_value(row_n, col_n);
-                        if dir >= 0 {
-                            // move x and y accordingly
-                            col_n += dx[dir as usize];
-                            row_n += dy[dir as usize];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            z = output.get_value(row_n, col_n);
-                            if z != nodata {
-                                outlet_id = z;
-                                flag = false;
-                            }
-                        } else {
-                            flag = false;
-                        }
-                    }
-
-                    flag = true;
-                    col_n = col;
-                    row_n = row;
-                    output.set_value(row_n, col_n, outlet_id);
-                    while flag {
-                        // find its downslope neighbour
-                        dir = flow_dir.get_value(row_n, col_n);
-                        if dir >= 0 {
-                            // move x and y accordingly
-                            col_n += dx[dir as usize];
-                            row_n += dy[dir as usize];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            z = output.get_value(row_n, col_n);
-                            if z != nodata {
-                                outlet_id = z;
-                                flag = false;
-                            }
-                        } else {
-                            flag = false;
-                        }
-                        output.set_value(row_n, col_n, outlet_id);
-                    }
+                if gap[(row, col)] == 1 {
+                    output.set_value(row, col, out_nodata);
                 }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Labelling basins: {}%", progress);
-                    old_progress = progress;
+                z = output.get_value(row, col);
+                if z != out_nodata {
+                    basin_cells[z as usize].push((row, col));
                 }
             }
         }
+        let min_fa = (target_fa as f64 * (1f64 - tolerance)).ceil() as usize;
+        let mut num_within_tolerance = 0;
+        let mut total_deviation = 0f64;
+        for id in 1..num_basins + 1 {
+            let n = basin_cells[id].len();
+            if n >= min_fa && n <= max_fa {
+                num_within_tolerance += 1;
+            }
+            total_deviation += deviation(n) as f64 / target_fa as f64;
+        }
+        let mean_deviation = if num_basins > 0 {
+            100f64 * total_deviation / num_basins as f64
+        } else {
+            0f64
+        };
+        if verbose {
+            report::metadata("Number of basins", &num_basins.to_string());
+            report::message(&format!(
+                "Basins within the size tolerance: {} ({:.1}%)",
+                num_within_tolerance,
+                100f64 * num_within_tolerance as f64 / num_basins.max(1) as f64
+            ));
+            report::message(&format!(
+                "Mean absolute deviation from the target size: {:.1}%",
+                mean_deviation
+            ));
+        }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         output.configs.data_type = DataType::F32;
@@ -506,29 +731,107 @@ impl WhiteboxTool for Isobasins {
         ));
         output.add_metadata_entry(format!("Input file: {}", input_file));
         output.add_metadata_entry(format!("Target basin size: {}", target_fa));
+        output.add_metadata_entry(format!("Basin size tolerance: {}", tolerance));
+        if !seeds_file.is_empty() {
+            output.add_metadata_entry(format!("Seeds file: {}", seeds_file));
+        }
+        output.add_metadata_entry(format!("Route NoData gaps: {}", route_gaps));
+        output.add_metadata_entry(format!("Number of basins: {}", num_basins));
+        output.add_metadata_entry(format!(
+            "Basins within the size tolerance: {}",
+            num_within_tolerance
+        ));
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
+
+        if !vector_file.is_empty() {
+            let mut label: Array2D<i32> = Array2D::new(rows, columns, 0, 0)?;
+            for id in 1..num_basins + 1 {
+                for &(row, col) in &basin_cells[id] {
+                    label[(row, col)] = id as i32;
+                }
+            }
+            let cell_area = cell_size_x * cell_size_y;
+            let mut vector = Shapefile::new(&vector_file, ShapeType::Polygon)?;
+            vector.projection = input.configs.coordinate_ref_system_wkt.clone();
+            vector
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+            vector
+                .attributes
+                .add_field(&AttributeField::new("BASIN_ID", FieldDataType::Int, 7u8, 0u8));
+            vector
+                .attributes
+                .add_field(&AttributeField::new("NUM_CELLS", FieldDataType::Int, 10u8, 0u8));
+            vector.attributes.add_field(&AttributeField::new(
+                "AREA",
+                FieldDataType::Real,
+                14u8,
+                2u8,
+            ));
+            vector.attributes.add_field(&AttributeField::new(
+                "AREA_DEV",
+                FieldDataType::Real,
+                10u8,
+                2u8,
+            ));
+            let mut fid = 1;
+            for id in 1..num_basins + 1 {
+                if basin_cells[id].is_empty() {
+                    continue;
+                }
+                let rings = trace_region_boundaries(&basin_cells[id], &label, id as i32, &input);
+                let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+                for ring in &rings {
+                    sfg.add_part(ring);
+                }
+                vector.add_record(sfg);
+                let n = basin_cells[id].len();
+                vector.attributes.add_record(
+                    vec![
+                        FieldData::Int(fid),
+                        FieldData::Int(id as i32),
+                        FieldData::Int(n as i32),
+                        FieldData::Real(n as f64 * cell_area),
+                        FieldData::Real(
+                            100f64 * (n as f64 - target_fa as f64) / target_fa as f64,
+                        ),
+                    ],
+                    false,
+                );
+                fid += 1;
+                if verbose {
+                    progress = (100.0_f64 * id as f64 / num_basins as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Creating polygons", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+            let _ = match vector.write() {
+                Ok(_) => if verbose {
+                    report::message("Output vector file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 27, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::pour_points::*;
 use raster::*;
 use std::env;
 use std::f64;
@@ -15,6 +16,33 @@ use std::path;
 use tools::*;
 use vector::*;
 
+/// This tool moves outlet points (`--pour_pts`), used to specify the points of interest in
+/// a watershedding operation, to the nearest stream cell (`--streams`), following the
+/// method of Jenson (1991). Stream cells are those with positive values in the streams
+/// raster. The search is confined to a square neighbourhood of each point, sized by the
+/// maximum snap distance (`--snap_dist`) in map units; points with no stream cell in their
+/// neighbourhood are not moved. The snapped points are written to a point vector
+/// (`--output`) with the attributes of the input points.
+///
+/// The pour points may be a point vector or, if the file has a .csv or .txt extension, a
+/// delimited text file of outlet coordinates with a header row, e.g. `id,x,y`; see
+/// `CsvPointsToVector` for the supported formats. Points read from a text file are
+/// assigned the coordinate system of the streams raster.
+///
+/// Optionally, a CSV snapping report (`--report`) may be output, with one row per outlet
+/// containing its identifier (from an ID or FID field, or its record number), original
+/// and snapped coordinates, and the distance it was moved. If a D8 flow accumulation
+/// raster (`--flow_accum`) is specified, the report also gives the flow accumulation at
+/// each snapped cell. The WARNING column flags outlets that could not be snapped and
+/// outlets that were snapped to the same cell as other outlets; the latter are also
+/// reported when the tool is run in verbose mode.
+///
+/// # Reference
+/// Jenson, S. K. (1991), Applications of hydrological information automatically extracted
+/// from digital elevation models, Hydrological Processes, 5, 31-44.
+///
+/// # See Also
+/// `SnapPourPoints`, `Watershed`
 pub struct JensonSnapPourPoints {
     name: String,
     description: String,
@@ -34,10 +62,9 @@ impl JensonSnapPourPoints {
         parameters.push(ToolParameter {
             name: "Input Pour Points (Outlet) File".to_owned(),
             flags: vec!["--pour_pts".to_owned()],
-            description: "Input vector pour points (outlet) file.".to_owned(),
-            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
-                VectorGeometryType::Point,
-            )),
+            description: "Input vector pour points (outlet) file, or CSV file of outlet coordinates."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Any),
             default_value: None,
             optional: false,
         });
@@ -71,6 +98,25 @@ impl JensonSnapPourPoints {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Output Snapping Report File (optional)".to_owned(),
+            flags: vec!["--report".to_owned()],
+            description: "Optional output CSV snapping report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Flow Accumulation File (optional)".to_owned(),
+            flags: vec!["--flow_accum".to_owned()],
+            description: "Optional input raster D8 flow accumulation file, used in the report."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -82,7 +128,7 @@ impl JensonSnapPourPoints {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --pour_pts='pour_pts.shp' --streams='streams.tif' -o='output.shp' --snap_dist=15.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --pour_pts='pour_pts.shp' --streams='streams.tif' -o='output.shp' --snap_dist=15.0 --report='snapping.csv' --flow_accum='d8accum.tif'", short_exe, name).replace("*", &sep);
 
         JensonSnapPourPoints {
             name: name,
@@ -132,6 +178,8 @@ impl WhiteboxTool for JensonSnapPourPoints {
         let mut streams_file = String::new();
         let mut output_file = String::new();
         let mut snap_dist = 0.0;
+        let mut report_file = String::new();
+        let mut flow_accum_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -139,47 +187,28 @@ impl WhiteboxTool for JensonSnapPourPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-pour_pts" {
-                pourpts_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-streams" {
-                streams_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-snap_dist" {
-                snap_dist = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--pour_pts"])? {
+            pourpts_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--snap_dist"])? {
+            snap_dist = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--report"])? {
+            report_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--flow_accum"])? {
+            flow_accum_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,23 +225,46 @@ impl WhiteboxTool for JensonSnapPourPoints {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !report_file.is_empty() && !report_file.contains(&sep) && !report_file.contains("/") {
+            report_file = format!("{}{}", working_directory, report_file);
+        }
+        if !flow_accum_file.is_empty()
+            && !flow_accum_file.contains(&sep)
+            && !flow_accum_file.contains("/")
+        {
+            flow_accum_file = format!("{}{}", working_directory, flow_accum_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
-        // let pourpts = Raster::new(&pourpts_file, "r")?;
-        let pourpts = Shapefile::read(&pourpts_file)?;
+        let streams = Raster::new(&streams_file, "r")?;
 
-        // make sure the input vector file is of points type
-        if pourpts.header.shape_type.base_shape_type() != ShapeType::Point {
-            return Err(Error::new(
-                ErrorKind::InvalidInput,
-                "The input vector data must be of point base shape type.",
+        let (pourpts, num_skipped) =
+            read_pour_points(&pourpts_file, &streams.configs.coordinate_ref_system_wkt)?;
+        if verbose && num_skipped > 0 {
+            report::warning(&format!(
+                "{} rows with missing or invalid coordinates were skipped.",
+                num_skipped
             ));
         }
-
-        let streams = Raster::new(&streams_file, "r")?;
+        let ids = get_pour_point_ids(&pourpts);
+
+        let flow_accum = if !flow_accum_file.is_empty() {
+            let flow_accum = Raster::new(&flow_accum_file, "r")?;
+            if flow_accum.configs.rows != streams.configs.rows
+                || flow_accum.configs.columns != streams.configs.columns
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(flow_accum)
+        } else {
+            None
+        };
 
         let start = Instant::now();
 
@@ -230,6 +282,8 @@ impl WhiteboxTool for JensonSnapPourPoints {
         let (mut row, mut col): (isize, isize);
         let (mut xn, mut yn): (f64, f64);
         let (mut x, mut y): (f64, f64);
+        let (mut rn, mut cn): (isize, isize);
+        let mut snapped_points = Vec::with_capacity(pourpts.num_records);
         for record_num in 0..pourpts.num_records {
             let record = pourpts.get_record(record_num);
             let attr_rec = pourpts.attributes.get_record(record_num);
@@ -241,6 +295,8 @@ impl WhiteboxTool for JensonSnapPourPoints {
             min_dist = f64::INFINITY;
             xn = record.points[0].x;
             yn = record.points[0].y;
+            rn = row;
+            cn = col;
             for c in (col - snap_dist_int)..(col + snap_dist_int + 1) {
                 for r in (row - snap_dist_int)..(row + snap_dist_int + 1) {
                     zn = streams.get_value(r, c);
@@ -254,16 +310,40 @@ impl WhiteboxTool for JensonSnapPourPoints {
                             min_dist = dist;
                             xn = x;
                             yn = y;
+                            rn = r;
+                            cn = c;
                         }
                     }
                 }
             }
             output.add_point_record(xn, yn);
+            let accum = match flow_accum {
+                Some(ref fa) if min_dist.is_finite() => {
+                    let v = fa.get_value(rn, cn);
+                    if v != fa.configs.nodata {
+                        Some(v)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            snapped_points.push(SnappedPoint {
+                id: ids[record_num].clone(),
+                x: record.points[0].x,
+                y: record.points[0].y,
+                snap_x: xn,
+                snap_y: yn,
+                row: rn,
+                column: cn,
+                flow_accum: accum,
+                snapped: min_dist.is_finite(),
+            });
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (pourpts.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -280,7 +360,7 @@ impl WhiteboxTool for JensonSnapPourPoints {
         // {
         //     return Err(Error::new(
         //         ErrorKind::InvalidInput,
-        //         "The input files must have the same number of rows and columns and spatial extent.",
+        //         "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
         //     ));
         // }
 
@@ -328,6 +408,21 @@ impl WhiteboxTool for JensonSnapPourPoints {
         //     }
         // }
 
+        let shared = find_shared_cells(&snapped_points);
+        if verbose {
+            for (i, p) in snapped_points.iter().enumerate() {
+                if !shared[i].is_empty() && shared[i][0] > i {
+                    let ids: Vec<String> =
+                        shared[i].iter().map(|&j| snapped_points[j].id.clone()).collect();
+                    report::warning(&format!(
+                        "Outlet {} was snapped to the same cell as outlet(s) {}.",
+                        p.id,
+                        ids.join(", ")
+                    ));
+                }
+            }
+        }
+
         let elapsed_time = get_formatted_elapsed_time(start);
         // output.add_metadata_entry(format!(
         //     "Created by whitebox_tools\' {} tool",
@@ -341,19 +436,22 @@ impl WhiteboxTool for JensonSnapPourPoints {
         // );
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
+        if !report_file.is_empty() {
+            write_snap_report(&report_file, &snapped_points, &shared)?;
+            if verbose {
+                report::message("Snapping report written")
+            };
+        }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
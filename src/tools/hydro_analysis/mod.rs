@@ -1,9 +1,14 @@
 // private sub-module defined in other files
 mod average_flowpath_slope;
 mod average_upslope_flowpath_length;
+mod basin_labelling;
 mod basins;
 mod breach_depressions;
+mod breach_depressions_least_cost;
+mod breach_or_fill_depressions;
 mod breach_pits;
+mod condition_stream_profiles;
+mod curve_number_runoff;
 mod d8_flow_accum;
 mod d8_mass_flux;
 mod d8_pointer;
@@ -11,8 +16,10 @@ mod depth_in_sink;
 mod dinf_flow_accum;
 mod dinf_mass_flux;
 mod dinf_pointer;
+mod distance_to_stream_metrics;
 mod downslope_distance_to_stream;
 mod downslope_flowpath_length;
+mod edge_contamination;
 mod elevation_above_stream;
 mod elevation_above_stream_euclidean;
 mod fd8_flow_accum;
@@ -28,37 +35,50 @@ mod flow_accum_full_workflow;
 mod flow_length_diff;
 mod hillslopes;
 mod impoundment_index;
+mod impoundment_storage_curve;
 mod isobasins;
 mod jenson_snap_pour_points;
 mod longest_flowpath;
 mod max_upslope_flowpath;
 mod num_inflowing_neighbours;
+mod pour_points;
 mod raise_walls;
 mod rho8_pointer;
 mod sink;
+mod sink_statistics;
 mod snap_pour_points;
 mod stochastic_depression_analysis;
 mod strahler_basins;
+mod subbasin_parameters;
 mod subbasins;
 mod trace_downslope_flowpaths;
+mod trace_flowpath_lines;
 mod unnest_basins;
+mod upscale_flow_directions;
 mod watershed;
+mod weighted_flow_accumulation;
 
 // exports identifiers from private sub-modules in the current module namespace
 pub use self::average_flowpath_slope::AverageFlowpathSlope;
 pub use self::average_upslope_flowpath_length::AverageUpslopeFlowpathLength;
 pub use self::basins::Basins;
 pub use self::breach_depressions::BreachDepressions;
+pub use self::breach_depressions_least_cost::BreachDepressionsLeastCost;
+pub use self::breach_or_fill_depressions::BreachOrFillDepressions;
 pub use self::breach_pits::BreachSingleCellPits;
-pub use self::d8_flow_accum::D8FlowAccumulation;
+pub use self::condition_stream_profiles::ConditionStreamProfiles;
+pub use self::curve_number_runoff::CurveNumberRunoff;
+pub use self::d8_flow_accum::{d8_flow_accumulation, D8FlowAccumulation};
 pub use self::d8_mass_flux::D8MassFlux;
-pub use self::d8_pointer::D8Pointer;
+pub use self::d8_pointer::{d8_pointer, D8Pointer};
 pub use self::depth_in_sink::DepthInSink;
 pub use self::dinf_flow_accum::DInfFlowAccumulation;
 pub use self::dinf_mass_flux::DInfMassFlux;
 pub use self::dinf_pointer::DInfPointer;
+pub use self::distance_to_stream_metrics::DistanceToStreamMetrics;
 pub use self::downslope_distance_to_stream::DownslopeDistanceToStream;
 pub use self::downslope_flowpath_length::DownslopeFlowpathLength;
+pub use self::edge_contamination::EdgeContamination;
 pub use self::elevation_above_stream::ElevationAboveStream;
 pub use self::elevation_above_stream_euclidean::ElevationAboveStreamEuclidean;
 pub use self::fd8_flow_accum::FD8FlowAccumulation;
@@ -74,6 +94,7 @@ pub use self::flow_accum_full_workflow::FlowAccumulationFullWorkflow;
 pub use self::flow_length_diff::FlowLengthDiff;
 pub use self::hillslopes::Hillslopes;
 pub use self::impoundment_index::ImpoundmentIndex;
+pub use self::impoundment_storage_curve::ImpoundmentStorageCurve;
 pub use self::isobasins::Isobasins;
 pub use self::jenson_snap_pour_points::JensonSnapPourPoints;
 pub use self::longest_flowpath::LongestFlowpath;
@@ -82,10 +103,15 @@ pub use self::num_inflowing_neighbours::NumInflowingNeighbours;
 pub use self::raise_walls::RaiseWalls;
 pub use self::rho8_pointer::Rho8Pointer;
 pub use self::sink::Sink;
+pub use self::sink_statistics::SinkStatistics;
 pub use self::snap_pour_points::SnapPourPoints;
 pub use self::stochastic_depression_analysis::StochasticDepressionAnalysis;
 pub use self::strahler_basins::StrahlerOrderBasins;
+pub use self::subbasin_parameters::SubbasinParameters;
 pub use self::subbasins::Subbasins;
 pub use self::trace_downslope_flowpaths::TraceDownslopeFlowpaths;
+pub use self::trace_flowpath_lines::TraceFlowpathLines;
 pub use self::unnest_basins::UnnestBasins;
+pub use self::upscale_flow_directions::UpscaleFlowDirections;
 pub use self::watershed::Watershed;
+pub use self::weighted_flow_accumulation::WeightedFlowAccumulation;
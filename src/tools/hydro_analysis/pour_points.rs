@@ -0,0 +1,134 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+use tools::data_tools::read_csv_points;
+use vector::*;
+
+/// Reads the pour points (outlets) of the snapping tools, either from a point vector or,
+/// if the file has a .csv or .txt extension, from a delimited text file of outlet
+/// coordinates (e.g. id,x,y). Points read from a text file are assigned `projection`.
+/// Returns the points and the number of text rows skipped for invalid coordinates.
+pub fn read_pour_points(file_name: &str, projection: &str) -> Result<(Shapefile, usize), Error> {
+    let lower = file_name.to_lowercase();
+    if lower.ends_with(".csv") || lower.ends_with(".txt") {
+        let (mut points, num_skipped) = read_csv_points(file_name, "pour_pts.shp", "", "")?;
+        points.projection = projection.to_string();
+        return Ok((points, num_skipped));
+    }
+    let points = Shapefile::read(file_name)?;
+    if points.header.shape_type.base_shape_type() != ShapeType::Point {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The input vector data must be of point base shape type.",
+        ));
+    }
+    Ok((points, 0))
+}
+
+/// Returns the identifier of each pour point, taken from its ID field (in any case) if it
+/// has one, otherwise from its FID field, and otherwise its one-based record number.
+pub fn get_pour_point_ids(points: &Shapefile) -> Vec<String> {
+    let fields = points.attributes.get_fields();
+    let field_index = match fields.iter().position(|f| f.name.to_lowercase() == "id") {
+        Some(i) => Some(i),
+        None => fields.iter().position(|f| f.name.to_lowercase() == "fid"),
+    };
+    (0..points.num_records)
+        .map(|record_num| {
+            let value = match field_index {
+                Some(i) => points.attributes.get_record(record_num)[i].clone(),
+                None => FieldData::Null,
+            };
+            match value {
+                FieldData::Int(v) => v.to_string(),
+                FieldData::Real(v) => v.to_string(),
+                FieldData::Text(v) => v.trim().to_string(),
+                _ => (record_num + 1).to_string(),
+            }
+        })
+        .collect()
+}
+
+/// The outcome of snapping one pour point.
+pub struct SnappedPoint {
+    pub id: String,
+    pub x: f64,
+    pub y: f64,
+    pub snap_x: f64,
+    pub snap_y: f64,
+    pub row: isize,
+    pub column: isize,
+    pub flow_accum: Option<f64>,
+    pub snapped: bool,
+}
+
+/// Returns, for each snapped point, the indices of the other points snapped to its cell.
+pub fn find_shared_cells(points: &[SnappedPoint]) -> Vec<Vec<usize>> {
+    let mut cells: HashMap<(isize, isize), Vec<usize>> = HashMap::new();
+    for (i, p) in points.iter().enumerate() {
+        if p.snapped {
+            cells.entry((p.row, p.column)).or_insert(vec![]).push(i);
+        }
+    }
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            if !p.snapped {
+                return vec![];
+            }
+            cells[&(p.row, p.column)]
+                .iter()
+                .cloned()
+                .filter(|&j| j != i)
+                .collect()
+        })
+        .collect()
+}
+
+/// Writes a CSV report of the snapping, with one row per pour point giving its original
+/// and snapped coordinates, the distance moved, the flow accumulation at the snapped cell
+/// (blank if unknown) and a warning for points that could not be snapped or that share
+/// their snapped cell with other points.
+pub fn write_snap_report(
+    file_name: &str,
+    points: &[SnappedPoint],
+    shared: &[Vec<usize>],
+) -> Result<(), Error> {
+    let f = File::create(file_name)?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all("ID,X,Y,SNAP_X,SNAP_Y,DISTANCE,FLOW_ACCUM,WARNING\n".as_bytes())?;
+    for (i, p) in points.iter().enumerate() {
+        let flow_accum = match p.flow_accum {
+            Some(v) => v.to_string(),
+            None => String::new(),
+        };
+        let warning = if !p.snapped {
+            "not snapped".to_string()
+        } else if !shared[i].is_empty() {
+            let ids: Vec<String> = shared[i].iter().map(|&j| points[j].id.clone()).collect();
+            format!("shares cell with {}", ids.join(" "))
+        } else {
+            String::new()
+        };
+        let distance = ((p.snap_x - p.x) * (p.snap_x - p.x)
+            + (p.snap_y - p.y) * (p.snap_y - p.y))
+            .sqrt();
+        writer.write_all(
+            format!(
+                "{},{},{},{},{},{},{},{}\n",
+                p.id, p.x, p.y, p.snap_x, p.snap_y, distance, flow_accum, warning
+            ).as_bytes(),
+        )?;
+    }
+    Ok(())
+}
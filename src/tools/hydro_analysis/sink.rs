@@ -131,41 +131,19 @@ impl WhiteboxTool for Sink {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-            {
-                zero_background = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            zero_background = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -181,7 +159,7 @@ impl WhiteboxTool for Sink {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -193,6 +171,7 @@ impl WhiteboxTool for Sink {
         let nodata = input.configs.nodata;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let mut background_val = (i32::min_value() + 1) as f64;
         output.reinitialize_values(background_val);
 
@@ -248,7 +227,7 @@ impl WhiteboxTool for Sink {
                 zout_n = output[(row_n, col_n)];
                 if zout_n == background_val {
                     if zin_n == nodata {
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         queue.push_back((row_n, col_n));
                     } else {
                         output[(row_n, col_n)] = zin_n;
@@ -266,7 +245,7 @@ impl WhiteboxTool for Sink {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("progress: {}%", progress);
+                    report::progress("progress", progress);
                     old_progress = progress;
                 }
             }
@@ -296,7 +275,7 @@ impl WhiteboxTool for Sink {
                         });
                     } else {
                         // Interior nodata cells are still treated as nodata and are not filled.
-                        output[(row_n, col_n)] = nodata;
+                        output[(row_n, col_n)] = out_nodata;
                         num_solved_cells += 1;
                     }
                 }
@@ -306,7 +285,7 @@ impl WhiteboxTool for Sink {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -314,14 +293,17 @@ impl WhiteboxTool for Sink {
 
         // Reclassify the output such that all cells that are higher than the input are identified.
         let mut fid = 0f64;
-        background_val = nodata;
+        background_val = out_nodata;
         if zero_background {
             background_val = 0f64;
         }
         let mut visited: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
         for row in 0..rows {
             for col in 0..columns {
-                if output[(row, col)] > input[(row, col)] && visited[(row, col)] != 1 {
+                if input[(row, col)] != nodata
+                    && output[(row, col)] > input[(row, col)]
+                    && visited[(row, col)] != 1
+                {
                     fid += 1f64;
                     output[(row, col)] = fid;
                     visited[(row, col)] = 1;
@@ -345,14 +327,14 @@ impl WhiteboxTool for Sink {
                     if input[(row, col)] != nodata {
                         output[(row, col)] = background_val;
                     } else {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     }
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Clumping: {}%", progress);
+                    report::progress("Clumping", progress);
                     old_progress = progress;
                 }
             }
@@ -370,19 +352,16 @@ impl WhiteboxTool for Sink {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
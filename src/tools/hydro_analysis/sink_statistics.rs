@@ -0,0 +1,593 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::point_in_poly;
+use raster::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{Array2D, Point2D};
+use tools::*;
+use vector::{ShapeType, Shapefile};
+
+/// This tool identifies the sinks (depressions) in a digital elevation model (DEM) and
+/// reports their statistics, so that depressions may be screened before the DEM is
+/// conditioned. Like the `Sink` and `DepthInSink` tools, the depressions are found by
+/// filling the DEM with a priority-flood operation: each connected group of cells that is
+/// raised by filling is a depression. The output raster (`--output`) contains the unique
+/// identifier of the depression containing each cell, and the depth of each cell below
+/// the spill elevation may optionally be output as a second raster (`--out_depth`).
+/// Cells outside of depressions are assigned NoData, or zero if the `--zero_background`
+/// flag is specified.
+///
+/// The statistics of each depression are written to a CSV file (`--csv`), with one row
+/// per depression containing its number of cells, area, volume, maximum and mean depth,
+/// the location and elevation of its pit (lowest cell), and the location and elevation of
+/// its spill cell, i.e. the lowest cell on its rim, through which it overflows.
+///
+/// Optionally, a vector of waterbody or wetland polygons (`--waterbodies`) may be input.
+/// Depressions that contain the centre of any grid cell within the polygons are flagged
+/// in the WATERBODY column of the CSV file, which also gives the percentage of the
+/// depression's cells that are within the polygons (WATERBODY_PCT). This allows real
+/// wetlands and lakes, which should be preserved when the DEM is conditioned, to be
+/// distinguished from artifact depressions, e.g. by extracting the flagged depressions
+/// from the output raster.
+///
+/// # See Also
+/// `Sink`, `DepthInSink`, `FillDepressions`, `BreachOrFillDepressions`
+pub struct SinkStatistics {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SinkStatistics {
+    pub fn new() -> SinkStatistics {
+        // public constructor
+        let name = "SinkStatistics".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description = "Identifies the depressions in a DEM and reports their area, volume, depth, and spill location, flagging those that intersect waterbody polygons.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file of depression identifiers.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Depression Statistics File".to_owned(),
+            flags: vec!["--csv".to_owned()],
+            description: "Output CSV file of depression statistics.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Waterbody Polygons File (optional)".to_owned(),
+            flags: vec!["--waterbodies".to_owned()],
+            description: "Optional input vector waterbody or wetland polygons file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Depth File (optional)".to_owned(),
+            flags: vec!["--out_depth".to_owned()],
+            description: "Optional output raster file of depth in depressions.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Should a background value of zero be used?".to_owned(),
+            flags: vec!["--zero_background".to_owned()],
+            description: "Flag indicating whether the background value of zero should be used."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=sinks.tif --csv=sinks.csv --waterbodies=lakes.shp --out_depth=depth.tif", short_exe, name).replace("*", &sep);
+
+        SinkStatistics {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SinkStatistics {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut csv_file = String::new();
+        let mut waterbodies_file = String::new();
+        let mut depth_file = String::new();
+        let mut zero_background = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--csv"])? {
+            csv_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--waterbodies"])? {
+            waterbodies_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_depth"])? {
+            depth_file = v;
+        }
+        if tool_args.get_flag(&["--zero_background", "--esri_style"])? {
+            zero_background = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !csv_file.contains(&sep) && !csv_file.contains("/") {
+            csv_file = format!("{}{}", working_directory, csv_file);
+        }
+        if !waterbodies_file.is_empty()
+            && !waterbodies_file.contains(&sep)
+            && !waterbodies_file.contains("/")
+        {
+            waterbodies_file = format!("{}{}", working_directory, waterbodies_file);
+        }
+        if !depth_file.is_empty() && !depth_file.contains(&sep) && !depth_file.contains("/") {
+            depth_file = format!("{}{}", working_directory, depth_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+        let waterbodies = if !waterbodies_file.is_empty() {
+            let polygons = Shapefile::read(&waterbodies_file)?;
+            if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input waterbodies vector data must be of polygon base shape type.",
+                ));
+            }
+            Some(polygons)
+        } else {
+            None
+        };
+
+        let start = Instant::now();
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let cell_area = input.configs.resolution_x * input.configs.resolution_y;
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+
+        /*
+        Fill the DEM using a priority-flood operation. As in the DepthInSink tool, the
+        flood is seeded from the data edges, which are found by region-growing from the
+        raster's edges through nodata cells, so that interior nodata holes are not
+        treated as outlets.
+        */
+        let unsolved = f64::NEG_INFINITY;
+        let mut filled: Array2D<f64> = Array2D::new(rows, columns, unsolved, nodata)?;
+        let mut queue: VecDeque<(isize, isize)> = VecDeque::new();
+        for row in 0..rows {
+            queue.push_back((row, -1));
+            queue.push_back((row, columns));
+        }
+        for col in 0..columns {
+            queue.push_back((-1, col));
+            queue.push_back((rows, col));
+        }
+        let mut minheap = BinaryHeap::new();
+        while let Some((row, col)) = queue.pop_front() {
+            for n in 0..8 {
+                let (row_n, col_n) = (row + dy[n], col + dx[n]);
+                if filled.get_value(row_n, col_n) == unsolved {
+                    let z_n = input.get_value(row_n, col_n);
+                    filled.set_value(row_n, col_n, z_n);
+                    if z_n == nodata {
+                        queue.push_back((row_n, col_n));
+                    } else {
+                        minheap.push(GridCell {
+                            row: row_n,
+                            column: col_n,
+                            priority: z_n,
+                        });
+                    }
+                }
+            }
+        }
+        let mut num_solved_cells = 0;
+        let num_cells = rows * columns;
+        while let Some(cell) = minheap.pop() {
+            for n in 0..8 {
+                let (row_n, col_n) = (cell.row + dy[n], cell.column + dx[n]);
+                if filled.get_value(row_n, col_n) == unsolved {
+                    let z_n = input.get_value(row_n, col_n);
+                    if z_n != nodata {
+                        // We're in a depression if the neighbour is lower. Raise the elevation.
+                        let z_n = z_n.max(cell.priority);
+                        filled.set_value(row_n, col_n, z_n);
+                        minheap.push(GridCell {
+                            row: row_n,
+                            column: col_n,
+                            priority: z_n,
+                        });
+                    } else {
+                        filled.set_value(row_n, col_n, nodata);
+                    }
+                }
+            }
+            if verbose {
+                num_solved_cells += 1;
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Filling", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Find the grid cells whose centres are within the waterbody polygons.
+        let mut in_waterbody: Array2D<u8> = Array2D::new(rows, columns, 0u8, 0u8)?;
+        if let Some(ref polygons) = waterbodies {
+            for record_num in 0..polygons.num_records {
+                let record = polygons.get_record(record_num);
+                let starting_row = input.get_row_from_y(record.y_max).max(0);
+                let ending_row = input.get_row_from_y(record.y_min).min(rows - 1);
+                let starting_col = input.get_column_from_x(record.x_min).max(0);
+                let ending_col = input.get_column_from_x(record.x_max).min(columns - 1);
+                for row in starting_row..ending_row + 1 {
+                    let y = input.get_y_from_row(row);
+                    for col in starting_col..ending_col + 1 {
+                        let x = input.get_x_from_column(col);
+                        // A cell is within the polygon if it is within an odd number of its
+                        // parts, which excludes the cells within holes.
+                        let mut num_containing_parts = 0;
+                        for part in 0..record.num_parts as usize {
+                            let start_point_in_part = record.parts[part] as usize;
+                            let end_point_in_part = if part < record.num_parts as usize - 1 {
+                                record.parts[part + 1] as usize - 1
+                            } else {
+                                record.num_points as usize - 1
+                            };
+                            if point_in_poly(
+                                &Point2D { x: x, y: y },
+                                &record.points[start_point_in_part..end_point_in_part + 1],
+                            ) {
+                                num_containing_parts += 1;
+                            }
+                        }
+                        if num_containing_parts % 2 == 1 {
+                            in_waterbody.set_value(row, col, 1u8);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Label the depressions, i.e. the connected cells raised by filling, and measure them.
+        let mut labels: Array2D<i32> = Array2D::new(rows, columns, 0, 0)?;
+        let mut sinks: Vec<SinkStats> = vec![];
+        for row in 0..rows {
+            for col in 0..columns {
+                let z = input.get_value(row, col);
+                if z == nodata || filled.get_value(row, col) <= z || labels.get_value(row, col) > 0
+                {
+                    continue;
+                }
+                let id = sinks.len() as i32 + 1;
+                let level = filled.get_value(row, col);
+                let mut s = SinkStats {
+                    num_cells: 0,
+                    volume: 0f64,
+                    level: level,
+                    pit: (row, col, z),
+                    spill: (row, col, f64::INFINITY),
+                    num_waterbody_cells: 0,
+                };
+                labels.set_value(row, col, id);
+                queue.push_back((row, col));
+                while let Some((r, c)) = queue.pop_front() {
+                    let z_c = input.get_value(r, c);
+                    s.num_cells += 1;
+                    s.volume += (level - z_c) * cell_area;
+                    if z_c < s.pit.2 {
+                        s.pit = (r, c, z_c);
+                    }
+                    if in_waterbody.get_value(r, c) == 1 {
+                        s.num_waterbody_cells += 1;
+                    }
+                    for n in 0..8 {
+                        let (r_n, c_n) = (r + dy[n], c + dx[n]);
+                        let z_n = input.get_value(r_n, c_n);
+                        if z_n == nodata {
+                            continue;
+                        }
+                        if filled.get_value(r_n, c_n) > z_n {
+                            if labels.get_value(r_n, c_n) == 0 {
+                                labels.set_value(r_n, c_n, id);
+                                queue.push_back((r_n, c_n));
+                            }
+                        } else if z_n < s.spill.2 {
+                            // the lowest cell on the rim is the spill cell
+                            s.spill = (r_n, c_n, z_n);
+                        }
+                    }
+                }
+                sinks.push(s);
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Measuring depressions", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut configs = input.configs.clone();
+        configs.data_type = DataType::I32;
+        configs.nodata = -32768f64;
+        configs.photometric_interp = PhotometricInterpretation::Categorical;
+        configs.palette = "qual.plt".to_string();
+        configs.metadata = vec![];
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+        let background_val = if zero_background { 0f64 } else { output.configs.nodata };
+        let mut depth = if !depth_file.is_empty() {
+            let mut configs = input.configs.clone();
+            configs.data_type = DataType::F32;
+            configs.nodata = -32768f64;
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+            configs.palette = "blueyellow.plt".to_string();
+            configs.metadata = vec![];
+            Some(Raster::initialize_using_config(&depth_file, &configs))
+        } else {
+            None
+        };
+        for row in 0..rows {
+            for col in 0..columns {
+                let z = input.get_value(row, col);
+                if z == nodata {
+                    continue; // the output is initialized to nodata
+                }
+                let id = labels.get_value(row, col);
+                if id > 0 {
+                    output.set_value(row, col, id as f64);
+                } else {
+                    output.set_value(row, col, background_val);
+                }
+                if let Some(ref mut d) = depth {
+                    if id > 0 {
+                        d.set_value(row, col, filled.get_value(row, col) - z);
+                    } else if zero_background {
+                        d.set_value(row, col, 0f64);
+                    }
+                }
+            }
+        }
+
+        let num_in_waterbodies = sinks.iter().filter(|s| s.num_waterbody_cells > 0).count();
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        if waterbodies.is_some() {
+            output.add_metadata_entry(format!("Waterbodies file: {}", waterbodies_file));
+        }
+        output.add_metadata_entry(format!("Number of depressions: {}", sinks.len()));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::metadata("Number of depressions", &sinks.len().to_string());
+            if waterbodies.is_some() {
+                report::metadata(
+                    "Number of depressions intersecting waterbodies",
+                    &num_in_waterbodies.to_string(),
+                );
+            }
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if let Some(mut d) = depth {
+            d.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            d.add_metadata_entry(format!("Input file: {}", input_file));
+            let _ = match d.write() {
+                Ok(_) => if verbose {
+                    report::message("Depth file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        let f = File::create(csv_file.clone())?;
+        let mut writer = BufWriter::new(f);
+        let mut header = "ID,NUM_CELLS,AREA,VOLUME,MAX_DEPTH,MEAN_DEPTH,PIT_X,PIT_Y,PIT_Z,SPILL_X,SPILL_Y,SPILL_Z".to_string();
+        if waterbodies.is_some() {
+            header.push_str(",WATERBODY,WATERBODY_PCT");
+        }
+        writer.write_all(format!("{}\n", header).as_bytes())?;
+        for (i, s) in sinks.iter().enumerate() {
+            let area = s.num_cells as f64 * cell_area;
+            let mut line = format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{}",
+                i + 1,
+                s.num_cells,
+                area,
+                s.volume,
+                s.level - s.pit.2,
+                s.volume / area,
+                input.get_x_from_column(s.pit.1),
+                input.get_y_from_row(s.pit.0),
+                s.pit.2,
+                input.get_x_from_column(s.spill.1),
+                input.get_y_from_row(s.spill.0),
+                s.spill.2
+            );
+            if waterbodies.is_some() {
+                line.push_str(&format!(
+                    ",{},{}",
+                    if s.num_waterbody_cells > 0 { 1 } else { 0 },
+                    100f64 * s.num_waterbody_cells as f64 / s.num_cells as f64
+                ));
+            }
+            writer.write_all(format!("{}\n", line).as_bytes())?;
+        }
+        if verbose {
+            report::message("Depression statistics file written")
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+struct SinkStats {
+    num_cells: usize,
+    volume: f64,
+    level: f64,
+    pit: (isize, isize, f64),
+    spill: (isize, isize, f64),
+    num_waterbody_cells: usize,
+}
+
+#[derive(PartialEq, Debug)]
+struct GridCell {
+    row: isize,
+    column: isize,
+    priority: f64,
+}
+
+impl Eq for GridCell {}
+
+impl PartialOrd for GridCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.priority.partial_cmp(&self.priority)
+    }
+}
+
+impl Ord for GridCell {
+    fn cmp(&self, other: &GridCell) -> Ordering {
+        let ord = self.partial_cmp(other).unwrap();
+        match ord {
+            Ordering::Greater => Ordering::Less,
+            Ordering::Less => Ordering::Greater,
+            Ordering::Equal => ord,
+        }
+    }
+}
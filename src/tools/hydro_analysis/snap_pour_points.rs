@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 27, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::pour_points::*;
 use raster::*;
 use std::env;
 use std::f64;
@@ -14,6 +15,26 @@ use std::path;
 use tools::*;
 use vector::*;
 
+/// This tool moves outlet points (`--pour_pts`), used to specify the points of interest in
+/// a watershedding operation, to the cell of highest D8 flow accumulation (`--flow_accum`)
+/// within a square neighbourhood of each point. The neighbourhood size is set by the
+/// maximum snap distance (`--snap_dist`), in map units. The snapped points are written to
+/// a point vector (`--output`) with the attributes of the input points.
+///
+/// The pour points may be a point vector or, if the file has a .csv or .txt extension, a
+/// delimited text file of outlet coordinates with a header row, e.g. `id,x,y`; see
+/// `CsvPointsToVector` for the supported formats. Points read from a text file are
+/// assigned the coordinate system of the flow accumulation raster.
+///
+/// Optionally, a CSV snapping report (`--report`) may be output, with one row per outlet
+/// containing its identifier (from an ID or FID field, or its record number), original
+/// and snapped coordinates, the distance it was moved, and the flow accumulation at the
+/// snapped cell. The WARNING column flags outlets that were snapped to the same cell as
+/// other outlets, which would otherwise produce empty or duplicate watersheds; these
+/// are also reported when the tool is run in verbose mode.
+///
+/// # See Also
+/// `JensonSnapPourPoints`, `Watershed`, `D8FlowAccumulation`
 pub struct SnapPourPoints {
     name: String,
     description: String,
@@ -33,10 +54,9 @@ impl SnapPourPoints {
         parameters.push(ToolParameter {
             name: "Input Pour Points (Outlet) File".to_owned(),
             flags: vec!["--pour_pts".to_owned()],
-            description: "Input vector pour points (outlet) file.".to_owned(),
-            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
-                VectorGeometryType::Point,
-            )),
+            description: "Input vector pour points (outlet) file, or CSV file of outlet coordinates."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Any),
             default_value: None,
             optional: false,
         });
@@ -70,6 +90,15 @@ impl SnapPourPoints {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Output Snapping Report File (optional)".to_owned(),
+            flags: vec!["--report".to_owned()],
+            description: "Optional output CSV snapping report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -81,7 +110,7 @@ impl SnapPourPoints {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --pour_pts='pour_pts.shp' --flow_accum='d8accum.tif' -o='output.shp' --snap_dist=15.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --pour_pts='pour_pts.shp' --flow_accum='d8accum.tif' -o='output.shp' --snap_dist=15.0 --report='snapping.csv'", short_exe, name).replace("*", &sep);
 
         SnapPourPoints {
             name: name,
@@ -131,6 +160,7 @@ impl WhiteboxTool for SnapPourPoints {
         let mut flow_accum_file = String::new();
         let mut output_file = String::new();
         let mut snap_dist = 0.0;
+        let mut report_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -138,47 +168,25 @@ impl WhiteboxTool for SnapPourPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-pour_pts" {
-                pourpts_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-flow_accum" {
-                flow_accum_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-snap_dist" {
-                snap_dist = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--pour_pts"])? {
+            pourpts_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--flow_accum"])? {
+            flow_accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--snap_dist"])? {
+            snap_dist = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--report"])? {
+            report_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -195,23 +203,25 @@ impl WhiteboxTool for SnapPourPoints {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !report_file.is_empty() && !report_file.contains(&sep) && !report_file.contains("/") {
+            report_file = format!("{}{}", working_directory, report_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
-        // let pourpts = Raster::new(&pourpts_file, "r")?;
-        let pourpts = Shapefile::read(&pourpts_file)?;
+        let flow_accum = Raster::new(&flow_accum_file, "r")?;
 
-        // make sure the input vector file is of points type
-        if pourpts.header.shape_type.base_shape_type() != ShapeType::Point {
-            return Err(Error::new(
-                ErrorKind::InvalidInput,
-                "The input vector data must be of point base shape type.",
+        let (pourpts, num_skipped) =
+            read_pour_points(&pourpts_file, &flow_accum.configs.coordinate_ref_system_wkt)?;
+        if verbose && num_skipped > 0 {
+            report::warning(&format!(
+                "{} rows with missing or invalid coordinates were skipped.",
+                num_skipped
             ));
         }
-
-        let flow_accum = Raster::new(&flow_accum_file, "r")?;
+        let ids = get_pour_point_ids(&pourpts);
 
         let start = Instant::now();
 
@@ -230,6 +240,7 @@ impl WhiteboxTool for SnapPourPoints {
         let (mut row, mut col): (isize, isize);
         let (mut xn, mut yn): (isize, isize);
         let (mut x, mut y): (f64, f64);
+        let mut snapped_points = Vec::with_capacity(pourpts.num_records);
         for record_num in 0..pourpts.num_records {
             let record = pourpts.get_record(record_num);
             let attr_rec = pourpts.attributes.get_record(record_num);
@@ -254,11 +265,22 @@ impl WhiteboxTool for SnapPourPoints {
             x = flow_accum.get_x_from_column(xn);
             y = flow_accum.get_y_from_row(yn);
             output.add_point_record(x, y);
+            snapped_points.push(SnappedPoint {
+                id: ids[record_num].clone(),
+                x: record.points[0].x,
+                y: record.points[0].y,
+                snap_x: x,
+                snap_y: y,
+                row: yn,
+                column: xn,
+                flow_accum: if max_accum > 0.0 { Some(max_accum) } else { None },
+                snapped: max_accum > 0.0,
+            });
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (pourpts.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -279,7 +301,7 @@ impl WhiteboxTool for SnapPourPoints {
         // {
         //     return Err(Error::new(
         //         ErrorKind::InvalidInput,
-        //         "The input files must have the same number of rows and columns and spatial extent.",
+        //         "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
         //     ));
         // }
 
@@ -322,6 +344,21 @@ impl WhiteboxTool for SnapPourPoints {
         //     }
         // }
 
+        let shared = find_shared_cells(&snapped_points);
+        if verbose {
+            for (i, p) in snapped_points.iter().enumerate() {
+                if !shared[i].is_empty() && shared[i][0] > i {
+                    let ids: Vec<String> =
+                        shared[i].iter().map(|&j| snapped_points[j].id.clone()).collect();
+                    report::warning(&format!(
+                        "Outlet {} was snapped to the same cell as outlet(s) {}.",
+                        p.id,
+                        ids.join(", ")
+                    ));
+                }
+            }
+        }
+
         let elapsed_time = get_formatted_elapsed_time(start);
         // output.add_metadata_entry(format!(
         //     "Created by whitebox_tools\' {} tool",
@@ -335,19 +372,22 @@ impl WhiteboxTool for SnapPourPoints {
         // );
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
+        if !report_file.is_empty() {
+            write_snap_report(&report_file, &snapped_points, &shared)?;
+            if verbose {
+                report::message("Snapping report written")
+            };
+        }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
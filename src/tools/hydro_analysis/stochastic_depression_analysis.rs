@@ -184,53 +184,25 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-rmse" {
-                rmse = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-range" {
-                range = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-iterations" {
-                iterations = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--rmse"])? {
+            rmse = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--range"])? {
+            range = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--iterations"])? {
+            iterations = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -341,10 +313,10 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                     };
 
                     if verbose {
-                        println!("Loop {} of {}", iter_num + 1, iterations);
+                        report::message(&format!("Loop {} of {}", iter_num + 1, iterations));
                         let progress =
                             (100f64 * (iter_num + 1) as f64 / iterations as f64) as isize;
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress as usize);
                     }
 
                     /////////////////////////////
@@ -742,21 +714,18 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 13, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::basin_labelling::{d8_flow_directions, label_upslope_cells};
 use raster::*;
 use std::env;
 use std::f64;
@@ -135,45 +136,22 @@ impl WhiteboxTool for StrahlerOrderBasins {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-d8_pntr" || vec[0].to_lowercase() == "--d8_pntr" {
-                if keyval {
-                    d8_file = vec[1].to_string();
-                } else {
-                    d8_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
-                if keyval {
-                    streams_file = vec[1].to_string();
-                } else {
-                    streams_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-esri_pntr"
-                || vec[0].to_lowercase() == "--esri_pntr"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -192,7 +170,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -211,7 +189,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -340,7 +318,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -348,6 +326,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
 
         // Now perform the watershedding operation
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        let out_nodata = output.configs.nodata;
         output.configs.data_type = DataType::F32;
         output.configs.palette = "qual.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -358,7 +337,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
         for row in 0..rows {
             for col in 0..columns {
                 if pntr[(row, col)] == pntr_nodata {
-                    output[(row, col)] = nodata;
+                    output[(row, col)] = out_nodata;
                 }
                 z = pourpts[(row, col)];
                 if z != nodata && z > 0.0 {
@@ -368,72 +347,14 @@ impl WhiteboxTool for StrahlerOrderBasins {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
+                    report::progress("Watershedding (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        let mut flag: bool;
-        let (mut x, mut y): (isize, isize);
-        let mut outlet_id: f64;
-        let mut c: usize;
-        for row in 0..rows {
-            for col in 0..columns {
-                if output[(row, col)] == low_value {
-                    flag = false;
-                    x = col;
-                    y = row;
-                    outlet_id = nodata;
-                    while !flag {
-                        dir = pntr[(y, x)] as usize;
-                        if dir > 0 {
-                            c = pntr_matches[dir];
-                            y += dy[c];
-                            x += dx[c];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
-                            if z != low_value {
-                                outlet_id = z;
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                    }
-
-                    flag = false;
-                    x = col;
-                    y = row;
-                    output[(y, x)] = outlet_id;
-                    while !flag {
-                        // find its downslope neighbour
-                        dir = pntr[(y, x)] as usize;
-                        if dir > 0 {
-                            c = pntr_matches[dir];
-                            y += dy[c];
-                            x += dx[c];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                        output[(y, x)] = outlet_id;
-                    }
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        let flow_dir = d8_flow_directions(&pntr, esri_style)?;
+        label_upslope_cells(&flow_dir, &mut output, low_value, verbose);
 
         let elapsed_time = get_formatted_elapsed_time(start);
         output.add_metadata_entry(format!(
@@ -445,19 +366,16 @@ impl WhiteboxTool for StrahlerOrderBasins {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,776 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufReader, BufWriter, Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool summarizes the physical properties of each sub-catchment in a subbasins
+/// raster (`--subbasins`), e.g. created using the `Subbasins`, `Watershed`, or
+/// `Isobasins` tools, and writes them to a CSV parameter table (`--output`) formatted for
+/// import into the SWAT or HEC-HMS hydrological models (`--format`). The tool also
+/// requires a DEM (`--dem`) and the D8 flow pointer raster (`--d8_pntr`) from which the
+/// subbasins were derived. Each subbasin is described by its area, its mean elevation and
+/// elevation range, its mean slope gradient, calculated using Horn's (1981) method, and
+/// the length and slope of its longest flowpath, i.e. the longest D8 flow path that
+/// connects a cell of the subbasin to its outlet (see `LongestFlowpath`).
+///
+/// Optionally, a land-cover raster (`--landcover`), a soils raster (`--soils`), and a
+/// lookup table (`--lookup`) may be used to calculate the area-weighted mean of a
+/// hydrological parameter (`--parameter`) within each subbasin, either the SCS curve
+/// number (`CN`) or Manning's roughness coefficient (`roughness`). The lookup table is a
+/// text file containing one record per line, with fields separated by commas,
+/// semicolons, spaces, or tabs. Each record contains a land-cover value, a soil value, and
+/// the parameter value for that combination, e.g. `41,B,55`. Hydrologic soil groups may
+/// be given either as numeric codes or as the letters A, B, C, and D, which correspond to
+/// soil raster values of 1, 2, 3, and 4 respectively. Records with only two fields, i.e.
+/// a land-cover value and a parameter value, apply to all soils, and are used when no
+/// soils raster is specified or when a land-cover and soil combination has no record of
+/// its own. Lines that do not begin with a number, such as a header line, are ignored.
+///
+/// The SWAT table contains the fields `Subbasin`, `Area` (ha), `Slo1` (mean slope, %),
+/// `Len1` (longest flowpath length, m), `Csl` (longest flowpath slope, %), `Elev`,
+/// `ElevMin`, and `ElevMax`, followed by `CN2` or `OV_N` if a parameter is calculated.
+/// The HEC-HMS table contains the fields `Name`, `Area` (km<sup>2</sup>), `Slope` (%),
+/// `FlowpathLength` (m), `FlowpathSlope` (%), `Elev`, and either `CurveNumber` and
+/// `LagTime` or `Roughness`. The lag time (minutes), used by the SCS unit hydrograph
+/// transform, is estimated from the longest flowpath length, the curve number, and the
+/// mean slope using the SCS lag equation. Areas and lengths assume that the rasters use
+/// a projected coordinate system with units of metres.
+///
+/// # Reference
+/// Horn, B. K. (1981). Hill shading and the reflectance map. Proceedings of the IEEE,
+/// 69(1), 14-47.
+///
+/// # See Also
+/// `Subbasins`, `LongestFlowpath`, `Hillslopes`
+pub struct SubbasinParameters {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SubbasinParameters {
+    pub fn new() -> SubbasinParameters {
+        // public constructor
+        let name = "SubbasinParameters".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Creates a table of sub-catchment parameters for the SWAT or HEC-HMS hydrological models."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Subbasins File".to_owned(),
+            flags: vec!["--subbasins".to_owned()],
+            description: "Input raster subbasins file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Land-Cover File (optional)".to_owned(),
+            flags: vec!["--landcover".to_owned()],
+            description: "Input raster land-cover file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Soils File (optional)".to_owned(),
+            flags: vec!["--soils".to_owned()],
+            description: "Input raster soils (e.g. hydrologic soil group) file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Lookup Table (optional)".to_owned(),
+            flags: vec!["--lookup".to_owned()],
+            description: "Input lookup table of parameter values for each land-cover and soil combination.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Parameter".to_owned(),
+            flags: vec!["--parameter".to_owned()],
+            description: "Parameter contained in the lookup table; options are 'CN' and 'roughness'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "CN".to_owned(),
+                "roughness".to_owned(),
+            ]),
+            default_value: Some("CN".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Table Format".to_owned(),
+            flags: vec!["--format".to_owned()],
+            description: "Format of the output table; options are 'SWAT' and 'HEC-HMS'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "SWAT".to_owned(),
+                "HEC-HMS".to_owned(),
+            ]),
+            default_value: Some("SWAT".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --subbasins=subbasins.tif --dem=DEM.tif --d8_pntr=pntr.tif -o=subbasins.csv
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --subbasins=subbasins.tif --dem=DEM.tif --d8_pntr=pntr.tif --landcover=landcover.tif --soils=hsg.tif --lookup=cn.csv --parameter=CN --format=HEC-HMS -o=subbasins.csv",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SubbasinParameters {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SubbasinParameters {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut subbasins_file = String::new();
+        let mut dem_file = String::new();
+        let mut d8_file = String::new();
+        let mut landcover_file = String::new();
+        let mut soils_file = String::new();
+        let mut lookup_file = String::new();
+        let mut parameter = "cn".to_string();
+        let mut format = "swat".to_string();
+        let mut output_file = String::new();
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--subbasins"])? {
+            subbasins_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--landcover"])? {
+            landcover_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--soils"])? {
+            soils_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--lookup"])? {
+            lookup_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--parameter"])? {
+            parameter = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_string(&["--format"])? {
+            format = v.to_lowercase().replace("-", "");
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if parameter != "cn" && !parameter.contains("rough") {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The parameter (--parameter) must be either 'CN' or 'roughness'.",
+            ));
+        }
+        let is_cn = parameter == "cn";
+        if format != "swat" && format != "hechms" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The table format (--format) must be either 'SWAT' or 'HEC-HMS'.",
+            ));
+        }
+        if landcover_file.is_empty() != lookup_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The land-cover (--landcover) and lookup table (--lookup) files must be specified together.",
+            ));
+        }
+        if !soils_file.is_empty() && landcover_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A soils file (--soils) may only be used with a land-cover file (--landcover).",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        for file in [
+            &mut subbasins_file,
+            &mut dem_file,
+            &mut d8_file,
+            &mut landcover_file,
+            &mut soils_file,
+            &mut lookup_file,
+            &mut output_file,
+        ].iter_mut()
+        {
+            if !file.is_empty() && !file.contains(&sep) && !file.contains("/") {
+                **file = format!("{}{}", working_directory, file);
+            }
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let subbasins = Raster::new(&subbasins_file, "r")?;
+        let dem = Raster::new(&dem_file, "r")?;
+        let pntr = Raster::new(&d8_file, "r")?;
+        let landcover = if !landcover_file.is_empty() {
+            Some(Raster::new(&landcover_file, "r")?)
+        } else {
+            None
+        };
+        let soils = if !soils_file.is_empty() {
+            Some(Raster::new(&soils_file, "r")?)
+        } else {
+            None
+        };
+        let lookup = if !lookup_file.is_empty() {
+            read_parameter_lookup(&lookup_file)?
+        } else {
+            HashMap::new()
+        };
+
+        let start = Instant::now();
+
+        let rows = subbasins.configs.rows as isize;
+        let columns = subbasins.configs.columns as isize;
+        let nodata = subbasins.configs.nodata;
+        let dem_nodata = dem.configs.nodata;
+        let pntr_nodata = pntr.configs.nodata;
+        let res_x = subbasins.configs.resolution_x;
+        let res_y = subbasins.configs.resolution_y;
+
+        // make sure the input files have the same size
+        let mut inputs = vec![&dem, &pntr];
+        if let Some(ref r) = landcover {
+            inputs.push(r);
+        }
+        if let Some(ref r) = soils {
+            inputs.push(r);
+        }
+        for r in inputs {
+            if r.configs.rows as isize != rows || r.configs.columns as isize != columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+                ));
+            }
+        }
+
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+        }
+        let diag_length = (res_x * res_x + res_y * res_y).sqrt();
+        let lengths = [
+            diag_length,
+            res_x,
+            diag_length,
+            res_y,
+            diag_length,
+            res_x,
+            diag_length,
+            res_y,
+        ];
+
+        let basin_id = |row: isize, col: isize| -> Option<i64> {
+            let z = subbasins[(row, col)];
+            if z != nodata && z > 0f64 {
+                Some(z.round() as i64)
+            } else {
+                None
+            }
+        };
+
+        // the downstream neighbour of a cell, if it lies within the same subbasin
+        let receiver = |row: isize, col: isize| -> Option<usize> {
+            let dir = pntr[(row, col)];
+            if dir <= 0f64 || dir == pntr_nodata || dir > 128f64 {
+                return None;
+            }
+            let c = pntr_matches[dir as usize];
+            if c == 999 || basin_id(row + dy[c], col + dx[c]) != basin_id(row, col) {
+                return None;
+            }
+            Some(c)
+        };
+
+        // calculate the flow distance of each cell to the outlet of its subbasin and
+        // accumulate the subbasin statistics
+        let mut stats: HashMap<i64, SubbasinStats> = HashMap::new();
+        let mut flow_dist: Array2D<f64> = Array2D::new(rows, columns, -1f64, -1f64)?;
+        let mut path = vec![];
+        let mut n = [0f64; 8];
+        let (mut y, mut x): (isize, isize);
+        let (mut z, mut dist): (f64, f64);
+        let mut num_unmatched = 0usize;
+        for row in 0..rows {
+            for col in 0..columns {
+                let id = match basin_id(row, col) {
+                    Some(id) => id,
+                    None => continue,
+                };
+                if flow_dist[(row, col)] == -1f64 {
+                    path.clear();
+                    y = row;
+                    x = col;
+                    loop {
+                        if flow_dist[(y, x)] != -1f64 {
+                            dist = flow_dist[(y, x)];
+                            break;
+                        }
+                        path.push((y, x));
+                        match receiver(y, x) {
+                            Some(c) => {
+                                y += dy[c];
+                                x += dx[c];
+                            }
+                            None => {
+                                // the outlet of the subbasin
+                                dist = 0f64;
+                                path.pop();
+                                flow_dist[(y, x)] = 0f64;
+                                break;
+                            }
+                        }
+                    }
+                    for &(py, px) in path.iter().rev() {
+                        dist += lengths[receiver(py, px).unwrap()];
+                        flow_dist[(py, px)] = dist;
+                    }
+                }
+
+                let s = stats.entry(id).or_insert(SubbasinStats::new());
+                s.num_cells += 1;
+                if flow_dist[(row, col)] > s.flowpath_length || s.source.is_none() {
+                    s.flowpath_length = flow_dist[(row, col)];
+                    s.source = Some((row, col));
+                }
+                z = dem[(row, col)];
+                if z != dem_nodata {
+                    s.elev_sum += z;
+                    s.num_elevs += 1;
+                    s.elev_min = s.elev_min.min(z);
+                    s.elev_max = s.elev_max.max(z);
+                    for i in 0..8 {
+                        n[i] = dem[(row + dy[i], col + dx[i])];
+                        if n[i] == dem_nodata {
+                            n[i] = z;
+                        }
+                    }
+                    // Horn's method; the neighbours are NE, E, SE, S, SW, W, NW, N
+                    let fx = (n[0] + 2f64 * n[1] + n[2] - n[6] - 2f64 * n[5] - n[4])
+                        / (8f64 * res_x);
+                    let fy = (n[6] + 2f64 * n[7] + n[0] - n[4] - 2f64 * n[3] - n[2])
+                        / (8f64 * res_y);
+                    s.slope_sum += (fx * fx + fy * fy).sqrt() * 100f64;
+                }
+                if let Some(ref landcover) = landcover {
+                    let lc = landcover[(row, col)];
+                    if lc != landcover.configs.nodata {
+                        let soil = match soils {
+                            Some(ref soils) if soils[(row, col)] != soils.configs.nodata => {
+                                Some(soils[(row, col)])
+                            }
+                            _ => None,
+                        };
+                        match lookup_parameter(&lookup, lc, soil) {
+                            Some(v) => {
+                                s.param_sum += v;
+                                s.num_params += 1;
+                            }
+                            None => num_unmatched += 1,
+                        }
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Calculating subbasin parameters", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if stats.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The subbasins file does not contain any subbasins (positive, non-NoData values).",
+            ));
+        }
+        if verbose && num_unmatched > 0 {
+            report::warning(&format!(
+                "{} cells have land-cover and soil values that are missing from the lookup table.",
+                num_unmatched
+            ));
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let mut ids: Vec<i64> = stats.keys().cloned().collect();
+        ids.sort();
+        let has_param = landcover.is_some();
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+        let mut header = if format == "swat" {
+            "Subbasin,Area,Slo1,Len1,Csl,Elev,ElevMin,ElevMax".to_string()
+        } else {
+            "Name,Area,Slope,FlowpathLength,FlowpathSlope,Elev".to_string()
+        };
+        if has_param {
+            header.push_str(match (format == "swat", is_cn) {
+                (true, true) => ",CN2",
+                (true, false) => ",OV_N",
+                (false, true) => ",CurveNumber,LagTime",
+                (false, false) => ",Roughness",
+            });
+        }
+        writer.write_all(format!("{}\n", header).as_bytes())?;
+        for id in ids {
+            let s = &stats[&id];
+            let area = s.num_cells as f64 * res_x * res_y;
+            let (mean_elev, mean_slope) = if s.num_elevs > 0 {
+                (
+                    s.elev_sum / s.num_elevs as f64,
+                    s.slope_sum / s.num_elevs as f64,
+                )
+            } else {
+                (nodata, nodata)
+            };
+
+            // the slope of the longest flowpath, between its source and the outlet
+            let (mut row, mut col) = s.source.unwrap();
+            let source_z = dem[(row, col)];
+            while let Some(c) = receiver(row, col) {
+                row += dy[c];
+                col += dx[c];
+            }
+            let outlet_z = dem[(row, col)];
+            let flowpath_slope = if source_z == dem_nodata || outlet_z == dem_nodata {
+                nodata
+            } else if s.flowpath_length > 0f64 {
+                (source_z - outlet_z) / s.flowpath_length * 100f64
+            } else {
+                0f64
+            };
+
+            let mut line = if format == "swat" {
+                format!(
+                    "{},{:.4},{:.4},{:.2},{:.4},{:.2},{:.2},{:.2}",
+                    id,
+                    area / 10_000f64,
+                    mean_slope,
+                    s.flowpath_length,
+                    flowpath_slope,
+                    mean_elev,
+                    if s.num_elevs > 0 { s.elev_min } else { nodata },
+                    if s.num_elevs > 0 { s.elev_max } else { nodata }
+                )
+            } else {
+                format!(
+                    "Subbasin-{},{:.6},{:.4},{:.2},{:.4},{:.2}",
+                    id,
+                    area / 1_000_000f64,
+                    mean_slope,
+                    s.flowpath_length,
+                    flowpath_slope,
+                    mean_elev
+                )
+            };
+            if has_param {
+                if s.num_params > 0 {
+                    let param = s.param_sum / s.num_params as f64;
+                    line.push_str(&format!(",{:.4}", param));
+                    if format != "swat" && is_cn {
+                        // the SCS lag equation, with the flowpath length in feet, the
+                        // potential maximum retention in inches and the lag in hours
+                        let retention = 1000f64 / param - 10f64;
+                        let lag = if mean_slope > 0f64 && mean_slope != nodata {
+                            60f64 * (s.flowpath_length * 3.28084).powf(0.8)
+                                * (retention + 1f64).powf(0.7)
+                                / (1900f64 * mean_slope.sqrt())
+                        } else {
+                            nodata
+                        };
+                        line.push_str(&format!(",{:.2}", lag));
+                    }
+                } else {
+                    line.push_str(&format!(",{}", nodata));
+                    if format != "swat" && is_cn {
+                        line.push_str(&format!(",{}", nodata));
+                    }
+                }
+            }
+            writer.write_all(format!("{}\n", line).as_bytes())?;
+        }
+        writer.flush()?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::message("Output file written");
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+struct SubbasinStats {
+    num_cells: usize,
+    num_elevs: usize,
+    elev_sum: f64,
+    elev_min: f64,
+    elev_max: f64,
+    slope_sum: f64,
+    flowpath_length: f64,
+    source: Option<(isize, isize)>,
+    param_sum: f64,
+    num_params: usize,
+}
+
+impl SubbasinStats {
+    fn new() -> SubbasinStats {
+        SubbasinStats {
+            num_cells: 0,
+            num_elevs: 0,
+            elev_sum: 0f64,
+            elev_min: f64::INFINITY,
+            elev_max: f64::NEG_INFINITY,
+            slope_sum: 0f64,
+            flowpath_length: 0f64,
+            source: None,
+            param_sum: 0f64,
+            num_params: 0,
+        }
+    }
+}
+
+/// Reads a lookup table of parameter values for land-cover and soil combinations. Each
+/// line contains a land-cover value, an optional soil value, and a parameter value,
+/// separated by commas, semicolons, spaces, or tabs. Soil values may be hydrologic soil
+/// groups, A to D, which are read as 1 to 4. The records without a soil value are stored
+/// with a soil key of `None`. Lines that do not begin with a number are ignored.
+pub fn read_parameter_lookup(file_name: &str) -> Result<HashMap<(i64, Option<i64>), f64>, Error> {
+    let f = BufReader::new(File::open(file_name)?);
+    let mut lookup = HashMap::new();
+    for line in f.lines() {
+        let line = line?;
+        let v: Vec<&str> = line
+            .split(|c| c == ',' || c == ';' || c == ' ' || c == '\t')
+            .filter(|s| !s.is_empty())
+            .collect();
+        if v.is_empty() {
+            continue;
+        }
+        let landcover = match v[0].parse::<f64>() {
+            Ok(lc) => lc.round() as i64,
+            Err(_) => continue, // a header or comment line
+        };
+        let soil = match v.len() {
+            2 => None,
+            3 => Some(match v[1].to_uppercase().as_ref() {
+                "A" => 1,
+                "B" => 2,
+                "C" => 3,
+                "D" => 4,
+                s => match s.parse::<f64>() {
+                    Ok(soil) => soil.round() as i64,
+                    Err(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Unrecognized soil value in the lookup table: {}", line),
+                        ))
+                    }
+                },
+            }),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Lookup table records must contain two or three fields: {}", line),
+                ))
+            }
+        };
+        let value = match v[v.len() - 1].parse::<f64>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unrecognized parameter value in the lookup table: {}", line),
+                ))
+            }
+        };
+        lookup.insert((landcover, soil), value);
+    }
+    if lookup.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The lookup table does not contain any records.",
+        ));
+    }
+    Ok(lookup)
+}
+
+/// Returns the parameter value of a land-cover and soil combination from a lookup table
+/// read by `read_parameter_lookup`, falling back on the land-cover's record for all soils.
+pub fn lookup_parameter(
+    lookup: &HashMap<(i64, Option<i64>), f64>,
+    landcover: f64,
+    soil: Option<f64>,
+) -> Option<f64> {
+    let landcover = landcover.round() as i64;
+    if let Some(soil) = soil {
+        if let Some(&v) = lookup.get(&(landcover, Some(soil.round() as i64))) {
+            return Some(v);
+        }
+    }
+    lookup.get(&(landcover, None)).cloned()
+}
@@ -160,52 +160,25 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-seed_pts" || vec[0].to_lowercase() == "--seed_pts" {
-                if keyval {
-                    seed_file = vec[1].to_string();
-                } else {
-                    seed_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "--d8_pntr"
-                || vec[0].to_lowercase() == "-flow_dir"
-                || vec[0].to_lowercase() == "--flow_dir"
-            {
-                if keyval {
-                    flowdir_file = vec[1].to_string();
-                } else {
-                    flowdir_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-esri_pntr"
-                || vec[0].to_lowercase() == "--esri_pntr"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                esri_style = true;
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-            {
-                background_val = 0f64;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--seed_pts"])? {
+            seed_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--d8_pntr", "--flow_dir"])? {
+            flowdir_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            background_val = 0f64;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -227,25 +200,26 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         // let seeds = Raster::new(&seed_file, "r")?;
 
         if verbose {
-            println!("Reading flow direction data...")
+            report::message("Reading flow direction data...")
         };
         let flowdir = Raster::new(&flowdir_file, "r")?;
 
         // make sure the input files have the same size
         // if seeds.configs.rows != flowdir.configs.rows || seeds.configs.columns != flowdir.configs.columns {
         //     return Err(Error::new(ErrorKind::InvalidInput,
-        //                         "The input files must have the same number of rows and columns and spatial extent."));
+        //                         "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
         // }
 
         let start = Instant::now();
         let rows = flowdir.configs.rows as isize;
         let columns = flowdir.configs.columns as isize;
         let nodata = flowdir.configs.nodata;
-        if background_val == f64::NEG_INFINITY {
-            background_val = nodata;
-        }
 
         let mut output = Raster::initialize_using_file(&output_file, &flowdir);
+        let out_nodata = output.configs.nodata;
+        if background_val == f64::NEG_INFINITY {
+            background_val = out_nodata;
+        }
         output.reinitialize_values(background_val);
 
         let seeds = Shapefile::read(&seed_file)?;
@@ -269,7 +243,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                 progress =
                     (100.0_f64 * record_num as f64 / (seeds.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating seed points: {}%", progress);
+                    report::progress("Locating seed points", progress);
                     old_progress = progress;
                 }
             }
@@ -366,7 +340,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
             if verbose {
                 progress = (100.0_f64 * i as f64 / (seed_cols.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -375,13 +349,13 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         for row in 0..rows {
             for col in 0..columns {
                 if flowdir.get_value(row, col) == nodata {
-                    output.set_value(row, col, nodata);
+                    output.set_value(row, col, out_nodata);
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -403,19 +377,16 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,666 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Point2D;
+use tools::*;
+use vector::*;
+
+/// This tool traces the downslope flowpaths that originate at a set of seed points
+/// (`--seed_pts`) and outputs them as vector polylines (`--output`), one per seed point. It
+/// is useful for mapping the likely pathways of spills and other contaminants. The
+/// `TraceDownslopeFlowpaths` tool performs a similar operation but outputs a raster.
+///
+/// Flowpaths may be traced using either a D8 flow pointer raster or a D-infinity flow
+/// direction raster (`--pntr`), as specified by `--pntr_type`. D8 flowpaths connect the
+/// centres of the grid cells along the path. D-infinity flowpaths are traced as particle
+/// paths, i.e. the flowpath crosses each grid cell along the cell's flow direction, starting
+/// from the exact location of the seed point. Where the flow directions of neighbouring cells
+/// converge on their shared edge, as is common along valley bottoms, the particle moves along
+/// the edge. D-infinity flow direction rasters are created by the `DInfPointer` tool, and the
+/// `--esri_pntr` flag applies only to D8 pointers. A flowpath ends where it reaches a cell
+/// without a flow direction (e.g. a pit or the edge of the data), the edge of the raster,
+/// a flow loop, or the maximum trace length (`--max_length`), if specified.
+///
+/// The output polylines contain attributes for the seed point ID (`SEED_ID`), flowpath
+/// length (`LENGTH`), the elevations of the start and end of the flowpath (`START_Z` and
+/// `END_Z`), which are read from the input DEM (`--dem`), the elevation drop (`DROP`), and
+/// the travel time (`TIME`). The travel time is based on a flow velocity (`--velocity`),
+/// which may be either a raster or a constant value, in distance units per unit of time.
+/// Cells with velocities that are not positive do not contribute to the travel time.
+///
+/// # See Also
+/// `TraceDownslopeFlowpaths`, `D8Pointer`, `DInfPointer`, `DownslopeFlowpathLength`
+pub struct TraceFlowpathLines {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl TraceFlowpathLines {
+    pub fn new() -> TraceFlowpathLines {
+        // public constructor
+        let name = "TraceFlowpathLines".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Traces downslope flowpaths from seed points and outputs them as vector polylines."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Seed Points File".to_owned(),
+            flags: vec!["--seed_pts".to_owned()],
+            description: "Input vector seed points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Flow Direction File".to_owned(),
+            flags: vec!["--pntr".to_owned()],
+            description: "Input D8 pointer or D-infinity flow direction raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flow Direction Type".to_owned(),
+            flags: vec!["--pntr_type".to_owned()],
+            description: "Type of flow direction raster; one of 'd8' (default) and 'dinf'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec!["d8".to_owned(), "dinf".to_owned()]),
+            default_value: Some("d8".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flow Velocity File Or Constant Value".to_owned(),
+            flags: vec!["--velocity".to_owned()],
+            description: "Input flow velocity raster file or constant value, used for travel times."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector polyline file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Trace Length".to_owned(),
+            flags: vec!["--max_length".to_owned()],
+            description: "Optional maximum flowpath length, in distance units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --seed_pts=spills.shp --pntr=D8.tif --dem=DEM.tif -o=flowpaths.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --seed_pts=spills.shp --pntr=dinf.tif --pntr_type=dinf --dem=DEM.tif --velocity=velocity.tif --max_length=5000.0 -o=flowpaths.shp", short_exe, name).replace("*", &sep);
+
+        TraceFlowpathLines {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for TraceFlowpathLines {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut seed_file = String::new();
+        let mut pntr_file = String::new();
+        let mut dinf = false;
+        let mut dem_file = String::new();
+        let mut velocity = String::from("1.0");
+        let mut output_file = String::new();
+        let mut max_length = f64::INFINITY;
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--seed_pts"])? {
+            seed_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--pntr", "--d8_pntr", "--flow_dir"])? {
+            pntr_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--pntr_type"])? {
+            let val = v.to_lowercase();
+            dinf = val.contains("inf");
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--velocity"])? {
+            velocity = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_length"])? {
+            max_length = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !seed_file.contains(&sep) && !seed_file.contains("/") {
+            seed_file = format!("{}{}", working_directory, seed_file);
+        }
+        if !pntr_file.contains(&sep) && !pntr_file.contains("/") {
+            pntr_file = format!("{}{}", working_directory, pntr_file);
+        }
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        let mut velocity_constant = f64::NAN;
+        let velocity_is_constant = match velocity.parse::<f64>() {
+            Ok(val) => {
+                velocity_constant = val;
+                true
+            }
+            Err(_) => false,
+        };
+        if !velocity_is_constant && !velocity.contains(&sep) && !velocity.contains("/") {
+            velocity = format!("{}{}", working_directory, velocity);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let seeds = Shapefile::read(&seed_file)?;
+        let pntr = Raster::new(&pntr_file, "r")?;
+        let dem = Raster::new(&dem_file, "r")?;
+        let velocity_raster = if !velocity_is_constant {
+            Some(Raster::new(&velocity, "r")?)
+        } else {
+            None
+        };
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of points type
+        if seeds.header.shape_type.base_shape_type() != ShapeType::Point {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of point base shape type.",
+            ));
+        }
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let nodata = pntr.configs.nodata;
+        let dem_nodata = dem.configs.nodata;
+        if dem.configs.rows as isize != rows || dem.configs.columns as isize != columns {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+        if let Some(ref v) = velocity_raster {
+            if v.configs.rows as isize != rows || v.configs.columns as isize != columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+                ));
+            }
+        }
+
+        let west = pntr.configs.west;
+        let north = pntr.configs.north;
+        let res_x = pntr.configs.resolution_x;
+        let res_y = pntr.configs.resolution_y;
+        let tolerance = 1.0e-9 * (res_x + res_y);
+
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+        }
+
+        let get_velocity = |row: isize, col: isize| -> f64 {
+            match velocity_raster {
+                Some(ref v) => {
+                    let val = v.get_value(row, col);
+                    if val != v.configs.nodata {
+                        val
+                    } else {
+                        0f64
+                    }
+                }
+                None => velocity_constant,
+            }
+        };
+
+        // Returns the unit flow vector of a D-infinity cell, if it has a flow direction.
+        let flow_vector = |row: isize, col: isize| -> Option<(f64, f64)> {
+            if row < 0 || col < 0 || row >= rows || col >= columns {
+                return None;
+            }
+            let dir = pntr.get_value(row, col);
+            if dir == nodata || dir < 0f64 || dir > 360f64 {
+                return None;
+            }
+            let angle = dir.to_radians();
+            Some((angle.sin(), angle.cos()))
+        };
+
+        let mut output = Shapefile::new(&output_file, ShapeType::PolyLine)?;
+        output.projection = pntr.configs.coordinate_ref_system_wkt.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("SEED_ID", FieldDataType::Int, 7u8, 0u8));
+        output.attributes.add_field(&AttributeField::new(
+            "LENGTH",
+            FieldDataType::Real,
+            14u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "START_Z",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "END_Z",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "DROP",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "TIME",
+            FieldDataType::Real,
+            14u8,
+            4u8,
+        ));
+
+        let mut fid = 1i32;
+        let mut num_skipped = 0;
+        for record_num in 0..seeds.num_records {
+            let record = seeds.get_record(record_num);
+            let seed = record.points[0];
+            let mut row = pntr.get_row_from_y(seed.y);
+            let mut col = pntr.get_column_from_x(seed.x);
+            if row < 0 || col < 0 || row >= rows || col >= columns {
+                num_skipped += 1;
+                continue;
+            }
+            let start_z = dem.get_value(row, col);
+            let mut points = vec![seed];
+            let mut length = 0f64;
+            let mut time = 0f64;
+            // the number of times that each cell has been entered, used to detect flow loops
+            let mut visits: HashMap<(isize, isize), usize> = HashMap::new();
+            let mut p = seed;
+
+            // Adds a segment from p to q to the flowpath, returning false if the maximum
+            // trace length has been reached.
+            let add_segment = |p: Point2D,
+                                   q: Point2D,
+                                   row: isize,
+                                   col: isize,
+                                   points: &mut Vec<Point2D>,
+                                   length: &mut f64,
+                                   time: &mut f64|
+             -> bool {
+                let mut q = q;
+                let mut dist = p.distance(&q);
+                let mut within_max = true;
+                if *length + dist > max_length {
+                    let f = (max_length - *length) / dist;
+                    q = Point2D::new(p.x + f * (q.x - p.x), p.y + f * (q.y - p.y));
+                    dist = max_length - *length;
+                    within_max = false;
+                }
+                *length += dist;
+                let v = get_velocity(row, col);
+                if v > 0f64 {
+                    *time += dist / v;
+                }
+                points.push(q);
+                within_max
+            };
+
+            if !dinf {
+                loop {
+                    *visits.entry((row, col)).or_insert(0) += 1;
+                    if visits[&(row, col)] > 1 {
+                        break;
+                    }
+                    let dir = pntr.get_value(row, col);
+                    if dir == nodata || dir <= 0f64 || dir > 128f64 {
+                        break;
+                    }
+                    let n = pntr_matches[dir as usize];
+                    if n > 7 {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools.",
+                        ));
+                    }
+                    let (row_n, col_n) = (row + dy[n], col + dx[n]);
+                    if row_n < 0 || col_n < 0 || row_n >= rows || col_n >= columns {
+                        break;
+                    }
+                    let q = Point2D::new(
+                        pntr.get_x_from_column(col_n),
+                        pntr.get_y_from_row(row_n),
+                    );
+                    let within_max =
+                        add_segment(p, q, row, col, &mut points, &mut length, &mut time);
+                    p = q;
+                    row = row_n;
+                    col = col_n;
+                    if !within_max {
+                        break;
+                    }
+                }
+            } else {
+                let mut prev = (-1isize, -1isize);
+                loop {
+                    *visits.entry((row, col)).or_insert(0) += 1;
+                    if visits[&(row, col)] > 4 {
+                        break;
+                    }
+                    let (vx, vy) = match flow_vector(row, col) {
+                        Some(v) => v,
+                        None => break,
+                    };
+                    // the bounds of the current cell
+                    let x0 = west + col as f64 * res_x;
+                    let x1 = x0 + res_x;
+                    let y1 = north - row as f64 * res_y;
+                    let y0 = y1 - res_y;
+                    p.x = p.x.max(x0).min(x1);
+                    p.y = p.y.max(y0).min(y1);
+                    let tx = if vx > 1.0e-12 {
+                        (x1 - p.x) / vx
+                    } else if vx < -1.0e-12 {
+                        (x0 - p.x) / vx
+                    } else {
+                        f64::INFINITY
+                    };
+                    let ty = if vy > 1.0e-12 {
+                        (y1 - p.y) / vy
+                    } else if vy < -1.0e-12 {
+                        (y0 - p.y) / vy
+                    } else {
+                        f64::INFINITY
+                    };
+                    let t = tx.min(ty);
+                    let mut row_n = row;
+                    let mut col_n = col;
+                    if tx <= t + tolerance {
+                        col_n += if vx > 0f64 { 1 } else { -1 };
+                    }
+                    if ty <= t + tolerance {
+                        row_n += if vy > 0f64 { -1 } else { 1 };
+                    }
+
+                    if (row_n, col_n) == prev && (row_n == row || col_n == col) {
+                        // The flow directions of this cell and the previous cell converge
+                        // on their shared edge; move along the edge, in the direction of
+                        // their combined flow, to the next cell.
+                        let (ux, uy) = flow_vector(prev.0, prev.1).unwrap_or((0f64, 0f64));
+                        let (wx, wy) = (vx + ux, vy + uy);
+                        let q: Point2D;
+                        if col_n != col {
+                            // shared vertical edge
+                            if wy.abs() < 1.0e-12 {
+                                break;
+                            }
+                            let x_e = if col_n > col { x1 } else { x0 };
+                            q = Point2D::new(x_e, if wy > 0f64 { y1 } else { y0 });
+                            row_n = row + if wy > 0f64 { -1 } else { 1 };
+                            let next_col = if wx > 0f64 {
+                                col.max(prev.1)
+                            } else if wx < 0f64 {
+                                col.min(prev.1)
+                            } else {
+                                col
+                            };
+                            prev = (row, next_col);
+                            col_n = next_col;
+                        } else {
+                            // shared horizontal edge
+                            if wx.abs() < 1.0e-12 {
+                                break;
+                            }
+                            let y_e = if row_n < row { y1 } else { y0 };
+                            q = Point2D::new(if wx > 0f64 { x1 } else { x0 }, y_e);
+                            col_n = col + if wx > 0f64 { 1 } else { -1 };
+                            let next_row = if wy > 0f64 {
+                                row.min(prev.0)
+                            } else if wy < 0f64 {
+                                row.max(prev.0)
+                            } else {
+                                row
+                            };
+                            prev = (next_row, col);
+                            row_n = next_row;
+                        }
+                        let within_max =
+                            add_segment(p, q, row, col, &mut points, &mut length, &mut time);
+                        p = q;
+                        if !within_max {
+                            break;
+                        }
+                    } else {
+                        let q = Point2D::new(p.x + t * vx, p.y + t * vy);
+                        if t > tolerance {
+                            let within_max =
+                                add_segment(p, q, row, col, &mut points, &mut length, &mut time);
+                            if !within_max {
+                                break;
+                            }
+                        }
+                        p = q;
+                        prev = (row, col);
+                    }
+                    if row_n < 0 || col_n < 0 || row_n >= rows || col_n >= columns {
+                        break;
+                    }
+                    row = row_n;
+                    col = col_n;
+                }
+            }
+
+            if points.len() < 2 {
+                // the seed point is located in a cell without a flow direction
+                points.push(seed);
+            }
+            let end_z = dem.get_value(row, col);
+            let (start_z, end_z, drop) = if start_z != dem_nodata && end_z != dem_nodata {
+                (start_z, end_z, start_z - end_z)
+            } else {
+                (0f64, 0f64, 0f64)
+            };
+            let mut sfg = ShapefileGeometry::new(ShapeType::PolyLine);
+            sfg.add_part(&points);
+            output.add_record(sfg);
+            output.attributes.add_record(
+                vec![
+                    FieldData::Int(fid),
+                    FieldData::Int(record_num as i32 + 1),
+                    FieldData::Real(length),
+                    FieldData::Real(start_z),
+                    FieldData::Real(end_z),
+                    FieldData::Real(drop),
+                    FieldData::Real(time),
+                ],
+                false,
+            );
+            fid += 1;
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / seeds.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if num_skipped > 0 {
+            report::warning(&format!(
+                "{} seed points were located outside of the raster and were skipped.",
+                num_skipped
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
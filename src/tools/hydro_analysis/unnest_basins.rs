@@ -2,38 +2,68 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 27/04/2018
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
-
-HELP:
-In some applications it is necessary to relate a measured variable for a group of 
-hydrometric stations (e.g. characteristics of flow timing and duration or water 
-chemistry) to some characteristics of each outlet's catchment (e.g. mean slope, 
-area of wetlands, etc.). When the group of outlets are nested, i.e. some stations 
-are located downstream of others, then performing a watershed operation will 
-result in inappropriate watershed delineation. In particular, the delineated 
-watersheds of each nested outlet will not include the catchment areas of upstream 
-outlets. This creates a serious problem for this type of application.
-
-The Unnest Basin tool can be used to perform a watershedding operation based on a 
-group of specified pour points, i.e. outlets or target cells, such that each 
-complete watershed is delineated. The user must specify the name of a flow pointer 
-(flow direction) raster, a pour point raster, and the name of the output rasters. 
-Multiple numbered outputs will be created, one for each nesting level. Pour point, 
-or target, cells are denoted in the input pour-point image as any non-zero, 
-non-NoData value. The flow pointer raster should be generated using the D8 
-algorithm.
 */
 
+use super::basin_labelling::{d8_flow_directions, label_upslope_cells};
 use raster::*;
 use std::env;
 use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::Array2D;
 use tools::*;
 use vector::*;
 
+/// In some applications it is necessary to relate a measured variable for a group of
+/// hydrometric stations (e.g. characteristics of flow timing and duration or water
+/// chemistry) to some characteristics of each outlet's catchment (e.g. mean slope,
+/// area of wetlands, etc.). When the group of outlets are nested, i.e. some stations
+/// are located downstream of others, then performing a watershed operation will
+/// result in inappropriate watershed delineation. In particular, the delineated
+/// watersheds of each nested outlet will not include the catchment areas of upstream
+/// outlets. This creates a serious problem for this type of application.
+///
+/// The Unnest Basin tool can be used to perform a watershedding operation based on a
+/// group of specified pour points, i.e. outlets or target cells, such that each
+/// complete watershed is delineated. The user must specify the name of a flow pointer
+/// (flow direction) raster (`--d8_pntr`), a vector pour points file (`--pour_pts`), and
+/// the name of the output raster (`--output`). The flow pointer raster should be
+/// generated using the D8 algorithm. Pour points should be snapped to the stream network
+/// beforehand, e.g. using the `JensonSnapPourPoints` tool.
+///
+/// The nesting structure of the outlets is determined in a single pass. Every grid cell is
+/// first assigned to the nearest outlet downslope. This divides the landscape into
+/// the incremental drainage areas of the outlets and links each outlet to the next outlet
+/// downstream. Outlets without any outlets upstream are at nesting level 1, and every
+/// other outlet is one level above the highest of the outlets that drain to it. The
+/// complete watershed of every outlet at a nesting level is written to that level's
+/// output, with the outlets identified by their record numbers in the pour points file.
+///
+/// By default, multiple numbered outputs are created, one for each nesting level (e.g.
+/// `output_1.tif`, `output_2.tif`, etc.). Alternatively, the `--multiband` flag may be
+/// used to write the nesting levels as the bands of a single multi-band GeoTIFF. The
+/// outlet hierarchy can also be written to a CSV file (`--csv`), with one row per outlet:
+///
+/// - `OUTLET_ID`: the outlet's record number
+/// - `X`, `Y`: the outlet's coordinates
+/// - `NESTING_LEVEL`: the outlet's nesting level
+/// - `DOWNSTREAM_ID`: the next outlet downstream, or 0 if there is none
+/// - `NUM_UPSTREAM`: the number of outlets upstream
+/// - `NUM_CELLS`: the number of grid cells in the outlet's complete watershed
+/// - `AREA`: the area of the complete watershed, in map units
+/// - `INCREMENTAL_AREA`: the area draining to the outlet that is not upstream of any other
+///   outlet
+///
+/// Pour points that are located outside of the pointer raster or on NoData cells are
+/// ignored. Where several pour points fall in the same grid cell, only the last of them is
+/// used. Ignored points are listed in the CSV file with a nesting level of 0.
+///
+/// # See Also
+/// `Watershed`, `JensonSnapPourPoints`, `ImageStack`
 pub struct UnnestBasins {
     name: String,
     description: String,
@@ -88,6 +118,24 @@ impl UnnestBasins {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Write a single multi-band output?".to_owned(),
+            flags: vec!["--multiband".to_owned()],
+            description: "Optional flag to write the nesting levels as the bands of a single multi-band GeoTIFF.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Outlet Hierarchy CSV File (optional)".to_owned(),
+            flags: vec!["--csv".to_owned()],
+            description: "Optional output CSV file describing the outlet hierarchy.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -99,7 +147,8 @@ impl UnnestBasins {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr='d8pntr.tif' --pour_pts='pour_pts.shp' -o='output.tif'", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr='d8pntr.tif' --pour_pts='pour_pts.shp' -o='output.tif'
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr='d8pntr.tif' --pour_pts='pour_pts.shp' -o='output.tif' --multiband --csv='hierarchy.csv'", short_exe, name).replace("*", &sep);
 
         UnnestBasins {
             name: name,
@@ -149,6 +198,8 @@ impl WhiteboxTool for UnnestBasins {
         let mut pourpts_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
+        let mut multiband = false;
+        let mut csv_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -156,43 +207,28 @@ impl WhiteboxTool for UnnestBasins {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-d8_pntr" {
-                d8_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-pour_pts" {
-                pourpts_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--pour_pts"])? {
+            pourpts_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if tool_args.get_flag(&["--multiband"])? {
+            multiband = true;
+        }
+        if let Some(v) = tool_args.get_string(&["--csv"])? {
+            csv_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -209,11 +245,14 @@ impl WhiteboxTool for UnnestBasins {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !csv_file.is_empty() && !csv_file.contains(&sep) && !csv_file.contains("/") {
+            csv_file = format!("{}{}", working_directory, csv_file);
+        }
 
         let start = Instant::now();
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -230,248 +269,276 @@ impl WhiteboxTool for UnnestBasins {
 
         let rows = pntr.configs.rows as isize;
         let columns = pntr.configs.columns as isize;
-        let nodata = -32768f64; //pour_pts.configs.nodata;
+        let nodata = -32768f64;
         let pntr_nodata = pntr.configs.nodata;
+        let num_outlets = pourpts.num_records;
 
-        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
-        let mut outlet_points: Array2D<isize> = Array2D::new(rows, columns, 0, 0)?;
-        let mut outlet_rows = vec![0isize; pourpts.num_records + 1];
-        let mut outlet_columns = vec![0isize; pourpts.num_records + 1];
-        let mut nesting_order = vec![0usize; pourpts.num_records + 1];
-        let mut outlet: usize;
-
-        for record_num in 0..pourpts.num_records {
+        let mut output = Raster::initialize_using_file(&output_file, &pntr);
+        if multiband && output.raster_type != RasterType::GeoTiff {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A multi-band output (--multiband) must be a GeoTIFF (.tif) file.",
+            ));
+        }
+        output.configs.nodata = nodata;
+        output.configs.data_type = if num_outlets < 32767 {
+            DataType::I16
+        } else {
+            DataType::I32
+        };
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        output.configs.palette = "qual.pal".to_string();
+
+        let flow_dir = d8_flow_directions(&pntr, esri_style)?;
+
+        // Label each cell with the nearest outlet downslope, which divides the landscape into
+        // the incremental drainage areas of the outlets.
+        let mut nearest = Raster::initialize_using_file(&output_file, &pntr);
+        nearest.configs.nodata = nodata;
+        let low_value = f64::MIN;
+        nearest.reinitialize_values(low_value);
+        for row in 0..rows {
+            for col in 0..columns {
+                if pntr.get_value(row, col) == pntr_nodata {
+                    nearest.set_value(row, col, nodata);
+                }
+            }
+        }
+        let mut outlet_points = vec![(0f64, 0f64); num_outlets + 1];
+        let mut outlet_cells = vec![(-1isize, -1isize); num_outlets + 1];
+        for record_num in 0..num_outlets {
             let record = pourpts.get_record(record_num);
-            outlet = record_num + 1;
+            let outlet = record_num + 1;
+            outlet_points[outlet] = (record.points[0].x, record.points[0].y);
             let row = pntr.get_row_from_y(record.points[0].y);
             let col = pntr.get_column_from_x(record.points[0].x);
-            outlet_points.set_value(row, col, outlet as isize);
-            outlet_rows[outlet] = row;
-            outlet_columns[outlet] = col;
+            if flow_dir.get_value(row, col) != -2i8 {
+                nearest.set_value(row, col, outlet as f64);
+                outlet_cells[outlet] = (row, col);
+            }
 
             if verbose {
-                progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
+                progress = (100.0_f64 * outlet as f64 / num_outlets as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating pour points: {}%", progress);
+                    report::progress("Locating pour points", progress);
                     old_progress = progress;
                 }
             }
         }
-
-        // Create a mapping from the pointer values to cells offsets.
-        // This may seem wasteful, using only 8 of 129 values in the array,
-        // but the mapping method is far faster than calculating z.ln() / ln(2.0).
-        // It's also a good way of allowing for different point styles.
-        let mut pntr_matches: [i8; 129] = [0i8; 129];
-        if !esri_style {
-            // This maps Whitebox-style D8 pointer values
-            // onto the cell offsets in dx and dy.
-            pntr_matches[1] = 0i8;
-            pntr_matches[2] = 1i8;
-            pntr_matches[4] = 2i8;
-            pntr_matches[8] = 3i8;
-            pntr_matches[16] = 4i8;
-            pntr_matches[32] = 5i8;
-            pntr_matches[64] = 6i8;
-            pntr_matches[128] = 7i8;
-        } else {
-            // This maps Esri-style D8 pointer values
-            // onto the cell offsets in dx and dy.
-            pntr_matches[1] = 1i8;
-            pntr_matches[2] = 2i8;
-            pntr_matches[4] = 3i8;
-            pntr_matches[8] = 4i8;
-            pntr_matches[16] = 5i8;
-            pntr_matches[32] = 6i8;
-            pntr_matches[64] = 7i8;
-            pntr_matches[128] = 0i8;
+        let mut num_ignored = 0;
+        for outlet in 1..num_outlets + 1 {
+            let (row, col) = outlet_cells[outlet];
+            if row < 0 {
+                num_ignored += 1;
+            } else if nearest.get_value(row, col) != outlet as f64 {
+                // another pour point is located in the same cell
+                outlet_cells[outlet] = (-1, -1);
+                num_ignored += 1;
+            }
+        }
+        if verbose && num_ignored > 0 {
+            report::warning(&format!(
+                "{} pour points were ignored because they are outside of the pointer raster, on NoData cells, or share a cell with another pour point.",
+                num_ignored
+            ));
         }
 
+        label_upslope_cells(&flow_dir, &mut nearest, low_value, verbose);
+
+        // Find the next outlet downstream of each outlet, and the size of each
+        // incremental drainage area.
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut downstream = vec![0usize; num_outlets + 1];
+        let mut num_inflowing = vec![0usize; num_outlets + 1];
+        let mut incremental_cells = vec![0usize; num_outlets + 1];
         let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                z = pntr.get_value(row, col);
-                if z != pntr_nodata {
-                    if z > 0.0 {
-                        flow_dir.set_value(row, col, pntr_matches[z as usize]);
-                    } else {
-                        flow_dir.set_value(row, col, -1i8);
-                    }
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing: {}%", progress);
-                    old_progress = progress;
+                z = nearest.get_value(row, col);
+                if z != nodata {
+                    incremental_cells[z as usize] += 1;
                 }
             }
         }
-
-        // calculate the nesting order for each outlet point
-        let mut flag: bool;
-        let mut cur_order: usize;
-        let (mut x, mut y): (isize, isize);
         let mut dir: i8;
-        let mut max_nesting_order = 1;
-        for record_num in 0..pourpts.num_records {
-            outlet = record_num + 1;
-            cur_order = 1;
-            if nesting_order[outlet] < cur_order {
-                nesting_order[outlet] = cur_order;
-                flag = false;
-                y = outlet_rows[outlet];
-                x = outlet_columns[outlet];
-                while !flag {
-                    // find its downslope neighbour
-                    dir = flow_dir.get_value(y, x);
-                    if dir >= 0 {
-                        // move x and y accordingly
-                        x += dx[dir as usize];
-                        y += dy[dir as usize];
-                        if outlet_points.get_value(y, x) > 0 {
-                            outlet = outlet_points.get_value(y, x) as usize;
-                            cur_order += 1;
-                            if nesting_order[outlet] < cur_order {
-                                nesting_order[outlet] = cur_order;
-                                if cur_order > max_nesting_order {
-                                    max_nesting_order = cur_order;
-                                }
-                            } else {
-                                flag = true;
-                            }
-                        }
-                    } else {
-                        flag = true;
-                    }
-                }
+        for outlet in 1..num_outlets + 1 {
+            let (row, col) = outlet_cells[outlet];
+            if row < 0 {
+                continue;
             }
-            if verbose {
-                progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating outlet nesting order: {}%", progress);
-                    old_progress = progress;
+            dir = flow_dir.get_value(row, col);
+            if dir >= 0 {
+                z = nearest.get_value(row + dy[dir as usize], col + dx[dir as usize]);
+                if z != nodata && z as usize != outlet {
+                    downstream[outlet] = z as usize;
+                    num_inflowing[z as usize] += 1;
                 }
             }
         }
 
-        for order in 1..max_nesting_order + 1 {
-            let start2 = Instant::now();
-            // there will be an output file for each nesting order
-            let pos_of_dot = output_file.rfind('.').unwrap_or(0);
-            let ext = &output_file[pos_of_dot..];
-            let output_file_order = output_file.replace(ext, &format!("_{}{}", order, ext));
-
-            let mut output = Raster::initialize_using_file(&output_file_order, &pntr);
-            output.configs.nodata = nodata;
-            output.configs.data_type = DataType::I16;
-            output.configs.photometric_interp = PhotometricInterpretation::Categorical;
-            output.configs.palette = "qual.pal".to_string();
-            let low_value = f64::MIN;
-            output.reinitialize_values(low_value);
-
-            for outlet in 1..pourpts.num_records + 1 {
-                if nesting_order[outlet] == order {
-                    y = outlet_rows[outlet];
-                    x = outlet_columns[outlet];
-                    output.set_value(y, x, outlet as f64);
+        // Calculate the nesting level of each outlet, and the total size and number of
+        // outlets of its watershed, visiting the outlets in upstream-to-downstream order.
+        let mut level = vec![0usize; num_outlets + 1];
+        let mut total_cells = incremental_cells.clone();
+        let mut num_upstream = vec![0usize; num_outlets + 1];
+        let mut order = Vec::with_capacity(num_outlets);
+        let mut stack: Vec<usize> = (1..num_outlets + 1)
+            .filter(|&o| outlet_cells[o].0 >= 0 && num_inflowing[o] == 0)
+            .collect();
+        for &outlet in &stack {
+            level[outlet] = 1;
+        }
+        let mut max_level = 1;
+        while let Some(outlet) = stack.pop() {
+            order.push(outlet);
+            if level[outlet] > max_level {
+                max_level = level[outlet];
+            }
+            let d = downstream[outlet];
+            if d > 0 {
+                level[d] = level[d].max(level[outlet] + 1);
+                total_cells[d] += total_cells[outlet];
+                num_upstream[d] += num_upstream[outlet] + 1;
+                num_inflowing[d] -= 1;
+                if num_inflowing[d] == 0 {
+                    stack.push(d);
                 }
             }
+        }
+        if verbose {
+            report::metadata("Number of nesting levels", &max_level.to_string());
+        }
 
-            let mut outlet_id: f64;
-            for row in 0..rows {
-                for col in 0..columns {
-                    if flow_dir.get_value(row, col) == -2 {
-                        output.set_value(row, col, nodata);
-                    }
-                    if output.get_value(row, col) == low_value {
-                        flag = false;
-                        x = col;
-                        y = row;
-                        outlet_id = nodata;
-                        while !flag {
-                            // find its downslope neighbour
-                            dir = flow_dir.get_value(y, x);
-                            if dir >= 0 {
-                                // move x and y accordingly
-                                x += dx[dir as usize];
-                                y += dy[dir as usize];
-
-                                // if the new cell already has a value in the output, use that as the outletID
-                                z = output.get_value(y, x);
-                                if z != low_value {
-                                    outlet_id = z;
-                                    flag = true;
-                                }
-                            } else {
-                                flag = true;
-                            }
-                        }
-
-                        flag = false;
-                        x = col;
-                        y = row;
-                        output.set_value(y, x, outlet_id);
-                        while !flag {
-                            // find its downslope neighbour
-                            dir = flow_dir.get_value(y, x);
-                            if dir >= 0 {
-                                // move x and y accordingly
-                                x += dx[dir as usize];
-                                y += dy[dir as usize];
-
-                                // if the new cell already has a value in the output, use that as the outletID
-                                if output.get_value(y, x) != low_value {
-                                    flag = true;
-                                }
-                            } else {
-                                flag = true;
-                            }
-                            output.set_value(y, x, outlet_id);
+        // Each nesting level's output is created in a shared buffer. The watershed of an
+        // outlet at a level contains the incremental drainage areas of the outlets that
+        // drain to it, and the outlet that each area belongs to is found by visiting the
+        // outlets in downstream-to-upstream order.
+        let mut level_outlet = vec![0usize; num_outlets + 1];
+        let mut band = if multiband && max_level > 1 {
+            Some(Raster::initialize_using_file(&output_file, &output))
+        } else {
+            None
+        };
+        let pos_of_dot = output_file.rfind('.').unwrap_or(output_file.len());
+        let (stem, ext) = output_file.split_at(pos_of_dot);
+        for lvl in 1..max_level + 1 {
+            for &outlet in order.iter().rev() {
+                level_outlet[outlet] = if level[outlet] == lvl {
+                    outlet
+                } else if level[outlet] > lvl {
+                    0
+                } else {
+                    level_outlet[downstream[outlet]]
+                };
+            }
+            {
+                let buffer = match band {
+                    Some(ref mut b) if lvl > 1 => b,
+                    _ => &mut output,
+                };
+                for row in 0..rows {
+                    let mut data = vec![nodata; columns as usize];
+                    for col in 0..columns {
+                        z = nearest.get_value(row, col);
+                        if z != nodata && level_outlet[z as usize] > 0 {
+                            data[col as usize] = level_outlet[z as usize] as f64;
                         }
                     }
+                    buffer.set_row_data(row, data);
                 }
+            }
+
+            if multiband {
+                if lvl > 1 {
+                    output.add_band(band.as_ref().unwrap())?;
+                }
+            } else {
+                output.file_name = format!("{}_{}{}", stem, lvl, ext);
+                output.configs.metadata.clear();
+                output.add_metadata_entry(format!(
+                    "Created by whitebox_tools\' {} tool",
+                    self.get_tool_name()
+                ));
+                output.add_metadata_entry(format!("D8 pointer file: {}", d8_file));
+                output.add_metadata_entry(format!("Pour-points file: {}", pourpts_file));
+                output.add_metadata_entry(format!("Nesting level: {}", lvl));
+
                 if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Progress (Loop {} of {}): {}%",
-                            order, max_nesting_order, progress
-                        );
-                        old_progress = progress;
-                    }
+                    report::message(&format!("Saving data for nesting level {}...", lvl))
+                };
+                let _ = match output.write() {
+                    Ok(_) => if verbose {
+                        report::message("Output file written")
+                    },
+                    Err(e) => return Err(e),
+                };
+            }
+            if verbose {
+                progress = (100.0_f64 * lvl as f64 / max_level as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Creating nesting levels", progress);
+                    old_progress = progress;
                 }
             }
+        }
 
-            let elapsed_time2 = get_formatted_elapsed_time(start2);
+        if multiband {
+            output.configs.band_names = (1..max_level + 1)
+                .map(|lvl| format!("nesting_level_{}", lvl))
+                .collect();
             output.add_metadata_entry(format!(
                 "Created by whitebox_tools\' {} tool",
                 self.get_tool_name()
             ));
             output.add_metadata_entry(format!("D8 pointer file: {}", d8_file));
             output.add_metadata_entry(format!("Pour-points file: {}", pourpts_file));
-            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time2));
+            output.add_metadata_entry(format!("Number of nesting levels: {}", max_level));
 
             if verbose {
-                println!("Saving data for nesting order {}...", order)
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
         }
 
+        if !csv_file.is_empty() {
+            let cell_area = pntr.configs.resolution_x * pntr.configs.resolution_y;
+            let f = File::create(csv_file.clone())?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all(
+                "OUTLET_ID,X,Y,NESTING_LEVEL,DOWNSTREAM_ID,NUM_UPSTREAM,NUM_CELLS,AREA,INCREMENTAL_AREA\n"
+                    .as_bytes(),
+            )?;
+            for outlet in 1..num_outlets + 1 {
+                writer.write_all(
+                    format!(
+                        "{},{},{},{},{},{},{},{},{}\n",
+                        outlet,
+                        outlet_points[outlet].0,
+                        outlet_points[outlet].1,
+                        level[outlet],
+                        downstream[outlet],
+                        num_upstream[outlet],
+                        total_cells[outlet],
+                        total_cells[outlet] as f64 * cell_area,
+                        incremental_cells[outlet] as f64 * cell_area
+                    ).as_bytes(),
+                )?;
+            }
+            if verbose {
+                report::message("Outlet hierarchy file written")
+            };
+        }
+
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
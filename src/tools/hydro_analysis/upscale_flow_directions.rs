@@ -0,0 +1,646 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::basin_labelling::d8_flow_directions;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool aggregates a fine-resolution D8 flow pointer raster (`--d8_pntr`) to a coarser
+/// resolution while preserving the structure of the fine-scale drainage network. This is
+/// needed to derive the flow directions of large-scale hydrological and land-surface
+/// models, for which resampling a DEM and then calculating flow directions at the coarse
+/// resolution misrepresents the river network. Each coarse cell covers a block of
+/// `--factor` by `--factor` fine cells.
+///
+/// The outlet of each coarse cell is the fine cell within it that has the largest upslope
+/// area. The coarse flow direction is found by tracing the fine-scale flowpath downstream
+/// from the outlet. Two methods (`--method`) are available:
+///
+/// - `cotat` (the default): the *cell outlet tracing with an area threshold* method. The
+///   flowpath is traced until it reaches the outlet of another coarse cell, until the
+///   upslope area has increased by more than the area threshold after leaving the coarse
+///   cell, or until it leaves the 3 x 3 neighbourhood of coarse cells. The coarse cell is
+///   directed to the neighbour in which the trace ends. The area threshold
+///   (`--area_threshold`) is a proportion of the area of a coarse cell, and defaults to
+///   0.5. It prevents the flowpath of a small river from being captured by a neighbouring
+///   cell that it only clips.
+/// - `dmm`: the *double maximum method*. The coarse cell is directed to the neighbour that
+///   the flowpath enters first.
+///
+/// Because the upslope area increases along every fine-scale flowpath, and each coarse cell
+/// flows to a cell with a larger outlet upslope area, the coarse flow directions contain no
+/// cycles. Coarse cells whose outlets do not drain into a neighbouring coarse cell, e.g. at
+/// the edges of the data, are given a pointer value of zero.
+///
+/// The output coarse D8 pointer (`--output`) uses the same pointer scheme as the input
+/// (see `--esri_pntr`). Optionally, the upslope area of each coarse cell, accumulated along
+/// the coarse flow directions and measured in map units, may be output (`--out_accum`).
+/// The upslope areas at the coarse cells' outlets in the fine-scale network are used to
+/// check the consistency of the two networks. The mean absolute difference between them
+/// is reported as a percentage, for the coarse cells whose outlets drain at least ten
+/// coarse cells, i.e. along the rivers.
+///
+/// A coarse DEM may also be created (`--out_dem`) from an input fine-resolution DEM
+/// (`--dem`) by assigning each coarse cell the elevation of its outlet. Where a coarse cell
+/// drains to the outlet of its neighbour, as along the rivers, the coarse elevations
+/// therefore decrease downstream.
+///
+/// # Reference
+/// Reed, S. M. (2003). Deriving flow directions for coarse-resolution (1–4 km) gridded
+/// hydrologic modeling. *Water Resources Research*, 39(9).
+///
+/// Olivera, F., Lear, M. S., Famiglietti, J. S., and Asante, K. (2002). Extracting
+/// low-resolution river networks from high-resolution digital elevation models. *Water
+/// Resources Research*, 38(11).
+///
+/// # See Also
+/// `D8Pointer`, `D8FlowAccumulation`, `AggregateRaster`
+pub struct UpscaleFlowDirections {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl UpscaleFlowDirections {
+    pub fn new() -> UpscaleFlowDirections {
+        // public constructor
+        let name = "UpscaleFlowDirections".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Aggregates a D8 flow pointer to a coarser resolution while preserving the drainage network."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input fine-resolution raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output coarse-resolution raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Aggregation Factor (cells)".to_owned(),
+            flags: vec!["--factor".to_owned()],
+            description: "Number of fine cells along each side of a coarse cell.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("10".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Upscaling Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Upscaling method; options are 'cotat' and 'dmm'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "cotat".to_owned(),
+                "dmm".to_owned(),
+            ]),
+            default_value: Some("cotat".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Area Threshold (proportion of a coarse cell)".to_owned(),
+            flags: vec!["--area_threshold".to_owned()],
+            description: "Upslope area increase at which the COTAT method stops tracing, as a proportion of a coarse cell's area.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.5".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Upslope Area File (optional)".to_owned(),
+            flags: vec!["--out_accum".to_owned()],
+            description: "Optional output coarse-resolution upslope area raster file."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File (optional)".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Optional input fine-resolution raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output DEM File (optional)".to_owned(),
+            flags: vec!["--out_dem".to_owned()],
+            description: "Optional output coarse-resolution raster DEM file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=pntr.tif -o=coarse_pntr.tif --factor=10
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=pntr.tif -o=coarse_pntr.tif --factor=20 --method=cotat --area_threshold=0.5 --out_accum=coarse_area.tif --dem=DEM.tif --out_dem=coarse_dem.tif", short_exe, name).replace("*", &sep);
+
+        UpscaleFlowDirections {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for UpscaleFlowDirections {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut d8_file = String::new();
+        let mut output_file = String::new();
+        let mut factor = 10isize;
+        let mut method = "cotat".to_string();
+        let mut area_threshold = 0.5f64;
+        let mut esri_style = false;
+        let mut accum_file = String::new();
+        let mut dem_file = String::new();
+        let mut out_dem_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--factor"])? {
+            factor = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--area_threshold"])? {
+            area_threshold = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_accum"])? {
+            accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_dem"])? {
+            out_dem_file = v;
+        }
+        let use_cotat = if method.contains("cotat") {
+            true
+        } else if method.contains("dmm") {
+            false
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The upscaling method (--method) must be either 'cotat' or 'dmm'.",
+            ));
+        };
+        if factor < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The aggregation factor (--factor) must be at least 2.",
+            ));
+        }
+        if area_threshold < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The area threshold (--area_threshold) must not be negative.",
+            ));
+        }
+        if dem_file.is_empty() != out_dem_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "An input DEM (--dem) and an output DEM (--out_dem) must be specified together.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !accum_file.is_empty() && !accum_file.contains(&sep) && !accum_file.contains("/") {
+            accum_file = format!("{}{}", working_directory, accum_file);
+        }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !out_dem_file.is_empty() && !out_dem_file.contains(&sep) && !out_dem_file.contains("/")
+        {
+            out_dem_file = format!("{}{}", working_directory, out_dem_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let pntr = Raster::new(&d8_file, "r")?;
+        let dem = if !dem_file.is_empty() {
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns
+            {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent."));
+            }
+            Some(dem)
+        } else {
+            None
+        };
+
+        let start = Instant::now();
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let flow_dir = d8_flow_directions(&pntr, esri_style)?;
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
+
+        // calculate the fine-scale upslope areas, in grid cells
+        let mut accum: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut stack = vec![];
+        let (mut row_n, mut col_n): (isize, isize);
+        let mut dir: i8;
+        for row in 0..rows {
+            for col in 0..columns {
+                if flow_dir[(row, col)] == -2i8 {
+                    continue;
+                }
+                accum[(row, col)] = 1f64;
+                let mut count = 0i8;
+                for i in 0..8 {
+                    if flow_dir[(row + dy[i], col + dx[i])] == inflowing_vals[i] {
+                        count += 1;
+                    }
+                }
+                num_inflowing[(row, col)] = count;
+                if count == 0 {
+                    stack.push((row, col));
+                }
+            }
+        }
+        let mut num_solved = 0usize;
+        let num_cells = rows * columns;
+        while let Some((row, col)) = stack.pop() {
+            dir = flow_dir[(row, col)];
+            if dir >= 0 {
+                row_n = row + dy[dir as usize];
+                col_n = col + dx[dir as usize];
+                if flow_dir[(row_n, col_n)] != -2i8 {
+                    let fa = accum[(row, col)];
+                    accum.increment(row_n, col_n, fa);
+                    num_inflowing.decrement(row_n, col_n, 1i8);
+                    if num_inflowing[(row_n, col_n)] == 0i8 {
+                        stack.push((row_n, col_n));
+                    }
+                }
+            }
+            if verbose {
+                num_solved += 1;
+                progress = (100.0_f64 * num_solved as f64 / num_cells as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Flow accumulation", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // find the outlet of each coarse cell, i.e. its cell with the largest upslope area
+        let coarse_rows = (rows + factor - 1) / factor;
+        let coarse_columns = (columns + factor - 1) / factor;
+        let mut outlet: Array2D<isize> = Array2D::new(coarse_rows, coarse_columns, -1, -1)?;
+        let mut num_valid: Array2D<usize> = Array2D::new(coarse_rows, coarse_columns, 0, 0)?;
+        let mut max_accum: f64;
+        for cr in 0..coarse_rows {
+            for cc in 0..coarse_columns {
+                max_accum = 0f64;
+                for row in cr * factor..((cr + 1) * factor).min(rows) {
+                    for col in cc * factor..((cc + 1) * factor).min(columns) {
+                        if flow_dir[(row, col)] != -2i8 {
+                            num_valid.increment(cr, cc, 1);
+                            if accum[(row, col)] > max_accum {
+                                max_accum = accum[(row, col)];
+                                outlet[(cr, cc)] = row * columns + col;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Trace the flowpath downstream from each outlet to find the coarse flow direction,
+        // as an index into the offsets; -1 indicates that the coarse cell has no downslope
+        // neighbour.
+        let threshold = area_threshold * (factor * factor) as f64;
+        let mut coarse_dir: Array2D<i8> = Array2D::new(coarse_rows, coarse_columns, -1, -1)?;
+        let (mut row, mut col): (isize, isize);
+        let (mut br, mut bc): (isize, isize);
+        for cr in 0..coarse_rows {
+            for cc in 0..coarse_columns {
+                if outlet[(cr, cc)] < 0 {
+                    continue;
+                }
+                row = outlet[(cr, cc)] / columns;
+                col = outlet[(cr, cc)] % columns;
+                let outlet_accum = accum[(row, col)];
+                let mut target = (cr, cc);
+                loop {
+                    dir = flow_dir[(row, col)];
+                    if dir < 0 {
+                        break;
+                    }
+                    row += dy[dir as usize];
+                    col += dx[dir as usize];
+                    if flow_dir[(row, col)] == -2i8 {
+                        // the flowpath leaves the data
+                        break;
+                    }
+                    br = row / factor;
+                    bc = col / factor;
+                    if (br - cr).abs() > 1 || (bc - cc).abs() > 1 {
+                        break;
+                    }
+                    if (br, bc) != (cr, cc) {
+                        target = (br, bc);
+                        if !use_cotat
+                            || outlet[(br, bc)] == row * columns + col
+                            || accum[(row, col)] - outlet_accum > threshold
+                        {
+                            break;
+                        }
+                    }
+                }
+                if target != (cr, cc) {
+                    for i in 0..8 {
+                        if cc + dx[i] == target.1 && cr + dy[i] == target.0 {
+                            coarse_dir[(cr, cc)] = i as i8;
+                        }
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * (cr + 1) as f64 / coarse_rows as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Tracing outlets", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Accumulate the coarse upslope areas. Each coarse cell drains to a cell with a
+        // larger outlet upslope area, so visiting the cells in order of increasing outlet
+        // upslope area visits them in upstream-to-downstream order.
+        let cell_area = pntr.configs.resolution_x * pntr.configs.resolution_y;
+        let mut order = vec![];
+        for cr in 0..coarse_rows {
+            for cc in 0..coarse_columns {
+                if outlet[(cr, cc)] >= 0 {
+                    let o = outlet[(cr, cc)];
+                    order.push((accum[(o / columns, o % columns)], cr, cc));
+                }
+            }
+        }
+        order.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut coarse_area: Array2D<f64> =
+            Array2D::new(coarse_rows, coarse_columns, 0f64, 0f64)?;
+        for &(_, cr, cc) in &order {
+            coarse_area.increment(cr, cc, num_valid[(cr, cc)] as f64 * cell_area);
+            dir = coarse_dir[(cr, cc)];
+            if dir >= 0 {
+                let a = coarse_area[(cr, cc)];
+                coarse_area.increment(cr + dy[dir as usize], cc + dx[dir as usize], a);
+            }
+        }
+        // Compare the coarse and fine-scale upslope areas along the rivers, i.e. where the
+        // fine-scale outlet drains at least ten coarse cells.
+        let min_river_accum = 10f64 * (factor * factor) as f64;
+        let mut total_difference = 0f64;
+        let mut num_river_cells = 0;
+        for &(fine_accum, cr, cc) in &order {
+            if fine_accum >= min_river_accum {
+                total_difference += (coarse_area[(cr, cc)] - fine_accum * cell_area).abs()
+                    / (fine_accum * cell_area);
+                num_river_cells += 1;
+            }
+        }
+        let mean_difference = if num_river_cells > 0 {
+            100f64 * total_difference / num_river_cells as f64
+        } else {
+            0f64
+        };
+        if verbose {
+            report::metadata("Number of coarse river cells", &num_river_cells.to_string());
+            report::message(&format!(
+                "Mean absolute difference between the coarse and fine-scale upslope areas: {:.2}%",
+                mean_difference
+            ));
+        }
+
+        let mut configs = pntr.configs.clone();
+        configs.rows = coarse_rows as usize;
+        configs.columns = coarse_columns as usize;
+        configs.resolution_x = pntr.configs.resolution_x * factor as f64;
+        configs.resolution_y = pntr.configs.resolution_y * factor as f64;
+        configs.south = configs.north - configs.rows as f64 * configs.resolution_y;
+        configs.east = configs.west + configs.columns as f64 * configs.resolution_x;
+        configs.nodata = -32768f64;
+        configs.metadata.clear();
+
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+        output.configs.data_type = DataType::I16;
+        output.configs.palette = "qual.plt".to_string();
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        for cr in 0..coarse_rows {
+            for cc in 0..coarse_columns {
+                if outlet[(cr, cc)] >= 0 {
+                    dir = coarse_dir[(cr, cc)];
+                    output[(cr, cc)] = if dir < 0 {
+                        0f64
+                    } else if !esri_style {
+                        (1 << dir) as f64
+                    } else {
+                        (1 << ((dir + 7) % 8)) as f64
+                    };
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("D8 pointer file: {}", d8_file));
+        output.add_metadata_entry(format!("Aggregation factor: {}", factor));
+        output.add_metadata_entry(format!("Method: {}", if use_cotat { "cotat" } else { "dmm" }));
+        if use_cotat {
+            output.add_metadata_entry(format!("Area threshold: {}", area_threshold));
+        }
+        output.add_metadata_entry(format!(
+            "Mean absolute upslope area difference: {:.2}%",
+            mean_difference
+        ));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if !accum_file.is_empty() {
+            let mut output = Raster::initialize_using_config(&accum_file, &configs);
+            output.configs.data_type = DataType::F32;
+            output.configs.palette = "blueyellow.plt".to_string();
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            for &(_, cr, cc) in &order {
+                output[(cr, cc)] = coarse_area[(cr, cc)];
+            }
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("D8 pointer file: {}", d8_file));
+            output.add_metadata_entry(format!("Aggregation factor: {}", factor));
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Upslope area file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if let Some(ref dem) = dem {
+            let mut output = Raster::initialize_using_config(&out_dem_file, &configs);
+            output.configs.data_type = DataType::F32;
+            output.configs.palette = dem.configs.palette.clone();
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            let dem_nodata = dem.configs.nodata;
+            for &(_, cr, cc) in &order {
+                let o = outlet[(cr, cc)];
+                let z = dem[(o / columns, o % columns)];
+                if z != dem_nodata {
+                    output[(cr, cc)] = z;
+                }
+            }
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("DEM file: {}", dem_file));
+            output.add_metadata_entry(format!("Aggregation factor: {}", factor));
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("DEM file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
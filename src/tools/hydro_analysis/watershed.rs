@@ -2,16 +2,16 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 22, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::basin_labelling::{d8_flow_directions, label_upslope_cells};
 use raster::*;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
-use structures::Array2D;
 use tools::*;
 use vector::*;
 
@@ -139,43 +139,22 @@ impl WhiteboxTool for Watershed {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-d8_pntr" {
-                d8_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-pour_pts" {
-                pourpts_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--pour_pts"])? {
+            pourpts_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -194,7 +173,7 @@ impl WhiteboxTool for Watershed {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -221,13 +200,10 @@ impl WhiteboxTool for Watershed {
         // // make sure the input files have the same size
         // if pourpts.configs.rows != pntr.configs.rows || pourpts.configs.columns != pntr.configs.columns {
         //     return Err(Error::new(ErrorKind::InvalidInput,
-        //                         "The input files must have the same number of rows and columns and spatial extent."));
+        //                         "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
         // }
 
-        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-
-        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
+        let flow_dir = d8_flow_directions(&pntr, esri_style)?;
         let mut output = Raster::initialize_using_file(&output_file, &pntr);
         output.configs.nodata = nodata;
         output.configs.data_type = DataType::I16;
@@ -246,129 +222,28 @@ impl WhiteboxTool for Watershed {
                 progress =
                     (100.0_f64 * record_num as f64 / (pourpts.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating pour points: {}%", progress);
+                    report::progress("Locating pour points", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        // Create a mapping from the pointer values to cells offsets.
-        // This may seem wasteful, using only 8 of 129 values in the array,
-        // but the mapping method is far faster than calculating z.ln() / ln(2.0).
-        // It's also a good way of allowing for different point styles.
-        let mut pntr_matches: [i8; 129] = [0i8; 129];
-        if !esri_style {
-            // This maps Whitebox-style D8 pointer values
-            // onto the cell offsets in dx and dy.
-            pntr_matches[1] = 0i8;
-            pntr_matches[2] = 1i8;
-            pntr_matches[4] = 2i8;
-            pntr_matches[8] = 3i8;
-            pntr_matches[16] = 4i8;
-            pntr_matches[32] = 5i8;
-            pntr_matches[64] = 6i8;
-            pntr_matches[128] = 7i8;
-        } else {
-            // This maps Esri-style D8 pointer values
-            // onto the cell offsets in dx and dy.
-            pntr_matches[1] = 1i8;
-            pntr_matches[2] = 2i8;
-            pntr_matches[4] = 3i8;
-            pntr_matches[8] = 4i8;
-            pntr_matches[16] = 5i8;
-            pntr_matches[32] = 6i8;
-            pntr_matches[64] = 7i8;
-            pntr_matches[128] = 0i8;
-        }
-
-        let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                z = pntr[(row, col)];
-                if z != pntr_nodata {
-                    if z > 0.0 {
-                        flow_dir[(row, col)] = pntr_matches[z as usize];
-                    } else {
-                        flow_dir[(row, col)] = -1i8;
-                    }
-                } else {
+                if pntr[(row, col)] == pntr_nodata {
                     output[(row, col)] = nodata;
                 }
-                // z = pourpts[(row, col)];
-                // if z != nodata && z > 0.0 {
-                //     output[(row, col)] = z;
-                // }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing: {}%", progress);
+                    report::progress("Initializing", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        let mut flag: bool;
-        let (mut x, mut y): (isize, isize);
-        let mut dir: i8;
-        let mut outlet_id: f64;
-        for row in 0..rows {
-            for col in 0..columns {
-                if output[(row, col)] == low_value {
-                    flag = false;
-                    x = col;
-                    y = row;
-                    outlet_id = nodata;
-                    while !flag {
-                        // find its downslope neighbour
-                        dir = flow_dir[(y, x)];
-                        if dir >= 0 {
-                            // move x and y accordingly
-                            x += dx[dir as usize];
-                            y += dy[dir as usize];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
-                            if z != low_value {
-                                outlet_id = z;
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                    }
-
-                    flag = false;
-                    x = col;
-                    y = row;
-                    output[(y, x)] = outlet_id;
-                    while !flag {
-                        // find its downslope neighbour
-                        dir = flow_dir[(y, x)];
-                        if dir >= 0 {
-                            // move x and y accordingly
-                            x += dx[dir as usize];
-                            y += dy[dir as usize];
-
-                            // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
-                                flag = true;
-                            }
-                        } else {
-                            flag = true;
-                        }
-                        output[(y, x)] = outlet_id;
-                    }
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        label_upslope_cells(&flow_dir, &mut output, low_value, verbose);
 
         let elapsed_time = get_formatted_elapsed_time(start);
         output.add_metadata_entry(format!(
@@ -380,19 +255,16 @@ impl WhiteboxTool for Watershed {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,620 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool routes a load, e.g. sediment, nutrients, or snow melt, downslope over a digital
+/// elevation model (DEM; `--dem`) and calculates the load that is delivered out of each grid
+/// cell. The load generated in each cell is specified by the input loading raster
+/// (`--loading`). As the load passes through a cell, a fraction of it may be retained
+/// (i.e. lost from the flow), as specified by the optional retention raster (`--retention`).
+/// Retention values should be proportions between 0 and 1; if the raster's maximum value is
+/// greater than 1, the values are assumed to be percentages. In addition, the load may decay
+/// exponentially with travel distance (`--decay`), such that the fraction of the load that
+/// survives the flow between two neighbouring cells that are a distance *L* apart is
+/// *exp(-k L)*, where *k* is the decay coefficient in units of inverse distance.
+///
+/// The flow may be routed using the D8, D-infinity (Tarboton, 1997), or FD8 (Quinn et al., 1995)
+/// flow algorithms (`--method`); the FD8 `--exponent` parameter controls the degree of flow
+/// dispersion. The output raster (`--output`) contains the load delivered out of each cell,
+/// i.e. the cell's own load plus its inflowing load, less the amount retained in the cell.
+/// The load that is retained within each cell, including the load that is lost to decay
+/// on its way to the downslope neighbours, can optionally be output (`--out_retained`).
+/// The ratio of the delivered load to the accumulated total load in the absence of losses
+/// is a sediment delivery ratio.
+///
+/// With no retention and no decay, the output is equivalent to a flow accumulation weighted
+/// by the loading raster. This tool generalizes the `D8MassFlux` and `DInfMassFlux` tools,
+/// which use absolute absorption values rather than proportional retention. Like other flow
+/// accumulation tools, this tool should be applied to a DEM that has been hydrologically
+/// conditioned to remove depressions.
+///
+/// # References
+/// Quinn, P. F., K. J. Beven, Lamb, R. 1995. The in (a/tanβ) index: How to calculate it and
+/// how to use it within the topmodel framework. *Hydrological Processes* 9(2): 161-182.
+///
+/// Tarboton, D. G. (1997), A new method for the determination of flow directions and upslope
+/// areas in grid digital elevation models, *Water Resources Research*, 33(2), 309–319.
+///
+/// # See Also
+/// `D8MassFlux`, `DInfMassFlux`, `D8FlowAccumulation`, `DInfFlowAccumulation`, `FD8FlowAccumulation`
+pub struct WeightedFlowAccumulation {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl WeightedFlowAccumulation {
+    pub fn new() -> WeightedFlowAccumulation {
+        // public constructor
+        let name = "WeightedFlowAccumulation".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description = "Routes a load downslope using D8, D-infinity, or FD8 flow, with proportional retention and distance decay.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Loading File".to_owned(),
+            flags: vec!["--loading".to_owned()],
+            description: "Input loading raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Retention File".to_owned(),
+            flags: vec!["--retention".to_owned()],
+            description: "Optional input raster file of the proportion of load retained in each cell.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file of delivered load.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Retained Load File".to_owned(),
+            flags: vec!["--out_retained".to_owned()],
+            description: "Optional output raster file of retained load.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flow Algorithm".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Flow routing algorithm; one of 'd8' (default), 'dinf', and 'fd8'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "d8".to_owned(),
+                "dinf".to_owned(),
+                "fd8".to_owned(),
+            ]),
+            default_value: Some("d8".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "FD8 Exponent".to_owned(),
+            flags: vec!["--exponent".to_owned()],
+            description: "Optional exponent parameter for the FD8 method; default is 1.1."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.1".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Decay Coefficient".to_owned(),
+            flags: vec!["--decay".to_owned()],
+            description: "Distance decay coefficient, in inverse distance units; default is 0.0."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --loading=load.tif -o=output.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --loading=load.tif --retention=trap.tif -o=output.tif --out_retained=deposition.tif --method=fd8 --exponent=1.5 --decay=0.001", short_exe, name).replace("*", &sep);
+
+        WeightedFlowAccumulation {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for WeightedFlowAccumulation {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut dem_file = String::new();
+        let mut loading_file = String::new();
+        let mut retention_file = String::new();
+        let mut output_file = String::new();
+        let mut retained_file = String::new();
+        let mut method = String::from("d8");
+        let mut exponent = 1.1f64;
+        let mut decay = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--loading"])? {
+            loading_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--retention"])? {
+            retention_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_retained"])? {
+            retained_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            let val = v.to_lowercase();
+            method = if val.contains("inf") {
+                "dinf".to_string()
+            } else if val.contains("fd8") || val.contains("mfd") {
+                "fd8".to_string()
+            } else {
+                "d8".to_string()
+            };
+        }
+        if let Some(v) = tool_args.get_f64(&["--exponent"])? {
+            exponent = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--decay"])? {
+            decay = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !loading_file.contains(&sep) && !loading_file.contains("/") {
+            loading_file = format!("{}{}", working_directory, loading_file);
+        }
+        if !retention_file.is_empty()
+            && !retention_file.contains(&sep)
+            && !retention_file.contains("/")
+        {
+            retention_file = format!("{}{}", working_directory, retention_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !retained_file.is_empty()
+            && !retained_file.contains(&sep)
+            && !retained_file.contains("/")
+        {
+            retained_file = format!("{}{}", working_directory, retained_file);
+        }
+        if decay < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The decay coefficient must not be negative.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&dem_file, "r")?;
+        let loading = Raster::new(&loading_file, "r")?;
+
+        let start = Instant::now();
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let num_cells = rows * columns;
+        let nodata = input.configs.nodata;
+        let load_nodata = loading.configs.nodata;
+        let cell_size_x = input.configs.resolution_x;
+        let cell_size_y = input.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+
+        if loading.configs.rows as isize != rows || loading.configs.columns as isize != columns {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
+        }
+
+        // The retention proportions; a raster with values greater than 1 is assumed to
+        // contain percentages.
+        let mut retention: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        if !retention_file.is_empty() {
+            let ret = Raster::new(&retention_file, "r")?;
+            if ret.configs.rows as isize != rows || ret.configs.columns as isize != columns {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                    "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
+            }
+            let multiplier = if ret.configs.maximum > 1f64 {
+                0.01f64
+            } else {
+                1f64
+            };
+            let ret_nodata = ret.configs.nodata;
+            let mut r: f64;
+            for row in 0..rows {
+                for col in 0..columns {
+                    r = ret.get_value(row, col);
+                    if r != ret_nodata {
+                        r *= multiplier;
+                        retention.set_value(row, col, r.max(0f64).min(1f64));
+                    }
+                }
+            }
+        }
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+        // the proportion of load that survives the flow to each neighbour
+        let mut transmission = [1f64; 8];
+        for i in 0..8 {
+            transmission[i] = (-decay * grid_lengths[i]).exp();
+        }
+
+        // D-infinity flow directions, in degrees clockwise from north
+        let mut dinf_dir: Array2D<f64> = Array2D::new(rows, columns, -1f64, -1f64)?;
+        if method == "dinf" {
+            let grid_res = (cell_size_x + cell_size_y) / 2.0;
+            let ac_vals = [0f64, 1f64, 1f64, 2f64, 2f64, 3f64, 3f64, 4f64];
+            let af_vals = [1f64, -1f64, 1f64, -1f64, 1f64, -1f64, 1f64, -1f64];
+            let e1_col = [1, 0, 0, -1, -1, 0, 0, 1];
+            let e1_row = [0, -1, -1, 0, 0, 1, 1, 0];
+            let e2_col = [1, 1, -1, -1, -1, -1, 1, 1];
+            let e2_row = [-1, -1, -1, -1, 1, 1, 1, 1];
+            let atanof1 = 1.0f64.atan();
+            let (mut e0, mut e1, mut e2, mut r, mut s, mut s1, mut s2): (
+                f64,
+                f64,
+                f64,
+                f64,
+                f64,
+                f64,
+                f64,
+            );
+            let (mut dir, mut max_slope): (f64, f64);
+            for row in 0..rows {
+                for col in 0..columns {
+                    e0 = input.get_value(row, col);
+                    if e0 == nodata {
+                        continue;
+                    }
+                    dir = 360.0;
+                    max_slope = f64::MIN;
+                    for i in 0..8 {
+                        e1 = input.get_value(row + e1_row[i], col + e1_col[i]);
+                        e2 = input.get_value(row + e2_row[i], col + e2_col[i]);
+                        if e1 == nodata || e2 == nodata {
+                            continue;
+                        }
+                        if e0 > e1 && e0 > e2 {
+                            s1 = (e0 - e1) / grid_res;
+                            s2 = (e1 - e2) / grid_res;
+                            r = (s2 / s1).atan();
+                            s = (s1 * s1 + s2 * s2).sqrt();
+                            if r < 0.0 {
+                                r = 0.0;
+                                s = s1;
+                            } else if r > atanof1 {
+                                r = atanof1;
+                                s = (e0 - e2) / diag_cell_size;
+                            }
+                        } else if e0 > e1 {
+                            r = 0.0;
+                            s = (e0 - e1) / grid_res;
+                        } else if e0 > e2 {
+                            r = atanof1;
+                            s = (e0 - e2) / diag_cell_size;
+                        } else {
+                            continue;
+                        }
+                        if s >= max_slope {
+                            max_slope = s;
+                            dir = af_vals[i] * r + ac_vals[i] * (PI / 2.0);
+                        }
+                    }
+                    if max_slope > 0f64 {
+                        dir = 360.0 - dir.to_degrees() + 90.0;
+                        if dir >= 360.0 {
+                            dir -= 360.0;
+                        }
+                        dinf_dir.set_value(row, col, dir);
+                    }
+                }
+                if verbose {
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Flow directions", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+        }
+
+        // Returns the proportion of the flow from a cell that goes to each neighbour.
+        let get_proportions = |row: isize, col: isize| -> [f64; 8] {
+            let mut p = [0f64; 8];
+            let z = input.get_value(row, col);
+            if z == nodata {
+                return p;
+            }
+            if method == "dinf" {
+                let dir = dinf_dir.get_value(row, col);
+                if dir >= 0f64 {
+                    // the neighbour at k x 45 degrees is found at index (k + 7) % 8
+                    let k = ((dir / 45f64).floor() as usize).min(7);
+                    p[(k + 7) % 8] = ((k + 1) as f64 * 45f64 - dir) / 45f64;
+                    p[k % 8] += (dir - k as f64 * 45f64) / 45f64;
+                }
+            } else if method == "fd8" {
+                let mut total_weights = 0f64;
+                for i in 0..8 {
+                    let z_n = input.get_value(row + d_y[i], col + d_x[i]);
+                    if z_n != nodata && z_n < z {
+                        p[i] = (z - z_n).powf(exponent);
+                        total_weights += p[i];
+                    }
+                }
+                if total_weights > 0f64 {
+                    for i in 0..8 {
+                        p[i] /= total_weights;
+                    }
+                }
+            } else {
+                let mut dir = 8;
+                let mut max_slope = 0f64;
+                for i in 0..8 {
+                    let z_n = input.get_value(row + d_y[i], col + d_x[i]);
+                    if z_n != nodata && (z - z_n) / grid_lengths[i] > max_slope {
+                        max_slope = (z - z_n) / grid_lengths[i];
+                        dir = i;
+                    }
+                }
+                if dir < 8 {
+                    p[dir] = 1f64;
+                }
+            }
+            p
+        };
+
+        // calculate the number of inflowing cells
+        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+        let mut stack = Vec::with_capacity((rows * columns) as usize);
+        let mut num_solved_cells = 0usize;
+        for row in 0..rows {
+            for col in 0..columns {
+                if input.get_value(row, col) != nodata {
+                    if num_inflowing.get_value(row, col) == -1 {
+                        num_inflowing.set_value(row, col, 0);
+                    }
+                    let p = get_proportions(row, col);
+                    for i in 0..8 {
+                        if p[i] > 0f64 {
+                            let (row_n, col_n) = (row + d_y[i], col + d_x[i]);
+                            if num_inflowing.get_value(row_n, col_n) == -1 {
+                                num_inflowing.set_value(row_n, col_n, 0);
+                            }
+                            num_inflowing.increment(row_n, col_n, 1);
+                        }
+                    }
+                } else {
+                    num_solved_cells += 1;
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Num. inflowing neighbours", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        for row in 0..rows {
+            for col in 0..columns {
+                if input.get_value(row, col) != nodata && num_inflowing.get_value(row, col) == 0 {
+                    stack.push((row, col));
+                }
+            }
+        }
+
+        let mut inflow: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        let mut retained_load: Array2D<f64> = Array2D::new(rows, columns, out_nodata, out_nodata)?;
+        let (mut load, mut total, mut retained, mut delivered, mut transmitted): (
+            f64,
+            f64,
+            f64,
+            f64,
+            f64,
+        );
+        while let Some((row, col)) = stack.pop() {
+            load = loading.get_value(row, col);
+            if load == load_nodata {
+                load = 0f64;
+            }
+            total = load + inflow.get_value(row, col);
+            retained = total * retention.get_value(row, col);
+            delivered = total - retained;
+            output.set_value(row, col, delivered);
+            let p = get_proportions(row, col);
+            for i in 0..8 {
+                if p[i] > 0f64 {
+                    let (row_n, col_n) = (row + d_y[i], col + d_x[i]);
+                    transmitted = delivered * p[i] * transmission[i];
+                    retained += delivered * p[i] - transmitted;
+                    inflow.increment(row_n, col_n, transmitted);
+                    num_inflowing.decrement(row_n, col_n, 1);
+                    if num_inflowing.get_value(row_n, col_n) == 0 {
+                        stack.push((row_n, col_n));
+                    }
+                }
+            }
+            retained_load.set_value(row, col, retained);
+
+            if verbose {
+                num_solved_cells += 1;
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Flow accumulation", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        let mut outputs = vec![output];
+        if !retained_file.is_empty() {
+            let mut output_retained = Raster::initialize_using_file(&retained_file, &input);
+            for row in 0..rows {
+                output_retained.set_row_data(row, retained_load.get_row_data(row));
+            }
+            outputs.push(output_retained);
+        }
+        for out in outputs.iter_mut() {
+            out.configs.palette = "blueyellow.plt".to_string();
+            out.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            out.configs.data_type = DataType::F32;
+            out.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            out.add_metadata_entry(format!("Input DEM file: {}", dem_file));
+            out.add_metadata_entry(format!("Input loading file: {}", loading_file));
+            if !retention_file.is_empty() {
+                out.add_metadata_entry(format!("Input retention file: {}", retention_file));
+            }
+            out.add_metadata_entry(format!("Method: {}", method));
+            if method == "fd8" {
+                out.add_metadata_entry(format!("Exponent: {}", exponent));
+            }
+            out.add_metadata_entry(format!("Decay coefficient: {}", decay));
+            out.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match out.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
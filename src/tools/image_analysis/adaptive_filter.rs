@@ -149,61 +149,29 @@ impl WhiteboxTool for AdaptiveFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if vec[0].to_lowercase() == "-filterx" || vec[0].to_lowercase() == "--filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-filtery" || vec[0].to_lowercase() == "--filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-threshold"
-                || vec[0].to_lowercase() == "--threshold"
-            {
-                if keyval {
-                    threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--threshold"])? {
+            threshold = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -236,7 +204,7 @@ impl WhiteboxTool for AdaptiveFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -255,6 +223,8 @@ impl WhiteboxTool for AdaptiveFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let min_val = if !is_rgb_image {
             input.configs.minimum
@@ -314,7 +284,7 @@ impl WhiteboxTool for AdaptiveFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating integral images: {}%", progress);
+                    report::progress("Calculating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -323,7 +293,6 @@ impl WhiteboxTool for AdaptiveFilter {
         let i = Arc::new(integral); // wrap integral in an Arc
         let i2 = Arc::new(integral2); // wrap integral2 in an Arc
         let i_n = Arc::new(integral_n); // wrap integral_n in an Arc
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
@@ -380,7 +349,7 @@ impl WhiteboxTool for AdaptiveFilter {
                     if y2 >= rows {
                         y2 = rows - 1;
                     }
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input_fn(row, col);
                         if z != nodata {
@@ -432,7 +401,7 @@ impl WhiteboxTool for AdaptiveFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -450,22 +419,19 @@ impl WhiteboxTool for AdaptiveFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,298 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::byte_stretch::{read_bands, stretch_to_bytes};
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool applies a user-defined contrast stretch, or look-up table (LUT), to an image
+/// and writes the result as an 8-bit raster that is ready for display. The LUT is read from
+/// a text file (`--lut`) of breakpoints, one per line, each consisting of an input value and
+/// the output value (0 to 255) that it is mapped to, separated by a comma, space, or tab.
+/// Blank lines and lines starting with '#' are ignored. For example, the following LUT
+/// brightens the dark values of an image with values from 0 to 1000:
+///
+/// ```text
+/// # input, output
+/// 0, 0
+/// 200, 160
+/// 1000, 255
+/// ```
+///
+/// Values between two breakpoints are interpolated linearly between the breakpoints' output
+/// values, unless the `--discrete` flag is specified, in which case each value is assigned
+/// the output value of the nearest breakpoint with an input value no greater than its own,
+/// which is useful for assigning classes of values to tones. Values outside of the range of
+/// the breakpoints are assigned the output value of the first or last breakpoint.
+///
+/// Nodata cells are assigned a value of 0, which is the output's nodata value, and valid
+/// cells that are mapped to 0 are therefore assigned 1 instead. Each band of a multi-band
+/// GeoTIFF is mapped using the same LUT, and the output has the same number of bands.
+///
+/// # See Also
+/// `ByteContrastStretch`, `Reclass`
+pub struct ApplyLookupTable {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ApplyLookupTable {
+    pub fn new() -> ApplyLookupTable {
+        // public constructor
+        let name = "ApplyLookupTable".to_string();
+        let toolbox = "Image Processing Tools/Image Enhancement".to_string();
+        let description =
+            "Applies a look-up table of breakpoints to an image, writing an 8-bit display raster."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Look-Up Table File".to_owned(),
+            flags: vec!["--lut".to_owned()],
+            description: "Input text file of breakpoints, each an input value and an output value from 0 to 255.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Text),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Use discrete (stepped) values?".to_owned(),
+            flags: vec!["--discrete".to_owned()],
+            description: "Assign the output value of the breakpoint below each value, rather than interpolating.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif --lut=stretch.txt -o=output.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif --lut=classes.txt -o=output.tif --discrete", short_exe, name).replace("*", &sep);
+
+        ApplyLookupTable {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ApplyLookupTable {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut lut_file = String::new();
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--lut"])? {
+            lut_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        let discrete = tool_args.get_flag(&["--discrete"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !lut_file.contains(&sep) && !lut_file.contains("/") {
+            lut_file = format!("{}{}", working_directory, lut_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let breakpoints = read_lut(&lut_file)?;
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let bands = read_bands(&input_file)?;
+
+        let start = Instant::now();
+
+        let mut output = stretch_to_bytes(&bands, &output_file, |_, _| {
+            let breakpoints = breakpoints.clone();
+            Ok(Box::new(move |z| lookup(&breakpoints, z, discrete)))
+        })?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Look-up table file: {}", lut_file));
+        output.add_metadata_entry(format!("Discrete: {}", discrete));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the breakpoints of a look-up table file, sorted by their input values.
+fn read_lut(file_name: &str) -> Result<Vec<(f64, f64)>, Error> {
+    let f = File::open(file_name)?;
+    let mut breakpoints = vec![];
+    for (i, line) in BufReader::new(f).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let values: Vec<f64> = line
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Line {} of the look-up table could not be read.", i + 1),
+                )
+            })?;
+        if values.len() != 2 || values[1] < 0f64 || values[1] > 255f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Line {} of the look-up table must contain an input value and an output value from 0 to 255.",
+                    i + 1
+                ),
+            ));
+        }
+        breakpoints.push((values[0], values[1]));
+    }
+    if breakpoints.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The look-up table does not contain any breakpoints.",
+        ));
+    }
+    breakpoints.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    Ok(breakpoints)
+}
+
+/// Maps a value using the sorted breakpoints of a look-up table.
+fn lookup(breakpoints: &[(f64, f64)], z: f64, discrete: bool) -> f64 {
+    // the number of breakpoints with input values no greater than z
+    let i = breakpoints.iter().take_while(|b| b.0 <= z).count();
+    if i == 0 {
+        return breakpoints[0].1;
+    }
+    if i == breakpoints.len() || discrete {
+        return breakpoints[i - 1].1;
+    }
+    let (x0, y0) = breakpoints[i - 1];
+    let (x1, y1) = breakpoints[i];
+    y0 + (y1 - y0) * (z - x0) / (x1 - x0)
+}
@@ -128,45 +128,19 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "-input"
-                || vec[0].to_lowercase() == "--input"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-band_mean"
-                || vec[0].to_lowercase() == "--band_mean"
-            {
-                if keyval {
-                    e = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    e = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--band_mean"])? {
+            e = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -182,7 +156,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         }
 
         if verbose {
-            println!("Reading image data...")
+            report::message("Reading image data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -191,6 +165,8 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let l = 0f64;
         let h = 255f64;
@@ -335,7 +311,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
             if verbose {
                 progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    report::progress("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -374,7 +350,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                 let (mut r_out, mut g_out, mut b_out): (u32, u32, u32);
                 let (mut r_outf, mut g_outf, mut b_outf): (f64, f64, f64);
                 for row in (0..rows).filter(|rt| rt % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input[(row, col)];
                         if z != nodata {
@@ -420,7 +396,6 @@ impl WhiteboxTool for BalanceContrastEnhancement {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
@@ -429,7 +404,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -445,21 +420,18 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
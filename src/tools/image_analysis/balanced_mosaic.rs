@@ -0,0 +1,683 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::resample::sample_value;
+use na::{DMatrix, DVector};
+use raster::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool creates a seamless mosaic from two or more overlapping images (`--inputs`),
+/// such as air photos or satellite scenes acquired under differing conditions. Unlike the
+/// `Mosaic` tool, which simply places the images one on top of another, this tool balances
+/// the brightness and contrast of the images and, optionally, places the seamlines between
+/// them where the images are most similar, such that tile boundaries are not visible in the
+/// output.
+///
+/// **Colour balancing**. A global linear adjustment (gain and offset) is calculated for each
+/// image from the statistics of its overlaps with the other images. The gains are chosen such
+/// that the standard deviations of overlapping images match, in a least-squares sense over all
+/// overlaps, and the offsets are then chosen such that their means match. The first input
+/// image is used as the reference and is not adjusted, and each overlap is weighted by its
+/// number of cells. Images that do not overlap any other image, directly or indirectly, are
+/// not adjusted. The bands of RGB colour composites are balanced separately.
+///
+/// **Seamlines**. By default, each output cell is taken from the first image in the input list
+/// that covers it, as with `Mosaic`. When the `--seamlines` flag is specified, the cells of
+/// overlapping areas are instead assigned to images by a marker-controlled watershed, which
+/// grows outwards from the areas covered by only one image, such that the seamlines follow
+/// low-cost paths through the overlaps. The cost of each cell is the difference between the
+/// balanced images that cover it plus their mean gradient magnitude, so seamlines avoid both
+/// areas where the images disagree (e.g. due to moving objects or relief displacement) and
+/// edges, where seams are most noticeable. Images that are entirely covered by other images
+/// have no area of their own from which to grow and are not used. The image that each output
+/// cell is taken from may be saved to a raster (`--out_labels`), numbered in the order of
+/// the inputs.
+///
+/// The output grid covers the combined extent of the inputs at the finest input resolution.
+/// The images are resampled using nearest neighbour (`nn`), bilinear interpolation
+/// (`bilinear`), or cubic convolution (`cc`) resampling (`--method`), except for RGB colour
+/// composites, which are always resampled using nearest neighbour. The output is an F32
+/// raster, or an RGB colour composite if the inputs are colour composites.
+///
+/// # See Also
+/// `Mosaic`, `Resample`, `HistogramMatchingTwoImages`
+pub struct BalancedMosaic {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl BalancedMosaic {
+    pub fn new() -> BalancedMosaic {
+        // public constructor
+        let name = "BalancedMosaic".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Mosaics overlapping images with colour balancing and optional seamline placement.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input raster files; the first is the balancing reference.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Resampling Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Resampling method; options include 'nn' (nearest neighbour), 'bilinear', and 'cc' (cubic convolution)".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "nn".to_owned(),
+                "bilinear".to_owned(),
+                "cc".to_owned(),
+            ]),
+            default_value: Some("cc".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Place seamlines along low-cost paths?".to_owned(),
+            flags: vec!["--seamlines".to_owned()],
+            description: "Place the seamlines between images along low-difference, low-gradient paths.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Image Labels File".to_owned(),
+            flags: vec!["--out_labels".to_owned()],
+            description: "Optional output raster of the input image used for each cell.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='photo1.tif;photo2.tif;photo3.tif' -o=mosaic.tif --method=bilinear --seamlines --out_labels=seams.tif", short_exe, name).replace("*", &sep);
+
+        BalancedMosaic {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for BalancedMosaic {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_files_str = String::new();
+        let mut output_file = String::new();
+        let mut method = String::from("cc");
+        let mut labels_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            let v = v.to_lowercase();
+            method = if v.contains("nn") || v.contains("nearest") {
+                "nn".to_string()
+            } else if v.contains("bi") {
+                "bilinear".to_string()
+            } else {
+                "cc".to_string()
+            };
+        }
+        let seamlines = tool_args.get_flag(&["--seamlines"])?;
+        if let Some(v) = tool_args.get_string(&["--out_labels"])? {
+            labels_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !labels_file.is_empty() && !labels_file.contains(&sep) && !labels_file.contains("/") {
+            labels_file = format!("{}{}", working_directory, labels_file);
+        }
+
+        let input_files: Vec<String> = input_files_str
+            .split(|c| c == ';' || c == ',')
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", working_directory, f)
+                } else {
+                    f.to_string()
+                }
+            })
+            .collect();
+        let num_files = input_files.len();
+        if num_files < 2 {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                "There is something incorrect about the input files. At least two inputs are required to operate this tool."));
+        }
+
+        // read the input files
+        if verbose {
+            report::message("Reading data...")
+        };
+        let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
+        for input_file in &input_files {
+            inputs.push(Raster::new(input_file, "r")?);
+        }
+        let is_rgb = inputs[0].configs.photometric_interp == PhotometricInterpretation::RGB;
+        if inputs
+            .iter()
+            .any(|r| (r.configs.photometric_interp == PhotometricInterpretation::RGB) != is_rgb)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The inputs must either all be RGB colour composites or all be single-band images.",
+            ));
+        }
+        let num_channels = if is_rgb { 3 } else { 1 };
+
+        let start = Instant::now();
+
+        // create the output grid, covering the combined extent of the inputs
+        let mut north = f64::NEG_INFINITY;
+        let mut south = f64::INFINITY;
+        let mut east = f64::NEG_INFINITY;
+        let mut west = f64::INFINITY;
+        let mut resolution_x = f64::INFINITY;
+        let mut resolution_y = f64::INFINITY;
+        for input in &inputs {
+            north = north.max(input.configs.north);
+            south = south.min(input.configs.south);
+            east = east.max(input.configs.east);
+            west = west.min(input.configs.west);
+            resolution_x = resolution_x.min(input.configs.resolution_x);
+            resolution_y = resolution_y.min(input.configs.resolution_y);
+        }
+        let rows = ((north - south) / resolution_y).ceil() as isize;
+        let columns = ((east - west) / resolution_x).ceil() as isize;
+        let nodata = -32768f64;
+
+        let mut configs = RasterConfigs {
+            ..Default::default()
+        };
+        configs.rows = rows as usize;
+        configs.columns = columns as usize;
+        configs.north = north;
+        configs.south = north - rows as f64 * resolution_y;
+        configs.east = west + columns as f64 * resolution_x;
+        configs.west = west;
+        configs.resolution_x = resolution_x;
+        configs.resolution_y = resolution_y;
+        configs.nodata = nodata;
+        configs.data_type = DataType::F32;
+        configs.photometric_interp = PhotometricInterpretation::Continuous;
+        configs.projection = inputs[0].configs.projection.clone();
+        configs.xy_units = inputs[0].configs.xy_units.clone();
+        configs.z_units = inputs[0].configs.z_units.clone();
+        configs.epsg_code = inputs[0].configs.epsg_code;
+        configs.coordinate_ref_system_wkt = inputs[0].configs.coordinate_ref_system_wkt.clone();
+        configs.pixel_is_area = inputs[0].configs.pixel_is_area;
+        if is_rgb {
+            configs.data_type = DataType::RGBA32;
+            configs.photometric_interp = PhotometricInterpretation::RGB;
+            configs.nodata = 0f64;
+        }
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+
+        // resample the bands of each input onto the output grid
+        if verbose {
+            report::message("Resampling the inputs...")
+        };
+        let mut layers: Vec<Vec<Array2D<f64>>> = Vec::with_capacity(num_files);
+        for i in 0..num_files {
+            let in_nodata = inputs[i].configs.nodata;
+            let mut bands = vec![];
+            for _ in 0..num_channels {
+                bands.push(Array2D::new(rows, columns, nodata, nodata)?);
+            }
+            for row in 0..rows {
+                let y = output.get_y_from_row(row);
+                for col in 0..columns {
+                    let x = output.get_x_from_column(col);
+                    if is_rgb {
+                        let r = inputs[i].get_row_from_y(y);
+                        let c = inputs[i].get_column_from_x(x);
+                        let z = inputs[i].get_value(r, c);
+                        if z != in_nodata {
+                            let (red, green, blue, _) = inputs[i].get_value_as_rgba(r, c);
+                            bands[0].set_value(row, col, red as f64);
+                            bands[1].set_value(row, col, green as f64);
+                            bands[2].set_value(row, col, blue as f64);
+                        }
+                    } else {
+                        let z = sample_value(&inputs[i], x, y, &method);
+                        if z != in_nodata {
+                            bands[0].set_value(row, col, z);
+                        }
+                    }
+                }
+            }
+            layers.push(bands);
+            if verbose {
+                report::message(&format!("Resampled image {} of {}", i + 1, num_files));
+            }
+        }
+        drop(inputs);
+
+        // calculate the overlap statistics and balance the images
+        for ch in 0..num_channels {
+            // (i, j, n, mean_i, sd_i, mean_j, sd_j)
+            let mut overlaps = vec![];
+            for i in 0..num_files {
+                for j in i + 1..num_files {
+                    let (mut n, mut sum_i, mut sq_i, mut sum_j, mut sq_j) =
+                        (0f64, 0f64, 0f64, 0f64, 0f64);
+                    for row in 0..rows {
+                        for col in 0..columns {
+                            let zi = layers[i][ch].get_value(row, col);
+                            let zj = layers[j][ch].get_value(row, col);
+                            if zi != nodata && zj != nodata {
+                                n += 1f64;
+                                sum_i += zi;
+                                sq_i += zi * zi;
+                                sum_j += zj;
+                                sq_j += zj * zj;
+                            }
+                        }
+                    }
+                    if n > 1f64 {
+                        let (mean_i, mean_j) = (sum_i / n, sum_j / n);
+                        let sd_i = (sq_i / n - mean_i * mean_i).max(0f64).sqrt();
+                        let sd_j = (sq_j / n - mean_j * mean_j).max(0f64).sqrt();
+                        overlaps.push((i, j, n, mean_i, sd_i, mean_j, sd_j));
+                    }
+                }
+            }
+            if overlaps.is_empty() {
+                if verbose && ch == 0 {
+                    report::warning("None of the input images overlap; they are not balanced.");
+                }
+                continue;
+            }
+
+            // the gains match the standard deviations, in log space, and the offsets the means
+            let gain_equations: Vec<(usize, usize, f64, f64)> = overlaps
+                .iter()
+                .filter(|o| o.4 > 0f64 && o.6 > 0f64)
+                .map(|o| (o.0, o.1, o.2, o.6.ln() - o.4.ln()))
+                .collect();
+            let log_gains = solve_relative(num_files, &gain_equations)?;
+            let gains: Vec<f64> = log_gains.iter().map(|g| g.exp()).collect();
+            let offset_equations: Vec<(usize, usize, f64, f64)> = overlaps
+                .iter()
+                .map(|o| (o.0, o.1, o.2, gains[o.1] * o.5 - gains[o.0] * o.3))
+                .collect();
+            let offsets = solve_relative(num_files, &offset_equations)?;
+
+            for i in 0..num_files {
+                if verbose {
+                    if is_rgb {
+                        report::message(&format!(
+                            "Image {} band {}: gain = {:.4}, offset = {:.4}",
+                            i + 1,
+                            ch + 1,
+                            gains[i],
+                            offsets[i]
+                        ));
+                    } else {
+                        report::message(&format!(
+                            "Image {}: gain = {:.4}, offset = {:.4}",
+                            i + 1,
+                            gains[i],
+                            offsets[i]
+                        ));
+                    }
+                }
+                for row in 0..rows {
+                    for col in 0..columns {
+                        let z = layers[i][ch].get_value(row, col);
+                        if z != nodata {
+                            layers[i][ch].set_value(row, col, gains[i] * z + offsets[i]);
+                        }
+                    }
+                }
+            }
+        }
+
+        // assign each output cell to an input image
+        let mut labels: Array2D<i16> = Array2D::new(rows, columns, -1, -1)?;
+        let coverage = |row: isize, col: isize| -> Vec<usize> {
+            (0..num_files)
+                .filter(|&i| layers[i][0].get_value(row, col) != nodata)
+                .collect()
+        };
+        if seamlines {
+            if verbose {
+                report::message("Placing seamlines...")
+            };
+            let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+            let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let mut queue = BinaryHeap::new();
+            for row in 0..rows {
+                for col in 0..columns {
+                    let images = coverage(row, col);
+                    if images.len() == 1 {
+                        labels.set_value(row, col, images[0] as i16);
+                        queue.push(SeamCell {
+                            row: row,
+                            column: col,
+                            image: images[0],
+                            priority: f64::INFINITY,
+                        });
+                    }
+                }
+            }
+            while let Some(cell) = queue.pop() {
+                for n in 0..8 {
+                    let (row_n, col_n) = (cell.row + dy[n], cell.column + dx[n]);
+                    if labels.get_value(row_n, col_n) != -1
+                        || row_n < 0
+                        || col_n < 0
+                        || row_n >= rows
+                        || col_n >= columns
+                        || layers[cell.image][0].get_value(row_n, col_n) == nodata
+                    {
+                        continue;
+                    }
+                    labels.set_value(row_n, col_n, cell.image as i16);
+                    queue.push(SeamCell {
+                        row: row_n,
+                        column: col_n,
+                        image: cell.image,
+                        priority: seam_cost(&layers, &coverage(row_n, col_n), row_n, col_n, nodata),
+                    });
+                }
+            }
+        }
+        for row in 0..rows {
+            for col in 0..columns {
+                if labels.get_value(row, col) == -1 {
+                    if let Some(i) = coverage(row, col).first() {
+                        labels.set_value(row, col, *i as i16);
+                    }
+                }
+            }
+        }
+
+        // create the output
+        for row in 0..rows {
+            for col in 0..columns {
+                let label = labels.get_value(row, col);
+                if label < 0 {
+                    continue;
+                }
+                let bands = &layers[label as usize];
+                if is_rgb {
+                    let channel = |ch: usize| {
+                        let z = bands[ch].get_value(row, col).round().max(0f64).min(255f64);
+                        z as u32
+                    };
+                    let value =
+                        (255u32 << 24) | (channel(2) << 16) | (channel(1) << 8) | channel(0);
+                    output.set_value(row, col, value as f64);
+                } else {
+                    output.set_value(row, col, bands[0].get_value(row, col));
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if !labels_file.is_empty() {
+            let mut label_configs = configs.clone();
+            label_configs.data_type = DataType::I16;
+            label_configs.photometric_interp = PhotometricInterpretation::Categorical;
+            label_configs.nodata = 0f64;
+            let mut out_labels = Raster::initialize_using_config(&labels_file, &label_configs);
+            for row in 0..rows {
+                for col in 0..columns {
+                    let label = labels.get_value(row, col);
+                    if label >= 0 {
+                        out_labels.set_value(row, col, (label + 1) as f64);
+                    }
+                }
+            }
+            out_labels.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            let _ = match out_labels.write() {
+                Ok(_) => if verbose {
+                    report::message("Labels file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Resampling method: {}", method));
+        output.add_metadata_entry(format!("Seamlines: {}", seamlines));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Solves for the values of a set of images from weighted equations of the form
+/// value_i - value_j = target, in a least-squares sense, with the value of the first image
+/// fixed at zero. Images that are not connected to the first image by any equation are
+/// assigned zero.
+fn solve_relative(
+    num_images: usize,
+    equations: &[(usize, usize, f64, f64)],
+) -> Result<Vec<f64>, Error> {
+    let total_weight: f64 = equations.iter().map(|e| e.2).sum();
+    let mut matrix = vec![0f64; num_images * num_images];
+    let mut b = vec![0f64; num_images];
+    for &(i, j, weight, target) in equations {
+        matrix[i * num_images + i] += weight;
+        matrix[j * num_images + j] += weight;
+        matrix[i * num_images + j] -= weight;
+        matrix[j * num_images + i] -= weight;
+        b[i] += weight * target;
+        b[j] -= weight * target;
+    }
+    // fix the reference image and weakly tie all of the images to zero, which keeps the
+    // system solvable when some images are not connected to the reference
+    matrix[0] += 1e6 * total_weight.max(1f64);
+    for i in 0..num_images {
+        matrix[i * num_images + i] += 1e-9 * total_weight.max(1f64);
+    }
+    let a = DMatrix::from_row_slice(num_images, num_images, &matrix);
+    match a.try_inverse() {
+        Some(inverse) => Ok((inverse * DVector::from_row_slice(num_images, &b))
+            .as_slice()
+            .to_vec()),
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            "The colour balancing adjustments could not be solved.",
+        )),
+    }
+}
+
+/// Returns the seamline cost of a cell: the range of the values of the images covering
+/// it plus their mean gradient magnitude, summed over the bands.
+fn seam_cost(
+    layers: &[Vec<Array2D<f64>>],
+    images: &[usize],
+    row: isize,
+    col: isize,
+    nodata: f64,
+) -> f64 {
+    let mut cost = 0f64;
+    for ch in 0..layers[0].len() {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        let mut gradient = 0f64;
+        for &i in images {
+            let band = &layers[i][ch];
+            let z = band.get_value(row, col);
+            min = min.min(z);
+            max = max.max(z);
+            let neighbour = |r: isize, c: isize| {
+                let v = band.get_value(r, c);
+                if v == nodata {
+                    z
+                } else {
+                    v
+                }
+            };
+            let gx = (neighbour(row, col + 1) - neighbour(row, col - 1)) / 2f64;
+            let gy = (neighbour(row + 1, col) - neighbour(row - 1, col)) / 2f64;
+            gradient += (gx * gx + gy * gy).sqrt();
+        }
+        if !images.is_empty() {
+            cost += max - min + gradient / images.len() as f64;
+        }
+    }
+    cost
+}
+
+/// A cell of the seamline watershed; cells with the greatest cost are processed first, such
+/// that the regions meet along low-cost paths.
+struct SeamCell {
+    row: isize,
+    column: isize,
+    image: usize,
+    priority: f64,
+}
+
+impl PartialEq for SeamCell {
+    fn eq(&self, other: &SeamCell) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for SeamCell {}
+
+impl PartialOrd for SeamCell {
+    fn partial_cmp(&self, other: &SeamCell) -> Option<Ordering> {
+        self.priority.partial_cmp(&other.priority)
+    }
+}
+
+impl Ord for SeamCell {
+    fn cmp(&self, other: &SeamCell) -> Ordering {
+        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+    }
+}
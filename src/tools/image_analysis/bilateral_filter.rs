@@ -138,50 +138,22 @@ impl WhiteboxTool for BilateralFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-sigma_dist"
-                || vec[0].to_lowercase() == "--sigma_dist"
-            {
-                if keyval {
-                    sigma_dist = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    sigma_dist = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-sigma_int"
-                || vec[0].to_lowercase() == "--sigma_int"
-            {
-                if keyval {
-                    sigma_int = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    sigma_int = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--sigma_dist"])? {
+            sigma_dist = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--sigma_int"])? {
+            sigma_int = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,7 +227,7 @@ impl WhiteboxTool for BilateralFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -268,6 +240,8 @@ impl WhiteboxTool for BilateralFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let is_rgb_image = if input.configs.data_type == DataType::RGB24
             || input.configs.data_type == DataType::RGBA32
@@ -278,8 +252,6 @@ impl WhiteboxTool for BilateralFilter {
             false
         };
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
@@ -323,7 +295,7 @@ impl WhiteboxTool for BilateralFilter {
                 let mut weights_i = vec![0.0; num_pixels_in_filter];
 
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input_fn(row, col);
                         if z != nodata {
@@ -367,7 +339,7 @@ impl WhiteboxTool for BilateralFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -385,22 +357,19 @@ impl WhiteboxTool for BilateralFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
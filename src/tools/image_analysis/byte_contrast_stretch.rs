@@ -0,0 +1,266 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::byte_stretch::{read_bands, stretch_to_bytes, Stretch};
+use std::f64;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool applies a contrast stretch to an image and writes the result as an 8-bit
+/// raster that is ready for display, rather than leaving the stretch to be reproduced by
+/// viewers from the display minimum and maximum values of a raster, which many viewers
+/// ignore. Each valid cell is assigned a value from 1 to 255, and nodata cells are assigned
+/// a value of 0, which is the output's nodata value. The following stretches (`--method`)
+/// are available:
+///
+/// - `percent` (the default) linearly stretches the values between the percentiles that
+///   clip the specified percentage (`--clip`) of the cells from each tail of the
+///   distribution;
+/// - `stdev` linearly stretches the values within the specified number of standard
+///   deviations (`--stdev`) of the mean;
+/// - `minmax` linearly stretches the values between the minimum and maximum; and
+/// - `equalize` applies a histogram equalization, which assigns each value its rank in the
+///   cumulative distribution of the image, such that the output tones are used about
+///   equally often.
+///
+/// Values outside of the range of a linear stretch are assigned 1 or 255. Each band of a
+/// multi-band GeoTIFF is stretched independently, and the output has the same number of
+/// bands; colour-composite images must first be split into their bands (e.g. with
+/// `SplitColourComposite`). To apply a stretch that is defined by a set of breakpoints, use
+/// `ApplyLookupTable`.
+///
+/// # See Also
+/// `ApplyLookupTable`, `PercentageContrastStretch`, `StandardDeviationContrastStretch`,
+/// `HistogramEqualization`
+pub struct ByteContrastStretch {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ByteContrastStretch {
+    pub fn new() -> ByteContrastStretch {
+        // public constructor
+        let name = "ByteContrastStretch".to_string();
+        let toolbox = "Image Processing Tools/Image Enhancement".to_string();
+        let description =
+            "Applies a percent clip, standard deviation, min-max, or equalization stretch to an image, writing an 8-bit display raster.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Stretch Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Stretch method; options include 'percent', 'stdev', 'minmax', and 'equalize'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "percent".to_owned(),
+                "stdev".to_owned(),
+                "minmax".to_owned(),
+                "equalize".to_owned(),
+            ]),
+            default_value: Some("percent".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Distribution Tail Clip Amount (%)".to_owned(),
+            flags: vec!["--clip".to_owned()],
+            description: "Percentage of cells clipped from each tail by the 'percent' stretch."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Standard Deviations".to_owned(),
+            flags: vec!["--stdev".to_owned()],
+            description: "Number of standard deviations either side of the mean stretched by the 'stdev' stretch.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif --method=percent --clip=1.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif --method=stdev --stdev=2.5", short_exe, name).replace("*", &sep);
+
+        ByteContrastStretch {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ByteContrastStretch {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut method = String::from("percent");
+        let mut clip = 2f64;
+        let mut num_stdev = 2f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip"])? {
+            clip = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--stdev"])? {
+            num_stdev = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let method = Stretch::parse(&method, clip, num_stdev)?;
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let bands = read_bands(&input_file)?;
+
+        let start = Instant::now();
+
+        let mut band_entries = vec![];
+        let num_bands = bands.len();
+        let mut output = stretch_to_bytes(&bands, &output_file, |b, band| match method.fit(band) {
+            Some((f, description)) => {
+                if verbose {
+                    report::message(&format!("Band {} of {}: {}", b + 1, num_bands, description));
+                }
+                band_entries.push(format!("Band {}: {}", b + 1, description));
+                Ok(Box::new(move |z| 1f64 + f(z) * 254f64))
+            }
+            None => Ok(Box::new(|_| f64::NAN)),
+        })?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Method: {}", method.name()));
+        for entry in band_entries {
+            output.add_metadata_entry(entry);
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
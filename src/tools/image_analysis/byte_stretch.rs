@@ -0,0 +1,243 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::cmp::Ordering::Equal;
+use std::f64;
+use std::io::{Error, ErrorKind};
+
+/// The nodata value of 8-bit display rasters; valid cells are assigned values from 1 to 255.
+pub const BYTE_NODATA: f64 = 0f64;
+
+/// Reads every band of a raster file.
+pub fn read_bands(input_file: &str) -> Result<Vec<Raster>, Error> {
+    let first_band = Raster::new(input_file, "r")?;
+    match first_band.configs.data_type {
+        DataType::RGB24 | DataType::RGB48 | DataType::RGBA32 => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "This tool cannot be applied to colour-composite images; split the composite into its bands first.",
+            ))
+        }
+        _ => {}
+    }
+    let num_bands = first_band.configs.bands.max(1) as usize;
+    let mut bands = vec![first_band];
+    for b in 1..num_bands {
+        bands.push(Raster::read_band(input_file, b)?);
+    }
+    Ok(bands)
+}
+
+/// Returns the valid values of a band, in ascending order.
+pub fn sorted_values(band: &Raster) -> Vec<f64> {
+    let nodata = band.configs.nodata;
+    let mut values = vec![];
+    for row in 0..band.configs.rows as isize {
+        values.extend(
+            band.get_row_data(row)
+                .into_iter()
+                .filter(|z| *z != nodata && z.is_finite()),
+        );
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
+    values
+}
+
+/// Returns the value below which the fraction `p` of the sorted values lie, interpolating
+/// linearly between ranks.
+pub fn quantile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    let position = p.max(0f64).min(1f64) * (sorted.len() - 1) as f64;
+    let i = position.floor() as usize;
+    let j = (i + 1).min(sorted.len() - 1);
+    sorted[i] + (sorted[j] - sorted[i]) * (position - i as f64)
+}
+
+/// A contrast stretch, which maps the values of a band to the range 0 to 1.
+#[derive(Clone, Copy)]
+pub enum Stretch {
+    /// A linear stretch between the percentiles that clip a percentage from each tail.
+    Percent(f64),
+    /// A linear stretch of a number of standard deviations either side of the mean.
+    StandardDeviation(f64),
+    /// A histogram equalization, which maps each value to its cumulative proportion.
+    Equalize,
+}
+
+impl Stretch {
+    /// Parses the name of a stretch method ('percent', 'stdev', 'minmax', or 'equalize'),
+    /// validating the clip percentage and number of standard deviations that it uses.
+    pub fn parse(method: &str, clip: f64, num_stdev: f64) -> Result<Stretch, Error> {
+        let method = method.to_lowercase();
+        if method.contains("eq") || method.contains("hist") {
+            Ok(Stretch::Equalize)
+        } else if method.contains("std") || method.contains("sd") {
+            if num_stdev <= 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The number of standard deviations must be greater than zero.",
+                ));
+            }
+            Ok(Stretch::StandardDeviation(num_stdev))
+        } else if method.contains("min") || method.contains("max") {
+            Ok(Stretch::Percent(0f64))
+        } else if method.contains("per") || method.contains("clip") {
+            if clip < 0f64 || clip >= 50f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The clip value must be at least 0 and less than 50 percent.",
+                ));
+            }
+            Ok(Stretch::Percent(clip))
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized stretch method; options include 'percent', 'stdev', 'minmax', and 'equalize'.",
+            ))
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match *self {
+            Stretch::Percent(p) if p == 0f64 => String::from("minmax"),
+            Stretch::Percent(p) => format!("percent (clip {}%)", p),
+            Stretch::StandardDeviation(k) => format!("stdev ({} standard deviations)", k),
+            Stretch::Equalize => String::from("equalize"),
+        }
+    }
+
+    /// Fits the stretch to a band, returning a function that maps the band's values to the
+    /// range 0 to 1, and a description of the fitted stretch, or None if the band has no
+    /// valid cells. Values outside of the range of a linear stretch are mapped to 0 or 1,
+    /// and the values of a band with a single value are mapped to 0.5.
+    pub fn fit(&self, band: &Raster) -> Option<(Box<Fn(f64) -> f64 + Send + Sync>, String)> {
+        let values = sorted_values(band);
+        if values.is_empty() {
+            return None;
+        }
+        let (lower, upper) = match *self {
+            Stretch::Percent(p) => (
+                quantile(&values, p / 100f64),
+                quantile(&values, 1f64 - p / 100f64),
+            ),
+            Stretch::StandardDeviation(k) => {
+                let n = values.len() as f64;
+                let mean = values.iter().sum::<f64>() / n;
+                let variance = values.iter().map(|z| (z - mean) * (z - mean)).sum::<f64>() / n;
+                (mean - k * variance.sqrt(), mean + k * variance.sqrt())
+            }
+            Stretch::Equalize => {
+                let n = values.len() as f64;
+                // the proportion of the cells with values no greater than z
+                let f = move |z: f64| upper_bound(&values, z) as f64 / n;
+                return Some((Box::new(f), String::from("histogram equalization")));
+            }
+        };
+        let range = upper - lower;
+        let f = move |z: f64| {
+            if range > 0f64 {
+                ((z - lower) / range).max(0f64).min(1f64)
+            } else {
+                0.5f64
+            }
+        };
+        Some((
+            Box::new(f),
+            format!("stretched from {} to {}", lower, upper),
+        ))
+    }
+}
+
+/// Returns the number of sorted values that are no greater than `z`.
+fn upper_bound(sorted: &[f64], z: f64) -> usize {
+    let (mut low, mut high) = (0usize, sorted.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if sorted[mid] <= z {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Creates an 8-bit display raster with one band for each input band. Each valid cell of an
+/// input band is converted using the function returned by `stretch` for the band (which is
+/// passed the band's index and data), and the result is rounded and clamped to the range
+/// 1 to 255. Nodata cells, and cells converted to NaN, are assigned `BYTE_NODATA`.
+pub fn stretch_to_bytes<F>(
+    bands: &[Raster],
+    output_file: &str,
+    mut stretch: F,
+) -> Result<Raster, Error>
+where
+    F: FnMut(usize, &Raster) -> Result<Box<Fn(f64) -> f64>, Error>,
+{
+    let mut output: Option<Raster> = None;
+    for (b, band) in bands.iter().enumerate() {
+        let f = stretch(b, band)?;
+        let nodata = band.configs.nodata;
+        let mut configs = band.configs.clone();
+        configs.data_type = DataType::U8;
+        configs.nodata = BYTE_NODATA;
+        configs.photometric_interp = PhotometricInterpretation::Continuous;
+        configs.palette = "grey.plt".to_string();
+        configs.bands = 1;
+        configs.band_names = vec![];
+        configs.display_min = 1f64;
+        configs.display_max = 255f64;
+        configs.minimum = 1f64;
+        configs.maximum = 255f64;
+        let mut stretched = Raster::initialize_using_config(output_file, &configs);
+        let out_nodata = stretched.configs.nodata;
+        for row in 0..configs.rows as isize {
+            let data = band
+                .get_row_data(row)
+                .into_iter()
+                .map(|z| {
+                    if z != nodata && z.is_finite() {
+                        let v = f(z);
+                        if v.is_nan() {
+                            out_nodata
+                        } else {
+                            v.round().max(1f64).min(255f64)
+                        }
+                    } else {
+                        out_nodata
+                    }
+                })
+                .collect();
+            stretched.set_row_data(row, data);
+        }
+        match output {
+            Some(ref mut o) => o.add_band(&stretched)?,
+            None => output = Some(stretched),
+        }
+    }
+    let mut output = match output {
+        Some(o) => o,
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster does not contain any bands.",
+            ))
+        }
+    };
+    if output.num_bands() > 1 && output.raster_type != RasterType::GeoTiff {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The output of a multi-band input must be a GeoTIFF (.tif) file.",
+        ));
+    }
+    output.configs.band_names = bands[0].configs.band_names.clone();
+    Ok(output)
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 29/04/2018
-Last Modified: 29/04/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -21,7 +21,10 @@ use tools::*;
 /// the multispectral data for each date. Note that there must be the same number of
 /// image files (bands) for the two dates and they must be entered in the same order,
 /// i.e. if three bands, red, green, and blue are entered for date one, these same
-/// bands must be entered in the same order for date two.
+/// bands must be entered in the same order for date two. Multi-band GeoTIFFs, e.g. those
+/// created by the `ImageStack` tool, may also be used, in which case each of their bands
+/// contributes a dimension to the change vector; corresponding files of the two dates must
+/// have the same number of bands.
 ///
 /// CVA outputs two image files. The first image contains the change vector length,
 /// i.e. magnitude, for each pixel in the multi-spectral dataset. The second image
@@ -38,6 +41,9 @@ use tools::*;
 /// determine 'actual' change (i.e. change above some assumed level of error). The type
 /// of change (qualitatively) is then defined according to the corresponding sector code.
 /// Jensen (2005) provides a useful description of this approach to change detection.
+///
+/// # See Also
+/// `ImageDifferencing`, `ImageRatio`, `RegressionChangeDetection`, `WriteFunctionMemoryInsertion`
 pub struct ChangeVectorAnalysis {
     name: String,
     description: String,
@@ -159,47 +165,22 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-date1" {
-                input1_files_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-date2" {
-                input2_files_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-magnitude" {
-                magnitude_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-direction" {
-                direction_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--date1"])? {
+            input1_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--date2"])? {
+            input2_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--magnitude"])? {
+            magnitude_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--direction"])? {
+            direction_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let start = Instant::now();
@@ -250,11 +231,6 @@ impl WhiteboxTool for ChangeVectorAnalysis {
             ));
         }
 
-        let mut direction_array: Vec<f64> = Vec::with_capacity(num_files);
-        for i in 0..num_files {
-            direction_array.push(2f64.powf(i as f64));
-        }
-
         // We will need to read one of the files in to get the rows, columns, and nodata values
         // in order to create the output files.
         let mut input1_file = input1_files[0].trim().to_owned();
@@ -264,18 +240,22 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         let input1 = Raster::new(&input1_file, "r")?;
         let rows = input1.configs.rows as isize;
         let columns = input1.configs.columns as isize;
-        let nodata = input1.configs.nodata;
 
         // Create the output files
         let mut out_magnitude = Raster::initialize_using_file(&magnitude_file, &input1);
+        out_magnitude.configs.data_type = DataType::F32;
+        out_magnitude.configs.photometric_interp = PhotometricInterpretation::Continuous;
         let mut out_direction = Raster::initialize_using_file(&direction_file, &input1);
+        out_direction.configs.data_type = DataType::I32;
 
         let mut nodata_detected: Array2D<i8> = Array2D::new(rows, columns, -1i8, -1i8)?;
         // let num_procs = num_cpus::get() as isize;
 
+        // the bands of multi-band inputs each contribute a dimension to the change vector
+        let mut num_bands = 0usize;
         for i in 0..num_files {
             if verbose {
-                println!("Reading file {} of {}", i + 1, num_files);
+                report::message(&format!("Reading file {} of {}", i + 1, num_files));
             }
             if !input1_files[i].trim().is_empty() && !input2_files[i].trim().is_empty() {
                 let mut input1_file = input1_files[i].trim().to_owned();
@@ -288,47 +268,76 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                     input2_file = format!("{}{}", working_directory, input2_file);
                 }
 
-                let input1 = Raster::new(&input1_file, "r")?;
-                let input2 = Raster::new(&input2_file, "r")?;
-
-                // make sure the images have the right rows and columns
-                if input1.configs.rows as isize != rows
-                    || input1.configs.columns as isize != columns
-                    || input2.configs.rows as isize != rows
-                    || input1.configs.columns as isize != columns
-                {
+                let first1 = Raster::new(&input1_file, "r")?;
+                let first2 = Raster::new(&input2_file, "r")?;
+                let file_bands = first1.configs.bands.max(1) as usize;
+                if first2.configs.bands.max(1) as usize != file_bands {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "{} and {} do not have the same number of bands.",
+                            input1_file, input2_file
+                        ),
+                    ));
+                }
+                if num_bands + file_bands > 31 {
                     return Err(Error::new(
                         ErrorKind::InvalidInput,
-                        "All of the input files must share the same extent (rows and columns).",
+                        "The change vector cannot have more than 31 bands.",
                     ));
                 }
 
-                let nodata1 = input1.configs.nodata;
-                let nodata2 = input2.configs.nodata;
-
-                let (mut z1, mut z2): (f64, f64);
-                let mut z: f64;
-                for row in 0..rows {
-                    for col in 0..columns {
-                        z1 = input1.get_value(row, col);
-                        z2 = input2.get_value(row, col);
-                        if z1 != nodata1 && z2 != nodata2 {
-                            z = z2 - z1;
-                            out_magnitude.increment(row, col, z * z);
-                            if z >= 0f64 {
-                                out_direction.increment(row, col, direction_array[i]);
+                for b in 0..file_bands {
+                    let (input1, input2) = if b == 0 {
+                        (first1.clone(), first2.clone())
+                    } else {
+                        (
+                            Raster::read_band(&input1_file, b)?,
+                            Raster::read_band(&input2_file, b)?,
+                        )
+                    };
+
+                    // make sure the images have the right rows and columns
+                    if input1.configs.rows as isize != rows
+                        || input1.configs.columns as isize != columns
+                        || input2.configs.rows as isize != rows
+                        || input2.configs.columns as isize != columns
+                    {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "All of the input files must share the same extent (rows and columns).",
+                        ));
+                    }
+
+                    let nodata1 = input1.configs.nodata;
+                    let nodata2 = input2.configs.nodata;
+                    let direction_value = 2f64.powf(num_bands as f64);
+
+                    let (mut z1, mut z2): (f64, f64);
+                    let mut z: f64;
+                    for row in 0..rows {
+                        for col in 0..columns {
+                            z1 = input1.get_value(row, col);
+                            z2 = input2.get_value(row, col);
+                            if z1 != nodata1 && z2 != nodata2 {
+                                z = z2 - z1;
+                                out_magnitude.increment(row, col, z * z);
+                                if z >= 0f64 {
+                                    out_direction.increment(row, col, direction_value);
+                                }
+                            } else {
+                                nodata_detected.set_value(row, col, 1i8);
                             }
-                        } else {
-                            nodata_detected.set_value(row, col, 1i8);
                         }
-                    }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
+                        if verbose {
+                            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                            if progress != old_progress {
+                                report::progress("Progress", progress);
+                                old_progress = progress;
+                            }
                         }
                     }
+                    num_bands += 1;
                 }
             }
         }
@@ -340,21 +349,21 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                     z = out_magnitude.get_value(row, col);
                     out_magnitude.set_value(row, col, z.sqrt());
                 } else {
-                    out_magnitude.set_value(row, col, nodata);
-                    out_direction.set_value(row, col, nodata);
+                    out_magnitude.set_value(row, col, out_magnitude.configs.nodata);
+                    out_direction.set_value(row, col, out_direction.configs.nodata);
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -366,7 +375,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
 
         let _ = match out_magnitude.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -379,21 +388,21 @@ impl WhiteboxTool for ChangeVectorAnalysis {
 
         let _ = match out_direction.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         // print out a key for interpreting the direction image
         let mut s = "Key For Interpreting The CVA Direction Image:\n\n\tDirection of Change (+ or -)\nValue".to_string();
-        for i in 0..num_files {
+        for i in 0..num_bands {
             s.push_str(&format!("\tBand{}", i + 1));
         }
         s.push_str("\n");
         let mut line: String;
-        for a in 0..(2u32 * 2u32.pow(num_files as u32 - 1u32)) as usize {
+        for a in 0..(2u32 * 2u32.pow(num_bands as u32 - 1u32)) as usize {
             line = format!("{}\t", a);
-            for i in 0..num_files {
+            for i in 0..num_bands {
                 if a >> i & 1usize == 1usize {
                     line.push_str("+\t");
                 } else {
@@ -403,13 +412,10 @@ impl WhiteboxTool for ChangeVectorAnalysis {
             s.push_str(&format!("{}\n", line));
         }
 
-        println!("{}", s);
+        report::message(&s);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,378 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::rgb_image::{
+    is_colour_composite, pack_rgb, parse_channel_values, unpack_alpha, unpack_rgb,
+};
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool adjusts the colour balance and gamma of a colour-composite image (`--input`),
+/// e.g. to remove a colour cast caused by the atmosphere or by the illumination of a scene.
+/// Each of the red, green, and blue channels, scaled to the range 0 to 1, is multiplied by a
+/// gain, and the result is gamma corrected and clipped to the range 0 to 1:
+///
+/// > c' = (g<sub>c</sub> c)<sup>&gamma;<sub>c</sub></sup>
+///
+/// The gains are determined by the balance method (`--method`):
+///
+/// - `grey_world` (the default) assumes that the average colour of the scene is grey, and
+///   scales each channel such that its mean equals the mean of the three channels;
+/// - `white_patch` assumes that the brightest parts of the scene are white, and scales each
+///   channel such that its value at the specified percentile (`--percentile`, by default the
+///   99th) becomes 1; and
+/// - `manual` applies user-specified gains (`--gains`), either a single value for all three
+///   channels or three comma-separated values for the red, green, and blue channels.
+///
+/// The gamma correction (`--gamma`) may similarly be either a single value or three
+/// comma-separated values; gamma values less than 1 brighten the image and values greater
+/// than 1 darken it. The default gamma of 1 leaves the balanced image unchanged. The alpha
+/// channel and nodata cells of the input are preserved.
+///
+/// # See Also
+/// `CreateColourComposite`, `GammaCorrection`, `BalanceContrastEnhancement`, `RgbToHsv`
+pub struct ColourBalance {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ColourBalance {
+    /// Public constructor.
+    pub fn new() -> ColourBalance {
+        let name = "ColourBalance".to_string();
+        let toolbox = "Image Processing Tools/Image Enhancement".to_string();
+        let description =
+            "Adjusts the white balance and gamma of a colour-composite image.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Colour-Composite Image File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input colour-composite image file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output colour-composite file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Balance Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Balance method; options include 'grey_world', 'white_patch', and 'manual'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "grey_world".to_owned(),
+                "white_patch".to_owned(),
+                "manual".to_owned(),
+            ]),
+            default_value: Some("grey_world".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "White Patch Percentile".to_owned(),
+            flags: vec!["--percentile".to_owned()],
+            description: "Percentile of each channel that becomes white with the 'white_patch' method.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("99.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Gains".to_owned(),
+            flags: vec!["--gains".to_owned()],
+            description: "Gain, or comma-separated red, green, and blue gains, for the 'manual' method.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Gamma".to_owned(),
+            flags: vec!["--gamma".to_owned()],
+            description: "Gamma value, or comma-separated red, green, and blue gamma values."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=balanced.tif --method=white_patch --percentile=98.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=balanced.tif --method=manual --gains='1.1,1.0,0.85' --gamma=0.8", short_exe, name).replace("*", &sep);
+
+        ColourBalance {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ColourBalance {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut method = String::from("grey_world");
+        let mut percentile = 99f64;
+        let mut gains_str = String::from("1.0");
+        let mut gamma_str = String::from("1.0");
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--percentile"])? {
+            percentile = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--gains"])? {
+            gains_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--gamma"])? {
+            gamma_str = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let gamma = parse_channel_values(&gamma_str, "gamma")?;
+        if percentile <= 0f64 || percentile > 100f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The percentile must be greater than 0 and no greater than 100.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        if !is_colour_composite(&input) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input image must be a colour composite; use CreateColourComposite to combine separate bands.",
+            ));
+        }
+        let rows = input.configs.rows as isize;
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+
+        let start = Instant::now();
+
+        let gains = if method.contains("man") {
+            parse_channel_values(&gains_str, "gain")?
+        } else {
+            // histograms of the channel values, 0 to 255
+            let mut histograms = vec![vec![0usize; 256]; 3];
+            for row in 0..rows {
+                for z in input.get_row_data(row) {
+                    if z != nodata {
+                        let v = z as u32;
+                        for c in 0..3 {
+                            histograms[c][((v >> (8 * c)) & 0xFF) as usize] += 1;
+                        }
+                    }
+                }
+            }
+            let n = histograms[0].iter().sum::<usize>();
+            if n == 0 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input image does not contain any valid cells.",
+                ));
+            }
+            if method.contains("white") || method.contains("patch") {
+                let mut gains = [1f64; 3];
+                let target = percentile / 100f64 * n as f64;
+                for c in 0..3 {
+                    let mut cumulative = 0usize;
+                    for (v, count) in histograms[c].iter().enumerate() {
+                        cumulative += count;
+                        if cumulative as f64 >= target {
+                            gains[c] = 255f64 / (v.max(1) as f64);
+                            break;
+                        }
+                    }
+                }
+                gains
+            } else if method.contains("grey") || method.contains("gray") {
+                let mut means = [0f64; 3];
+                for c in 0..3 {
+                    means[c] = histograms[c]
+                        .iter()
+                        .enumerate()
+                        .map(|(v, count)| v as f64 * *count as f64)
+                        .sum::<f64>()
+                        / n as f64;
+                }
+                let grey = (means[0] + means[1] + means[2]) / 3f64;
+                let mut gains = [1f64; 3];
+                for c in 0..3 {
+                    if means[c] > 0f64 {
+                        gains[c] = grey / means[c];
+                    }
+                }
+                gains
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized --method; options include 'grey_world', 'white_patch', and 'manual'.",
+                ));
+            }
+        };
+        if verbose {
+            report::message(&format!(
+                "Gains (red, green, blue): {:.4}, {:.4}, {:.4}",
+                gains[0], gains[1], gains[2]
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data = input
+                .get_row_data(row)
+                .into_iter()
+                .map(|z| {
+                    if z == nodata {
+                        return out_nodata;
+                    }
+                    let (r, g, b) = unpack_rgb(z);
+                    let adjust =
+                        |v: f64, c: usize| (v * gains[c]).max(0f64).min(1f64).powf(gamma[c]);
+                    pack_rgb(adjust(r, 0), adjust(g, 1), adjust(b, 2), unpack_alpha(z))
+                })
+                .collect();
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Method: {}", method));
+        output.add_metadata_entry(format!(
+            "Gains: {}, {}, {}",
+            gains[0], gains[1], gains[2]
+        ));
+        output.add_metadata_entry(format!("Gamma: {}, {}, {}", gamma[0], gamma[1], gamma[2]));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
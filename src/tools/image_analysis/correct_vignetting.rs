@@ -179,59 +179,28 @@ impl WhiteboxTool for CorrectVignetting {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-pp" {
-                pp_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-focal_length" {
-                focal_length = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-image_width" {
-                image_width = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-n" {
-                n_param = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--pp"])? {
+            pp_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--focal_length"])? {
+            focal_length = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--image_width"])? {
+            image_width = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["-n"])? {
+            n_param = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,12 +219,14 @@ impl WhiteboxTool for CorrectVignetting {
         }
 
         if verbose {
-            println!("Reading input data...")
+            report::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let scale_factor = image_width / columns as f64;
 
         let is_rgb_image = if input.configs.data_type == DataType::RGB24
@@ -369,7 +340,7 @@ impl WhiteboxTool for CorrectVignetting {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    report::progress("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -401,7 +372,7 @@ impl WhiteboxTool for CorrectVignetting {
                 let mut z_in: f64;
                 let mut z_out: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z_in = unscaled_data.get_value(row, col);
                         if z_in != nodata {
@@ -414,7 +385,6 @@ impl WhiteboxTool for CorrectVignetting {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         if is_rgb_image {
             output.configs.photometric_interp = PhotometricInterpretation::RGB;
             output.configs.data_type = DataType::RGBA32;
@@ -425,7 +395,7 @@ impl WhiteboxTool for CorrectVignetting {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -444,19 +414,16 @@ impl WhiteboxTool for CorrectVignetting {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -2,10 +2,12 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 19, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::byte_stretch::Stretch;
+use super::rgb_image::parse_channel_values;
 use num_cpus;
 use raster::*;
 use std::env;
@@ -18,7 +20,35 @@ use std::thread;
 use structures::Array2D;
 use tools::*;
 
-/// Creates a colour-composite image from three bands of multispectral imagery.
+/// This tool creates a colour-composite image from three bands of multispectral imagery,
+/// which are assigned to the red (`--red`), green (`--green`), and blue (`--blue`) channels
+/// of the output, and an optional opacity band (`--opacity`), which is assigned to its
+/// alpha channel. The output is an RGBA colour composite, and is the counterpart of
+/// `SplitColourComposite`.
+///
+/// Each band is stretched to the 0 to 255 range of the channels using the stretch method
+/// (`--stretch`). By default (`display`), the values between the display minimum and
+/// maximum of each band are stretched. The other methods fit the stretch to the
+/// distribution of each band's values: `percent` stretches the values between the
+/// percentiles that clip the specified percentage (`--clip`) of the cells from each tail,
+/// `stdev` stretches the values within the specified number of standard deviations
+/// (`--stdev`) of the mean, `minmax` stretches the values between the minimum and maximum,
+/// and `equalize` applies a histogram equalization (see `ByteContrastStretch`). The opacity
+/// band is always stretched between its display minimum and maximum.
+///
+/// A gamma correction (`--gamma`) may be applied to the stretched values, as
+/// v<sup>&gamma;</sup>, where v is the stretched value scaled to the range 0 to 1, such
+/// that gamma values less than 1 brighten the image and values greater than 1 darken it.
+/// Either a single gamma value, which is applied to all three channels, or three
+/// comma-separated values, for the red, green, and blue channels respectively, may be
+/// specified. Finally, a balance contrast enhancement (`--enhance`) may be applied to the
+/// composite.
+///
+/// Cells that are nodata in any of the three bands are nodata in the output.
+///
+/// # See Also
+/// `SplitColourComposite`, `ByteContrastStretch`, `BalanceContrastEnhancement`,
+/// `GammaCorrection`
 pub struct CreateColourComposite {
     name: String,
     description: String,
@@ -93,6 +123,50 @@ impl CreateColourComposite {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Stretch Method".to_owned(),
+            flags: vec!["--stretch".to_owned()],
+            description: "Stretch applied to each band; options include 'display', 'percent', 'stdev', 'minmax', and 'equalize'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "display".to_owned(),
+                "percent".to_owned(),
+                "stdev".to_owned(),
+                "minmax".to_owned(),
+                "equalize".to_owned(),
+            ]),
+            default_value: Some("display".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Distribution Tail Clip Amount (%)".to_owned(),
+            flags: vec!["--clip".to_owned()],
+            description: "Percentage of cells clipped from each tail by the 'percent' stretch."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Standard Deviations".to_owned(),
+            flags: vec!["--stdev".to_owned()],
+            description: "Number of standard deviations either side of the mean stretched by the 'stdev' stretch.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Gamma".to_owned(),
+            flags: vec!["--gamma".to_owned()],
+            description: "Gamma value, or comma-separated red, green, and blue gamma values."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -105,7 +179,8 @@ impl CreateColourComposite {
             short_exe += ".exe";
         }
         let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --red=band3.tif --green=band2.tif --blue=band1.tif -o=output.tif
->>.*{0} -r={1} -v --wd=\"*path*to*data*\" --red=band3.tif --green=band2.tif --blue=band1.tif --opacity=a.tif -o=output.tif", short_exe, name).replace("*", &sep);
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --red=band3.tif --green=band2.tif --blue=band1.tif --opacity=a.tif -o=output.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --red=band4.tif --green=band3.tif --blue=band2.tif -o=output.tif --stretch=percent --clip=1.0 --gamma='0.8,0.9,1.0'", short_exe, name).replace("*", &sep);
 
         CreateColourComposite {
             name: name,
@@ -158,61 +233,51 @@ impl WhiteboxTool for CreateColourComposite {
         let mut input4_used = false;
         let mut output_file = String::new();
         let mut enhance = false;
+        let mut stretch_method = String::from("display");
+        let mut clip = 2f64;
+        let mut num_stdev = 2f64;
+        let mut gamma_str = String::from("1.0");
         if args.len() == 0 {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-red" || vec[0].to_lowercase() == "--red" {
-                if keyval {
-                    input1_file = vec[1].to_string();
-                } else {
-                    input1_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-green" || vec[0].to_lowercase() == "--green" {
-                if keyval {
-                    input2_file = vec[1].to_string();
-                } else {
-                    input2_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-blue" || vec[0].to_lowercase() == "--blue" {
-                if keyval {
-                    input3_file = vec[1].to_string();
-                } else {
-                    input3_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-opacity" || vec[0].to_lowercase() == "--opacity" {
-                if keyval {
-                    input4_file = vec[1].to_string();
-                } else {
-                    input4_file = args[i + 1].to_string();
-                }
-                input4_used = true;
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-enchance" || vec[0].to_lowercase() == "--enhance" {
-                enhance = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--green"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--blue"])? {
+            input3_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--opacity"])? {
+            input4_file = v;
+            input4_used = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--enchance", "--enhance"])? {
+            enhance = true;
+        }
+        if let Some(v) = tool_args.get_string(&["--stretch"])? {
+            stretch_method = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip"])? {
+            clip = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--stdev"])? {
+            num_stdev = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--gamma"])? {
+            gamma_str = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -238,16 +303,23 @@ impl WhiteboxTool for CreateColourComposite {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
+        let stretch = if stretch_method.contains("disp") {
+            None
+        } else {
+            Some(Stretch::parse(&stretch_method, clip, num_stdev)?)
+        };
+        let gamma = parse_channel_values(&gamma_str, "gamma")?;
+
         if verbose {
-            println!("Reading red band data...")
+            report::message("Reading red band data...")
         };
         let input_r = Arc::new(Raster::new(&input1_file, "r")?);
         if verbose {
-            println!("Reading green band data...")
+            report::message("Reading green band data...")
         };
         let input_g = Arc::new(Raster::new(&input2_file, "r")?);
         if verbose {
-            println!("Reading blue band data...")
+            report::message("Reading blue band data...")
         };
         let input_b = Arc::new(Raster::new(&input3_file, "r")?);
 
@@ -256,18 +328,14 @@ impl WhiteboxTool for CreateColourComposite {
         let nodata_r = input_r.configs.nodata;
         let nodata_g = input_g.configs.nodata;
         let nodata_b = input_b.configs.nodata;
-        let red_min = input_r.configs.display_min;
-        let green_min = input_g.configs.display_min;
-        let blue_min = input_b.configs.display_min;
-        let red_range = input_r.configs.display_max - red_min;
-        let green_range = input_g.configs.display_max - green_min;
-        let blue_range = input_b.configs.display_max - blue_min;
+        let mut output = Raster::initialize_using_file(&output_file, &input_r);
+        let out_nodata = output.configs.nodata;
         let a_min: f64;
         let a_range: f64;
         let input_a = match input4_used {
             true => {
                 if verbose {
-                    println!("Reading opacity data...")
+                    report::message("Reading opacity data...")
                 };
                 let opacity = Raster::new(&input4_file, "r")?;
                 a_min = opacity.configs.display_min;
@@ -276,7 +344,7 @@ impl WhiteboxTool for CreateColourComposite {
                     || input_r.configs.columns != opacity.configs.columns
                 {
                     return Err(Error::new(ErrorKind::InvalidInput,
-                                          "The input files must have the same number of rows and columns and spatial extent."));
+                                          "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
                 }
                 Arc::new(opacity.get_data_as_array2d())
             }
@@ -296,7 +364,7 @@ impl WhiteboxTool for CreateColourComposite {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
         if input_r.configs.rows != input_b.configs.rows
@@ -304,13 +372,39 @@ impl WhiteboxTool for CreateColourComposite {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
+        // the functions that map the values of each band to the range 0 to 1
+        let mut stretch_fns: Vec<Box<Fn(f64) -> f64 + Send + Sync>> = vec![];
+        for input in [&input_r, &input_g, &input_b].iter() {
+            let min = input.configs.display_min;
+            let range = input.configs.display_max - min;
+            let display_fn = move |z: f64| ((z - min) / range).max(0f64).min(1f64);
+            stretch_fns.push(match stretch {
+                Some(s) => match s.fit(input) {
+                    Some((f, description)) => {
+                        if verbose {
+                            report::message(&format!(
+                                "{}: {}",
+                                input.get_short_filename(),
+                                description
+                            ));
+                        }
+                        f
+                    }
+                    None => Box::new(display_fn),
+                },
+                None => Box::new(display_fn),
+            });
+        }
+        let stretch_fns = Arc::new(stretch_fns);
+
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
+            let stretch_fns = stretch_fns.clone();
             let input_r = input_r.clone();
             let input_g = input_g.clone();
             let input_b = input_b.clone();
@@ -323,38 +417,15 @@ impl WhiteboxTool for CreateColourComposite {
                 let mut a_val: f64;
                 let (mut r, mut g, mut b, mut a): (u32, u32, u32, u32);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata_r; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         red_val = input_r[(row, col)];
                         green_val = input_g[(row, col)];
                         blue_val = input_b[(row, col)];
                         if red_val != nodata_r && green_val != nodata_g && blue_val != nodata_b {
-                            red_val = (red_val - red_min) / red_range * 255f64;
-                            if red_val < 0f64 {
-                                red_val = 0f64;
-                            }
-                            if red_val > 255f64 {
-                                red_val = 255f64;
-                            }
-                            r = red_val as u32;
-
-                            green_val = (green_val - green_min) / green_range * 255f64;
-                            if green_val < 0f64 {
-                                green_val = 0f64;
-                            }
-                            if green_val > 255f64 {
-                                green_val = 255f64;
-                            }
-                            g = green_val as u32;
-
-                            blue_val = (blue_val - blue_min) / blue_range * 255f64;
-                            if blue_val < 0f64 {
-                                blue_val = 0f64;
-                            }
-                            if blue_val > 255f64 {
-                                blue_val = 255f64;
-                            }
-                            b = blue_val as u32;
+                            r = (255f64 * stretch_fns[0](red_val).powf(gamma[0])) as u32;
+                            g = (255f64 * stretch_fns[1](green_val).powf(gamma[1])) as u32;
+                            b = (255f64 * stretch_fns[2](blue_val).powf(gamma[2])) as u32;
 
                             a_val = input_a[(row, col)];
                             a_val = (a_val - a_min) / a_range * 255f64;
@@ -373,7 +444,6 @@ impl WhiteboxTool for CreateColourComposite {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input_r);
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
@@ -382,7 +452,7 @@ impl WhiteboxTool for CreateColourComposite {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -413,7 +483,7 @@ impl WhiteboxTool for CreateColourComposite {
             for row in 0..rows {
                 for col in 0..columns {
                     z = output[(row, col)];
-                    if z != nodata_r {
+                    if z != out_nodata {
                         num_pixels += 1f64;
                         r = z as u32 & 0xFF;
                         g = (z as u32 >> 8) & 0xFF;
@@ -450,7 +520,7 @@ impl WhiteboxTool for CreateColourComposite {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Enhancement (1 of 2): {}%", progress);
+                        report::progress("Performing Enhancement (1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -482,7 +552,7 @@ impl WhiteboxTool for CreateColourComposite {
             for row in 0..rows {
                 for col in 0..columns {
                     z = output[(row, col)];
-                    if z != nodata_r {
+                    if z != out_nodata {
                         r = z as u32 & 0xFF;
                         g = (z as u32 >> 8) & 0xFF;
                         b = (z as u32 >> 16) & 0xFF;
@@ -523,7 +593,7 @@ impl WhiteboxTool for CreateColourComposite {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Enhancement (2 of 2): {}%", progress);
+                        report::progress("Performing Enhancement (2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -541,25 +611,30 @@ impl WhiteboxTool for CreateColourComposite {
         if input4_used {
             output.add_metadata_entry(format!("Input opacity file: {}", input4_file));
         }
+        output.add_metadata_entry(format!(
+            "Stretch: {}",
+            match stretch {
+                Some(s) => s.name(),
+                None => String::from("display"),
+            }
+        ));
+        output.add_metadata_entry(format!("Gamma: {}, {}, {}", gamma[0], gamma[1], gamma[2]));
         output.add_metadata_entry(format!("Balance contrast enhancement: {}", enhance));
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
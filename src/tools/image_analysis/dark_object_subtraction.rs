@@ -0,0 +1,435 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::sensor_metadata::output_directory;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+
+/// This tool performs a simple image-based atmospheric correction of multispectral bands
+/// using the dark-object subtraction (DOS) method of Chavez (1996), converting top-of-
+/// atmosphere (TOA) reflectance to an approximation of surface reflectance. The inputs
+/// (`--inputs`) must be TOA reflectance bands, such as those created by the
+/// `LandsatToaConversion` and `Sentinel2ToaConversion` tools.
+///
+/// The method assumes that the darkest objects in a scene, e.g. deep clear water or dense
+/// shadow, have a true reflectance of 1%, such that any additional reflectance of these
+/// objects is due to atmospheric path radiance (haze). The dark-object value of each band is
+/// estimated automatically from its histogram as the lowest value that is shared by at least
+/// `--dark_pixels` cells (default 1000), which avoids selecting isolated noisy cells. For
+/// small images, the threshold is reduced to 0.1% of the valid cells. The surface
+/// reflectance is then:
+///
+/// > &rho; = (&rho;<sub>TOA</sub> - &rho;<sub>haze</sub>) / (T<sub>v</sub> T<sub>z</sub>)
+///
+/// where &rho;<sub>haze</sub> = &rho;<sub>dark</sub> - 0.01 T<sub>v</sub> T<sub>z</sub>, and
+/// T<sub>v</sub> and T<sub>z</sub> are the atmospheric transmittances along the view and
+/// illumination paths. The `--method` parameter selects one of two models:
+///
+/// - **DOS1** assumes that both transmittances are 1.
+/// - **DOS2** assumes that T<sub>v</sub> is 1 and that T<sub>z</sub> is the cosine of the sun
+///   zenith angle (`--sun_zenith`, in degrees) for bands with wavelengths shorter than 1 &mu;m,
+///   and 1 otherwise. The central wavelengths of the inputs, in &mu;m, may be specified
+///   (`--wavelengths`) in the order of the input files; if they are unspecified, the
+///   transmittance is applied to all bands, which is appropriate for visible and near-infrared
+///   bands only.
+///
+/// Each output is saved as an F32 GeoTIFF in the output directory (`--output_dir`; by default
+/// the working directory), named after its input with an '_sr' suffix. Negative surface
+/// reflectance values are set to zero. The estimated dark-object value of each band is
+/// reported and stored in the output metadata.
+///
+/// # Reference
+/// Chavez, P. S. (1996). Image-based atmospheric corrections - revisited and improved.
+/// *Photogrammetric Engineering and Remote Sensing*, 62(9), 1025-1036.
+///
+/// # See Also
+/// `LandsatToaConversion`, `Sentinel2ToaConversion`
+pub struct DarkObjectSubtraction {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DarkObjectSubtraction {
+    pub fn new() -> DarkObjectSubtraction {
+        // public constructor
+        let name = "DarkObjectSubtraction".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Performs a DOS1 or DOS2 dark-object subtraction atmospheric correction of TOA reflectance bands.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input TOA Reflectance Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input TOA reflectance band files.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Directory".to_owned(),
+            flags: vec!["--output_dir".to_owned()],
+            description: "Output directory; the working directory if unspecified.".to_owned(),
+            parameter_type: ParameterType::Directory,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Correction method; options are 'DOS1' and 'DOS2'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec!["DOS1".to_owned(), "DOS2".to_owned()]),
+            default_value: Some("DOS1".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sun Zenith Angle (DOS2)".to_owned(),
+            flags: vec!["--sun_zenith".to_owned()],
+            description: "Sun zenith angle of the scene, in degrees; required by DOS2.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Band Wavelengths (DOS2)".to_owned(),
+            flags: vec!["--wavelengths".to_owned()],
+            description: "Central wavelengths of the input bands, in micrometres, separated by semicolons.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Dark-Object Pixels".to_owned(),
+            flags: vec!["--dark_pixels".to_owned()],
+            description: "Minimum number of cells sharing the dark-object value.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1000".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='B2_toa.tif;B3_toa.tif;B4_toa.tif' --method=DOS1
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='B4_toa.tif;B5_toa.tif;B6_toa.tif' --output_dir=sr --method=DOS2 --sun_zenith=35.2 --wavelengths='0.655;0.865;1.61'", short_exe, name).replace("*", &sep);
+
+        DarkObjectSubtraction {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DarkObjectSubtraction {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_files_str = String::new();
+        let mut output_dir = String::new();
+        let mut method = String::from("dos1");
+        let mut sun_zenith: Option<f64> = None;
+        let mut wavelengths_str = String::new();
+        let mut dark_pixels = 1000usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--output_dir"])? {
+            output_dir = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.trim().to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--sun_zenith"])? {
+            sun_zenith = Some(v);
+        }
+        if let Some(v) = tool_args.get_string(&["--wavelengths"])? {
+            wavelengths_str = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--dark_pixels"])? {
+            dark_pixels = v.max(1) as usize;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let input_files: Vec<String> = input_files_str
+            .split(|c| c == ';' || c == ',')
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", working_directory, f)
+                } else {
+                    f.to_string()
+                }
+            })
+            .collect();
+        if input_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one input band file is required to operate this tool.",
+            ));
+        }
+
+        let dos2 = match method.as_ref() {
+            "dos1" => false,
+            "dos2" => true,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The --method parameter must be either 'DOS1' or 'DOS2'.",
+                ))
+            }
+        };
+        let cos_zenith = if dos2 {
+            match sun_zenith {
+                Some(z) if z >= 0f64 && z < 90f64 => z.to_radians().cos(),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The DOS2 method requires a sun zenith angle (--sun_zenith) between 0 and 90 degrees.",
+                    ))
+                }
+            }
+        } else {
+            1f64
+        };
+
+        let mut wavelengths: Vec<f64> = vec![];
+        for w in wavelengths_str.split(|c| c == ';' || c == ',') {
+            if w.trim().is_empty() {
+                continue;
+            }
+            match w.trim().parse::<f64>() {
+                Ok(v) => wavelengths.push(v),
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Invalid band wavelength '{}'.", w.trim()),
+                    ))
+                }
+            }
+        }
+        if !wavelengths.is_empty() && wavelengths.len() != input_files.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of band wavelengths does not match the number of input files.",
+            ));
+        }
+
+        let output_dir = output_directory(&output_dir, working_directory)?;
+
+        let start = Instant::now();
+
+        let num_files = input_files.len();
+        for (i, input_file) in input_files.iter().enumerate() {
+            let input = Raster::new(input_file, "r")?;
+            let in_nodata = input.configs.nodata;
+            let dark = match dark_object_value(&input, dark_pixels) {
+                Some(v) => v,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{} does not contain any valid cells.", input_file),
+                    ))
+                }
+            };
+
+            // the transmittance of the illumination path; DOS2 only applies it to bands
+            // with wavelengths shorter than 1 micrometre
+            let tz = match wavelengths.get(i) {
+                Some(w) if *w >= 1f64 => 1f64,
+                _ => cos_zenith,
+            };
+            let haze = dark - 0.01 * tz;
+
+            let stem = match Path::new(input_file).file_stem() {
+                Some(s) => s.to_string_lossy().to_string(),
+                None => format!("band{}", i + 1),
+            };
+            let output_file = Path::new(&output_dir)
+                .join(format!("{}_sr.tif", stem))
+                .to_string_lossy()
+                .to_string();
+            let nodata = -32768f64;
+            let mut output = Raster::initialize_using_file(&output_file, &input);
+            output.configs.data_type = DataType::F32;
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.configs.nodata = nodata;
+            output.configs.color_map = vec![];
+            for row in 0..input.configs.rows as isize {
+                let data: Vec<f64> = input
+                    .get_row_data(row)
+                    .iter()
+                    .map(|&z| {
+                        if z == in_nodata || !z.is_finite() {
+                            nodata
+                        } else {
+                            ((z - haze) / tz).max(0f64)
+                        }
+                    })
+                    .collect();
+                output.set_row_data(row, data);
+            }
+
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            output.add_metadata_entry(format!("Method: {}", method.to_uppercase()));
+            output.add_metadata_entry(format!("Dark-object value: {}", dark));
+            output.add_metadata_entry(format!("Haze reflectance: {}", haze));
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message(&format!(
+                        "{} written (dark-object value {:.6}, haze {:.6})",
+                        output_file, dark, haze
+                    ))
+                },
+                Err(e) => return Err(e),
+            };
+            if verbose {
+                report::progress(
+                    "Progress",
+                    (100.0_f64 * (i + 1) as f64 / num_files as f64) as usize,
+                );
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Estimates the dark-object value of a band from its histogram, as the lowest value that is
+/// shared by at least `min_pixels` cells, or by 0.1% of the valid cells for small images.
+/// Integer-valued bands are binned by value; other bands use 10000 bins spanning the range
+/// of the data.
+fn dark_object_value(input: &Raster, min_pixels: usize) -> Option<f64> {
+    let nodata = input.configs.nodata;
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut num_valid = 0usize;
+    let mut is_integer = true;
+    for row in 0..input.configs.rows as isize {
+        for z in input.get_row_data(row) {
+            if z != nodata && z.is_finite() {
+                num_valid += 1;
+                min = min.min(z);
+                max = max.max(z);
+                if z.fract() != 0f64 {
+                    is_integer = false;
+                }
+            }
+        }
+    }
+    if num_valid == 0 {
+        return None;
+    }
+    if max == min {
+        return Some(min);
+    }
+    let bin_width = if is_integer && max - min < 65536f64 {
+        1f64
+    } else {
+        (max - min) / 10000f64
+    };
+    let num_bins = ((max - min) / bin_width) as usize + 1;
+    let mut histogram = vec![0usize; num_bins];
+    for row in 0..input.configs.rows as isize {
+        for z in input.get_row_data(row) {
+            if z != nodata && z.is_finite() {
+                let bin = (((z - min) / bin_width) as usize).min(num_bins - 1);
+                histogram[bin] += 1;
+            }
+        }
+    }
+    let threshold = min_pixels.min(num_valid / 1000).max(1);
+    for (bin, count) in histogram.iter().enumerate() {
+        if *count >= threshold {
+            return Some(min + bin as f64 * bin_width);
+        }
+    }
+    Some(min)
+}
@@ -0,0 +1,533 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{dwt_2d, fft, idwt_2d};
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool removes striping from images (`--input`), such as the detector striping of
+/// push-broom and whisk-broom sensors, the banding of scanned imagery, and the flight-line
+/// and scan-line artifacts of LiDAR intensity rasters. The stripes may be vertical, i.e.
+/// aligned with the columns of the raster, or horizontal, aligned with its rows
+/// (`--direction`). Two methods are available (`--method`):
+///
+/// **Moment matching** (`moments`) assumes that each line of the image (each column, for
+/// vertical stripes) has a bias and gain of its own, and adjusts the mean and standard
+/// deviation of the line to those of a reference. The reference is either the whole image
+/// (`--window` of 0, the default) or, to preserve gradual changes in the scene across the
+/// image, the average moments of the lines within a moving window of the specified number
+/// of lines centred on each line. This method is fast and effective for detector striping,
+/// but assumes that the lines sample similar land covers.
+///
+/// **Wavelet-FFT filtering** (`wavelet`) is the combined filter of Münch et al. (2009). The
+/// image is decomposed with a multi-level discrete wavelet transform (`--levels`; Daubechies
+/// D4 wavelet), which separates the stripes, at each scale, into the detail coefficients
+/// that vary across the stripes. These coefficients are then Fourier transformed along the
+/// stripe direction, the low frequencies, which hold the stripe information since stripes
+/// do not vary along their length, are damped with a Gaussian notch of width `--sigma`
+/// (in frequency indices), and the image is reconstructed. This method also removes
+/// stripes that vary in strength along their length, and partial stripes, but more levels
+/// and larger sigma values also remove more of the scene's own linear features in the
+/// stripe direction.
+///
+/// Nodata cells are excluded from the moments and, for the wavelet filter, are assigned the
+/// mean of their line before filtering; they are nodata in the output. The output is a
+/// floating-point raster.
+///
+/// # Reference
+/// Münch, B., Trtik, P., Marone, F., and Stampanoni, M. (2009). Stripe and ring artifact
+/// removal with combined wavelet-Fourier filtering. Optics Express, 17(10), 8567-8591.
+///
+/// # See Also
+/// `FrequencyDomainFilter`, `FastFourierTransform`
+pub struct DestripingFilter {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DestripingFilter {
+    pub fn new() -> DestripingFilter {
+        // public constructor
+        let name = "DestripingFilter".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Removes horizontal or vertical striping from images using moment matching or a wavelet-FFT filter.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Destriping method; options include 'moments' and 'wavelet'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "moments".to_owned(),
+                "wavelet".to_owned(),
+            ]),
+            default_value: Some("moments".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Stripe Direction".to_owned(),
+            flags: vec!["--direction".to_owned()],
+            description: "Direction of the stripes; options include 'vertical' and 'horizontal'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "vertical".to_owned(),
+                "horizontal".to_owned(),
+            ]),
+            default_value: Some("vertical".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Reference Window Size (Lines)".to_owned(),
+            flags: vec!["--window".to_owned()],
+            description: "Moment matching reference window, in lines; 0 uses the whole image.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Wavelet Levels".to_owned(),
+            flags: vec!["--levels".to_owned()],
+            description: "Number of wavelet decomposition levels.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("4".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Damping Width (Sigma)".to_owned(),
+            flags: vec!["--sigma".to_owned()],
+            description: "Width of the Gaussian damping of the wavelet-FFT filter.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --direction=vertical --window=101
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=intensity.tif -o=output.tif --method=wavelet --direction=horizontal --levels=5 --sigma=3.0", short_exe, name).replace("*", &sep);
+
+        DestripingFilter {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DestripingFilter {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut method = String::from("moments");
+        let mut direction = String::from("vertical");
+        let mut window = 0isize;
+        let mut levels = 4isize;
+        let mut sigma = 2f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.trim().to_lowercase();
+        }
+        if let Some(v) = tool_args.get_string(&["--direction"])? {
+            direction = v.trim().to_lowercase();
+        }
+        if let Some(v) = tool_args.get_isize(&["--window"])? {
+            window = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--levels"])? {
+            levels = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--sigma"])? {
+            sigma = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let use_wavelet = if method.starts_with("wav") {
+            true
+        } else if method.starts_with("mom") {
+            false
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized method; options include 'moments' and 'wavelet'.",
+            ));
+        };
+        let vertical = if direction.starts_with("v") {
+            true
+        } else if direction.starts_with("h") {
+            false
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized stripe direction; options include 'vertical' and 'horizontal'.",
+            ));
+        };
+        if window < 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The reference window size must be zero or greater.",
+            ));
+        }
+        if use_wavelet && (levels < 1 || !(sigma > 0f64)) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of wavelet levels must be at least 1 and sigma must be greater than zero.",
+            ));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        if input.configs.photometric_interp == PhotometricInterpretation::RGB {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "This tool cannot be applied to RGB colour composites; split the composite into its components using the SplitColourComposite tool.",
+            ));
+        }
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows;
+        let columns = input.configs.columns;
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+
+        // the grid is arranged such that the stripes are vertical, i.e. each column is a line
+        let (num_along, num_lines) = if vertical {
+            (rows, columns)
+        } else {
+            (columns, rows)
+        };
+        let mut grid = vec![nodata; rows * columns];
+        for row in 0..rows {
+            let data = input.get_row_data(row as isize);
+            for col in 0..columns {
+                let i = if vertical {
+                    row * columns + col
+                } else {
+                    col * rows + row
+                };
+                grid[i] = data[col];
+            }
+        }
+
+        // the moments of each line
+        let mut counts = vec![0f64; num_lines];
+        let mut means = vec![0f64; num_lines];
+        let mut std_devs = vec![0f64; num_lines];
+        for l in 0..num_lines {
+            let (mut n, mut sum, mut sq) = (0f64, 0f64, 0f64);
+            for a in 0..num_along {
+                let z = grid[a * num_lines + l];
+                if z != nodata {
+                    n += 1f64;
+                    sum += z;
+                    sq += z * z;
+                }
+            }
+            if n > 0f64 {
+                counts[l] = n;
+                means[l] = sum / n;
+                std_devs[l] = (sq / n - means[l] * means[l]).max(0f64).sqrt();
+            }
+        }
+        if counts.iter().all(|&n| n == 0f64) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster does not contain any valid cells.",
+            ));
+        }
+
+        if use_wavelet {
+            let levels = levels as usize;
+            let min_dim = num_along.min(num_lines);
+            if min_dim >> levels < 2 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "There are too many wavelet levels for the size of the image.",
+                ));
+            }
+            if verbose {
+                report::message("Applying the wavelet-FFT filter...")
+            };
+            // nodata cells are assigned the mean of their line, and the grid is extended by
+            // reflection such that its dimensions are divisible by 2^levels
+            let total: f64 = (0..num_lines).map(|l| means[l] * counts[l]).sum();
+            let global_mean = total / counts.iter().sum::<f64>();
+            let block = 1usize << levels;
+            let padded_along = (num_along + block - 1) / block * block;
+            let padded_lines = (num_lines + block - 1) / block * block;
+            let reflect = |i: usize, n: usize| {
+                if i < n {
+                    i
+                } else {
+                    (2 * n).saturating_sub(2 + i).min(n - 1)
+                }
+            };
+            let mut padded = vec![0f64; padded_along * padded_lines];
+            for a in 0..padded_along {
+                let a_in = reflect(a, num_along);
+                for l in 0..padded_lines {
+                    let l_in = reflect(l, num_lines);
+                    let mut z = grid[a_in * num_lines + l_in];
+                    if z == nodata {
+                        z = if counts[l_in] > 0f64 {
+                            means[l_in]
+                        } else {
+                            global_mean
+                        };
+                    }
+                    padded[a * padded_lines + l] = z;
+                }
+            }
+
+            dwt_2d(&mut padded, padded_along, padded_lines, levels);
+            for level in 1..levels + 1 {
+                // the details that vary across the stripes, at this level
+                let n = padded_along >> level;
+                let (first, last) = (padded_lines >> level, padded_lines >> (level - 1));
+                let mut re = vec![0f64; n];
+                let mut im = vec![0f64; n];
+                for l in first..last {
+                    for a in 0..n {
+                        re[a] = padded[a * padded_lines + l];
+                        im[a] = 0f64;
+                    }
+                    fft(&mut re, &mut im, false);
+                    for k in 0..n {
+                        let f = k.min(n - k) as f64;
+                        let damping = 1f64 - (-f * f / (2f64 * sigma * sigma)).exp();
+                        re[k] *= damping;
+                        im[k] *= damping;
+                    }
+                    fft(&mut re, &mut im, true);
+                    for a in 0..n {
+                        padded[a * padded_lines + l] = re[a];
+                    }
+                }
+                if verbose {
+                    report::message(&format!("Filtered level {} of {}", level, levels));
+                }
+            }
+            idwt_2d(&mut padded, padded_along, padded_lines, levels);
+
+            for a in 0..num_along {
+                for l in 0..num_lines {
+                    let i = a * num_lines + l;
+                    if grid[i] != nodata {
+                        grid[i] = padded[a * padded_lines + l];
+                    }
+                }
+            }
+        } else {
+            if verbose {
+                report::message("Matching the moments of the lines...")
+            };
+            // the reference moments are the count-weighted averages of the lines' moments,
+            // over the window or the whole image
+            let half_window = if window == 0 {
+                num_lines
+            } else {
+                window as usize / 2
+            };
+            for l in 0..num_lines {
+                if counts[l] == 0f64 {
+                    continue;
+                }
+                let first = l.saturating_sub(half_window);
+                let last = (l + half_window + 1).min(num_lines);
+                let (mut n, mut mean, mut std_dev) = (0f64, 0f64, 0f64);
+                for j in first..last {
+                    n += counts[j];
+                    mean += counts[j] * means[j];
+                    std_dev += counts[j] * std_devs[j];
+                }
+                mean /= n;
+                std_dev /= n;
+                let gain = if std_devs[l] > 0f64 {
+                    std_dev / std_devs[l]
+                } else {
+                    1f64
+                };
+                for a in 0..num_along {
+                    let i = a * num_lines + l;
+                    if grid[i] != nodata {
+                        grid[i] = (grid[i] - means[l]) * gain + mean;
+                    }
+                }
+            }
+        }
+
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        if output.configs.data_type != DataType::F64 {
+            output.configs.data_type = DataType::F32;
+        }
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data: Vec<f64> = (0..columns)
+                .map(|col| {
+                    let z = if vertical {
+                        grid[row * columns + col]
+                    } else {
+                        grid[col * rows + row]
+                    };
+                    if z != nodata {
+                        z
+                    } else {
+                        out_nodata
+                    }
+                })
+                .collect();
+            output.set_row_data(row as isize, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!(
+            "Stripe direction: {}",
+            if vertical { "vertical" } else { "horizontal" }
+        ));
+        if use_wavelet {
+            output.add_metadata_entry(format!("Method: wavelet-FFT; levels: {}; sigma: {}", levels, sigma));
+        } else {
+            output.add_metadata_entry(format!("Method: moment matching; window: {}", window));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -142,59 +142,29 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["-k"])? {
+            achromatic_factor = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip_percent", "--clip"])? {
+            clip_percent = v;
+            if clip_percent < 0f64 {
+                clip_percent = 0f64;
             }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "-input"
-                || vec[0].to_lowercase() == "--input"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-k" || vec[0].to_lowercase() == "--k" {
-                if keyval {
-                    achromatic_factor = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    achromatic_factor = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-clip_percent"
-                || vec[0].to_lowercase() == "--clip_percent"
-                || vec[0].to_lowercase() == "--clip"
-            {
-                if keyval {
-                    clip_percent = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    clip_percent = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                if clip_percent < 0f64 {
-                    clip_percent = 0f64;
-                }
-                if clip_percent > 50f64 {
-                    clip_percent = 50f64;
-                }
-                clip_percent = clip_percent / 100f64;
+            if clip_percent > 50f64 {
+                clip_percent = 50f64;
             }
+            clip_percent = clip_percent / 100f64;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -217,7 +187,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         }
 
         if verbose {
-            println!("Reading image data...")
+            report::message("Reading image data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         // let input = Raster::new(&input_file, "r")?;
@@ -338,7 +308,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    report::progress("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -483,7 +453,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -500,12 +470,12 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -628,10 +598,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         // }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
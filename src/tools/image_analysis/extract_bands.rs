@@ -0,0 +1,330 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+
+/// This tool extracts selected bands from a multi-band GeoTIFF (`--input`), such as one
+/// created by the `ImageStack` tool. The bands are specified as a list of band numbers,
+/// starting from one, and ranges (`--bands`), e.g. '1,3-5'; all bands are extracted if this
+/// parameter is unspecified. The band names stored in the input file are retained.
+///
+/// By default, each selected band is saved as a separate single-band raster. If a single
+/// band is selected, it is saved to the output file (`--output`); otherwise, the band number
+/// is appended to the output file name, e.g. output_band3.tif. Alternatively, the `--stack`
+/// flag saves the selected bands, in the order specified, as a single multi-band GeoTIFF,
+/// which can be used to reorder or subset the bands of an image stack.
+///
+/// # See Also
+/// `ImageStack`, `SplitColourComposite`
+pub struct ExtractBands {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ExtractBands {
+    pub fn new() -> ExtractBands {
+        // public constructor
+        let name = "ExtractBands".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Extracts selected bands from a multi-band GeoTIFF.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input multi-band raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Bands".to_owned(),
+            flags: vec!["--bands".to_owned()],
+            description: "Band numbers and ranges to extract, e.g. '1,3-5'; default is all bands."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Save the bands as a single multi-band file?".to_owned(),
+            flags: vec!["--stack".to_owned()],
+            description: "Save the selected bands as a single multi-band GeoTIFF.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=stack.tif -o=band.tif --bands='2,4'
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=stack.tif -o=subset.tif --bands='3-5' --stack", short_exe, name).replace("*", &sep);
+
+        ExtractBands {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ExtractBands {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut bands_str = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--bands"])? {
+            bands_str = v;
+        }
+        let stack = tool_args.get_flag(&["--stack"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let first_band = Raster::new(&input_file, "r")?;
+        let num_bands = first_band.configs.bands.max(1) as usize;
+        let selected = parse_band_list(&bands_str, num_bands)?;
+
+        let start = Instant::now();
+
+        let band_name = |b: usize| match first_band.configs.band_names.get(b) {
+            Some(n) => n.clone(),
+            None => String::new(),
+        };
+
+        let mut stacked: Option<Raster> = None;
+        for (i, &b) in selected.iter().enumerate() {
+            let band = if b == 0 {
+                first_band.clone()
+            } else {
+                Raster::read_band(&input_file, b)?
+            };
+            if stack {
+                match stacked {
+                    Some(ref mut output) => output.add_band(&band)?,
+                    None => {
+                        let mut output = Raster::initialize_using_file(&output_file, &band);
+                        if output.raster_type != RasterType::GeoTiff {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                "The --stack output of this tool must be a GeoTIFF (.tif) file.",
+                            ));
+                        }
+                        output.set_data_from_raster(&band)?;
+                        stacked = Some(output);
+                    }
+                }
+            } else {
+                let file_name = if selected.len() == 1 {
+                    output_file.clone()
+                } else {
+                    band_file_name(&output_file, b + 1)
+                };
+                let mut output = Raster::initialize_using_file(&file_name, &band);
+                output.set_data_from_raster(&band)?;
+                output.configs.band_names = vec![band_name(b)];
+                output.add_metadata_entry(format!(
+                    "Created by whitebox_tools\' {} tool",
+                    self.get_tool_name()
+                ));
+                output.add_metadata_entry(format!("Input file: {}", input_file));
+                output.add_metadata_entry(format!("Band: {}", b + 1));
+                let _ = match output.write() {
+                    Ok(_) => if verbose {
+                        report::message(&format!("Band {} written to {}", b + 1, file_name))
+                    },
+                    Err(e) => return Err(e),
+                };
+            }
+            if verbose {
+                report::progress(
+                    "Progress",
+                    (100.0_f64 * (i + 1) as f64 / selected.len() as f64) as usize,
+                );
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if let Some(mut output) = stacked {
+            output.configs.band_names = selected.iter().map(|&b| band_name(b)).collect();
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            let band_list: Vec<String> = selected.iter().map(|b| (b + 1).to_string()).collect();
+            output.add_metadata_entry(format!("Bands: {}", band_list.join(",")));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a list of one-based band numbers and ranges (e.g. '1,3-5') into zero-based band
+/// indices. An empty list selects all bands.
+fn parse_band_list(value: &str, num_bands: usize) -> Result<Vec<usize>, Error> {
+    if value.trim().is_empty() {
+        return Ok((0..num_bands).collect());
+    }
+    let invalid = |s: &str| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Invalid band '{}'; bands are numbered from 1 to {}.",
+                s.trim(),
+                num_bands
+            ),
+        )
+    };
+    let parse_band = |s: &str| match s.trim().parse::<usize>() {
+        Ok(b) if b >= 1 && b <= num_bands => Ok(b - 1),
+        _ => Err(invalid(s)),
+    };
+    let mut bands = vec![];
+    for item in value.split(|c| c == ',' || c == ';') {
+        if item.trim().is_empty() {
+            continue;
+        }
+        let range: Vec<&str> = item.splitn(2, '-').collect();
+        if range.len() == 2 {
+            let (first, last) = (parse_band(range[0])?, parse_band(range[1])?);
+            if first > last {
+                return Err(invalid(item));
+            }
+            bands.extend(first..last + 1);
+        } else {
+            bands.push(parse_band(item)?);
+        }
+    }
+    Ok(bands)
+}
+
+/// Appends a band number to a file name, before its extension.
+fn band_file_name(file_name: &str, band: usize) -> String {
+    let path = Path::new(file_name);
+    let stem = match path.file_stem() {
+        Some(s) => s.to_string_lossy().to_string(),
+        None => String::from("band"),
+    };
+    let name = match path.extension() {
+        Some(ext) => format!("{}_band{}.{}", stem, band, ext.to_string_lossy()),
+        None => format!("{}_band{}", stem, band),
+    };
+    path.with_file_name(name).to_string_lossy().to_string()
+}
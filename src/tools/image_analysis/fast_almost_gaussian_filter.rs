@@ -138,40 +138,19 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-sigma" || vec[0].to_lowercase() == "--sigma" {
-                if keyval {
-                    sigma = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    sigma = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--sigma"])? {
+            sigma = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -187,7 +166,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         let mut old_progress: usize = 1;
 
         if sigma < 1.8 {
-            println!("Warning: Sigma values less than 1.8 cannot be achieved using this filter. Perhaps use the GaussianFilter tool instead.");
+            report::warning("Sigma values less than 1.8 cannot be achieved using this filter. Perhaps use the GaussianFilter tool instead.");
             sigma = 1.8;
         }
 
@@ -207,7 +186,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
             (((m * wl * wl) as f64 + ((n - m) as f64) * (wu * wu) as f64 - n as f64) / 12f64)
                 .sqrt();
         if verbose {
-            println!("Actual sigma: {:.3}", sigma_actual);
+            report::metadata("Actual sigma", &format!("{:.3}", sigma_actual));
         }
 
         let input = Raster::new(&input_file, "r")?;
@@ -217,6 +196,8 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let is_rgb_image = if input.configs.data_type == DataType::RGB24
             || input.configs.data_type == DataType::RGBA32
@@ -229,7 +210,6 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
 
         let mut integral: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
         let mut integral_n: Array2D<i32> = Array2D::new(rows, columns, 0, -1)?;
-        let mut output = Raster::initialize_using_file(&output_file, &input);
 
         let mut val: f64;
         let mut sum: f64;
@@ -241,7 +221,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
 
         for iteration_num in 0..n {
             if verbose {
-                println!("Loop {} of {}", iteration_num + 1, n);
+                report::message(&format!("Loop {} of {}", iteration_num + 1, n));
             }
 
             let midpoint = if iteration_num < m {
@@ -296,7 +276,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                     sum = 0f64;
                     for col in 0..columns {
                         val = output.get_value(row, col);
-                        if val == nodata {
+                        if val == out_nodata {
                             val = 0f64;
                         }
                         sum += val;
@@ -351,11 +331,9 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Progress (Loop {} of {}): {}%",
-                                iteration_num + 1,
-                                n,
-                                progress
+                            report::progress(
+                                &format!("Progress (Loop {} of {})", iteration_num + 1, n),
+                                progress,
                             );
                             old_progress = progress;
                         }
@@ -419,11 +397,9 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Progress (Loop {} of {}): {}%",
-                                iteration_num + 1,
-                                n,
-                                progress
+                            report::progress(
+                                &format!("Progress (Loop {} of {})", iteration_num + 1, n),
+                                progress,
                             );
                             old_progress = progress;
                         }
@@ -442,22 +418,19 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
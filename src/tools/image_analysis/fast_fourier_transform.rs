@@ -0,0 +1,293 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::frequency_domain::{centred_index, forward_transform};
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool calculates the two-dimensional discrete Fourier transform of a raster
+/// (`--input`) using the fast Fourier transform (FFT) and outputs the magnitude of the
+/// spectrum (`--output`), which shows the strength of the image's spatial frequencies.
+/// Periodic patterns in the image, such as the striping of scanned air photos or the
+/// periodic noise of some sensors, appear in the magnitude image as bright spots located
+/// away from its centre. The output is the natural logarithm of one plus the magnitude,
+/// which compresses the very large range of magnitudes for display.
+///
+/// The spectrum is centred, i.e. the zero frequency (the image mean) is at the centre cell
+/// (row rows / 2, column columns / 2) and frequencies increase outwards to the Nyquist
+/// frequency at the edges. The magnitude image has the same grid as the input, such that
+/// masks of the noise spikes can be digitized over it and used with the
+/// `FrequencyDomainFilter` tool.
+///
+/// The complex spectrum may also be saved (`--spectrum`), as a two-band GeoTIFF of the real
+/// and imaginary parts with the same centred layout, which can be transformed back into an
+/// image using the `InverseFastFourierTransform` tool. Nodata cells are assigned the mean of
+/// the valid cells before the transform. Because the transform treats the image as
+/// periodic, discontinuities between opposite edges of the image produce a bright cross
+/// through the centre of the magnitude image.
+///
+/// # See Also
+/// `InverseFastFourierTransform`, `FrequencyDomainFilter`
+pub struct FastFourierTransform {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl FastFourierTransform {
+    pub fn new() -> FastFourierTransform {
+        // public constructor
+        let name = "FastFourierTransform".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Calculates the 2D Fourier transform of a raster and outputs its magnitude spectrum.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Magnitude File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output log-scaled magnitude raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Spectrum File".to_owned(),
+            flags: vec!["--spectrum".to_owned()],
+            description: "Optional output two-band GeoTIFF of the real and imaginary parts of the spectrum.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=magnitude.tif --spectrum=spectrum.tif", short_exe, name).replace("*", &sep);
+
+        FastFourierTransform {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for FastFourierTransform {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut spectrum_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--spectrum"])? {
+            spectrum_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !spectrum_file.is_empty() && !spectrum_file.contains(&sep) && !spectrum_file.contains("/")
+        {
+            spectrum_file = format!("{}{}", working_directory, spectrum_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        if input.configs.photometric_interp == PhotometricInterpretation::RGB {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "This tool cannot be applied to RGB colour composites; split the composite into its components using the SplitColourComposite tool.",
+            ));
+        }
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows;
+        let columns = input.configs.columns;
+
+        if verbose {
+            report::message("Calculating the Fourier transform...")
+        };
+        let (re, im) = forward_transform(&input);
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.nodata = -32768f64;
+        output.configs.z_units = String::from("not specified");
+        output.configs.band_names = vec![];
+        let mut spectrum = if spectrum_file.is_empty() {
+            None
+        } else {
+            let mut s = Raster::initialize_using_file(&spectrum_file, &input);
+            if s.raster_type != RasterType::GeoTiff {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The spectrum output of this tool must be a GeoTIFF (.tif) file.",
+                ));
+            }
+            s.configs.data_type = DataType::F64;
+            s.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            s.configs.nodata = -32768f64;
+            s.configs.z_units = String::from("not specified");
+            s.configs.band_names = vec![String::from("real"), String::from("imaginary")];
+            Some((s.clone(), s))
+        };
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for u in 0..rows {
+            let row = centred_index(u, rows) as isize;
+            for v in 0..columns {
+                let col = centred_index(v, columns) as isize;
+                let i = u * columns + v;
+                let magnitude = (re[i] * re[i] + im[i] * im[i]).sqrt();
+                output.set_value(row, col, magnitude.ln_1p());
+                if let Some((ref mut real, ref mut imaginary)) = spectrum {
+                    real.set_value(row, col, re[i]);
+                    imaginary.set_value(row, col, im[i]);
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * u as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if let Some((mut real, imaginary)) = spectrum {
+            real.add_band(&imaginary)?;
+            real.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            real.add_metadata_entry(format!("Input file: {}", input_file));
+            real.add_metadata_entry(String::from("Centred spectrum; band 1 is real, band 2 is imaginary"));
+            let _ = match real.write() {
+                Ok(_) => if verbose {
+                    report::message("Spectrum file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(String::from("Centred spectrum; values are ln(1 + magnitude)"));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -130,49 +130,28 @@ impl WhiteboxTool for FlipImage {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-dir" || vec[0].to_lowercase() == "--direction" {
-                if keyval {
-                    direction = vec[1].to_string();
-                } else {
-                    direction = args[i + 1].to_string();
-                }
-                if direction.to_lowercase().contains("v") {
-                    direction = "v".to_string();
-                } else if direction.to_lowercase().contains("h")
-                    && !direction.to_lowercase().contains("b")
-                {
-                    direction = "h".to_string();
-                } else {
-                    direction = "b".to_string();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dir", "--direction"])? {
+            direction = v;
+            if direction.to_lowercase().contains("v") {
+                direction = "v".to_string();
+            } else if direction.to_lowercase().contains("h")
+                && !direction.to_lowercase().contains("b")
+            {
+                direction = "h".to_string();
+            } else {
+                direction = "b".to_string();
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -193,8 +172,8 @@ impl WhiteboxTool for FlipImage {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
-
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -208,18 +187,24 @@ impl WhiteboxTool for FlipImage {
                 match &direction as &str {
                     "v" => {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
-                                data[col as usize] = input[(rows_less_one - row, col)];
+                                let z = input[(rows_less_one - row, col)];
+                                if z != nodata {
+                                    data[col as usize] = z;
+                                }
                             }
                             tx.send((row, data)).unwrap();
                         }
                     }
                     "h" => {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
-                                data[col as usize] = input[(row, cols_less_one - col)];
+                                let z = input[(row, cols_less_one - col)];
+                                if z != nodata {
+                                    data[col as usize] = z;
+                                }
                             }
                             tx.send((row, data)).unwrap();
                         }
@@ -227,10 +212,12 @@ impl WhiteboxTool for FlipImage {
                     _ => {
                         // both
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
-                                data[col as usize] =
-                                    input[(rows_less_one - row, cols_less_one - col)];
+                                let z = input[(rows_less_one - row, cols_less_one - col)];
+                                if z != nodata {
+                                    data[col as usize] = z;
+                                }
                             }
                             tx.send((row, data)).unwrap();
                         }
@@ -245,7 +232,7 @@ impl WhiteboxTool for FlipImage {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -261,21 +248,18 @@ impl WhiteboxTool for FlipImage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
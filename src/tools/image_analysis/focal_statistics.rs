@@ -0,0 +1,717 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::majority_filter::kernel_offsets;
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use tools::*;
+
+/// This tool calculates a statistic (`--stat`) of the values within a moving window, or
+/// kernel, centred on each grid cell of an input raster (`--input`). The available
+/// statistics are the mean, median, minimum, maximum, range, standard deviation
+/// (`stdev`), and an arbitrary percentile (`percentile`, with the percentile specified
+/// by `--percentile`).
+///
+/// The kernel (`--kernel`) may be:
+///
+/// - `rectangle`: all cells within a rectangle of `--filterx` by `--filtery` cells.
+/// - `circle`: the cells within the ellipse inscribed in this rectangle.
+/// - `annulus`: the cells of the circular kernel that lie outside of an inner circle with
+///   a diameter of `--inner_size` cells, which is useful for comparing cells with their
+///   surroundings, e.g. for topographic position analysis.
+/// - `file`: a matrix of weights read from a text file (`--weights`), in which each line
+///   contains one row of the kernel, with the weights separated by commas or spaces. The
+///   kernel must have odd numbers of rows and columns and is centred on each cell. Cells
+///   with zero weight are excluded and the mean, standard deviation, median, and
+///   percentiles are weighted.
+///
+/// NoData values within the kernel are ignored and NoData cells remain NoData in the
+/// output. For rectangular kernels, the mean and standard deviation are updated from
+/// column to column as the kernel moves along a row, rather than being recalculated, and
+/// the median and percentiles are found using a rolling histogram of the values, as in
+/// `ElevPercentile`. In this case, the values are binned to a specified number of
+/// significant decimal digits (`--sig_digits`), which determines the precision of the
+/// output. The median and percentiles of other kernels are calculated exactly, with the
+/// percentile being the smallest value for which the (weighted) proportion of the kernel's
+/// values that are less than or equal to it reaches the percentile.
+///
+/// # See Also
+/// `MeanFilter`, `MedianFilter`, `PercentileFilter`, `UserDefinedWeightsFilter`,
+/// `ElevPercentile`
+pub struct FocalStatistics {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl FocalStatistics {
+    pub fn new() -> FocalStatistics {
+        // public constructor
+        let name = "FocalStatistics".to_string();
+        let toolbox = "Image Processing Tools/Filters".to_string();
+        let description =
+            "Calculates a statistic of the values within a rectangular, circular, annular, or user-defined kernel."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Statistic".to_owned(),
+            flags: vec!["--stat".to_owned()],
+            description: "Statistic to calculate; options are 'mean', 'median', 'minimum', 'maximum', 'range', 'stdev', and 'percentile'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "mean".to_owned(),
+                "median".to_owned(),
+                "minimum".to_owned(),
+                "maximum".to_owned(),
+                "range".to_owned(),
+                "stdev".to_owned(),
+                "percentile".to_owned(),
+            ]),
+            default_value: Some("mean".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Percentile".to_owned(),
+            flags: vec!["--percentile".to_owned()],
+            description: "Percentile (0-100) calculated by the 'percentile' statistic.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("50.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Kernel Shape".to_owned(),
+            flags: vec!["--kernel".to_owned()],
+            description: "Shape of the kernel; options are 'rectangle', 'circle', 'annulus', and 'file'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "rectangle".to_owned(),
+                "circle".to_owned(),
+                "annulus".to_owned(),
+                "file".to_owned(),
+            ]),
+            default_value: Some("rectangle".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter X-Dimension".to_owned(),
+            flags: vec!["--filterx".to_owned()],
+            description: "Size of the kernel in the x-direction.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("11".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Y-Dimension".to_owned(),
+            flags: vec!["--filtery".to_owned()],
+            description: "Size of the kernel in the y-direction.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("11".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Annulus Inner Diameter".to_owned(),
+            flags: vec!["--inner_size".to_owned()],
+            description: "Diameter, in cells, of the inner circle excluded from an annulus kernel."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Kernel Weights File".to_owned(),
+            flags: vec!["--weights".to_owned()],
+            description: "Text file containing the kernel weights, for the 'file' kernel."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Text),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Significant Digits".to_owned(),
+            flags: vec!["--sig_digits".to_owned()],
+            description: "Number of significant digits of the rolling histogram of rectangular kernels."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("2".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --stat=stdev --kernel=circle --filterx=15 --filtery=15
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --stat=percentile --percentile=90 --kernel=annulus --filterx=21 --filtery=21 --inner_size=9
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --stat=median --kernel=file --weights=kernel.txt",
+            short_exe, name
+        ).replace("*", &sep);
+
+        FocalStatistics {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for FocalStatistics {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut stat_name = "mean".to_string();
+        let mut percentile = 50f64;
+        let mut kernel_shape = "rectangle".to_string();
+        let mut filter_size_x = 11usize;
+        let mut filter_size_y = 11usize;
+        let mut inner_size = 3usize;
+        let mut weights_file = String::new();
+        let mut num_sig_digits = 2i32;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--stat"])? {
+            stat_name = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--percentile"])? {
+            percentile = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--kernel"])? {
+            kernel_shape = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--inner_size"])? {
+            inner_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--weights"])? {
+            weights_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--sig_digits"])? {
+            num_sig_digits = v as i32;
+        }
+
+        let stat = match stat_name.as_ref() {
+            "mean" | "average" => Statistic::Mean,
+            "median" => Statistic::Percentile(50f64),
+            "min" | "minimum" => Statistic::Minimum,
+            "max" | "maximum" => Statistic::Maximum,
+            "range" => Statistic::Range,
+            "stdev" | "std" | "sd" | "standard deviation" => Statistic::StdDev,
+            "percentile" => {
+                if percentile < 0f64 || percentile > 100f64 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The percentile must be between 0 and 100.",
+                    ));
+                }
+                Statistic::Percentile(percentile)
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unrecognized statistic '{}'.", stat_name),
+                ))
+            }
+        };
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        // The filter dimensions must be odd numbers such that there is a middle pixel
+        if filter_size_x < 3 {
+            filter_size_x = 3;
+        }
+        if filter_size_y < 3 {
+            filter_size_y = 3;
+        }
+        if filter_size_x % 2 == 0 {
+            filter_size_x += 1;
+        }
+        if filter_size_y % 2 == 0 {
+            filter_size_y += 1;
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        // the kernel's (column, row) offsets and weights
+        let mut kernel: Vec<(isize, isize, f64)> = vec![];
+        let rectangular = kernel_shape.starts_with("rect");
+        if rectangular || kernel_shape.starts_with("circ") {
+            for (dx, dy) in kernel_offsets(filter_size_x, filter_size_y, !rectangular) {
+                kernel.push((dx, dy, 1f64));
+            }
+        } else if kernel_shape.starts_with("ann") {
+            let r = inner_size as f64 / 2f64;
+            for (dx, dy) in kernel_offsets(filter_size_x, filter_size_y, true) {
+                if ((dx * dx + dy * dy) as f64).sqrt() > r {
+                    kernel.push((dx, dy, 1f64));
+                }
+            }
+        } else if kernel_shape.starts_with("file") {
+            if weights_file.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "A kernel weights file (--weights) must be specified for the 'file' kernel.",
+                ));
+            }
+            if !weights_file.contains(&sep) && !weights_file.contains("/") {
+                weights_file = format!("{}{}", working_directory, weights_file);
+            }
+            kernel = read_kernel_weights(&weights_file)?;
+            let (nx, ny) = kernel.iter().fold((0, 0), |a, k| (a.0.max(k.0), a.1.max(k.1)));
+            filter_size_x = 2 * nx as usize + 1;
+            filter_size_y = 2 * ny as usize + 1;
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized kernel shape '{}'.", kernel_shape),
+            ));
+        }
+        if kernel.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The kernel does not contain any cells.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        let midpoint_x = (filter_size_x / 2) as isize;
+        let midpoint_y = (filter_size_y / 2) as isize;
+
+        // for the rolling histogram, the values are binned to the significant digits
+        let multiplier = 10f64.powi(num_sig_digits);
+        let min_bin = (input.configs.minimum * multiplier).floor() as i64;
+        let num_bins = ((input.configs.maximum * multiplier).floor() as i64 - min_bin + 1) as usize;
+
+        let shift = (input.configs.minimum + input.configs.maximum) / 2f64;
+
+        let kernel = Arc::new(kernel);
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let kernel = kernel.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut z: f64;
+                let mut values: Vec<(f64, f64)> = Vec::with_capacity(kernel.len());
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![out_nodata; columns as usize];
+                    if rectangular && stat.is_percentile() {
+                        let p = stat.percentile();
+                        let mut window = RollingHistogram::new(num_bins);
+                        for col in 0..columns {
+                            // slide the window; the first column initializes it
+                            let first_col = if col == 0 { -midpoint_x } else { col + midpoint_x };
+                            for col2 in first_col..col + midpoint_x + 1 {
+                                for row2 in row - midpoint_y..row + midpoint_y + 1 {
+                                    z = input.get_value(row2, col2);
+                                    if z != nodata {
+                                        window.add((z * multiplier).floor() as i64 - min_bin);
+                                    }
+                                    if col > 0 {
+                                        z = input.get_value(row2, col - midpoint_x - 1);
+                                        if z != nodata {
+                                            window.remove(
+                                                (z * multiplier).floor() as i64 - min_bin,
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                            if input.get_value(row, col) != nodata && window.n > 0 {
+                                data[col as usize] =
+                                    (window.percentile(p) as i64 + min_bin) as f64 / multiplier;
+                            }
+                        }
+                    } else if rectangular && (stat == Statistic::Mean || stat == Statistic::StdDev)
+                    {
+                        // running sums, of values centred on the data range for precision
+                        let (mut n, mut sum, mut sum_sqr) = (0f64, 0f64, 0f64);
+                        for col in 0..columns {
+                            let first_col = if col == 0 { -midpoint_x } else { col + midpoint_x };
+                            for col2 in first_col..col + midpoint_x + 1 {
+                                for row2 in row - midpoint_y..row + midpoint_y + 1 {
+                                    z = input.get_value(row2, col2);
+                                    if z != nodata {
+                                        n += 1f64;
+                                        sum += z - shift;
+                                        sum_sqr += (z - shift) * (z - shift);
+                                    }
+                                    if col > 0 {
+                                        z = input.get_value(row2, col - midpoint_x - 1);
+                                        if z != nodata {
+                                            n -= 1f64;
+                                            sum -= z - shift;
+                                            sum_sqr -= (z - shift) * (z - shift);
+                                        }
+                                    }
+                                }
+                            }
+                            if input.get_value(row, col) != nodata && n > 0f64 {
+                                data[col as usize] = if stat == Statistic::Mean {
+                                    shift + sum / n
+                                } else {
+                                    (sum_sqr / n - (sum / n) * (sum / n)).max(0f64).sqrt()
+                                };
+                            }
+                        }
+                    } else {
+                        for col in 0..columns {
+                            if input.get_value(row, col) == nodata {
+                                continue;
+                            }
+                            values.clear();
+                            for &(dx, dy, w) in kernel.iter() {
+                                z = input.get_value(row + dy, col + dx);
+                                if z != nodata && w > 0f64 {
+                                    values.push((z, w));
+                                }
+                            }
+                            if !values.is_empty() {
+                                data[col as usize] = stat.calculate(&mut values);
+                            }
+                        }
+                    }
+                    tx.send((row, data)).unwrap();
+                }
+            });
+        }
+
+        output.configs.data_type = DataType::F32;
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for r in 0..rows {
+            let (row, data) = rx.recv().unwrap();
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Statistic: {}", stat_name));
+        if stat_name == "percentile" {
+            output.add_metadata_entry(format!("Percentile: {}", percentile));
+        }
+        output.add_metadata_entry(format!("Kernel shape: {}", kernel_shape));
+        if weights_file.is_empty() {
+            output.add_metadata_entry(format!("Filter size x: {}", filter_size_x));
+            output.add_metadata_entry(format!("Filter size y: {}", filter_size_y));
+        } else {
+            output.add_metadata_entry(format!("Weights file: {}", weights_file));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Statistic {
+    Mean,
+    Minimum,
+    Maximum,
+    Range,
+    StdDev,
+    Percentile(f64),
+}
+
+impl Statistic {
+    fn is_percentile(&self) -> bool {
+        match *self {
+            Statistic::Percentile(_) => true,
+            _ => false,
+        }
+    }
+
+    fn percentile(&self) -> f64 {
+        match *self {
+            Statistic::Percentile(p) => p,
+            _ => 50f64,
+        }
+    }
+
+    /// Calculates the statistic of a non-empty set of (value, weight) pairs.
+    fn calculate(&self, values: &mut Vec<(f64, f64)>) -> f64 {
+        match *self {
+            Statistic::Mean | Statistic::StdDev => {
+                let sum_w: f64 = values.iter().map(|v| v.1).sum();
+                let mean = values.iter().map(|v| v.0 * v.1).sum::<f64>() / sum_w;
+                if *self == Statistic::Mean {
+                    return mean;
+                }
+                let ss: f64 = values.iter().map(|v| v.1 * (v.0 - mean) * (v.0 - mean)).sum();
+                (ss / sum_w).sqrt()
+            }
+            Statistic::Minimum => values.iter().fold(f64::INFINITY, |a, v| a.min(v.0)),
+            Statistic::Maximum => values.iter().fold(f64::NEG_INFINITY, |a, v| a.max(v.0)),
+            Statistic::Range => {
+                let (min, max) = values
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |a, v| {
+                        (a.0.min(v.0), a.1.max(v.0))
+                    });
+                max - min
+            }
+            Statistic::Percentile(p) => {
+                values.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                let target = p / 100f64 * values.iter().map(|v| v.1).sum::<f64>();
+                let mut cumulative = 0f64;
+                for v in values.iter() {
+                    cumulative += v.1;
+                    if cumulative >= target {
+                        return v.0;
+                    }
+                }
+                values[values.len() - 1].0
+            }
+        }
+    }
+}
+
+/// A histogram of binned values that tracks the bin containing a percentile as values
+/// are added and removed, such that the percentile bin only moves by the distance that
+/// the window's values change.
+struct RollingHistogram {
+    histo: Vec<usize>,
+    n: usize,
+    bin: usize,
+    n_below: usize,
+}
+
+impl RollingHistogram {
+    fn new(num_bins: usize) -> RollingHistogram {
+        RollingHistogram {
+            histo: vec![0; num_bins],
+            n: 0,
+            bin: 0,
+            n_below: 0,
+        }
+    }
+
+    fn add(&mut self, bin: i64) {
+        let bin = bin as usize;
+        self.histo[bin] += 1;
+        self.n += 1;
+        if bin < self.bin {
+            self.n_below += 1;
+        }
+    }
+
+    fn remove(&mut self, bin: i64) {
+        let bin = bin as usize;
+        self.histo[bin] -= 1;
+        self.n -= 1;
+        if bin < self.bin {
+            self.n_below -= 1;
+        }
+    }
+
+    /// Returns the lowest bin at which the cumulative count reaches the percentile `p`.
+    fn percentile(&mut self, p: f64) -> usize {
+        let target = ((p / 100f64 * self.n as f64).ceil() as usize).max(1);
+        while self.bin > 0 && self.n_below >= target {
+            self.bin -= 1;
+            self.n_below -= self.histo[self.bin];
+        }
+        while self.n_below + self.histo[self.bin] < target {
+            self.n_below += self.histo[self.bin];
+            self.bin += 1;
+        }
+        self.bin
+    }
+}
+
+/// Reads a kernel of weights, one row per line, with the weights separated by commas or
+/// spaces, returning the (column, row) offsets of the cells from the kernel centre.
+fn read_kernel_weights(file_name: &str) -> Result<Vec<(isize, isize, f64)>, Error> {
+    let f = BufReader::new(File::open(file_name)?);
+    let mut weights: Vec<Vec<f64>> = vec![];
+    for line in f.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut row = vec![];
+        for s in line.split(|c| c == ',' || c == ' ' || c == '\t' || c == ';') {
+            if !s.trim().is_empty() {
+                match s.trim().parse::<f64>() {
+                    Ok(w) if w >= 0f64 => row.push(w),
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("Invalid kernel weight '{}'; weights must be non-negative numbers.", s.trim()),
+                        ))
+                    }
+                }
+            }
+        }
+        weights.push(row);
+    }
+    let num_rows = weights.len();
+    let num_cols = if num_rows > 0 { weights[0].len() } else { 0 };
+    if num_rows % 2 == 0 || num_cols % 2 == 0 || weights.iter().any(|r| r.len() != num_cols) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "The kernel must contain an odd number of rows, each with the same odd number of weights.",
+        ));
+    }
+    let mut kernel = vec![];
+    for r in 0..num_rows {
+        for c in 0..num_cols {
+            if weights[r][c] > 0f64 {
+                kernel.push((
+                    c as isize - (num_cols / 2) as isize,
+                    r as isize - (num_rows / 2) as isize,
+                    weights[r][c],
+                ));
+            }
+        }
+    }
+    Ok(kernel)
+}
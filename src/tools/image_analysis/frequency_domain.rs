@@ -0,0 +1,56 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::fft_2d;
+use raster::*;
+
+/// Calculates the 2D Fourier transform of a raster, returning the real and imaginary parts
+/// of the spectrum in row-major order, with the zero frequency in the first cell. Nodata
+/// cells are assigned the mean of the valid cells before the transform, such that they
+/// do not contribute to any frequency but the zero frequency.
+pub fn forward_transform(input: &Raster) -> (Vec<f64>, Vec<f64>) {
+    let rows = input.configs.rows;
+    let columns = input.configs.columns;
+    let nodata = input.configs.nodata;
+    let mut re = Vec::with_capacity(rows * columns);
+    let (mut sum, mut n) = (0f64, 0f64);
+    for row in 0..rows as isize {
+        for z in input.get_row_data(row) {
+            if z != nodata {
+                sum += z;
+                n += 1f64;
+            }
+            re.push(z);
+        }
+    }
+    let mean = if n > 0f64 { sum / n } else { 0f64 };
+    for z in re.iter_mut() {
+        if *z == nodata {
+            *z = mean;
+        }
+    }
+    let mut im = vec![0f64; rows * columns];
+    fft_2d(&mut re, &mut im, rows, columns, false);
+    (re, im)
+}
+
+/// Returns the index at which the frequency index `u` of a sequence of length `n` is
+/// stored in a centred spectrum, in which the zero frequency is at index n / 2.
+pub fn centred_index(u: usize, n: usize) -> usize {
+    (u + n / 2) % n
+}
+
+/// Returns the signed frequency, in cycles per cell, of the frequency index `u` of a
+/// sequence of length `n`.
+pub fn frequency(u: usize, n: usize) -> f64 {
+    if 2 * u < n {
+        u as f64 / n as f64
+    } else {
+        u as f64 / n as f64 - 1f64
+    }
+}
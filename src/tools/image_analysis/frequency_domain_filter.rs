@@ -0,0 +1,503 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::frequency_domain::{centred_index, forward_transform, frequency};
+use algorithms::fft_2d;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool filters a raster (`--input`) in the frequency domain. The image is transformed
+/// using the fast Fourier transform (FFT), its spectrum is multiplied by the transfer
+/// function of the filter, and the result is transformed back into an image (`--output`).
+/// Frequency-domain filtering is well suited to removing periodic noise, such as the
+/// striping of scanned air photos and the banding of some sensors, and to separating
+/// regional and local components of surfaces.
+///
+/// The filter type (`--filter`) may be `lowpass`, which retains wavelengths longer than the
+/// cutoff wavelength (`--cutoff`), `highpass`, which retains shorter wavelengths, or
+/// `bandpass` and `bandstop`, which retain or remove the wavelengths between `--cutoff` and
+/// a second cutoff wavelength (`--cutoff2`). Wavelengths are in the horizontal units of the
+/// input (e.g. metres), and the filters are radial, i.e. they affect all orientations
+/// equally. The shape of the transfer function (`--shape`) may be `ideal`, which has a
+/// sharp cutoff but causes ringing near edges, `butterworth`, whose steepness is set by its
+/// order (`--order`), or `gaussian`, which has the smoothest transition. A high-pass filter
+/// removes the image mean, and its output values are therefore centred on zero.
+///
+/// Alternatively, or in addition, the frequencies to remove may be specified using a mask
+/// (`--mask`), which is typically digitized over the noise spikes of the magnitude image
+/// created by the `FastFourierTransform` tool and rasterized on the same grid. The spectrum
+/// is multiplied by one minus the mask value, clipped to the range 0-1, such that cells with
+/// a value of one (or more) are removed and cells with a value of zero, or nodata, are
+/// retained; fractional values may be used to feather the edges of the mask. The mask is
+/// applied symmetrically about the centre of the spectrum, since the spectra of real images
+/// are symmetric, and it therefore only needs to be digitized for one of each pair of spikes.
+///
+/// Nodata cells are assigned the mean of the valid cells before the transform and are
+/// restored in the output. Because the transform treats the image as periodic, values near
+/// the edges of the image are affected by those near the opposite edges.
+///
+/// # See Also
+/// `FastFourierTransform`, `InverseFastFourierTransform`, `GaussianFilter`, `HighPassFilter`
+pub struct FrequencyDomainFilter {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl FrequencyDomainFilter {
+    pub fn new() -> FrequencyDomainFilter {
+        // public constructor
+        let name = "FrequencyDomainFilter".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Applies low-pass, high-pass, band-pass, band-stop or masked filters to a raster in the frequency domain.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Type".to_owned(),
+            flags: vec!["--filter".to_owned()],
+            description: "Filter type; options include 'none', 'lowpass', 'highpass', 'bandpass', and 'bandstop'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "none".to_owned(),
+                "lowpass".to_owned(),
+                "highpass".to_owned(),
+                "bandpass".to_owned(),
+                "bandstop".to_owned(),
+            ]),
+            default_value: Some("none".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cutoff Wavelength".to_owned(),
+            flags: vec!["--cutoff".to_owned()],
+            description: "Cutoff wavelength, in horizontal units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Second Cutoff Wavelength".to_owned(),
+            flags: vec!["--cutoff2".to_owned()],
+            description: "Second cutoff wavelength of band-pass and band-stop filters.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Shape".to_owned(),
+            flags: vec!["--shape".to_owned()],
+            description: "Shape of the transfer function; options include 'ideal', 'butterworth', and 'gaussian'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "ideal".to_owned(),
+                "butterworth".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("butterworth".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Butterworth Order".to_owned(),
+            flags: vec!["--order".to_owned()],
+            description: "Order of the Butterworth filter.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("2".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Mask File (optional)".to_owned(),
+            flags: vec!["--mask".to_owned()],
+            description: "Optional raster of the frequencies to remove, on the grid of the centred spectrum.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --filter=lowpass --cutoff=250.0 --shape=gaussian
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --filter=bandstop --cutoff=20.0 --cutoff2=30.0 --order=4
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=air_photo.tif -o=destriped.tif --mask=spikes.tif", short_exe, name).replace("*", &sep);
+
+        FrequencyDomainFilter {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for FrequencyDomainFilter {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut filter_type = String::from("none");
+        let mut cutoff = f64::NAN;
+        let mut cutoff2 = f64::NAN;
+        let mut shape = String::from("butterworth");
+        let mut order = 2isize;
+        let mut mask_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--filter"])? {
+            filter_type = v.trim().to_lowercase().replace("-", "").replace("_", "");
+        }
+        if let Some(v) = tool_args.get_f64(&["--cutoff"])? {
+            cutoff = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cutoff2"])? {
+            cutoff2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--shape"])? {
+            shape = v.trim().to_lowercase();
+        }
+        if let Some(v) = tool_args.get_isize(&["--order"])? {
+            order = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--mask"])? {
+            mask_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let filter_type = match filter_type.as_str() {
+            "" | "none" => FilterType::None,
+            "lowpass" => FilterType::LowPass,
+            "highpass" => FilterType::HighPass,
+            "bandpass" => FilterType::BandPass,
+            "bandstop" => FilterType::BandStop,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized filter type; options include 'none', 'lowpass', 'highpass', 'bandpass', and 'bandstop'.",
+                ))
+            }
+        };
+        let shape = if shape.starts_with("ideal") {
+            FilterShape::Ideal
+        } else if shape.starts_with("gauss") {
+            FilterShape::Gaussian
+        } else if shape.starts_with("butter") {
+            if order < 1 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The order of the Butterworth filter must be at least 1.",
+                ));
+            }
+            FilterShape::Butterworth(order as i32)
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized filter shape; options include 'ideal', 'butterworth', and 'gaussian'.",
+            ));
+        };
+        if filter_type == FilterType::None && mask_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a filter type (--filter) or a mask (--mask) must be specified.",
+            ));
+        }
+        if filter_type != FilterType::None && !(cutoff > 0f64) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The cutoff wavelength (--cutoff) must be specified and greater than zero.",
+            ));
+        }
+        let band_filter = filter_type == FilterType::BandPass || filter_type == FilterType::BandStop;
+        if band_filter && !(cutoff2 > 0f64 && cutoff2 != cutoff) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Band-pass and band-stop filters require a second cutoff wavelength (--cutoff2) that differs from the first.",
+            ));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !mask_file.is_empty() && !mask_file.contains(&sep) && !mask_file.contains("/") {
+            mask_file = format!("{}{}", working_directory, mask_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        if input.configs.photometric_interp == PhotometricInterpretation::RGB {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "This tool cannot be applied to RGB colour composites; split the composite into its components using the SplitColourComposite tool.",
+            ));
+        }
+        let rows = input.configs.rows;
+        let columns = input.configs.columns;
+        let mask = if mask_file.is_empty() {
+            None
+        } else {
+            let m = Raster::new(&mask_file, "r")?;
+            if m.configs.rows != rows || m.configs.columns != columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The mask must have the same number of rows and columns as the input.",
+                ));
+            }
+            Some(m)
+        };
+
+        let start = Instant::now();
+
+        if verbose {
+            report::message("Calculating the Fourier transform...")
+        };
+        let (mut re, mut im) = forward_transform(&input);
+
+        // frequencies are in cycles per horizontal unit
+        let (low, high) = if band_filter {
+            (1f64 / cutoff.max(cutoff2), 1f64 / cutoff.min(cutoff2))
+        } else {
+            (1f64 / cutoff, f64::NAN)
+        };
+        let resolution_x = input.configs.resolution_x;
+        let resolution_y = input.configs.resolution_y;
+        let mask_weight = |u: usize, v: usize| -> f64 {
+            match mask {
+                Some(ref m) => {
+                    let z = m.get_value(
+                        centred_index(u, rows) as isize,
+                        centred_index(v, columns) as isize,
+                    );
+                    if z == m.configs.nodata {
+                        1f64
+                    } else {
+                        1f64 - z.max(0f64).min(1f64)
+                    }
+                }
+                None => 1f64,
+            }
+        };
+        for u in 0..rows {
+            let fy = frequency(u, rows) / resolution_y;
+            for v in 0..columns {
+                let fx = frequency(v, columns) / resolution_x;
+                let f = (fx * fx + fy * fy).sqrt();
+                let mut h = match filter_type {
+                    FilterType::None => 1f64,
+                    FilterType::LowPass => shape.low_pass(f, low),
+                    FilterType::HighPass => 1f64 - shape.low_pass(f, low),
+                    FilterType::BandPass => {
+                        shape.low_pass(f, high) * (1f64 - shape.low_pass(f, low))
+                    }
+                    FilterType::BandStop => {
+                        1f64 - shape.low_pass(f, high) * (1f64 - shape.low_pass(f, low))
+                    }
+                };
+                // the mask is applied to both of each pair of conjugate frequencies
+                let (u2, v2) = ((rows - u) % rows, (columns - v) % columns);
+                h *= mask_weight(u, v).min(mask_weight(u2, v2));
+                let i = u * columns + v;
+                re[i] *= h;
+                im[i] *= h;
+            }
+        }
+
+        if verbose {
+            report::message("Calculating the inverse Fourier transform...")
+        };
+        fft_2d(&mut re, &mut im, rows, columns, true);
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        if output.configs.data_type != DataType::F64 {
+            output.configs.data_type = DataType::F32;
+        }
+        let nodata = input.configs.nodata;
+        let out_nodata = output.configs.nodata;
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let mut data = re[row * columns..(row + 1) * columns].to_vec();
+            for (z, zi) in data.iter_mut().zip(input.get_row_data(row as isize)) {
+                if zi == nodata {
+                    *z = out_nodata;
+                }
+            }
+            output.set_row_data(row as isize, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Filter: {:?}", filter_type));
+        if filter_type != FilterType::None {
+            output.add_metadata_entry(format!("Shape: {:?}", shape));
+            output.add_metadata_entry(format!("Cutoff wavelength: {}", cutoff));
+            if band_filter {
+                output.add_metadata_entry(format!("Second cutoff wavelength: {}", cutoff2));
+            }
+        }
+        if !mask_file.is_empty() {
+            output.add_metadata_entry(format!("Mask file: {}", mask_file));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FilterType {
+    None,
+    LowPass,
+    HighPass,
+    BandPass,
+    BandStop,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FilterShape {
+    Ideal,
+    Butterworth(i32),
+    Gaussian,
+}
+
+impl FilterShape {
+    /// Returns the transfer function of a low-pass filter with the cutoff frequency `cutoff`
+    /// at the frequency `f`.
+    fn low_pass(&self, f: f64, cutoff: f64) -> f64 {
+        match *self {
+            FilterShape::Ideal => {
+                if f <= cutoff {
+                    1f64
+                } else {
+                    0f64
+                }
+            }
+            FilterShape::Butterworth(order) => 1f64 / (1f64 + (f / cutoff).powi(2 * order)),
+            FilterShape::Gaussian => (-f * f / (2f64 * cutoff * cutoff)).exp(),
+        }
+    }
+}
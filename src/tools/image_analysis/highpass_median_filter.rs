@@ -162,60 +162,29 @@ impl WhiteboxTool for HighPassMedianFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if flag_val == "-filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-sig_digits" {
-                if keyval {
-                    num_sig_digits = vec[1].to_string().parse::<i32>().unwrap();
-                } else {
-                    num_sig_digits = args[i + 1].to_string().parse::<i32>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--sig_digits"])? {
+            num_sig_digits = v as i32;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,7 +219,7 @@ impl WhiteboxTool for HighPassMedianFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -274,6 +243,8 @@ impl WhiteboxTool for HighPassMedianFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let multiplier = 10f64.powi(num_sig_digits);
         let min_val = if !is_rgb_image {
             input.configs.minimum
@@ -330,14 +301,13 @@ impl WhiteboxTool for HighPassMedianFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning data: {}%", progress);
+                    report::progress("Binning data", progress);
                     old_progress = progress;
                 }
             }
         }
 
         let bd = Arc::new(binned_data); // wrap binned_data in an Arc
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let binned_data = bd.clone();
@@ -375,7 +345,7 @@ impl WhiteboxTool for HighPassMedianFilter {
                     median = bin_nodata;
                     n = 0.0;
                     n_less_than = 0.0;
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         bin_val = binned_data.get_value(row, col);
                         if bin_val != bin_nodata {
@@ -461,7 +431,7 @@ impl WhiteboxTool for HighPassMedianFilter {
                                 data[col as usize] =
                                     output_fn(row, col, (bin_val - median) as f64 / multiplier);
                             } else {
-                                data[col as usize] = nodata;
+                                data[col as usize] = out_nodata;
                             }
 
                             old_median = median;
@@ -480,7 +450,7 @@ impl WhiteboxTool for HighPassMedianFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    report::progress("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -498,19 +468,16 @@ impl WhiteboxTool for HighPassMedianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -128,46 +128,19 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i1"
-                || vec[0].to_lowercase() == "--i1"
-                || vec[0].to_lowercase() == "--input1"
-            {
-                if keyval {
-                    input_file1 = vec[1].to_string();
-                } else {
-                    input_file1 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-i2"
-                || vec[0].to_lowercase() == "--i2"
-                || vec[0].to_lowercase() == "--input2"
-            {
-                if keyval {
-                    input_file2 = vec[1].to_string();
-                } else {
-                    input_file2 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input_file1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input_file2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -186,7 +159,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         }
 
         if verbose {
-            println!("Reading input data...")
+            report::message("Reading input data...")
         };
         let input1 = Arc::new(Raster::new(&input_file1, "r")?);
         // let input2 = Arc::new(Raster::new(&input_file2, "r")?);
@@ -214,6 +187,8 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         let rows1 = input1.configs.rows as isize;
         let columns1 = input1.configs.columns as isize;
         let nodata1 = input1.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input1);
+        let out_nodata = output.configs.nodata;
         let min_value1 = input1.configs.minimum;
         let max_value1 = input1.configs.maximum;
         let num_bins1 = ((max_value1 - min_value1).max(1024f64)).ceil() as usize; //(2f64 * (max_value1 - min_value1 + 1f64).ceil().max((((rows1 * columns1) as f64).powf(1f64 / 3f64)).ceil())) as usize;
@@ -238,7 +213,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows1 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 1 of 3: {}%", progress);
+                    report::progress("Loop 1 of 3", progress);
                     old_progress = progress;
                 }
             }
@@ -279,7 +254,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows2 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 2 of 3: {}%", progress);
+                    report::progress("Loop 2 of 3", progress);
                     old_progress = progress;
                 }
             }
@@ -397,7 +372,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                 let mut p_val: f64;
                 let (mut x1, mut x2, mut p1, mut p2): (f64, f64, f64, f64);
                 for row in (0..rows1).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata1; columns1 as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns1 as usize];
                     for col in 0..columns1 {
                         z = input1[(row, col)];
                         if z != nodata1 {
@@ -433,14 +408,13 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input1);
         for r in 0..rows1 {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows1 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 3 of 3: {}%", progress);
+                    report::progress("Loop 3 of 3", progress);
                     old_progress = progress;
                 }
             }
@@ -456,21 +430,18 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
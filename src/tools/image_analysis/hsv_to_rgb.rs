@@ -0,0 +1,352 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::rgb_image::{hsv_to_rgb, pack_rgb};
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool transforms an image from the hue, saturation, and value (HSV) colour space to
+/// the red, green, and blue (RGB) colour space, and is the inverse of `RgbToHsv`. Hue is in
+/// degrees (values outside of the range 0 to 360 are wrapped around the colour wheel), and
+/// saturation and value range from 0 to 1. A typical use is to modify the saturation or
+/// value image produced by `RgbToHsv` (e.g. with a contrast stretch, or by substituting a
+/// panchromatic band for value, as in HSV pan-sharpening) before transforming the image back
+/// to RGB.
+///
+/// The output may be either a colour composite (`--output`), or three band rasters (`--red`,
+/// `--green`, and `--blue`) with values from 0 to 1. Cells that are nodata in any of the
+/// inputs are nodata in the outputs.
+///
+/// # See Also
+/// `RgbToHsv`, `IhsToRgb`, `CreateColourComposite`
+pub struct HsvToRgb {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl HsvToRgb {
+    /// Public constructor.
+    pub fn new() -> HsvToRgb {
+        let name = "HsvToRgb".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Converts hue, saturation, and value (HSV) images into red, green, and blue (RGB) images.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Hue File".to_owned(),
+            flags: vec!["--hue".to_owned()],
+            description: "Input hue file, in degrees.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Saturation File".to_owned(),
+            flags: vec!["--saturation".to_owned()],
+            description: "Input saturation file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Value File".to_owned(),
+            flags: vec!["--value".to_owned()],
+            description: "Input value (brightness) file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Red Band File (optional; only if colour-composite not specified)"
+                .to_owned(),
+            flags: vec!["--red".to_owned()],
+            description:
+                "Output red band file. Optionally specified if colour-composite not specified."
+                    .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Green Band File (optional; only if colour-composite not specified)"
+                .to_owned(),
+            flags: vec!["--green".to_owned()],
+            description:
+                "Output green band file. Optionally specified if colour-composite not specified."
+                    .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Blue Band File (optional; only if colour-composite not specified)"
+                .to_owned(),
+            flags: vec!["--blue".to_owned()],
+            description:
+                "Output blue band file. Optionally specified if colour-composite not specified."
+                    .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Colour-Composite File (optional; only if individual bands not specified)"
+                .to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description:
+                "Output colour-composite file. Only used if individual bands are not specified."
+                    .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --hue=hue.tif --saturation=saturation.tif --value=value.tif --red=band3.tif --green=band2.tif --blue=band1.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --hue=hue.tif --saturation=saturation.tif --value=value.tif -o=image.tif", short_exe, name).replace("*", &sep);
+
+        HsvToRgb {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for HsvToRgb {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut hue_file = String::new();
+        let mut saturation_file = String::new();
+        let mut value_file = String::new();
+        let mut red_file = String::new();
+        let mut green_file = String::new();
+        let mut blue_file = String::new();
+        let mut composite_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--hue"])? {
+            hue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--saturation"])? {
+            saturation_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--value"])? {
+            value_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            red_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--green"])? {
+            green_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--blue"])? {
+            blue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output", "--composite"])? {
+            composite_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let full_path = |f: String| {
+            if !f.is_empty() && !f.contains(&sep) && !f.contains("/") {
+                format!("{}{}", working_directory, f)
+            } else {
+                f
+            }
+        };
+        let hue_file = full_path(hue_file);
+        let saturation_file = full_path(saturation_file);
+        let value_file = full_path(value_file);
+        let red_file = full_path(red_file);
+        let green_file = full_path(green_file);
+        let blue_file = full_path(blue_file);
+        let composite_file = full_path(composite_file);
+
+        let use_composite = !composite_file.is_empty();
+        if !use_composite && (red_file.is_empty() || green_file.is_empty() || blue_file.is_empty())
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either an output colour composite or red, green, and blue bands must be specified.",
+            ));
+        }
+
+        let mut inputs = vec![];
+        for f in [&hue_file, &saturation_file, &value_file].iter() {
+            if verbose {
+                report::message(&format!("Reading {}...", f))
+            };
+            inputs.push(Raster::new(f, "r")?);
+        }
+        if inputs.iter().any(|r| {
+            r.configs.rows != inputs[0].configs.rows
+                || r.configs.columns != inputs[0].configs.columns
+        }) {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
+        }
+
+        let start = Instant::now();
+
+        let rows = inputs[0].configs.rows as isize;
+        let columns = inputs[0].configs.columns as usize;
+
+        let mut outputs = vec![];
+        if use_composite {
+            let mut output = Raster::initialize_using_file(&composite_file, &inputs[0]);
+            output.configs.photometric_interp = PhotometricInterpretation::RGB;
+            output.configs.data_type = DataType::RGB24;
+            output.configs.nodata = 0f64;
+            output.reinitialize_values(0f64);
+            outputs.push(output);
+        } else {
+            for f in [&red_file, &green_file, &blue_file].iter() {
+                let mut output = Raster::initialize_using_file(f, &inputs[0]);
+                output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+                output.configs.data_type = DataType::F32;
+                output.configs.nodata = -32768f64;
+                output.configs.palette = "grey.plt".to_string();
+                output.reinitialize_values(-32768f64);
+                outputs.push(output);
+            }
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data: Vec<Vec<f64>> = inputs.iter().map(|r| r.get_row_data(row)).collect();
+            let mut out_data: Vec<Vec<f64>> = outputs
+                .iter()
+                .map(|o| vec![o.configs.nodata; columns])
+                .collect();
+            for col in 0..columns {
+                if (0..3).any(|i| data[i][col] == inputs[i].configs.nodata) {
+                    continue;
+                }
+                let (r, g, b) = hsv_to_rgb(data[0][col], data[1][col], data[2][col]);
+                if use_composite {
+                    out_data[0][col] = pack_rgb(r, g, b, 255);
+                } else {
+                    out_data[0][col] = r;
+                    out_data[1][col] = g;
+                    out_data[2][col] = b;
+                }
+            }
+            for (output, values) in outputs.iter_mut().zip(out_data.into_iter()) {
+                output.set_row_data(row, values);
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        for output in outputs.iter_mut() {
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input hue file: {}", hue_file));
+            output.add_metadata_entry(format!("Input saturation file: {}", saturation_file));
+            output.add_metadata_entry(format!("Input value file: {}", value_file));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 25, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -181,83 +181,32 @@ impl WhiteboxTool for IhsToRgb {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-red" || vec[0].to_lowercase() == "--red" {
-                if keyval {
-                    red_file = vec[1].to_string();
-                } else {
-                    red_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-g"
-                || vec[0].to_lowercase() == "-green"
-                || vec[0].to_lowercase() == "--green"
-            {
-                if keyval {
-                    green_file = vec[1].to_string();
-                } else {
-                    green_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-b"
-                || vec[0].to_lowercase() == "-blue"
-                || vec[0].to_lowercase() == "--blue"
-            {
-                if keyval {
-                    blue_file = vec[1].to_string();
-                } else {
-                    blue_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "-intensity"
-                || vec[0].to_lowercase() == "--intensity"
-            {
-                if keyval {
-                    intensity_file = vec[1].to_string();
-                } else {
-                    intensity_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-h"
-                || vec[0].to_lowercase() == "-hue"
-                || vec[0].to_lowercase() == "--hue"
-            {
-                if keyval {
-                    hue_file = vec[1].to_string();
-                } else {
-                    hue_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-s"
-                || vec[0].to_lowercase() == "-saturation"
-                || vec[0].to_lowercase() == "--saturation"
-            {
-                if keyval {
-                    saturation_file = vec[1].to_string();
-                } else {
-                    saturation_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o"
-                || vec[0].to_lowercase() == "-composite"
-                || vec[0].to_lowercase() == "--composite"
-            {
-                if keyval {
-                    composite_file = vec[1].to_string();
-                } else {
-                    composite_file = args[i + 1].to_string();
-                }
-                use_composite = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            red_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-g", "--green"])? {
+            green_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-b", "--blue"])? {
+            blue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-i", "--intensity"])? {
+            intensity_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-h", "--hue"])? {
+            hue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-s", "--saturation"])? {
+            saturation_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output", "--composite"])? {
+            composite_file = v;
+            use_composite = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -285,15 +234,15 @@ impl WhiteboxTool for IhsToRgb {
         }
 
         if verbose {
-            println!("Reading intensity band data...")
+            report::message("Reading intensity band data...")
         };
         let input_i = Arc::new(Raster::new(&intensity_file, "r")?);
         if verbose {
-            println!("Reading hue band data...")
+            report::message("Reading hue band data...")
         };
         let input_h = Arc::new(Raster::new(&hue_file, "r")?);
         if verbose {
-            println!("Reading saturation band data...")
+            report::message("Reading saturation band data...")
         };
         let input_s = Arc::new(Raster::new(&saturation_file, "r")?);
 
@@ -311,7 +260,7 @@ impl WhiteboxTool for IhsToRgb {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
         if input_i.configs.rows != input_s.configs.rows
@@ -319,7 +268,7 @@ impl WhiteboxTool for IhsToRgb {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -379,16 +328,27 @@ impl WhiteboxTool for IhsToRgb {
             let mut output_b = Raster::initialize_using_file(&blue_file, &input_i);
             output_b.configs.photometric_interp = PhotometricInterpretation::Continuous;
             output_b.configs.data_type = DataType::F32;
+            let out_nodata = output_r.configs.nodata;
 
             for row in 0..rows {
-                let data = rx.recv().unwrap();
+                let mut data = rx.recv().unwrap();
+                for col in 0..columns {
+                    if input_i[(data.0, col)] == nodata_i
+                        || input_h[(data.0, col)] == nodata_h
+                        || input_s[(data.0, col)] == nodata_s
+                    {
+                        data.1[col as usize] = out_nodata;
+                        data.2[col as usize] = out_nodata;
+                        data.3[col as usize] = out_nodata;
+                    }
+                }
                 output_r.set_row_data(data.0, data.1);
                 output_g.set_row_data(data.0, data.2);
                 output_b.set_row_data(data.0, data.3);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -407,11 +367,11 @@ impl WhiteboxTool for IhsToRgb {
             output_r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving red data...")
+                report::message("Saving red data...")
             };
             let _ = match output_r.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -429,11 +389,11 @@ impl WhiteboxTool for IhsToRgb {
             );
 
             if verbose {
-                println!("Saving green data...")
+                report::message("Saving green data...")
             };
             let _ = match output_g.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -451,40 +411,42 @@ impl WhiteboxTool for IhsToRgb {
             );
 
             if verbose {
-                println!("Saving blue data...")
+                report::message("Saving blue data...")
             };
             let _ = match output_b.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
 
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         } else {
             let mut output = Raster::initialize_using_file(&composite_file, &input_i);
             output.configs.photometric_interp = PhotometricInterpretation::RGB;
             output.configs.data_type = DataType::RGB24;
             let out_nodata = 0f64;
+            output.configs.nodata = out_nodata;
             let (mut r, mut g, mut b): (u32, u32, u32);
             let alpha_mask = (255 << 24) as u32;
             for row in 0..rows {
                 let data = rx.recv().unwrap();
                 let mut out_data = vec![out_nodata; columns as usize];
                 for col in 0..columns {
-                    r = data.1[col as usize] as u32;
-                    g = data.2[col as usize] as u32;
-                    b = data.3[col as usize] as u32;
+                    if data.1[col as usize] == nodata_i {
+                        continue;
+                    }
+                    // the RGB values range from 0 to 1
+                    r = (data.1[col as usize] * 255f64).round().max(0f64).min(255f64) as u32;
+                    g = (data.2[col as usize] * 255f64).round().max(0f64).min(255f64) as u32;
+                    b = (data.3[col as usize] * 255f64).round().max(0f64).min(255f64) as u32;
                     out_data[col as usize] = (alpha_mask | (b << 16) | (g << 8) | r) as f64;
                 }
                 output.set_row_data(data.0, out_data);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -502,19 +464,16 @@ impl WhiteboxTool for IhsToRgb {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving red data...")
+                report::message("Saving red data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         }
 
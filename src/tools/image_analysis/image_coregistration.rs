@@ -0,0 +1,740 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::resample::sample_value;
+use na::{DMatrix, DVector};
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::Array2D;
+use tools::*;
+
+/// This tool co-registers an image (`--input`) to a reference image (`--reference`) of the
+/// same area, e.g. a second acquisition of a multi-date dataset, by automatically finding
+/// tie points between the two images. Accurate co-registration is an essential step before
+/// change detection, since small misalignments produce spurious change along edges.
+///
+/// Candidate tie points are placed on a regular grid over the reference image, with a
+/// spacing of `--spacing` cells. Around each candidate, a square template of the reference
+/// image (`--window` cells wide) is matched against the input image, which is first
+/// resampled onto the reference grid using its current georeferencing, within a search
+/// radius of `--search` cells. The match is the offset with the highest normalized
+/// cross-correlation (NCC), refined to sub-cell precision by fitting a parabola through the
+/// correlation peak. Tie points are rejected if the template has no contrast, if the
+/// correlation is lower than `--min_correlation`, or if the peak lies on the edge of the
+/// search area. The images must therefore overlap and should have similar spectral content
+/// (e.g. the same band of the two images) and an initial misalignment smaller than the
+/// search radius.
+///
+/// A polynomial transformation of the specified order (`--order`; 1 is an affine
+/// transformation, 2 and 3 are quadratic and cubic) that maps reference coordinates onto
+/// input coordinates is fitted to the tie points by least squares. Outlying tie points, with
+/// residuals greater than three times the root-mean-square error (RMSE) and more than one
+/// cell, are removed iteratively. The RMSE of the final transformation is reported, in map
+/// units and in cells, and the tie points may optionally be saved to a CSV file
+/// (`--tie_points`), along with their correlations, residuals, and whether they were used.
+///
+/// Lastly, the input image is resampled onto the grid of the reference image using the
+/// fitted transformation and either nearest neighbour (`nn`), bilinear interpolation
+/// (`bilinear`), or cubic convolution (`cc`) resampling (`--method`). The output has the
+/// extent, resolution, and coordinate reference system of the reference image, and the data
+/// type of the input image for nearest neighbour resampling.
+///
+/// # See Also
+/// `Resample`, `ImageDifferencing`, `ChangeVectorAnalysis`
+pub struct ImageCoregistration {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ImageCoregistration {
+    pub fn new() -> ImageCoregistration {
+        // public constructor
+        let name = "ImageCoregistration".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Co-registers an image to a reference image using automatically matched tie points.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Reference File".to_owned(),
+            flags: vec!["--reference".to_owned()],
+            description: "Input reference (master) raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file to co-register.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Transformation Order".to_owned(),
+            flags: vec!["--order".to_owned()],
+            description: "Polynomial order of the transformation (1 = affine, 2, or 3)."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Tie-Point Spacing (cells)".to_owned(),
+            flags: vec!["--spacing".to_owned()],
+            description: "Spacing of the candidate tie points, in reference cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("50".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Matching Window Size (cells)".to_owned(),
+            flags: vec!["--window".to_owned()],
+            description: "Width of the matching template, in cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("31".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Radius (cells)".to_owned(),
+            flags: vec!["--search".to_owned()],
+            description: "Maximum offset searched for each tie point, in cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("15".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Correlation".to_owned(),
+            flags: vec!["--min_correlation".to_owned()],
+            description: "Minimum normalized cross-correlation of accepted tie points."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.7".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Resampling Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Resampling method; options include 'nn' (nearest neighbour), 'bilinear', and 'cc' (cubic convolution)".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "nn".to_owned(),
+                "bilinear".to_owned(),
+                "cc".to_owned(),
+            ]),
+            default_value: Some("cc".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Tie Points File".to_owned(),
+            flags: vec!["--tie_points".to_owned()],
+            description: "Optional output CSV file of the tie points.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --reference=nir_2015.tif -i=nir_2020.tif -o=nir_2020_reg.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --reference=nir_2015.tif -i=nir_2020.tif -o=nir_2020_reg.tif --order=2 --spacing=30 --window=21 --search=10 --min_correlation=0.8 --method=bilinear --tie_points=tie_points.csv", short_exe, name).replace("*", &sep);
+
+        ImageCoregistration {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ImageCoregistration {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut reference_file = String::new();
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut order = 1usize;
+        let mut spacing = 50isize;
+        let mut window = 31isize;
+        let mut search = 15isize;
+        let mut min_correlation = 0.7f64;
+        let mut method = String::from("cc");
+        let mut tie_points_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--reference"])? {
+            reference_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--order"])? {
+            if v < 1 || v > 3 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The transformation --order must be 1, 2, or 3.",
+                ));
+            }
+            order = v as usize;
+        }
+        if let Some(v) = tool_args.get_isize(&["--spacing"])? {
+            spacing = v.max(1);
+        }
+        if let Some(v) = tool_args.get_isize(&["--window"])? {
+            window = v.max(3);
+        }
+        if let Some(v) = tool_args.get_isize(&["--search"])? {
+            search = v.max(1);
+        }
+        if let Some(v) = tool_args.get_f64(&["--min_correlation"])? {
+            min_correlation = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            let v = v.to_lowercase();
+            method = if v.contains("nn") || v.contains("nearest") {
+                "nn".to_string()
+            } else if v.contains("bi") {
+                "bilinear".to_string()
+            } else {
+                "cc".to_string()
+            };
+        }
+        if let Some(v) = tool_args.get_string(&["--tie_points"])? {
+            tie_points_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !reference_file.contains(&sep) && !reference_file.contains("/") {
+            reference_file = format!("{}{}", working_directory, reference_file);
+        }
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !tie_points_file.is_empty()
+            && !tie_points_file.contains(&sep)
+            && !tie_points_file.contains("/")
+        {
+            tie_points_file = format!("{}{}", working_directory, tie_points_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let reference = Arc::new(Raster::new(&reference_file, "r")?);
+        let input = Raster::new(&input_file, "r")?;
+        let rows = reference.configs.rows as isize;
+        let columns = reference.configs.columns as isize;
+        let in_nodata = input.configs.nodata;
+
+        let start = Instant::now();
+
+        // resample the input onto the reference grid using its current georeferencing
+        let mut warped: Array2D<f64> = Array2D::new(rows, columns, in_nodata, in_nodata)?;
+        for row in 0..rows {
+            let y = reference.get_y_from_row(row);
+            for col in 0..columns {
+                let x = reference.get_x_from_column(col);
+                warped.set_value(row, col, sample_value(&input, x, y, "bilinear"));
+            }
+        }
+        let warped = Arc::new(warped);
+
+        // find the tie points
+        let half_window = window / 2;
+        let margin = half_window + search + 1;
+        let mut candidates: Vec<(isize, isize)> = vec![];
+        let mut row = margin;
+        while row < rows - margin {
+            let mut col = margin;
+            while col < columns - margin {
+                candidates.push((row, col));
+                col += spacing;
+            }
+            row += spacing;
+        }
+        if candidates.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The reference image is too small for the specified matching window and search radius.",
+            ));
+        }
+        if verbose {
+            report::message(&format!("Matching {} candidate tie points...", candidates.len()));
+        }
+
+        let num_candidates = candidates.len();
+        let candidates = Arc::new(candidates);
+        let num_procs = num_cpus::get();
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let reference = reference.clone();
+            let warped = warped.clone();
+            let candidates = candidates.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in (0..candidates.len()).filter(|i| i % num_procs == tid) {
+                    let (row, col) = candidates[i];
+                    let result =
+                        match_template(&reference, &warped, row, col, half_window, search);
+                    tx.send((i, result)).unwrap();
+                }
+            });
+        }
+
+        // (reference x, reference y, input x, input y, correlation)
+        let mut tie_points: Vec<(f64, f64, f64, f64, f64)> = vec![];
+        let mut matches = vec![None; num_candidates];
+        for i in 0..num_candidates {
+            let (index, result) = rx.recv().unwrap();
+            matches[index] = result;
+            if verbose {
+                progress = (100.0_f64 * i as f64 / (num_candidates - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        for i in 0..num_candidates {
+            if let Some((row_offset, col_offset, correlation)) = matches[i] {
+                if correlation >= min_correlation {
+                    let (row, col) = candidates[i];
+                    let x = reference.get_x_from_column(col);
+                    let y = reference.get_y_from_row(row);
+                    tie_points.push((
+                        x,
+                        y,
+                        x + col_offset * reference.configs.resolution_x,
+                        y - row_offset * reference.configs.resolution_y,
+                        correlation,
+                    ));
+                }
+            }
+        }
+
+        let num_coefficients = (order + 1) * (order + 2) / 2;
+        if tie_points.len() <= num_coefficients {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Only {} tie points were found, which is too few to fit the transformation; try reducing the --spacing or --min_correlation, or increasing the --search radius.",
+                    tie_points.len()
+                ),
+            ));
+        }
+
+        // fit the transformation, iteratively removing outlying tie points
+        let cell_size = (reference.configs.resolution_x + reference.configs.resolution_y) / 2f64;
+        let mut used = vec![true; tie_points.len()];
+        let (transform, residuals, rmse) = loop {
+            let src: Vec<(f64, f64)> = (0..tie_points.len())
+                .filter(|&i| used[i])
+                .map(|i| (tie_points[i].0, tie_points[i].1))
+                .collect();
+            let dst: Vec<(f64, f64)> = (0..tie_points.len())
+                .filter(|&i| used[i])
+                .map(|i| (tie_points[i].2, tie_points[i].3))
+                .collect();
+            let transform = PolynomialTransform::fit(&src, &dst, order)?;
+            let residuals: Vec<f64> = tie_points
+                .iter()
+                .map(|tp| {
+                    let (x, y) = transform.transform(tp.0, tp.1);
+                    ((x - tp.2) * (x - tp.2) + (y - tp.3) * (y - tp.3)).sqrt()
+                })
+                .collect();
+            let num_used = src.len();
+            let rmse = ((0..tie_points.len())
+                .filter(|&i| used[i])
+                .map(|i| residuals[i] * residuals[i])
+                .sum::<f64>()
+                / num_used as f64)
+                .sqrt();
+            let mut worst = 0;
+            let mut worst_residual = f64::NEG_INFINITY;
+            for i in (0..tie_points.len()).filter(|&i| used[i]) {
+                if residuals[i] > worst_residual {
+                    worst_residual = residuals[i];
+                    worst = i;
+                }
+            }
+            if num_used > num_coefficients + 1
+                && worst_residual > 3f64 * rmse
+                && worst_residual > cell_size
+            {
+                used[worst] = false;
+            } else {
+                break (transform, residuals, rmse);
+            }
+        };
+        let num_used = used.iter().filter(|u| **u).count();
+
+        if verbose {
+            report::message(&format!(
+                "Tie points: {} matched, {} used",
+                tie_points.len(),
+                num_used
+            ));
+            report::message(&format!(
+                "RMSE: {:.4} (map units), {:.4} (cells)",
+                rmse,
+                rmse / cell_size
+            ));
+        }
+
+        if !tie_points_file.is_empty() {
+            let f = File::create(&tie_points_file)?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all(b"REF_X,REF_Y,INPUT_X,INPUT_Y,CORRELATION,RESIDUAL,USED\n")?;
+            for i in 0..tie_points.len() {
+                let tp = tie_points[i];
+                writer.write_all(
+                    format!(
+                        "{},{},{},{},{},{},{}\n",
+                        tp.0, tp.1, tp.2, tp.3, tp.4, residuals[i], used[i]
+                    ).as_bytes(),
+                )?;
+            }
+            let _ = writer.flush();
+        }
+
+        // resample the input onto the reference grid
+        if verbose {
+            report::message("Resampling...");
+        }
+        let mut output = Raster::initialize_using_file(&output_file, &reference);
+        output.configs.data_type = input.configs.data_type;
+        output.configs.photometric_interp = input.configs.photometric_interp;
+        output.configs.palette = input.configs.palette.clone();
+        output.configs.color_map = input.configs.color_map.clone();
+        output.configs.z_units = input.configs.z_units.clone();
+        output.configs.band_names = vec![];
+        output.set_nodata_value(in_nodata);
+        output.reinitialize_values(in_nodata);
+        if method != "nn" {
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.configs.data_type = DataType::F32;
+            output.configs.color_map = vec![];
+        }
+        for row in 0..rows {
+            let y = reference.get_y_from_row(row);
+            let data: Vec<f64> = (0..columns)
+                .map(|col| {
+                    let (x, y) = transform.transform(reference.get_x_from_column(col), y);
+                    sample_value(&input, x, y, &method)
+                })
+                .collect();
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Reference file: {}", reference_file));
+        output.add_metadata_entry(format!("Transformation order: {}", order));
+        output.add_metadata_entry(format!("Tie points used: {}", num_used));
+        output.add_metadata_entry(format!("RMSE: {}", rmse));
+        output.add_metadata_entry(format!("Resampling method: {}", method));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Matches the reference template centred on (`row`, `col`) against the warped input image
+/// and returns the sub-cell row and column offsets of the best match and its normalized
+/// cross-correlation, or None if the template has no contrast or the best match lies on the
+/// edge of the search area.
+fn match_template(
+    reference: &Raster,
+    warped: &Array2D<f64>,
+    row: isize,
+    col: isize,
+    half_window: isize,
+    search: isize,
+) -> Option<(f64, f64, f64)> {
+    let ref_nodata = reference.configs.nodata;
+    let nodata = warped.nodata;
+    let mut template = vec![];
+    for r in -half_window..half_window + 1 {
+        for c in -half_window..half_window + 1 {
+            let z = reference.get_value(row + r, col + c);
+            if z == ref_nodata {
+                return None;
+            }
+            template.push(z);
+        }
+    }
+    let n = template.len() as f64;
+    let mean = template.iter().sum::<f64>() / n;
+    for t in template.iter_mut() {
+        *t -= mean;
+    }
+    let ss_template: f64 = template.iter().map(|t| t * t).sum();
+    if ss_template <= 0f64 {
+        return None;
+    }
+
+    let size = (2 * search + 1) as usize;
+    let mut scores = vec![f64::NAN; size * size];
+    let mut best = (0usize, 0usize, f64::NEG_INFINITY);
+    for dr in -search..search + 1 {
+        'offset: for dc in -search..search + 1 {
+            let (mut sum, mut sum_sq, mut sum_product) = (0f64, 0f64, 0f64);
+            let mut i = 0;
+            for r in -half_window..half_window + 1 {
+                for c in -half_window..half_window + 1 {
+                    let z = warped.get_value(row + dr + r, col + dc + c);
+                    if z == nodata {
+                        continue 'offset;
+                    }
+                    sum += z;
+                    sum_sq += z * z;
+                    sum_product += template[i] * z;
+                    i += 1;
+                }
+            }
+            let ss_window = sum_sq - sum * sum / n;
+            if ss_window <= 0f64 {
+                continue;
+            }
+            let score = sum_product / (ss_template * ss_window).sqrt();
+            let (a, b) = ((dr + search) as usize, (dc + search) as usize);
+            scores[a * size + b] = score;
+            if score > best.2 {
+                best = (a, b, score);
+            }
+        }
+    }
+    let (a, b, score) = best;
+    if !score.is_finite() || a == 0 || b == 0 || a == size - 1 || b == size - 1 {
+        return None;
+    }
+
+    // refine the peak by fitting parabolas through the neighbouring scores
+    let refine = |low: f64, high: f64| {
+        let denominator = low - 2f64 * score + high;
+        if low.is_nan() || high.is_nan() || denominator >= 0f64 {
+            0f64
+        } else {
+            (0.5 * (low - high) / denominator).max(-0.5).min(0.5)
+        }
+    };
+    let row_offset = (a as isize - search) as f64
+        + refine(scores[(a - 1) * size + b], scores[(a + 1) * size + b]);
+    let col_offset = (b as isize - search) as f64
+        + refine(scores[a * size + b - 1], scores[a * size + b + 1]);
+    Some((row_offset, col_offset, score))
+}
+
+/// A two-dimensional polynomial transformation, fitted by least squares, with coordinates
+/// centred and scaled to improve the conditioning of the fit.
+struct PolynomialTransform {
+    order: usize,
+    x_mean: f64,
+    y_mean: f64,
+    scale: f64,
+    x_coefficients: Vec<f64>,
+    y_coefficients: Vec<f64>,
+}
+
+impl PolynomialTransform {
+    fn fit(
+        src: &[(f64, f64)],
+        dst: &[(f64, f64)],
+        order: usize,
+    ) -> Result<PolynomialTransform, Error> {
+        let n = src.len();
+        let x_mean = src.iter().map(|p| p.0).sum::<f64>() / n as f64;
+        let y_mean = src.iter().map(|p| p.1).sum::<f64>() / n as f64;
+        let mut scale = src
+            .iter()
+            .map(|p| (p.0 - x_mean).abs().max((p.1 - y_mean).abs()))
+            .fold(0f64, f64::max);
+        if scale == 0f64 {
+            scale = 1f64;
+        }
+        let mut transform = PolynomialTransform {
+            order: order,
+            x_mean: x_mean,
+            y_mean: y_mean,
+            scale: scale,
+            x_coefficients: vec![],
+            y_coefficients: vec![],
+        };
+        let mut terms = vec![];
+        for p in src {
+            terms.extend(transform.terms(p.0, p.1));
+        }
+        let num_coefficients = terms.len() / n;
+        let matrix = DMatrix::from_row_slice(n, num_coefficients, &terms);
+        let qr = matrix.qr();
+        let q = qr.q();
+        let r = qr.r();
+        let r_inverse = match r.try_inverse() {
+            Some(m) => m,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The transformation cannot be fitted because the tie points are collinear; try reducing the --order.",
+                ))
+            }
+        };
+        let solve = |values: Vec<f64>| {
+            let b = DVector::from_row_slice(n, &values);
+            (&r_inverse * q.transpose() * b).as_slice().to_vec()
+        };
+        transform.x_coefficients = solve(dst.iter().map(|p| p.0).collect());
+        transform.y_coefficients = solve(dst.iter().map(|p| p.1).collect());
+        Ok(transform)
+    }
+
+    fn terms(&self, x: f64, y: f64) -> Vec<f64> {
+        let x = (x - self.x_mean) / self.scale;
+        let y = (y - self.y_mean) / self.scale;
+        let mut terms = vec![];
+        for j in 0..(self.order + 1) {
+            for k in 0..(self.order - j + 1) {
+                terms.push(x.powi(j as i32) * y.powi(k as i32));
+            }
+        }
+        terms
+    }
+
+    fn transform(&self, x: f64, y: f64) -> (f64, f64) {
+        let terms = self.terms(x, y);
+        let mut tx = 0f64;
+        let mut ty = 0f64;
+        for i in 0..terms.len() {
+            tx += terms[i] * self.x_coefficients[i];
+            ty += terms[i] * self.y_coefficients[i];
+        }
+        (tx, ty)
+    }
+}
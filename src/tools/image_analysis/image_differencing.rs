@@ -0,0 +1,276 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool performs image differencing, a simple change detection method, on two
+/// co-registered images of the same area acquired at different times. The difference is the
+/// later image (`--input2`) minus the earlier image (`--input1`), such that positive values
+/// indicate an increase and negative values a decrease in the measured quantity, e.g. the
+/// NDVI or the reflectance of a band.
+///
+/// When the `--normalized` flag is specified, the normalized difference is calculated
+/// instead:
+///
+/// > (t<sub>2</sub> - t<sub>1</sub>) / (t<sub>2</sub> + t<sub>1</sub>)
+///
+/// which ranges from -1 to 1 for non-negative inputs and expresses the change relative to
+/// the magnitude of the values, reducing the influence of overall brightness differences.
+/// Cells for which both inputs are zero have a normalized difference of zero.
+///
+/// Changed areas are commonly identified by thresholding the difference image, e.g. at a
+/// number of standard deviations from its mean. The output is an F32 raster and the
+/// inputs must have the same numbers of rows and columns.
+///
+/// # See Also
+/// `ImageRatio`, `RegressionChangeDetection`, `ChangeVectorAnalysis`
+pub struct ImageDifferencing {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ImageDifferencing {
+    pub fn new() -> ImageDifferencing {
+        // public constructor
+        let name = "ImageDifferencing".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description =
+            "Performs change detection by differencing two images, optionally normalized."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Earlier Date Input File".to_owned(),
+            flags: vec!["--i1".to_owned(), "--input1".to_owned()],
+            description: "Input raster file for the earlier date.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Later Date Input File".to_owned(),
+            flags: vec!["--i2".to_owned(), "--input2".to_owned()],
+            description: "Input raster file for the later date.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Calculate the normalized difference?".to_owned(),
+            flags: vec!["--normalized".to_owned()],
+            description: "Calculate the normalized difference rather than the difference."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --i1=ndvi_2015.tif --i2=ndvi_2020.tif -o=ndvi_change.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --i1=nir_2015.tif --i2=nir_2020.tif -o=nir_change.tif --normalized", short_exe, name).replace("*", &sep);
+
+        ImageDifferencing {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ImageDifferencing {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input1_file = String::new();
+        let mut input2_file = String::new();
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        let normalized = tool_args.get_flag(&["--normalized"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input1_file.contains(&sep) && !input1_file.contains("/") {
+            input1_file = format!("{}{}", working_directory, input1_file);
+        }
+        if !input2_file.contains(&sep) && !input2_file.contains("/") {
+            input2_file = format!("{}{}", working_directory, input2_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input1 = Raster::new(&input1_file, "r")?;
+        let input2 = Raster::new(&input2_file, "r")?;
+        let rows = input1.configs.rows as isize;
+        if input2.configs.rows != input1.configs.rows
+            || input2.configs.columns != input1.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent.",
+            ));
+        }
+        let nodata1 = input1.configs.nodata;
+        let nodata2 = input2.configs.nodata;
+
+        let start = Instant::now();
+
+        let nodata = -32768f64;
+        let mut output = Raster::initialize_using_file(&output_file, &input1);
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.nodata = nodata;
+        output.configs.color_map = vec![];
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data1 = input1.get_row_data(row);
+            let data2 = input2.get_row_data(row);
+            let data: Vec<f64> = data1
+                .iter()
+                .zip(data2.iter())
+                .map(|(&z1, &z2)| {
+                    if z1 == nodata1 || z2 == nodata2 {
+                        nodata
+                    } else if !normalized {
+                        z2 - z1
+                    } else if z1 + z2 != 0f64 {
+                        (z2 - z1) / (z2 + z1)
+                    } else {
+                        0f64
+                    }
+                })
+                .collect();
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Earlier date input file: {}", input1_file));
+        output.add_metadata_entry(format!("Later date input file: {}", input2_file));
+        output.add_metadata_entry(format!("Normalized difference: {}", normalized));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool performs image ratioing, a simple change detection method, on two co-registered
+/// images of the same area acquired at different times. The ratio is the later image
+/// (`--input2`) divided by the earlier image (`--input1`), such that values near one indicate
+/// little change. Because ratios are insensitive to multiplicative differences, such as
+/// those caused by differing illumination, image ratioing is often preferred to
+/// differencing for reflectance data. Cells with an earlier value of zero are assigned
+/// nodata.
+///
+/// Since ratios of decreases fall between zero and one while ratios of increases range from
+/// one upwards, the ratio is asymmetric and is usually stretched (`--stretch`) before it is
+/// displayed or thresholded. The options are:
+///
+/// - **none**: the ratio, as an F32 raster.
+/// - **log**: the natural logarithm of the ratio, as an F32 raster, which is symmetric about
+///   zero, i.e. a doubling and a halving have the same magnitude. Cells with non-positive
+///   ratios are assigned nodata.
+/// - **byte**: the ratio stretched to an 8-bit (1-255) range following Jensen (2005), in
+///   which ratios from 1/255 to 1 are mapped to 1-128 by *int(127 r + 1)* and ratios from 1 to
+///   255 are mapped to 128-255 by *int(128 + r / 2)*. Ratios outside of this range are clipped
+///   and nodata cells are assigned zero.
+///
+/// The inputs must have the same numbers of rows and columns.
+///
+/// # Reference
+/// Jensen, J. R. (2005). *Introductory Digital Image Processing: A Remote Sensing
+/// Perspective* (3rd ed.). Prentice Hall.
+///
+/// # See Also
+/// `ImageRatio`, `RegressionChangeDetection`, `ChangeVectorAnalysis`
+pub struct ImageRatio {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ImageRatio {
+    pub fn new() -> ImageRatio {
+        // public constructor
+        let name = "ImageRatio".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description =
+            "Performs change detection by ratioing two images, with an optional stretch."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Earlier Date Input File".to_owned(),
+            flags: vec!["--i1".to_owned(), "--input1".to_owned()],
+            description: "Input raster file for the earlier date.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Later Date Input File".to_owned(),
+            flags: vec!["--i2".to_owned(), "--input2".to_owned()],
+            description: "Input raster file for the later date.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Ratio Stretch".to_owned(),
+            flags: vec!["--stretch".to_owned()],
+            description: "Stretch applied to the ratio; options are 'none', 'log', and 'byte'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "none".to_owned(),
+                "log".to_owned(),
+                "byte".to_owned(),
+            ]),
+            default_value: Some("none".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --i1=nir_2015.tif --i2=nir_2020.tif -o=nir_ratio.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --i1=nir_2015.tif --i2=nir_2020.tif -o=nir_ratio.tif --stretch=log", short_exe, name).replace("*", &sep);
+
+        ImageRatio {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ImageRatio {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input1_file = String::new();
+        let mut input2_file = String::new();
+        let mut output_file = String::new();
+        let mut stretch = String::from("none");
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--stretch"])? {
+            stretch = v.trim().to_lowercase();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input1_file.contains(&sep) && !input1_file.contains("/") {
+            input1_file = format!("{}{}", working_directory, input1_file);
+        }
+        if !input2_file.contains(&sep) && !input2_file.contains("/") {
+            input2_file = format!("{}{}", working_directory, input2_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if stretch != "none" && stretch != "log" && stretch != "byte" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The --stretch parameter must be one of 'none', 'log', or 'byte'.",
+            ));
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input1 = Raster::new(&input1_file, "r")?;
+        let input2 = Raster::new(&input2_file, "r")?;
+        let rows = input1.configs.rows as isize;
+        if input2.configs.rows != input1.configs.rows
+            || input2.configs.columns != input1.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent.",
+            ));
+        }
+        let nodata1 = input1.configs.nodata;
+        let nodata2 = input2.configs.nodata;
+
+        let start = Instant::now();
+
+        let byte_stretch = stretch == "byte";
+        let log_stretch = stretch == "log";
+        let nodata = if byte_stretch { 0f64 } else { -32768f64 };
+        let mut output = Raster::initialize_using_file(&output_file, &input1);
+        output.configs.data_type = if byte_stretch {
+            DataType::U8
+        } else {
+            DataType::F32
+        };
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.nodata = nodata;
+        output.configs.color_map = vec![];
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data1 = input1.get_row_data(row);
+            let data2 = input2.get_row_data(row);
+            let data: Vec<f64> = data1
+                .iter()
+                .zip(data2.iter())
+                .map(|(&z1, &z2)| {
+                    if z1 == nodata1 || z2 == nodata2 || z1 == 0f64 {
+                        return nodata;
+                    }
+                    let ratio = z2 / z1;
+                    if byte_stretch {
+                        let r = ratio.max(1f64 / 255f64).min(255f64);
+                        if r < 1f64 {
+                            (127f64 * r + 1f64).floor()
+                        } else {
+                            (128f64 + r / 2f64).floor()
+                        }
+                    } else if log_stretch {
+                        if ratio > 0f64 {
+                            ratio.ln()
+                        } else {
+                            nodata
+                        }
+                    } else {
+                        ratio
+                    }
+                })
+                .collect();
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Earlier date input file: {}", input1_file));
+        output.add_metadata_entry(format!("Later date input file: {}", input2_file));
+        output.add_metadata_entry(format!("Stretch: {}", stretch));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+
+/// This tool stacks a list of single-band rasters (`--inputs`), e.g. the individual bands
+/// of a multispectral image, into a single multi-band GeoTIFF (`--output`), which is a
+/// common preparation step for classification workflows. The bands of the output are in
+/// the order of the input files. Inputs that are themselves multi-band GeoTIFFs contribute
+/// all of their bands. All inputs must have the same number of rows and columns.
+///
+/// Each output band is named, either using the optional list of band names (`--names`) or,
+/// by default, after the band's name within its input file or the input file name. The names
+/// are stored in the GDAL metadata of the output GeoTIFF and are retained by
+/// `ExtractBands`.
+///
+/// The output data type is that of the inputs when they share the same data type and is
+/// otherwise a floating-point type that can hold the values of all of the inputs. The nodata
+/// value of the output is that of the first input, and the nodata cells of each input are
+/// assigned this value in the output. RGB colour composites cannot be stacked and should
+/// first be split into their components using `SplitColourComposite`.
+///
+/// # See Also
+/// `ExtractBands`, `SplitColourComposite`, `ImageStackProfile`
+pub struct ImageStack {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ImageStack {
+    pub fn new() -> ImageStack {
+        // public constructor
+        let name = "ImageStack".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Stacks a list of single-band rasters into a multi-band GeoTIFF.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input raster files, in band order.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output multi-band GeoTIFF file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Band Names".to_owned(),
+            flags: vec!["--names".to_owned()],
+            description: "Optional list of output band names, separated by semicolons.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='band1.tif;band2.tif;band3.tif' -o=stack.tif --names='blue;green;red'", short_exe, name).replace("*", &sep);
+
+        ImageStack {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ImageStack {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_files_str = String::new();
+        let mut output_file = String::new();
+        let mut names_str = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--names"])? {
+            names_str = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut cmd = input_files_str.split(";");
+        let mut input_files = cmd.collect::<Vec<&str>>();
+        if input_files.len() == 1 {
+            cmd = input_files_str.split(",");
+            input_files = cmd.collect::<Vec<&str>>();
+        }
+        let input_files: Vec<String> = input_files
+            .iter()
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", working_directory, f)
+                } else {
+                    f.to_string()
+                }
+            })
+            .collect();
+        if input_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "There is something incorrect about the input files. At least one input is required to operate this tool.",
+            ));
+        }
+
+        let mut names: Vec<String> = if names_str.contains(";") {
+            names_str.split(";").map(|n| n.trim().to_string()).collect()
+        } else {
+            names_str.split(",").map(|n| n.trim().to_string()).collect()
+        };
+        names.retain(|n| !n.is_empty());
+
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        // read the bands of each of the inputs
+        let mut bands: Vec<Raster> = vec![];
+        let mut default_names: Vec<String> = vec![];
+        for input_file in &input_files {
+            if verbose {
+                report::message(&format!("Reading {}...", input_file));
+            }
+            let first_band = Raster::new(input_file, "r")?;
+            let num_bands = first_band.configs.bands.max(1) as usize;
+            let stem = match Path::new(input_file).file_stem() {
+                Some(s) => s.to_string_lossy().to_string(),
+                None => input_file.clone(),
+            };
+            for b in 0..num_bands {
+                let band = if b == 0 {
+                    first_band.clone()
+                } else {
+                    Raster::read_band(input_file, b)?
+                };
+                if band.configs.photometric_interp == PhotometricInterpretation::RGB {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "{} is an RGB colour composite; split it into its components using the SplitColourComposite tool before stacking.",
+                            input_file
+                        ),
+                    ));
+                }
+                if !bands.is_empty()
+                    && (band.configs.rows != bands[0].configs.rows
+                        || band.configs.columns != bands[0].configs.columns)
+                {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The input files must have the same number of rows and columns and spatial extent.",
+                    ));
+                }
+                let name = match band.configs.band_names.get(b) {
+                    Some(n) if !n.is_empty() => n.clone(),
+                    _ if num_bands > 1 => format!("{}_band{}", stem, b + 1),
+                    _ => stem.clone(),
+                };
+                default_names.push(name);
+                bands.push(band);
+            }
+        }
+
+        let num_bands = bands.len();
+        if num_bands > 255 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Image stacks cannot contain more than 255 bands.",
+            ));
+        }
+        if !names.is_empty() && names.len() != num_bands {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The number of band names ({}) does not match the number of bands ({}).",
+                    names.len(),
+                    num_bands
+                ),
+            ));
+        }
+        if names.is_empty() {
+            names = default_names;
+        }
+
+        let start = Instant::now();
+
+        let data_types: Vec<DataType> = bands.iter().map(|b| b.configs.data_type).collect();
+        let data_type = stack_data_type(&data_types);
+
+        let mut output = Raster::initialize_using_file(&output_file, &bands[0]);
+        if output.raster_type != RasterType::GeoTiff {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The output of this tool must be a GeoTIFF (.tif) file.",
+            ));
+        }
+        output.set_data_from_raster(&bands[0])?;
+        output.configs.data_type = data_type;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.color_map = vec![];
+        output.configs.band_names = names.clone();
+        for b in 1..num_bands {
+            output.add_band(&bands[b])?;
+            if verbose {
+                report::progress(
+                    "Progress",
+                    (100.0_f64 * b as f64 / (num_bands - 1) as f64) as usize,
+                );
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        for (b, name) in names.iter().enumerate() {
+            output.add_metadata_entry(format!("Band {}: {}", b + 1, name));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the data type of an image stack. Bands that share the same data type keep it;
+/// otherwise F32 is used, which holds 8- and 16-bit integers exactly, or F64 if any band
+/// has 32- or 64-bit values.
+fn stack_data_type(data_types: &[DataType]) -> DataType {
+    if data_types.iter().all(|dt| *dt == data_types[0]) {
+        return data_types[0];
+    }
+    if data_types.iter().any(|dt| dt.get_data_size() > 2 && *dt != DataType::F32) {
+        return DataType::F64;
+    }
+    DataType::F32
+}
@@ -139,41 +139,19 @@ impl WhiteboxTool for ImageStackProfile {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" || flag_val == "-input" {
-                input_files_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-points" {
-                points_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--points"])? {
+            points_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -206,7 +184,7 @@ impl WhiteboxTool for ImageStackProfile {
         }
 
         if verbose {
-            println!("Reading points data...")
+            report::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -267,7 +245,7 @@ impl WhiteboxTool for ImageStackProfile {
             if verbose {
                 progress = (100.0_f64 * i as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -356,10 +334,10 @@ impl WhiteboxTool for ImageStackProfile {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -387,7 +365,7 @@ impl WhiteboxTool for ImageStackProfile {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -0,0 +1,283 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::frequency_domain::centred_index;
+use algorithms::fft_2d;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool transforms a complex spectrum (`--input`), such as one created by the
+/// `FastFourierTransform` tool, back into an image (`--output`) using the inverse fast
+/// Fourier transform. The spectrum is a two-band GeoTIFF of the real and imaginary parts,
+/// centred such that the zero frequency is at row rows / 2 and column columns / 2. The real
+/// part of the inverse transform is output; the imaginary part is zero for the spectra of
+/// real images, unless they have been edited asymmetrically.
+///
+/// Nodata cells of the image from which the spectrum was calculated contain the mean of the
+/// valid cells in the output, and can be restored by supplying that image (`--base`), whose
+/// nodata cells are then assigned nodata in the output. Frequency-domain filters and masks
+/// can be applied in a single step, without an intermediate spectrum file, using the
+/// `FrequencyDomainFilter` tool.
+///
+/// # See Also
+/// `FastFourierTransform`, `FrequencyDomainFilter`
+pub struct InverseFastFourierTransform {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl InverseFastFourierTransform {
+    pub fn new() -> InverseFastFourierTransform {
+        // public constructor
+        let name = "InverseFastFourierTransform".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Transforms a complex spectrum back into an image using the inverse FFT.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Spectrum File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input two-band spectrum GeoTIFF, as created by FastFourierTransform.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Base File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Optional image from which the spectrum was calculated, used to restore its nodata cells.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=spectrum.tif -o=image.tif --base=original.tif", short_exe, name).replace("*", &sep);
+
+        InverseFastFourierTransform {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for InverseFastFourierTransform {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut base_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !base_file.is_empty() && !base_file.contains(&sep) && !base_file.contains("/") {
+            base_file = format!("{}{}", working_directory, base_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let real = Raster::new(&input_file, "r")?;
+        if real.configs.bands < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input spectrum must be a two-band GeoTIFF of the real and imaginary parts.",
+            ));
+        }
+        let imaginary = Raster::read_band(&input_file, 1)?;
+        let base = if base_file.is_empty() {
+            None
+        } else {
+            let b = Raster::new(&base_file, "r")?;
+            if b.configs.rows != real.configs.rows || b.configs.columns != real.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The base image must have the same number of rows and columns as the spectrum.",
+                ));
+            }
+            Some(b)
+        };
+
+        let start = Instant::now();
+
+        let rows = real.configs.rows;
+        let columns = real.configs.columns;
+
+        let mut re = vec![0f64; rows * columns];
+        let mut im = vec![0f64; rows * columns];
+        for u in 0..rows {
+            let row = centred_index(u, rows) as isize;
+            for v in 0..columns {
+                let col = centred_index(v, columns) as isize;
+                re[u * columns + v] = real.get_value(row, col);
+                im[u * columns + v] = imaginary.get_value(row, col);
+            }
+        }
+
+        if verbose {
+            report::message("Calculating the inverse Fourier transform...")
+        };
+        fft_2d(&mut re, &mut im, rows, columns, true);
+
+        let mut output = match base {
+            Some(ref b) => Raster::initialize_using_file(&output_file, b),
+            None => Raster::initialize_using_file(&output_file, &real),
+        };
+        output.configs.bands = 1;
+        output.configs.band_names = vec![];
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        if output.configs.data_type != DataType::F64 {
+            output.configs.data_type = DataType::F32;
+        }
+        if base.is_none() {
+            output.configs.nodata = -32768f64;
+            output.configs.z_units = String::from("not specified");
+        }
+        let nodata = output.configs.nodata;
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let mut data = re[row * columns..(row + 1) * columns].to_vec();
+            if let Some(ref b) = base {
+                let base_nodata = b.configs.nodata;
+                for (z, zb) in data.iter_mut().zip(b.get_row_data(row as isize)) {
+                    if zb == base_nodata {
+                        *z = nodata;
+                    }
+                }
+            }
+            output.set_row_data(row as isize, data);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
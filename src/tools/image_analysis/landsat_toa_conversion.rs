@@ -0,0 +1,273 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::sensor_metadata::{convert_band, output_directory, LandsatMetadata};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+
+/// This tool converts the digital numbers (DNs) of Landsat bands to top-of-atmosphere (TOA)
+/// reflectance, or to TOA radiance, using the rescaling coefficients in the scene's MTL
+/// metadata file (`--metadata`), such that spectral indices and classifications are based on
+/// physically meaningful values. TOA reflectance is corrected for the sun elevation of the
+/// scene:
+///
+/// > &rho; = (M<sub>&rho;</sub> DN + A<sub>&rho;</sub>) / sin(&theta;<sub>SE</sub>)
+///
+/// Thermal bands (e.g. Landsat 8 bands 10 and 11) are converted to at-sensor brightness
+/// temperature, in kelvin, using the band's thermal constants:
+///
+/// > T = K<sub>2</sub> / ln(K<sub>1</sub> / L + 1)
+///
+/// where L is the TOA radiance. When the `--radiance` flag is specified, all bands, including
+/// the thermal bands, are instead converted to TOA radiance (W/(m<sup>2</sup> sr &mu;m)).
+///
+/// The band rasters to convert (`--inputs`) are identified by the band file names listed in
+/// the metadata, or by a '_B' band suffix, e.g. LC08_L1TP_018030_20200621_20200707_01_T1_B4.tif.
+/// If no inputs are specified, all of the bands listed in the metadata are converted. Each
+/// output is saved as an F32 GeoTIFF in the output directory (`--output_dir`; by default the
+/// working directory), named after its input with a '_toa', '_rad', or '_bt' suffix. DNs of
+/// zero, which Landsat uses for fill, are assigned nodata in the outputs.
+///
+/// # See Also
+/// `Sentinel2ToaConversion`, `NormalizedDifferenceVegetationIndex`
+pub struct LandsatToaConversion {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LandsatToaConversion {
+    pub fn new() -> LandsatToaConversion {
+        // public constructor
+        let name = "LandsatToaConversion".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Converts Landsat DNs to TOA reflectance, radiance, or brightness temperature using the MTL metadata.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input MTL Metadata File".to_owned(),
+            flags: vec!["--metadata".to_owned()],
+            description: "Input Landsat MTL metadata text file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Text),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Band Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input band files; all bands in the metadata if unspecified.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Directory".to_owned(),
+            flags: vec!["--output_dir".to_owned()],
+            description: "Output directory; the working directory if unspecified.".to_owned(),
+            parameter_type: ParameterType::Directory,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Convert to radiance?".to_owned(),
+            flags: vec!["--radiance".to_owned()],
+            description: "Convert all bands to TOA radiance rather than reflectance and brightness temperature.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --metadata=LC08_MTL.txt -i='LC08_B4.tif;LC08_B5.tif;LC08_B10.tif'
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --metadata=LC08_MTL.txt --output_dir=toa --radiance", short_exe, name).replace("*", &sep);
+
+        LandsatToaConversion {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LandsatToaConversion {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut metadata_file = String::new();
+        let mut input_files_str = String::new();
+        let mut output_dir = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--metadata"])? {
+            metadata_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--output_dir"])? {
+            output_dir = v;
+        }
+        let radiance = tool_args.get_flag(&["--radiance"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !metadata_file.contains(&sep) && !metadata_file.contains("/") {
+            metadata_file = format!("{}{}", working_directory, metadata_file);
+        }
+        let output_dir = output_directory(&output_dir, working_directory)?;
+
+        let start = Instant::now();
+
+        let metadata = LandsatMetadata::read(&metadata_file)?;
+
+        // pair each input with its band
+        let mut bands: Vec<(String, String)> = vec![];
+        if input_files_str.trim().is_empty() {
+            for (band, file) in metadata.band_files() {
+                if metadata.get_f64(&format!("RADIANCE_MULT_BAND_{}", band)).is_some()
+                    && Path::new(&file).exists()
+                {
+                    bands.push((band, file));
+                }
+            }
+            if bands.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "None of the band files listed in the metadata were found; specify the input files.",
+                ));
+            }
+        } else {
+            for f in input_files_str.split(|c| c == ';' || c == ',') {
+                let mut file = f.trim().to_string();
+                if file.is_empty() {
+                    continue;
+                }
+                if !file.contains(&sep) && !file.contains("/") {
+                    file = format!("{}{}", working_directory, file);
+                }
+                match metadata.band_from_file_name(&file) {
+                    Some(band) => bands.push((band, file)),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("The band of {} could not be identified from its file name.", file),
+                        ))
+                    }
+                }
+            }
+        }
+
+        let num_bands = bands.len();
+        for (i, (band, input_file)) in bands.into_iter().enumerate() {
+            let conversion = metadata.conversion(&band, radiance)?;
+            let suffix = if radiance {
+                "rad"
+            } else if metadata.is_thermal(&band) {
+                "bt"
+            } else {
+                "toa"
+            };
+            let stem = match Path::new(&input_file).file_stem() {
+                Some(s) => s.to_string_lossy().to_string(),
+                None => format!("band{}", band),
+            };
+            let output_file = Path::new(&output_dir)
+                .join(format!("{}_{}.tif", stem, suffix))
+                .to_string_lossy()
+                .to_string();
+            let entries = vec![
+                format!("Created by whitebox_tools\' {} tool", self.get_tool_name()),
+                format!("Input file: {}", input_file),
+                format!("Metadata file: {}", metadata_file),
+                format!("Band: {}", band),
+            ];
+            convert_band(&input_file, &output_file, conversion, entries)?;
+            if verbose {
+                report::message(&format!("Band {} written to {}", band, output_file));
+                report::progress(
+                    "Progress",
+                    (100.0_f64 * (i + 1) as f64 / num_bands as f64) as usize,
+                );
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -149,60 +149,37 @@ impl WhiteboxTool for LineDetectionFilter {
         let mut variant = "v".to_string();
         let mut absvals = false;
         let mut clip_amount = 0.0;
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--variant"])? {
+            variant = v;
+            if variant.to_lowercase().contains("v") {
+                variant = "v".to_string();
+            } else if variant.to_lowercase().contains("h") {
+                variant = "h".to_string();
+            } else if variant.to_lowercase().contains("45") {
+                variant = "45".to_string();
+            } else if variant.to_lowercase().contains("135") {
+                variant = "135".to_string();
             }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-variant" || vec[0].to_lowercase() == "--variant" {
-                if keyval {
-                    variant = vec[1].to_string();
-                } else {
-                    variant = args[i + 1].to_string();
-                }
-                if variant.to_lowercase().contains("v") {
-                    variant = "v".to_string();
-                } else if variant.to_lowercase().contains("h") {
-                    variant = "h".to_string();
-                } else if variant.to_lowercase().contains("45") {
-                    variant = "45".to_string();
-                } else if variant.to_lowercase().contains("135") {
-                    variant = "135".to_string();
-                }
-            } else if vec[0].to_lowercase() == "-absvals" || vec[0].to_lowercase() == "--absvals" {
-                absvals = true;
-            } else if vec[0].to_lowercase() == "-clip" || vec[0].to_lowercase() == "--clip" {
-                if keyval {
-                    clip_amount = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    clip_amount = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                if clip_amount < 0.0 {
-                    clip_amount = 0.0;
-                }
+        }
+        if tool_args.get_flag(&["--absvals"])? {
+            absvals = true;
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip"])? {
+            clip_amount = v;
+            if clip_amount < 0.0 {
+                clip_amount = 0.0;
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,7 +195,7 @@ impl WhiteboxTool for LineDetectionFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -228,8 +205,8 @@ impl WhiteboxTool for LineDetectionFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
-
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -255,7 +232,7 @@ impl WhiteboxTool for LineDetectionFilter {
 
                 if !absvals {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
+                        let mut data = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z = input[(row, col)];
                             if z != nodata {
@@ -274,7 +251,7 @@ impl WhiteboxTool for LineDetectionFilter {
                     }
                 } else {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
+                        let mut data = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z = input[(row, col)];
                             if z != nodata {
@@ -301,14 +278,14 @@ impl WhiteboxTool for LineDetectionFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if clip_amount > 0.0 {
-            println!("Clipping output...");
+            report::message("Clipping output...");
             output.clip_min_and_max_by_percent(clip_amount);
         }
 
@@ -325,22 +302,19 @@ impl WhiteboxTool for LineDetectionFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -123,37 +123,16 @@ impl WhiteboxTool for LineThinning {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -169,13 +148,15 @@ impl WhiteboxTool for LineThinning {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let start = Instant::now();
 
@@ -193,7 +174,7 @@ impl WhiteboxTool for LineThinning {
             let tx = tx.clone();
             thread::spawn(move || {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         if input[(row, col)] > 0.0 && input[(row, col)] != nodata {
                             data[col as usize] = 1.0;
@@ -206,7 +187,6 @@ impl WhiteboxTool for LineThinning {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
@@ -214,7 +194,7 @@ impl WhiteboxTool for LineThinning {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
+                    report::progress("Initializing output", progress);
                     old_progress = progress;
                 }
             }
@@ -256,7 +236,7 @@ impl WhiteboxTool for LineThinning {
                 for row in 0..rows {
                     for col in 0..columns {
                         z = output[(row, col)];
-                        if z > 0.0 && z != nodata {
+                        if z > 0.0 && z != out_nodata {
                             // fill the neighbours array
                             for i in 0..8 {
                                 neighbours[i] = output[(row + dy[i], col + dx[i])];
@@ -279,7 +259,7 @@ impl WhiteboxTool for LineThinning {
                 if verbose {
                     progress = (100.0_f64 * a as f64 / 7.0) as usize;
                     if progress != old_progress {
-                        println!("Loop Number {}: {}%", loop_num, progress);
+                        report::progress(&format!("Loop Number {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -295,19 +275,16 @@ impl WhiteboxTool for LineThinning {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 6, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTES: The input image should contain integer values but floating point data will be handled using a multiplier.
@@ -21,6 +21,15 @@ use std::thread;
 use tools::*;
 
 /// Assigns each cell in the output grid the most frequently occurring value (mode) in a moving window centred on each grid cell in the input raster.
+///
+/// The kernel may be either a rectangle (the default) or a circle (`--kernel`), i.e. an
+/// ellipse inscribed within the filter dimensions. A circular kernel reduces the tendency
+/// of the filter to square off the corners of class boundaries. When there are several
+/// modes within a circular kernel, the value of the centre cell is retained if it is one
+/// of them, and otherwise the smallest of them is used.
+///
+/// # See Also
+/// `MinorityFilter`, `GeneralizeClassifiedRaster`, `BoundaryClean`
 pub struct MajorityFilter {
     name: String,
     description: String,
@@ -73,6 +82,19 @@ impl MajorityFilter {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Kernel Shape".to_owned(),
+            flags: vec!["--kernel".to_owned()],
+            description: "Shape of the filter kernel; options are 'rectangle' and 'circle'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "rectangle".to_owned(),
+                "circle".to_owned(),
+            ]),
+            default_value: Some("rectangle".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -85,7 +107,8 @@ impl MajorityFilter {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --filter=25",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --filter=25
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --filterx=7 --filtery=7 --kernel=circle",
             short_exe, name
         ).replace("*", &sep);
 
@@ -137,59 +160,36 @@ impl WhiteboxTool for MajorityFilter {
         let mut output_file = String::new();
         let mut filter_size_x = 11usize;
         let mut filter_size_y = 11usize;
+        let mut circular = false;
         if args.len() == 0 {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if vec[0].to_lowercase() == "-filterx" || vec[0].to_lowercase() == "--filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-filtery" || vec[0].to_lowercase() == "--filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--kernel"])? {
+            circular = v.to_lowercase().contains("circ");
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -222,7 +222,7 @@ impl WhiteboxTool for MajorityFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -233,6 +233,7 @@ impl WhiteboxTool for MajorityFilter {
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         /* 
         Need to know if the image contains integer or floating point values.
@@ -247,83 +248,58 @@ impl WhiteboxTool for MajorityFilter {
         let min_val_mult = min_val * multiplier;
         let num_bins = (max_val * multiplier - min_val_mult).ceil() as usize + 1;
 
-        let num_procs = num_cpus::get() as isize;
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let input = input.clone();
-            let tx1 = tx.clone();
-            thread::spawn(move || {
-                let mut bin_val: usize;
-                let (mut start_col, mut end_col, mut start_row, mut end_row): (
-                    isize,
-                    isize,
-                    isize,
-                    isize,
-                );
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    start_row = row - midpoint_y;
-                    end_row = row + midpoint_y;
-                    let mut data = vec![nodata; columns as usize];
-                    let mut histo = vec![0; num_bins];
-                    let mut set = HashSet::new();
-                    // I realize that the above two lines could be combined
-                    // to use a HashMap instead of a Vec and a HashSet. Trouble is
-                    // Rust's HashMap is painful to use.
-                    let mut mode_bin = 0usize;
-                    let mut mode_freq = 0usize;
-                    let mut z: f64;
-                    for col in 0..columns {
-                        if col > 0 {
-                            start_col = col - midpoint_x;
-                            end_col = col + midpoint_x;
-                            // remove the trailing column from the histo
-                            for row2 in start_row..end_row + 1 {
-                                z = input.get_value(row2, start_col - 1);
-                                if z != nodata {
-                                    bin_val = (z * multiplier - min_val_mult).floor() as usize;
-                                    histo[bin_val] -= 1;
-                                    if histo[bin_val] == 0 {
-                                        set.remove(&bin_val);
-                                    }
-                                }
-                            }
-
-                            // add the leading column to the histo
-                            for row2 in start_row..end_row + 1 {
-                                z = input.get_value(row2, end_col);
-                                if z != nodata {
-                                    bin_val = (z * multiplier - min_val_mult).floor() as usize;
-                                    histo[bin_val] += 1;
-                                    if histo[bin_val] > histo[mode_bin] {
-                                        mode_freq = histo[bin_val];
-                                        mode_bin = bin_val;
-                                    }
-                                    if histo[bin_val] == 1 {
-                                        set.insert(bin_val);
+        if circular {
+            let offsets = kernel_offsets(filter_size_x, filter_size_y, true);
+            frequency_filter(input, &mut output, offsets, false, verbose);
+        } else {
+            let num_procs = num_cpus::get() as isize;
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let input = input.clone();
+                let tx1 = tx.clone();
+                thread::spawn(move || {
+                    let mut bin_val: usize;
+                    let (mut start_col, mut end_col, mut start_row, mut end_row): (
+                        isize,
+                        isize,
+                        isize,
+                        isize,
+                    );
+                    for row in (0..rows).filter(|r| r % num_procs == tid) {
+                        start_row = row - midpoint_y;
+                        end_row = row + midpoint_y;
+                        let mut data = vec![out_nodata; columns as usize];
+                        let mut histo = vec![0; num_bins];
+                        let mut set = HashSet::new();
+                        // I realize that the above two lines could be combined
+                        // to use a HashMap instead of a Vec and a HashSet. Trouble is
+                        // Rust's HashMap is painful to use.
+                        let mut mode_bin = 0usize;
+                        let mut mode_freq = 0usize;
+                        let mut z: f64;
+                        for col in 0..columns {
+                            if col > 0 {
+                                start_col = col - midpoint_x;
+                                end_col = col + midpoint_x;
+                                // remove the trailing column from the histo
+                                for row2 in start_row..end_row + 1 {
+                                    z = input.get_value(row2, start_col - 1);
+                                    if z != nodata {
+                                        bin_val = (z * multiplier - min_val_mult).floor() as usize;
+                                        histo[bin_val] -= 1;
+                                        if histo[bin_val] == 0 {
+                                            set.remove(&bin_val);
+                                        }
                                     }
                                 }
-                            }
 
-                            if histo[mode_bin] < mode_freq {
-                                mode_freq = histo[mode_bin];
-                                for x in &set {
-                                    if histo[*x] > mode_freq {
-                                        mode_freq = histo[*x];
-                                        mode_bin = *x;
-                                    }
-                                }
-                            }
-                        } else {
-                            // initialize the filter histo
-                            start_col = col - midpoint_x;
-                            end_col = col + midpoint_x;
-                            for col2 in start_col..end_col + 1 {
+                                // add the leading column to the histo
                                 for row2 in start_row..end_row + 1 {
-                                    z = input.get_value(row2, col2);
+                                    z = input.get_value(row2, end_col);
                                     if z != nodata {
                                         bin_val = (z * multiplier - min_val_mult).floor() as usize;
                                         histo[bin_val] += 1;
-                                        if histo[bin_val] > mode_freq {
+                                        if histo[bin_val] > histo[mode_bin] {
                                             mode_freq = histo[bin_val];
                                             mode_bin = bin_val;
                                         }
@@ -332,25 +308,56 @@ impl WhiteboxTool for MajorityFilter {
                                         }
                                     }
                                 }
+
+                                if histo[mode_bin] < mode_freq {
+                                    mode_freq = histo[mode_bin];
+                                    for x in &set {
+                                        if histo[*x] > mode_freq {
+                                            mode_freq = histo[*x];
+                                            mode_bin = *x;
+                                        }
+                                    }
+                                }
+                            } else {
+                                // initialize the filter histo
+                                start_col = col - midpoint_x;
+                                end_col = col + midpoint_x;
+                                for col2 in start_col..end_col + 1 {
+                                    for row2 in start_row..end_row + 1 {
+                                        z = input.get_value(row2, col2);
+                                        if z != nodata {
+                                            bin_val =
+                                                (z * multiplier - min_val_mult).floor() as usize;
+                                            histo[bin_val] += 1;
+                                            if histo[bin_val] > mode_freq {
+                                                mode_freq = histo[bin_val];
+                                                mode_bin = bin_val;
+                                            }
+                                            if histo[bin_val] == 1 {
+                                                set.insert(bin_val);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            if input.get_value(row, col) != nodata {
+                                data[col as usize] = (mode_bin as f64 + min_val_mult) / multiplier;
                             }
                         }
-                        if input.get_value(row, col) != nodata {
-                            data[col as usize] = (mode_bin as f64 + min_val_mult) / multiplier;
-                        }
+                        tx1.send((row, data)).unwrap();
                     }
-                    tx1.send((row, data)).unwrap();
-                }
-            });
-        }
+                });
+            }
 
-        for row in 0..rows {
-            let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
+            for row in 0..rows {
+                let data = rx.recv().unwrap();
+                output.set_row_data(data.0, data.1);
+                if verbose {
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Progress", progress);
+                        old_progress = progress;
+                    }
                 }
             }
         }
@@ -363,24 +370,122 @@ impl WhiteboxTool for MajorityFilter {
         output.add_metadata_entry(format!("Input file: {}", input_file));
         output.add_metadata_entry(format!("Filter size x: {}", filter_size_x));
         output.add_metadata_entry(format!("Filter size y: {}", filter_size_y));
+        if circular {
+            output.add_metadata_entry("Kernel shape: circle".to_string());
+        }
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// Returns the (column, row) offsets of the cells of a filter kernel with the specified
+/// (odd) dimensions, which is either a rectangle or an inscribed ellipse (`circular`).
+pub fn kernel_offsets(
+    filter_size_x: usize,
+    filter_size_y: usize,
+    circular: bool,
+) -> Vec<(isize, isize)> {
+    let midpoint_x = (filter_size_x / 2) as isize;
+    let midpoint_y = (filter_size_y / 2) as isize;
+    let mut offsets = vec![];
+    for dy in -midpoint_y..midpoint_y + 1 {
+        for dx in -midpoint_x..midpoint_x + 1 {
+            if !circular
+                || (dx as f64 / midpoint_x.max(1) as f64).powi(2)
+                    + (dy as f64 / midpoint_y.max(1) as f64).powi(2)
+                    <= 1f64
+            {
+                offsets.push((dx, dy));
+            }
+        }
+    }
+    offsets
+}
+
+/// Assigns each valid cell of `output` the most frequent (or, if `minority` is true, the
+/// least frequent) value among the valid cells of the kernel centred on it in `input`.
+/// Ties are resolved in favour of the centre cell's value, if it is one of the tied
+/// values, and otherwise in favour of the smallest value.
+pub fn frequency_filter(
+    input: Arc<Raster>,
+    output: &mut Raster,
+    offsets: Vec<(isize, isize)>,
+    minority: bool,
+    verbose: bool,
+) {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let nodata = input.configs.nodata;
+    let out_nodata = output.configs.nodata;
+    let offsets = Arc::new(offsets);
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let offsets = offsets.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let (mut z, mut zn): (f64, f64);
+            // the kernel's values and their frequencies; categorical rasters have few
+            // distinct values within a kernel, so a linear search is efficient.
+            let mut freq: Vec<(f64, usize)> = Vec::with_capacity(offsets.len());
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data = vec![out_nodata; columns as usize];
+                for col in 0..columns {
+                    z = input.get_value(row, col);
+                    if z == nodata {
+                        continue;
+                    }
+                    freq.clear();
+                    for &(dx, dy) in offsets.iter() {
+                        zn = input.get_value(row + dy, col + dx);
+                        if zn != nodata {
+                            match freq.iter().position(|f| f.0 == zn) {
+                                Some(i) => freq[i].1 += 1,
+                                None => freq.push((zn, 1)),
+                            }
+                        }
+                    }
+                    let mut best = freq[0];
+                    for &f in freq.iter().skip(1) {
+                        let better = if minority { f.1 < best.1 } else { f.1 > best.1 };
+                        if better || (f.1 == best.1 && best.0 != z && (f.0 == z || f.0 < best.0))
+                        {
+                            best = f;
+                        }
+                    }
+                    data[col as usize] = best.0;
+                }
+                tx.send((row, data)).unwrap();
+            }
+        });
+    }
+
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+    for r in 0..rows {
+        let (row, data) = rx.recv().unwrap();
+        output.set_row_data(row, data);
+        if verbose {
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Progress", progress);
+                old_progress = progress;
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 15, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTES: This tool uses the efficient running-median filtering algorithm of Huang, Yang, and Tang (1979).
@@ -162,60 +162,29 @@ impl WhiteboxTool for MedianFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if flag_val == "-filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-sig_digits" {
-                if keyval {
-                    num_sig_digits = vec[1].to_string().parse::<i32>().unwrap();
-                } else {
-                    num_sig_digits = args[i + 1].to_string().parse::<i32>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--sig_digits"])? {
+            num_sig_digits = v as i32;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,7 +219,7 @@ impl WhiteboxTool for MedianFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -341,14 +310,16 @@ impl WhiteboxTool for MedianFilter {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning data: {}%", progress);
+                    report::progress("Binning data", progress);
                     old_progress = progress;
                 }
             }
         }
 
         let bd = Arc::new(binned_data); // wrap binned_data in an Arc
-        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let mut output = RasterWriter::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        output.begin_write()?;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let binned_data = bd.clone();
@@ -386,7 +357,7 @@ impl WhiteboxTool for MedianFilter {
                     median = bin_nodata;
                     n = 0.0;
                     n_less_than = 0.0;
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         bin_val = binned_data.get_value(row, col);
                         if bin_val != bin_nodata {
@@ -472,7 +443,7 @@ impl WhiteboxTool for MedianFilter {
                                 data[col as usize] =
                                     output_fn(row, col, (median + min_bin) as f64 / multiplier);
                             } else {
-                                data[col as usize] = nodata;
+                                data[col as usize] = out_nodata;
                             }
 
                             old_median = median;
@@ -487,11 +458,11 @@ impl WhiteboxTool for MedianFilter {
 
         for row in 0..rows {
             let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
+            output.write_rows(data.0, data.1)?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    report::progress("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -511,19 +482,16 @@ impl WhiteboxTool for MedianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
-        let _ = match output.write() {
+        let _ = match output.finalize() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
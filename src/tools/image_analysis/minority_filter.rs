@@ -0,0 +1,256 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::majority_filter::{frequency_filter, kernel_offsets};
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::Arc;
+use tools::*;
+
+/// Assigns each cell in the output grid the least frequently occurring value in a moving
+/// window centred on each grid cell in the input raster. The minority filter is the
+/// complement of the `MajorityFilter` and is useful for highlighting the rare classes,
+/// and the boundaries between classes, in categorical rasters such as classified images.
+///
+/// The kernel may be either a rectangle (the default) or a circle (`--kernel`), i.e. an
+/// ellipse inscribed within the filter dimensions (`--filterx` and `--filtery`). When
+/// several values are equally infrequent within the kernel, the value of the centre cell
+/// is retained if it is one of them, and otherwise the smallest of them is used. NoData
+/// cells are ignored.
+///
+/// # See Also
+/// `MajorityFilter`, `DiversityFilter`
+pub struct MinorityFilter {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl MinorityFilter {
+    /// Public constructor.
+    pub fn new() -> MinorityFilter {
+        let name = "MinorityFilter".to_string();
+        let toolbox = "Image Processing Tools/Filters".to_string();
+        let description = "Assigns each cell in the output grid the least frequently occurring value in a moving window centred on each grid cell in the input raster.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter X-Dimension".to_owned(),
+            flags: vec!["--filterx".to_owned()],
+            description: "Size of the filter kernel in the x-direction.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Y-Dimension".to_owned(),
+            flags: vec!["--filtery".to_owned()],
+            description: "Size of the filter kernel in the y-direction.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Kernel Shape".to_owned(),
+            flags: vec!["--kernel".to_owned()],
+            description: "Shape of the filter kernel; options are 'rectangle' and 'circle'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "rectangle".to_owned(),
+                "circle".to_owned(),
+            ]),
+            default_value: Some("rectangle".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=image.tif -o=output.tif --filterx=5 --filtery=5 --kernel=circle",
+            short_exe, name
+        ).replace("*", &sep);
+
+        MinorityFilter {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for MinorityFilter {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut filter_size_x = 3usize;
+        let mut filter_size_y = 3usize;
+        let mut circular = false;
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--kernel"])? {
+            circular = v.to_lowercase().contains("circ");
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if filter_size_x < 3 {
+            filter_size_x = 3;
+        }
+        if filter_size_y < 3 {
+            filter_size_y = 3;
+        }
+
+        // The filter dimensions must be odd numbers such that there is a middle pixel
+        if filter_size_x % 2 == 0 {
+            filter_size_x += 1;
+        }
+        if filter_size_y % 2 == 0 {
+            filter_size_y += 1;
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let offsets = kernel_offsets(filter_size_x, filter_size_y, circular);
+        frequency_filter(input, &mut output, offsets, true, verbose);
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Filter size x: {}", filter_size_x));
+        output.add_metadata_entry(format!("Filter size y: {}", filter_size_y));
+        output.add_metadata_entry(format!(
+            "Kernel shape: {}",
+            if circular { "circle" } else { "rectangle" }
+        ));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
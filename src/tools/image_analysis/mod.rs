@@ -1,20 +1,32 @@
 // private sub-module defined in other files
 mod adaptive_filter;
+mod apply_lookup_table;
 mod balance_contrast_enhancement;
+mod balanced_mosaic;
 mod bilateral_filter;
+mod byte_contrast_stretch;
+mod byte_stretch;
 mod change_vector_analysis;
 mod closing;
+mod colour_balance;
 mod conservative_smoothing_filter;
 mod corner_detection;
 mod correct_vignetting;
 mod create_colour_composite;
+mod dark_object_subtraction;
+mod destriping_filter;
 mod direct_decorrelation_stretch;
 mod diversity_filter;
 mod dog_filter;
 mod edge_preserving_mean_filter;
 mod emboss_filter;
+mod extract_bands;
 mod fast_almost_gaussian_filter;
+mod fast_fourier_transform;
 mod flip_image;
+mod frequency_domain;
+mod focal_statistics;
+mod frequency_domain_filter;
 mod gamma_correction;
 mod gaussian_contrast_stretch;
 mod gaussian_filter;
@@ -23,11 +35,18 @@ mod highpass_median_filter;
 mod histogram_equalization;
 mod histogram_matching;
 mod histogram_matching_two_images;
+mod hsv_to_rgb;
 mod ihs_to_rgb;
+mod image_coregistration;
+mod image_differencing;
+mod image_ratio;
+mod image_stack;
 mod image_stack_profile;
 mod integral_image;
+mod inverse_fast_fourier_transform;
 mod k_means_clustering;
 mod k_nearest_mean_filter;
+mod landsat_toa_conversion;
 mod laplacian_filter;
 mod lee_filter;
 mod line_detection_filter;
@@ -39,7 +58,10 @@ mod mean_filter;
 mod median_filter;
 mod min_filter;
 mod min_max_contrast_stretch;
+mod minority_filter;
 mod modified_k_means_clustering;
+mod morphology;
+mod morphological_filter;
 mod mosaic;
 mod ndvi;
 mod olympic_filter;
@@ -49,11 +71,17 @@ mod percentage_contrast_stretch;
 mod percentile_filter;
 mod prewitt_filter;
 mod range_filter;
+mod rank_filter;
+mod regression_change_detection;
 mod remove_spurs;
 mod resample;
+mod rgb_image;
+mod rgb_to_hsv;
 mod rgb_to_ihs;
 mod roberts_filter;
 mod scharr_filter;
+mod sensor_metadata;
+mod sentinel2_toa_conversion;
 mod sigmoidal_contrast_stretch;
 mod sobel_filter;
 mod split_colour_composite;
@@ -61,6 +89,7 @@ mod stdev_contrast_stretch;
 mod stdev_filter;
 mod thicken_line;
 mod tophat;
+mod topographic_correction;
 mod total_filter;
 mod unsharp_masking;
 mod user_defined_weights_filter;
@@ -68,21 +97,31 @@ mod write_func_memory_insertion;
 
 // exports identifiers from private sub-modules in the current module namespace
 pub use self::adaptive_filter::AdaptiveFilter;
+pub use self::apply_lookup_table::ApplyLookupTable;
 pub use self::balance_contrast_enhancement::BalanceContrastEnhancement;
+pub use self::balanced_mosaic::BalancedMosaic;
 pub use self::bilateral_filter::BilateralFilter;
+pub use self::byte_contrast_stretch::ByteContrastStretch;
 pub use self::change_vector_analysis::ChangeVectorAnalysis;
 pub use self::closing::Closing;
+pub use self::colour_balance::ColourBalance;
 pub use self::conservative_smoothing_filter::ConservativeSmoothingFilter;
 pub use self::corner_detection::CornerDetection;
 pub use self::correct_vignetting::CorrectVignetting;
 pub use self::create_colour_composite::CreateColourComposite;
+pub use self::dark_object_subtraction::DarkObjectSubtraction;
+pub use self::destriping_filter::DestripingFilter;
 pub use self::direct_decorrelation_stretch::DirectDecorrelationStretch;
 pub use self::diversity_filter::DiversityFilter;
 pub use self::dog_filter::DiffOfGaussianFilter;
 pub use self::edge_preserving_mean_filter::EdgePreservingMeanFilter;
 pub use self::emboss_filter::EmbossFilter;
+pub use self::extract_bands::ExtractBands;
 pub use self::fast_almost_gaussian_filter::FastAlmostGaussianFilter;
+pub use self::fast_fourier_transform::FastFourierTransform;
 pub use self::flip_image::FlipImage;
+pub use self::focal_statistics::FocalStatistics;
+pub use self::frequency_domain_filter::FrequencyDomainFilter;
 pub use self::gamma_correction::GammaCorrection;
 pub use self::gaussian_contrast_stretch::GaussianContrastStretch;
 pub use self::gaussian_filter::GaussianFilter;
@@ -91,11 +130,18 @@ pub use self::highpass_median_filter::HighPassMedianFilter;
 pub use self::histogram_equalization::HistogramEqualization;
 pub use self::histogram_matching::HistogramMatching;
 pub use self::histogram_matching_two_images::HistogramMatchingTwoImages;
+pub use self::hsv_to_rgb::HsvToRgb;
 pub use self::ihs_to_rgb::IhsToRgb;
+pub use self::image_coregistration::ImageCoregistration;
+pub use self::image_differencing::ImageDifferencing;
+pub use self::image_ratio::ImageRatio;
+pub use self::image_stack::ImageStack;
 pub use self::image_stack_profile::ImageStackProfile;
 pub use self::integral_image::IntegralImage;
+pub use self::inverse_fast_fourier_transform::InverseFastFourierTransform;
 pub use self::k_means_clustering::KMeansClustering;
 pub use self::k_nearest_mean_filter::KNearestMeanFilter;
+pub use self::landsat_toa_conversion::LandsatToaConversion;
 pub use self::laplacian_filter::LaplacianFilter;
 pub use self::lee_filter::LeeFilter;
 pub use self::line_detection_filter::LineDetectionFilter;
@@ -107,7 +153,9 @@ pub use self::mean_filter::MeanFilter;
 pub use self::median_filter::MedianFilter;
 pub use self::min_filter::MinimumFilter;
 pub use self::min_max_contrast_stretch::MinMaxContrastStretch;
+pub use self::minority_filter::MinorityFilter;
 pub use self::modified_k_means_clustering::ModifiedKMeansClustering;
+pub use self::morphological_filter::MorphologicalFilter;
 pub use self::mosaic::Mosaic;
 pub use self::ndvi::NormalizedDifferenceVegetationIndex;
 pub use self::olympic_filter::OlympicFilter;
@@ -117,11 +165,15 @@ pub use self::percentage_contrast_stretch::PercentageContrastStretch;
 pub use self::percentile_filter::PercentileFilter;
 pub use self::prewitt_filter::PrewittFilter;
 pub use self::range_filter::RangeFilter;
+pub use self::rank_filter::RankFilter;
+pub use self::regression_change_detection::RegressionChangeDetection;
 pub use self::remove_spurs::RemoveSpurs;
 pub use self::resample::Resample;
+pub use self::rgb_to_hsv::RgbToHsv;
 pub use self::rgb_to_ihs::RgbToIhs;
 pub use self::roberts_filter::RobertsCrossFilter;
 pub use self::scharr_filter::ScharrFilter;
+pub use self::sentinel2_toa_conversion::Sentinel2ToaConversion;
 pub use self::sigmoidal_contrast_stretch::SigmoidalContrastStretch;
 pub use self::sobel_filter::SobelFilter;
 pub use self::split_colour_composite::SplitColourComposite;
@@ -129,6 +181,7 @@ pub use self::stdev_contrast_stretch::StandardDeviationContrastStretch;
 pub use self::stdev_filter::StandardDeviationFilter;
 pub use self::thicken_line::ThickenRasterLine;
 pub use self::tophat::TophatTransform;
+pub use self::topographic_correction::TopographicCorrection;
 pub use self::total_filter::TotalFilter;
 pub use self::unsharp_masking::UnsharpMasking;
 pub use self::user_defined_weights_filter::UserDefinedWeightsFilter;
@@ -203,82 +203,32 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" || flag_val == "-input" {
-                if keyval {
-                    input_files_str = vec[1].to_string();
-                } else {
-                    input_files_str = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-out_html" {
-                if keyval {
-                    output_html_file = vec[1].to_string();
-                } else {
-                    output_html_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-start_clusters" {
-                if keyval {
-                    num_classes = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    num_classes = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-merger_dist" {
-                if keyval {
-                    merger_dist = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    merger_dist = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                merger_dist *= merger_dist;
-            } else if flag_val == "-max_iterations" {
-                if keyval {
-                    max_iterations = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    max_iterations = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-class_change" {
-                if keyval {
-                    percent_changed_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    percent_changed_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                // } else if flag_val == "-initialize" {
-                //     if keyval {
-                //         if vec[1].to_string().to_lowercase().contains("rand") {
-                //             initialization_mode = 0;
-                //         }
-                //     } else {
-                //         if args[i + 1].to_string().to_lowercase().contains("diag") {
-                //             initialization_mode = 1;
-                //         }
-                //     }
-                // } else if flag_val == "-min_class_size" {
-                //     if keyval {
-                //         min_class_size = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                //     } else {
-                //         min_class_size = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                //     }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_html"])? {
+            output_html_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--start_clusters"])? {
+            num_classes = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--merger_dist"])? {
+            merger_dist = v;
+            merger_dist *= merger_dist;
+        }
+        if let Some(v) = tool_args.get_usize(&["--max_iterations"])? {
+            max_iterations = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--class_change"])? {
+            percent_changed_threshold = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -342,7 +292,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         let mut input_raster: Vec<Raster> = Vec::with_capacity(num_files);
 
         for i in 0..num_files {
-            println!("Reading file {} of {}", i + 1, num_files);
+            report::message(&format!("Reading file {} of {}", i + 1, num_files));
             if !input_files[i].trim().is_empty() {
                 let mut input_file = input_files[i].trim().to_owned();
                 if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -371,7 +321,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                         || input_raster[i].configs.columns as isize != columns
                     {
                         return Err(Error::new(ErrorKind::InvalidInput,
-                            "All input images must share the same dimensions (rows and columns) and spatial extent."));
+                            "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
                     }
                 }
             }
@@ -384,8 +334,8 @@ impl WhiteboxTool for ModifiedKMeansClustering {
             ));
         }
 
-        let out_nodata = nodata[0];
         let mut output = Raster::initialize_using_file(&output_file, &input_raster[0]);
+        let out_nodata = output.configs.nodata;
         let mut class_centres = vec![vec![0f64; num_files]; num_classes];
 
         // if initialization_mode == 0 {
@@ -507,11 +457,9 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (loop {} of {}): {}%",
-                            loop_num + 1,
-                            max_iterations,
-                            progress
+                        report::progress(
+                            &format!("Progress (loop {} of {})", loop_num + 1, max_iterations),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -571,13 +519,13 @@ impl WhiteboxTool for ModifiedKMeansClustering {
 
             num_classes = class_n.len();
 
-            println!("Number of clusters: {}", num_classes);
+            report::metadata("Number of clusters", &num_classes.to_string());
 
             percent_changed = 100f64 * cells_changed / n;
-            println!(
+            report::message(&format!(
                 "Cells changed {} ({:.4} percent)",
                 cells_changed, percent_changed
-            );
+            ));
             if percent_changed < percent_changed_threshold {
                 break;
             }
@@ -604,19 +552,16 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         if !output_html_file.trim().is_empty() {
@@ -782,7 +727,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                     let _ = output.stdout;
                 }
 
-                println!("Complete! Please see {} for output.", output_html_file);
+                report::message(&format!("Complete! Please see {} for output.", output_html_file));
             }
         }
 
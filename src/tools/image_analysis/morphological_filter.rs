@@ -0,0 +1,384 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::morphology::{erode_or_dilate, StructuringElement};
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::Arc;
+use structures::Array2D;
+use tools::*;
+
+/// This tool performs the mathematical morphology operations of erosion, dilation, opening,
+/// closing, and the white and black top-hat transforms on a greyscale or binary image, using
+/// a structuring element of a user-specified shape (`--shape`) and size (`--size`, in cells).
+/// The structuring element may be a 'square', a 'disk', or a 'line', which is oriented at an
+/// angle (`--angle`) measured in degrees counter-clockwise from the x-axis, e.g. 0 for a
+/// horizontal line and 90 for a vertical line. Even sizes are increased by one, such that the
+/// structuring element is centred on each cell.
+///
+/// The available operations (`--operation`) are:
+///
+/// | Operation | Result |
+/// |-----------|--------|
+/// | erosion   | The minimum value within the structuring element. |
+/// | dilation  | The maximum value within the structuring element. |
+/// | opening   | The dilation of the erosion, which removes bright features smaller than the structuring element. |
+/// | closing   | The erosion of the dilation, which fills dark features smaller than the structuring element. |
+/// | tophat    | The image minus its opening, i.e. the bright features removed by the opening. |
+/// | blackhat  | The closing minus the image, i.e. the dark features filled by the closing. |
+///
+/// For example, the top-hat transform of a normalized digital surface model (nDSM) with a
+/// disk larger than the largest building isolates buildings from broad terrain residuals,
+/// and the opening of a binary stream mask with a line oriented along the channels removes
+/// isolated noise cells while retaining the streams.
+///
+/// When the `--binary` flag is specified, the input is first converted to a binary image, in
+/// which positive values are assigned 1 and all other valid values 0, and the output is also
+/// binary. Nodata cells and cells beyond the edges of the image are ignored by the
+/// structuring element, and nodata cells in the input are nodata in the output.
+///
+/// `Opening`, `Closing`, and `TophatTransform` offer the same operations with rectangular
+/// structuring elements, using a faster algorithm that is better suited to large elements.
+///
+/// # See Also
+/// `Opening`, `Closing`, `TophatTransform`, `MinimumFilter`, `MaximumFilter`
+pub struct MorphologicalFilter {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl MorphologicalFilter {
+    pub fn new() -> MorphologicalFilter {
+        // public constructor
+        let name = "MorphologicalFilter".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Performs a greyscale or binary morphological operation with a square, disk, or line structuring element.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Operation".to_owned(),
+            flags: vec!["--operation".to_owned()],
+            description: "Morphological operation; options include 'erosion', 'dilation', 'opening', 'closing', 'tophat', and 'blackhat'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "erosion".to_owned(),
+                "dilation".to_owned(),
+                "opening".to_owned(),
+                "closing".to_owned(),
+                "tophat".to_owned(),
+                "blackhat".to_owned(),
+            ]),
+            default_value: Some("opening".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Structuring Element Shape".to_owned(),
+            flags: vec!["--shape".to_owned()],
+            description: "Shape of the structuring element; options include 'square', 'disk', and 'line'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "square".to_owned(),
+                "disk".to_owned(),
+                "line".to_owned(),
+            ]),
+            default_value: Some("square".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Structuring Element Size".to_owned(),
+            flags: vec!["--size".to_owned()],
+            description: "Side length, diameter, or length of the structuring element, in cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Line Angle (degrees)".to_owned(),
+            flags: vec!["--angle".to_owned()],
+            description: "Orientation of a line structuring element, in degrees counter-clockwise from the x-axis.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Treat as a binary image?".to_owned(),
+            flags: vec!["--binary".to_owned()],
+            description: "Convert the input to a binary image, in which positive values are 1, before the operation.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=ndsm.tif -o=buildings.tif --operation=tophat --shape=disk --size=31
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.tif -o=cleaned.tif --operation=opening --shape=line --size=5 --angle=45 --binary", short_exe, name).replace("*", &sep);
+
+        MorphologicalFilter {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for MorphologicalFilter {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut operation = "opening".to_string();
+        let mut shape = "square".to_string();
+        let mut size = 3usize;
+        let mut angle = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--operation"])? {
+            operation = v.to_lowercase().replace("-", "").replace("_", "");
+        }
+        if let Some(v) = tool_args.get_string(&["--shape"])? {
+            shape = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_isize(&["--size"])? {
+            if v < 1 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The structuring element size must be at least one cell.",
+                ));
+            }
+            size = v as usize;
+        }
+        if let Some(v) = tool_args.get_f64(&["--angle"])? {
+            angle = v;
+        }
+        let binary = tool_args.get_flag(&["--binary"])?;
+
+        // the first and second (if any) passes, each either a dilation (true) or an erosion,
+        // and whether the result is subtracted from the image (tophat) or vice versa (blackhat)
+        let (first, second, is_tophat, is_blackhat) = match &operation as &str {
+            "erosion" | "erode" => (false, None, false, false),
+            "dilation" | "dilate" => (true, None, false, false),
+            "opening" | "open" => (false, Some(true), false, false),
+            "closing" | "close" => (true, Some(false), false, false),
+            "tophat" | "whitetophat" => (false, Some(true), true, false),
+            "blackhat" | "blacktophat" => (true, Some(false), false, true),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized operation; options include 'erosion', 'dilation', 'opening', 'closing', 'tophat', and 'blackhat'.",
+                ))
+            }
+        };
+        let element = Arc::new(StructuringElement::new(&shape, size, angle)?);
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+
+        let mut data: Array2D<f64> = input.get_data_as_array2d();
+        if binary {
+            for row in 0..rows {
+                for col in 0..columns {
+                    let z = data.get_value(row, col);
+                    if z != nodata {
+                        data.set_value(row, col, if z > 0f64 { 1f64 } else { 0f64 });
+                    }
+                }
+            }
+        }
+        let data = Arc::new(data);
+
+        if verbose {
+            report::message(&format!(
+                "Performing the {}...",
+                if first { "dilation" } else { "erosion" }
+            ));
+        }
+        let mut result = erode_or_dilate(data.clone(), element.clone(), first)?;
+        if let Some(dilate) = second {
+            if verbose {
+                report::message(&format!(
+                    "Performing the {}...",
+                    if dilate { "dilation" } else { "erosion" }
+                ));
+            }
+            result = erode_or_dilate(Arc::new(result), element.clone(), dilate)?;
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let mut values = result.get_row_data(row);
+            if is_tophat || is_blackhat {
+                let z = data.get_row_data(row);
+                for col in 0..columns as usize {
+                    if z[col] != nodata && values[col] != nodata {
+                        values[col] = if is_tophat {
+                            z[col] - values[col]
+                        } else {
+                            values[col] - z[col]
+                        };
+                    }
+                }
+            }
+            for z in values.iter_mut() {
+                if *z == nodata {
+                    *z = out_nodata;
+                }
+            }
+            output.set_row_data(row, values);
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Operation: {}", operation));
+        output.add_metadata_entry(format!("Structuring element: {} of size {}", shape, size));
+        if shape == "line" {
+            output.add_metadata_entry(format!("Line angle: {}", angle));
+        }
+        output.add_metadata_entry(format!("Binary: {}", binary));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
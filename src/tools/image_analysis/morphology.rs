@@ -0,0 +1,144 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use num_cpus;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::Array2D;
+
+/// A structuring element, stored as the offsets of its cells from its centre cell. Each of
+/// the available shapes is symmetric about its centre, such that an element and its
+/// reflection are the same and openings and closings may be calculated with one element.
+pub struct StructuringElement {
+    pub dx: Vec<isize>,
+    pub dy: Vec<isize>,
+}
+
+impl StructuringElement {
+    /// Creates a structuring element. The `size`, in cells, is the side length of a 'square',
+    /// the diameter of a 'disk', or the length of a 'line', which is oriented at `angle`
+    /// degrees counter-clockwise from the x-axis. Even sizes are increased by one such that
+    /// the element has a centre cell.
+    pub fn new(shape: &str, size: usize, angle: f64) -> Result<StructuringElement, Error> {
+        let size = if size % 2 == 0 { size + 1 } else { size };
+        let half = (size / 2) as isize;
+        let mut dx = vec![];
+        let mut dy = vec![];
+        match shape {
+            "square" => {
+                for y in -half..half + 1 {
+                    for x in -half..half + 1 {
+                        dx.push(x);
+                        dy.push(y);
+                    }
+                }
+            }
+            "disk" | "disc" | "circle" => {
+                let r2 = (size as f64 / 2f64) * (size as f64 / 2f64);
+                for y in -half..half + 1 {
+                    for x in -half..half + 1 {
+                        if ((x * x + y * y) as f64) < r2 {
+                            dx.push(x);
+                            dy.push(y);
+                        }
+                    }
+                }
+            }
+            "line" => {
+                // step along the dominant axis of the line, such that it is continuous
+                let (cos, sin) = (angle.to_radians().cos(), angle.to_radians().sin());
+                for i in -half..half + 1 {
+                    if cos.abs() >= sin.abs() {
+                        dx.push(i);
+                        dy.push(-(i as f64 * sin / cos).round() as isize);
+                    } else {
+                        dx.push((i as f64 * cos / sin).round() as isize);
+                        dy.push(-i);
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized structuring element shape; options include 'square', 'disk', and 'line'.",
+                ))
+            }
+        }
+        Ok(StructuringElement { dx: dx, dy: dy })
+    }
+
+    pub fn len(&self) -> usize {
+        self.dx.len()
+    }
+}
+
+/// Calculates the greyscale erosion (the minimum within the structuring element) or, if
+/// `dilate` is true, dilation (the maximum) of a grid in parallel. Nodata cells and cells
+/// beyond the grid edges are ignored, and nodata cells remain nodata in the output.
+pub fn erode_or_dilate(
+    input: Arc<Array2D<f64>>,
+    element: Arc<StructuringElement>,
+    dilate: bool,
+) -> Result<Array2D<f64>, Error> {
+    let rows = input.rows();
+    let columns = input.columns();
+    let nodata = input.nodata();
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let element = element.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let (mut x, mut y): (isize, isize);
+            let mut z: f64;
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data = vec![nodata; columns as usize];
+                for col in 0..columns {
+                    if input.get_value(row, col) == nodata {
+                        continue;
+                    }
+                    let mut extreme = if dilate {
+                        f64::NEG_INFINITY
+                    } else {
+                        f64::INFINITY
+                    };
+                    for a in 0..element.len() {
+                        x = col + element.dx[a];
+                        y = row + element.dy[a];
+                        if x < 0 || x >= columns || y < 0 || y >= rows {
+                            continue;
+                        }
+                        z = input.get_value(y, x);
+                        if z != nodata {
+                            if dilate {
+                                if z > extreme {
+                                    extreme = z;
+                                }
+                            } else if z < extreme {
+                                extreme = z;
+                            }
+                        }
+                    }
+                    data[col as usize] = extreme;
+                }
+                tx.send((row, data)).unwrap();
+            }
+        });
+    }
+
+    let mut output: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+    for _ in 0..rows {
+        let (row, data) = rx.recv().unwrap();
+        output.set_row_data(row, data);
+    }
+    Ok(output)
+}
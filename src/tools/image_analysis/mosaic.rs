@@ -144,53 +144,31 @@ impl WhiteboxTool for Mosaic {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-method" {
-                method = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                if method.to_lowercase().contains("nn") || method.to_lowercase().contains("nearest")
-                {
-                    method = "nn".to_string();
-                } else if method.to_lowercase().contains("bilinear")
-                    || method.to_lowercase().contains("bi")
-                {
-                    method = "bilinear".to_string();
-                } else if method.to_lowercase().contains("cc")
-                    || method.to_lowercase().contains("cubic")
-                {
-                    method = "cc".to_string();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v;
+            if method.to_lowercase().contains("nn") || method.to_lowercase().contains("nearest")
+            {
+                method = "nn".to_string();
+            } else if method.to_lowercase().contains("bilinear")
+                || method.to_lowercase().contains("bi")
+            {
+                method = "bilinear".to_string();
+            } else if method.to_lowercase().contains("cc")
+                || method.to_lowercase().contains("cubic")
+            {
+                method = "cc".to_string();
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,7 +196,7 @@ impl WhiteboxTool for Mosaic {
 
         // read the input files
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
         let mut nodata_vals: Vec<f64> = Vec::with_capacity(num_files);
@@ -378,7 +356,7 @@ impl WhiteboxTool for Mosaic {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -465,7 +443,7 @@ impl WhiteboxTool for Mosaic {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -550,7 +528,7 @@ impl WhiteboxTool for Mosaic {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -565,19 +543,16 @@ impl WhiteboxTool for Mosaic {
         output.add_metadata_entry(format!("Resampling method: {}", method));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -156,52 +156,29 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-nir" || vec[0].to_lowercase() == "--nir" {
-                if keyval {
-                    nir_file = vec[1].to_string();
-                } else {
-                    nir_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-red" || vec[0].to_lowercase() == "--red" {
-                if keyval {
-                    red_file = vec[1].to_string();
-                } else {
-                    red_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-clip" || vec[0].to_lowercase() == "--clip" {
-                if keyval {
-                    clip_amount = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    clip_amount = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-                if clip_amount < 0.0 {
-                    clip_amount = 0.0;
-                }
-            } else if vec[0].to_lowercase() == "-osavi" || vec[0].to_lowercase() == "--osavi" {
-                osavi_mode = true;
-                correction_factor = 0.16;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--nir"])? {
+            nir_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            red_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip"])? {
+            clip_amount = v;
+            if clip_amount < 0.0 {
+                clip_amount = 0.0;
             }
         }
+        if tool_args.get_flag(&["--osavi"])? {
+            osavi_mode = true;
+            correction_factor = 0.16;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -220,7 +197,7 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let nir = Arc::new(Raster::new(&nir_file, "r")?);
@@ -235,13 +212,14 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
         if nir.configs.rows != red.configs.rows || nir.configs.columns != red.configs.columns {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &nir);
+        let out_nodata = output.configs.nodata;
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -252,7 +230,7 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
             thread::spawn(move || {
                 let (mut z_nir, mut z_red): (f64, f64);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nir_nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z_nir = nir[(row, col)];
                         z_red = red[(row, col)];
@@ -261,7 +239,7 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
                                 data[col as usize] =
                                     (z_nir - z_red) / (z_nir + z_red + correction_factor);
                             } else {
-                                data[col as usize] = nir_nodata;
+                                data[col as usize] = out_nodata;
                             }
                         }
                     }
@@ -276,14 +254,14 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if clip_amount > 0.0 {
-            println!("Clipping output...");
+            report::message("Clipping output...");
             output.clip_min_and_max_by_percent(clip_amount);
         }
 
@@ -301,19 +279,16 @@ impl WhiteboxTool for NormalizedDifferenceVegetationIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
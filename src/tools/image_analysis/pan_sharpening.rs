@@ -181,85 +181,37 @@ impl WhiteboxTool for PanchromaticSharpening {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-red" || vec[0].to_lowercase() == "--red" {
-                if keyval {
-                    red_file = vec[1].to_string();
-                } else {
-                    red_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-g"
-                || vec[0].to_lowercase() == "-green"
-                || vec[0].to_lowercase() == "--green"
-            {
-                if keyval {
-                    green_file = vec[1].to_string();
-                } else {
-                    green_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-b"
-                || vec[0].to_lowercase() == "-blue"
-                || vec[0].to_lowercase() == "--blue"
-            {
-                if keyval {
-                    blue_file = vec[1].to_string();
-                } else {
-                    blue_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-p"
-                || vec[0].to_lowercase() == "-pan"
-                || vec[0].to_lowercase() == "--pan"
-            {
-                if keyval {
-                    pan_file = vec[1].to_string();
-                } else {
-                    pan_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-c"
-                || vec[0].to_lowercase() == "-composite"
-                || vec[0].to_lowercase() == "--composite"
-            {
-                if keyval {
-                    composite_file = vec[1].to_string();
-                } else {
-                    composite_file = args[i + 1].to_string();
-                }
-                use_composite = true;
-            } else if vec[0].to_lowercase() == "-o"
-                || vec[0].to_lowercase() == "-output"
-                || vec[0].to_lowercase() == "--output"
-            {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-method" || vec[0].to_lowercase() == "--method" {
-                if keyval {
-                    fusion_method = vec[1].to_string();
-                } else {
-                    fusion_method = args[i + 1].to_string();
-                }
-                if fusion_method.to_lowercase().contains("bro") {
-                    fusion_method = String::from("brovey");
-                } else {
-                    fusion_method = String::from("ihs");
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            red_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-g", "--green"])? {
+            green_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-b", "--blue"])? {
+            blue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-p", "--pan"])? {
+            pan_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-c", "--composite"])? {
+            composite_file = v;
+            use_composite = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            fusion_method = v;
+            if fusion_method.to_lowercase().contains("bro") {
+                fusion_method = String::from("brovey");
+            } else {
+                fusion_method = String::from("ihs");
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -301,7 +253,7 @@ impl WhiteboxTool for PanchromaticSharpening {
 
         if use_composite {
             if verbose {
-                println!("Reading multispec image data...")
+                report::message("Reading multispec image data...")
             };
             let input_c = Raster::new(&composite_file, "r")?;
 
@@ -317,15 +269,15 @@ impl WhiteboxTool for PanchromaticSharpening {
             input = input_c.get_data_as_array2d();
         } else {
             if verbose {
-                println!("Reading red band data...")
+                report::message("Reading red band data...")
             };
             let input_r = Raster::new(&red_file, "r")?;
             if verbose {
-                println!("Reading green band data...")
+                report::message("Reading green band data...")
             };
             let input_g = Raster::new(&green_file, "r")?;
             if verbose {
-                println!("Reading blue band data...")
+                report::message("Reading blue band data...")
             };
             let input_b = Raster::new(&blue_file, "r")?;
 
@@ -334,13 +286,13 @@ impl WhiteboxTool for PanchromaticSharpening {
                 || input_r.configs.columns != input_g.configs.columns
             {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                                      "The input files must have the same number of rows and columns and spatial extent."));
+                                      "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
             if input_r.configs.rows != input_b.configs.rows
                 || input_r.configs.columns != input_b.configs.columns
             {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                                      "The input files must have the same number of rows and columns and spatial extent."));
+                                      "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
 
             let nodata_r = input_r.configs.nodata;
@@ -407,7 +359,7 @@ impl WhiteboxTool for PanchromaticSharpening {
         let input = Arc::new(input);
 
         if verbose {
-            println!("Reading pan image data...")
+            report::message("Reading pan image data...")
         };
         let pan = Arc::new(Raster::new(&pan_file, "r")?);
         let rows_pan = pan.configs.rows as isize;
@@ -495,7 +447,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows_pan - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -545,7 +497,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                 if verbose {
                     progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -671,7 +623,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows_pan - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -696,21 +648,18 @@ impl WhiteboxTool for PanchromaticSharpening {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -162,64 +162,32 @@ impl WhiteboxTool for PercentageContrastStretch {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-clip" || vec[0].to_lowercase() == "--clip" {
-                if keyval {
-                    clip = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    clip = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-tail"
-                || vec[0].to_lowercase() == "--tail"
-                || vec[0].to_lowercase() == "--tails"
-            {
-                if keyval {
-                    tail = vec[1].to_string();
-                } else {
-                    tail = args[i + 1].to_string();
-                }
-                if tail.to_lowercase().contains("u") {
-                    tail = String::from("upper");
-                } else if tail.to_lowercase().contains("l") {
-                    tail = String::from("lower");
-                } else {
-                    tail = String::from("both");
-                }
-            } else if vec[0].to_lowercase() == "-num_tones"
-                || vec[0].to_lowercase() == "--num_tones"
-            {
-                if keyval {
-                    num_tones = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    num_tones = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip"])? {
+            clip = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--tail", "--tails"])? {
+            tail = v;
+            if tail.to_lowercase().contains("u") {
+                tail = String::from("upper");
+            } else if tail.to_lowercase().contains("l") {
+                tail = String::from("lower");
+            } else {
+                tail = String::from("both");
             }
         }
+        if let Some(v) = tool_args.get_f64(&["--num_tones"])? {
+            num_tones = v;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -235,7 +203,9 @@ impl WhiteboxTool for PercentageContrastStretch {
         }
 
         if num_tones < 16f64 {
-            println!("Warning: The output number of greytones must be at least 16. The value has been modified.");
+            report::warning(
+                "The output number of greytones must be at least 16. The value has been modified.",
+            );
             num_tones = 16f64;
         }
 
@@ -250,12 +220,14 @@ impl WhiteboxTool for PercentageContrastStretch {
         }
 
         if verbose {
-            println!("Reading input data...")
+            report::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let is_rgb_image = if input.configs.data_type == DataType::RGB24
             || input.configs.data_type == DataType::RGBA32
@@ -276,7 +248,7 @@ impl WhiteboxTool for PercentageContrastStretch {
         let start = Instant::now();
 
         if verbose {
-            println!("Calculating clip values...")
+            report::message("Calculating clip values...")
         };
 
         let (min_val, max_val) = if !is_rgb_image {
@@ -326,7 +298,7 @@ impl WhiteboxTool for PercentageContrastStretch {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Calculating clip values: {}%", progress);
+                        report::progress("Calculating clip values", progress);
                         old_progress = progress;
                     }
                 }
@@ -410,7 +382,7 @@ impl WhiteboxTool for PercentageContrastStretch {
                 let mut z_in: f64;
                 let mut z_out: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z_in = input_fn(row, col);
                         if z_in != nodata {
@@ -429,14 +401,13 @@ impl WhiteboxTool for PercentageContrastStretch {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -454,22 +425,19 @@ impl WhiteboxTool for PercentageContrastStretch {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
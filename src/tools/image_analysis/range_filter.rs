@@ -2,13 +2,13 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 26, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use algorithms::moving_extrema;
 use num_cpus;
 use raster::*;
-use std::collections::VecDeque;
 use std::env;
 use std::f64;
 use std::f64::consts::PI;
@@ -148,53 +148,26 @@ impl WhiteboxTool for RangeFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if vec[0].to_lowercase() == "-filterx" || vec[0].to_lowercase() == "--filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-filtery" || vec[0].to_lowercase() == "--filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -228,7 +201,7 @@ impl WhiteboxTool for RangeFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -248,26 +221,49 @@ impl WhiteboxTool for RangeFilter {
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let mut output = RasterWriter::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+        output.begin_write()?;
+
+        // find the minimum and maximum values within the filter of each cell
+        let (min_vals, max_vals) = {
+            let input_fn: Box<Fn(isize, isize) -> f64> = if !is_rgb_image {
+                Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
+            } else {
+                Box::new(|row: isize, col: isize| -> f64 {
+                    let value = input.get_value(row, col);
+                    if value != nodata {
+                        return value2i(value);
+                    }
+                    nodata
+                })
+            };
+            moving_extrema(
+                rows as usize,
+                columns as usize,
+                midpoint_x as usize,
+                midpoint_y as usize,
+                |row, col| {
+                    let z = input_fn(row as isize, col as isize);
+                    if z != nodata {
+                        Some(z)
+                    } else {
+                        None
+                    }
+                },
+            )
+        };
+        let min_vals = Arc::new(min_vals);
+        let max_vals = Arc::new(max_vals);
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let input = input.clone();
+            let min_vals = min_vals.clone();
+            let max_vals = max_vals.clone();
             let tx1 = tx.clone();
             thread::spawn(move || {
-                let input_fn: Box<Fn(isize, isize) -> f64> = if !is_rgb_image {
-                    Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
-                } else {
-                    Box::new(|row: isize, col: isize| -> f64 {
-                        let value = input.get_value(row, col);
-                        if value != nodata {
-                            return value2i(value);
-                        }
-                        nodata
-                    })
-                };
-
                 let output_fn: Box<Fn(isize, isize, f64) -> f64> = if !is_rgb_image {
                     // simply return the value.
                     Box::new(|_: isize, _: isize, value: f64| -> f64 { value })
@@ -281,76 +277,13 @@ impl WhiteboxTool for RangeFilter {
                         nodata
                     })
                 };
-                let (mut z_n, mut z): (f64, f64);
-                let (mut min_val, mut max_val): (f64, f64);
-                let (mut start_col, mut end_col, mut start_row, mut end_row): (
-                    isize,
-                    isize,
-                    isize,
-                    isize,
-                );
+                let mut i: usize;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut filter_min_vals: VecDeque<f64> = VecDeque::with_capacity(filter_size_x);
-                    let mut filter_max_vals: VecDeque<f64> = VecDeque::with_capacity(filter_size_x);
-                    start_row = row - midpoint_y;
-                    end_row = row + midpoint_y;
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
-                        if col > 0 {
-                            filter_min_vals.pop_front();
-                            filter_max_vals.pop_front();
-                            min_val = f64::INFINITY;
-                            max_val = f64::NEG_INFINITY;
-                            for row2 in start_row..end_row + 1 {
-                                z_n = input_fn(row2, col + midpoint_x);
-                                if z_n != nodata {
-                                    if z_n < min_val {
-                                        min_val = z_n;
-                                    }
-                                    if z_n > max_val {
-                                        max_val = z_n;
-                                    }
-                                }
-                            }
-                            filter_min_vals.push_back(min_val);
-                            filter_max_vals.push_back(max_val);
-                        } else {
-                            // initialize the filter_vals
-                            start_col = col - midpoint_x;
-                            end_col = col + midpoint_x;
-                            for col2 in start_col..end_col + 1 {
-                                min_val = f64::INFINITY;
-                                max_val = f64::NEG_INFINITY;
-                                for row2 in start_row..end_row + 1 {
-                                    z_n = input_fn(row2, col2);
-                                    if z_n != nodata {
-                                        if z_n < min_val {
-                                            min_val = z_n;
-                                        }
-                                        if z_n > max_val {
-                                            max_val = z_n;
-                                        }
-                                    }
-                                }
-                                filter_min_vals.push_back(min_val);
-                                filter_max_vals.push_back(max_val);
-                            }
-                        }
-                        z = input_fn(row, col);
-                        if z != nodata {
-                            min_val = f64::INFINITY;
-                            max_val = f64::NEG_INFINITY;
-                            for i in 0..filter_size_x {
-                                if filter_min_vals[i] < min_val {
-                                    min_val = filter_min_vals[i];
-                                }
-                                if filter_max_vals[i] > max_val {
-                                    max_val = filter_max_vals[i];
-                                }
-                            }
-                            if min_val < f64::INFINITY && max_val > f64::NEG_INFINITY {
-                                data[col as usize] = output_fn(row, col, max_val - min_val);
-                            }
+                        if input.get_value(row, col) != nodata {
+                            i = (row * columns + col) as usize;
+                            data[col as usize] = output_fn(row, col, max_vals[i] - min_vals[i]);
                         }
                     }
                     tx1.send((row, data)).unwrap();
@@ -360,11 +293,11 @@ impl WhiteboxTool for RangeFilter {
 
         for row in 0..rows {
             let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
+            output.write_rows(data.0, data.1)?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -382,19 +315,16 @@ impl WhiteboxTool for RangeFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
-        let _ = match output.write() {
+        let _ = match output.finalize() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,453 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{rank_filter, RankStatistic, NO_BIN};
+use num_cpus;
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use tools::*;
+
+/// This tool performs a median, percentile, or majority filter (`--stat`) on an input
+/// raster (`--input`) using the constant-time filtering algorithm of Perreault and Hébert
+/// (2007), for which the time taken per cell does not depend on the size of the filter
+/// (`--filterx` and `--filtery`). Unlike `MedianFilter`, `MajorityFilter`, and the
+/// `FocalStatistics` tool, whose running times increase with the filter size, the tool
+/// therefore remains fast for very large kernels, e.g. 101 x 101 cells or more.
+///
+/// The algorithm works with histograms of the raster's values. For the `median` and
+/// `percentile` statistics (the percentile is specified by `--percentile`), the range of
+/// the input values is divided into a number of equal bins (`--num_bins`), and each
+/// output cell is assigned the centre of the bin containing the percentile. The number of
+/// bins therefore determines the precision of the output, i.e. the data range divided by
+/// the number of bins, and affects the memory requirements and running time. For the
+/// `majority` statistic, which is intended for categorical data, each distinct value of
+/// the input has its own bin and the output values are exact. When there are several
+/// majority values, the value of the centre cell is retained if it is one of them, and
+/// otherwise the smallest of them is used.
+///
+/// NoData values within the filter are ignored and NoData cells remain NoData in the
+/// output.
+///
+/// # Reference
+/// Perreault, S., and Hébert, P. (2007). Median filtering in constant time. IEEE
+/// Transactions on Image Processing, 16(9), 2389-2394.
+///
+/// # See Also
+/// `MedianFilter`, `PercentileFilter`, `MajorityFilter`, `FocalStatistics`
+pub struct RankFilter {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RankFilter {
+    pub fn new() -> RankFilter {
+        // public constructor
+        let name = "RankFilter".to_string();
+        let toolbox = "Image Processing Tools/Filters".to_string();
+        let description =
+            "Performs a constant-time median, percentile, or majority filter, suitable for very large kernels."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Statistic".to_owned(),
+            flags: vec!["--stat".to_owned()],
+            description: "Filter statistic; options are 'median', 'percentile', and 'majority'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "median".to_owned(),
+                "percentile".to_owned(),
+                "majority".to_owned(),
+            ]),
+            default_value: Some("median".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Percentile".to_owned(),
+            flags: vec!["--percentile".to_owned()],
+            description: "Percentile (0-100) calculated by the 'percentile' statistic.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("50.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter X-Dimension".to_owned(),
+            flags: vec!["--filterx".to_owned()],
+            description: "Size of the filter kernel in the x-direction.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("101".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Y-Dimension".to_owned(),
+            flags: vec!["--filtery".to_owned()],
+            description: "Size of the filter kernel in the y-direction.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("101".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Bins".to_owned(),
+            flags: vec!["--num_bins".to_owned()],
+            description: "Number of histogram bins for the 'median' and 'percentile' statistics."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1024".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --stat=median --filter=101 --num_bins=2048
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=classes.tif -o=output.tif --stat=majority --filterx=51 --filtery=51",
+            short_exe, name
+        ).replace("*", &sep);
+
+        RankFilter {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RankFilter {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut stat_name = "median".to_string();
+        let mut percentile = 50f64;
+        let mut filter_size_x = 101usize;
+        let mut filter_size_y = 101usize;
+        let mut num_bins = 1024usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--stat"])? {
+            stat_name = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--percentile"])? {
+            percentile = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_bins"])? {
+            num_bins = v.max(1);
+        }
+
+        let majority = stat_name.starts_with("maj") || stat_name == "mode";
+        let statistic = if majority {
+            RankStatistic::Mode
+        } else if stat_name == "median" {
+            RankStatistic::Percentile(50f64)
+        } else if stat_name == "percentile" {
+            if percentile < 0f64 || percentile > 100f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The percentile must be between 0 and 100.",
+                ));
+            }
+            RankStatistic::Percentile(percentile)
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized statistic '{}'.", stat_name),
+            ));
+        };
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        // The filter dimensions must be odd numbers such that there is a middle pixel
+        if filter_size_x < 3 {
+            filter_size_x = 3;
+        }
+        if filter_size_y < 3 {
+            filter_size_y = 3;
+        }
+        if filter_size_x % 2 == 0 {
+            filter_size_x += 1;
+        }
+        if filter_size_y % 2 == 0 {
+            filter_size_y += 1;
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows;
+        let columns = input.configs.columns;
+        let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
+
+        // bin the data; bin_values holds the output value of each bin
+        let mut bins = vec![NO_BIN; rows * columns];
+        let mut bin_values: Vec<f64> = vec![];
+        let mut z: f64;
+        if majority {
+            let mut classes: HashMap<u64, u32> = HashMap::new();
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = input.get_value(row as isize, col as isize);
+                    if z != nodata {
+                        let n = bin_values.len() as u32;
+                        let bin = *classes.entry(z.to_bits()).or_insert(n);
+                        if bin == n {
+                            bin_values.push(z);
+                            if bin_values.len() > 65536 {
+                                return Err(Error::new(
+                                    ErrorKind::InvalidInput,
+                                    "The input contains too many distinct values for the majority statistic; it should be a categorical raster.",
+                                ));
+                            }
+                        }
+                        bins[row * columns + col] = bin;
+                    }
+                }
+            }
+            // the bins are ordered by value, so that ties are resolved by value
+            let mut order: Vec<u32> = (0..bin_values.len() as u32).collect();
+            order.sort_by(|&a, &b| {
+                bin_values[a as usize]
+                    .partial_cmp(&bin_values[b as usize])
+                    .unwrap()
+            });
+            let mut new_bin = vec![0u32; order.len()];
+            for (i, &b) in order.iter().enumerate() {
+                new_bin[b as usize] = i as u32;
+            }
+            for bin in bins.iter_mut() {
+                if *bin != NO_BIN {
+                    *bin = new_bin[*bin as usize];
+                }
+            }
+            bin_values = order.iter().map(|&b| bin_values[b as usize]).collect();
+        } else {
+            let min_val = input.configs.minimum;
+            let max_val = input.configs.maximum;
+            if max_val <= min_val {
+                num_bins = 1;
+            }
+            let bin_width = if max_val > min_val {
+                (max_val - min_val) / num_bins as f64
+            } else {
+                1f64
+            };
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = input.get_value(row as isize, col as isize);
+                    if z != nodata {
+                        bins[row * columns + col] =
+                            (((z - min_val) / bin_width).floor() as u32).min(num_bins as u32 - 1);
+                    }
+                }
+            }
+            bin_values = (0..num_bins)
+                .map(|b| {
+                    if max_val > min_val {
+                        min_val + (b as f64 + 0.5) * bin_width
+                    } else {
+                        min_val
+                    }
+                }).collect();
+        }
+        let num_bins = bin_values.len().max(1);
+
+        // each thread filters a strip of contiguous rows
+        let bins = Arc::new(bins);
+        let bin_values = Arc::new(bin_values);
+        let num_procs = num_cpus::get();
+        let strip_size = (rows + num_procs - 1) / num_procs;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let bins = bins.clone();
+            let bin_values = bin_values.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let row_range = (tid * strip_size).min(rows)..((tid + 1) * strip_size).min(rows);
+                rank_filter(
+                    &bins,
+                    rows,
+                    columns,
+                    filter_size_x / 2,
+                    filter_size_y / 2,
+                    num_bins,
+                    row_range,
+                    statistic,
+                    |row, data| {
+                        let data: Vec<f64> = data
+                            .iter()
+                            .map(|&b| if b != NO_BIN { bin_values[b as usize] } else { out_nodata })
+                            .collect();
+                        tx.send((row, data)).unwrap();
+                    },
+                );
+            });
+        }
+
+        if !majority {
+            output.configs.data_type = DataType::F32;
+        }
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for r in 0..rows {
+            let (row, data) = rx.recv().unwrap();
+            output.set_row_data(row as isize, data);
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Statistic: {}", stat_name));
+        if stat_name == "percentile" {
+            output.add_metadata_entry(format!("Percentile: {}", percentile));
+        }
+        output.add_metadata_entry(format!("Filter size x: {}", filter_size_x));
+        output.add_metadata_entry(format!("Filter size y: {}", filter_size_y));
+        if !majority {
+            output.add_metadata_entry(format!("Number of bins: {}", num_bins));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
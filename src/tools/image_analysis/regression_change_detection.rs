@@ -0,0 +1,359 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool performs regression-based change detection on two co-registered images of the
+/// same area acquired at different times. An ordinary least-squares (OLS) regression of the
+/// later image (`--input2`) on the earlier image (`--input1`) is fitted using all cells that
+/// are valid in both images:
+///
+/// > t<sub>2</sub> = a + b t<sub>1</sub>
+///
+/// The regression models the expected, no-change relation between the two dates,
+/// accounting for differences in illumination, atmospheric conditions, and sensor
+/// calibration. The residuals (observed minus predicted later values) therefore measure
+/// change, with large positive or negative residuals indicating areas that changed
+/// differently from the scene as a whole.
+///
+/// The tool outputs the residuals (`--out_residuals`) and/or the standardized residuals
+/// (`--out_standardized`), i.e. the residuals divided by the standard error of the
+/// regression. The standardized residuals can be thresholded directly, e.g. cells with
+/// absolute values greater than 2 or 3 are commonly considered to have changed. At least one
+/// of the outputs must be specified. The regression coefficients and the coefficient of
+/// determination (r<sup>2</sup>) are reported and stored in the output metadata. The
+/// inputs must have the same numbers of rows and columns.
+///
+/// # See Also
+/// `RegressionChangeDetection`, `ImageRatio`, `ChangeVectorAnalysis`, `ImageRegression`
+pub struct RegressionChangeDetection {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RegressionChangeDetection {
+    pub fn new() -> RegressionChangeDetection {
+        // public constructor
+        let name = "RegressionChangeDetection".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description =
+            "Performs regression-based change detection, outputting residuals of the later image."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Earlier Date Input File".to_owned(),
+            flags: vec!["--i1".to_owned(), "--input1".to_owned()],
+            description: "Input raster file for the earlier date.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Later Date Input File".to_owned(),
+            flags: vec!["--i2".to_owned(), "--input2".to_owned()],
+            description: "Input raster file for the later date.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Residuals File".to_owned(),
+            flags: vec!["--out_residuals".to_owned()],
+            description: "Output regression residuals raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Standardized Residuals File".to_owned(),
+            flags: vec!["--out_standardized".to_owned()],
+            description: "Output standardized regression residuals raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --i1=nir_2015.tif --i2=nir_2020.tif --out_residuals=residuals.tif --out_standardized=std_residuals.tif", short_exe, name).replace("*", &sep);
+
+        RegressionChangeDetection {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RegressionChangeDetection {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input1_file = String::new();
+        let mut input2_file = String::new();
+        let mut residuals_file = String::new();
+        let mut standardized_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_residuals"])? {
+            residuals_file = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["--out_standardized"])? {
+            standardized_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        if residuals_file.is_empty() && standardized_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one of the --out_residuals and --out_standardized outputs must be specified.",
+            ));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input1_file.contains(&sep) && !input1_file.contains("/") {
+            input1_file = format!("{}{}", working_directory, input1_file);
+        }
+        if !input2_file.contains(&sep) && !input2_file.contains("/") {
+            input2_file = format!("{}{}", working_directory, input2_file);
+        }
+        if !residuals_file.is_empty()
+            && !residuals_file.contains(&sep)
+            && !residuals_file.contains("/")
+        {
+            residuals_file = format!("{}{}", working_directory, residuals_file);
+        }
+        if !standardized_file.is_empty()
+            && !standardized_file.contains(&sep)
+            && !standardized_file.contains("/")
+        {
+            standardized_file = format!("{}{}", working_directory, standardized_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input1 = Raster::new(&input1_file, "r")?;
+        let input2 = Raster::new(&input2_file, "r")?;
+        let rows = input1.configs.rows as isize;
+        if input2.configs.rows != input1.configs.rows
+            || input2.configs.columns != input1.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent.",
+            ));
+        }
+        let nodata1 = input1.configs.nodata;
+        let nodata2 = input2.configs.nodata;
+
+        let start = Instant::now();
+
+        // fit the regression of the later image on the earlier image
+        let (mut n, mut sum_x, mut sum_y) = (0f64, 0f64, 0f64);
+        let (mut sum_xx, mut sum_xy, mut sum_yy) = (0f64, 0f64, 0f64);
+        for row in 0..rows {
+            let data1 = input1.get_row_data(row);
+            let data2 = input2.get_row_data(row);
+            for (&x, &y) in data1.iter().zip(data2.iter()) {
+                if x != nodata1 && y != nodata2 {
+                    n += 1f64;
+                    sum_x += x;
+                    sum_y += y;
+                    sum_xx += x * x;
+                    sum_xy += x * y;
+                    sum_yy += y * y;
+                }
+            }
+        }
+        let sxx = sum_xx - sum_x * sum_x / n;
+        let sxy = sum_xy - sum_x * sum_y / n;
+        let syy = sum_yy - sum_y * sum_y / n;
+        if n < 3f64 || sxx <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The regression cannot be fitted; the earlier image must have at least three valid cells that are not all equal.",
+            ));
+        }
+        let slope = sxy / sxx;
+        let intercept = (sum_y - slope * sum_x) / n;
+        let sse = (syy - slope * sxy).max(0f64);
+        let r_squared = if syy > 0f64 { 1f64 - sse / syy } else { 1f64 };
+        let std_error = (sse / (n - 2f64)).sqrt();
+        if verbose {
+            report::message(&format!(
+                "Regression: t2 = {:.6} + {:.6} t1 (r-squared = {:.4}, standard error = {:.6}, n = {})",
+                intercept, slope, r_squared, std_error, n
+            ));
+        }
+        if !standardized_file.is_empty() && std_error == 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The regression fits the data perfectly, so the residuals cannot be standardized.",
+            ));
+        }
+
+        let nodata = -32768f64;
+        // each output is paired with whether it holds standardized residuals
+        let mut outputs: Vec<(Raster, bool)> = vec![];
+        if !residuals_file.is_empty() {
+            outputs.push((Raster::initialize_using_file(&residuals_file, &input1), false));
+        }
+        if !standardized_file.is_empty() {
+            outputs.push((Raster::initialize_using_file(&standardized_file, &input1), true));
+        }
+        for &mut (ref mut output, _) in outputs.iter_mut() {
+            output.configs.data_type = DataType::F32;
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.configs.nodata = nodata;
+            output.configs.color_map = vec![];
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data1 = input1.get_row_data(row);
+            let data2 = input2.get_row_data(row);
+            let residuals: Vec<f64> = data1
+                .iter()
+                .zip(data2.iter())
+                .map(|(&x, &y)| {
+                    if x != nodata1 && y != nodata2 {
+                        y - (intercept + slope * x)
+                    } else {
+                        f64::NAN
+                    }
+                })
+                .collect();
+            for &mut (ref mut output, standardized) in outputs.iter_mut() {
+                let divisor = if standardized { std_error } else { 1f64 };
+                let data: Vec<f64> = residuals
+                    .iter()
+                    .map(|&r| if r.is_nan() { nodata } else { r / divisor })
+                    .collect();
+                output.set_row_data(row, data);
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        for (mut output, standardized) in outputs {
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Earlier date input file: {}", input1_file));
+            output.add_metadata_entry(format!("Later date input file: {}", input2_file));
+            output.add_metadata_entry(format!(
+                "Regression: t2 = {} + {} t1 (r-squared = {})",
+                intercept, slope, r_squared
+            ));
+            if standardized {
+                output.add_metadata_entry(format!("Standard error: {}", std_error));
+            }
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
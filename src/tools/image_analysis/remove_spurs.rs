@@ -140,45 +140,19 @@ impl WhiteboxTool for RemoveSpurs {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-iterations"
-                || vec[0].to_lowercase() == "--iterations"
-            {
-                if keyval {
-                    max_iterations = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    max_iterations = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--iterations"])? {
+            max_iterations = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -194,13 +168,15 @@ impl WhiteboxTool for RemoveSpurs {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let start = Instant::now();
 
@@ -211,7 +187,7 @@ impl WhiteboxTool for RemoveSpurs {
             let tx = tx.clone();
             thread::spawn(move || {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         if input[(row, col)] > 0.0 && input[(row, col)] != nodata {
                             data[col as usize] = 1.0;
@@ -224,7 +200,6 @@ impl WhiteboxTool for RemoveSpurs {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
@@ -232,7 +207,7 @@ impl WhiteboxTool for RemoveSpurs {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
+                    report::progress("Initializing output", progress);
                     old_progress = progress;
                 }
             }
@@ -263,7 +238,7 @@ impl WhiteboxTool for RemoveSpurs {
                     for row in 0..rows {
                         for col in 0..columns {
                             z = output[(row, col)];
-                            if z > 0.0 && z != nodata {
+                            if z > 0.0 && z != out_nodata {
                                 // fill the neighbours array
                                 for i in 0..8 {
                                     neighbours[i] = output[(row + dy[i], col + dx[i])];
@@ -286,7 +261,7 @@ impl WhiteboxTool for RemoveSpurs {
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as usize;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num + 1, progress);
+                            report::progress(&format!("Loop Number {}", loop_num + 1), progress);
                             old_progress = progress;
                         }
                     }
@@ -296,7 +271,7 @@ impl WhiteboxTool for RemoveSpurs {
                     for row in (0..rows).rev() {
                         for col in (0..columns).rev() {
                             z = output[(row, col)];
-                            if z > 0.0 && z != nodata {
+                            if z > 0.0 && z != out_nodata {
                                 // fill the neighbours array
                                 for i in 0..8 {
                                     neighbours[i] = output[(row + dy[i], col + dx[i])];
@@ -319,7 +294,7 @@ impl WhiteboxTool for RemoveSpurs {
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as usize;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num + 1, progress);
+                            report::progress(&format!("Loop Number {}", loop_num + 1), progress);
                             old_progress = progress;
                         }
                     }
@@ -340,19 +315,16 @@ impl WhiteboxTool for RemoveSpurs {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -1,8 +1,8 @@
-/* 
+/*
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: January 1 2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -17,17 +17,34 @@ use std::sync::Arc;
 use std::thread;
 use tools::*;
 
-/// Resample is very similar in operation to the Mosaic tool. The Resample tool should
-/// be used when there is an existing image into which you would like to dump information
-/// from one or more source images. If the source images are more extensive than the
-/// destination image, i.e. there are areas that extend beyond the destination image
-/// boundaries, these areas will not be represented in the updated image. Grid cells in the
-/// destination image that are not overlapping with any of the input source images will not
-/// be updated, i.e. they will possess the same value as before the resampling operation. The
-/// Mosaic tool is used when there is no existing destination image. In this case, a new
-/// image is created that represents the bounding rectangle of each of the two or more input
-/// images. Grid cells in the output image that do not overlap with any of the input images
-/// will be assigned the NoData value.
+/// This tool resamples one or more input images (`--inputs`) onto a new grid, using
+/// either nearest neighbour (`nn`), bilinear interpolation (`bilinear`), or cubic
+/// convolution (`cc`) (`--method`). Nearest neighbour resampling should be used for
+/// categorical data (e.g. land-cover classes), while bilinear interpolation and cubic
+/// convolution are suited to continuous data (e.g. elevation).
+///
+/// The grid of the output image (`--output`) is determined in one of two ways:
+///
+/// - With a base raster (`--base`), the output image has exactly the same grid as the
+///   base image, i.e. the same extent, number of rows and columns, and cell size. This
+///   is the most convenient way of aligning images from different sources so that they
+///   can be used together in tools that require their inputs to share the same grid
+///   (e.g. overlay and mathematical tools).
+/// - Otherwise, a grid cell size (`--cell_size`) must be specified, and the output image
+///   covers the combined extent of the input images.
+///
+/// Alternatively, an existing destination image may be updated (`--destination`) in
+/// place of creating a new output image. In this case, grid cells in the destination
+/// image that do not overlap with any of the input images are not updated, i.e. they
+/// retain the same value as before the resampling operation.
+///
+/// Where the input images overlap, the value of an output grid cell is taken from the
+/// first input image (in the order listed) with a valid value at the cell. Where the
+/// bilinear or cubic convolution neighbourhood of a grid cell includes NoData values or
+/// extends beyond the edge of an input image, the next simplest method is used instead.
+///
+/// # See Also
+/// `Mosaic`, `AggregateRaster`
 pub struct Resample {
     name: String,
     description: String,
@@ -42,7 +59,8 @@ impl Resample {
         let name = "Resample".to_string();
         let toolbox = "Image Processing Tools".to_string();
         let description =
-            "Resamples one or more input images into a destination image.".to_string();
+            "Resamples one or more input images to a new cell size or to the grid of a base image."
+                .to_string();
 
         let mut parameters = vec![];
         parameters.push(ToolParameter {
@@ -55,17 +73,44 @@ impl Resample {
         });
 
         parameters.push(ToolParameter {
-            name: "Destination File".to_owned(),
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cell Size (optional)".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Optionally specified cell size of the output raster. Not used when a base raster is specified.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Base Raster File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Optionally specified input base raster file; the output raster has the same grid (extent, rows, columns and cell size).".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Destination File (optional)".to_owned(),
             flags: vec!["--destination".to_owned()],
-            description: "Destination raster file.".to_owned(),
+            description: "Optionally specified existing destination raster file, which is updated in place of creating an output raster.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
-            optional: false,
+            optional: true,
         });
 
         parameters.push(ToolParameter{
-            name: "Resampling Method".to_owned(), 
-            flags: vec!["--method".to_owned()], 
+            name: "Resampling Method".to_owned(),
+            flags: vec!["--method".to_owned()],
             description: "Resampling method; options include 'nn' (nearest neighbour), 'bilinear', and 'cc' (cubic convolution)".to_owned(),
             parameter_type: ParameterType::OptionList(vec!["nn".to_owned(), "bilinear".to_owned(), "cc".to_owned()]),
             default_value: Some("cc".to_owned()),
@@ -83,7 +128,8 @@ impl Resample {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{} -r={} -v --wd='*path*to*data*' -i='image1.tif;image2.tif;image3.tif' --destination=dest.tif --method='cc", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd='*path*to*data*' -i='image1.tif;image2.tif' -o=output.tif --base=dem.tif --method='cc'
+>>.*{0} -r={1} -v --wd='*path*to*data*' -i=landcover.tif -o=output.tif --cell_size=30.0 --method='nn'", short_exe, name).replace("*", &sep);
 
         Resample {
             name: name,
@@ -130,6 +176,9 @@ impl WhiteboxTool for Resample {
         verbose: bool,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
+        let mut output_file = String::new();
+        let mut cell_size = 0f64;
+        let mut base_file = String::new();
         let mut destination_file = String::new();
         let mut method = String::from("cc");
 
@@ -139,53 +188,40 @@ impl WhiteboxTool for Resample {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-destination" {
-                destination_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-method" {
-                method = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                if method.to_lowercase().contains("nn") || method.to_lowercase().contains("nearest")
-                {
-                    method = "nn".to_string();
-                } else if method.to_lowercase().contains("bilinear")
-                    || method.to_lowercase().contains("bi")
-                {
-                    method = "bilinear".to_string();
-                } else if method.to_lowercase().contains("cc")
-                    || method.to_lowercase().contains("cubic")
-                {
-                    method = "cc".to_string();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            cell_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--destination"])? {
+            destination_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v;
+            if method.to_lowercase().contains("nn") || method.to_lowercase().contains("nearest")
+            {
+                method = "nn".to_string();
+            } else if method.to_lowercase().contains("bilinear")
+                || method.to_lowercase().contains("bi")
+            {
+                method = "bilinear".to_string();
+            } else if method.to_lowercase().contains("cc")
+                || method.to_lowercase().contains("cubic")
+            {
+                method = "cc".to_string();
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -193,14 +229,42 @@ impl WhiteboxTool for Resample {
         let mut progress: usize;
         let mut old_progress: usize = 1;
 
-        if !destination_file.contains(&sep) && !destination_file.contains("/") {
+        if output_file.is_empty() && destination_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either an output file (--output) or an existing destination file (--destination) must be specified.",
+            ));
+        }
+        if !output_file.is_empty() && !destination_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Only one of an output file (--output) and a destination file (--destination) may be specified.",
+            ));
+        }
+        if !output_file.is_empty() && base_file.is_empty() && cell_size <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a base raster (--base) or a cell size (--cell_size) greater than zero must be specified.",
+            ));
+        }
+
+        if !output_file.is_empty() && !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !base_file.is_empty() && !base_file.contains(&sep) && !base_file.contains("/") {
+            base_file = format!("{}{}", working_directory, base_file);
+        }
+        if !destination_file.is_empty()
+            && !destination_file.contains(&sep)
+            && !destination_file.contains("/")
+        {
             destination_file = format!("{}{}", working_directory, destination_file);
         }
 
         // see if the destination file exists.
-        if !path::Path::new(&destination_file).exists() {
+        if !destination_file.is_empty() && !path::Path::new(&destination_file).exists() {
             return Err(Error::new(ErrorKind::InvalidInput,
-                "The destination raster file does not exist. If you want to create a new file, try the Mosaic tool rather than Resample."));
+                "The destination raster file does not exist. If you want to create a new file, use the --output parameter rather than --destination."));
         }
 
         let mut cmd = input_files.split(";");
@@ -215,17 +279,9 @@ impl WhiteboxTool for Resample {
                 "There is something incorrect about the input files. At least one input is required to operate this tool."));
         }
 
-        let start = Instant::now();
-
-        // Open the destination raster.
-        let mut destination = Raster::new(&destination_file, "rw")?;
-        let rows = destination.configs.rows as isize;
-        let columns = destination.configs.columns as isize;
-        let nodata = destination.configs.nodata;
-
         // read the input files
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
         let mut nodata_vals: Vec<f64> = Vec::with_capacity(num_files);
@@ -244,6 +300,69 @@ impl WhiteboxTool for Resample {
             }
         }
 
+        let start = Instant::now();
+
+        // Open the destination raster, or create the output raster.
+        let mut destination = if !destination_file.is_empty() {
+            Raster::new(&destination_file, "rw")?
+        } else if !base_file.is_empty() {
+            let base = Raster::new(&base_file, "r")?;
+            let mut output = Raster::initialize_using_file(&output_file, &base);
+            output.configs.data_type = inputs[0].configs.data_type;
+            output.configs.photometric_interp = inputs[0].configs.photometric_interp;
+            output.configs.palette = inputs[0].configs.palette.clone();
+            output.configs.z_units = inputs[0].configs.z_units.clone();
+            output.set_nodata_value(nodata_vals[0]);
+            output.reinitialize_values(nodata_vals[0]);
+            output
+        } else {
+            // the output covers the combined extent of the inputs
+            let mut north = f64::NEG_INFINITY;
+            let mut south = f64::INFINITY;
+            let mut east = f64::NEG_INFINITY;
+            let mut west = f64::INFINITY;
+            for i in 0..num_files {
+                north = north.max(inputs[i].configs.north);
+                south = south.min(inputs[i].configs.south);
+                east = east.max(inputs[i].configs.east);
+                west = west.min(inputs[i].configs.west);
+            }
+            let rows = ((north - south) / cell_size).ceil() as usize;
+            let columns = ((east - west) / cell_size).ceil() as usize;
+
+            let mut configs = RasterConfigs {
+                ..Default::default()
+            };
+            configs.rows = rows;
+            configs.columns = columns;
+            configs.north = north;
+            configs.south = north - rows as f64 * cell_size;
+            configs.east = west + columns as f64 * cell_size;
+            configs.west = west;
+            configs.resolution_x = cell_size;
+            configs.resolution_y = cell_size;
+            configs.nodata = nodata_vals[0];
+            configs.data_type = inputs[0].configs.data_type;
+            configs.photometric_interp = inputs[0].configs.photometric_interp;
+            configs.palette = inputs[0].configs.palette.clone();
+            configs.projection = inputs[0].configs.projection.clone();
+            configs.xy_units = inputs[0].configs.xy_units.clone();
+            configs.z_units = inputs[0].configs.z_units.clone();
+            configs.epsg_code = inputs[0].configs.epsg_code;
+            configs.coordinate_ref_system_wkt =
+                inputs[0].configs.coordinate_ref_system_wkt.clone();
+            configs.pixel_is_area = inputs[0].configs.pixel_is_area;
+            Raster::initialize_using_config(&output_file, &configs)
+        };
+        let rows = destination.configs.rows as isize;
+        let columns = destination.configs.columns as isize;
+        let nodata = destination.configs.nodata;
+
+        if method != "nn" {
+            destination.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            destination.configs.data_type = DataType::F32;
+        }
+
         // create the x and y arrays
         let mut x: Vec<f64> = Vec::with_capacity(columns as usize);
         for col in 0..columns {
@@ -261,242 +380,135 @@ impl WhiteboxTool for Resample {
         let nodata_vals = Arc::new(nodata_vals);
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
-        if method == "nn" {
-            for tid in 0..num_procs {
-                let inputs = inputs.clone();
-                let nodata_vals = nodata_vals.clone();
-                let x = x.clone();
-                let y = y.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let (mut col_src, mut row_src): (isize, isize);
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
-                        for col in 0..columns {
-                            for i in 0..num_files {
-                                row_src = inputs[i].get_row_from_y(y[row as usize]);
-                                col_src = inputs[i].get_column_from_x(x[col as usize]);
-                                // row_src = ((inputs[i].configs.north - y[row as usize]) / inputs[i].configs.resolution_y).round() as isize;
-                                // col_src = ((x[col as usize] - inputs[i].configs.west) / inputs[i].configs.resolution_x).round() as isize;
-                                z = inputs[i].get_value(row_src, col_src);
-                                if z != nodata_vals[i] {
-                                    data[col as usize] = z;
-                                    break;
-                                }
+        for tid in 0..num_procs {
+            let inputs = inputs.clone();
+            let nodata_vals = nodata_vals.clone();
+            let x = x.clone();
+            let y = y.clone();
+            let method = method.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut z: f64;
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![nodata; columns as usize];
+                    for col in 0..columns {
+                        for i in 0..num_files {
+                            z = sample_value(&inputs[i], x[col as usize], y[row as usize], &method);
+                            if z != nodata_vals[i] {
+                                data[col as usize] = z;
+                                break;
                             }
                         }
-                        tx.send((row, data)).unwrap();
-                    }
-                });
-            }
-            for r in 0..rows {
-                let (row, data) = rx.recv().unwrap();
-                for col in 0..columns {
-                    if data[col as usize] != nodata {
-                        destination.set_value(row, col, data[col as usize]);
                     }
+                    tx.send((row, data)).unwrap();
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+            });
+        }
+        for r in 0..rows {
+            let (row, data) = rx.recv().unwrap();
+            for col in 0..columns as usize {
+                if data[col] != nodata {
+                    destination.set_value(row, col as isize, data[col]);
                 }
             }
-        } else if method == "cc" {
-            destination.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            destination.configs.data_type = DataType::F32;
-
-            for tid in 0..num_procs {
-                let inputs = inputs.clone();
-                let nodata_vals = nodata_vals.clone();
-                let x = x.clone();
-                let y = y.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let shift_x = [-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2];
-                    let shift_y = [-1, -1, -1, -1, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2];
-                    let num_neighbours = 16;
-                    let mut neighbour = [[0f64; 2]; 16];
-                    let (mut col_src, mut row_src): (f64, f64);
-                    let (mut col_n, mut row_n): (isize, isize);
-                    let (mut origin_row, mut origin_col): (isize, isize);
-                    let (mut dx, mut dy): (f64, f64);
-                    let mut sum_dist: f64;
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
-                        for col in 0..columns {
-                            let mut flag = true;
-                            for i in 0..num_files {
-                                if !flag {
-                                    break;
-                                }
-                                // row_src = inputs[i].get_row_from_y(y[row as usize]);
-                                // col_src = inputs[i].get_column_from_x(x[col as usize]);
-                                row_src = (inputs[i].configs.north - y[row as usize])
-                                    / inputs[i].configs.resolution_y;
-                                col_src = (x[col as usize] - inputs[i].configs.west)
-                                    / inputs[i].configs.resolution_x;
-                                origin_row = row_src.floor() as isize;
-                                origin_col = col_src.floor() as isize;
-                                sum_dist = 0f64;
-                                for n in 0..num_neighbours {
-                                    row_n = origin_row + shift_y[n];
-                                    col_n = origin_col + shift_x[n];
-                                    neighbour[n][0] = inputs[i].get_value(row_n, col_n);;
-                                    dy = row_n as f64 - row_src;
-                                    dx = col_n as f64 - col_src;
-
-                                    if (dx + dy) != 0f64 && neighbour[n][0] != nodata_vals[i] {
-                                        neighbour[n][1] = 1f64 / (dx * dx + dy * dy);
-                                        sum_dist += neighbour[n][1];
-                                    } else if neighbour[n][0] == nodata_vals[i] {
-                                        neighbour[n][1] = 0f64;
-                                    } else {
-                                        data[col as usize] = neighbour[n][0];
-                                        flag = false;
-                                    }
-                                }
-
-                                if sum_dist > 0f64 {
-                                    z = 0f64;
-                                    for n in 0..num_neighbours {
-                                        z += (neighbour[n][0] * neighbour[n][1]) / sum_dist;
-                                    }
-                                    data[col as usize] = z;
-                                    flag = false;
-                                }
-                            }
-                        }
-                        tx.send((row, data)).unwrap();
-                    }
-                });
-            }
-            for r in 0..rows {
-                let (row, data) = rx.recv().unwrap();
-                for col in 0..columns as usize {
-                    if data[col] != nodata {
-                        destination.set_value(row, col as isize, data[col]);
-                    }
-                }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
-            }
-        } else {
-            // bilinear
-            destination.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            destination.configs.data_type = DataType::F32;
-            for tid in 0..num_procs {
-                let inputs = inputs.clone();
-                let nodata_vals = nodata_vals.clone();
-                let x = x.clone();
-                let y = y.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let shift_x = [0, 1, 0, 1];
-                    let shift_y = [0, 0, 1, 1];
-                    let num_neighbours = 4;
-                    let mut neighbour = [[0f64; 2]; 4];
-                    let (mut col_src, mut row_src): (f64, f64);
-                    let (mut col_n, mut row_n): (isize, isize);
-                    let (mut origin_col, mut origin_row): (isize, isize);
-                    let (mut dx, mut dy): (f64, f64);
-                    let mut sum_dist: f64;
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
-                        for col in 0..columns {
-                            let mut flag = true;
-                            for i in 0..num_files {
-                                if !flag {
-                                    break;
-                                }
-                                row_src = (inputs[i].configs.north - y[row as usize])
-                                    / inputs[i].configs.resolution_y;
-                                col_src = (x[col as usize] - inputs[i].configs.west)
-                                    / inputs[i].configs.resolution_x;
-                                origin_row = row_src.floor() as isize;
-                                origin_col = col_src.floor() as isize;
-                                sum_dist = 0f64;
-                                for n in 0..num_neighbours {
-                                    row_n = origin_row + shift_y[n];
-                                    col_n = origin_col + shift_x[n];
-                                    neighbour[n][0] = inputs[i].get_value(row_n, col_n);;
-                                    dy = row_n as f64 - row_src;
-                                    dx = col_n as f64 - col_src;
-
-                                    if (dx + dy) != 0f64 && neighbour[n][0] != nodata_vals[i] {
-                                        neighbour[n][1] = 1f64 / (dx * dx + dy * dy);
-                                        sum_dist += neighbour[n][1];
-                                    } else if neighbour[n][0] == nodata_vals[i] {
-                                        neighbour[n][1] = 0f64;
-                                    } else {
-                                        data[col as usize] = neighbour[n][0];
-                                        flag = false;
-                                    }
-                                }
-
-                                if sum_dist > 0f64 {
-                                    z = 0f64;
-                                    for n in 0..num_neighbours {
-                                        z += (neighbour[n][0] * neighbour[n][1]) / sum_dist;
-                                    }
-                                    data[col as usize] = z;
-                                    flag = false;
-                                }
-                            }
-                        }
-                        tx.send((row, data)).unwrap();
-                    }
-                });
-            }
-            for r in 0..rows {
-                let (row, data) = rx.recv().unwrap();
-                for col in 0..columns as usize {
-                    if data[col] != nodata {
-                        destination.set_value(row, col as isize, data[col]);
-                    }
-                }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
                 }
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
-        destination.add_metadata_entry(format!(
-            "Modified by whitebox_tools\' {} tool",
-            self.get_tool_name()
-        ));
+        if destination_file.is_empty() {
+            destination.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+        } else {
+            destination.add_metadata_entry(format!(
+                "Modified by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+        }
+        destination.add_metadata_entry(format!("Resampling method: {}", method));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match destination.write() {
             Ok(_) => if verbose {
-                println!("Destination file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// Returns the value of `input` at (`x`, `y`) using the specified resampling method,
+/// or the NoData value of `input` if there is none. Bilinear interpolation falls back
+/// to the nearest neighbour, and cubic convolution to bilinear interpolation, when any
+/// of the neighbouring cells are NoData or beyond the edge of the grid.
+pub fn sample_value(input: &Raster, x: f64, y: f64, method: &str) -> f64 {
+    let nearest = || input.get_value(input.get_row_from_y(y), input.get_column_from_x(x));
+    if method == "nn" {
+        return nearest();
+    }
+
+    // the position relative to the centres of the grid cells
+    let row_src = (input.configs.north - y) / input.configs.resolution_y - 0.5;
+    let col_src = (x - input.configs.west) / input.configs.resolution_x - 0.5;
+    let origin_row = row_src.floor();
+    let origin_col = col_src.floor();
+    let dy = row_src - origin_row;
+    let dx = col_src - origin_col;
+    let (origin_row, origin_col) = (origin_row as isize, origin_col as isize);
+    let nodata = input.configs.nodata;
+
+    if method == "cc" {
+        let mut sum = 0f64;
+        let mut valid = true;
+        'outer: for m in -1..3isize {
+            let wy = cubic_convolution_weight(dy - m as f64);
+            for n in -1..3isize {
+                let z = input.get_value(origin_row + m, origin_col + n);
+                if z == nodata {
+                    valid = false;
+                    break 'outer;
+                }
+                sum += z * wy * cubic_convolution_weight(dx - n as f64);
+            }
+        }
+        if valid {
+            return sum;
+        }
+    }
+
+    let z00 = input.get_value(origin_row, origin_col);
+    let z01 = input.get_value(origin_row, origin_col + 1);
+    let z10 = input.get_value(origin_row + 1, origin_col);
+    let z11 = input.get_value(origin_row + 1, origin_col + 1);
+    if z00 == nodata || z01 == nodata || z10 == nodata || z11 == nodata {
+        return nearest();
+    }
+    (z00 * (1f64 - dx) + z01 * dx) * (1f64 - dy) + (z10 * (1f64 - dx) + z11 * dx) * dy
+}
+
+/// The cubic convolution kernel (Keys, 1981), with a = -0.5.
+fn cubic_convolution_weight(t: f64) -> f64 {
+    let a = -0.5f64;
+    let t = t.abs();
+    if t <= 1f64 {
+        (a + 2f64) * t * t * t - (a + 3f64) * t * t + 1f64
+    } else if t < 2f64 {
+        a * t * t * t - 5f64 * a * t * t + 8f64 * a * t - 4f64 * a
+    } else {
+        0f64
+    }
+}
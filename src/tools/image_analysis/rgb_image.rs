@@ -0,0 +1,115 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::io::{Error, ErrorKind};
+
+/// Returns whether a raster is a packed colour composite.
+pub fn is_colour_composite(raster: &Raster) -> bool {
+    raster.configs.data_type == DataType::RGB24
+        || raster.configs.data_type == DataType::RGBA32
+        || raster.configs.photometric_interp == PhotometricInterpretation::RGB
+}
+
+/// Unpacks the red, green, and blue channels of a colour-composite value, scaled to the
+/// range 0 to 1.
+pub fn unpack_rgb(value: f64) -> (f64, f64, f64) {
+    let v = value as u32;
+    (
+        (v & 0xFF) as f64 / 255f64,
+        ((v >> 8) & 0xFF) as f64 / 255f64,
+        ((v >> 16) & 0xFF) as f64 / 255f64,
+    )
+}
+
+/// Returns the alpha channel of a colour-composite value, from 0 to 255.
+pub fn unpack_alpha(value: f64) -> u32 {
+    (value as u32 >> 24) & 0xFF
+}
+
+/// Packs red, green, and blue channel values in the range 0 to 1, and an alpha value from
+/// 0 to 255, into a colour-composite value.
+pub fn pack_rgb(r: f64, g: f64, b: f64, alpha: u32) -> f64 {
+    let channel = |v: f64| (v.max(0f64).min(1f64) * 255f64).round() as u32;
+    ((alpha << 24) | (channel(b) << 16) | (channel(g) << 8) | channel(r)) as f64
+}
+
+/// Converts red, green, and blue values in the range 0 to 1 to hue (in degrees, from 0 to
+/// 360), saturation, and value (from 0 to 1). The hue of a grey is 0.
+pub fn rgb_to_hsv(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let h = if delta == 0f64 {
+        0f64
+    } else if max == r {
+        60f64 * ((g - b) / delta)
+    } else if max == g {
+        60f64 * ((b - r) / delta + 2f64)
+    } else {
+        60f64 * ((r - g) / delta + 4f64)
+    };
+    let h = if h < 0f64 { h + 360f64 } else { h };
+    let s = if max > 0f64 { delta / max } else { 0f64 };
+    (h, s, max)
+}
+
+/// Converts hue (in degrees), saturation, and value (from 0 to 1) to red, green, and blue
+/// values in the range 0 to 1.
+pub fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (f64, f64, f64) {
+    let h = ((h % 360f64) + 360f64) % 360f64 / 60f64;
+    let s = s.max(0f64).min(1f64);
+    let c = v * s;
+    let x = c * (1f64 - (h % 2f64 - 1f64).abs());
+    let (r, g, b) = match h as usize {
+        0 => (c, x, 0f64),
+        1 => (x, c, 0f64),
+        2 => (0f64, c, x),
+        3 => (0f64, x, c),
+        4 => (x, 0f64, c),
+        _ => (c, 0f64, x),
+    };
+    let m = v - c;
+    (r + m, g + m, b + m)
+}
+
+/// Parses a single value, which applies to all three colour channels, or three
+/// comma-separated values for the red, green, and blue channels; `name` names the values
+/// in error messages. The values must be greater than zero.
+pub fn parse_channel_values(value: &str, name: &str) -> Result<[f64; 3], Error> {
+    let values: Vec<f64> = value
+        .split(|c| c == ',' || c == ';')
+        .map(|s| s.trim().parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("The {} value '{}' could not be read.", name, value),
+            )
+        })?;
+    let channel_values = match values.len() {
+        1 => [values[0]; 3],
+        3 => [values[0], values[1], values[2]],
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Either one {0} value or three comma-separated {0} values are required.",
+                    name
+                ),
+            ))
+        }
+    };
+    if channel_values.iter().any(|v| !(*v > 0f64)) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("The {} values must be greater than zero.", name),
+        ));
+    }
+    Ok(channel_values)
+}
@@ -0,0 +1,373 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::rgb_image::{is_colour_composite, rgb_to_hsv, unpack_rgb};
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool transforms a colour image from the red, green, and blue (RGB) colour space to
+/// the hue, saturation, and value (HSV) colour space. Hue is the angle of the colour around
+/// the colour wheel, in degrees from 0 (red) through 120 (green) and 240 (blue) to 360;
+/// saturation is the purity of the colour, from 0 (grey) to 1; and value is the brightness
+/// of the colour, i.e. its largest RGB component, from 0 to 1. Greys are assigned a hue of
+/// 0. Unlike the intensity of the IHS colour space (see `RgbToIhs`), value does not mix the
+/// three components, which makes HSV convenient for adjusting the brightness or saturation
+/// of an image without shifting its hues; `HsvToRgb` performs the inverse transform.
+///
+/// The image may be input either as a colour composite (`--composite`) or as three band
+/// rasters (`--red`, `--green`, and `--blue`). Band values are scaled to the range 0 to 1
+/// using the lowest display minimum and highest display maximum of the three bands, such
+/// that the relative brightness of the bands is preserved. Cells that are nodata in any of
+/// the bands are nodata in the outputs.
+///
+/// # See Also
+/// `HsvToRgb`, `RgbToIhs`, `IhsToRgb`, `SplitColourComposite`
+pub struct RgbToHsv {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RgbToHsv {
+    /// Public constructor.
+    pub fn new() -> RgbToHsv {
+        let name = "RgbToHsv".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Converts red, green, and blue (RGB) images into hue, saturation, and value (HSV) images.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Red Band File (optional; only if colour-composite not specified)"
+                .to_owned(),
+            flags: vec!["--red".to_owned()],
+            description:
+                "Input red band image file. Optionally specified if colour-composite not specified."
+                    .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter{
+            name: "Input Green Band File (optional; only if colour-composite not specified)".to_owned(),
+            flags: vec!["--green".to_owned()],
+            description: "Input green band image file. Optionally specified if colour-composite not specified.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true
+        });
+
+        parameters.push(ToolParameter{
+            name: "Input Blue Band File (optional; only if colour-composite not specified)".to_owned(),
+            flags: vec!["--blue".to_owned()],
+            description: "Input blue band image file. Optionally specified if colour-composite not specified.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true
+        });
+
+        parameters.push(ToolParameter{
+            name: "Input Colour-Composite Image File (optional; only if individual bands not specified)".to_owned(),
+            flags: vec!["--composite".to_owned()],
+            description: "Input colour-composite image file. Only used if individual bands are not specified.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Hue File".to_owned(),
+            flags: vec!["--hue".to_owned()],
+            description: "Output hue raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Saturation File".to_owned(),
+            flags: vec!["--saturation".to_owned()],
+            description: "Output saturation raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Value File".to_owned(),
+            flags: vec!["--value".to_owned()],
+            description: "Output value (brightness) raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --red=band3.tif --green=band2.tif --blue=band1.tif --hue=hue.tif --saturation=saturation.tif --value=value.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --composite=image.tif --hue=hue.tif --saturation=saturation.tif --value=value.tif", short_exe, name).replace("*", &sep);
+
+        RgbToHsv {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RgbToHsv {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut red_file = String::new();
+        let mut green_file = String::new();
+        let mut blue_file = String::new();
+        let mut composite_file = String::new();
+        let mut hue_file = String::new();
+        let mut saturation_file = String::new();
+        let mut value_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            red_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--green"])? {
+            green_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--blue"])? {
+            blue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--composite"])? {
+            composite_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--hue"])? {
+            hue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--saturation"])? {
+            saturation_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--value"])? {
+            value_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let full_path = |f: String| {
+            if !f.is_empty() && !f.contains(&sep) && !f.contains("/") {
+                format!("{}{}", working_directory, f)
+            } else {
+                f
+            }
+        };
+        let red_file = full_path(red_file);
+        let green_file = full_path(green_file);
+        let blue_file = full_path(blue_file);
+        let composite_file = full_path(composite_file);
+        let hue_file = full_path(hue_file);
+        let saturation_file = full_path(saturation_file);
+        let value_file = full_path(value_file);
+
+        // each input returns the RGB values of a row, scaled to the range 0 to 1
+        let use_composite = !composite_file.is_empty();
+        let mut inputs = vec![];
+        if use_composite {
+            if verbose {
+                report::message("Reading image data...")
+            };
+            let input = Raster::new(&composite_file, "r")?;
+            if !is_colour_composite(&input) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The --composite input must be a colour-composite image.",
+                ));
+            }
+            inputs.push(input);
+        } else {
+            if red_file.is_empty() || green_file.is_empty() || blue_file.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Either a colour composite or red, green, and blue bands must be specified.",
+                ));
+            }
+            for f in [&red_file, &green_file, &blue_file].iter() {
+                if verbose {
+                    report::message(&format!("Reading {}...", f))
+                };
+                inputs.push(Raster::new(f, "r")?);
+            }
+            if inputs.iter().any(|r| {
+                r.configs.rows != inputs[0].configs.rows
+                    || r.configs.columns != inputs[0].configs.columns
+            }) {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
+            }
+        }
+
+        let start = Instant::now();
+
+        let rows = inputs[0].configs.rows as isize;
+        let columns = inputs[0].configs.columns as usize;
+        let overall_min = inputs
+            .iter()
+            .fold(f64::INFINITY, |m, r| m.min(r.configs.display_min));
+        let overall_max = inputs
+            .iter()
+            .fold(f64::NEG_INFINITY, |m, r| m.max(r.configs.display_max));
+        let range = if overall_max > overall_min {
+            overall_max - overall_min
+        } else {
+            1f64
+        };
+        let scale = |z: f64| ((z - overall_min) / range).max(0f64).min(1f64);
+
+        let out_nodata = -32768f64;
+        let mut outputs = vec![];
+        for f in [&hue_file, &saturation_file, &value_file].iter() {
+            let mut output = Raster::initialize_using_file(f, &inputs[0]);
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.configs.data_type = DataType::F32;
+            output.configs.nodata = out_nodata;
+            output.configs.palette = "grey.plt".to_string();
+            output.reinitialize_values(out_nodata);
+            outputs.push(output);
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        for row in 0..rows {
+            let data: Vec<Vec<f64>> = inputs.iter().map(|r| r.get_row_data(row)).collect();
+            let mut hsv = vec![vec![out_nodata; columns]; 3];
+            for col in 0..columns {
+                let rgb = if use_composite {
+                    let z = data[0][col];
+                    if z == inputs[0].configs.nodata {
+                        None
+                    } else {
+                        Some(unpack_rgb(z))
+                    }
+                } else if (0..3).any(|i| data[i][col] == inputs[i].configs.nodata) {
+                    None
+                } else {
+                    Some((scale(data[0][col]), scale(data[1][col]), scale(data[2][col])))
+                };
+                if let Some((r, g, b)) = rgb {
+                    let (h, s, v) = rgb_to_hsv(r, g, b);
+                    hsv[0][col] = h;
+                    hsv[1][col] = s;
+                    hsv[2][col] = v;
+                }
+            }
+            for (output, values) in outputs.iter_mut().zip(hsv.into_iter()) {
+                output.set_row_data(row, values);
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1).max(1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        for (output, component) in outputs.iter_mut().zip(["hue", "saturation", "value"].iter()) {
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            if use_composite {
+                output.add_metadata_entry(format!("Input colour composite file: {}", composite_file));
+            } else {
+                output.add_metadata_entry(format!("Input red band file: {}", red_file));
+                output.add_metadata_entry(format!("Input green band file: {}", green_file));
+                output.add_metadata_entry(format!("Input blue band file: {}", blue_file));
+            }
+            output.add_metadata_entry(format!("Component: {}", component));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message(&format!("Saving {} data...", component))
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
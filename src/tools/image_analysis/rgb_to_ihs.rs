@@ -179,82 +179,32 @@ impl WhiteboxTool for RgbToIhs {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-red" || vec[0].to_lowercase() == "--red" {
-                if keyval {
-                    red_file = vec[1].to_string();
-                } else {
-                    red_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-g"
-                || vec[0].to_lowercase() == "-green"
-                || vec[0].to_lowercase() == "--green"
-            {
-                if keyval {
-                    green_file = vec[1].to_string();
-                } else {
-                    green_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-b"
-                || vec[0].to_lowercase() == "-blue"
-                || vec[0].to_lowercase() == "--blue"
-            {
-                if keyval {
-                    blue_file = vec[1].to_string();
-                } else {
-                    blue_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-composite"
-                || vec[0].to_lowercase() == "--composite"
-            {
-                if keyval {
-                    composite_file = vec[1].to_string();
-                } else {
-                    composite_file = args[i + 1].to_string();
-                }
-                use_composite = true;
-            } else if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "-intensity"
-                || vec[0].to_lowercase() == "--intensity"
-            {
-                if keyval {
-                    intensity_file = vec[1].to_string();
-                } else {
-                    intensity_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-h"
-                || vec[0].to_lowercase() == "-hue"
-                || vec[0].to_lowercase() == "--hue"
-            {
-                if keyval {
-                    hue_file = vec[1].to_string();
-                } else {
-                    hue_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-s"
-                || vec[0].to_lowercase() == "-saturation"
-                || vec[0].to_lowercase() == "--saturation"
-            {
-                if keyval {
-                    saturation_file = vec[1].to_string();
-                } else {
-                    saturation_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--red"])? {
+            red_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-g", "--green"])? {
+            green_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-b", "--blue"])? {
+            blue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--composite"])? {
+            composite_file = v;
+            use_composite = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-i", "--intensity"])? {
+            intensity_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-h", "--hue"])? {
+            hue_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-s", "--saturation"])? {
+            saturation_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -288,15 +238,15 @@ impl WhiteboxTool for RgbToIhs {
 
         if !use_composite {
             if verbose {
-                println!("Reading red band data...")
+                report::message("Reading red band data...")
             };
             let input_r = Arc::new(Raster::new(&red_file, "r")?);
             if verbose {
-                println!("Reading green band data...")
+                report::message("Reading green band data...")
             };
             let input_g = Arc::new(Raster::new(&green_file, "r")?);
             if verbose {
-                println!("Reading blue band data...")
+                report::message("Reading blue band data...")
             };
             let input_b = Arc::new(Raster::new(&blue_file, "r")?);
 
@@ -305,6 +255,18 @@ impl WhiteboxTool for RgbToIhs {
             let nodata_r = input_r.configs.nodata;
             let nodata_g = input_g.configs.nodata;
             let nodata_b = input_b.configs.nodata;
+            let mut output_i = Raster::initialize_using_file(&intensity_file, &input_r);
+            output_i.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_i.configs.data_type = DataType::F32;
+
+            let mut output_h = Raster::initialize_using_file(&hue_file, &input_r);
+            output_h.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_h.configs.data_type = DataType::F32;
+
+            let mut output_s = Raster::initialize_using_file(&saturation_file, &input_r);
+            output_s.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_s.configs.data_type = DataType::F32;
+            let out_nodata = output_i.configs.nodata;
             let red_min = input_r.configs.display_min;
             let green_min = input_g.configs.display_min;
             let blue_min = input_b.configs.display_min;
@@ -322,13 +284,13 @@ impl WhiteboxTool for RgbToIhs {
                 || input_r.configs.columns != input_g.configs.columns
             {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                                    "The input files must have the same number of rows and columns and spatial extent."));
+                                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
             if input_r.configs.rows != input_b.configs.rows
                 || input_r.configs.columns != input_b.configs.columns
             {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                                    "The input files must have the same number of rows and columns and spatial extent."));
+                                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
 
             let (tx, rx) = mpsc::channel();
@@ -341,9 +303,9 @@ impl WhiteboxTool for RgbToIhs {
                     let (mut r, mut g, mut b): (f64, f64, f64);
                     let (mut i, mut h, mut s, mut m): (f64, f64, f64, f64);
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut intensity_data = vec![nodata_r; columns as usize];
-                        let mut hue_data = vec![nodata_r; columns as usize];
-                        let mut saturation_data = vec![nodata_r; columns as usize];
+                        let mut intensity_data = vec![out_nodata; columns as usize];
+                        let mut hue_data = vec![out_nodata; columns as usize];
+                        let mut saturation_data = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             r = input_r[(row, col)];
                             g = input_g[(row, col)];
@@ -408,18 +370,6 @@ impl WhiteboxTool for RgbToIhs {
                 });
             }
 
-            let mut output_i = Raster::initialize_using_file(&intensity_file, &input_r);
-            output_i.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output_i.configs.data_type = DataType::F32;
-
-            let mut output_h = Raster::initialize_using_file(&hue_file, &input_r);
-            output_h.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output_h.configs.data_type = DataType::F32;
-
-            let mut output_s = Raster::initialize_using_file(&saturation_file, &input_r);
-            output_s.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output_s.configs.data_type = DataType::F32;
-
             for row in 0..rows {
                 let data = rx.recv().unwrap();
                 output_i.set_row_data(data.0, data.1);
@@ -428,7 +378,7 @@ impl WhiteboxTool for RgbToIhs {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -444,11 +394,11 @@ impl WhiteboxTool for RgbToIhs {
             output_i.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving intensity data...")
+                report::message("Saving intensity data...")
             };
             let _ = match output_i.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -461,11 +411,11 @@ impl WhiteboxTool for RgbToIhs {
             output_h.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving hue data...")
+                report::message("Saving hue data...")
             };
             let _ = match output_h.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -480,27 +430,36 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving saturation data...")
+                report::message("Saving saturation data...")
             };
             let _ = match output_s.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
 
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         } else {
             if verbose {
-                println!("Reading image data...")
+                report::message("Reading image data...")
             };
             let input = Arc::new(Raster::new(&composite_file, "r")?);
             let rows = input.configs.rows as isize;
             let columns = input.configs.columns as isize;
             let nodata = input.configs.nodata;
+            let mut output_i = Raster::initialize_using_file(&intensity_file, &input);
+            output_i.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_i.configs.data_type = DataType::F32;
+
+            let mut output_h = Raster::initialize_using_file(&hue_file, &input);
+            output_h.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_h.configs.data_type = DataType::F32;
+
+            let mut output_s = Raster::initialize_using_file(&saturation_file, &input);
+            output_s.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output_s.configs.data_type = DataType::F32;
+            let out_nodata = output_i.configs.nodata;
 
             let start = Instant::now();
 
@@ -562,7 +521,7 @@ impl WhiteboxTool for RgbToIhs {
                 if verbose {
                     progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -579,9 +538,9 @@ impl WhiteboxTool for RgbToIhs {
                     let (mut i, mut h, mut s, mut m): (f64, f64, f64, f64);
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut intensity_data = vec![nodata; columns as usize];
-                        let mut hue_data = vec![nodata; columns as usize];
-                        let mut saturation_data = vec![nodata; columns as usize];
+                        let mut intensity_data = vec![out_nodata; columns as usize];
+                        let mut hue_data = vec![out_nodata; columns as usize];
+                        let mut saturation_data = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z = input[(row, col)];
                             if z != nodata {
@@ -648,18 +607,6 @@ impl WhiteboxTool for RgbToIhs {
                 });
             }
 
-            let mut output_i = Raster::initialize_using_file(&intensity_file, &input);
-            output_i.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output_i.configs.data_type = DataType::F32;
-
-            let mut output_h = Raster::initialize_using_file(&hue_file, &input);
-            output_h.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output_h.configs.data_type = DataType::F32;
-
-            let mut output_s = Raster::initialize_using_file(&saturation_file, &input);
-            output_s.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output_s.configs.data_type = DataType::F32;
-
             for row in 0..rows {
                 let data = rx.recv().unwrap();
                 output_i.set_row_data(data.0, data.1);
@@ -668,7 +615,7 @@ impl WhiteboxTool for RgbToIhs {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -686,11 +633,11 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving intensity data...")
+                report::message("Saving intensity data...")
             };
             let _ = match output_i.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -705,11 +652,11 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving hue data...")
+                report::message("Saving hue data...")
             };
             let _ = match output_h.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -724,19 +671,16 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving saturation data...")
+                report::message("Saving saturation data...")
             };
             let _ = match output_s.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         }
 
@@ -0,0 +1,425 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::collections::HashMap;
+use std::f64;
+use std::f64::consts::PI;
+use std::fs;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+
+/// The conversion of the digital numbers (DNs) of a band to physical values.
+#[derive(Clone, Copy, Debug)]
+pub enum BandConversion {
+    /// A linear rescaling, i.e. gain * DN + offset, giving radiance or reflectance.
+    Linear { gain: f64, offset: f64 },
+    /// A linear rescaling to radiance followed by the conversion of radiance to
+    /// brightness temperature (in kelvin) using the thermal constants K1 and K2.
+    BrightnessTemperature {
+        gain: f64,
+        offset: f64,
+        k1: f64,
+        k2: f64,
+    },
+}
+
+impl BandConversion {
+    pub fn convert(&self, dn: f64) -> f64 {
+        match *self {
+            BandConversion::Linear { gain, offset } => gain * dn + offset,
+            BandConversion::BrightnessTemperature {
+                gain,
+                offset,
+                k1,
+                k2,
+            } => {
+                let radiance = gain * dn + offset;
+                if radiance > 0f64 {
+                    k2 / (k1 / radiance + 1f64).ln()
+                } else {
+                    f64::NAN
+                }
+            }
+        }
+    }
+}
+
+/// Converts the DNs of a band raster to physical values and writes them to a new F32
+/// raster. Zero-valued DNs, which are used for fill, and nodata cells are assigned nodata.
+pub fn convert_band(
+    input_file: &str,
+    output_file: &str,
+    conversion: BandConversion,
+    metadata: Vec<String>,
+) -> Result<(), Error> {
+    let input = Raster::new(input_file, "r")?;
+    let in_nodata = input.configs.nodata;
+    let nodata = -32768f64;
+    let mut output = Raster::initialize_using_file(output_file, &input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    output.configs.nodata = nodata;
+    output.configs.color_map = vec![];
+    for row in 0..input.configs.rows as isize {
+        let data: Vec<f64> = input
+            .get_row_data(row)
+            .iter()
+            .map(|&dn| {
+                if dn == in_nodata || dn == 0f64 {
+                    return nodata;
+                }
+                let z = conversion.convert(dn);
+                if z.is_finite() {
+                    z
+                } else {
+                    nodata
+                }
+            })
+            .collect();
+        output.set_row_data(row, data);
+    }
+    for entry in metadata {
+        output.add_metadata_entry(entry);
+    }
+    output.write()
+}
+
+/// Resolves an output directory relative to the working directory and creates it if needed.
+pub fn output_directory(output_dir: &str, working_directory: &str) -> Result<String, Error> {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let dir = if output_dir.trim().is_empty() {
+        working_directory.to_string()
+    } else if !output_dir.contains(&sep) && !output_dir.contains("/") {
+        format!("{}{}", working_directory, output_dir.trim())
+    } else {
+        output_dir.trim().to_string()
+    };
+    if !Path::new(&dir).exists() {
+        fs::create_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
+/// The metadata of a Landsat scene, read from its MTL text file.
+pub struct LandsatMetadata {
+    values: HashMap<String, String>,
+    directory: String,
+}
+
+impl LandsatMetadata {
+    pub fn read(file_name: &str) -> Result<LandsatMetadata, Error> {
+        let mut contents = String::new();
+        File::open(file_name)?.read_to_string(&mut contents)?;
+        let mut values = HashMap::new();
+        for line in contents.lines() {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            if let Some(value) = parts.next() {
+                if key != "GROUP" && key != "END_GROUP" {
+                    values.insert(key.to_string(), value.trim().trim_matches('"').to_string());
+                }
+            }
+        }
+        if !values.contains_key("SUN_ELEVATION") {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{} does not appear to be a Landsat MTL metadata file.", file_name),
+            ));
+        }
+        let directory = match Path::new(file_name).parent() {
+            Some(p) => p.to_string_lossy().to_string(),
+            None => String::new(),
+        };
+        Ok(LandsatMetadata {
+            values: values,
+            directory: directory,
+        })
+    }
+
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        match self.values.get(key) {
+            Some(v) => v.parse::<f64>().ok(),
+            None => None,
+        }
+    }
+
+    /// Returns the band identifiers (e.g. '4', '10', or '6_VCID_1') and file names of the
+    /// bands listed in the metadata, located in the directory of the metadata file.
+    pub fn band_files(&self) -> Vec<(String, String)> {
+        let mut bands = vec![];
+        for (key, value) in &self.values {
+            if key.starts_with("FILE_NAME_BAND_") {
+                let band = key["FILE_NAME_BAND_".len()..].to_string();
+                let file = Path::new(&self.directory).join(value);
+                bands.push((band, file.to_string_lossy().to_string()));
+            }
+        }
+        bands.sort_by_key(|b| band_sort_key(&b.0));
+        bands
+    }
+
+    /// Identifies the band of a raster file, either from the file names listed in the
+    /// metadata or from a '_B<band>' suffix of the file name (e.g. LC08_..._B4.TIF).
+    pub fn band_from_file_name(&self, file_name: &str) -> Option<String> {
+        let stem = match Path::new(file_name).file_stem() {
+            Some(s) => s.to_string_lossy().to_uppercase(),
+            None => return None,
+        };
+        for (band, file) in self.band_files() {
+            if let Some(s) = Path::new(&file).file_stem() {
+                if s.to_string_lossy().to_uppercase() == stem {
+                    return Some(band);
+                }
+            }
+        }
+        match stem.rfind("_B") {
+            Some(idx) => {
+                let band = stem[idx + 2..].to_string();
+                if self.values.contains_key(&format!("RADIANCE_MULT_BAND_{}", band)) {
+                    Some(band)
+                } else {
+                    None
+                }
+            }
+            None => None,
+        }
+    }
+
+    /// Returns whether a band is a thermal band, i.e. one with thermal constants.
+    pub fn is_thermal(&self, band: &str) -> bool {
+        self.values.contains_key(&format!("K1_CONSTANT_BAND_{}", band))
+    }
+
+    /// Returns the conversion of a band's DNs to TOA radiance (if `radiance` is true) or to
+    /// TOA reflectance, corrected for the sun elevation, or brightness temperature for
+    /// thermal bands.
+    pub fn conversion(&self, band: &str, radiance: bool) -> Result<BandConversion, Error> {
+        let missing = |key: String| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("The metadata file does not contain {}.", key),
+            )
+        };
+        let get = |key: String| match self.get_f64(&key) {
+            Some(v) => Ok(v),
+            None => Err(missing(key)),
+        };
+        let rad_gain = get(format!("RADIANCE_MULT_BAND_{}", band))?;
+        let rad_offset = get(format!("RADIANCE_ADD_BAND_{}", band))?;
+        if radiance {
+            return Ok(BandConversion::Linear {
+                gain: rad_gain,
+                offset: rad_offset,
+            });
+        }
+        if self.is_thermal(band) {
+            return Ok(BandConversion::BrightnessTemperature {
+                gain: rad_gain,
+                offset: rad_offset,
+                k1: get(format!("K1_CONSTANT_BAND_{}", band))?,
+                k2: get(format!("K2_CONSTANT_BAND_{}", band))?,
+            });
+        }
+        let sun_elevation = get("SUN_ELEVATION".to_string())?;
+        let sin_elev = sun_elevation.to_radians().sin();
+        if sin_elev <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The sun is below the horizon in this scene; TOA reflectance is undefined.",
+            ));
+        }
+        Ok(BandConversion::Linear {
+            gain: get(format!("REFLECTANCE_MULT_BAND_{}", band))? / sin_elev,
+            offset: get(format!("REFLECTANCE_ADD_BAND_{}", band))? / sin_elev,
+        })
+    }
+}
+
+fn band_sort_key(band: &str) -> (usize, String) {
+    let digits: String = band.chars().take_while(|c| c.is_digit(10)).collect();
+    (digits.parse::<usize>().unwrap_or(0), band.to_string())
+}
+
+/// The names of the Sentinel-2 MSI bands, in the order of their band ids.
+const SENTINEL2_BANDS: [&str; 13] = [
+    "B1", "B2", "B3", "B4", "B5", "B6", "B7", "B8", "B8A", "B9", "B10", "B11", "B12",
+];
+
+/// The metadata of a Sentinel-2 Level-1C product, read from the product metadata
+/// (MTD_MSIL1C.xml) or, for the sun angles, the granule metadata (MTD_TL.xml).
+pub struct Sentinel2Metadata {
+    quantification_value: f64,
+    offsets: HashMap<usize, f64>,
+    solar_irradiance: HashMap<usize, f64>,
+    earth_sun_correction: f64,
+    pub sun_zenith: Option<f64>,
+}
+
+impl Sentinel2Metadata {
+    pub fn read(file_name: &str) -> Result<Sentinel2Metadata, Error> {
+        let mut xml = String::new();
+        File::open(file_name)?.read_to_string(&mut xml)?;
+        let quantification_value = xml_elements(&xml, "QUANTIFICATION_VALUE")
+            .first()
+            .and_then(|e| e.1.trim().parse::<f64>().ok());
+        let sun_zenith = xml_elements(&xml, "Mean_Sun_Angle")
+            .first()
+            .and_then(|e| xml_elements(&e.1, "ZENITH_ANGLE").first().cloned())
+            .and_then(|e| e.1.trim().parse::<f64>().ok());
+        if quantification_value.is_none() && sun_zenith.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{} does not appear to be a Sentinel-2 L1C metadata file.", file_name),
+            ));
+        }
+        let mut offsets = HashMap::new();
+        for (attributes, text) in xml_elements(&xml, "RADIO_ADD_OFFSET") {
+            if let (Some(id), Ok(v)) = (band_id_attribute(&attributes), text.trim().parse()) {
+                offsets.insert(id, v);
+            }
+        }
+        let mut solar_irradiance = HashMap::new();
+        for (attributes, text) in xml_elements(&xml, "SOLAR_IRRADIANCE") {
+            if let (Some(id), Ok(v)) = (band_id_attribute(&attributes), text.trim().parse()) {
+                solar_irradiance.insert(id, v);
+            }
+        }
+        let earth_sun_correction = xml_elements(&xml, "U")
+            .first()
+            .and_then(|e| e.1.trim().parse::<f64>().ok())
+            .unwrap_or(1f64);
+        Ok(Sentinel2Metadata {
+            quantification_value: quantification_value.unwrap_or(10000f64),
+            offsets: offsets,
+            solar_irradiance: solar_irradiance,
+            earth_sun_correction: earth_sun_correction,
+            sun_zenith: sun_zenith,
+        })
+    }
+
+    /// Adds the metadata of a second file, e.g. the sun angles of the granule metadata.
+    pub fn merge(&mut self, other: Sentinel2Metadata) {
+        if self.sun_zenith.is_none() {
+            self.sun_zenith = other.sun_zenith;
+        }
+        if self.solar_irradiance.is_empty() {
+            self.solar_irradiance = other.solar_irradiance;
+            self.offsets = other.offsets;
+            self.quantification_value = other.quantification_value;
+            self.earth_sun_correction = other.earth_sun_correction;
+        }
+    }
+
+    /// Identifies the band id (0-12) of a raster file from a '_B<band>' suffix of the file
+    /// name, e.g. T32TQM_20170717T101031_B8A.tif.
+    pub fn band_from_file_name(file_name: &str) -> Option<usize> {
+        let stem = match Path::new(file_name).file_stem() {
+            Some(s) => s.to_string_lossy().to_uppercase(),
+            None => return None,
+        };
+        let band = match stem.rfind("_B") {
+            Some(idx) => stem[idx + 1..].split('_').next().unwrap_or("").to_string(),
+            None => return None,
+        };
+        // band numbers may be zero-padded, e.g. B02
+        let band = if band.len() > 2 && band.starts_with("B0") {
+            format!("B{}", &band[2..])
+        } else {
+            band
+        };
+        SENTINEL2_BANDS.iter().position(|b| *b == band)
+    }
+
+    pub fn band_name(band_id: usize) -> &'static str {
+        SENTINEL2_BANDS[band_id]
+    }
+
+    /// Returns the conversion of a band's DNs to TOA reflectance or, if `radiance` is true,
+    /// to TOA radiance, which requires the solar irradiance and sun zenith angle.
+    pub fn conversion(&self, band_id: usize, radiance: bool) -> Result<BandConversion, Error> {
+        let q = self.quantification_value;
+        let offset = *self.offsets.get(&band_id).unwrap_or(&0f64);
+        if !radiance {
+            return Ok(BandConversion::Linear {
+                gain: 1f64 / q,
+                offset: offset / q,
+            });
+        }
+        let irradiance = match self.solar_irradiance.get(&band_id) {
+            Some(v) => *v,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The metadata do not contain the solar irradiance needed to calculate radiance.",
+                ))
+            }
+        };
+        let zenith = match self.sun_zenith {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The sun zenith angle, which is needed to calculate radiance, is unknown; specify the granule metadata (MTD_TL.xml) or the sun zenith angle.",
+                ))
+            }
+        };
+        let scale = irradiance * zenith.to_radians().cos() * self.earth_sun_correction / (PI * q);
+        Ok(BandConversion::Linear {
+            gain: scale,
+            offset: offset * scale,
+        })
+    }
+}
+
+fn band_id_attribute(attributes: &str) -> Option<usize> {
+    for name in ["bandId=\"", "band_id=\""].iter() {
+        if let Some(idx) = attributes.find(name) {
+            let value = attributes[idx + name.len()..].split('"').next().unwrap_or("");
+            return value.parse::<usize>().ok();
+        }
+    }
+    None
+}
+
+/// Returns the attributes and contents of the XML elements with the specified tag name,
+/// ignoring any namespace prefix.
+fn xml_elements(xml: &str, tag: &str) -> Vec<(String, String)> {
+    let mut elements = vec![];
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut pos = 0;
+    while let Some(idx) = xml[pos..].find(&open) {
+        let start = pos + idx + open.len();
+        pos = start;
+        // make sure that the tag name is complete, e.g. not <UNIT when looking for <U
+        match xml[start..].chars().next() {
+            Some(c) if c == '>' || c == ' ' || c == '/' => {}
+            _ => continue,
+        }
+        let tag_end = match xml[start..].find('>') {
+            Some(i) => start + i,
+            None => break,
+        };
+        let attributes = xml[start..tag_end].to_string();
+        if attributes.ends_with('/') {
+            elements.push((attributes, String::new()));
+            continue;
+        }
+        let content_end = match xml[tag_end..].find(&close) {
+            Some(i) => tag_end + i,
+            None => break,
+        };
+        elements.push((attributes, xml[tag_end + 1..content_end].to_string()));
+        pos = content_end;
+    }
+    elements
+}
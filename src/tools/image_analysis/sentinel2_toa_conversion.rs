@@ -0,0 +1,296 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::sensor_metadata::{convert_band, output_directory, Sentinel2Metadata};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+
+/// This tool converts the digital numbers (DNs) of Sentinel-2 Level-1C bands to
+/// top-of-atmosphere (TOA) reflectance, or to TOA radiance, using the product metadata file
+/// (`--metadata`, MTD_MSIL1C.xml), such that spectral indices and classifications are based
+/// on physically meaningful values. TOA reflectance is calculated using the quantification
+/// value of the product and, for processing baseline 04.00 and later, the radiometric offset
+/// of each band:
+///
+/// > &rho; = (DN + offset) / quantification value
+///
+/// When the `--radiance` flag is specified, the bands are instead converted to TOA radiance
+/// (W/(m<sup>2</sup> sr &mu;m)) using the solar irradiance of each band, the Earth-Sun distance
+/// correction, and the sun zenith angle (&theta;<sub>SZ</sub>):
+///
+/// > L = &rho; E<sub>SUN</sub> cos(&theta;<sub>SZ</sub>) U / &pi;
+///
+/// The mean sun zenith angle of a tile is stored in its granule metadata (MTD_TL.xml), which
+/// can be specified using `--granule_metadata`; alternatively, the sun zenith angle may be
+/// specified directly (`--sun_zenith`).
+///
+/// Sentinel-2 bands are distributed as JPEG 2000 files, which must first be converted to a
+/// supported raster format, e.g. GeoTIFF. The band rasters (`--inputs`) are identified by
+/// the band suffix of their file names, e.g. T17TNJ_20200621T160911_B8A.tif. Each output is
+/// saved as an F32 GeoTIFF in the output directory (`--output_dir`; by default the working
+/// directory), named after its input with a '_toa' or '_rad' suffix. DNs of zero, which
+/// Sentinel-2 uses for nodata, are assigned nodata in the outputs.
+///
+/// # See Also
+/// `LandsatToaConversion`, `NormalizedDifferenceVegetationIndex`
+pub struct Sentinel2ToaConversion {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Sentinel2ToaConversion {
+    pub fn new() -> Sentinel2ToaConversion {
+        // public constructor
+        let name = "Sentinel2ToaConversion".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Converts Sentinel-2 L1C DNs to TOA reflectance or radiance using the product metadata.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Product Metadata File".to_owned(),
+            flags: vec!["--metadata".to_owned()],
+            description: "Input Sentinel-2 product metadata file (MTD_MSIL1C.xml).".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Text),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Band Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input band files.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Directory".to_owned(),
+            flags: vec!["--output_dir".to_owned()],
+            description: "Output directory; the working directory if unspecified.".to_owned(),
+            parameter_type: ParameterType::Directory,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Convert to radiance?".to_owned(),
+            flags: vec!["--radiance".to_owned()],
+            description: "Convert the bands to TOA radiance rather than reflectance.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Granule Metadata File (Radiance)".to_owned(),
+            flags: vec!["--granule_metadata".to_owned()],
+            description: "Input granule metadata file (MTD_TL.xml) containing the sun angles.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Text),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sun Zenith Angle (Radiance)".to_owned(),
+            flags: vec!["--sun_zenith".to_owned()],
+            description: "Sun zenith angle, in degrees, if the granule metadata are unavailable.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --metadata=MTD_MSIL1C.xml -i='T17TNJ_B04.tif;T17TNJ_B08.tif'
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --metadata=MTD_MSIL1C.xml -i='T17TNJ_B04.tif;T17TNJ_B08.tif' --output_dir=rad --radiance --granule_metadata=MTD_TL.xml", short_exe, name).replace("*", &sep);
+
+        Sentinel2ToaConversion {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Sentinel2ToaConversion {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut metadata_file = String::new();
+        let mut input_files_str = String::new();
+        let mut output_dir = String::new();
+        let mut granule_file = String::new();
+        let mut sun_zenith: Option<f64> = None;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--metadata"])? {
+            metadata_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--output_dir"])? {
+            output_dir = v;
+        }
+        let radiance = tool_args.get_flag(&["--radiance"])?;
+        if let Some(v) = tool_args.get_string(&["--granule_metadata"])? {
+            granule_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--sun_zenith"])? {
+            sun_zenith = Some(v);
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !metadata_file.contains(&sep) && !metadata_file.contains("/") {
+            metadata_file = format!("{}{}", working_directory, metadata_file);
+        }
+        let output_dir = output_directory(&output_dir, working_directory)?;
+
+        let start = Instant::now();
+
+        let mut metadata = Sentinel2Metadata::read(&metadata_file)?;
+        if !granule_file.trim().is_empty() {
+            if !granule_file.contains(&sep) && !granule_file.contains("/") {
+                granule_file = format!("{}{}", working_directory, granule_file);
+            }
+            metadata.merge(Sentinel2Metadata::read(&granule_file)?);
+        }
+        if sun_zenith.is_some() {
+            metadata.sun_zenith = sun_zenith;
+        }
+
+        // pair each input with its band
+        let mut bands: Vec<(usize, String)> = vec![];
+        for f in input_files_str.split(|c| c == ';' || c == ',') {
+            let mut file = f.trim().to_string();
+            if file.is_empty() {
+                continue;
+            }
+            if !file.contains(&sep) && !file.contains("/") {
+                file = format!("{}{}", working_directory, file);
+            }
+            match Sentinel2Metadata::band_from_file_name(&file) {
+                Some(band) => bands.push((band, file)),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The band of {} could not be identified from its file name.", file),
+                    ))
+                }
+            }
+        }
+        if bands.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one input band file is required to operate this tool.",
+            ));
+        }
+
+        let num_bands = bands.len();
+        for (i, (band, input_file)) in bands.into_iter().enumerate() {
+            let conversion = metadata.conversion(band, radiance)?;
+            let band_name = Sentinel2Metadata::band_name(band);
+            let suffix = if radiance { "rad" } else { "toa" };
+            let stem = match Path::new(&input_file).file_stem() {
+                Some(s) => s.to_string_lossy().to_string(),
+                None => band_name.to_string(),
+            };
+            let output_file = Path::new(&output_dir)
+                .join(format!("{}_{}.tif", stem, suffix))
+                .to_string_lossy()
+                .to_string();
+            let entries = vec![
+                format!("Created by whitebox_tools\' {} tool", self.get_tool_name()),
+                format!("Input file: {}", input_file),
+                format!("Metadata file: {}", metadata_file),
+                format!("Band: {}", band_name),
+            ];
+            convert_band(&input_file, &output_file, conversion, entries)?;
+            if verbose {
+                report::message(&format!("Band {} written to {}", band_name, output_file));
+                report::progress(
+                    "Progress",
+                    (100.0_f64 * (i + 1) as f64 / num_bands as f64) as usize,
+                );
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
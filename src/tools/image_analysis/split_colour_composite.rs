@@ -129,34 +129,16 @@ impl WhiteboxTool for SplitColourComposite {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -172,7 +154,7 @@ impl WhiteboxTool for SplitColourComposite {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -182,6 +164,21 @@ impl WhiteboxTool for SplitColourComposite {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output_r =
+            Raster::initialize_using_file(&output_file.replace(".dep", "_red.dep"), &input);
+        output_r.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output_r.configs.data_type = DataType::F32;
+
+        let mut output_g =
+            Raster::initialize_using_file(&output_file.replace(".dep", "_green.dep"), &input);
+        output_g.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output_g.configs.data_type = DataType::F32;
+
+        let mut output_b =
+            Raster::initialize_using_file(&output_file.replace(".dep", "_blue.dep"), &input);
+        output_b.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output_b.configs.data_type = DataType::F32;
+        let out_nodata = output_r.configs.nodata;
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -193,9 +190,9 @@ impl WhiteboxTool for SplitColourComposite {
                 let mut val: u32;
                 let (mut red, mut green, mut blue): (u32, u32, u32);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data_r = vec![nodata; columns as usize];
-                    let mut data_g = vec![nodata; columns as usize];
-                    let mut data_b = vec![nodata; columns as usize];
+                    let mut data_r = vec![out_nodata; columns as usize];
+                    let mut data_g = vec![out_nodata; columns as usize];
+                    let mut data_b = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         in_val = input.get_value(row, col);
                         if in_val != nodata {
@@ -213,21 +210,6 @@ impl WhiteboxTool for SplitColourComposite {
             });
         }
 
-        let mut output_r =
-            Raster::initialize_using_file(&output_file.replace(".dep", "_red.dep"), &input);
-        output_r.configs.photometric_interp = PhotometricInterpretation::Continuous;
-        output_r.configs.data_type = DataType::F32;
-
-        let mut output_g =
-            Raster::initialize_using_file(&output_file.replace(".dep", "_green.dep"), &input);
-        output_g.configs.photometric_interp = PhotometricInterpretation::Continuous;
-        output_g.configs.data_type = DataType::F32;
-
-        let mut output_b =
-            Raster::initialize_using_file(&output_file.replace(".dep", "_blue.dep"), &input);
-        output_b.configs.photometric_interp = PhotometricInterpretation::Continuous;
-        output_b.configs.data_type = DataType::F32;
-
         for row in 0..rows {
             let data = rx.recv().unwrap();
             output_r.set_row_data(data.0, data.1);
@@ -236,7 +218,7 @@ impl WhiteboxTool for SplitColourComposite {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -251,11 +233,11 @@ impl WhiteboxTool for SplitColourComposite {
         output_r.add_metadata_entry(format!("Input file: {}", input_file));
         output_r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving red image...")
+            report::message("Saving red image...")
         };
         let _ = match output_r.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -267,11 +249,11 @@ impl WhiteboxTool for SplitColourComposite {
         output_g.add_metadata_entry(format!("Input file: {}", input_file));
         output_g.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving green image...")
+            report::message("Saving green image...")
         };
         let _ = match output_g.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -283,19 +265,16 @@ impl WhiteboxTool for SplitColourComposite {
         output_b.add_metadata_entry(format!("Input file: {}", input_file));
         output_b.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving blue image...")
+            report::message("Saving blue image...")
         };
         let _ = match output_b.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
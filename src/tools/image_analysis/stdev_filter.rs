@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 26, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -10,13 +10,12 @@ use num_cpus;
 use raster::*;
 use std::env;
 use std::f64;
-use std::i32;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
-use structures::Array2D;
+use structures::SummedAreaTable;
 use tools::*;
 
 pub struct StandardDeviationFilter {
@@ -148,53 +147,26 @@ impl WhiteboxTool for StandardDeviationFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if vec[0].to_lowercase() == "-filterx" || vec[0].to_lowercase() == "--filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-filtery" || vec[0].to_lowercase() == "--filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -227,7 +199,7 @@ impl WhiteboxTool for StandardDeviationFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -237,120 +209,39 @@ impl WhiteboxTool for StandardDeviationFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = RasterWriter::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         // create the integral images
-        let mut integral: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
-        let mut integral2: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
-        let mut integral_n: Array2D<i32> = Array2D::new(rows, columns, 0, -1)?;
-
-        let mut val: f64;
-        let mut sum: f64;
-        let mut sum_sqr: f64;
-        let mut sum_n: i32;
-        let (mut i_prev, mut i2_prev): (f64, f64);
-        let mut n_prev: i32;
-        for row in 0..rows {
-            sum = 0f64;
-            sum_sqr = 0f64;
-            sum_n = 0;
-            for col in 0..columns {
-                val = input[(row, col)];
-                if val == nodata {
-                    val = 0f64;
-                } else {
-                    sum_n += 1;
-                }
-                sum += val;
-                sum_sqr += val * val;
-                if row > 0 {
-                    i_prev = integral[(row - 1, col)];
-                    i2_prev = integral2[(row - 1, col)];
-                    n_prev = integral_n[(row - 1, col)];
-                    integral[(row, col)] = sum + i_prev;
-                    integral2[(row, col)] = sum_sqr + i2_prev;
-                    integral_n[(row, col)] = sum_n + n_prev;
-                } else {
-                    integral[(row, col)] = sum;
-                    integral2[(row, col)] = sum_sqr;
-                    integral_n[(row, col)] = sum_n;
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
-                    old_progress = progress;
-                }
+        let sat = Arc::new(SummedAreaTable::new(rows, columns, true, |row, col| {
+            let z = input[(row, col)];
+            if z != nodata {
+                Some(z)
+            } else {
+                None
             }
-        }
-
-        let i = Arc::new(integral); // wrap integral in an Arc
-        let i2 = Arc::new(integral2); // wrap integral2 in an Arc
-        let i_n = Arc::new(integral_n); // wrap integral_n in an Arc
+        }));
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let input_data = input.clone();
-            let i = i.clone();
-            let i2 = i2.clone();
-            let i_n = i_n.clone();
+            let sat = sat.clone();
             let tx1 = tx.clone();
             thread::spawn(move || {
-                let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
-                let mut n: i32;
-                let (mut sum, mut sum_sqr): (f64, f64);
-                let (mut v, mut s): (f64, f64);
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    y1 = row - midpoint_y - 1;
-                    if y1 < 0 {
-                        y1 = 0;
-                    }
-                    if y1 >= rows {
-                        y1 = rows - 1;
-                    }
-
-                    y2 = row + midpoint_y;
-                    if y2 < 0 {
-                        y2 = 0;
-                    }
-                    if y2 >= rows {
-                        y2 = rows - 1;
-                    }
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input_data[(row, col)];
                         if z != nodata {
-                            x1 = col - midpoint_x - 1;
-                            if x1 < 0 {
-                                x1 = 0;
-                            }
-                            if x1 >= columns {
-                                x1 = columns - 1;
-                            }
-
-                            x2 = col + midpoint_x;
-                            if x2 < 0 {
-                                x2 = 0;
-                            }
-                            if x2 >= columns {
-                                x2 = columns - 1;
-                            }
-                            n = i_n[(y2, x2)] + i_n[(y1, x1)] - i_n[(y1, x2)] - i_n[(y2, x1)];
-                            if n > 0 {
-                                sum = i[(y2, x2)] + i[(y1, x1)] - i[(y1, x2)] - i[(y2, x1)];
-                                sum_sqr = i2[(y2, x2)] + i2[(y1, x1)] - i2[(y1, x2)] - i2[(y2, x1)];
-                                v = (sum_sqr - (sum * sum) / n as f64) / n as f64;
-                                if v > 0f64 {
-                                    s = v.sqrt();
-                                    data[col as usize] = s;
-                                } else {
-                                    data[col as usize] = 0f64;
-                                }
-                            } else {
-                                data[col as usize] = 0f64;
-                            }
+                            data[col as usize] = sat
+                                .std_dev(
+                                    row - midpoint_y,
+                                    col - midpoint_x,
+                                    row + midpoint_y,
+                                    col + midpoint_x,
+                                ).unwrap_or(0f64);
                         }
                     }
 
@@ -359,14 +250,14 @@ impl WhiteboxTool for StandardDeviationFilter {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.begin_write()?;
         for row in 0..rows {
             let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
+            output.write_rows(data.0, data.1)?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -384,22 +275,19 @@ impl WhiteboxTool for StandardDeviationFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
-        let _ = match output.write() {
+        let _ = match output.finalize() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -159,64 +159,34 @@ impl WhiteboxTool for TophatTransform {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if vec[0].to_lowercase() == "-filterx" || vec[0].to_lowercase() == "--filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-filtery" || vec[0].to_lowercase() == "--filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-variant" || vec[0].to_lowercase() == "--variant" {
-                if keyval {
-                    variant = vec[1].to_string();
-                } else {
-                    variant = args[i + 1].to_string();
-                }
-                if variant.to_lowercase().contains("b") {
-                    variant = "black".to_string();
-                } else {
-                    variant = "white".to_string();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--variant"])? {
+            variant = v;
+            if variant.to_lowercase().contains("b") {
+                variant = "black".to_string();
+            } else {
+                variant = "white".to_string();
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -249,7 +219,7 @@ impl WhiteboxTool for TophatTransform {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -259,6 +229,8 @@ impl WhiteboxTool for TophatTransform {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let num_procs = num_cpus::get() as isize;
 
         if variant == "white".to_string() {
@@ -332,7 +304,7 @@ impl WhiteboxTool for TophatTransform {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        report::progress("Progress (Loop 1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -354,7 +326,7 @@ impl WhiteboxTool for TophatTransform {
                             VecDeque::with_capacity(filter_size_x);
                         start_row = row - midpoint_y;
                         end_row = row + midpoint_y;
-                        let mut data = vec![nodata; columns as usize];
+                        let mut data = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             if col > 0 {
                                 filter_max_vals.pop_front();
@@ -403,14 +375,13 @@ impl WhiteboxTool for TophatTransform {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &input);
             for row in 0..rows {
                 let data = rx.recv().unwrap();
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
+                        report::progress("Progress (Loop 2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -428,19 +399,16 @@ impl WhiteboxTool for TophatTransform {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
 
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         } else {
             // black top-hat transform
             // first perform the dilation
@@ -513,7 +481,7 @@ impl WhiteboxTool for TophatTransform {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        report::progress("Progress (Loop 1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -535,7 +503,7 @@ impl WhiteboxTool for TophatTransform {
                             VecDeque::with_capacity(filter_size_x);
                         start_row = row - midpoint_y;
                         end_row = row + midpoint_y;
-                        let mut data = vec![nodata; columns as usize];
+                        let mut data = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             if col > 0 {
                                 filter_min_vals.pop_front();
@@ -584,14 +552,13 @@ impl WhiteboxTool for TophatTransform {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &input);
             for row in 0..rows {
                 let data = rx.recv().unwrap();
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
+                        report::progress("Progress (Loop 2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -609,19 +576,16 @@ impl WhiteboxTool for TophatTransform {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         }
 
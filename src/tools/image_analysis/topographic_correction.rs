@@ -0,0 +1,581 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::resample::sample_value;
+use super::sensor_metadata::output_directory;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use tools::*;
+
+/// This tool performs a topographic illumination correction of multispectral bands
+/// (`--inputs`), which reduces the differences in brightness between sunlit and shaded
+/// slopes in rugged terrain, such that similar land covers have similar values regardless of
+/// their topographic setting. This is an important preprocessing step for classification
+/// and change detection in mountainous areas. The inputs should be reflectance bands, such as
+/// those created by the `LandsatToaConversion` or `DarkObjectSubtraction` tools.
+///
+/// The illumination of each cell, i.e. the cosine of the solar incidence angle *i* between
+/// the sun and the normal of the land surface, is calculated from a digital elevation model
+/// (`--dem`) and the sun's azimuth (`--azimuth`) and altitude (`--altitude`) at the time of
+/// acquisition, which are recorded in the scene metadata (e.g. SUN_AZIMUTH and SUN_ELEVATION
+/// in Landsat MTL files). Slopes are calculated using the method of Horn (1981). The DEM is
+/// sampled using bilinear interpolation if its grid differs from that of the bands, and the
+/// illumination may be saved (`--illumination`). The correction method (`--method`) may be:
+///
+/// - **cosine**: &rho;<sub>H</sub> = &rho;<sub>T</sub> cos(*sz*) / cos(*i*), where *sz* is
+///   the solar zenith angle. This simple method assumes that surfaces are Lambertian and
+///   tends to over-correct weakly illuminated slopes.
+/// - **c** (the C-correction of Teillet et al., 1982): &rho;<sub>H</sub> = &rho;<sub>T</sub>
+///   (cos(*sz*) + *c*) / (cos(*i*) + *c*), where *c* = *a* / *b* is estimated for each band
+///   from the regression &rho;<sub>T</sub> = *a* + *b* cos(*i*). The empirical constant
+///   moderates the over-correction of the cosine method.
+/// - **scs_c** (the SCS+C correction of Soenen et al., 2005): &rho;<sub>H</sub> =
+///   &rho;<sub>T</sub> (cos(*s*) cos(*sz*) + *c*) / (cos(*i*) + *c*), where *s* is the slope,
+///   which preserves the geotropic nature of tree canopies in forested terrain.
+/// - **minnaert**: &rho;<sub>H</sub> = &rho;<sub>T</sub> cos(*s*) (cos(*sz*) / (cos(*i*)
+///   cos(*s*)))<sup>*k*</sup>, where the Minnaert constant *k* is estimated for each band from
+///   the regression of ln(&rho;<sub>T</sub> cos(*s*)) on ln(cos(*i*) cos(*s*)).
+///
+/// The regressions use the cells with positive illumination and, for the Minnaert
+/// correction, positive values. Cells that are not illuminated by the sun (cos(*i*) &le; 0),
+/// i.e. slopes facing away from the sun and cast shadows, are not corrected. The estimated
+/// constants of each band are reported and stored in the output metadata. Each output is
+/// saved as an F32 GeoTIFF in the output directory (`--output_dir`; by default the working
+/// directory), named after its input with a '_tc' suffix. If the DEM is in geographic
+/// coordinates, the z-conversion factor (`--zfactor`) is calculated automatically.
+///
+/// # References
+/// Soenen, S. A., Peddle, D. R., and Coburn, C. A. (2005). SCS+C: A modified sun-canopy-sensor
+/// topographic correction in forested terrain. *IEEE Transactions on Geoscience and Remote
+/// Sensing*, 43(9), 2148-2159.
+///
+/// Teillet, P. M., Guindon, B., and Goodenough, D. G. (1982). On the slope-aspect correction of
+/// multispectral scanner data. *Canadian Journal of Remote Sensing*, 8(2), 84-106.
+///
+/// # See Also
+/// `Hillshade`, `DarkObjectSubtraction`, `LandsatToaConversion`
+pub struct TopographicCorrection {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl TopographicCorrection {
+    pub fn new() -> TopographicCorrection {
+        // public constructor
+        let name = "TopographicCorrection".to_string();
+        let toolbox = "Image Processing Tools".to_string();
+        let description = "Corrects reflectance bands for topographic illumination using the cosine, C, SCS+C, or Minnaert method.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Band Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input reflectance band files.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input digital elevation model file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Directory".to_owned(),
+            flags: vec!["--output_dir".to_owned()],
+            description: "Output directory; the working directory if unspecified.".to_owned(),
+            parameter_type: ParameterType::Directory,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Correction Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Correction method; options include 'cosine', 'c', 'scs_c', and 'minnaert'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "cosine".to_owned(),
+                "c".to_owned(),
+                "scs_c".to_owned(),
+                "minnaert".to_owned(),
+            ]),
+            default_value: Some("c".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sun Azimuth (degrees)".to_owned(),
+            flags: vec!["--azimuth".to_owned()],
+            description: "Sun azimuth, in degrees clockwise from north.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sun Altitude (degrees)".to_owned(),
+            flags: vec!["--altitude".to_owned()],
+            description: "Sun altitude (elevation) above the horizon, in degrees.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Z Conversion Factor".to_owned(),
+            flags: vec!["--zfactor".to_owned()],
+            description: "Optional multiplier for when the vertical and horizontal units are not the same.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Illumination File (optional)".to_owned(),
+            flags: vec!["--illumination".to_owned()],
+            description: "Optional output raster of the cosine of the solar incidence angle.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='B4_sr.tif;B5_sr.tif;B6_sr.tif' --dem=DEM.tif --output_dir=corrected --method=scs_c --azimuth=151.2 --altitude=38.7 --illumination=cos_i.tif", short_exe, name).replace("*", &sep);
+
+        TopographicCorrection {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for TopographicCorrection {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_files_str = String::new();
+        let mut dem_file = String::new();
+        let mut output_dir = String::new();
+        let mut method = String::from("c");
+        let mut azimuth: Option<f64> = None;
+        let mut altitude: Option<f64> = None;
+        let mut z_factor = 1f64;
+        let mut illumination_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--output_dir"])? {
+            output_dir = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.trim().to_lowercase().replace("+", "_").replace("-", "_");
+        }
+        if let Some(v) = tool_args.get_f64(&["--azimuth"])? {
+            azimuth = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--altitude"])? {
+            altitude = Some(v);
+        }
+        if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+            z_factor = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--illumination"])? {
+            illumination_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let method = match method.as_ref() {
+            "cosine" | "cos" => Correction::Cosine,
+            "c" | "c_correction" => Correction::C,
+            "scs_c" | "scsc" => Correction::ScsC,
+            "minnaert" => Correction::Minnaert,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized method; options include 'cosine', 'c', 'scs_c', and 'minnaert'.",
+                ))
+            }
+        };
+        let (azimuth, altitude) = match (azimuth, altitude) {
+            (Some(az), Some(alt)) if alt > 0f64 && alt <= 90f64 => {
+                (az.to_radians(), alt.to_radians())
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The sun azimuth (--azimuth) and an altitude (--altitude) between 0 and 90 degrees must be specified.",
+                ))
+            }
+        };
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let input_files: Vec<String> = input_files_str
+            .split(|c| c == ';' || c == ',')
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", working_directory, f)
+                } else {
+                    f.to_string()
+                }
+            })
+            .collect();
+        if input_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one input band file is required to operate this tool.",
+            ));
+        }
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !illumination_file.is_empty()
+            && !illumination_file.contains(&sep)
+            && !illumination_file.contains("/")
+        {
+            illumination_file = format!("{}{}", working_directory, illumination_file);
+        }
+        let output_dir = output_directory(&output_dir, working_directory)?;
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let dem = Raster::new(&dem_file, "r")?;
+
+        let start = Instant::now();
+
+        // the cosines of the incidence angle and slope, on the grid of the DEM
+        if dem.is_in_geographic_coordinates() {
+            // calculate a new z-conversion factor
+            let mid_lat = (dem.configs.north + dem.configs.south) / 2f64;
+            if mid_lat <= 90f64 && mid_lat >= -90f64 {
+                z_factor = 1f64 / (111320f64 * mid_lat.to_radians().cos());
+            }
+        }
+        let dem_nodata = dem.configs.nodata;
+        let nodata = -32768f64;
+        let mut cos_i = Raster::initialize_using_file(&illumination_file, &dem);
+        cos_i.configs.data_type = DataType::F32;
+        cos_i.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        cos_i.configs.nodata = nodata;
+        cos_i.configs.z_units = String::from("not specified");
+        cos_i.reinitialize_values(nodata);
+        let mut cos_s = cos_i.clone();
+        let (sun_x, sun_y, sun_z) = (
+            azimuth.sin() * altitude.cos(),
+            azimuth.cos() * altitude.cos(),
+            altitude.sin(),
+        );
+        let eight_res_x = 8f64 * dem.configs.resolution_x;
+        let eight_res_y = 8f64 * dem.configs.resolution_y;
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut n = [0f64; 8];
+        for row in 0..dem.configs.rows as isize {
+            for col in 0..dem.configs.columns as isize {
+                let z = dem.get_value(row, col);
+                if z == dem_nodata {
+                    continue;
+                }
+                for c in 0..8 {
+                    let zn = dem.get_value(row + d_y[c], col + d_x[c]);
+                    n[c] = if zn != dem_nodata { zn * z_factor } else { z * z_factor };
+                }
+                // the gradient, positive to the east (fx) and to the north (fy)
+                let fx = (n[2] - n[4] + 2f64 * (n[1] - n[5]) + n[0] - n[6]) / eight_res_x;
+                let fy = (n[6] - n[4] + 2f64 * (n[7] - n[3]) + n[0] - n[2]) / eight_res_y;
+                let norm = (1f64 + fx * fx + fy * fy).sqrt();
+                cos_i.set_value(row, col, (sun_z - fx * sun_x - fy * sun_y) / norm);
+                cos_s.set_value(row, col, 1f64 / norm);
+            }
+        }
+        let cos_sz = altitude.sin();
+
+        let num_files = input_files.len();
+        for (b, input_file) in input_files.iter().enumerate() {
+            let input = Raster::new(input_file, "r")?;
+            let in_nodata = input.configs.nodata;
+            let rows = input.configs.rows as isize;
+            let columns = input.configs.columns as isize;
+            let same_grid = input.configs.rows == dem.configs.rows
+                && input.configs.columns == dem.configs.columns
+                && (input.configs.north - dem.configs.north).abs()
+                    < 0.001 * dem.configs.resolution_y
+                && (input.configs.west - dem.configs.west).abs()
+                    < 0.001 * dem.configs.resolution_x;
+
+            // the illumination and slope of each cell of the band; nodata if either is
+            // unavailable
+            let mut values: Vec<(f64, f64, f64)> = Vec::with_capacity((rows * columns) as usize);
+            for row in 0..rows {
+                let y = input.get_y_from_row(row);
+                for col in 0..columns {
+                    let z = input.get_value(row, col);
+                    let (ci, cs) = if same_grid {
+                        (cos_i.get_value(row, col), cos_s.get_value(row, col))
+                    } else {
+                        let x = input.get_x_from_column(col);
+                        (
+                            sample_value(&cos_i, x, y, "bilinear"),
+                            sample_value(&cos_s, x, y, "bilinear"),
+                        )
+                    };
+                    if z == in_nodata || !z.is_finite() || ci == nodata || cs == nodata {
+                        values.push((nodata, 0f64, 0f64));
+                    } else {
+                        values.push((z, ci, cs));
+                    }
+                }
+            }
+
+            // estimate the empirical constant of the band
+            let constant = match method {
+                Correction::Cosine => 0f64,
+                Correction::C | Correction::ScsC => {
+                    let points: Vec<(f64, f64)> = values
+                        .iter()
+                        .filter(|v| v.0 != nodata && v.1 > 0f64)
+                        .map(|v| (v.1, v.0))
+                        .collect();
+                    match regression(&points) {
+                        Some((a, b)) if b != 0f64 => a / b,
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                format!("The C constant of {} could not be estimated; the band may not be related to the illumination.", input_file),
+                            ))
+                        }
+                    }
+                }
+                Correction::Minnaert => {
+                    let points: Vec<(f64, f64)> = values
+                        .iter()
+                        .filter(|v| v.0 != nodata && v.0 > 0f64 && v.1 > 0f64)
+                        .map(|v| ((v.1 * v.2).ln(), (v.0 * v.2).ln()))
+                        .collect();
+                    match regression(&points) {
+                        Some((_, k)) => k,
+                        None => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                format!("The Minnaert constant of {} could not be estimated.", input_file),
+                            ))
+                        }
+                    }
+                }
+            };
+
+            let stem = match Path::new(input_file).file_stem() {
+                Some(s) => s.to_string_lossy().to_string(),
+                None => format!("band{}", b + 1),
+            };
+            let output_file = Path::new(&output_dir)
+                .join(format!("{}_tc.tif", stem))
+                .to_string_lossy()
+                .to_string();
+            let mut output = Raster::initialize_using_file(&output_file, &input);
+            output.configs.data_type = DataType::F32;
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.configs.nodata = nodata;
+            output.configs.color_map = vec![];
+            for row in 0..rows {
+                let data: Vec<f64> = (0..columns)
+                    .map(|col| {
+                        let (z, ci, cs) = values[(row * columns + col) as usize];
+                        if z == nodata || ci <= 0f64 {
+                            return z;
+                        }
+                        match method {
+                            Correction::Cosine => z * cos_sz / ci,
+                            Correction::C => z * (cos_sz + constant) / (ci + constant),
+                            Correction::ScsC => z * (cs * cos_sz + constant) / (ci + constant),
+                            Correction::Minnaert => z * cs * (cos_sz / (ci * cs)).powf(constant),
+                        }
+                    })
+                    .collect();
+                output.set_row_data(row, data);
+            }
+
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            output.add_metadata_entry(format!("DEM file: {}", dem_file));
+            output.add_metadata_entry(format!("Method: {:?}", method));
+            match method {
+                Correction::C | Correction::ScsC => {
+                    output.add_metadata_entry(format!("C constant: {}", constant))
+                }
+                Correction::Minnaert => {
+                    output.add_metadata_entry(format!("Minnaert constant: {}", constant))
+                }
+                Correction::Cosine => {}
+            }
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    match method {
+                        Correction::C | Correction::ScsC => {
+                            report::message(&format!(
+                                "{} written (c = {:.4})",
+                                output_file, constant
+                            ))
+                        }
+                        Correction::Minnaert => {
+                            report::message(&format!(
+                                "{} written (k = {:.4})",
+                                output_file, constant
+                            ))
+                        }
+                        Correction::Cosine => report::message(&format!("{} written", output_file)),
+                    }
+                },
+                Err(e) => return Err(e),
+            };
+            if verbose {
+                report::progress(
+                    "Progress",
+                    (100.0_f64 * (b + 1) as f64 / num_files as f64) as usize,
+                );
+            }
+        }
+
+        if !illumination_file.is_empty() {
+            cos_i.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            cos_i.add_metadata_entry(format!("DEM file: {}", dem_file));
+            cos_i.add_metadata_entry(format!(
+                "Sun azimuth: {}; altitude: {}",
+                azimuth.to_degrees(),
+                altitude.to_degrees()
+            ));
+            let _ = match cos_i.write() {
+                Ok(_) => if verbose {
+                    report::message("Illumination file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Correction {
+    Cosine,
+    C,
+    ScsC,
+    Minnaert,
+}
+
+/// Returns the intercept and slope of the least-squares regression of y on x, or None if
+/// there are fewer than two points or x does not vary.
+fn regression(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let (mut sxy, mut sxx) = (0f64, 0f64);
+    for &(x, y) in points {
+        sxy += (x - mean_x) * (y - mean_y);
+        sxx += (x - mean_x) * (x - mean_x);
+    }
+    if sxx <= 0f64 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    Some((mean_y - slope * mean_x, slope))
+}
@@ -150,53 +150,25 @@ impl WhiteboxTool for UnsharpMasking {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-sigma" {
-                if keyval {
-                    sigma_d = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    sigma_d = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-amount" {
-                if keyval {
-                    amount = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    amount = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-threshold" {
-                if keyval {
-                    threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--sigma"])? {
+            sigma_d = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--amount"])? {
+            amount = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--threshold"])? {
+            threshold = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -265,7 +237,7 @@ impl WhiteboxTool for UnsharpMasking {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -274,6 +246,8 @@ impl WhiteboxTool for UnsharpMasking {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let is_rgb_image = if input.configs.data_type == DataType::RGB24
             || input.configs.data_type == DataType::RGBA32
             || input.configs.photometric_interp == PhotometricInterpretation::RGB
@@ -341,7 +315,7 @@ impl WhiteboxTool for UnsharpMasking {
                 let mut zn: f64;
                 let mut diff: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input_fn(row, col);
                         if z != nodata {
@@ -369,7 +343,6 @@ impl WhiteboxTool for UnsharpMasking {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         if is_rgb_image {
             output.configs.photometric_interp = PhotometricInterpretation::RGB;
             output.configs.data_type = DataType::RGBA32;
@@ -381,7 +354,7 @@ impl WhiteboxTool for UnsharpMasking {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -397,22 +370,19 @@ impl WhiteboxTool for UnsharpMasking {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
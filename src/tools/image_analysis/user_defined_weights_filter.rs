@@ -96,6 +96,24 @@ impl UserDefinedWeightsFilter {
             optional: true
         });
 
+        parameters.push(ToolParameter{
+            name: "Edge Handling".to_owned(), 
+            flags: vec!["--edges".to_owned()], 
+            description: "Treatment of cells beyond the grid edge; options include 'nodata', 'reflect', 'nearest', and 'constant'".to_owned(),
+            parameter_type: ParameterType::OptionList(vec!["nodata".to_owned(), "reflect".to_owned(), "nearest".to_owned(), "constant".to_owned()]),
+            default_value: Some("nodata".to_owned()),
+            optional: true
+        });
+
+        parameters.push(ToolParameter{
+            name: "Constant Edge Value".to_owned(), 
+            flags: vec!["--edge_value".to_owned()], 
+            description: "Value assigned to cells beyond the grid edge when using 'constant' edge handling.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -107,7 +125,8 @@ impl UserDefinedWeightsFilter {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=image.tif --weights=weights.txt -o=output.tif --center=center --normalize", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif --weights=weights.txt -o=output.tif --center=center --normalize
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=image.tif --weights=weights.txt -o=output.tif --edges=constant --edge_value=0.0", short_exe, name).replace("*", &sep);
 
         UserDefinedWeightsFilter {
             name: name,
@@ -158,51 +177,51 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         let mut weights_file = String::new();
         let mut kernel_center = "center".to_string();
         let mut normalize = false;
+        let mut edges_str = "nodata".to_string();
+        let mut edge_value = 0f64;
         if args.len() == 0 {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-weights" {
-                weights_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-center" || flag_val == "-centre" {
-                kernel_center = vec[1].to_string().to_lowercase();
-            } else if flag_val == "-normalize" {
-                normalize = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--weights"])? {
+            weights_file = v;
         }
+        if let Some(v) = tool_args.get_string(&["--center", "--centre"])? {
+            kernel_center = v.to_lowercase();
+        }
+        if tool_args.get_flag(&["--normalize"])? {
+            normalize = true;
+        }
+        if let Some(v) = tool_args.get_string(&["--edges"])? {
+            edges_str = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--edge_value"])? {
+            edge_value = v;
+        }
+        let edges = match &edges_str as &str {
+            "nodata" => EdgeHandling::NoData,
+            "reflect" => EdgeHandling::Reflect,
+            "nearest" => EdgeHandling::Nearest,
+            "constant" => EdgeHandling::Constant(edge_value),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized --edges option; options include 'nodata', 'reflect', 'nearest', and 'constant'.",
+                ))
+            }
+        };
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -220,29 +239,52 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         // read in the filter weights
         let f = File::open(weights_file.clone())?;
         let f = BufReader::new(f);
-        let mut weight: f64;
-        let mut num_pixels_in_filter = 0;
         let mut weights = vec![];
         let mut kernel_rows = 0;
         let mut kernel_columns = 0;
         for line in f.lines() {
-            let line_unwrapped = line.unwrap();
-            let mut line_split = line_unwrapped.split(",");
-            let mut vec = line_split.collect::<Vec<&str>>();
-            kernel_rows += 1;
-            if vec.len() == 1 {
-                line_split = line_unwrapped.split(" ");
-                vec = line_split.collect::<Vec<&str>>();
+            let line_unwrapped = line?;
+            let line_trimmed = line_unwrapped.trim();
+            if line_trimmed.is_empty() || line_trimmed.starts_with("#") {
+                continue;
             }
+            let vec = line_trimmed
+                .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+                .filter(|v| !v.is_empty())
+                .collect::<Vec<&str>>();
+            kernel_rows += 1;
             if kernel_rows == 1 {
                 kernel_columns = vec.len();
+            } else if vec.len() != kernel_columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "Row {} of the filter kernel contains {} weights rather than {}.",
+                        kernel_rows,
+                        vec.len(),
+                        kernel_columns
+                    ),
+                ));
             }
             for i in 0..vec.len() {
-                weight = vec[i].trim().parse::<f64>().unwrap();
-                weights.push(weight);
-                num_pixels_in_filter += 1;
+                match vec[i].parse::<f64>() {
+                    Ok(weight) => weights.push(weight),
+                    Err(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Unable to parse the kernel weight '{}'.", vec[i]),
+                        ))
+                    }
+                }
             }
         }
+        let num_pixels_in_filter = weights.len();
+        if num_pixels_in_filter == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The weights file does not contain a filter kernel.",
+            ));
+        }
 
         // calculate the filter offsets
         let mut d_x: Vec<isize> = Vec::with_capacity(num_pixels_in_filter);
@@ -250,9 +292,9 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
 
         let (kernel_center_x, kernel_center_y) = match &kernel_center as &str {
             "upper-left" => (0isize, 0isize),
-            "upper-right" => (0isize, kernel_columns as isize),
-            "lower-left" => (kernel_rows as isize, 0isize),
-            "lower-right" => (kernel_rows as isize, kernel_columns as isize),
+            "upper-right" => (kernel_columns as isize - 1, 0isize),
+            "lower-left" => (0isize, kernel_rows as isize - 1),
+            "lower-right" => (kernel_columns as isize - 1, kernel_rows as isize - 1),
             _ => {
                 // assume 'center'
 
@@ -285,7 +327,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -303,6 +345,8 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let d_x = Arc::new(d_x);
         let d_y = Arc::new(d_y);
         let weights = Arc::new(weights);
@@ -346,7 +390,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
                 let mut zn: f64;
                 let (mut x, mut y): (isize, isize);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     if normalize {
                         for col in 0..columns {
                             z = input_fn(row, col);
@@ -356,7 +400,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
                                 for a in 0..num_pixels_in_filter {
                                     x = col + d_x[a];
                                     y = row + d_y[a];
-                                    zn = input_fn(y, x);
+                                    zn = edges.get_value(y, x, rows, columns, &*input_fn);
                                     if zn != nodata {
                                         sum_weights += weights[a];
                                         z_final += weights[a] * zn;
@@ -375,7 +419,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
                                 for a in 0..num_pixels_in_filter {
                                     x = col + d_x[a];
                                     y = row + d_y[a];
-                                    zn = input_fn(y, x);
+                                    zn = edges.get_value(y, x, rows, columns, &*input_fn);
                                     if zn != nodata {
                                         z_final += weights[a] * zn;
                                     }
@@ -390,14 +434,13 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         for row in 0..rows {
             let data = rx.recv().unwrap();
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -410,31 +453,77 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         ));
         output.add_metadata_entry(format!("Input file: {}", input_file));
         output.add_metadata_entry(format!("Weights file: {}", weights_file));
+        output.add_metadata_entry(format!("Edge handling: {}", edges_str));
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
 
+/// The treatment of the cells beyond the edges of the grid that are overlapped by the kernel.
+#[derive(Clone, Copy)]
+enum EdgeHandling {
+    NoData,
+    Reflect,
+    Nearest,
+    Constant(f64),
+}
+
+impl EdgeHandling {
+    /// Returns the value of a cell, which may lie beyond the edges of the grid, using
+    /// `input_fn` to read the values of cells within the grid.
+    fn get_value(
+        &self,
+        row: isize,
+        col: isize,
+        rows: isize,
+        columns: isize,
+        input_fn: &Fn(isize, isize) -> f64,
+    ) -> f64 {
+        if row >= 0 && row < rows && col >= 0 && col < columns {
+            return input_fn(row, col);
+        }
+        match *self {
+            EdgeHandling::NoData => input_fn(row, col),
+            EdgeHandling::Reflect => input_fn(reflect(row, rows), reflect(col, columns)),
+            EdgeHandling::Nearest => input_fn(
+                row.max(0).min(rows - 1),
+                col.max(0).min(columns - 1),
+            ),
+            EdgeHandling::Constant(value) => value,
+        }
+    }
+}
+
+/// Mirrors an index across the edges of a sequence of length `n`, such that the edge cells
+/// are repeated, e.g. -1 maps to 0 and n maps to n - 1.
+#[inline]
+fn reflect(i: isize, n: isize) -> isize {
+    let period = 2 * n;
+    let j = ((i % period) + period) % period;
+    if j < n {
+        j
+    } else {
+        period - j - 1
+    }
+}
+
 #[inline]
 fn value2i(value: f64) -> f64 {
     let r = (value as u32 & 0xFF) as f64 / 255f64;
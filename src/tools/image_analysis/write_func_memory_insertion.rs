@@ -148,47 +148,23 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "--i1" || vec[0].to_lowercase() == "--input1" {
-                if keyval {
-                    input1_file = vec[1].to_string();
-                } else {
-                    input1_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "--i2" || vec[0].to_lowercase() == "--input2" {
-                if keyval {
-                    input2_file = vec[1].to_string();
-                } else {
-                    input2_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "--i3" || vec[0].to_lowercase() == "--input3" {
-                if keyval {
-                    input3_file = vec[1].to_string();
-                } else {
-                    input3_file = args[i + 1].to_string();
-                }
-                input3_used = true;
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i3", "--input3"])? {
+            input3_file = v;
+            input3_used = true;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -212,7 +188,7 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input_r = Arc::new(Raster::new(&input1_file, "r")?);
@@ -230,7 +206,7 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
         if input_r.configs.rows != input_b.configs.rows
@@ -238,7 +214,7 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -247,6 +223,8 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
         let nodata_r = input_r.configs.nodata;
         let nodata_g = input_g.configs.nodata;
         let nodata_b = input_b.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input_r);
+        let out_nodata = output.configs.nodata;
         let red_min = input_r.configs.display_min;
         let green_min = input_g.configs.display_min;
         let blue_min = input_b.configs.display_min;
@@ -268,7 +246,7 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
                 let (mut r, mut g, mut b): (u32, u32, u32);
                 let alpha_mask = (255 << 24) as u32;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata_r; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         red_val = input_r[(row, col)];
                         green_val = input_g[(row, col)];
@@ -308,7 +286,6 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input_r);
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGB24;
         for row in 0..rows {
@@ -317,7 +294,7 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -336,19 +313,16 @@ impl WhiteboxTool for WriteFunctionMemoryInsertion {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,143 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! Structured (JSON lines) reporting of tool runs.
+//!
+//! The tool is run in-process with the JSON report format (see `utils::report`), such
+//! that its progress, warnings, metadata, and other messages are each written as a JSON
+//! object on a single line of stdout. The run itself is reported by the following:
+//!
+//! | type     | fields                                                                   |
+//! | -------- | ------------------------------------------------------------------------ |
+//! | `start`  | `tool`, `args`                                                           |
+//! | `error`  | `message`                                                                |
+//! | `result` | `tool`, `success`, `outputs` (`flag`, `file`, `written`), `elapsed_time` |
+
+use serde_json;
+use serde_json::{Map, Value};
+use std::any::Any;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::panic::{self, AssertUnwindSafe};
+use std::path;
+use std::time::{Instant, SystemTime};
+use tools::{ParameterType, ToolArgs, ToolManager, ToolParameter, WhiteboxTool};
+use utils::get_formatted_elapsed_time;
+use utils::report;
+
+impl ToolManager {
+    /// Runs a tool, reporting its progress, warnings, metadata, and output files as
+    /// JSON lines on stdout.
+    pub fn run_tool_json(&self, tool_name: String, args: Vec<String>) -> Result<(), Error> {
+        let start = Instant::now();
+        let start_time = SystemTime::now();
+        let tool = match self.get_tool(tool_name.as_ref()) {
+            Some(t) => t,
+            None => {
+                let msg = format!("Unrecognized tool name {}.", tool_name);
+                report::emit(vec![
+                    ("type", Value::from("error")),
+                    ("message", Value::from(msg.clone())),
+                ]);
+                return Err(Error::new(ErrorKind::NotFound, msg));
+            }
+        };
+        let tool_name = tool.get_tool_name();
+        report::emit(vec![
+            ("type", Value::from("start")),
+            ("tool", Value::from(tool_name.clone())),
+            ("args", Value::from(args.clone())),
+        ]);
+
+        // tools only report their progress in verbose mode
+        let tm = ToolManager {
+            working_dir: self.working_dir.clone(),
+            verbose: true,
+            tool_names: self.tool_names.clone(),
+        };
+        let was_json = report::is_json_format();
+        report::set_json_format(true);
+        let result = match panic::catch_unwind(AssertUnwindSafe(|| {
+            tm.run_tool(tool_name.clone(), args.clone())
+        })) {
+            Ok(r) => r,
+            Err(payload) => Err(Error::new(ErrorKind::Other, get_panic_message(payload))),
+        };
+        report::set_json_format(was_json);
+
+        if let Err(ref e) = result {
+            report::emit(vec![
+                ("type", Value::from("error")),
+                ("message", Value::from(e.to_string())),
+            ]);
+        }
+
+        // the output files
+        let parameters = get_parameters(&tool);
+        let tool_args = ToolArgs::new(&parameters, &args);
+        let sep = path::MAIN_SEPARATOR.to_string();
+        let mut outputs = vec![];
+        for p in &parameters {
+            match p.parameter_type {
+                ParameterType::NewFile(_) => {}
+                _ => continue,
+            }
+            let flags: Vec<&str> = p.flags.iter().map(|f| f.as_str()).collect();
+            let mut file = match tool_args.get_string(&flags) {
+                Ok(Some(f)) => f,
+                _ => continue,
+            };
+            if file.is_empty() {
+                continue;
+            }
+            if !file.contains(&sep) && !file.contains("/") {
+                file = format!("{}{}", self.working_dir, file);
+            }
+            let mut output = Map::new();
+            output.insert(
+                "flag".to_string(),
+                Value::from(p.flags[p.flags.len() - 1].clone()),
+            );
+            let written = match fs::metadata(&file).and_then(|m| m.modified()) {
+                Ok(t) => t >= start_time,
+                Err(_) => false,
+            };
+            output.insert("written".to_string(), Value::from(written));
+            output.insert("file".to_string(), Value::from(file));
+            outputs.push(Value::Object(output));
+        }
+        report::emit(vec![
+            ("type", Value::from("result")),
+            ("tool", Value::from(tool_name.clone())),
+            ("success", Value::from(result.is_ok())),
+            ("outputs", Value::Array(outputs)),
+            ("elapsed_time", Value::from(get_formatted_elapsed_time(start))),
+        ]);
+
+        result
+    }
+}
+
+/// Returns a tool's parameters.
+fn get_parameters<'a>(wt: &Box<WhiteboxTool + 'a>) -> Vec<ToolParameter> {
+    match serde_json::from_str::<Value>(&wt.get_tool_parameters()) {
+        Ok(p) => serde_json::from_value(p["parameters"].clone()).unwrap_or(vec![]),
+        Err(_) => vec![],
+    }
+}
+
+/// Returns the message of a panic, which is usually a string.
+fn get_panic_message(payload: Box<Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    match payload.downcast_ref::<String>() {
+        Some(s) => s.clone(),
+        None => "The tool panicked.".to_string(),
+    }
+}
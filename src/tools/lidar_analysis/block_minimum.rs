@@ -148,41 +148,18 @@ impl WhiteboxTool for LidarBlockMinimum {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-palette" {
-                palette = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--palette"])? {
+            palette = v;
         }
 
         let start = Instant::now();
@@ -195,7 +172,7 @@ impl WhiteboxTool for LidarBlockMinimum {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -223,9 +200,7 @@ impl WhiteboxTool for LidarBlockMinimum {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         for k in 0..inputs.len() {
@@ -233,12 +208,12 @@ impl WhiteboxTool for LidarBlockMinimum {
             output_file = outputs[k].replace("\"", "").clone();
 
             if verbose && inputs.len() > 1 {
-                println!(
+                report::message(&format!(
                     "Gridding {} of {} ({})",
                     k + 1,
                     inputs.len(),
                     input_file.clone()
-                );
+                ));
             }
 
             if !input_file.contains(path::MAIN_SEPARATOR) {
@@ -249,7 +224,7 @@ impl WhiteboxTool for LidarBlockMinimum {
             }
 
             if verbose && inputs.len() == 1 {
-                println!("Reading input LAS file...");
+                report::message("Reading input LAS file...");
             }
             let input = match LasFile::new(&input_file, "r") {
                 Ok(lf) => lf,
@@ -259,7 +234,7 @@ impl WhiteboxTool for LidarBlockMinimum {
             let start_run = Instant::now();
 
             if verbose && inputs.len() == 1 {
-                println!("Performing analysis...");
+                report::message("Performing analysis...");
             }
 
             let n_points = input.header.number_of_points as usize;
@@ -271,7 +246,6 @@ impl WhiteboxTool for LidarBlockMinimum {
             let columns: usize = (((input.header.max_x - west) / grid_res).ceil()) as usize;
             let south: f64 = north - rows as f64 * grid_res;
             let east = west + columns as f64 * grid_res;
-            let nodata = -32768.0f64;
             let half_grid_res = grid_res / 2.0;
             let ns_range = north - south;
             let ew_range = east - west;
@@ -287,12 +261,13 @@ impl WhiteboxTool for LidarBlockMinimum {
             configs.west = west;
             configs.resolution_x = grid_res;
             configs.resolution_y = grid_res;
-            configs.nodata = nodata;
+            configs.nodata = -32768f64;
             configs.data_type = DataType::F64;
             configs.photometric_interp = PhotometricInterpretation::Continuous;
             configs.palette = palette.clone();
 
             let mut output = Raster::initialize_using_config(&output_file, &configs);
+            let nodata = output.configs.nodata;
 
             let input = Arc::new(input); // wrap input in an Arc
             let num_procs = num_cpus::get();
@@ -330,7 +305,7 @@ impl WhiteboxTool for LidarBlockMinimum {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -348,12 +323,12 @@ impl WhiteboxTool for LidarBlockMinimum {
             ));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        report::message("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -362,10 +337,7 @@ impl WhiteboxTool for LidarBlockMinimum {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
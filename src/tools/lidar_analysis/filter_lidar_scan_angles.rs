@@ -135,42 +135,19 @@ impl WhiteboxTool for FilterLidarScanAngles {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-threshold"
-                || vec[0].to_lowercase() == "--threshold"
-            {
-                if keyval {
-                    threshold = vec[1].to_string().parse::<i16>().unwrap().abs();
-                } else {
-                    threshold = args[i + 1].to_string().parse::<i16>().unwrap().abs();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--threshold"])? {
+            threshold = (v as i16).abs();
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -182,7 +159,7 @@ impl WhiteboxTool for FilterLidarScanAngles {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -191,49 +168,55 @@ impl WhiteboxTool for FilterLidarScanAngles {
 
         let start = Instant::now();
 
-        if verbose {
-            println!("Performing analysis...");
-        }
-
-        let n_points = input.header.number_of_points as usize;
-        let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
-
-        let mut progress: i32;
-        let mut old_progress: i32 = -1;
-
-        // now output the data
-        let mut output = LasFile::initialize_using_file(&output_file, &input);
-        output.header.system_id = "EXTRACTION".to_string();
-
-        for i in 0..n_points {
-            if input[i].scan_angle.abs() <= threshold {
-                output.add_point_record(input.get_record(i));
-            }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        let mut output = filter_lidar_scan_angles(&input, threshold, verbose);
+        output.set_file_name(&output_file);
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// Returns the points of a LiDAR file whose absolute scan angles are no greater than
+/// `threshold`.
+pub fn filter_lidar_scan_angles(input: &LasFile, threshold: i16, verbose: bool) -> LasFile {
+    if verbose {
+        report::message("Performing analysis...");
+    }
+
+    let n_points = input.header.number_of_points as usize;
+    let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
+
+    let mut progress: i32;
+    let mut old_progress: i32 = -1;
+
+    // now output the data
+    let mut output = LasFile::initialize_using_file("", input);
+    output.header.system_id = "EXTRACTION".to_string();
+
+    for i in 0..n_points {
+        if input[i].scan_angle.abs() <= threshold {
+            output.add_point_record(input.get_record(i));
+        }
+        if verbose {
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                report::progress("Progress", progress as usize);
+                old_progress = progress;
+            }
+        }
+    }
+
+    output
+}
@@ -124,34 +124,16 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -163,7 +145,7 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -189,7 +171,7 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -197,23 +179,20 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        report::message("");
         if num_output_points > 0 {
             if verbose {
-                println!("Writing output LAS file...");
+                report::message("Writing output LAS file...");
             }
             let _ = match output.write() {
-                Ok(_) => println!("Complete!"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => report::message("Complete!"),
+                Err(e) => report::message(&format!("error while writing: {:?}", e)),
             };
         } else {
-            println!("No points were flagged as flightline edge features in the LAS files.");
+            report::message("No points were flagged as flightline edge features in the LAS files.");
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
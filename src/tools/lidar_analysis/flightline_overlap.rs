@@ -138,41 +138,18 @@ impl WhiteboxTool for FlightlineOverlap {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-palette" {
-                palette = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--palette"])? {
+            palette = v;
         }
 
         let start = Instant::now();
@@ -185,7 +162,7 @@ impl WhiteboxTool for FlightlineOverlap {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -210,9 +187,7 @@ impl WhiteboxTool for FlightlineOverlap {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         for k in 0..inputs.len() {
@@ -220,12 +195,12 @@ impl WhiteboxTool for FlightlineOverlap {
             output_file = outputs[k].replace("\"", "").clone();
 
             if verbose && inputs.len() > 1 {
-                println!(
+                report::message(&format!(
                     "Gridding {} of {} ({})",
                     k + 1,
                     inputs.len(),
                     input_file.clone()
-                );
+                ));
             }
 
             if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -236,7 +211,7 @@ impl WhiteboxTool for FlightlineOverlap {
             }
 
             if verbose && inputs.len() == 1 {
-                println!("Reading input LAS file...");
+                report::message("Reading input LAS file...");
             }
             let input = match LasFile::new(&input_file, "r") {
                 Ok(lf) => lf,
@@ -246,7 +221,7 @@ impl WhiteboxTool for FlightlineOverlap {
             let start_run = Instant::now();
 
             if verbose && inputs.len() == 1 {
-                println!("Performing analysis...");
+                report::message("Performing analysis...");
             }
 
             // Make sure that the input LAS file have GPS time data?
@@ -364,7 +339,7 @@ impl WhiteboxTool for FlightlineOverlap {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        report::progress("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -376,7 +351,6 @@ impl WhiteboxTool for FlightlineOverlap {
             let columns: usize = (((input.header.max_x - west) / grid_res).ceil()) as usize;
             let south: f64 = north - rows as f64 * grid_res;
             let east = west + columns as f64 * grid_res;
-            let nodata = -32768.0f64;
 
             let mut configs = RasterConfigs {
                 ..Default::default()
@@ -389,7 +363,7 @@ impl WhiteboxTool for FlightlineOverlap {
             configs.west = west;
             configs.resolution_x = grid_res;
             configs.resolution_y = grid_res;
-            configs.nodata = nodata;
+            configs.nodata = -32768f64;
             configs.data_type = DataType::F64;
             configs.photometric_interp = PhotometricInterpretation::Continuous;
             configs.palette = palette.clone();
@@ -400,6 +374,7 @@ impl WhiteboxTool for FlightlineOverlap {
             // configs.epsg_code = input.configs.epsg_code;
             // configs.coordinate_ref_system_wkt = input.configs.coordinate_ref_system_wkt.clone();
             let mut output = Raster::initialize_using_config(&output_file, &configs);
+            let nodata = output.configs.nodata;
             let time_threshold = 15f64;
             let (mut x_n, mut y_n): (f64, f64);
             let mut index_n: usize;
@@ -442,7 +417,7 @@ impl WhiteboxTool for FlightlineOverlap {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -460,12 +435,12 @@ impl WhiteboxTool for FlightlineOverlap {
             ));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        report::message("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -474,10 +449,7 @@ impl WhiteboxTool for FlightlineOverlap {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -114,29 +114,13 @@ impl WhiteboxTool for LasToAscii {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" || flag_val == "-input" {
-                if keyval {
-                    input_files = vec[1].to_string();
-                } else {
-                    input_files = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -206,9 +190,12 @@ impl WhiteboxTool for LasToAscii {
                         progress = (100.0_f64 * k as f64 / (n_points - 1) as f64) as usize;
                         if progress != old_progress {
                             if num_files > 1 {
-                                println!("Creating file: {} of {}: {}%", i, num_files, progress);
+                                report::progress(
+                                    &format!("Creating file: {} of {}", i, num_files),
+                                    progress,
+                                );
                             } else {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress);
                             }
                             old_progress = progress;
                         }
@@ -221,7 +208,7 @@ impl WhiteboxTool for LasToAscii {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
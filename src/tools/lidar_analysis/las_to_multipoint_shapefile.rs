@@ -133,29 +133,13 @@ impl WhiteboxTool for LasToMultipointShapefile {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let mut progress: usize;
@@ -170,7 +154,7 @@ impl WhiteboxTool for LasToMultipointShapefile {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -210,16 +194,16 @@ impl WhiteboxTool for LasToMultipointShapefile {
                         .replace(".LAS", ".shp");
 
                     if verbose && num_tiles == 1 {
-                        println!("Reading input LAS file...");
+                        report::message("Reading input LAS file...");
                     }
 
                     let path = path::Path::new(&input_file);
                     let filenm = path.file_stem().unwrap();
                     let short_filename = filenm.to_str().unwrap().to_string();
                     if verbose && num_tiles > 1 {
-                        println!("Processing {}", short_filename);
+                        report::message(&format!("Processing {}", short_filename));
                     } else if verbose {
-                        println!("Performing analysis...");
+                        report::message("Performing analysis...");
                     }
                     let ret_val = match LasFile::new(&input_file, "r") {
                         Ok(mut input) => {
@@ -283,15 +267,18 @@ impl WhiteboxTool for LasToMultipointShapefile {
             match rx.recv() {
                 Ok(data) => {
                     if !data.0 {
-                        println!("{}", data.1);
+                        report::message(&data.1);
                     }
                 }
-                Err(val) => println!("Error: {:?}", val),
+                Err(val) => report::message(&format!("Error: {:?}", val)),
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress ({} of {}): {}%", (tile + 1), num_tiles, progress);
+                    report::progress(
+                        &format!("Progress ({} of {})", (tile + 1), num_tiles),
+                        progress,
+                    );
                     old_progress = progress;
                 }
             }
@@ -299,7 +286,7 @@ impl WhiteboxTool for LasToMultipointShapefile {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
@@ -192,53 +192,25 @@ impl WhiteboxTool for LidarClassifySubset {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-base" {
-                input_base_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-subset" {
-                input_subset_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-subset_class" {
-                subset_class = if keyval {
-                    vec[1].to_string().parse::<u8>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<u8>().unwrap()
-                };
-            } else if flag_val == "-nonsubset_class" {
-                nonsubset_class = if keyval {
-                    vec[1].to_string().parse::<u8>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<u8>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            input_base_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--subset"])? {
+            input_subset_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--subset_class"])? {
+            subset_class = v as u8;
+        }
+        if let Some(v) = tool_args.get_usize(&["--nonsubset_class"])? {
+            nonsubset_class = v as u8;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -267,7 +239,7 @@ impl WhiteboxTool for LidarClassifySubset {
         }
 
         if verbose {
-            println!("Reading input files...");
+            report::message("Reading input files...");
         }
         let base_lidar = LasFile::new(&input_base_file, "r")?;
         let subset_lidar = LasFile::new(&input_subset_file, "r")?;
@@ -275,7 +247,7 @@ impl WhiteboxTool for LidarClassifySubset {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         let num_points = (base_lidar.header.number_of_points - 1) as f64;
@@ -284,7 +256,7 @@ impl WhiteboxTool for LidarClassifySubset {
 
         let capacity_per_node = 128;
         let mut kdtree = KdTree::new_with_capacity(3, capacity_per_node);
-        println!("Creating tree...");
+        report::message("Creating tree...");
         for i in 0..subset_lidar.header.number_of_points as usize {
             let p: PointData = subset_lidar.get_point_info(i);
             kdtree.add([p.x, p.y, p.z], i).unwrap();
@@ -298,7 +270,7 @@ impl WhiteboxTool for LidarClassifySubset {
         // now output the data
         let mut output = LasFile::initialize_using_file(&output_file, &base_lidar);
 
-        println!("Performing analysis...");
+        report::message("Performing analysis...");
 
         for i in 0..base_lidar.header.number_of_points as usize {
             let p: PointData = base_lidar.get_point_info(i);
@@ -575,7 +547,7 @@ impl WhiteboxTool for LidarClassifySubset {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -583,19 +555,16 @@ impl WhiteboxTool for LidarClassifySubset {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        report::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
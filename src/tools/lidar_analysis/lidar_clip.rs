@@ -0,0 +1,371 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::point_in_rings;
+use lidar::*;
+use raster::Raster;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, Point2D, RTree};
+use tools::*;
+use vector::{ShapeType, Shapefile};
+
+/// The area used to subset a point cloud, defined by a set of polygons, a raster mask of
+/// valid cells, or both.
+struct ClipRegion {
+    rings: Vec<Vec<Vec<Point2D>>>,
+    tree: Option<RTree<usize>>,
+    mask: Option<Raster>,
+}
+
+impl ClipRegion {
+    fn new(polygons: Option<&Shapefile>, mask: Option<Raster>) -> ClipRegion {
+        let mut rings = vec![];
+        let mut tree = None;
+        if let Some(polygons) = polygons {
+            let mut entries = Vec::with_capacity(polygons.num_records);
+            for record_num in 0..polygons.num_records {
+                let record = polygons.get_record(record_num);
+                let mut r = Vec::with_capacity(record.num_parts as usize);
+                for part in 0..record.num_parts as usize {
+                    let part_start = record.parts[part] as usize;
+                    let part_end = if part < record.num_parts as usize - 1 {
+                        record.parts[part + 1] as usize
+                    } else {
+                        record.num_points as usize
+                    };
+                    r.push(record.points[part_start..part_end].to_vec());
+                }
+                if record.num_points > 0 {
+                    entries.push((
+                        BoundingBox::new(record.x_min, record.x_max, record.y_min, record.y_max),
+                        record_num,
+                    ));
+                }
+                rings.push(r);
+            }
+            tree = Some(RTree::new(entries));
+        }
+        ClipRegion {
+            rings: rings,
+            tree: tree,
+            mask: mask,
+        }
+    }
+
+    /// Returns true if the point (x, y) is within one of the polygons, if any, and on a
+    /// valid cell of the mask, if any. Valid cells are those that are neither NoData nor zero.
+    fn contains(&self, x: f64, y: f64) -> bool {
+        if let Some(ref mask) = self.mask {
+            let z = mask.get_value(mask.get_row_from_y(y), mask.get_column_from_x(x));
+            if z == mask.configs.nodata || z == 0f64 {
+                return false;
+            }
+        }
+        match self.tree {
+            Some(ref tree) => {
+                let p = Point2D::new(x, y);
+                tree.search_point(x, y)
+                    .into_iter()
+                    .any(|record_num| point_in_rings(&p, &self.rings[record_num]))
+            }
+            None => true,
+        }
+    }
+}
+
+/// Copies the points of a LiDAR file that are within (`keep_inside` is true), or outside of,
+/// a region to an output file, returning the number of points copied.
+fn subset_lidar(
+    input: &LasFile,
+    output: &mut LasFile,
+    region: &ClipRegion,
+    keep_inside: bool,
+    verbose: bool,
+) -> usize {
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+    let n_points = input.header.number_of_points as usize;
+    let mut num_copied = 0;
+    let mut p: PointData;
+    for point_num in 0..n_points {
+        p = input.get_point_info(point_num);
+        if region.contains(p.x, p.y) == keep_inside {
+            output.add_point_record(input.get_record(point_num));
+            num_copied += 1;
+        }
+        if verbose {
+            progress = (100.0_f64 * (point_num + 1) as f64 / n_points as f64) as usize;
+            if progress != old_progress {
+                report::progress("Progress", progress);
+                old_progress = progress;
+            }
+        }
+    }
+    num_copied
+}
+
+/// This tool subsets a LiDAR point cloud (`--input`), retaining only the points that are
+/// within a set of vector polygons (`--polygons`), on the valid cells of a raster mask
+/// (`--mask`), or both. Valid mask cells are those that are neither NoData nor zero, and
+/// points that are beyond the extent of the mask are excluded. When both polygons and a
+/// mask are specified, points must satisfy both conditions to be retained. Points within the
+/// holes of polygons are excluded.
+///
+/// All of the attributes of the retained points, e.g. intensity, classification, GPS time,
+/// and colour, are preserved, and the extents and point counts of the output file's
+/// (`--output`) header are updated to reflect the retained points. Points are tested using
+/// their x and y coordinates only. The `LidarErase` tool can be used to perform the
+/// complementary operation, removing the points within the region.
+///
+/// The input may be a LAS file or a zipped LAS file; LAZ-compressed files are not currently
+/// supported and must first be decompressed.
+///
+/// # See Also
+/// `LidarErase`, `ClipLidarToPolygon`, `LidarTile`, `SelectTilesByPolygon`
+pub struct LidarClip {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LidarClip {
+    /// public constructor
+    pub fn new() -> LidarClip {
+        let name = "LidarClip".to_string();
+        let toolbox = "LiDAR Tools".to_string();
+        let description =
+            "Clips a LiDAR point cloud to a set of vector polygons and/or a raster mask."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input LiDAR file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Lidar),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Vector Polygon File (optional)".to_owned(),
+            flags: vec!["--polygons".to_owned()],
+            description: "Input vector polygons file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Raster Mask File (optional)".to_owned(),
+            flags: vec!["--mask".to_owned()],
+            description: "Input raster mask file; valid cells are neither NoData nor zero.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output LiDAR file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Lidar),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='data.las' --polygons='site.shp' --mask='valid.tif' -o='output.las'", short_exe, name).replace("*", &sep);
+
+        LidarClip {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LidarClip {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut polygons_file = String::new();
+        let mut mask_file = String::new();
+        let mut output_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--polygon", "--polygons"])? {
+            polygons_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--mask"])? {
+            mask_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        clip_lidar(
+            &input_file,
+            &polygons_file,
+            &mask_file,
+            &output_file,
+            working_directory,
+            true,
+            verbose,
+        )
+    }
+}
+
+/// Copies the points of a LiDAR file that are within (`keep_inside` is true), or outside of,
+/// the region defined by a polygons file and/or a raster mask file to an output file. This
+/// performs the work of both the `LidarClip` and `LidarErase` tools; empty file names are
+/// treated as unspecified.
+pub fn clip_lidar(
+    input_file: &str,
+    polygons_file: &str,
+    mask_file: &str,
+    output_file: &str,
+    working_directory: &str,
+    keep_inside: bool,
+    verbose: bool,
+) -> Result<(), Error> {
+    if polygons_file.is_empty() && mask_file.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Either a polygons file (--polygons), a raster mask (--mask), or both, must be specified.",
+        ));
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let resolve = |f: &str| {
+        if !f.contains(&sep) && !f.contains("/") {
+            format!("{}{}", working_directory, f)
+        } else {
+            f.to_string()
+        }
+    };
+    let input_file = resolve(input_file);
+    let output_file = resolve(output_file);
+
+    if verbose {
+        report::message("Reading data...")
+    };
+    let input = LasFile::new(&input_file, "r")?;
+    let polygons = if !polygons_file.is_empty() {
+        let polygons = Shapefile::read(&resolve(polygons_file))?;
+        if polygons.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polygon base shape type.",
+            ));
+        }
+        Some(polygons)
+    } else {
+        None
+    };
+    let mask = if !mask_file.is_empty() {
+        Some(Raster::new(&resolve(mask_file), "r")?)
+    } else {
+        None
+    };
+    let region = ClipRegion::new(polygons.as_ref(), mask);
+
+    let start = Instant::now();
+
+    let mut output = LasFile::initialize_using_file(&output_file, &input);
+    output.header.system_id = "EXTRACTION".to_string();
+
+    let num_copied = subset_lidar(&input, &mut output, &region, keep_inside, verbose);
+    if num_copied == 0 {
+        return Err(Error::new(
+            ErrorKind::Other,
+            "No points were retained and the output file was not created.",
+        ));
+    }
+
+    let elapsed_time = get_formatted_elapsed_time(start);
+
+    if verbose {
+        report::message(&format!(
+            "Retained {} of {} points.",
+            num_copied, input.header.number_of_points
+        ));
+        report::message("Writing output LAS file...");
+    }
+    let _ = match output.write() {
+        Ok(_) => report::message("Complete!"),
+        Err(e) => report::message(&format!("error while writing: {:?}", e)),
+    };
+    if verbose {
+        report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+    }
+
+    Ok(())
+}
@@ -138,41 +138,19 @@ impl WhiteboxTool for LidarColourize {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-in_lidar" {
-                input_lidar_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-in_image" {
-                input_image_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--in_lidar"])? {
+            input_lidar_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--in_image"])? {
+            input_image_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -187,7 +165,7 @@ impl WhiteboxTool for LidarColourize {
         }
 
         if verbose {
-            println!("Reading input files...");
+            report::message("Reading input files...");
         }
         let in_lidar = Arc::new(LasFile::new(&input_lidar_file, "r")?);
         let in_image = Arc::new(Raster::new(&input_image_file, "r")?);
@@ -195,7 +173,7 @@ impl WhiteboxTool for LidarColourize {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         let n_points = in_lidar.header.number_of_points as usize;
@@ -234,7 +212,7 @@ impl WhiteboxTool for LidarColourize {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -294,7 +272,7 @@ impl WhiteboxTool for LidarColourize {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    report::progress("Saving data", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -302,19 +280,16 @@ impl WhiteboxTool for LidarColourize {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        report::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -167,7 +167,6 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         let mut output_file: String = "".to_string();
         let mut return_type = "all".to_string();
         let mut include_class_vals = vec![true; 256];
-        let mut exclude_cls_str: String;
         let mut max_z = f64::INFINITY;
         let mut min_z = f64::NEG_INFINITY;
 
@@ -178,71 +177,30 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-returns" {
-                if keyval {
-                    return_type = vec[1].to_string();
-                } else {
-                    return_type = args[i + 1].to_string();
-                }
-            } else if flag_val == "-exclude_cls" {
-                if keyval {
-                    exclude_cls_str = vec[1].to_string();
-                } else {
-                    exclude_cls_str = args[i + 1].to_string();
-                }
-                let mut cmd = exclude_cls_str.split(",");
-                let mut vec = cmd.collect::<Vec<&str>>();
-                if vec.len() == 1 {
-                    cmd = exclude_cls_str.split(";");
-                    vec = cmd.collect::<Vec<&str>>();
-                }
-                for value in vec {
-                    if !value.trim().is_empty() {
-                        let c = value.trim().parse::<usize>().unwrap();
-                        include_class_vals[c] = false;
-                    }
-                }
-            } else if flag_val == "-minz" {
-                if keyval {
-                    min_z = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    min_z = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-maxz" {
-                if keyval {
-                    max_z = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_z = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--returns"])? {
+            return_type = v;
+        }
+        if let Some(classes) = tool_args.get_u8_list(&["--exclude_cls"])? {
+            for c in classes {
+                include_class_vals[c as usize] = false;
             }
         }
+        if let Some(v) = tool_args.get_f64(&["--minz"])? {
+            min_z = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--maxz"])? {
+            max_z = v;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let start = Instant::now();
@@ -271,7 +229,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -310,7 +268,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            report::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -342,7 +300,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                     let mut z_values = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        report::message("Reading input LAS file...");
                     }
 
                     let mut progress: usize;
@@ -378,7 +336,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                         if verbose && inputs.len() == 1 {
                             progress = (100.0_f64 * i as f64 / num_points) as usize;
                             if progress != old_progress {
-                                println!("Reading points: {}%", progress);
+                                report::progress("Reading points", progress);
                                 old_progress = progress;
                             }
                         }
@@ -422,7 +380,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
 
                     // do the triangulation
                     if num_tiles == 1 && verbose {
-                        println!("Performing triangulation...");
+                        report::message("Performing triangulation...");
                     }
                     let result = triangulate(&points).expect("No triangulation exists.");
                     let (mut p1, mut p2, mut p3): (usize, usize, usize);
@@ -492,19 +450,19 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                             progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Creating polygons: {}%", progress);
+                                report::progress("Creating polygons", progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
 
                     let _ = match output.write() {
                         Ok(_) => if verbose {
-                            println!("Output file written")
+                            report::message("Output file written")
                         },
                         Err(e) => panic!("Error reading file {}:\n{:?}", input_file, e),
                     };
@@ -519,7 +477,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         for tile in 0..inputs.len() {
             let tile_completed = rx.recv().unwrap();
             if verbose {
-                println!(
+                report::message(&format!(
                     "Finished TINing {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -527,12 +485,12 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -541,10 +499,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,413 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{eigen_features, EigenFeatures};
+use lidar::*;
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::{DistanceMetric, KdTree};
+use tools::*;
+
+/// This tool calculates the geometric features of the neighbourhood of each point in a LiDAR
+/// point cloud (`--input`), derived from the eigenvalues (l1 >= l2 >= l3) and eigenvectors of
+/// the covariance matrix of the 3D coordinates of the neighbouring points. The neighbourhood
+/// of a point contains the points within a 3D search radius (`--radius`), or alternatively
+/// its nearest neighbours (`--num_neighbours`). The features are:
+///
+/// | Feature     | Definition        | Description                                           |
+/// |-------------|-------------------|-------------------------------------------------------|
+/// | linearity   | (l1 - l2) / l1    | High for points along linear structures, e.g. wires   |
+/// | planarity   | (l2 - l3) / l1    | High for points on surfaces, e.g. roofs and the ground |
+/// | sphericity  | l3 / l1           | High for scattered points, e.g. vegetation            |
+/// | verticality | 1 - abs(nz)       | 0 for horizontal and 1 for vertical surfaces, e.g. walls |
+/// | normal_x/y/z | (nx, ny, nz)     | The normal of the best-fit plane, with nz >= 0        |
+///
+/// The linearity, planarity, and sphericity of a point sum to one, and together with the
+/// verticality they provide a basis for the rule-based classification of point clouds into
+/// classes such as buildings, vegetation, and wires.
+///
+/// The features of the points are gridded to rasters at the specified grid resolution
+/// (`--resolution`), with each grid cell assigned the mean feature value of the points that it
+/// contains. One raster is created for each feature, and saved in the input file's directory
+/// with a name made from the input file name and the feature name, e.g. `input_planarity.tif`.
+/// Points with fewer than three neighbours within the search radius, or with coincident
+/// neighbours, have undefined features and are not included in the means; cells with no
+/// points with defined features are assigned NoData. The LAS format's extra bytes, which
+/// would be needed to store the features for each point, are not supported, although the
+/// `NormalVectors` tool can be used to store the normal vectors of points in their RGB fields.
+///
+/// # See Also
+/// `NormalVectors`, `LidarPointStats`, `LidarSegmentation`
+pub struct LidarEigenvalueFeatures {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LidarEigenvalueFeatures {
+    pub fn new() -> LidarEigenvalueFeatures {
+        // public constructor
+        let name = "LidarEigenvalueFeatures".to_string();
+        let toolbox = "LiDAR Tools".to_string();
+        let description = "Calculates the linearity, planarity, sphericity, verticality, and normal vectors of the neighbourhoods of LiDAR points, gridded to rasters.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input LiDAR file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Lidar),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Radius".to_owned(),
+            flags: vec!["--radius".to_owned()],
+            description: "Search radius of the 3D neighbourhood of each point.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Neighbours (optional)".to_owned(),
+            flags: vec!["--num_neighbours".to_owned()],
+            description: "Optional number of nearest neighbours in each point's neighbourhood, used instead of the search radius.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Grid Resolution".to_owned(),
+            flags: vec!["--resolution".to_owned()],
+            description: "Output rasters' grid resolution.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.las --radius=1.5 --resolution=2.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LidarEigenvalueFeatures {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LidarEigenvalueFeatures {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut search_radius = 1f64;
+        let mut num_neighbours = 0usize;
+        let mut grid_res = 1f64;
+
+        // read the arguments
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            search_radius = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_neighbours"])? {
+            num_neighbours = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if num_neighbours == 0 && search_radius <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The search radius must be greater than zero.",
+            ));
+        }
+        if num_neighbours > 0 && num_neighbours < 3 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of neighbours must be at least three.",
+            ));
+        }
+        if grid_res <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The grid resolution must be greater than zero.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep = path::MAIN_SEPARATOR;
+        if !input_file.contains(sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+
+        if verbose {
+            report::message("Reading input LAS file...");
+        }
+        let input = LasFile::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        if verbose {
+            report::message("Performing analysis...");
+        }
+
+        let n_points = input.header.number_of_points as usize;
+
+        let mut progress: i32;
+        let mut old_progress: i32 = -1;
+        let mut coords = Vec::with_capacity(n_points * 3);
+        for i in 0..n_points {
+            let p: PointData = input.get_point_info(i);
+            coords.extend_from_slice(&[p.x, p.y, p.z]);
+        }
+
+        let tree = Arc::new(KdTree::new(
+            3,
+            coords,
+            (0..n_points).collect(),
+            DistanceMetric::Euclidean,
+        ));
+        let input = Arc::new(input);
+        let num_procs = num_cpus::get();
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let tree = tree.clone();
+            let input = input.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
+                    let p: PointData = input.get_point_info(i);
+                    let ret = if num_neighbours > 0 {
+                        tree.knn_search(&[p.x, p.y, p.z], num_neighbours)
+                    } else {
+                        tree.radius_search(&[p.x, p.y, p.z], search_radius)
+                    };
+                    let neighbours: Vec<[f64; 3]> = ret
+                        .iter()
+                        .map(|&(j, _)| {
+                            let p2: PointData = input.get_point_info(j);
+                            [p2.x, p2.y, p2.z]
+                        })
+                        .collect();
+                    tx.send((i, eigen_features(&neighbours))).unwrap();
+                }
+            });
+        }
+
+        let mut features: Vec<Option<EigenFeatures>> = vec![None; n_points];
+        for i in 0..n_points {
+            let data = rx.recv().unwrap();
+            features[data.0] = data.1;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / n_points as f64) as i32;
+                if progress != old_progress {
+                    report::progress("Calculating point features", progress as usize);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // grid the features
+        let west = input.header.min_x;
+        let north = input.header.max_y;
+        let rows = (((north - input.header.min_y) / grid_res).ceil() as usize).max(1);
+        let columns = (((input.header.max_x - west) / grid_res).ceil() as usize).max(1);
+        let nodata = -32768.0f64;
+
+        let mut configs = RasterConfigs {
+            ..Default::default()
+        };
+        configs.rows = rows;
+        configs.columns = columns;
+        configs.north = north;
+        configs.south = north - rows as f64 * grid_res;
+        configs.east = west + columns as f64 * grid_res;
+        configs.west = west;
+        configs.resolution_x = grid_res;
+        configs.resolution_y = grid_res;
+        configs.nodata = nodata;
+        configs.data_type = DataType::F32;
+        configs.photometric_interp = PhotometricInterpretation::Continuous;
+
+        let feature_names = [
+            "linearity",
+            "planarity",
+            "sphericity",
+            "verticality",
+            "normal_x",
+            "normal_y",
+            "normal_z",
+        ];
+        let num_features = feature_names.len();
+        let mut sums = vec![vec![0f64; rows * columns]; num_features];
+        let mut counts = vec![0usize; rows * columns];
+        let mut num_undefined = 0;
+        for i in 0..n_points {
+            let f = match features[i] {
+                Some(f) => f,
+                None => {
+                    num_undefined += 1;
+                    continue;
+                }
+            };
+            let p: PointData = input.get_point_info(i);
+            let row = (((north - p.y) / grid_res).floor() as usize).min(rows - 1);
+            let col = (((p.x - west) / grid_res).floor() as usize).min(columns - 1);
+            let cell = row * columns + col;
+            let values = [
+                f.linearity,
+                f.planarity,
+                f.sphericity,
+                f.verticality,
+                f.normal[0],
+                f.normal[1],
+                f.normal[2],
+            ];
+            for k in 0..num_features {
+                sums[k][cell] += values[k];
+            }
+            counts[cell] += 1;
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if num_undefined > 0 {
+            report::warning(&format!(
+                "The features of {} points with fewer than three neighbours, or coincident neighbours, are undefined.",
+                num_undefined
+            ));
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let base_name = match input_file.rfind('.') {
+            Some(i) => input_file[..i].to_string(),
+            None => input_file.clone(),
+        };
+        for k in 0..num_features {
+            let output_file = format!("{}_{}.tif", base_name, feature_names[k]);
+            let mut output = Raster::initialize_using_config(&output_file, &configs);
+            for row in 0..rows {
+                for col in 0..columns {
+                    let cell = row * columns + col;
+                    if counts[cell] > 0 {
+                        output.set_value(
+                            row as isize,
+                            col as isize,
+                            sums[k][cell] / counts[cell] as f64,
+                        );
+                    }
+                }
+            }
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            if num_neighbours > 0 {
+                output.add_metadata_entry(format!("Number of neighbours: {}", num_neighbours));
+            } else {
+                output.add_metadata_entry(format!("Search radius: {}", search_radius));
+            }
+            output.add_metadata_entry(format!("Grid resolution: {}", grid_res));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        report::metadata("Output file written", &output_file)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
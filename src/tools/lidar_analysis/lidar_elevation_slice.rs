@@ -160,54 +160,29 @@ impl WhiteboxTool for LidarElevationSlice {
         if args.len() == 0 {
             return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters."));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 { keyval = true; }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i+1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i+1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-maxz" || vec[0].to_lowercase() == "--maxz" {
-                if keyval {
-                    maxz = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    maxz = args[i+1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-minz" || vec[0].to_lowercase() == "--minz" {
-                if keyval {
-                    minz = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    minz = args[i+1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-class" || vec[0].to_lowercase() == "--class" {
-                filter = false;
-            } else if vec[0].to_lowercase() == "-inclassval" || vec[0].to_lowercase() == "--inclassval" {
-                filter = false;
-                if keyval {
-                    in_class_value = vec[1].to_string().parse::<u8>().unwrap();
-                } else {
-                    in_class_value = args[i+1].to_string().parse::<u8>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-outclassval" || vec[0].to_lowercase() == "--outclassval" {
-                filter = false;
-                if keyval {
-                    out_class_value = vec[1].to_string().parse::<u8>().unwrap();
-                } else {
-                    out_class_value = args[i+1].to_string().parse::<u8>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--maxz"])? {
+            maxz = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--minz"])? {
+            minz = v;
+        }
+        if tool_args.get_flag(&["--class"])? {
+            filter = false;
+        }
+        if let Some(v) = tool_args.get_usize(&["--inclassval"])? {
+            filter = false;
+            in_class_value = v as u8;
+        }
+        if let Some(v) = tool_args.get_usize(&["--outclassval"])? {
+            filter = false;
+            out_class_value = v as u8;
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -218,9 +193,7 @@ impl WhiteboxTool for LidarElevationSlice {
         }
 
         if verbose {
-            println!("***********************************");
-            println!("* Welcome to lidar_elevation_slice *");
-            println!("************************************");
+            report::welcome(&self.get_tool_name());
         }
 
         if in_class_value > 31 || out_class_value > 31 {
@@ -235,7 +208,7 @@ impl WhiteboxTool for LidarElevationSlice {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
-        if verbose { println!("Reading input LAS file..."); }
+        if verbose { report::message("Reading input LAS file..."); }
         let input: LasFile = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
             Err(_) => return Err(Error::new(ErrorKind::NotFound, format!("No such file or directory ({})", input_file))),
@@ -243,7 +216,7 @@ impl WhiteboxTool for LidarElevationSlice {
         let mut output = LasFile::initialize_using_file(&output_file, &input);
         output.header.system_id = "EXTRACTION".to_string();
 
-        if verbose { println!("Performing analysis..."); }
+        if verbose { report::message("Performing analysis..."); }
         let mut z: f64;
         let mut progress: i32;
         let mut old_progress: i32 = -1;
@@ -260,7 +233,7 @@ impl WhiteboxTool for LidarElevationSlice {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -333,7 +306,7 @@ impl WhiteboxTool for LidarElevationSlice {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -342,13 +315,13 @@ impl WhiteboxTool for LidarElevationSlice {
         }
 
         if num_points_filtered > 0 {
-            if verbose { println!("Writing output LAS file..."); }
+            if verbose { report::message("Writing output LAS file..."); }
             let _ = match output.write() {
-                Ok(_) => println!("Complete!"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => report::message("Complete!"),
+                Err(e) => report::message(&format!("error while writing: {:?}", e)),
             };
         } else {
-            println!("No points were contained in the elevation slice.");
+            report::message("No points were contained in the elevation slice.");
         }
 
         Ok(())
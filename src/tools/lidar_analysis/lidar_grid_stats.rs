@@ -0,0 +1,438 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use lidar::*;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool creates several rasters summarizing the points of a LiDAR file (`--input`)
+/// within the cells of a grid of a specified resolution (`--resolution`), in a single pass
+/// over the point cloud. Unlike `LidarPointStats`, which reads the points once for each
+/// group of outputs, each point is visited exactly once, regardless of the number of
+/// outputs, which greatly reduces the run time for large point clouds. The available
+/// outputs are:
+///
+/// - `--point_density`: the number of points per unit area (`_point_density`).
+/// - `--pulse_density`: the number of pulses per unit area (`_pulse_density`), i.e. the
+///   density of first and only returns. Pulses without any returns, e.g. over water, are
+///   not counted.
+/// - `--return_props`: the proportions of the points in each cell that are first returns,
+///   last returns, and only returns (`_first_returns`, `_last_returns`, and
+///   `_only_returns`), with first and last returns including only returns. These
+///   indicate the penetrability of the surface, e.g. of vegetation canopies.
+/// - `--z_stats`: the minimum, maximum, and range of the point elevations (`_min_z`,
+///   `_max_z`, and `_z_range`).
+/// - `--mean_intensity`: the average point intensity (`_mean_intensity`).
+///
+/// If none of the output flags are specified, all of the outputs are created. The output
+/// file names are derived from the output file name (`--output`), if specified, or
+/// otherwise from the input file name, by appending the suffixes shown above. Cells that
+/// do not contain any points have densities of zero and nodata values in the other
+/// outputs. Points that are flagged as withheld are ignored.
+///
+/// # See Also
+/// `LidarPointStats`, `LidarPointDensity`
+pub struct LidarGridStats {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LidarGridStats {
+    pub fn new() -> LidarGridStats {
+        // public constructor
+        let name = "LidarGridStats".to_string();
+        let toolbox = "LiDAR Tools".to_string();
+        let description = "Creates density, return proportion, elevation, and intensity rasters from a LAS file in a single pass.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input LiDAR File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input LiDAR file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Lidar),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file, from which the output names are derived (optional)."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Grid Resolution".to_owned(),
+            flags: vec!["--resolution".to_owned()],
+            description: "Output raster's grid resolution.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output point density?".to_owned(),
+            flags: vec!["--point_density".to_owned()],
+            description: "Flag indicating whether or not to output the point density raster."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output pulse density?".to_owned(),
+            flags: vec!["--pulse_density".to_owned()],
+            description: "Flag indicating whether or not to output the pulse density raster."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output return proportions?".to_owned(),
+            flags: vec!["--return_props".to_owned()],
+            description:
+                "Flag indicating whether or not to output the first, last, and only return proportion rasters."
+                    .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output elevation statistics?".to_owned(),
+            flags: vec!["--z_stats".to_owned()],
+            description:
+                "Flag indicating whether or not to output the minimum, maximum, and range of elevation rasters."
+                    .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output mean intensity?".to_owned(),
+            flags: vec!["--mean_intensity".to_owned()],
+            description: "Flag indicating whether or not to output the mean intensity raster."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las --resolution=2.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=stats.tif --resolution=2.0 --pulse_density --return_props",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LidarGridStats {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LidarGridStats {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut grid_res = 1f64;
+        let mut point_density = false;
+        let mut pulse_density = false;
+        let mut return_props = false;
+        let mut z_stats = false;
+        let mut mean_intensity = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if tool_args.get_flag(&["--point_density"])? {
+            point_density = true;
+        }
+        if tool_args.get_flag(&["--pulse_density"])? {
+            pulse_density = true;
+        }
+        if tool_args.get_flag(&["--return_props"])? {
+            return_props = true;
+        }
+        if tool_args.get_flag(&["--z_stats"])? {
+            z_stats = true;
+        }
+        if tool_args.get_flag(&["--mean_intensity"])? {
+            mean_intensity = true;
+        }
+
+        // if none of the outputs are specified, they are all created
+        if !point_density && !pulse_density && !return_props && !z_stats && !mean_intensity {
+            point_density = true;
+            pulse_density = true;
+            return_props = true;
+            z_stats = true;
+            mean_intensity = true;
+        }
+
+        if grid_res <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The grid resolution must be greater than zero.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if output_file.is_empty() {
+            output_file = input_file.clone();
+        } else if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        // the output names are formed by appending a suffix to the base name
+        let (output_base, output_ext) = match output_file.rfind('.') {
+            Some(i) if !output_file[i..].contains(&sep) && !output_file[i..].contains("/") => {
+                let ext = output_file[i..].to_lowercase();
+                let ext = if ext == ".las" || ext == ".zip" || ext == ".laz" {
+                    ".tif".to_string()
+                } else {
+                    output_file[i..].to_string()
+                };
+                (output_file[..i].to_string(), ext)
+            }
+            _ => (output_file.clone(), ".tif".to_string()),
+        };
+
+        if verbose {
+            report::message("Reading input LAS file...");
+        }
+        let input = LasFile::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let west = input.header.min_x;
+        let north = input.header.max_y;
+        let rows = (((north - input.header.min_y) / grid_res).ceil() as usize).max(1);
+        let columns = (((input.header.max_x - west) / grid_res).ceil() as usize).max(1);
+
+        let mut configs = RasterConfigs {
+            ..Default::default()
+        };
+        configs.rows = rows;
+        configs.columns = columns;
+        configs.north = north;
+        configs.south = north - rows as f64 * grid_res;
+        configs.east = west + columns as f64 * grid_res;
+        configs.west = west;
+        configs.resolution_x = grid_res;
+        configs.resolution_y = grid_res;
+        configs.nodata = -32768f64;
+        configs.data_type = DataType::F32;
+        configs.photometric_interp = PhotometricInterpretation::Continuous;
+
+        // accumulate the statistics of each cell
+        let num_cells = rows * columns;
+        let mut num_points = vec![0u32; num_cells];
+        let mut num_first = vec![0u32; num_cells];
+        let mut num_last = vec![0u32; num_cells];
+        let mut num_only = vec![0u32; num_cells];
+        let mut min_z = vec![f64::INFINITY; num_cells];
+        let mut max_z = vec![f64::NEG_INFINITY; num_cells];
+        let mut intensity_sum = vec![0f64; num_cells];
+
+        let n_points = input.header.number_of_points as usize;
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+        let (mut row, mut col, mut cell): (usize, usize, usize);
+        for i in 0..n_points {
+            let p: PointData = input.get_point_info(i);
+            if p.withheld() {
+                continue;
+            }
+            col = (((p.x - west) / grid_res).floor().max(0f64) as usize).min(columns - 1);
+            row = (((north - p.y) / grid_res).floor().max(0f64) as usize).min(rows - 1);
+            cell = row * columns + col;
+            num_points[cell] += 1;
+            if p.is_early_return() {
+                num_first[cell] += 1;
+            }
+            if p.is_late_return() {
+                num_last[cell] += 1;
+            }
+            if p.is_only_return() {
+                num_only[cell] += 1;
+            }
+            if p.z < min_z[cell] {
+                min_z[cell] = p.z;
+            }
+            if p.z > max_z[cell] {
+                max_z[cell] = p.z;
+            }
+            intensity_sum[cell] += p.intensity as f64;
+
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / n_points as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        let mut outputs = vec![];
+        if point_density {
+            outputs.push("point_density");
+        }
+        if pulse_density {
+            outputs.push("pulse_density");
+        }
+        if return_props {
+            outputs.extend_from_slice(&["first_returns", "last_returns", "only_returns"]);
+        }
+        if z_stats {
+            outputs.extend_from_slice(&["min_z", "max_z", "z_range"]);
+        }
+        if mean_intensity {
+            outputs.push("mean_intensity");
+        }
+
+        let cell_area = grid_res * grid_res;
+        if verbose {
+            report::message("Saving data...")
+        };
+        for suffix in outputs {
+            let file_name = format!("{}_{}{}", output_base, suffix, output_ext);
+            let mut output = Raster::initialize_using_config(&file_name, &configs);
+            let nodata = output.configs.nodata;
+            for row in 0..rows {
+                for col in 0..columns {
+                    let c = row * columns + col;
+                    let n = num_points[c] as f64;
+                    let value = match suffix {
+                        "point_density" => n / cell_area,
+                        "pulse_density" => num_first[c] as f64 / cell_area,
+                        _ if n == 0f64 => nodata,
+                        "first_returns" => num_first[c] as f64 / n,
+                        "last_returns" => num_last[c] as f64 / n,
+                        "only_returns" => num_only[c] as f64 / n,
+                        "min_z" => min_z[c],
+                        "max_z" => max_z[c],
+                        "z_range" => max_z[c] - min_z[c],
+                        _ => intensity_sum[c] / n,
+                    };
+                    output.set_value(row as isize, col as isize, value);
+                }
+            }
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            output.add_metadata_entry(format!("Grid resolution: {}", grid_res));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        report::metadata("Output file written", &file_name)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
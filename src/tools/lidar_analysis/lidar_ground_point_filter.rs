@@ -191,63 +191,34 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-radius" {
-                search_radius = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-min_neighbours" || flag_val == "-min_neighbors" {
-                min_neighbours = if keyval {
-                    vec[1].to_string().parse::<usize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<usize>().unwrap()
-                };
-            } else if flag_val == "-height_threshold" {
-                height_threshold = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-slope_threshold" {
-                slope_threshold = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-classify" {
-                filter = false;
-            } else if flag_val == "-slope_norm" {
-                slope_norm = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            search_radius = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--min_neighbours", "--min_neighbors"])? {
+            min_neighbours = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--height_threshold"])? {
+            height_threshold = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--slope_threshold"])? {
+            slope_threshold = v;
+        }
+        if tool_args.get_flag(&["--classify"])? {
+            filter = false;
+        }
+        if tool_args.get_flag(&["--slope_norm"])? {
+            slope_norm = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -259,7 +230,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -269,7 +240,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         slope_threshold = slope_threshold.to_radians().tan();
@@ -296,7 +267,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    report::progress("Binning points", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -348,7 +319,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Erosion: {}%", progress);
+                        report::progress("Erosion", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -398,7 +369,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Dilation: {}%", progress);
+                        report::progress("Dilation", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -462,7 +433,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Slope-based Filter: {}%", progress);
+                    report::progress("Slope-based Filter", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -489,7 +460,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -633,7 +604,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -642,25 +613,22 @@ impl WhiteboxTool for LidarGroundPointFilter {
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No points were filtered from the point cloud.");
+            report::warning("No points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Complete!")
+                report::message("Complete!")
             },
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
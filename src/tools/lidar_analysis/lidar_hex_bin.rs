@@ -175,47 +175,24 @@ impl WhiteboxTool for LidarHexBinning {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-width" {
-                width = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val.contains("ori") {
-                orientation = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                if orientation.to_lowercase().contains("v") {
-                    // vertical orientation
-                    orientation = String::from("v");
-                } else {
-                    // horizontal orientation
-                    orientation = String::from("h");
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--width"])? {
+            width = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--orientation"])? {
+            orientation = v;
+            if orientation.to_lowercase().contains("v") {
+                // vertical orientation
+                orientation = String::from("v");
+            } else {
+                // horizontal orientation
+                orientation = String::from("h");
             }
         }
 
@@ -226,9 +203,7 @@ impl WhiteboxTool for LidarHexBinning {
         let start = Instant::now();
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if width <= 0f64 {
@@ -361,7 +336,7 @@ impl WhiteboxTool for LidarHexBinning {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        report::progress("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -408,7 +383,7 @@ impl WhiteboxTool for LidarHexBinning {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -469,7 +444,7 @@ impl WhiteboxTool for LidarHexBinning {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        report::progress("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -515,7 +490,7 @@ impl WhiteboxTool for LidarHexBinning {
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -523,11 +498,11 @@ impl WhiteboxTool for LidarHexBinning {
         }
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -535,7 +510,7 @@ impl WhiteboxTool for LidarHexBinning {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
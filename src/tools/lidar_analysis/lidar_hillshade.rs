@@ -159,53 +159,25 @@ impl WhiteboxTool for LidarHillshade {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-azimuth" {
-                if keyval {
-                    azimuth = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    azimuth = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-altitude" {
-                if keyval {
-                    altitude = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    altitude = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-radius" {
-                if keyval {
-                    search_radius = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    search_radius = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--azimuth"])? {
+            azimuth = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--altitude"])? {
+            altitude = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            search_radius = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         azimuth = (azimuth - 90f64).to_radians();
@@ -222,7 +194,7 @@ impl WhiteboxTool for LidarHillshade {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -232,7 +204,7 @@ impl WhiteboxTool for LidarHillshade {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -248,7 +220,7 @@ impl WhiteboxTool for LidarHillshade {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    report::progress("Binning points", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -286,7 +258,7 @@ impl WhiteboxTool for LidarHillshade {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
+                    report::progress("Calculating point normals", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -339,7 +311,7 @@ impl WhiteboxTool for LidarHillshade {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    report::progress("Saving data", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -347,19 +319,16 @@ impl WhiteboxTool for LidarHillshade {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        report::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
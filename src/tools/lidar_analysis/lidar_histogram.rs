@@ -156,47 +156,22 @@ impl WhiteboxTool for LidarHistogram {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-parameter" {
-                if keyval {
-                    parameter = vec[1].to_string().to_lowercase();
-                } else {
-                    parameter = args[i + 1].to_string().to_lowercase();
-                }
-            } else if flag_val == "-clip" {
-                if keyval {
-                    clip_percent = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    clip_percent = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--parameter"])? {
+            parameter = v.to_lowercase();
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip"])? {
+            clip_percent = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -214,7 +189,7 @@ impl WhiteboxTool for LidarHistogram {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -231,7 +206,7 @@ impl WhiteboxTool for LidarHistogram {
             "scan angle" => 2,
             "class" => 3,
             _ => {
-                println!("Warning: unrecognized parameter; elevation will be used");
+                report::warning("Unrecognized parameter; elevation will be used");
                 0 // elevation
             }
         };
@@ -257,7 +232,7 @@ impl WhiteboxTool for LidarHistogram {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating min and max: {}%", progress);
+                    report::progress("Calculating min and max", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -282,7 +257,7 @@ impl WhiteboxTool for LidarHistogram {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Clipping the tails: {}%", progress);
+                        report::progress("Clipping the tails", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -345,7 +320,7 @@ impl WhiteboxTool for LidarHistogram {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Binning the data: {}%", progress);
+                        report::progress("Binning the data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -366,7 +341,7 @@ impl WhiteboxTool for LidarHistogram {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Binning the data: {}%", progress);
+                        report::progress("Binning the data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -435,10 +410,10 @@ impl WhiteboxTool for LidarHistogram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -466,7 +441,7 @@ impl WhiteboxTool for LidarHistogram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -232,96 +232,45 @@ impl WhiteboxTool for LidarIdwInterpolation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-parameter" {
-                interp_parameter = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-returns" {
-                return_type = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-weight" {
-                weight = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-radius" {
-                search_radius = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-palette" {
-                palette = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-exclude_cls" {
-                exclude_cls_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                let mut cmd = exclude_cls_str.split(",");
-                let mut vec = cmd.collect::<Vec<&str>>();
-                if vec.len() == 1 {
-                    cmd = exclude_cls_str.split(";");
-                    vec = cmd.collect::<Vec<&str>>();
-                }
-                for value in vec {
-                    if !value.trim().is_empty() {
-                        let c = value.trim().parse::<usize>().unwrap();
-                        include_class_vals[c] = false;
-                    }
-                }
-            } else if flag_val == "-minz" {
-                min_z = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-maxz" {
-                max_z = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--parameter"])? {
+            interp_parameter = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--returns"])? {
+            return_type = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--weight"])? {
+            weight = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            search_radius = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--palette"])? {
+            palette = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--exclude_cls"])? {
+            exclude_cls_str = v;
+        }
+        if let Some(classes) = tool_args.get_u8_list(&["--exclude_cls"])? {
+            for c in classes {
+                include_class_vals[c as usize] = false;
             }
         }
+        if let Some(v) = tool_args.get_f64(&["--minz"])? {
+            min_z = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--maxz"])? {
+            max_z = v;
+        }
 
         let (all_returns, late_returns, early_returns): (bool, bool, bool);
         if return_type.contains("last") {
@@ -340,9 +289,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let start = Instant::now();
@@ -355,7 +302,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -409,7 +356,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            report::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -457,7 +404,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::Euclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        report::message("Reading input LAS file...");
                     }
 
                     let mut progress: i32;
@@ -500,7 +447,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -527,7 +477,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -554,7 +507,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -585,7 +541,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -613,7 +572,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -631,7 +593,6 @@ impl WhiteboxTool for LidarIdwInterpolation {
                         (((bounding_boxes[tile].max_x - west) / grid_res).ceil()) as isize;
                     let south: f64 = north - rows as f64 * grid_res;
                     let east = west + columns as f64 * grid_res;
-                    let nodata = -32768.0f64;
 
                     let mut configs = RasterConfigs {
                         ..Default::default()
@@ -644,12 +605,13 @@ impl WhiteboxTool for LidarIdwInterpolation {
                     configs.west = west;
                     configs.resolution_x = grid_res;
                     configs.resolution_y = grid_res;
-                    configs.nodata = nodata;
+                    configs.nodata = -32768f64;
                     configs.data_type = DataType::F32;
                     configs.photometric_interp = PhotometricInterpretation::Continuous;
                     configs.palette = palette.clone();
 
                     let mut output = Raster::initialize_using_config(&output_file, &configs);
+                    let nodata = output.configs.nodata;
 
                     if num_tiles > 1 {
                         let (mut x, mut y): (f64, f64);
@@ -685,7 +647,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -741,7 +703,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                             if verbose {
                                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -770,7 +732,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                     ));
 
                     if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
 
                     let _ = output.write().unwrap();
@@ -785,7 +747,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
             if verbose {
-                println!(
+                report::message(&format!(
                     "Finished interpolating {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -793,12 +755,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -807,10 +769,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -147,46 +147,28 @@ impl WhiteboxTool for LidarInfo {
         let mut output_file = String::new();
         let mut show_vlrs = false;
         let mut show_geokeys = false;
-        let mut keyval: bool;
         if args.len() == 0 {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-vlr" {
-                show_vlrs = true;
-            } else if flag_val == "-geokeys" {
-                show_geokeys = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--vlr"])? {
+            show_vlrs = true;
+        }
+        if tool_args.get_flag(&["--geokeys"])? {
+            show_geokeys = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -507,7 +489,7 @@ impl WhiteboxTool for LidarInfo {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
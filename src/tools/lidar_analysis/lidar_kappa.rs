@@ -135,41 +135,19 @@ impl WhiteboxTool for LidarKappaIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i1" || flag_val == "-input1" {
-                input_file1 = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-i2" || flag_val == "-input2" {
-                input_file2 = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input_file1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input_file2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -191,7 +169,7 @@ impl WhiteboxTool for LidarKappaIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let start = Instant::now();
 
@@ -219,7 +197,7 @@ impl WhiteboxTool for LidarKappaIndex {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points2 as f64) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    report::progress("Binning points", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -244,7 +222,7 @@ impl WhiteboxTool for LidarKappaIndex {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points1 as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -568,15 +546,15 @@ impl WhiteboxTool for LidarKappaIndex {
                 let _ = output.stdout;
             }
 
-            println!("Complete!\nPlease see {} for output.", output_file);
+            report::message(&format!("Complete!\nPlease see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
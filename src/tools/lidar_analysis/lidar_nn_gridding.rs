@@ -213,95 +213,45 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-parameter" {
-                if keyval {
-                    interp_parameter = vec[1].to_string();
-                } else {
-                    interp_parameter = args[i + 1].to_string();
-                }
-            } else if flag_val == "-returns" {
-                if keyval {
-                    return_type = vec[1].to_string();
-                } else {
-                    return_type = args[i + 1].to_string();
-                }
-            } else if flag_val == "-resolution" {
-                if keyval {
-                    grid_res = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    grid_res = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-radius" {
-                if keyval {
-                    search_radius = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    search_radius = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-palette" {
-                if keyval {
-                    palette = vec[1].to_string();
-                } else {
-                    palette = args[i + 1].to_string();
-                }
-            } else if flag_val == "-exclude_cls" {
-                if keyval {
-                    exclude_cls_str = vec[1].to_string();
-                } else {
-                    exclude_cls_str = args[i + 1].to_string();
-                }
-                let mut cmd = exclude_cls_str.split(",");
-                let mut vec = cmd.collect::<Vec<&str>>();
-                if vec.len() == 1 {
-                    cmd = exclude_cls_str.split(";");
-                    vec = cmd.collect::<Vec<&str>>();
-                }
-                for value in vec {
-                    if !value.trim().is_empty() {
-                        let c = value.trim().parse::<usize>().unwrap();
-                        include_class_vals[c] = false;
-                    }
-                }
-            } else if flag_val == "-minz" {
-                if keyval {
-                    min_z = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    min_z = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-maxz" {
-                if keyval {
-                    max_z = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_z = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--parameter"])? {
+            interp_parameter = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--returns"])? {
+            return_type = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            search_radius = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--palette"])? {
+            palette = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--exclude_cls"])? {
+            exclude_cls_str = v;
+        }
+        if let Some(classes) = tool_args.get_u8_list(&["--exclude_cls"])? {
+            for c in classes {
+                include_class_vals[c as usize] = false;
             }
         }
+        if let Some(v) = tool_args.get_f64(&["--minz"])? {
+            min_z = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--maxz"])? {
+            max_z = v;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let start = Instant::now();
@@ -330,7 +280,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -410,7 +360,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         // }
 
         if verbose {
-            println!("Performing interpolation...");
+            report::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -457,7 +407,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        report::message("Reading input LAS file...");
                     }
 
                     let mut progress: i32;
@@ -500,7 +450,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -527,7 +480,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -554,7 +510,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -585,7 +544,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -613,7 +575,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                report::progress(
+                                                    "Binning points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -631,7 +596,6 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                         (((bounding_boxes[tile].max_x - west) / grid_res).ceil()) as isize;
                     let south: f64 = north - rows as f64 * grid_res;
                     let east = west + columns as f64 * grid_res;
-                    let nodata = -32768.0f64;
 
                     let mut configs = RasterConfigs {
                         ..Default::default()
@@ -644,12 +608,13 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                     configs.west = west;
                     configs.resolution_x = grid_res;
                     configs.resolution_y = grid_res;
-                    configs.nodata = nodata;
+                    configs.nodata = -32768f64;
                     configs.data_type = DataType::F32;
                     configs.photometric_interp = PhotometricInterpretation::Continuous;
                     configs.palette = palette.clone();
 
                     let mut output = Raster::initialize_using_config(&output_file, &configs);
+                    let nodata = output.configs.nodata;
 
                     if num_tiles > 1 {
                         let (mut x, mut y): (f64, f64);
@@ -679,7 +644,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -729,7 +694,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             if verbose {
                                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -757,7 +722,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                     ));
 
                     if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
 
                     let _ = output.write().unwrap();
@@ -772,7 +737,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
             if verbose {
-                println!(
+                report::message(&format!(
                     "Finished interpolating {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -780,12 +745,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -794,10 +759,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -196,39 +196,27 @@ impl WhiteboxTool for LidarPointStats {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-num_points" {
-                num_points = true;
-            } else if flag_val == "-num_pulses" {
-                num_pulses = true;
-            } else if flag_val == "-z_range" || flag_val == "elev_range" {
-                z_range = true;
-            } else if flag_val == "-intensity_range" || flag_val == "i_range" {
-                intensity_range = true;
-            } else if flag_val == "-predom_class" || flag_val == "-predominant_class" {
-                predominant_class = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if tool_args.get_flag(&["--num_points"])? {
+            num_points = true;
+        }
+        if tool_args.get_flag(&["--num_pulses"])? {
+            num_pulses = true;
+        }
+        if tool_args.get_flag(&["--z_range", "--elev_range"])? {
+            z_range = true;
+        }
+        if tool_args.get_flag(&["--intensity_range", "--i_range"])? {
+            intensity_range = true;
+        }
+        if tool_args.get_flag(&["--predom_class", "--predominant_class"])? {
+            predominant_class = true;
         }
 
         let start = Instant::now();
@@ -249,7 +237,7 @@ impl WhiteboxTool for LidarPointStats {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -262,9 +250,7 @@ impl WhiteboxTool for LidarPointStats {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let num_tiles = inputs.len();
@@ -289,7 +275,7 @@ impl WhiteboxTool for LidarPointStats {
 
                     let input_file = inputs[tile].replace("\"", "").clone();
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        report::message("Reading input LAS file...");
                     }
                     let input = match LasFile::new(&input_file, "r") {
                         Ok(lf) => lf,
@@ -359,7 +345,7 @@ impl WhiteboxTool for LidarPointStats {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -368,7 +354,7 @@ impl WhiteboxTool for LidarPointStats {
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            report::message("Saving data...")
                         };
 
                         if num_points {
@@ -482,7 +468,7 @@ impl WhiteboxTool for LidarPointStats {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -491,7 +477,7 @@ impl WhiteboxTool for LidarPointStats {
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            report::message("Saving data...")
                         };
 
                         if z_range {
@@ -565,7 +551,7 @@ impl WhiteboxTool for LidarPointStats {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    report::progress("Progress", progress as usize);
                                     old_progress = progress;
                                 }
                             }
@@ -574,7 +560,7 @@ impl WhiteboxTool for LidarPointStats {
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            report::message("Saving data...")
                         };
 
                         out_predominant_class.add_metadata_entry(format!(
@@ -602,7 +588,7 @@ impl WhiteboxTool for LidarPointStats {
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
             if verbose {
-                println!(
+                report::message(&format!(
                     "Finished {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -610,12 +596,12 @@ impl WhiteboxTool for LidarPointStats {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -624,10 +610,7 @@ impl WhiteboxTool for LidarPointStats {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
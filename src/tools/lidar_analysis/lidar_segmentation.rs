@@ -168,53 +168,25 @@ impl WhiteboxTool for LidarSegmentation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-dist" || flag_val == "-radius" {
-                if keyval {
-                    search_radius = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    search_radius = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-norm_diff" {
-                if keyval {
-                    max_norm_diff = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_norm_diff = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-maxzdiff" {
-                if keyval {
-                    max_z_diff = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_z_diff = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--dist", "--radius"])? {
+            search_radius = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--norm_diff"])? {
+            max_norm_diff = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--maxzdiff"])? {
+            max_z_diff = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -226,7 +198,7 @@ impl WhiteboxTool for LidarSegmentation {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -254,7 +226,7 @@ impl WhiteboxTool for LidarSegmentation {
         // Calculate the normals for each point in the dataset //
         /////////////////////////////////////////////////////////
         if verbose {
-            println!("Calculating point normals...");
+            report::message("Calculating point normals...");
         }
         let mut frs3d: FixedRadiusSearch3D<usize> =
             FixedRadiusSearch3D::new(search_radius, DistanceMetric::SquaredEuclidean);
@@ -264,7 +236,7 @@ impl WhiteboxTool for LidarSegmentation {
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points in 3D: {}%", progress);
+                    report::progress("Binning points in 3D", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -303,7 +275,7 @@ impl WhiteboxTool for LidarSegmentation {
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
+                    report::progress("Calculating point normals", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -313,7 +285,7 @@ impl WhiteboxTool for LidarSegmentation {
         // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            report::message("Segmenting the point cloud...");
         }
         let mut segment_id = vec![0usize; n_points];
         let mut current_segment = 0usize;
@@ -342,7 +314,7 @@ impl WhiteboxTool for LidarSegmentation {
                 if verbose {
                     progress = (100f64 * solved_points as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Segmenting the point cloud: {}%", progress);
+                        report::progress("Segmenting the point cloud", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -416,7 +388,7 @@ impl WhiteboxTool for LidarSegmentation {
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    report::progress("Saving data", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -442,19 +414,16 @@ impl WhiteboxTool for LidarSegmentation {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        report::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -161,44 +161,22 @@ impl WhiteboxTool for LidarThin {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-method" {
-                method = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-                method = method.to_lowercase();
-            } else if flag_val == "-save_filtered" {
-                save_filtered = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v;
+            method = method.to_lowercase();
+        }
+        if tool_args.get_flag(&["--save_filtered"])? {
+            save_filtered = true;
         }
 
         let start = Instant::now();
@@ -216,18 +194,16 @@ impl WhiteboxTool for LidarThin {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
-        println!("Reading input LAS file...");
+        report::message("Reading input LAS file...");
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
             Err(err) => panic!("Error reading file {}: {}", input_file, err),
         };
 
-        println!("Performing analysis...");
+        report::message("Performing analysis...");
 
         // Make sure that the input LAS file have GPS time data?
         if input.header.point_format == 0u8 || input.header.point_format == 2u8 {
@@ -269,7 +245,7 @@ impl WhiteboxTool for LidarThin {
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -292,7 +268,7 @@ impl WhiteboxTool for LidarThin {
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -317,7 +293,7 @@ impl WhiteboxTool for LidarThin {
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -342,7 +318,7 @@ impl WhiteboxTool for LidarThin {
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -378,7 +354,7 @@ impl WhiteboxTool for LidarThin {
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress);
                             old_progress = progress;
                         }
                     }
@@ -409,7 +385,7 @@ impl WhiteboxTool for LidarThin {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -433,7 +409,7 @@ impl WhiteboxTool for LidarThin {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -442,20 +418,20 @@ impl WhiteboxTool for LidarThin {
             end = get_formatted_elapsed_time(start);
 
             let _ = match filtered_output.write() {
-                Ok(_) => println!("Filtered points LAS file saved"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => report::message("Filtered points LAS file saved"),
+                Err(e) => report::message(&format!("error while writing: {:?}", e)),
             };
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time (excluding I/O): {}", end));
+            report::metadata("Elapsed Time (excluding I/O)", &end);
         }
 
         Ok(())
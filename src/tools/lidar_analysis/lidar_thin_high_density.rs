@@ -156,49 +156,25 @@ impl WhiteboxTool for LidarThinHighDensity {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-resolution" {
-                grid_res = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-density" {
-                density = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-save_filtered" {
-                save_filtered = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--density"])? {
+            density = v;
+        }
+        if tool_args.get_flag(&["--save_filtered"])? {
+            save_filtered = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -210,7 +186,7 @@ impl WhiteboxTool for LidarThinHighDensity {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -220,7 +196,7 @@ impl WhiteboxTool for LidarThinHighDensity {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         let west: f64 = input.header.min_x;
@@ -257,7 +233,7 @@ impl WhiteboxTool for LidarThinHighDensity {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    report::progress("Binning points", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -317,7 +293,7 @@ impl WhiteboxTool for LidarThinHighDensity {
                     if verbose {
                         progress = (100.0_f64 * num_solved_points as f64 / num_points) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress as usize);
                             old_progress = progress;
                         }
                     }
@@ -339,7 +315,7 @@ impl WhiteboxTool for LidarThinHighDensity {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -363,7 +339,7 @@ impl WhiteboxTool for LidarThinHighDensity {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        report::progress("Saving data", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -372,20 +348,20 @@ impl WhiteboxTool for LidarThinHighDensity {
             end = get_formatted_elapsed_time(start);
 
             let _ = match filtered_output.write() {
-                Ok(_) => println!("Filtered points LAS file saved"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => report::message("Filtered points LAS file saved"),
+                Err(e) => report::message(&format!("error while writing: {:?}", e)),
             };
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time (excluding I/O): {}", end));
+            report::metadata("Elapsed Time (excluding I/O)", &end);
         }
 
         Ok(())
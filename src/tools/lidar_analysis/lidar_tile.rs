@@ -2,19 +2,52 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 26, 2017
-Last Modified: 29/08/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 use lidar::*;
-use std;
+use std::collections::HashMap;
 use std::env;
+use std::fs;
 use std::fs::DirBuilder;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::path::Path;
 use tools::*;
 
-/// Tiles a LiDAR LAS file into multiple LAS files.
+/// This tool splits one or more LiDAR LAS files (`--input`) into a regular grid of tiles,
+/// each of which is saved as a separate LAS file. The tile grid is defined by the tile widths
+/// in the x and y dimensions (`--width_x` and `--width_y`) and the coordinates of a point on
+/// the grid (`--origin_x` and `--origin_y`). Only tiles containing more than the minimum number
+/// of points (`--min_points`) are saved.
+///
+/// When multiple input files are specified, or when no input is specified, in which case all
+/// of the LAS files in the working directory are used, the points of all of the inputs are
+/// pooled before tiling. This joins and re-tiles an arbitrary collection of LAS files, e.g.
+/// flight lines or tiles of a different size, onto a single regular grid, such that the
+/// tiles can be processed in parallel by the tools that accept a working directory of LAS
+/// files, e.g. `LidarIdwInterpolation`, `LidarTINGridding`, and `LidarNearestNeighbourGridding`.
+/// All of the input files must have the same point format.
+///
+/// Each tile may optionally include the points within a buffer distance (`--buffer`) beyond
+/// its edges, such that the tiles overlap. This avoids the edge effects that would otherwise
+/// occur along tile boundaries when the tiles are interpolated individually. Buffer points
+/// are duplicated among the neighbouring tiles and are not flagged, and the minimum number of
+/// points is evaluated using the points within the tile's edges only.
+///
+/// Tiles are saved in the output directory (`--output_dir`), which by default is a
+/// sub-directory of the input file's directory named after the input file, or a `tiles`
+/// sub-directory of the working directory for multiple inputs. Output file names are
+/// created from a naming template (`--name_template`; by default `{name}_row{row}_col{col}`)
+/// in which `{name}` is replaced by the input file name (or `tile` for multiple inputs),
+/// `{row}` and `{col}` by the tile's row and column numbers, starting at 1 from the
+/// lower-left tile, and `{x}` and `{y}` by the coordinates of the tile's lower-left corner.
+/// The `.las` extension is added to the file names.
+///
+/// Notice that all of the points are held in memory during tiling.
+///
+/// # See Also
+/// `LidarJoin`, `LidarTileFootprint`, `LidarClip`
 pub struct LidarTile {
     name: String,
     description: String,
@@ -28,16 +61,27 @@ impl LidarTile {
         // public constructor
         let name = "LidarTile".to_string();
         let toolbox = "LiDAR Tools".to_string();
-        let description = "Tiles a LiDAR LAS file into multiple LAS files.".to_string();
+        let description =
+            "Tiles one or more LiDAR LAS files into a grid of multiple, optionally overlapping, LAS files."
+                .to_string();
 
         let mut parameters = vec![];
         parameters.push(ToolParameter {
-            name: "Input File".to_owned(),
+            name: "Input File(s)".to_owned(),
             flags: vec!["-i".to_owned(), "--input".to_owned()],
-            description: "Input LiDAR file.".to_owned(),
-            parameter_type: ParameterType::ExistingFile(ParameterFileType::Lidar),
+            description: "Input LiDAR file(s); all of the LAS files in the working directory if unspecified.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Lidar),
             default_value: None,
-            optional: false,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Directory".to_owned(),
+            flags: vec!["--output_dir".to_owned()],
+            description: "Output directory.".to_owned(),
+            parameter_type: ParameterType::Directory,
+            default_value: None,
+            optional: true,
         });
 
         parameters.push(ToolParameter {
@@ -86,6 +130,24 @@ impl LidarTile {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Buffer Distance".to_owned(),
+            flags: vec!["--buffer".to_owned()],
+            description: "Distance beyond the edges of each tile within which points are also included in the tile.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File Name Template".to_owned(),
+            flags: vec!["--name_template".to_owned()],
+            description: "Output file name template, using {name}, {row}, {col}, {x}, and {y} placeholders.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: Some("{name}_row{row}_col{col}".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -97,7 +159,8 @@ impl LidarTile {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v -i=*path*to*data*input.las --width_x=1000.0 --width_y=2500.0 -=min_points=100", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v -i=*path*to*data*input.las --width_x=1000.0 --width_y=2500.0 --min_points=100
+>>.*{0} -r={1} -v --wd=*path*to*data* -i='flightline1.las;flightline2.las' --output_dir=tiles --width_x=500.0 --width_y=500.0 --buffer=10.0 --name_template='tile_{{x}}_{{y}}'", short_exe, name).replace("*", &sep);
 
         LidarTile {
             name: name,
@@ -150,12 +213,15 @@ impl WhiteboxTool for LidarTile {
         working_directory: &'a str,
         verbose: bool,
     ) -> Result<(), Error> {
-        let mut input_file: String = String::new();
+        let mut input_files = String::new();
+        let mut output_dir = String::new();
         let mut width_x = 1000.0;
         let mut width_y = 1000.0;
         let mut origin_x = 0.0;
         let mut origin_y = 0.0;
         let mut min_points = 2;
+        let mut buffer = 0f64;
+        let mut name_template = "{name}_row{row}_col{col}".to_string();
 
         // read the arguments
         if args.len() == 0 {
@@ -164,229 +230,263 @@ impl WhiteboxTool for LidarTile {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-width_x" {
-                if keyval {
-                    width_x = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    width_x = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-width_y" {
-                if keyval {
-                    width_y = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    width_y = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-origin_x" {
-                if keyval {
-                    origin_x = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    origin_x = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-origin_y" {
-                if keyval {
-                    origin_y = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    origin_y = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-min_points" {
-                if keyval {
-                    min_points = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    min_points = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--output_dir"])? {
+            output_dir = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--width_x"])? {
+            width_x = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--width_y"])? {
+            width_y = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--origin_x"])? {
+            origin_x = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--origin_y"])? {
+            origin_y = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--min_points"])? {
+            min_points = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--buffer"])? {
+            buffer = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--name_template"])? {
+            name_template = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
-        let sep = std::path::MAIN_SEPARATOR;
+        let sep: String = path::MAIN_SEPARATOR.to_string();
 
+        if width_x <= 0f64 || width_y <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The tile widths must be greater than zero.",
+            ));
+        }
+        if buffer < 0f64 {
+            buffer = 0f64;
+        }
         if min_points < 2 {
             min_points = 2;
         }
-
-        if !input_file.contains(sep) && !input_file.contains("/") {
-            input_file = format!("{}{}", working_directory, input_file);
+        if !(name_template.contains("{row}") && name_template.contains("{col}"))
+            && !(name_template.contains("{x}") && name_template.contains("{y}"))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The name template must contain either the {row} and {col} or the {x} and {y} placeholders.",
+            ));
         }
 
-        if verbose {
-            println!("Performing analysis...");
+        let mut inputs = vec![];
+        if input_files.trim().is_empty() {
+            if working_directory.is_empty() {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                    "This tool must be run by specifying either an individual input file or a working directory."));
+            }
+            match fs::read_dir(working_directory) {
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
+                Ok(paths) => for path in paths {
+                    let s = format!("{}", path.unwrap().path().display());
+                    if s.to_lowercase().ends_with(".las") {
+                        inputs.push(s);
+                    }
+                },
+            }
+            inputs.sort();
+        } else {
+            let mut vec = input_files.split(";").collect::<Vec<&str>>();
+            if vec.len() == 1 {
+                vec = input_files.split(",").collect::<Vec<&str>>();
+            }
+            for value in vec {
+                let mut input_file = value.trim().to_owned();
+                if input_file.is_empty() {
+                    continue;
+                }
+                if !input_file.contains(&sep) && !input_file.contains("/") {
+                    input_file = format!("{}{}", working_directory, input_file);
+                }
+                inputs.push(input_file);
+            }
         }
-
-        let input = match LasFile::new(&input_file, "r") {
-            Ok(lf) => lf,
-            Err(err) => panic!("Error reading file {}: {}", input_file, err),
-        };
-
-        let min_x = input.header.min_x;
-        let max_x = input.header.max_x;
-        let min_y = input.header.min_y;
-        let max_y = input.header.max_y;
-
-        let n_points = input.header.number_of_points as usize;
-        let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
-
-        let start_x_grid = ((min_x - origin_x) / width_x).floor();
-        let end_x_grid = ((max_x - origin_x) / width_x).ceil();
-        let start_y_grid = ((min_y - origin_y) / width_y).floor();
-        let end_y_grid = ((max_y - origin_y) / width_y).ceil();
-        let cols = (end_x_grid - start_x_grid).abs() as usize;
-        let rows = (end_y_grid - start_y_grid).abs() as usize;
-        let num_tiles = rows * cols;
-
-        if num_tiles > 32767usize {
+        if inputs.is_empty() {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "There are too many output tiles. Try choosing a larger grid width.",
+                "No input LAS files were found.",
             ));
         }
 
-        let mut tile_data = vec![0usize; n_points];
-
-        let (mut col, mut row): (usize, usize);
-        let mut progress: i32;
-        let mut old_progress: i32 = -1;
-        for i in 0..n_points {
-            let p: PointData = input[i];
-            col = (((p.x - origin_x) / width_x) - start_x_grid).floor() as usize; // relative to the grid edge
-            row = (((p.y - origin_y) / width_y) - start_y_grid).floor() as usize; // relative to the grid edge
-            tile_data[i] = row * cols + col;
-
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+        // the name of the tiles and the default output directory
+        let name: String = if inputs.len() == 1 {
+            match Path::new(&inputs[0]).file_stem().unwrap().to_str() {
+                Some(n) => n.to_string(),
+                None => "".to_string(),
             }
+        } else {
+            "tile".to_string()
+        };
+        if output_dir.is_empty() {
+            output_dir = if inputs.len() == 1 {
+                let dir: String = match Path::new(&inputs[0]).parent().unwrap().to_str() {
+                    Some(n) => n.to_string(),
+                    None => "".to_string(),
+                };
+                format!("{}{}{}", dir, sep, name)
+            } else {
+                format!("{}tiles", working_directory)
+            };
+        } else if !output_dir.contains(&sep) && !output_dir.contains("/") {
+            output_dir = format!("{}{}", working_directory, output_dir);
+        }
+        if !output_dir.ends_with(&sep) && !output_dir.ends_with("/") {
+            output_dir.push_str(&sep);
+        }
+
+        if verbose {
+            report::message("Performing analysis...");
         }
 
-        // figure out the last point for each tile, so that the shapefile can be closed afterwards
-        let n_points_plus_one = n_points + 1;
-        let mut first_point_num = vec![n_points_plus_one; num_tiles];
-        let mut last_point_num = vec![0usize; num_tiles];
-        let mut num_points_in_tile = vec![0usize; num_tiles];
-
-        for i in 0..n_points {
-            last_point_num[tile_data[i]] = i;
-            num_points_in_tile[tile_data[i]] += 1;
-            if first_point_num[tile_data[i]] == n_points_plus_one {
-                first_point_num[tile_data[i]] = i;
+        // Distribute the points of each input among the tiles, identified by their grid
+        // column and row relative to the origin. Each tile keeps a header-only copy of the
+        // first input that contributed to it, from which the output file is initialized.
+        let mut templates: Vec<LasFile> = vec![];
+        let mut tiles: HashMap<(isize, isize), (usize, Vec<LidarPointRecord>, usize)> =
+            HashMap::new();
+        let mut point_format = -1i32;
+        let mut progress: i32;
+        let mut old_progress: i32 = -1;
+        for (file_num, input_file) in inputs.iter().enumerate() {
+            if verbose && inputs.len() > 1 {
+                report::message(&format!(
+                    "Reading {} of {}: {}",
+                    file_num + 1,
+                    inputs.len(),
+                    input_file
+                ));
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
-                    old_progress = progress;
+            let input = match LasFile::new(&input_file, "r") {
+                Ok(lf) => lf,
+                Err(err) => {
+                    return Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("Error reading file {}: {}", input_file, err),
+                    ))
                 }
+            };
+            if point_format == -1 {
+                point_format = input.header.point_format as i32;
+            } else if input.header.point_format as i32 != point_format {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "All of the input files must have the same point format.",
+                ));
             }
-        }
+            templates.push(LasFile::initialize_using_file("", &input));
+
+            let n_points = input.header.number_of_points as usize;
+            for i in 0..n_points {
+                let p: PointData = input[i];
+                let col = ((p.x - origin_x) / width_x).floor() as isize;
+                let row = ((p.y - origin_y) / width_y).floor() as isize;
+                let (start_col, end_col, start_row, end_row) = if buffer > 0f64 {
+                    (
+                        ((p.x - buffer - origin_x) / width_x).floor() as isize,
+                        ((p.x + buffer - origin_x) / width_x).floor() as isize,
+                        ((p.y - buffer - origin_y) / width_y).floor() as isize,
+                        ((p.y + buffer - origin_y) / width_y).floor() as isize,
+                    )
+                } else {
+                    (col, col, row, row)
+                };
+                for r in start_row..=end_row {
+                    for c in start_col..=end_col {
+                        let tile = tiles
+                            .entry((c, r))
+                            .or_insert((file_num, vec![], 0usize));
+                        tile.1.push(input.get_record(i));
+                        if r == row && c == col {
+                            tile.2 += 1;
+                        }
+                    }
+                }
 
-        let mut output_tile = vec![false; num_tiles];
-        for tile_num in 0..num_tiles {
-            if num_points_in_tile[tile_num] > min_points {
-                output_tile[tile_num] = true;
+                if verbose {
+                    progress = (100.0_f64 * (i + 1) as f64 / n_points as f64) as i32;
+                    if progress != old_progress {
+                        report::progress("Progress (Loop 1 of 2)", progress as usize);
+                        old_progress = progress;
+                    }
+                }
             }
         }
 
-        let mut min_row = 999999;
-        let mut min_col = 999999;
-        for tile_num in 0..num_tiles {
-            if output_tile[tile_num] {
-                row = (tile_num as f64 / cols as f64).floor() as usize;
-                col = tile_num % cols;
-                if row < min_row {
-                    min_row = row;
-                }
-                if col < min_col {
-                    min_col = col;
-                }
-            }
+        // only save the tiles that contain enough points within their edges
+        let mut keys: Vec<(isize, isize)> = tiles
+            .iter()
+            .filter(|&(_, tile)| tile.2 > min_points)
+            .map(|(&key, _)| key)
+            .collect();
+        if keys.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "Error: No tiles were created.",
+            ));
         }
+        keys.sort_by(|a, b| (a.1, a.0).cmp(&(b.1, b.0)));
+        let min_col = keys.iter().map(|k| k.0).min().unwrap();
+        let min_row = keys.iter().map(|k| k.1).min().unwrap();
 
-        let sep: String = path::MAIN_SEPARATOR.to_string();
-        let name: String = match Path::new(&input_file).file_stem().unwrap().to_str() {
-            Some(n) => n.to_string(),
-            None => "".to_string(),
-        };
-        let dir: String = match Path::new(&input_file).parent().unwrap().to_str() {
-            Some(n) => n.to_string(),
-            None => "".to_string(),
-        };
-        let output_dir: String = format!("{}{}{}{}", dir.to_string(), sep, name, sep);
-        DirBuilder::new()
-            .recursive(true)
-            .create(output_dir.clone())
-            .unwrap();
+        DirBuilder::new().recursive(true).create(output_dir.clone())?;
+        let num_tiles = keys.len();
         let mut num_tiles_created = 0;
-        for tile_num in 0..num_tiles {
-            if output_tile[tile_num] {
-                row = (tile_num as f64 / cols as f64).floor() as usize;
-                col = tile_num % cols;
-                let output_file = format!(
-                    "{}{}_row{}_col{}.las",
-                    output_dir,
-                    name,
-                    row - min_row + 1,
-                    col - min_col + 1
-                );
-                let mut output = LasFile::initialize_using_file(&output_file, &input);
-                output.header.system_id = "EXTRACTION".to_string();
-
-                for i in first_point_num[tile_num]..last_point_num[tile_num] {
-                    if tile_data[i] == tile_num {
-                        output.add_point_record(input.get_record(i));
-                    }
-                }
-                let _ = match output.write() {
-                    Ok(_) => (), // do nothing
-                    Err(e) => {
-                        return Err(Error::new(
-                            ErrorKind::Other,
-                            format!("Error while writing: {:?}", e),
-                        ))
-                    }
-                };
-                num_tiles_created += 1;
+        for (tile_num, key) in keys.iter().enumerate() {
+            let (template, records, _) = tiles.remove(key).unwrap();
+            let (col, row) = *key;
+            let file_name = name_template
+                .replace("{name}", &name)
+                .replace("{row}", &format!("{}", row - min_row + 1))
+                .replace("{col}", &format!("{}", col - min_col + 1))
+                .replace("{x}", &format!("{}", origin_x + col as f64 * width_x))
+                .replace("{y}", &format!("{}", origin_y + row as f64 * width_y));
+            let output_file = format!("{}{}.las", output_dir, file_name);
+            let mut output = LasFile::initialize_using_file(&output_file, &templates[template]);
+            output.header.system_id = "EXTRACTION".to_string();
+            for record in records {
+                output.add_point_record(record);
             }
+            let _ = match output.write() {
+                Ok(_) => (), // do nothing
+                Err(e) => {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        format!("Error while writing: {:?}", e),
+                    ))
+                }
+            };
+            num_tiles_created += 1;
 
             if verbose {
-                progress = (100.0_f64 * tile_num as f64 / (num_tiles - 1) as f64) as i32;
+                progress = (100.0_f64 * (tile_num + 1) as f64 / num_tiles as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress as usize);
                     old_progress = progress;
                 }
             }
         }
 
-        if num_tiles_created > 0 {
-            if verbose {
-                println!("Successfully created {} tiles.", num_tiles_created);
-            }
-        } else if num_tiles_created == 0 {
-            return Err(Error::new(
-                ErrorKind::Other,
-                "Error: No tiles were created.",
+        if verbose {
+            report::message(&format!(
+                "Successfully created {} tiles in {}",
+                num_tiles_created, output_dir
             ));
         }
 
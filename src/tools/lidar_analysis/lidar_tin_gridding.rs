@@ -195,83 +195,39 @@ impl WhiteboxTool for LidarTINGridding {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-parameter" {
-                if keyval {
-                    interp_parameter = vec[1].to_string();
-                } else {
-                    interp_parameter = args[i + 1].to_string();
-                }
-            } else if flag_val == "-returns" {
-                if keyval {
-                    return_type = vec[1].to_string();
-                } else {
-                    return_type = args[i + 1].to_string();
-                }
-            } else if flag_val == "-resolution" {
-                if keyval {
-                    grid_res = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    grid_res = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-exclude_cls" {
-                if keyval {
-                    exclude_cls_str = vec[1].to_string();
-                } else {
-                    exclude_cls_str = args[i + 1].to_string();
-                }
-                let mut cmd = exclude_cls_str.split(",");
-                let mut vec = cmd.collect::<Vec<&str>>();
-                if vec.len() == 1 {
-                    cmd = exclude_cls_str.split(";");
-                    vec = cmd.collect::<Vec<&str>>();
-                }
-                for value in vec {
-                    if !value.trim().is_empty() {
-                        let c = value.trim().parse::<usize>().unwrap();
-                        include_class_vals[c] = false;
-                    }
-                }
-            } else if flag_val == "-minz" {
-                if keyval {
-                    min_z = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    min_z = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if flag_val == "-maxz" {
-                if keyval {
-                    max_z = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_z = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--parameter"])? {
+            interp_parameter = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--returns"])? {
+            return_type = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--resolution"])? {
+            grid_res = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--exclude_cls"])? {
+            exclude_cls_str = v;
+        }
+        if let Some(classes) = tool_args.get_u8_list(&["--exclude_cls"])? {
+            for c in classes {
+                include_class_vals[c as usize] = false;
             }
         }
+        if let Some(v) = tool_args.get_f64(&["--minz"])? {
+            min_z = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--maxz"])? {
+            max_z = v;
+        }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let start = Instant::now();
@@ -302,7 +258,7 @@ impl WhiteboxTool for LidarTINGridding {
                     "This tool must be run by specifying either an individual input file or a working directory."));
             }
             match fs::read_dir(working_directory) {
-                Err(why) => println!("! {:?}", why.kind()),
+                Err(why) => report::message(&format!("! {:?}", why.kind())),
                 Ok(paths) => for path in paths {
                     let s = format!("{:?}", path.unwrap().path());
                     if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -357,7 +313,7 @@ impl WhiteboxTool for LidarTINGridding {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            report::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -405,7 +361,7 @@ impl WhiteboxTool for LidarTINGridding {
                     let mut z_values = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        report::message("Reading input LAS file...");
                     }
 
                     let mut progress: i32;
@@ -449,7 +405,10 @@ impl WhiteboxTool for LidarTINGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                report::progress(
+                                                    "Reading points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -477,7 +436,10 @@ impl WhiteboxTool for LidarTINGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                report::progress(
+                                                    "Reading points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -505,7 +467,10 @@ impl WhiteboxTool for LidarTINGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                report::progress(
+                                                    "Reading points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -533,7 +498,10 @@ impl WhiteboxTool for LidarTINGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                report::progress(
+                                                    "Reading points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -562,7 +530,10 @@ impl WhiteboxTool for LidarTINGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                report::progress(
+                                                    "Reading points",
+                                                    progress as usize,
+                                                );
                                                 old_progress = progress;
                                             }
                                         }
@@ -601,7 +572,7 @@ impl WhiteboxTool for LidarTINGridding {
 
                     // do the triangulation
                     if num_tiles == 1 && verbose {
-                        println!("Performing triangulation...");
+                        report::message("Performing triangulation...");
                     }
                     let result = triangulate(&points).expect("No triangulation exists.");
                     let num_triangles = result.triangles.len() / 3;
@@ -682,7 +653,7 @@ impl WhiteboxTool for LidarTINGridding {
                             progress =
                                 (100.0_f64 * triangle as f64 / (num_triangles - 1) as f64) as i32;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                report::progress("Progress", progress as usize);
                                 old_progress = progress;
                             }
                         }
@@ -708,7 +679,7 @@ impl WhiteboxTool for LidarTINGridding {
                     ));
 
                     if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
+                        report::message("Saving data...")
                     };
 
                     let _ = output.write().unwrap();
@@ -723,7 +694,7 @@ impl WhiteboxTool for LidarTINGridding {
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
             if verbose {
-                println!(
+                report::message(&format!(
                     "Finished interpolating {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -731,12 +702,12 @@ impl WhiteboxTool for LidarTINGridding {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -745,10 +716,7 @@ impl WhiteboxTool for LidarTINGridding {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -11,9 +11,13 @@ mod las_to_ascii;
 mod las_to_multipoint_shapefile;
 mod las_to_shapefile;
 mod lidar_classify_subset;
+mod lidar_clip;
 mod lidar_colourize;
 mod lidar_construct_vector_tin;
+mod lidar_eigenvalue_features;
 mod lidar_elevation_slice;
+mod lidar_erase;
+mod lidar_grid_stats;
 mod lidar_ground_point_filter;
 mod lidar_hex_bin;
 mod lidar_hillshade;
@@ -44,16 +48,20 @@ pub use self::block_minimum::LidarBlockMinimum;
 pub use self::classify_overlap_points::ClassifyOverlapPoints;
 pub use self::clip_lidar_to_polygon::ClipLidarToPolygon;
 pub use self::erase_polygon_from_lidar::ErasePolygonFromLidar;
-pub use self::filter_lidar_scan_angles::FilterLidarScanAngles;
+pub use self::filter_lidar_scan_angles::{filter_lidar_scan_angles, FilterLidarScanAngles};
 pub use self::find_flightline_edge_points::FindFlightlineEdgePoints;
 pub use self::flightline_overlap::FlightlineOverlap;
 pub use self::las_to_ascii::LasToAscii;
 pub use self::las_to_multipoint_shapefile::LasToMultipointShapefile;
 pub use self::las_to_shapefile::LasToShapefile;
 pub use self::lidar_classify_subset::LidarClassifySubset;
+pub use self::lidar_clip::LidarClip;
 pub use self::lidar_colourize::LidarColourize;
 pub use self::lidar_construct_vector_tin::LidarConstructVectorTIN;
+pub use self::lidar_eigenvalue_features::LidarEigenvalueFeatures;
 pub use self::lidar_elevation_slice::LidarElevationSlice;
+pub use self::lidar_erase::LidarErase;
+pub use self::lidar_grid_stats::LidarGridStats;
 pub use self::lidar_ground_point_filter::LidarGroundPointFilter;
 pub use self::lidar_hex_bin::LidarHexBinning;
 pub use self::lidar_hillshade::LidarHillshade;
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 26, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -17,7 +17,7 @@ use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
-use structures::{DistanceMetric, FixedRadiusSearch3D};
+use structures::{DistanceMetric, KdTree};
 use tools::*;
 
 /// Calculates normal vectors for points within a LAS file and stores these data (XYZ vector components) in the RGB field.
@@ -139,41 +139,19 @@ impl WhiteboxTool for NormalVectors {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-radius" {
-                if keyval {
-                    search_radius = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    search_radius = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--radius"])? {
+            search_radius = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -185,7 +163,7 @@ impl WhiteboxTool for NormalVectors {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -195,7 +173,7 @@ impl WhiteboxTool for NormalVectors {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -203,15 +181,14 @@ impl WhiteboxTool for NormalVectors {
 
         let mut progress: i32;
         let mut old_progress: i32 = -1;
-        let mut frs: FixedRadiusSearch3D<usize> =
-            FixedRadiusSearch3D::new(search_radius, DistanceMetric::SquaredEuclidean);
+        let mut coords = Vec::with_capacity(n_points * 3);
         for i in 0..n_points {
             let p: PointData = input.get_point_info(i);
-            frs.insert(p.x, p.y, p.z, i);
+            coords.extend_from_slice(&[p.x, p.y, p.z]);
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    report::progress("Reading points", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -219,19 +196,24 @@ impl WhiteboxTool for NormalVectors {
 
         let mut normal_values: Vec<Vector3<f64>> = vec![Vector3::new(0.0, 0.0, 0.0); n_points];
 
-        let frs = Arc::new(frs); // wrap FRS in an Arc
+        let tree = Arc::new(KdTree::new(
+            3,
+            coords,
+            (0..n_points).collect(),
+            DistanceMetric::SquaredEuclidean,
+        ));
         let input = Arc::new(input); // wrap input in an Arc
         let num_procs = num_cpus::get();
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
-            let frs = frs.clone();
+            let tree = tree.clone();
             let input = input.clone();
             let tx = tx.clone();
             thread::spawn(move || {
                 let mut index_n: usize;
                 for i in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
                     let p: PointData = input.get_point_info(i);
-                    let ret = frs.search(p.x, p.y, p.z);
+                    let ret = tree.radius_search(&[p.x, p.y, p.z], search_radius);
                     let mut data: Vec<Vector3<f64>> = Vec::with_capacity(ret.len());
                     for j in 0..ret.len() {
                         index_n = ret[j].0;
@@ -249,7 +231,7 @@ impl WhiteboxTool for NormalVectors {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
+                    report::progress("Calculating point normals", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -280,7 +262,7 @@ impl WhiteboxTool for NormalVectors {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    report::progress("Saving data", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -288,19 +270,16 @@ impl WhiteboxTool for NormalVectors {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        report::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: March 1, 2018
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -15,7 +15,7 @@ use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
-use structures::{DistanceMetric, FixedRadiusSearch2D};
+use structures::{DistanceMetric, KdTree};
 use tools::*;
 
 /// Removes duplicate points from a LiDAR data set.
@@ -141,37 +141,19 @@ impl WhiteboxTool for LidarRemoveDuplicates {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-include_z" {
-                include_z = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--include_z"])? {
+            include_z = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -183,7 +165,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            report::message("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -193,7 +175,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            report::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -201,26 +183,30 @@ impl WhiteboxTool for LidarRemoveDuplicates {
 
         let mut progress: i32;
         let mut old_progress: i32 = -1;
-        let mut frs: FixedRadiusSearch2D<usize> =
-            FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
+        let mut coords = Vec::with_capacity(n_points * 2);
         for i in 0..n_points {
             let p: PointData = input.get_point_info(i);
-            frs.insert(p.x, p.y, i);
+            coords.extend_from_slice(&[p.x, p.y]);
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    report::progress("Reading points", progress as usize);
                     old_progress = progress;
                 }
             }
         }
 
-        let frs = Arc::new(frs); // wrap FRS in an Arc
+        let tree = Arc::new(KdTree::new(
+            2,
+            coords,
+            (0..n_points).collect(),
+            DistanceMetric::SquaredEuclidean,
+        ));
         let input = Arc::new(input); // wrap input in an Arc
         let num_procs = num_cpus::get();
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
-            let frs = frs.clone();
+            let tree = tree.clone();
             let input = input.clone();
             let tx = tx.clone();
             thread::spawn(move || {
@@ -228,7 +214,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
                 let mut dup: bool;
                 for point_num in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
                     let p: PointData = input.get_point_info(point_num);
-                    let ret = frs.search(p.x, p.y);
+                    let ret = tree.radius_search(&[p.x, p.y], search_radius);
                     dup = false;
                     for j in 0..ret.len() {
                         index_n = ret[j].0;
@@ -259,7 +245,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Erosion: {}%", progress);
+                    report::progress("Erosion", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -276,7 +262,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    report::progress("Saving data", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -285,17 +271,14 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            report::message("Writing output LAS file...");
         }
         let _ = match output.write() {
-            Ok(_) => println!("Complete!"),
-            Err(e) => println!("error while writing: {:?}", e),
+            Ok(_) => report::message("Complete!"),
+            Err(e) => report::message(&format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -144,35 +144,15 @@ impl WhiteboxTool for SelectTilesByPolygon {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-indir" {
-                input_directory = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-outdir" {
-                output_directory = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-polygon" || flag_val == "-polygons" {
-                polygons_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--indir"])? {
+            input_directory = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--outdir"])? {
+            output_directory = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--polygon", "--polygons"])? {
+            polygons_file = v;
         }
 
         let start = Instant::now();
@@ -186,15 +166,13 @@ impl WhiteboxTool for SelectTilesByPolygon {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let mut inputs = vec![];
 
         match fs::read_dir(input_directory.clone()) {
-            Err(why) => println!("{:?}", why.kind()),
+            Err(why) => report::message(&format!("{:?}", why.kind())),
             Ok(paths) => for path in paths {
                 let s = format!("{:?}", path.unwrap().path());
                 if s.replace("\"", "").to_lowercase().ends_with(".las") {
@@ -336,11 +314,11 @@ impl WhiteboxTool for SelectTilesByPolygon {
                             .clone();
 
                         match fs::copy(input_file.clone(), output_file.clone()) {
-                            Ok(_) => println!(
+                            Ok(_) => report::message(&format!(
                                 "Copied \"{}\" to \"{}\"",
                                 input_file.replace(&input_directory, "").clone(),
                                 output_directory.clone()
-                            ),
+                            )),
                             Err(e) => panic!("Error copying file {} \n{}", input_file, e),
                         }
                     }
@@ -361,7 +339,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -370,9 +348,9 @@ impl WhiteboxTool for SelectTilesByPolygon {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Number of files copied: {}", num_files_copied);
+            report::metadata("Number of files copied", &num_files_copied.to_string());
 
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::metadata("Elapsed Time", &elapsed_time);
         }
 
         Ok(())
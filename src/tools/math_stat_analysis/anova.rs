@@ -141,41 +141,19 @@ impl WhiteboxTool for Anova {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-features" || vec[0].to_lowercase() == "--features"
-            {
-                if keyval {
-                    feature_file = vec[1].to_string();
-                } else {
-                    feature_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--features"])? {
+            feature_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -197,7 +175,7 @@ impl WhiteboxTool for Anova {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -357,7 +335,7 @@ impl WhiteboxTool for Anova {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (loop 1 of 2): {}%", progress);
+                    report::progress("Progress (loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -393,7 +371,7 @@ impl WhiteboxTool for Anova {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (loop 2 of 2): {}%", progress);
+                    report::progress("Progress (loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -430,7 +408,7 @@ impl WhiteboxTool for Anova {
         let ms_w = ss_w / df_w as f64;
         let f = ms_b / ms_w;
 
-        println!("Calclating the p-value...");
+        report::message("Calclating the p-value...");
         // Formula Used:
         // P value = [ 1 / Β(ndf/2,ddf/2) ] × [ (ndf × x) / (ndf × x + ddf) ]^ndf/2 × [1 - (ndf × x) / (ndf × x + ddf) ]^ddf/2 × x-1
         // Where,
@@ -441,7 +419,7 @@ impl WhiteboxTool for Anova {
         // from: https://www.easycalculation.com/statistics/f-test-p-value.php
         let p = f_call(f_spin(f, df_b, df_w));
 
-        println!("Saving output...");
+        report::message("Saving output...");
 
         s = "<br><table align=\"center\">
         <caption>Group Summaries</caption>
@@ -641,15 +619,15 @@ impl WhiteboxTool for Anova {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
@@ -135,35 +135,16 @@ impl WhiteboxTool for AttributeCorrelation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -189,7 +170,7 @@ impl WhiteboxTool for AttributeCorrelation {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            report::message("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -216,7 +197,7 @@ impl WhiteboxTool for AttributeCorrelation {
         let mut field_averages = vec![0f64; num_fields];
         let mut correlation_matrix = vec![vec![-99f64; num_fields]; num_fields];
         if verbose {
-            println!("Calculating attribute averages...");
+            report::message("Calculating attribute averages...");
         }
 
         for record_num in 0..vector_data.num_records {
@@ -247,7 +228,7 @@ impl WhiteboxTool for AttributeCorrelation {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -260,7 +241,7 @@ impl WhiteboxTool for AttributeCorrelation {
         }
 
         if verbose {
-            println!("Calculating the correlation matrix:");
+            report::message("Calculating the correlation matrix:");
         }
         let mut i = 0;
         let nodata = -32768f64;
@@ -310,11 +291,13 @@ impl WhiteboxTool for AttributeCorrelation {
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_fields - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Calculating the correlation matrix ({} of {}): {}%",
-                        (a + 1),
-                        num_fields,
-                        progress
+                    report::progress(
+                        &format!(
+                            "Calculating the correlation matrix ({} of {})",
+                            (a + 1),
+                            num_fields
+                        ),
+                        progress,
                     );
                     old_progress = progress;
                 }
@@ -324,10 +307,10 @@ impl WhiteboxTool for AttributeCorrelation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -427,7 +410,7 @@ impl WhiteboxTool for AttributeCorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
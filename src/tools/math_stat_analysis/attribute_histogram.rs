@@ -147,41 +147,19 @@ impl WhiteboxTool for AttributeHistogram {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,7 +177,7 @@ impl WhiteboxTool for AttributeHistogram {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            report::message("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -253,7 +231,7 @@ impl WhiteboxTool for AttributeHistogram {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding min and max: {}%", progress);
+                    report::progress("Finding min and max", progress);
                     old_progress = progress;
                 }
             }
@@ -290,7 +268,7 @@ impl WhiteboxTool for AttributeHistogram {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning data: {}%", progress);
+                    report::progress("Binning data", progress);
                     old_progress = progress;
                 }
             }
@@ -299,10 +277,10 @@ impl WhiteboxTool for AttributeHistogram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -376,7 +354,7 @@ impl WhiteboxTool for AttributeHistogram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
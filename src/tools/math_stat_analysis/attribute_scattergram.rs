@@ -168,49 +168,25 @@ impl WhiteboxTool for AttributeScattergram {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-fieldx" {
-                field_name_x = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-fieldy" {
-                field_name_y = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-trendline" {
-                trendline = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--fieldx"])? {
+            field_name_x = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--fieldy"])? {
+            field_name_y = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--trendline"])? {
+            trendline = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -228,7 +204,7 @@ impl WhiteboxTool for AttributeScattergram {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            report::message("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -312,7 +288,7 @@ impl WhiteboxTool for AttributeScattergram {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading data: {}%", progress);
+                    report::progress("Reading data", progress);
                     old_progress = progress;
                 }
             }
@@ -325,10 +301,10 @@ impl WhiteboxTool for AttributeScattergram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -413,7 +389,7 @@ impl WhiteboxTool for AttributeScattergram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
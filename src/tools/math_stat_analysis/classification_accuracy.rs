@@ -0,0 +1,616 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::collections::BTreeMap;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use std::process::Command;
+use tools::*;
+use vector::{FieldData, ShapeType, Shapefile};
+
+/// This tool assesses the accuracy of a classified raster (`--input`), e.g. the output of a
+/// supervised or unsupervised classification, against reference data (`--reference`). The
+/// reference data may be either a raster of the same dimensions, in which case every cell
+/// that is valid in both rasters is compared, or a vector of points, e.g. ground-truth
+/// sites, with the reference class of each point stored in an attribute field (`--field`).
+/// Points that fall outside of the classified raster or in nodata cells are ignored. Classes
+/// are integer values, and real-valued or text reference classes are rounded or parsed
+/// accordingly.
+///
+/// The tool builds the confusion (error) matrix, with the classified classes as rows and the
+/// reference classes as columns, and reports the following statistics:
+///
+/// - the overall accuracy, i.e. the proportion of correctly classified samples;
+/// - Cohen's kappa coefficient, which adjusts the overall accuracy for chance agreement;
+/// - the user's accuracy (precision) of each class, i.e. the proportion of the samples
+///   classified as the class that truly belong to it;
+/// - the producer's accuracy (recall) of each class, i.e. the proportion of the reference
+///   samples of the class that were correctly classified; and
+/// - the F1 score of each class, i.e. the harmonic mean of its user's and producer's
+///   accuracies, along with the mean (macro-averaged) F1 score.
+///
+/// The results are written to an HTML report (`--output`) and, optionally, to a CSV file
+/// (`--csv`) for further analysis.
+///
+/// # See Also
+/// `KappaIndex`, `KMeansClustering`, `ModifiedKMeansClustering`
+pub struct ClassificationAccuracy {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ClassificationAccuracy {
+    pub fn new() -> ClassificationAccuracy {
+        // public constructor
+        let name = "ClassificationAccuracy".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description = "Assesses the accuracy of a classified raster using a confusion matrix, kappa, and per-class F1 scores.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Classified File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input classified raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Reference File".to_owned(),
+            flags: vec!["--reference".to_owned()],
+            description: "Input reference raster file or vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Reference Class Field (Points)".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Attribute field containing the reference class of each point."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--reference".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output HTML File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output HTML report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File".to_owned(),
+            flags: vec!["--csv".to_owned()],
+            description: "Optional output CSV file of the confusion matrix and statistics."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=classes.tif --reference=ground_truth.shp --field=CLASS -o=accuracy.html --csv=accuracy.csv
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=classes.tif --reference=reference.tif -o=accuracy.html", short_exe, name).replace("*", &sep);
+
+        ClassificationAccuracy {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ClassificationAccuracy {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut reference_file = String::new();
+        let mut field_name = String::new();
+        let mut output_file = String::new();
+        let mut csv_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--reference"])? {
+            reference_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--csv"])? {
+            csv_file = v.trim().to_string();
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !reference_file.contains(&sep) && !reference_file.contains("/") {
+            reference_file = format!("{}{}", working_directory, reference_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !output_file.ends_with(".html") {
+            output_file = output_file + ".html";
+        }
+        if !csv_file.is_empty() && !csv_file.contains(&sep) && !csv_file.contains("/") {
+            csv_file = format!("{}{}", working_directory, csv_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+        let nodata = input.configs.nodata;
+
+        let start = Instant::now();
+
+        // pairs of (classified, reference) classes
+        let mut samples: Vec<(i64, i64)> = vec![];
+        if reference_file.to_lowercase().ends_with(".shp") {
+            let reference = Shapefile::read(&reference_file)?;
+            if reference.header.shape_type.base_shape_type() != ShapeType::Point
+                && reference.header.shape_type.base_shape_type() != ShapeType::MultiPoint
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The reference vector data must be of a point base shape type.",
+                ));
+            }
+            if field_name.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The --field parameter is required for vector reference data.",
+                ));
+            }
+            if reference.attributes.get_field_num(&field_name).is_none() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The reference data do not contain a field named {}.", field_name),
+                ));
+            }
+            for record_num in 0..reference.num_records {
+                let class = match reference.attributes.get_value(record_num, &field_name) {
+                    FieldData::Int(v) => v as i64,
+                    FieldData::Real(v) => v.round() as i64,
+                    FieldData::Text(ref v) => match v.trim().parse::<f64>() {
+                        Ok(v) => v.round() as i64,
+                        Err(_) => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                format!("The reference class '{}' is not numeric.", v.trim()),
+                            ))
+                        }
+                    },
+                    _ => continue,
+                };
+                let record = reference.get_record(record_num);
+                for p in &record.points {
+                    let row = input.get_row_from_y(p.y);
+                    let col = input.get_column_from_x(p.x);
+                    if row < 0
+                        || col < 0
+                        || row >= input.configs.rows as isize
+                        || col >= input.configs.columns as isize
+                    {
+                        continue;
+                    }
+                    let z = input.get_value(row, col);
+                    if z != nodata {
+                        samples.push((z.round() as i64, class));
+                    }
+                }
+            }
+        } else {
+            let reference = Raster::new(&reference_file, "r")?;
+            if reference.configs.rows != input.configs.rows
+                || reference.configs.columns != input.configs.columns
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input and reference rasters must have the same number of rows and columns.",
+                ));
+            }
+            let ref_nodata = reference.configs.nodata;
+            for row in 0..input.configs.rows as isize {
+                let data = input.get_row_data(row);
+                let ref_data = reference.get_row_data(row);
+                for (&z, &r) in data.iter().zip(ref_data.iter()) {
+                    if z != nodata && r != ref_nodata {
+                        samples.push((z.round() as i64, r.round() as i64));
+                    }
+                }
+            }
+        }
+        if samples.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "There are no valid samples shared by the classified and reference data.",
+            ));
+        }
+
+        let matrix = ConfusionMatrix::new(&samples);
+        let stats = matrix.class_statistics();
+        let (overall_accuracy, kappa) = (matrix.overall_accuracy(), matrix.kappa());
+        let mean_f1 = stats.iter().map(|s| s.3).sum::<f64>() / stats.len() as f64;
+
+        if verbose {
+            report::metadata("Number of samples", &matrix.total.to_string());
+            report::message(&format!("Overall accuracy: {:.2}%", 100f64 * overall_accuracy));
+            report::metadata("Kappa", &format!("{:.3}", kappa));
+            report::metadata("Mean F1 score", &format!("{:.3}", mean_f1));
+        }
+
+        let num_classes = matrix.classes.len();
+        let format_ratio = |v: f64| {
+            if v.is_nan() {
+                String::from("n/a")
+            } else {
+                format!("{:.2}%", 100f64 * v)
+            }
+        };
+        let format_score = |v: f64| {
+            if v.is_nan() {
+                String::from("n/a")
+            } else {
+                format!("{:.3}", v)
+            }
+        };
+
+        let f = File::create(output_file.as_str())?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all(&r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd">
+        <head>
+            <meta content="text/html; charset=UTF-8" http-equiv="content-type">
+            <title>Classification Accuracy Assessment</title>
+            <style  type="text/css">
+                h1 {
+                    font-size: 14pt;
+                    margin-left: 15px;
+                    margin-right: 15px;
+                    text-align: center;
+                    font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                }
+                p, caption {
+                    font-size: 12pt;
+                    font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                    margin-left: 15px;
+                    margin-right: 15px;
+                }
+                table {
+                    font-size: 12pt;
+                    font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                    border-collapse: collapse;
+                    align: center;
+                }
+                td {
+                    text-align: right;
+                    padding: 8px;
+                }
+                th {
+                    padding: 8px;
+                    background-color: #ffffff;
+                    border-bottom: 1px solid #333333;
+                    text-align: center;
+                }
+                tr:nth-child(1) {
+                    border-bottom: 1px solid #333333;
+                    border-top: 2px solid #333333;
+                }
+                tr:last-child {
+                    border-bottom: 2px solid #333333;
+                }
+                tr:nth-child(even) {
+                    background-color: #dddddd;
+                }
+            </style>
+        </head>
+        <body>
+            <h1>Classification Accuracy Assessment</h1>
+        "#.as_bytes())?;
+        writer.write_all(
+            &format!(
+                "<p><strong>Classified Data:</strong> {}<br><strong>Reference Data:</strong> {}<br><strong>Number of Samples:</strong> {}</p>",
+                Path::new(&input_file).file_name().unwrap().to_string_lossy(),
+                Path::new(&reference_file).file_name().unwrap().to_string_lossy(),
+                matrix.total
+            ).as_bytes(),
+        )?;
+
+        writer.write_all(b"<br><table><caption>Confusion Matrix</caption>")?;
+        writer.write_all(
+            &format!(
+                "<tr><th colspan=\"2\" rowspan=\"2\"></th><th colspan=\"{}\">Reference Data</th><th rowspan=\"2\">Row<br>Totals</th></tr><tr>",
+                num_classes
+            ).as_bytes(),
+        )?;
+        for class in &matrix.classes {
+            writer.write_all(&format!("<th>{}</th>", class).as_bytes())?;
+        }
+        writer.write_all(b"</tr>")?;
+        for a in 0..num_classes {
+            if a == 0 {
+                writer.write_all(
+                    &format!(
+                        "<tr><th rowspan=\"{}\">Classified<br>Data</th>",
+                        num_classes
+                    ).as_bytes(),
+                )?;
+            } else {
+                writer.write_all(b"<tr>")?;
+            }
+            writer.write_all(&format!("<th>{}</th>", matrix.classes[a]).as_bytes())?;
+            for b in 0..num_classes {
+                writer.write_all(&format!("<td>{}</td>", matrix.counts[a][b]).as_bytes())?;
+            }
+            writer.write_all(&format!("<td>{}</td></tr>", matrix.row_total(a)).as_bytes())?;
+        }
+        writer.write_all(b"<tr><th colspan=\"2\">Column Totals</th>")?;
+        for b in 0..num_classes {
+            writer.write_all(&format!("<td>{}</td>", matrix.column_total(b)).as_bytes())?;
+        }
+        writer.write_all(
+            &format!("<td><b>N</b>={}</td></tr></table>", matrix.total).as_bytes(),
+        )?;
+
+        writer.write_all(b"<br><br><table><caption>Class Statistics</caption>")?;
+        writer.write_all(b"<tr><th>Class</th><th>User's Accuracy<br>(Precision)</th><th>Producer's Accuracy<br>(Recall)</th><th>F1 Score</th></tr>")?;
+        for &(class, users, producers, f1) in &stats {
+            writer.write_all(
+                &format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    class,
+                    format_ratio(users),
+                    format_ratio(producers),
+                    format_score(f1)
+                ).as_bytes(),
+            )?;
+        }
+        writer.write_all(b"</table>")?;
+        writer.write_all(
+            &format!(
+                "<p><b>Overall Accuracy</b> = {}<br><b>Kappa</b> = {}<br><b>Mean F1 Score</b> = {}</p>",
+                format_ratio(overall_accuracy),
+                format_score(kappa),
+                format_score(mean_f1)
+            ).as_bytes(),
+        )?;
+        writer.write_all(b"<p>Notes: User's accuracy is the proportion of the samples classified as a class (row total) that belong to the class in the reference data. Producer's accuracy is the proportion of the reference samples of a class (column total) that were correctly classified. The F1 score is the harmonic mean of the two. Values that are undefined because a class is absent from the classified or reference data are shown as n/a.</p>")?;
+        writer.write_all(b"</body>")?;
+        let _ = writer.flush();
+
+        if !csv_file.is_empty() {
+            let f = File::create(&csv_file)?;
+            let mut writer = BufWriter::new(f);
+            let classes: Vec<String> = matrix.classes.iter().map(|c| c.to_string()).collect();
+            writer.write_all(
+                format!("CLASSIFIED\\REFERENCE,{},TOTAL\n", classes.join(",")).as_bytes(),
+            )?;
+            for a in 0..num_classes {
+                let counts: Vec<String> = matrix.counts[a].iter().map(|c| c.to_string()).collect();
+                writer.write_all(
+                    format!(
+                        "{},{},{}\n",
+                        matrix.classes[a],
+                        counts.join(","),
+                        matrix.row_total(a)
+                    ).as_bytes(),
+                )?;
+            }
+            let totals: Vec<String> = (0..num_classes)
+                .map(|b| matrix.column_total(b).to_string())
+                .collect();
+            writer.write_all(
+                format!("TOTAL,{},{}\n\n", totals.join(","), matrix.total).as_bytes(),
+            )?;
+            writer.write_all(b"CLASS,USERS_ACCURACY,PRODUCERS_ACCURACY,F1\n")?;
+            for &(class, users, producers, f1) in &stats {
+                writer.write_all(format!("{},{},{},{}\n", class, users, producers, f1).as_bytes())?;
+            }
+            writer.write_all(
+                format!(
+                    "\nSTATISTIC,VALUE\nOVERALL_ACCURACY,{}\nKAPPA,{}\nMEAN_F1,{}\nN,{}\n",
+                    overall_accuracy, kappa, mean_f1, matrix.total
+                ).as_bytes(),
+            )?;
+            let _ = writer.flush();
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+                let output = Command::new("open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "windows") {
+                let output = Command::new("explorer.exe")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "linux") {
+                let output = Command::new("xdg-open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            }
+
+            report::message(&format!("Complete! Please see {} for output.", output_file));
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// A confusion matrix, with the classified classes as rows and the reference classes as
+/// columns.
+struct ConfusionMatrix {
+    classes: Vec<i64>,
+    counts: Vec<Vec<usize>>,
+    total: usize,
+}
+
+impl ConfusionMatrix {
+    fn new(samples: &[(i64, i64)]) -> ConfusionMatrix {
+        let mut index = BTreeMap::new();
+        for &(c, r) in samples {
+            index.insert(c, 0usize);
+            index.insert(r, 0usize);
+        }
+        let classes: Vec<i64> = index.keys().cloned().collect();
+        for (i, class) in classes.iter().enumerate() {
+            index.insert(*class, i);
+        }
+        let mut counts = vec![vec![0usize; classes.len()]; classes.len()];
+        for &(c, r) in samples {
+            counts[index[&c]][index[&r]] += 1;
+        }
+        ConfusionMatrix {
+            classes: classes,
+            counts: counts,
+            total: samples.len(),
+        }
+    }
+
+    fn row_total(&self, a: usize) -> usize {
+        self.counts[a].iter().sum()
+    }
+
+    fn column_total(&self, b: usize) -> usize {
+        self.counts.iter().map(|row| row[b]).sum()
+    }
+
+    fn overall_accuracy(&self) -> f64 {
+        let agreements: usize = (0..self.classes.len()).map(|a| self.counts[a][a]).sum();
+        agreements as f64 / self.total as f64
+    }
+
+    fn kappa(&self) -> f64 {
+        let n = self.total as f64;
+        let expected: f64 = (0..self.classes.len())
+            .map(|a| self.row_total(a) as f64 * self.column_total(a) as f64 / (n * n))
+            .sum();
+        if expected == 1f64 {
+            return f64::NAN;
+        }
+        (self.overall_accuracy() - expected) / (1f64 - expected)
+    }
+
+    /// Returns the class, user's accuracy, producer's accuracy, and F1 score of each class.
+    fn class_statistics(&self) -> Vec<(i64, f64, f64, f64)> {
+        let mut stats = vec![];
+        for a in 0..self.classes.len() {
+            let correct = self.counts[a][a] as f64;
+            let users = correct / self.row_total(a) as f64;
+            let producers = correct / self.column_total(a) as f64;
+            let f1 = if correct == 0f64 {
+                0f64
+            } else {
+                2f64 * users * producers / (users + producers)
+            };
+            stats.push((self.classes[a], users, producers, f1));
+        }
+        stats
+    }
+}
@@ -137,41 +137,19 @@ impl WhiteboxTool for CrossTabulation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i1" || flag_val == "-input1" {
-                if keyval {
-                    input_file1 = vec[1].to_string();
-                } else {
-                    input_file1 = args[i + 1].to_string();
-                }
-            } else if flag_val == "-i2" || flag_val == "-input2" {
-                if keyval {
-                    input_file2 = vec[1].to_string();
-                } else {
-                    input_file2 = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input_file1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input_file2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -231,7 +209,7 @@ impl WhiteboxTool for CrossTabulation {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating contingency table: {}%", progress);
+                    report::progress("Creating contingency table", progress);
                     old_progress = progress;
                 }
             }
@@ -240,10 +218,10 @@ impl WhiteboxTool for CrossTabulation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -371,7 +349,7 @@ impl WhiteboxTool for CrossTabulation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 5, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::raster_math::{MathOutputType, MathResultKind};
 use num_cpus;
 use raster::*;
 use std::env;
@@ -62,6 +63,27 @@ impl Divide {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Output Data Type".to_owned(),
+            flags: vec!["--data_type".to_owned()],
+            description: "Output data type; 'auto' keeps integer inputs in an integer type.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "auto".to_owned(),
+                "f64".to_owned(),
+                "f32".to_owned(),
+                "i64".to_owned(),
+                "i32".to_owned(),
+                "i16".to_owned(),
+                "i8".to_owned(),
+                "u64".to_owned(),
+                "u32".to_owned(),
+                "u16".to_owned(),
+                "u8".to_owned(),
+            ]),
+            default_value: Some("auto".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -129,6 +151,7 @@ impl WhiteboxTool for Divide {
         let mut input1 = String::new();
         let mut input2 = String::new();
         let mut output_file = String::new();
+        let mut data_type = String::from("auto");
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -136,40 +159,22 @@ impl WhiteboxTool for Divide {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i1" || vec[0].to_lowercase() == "--input1" {
-                if keyval {
-                    input1 = vec[1].to_string();
-                } else {
-                    input1 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-i2" || vec[0].to_lowercase() == "--input2" {
-                if keyval {
-                    input2 = vec[1].to_string();
-                } else {
-                    input2 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--data_type"])? {
+            data_type = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -214,14 +219,14 @@ impl WhiteboxTool for Divide {
             // return Err(Error::new(ErrorKind::InvalidInput,
             //                     "At least one of the inputs must be a raster."));
             if input2_constant != 0.0 {
-                println!("{}", input1_constant / input2_constant);
+                report::message(&(input1_constant / input2_constant).to_string());
             } else {
-                println!("Inf");
+                report::message("Inf");
             }
             return Ok(());
         } else if input1_is_constant && !input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in2 = Arc::new(Raster::new(&input2, "r")?);
 
@@ -229,6 +234,15 @@ impl WhiteboxTool for Divide {
             let rows = in2.configs.rows as isize;
             let columns = in2.configs.columns as isize;
             let nodata2 = in2.configs.nodata;
+            let mut output = Raster::initialize_using_file(&output_file, &in2);
+            let out_type = MathOutputType::new(
+                &data_type,
+                &[in2.configs.data_type],
+                &[input1_constant],
+                MathResultKind::Float,
+                output.configs.nodata,
+            )?;
+            let out_nodata = out_type.nodata;
 
             let num_procs = num_cpus::get() as isize;
             let (tx, rx) = mpsc::channel();
@@ -238,17 +252,17 @@ impl WhiteboxTool for Divide {
                 thread::spawn(move || {
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata2; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z2 = in2[(row, col)];
                             if z2 != nodata2 {
                                 if z2 != 0.0 {
-                                    data[col as usize] = input1_constant / z2;
+                                    data[col as usize] = out_type.convert(input1_constant / z2);
                                 } else {
-                                    data[col as usize] = nodata2;
+                                    data[col as usize] = out_nodata;
                                 }
                             } else {
-                                data[col as usize] = nodata2;
+                                data[col as usize] = out_nodata;
                             }
                         }
                         tx.send((row, data)).unwrap();
@@ -256,7 +270,7 @@ impl WhiteboxTool for Divide {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &in2);
+            out_type.configure(&mut output);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -264,7 +278,7 @@ impl WhiteboxTool for Divide {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -278,23 +292,20 @@ impl WhiteboxTool for Divide {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         } else if !input1_is_constant && input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
 
@@ -302,6 +313,15 @@ impl WhiteboxTool for Divide {
             let rows = in1.configs.rows as isize;
             let columns = in1.configs.columns as isize;
             let nodata1 = in1.configs.nodata;
+            let mut output = Raster::initialize_using_file(&output_file, &in1);
+            let out_type = MathOutputType::new(
+                &data_type,
+                &[in1.configs.data_type],
+                &[input2_constant],
+                MathResultKind::Float,
+                output.configs.nodata,
+            )?;
+            let out_nodata = out_type.nodata;
 
             let num_procs = num_cpus::get() as isize;
             let (tx, rx) = mpsc::channel();
@@ -311,17 +331,17 @@ impl WhiteboxTool for Divide {
                 thread::spawn(move || {
                     let mut z1: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata1; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z1 = in1[(row, col)];
                             if z1 != nodata1 {
                                 if input2_constant != 0.0 {
-                                    data[col as usize] = z1 / input2_constant;
+                                    data[col as usize] = out_type.convert(z1 / input2_constant);
                                 } else {
-                                    data[col as usize] = nodata1;
+                                    data[col as usize] = out_nodata;
                                 }
                             } else {
-                                data[col as usize] = nodata1;
+                                data[col as usize] = out_nodata;
                             }
                         }
                         tx.send((row, data)).unwrap();
@@ -329,7 +349,7 @@ impl WhiteboxTool for Divide {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &in1);
+            out_type.configure(&mut output);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -337,7 +357,7 @@ impl WhiteboxTool for Divide {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -351,24 +371,21 @@ impl WhiteboxTool for Divide {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         } else {
             // !input1_is_constant && !input2_is_constant
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
             let in2 = Arc::new(Raster::new(&input2, "r")?);
@@ -378,11 +395,20 @@ impl WhiteboxTool for Divide {
             let columns = in1.configs.columns as isize;
             let nodata1 = in1.configs.nodata;
             let nodata2 = in2.configs.nodata;
+            let mut output = Raster::initialize_using_file(&output_file, &in1);
+            let out_type = MathOutputType::new(
+                &data_type,
+                &[in1.configs.data_type, in2.configs.data_type],
+                &[],
+                MathResultKind::Float,
+                output.configs.nodata,
+            )?;
+            let out_nodata = out_type.nodata;
 
             // make sure the input files have the same size
             if in1.configs.rows != in2.configs.rows || in1.configs.columns != in2.configs.columns {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                                    "The input files must have the same number of rows and columns and spatial extent."));
+                                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
 
             let num_procs = num_cpus::get() as isize;
@@ -395,18 +421,18 @@ impl WhiteboxTool for Divide {
                     let mut z1: f64;
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata1; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z1 = in1[(row, col)];
                             z2 = in2[(row, col)];
                             if z1 != nodata1 && z2 != nodata2 {
                                 if z2 != 0.0 {
-                                    data[col as usize] = z1 / z2;
+                                    data[col as usize] = out_type.convert(z1 / z2);
                                 } else {
-                                    data[col as usize] = nodata1;
+                                    data[col as usize] = out_nodata;
                                 }
                             } else {
-                                data[col as usize] = nodata1;
+                                data[col as usize] = out_nodata;
                             }
                         }
                         tx.send((row, data)).unwrap();
@@ -414,7 +440,7 @@ impl WhiteboxTool for Divide {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &in1);
+            out_type.configure(&mut output);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -422,7 +448,7 @@ impl WhiteboxTool for Divide {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -436,19 +462,16 @@ impl WhiteboxTool for Divide {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         }
 
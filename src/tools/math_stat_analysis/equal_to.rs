@@ -134,40 +134,19 @@ impl WhiteboxTool for EqualTo {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i1" || vec[0].to_lowercase() == "--input1" {
-                if keyval {
-                    input1 = vec[1].to_string();
-                } else {
-                    input1 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-i2" || vec[0].to_lowercase() == "--input2" {
-                if keyval {
-                    input2 = vec[1].to_string();
-                } else {
-                    input2 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -211,11 +190,11 @@ impl WhiteboxTool for EqualTo {
         if input1_is_constant && input2_is_constant {
             // return Err(Error::new(ErrorKind::InvalidInput,
             //                     "At least one of the inputs must be a raster."));
-            println!("{}", equal_to(input1_constant, input2_constant));
+            report::message(&equal_to(input1_constant, input2_constant).to_string());
             return Ok(());
         } else if input1_is_constant && !input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in2 = Arc::new(Raster::new(&input2, "r")?);
 
@@ -223,6 +202,8 @@ impl WhiteboxTool for EqualTo {
             let rows = in2.configs.rows as isize;
             let columns = in2.configs.columns as isize;
             let nodata2 = in2.configs.nodata;
+            let mut output = Raster::initialize_using_file(&output_file, &in2);
+            let out_nodata = output.configs.nodata;
 
             let num_procs = num_cpus::get() as isize;
             let (tx, rx) = mpsc::channel();
@@ -232,13 +213,13 @@ impl WhiteboxTool for EqualTo {
                 thread::spawn(move || {
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata2; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z2 = in2[(row, col)];
                             if z2 != nodata2 {
                                 data[col as usize] = equal_to(input1_constant, z2);
                             } else {
-                                data[col as usize] = nodata2;
+                                data[col as usize] = out_nodata;
                             }
                         }
                         tx.send((row, data)).unwrap();
@@ -246,7 +227,6 @@ impl WhiteboxTool for EqualTo {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &in2);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -254,7 +234,7 @@ impl WhiteboxTool for EqualTo {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -271,23 +251,20 @@ impl WhiteboxTool for EqualTo {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         } else if !input1_is_constant && input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
 
@@ -295,6 +272,8 @@ impl WhiteboxTool for EqualTo {
             let rows = in1.configs.rows as isize;
             let columns = in1.configs.columns as isize;
             let nodata1 = in1.configs.nodata;
+            let mut output = Raster::initialize_using_file(&output_file, &in1);
+            let out_nodata = output.configs.nodata;
 
             let num_procs = num_cpus::get() as isize;
             let (tx, rx) = mpsc::channel();
@@ -304,13 +283,13 @@ impl WhiteboxTool for EqualTo {
                 thread::spawn(move || {
                     let mut z1: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata1; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z1 = in1[(row, col)];
                             if z1 != nodata1 {
                                 data[col as usize] = equal_to(z1, input2_constant);
                             } else {
-                                data[col as usize] = nodata1;
+                                data[col as usize] = out_nodata;
                             }
                         }
                         tx.send((row, data)).unwrap();
@@ -318,7 +297,6 @@ impl WhiteboxTool for EqualTo {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -326,7 +304,7 @@ impl WhiteboxTool for EqualTo {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -343,24 +321,21 @@ impl WhiteboxTool for EqualTo {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         } else {
             // !input1_is_constant && !input2_is_constant
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
             let in2 = Arc::new(Raster::new(&input2, "r")?);
@@ -370,11 +345,13 @@ impl WhiteboxTool for EqualTo {
             let columns = in1.configs.columns as isize;
             let nodata1 = in1.configs.nodata;
             let nodata2 = in2.configs.nodata;
+            let mut output = Raster::initialize_using_file(&output_file, &in1);
+            let out_nodata = output.configs.nodata;
 
             // make sure the input files have the same size
             if in1.configs.rows != in2.configs.rows || in1.configs.columns != in2.configs.columns {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                                    "The input files must have the same number of rows and columns and spatial extent."));
+                                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
 
             let num_procs = num_cpus::get() as isize;
@@ -387,14 +364,14 @@ impl WhiteboxTool for EqualTo {
                     let mut z1: f64;
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata1; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             z1 = in1[(row, col)];
                             z2 = in2[(row, col)];
                             if z1 != nodata1 && z2 != nodata2 {
                                 data[col as usize] = equal_to(z1, z2);
                             } else {
-                                data[col as usize] = nodata1;
+                                data[col as usize] = out_nodata;
                             }
                         }
                         tx.send((row, data)).unwrap();
@@ -402,7 +379,6 @@ impl WhiteboxTool for EqualTo {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -410,7 +386,7 @@ impl WhiteboxTool for EqualTo {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -427,19 +403,16 @@ impl WhiteboxTool for EqualTo {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
             }
         }
 
@@ -173,54 +173,25 @@ impl WhiteboxTool for ExtractRasterStatistics {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-features" {
-                if keyval {
-                    features_file = vec[1].to_string();
-                } else {
-                    features_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-out_table" {
-                // out_table = true;
-                if keyval {
-                    output_html_file = vec[1].to_string();
-                } else {
-                    output_html_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-stat" {
-                if keyval {
-                    stat_type = vec[1].to_string().to_lowercase();
-                } else {
-                    stat_type = args[i + 1].to_string().to_lowercase();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--features"])? {
+            features_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_table"])? {
+            output_html_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--stat"])? {
+            stat_type = v.to_lowercase();
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -252,7 +223,7 @@ impl WhiteboxTool for ExtractRasterStatistics {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let features = Arc::new(Raster::new(&features_file, "r")?);
@@ -313,7 +284,7 @@ impl WhiteboxTool for ExtractRasterStatistics {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
+                    report::progress("Progress (Loop 1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -356,7 +327,7 @@ impl WhiteboxTool for ExtractRasterStatistics {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
+                    report::progress("Progress (Loop 2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -382,7 +353,7 @@ impl WhiteboxTool for ExtractRasterStatistics {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
+                    report::progress("Progress (Loop 3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -424,7 +395,7 @@ impl WhiteboxTool for ExtractRasterStatistics {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Output: {}%", progress);
+                        report::progress("Output", progress);
                         old_progress = progress;
                     }
                 }
@@ -441,11 +412,11 @@ impl WhiteboxTool for ExtractRasterStatistics {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -600,15 +571,12 @@ impl WhiteboxTool for ExtractRasterStatistics {
                     let _ = output.stdout;
                 }
 
-                println!("Complete! Please see {} for output.", output_html_file);
+                report::message(&format!("Complete! Please see {} for output.", output_html_file));
             }
         }
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,413 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use super::spatial_weights::SpatialWeights;
+use num_cpus;
+use rand::prelude::*;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use tools::*;
+
+/// This tool calculates the Getis-Ord Gi* statistic (Getis and Ord, 1992; Ord and Getis,
+/// 1995) for each grid cell of an input raster (`--input`), for use in hot spot analysis.
+/// The Gi* statistic compares the weighted sum of the values within the neighbourhood of a
+/// cell, including the cell itself, with the sum that would be expected if values were
+/// distributed at random across the raster. It is a z-score: large positive values
+/// indicate hot spots, i.e. clusters of high values, and large negative values indicate
+/// cold spots, i.e. clusters of low values. Gi* values beyond ±1.96 are significant at the
+/// 0.05 level, without adjustment for multiple comparisons.
+///
+/// The neighbourhood of a cell consists of the valid cells within a square moving window
+/// (`--filter`, in grid cells) centred on it. Each neighbour is weighted (`--weights`)
+/// either equally ('binary', the default), by the inverse of its distance from the centre
+/// cell ('inverse_distance'), or by a Gaussian function of its distance, with a standard
+/// deviation of half of the window radius ('gaussian'); the centre cell itself has a
+/// weight of one.
+///
+/// Optionally, pseudo p-values (`--p_output`) are calculated with a conditional
+/// permutation test, in which the values of the neighbours of each cell are replaced with
+/// values drawn at random from the raster a number of times (`--num_permutations`). The
+/// pseudo p-value is (M + 1) / (R + 1), where R is the number of permutations and M is the
+/// number of them that yield a statistic as extreme as, or more extreme than, the observed
+/// one, in the direction of the observed statistic.
+///
+/// # References
+/// Getis, A., and Ord, J. K. (1992). The analysis of spatial association by use of distance
+/// statistics. *Geographical Analysis*, 24(3), 189-206.
+///
+/// Ord, J. K., and Getis, A. (1995). Local spatial autocorrelation statistics:
+/// distributional issues and an application. *Geographical Analysis*, 27(4), 286-306.
+///
+/// # See Also
+/// `LocalMoransI`, `ImageAutocorrelation`
+pub struct GetisOrdGiStar {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl GetisOrdGiStar {
+    pub fn new() -> GetisOrdGiStar {
+        // public constructor
+        let name = "GetisOrdGiStar".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description =
+            "Calculates the Getis-Ord Gi* hot spot statistic of a raster.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output Gi* (z-score) raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Pseudo P-Value File (optional)".to_owned(),
+            flags: vec!["--p_output".to_owned()],
+            description: "Optional output pseudo p-value raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Size".to_owned(),
+            flags: vec!["--filter".to_owned()],
+            description: "Size of the neighbourhood window, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Weighting Scheme".to_owned(),
+            flags: vec!["--weights".to_owned()],
+            description: "Neighbour weighting scheme; options are 'binary', 'inverse_distance', and 'gaussian'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "binary".to_owned(),
+                "inverse_distance".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("binary".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Permutations".to_owned(),
+            flags: vec!["--num_permutations".to_owned()],
+            description: "Number of permutations used to calculate the pseudo p-values."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("99".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=twi.tif -o=gi_star.tif --p_output=gi_star_p.tif --filter=9 --weights=gaussian",
+            short_exe, name
+        ).replace("*", &sep);
+
+        GetisOrdGiStar {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for GetisOrdGiStar {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut p_file = String::new();
+        let mut filter_size = 3usize;
+        let mut scheme = String::from("binary");
+        let mut num_permutations = 99usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--p_output"])? {
+            p_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--weights"])? {
+            scheme = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_permutations"])? {
+            num_permutations = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !p_file.is_empty() && !p_file.contains(&sep) && !p_file.contains("/") {
+            p_file = format!("{}{}", working_directory, p_file);
+        }
+        if p_file.is_empty() {
+            num_permutations = 0;
+        }
+
+        let weights = Arc::new(SpatialWeights::new(filter_size, &scheme, true)?);
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+
+        let mut values = Vec::with_capacity(rows as usize * columns as usize);
+        for row in 0..rows {
+            for col in 0..columns {
+                let z = input.get_value(row, col);
+                if z != nodata {
+                    values.push(z);
+                }
+            }
+        }
+        let n = values.len() as f64;
+        if n < 2f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster must contain at least two valid grid cells.",
+            ));
+        }
+        let mean = values.iter().sum::<f64>() / n;
+        let s = (values.iter().map(|z| (z - mean) * (z - mean)).sum::<f64>() / n).sqrt();
+        if s == 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster has no variation.",
+            ));
+        }
+        let values = Arc::new(values);
+
+        let out_nodata = -32768f64;
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let weights = weights.clone();
+            let values = values.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut rng = thread_rng();
+                let num_values = values.len();
+                let mut w = Vec::with_capacity(weights.len());
+                let (mut z, mut zn, mut sum_w, mut sum_w2, mut sum_wz): (f64, f64, f64, f64, f64);
+                let (mut denominator, mut gi, mut sum_p, mut count): (f64, f64, f64, usize);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data_g = vec![out_nodata; columns as usize];
+                    let mut data_p = vec![out_nodata; columns as usize];
+                    for col in 0..columns {
+                        z = input.get_value(row, col);
+                        if z == nodata {
+                            continue;
+                        }
+                        // the weights of the valid neighbours, excluding the centre cell
+                        w.clear();
+                        sum_w = 0f64;
+                        sum_w2 = 0f64;
+                        sum_wz = 0f64;
+                        for k in 0..weights.len() {
+                            zn = input.get_value(row + weights.dy[k], col + weights.dx[k]);
+                            if zn != nodata {
+                                if weights.dx[k] != 0 || weights.dy[k] != 0 {
+                                    w.push(weights.weights[k]);
+                                }
+                                sum_w += weights.weights[k];
+                                sum_w2 += weights.weights[k] * weights.weights[k];
+                                sum_wz += weights.weights[k] * zn;
+                            }
+                        }
+                        if w.is_empty() {
+                            continue;
+                        }
+                        denominator = s * ((n * sum_w2 - sum_w * sum_w) / (n - 1f64)).sqrt();
+                        if denominator <= 0f64 {
+                            continue;
+                        }
+                        gi = (sum_wz - mean * sum_w) / denominator;
+                        data_g[col as usize] = gi;
+
+                        if num_permutations > 0 {
+                            // the centre cell is held fixed, with a weight of one
+                            count = 0;
+                            for _ in 0..num_permutations {
+                                sum_p = z;
+                                for k in 0..w.len() {
+                                    sum_p += w[k] * values[rng.gen_range(0, num_values)];
+                                }
+                                if (gi >= 0f64 && sum_p >= sum_wz) || (gi < 0f64 && sum_p <= sum_wz)
+                                {
+                                    count += 1;
+                                }
+                            }
+                            data_p[col as usize] =
+                                (count + 1) as f64 / (num_permutations + 1) as f64;
+                        }
+                    }
+                    tx.send((row, data_g, data_p)).unwrap();
+                }
+            });
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.nodata = out_nodata;
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.palette = "blue_white_red.plt".to_string();
+        let mut p_output = if !p_file.is_empty() {
+            let mut r = Raster::initialize_using_file(&p_file, &output);
+            r.configs.palette = "spectrum.plt".to_string();
+            Some(r)
+        } else {
+            None
+        };
+        for r in 0..rows {
+            let (row, data_g, data_p) = rx.recv().unwrap();
+            output.set_row_data(row, data_g);
+            if let Some(ref mut o) = p_output {
+                o.set_row_data(row, data_p);
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        let metadata = vec![
+            format!("Created by whitebox_tools\' {} tool", self.get_tool_name()),
+            format!("Input file: {}", input_file),
+            format!("Filter size: {}", filter_size),
+            format!("Weighting scheme: {}", scheme),
+            format!("Number of permutations: {}", num_permutations),
+            format!("Elapsed Time (excluding I/O): {}", elapsed_time),
+        ];
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        for o in Some(&mut output).into_iter().chain(p_output.as_mut()) {
+            for m in &metadata {
+                o.add_metadata_entry(m.clone());
+            }
+            let _ = match o.write() {
+                Ok(_) => if verbose {
+                    report::metadata("Output file written", &o.file_name)
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: Dec. 16, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -23,6 +23,14 @@ use std::sync::Arc;
 use std::thread;
 use tools::*;
 
+/// This tool calculates the global spatial autocorrelation (Moran's I) of one or more
+/// rasters (`--inputs`), using binary weights for the neighbouring cells of each grid cell
+/// (`--contiguity`; 'Rook', 'King', or 'Bishop'). The output HTML report (`--output`)
+/// contains Moran's I, its expected value, and its variance, z-score, and p-value under
+/// both the normality and randomization assumptions.
+///
+/// # See Also
+/// `LocalMoransI`, `GetisOrdGiStar`, `ImageCorrelation`
 pub struct ImageAutocorrelation {
     name: String,
     description: String,
@@ -149,41 +157,19 @@ impl WhiteboxTool for ImageAutocorrelation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                if keyval {
-                    input_files = vec[1].to_string();
-                } else {
-                    input_files = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-contiguity" {
-                if keyval {
-                    contiguity = vec[1].to_string().to_lowercase();
-                } else {
-                    contiguity = args[i + 1].to_string().to_lowercase();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--contiguity"])? {
+            contiguity = v.to_lowercase();
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -258,7 +244,7 @@ impl WhiteboxTool for ImageAutocorrelation {
         let mut rows: isize = 0;
         let mut columns: isize = 0;
         if verbose {
-            println!("Calculating image averages...");
+            report::message("Calculating image averages...");
         }
         for a in 0..num_files {
             let value = &file_names[a]; //files_vec[a];
@@ -305,7 +291,7 @@ impl WhiteboxTool for ImageAutocorrelation {
                 if verbose && num_procs > 1 {
                     progress = (100.0_f64 * np as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        report::progress("Progress (Loop 1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -347,33 +333,35 @@ impl WhiteboxTool for ImageAutocorrelation {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
+                        report::progress("Progress (Loop 2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
             }
 
-            let s1 = 4f64 * w;
+            // with symmetric binary weights, S1 = 2W and S2 = sum((2 w_i)^2)
+            let s1 = 2f64 * w;
             s2 = s2 * 4f64;
 
             std_dev[a] = (total_deviation / (n[a] - 1f64)).sqrt();
 
             i[a] = n[a] * numerator / (total_deviation * w);
 
-            var_normality[a] =
-                (n[a] * n[a] * s1 - n[a] * s2 + 3f64 * w * w) / ((w * w) * (n[a] * n[a] - 1f64));
+            var_normality[a] = (n[a] * n[a] * s1 - n[a] * s2 + 3f64 * w * w)
+                / ((w * w) * (n[a] * n[a] - 1f64))
+                - e_i[a] * e_i[a];
 
             z_n[a] = (i[a] - e_i[a]) / var_normality[a].sqrt();
             p_value_n[a] = 2f64 * (1f64 - distribution.cdf(z_n[a].abs()));
 
-            k = k / (n[a] * std_dev[a] * std_dev[a] * std_dev[a] * std_dev[a]);
+            // sample kurtosis
+            k = n[a] * k / (total_deviation * total_deviation);
 
             var_randomization[a] = (n[a]
                 * ((n[a] * n[a] - 3f64 * n[a] + 3f64) * s1 - n[a] * s2 + 3f64 * w * w)
-                - k * (n[a] * n[a] - n[a]) * s1
-                - 2f64 * n[a] * s1
-                + 6f64 * w * w)
-                / ((n[a] - 1f64) * (n[a] - 2f64) * (n[a] - 3f64) * w * w);
+                - k * ((n[a] * n[a] - n[a]) * s1 - 2f64 * n[a] * s2 + 6f64 * w * w))
+                / ((n[a] - 1f64) * (n[a] - 2f64) * (n[a] - 3f64) * w * w)
+                - e_i[a] * e_i[a];
 
             z_r[a] = (i[a] - e_i[a]) / var_randomization[a].sqrt();
             p_value_r[a] = 2f64 * (1f64 - distribution.cdf(z_r[a].abs()));
@@ -381,7 +369,7 @@ impl WhiteboxTool for ImageAutocorrelation {
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop {} of {}: {}%", (a + 1), files_vec.len(), progress);
+                    report::progress(&format!("Loop {} of {}", (a + 1), files_vec.len()), progress);
                     old_progress = progress;
                 }
             }
@@ -390,10 +378,10 @@ impl WhiteboxTool for ImageAutocorrelation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -518,7 +506,7 @@ impl WhiteboxTool for ImageAutocorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -2,13 +2,16 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: September 3, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use self::statrs::distribution::{StudentsT, Univariate};
 use num_cpus;
 use raster::*;
 use rendering::html::*;
+use statrs;
+use std::cmp::Ordering;
 use std::env;
 use std::f64;
 use std::fs::File;
@@ -20,8 +23,37 @@ use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
+use structures::SummedAreaTable;
 use tools::*;
 
+/// This tool calculates the correlation matrix of two or more rasters (`--inputs`), which
+/// must share the same dimensions. Each correlation is calculated from the grid cells that
+/// are valid (i.e. not nodata) in both of the images of the pair, such that the nodata
+/// masks of the images need not coincide. Either Pearson's product-moment correlation
+/// (`--method=pearson`, the default) or Spearman's rank correlation (`--method=spearman`),
+/// which is the Pearson correlation of the ranks of the values, with tied values assigned
+/// their average rank, may be calculated.
+///
+/// The output HTML report (`--output`) contains the correlation matrix, the two-tailed
+/// significance (p-value) of each correlation, based on the t-statistic
+/// r((n - 2) / (1 - r<sup>2</sup>))<sup>0.5</sup> with n - 2 degrees of freedom, the
+/// covariance matrix of the image values, and the number of cells used for each pair.
+/// Notice that neighbouring grid cells are rarely independent, owing to spatial
+/// autocorrelation, and that with the very large sample sizes of most rasters, even
+/// weak correlations will be highly significant; the p-values should therefore be
+/// interpreted with caution. The correlation matrix may also be written to a CSV file
+/// (`--csv`), with one row and one column for each of the input images.
+///
+/// Optionally, a local correlation raster (`--local_output`) may be created for one pair
+/// of images (`--local_pair`, specified by the positions of the images in the input list,
+/// e.g. '1,2'). Each cell of this raster contains the Pearson correlation of the two
+/// images within a square moving window (`--filter`, in cells) centred on the cell, which
+/// shows where the relation between the images is strong, weak, or reversed. Cells whose
+/// windows contain fewer than three valid pairs of values, or no variation in either
+/// image, are assigned nodata.
+///
+/// # See Also
+/// `ImageAutocorrelation`, `ImageRegression`, `AttributeCorrelation`
 pub struct ImageCorrelation {
     name: String,
     description: String,
@@ -58,6 +90,56 @@ impl ImageCorrelation {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Correlation Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Correlation method; options are 'pearson' and 'spearman'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "pearson".to_owned(),
+                "spearman".to_owned(),
+            ]),
+            default_value: Some("pearson".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File (optional)".to_owned(),
+            flags: vec!["--csv".to_owned()],
+            description: "Optional output CSV file of the correlation matrix.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Local Correlation File (optional)".to_owned(),
+            flags: vec!["--local_output".to_owned()],
+            description: "Optional output raster of the moving-window correlation of a pair of images."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Local Correlation Image Pair".to_owned(),
+            flags: vec!["--local_pair".to_owned()],
+            description: "Positions of the two input images used for the local correlation, e.g. '1,2'."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: Some("1,2".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Local Correlation Filter Size".to_owned(),
+            flags: vec!["--filter".to_owned()],
+            description: "Size of the local correlation moving window, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("11".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -69,7 +151,8 @@ impl ImageCorrelation {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=\"file1.tif, file2.tif, file3.tif\" -o=outfile.html",
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=\"file1.tif, file2.tif, file3.tif\" -o=outfile.html
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=\"file1.tif, file2.tif, file3.tif\" -o=outfile.html --method=spearman --csv=matrix.csv --local_output=local.tif --local_pair='1,3' --filter=15",
                             short_exe,
                             name)
                 .replace("*", &sep);
@@ -127,6 +210,11 @@ impl WhiteboxTool for ImageCorrelation {
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut output_file = String::new();
+        let mut spearman = false;
+        let mut csv_file = String::new();
+        let mut local_file = String::new();
+        let mut local_pair = String::from("1,2");
+        let mut filter_size = 11usize;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -134,35 +222,31 @@ impl WhiteboxTool for ImageCorrelation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                input_files = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            spearman = v.to_lowercase().contains("spearman");
+        }
+        if let Some(v) = tool_args.get_string(&["--csv"])? {
+            csv_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--local_output"])? {
+            local_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--local_pair"])? {
+            local_pair = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -190,6 +274,12 @@ impl WhiteboxTool for ImageCorrelation {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !csv_file.is_empty() && !csv_file.contains(&sep) && !csv_file.contains("/") {
+            csv_file = format!("{}{}", working_directory, csv_file);
+        }
+        if !local_file.is_empty() && !local_file.contains(&sep) && !local_file.contains("/") {
+            local_file = format!("{}{}", working_directory, local_file);
+        }
 
         let mut file_names = vec![];
         for a in 0..files_vec.len() {
@@ -204,164 +294,271 @@ impl WhiteboxTool for ImageCorrelation {
         }
 
         let num_files = file_names.len();
+        if num_files < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least two input images are required to operate this tool.",
+            ));
+        }
 
-        let num_procs = num_cpus::get() as isize;
-        let (tx, rx) = mpsc::channel();
+        // parse the pair of images used for the local correlation
+        let mut local_images = (0usize, 1usize);
+        if !local_file.is_empty() {
+            let pair = local_pair
+                .split(|c| c == ',' || c == ';' || c == ' ')
+                .filter(|v| !v.trim().is_empty())
+                .map(|v| v.trim().parse::<usize>())
+                .collect::<Vec<_>>();
+            local_images = match pair.as_slice() {
+                [Ok(a), Ok(b)] if *a >= 1 && *b >= 1 && *a <= num_files && *b <= num_files => {
+                    (*a - 1, *b - 1)
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The local correlation pair must contain the positions of two input images, e.g. '1,2'.",
+                    ))
+                }
+            };
+            if filter_size < 3 {
+                filter_size = 3;
+            }
+            if filter_size % 2 == 0 {
+                filter_size += 1;
+            }
+        }
 
-        let mut image_totals = vec![0f64; num_files];
-        let mut image_n = vec![0f64; num_files];
-        let mut image_averages = vec![0f64; num_files];
         let mut correlation_matrix = vec![vec![-99f64; num_files]; num_files];
+        let mut p_value_matrix = vec![vec![-99f64; num_files]; num_files];
+        let mut covariance_matrix = vec![vec![-99f64; num_files]; num_files];
+        let mut n_matrix = vec![vec![0usize; num_files]; num_files];
         let mut rows: isize = 0;
         let mut columns: isize = 0;
+
         if verbose {
-            println!("Calculating image averages...");
+            report::message("Calculating the correlation matrix:");
         }
         for a in 0..num_files {
-            let value = &file_names[a]; //files_vec[a];
-            let input_file = value.trim(); //.to_owned();
-            let input = Arc::new(Raster::new(&input_file, "r")?);
-            let nodata = input.configs.nodata;
+            let image1 = Arc::new(Raster::new(&file_names[a], "r")?);
             if a == 0 {
-                rows = input.configs.rows as isize;
-                columns = input.configs.columns as isize;
-            } else {
-                if input.configs.columns as isize != columns || input.configs.rows as isize != rows
-                {
-                    return Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "All input images must have the same dimensions (rows and columns).",
-                    ));
-                }
-            }
-
-            for tid in 0..num_procs {
-                let input = input.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut total = 0f64;
-                    let mut n = 0f64;
-                    let mut z: f64;
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        for col in 0..columns {
-                            z = input[(row, col)];
-                            if z != nodata {
-                                // image_totals[a] += z;
-                                // image_n[a] += 1f64;
-                                total += z;
-                                n += 1f64;
-                            }
-                        }
-                    }
-                    tx.send((total, n)).unwrap();
-                });
+                rows = image1.configs.rows as isize;
+                columns = image1.configs.columns as isize;
+            } else if image1.configs.columns as isize != columns
+                || image1.configs.rows as isize != rows
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "All input images must have the same dimensions (rows and columns).",
+                ));
             }
-            for _ in 0..num_procs {
-                let (total, n) = rx.recv().unwrap();
-                image_totals[a] += total;
-                image_n[a] += n;
+            for b in 0..(a + 1) {
+                let image2 = if a == b {
+                    image1.clone()
+                } else {
+                    Arc::new(Raster::new(&file_names[b], "r")?)
+                };
+                let (mut x, mut y) = paired_values(image1.clone(), image2);
+                let n = x.len();
+                n_matrix[a][b] = n;
+                if n < 2 {
+                    continue;
+                }
+                let (r, covariance) = correlation(&x, &y);
+                covariance_matrix[a][b] = covariance;
+                if a == b {
+                    correlation_matrix[a][b] = 1.0;
+                    continue;
+                }
+                let r = if spearman {
+                    x = ranks(&x);
+                    y = ranks(&y);
+                    correlation(&x, &y).0
+                } else {
+                    r
+                };
+                correlation_matrix[a][b] = r;
+                if n > 2 && r.is_finite() {
+                    p_value_matrix[a][b] = if r.abs() < 1f64 {
+                        let df = (n - 2) as f64;
+                        let t = r * (df / (1f64 - r * r)).sqrt();
+                        let distribution = StudentsT::new(0.0, 1.0, df).unwrap();
+                        (2f64 * (1f64 - distribution.cdf(t.abs()))).max(0f64)
+                    } else {
+                        0f64
+                    };
+                }
             }
-            image_averages[a] = image_totals[a] / image_n[a];
 
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Calculating image averages ({} of {}): {}%",
-                        (a + 1),
-                        files_vec.len(),
-                        progress
+                    report::progress(
+                        &format!(
+                            "Calculating the correlation matrix ({} of {})",
+                            (a + 1),
+                            num_files
+                        ),
+                        progress,
                     );
                     old_progress = progress;
                 }
             }
         }
-        let image_averages = Arc::new(image_averages);
 
-        if verbose {
-            println!("Calculating the correlation matrix:");
-        }
-        let mut i = 0;
-        for a in 0..num_files {
-            let value = &file_names[a];
-            let image1 = Arc::new(Raster::new(&value, "r")?);
+        if !local_file.is_empty() {
+            if verbose {
+                report::message("Calculating the local correlation...");
+            }
+            let image1 = Arc::new(Raster::new(&file_names[local_images.0], "r")?);
+            let image2 = Arc::new(Raster::new(&file_names[local_images.1], "r")?);
             let nodata1 = image1.configs.nodata;
-            for b in 0..(i + 1) {
-                if a == b {
-                    correlation_matrix[a][b] = 1.0;
+            let nodata2 = image2.configs.nodata;
+            let out_nodata = -32768f64;
+
+            // centre the values on their means, which preserves precision in the sums of
+            // the cross-products
+            let (x, y) = paired_values(image1.clone(), image2.clone());
+            let n = x.len().max(1) as f64;
+            let mean1 = x.iter().sum::<f64>() / n;
+            let mean2 = y.iter().sum::<f64>() / n;
+            drop(x);
+            drop(y);
+            let pair_value = |row: isize, col: isize| -> Option<(f64, f64)> {
+                let z1 = image1[(row, col)];
+                let z2 = image2[(row, col)];
+                if z1 != nodata1 && z2 != nodata2 {
+                    Some((z1 - mean1, z2 - mean2))
                 } else {
-                    let image2 = Arc::new(Raster::new(&file_names[b], "r")?);
-                    let nodata2 = image2.configs.nodata;
-
-                    let (tx, rx) = mpsc::channel();
-                    for tid in 0..num_procs {
-                        let image1 = image1.clone();
-                        let image2 = image2.clone();
-                        let image_averages = image_averages.clone();
-                        let tx = tx.clone();
-                        thread::spawn(move || {
-                            let mut z1: f64;
-                            let mut z2: f64;
-                            let mut image1_total_deviation = 0f64;
-                            let mut image2_total_deviation = 0f64;
-                            let mut total_product_deviations = 0f64;
-                            for row in (0..rows).filter(|r| r % num_procs == tid) {
-                                for col in 0..columns {
-                                    z1 = image1[(row, col)];
-                                    z2 = image2[(row, col)];
-                                    if z1 != nodata1 && z2 != nodata2 {
-                                        image1_total_deviation +=
-                                            (z1 - image_averages[a]) * (z1 - image_averages[a]);
-                                        image2_total_deviation +=
-                                            (z2 - image_averages[b]) * (z2 - image_averages[b]);
-                                        total_product_deviations +=
-                                            (z1 - image_averages[a]) * (z2 - image_averages[b]);
-                                    }
-                                }
+                    None
+                }
+            };
+            let sat1 = Arc::new(SummedAreaTable::new(rows, columns, true, |row, col| {
+                pair_value(row, col).map(|v| v.0)
+            }));
+            let sat2 = Arc::new(SummedAreaTable::new(rows, columns, true, |row, col| {
+                pair_value(row, col).map(|v| v.1)
+            }));
+            let sat12 = Arc::new(SummedAreaTable::new(rows, columns, false, |row, col| {
+                pair_value(row, col).map(|v| v.0 * v.1)
+            }));
+
+            let midpoint = (filter_size / 2) as isize;
+            let num_procs = num_cpus::get() as isize;
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let image1 = image1.clone();
+                let image2 = image2.clone();
+                let sat1 = sat1.clone();
+                let sat2 = sat2.clone();
+                let sat12 = sat12.clone();
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    let (mut top, mut left, mut bottom, mut right): (isize, isize, isize, isize);
+                    let (mut n, mut cov, mut var1, mut var2): (f64, f64, f64, f64);
+                    for row in (0..rows).filter(|r| r % num_procs == tid) {
+                        let mut data = vec![out_nodata; columns as usize];
+                        for col in 0..columns {
+                            if image1[(row, col)] == nodata1 || image2[(row, col)] == nodata2 {
+                                continue;
+                            }
+                            top = row - midpoint;
+                            left = col - midpoint;
+                            bottom = row + midpoint;
+                            right = col + midpoint;
+                            n = sat12.count(top, left, bottom, right) as f64;
+                            if n < 3f64 {
+                                continue;
+                            }
+                            cov = sat12.sum(top, left, bottom, right) / n
+                                - sat1.sum(top, left, bottom, right)
+                                    * sat2.sum(top, left, bottom, right)
+                                    / (n * n);
+                            var1 = sat1.variance(top, left, bottom, right).unwrap_or(0f64);
+                            var2 = sat2.variance(top, left, bottom, right).unwrap_or(0f64);
+                            if var1 > 0f64 && var2 > 0f64 {
+                                data[col as usize] =
+                                    (cov / (var1 * var2).sqrt()).max(-1f64).min(1f64);
                             }
-                            tx.send((
-                                image1_total_deviation,
-                                image2_total_deviation,
-                                total_product_deviations,
-                            )).unwrap();
-                        });
+                        }
+                        tx.send((row, data)).unwrap();
                     }
-                    let mut image1_total_deviation = 0f64;
-                    let mut image2_total_deviation = 0f64;
-                    let mut total_product_deviations = 0f64;
-                    for _ in 0..num_procs {
-                        let (val1, val2, val3) = rx.recv().unwrap();
-                        image1_total_deviation += val1;
-                        image2_total_deviation += val2;
-                        total_product_deviations += val3;
+                });
+            }
+
+            let mut output = Raster::initialize_using_file(&local_file, &image1);
+            output.configs.nodata = out_nodata;
+            output.configs.data_type = DataType::F32;
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            for row in 0..rows {
+                let (r, data) = rx.recv().unwrap();
+                output.set_row_data(r, data);
+                if verbose {
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress("Progress", progress);
+                        old_progress = progress;
                     }
-                    correlation_matrix[a][b] = total_product_deviations
-                        / (image1_total_deviation * image2_total_deviation).sqrt();
                 }
             }
-            i += 1;
+            output.configs.palette = "blue_white_red.plt".to_string();
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file 1: {}", file_names[local_images.0]));
+            output.add_metadata_entry(format!("Input file 2: {}", file_names[local_images.1]));
+            output.add_metadata_entry(format!("Filter size: {}", filter_size));
 
             if verbose {
-                progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Calculating the correlation matrix ({} of {}): {}%",
-                        (a + 1),
-                        files_vec.len(),
-                        progress
-                    );
-                    old_progress = progress;
-                }
-            }
+                report::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
+        }
+
+        if !csv_file.is_empty() {
+            let f = File::create(csv_file.clone())?;
+            let mut writer = BufWriter::new(f);
+            let names = file_names
+                .iter()
+                .map(|f| {
+                    path::Path::new(f)
+                        .file_stem()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or(f.clone())
+                }).collect::<Vec<String>>();
+            writer.write_all(format!("IMAGE,{}\n", names.join(",")).as_bytes())?;
+            for a in 0..num_files {
+                let mut line = names[a].clone();
+                for b in 0..num_files {
+                    let value = if b <= a {
+                        correlation_matrix[a][b]
+                    } else {
+                        correlation_matrix[b][a]
+                    };
+                    if value != -99f64 {
+                        line.push_str(&format!(",{}", value));
+                    } else {
+                        line.push_str(",");
+                    }
+                }
+                line.push_str("\n");
+                writer.write_all(line.as_bytes())?;
+            }
+            let _ = writer.flush();
         }
 
         let f = File::create(output_file.clone())?;
@@ -392,33 +589,68 @@ impl WhiteboxTool for ImageCorrelation {
         }
         writer.write_all("</p>".as_bytes())?;
 
-        writer.write_all("<br><table align=\"center\">".as_bytes())?;
-        writer.write_all("<caption>Pearson correlation matrix</caption>".as_bytes())?;
+        let method = if spearman {
+            "Spearman rank"
+        } else {
+            "Pearson"
+        };
+        let tables = [
+            (format!("{} correlation matrix", method), &correlation_matrix, 4),
+            (
+                format!("Significance (two-tailed p-value) of {} correlations", method),
+                &p_value_matrix,
+                4,
+            ),
+            ("Covariance matrix".to_string(), &covariance_matrix, 4),
+        ];
+        for &(ref caption, matrix, precision) in tables.iter() {
+            writer.write_all("<br><table align=\"center\">".as_bytes())?;
+            writer.write_all(format!("<caption>{}</caption>", caption).as_bytes())?;
+            let mut out_string = String::from("<tr><th></th>");
+            for a in 0..num_files {
+                out_string.push_str(&format!("<th>Image {}</th>", a + 1));
+            }
+            out_string.push_str("</tr>");
+            for a in 0..num_files {
+                out_string.push_str("<tr>");
+                out_string.push_str(&format!("<td><strong>Image {}</strong></td>", a + 1));
+                for b in 0..num_files {
+                    let value = matrix[a][b];
+                    if value != -99f64 {
+                        out_string.push_str(&format!("<td>{:.*}</td>", precision, value));
+                    } else {
+                        out_string.push_str("<td></td>");
+                    }
+                }
+                out_string.push_str("</tr>");
+            }
+            writer.write_all(out_string.as_bytes())?;
+            writer.write_all("</table>".as_bytes())?;
+        }
 
+        writer.write_all("<br><table align=\"center\">".as_bytes())?;
+        writer.write_all("<caption>Number of cells with valid values in both images</caption>".as_bytes())?;
         let mut out_string = String::from("<tr><th></th>");
         for a in 0..num_files {
             out_string.push_str(&format!("<th>Image {}</th>", a + 1));
         }
         out_string.push_str("</tr>");
-
         for a in 0..num_files {
             out_string.push_str("<tr>");
             out_string.push_str(&format!("<td><strong>Image {}</strong></td>", a + 1));
             for b in 0..num_files {
-                let value = correlation_matrix[a][b];
-                if value != -99f64 {
-                    let value_str = &format!("{:.*}", 4, value);
-                    out_string.push_str(&format!("<td>{}</td>", value_str));
+                if b <= a {
+                    out_string.push_str(&format!("<td>{}</td>", n_matrix[a][b]));
                 } else {
                     out_string.push_str("<td></td>");
                 }
             }
             out_string.push_str("</tr>");
         }
-
         writer.write_all(out_string.as_bytes())?;
-
         writer.write_all("</table>".as_bytes())?;
+
+        writer.write_all("<p>Neighbouring grid cells are rarely independent and the p-values, which assume independent samples, should be interpreted with caution.</p>".as_bytes())?;
         writer.write_all("</body>".as_bytes())?;
 
         let _ = writer.flush();
@@ -447,10 +679,90 @@ impl WhiteboxTool for ImageCorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
         Ok(())
     }
 }
+
+/// Returns the values of the grid cells that are valid in both of two images.
+fn paired_values(image1: Arc<Raster>, image2: Arc<Raster>) -> (Vec<f64>, Vec<f64>) {
+    let rows = image1.configs.rows as isize;
+    let columns = image1.configs.columns as isize;
+    let nodata1 = image1.configs.nodata;
+    let nodata2 = image2.configs.nodata;
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let image1 = image1.clone();
+        let image2 = image2.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let mut x = vec![];
+            let mut y = vec![];
+            let (mut z1, mut z2): (f64, f64);
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                for col in 0..columns {
+                    z1 = image1[(row, col)];
+                    z2 = image2[(row, col)];
+                    if z1 != nodata1 && z2 != nodata2 {
+                        x.push(z1);
+                        y.push(z2);
+                    }
+                }
+            }
+            tx.send((x, y)).unwrap();
+        });
+    }
+    let mut x = vec![];
+    let mut y = vec![];
+    for _ in 0..num_procs {
+        let (mut x1, mut y1) = rx.recv().unwrap();
+        x.append(&mut x1);
+        y.append(&mut y1);
+    }
+    (x, y)
+}
+
+/// Returns the Pearson correlation and the (sample) covariance of two sets of values.
+fn correlation(x: &[f64], y: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean1 = x.iter().sum::<f64>() / n;
+    let mean2 = y.iter().sum::<f64>() / n;
+    let mut ss1 = 0f64;
+    let mut ss2 = 0f64;
+    let mut sp = 0f64;
+    for i in 0..x.len() {
+        ss1 += (x[i] - mean1) * (x[i] - mean1);
+        ss2 += (y[i] - mean2) * (y[i] - mean2);
+        sp += (x[i] - mean1) * (y[i] - mean2);
+    }
+    (sp / (ss1 * ss2).sqrt(), sp / (n - 1f64))
+}
+
+/// Returns the ranks of a set of values, starting at 1, with tied values assigned their
+/// average rank.
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| {
+        values[a]
+            .partial_cmp(&values[b])
+            .unwrap_or(Ordering::Equal)
+    });
+    let mut ranks = vec![0f64; values.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+        let rank = (i + j) as f64 / 2f64 + 1f64;
+        for k in i..(j + 1) {
+            ranks[order[k]] = rank;
+        }
+        i = j + 1;
+    }
+    ranks
+}
@@ -163,59 +163,26 @@ impl WhiteboxTool for ImageRegression {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i1"
-                || vec[0].to_lowercase() == "--i1"
-                || vec[0].to_lowercase() == "--input1"
-            {
-                if keyval {
-                    input_file1 = vec[1].to_string();
-                } else {
-                    input_file1 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-i2"
-                || vec[0].to_lowercase() == "--i2"
-                || vec[0].to_lowercase() == "--input2"
-            {
-                if keyval {
-                    input_file2 = vec[1].to_string();
-                } else {
-                    input_file2 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_residuals"
-                || vec[0].to_lowercase() == "--out_residuals"
-            {
-                if keyval {
-                    residuals_file = vec[1].to_string();
-                } else {
-                    residuals_file = args[i + 1].to_string();
-                }
-                output_residuals = true;
-            } else if vec[0].to_lowercase() == "-standardize"
-                || vec[0].to_lowercase() == "--standardize"
-            {
-                standardize_residuals = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input_file1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input_file2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_residuals"])? {
+            residuals_file = v;
+            output_residuals = true;
+        }
+        if tool_args.get_flag(&["--standardize"])? {
+            standardize_residuals = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -260,7 +227,7 @@ impl WhiteboxTool for ImageRegression {
         let start = Instant::now();
 
         if verbose {
-            println!("Loop 1 of 2...");
+            report::message("Loop 1 of 2...");
         }
 
         let num_procs = num_cpus::get() as isize;
@@ -321,7 +288,7 @@ impl WhiteboxTool for ImageRegression {
         let x_mean = sum_x / n;
 
         if verbose {
-            println!("Loop 2 of 2...");
+            report::message("Loop 2 of 2...");
         }
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
@@ -384,6 +351,8 @@ impl WhiteboxTool for ImageRegression {
             if !residuals_file.contains(&sep) {
                 residuals_file = format!("{}{}", working_directory, residuals_file);
             }
+            let mut output = Raster::initialize_using_file(&residuals_file, &input1);
+            let out_nodata = output.configs.nodata;
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
                 let input1 = input1.clone();
@@ -395,7 +364,7 @@ impl WhiteboxTool for ImageRegression {
                     let mut y_estimate: f64;
                     let mut residual: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data: Vec<f64> = vec![nodata1; columns as usize];
+                        let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                         for col in 0..columns {
                             x = input1[(row, col)];
                             y = input2[(row, col)];
@@ -414,7 +383,6 @@ impl WhiteboxTool for ImageRegression {
                 });
             }
 
-            let mut output = Raster::initialize_using_file(&residuals_file, &input1);
             for r in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 output.set_row_data(row, data);
@@ -422,18 +390,18 @@ impl WhiteboxTool for ImageRegression {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Creating residuals image: {}%", progress);
+                        report::progress("Creating residuals image", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                report::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 },
                 Err(e) => return Err(e),
             };
@@ -442,7 +410,7 @@ impl WhiteboxTool for ImageRegression {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("\n{}", &format!("Elapsed Time: {}", elapsed_time));
+            report::message(&format!("\n{}", &format!("Elapsed Time: {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -709,7 +677,7 @@ impl WhiteboxTool for ImageRegression {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
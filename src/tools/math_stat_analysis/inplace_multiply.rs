@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 18/03/2018
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
+use super::raster_math::MathOutputType;
 use raster::*;
 use std::env;
 use std::f64;
@@ -125,35 +126,16 @@ impl WhiteboxTool for InPlaceMultiply {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-input1" {
-                input1 = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-input2" {
-                input2 = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--input1"])? {
+            input1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--input2"])? {
+            input2 = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -180,7 +162,7 @@ impl WhiteboxTool for InPlaceMultiply {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let mut in1 = Raster::new(&input1, "rw")?;
 
@@ -189,6 +171,8 @@ impl WhiteboxTool for InPlaceMultiply {
         let rows = in1.configs.rows as isize;
         let columns = in1.configs.columns as isize;
         let nodata1 = in1.configs.nodata;
+        // values are rounded and range-checked when input1 has an integer data type
+        let out_type = MathOutputType::in_place(in1.configs.data_type, nodata1);
         let (mut z1, mut z2): (f64, f64);
 
         if input2_is_constant {
@@ -196,14 +180,14 @@ impl WhiteboxTool for InPlaceMultiply {
                 for col in 0..columns {
                     z1 = in1.get_value(row, col);
                     if z1 != nodata1 {
-                        in1.set_value(row, col, z1 * input2_constant);
+                        in1.set_value(row, col, out_type.convert(z1 * input2_constant));
                     }
                 }
 
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -211,7 +195,7 @@ impl WhiteboxTool for InPlaceMultiply {
         } else {
             // !input2_is_constant
             if verbose {
-                println!("Reading data...")
+                report::message("Reading data...")
             };
             let in2 = Raster::new(&input2, "r")?;
 
@@ -221,7 +205,7 @@ impl WhiteboxTool for InPlaceMultiply {
             // make sure the input files have the same size
             if in1.configs.rows != in2.configs.rows || in1.configs.columns != in2.configs.columns {
                 return Err(Error::new(ErrorKind::InvalidInput,
-                    "The input files must have the same number of rows and columns and spatial extent."));
+                    "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them."));
             }
 
             for row in 0..rows {
@@ -229,7 +213,7 @@ impl WhiteboxTool for InPlaceMultiply {
                     z1 = in1.get_value(row, col);
                     z2 = in2.get_value(row, col);
                     if z1 != nodata1 && z2 != nodata2 {
-                        in1.set_value(row, col, z1 * z2);
+                        in1.set_value(row, col, out_type.convert(z1 * z2));
                     } else if z1 != nodata1 {
                         in1.set_value(row, col, nodata1);
                     }
@@ -238,7 +222,7 @@ impl WhiteboxTool for InPlaceMultiply {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -248,22 +232,19 @@ impl WhiteboxTool for InPlaceMultiply {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         in1.update_min_max();
         in1.update_display_min_max();
         let _ = match in1.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -138,46 +138,19 @@ impl WhiteboxTool for KappaIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i1"
-                || vec[0].to_lowercase() == "--i1"
-                || vec[0].to_lowercase() == "--input1"
-            {
-                if keyval {
-                    input_file1 = vec[1].to_string();
-                } else {
-                    input_file1 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-i2"
-                || vec[0].to_lowercase() == "--i2"
-                || vec[0].to_lowercase() == "--input2"
-            {
-                if keyval {
-                    input_file2 = vec[1].to_string();
-                } else {
-                    input_file2 = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--i1", "--input1"])? {
+            input_file1 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--i2", "--input2"])? {
+            input_file2 = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,7 +172,7 @@ impl WhiteboxTool for KappaIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input1 = Raster::new(&input_file1, "r")?;
@@ -244,7 +217,7 @@ impl WhiteboxTool for KappaIndex {
             if verbose {
                 progress = (100.0_f64 * row as f64 / rows as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
@@ -558,15 +531,15 @@ impl WhiteboxTool for KappaIndex {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
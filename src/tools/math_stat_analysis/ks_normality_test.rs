@@ -148,41 +148,19 @@ impl WhiteboxTool for KSTestForNormality {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-base" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-num_samples" {
-                num_samples = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap() as usize
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--base"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_samples"])? {
+            num_samples = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -249,7 +227,7 @@ impl WhiteboxTool for KSTestForNormality {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / rows as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -267,7 +245,7 @@ impl WhiteboxTool for KSTestForNormality {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / rows as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -304,7 +282,7 @@ impl WhiteboxTool for KSTestForNormality {
                     if verbose {
                         progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress as usize);
                             old_progress = progress;
                         }
                     }
@@ -323,7 +301,7 @@ impl WhiteboxTool for KSTestForNormality {
                 if verbose {
                     progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress as usize);
                         old_progress = progress;
                     }
                 }
@@ -472,15 +450,12 @@ impl WhiteboxTool for KSTestForNormality {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
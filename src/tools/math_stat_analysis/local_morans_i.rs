@@ -0,0 +1,535 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use self::statrs::distribution::{Normal, Univariate};
+use super::spatial_weights::SpatialWeights;
+use num_cpus;
+use rand::prelude::*;
+use raster::*;
+use statrs;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use tools::*;
+
+/// This tool calculates the local Moran's I, a local indicator of spatial association
+/// (LISA; Anselin, 1995), for each grid cell of an input raster (`--input`). The local
+/// Moran's I of a cell is the product of its deviation from the raster mean and the
+/// weighted average deviation of its neighbours (the spatial lag), divided by the variance
+/// of the raster. Positive values indicate cells that are similar to their neighbours,
+/// i.e. parts of clusters of high or low values, and negative values indicate cells that
+/// differ from their neighbours (spatial outliers).
+///
+/// The neighbours of a cell are the valid cells within a square moving window (`--filter`,
+/// in grid cells) centred on it, excluding the cell itself. Each neighbour is weighted
+/// (`--weights`) either equally ('binary', the default), by the inverse of its distance
+/// from the centre cell ('inverse_distance'), or by a Gaussian function of its distance,
+/// with a standard deviation of half of the window radius ('gaussian'). The weights are
+/// row-standardized, i.e. the weights of the neighbours of each cell sum to one, such
+/// that cells near the edges of the raster or of nodata areas are treated consistently.
+///
+/// Besides the local Moran's I raster (`--output`), the tool optionally outputs the
+/// z-scores of the local statistic (`--z_output`), calculated from its expected value and
+/// variance under the randomization assumption (Anselin, 1995), and pseudo p-values
+/// (`--p_output`) from a conditional permutation test. In the permutation test, the values
+/// of the neighbours of each cell are replaced with values drawn at random from the raster
+/// a number of times (`--num_permutations`), and the pseudo p-value is (M + 1) / (R + 1),
+/// where R is the number of permutations and M is the number of them that yield a local
+/// statistic as extreme as, or more extreme than, the observed one. The `--clusters`
+/// output classifies the cells for which the pseudo p-value (or, if no permutations are
+/// performed, the two-tailed p-value of the z-score) is no greater than `--alpha` into
+/// high-high (1), low-low (2), high-low (3), and low-high (4) clusters and outliers; the
+/// remaining cells are assigned zero.
+///
+/// The global Moran's I, which with row-standardized weights is the mean of the local
+/// statistics, is reported when the tool is run in verbose mode and is recorded in the
+/// metadata of the output raster.
+///
+/// Notice that, as with all significance tests of local statistics, the many tests that
+/// are carried out (one per cell) are not independent, and the p-values are not corrected
+/// for multiple comparisons.
+///
+/// # Reference
+/// Anselin, L. (1995). Local indicators of spatial association—LISA. *Geographical
+/// Analysis*, 27(2), 93-115.
+///
+/// # See Also
+/// `GetisOrdGiStar`, `ImageAutocorrelation`
+pub struct LocalMoransI {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LocalMoransI {
+    pub fn new() -> LocalMoransI {
+        // public constructor
+        let name = "LocalMoransI".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description =
+            "Calculates the local Moran's I (LISA) spatial autocorrelation statistic of a raster."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output local Moran's I raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Z-Score File (optional)".to_owned(),
+            flags: vec!["--z_output".to_owned()],
+            description: "Optional output z-score raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Pseudo P-Value File (optional)".to_owned(),
+            flags: vec!["--p_output".to_owned()],
+            description: "Optional output pseudo p-value raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Cluster File (optional)".to_owned(),
+            flags: vec!["--clusters".to_owned()],
+            description: "Optional output raster file of significant clusters and outliers."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Size".to_owned(),
+            flags: vec!["--filter".to_owned()],
+            description: "Size of the neighbourhood window, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Weighting Scheme".to_owned(),
+            flags: vec!["--weights".to_owned()],
+            description: "Neighbour weighting scheme; options are 'binary', 'inverse_distance', and 'gaussian'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "binary".to_owned(),
+                "inverse_distance".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("binary".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Permutations".to_owned(),
+            flags: vec!["--num_permutations".to_owned()],
+            description: "Number of permutations used to calculate the pseudo p-values."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("99".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Significance Level".to_owned(),
+            flags: vec!["--alpha".to_owned()],
+            description: "Significance level used to identify clusters and outliers.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.05".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=twi.tif -o=lisa.tif --z_output=lisa_z.tif --p_output=lisa_p.tif --clusters=lisa_clusters.tif --filter=5 --weights=inverse_distance --num_permutations=999",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LocalMoransI {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LocalMoransI {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut z_file = String::new();
+        let mut p_file = String::new();
+        let mut cluster_file = String::new();
+        let mut filter_size = 3usize;
+        let mut scheme = String::from("binary");
+        let mut num_permutations = 99usize;
+        let mut alpha = 0.05f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--z_output"])? {
+            z_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--p_output"])? {
+            p_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--clusters"])? {
+            cluster_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--weights"])? {
+            scheme = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_permutations"])? {
+            num_permutations = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--alpha"])? {
+            alpha = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        for f in [&mut output_file, &mut z_file, &mut p_file, &mut cluster_file].iter_mut() {
+            if !f.is_empty() && !f.contains(&sep) && !f.contains("/") {
+                **f = format!("{}{}", working_directory, f);
+            }
+        }
+
+        let weights = Arc::new(SpatialWeights::new(filter_size, &scheme, false)?);
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+
+        let mut values = Vec::with_capacity(rows as usize * columns as usize);
+        for row in 0..rows {
+            for col in 0..columns {
+                let z = input.get_value(row, col);
+                if z != nodata {
+                    values.push(z);
+                }
+            }
+        }
+        let n = values.len() as f64;
+        if n < 3f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster must contain at least three valid grid cells.",
+            ));
+        }
+        let mean = values.iter().sum::<f64>() / n;
+        let m2 = values.iter().map(|z| (z - mean) * (z - mean)).sum::<f64>() / n;
+        let m4 = values.iter().map(|z| (z - mean).powi(4)).sum::<f64>() / n;
+        if m2 == 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input raster has no variation.",
+            ));
+        }
+        let b2 = m4 / (m2 * m2);
+        let values = Arc::new(values);
+
+        let out_nodata = -32768f64;
+        let num_procs = num_cpus::get() as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let weights = weights.clone();
+            let values = values.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut rng = thread_rng();
+                let distribution = Normal::new(0.0, 1.0).unwrap();
+                let num_values = values.len();
+                let e_i = -1f64 / (n - 1f64);
+                let mut w = Vec::with_capacity(weights.len());
+                let (mut z, mut zn, mut sum_w, mut sum_w2, mut lag): (f64, f64, f64, f64, f64);
+                let (mut local_i, mut var_i, mut lag_p, mut count): (f64, f64, f64, usize);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data_i = vec![out_nodata; columns as usize];
+                    let mut data_z = vec![out_nodata; columns as usize];
+                    let mut data_p = vec![out_nodata; columns as usize];
+                    let mut data_c = vec![out_nodata; columns as usize];
+                    for col in 0..columns {
+                        z = input.get_value(row, col);
+                        if z == nodata {
+                            continue;
+                        }
+                        w.clear();
+                        sum_w = 0f64;
+                        lag = 0f64;
+                        for k in 0..weights.len() {
+                            zn = input.get_value(row + weights.dy[k], col + weights.dx[k]);
+                            if zn != nodata {
+                                w.push(weights.weights[k]);
+                                sum_w += weights.weights[k];
+                                lag += weights.weights[k] * (zn - mean);
+                            }
+                        }
+                        if w.is_empty() || sum_w == 0f64 {
+                            continue;
+                        }
+                        // row-standardize the weights
+                        lag /= sum_w;
+                        sum_w2 = 0f64;
+                        for k in 0..w.len() {
+                            w[k] /= sum_w;
+                            sum_w2 += w[k] * w[k];
+                        }
+                        local_i = (z - mean) / m2 * lag;
+                        data_i[col as usize] = local_i;
+
+                        // expected value and variance under randomization (Anselin, 1995),
+                        // with row sums of one
+                        var_i = sum_w2 * (n - b2) / (n - 1f64)
+                            + (1f64 - sum_w2) * (2f64 * b2 - n) / ((n - 1f64) * (n - 2f64))
+                            - e_i * e_i;
+                        let z_score = if var_i > 0f64 {
+                            (local_i - e_i) / var_i.sqrt()
+                        } else {
+                            0f64
+                        };
+                        data_z[col as usize] = z_score;
+
+                        let p = if num_permutations > 0 {
+                            count = 0;
+                            for _ in 0..num_permutations {
+                                lag_p = 0f64;
+                                for k in 0..w.len() {
+                                    lag_p += w[k] * (values[rng.gen_range(0, num_values)] - mean);
+                                }
+                                if (local_i >= e_i && (z - mean) / m2 * lag_p >= local_i)
+                                    || (local_i < e_i && (z - mean) / m2 * lag_p <= local_i)
+                                {
+                                    count += 1;
+                                }
+                            }
+                            (count + 1) as f64 / (num_permutations + 1) as f64
+                        } else {
+                            2f64 * (1f64 - distribution.cdf(z_score.abs()))
+                        };
+                        data_p[col as usize] = p;
+
+                        data_c[col as usize] = if p <= alpha {
+                            match (z >= mean, lag >= 0f64) {
+                                (true, true) => 1f64,
+                                (false, false) => 2f64,
+                                (true, false) => 3f64,
+                                (false, true) => 4f64,
+                            }
+                        } else {
+                            0f64
+                        };
+                    }
+                    tx.send((row, data_i, data_z, data_p, data_c)).unwrap();
+                }
+            });
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.nodata = out_nodata;
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.palette = "blue_white_red.plt".to_string();
+        let mut z_output = if !z_file.is_empty() {
+            let mut r = Raster::initialize_using_file(&z_file, &output);
+            r.configs.palette = "blue_white_red.plt".to_string();
+            Some(r)
+        } else {
+            None
+        };
+        let mut p_output = if !p_file.is_empty() {
+            let mut r = Raster::initialize_using_file(&p_file, &output);
+            r.configs.palette = "spectrum.plt".to_string();
+            Some(r)
+        } else {
+            None
+        };
+        let mut cluster_output = if !cluster_file.is_empty() {
+            let mut r = Raster::initialize_using_file(&cluster_file, &output);
+            r.configs.data_type = DataType::I16;
+            r.configs.photometric_interp = PhotometricInterpretation::Categorical;
+            r.configs.palette = "qual.plt".to_string();
+            Some(r)
+        } else {
+            None
+        };
+        let mut sum_local_i = 0f64;
+        let mut num_local_i = 0f64;
+        for r in 0..rows {
+            let (row, data_i, data_z, data_p, data_c) = rx.recv().unwrap();
+            for v in data_i.iter().filter(|&&v| v != out_nodata) {
+                sum_local_i += v;
+                num_local_i += 1f64;
+            }
+            output.set_row_data(row, data_i);
+            if let Some(ref mut o) = z_output {
+                o.set_row_data(row, data_z);
+            }
+            if let Some(ref mut o) = p_output {
+                o.set_row_data(row, data_p);
+            }
+            if let Some(ref mut o) = cluster_output {
+                o.set_row_data(row, data_c);
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        let global_i = if num_local_i > 0f64 {
+            sum_local_i / num_local_i
+        } else {
+            0f64
+        };
+        if verbose {
+            report::metadata("Global Moran's I", &format!("{:.6}", global_i));
+            report::metadata("Expected value", &format!("{:.6}", -1f64 / (n - 1f64)));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        let metadata = vec![
+            format!("Created by whitebox_tools\' {} tool", self.get_tool_name()),
+            format!("Input file: {}", input_file),
+            format!("Filter size: {}", filter_size),
+            format!("Weighting scheme: {}", scheme),
+            format!("Number of permutations: {}", num_permutations),
+            format!("Global Moran's I: {}", global_i),
+            format!("Elapsed Time (excluding I/O): {}", elapsed_time),
+        ];
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        for o in Some(&mut output)
+            .into_iter()
+            .chain(z_output.as_mut())
+            .chain(p_output.as_mut())
+            .chain(cluster_output.as_mut())
+        {
+            for m in &metadata {
+                o.add_metadata_entry(m.clone());
+            }
+            let _ = match o.write() {
+                Ok(_) => if verbose {
+                    report::metadata("Output file written", &o.file_name)
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
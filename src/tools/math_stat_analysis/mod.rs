@@ -11,6 +11,7 @@ mod attribute_correlation;
 mod attribute_histogram;
 mod attribute_scattergram;
 mod ceil;
+mod classification_accuracy;
 mod cos;
 mod cosh;
 mod crispness_index;
@@ -23,6 +24,7 @@ mod exp;
 mod exp2;
 mod extract_statistics;
 mod floor;
+mod getis_ord_gi_star;
 mod greater_than;
 mod image_autocorrelation;
 mod image_correlation;
@@ -38,6 +40,7 @@ mod kappa_index;
 mod ks_normality_test;
 mod less_than;
 mod list_unique_values;
+mod local_morans_i;
 mod log10;
 mod log2;
 mod ln;
@@ -55,13 +58,17 @@ mod quantiles;
 mod random_field;
 mod random_sample;
 mod raster_histogram;
+mod raster_math;
+mod raster_statistics_report;
 mod raster_summary_stats;
 mod reciprocal;
 mod rescale_value_range;
 mod root_mean_square_error;
 mod round;
+mod semivariogram;
 mod sin;
 mod sinh;
+mod spatial_weights;
 mod sqrt;
 mod square;
 mod subtract;
@@ -90,6 +97,7 @@ pub use self::attribute_correlation::AttributeCorrelation;
 pub use self::attribute_histogram::AttributeHistogram;
 pub use self::attribute_scattergram::AttributeScattergram;
 pub use self::ceil::Ceil;
+pub use self::classification_accuracy::ClassificationAccuracy;
 pub use self::cos::Cos;
 pub use self::cosh::Cosh;
 pub use self::crispness_index::CrispnessIndex;
@@ -102,6 +110,7 @@ pub use self::exp::Exp;
 pub use self::exp2::Exp2;
 pub use self::extract_statistics::ExtractRasterStatistics;
 pub use self::floor::Floor;
+pub use self::getis_ord_gi_star::GetisOrdGiStar;
 pub use self::greater_than::GreaterThan;
 pub use self::image_autocorrelation::ImageAutocorrelation;
 pub use self::image_correlation::ImageCorrelation;
@@ -117,6 +126,7 @@ pub use self::kappa_index::KappaIndex;
 pub use self::ks_normality_test::KSTestForNormality;
 pub use self::less_than::LessThan;
 pub use self::list_unique_values::ListUniqueValues;
+pub use self::local_morans_i::LocalMoransI;
 pub use self::log10::Log10;
 pub use self::log2::Log2;
 pub use self::ln::Ln;
@@ -134,11 +144,13 @@ pub use self::quantiles::Quantiles;
 pub use self::random_field::RandomField;
 pub use self::random_sample::RandomSample;
 pub use self::raster_histogram::RasterHistogram;
+pub use self::raster_statistics_report::RasterStatisticsReport;
 pub use self::raster_summary_stats::RasterSummaryStats;
 pub use self::reciprocal::Reciprocal;
 pub use self::rescale_value_range::RescaleValueRange;
 pub use self::root_mean_square_error::RootMeanSquareError;
 pub use self::round::Round;
+pub use self::semivariogram::Semivariogram;
 pub use self::sin::Sin;
 pub use self::sinh::Sinh;
 pub use self::sqrt::SquareRoot;
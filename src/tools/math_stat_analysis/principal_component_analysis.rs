@@ -146,43 +146,22 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                input_files_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_html" {
-                output_html_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-num_comp" {
-                num_comp = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap() as usize
-                };
-            } else if flag_val == "-standardized" {
-                standardized = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_html"])? {
+            output_html_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_comp"])? {
+            num_comp = v;
+        }
+        if tool_args.get_flag(&["--standardized"])? {
+            standardized = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -221,7 +200,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         let mut input_raster: Vec<Raster> = Vec::with_capacity(num_files);
         let mut wd = "".to_string();
         let mut file_names = vec![];
-        println!("Calculating image means...");
+        report::message("Calculating image means...");
         for i in 0..num_files {
             if !input_files[i].trim().is_empty() {
                 // quality control on the image file name.
@@ -251,7 +230,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                         || input_raster[i].configs.columns as isize != columns
                     {
                         return Err(Error::new(ErrorKind::InvalidInput,
-                            "All input images must share the same dimensions (rows and columns) and spatial extent."));
+                            "All input images must share the same dimensions (rows and columns) and spatial extent; the Resample tool (--base) may be used to align them."));
                     }
                 }
             } else {
@@ -293,7 +272,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -502,7 +481,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                 let _ = output.stdout;
             }
 
-            println!("Please see {} for output report.", output_html_file);
+            report::message(&format!("Please see {} for output report.", output_html_file));
         }
 
         // Output the component images
@@ -525,7 +504,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Saving component image {}: {}%", (a + 1), progress);
+                        report::progress(&format!("Saving component image {}", (a + 1)), progress);
                         old_progress = progress;
                     }
                 }
@@ -539,7 +518,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
             output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving component image {}...", (a + 1))
+                report::message(&format!("Saving component image {}...", (a + 1)))
             };
             let _ = match output.write() {
                 Ok(_) => (),
@@ -550,10 +529,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,186 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::f32;
+use std::io::{Error, ErrorKind};
+
+/// The kind of values produced by a raster math operation, used to decide the
+/// output data type when `--data_type` is 'auto'.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MathResultKind {
+    /// Integer inputs always produce integers of the same signedness (e.g. add, multiply).
+    Integer,
+    /// Integer inputs produce integers that may be negative (e.g. subtract).
+    SignedInteger,
+    /// The operation produces fractional values (e.g. divide).
+    Float,
+}
+
+/// The output data type and nodata value of a raster math operation. Values are
+/// converted to the output type before they are stored, such that integer outputs
+/// are rounded and results that overflow the output type are assigned nodata, rather
+/// than being silently wrapped or truncated when the file is written.
+#[derive(Clone, Copy, Debug)]
+pub struct MathOutputType {
+    pub data_type: DataType,
+    pub nodata: f64,
+    range: Option<(f64, f64)>,
+}
+
+impl MathOutputType {
+    /// Determines the output type from the value of a `--data_type` parameter. The
+    /// 'auto' type keeps integer inputs in an integer type wide enough to hold the
+    /// input rasters and constants, and otherwise uses F32, or F64 if any input is F64.
+    /// `nodata` is the nodata value of the initialized output raster and is retained
+    /// whenever the output type can represent it.
+    pub fn new(
+        requested: &str,
+        inputs: &[DataType],
+        constants: &[f64],
+        kind: MathResultKind,
+        nodata: f64,
+    ) -> Result<MathOutputType, Error> {
+        let data_type = match requested.trim().to_lowercase().as_ref() {
+            "" | "auto" => MathOutputType::auto_type(inputs, constants, kind),
+            "f64" | "double" => DataType::F64,
+            "f32" | "float" => DataType::F32,
+            "i64" => DataType::I64,
+            "i32" | "integer" => DataType::I32,
+            "i16" => DataType::I16,
+            "i8" => DataType::I8,
+            "u64" => DataType::U64,
+            "u32" => DataType::U32,
+            "u16" => DataType::U16,
+            "u8" => DataType::U8,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "Unrecognized output data type '{}'; use one of auto, f64, f32, i64, i32, i16, i8, u64, u32, u16, or u8.",
+                        requested
+                    ),
+                ))
+            }
+        };
+        let nodata = if data_type.can_represent(nodata) {
+            nodata
+        } else {
+            match data_type.integer_range() {
+                Some((min, _)) if data_type.is_signed_integer() => min,
+                Some((_, max)) => max,
+                None => -32768f64,
+            }
+        };
+        Ok(MathOutputType::in_place(data_type, nodata))
+    }
+
+    /// The output type of an operation that stores its results in an existing raster
+    /// of the specified data type and nodata value.
+    pub fn in_place(data_type: DataType, nodata: f64) -> MathOutputType {
+        MathOutputType {
+            data_type: data_type,
+            nodata: nodata,
+            range: data_type.integer_range(),
+        }
+    }
+
+    /// Converts a computed value to the output type. Integer outputs are rounded, and
+    /// values that cannot be stored in the output type, including those that collide
+    /// with the nodata value, are assigned nodata.
+    pub fn convert(&self, value: f64) -> f64 {
+        if !value.is_finite() {
+            return self.nodata;
+        }
+        match self.range {
+            Some((min, max)) => {
+                let z = value.round();
+                if z < min || z > max || z == self.nodata {
+                    self.nodata
+                } else {
+                    z
+                }
+            }
+            None => {
+                if self.data_type == DataType::F32 && value.abs() > f32::MAX as f64 {
+                    self.nodata
+                } else {
+                    value
+                }
+            }
+        }
+    }
+
+    /// Sets the data type and nodata value of an output raster.
+    pub fn configure(&self, output: &mut Raster) {
+        output.configs.data_type = self.data_type;
+        output.configs.nodata = self.nodata;
+    }
+
+    fn auto_type(inputs: &[DataType], constants: &[f64], kind: MathResultKind) -> DataType {
+        let all_integer = inputs.iter().all(|dt| dt.is_integer())
+            && constants.iter().all(|c| c.is_finite() && c.fract() == 0f64);
+        if kind == MathResultKind::Float || !all_integer {
+            if inputs.iter().any(|dt| *dt == DataType::F64) {
+                return DataType::F64;
+            }
+            return DataType::F32;
+        }
+
+        // the number of bytes needed by signed and unsigned values respectively
+        let mut signed_size = 0usize;
+        let mut unsigned_size = 0usize;
+        for dt in inputs {
+            if dt.is_signed_integer() {
+                signed_size = signed_size.max(dt.get_data_size());
+            } else {
+                unsigned_size = unsigned_size.max(dt.get_data_size());
+            }
+        }
+        for c in constants {
+            let candidates = if *c < 0f64 {
+                [DataType::I8, DataType::I16, DataType::I32, DataType::I64]
+            } else {
+                [DataType::U8, DataType::U16, DataType::U32, DataType::U64]
+            };
+            let dt = candidates
+                .iter()
+                .find(|dt| dt.can_represent(*c))
+                .cloned()
+                .unwrap_or(candidates[3]);
+            if dt.is_signed_integer() {
+                signed_size = signed_size.max(dt.get_data_size());
+            } else {
+                unsigned_size = unsigned_size.max(dt.get_data_size());
+            }
+        }
+        if (signed_size > 0 || kind == MathResultKind::SignedInteger)
+            && signed_size <= unsigned_size
+        {
+            // unsigned values need a wider signed type to keep their full range
+            signed_size = (unsigned_size * 2).min(8);
+        }
+
+        if signed_size > 0 {
+            match signed_size {
+                1 => DataType::I8,
+                2 => DataType::I16,
+                4 => DataType::I32,
+                _ => DataType::I64,
+            }
+        } else {
+            match unsigned_size {
+                0 | 1 => DataType::U8,
+                2 => DataType::U16,
+                4 => DataType::U32,
+                _ => DataType::U64,
+            }
+        }
+    }
+}
+
@@ -0,0 +1,680 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use rendering::html::*;
+use rendering::{Histogram, Scattergram};
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use std::process::Command;
+use tools::*;
+
+/// This tool creates a standalone HTML report that summarizes one or more rasters
+/// (`--inputs`), for a quick look at the outputs of an analysis. For each raster, the report
+/// contains a table of summary statistics (the numbers of valid and nodata cells, minimum,
+/// maximum, mean, standard deviation, skewness, kurtosis, median, and percentiles), a
+/// histogram, and a cumulative distribution plot. The histograms have the specified number
+/// of bins (`--num_bins`). The median and percentiles are estimated from a 10,000-bin
+/// histogram and are therefore approximate for floating-point data.
+///
+/// When two or more rasters with the same numbers of rows and columns are specified, the
+/// report also contains the matrix of Pearson correlation coefficients between them, the
+/// least-squares regression of each pair, and a scatterplot of each pair. The correlations
+/// and regressions are calculated using all of the cells that are valid in both rasters,
+/// while the scatterplots show a systematic sample of at most `--sample` of these cells.
+///
+/// By default, the report is saved next to the first input, with the input's file name and a
+/// '_report.html' suffix; alternatively, an output file may be specified (`--output`).
+///
+/// # See Also
+/// `RasterHistogram`, `RasterSummaryStats`, `ImageCorrelation`, `ImageRegression`
+pub struct RasterStatisticsReport {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl RasterStatisticsReport {
+    pub fn new() -> RasterStatisticsReport {
+        // public constructor
+        let name = "RasterStatisticsReport".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description = "Creates an HTML report of the statistics, histograms, and correlations of one or more rasters.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Files".to_owned(),
+            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            description: "Input raster files.".to_owned(),
+            parameter_type: ParameterType::FileList(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output HTML File (optional)".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output HTML file; saved next to the first input if unspecified.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Histogram Bins".to_owned(),
+            flags: vec!["--num_bins".to_owned()],
+            description: "Number of histogram bins.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("100".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Scatterplot Sample Size".to_owned(),
+            flags: vec!["--sample".to_owned()],
+            description: "Maximum number of cells shown in each scatterplot.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1000".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='band1.tif;band2.tif;band3.tif' -o=report.html --num_bins=50 --sample=2000", short_exe, name).replace("*", &sep);
+
+        RasterStatisticsReport {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for RasterStatisticsReport {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_files_str = String::new();
+        let mut output_file = String::new();
+        let mut num_bins = 100usize;
+        let mut sample_size = 1000usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs", "--input"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v.trim().to_string();
+        }
+        if let Some(v) = tool_args.get_isize(&["--num_bins"])? {
+            num_bins = v.max(1) as usize;
+        }
+        if let Some(v) = tool_args.get_isize(&["--sample"])? {
+            sample_size = v.max(2) as usize;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let input_files: Vec<String> = input_files_str
+            .split(|c| c == ';' || c == ',')
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", working_directory, f)
+                } else {
+                    f.to_string()
+                }
+            })
+            .collect();
+        if input_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one input raster is required to operate this tool.",
+            ));
+        }
+        if output_file.is_empty() {
+            // the report is written next to the data
+            let p = Path::new(&input_files[0]);
+            let stem = match p.file_stem() {
+                Some(s) => s.to_string_lossy().to_string(),
+                None => String::from("raster"),
+            };
+            output_file = p
+                .with_file_name(format!("{}_report.html", stem))
+                .to_string_lossy()
+                .to_string();
+        } else if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        let num_files = input_files.len();
+        let mut inputs = Vec::with_capacity(num_files);
+        for input_file in &input_files {
+            if verbose {
+                report::message(&format!("Reading {}...", input_file));
+            }
+            inputs.push(Raster::new(input_file, "r")?);
+        }
+        let names: Vec<String> = input_files
+            .iter()
+            .map(|f| match Path::new(f).file_name() {
+                Some(n) => n.to_string_lossy().to_string(),
+                None => f.clone(),
+            })
+            .collect();
+
+        let start = Instant::now();
+
+        let f = File::create(output_file.as_str())?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all(&r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd">
+        <head>
+            <meta content="text/html; charset=UTF-8" http-equiv="content-type">
+            <title>Raster Statistics Report</title>"#.as_bytes())?;
+        writer.write_all(&get_css().as_bytes())?;
+        writer.write_all(
+            &r#"</head>
+        <body>
+            <h1>Raster Statistics Report</h1>"#
+                .as_bytes(),
+        )?;
+
+        // univariate statistics
+        let mut means = vec![0f64; num_files];
+        for (a, input) in inputs.iter().enumerate() {
+            let stats = match RasterStatistics::calculate(input, num_bins) {
+                Some(s) => s,
+                None => {
+                    writer.write_all(
+                        &format!(
+                            "<h2>{}</h2><p>The raster does not contain any valid cells.</p>",
+                            names[a]
+                        ).as_bytes(),
+                    )?;
+                    continue;
+                }
+            };
+            means[a] = stats.mean;
+            writer.write_all(&format!("<h2>{}</h2>", names[a]).as_bytes())?;
+            writer.write_all(
+                &format!("<p><strong>File</strong>: {}</p>", input_files[a]).as_bytes(),
+            )?;
+            writer.write_all(b"<div align=\"center\"><table><tr><th>Statistic</th><th>Value</th></tr>")?;
+            let configs = &input.configs;
+            let mut rows: Vec<(String, String)> = vec![
+                ("Rows".to_string(), configs.rows.to_string()),
+                ("Columns".to_string(), configs.columns.to_string()),
+                (
+                    "Resolution (x, y)".to_string(),
+                    format!("{}, {}", configs.resolution_x, configs.resolution_y),
+                ),
+                ("Data type".to_string(), format!("{:?}", configs.data_type)),
+                ("Nodata value".to_string(), configs.nodata.to_string()),
+                ("Valid cells".to_string(), stats.n.to_string()),
+                ("Nodata cells".to_string(), stats.num_nodata.to_string()),
+                ("Minimum".to_string(), format_value(stats.min)),
+                ("Maximum".to_string(), format_value(stats.max)),
+                ("Range".to_string(), format_value(stats.max - stats.min)),
+                ("Mean".to_string(), format_value(stats.mean)),
+                ("Standard deviation".to_string(), format_value(stats.std_dev)),
+                ("Variance".to_string(), format_value(stats.std_dev * stats.std_dev)),
+                ("Skewness".to_string(), format_value(stats.skewness)),
+                ("Kurtosis (excess)".to_string(), format_value(stats.kurtosis)),
+            ];
+            for &(p, label) in &[
+                (1f64, "1st percentile"),
+                (5f64, "5th percentile"),
+                (25f64, "Lower quartile"),
+                (50f64, "Median"),
+                (75f64, "Upper quartile"),
+                (95f64, "95th percentile"),
+                (99f64, "99th percentile"),
+            ] {
+                rows.push((label.to_string(), format_value(stats.percentile(p))));
+            }
+            for (label, value) in rows {
+                writer.write_all(
+                    &format!(
+                        "<tr><td>{}</td><td class=\"numberCell\">{}</td></tr>",
+                        label, value
+                    ).as_bytes(),
+                )?;
+            }
+            writer.write_all(b"</table></div>")?;
+
+            for &cumulative in &[false, true] {
+                let id = format!("histo{}{}", a, if cumulative { "cdf" } else { "" });
+                let histo = Histogram {
+                    parent_id: id.clone(),
+                    width: 700f64,
+                    height: 500f64,
+                    freq_data: stats.display_bins.clone(),
+                    min_bin_val: stats.min,
+                    bin_width: stats.display_bin_width,
+                    x_axis_label: names[a].clone(),
+                    cumulative: cumulative,
+                };
+                writer.write_all(
+                    &format!(
+                        "<p><strong>{}</strong></p><div id='{}' align=\"center\">{}</div>",
+                        if cumulative {
+                            "Cumulative distribution"
+                        } else {
+                            "Histogram"
+                        },
+                        id,
+                        histo.get_svg()
+                    ).as_bytes(),
+                )?;
+            }
+            if verbose {
+                report::message(&format!("Summarized {} of {}", a + 1, num_files));
+            }
+        }
+
+        // bivariate statistics
+        if num_files > 1 {
+            let rows = inputs[0].configs.rows;
+            let columns = inputs[0].configs.columns;
+            if inputs
+                .iter()
+                .any(|r| r.configs.rows != rows || r.configs.columns != columns)
+            {
+                writer.write_all(b"<h2>Correlation</h2><p>The correlations between the rasters are not reported because they do not all have the same numbers of rows and columns.</p>")?;
+            } else {
+                if verbose {
+                    report::message("Calculating the correlations...");
+                }
+                let mut pairs = vec![];
+                let mut correlations = vec![vec![1f64; num_files]; num_files];
+                for a in 0..num_files {
+                    for b in a + 1..num_files {
+                        let pair = PairStatistics::calculate(
+                            &inputs[a],
+                            &inputs[b],
+                            means[a],
+                            means[b],
+                            sample_size,
+                        );
+                        correlations[a][b] = pair.r;
+                        correlations[b][a] = pair.r;
+                        pairs.push((a, b, pair));
+                    }
+                }
+
+                writer.write_all(b"<h2>Correlation Matrix</h2><div align=\"center\"><table><tr><th></th>")?;
+                for a in 0..num_files {
+                    writer.write_all(&format!("<th>{}</th>", names[a]).as_bytes())?;
+                }
+                writer.write_all(b"</tr>")?;
+                for a in 0..num_files {
+                    writer.write_all(&format!("<tr><td><strong>{}</strong></td>", names[a]).as_bytes())?;
+                    for b in 0..num_files {
+                        writer.write_all(
+                            &format!(
+                                "<td class=\"numberCell\">{}</td>",
+                                format_value(correlations[a][b])
+                            ).as_bytes(),
+                        )?;
+                    }
+                    writer.write_all(b"</tr>")?;
+                }
+                writer.write_all(b"</table></div>")?;
+
+                writer.write_all(b"<h2>Pairwise Regressions</h2><div align=\"center\"><table><tr><th>X</th><th>Y</th><th>N</th><th>r</th><th>R<sup>2</sup></th><th>Slope</th><th>Intercept</th></tr>")?;
+                for &(a, b, ref pair) in &pairs {
+                    writer.write_all(
+                        &format!(
+                            "<tr><td>{}</td><td>{}</td><td class=\"numberCell\">{}</td><td class=\"numberCell\">{}</td><td class=\"numberCell\">{}</td><td class=\"numberCell\">{}</td><td class=\"numberCell\">{}</td></tr>",
+                            names[a],
+                            names[b],
+                            pair.n,
+                            format_value(pair.r),
+                            format_value(pair.r * pair.r),
+                            format_value(pair.slope),
+                            format_value(pair.intercept)
+                        ).as_bytes(),
+                    )?;
+                }
+                writer.write_all(b"</table></div>")?;
+
+                for &(a, b, ref pair) in &pairs {
+                    let id = format!("scatter{}_{}", a, b);
+                    let plot = Scattergram {
+                        parent_id: id.clone(),
+                        width: 700f64,
+                        height: 500f64,
+                        data_x: vec![pair.sample_x.clone()],
+                        data_y: vec![pair.sample_y.clone()],
+                        series_labels: vec![format!("{} vs. {}", names[b], names[a])],
+                        x_axis_label: names[a].clone(),
+                        y_axis_label: names[b].clone(),
+                        draw_trendline: true,
+                        draw_gridlines: true,
+                        draw_legend: false,
+                        draw_grey_background: false,
+                    };
+                    writer.write_all(
+                        &format!(
+                            "<p><strong>{} vs. {}</strong></p><div id='{}' align=\"center\">{}</div>",
+                            names[b],
+                            names[a],
+                            id,
+                            plot.get_svg()
+                        ).as_bytes(),
+                    )?;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        writer.write_all(
+            &format!("<p>Elapsed Time: {}</p></body>", elapsed_time).as_bytes(),
+        )?;
+        let _ = writer.flush();
+
+        if verbose {
+            if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+                let output = Command::new("open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "windows") {
+                let output = Command::new("explorer.exe")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "linux") {
+                let output = Command::new("xdg-open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            }
+            report::message(&format!("Complete! Please see {} for output.", output_file));
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a statistic for the report.
+fn format_value(value: f64) -> String {
+    if !value.is_finite() {
+        String::from("-")
+    } else if value == value.round() && value.abs() < 1e15 {
+        format!("{}", value)
+    } else {
+        format!("{:.4}", value)
+    }
+}
+
+/// The summary statistics of a raster.
+struct RasterStatistics {
+    n: usize,
+    num_nodata: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+    std_dev: f64,
+    skewness: f64,
+    kurtosis: f64,
+    fine_bins: Vec<usize>,
+    display_bins: Vec<usize>,
+    display_bin_width: f64,
+}
+
+impl RasterStatistics {
+    const NUM_FINE_BINS: usize = 10000;
+
+    /// Calculates the statistics of a raster in two passes, or returns None if it has no
+    /// valid cells.
+    fn calculate(input: &Raster, num_bins: usize) -> Option<RasterStatistics> {
+        let nodata = input.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let (mut n, mut num_nodata) = (0usize, 0usize);
+        let (mut min, mut max, mut sum) = (f64::INFINITY, f64::NEG_INFINITY, 0f64);
+        for row in 0..rows {
+            for z in input.get_row_data(row) {
+                if z != nodata && z.is_finite() {
+                    n += 1;
+                    sum += z;
+                    min = min.min(z);
+                    max = max.max(z);
+                } else {
+                    num_nodata += 1;
+                }
+            }
+        }
+        if n == 0 {
+            return None;
+        }
+        let mean = sum / n as f64;
+        let range = (max - min).max(f64::MIN_POSITIVE);
+        let display_bin_width = if max > min {
+            range / num_bins as f64
+        } else {
+            1f64
+        };
+        let mut fine_bins = vec![0usize; RasterStatistics::NUM_FINE_BINS];
+        let mut display_bins = vec![0usize; num_bins];
+        let (mut m2, mut m3, mut m4) = (0f64, 0f64, 0f64);
+        for row in 0..rows {
+            for z in input.get_row_data(row) {
+                if z != nodata && z.is_finite() {
+                    let d = z - mean;
+                    m2 += d * d;
+                    m3 += d * d * d;
+                    m4 += d * d * d * d;
+                    let p = (z - min) / range;
+                    let fine = (p * RasterStatistics::NUM_FINE_BINS as f64) as usize;
+                    fine_bins[fine.min(RasterStatistics::NUM_FINE_BINS - 1)] += 1;
+                    let bin = ((z - min) / display_bin_width) as usize;
+                    display_bins[bin.min(num_bins - 1)] += 1;
+                }
+            }
+        }
+        m2 /= n as f64;
+        m3 /= n as f64;
+        m4 /= n as f64;
+        let (skewness, kurtosis) = if m2 > 0f64 {
+            (m3 / m2.powf(1.5), m4 / (m2 * m2) - 3f64)
+        } else {
+            (f64::NAN, f64::NAN)
+        };
+        Some(RasterStatistics {
+            n: n,
+            num_nodata: num_nodata,
+            min: min,
+            max: max,
+            mean: mean,
+            std_dev: m2.sqrt(),
+            skewness: skewness,
+            kurtosis: kurtosis,
+            fine_bins: fine_bins,
+            display_bins: display_bins,
+            display_bin_width: display_bin_width,
+        })
+    }
+
+    /// Estimates a percentile from the fine histogram, interpolating within the bin that
+    /// contains it.
+    fn percentile(&self, p: f64) -> f64 {
+        if self.max == self.min {
+            return self.min;
+        }
+        let target = p / 100f64 * self.n as f64;
+        let bin_width = (self.max - self.min) / RasterStatistics::NUM_FINE_BINS as f64;
+        let mut cumulative = 0f64;
+        for (i, &count) in self.fine_bins.iter().enumerate() {
+            let next = cumulative + count as f64;
+            if next >= target && count > 0 {
+                let fraction = (target - cumulative) / count as f64;
+                return self.min + (i as f64 + fraction) * bin_width;
+            }
+            cumulative = next;
+        }
+        self.max
+    }
+}
+
+/// The correlation and regression of a pair of rasters, and a sample of their values.
+struct PairStatistics {
+    n: usize,
+    r: f64,
+    slope: f64,
+    intercept: f64,
+    sample_x: Vec<f64>,
+    sample_y: Vec<f64>,
+}
+
+impl PairStatistics {
+    fn calculate(
+        x: &Raster,
+        y: &Raster,
+        mean_x: f64,
+        mean_y: f64,
+        sample_size: usize,
+    ) -> PairStatistics {
+        let (nodata_x, nodata_y) = (x.configs.nodata, y.configs.nodata);
+        let rows = x.configs.rows as isize;
+        let valid = |zx: f64, zy: f64| {
+            zx != nodata_x && zy != nodata_y && zx.is_finite() && zy.is_finite()
+        };
+        let mut n = 0usize;
+        let (mut sxx, mut syy, mut sxy) = (0f64, 0f64, 0f64);
+        let (mut sum_x, mut sum_y) = (0f64, 0f64);
+        for row in 0..rows {
+            for (zx, zy) in x.get_row_data(row).into_iter().zip(y.get_row_data(row)) {
+                if valid(zx, zy) {
+                    n += 1;
+                    // deviations from the means of the whole rasters, for numerical
+                    // stability; the sums are corrected for the means of the pair below
+                    let (dx, dy) = (zx - mean_x, zy - mean_y);
+                    sum_x += dx;
+                    sum_y += dy;
+                    sxx += dx * dx;
+                    syy += dy * dy;
+                    sxy += dx * dy;
+                }
+            }
+        }
+        let nf = n as f64;
+        let (mut r, mut slope, mut intercept) = (f64::NAN, f64::NAN, f64::NAN);
+        if n > 1 {
+            sxx -= sum_x * sum_x / nf;
+            syy -= sum_y * sum_y / nf;
+            sxy -= sum_x * sum_y / nf;
+            if sxx > 0f64 && syy > 0f64 {
+                r = sxy / (sxx * syy).sqrt();
+            }
+            if sxx > 0f64 {
+                slope = sxy / sxx;
+                intercept = (mean_y + sum_y / nf) - slope * (mean_x + sum_x / nf);
+            }
+        }
+
+        // a systematic sample of the cells that are valid in both rasters
+        let step = ((n as f64 / sample_size as f64).ceil() as usize).max(1);
+        let mut sample_x = vec![];
+        let mut sample_y = vec![];
+        let mut i = 0usize;
+        for row in 0..rows {
+            for (zx, zy) in x.get_row_data(row).into_iter().zip(y.get_row_data(row)) {
+                if valid(zx, zy) {
+                    if i % step == 0 {
+                        sample_x.push(zx);
+                        sample_y.push(zy);
+                    }
+                    i += 1;
+                }
+            }
+        }
+        PairStatistics {
+            n: n,
+            r: r,
+            slope: slope,
+            intercept: intercept,
+            sample_x: sample_x,
+            sample_y: sample_y,
+        }
+    }
+}
@@ -2,21 +2,38 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 1, 2017
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
 use num_cpus;
 use raster::*;
+use serde_json;
 use std::env;
 use std::f64;
-use std::io::{Error, ErrorKind};
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
 use tools::*;
 
+/// This tool measures the summary statistics of a raster (`--input`), i.e. the numbers of
+/// valid and nodata cells, and the minimum, maximum, total, average, variance, and standard
+/// deviation of the valid cells' values, which are printed. The statistics may also be
+/// saved to an output file (`--output`), along with a histogram of the values with a
+/// specified number of bins (`--num_bins`) spanning the range of the data, for use in
+/// reporting pipelines. Files with a `.json` extension are written as a JSON object
+/// containing the statistics and the histogram's bin width, lower bin bounds, and counts.
+/// All other files are written as CSV, with a header row of statistic names and a row of
+/// values, followed by the bin width and the counts of the bins (`BIN_1`, `BIN_2`, ...);
+/// the CSV outputs of several rasters with the same number of bins may therefore be
+/// concatenated into a single table.
+///
+/// # See Also
+/// `RasterHistogram`, `ListUniqueValues`, `AttributesToCsv`
 pub struct RasterSummaryStats {
     name: String,
     description: String,
@@ -44,6 +61,24 @@ impl RasterSummaryStats {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV (.csv) or JSON (.json) statistics file (optional).".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Histogram Bins".to_owned(),
+            flags: vec!["--num_bins".to_owned()],
+            description: "Number of bins of the histogram in the output file.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("50".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -56,7 +91,8 @@ impl RasterSummaryStats {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=DEM_stats.json --num_bins=100",
             short_exe, name
         ).replace("*", &sep);
 
@@ -112,6 +148,8 @@ impl WhiteboxTool for RasterSummaryStats {
         verbose: bool,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut num_bins = 50usize;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -119,28 +157,19 @@ impl WhiteboxTool for RasterSummaryStats {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_bins"])? {
+            num_bins = v.max(1);
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -151,9 +180,12 @@ impl WhiteboxTool for RasterSummaryStats {
         if !input_file.contains(&sep) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
+        if !output_file.is_empty() && !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -178,15 +210,19 @@ impl WhiteboxTool for RasterSummaryStats {
                     let mut n = 0;
                     let mut s = 0.0;
                     let mut sq = 0.0;
+                    let mut min = f64::INFINITY;
+                    let mut max = f64::NEG_INFINITY;
                     for col in 0..columns {
                         z = input[(row, col)];
                         if z != nodata {
                             n += 1;
                             s += z;
                             sq += z * z;
+                            min = min.min(z);
+                            max = max.max(z);
                         }
                     }
-                    tx.send((n, s, sq)).unwrap();
+                    tx.send((n, s, sq, min, max)).unwrap();
                 }
             });
         }
@@ -194,16 +230,20 @@ impl WhiteboxTool for RasterSummaryStats {
         let mut num_cells = 0;
         let mut sum = 0.0;
         let mut sq_sum = 0.0;
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
         for row in 0..rows {
-            let (a, b, c) = rx.recv().unwrap();
+            let (a, b, c, d, e) = rx.recv().unwrap();
             num_cells += a;
             sum += b;
             sq_sum += c;
+            min = min.min(d);
+            max = max.max(e);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -213,24 +253,136 @@ impl WhiteboxTool for RasterSummaryStats {
         let variance = sq_sum / num_cells as f64 - mean * mean;
         let std_dev = variance.sqrt();
 
+        // the histogram of the values, only needed for the output file
+        let mut histogram = vec![0usize; num_bins];
+        let bin_width = if max > min {
+            (max - min) / num_bins as f64
+        } else {
+            1f64
+        };
+        if !output_file.is_empty() && num_cells > 0 {
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let input = input.clone();
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    let mut z: f64;
+                    for row in (0..rows).filter(|r| r % num_procs == tid) {
+                        let mut counts = vec![0usize; num_bins];
+                        for col in 0..columns {
+                            z = input[(row, col)];
+                            if z != nodata {
+                                let bin = ((z - min) / bin_width).floor() as usize;
+                                counts[bin.min(num_bins - 1)] += 1;
+                            }
+                        }
+                        tx.send(counts).unwrap();
+                    }
+                });
+            }
+            for _ in 0..rows {
+                let counts = rx.recv().unwrap();
+                for bin in 0..num_bins {
+                    histogram[bin] += counts[bin];
+                }
+            }
+        }
+
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("\nNumber of non-nodata grid cells: {}", num_cells);
-        println!(
-            "Number of nodata grid cells: {}",
-            input.num_cells() - num_cells
-        );
-        println!("Image total: {}", sum);
-        println!("Image average: {}", mean);
-        println!("Image variance: {}", variance);
-        println!("Image standard deviation: {}", std_dev);
+        let stats = SummaryStats {
+            file: input_file.clone(),
+            num_cells: num_cells,
+            num_nodata_cells: input.num_cells() - num_cells,
+            min: min,
+            max: max,
+            total: sum,
+            mean: mean,
+            variance: variance,
+            std_dev: std_dev,
+            histogram: Histogram {
+                bin_width: bin_width,
+                lower_bounds: (0..num_bins).map(|b| min + b as f64 * bin_width).collect(),
+                counts: histogram,
+            },
+        };
+
+        report::message(&format!("\nNumber of non-nodata grid cells: {}", stats.num_cells));
+        report::metadata("Number of nodata grid cells", &stats.num_nodata_cells.to_string());
+        report::metadata("Image minimum", &stats.min.to_string());
+        report::metadata("Image maximum", &stats.max.to_string());
+        report::metadata("Image total", &stats.total.to_string());
+        report::metadata("Image average", &stats.mean.to_string());
+        report::metadata("Image variance", &stats.variance.to_string());
+        report::metadata("Image standard deviation", &stats.std_dev.to_string());
+
+        if !output_file.is_empty() {
+            let f = File::create(&output_file)?;
+            let mut writer = BufWriter::new(f);
+            if output_file.to_lowercase().ends_with(".json") {
+                let s = match serde_json::to_string_pretty(&stats) {
+                    Ok(s) => s,
+                    Err(e) => return Err(Error::new(ErrorKind::Other, format!("{}", e))),
+                };
+                writer.write_all(s.as_bytes())?;
+                writer.write_all(b"\n")?;
+            } else {
+                let mut header = String::from(
+                    "FILE,NUM_CELLS,NUM_NODATA,MIN,MAX,TOTAL,MEAN,VARIANCE,STD_DEV,BIN_WIDTH",
+                );
+                let mut values = format!(
+                    "\"{}\",{},{},{},{},{},{},{},{},{}",
+                    stats.file.replace("\"", "\"\""),
+                    stats.num_cells,
+                    stats.num_nodata_cells,
+                    stats.min,
+                    stats.max,
+                    stats.total,
+                    stats.mean,
+                    stats.variance,
+                    stats.std_dev,
+                    stats.histogram.bin_width
+                );
+                for bin in 0..num_bins {
+                    header.push_str(&format!(",BIN_{}", bin + 1));
+                    values.push_str(&format!(",{}", stats.histogram.counts[bin]));
+                }
+                writer.write_all(format!("{}\n{}\n", header, values).as_bytes())?;
+            }
+            writer.flush()?;
+            if verbose {
+                report::message("\nOutput file written");
+            }
+        }
+
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
     }
 }
+
+#[derive(Serialize)]
+struct SummaryStats {
+    file: String,
+    num_cells: usize,
+    num_nodata_cells: usize,
+    min: f64,
+    max: f64,
+    total: f64,
+    mean: f64,
+    variance: f64,
+    std_dev: f64,
+    histogram: Histogram,
+}
+
+#[derive(Serialize)]
+struct Histogram {
+    bin_width: f64,
+    lower_bounds: Vec<f64>,
+    counts: Vec<usize>,
+}
@@ -166,64 +166,28 @@ impl WhiteboxTool for RescaleValueRange {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-clip_min" || vec[0].to_lowercase() == "--clip_min"
-            {
-                if keyval {
-                    min_val = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    min_val = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-clip_max" || vec[0].to_lowercase() == "--clip_max"
-            {
-                if keyval {
-                    max_val = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_val = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-out_min_val"
-                || vec[0].to_lowercase() == "--out_min_val"
-            {
-                if keyval {
-                    out_min_val = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    out_min_val = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-out_max_val"
-                || vec[0].to_lowercase() == "--out_max_val"
-            {
-                if keyval {
-                    out_max_val = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    out_max_val = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip_min"])? {
+            min_val = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--clip_max"])? {
+            max_val = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--out_min_val"])? {
+            out_min_val = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--out_max_val"])? {
+            out_max_val = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -239,12 +203,14 @@ impl WhiteboxTool for RescaleValueRange {
         }
 
         if verbose {
-            println!("Reading input data...")
+            report::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         if input.configs.data_type == DataType::RGB24
             || input.configs.data_type == DataType::RGBA32
@@ -299,7 +265,7 @@ impl WhiteboxTool for RescaleValueRange {
                 let mut z_in: f64;
                 let mut z_out: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z_in = input[(row, col)];
                         if z_in != nodata {
@@ -318,14 +284,13 @@ impl WhiteboxTool for RescaleValueRange {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -344,19 +309,16 @@ impl WhiteboxTool for RescaleValueRange {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
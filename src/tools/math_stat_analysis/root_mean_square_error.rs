@@ -127,34 +127,16 @@ impl WhiteboxTool for RootMeanSquareError {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-base" || vec[0].to_lowercase() == "--base" {
-                if keyval {
-                    base_file = vec[1].to_string();
-                } else {
-                    base_file = args[i + 1].to_string();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -170,7 +152,7 @@ impl WhiteboxTool for RootMeanSquareError {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -225,7 +207,7 @@ impl WhiteboxTool for RootMeanSquareError {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -234,15 +216,15 @@ impl WhiteboxTool for RootMeanSquareError {
             let rmse = (sq_sum / num_cells as f64).sqrt();
             let mean_vertical_error = sum / num_cells as f64;
 
-            println!("\nVertical Accuracy Analysis:\n");
-            println!("Comparison File: {}", input_file);
-            println!("Base File: {}", base_file);
-            println!("Mean vertical error: {:.4}", mean_vertical_error);
-            println!("RMSE: {:.4}", rmse);
-            println!(
+            report::message("\nVertical Accuracy Analysis:\n");
+            report::metadata("Comparison File", &input_file);
+            report::metadata("Base File", &base_file);
+            report::metadata("Mean vertical error", &format!("{:.4}", mean_vertical_error));
+            report::metadata("RMSE", &format!("{:.4}", rmse));
+            report::message(&format!(
                 "Accuracy at 95% confidence limit (m): {:.4}",
                 rmse * 1.96f64
-            );
+            ));
         } else {
             /* The two grids are not of the same resolution. Bilinear resampling will have to be 
                 carried out to estimate z-values. Base image = source; input image = destination */
@@ -358,7 +340,7 @@ impl WhiteboxTool for RootMeanSquareError {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -367,23 +349,23 @@ impl WhiteboxTool for RootMeanSquareError {
             let rmse = (sq_sum / num_cells as f64).sqrt();
             let mean_vertical_error = sum / num_cells as f64;
 
-            println!("\nVertical Accuracy Analysis:\n");
-            println!("Comparison File: {}", input_file);
-            println!("Base File: {}", base_file);
-            println!("Mean vertical error: {:.4}", mean_vertical_error);
-            println!("RMSE: {:.4}", rmse);
-            println!(
+            report::message("\nVertical Accuracy Analysis:\n");
+            report::metadata("Comparison File", &input_file);
+            report::metadata("Base File", &base_file);
+            report::metadata("Mean vertical error", &format!("{:.4}", mean_vertical_error));
+            report::metadata("RMSE", &format!("{:.4}", rmse));
+            report::message(&format!(
                 "Accuracy at 95% confidence limit (m): {:.4}",
                 rmse * 1.96f64
-            );
+            ));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
@@ -0,0 +1,518 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{experimental_variogram, fit_variogram, VariogramModelType};
+use rand::prelude::*;
+use raster::*;
+use rendering::html::*;
+use rendering::LineGraph;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::process::Command;
+use tools::*;
+use vector::{FieldData, ShapeType, Shapefile};
+
+/// This tool calculates the experimental semivariogram of either a raster or a set of vector
+/// points and fits a theoretical model to it. The semivariogram describes how the
+/// dissimilarity of values increases with the distance between them, and is the basis of
+/// geostatistical interpolation, e.g. `KrigingInterpolation`. For each lag bin, the
+/// semivariance is half of the mean squared difference between the values of the pairs of
+/// points whose separation falls within the bin.
+///
+/// If the input (`--input`) is a vector points file, the values are taken from a numeric
+/// attribute field (`--field`). If it is a raster, a random sample of valid grid cells
+/// (`--num_samples`, default 1000) is used, since the number of pairs increases with the
+/// square of the number of points. The semivariogram is calculated for a number of lag bins
+/// (`--num_lags`, default 15) of a specified size (`--lag_size`), which by default spans half
+/// of the diagonal of the data's extent.
+///
+/// A spherical, exponential, or Gaussian model (`--model`) is fitted to the experimental
+/// semivariogram by least squares, weighted by the number of pairs in each bin. The ranges of
+/// the exponential and Gaussian models are their practical ranges, at which the
+/// semivariance reaches 95% of the sill. The output HTML report (`--output`) lists the fitted
+/// nugget, partial sill, and range, the semivariance of each lag bin, and a graph of the
+/// experimental and modelled semivariograms.
+///
+/// # See Also
+/// `KrigingInterpolation`, `TrendSurface`, `TrendSurfaceVectorPoints`
+pub struct Semivariogram {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Semivariogram {
+    pub fn new() -> Semivariogram {
+        // public constructor
+        let name = "Semivariogram".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description =
+            "Calculates the experimental semivariogram of a raster or vector points and fits a model to it."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Raster or Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input raster or vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Input field name in attribute table, for vector inputs.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output HTML File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output HTML report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Lags".to_owned(),
+            flags: vec!["--num_lags".to_owned()],
+            description: "Number of lag bins.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("15".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Lag Size (optional)".to_owned(),
+            flags: vec!["--lag_size".to_owned()],
+            description: "Width of the lag bins, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Model Type".to_owned(),
+            flags: vec!["--model".to_owned()],
+            description: "Semivariogram model; options include 'spherical', 'exponential', and 'gaussian'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "spherical".to_owned(),
+                "exponential".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("spherical".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Samples (raster inputs)".to_owned(),
+            flags: vec!["--num_samples".to_owned()],
+            description: "Number of randomly sampled cells, for raster inputs.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1000".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=points.shp --field=ELEV -o=variogram.html --num_lags=20 --model=exponential
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=dem.tif -o=variogram.html --lag_size=50.0 --num_samples=2000",
+            short_exe, name
+        ).replace("*", &sep);
+
+        Semivariogram {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Semivariogram {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut field_name = String::new();
+        let mut output_file = String::new();
+        let mut num_lags = 15usize;
+        let mut lag_size = 0f64;
+        let mut model_name = String::from("spherical");
+        let mut num_samples = 1000usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_lags"])? {
+            num_lags = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--lag_size"])? {
+            lag_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--model"])? {
+            model_name = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_samples"])? {
+            num_samples = v;
+        }
+
+        let model_type = match VariogramModelType::from_str(&model_name) {
+            Some(m) => m,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Unrecognized model type; options include 'spherical', 'exponential', and 'gaussian'.",
+                ))
+            }
+        };
+        if num_lags < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of lags must be at least one.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !output_file.ends_with(".html") {
+            output_file.push_str(".html");
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let mut x: Vec<f64> = vec![];
+        let mut y: Vec<f64> = vec![];
+        let mut z: Vec<f64> = vec![];
+        let start: Instant;
+        if input_file.to_lowercase().ends_with(".shp") {
+            let vector_data = Shapefile::read(&input_file)?;
+            start = Instant::now();
+            if vector_data.header.shape_type.base_shape_type() != ShapeType::Point {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input vector data must be of point base shape type.",
+                ));
+            }
+            let field_index = match vector_data.attributes.get_field_num(&field_name) {
+                Some(i) => i,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The input field could not be located within the attribute table.",
+                    ));
+                }
+            };
+            if !vector_data.attributes.is_field_numeric(field_index) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input field is non-numeric.",
+                ));
+            }
+            for record_num in 0..vector_data.num_records {
+                let record = vector_data.get_record(record_num);
+                let value = match vector_data.attributes.get_value(record_num, &field_name) {
+                    FieldData::Int(val) => val as f64,
+                    FieldData::Real(val) => val,
+                    _ => continue, // likely a null value
+                };
+                x.push(record.points[0].x);
+                y.push(record.points[0].y);
+                z.push(value);
+            }
+        } else {
+            let input = Raster::new(&input_file, "r")?;
+            start = Instant::now();
+            let rows = input.configs.rows as isize;
+            let columns = input.configs.columns as isize;
+            let nodata = input.configs.nodata;
+            let mut num_valid = 0usize;
+            for row in 0..rows {
+                for col in 0..columns {
+                    if input.get_value(row, col) != nodata {
+                        num_valid += 1;
+                    }
+                }
+            }
+            if num_valid == 0 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input raster does not contain any valid cells.",
+                ));
+            }
+            // sample cells without replacement; if there are fewer valid cells than samples,
+            // every valid cell is used
+            let mut rng = thread_rng();
+            let mut sampled: Vec<bool> = vec![false; (rows * columns) as usize];
+            let num_samples = num_samples.min(num_valid);
+            while z.len() < num_samples {
+                let row = rng.gen_range(0, rows);
+                let col = rng.gen_range(0, columns);
+                let value = input.get_value(row, col);
+                if value != nodata && !sampled[(row * columns + col) as usize] {
+                    sampled[(row * columns + col) as usize] = true;
+                    x.push(input.get_x_from_column(col));
+                    y.push(input.get_y_from_row(row));
+                    z.push(value);
+                }
+            }
+        }
+
+        if z.len() < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least two points are needed to calculate a semivariogram.",
+            ));
+        }
+
+        if lag_size <= 0f64 {
+            let (mut min_x, mut max_x) = (f64::INFINITY, f64::NEG_INFINITY);
+            let (mut min_y, mut max_y) = (f64::INFINITY, f64::NEG_INFINITY);
+            for i in 0..z.len() {
+                min_x = min_x.min(x[i]);
+                max_x = max_x.max(x[i]);
+                min_y = min_y.min(y[i]);
+                max_y = max_y.max(y[i]);
+            }
+            let diagonal = ((max_x - min_x).powi(2) + (max_y - min_y).powi(2)).sqrt();
+            lag_size = diagonal / 2f64 / num_lags as f64;
+            if lag_size <= 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input points are coincident.",
+                ));
+            }
+        }
+
+        if verbose {
+            report::message("Calculating the semivariogram...");
+        }
+        let bins = experimental_variogram(&x, &y, &z, lag_size, num_lags);
+        let model = fit_variogram(&bins, model_type);
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+
+        writer.write_all(&r#"<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+        <html>
+            <head>
+                <meta content=\"text/html; charset=iso-8859-1\" http-equiv=\"content-type\">
+                <title>Semivariogram Report</title>"#.as_bytes())?;
+
+        // get the style sheet
+        writer.write_all(&get_css().as_bytes())?;
+
+        writer.write_all(
+            &r#"
+            </head>
+            <body>
+                <h1>Semivariogram Report</h1>
+                "#.as_bytes(),
+        )?;
+
+        writer.write_all((format!("<p><strong>Input</strong>: {}</p>", input_file)).as_bytes())?;
+        if !field_name.is_empty() && input_file.to_lowercase().ends_with(".shp") {
+            writer.write_all(
+                (format!("<p><strong>Field</strong>: {}</p>", field_name)).as_bytes(),
+            )?;
+        }
+        writer.write_all(
+            (format!("<p><strong>Number of points</strong>: {}</p>", z.len())).as_bytes(),
+        )?;
+        writer.write_all(
+            (format!("<p><strong>Lag size</strong>: {:.*}</p>", 5, lag_size)).as_bytes(),
+        )?;
+
+        writer.write_all("<p><table>".as_bytes())?;
+        writer.write_all("<caption>Fitted Model</caption>".as_bytes())?;
+        writer.write_all("<tr><th>Parameter</th><th>Value</th></tr>".as_bytes())?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Model</td><td class=\"numberCell\">{:?}</td></tr>",
+                model.model_type
+            ).as_bytes(),
+        )?;
+        for &(label, value) in &[
+            ("Nugget", model.nugget),
+            ("Partial sill", model.partial_sill),
+            ("Sill", model.sill()),
+            ("Range", model.range),
+        ] {
+            writer.write_all(
+                &format!(
+                    "<tr><td>{}</td><td class=\"numberCell\">{:.*}</td></tr>",
+                    label, 5, value
+                ).as_bytes(),
+            )?;
+        }
+        writer.write_all("</table></p>".as_bytes())?;
+
+        writer.write_all("<p><table>".as_bytes())?;
+        writer.write_all("<caption>Experimental Semivariogram</caption>".as_bytes())?;
+        writer.write_all(
+            "<tr><th>Mean Distance</th><th>Semivariance</th><th>Model</th><th>Num. Pairs</th></tr>"
+                .as_bytes(),
+        )?;
+        for b in &bins {
+            writer.write_all(&format!(
+                "<tr><td class=\"numberCell\">{:.*}</td><td class=\"numberCell\">{:.*}</td><td class=\"numberCell\">{:.*}</td><td class=\"numberCell\">{}</td></tr>",
+                5, b.distance, 5, b.semivariance, 5, model.semivariance(b.distance), b.num_pairs
+            ).as_bytes())?;
+        }
+        writer.write_all("</table></p>".as_bytes())?;
+
+        let num_model_points = 100;
+        let max_dist = lag_size * num_lags as f64;
+        let graph = LineGraph {
+            parent_id: "graph".to_string(),
+            width: 700f64,
+            height: 500f64,
+            data_x: vec![
+                bins.iter().map(|b| b.distance).collect(),
+                (0..num_model_points + 1)
+                    .map(|i| max_dist * i as f64 / num_model_points as f64)
+                    .collect(),
+            ],
+            data_y: vec![
+                bins.iter().map(|b| b.semivariance).collect(),
+                (0..num_model_points + 1)
+                    .map(|i| model.semivariance(max_dist * i as f64 / num_model_points as f64))
+                    .collect(),
+            ],
+            series_labels: vec!["Experimental".to_string(), "Model".to_string()],
+            x_axis_label: "Distance".to_string(),
+            y_axis_label: "Semivariance".to_string(),
+            draw_points: false,
+            draw_gridlines: true,
+            draw_legend: true,
+            draw_grey_background: false,
+        };
+        writer.write_all(
+            &format!("<div id='graph' align=\"center\">{}</div>", graph.get_svg()).as_bytes(),
+        )?;
+
+        writer.write_all("</body>".as_bytes())?;
+
+        let _ = writer.flush();
+
+        if verbose {
+            if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+                let output = Command::new("open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "windows") {
+                let output = Command::new("explorer.exe")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "linux") {
+                let output = Command::new("xdg-open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            }
+
+            report::message(&format!("Please see {} for output report.", output_file));
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::io::{Error, ErrorKind};
+
+/// The spatial weights of the cells within a square moving window, used by the local
+/// spatial autocorrelation tools. The weight of a neighbouring cell is either one
+/// ('binary'), the inverse of its distance from the centre cell, in grid cells
+/// ('inverse_distance'), or a Gaussian function of that distance, with a standard
+/// deviation of half of the window radius ('gaussian'). The centre cell has a weight
+/// of one in each case.
+pub struct SpatialWeights {
+    pub dx: Vec<isize>,
+    pub dy: Vec<isize>,
+    pub weights: Vec<f64>,
+}
+
+impl SpatialWeights {
+    /// Creates the weights of a window `filter_size` cells wide, excluding the centre cell
+    /// unless `include_centre` is true. Even filter sizes are increased by one.
+    pub fn new(
+        filter_size: usize,
+        scheme: &str,
+        include_centre: bool,
+    ) -> Result<SpatialWeights, Error> {
+        let mut filter_size = filter_size.max(3);
+        if filter_size % 2 == 0 {
+            filter_size += 1;
+        }
+        let radius = (filter_size / 2) as isize;
+        let scheme = scheme.to_lowercase();
+        let sigma = radius as f64 / 2f64;
+        let mut dx = vec![];
+        let mut dy = vec![];
+        let mut weights = vec![];
+        for y in -radius..(radius + 1) {
+            for x in -radius..(radius + 1) {
+                if x == 0 && y == 0 && !include_centre {
+                    continue;
+                }
+                let d = ((x * x + y * y) as f64).sqrt();
+                let w = if x == 0 && y == 0 || scheme.contains("binary") {
+                    1f64
+                } else if scheme.contains("inverse") {
+                    1f64 / d
+                } else if scheme.contains("gaussian") {
+                    (-d * d / (2f64 * sigma * sigma)).exp()
+                } else {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Unrecognized weighting scheme; options are 'binary', 'inverse_distance', and 'gaussian'.",
+                    ));
+                };
+                dx.push(x);
+                dy.push(y);
+                weights.push(w);
+            }
+        }
+        Ok(SpatialWeights {
+            dx: dx,
+            dy: dy,
+            weights: weights,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 30/04/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -35,6 +35,15 @@ use tools::*;
 /// Numerical stability is enhanced by transforming the x, y, z data by their minimum
 /// values before performing the regression analysis. These transform parameters
 /// are also reported in the output report.
+///
+/// The residuals of the regression, i.e. the differences between the input raster and
+/// the fitted trend surface, may optionally be output (`--residuals`). Residuals are
+/// commonly used to detrend a DEM, removing its regional slope and broad landforms, prior to
+/// spectral or surface roughness analysis. The root-mean-square error of the residuals is
+/// listed in the report along with the r-square value.
+///
+/// # See Also
+/// `TrendSurfaceVectorPoints`, `LocalReliefModel`, `Subtract`
 pub struct TrendSurface {
     name: String,
     description: String,
@@ -78,6 +87,16 @@ impl TrendSurface {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Output Residuals File (optional)".to_owned(),
+            flags: vec!["--residuals".to_owned()],
+            description: "Optional output raster file of the residuals (input minus trend)."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -90,7 +109,7 @@ impl TrendSurface {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.tif' -o='output.tif' --order=2",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.tif' -o='output.tif' --order=2 --residuals='residuals.tif'",
             short_exe, name
         ).replace("*", &sep);
 
@@ -140,6 +159,7 @@ impl WhiteboxTool for TrendSurface {
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
+        let mut residuals_file = String::new();
         let mut order = 1usize;
 
         if args.len() == 0 {
@@ -148,41 +168,22 @@ impl WhiteboxTool for TrendSurface {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-order" {
-                order = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--order"])? {
+            order = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--residuals"])? {
+            residuals_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,6 +197,12 @@ impl WhiteboxTool for TrendSurface {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !residuals_file.is_empty()
+            && !residuals_file.contains(&sep)
+            && !residuals_file.contains("/")
+        {
+            residuals_file = format!("{}{}", working_directory, residuals_file);
+        }
 
         if order < 1 {
             order = 1;
@@ -205,7 +212,7 @@ impl WhiteboxTool for TrendSurface {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -239,7 +246,7 @@ impl WhiteboxTool for TrendSurface {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -306,9 +313,9 @@ impl WhiteboxTool for TrendSurface {
             ss += z[i] * z[i];
             sum += z[i];
         }
-        let variance = (ss - (sum * sum) / n as f64) / n as f64;
-        let ss_total = (n - 1) as f64 * variance;
+        let ss_total = ss - (sum * sum) / n as f64;
         let r_sqr = 1f64 - ss_resid / ss_total;
+        let rmse = (ss_resid / n as f64).sqrt();
 
         // create the output trend-surface report
         let p = path::Path::new(&output_file);
@@ -342,6 +349,7 @@ impl WhiteboxTool for TrendSurface {
             (format!("<p><strong>Polynomial Order</strong>: {}</p>", order)).as_bytes(),
         )?;
         writer.write_all((format!("<p><strong>R-sqr</strong>: {:.*}</p>", 5, r_sqr)).as_bytes())?;
+        writer.write_all((format!("<p><strong>RMSE</strong>: {:.*}</p>", 5, rmse)).as_bytes())?;
 
         //////////////////////////
         // Transformation Table //
@@ -433,11 +441,18 @@ impl WhiteboxTool for TrendSurface {
                 let _ = output.stdout;
             }
 
-            println!("Please see {} for output report.", output_html_file);
+            report::message(&format!("Please see {} for output report.", output_html_file));
         }
 
         // create the output trend-surface raster
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let mut residuals_output = if residuals_file.is_empty() {
+            None
+        } else {
+            let mut r = Raster::initialize_using_file(&residuals_file, &input);
+            r.configs.data_type = DataType::F32;
+            Some(r)
+        };
         let mut term: f64;
         let mut m: usize;
         for row in 0..rows {
@@ -454,11 +469,17 @@ impl WhiteboxTool for TrendSurface {
                     }
                 }
                 output.set_value(row, col, z_val);
+                if let Some(ref mut r) = residuals_output {
+                    let z = input.get_value(row, col);
+                    if z != nodata {
+                        r.set_value(row, col, z - z_val);
+                    }
+                }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -475,20 +496,33 @@ impl WhiteboxTool for TrendSurface {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
+        if let Some(mut r) = residuals_output {
+            r.configs.palette = "blue_white_red.plt".to_string();
+            r.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            r.add_metadata_entry(format!("Input file: {}", input_file));
+            r.add_metadata_entry(format!("Polynomial order: {}", order));
+            r.add_metadata_entry(format!("RMSE: {}", rmse));
+            let _ = match r.write() {
+                Ok(_) => if verbose {
+                    report::message("Residuals file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
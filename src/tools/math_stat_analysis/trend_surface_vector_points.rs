@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 01/05/2018
-Last Modified: 13/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -18,7 +18,7 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::process::Command;
 use tools::*;
-use vector::{FieldData, ShapeType, Shapefile};
+use vector::*;
 
 /// This tool can be used to interpolate a trend surface from a vector points file. The
 /// technique uses a polynomial, least-squares regression analysis. The user must specify
@@ -37,6 +37,16 @@ use vector::{FieldData, ShapeType, Shapefile};
 /// Numerical stability is enhanced by transforming the x, y, z data by their minimum
 /// values before performing the regression analysis. These transform parameters
 /// are also reported in the output report.
+///
+/// The residuals of the regression may optionally be output (`--residuals`) as a copy of
+/// the input points, with the fitted trend value (TREND_VAL) and the residual (RESIDUAL), i.e.
+/// the attribute value minus the trend, appended to the attribute table. Points with null
+/// attribute values are excluded from the regression and are assigned null residuals. The
+/// root-mean-square error of the residuals is listed in the report along with the r-square
+/// value.
+///
+/// # See Also
+/// `TrendSurface`
 pub struct TrendSurfaceVectorPoints {
     name: String,
     description: String,
@@ -103,6 +113,17 @@ impl TrendSurfaceVectorPoints {
             optional: false
         });
 
+        parameters.push(ToolParameter {
+            name: "Output Residuals File (optional)".to_owned(),
+            flags: vec!["--residuals".to_owned()],
+            description: "Optional output vector points file containing the residuals.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -114,7 +135,7 @@ impl TrendSurfaceVectorPoints {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.shp' --field=ELEV  -o='output.tif' --order=2 --cell_size=10.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.shp' --field=ELEV  -o='output.tif' --order=2 --cell_size=10.0 --residuals='residuals.shp'", short_exe, name).replace("*", &sep);
 
         TrendSurfaceVectorPoints {
             name: name,
@@ -163,6 +184,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         let mut input_file = String::new();
         let mut field_name = String::new();
         let mut output_file = String::new();
+        let mut residuals_file = String::new();
         let mut order = 1usize;
         let mut cell_size = 0f64;
 
@@ -172,53 +194,28 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-field" {
-                field_name = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-order" {
-                order = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            } else if flag_val == "-cell_size" {
-                cell_size = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--order"])? {
+            order = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--cell_size"])? {
+            cell_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--residuals"])? {
+            residuals_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -232,6 +229,12 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !residuals_file.is_empty()
+            && !residuals_file.contains(&sep)
+            && !residuals_file.contains("/")
+        {
+            residuals_file = format!("{}{}", working_directory, residuals_file);
+        }
 
         if order < 1 {
             order = 1;
@@ -241,7 +244,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let vector_data = Shapefile::read(&input_file)?;
@@ -316,6 +319,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         let mut x: Vec<f64> = Vec::with_capacity(num_recs);
         let mut y: Vec<f64> = Vec::with_capacity(num_recs);
         let mut z: Vec<f64> = Vec::with_capacity(num_recs);
+        let mut record_nums: Vec<usize> = Vec::with_capacity(num_recs);
         let (mut x_val, mut y_val, mut z_val): (f64, f64, f64);
 
         for record_num in 0..num_recs {
@@ -330,25 +334,28 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
                 FieldData::Real(val) => val,
                 _ => nodata,
             };
-            if z_val < min_z {
-                min_z = z_val;
+            if z_val != nodata {
+                if z_val < min_z {
+                    min_z = z_val;
+                }
+                x.push(x_val);
+                y.push(y_val);
+                z.push(z_val);
+                record_nums.push(record_num);
             }
-            x.push(x_val);
-            y.push(y_val);
-            z.push(z_val);
 
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
+                    report::progress("Reading attributes", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        for record_num in 0..num_recs {
-            z[record_num] -= min_z;
+        for i in 0..z.len() {
+            z[i] -= min_z;
         }
 
         // calculate the equation
@@ -411,9 +418,9 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
             ss += z[i] * z[i];
             sum += z[i];
         }
-        let variance = (ss - (sum * sum) / n as f64) / n as f64;
-        let ss_total = (n - 1) as f64 * variance;
+        let ss_total = ss - (sum * sum) / n as f64;
         let r_sqr = 1f64 - ss_resid / ss_total;
+        let rmse = (ss_resid / n as f64).sqrt();
 
         // create the output trend-surface report
         let p = path::Path::new(&output_file);
@@ -447,6 +454,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
             (format!("<p><strong>Polynomial Order</strong>: {}</p>", order)).as_bytes(),
         )?;
         writer.write_all((format!("<p><strong>R-sqr</strong>: {:.*}</p>", 5, r_sqr)).as_bytes())?;
+        writer.write_all((format!("<p><strong>RMSE</strong>: {:.*}</p>", 5, rmse)).as_bytes())?;
 
         //////////////////////////
         // Transformation Table //
@@ -538,7 +546,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
                 let _ = output.stdout;
             }
 
-            println!("Please see {} for output report.", output_html_file);
+            report::message(&format!("Please see {} for output report.", output_html_file));
         }
 
         // create the output trend-surface raster
@@ -562,7 +570,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -579,20 +587,52 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
+        if !residuals_file.is_empty() {
+            let mut residuals_output = Shapefile::initialize_using_file(
+                &residuals_file,
+                &vector_data,
+                vector_data.header.shape_type,
+                true,
+            )?;
+            residuals_output
+                .attributes
+                .add_field(&AttributeField::new("TREND_VAL", FieldDataType::Real, 12, 5));
+            residuals_output
+                .attributes
+                .add_field(&AttributeField::new("RESIDUAL", FieldDataType::Real, 12, 5));
+            let mut i = 0;
+            for record_num in 0..num_recs {
+                let mut atts = vector_data.attributes.get_record(record_num);
+                if i < n && record_nums[i] == record_num {
+                    atts.push(FieldData::Real(z[i] - residuals[i] + min_z));
+                    atts.push(FieldData::Real(residuals[i]));
+                    i += 1;
+                } else {
+                    atts.push(FieldData::Null);
+                    atts.push(FieldData::Null);
+                }
+                residuals_output.add_record(vector_data.get_record(record_num).clone());
+                residuals_output.attributes.add_record(atts, false);
+            }
+            let _ = match residuals_output.write() {
+                Ok(_) => if verbose {
+                    report::message("Residuals file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (including I/O)", &elapsed_time);
         }
 
         Ok(())
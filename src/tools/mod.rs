@@ -6,12 +6,21 @@ pub mod lidar_analysis;
 pub mod math_stat_analysis;
 pub mod stream_network_analysis;
 pub mod terrain_analysis;
+mod batch;
+mod catalog;
+mod harmonize;
+mod json_output;
+mod runner;
+mod workflow;
+
+pub use self::runner::{Dataset, ToolOutputs, ToolRunner};
 
 use serde_json;
 use std::io::{Error, ErrorKind};
 use std::time::Instant;
 use tools;
 use utils::get_formatted_elapsed_time;
+use utils::report;
 
 #[derive(Default)]
 pub struct ToolManager {
@@ -27,14 +36,25 @@ impl ToolManager {
     ) -> Result<ToolManager, Error> {
         let mut tool_names = vec![];
         // data_tools
+        tool_names.push("AddField".to_string());
         tool_names.push("AddPointCoordinatesToTable".to_string());
+        tool_names.push("AssignProjection".to_string());
+        tool_names.push("AttributesToCsv".to_string());
+        tool_names.push("CalculateField".to_string());
         tool_names.push("ConvertNodataToZero".to_string());
         tool_names.push("ConvertRasterFormat".to_string());
+        tool_names.push("CsvPointsToRaster".to_string());
+        tool_names.push("CsvPointsToVector".to_string());
+        tool_names.push("DeleteField".to_string());
+        tool_names.push("ExportRasterToAscii".to_string());
         tool_names.push("ExportTableToCsv".to_string());
+        tool_names.push("Extend".to_string());
         tool_names.push("JoinTables".to_string());
+        tool_names.push("JoinTableToVector".to_string());
         tool_names.push("LinesToPolygons".to_string());
         tool_names.push("MergeTableWithCsv".to_string());
         tool_names.push("MergeVectors".to_string());
+        tool_names.push("ModifyProjection".to_string());
         tool_names.push("MultiPartToSinglePart".to_string());
         tool_names.push("NewRasterFromBase".to_string());
         tool_names.push("PolygonsToLines".to_string());
@@ -43,7 +63,9 @@ impl ToolManager {
         tool_names.push("RasterToVectorPoints".to_string());
         tool_names.push("ReinitializeAttributeTable".to_string());
         tool_names.push("RemovePolygonHoles".to_string());
+        tool_names.push("SetExtentFromReference".to_string());
         tool_names.push("SetNodataValue".to_string());
+        tool_names.push("Shrink".to_string());
         tool_names.push("SinglePartToMultiPart".to_string());
         tool_names.push("VectorLinesToRaster".to_string());
         tool_names.push("VectorPointsToRaster".to_string());
@@ -54,9 +76,11 @@ impl ToolManager {
         tool_names.push("AverageOverlay".to_string());
         tool_names.push("BlockMaximumGridding".to_string());
         tool_names.push("BlockMinimumGridding".to_string());
+        tool_names.push("BoundaryClean".to_string());
         tool_names.push("BufferRaster".to_string());
         tool_names.push("Centroid".to_string());
         tool_names.push("CentroidVector".to_string());
+        tool_names.push("Clip".to_string());
         tool_names.push("ClipRasterToPolygon".to_string());
         tool_names.push("Clump".to_string());
         tool_names.push("CompactnessRatio".to_string());
@@ -68,23 +92,34 @@ impl ToolManager {
         tool_names.push("CreateHexagonalVectorGrid".to_string());
         tool_names.push("CreatePlane".to_string());
         tool_names.push("CreateRectangularVectorGrid".to_string());
+        tool_names.push("DrapeVectorOnDem".to_string());
         tool_names.push("EdgeProportion".to_string());
         tool_names.push("EliminateCoincidentPoints".to_string());
         tool_names.push("ElongationRatio".to_string());
+        tool_names.push("Erase".to_string());
         tool_names.push("ErasePolygonFromRaster".to_string());
         tool_names.push("EuclideanAllocation".to_string());
+        tool_names.push("EuclideanDirection".to_string());
         tool_names.push("EuclideanDistance".to_string());
         tool_names.push("ExtendVectorLines".to_string());
         tool_names.push("ExtractNodes".to_string());
         tool_names.push("ExtractRasterValuesAtPoints".to_string());
+        tool_names.push("ExtractValuesToPoints".to_string());
         tool_names.push("FindLowestOrHighestPoints".to_string());
         tool_names.push("FindPatchOrClassEdgeCells".to_string());
+        tool_names.push("GeneralizeClassifiedRaster".to_string());
+        tool_names.push("GeodesicDistance".to_string());
         tool_names.push("HighestPosition".to_string());
         tool_names.push("HoleProportion".to_string());
         tool_names.push("IdwInterpolation".to_string());
+        tool_names.push("KernelDensity".to_string());
+        tool_names.push("KrigingInterpolation".to_string());
         tool_names.push("LayerFootprint".to_string());
+        tool_names.push("LeastCostCorridor".to_string());
+        tool_names.push("LeastCostPaths".to_string());
         tool_names.push("LinearityIndex".to_string());
         tool_names.push("LineIntersections".to_string());
+        tool_names.push("LineLength3D".to_string());
         tool_names.push("LowestPosition".to_string());
         tool_names.push("MaxAbsoluteOverlay".to_string());
         tool_names.push("MaxOverlay".to_string());
@@ -93,8 +128,10 @@ impl ToolManager {
         tool_names.push("MinimumBoundingBox".to_string());
         tool_names.push("MinimumBoundingCircle".to_string());
         tool_names.push("MinimumBoundingEnvelope".to_string());
+        tool_names.push("MinimumBoundingGeometry".to_string());
         tool_names.push("MinimumConvexHull".to_string());
         tool_names.push("NearestNeighbourGridding".to_string());
+        tool_names.push("NearestNeighbourIndex".to_string());
         tool_names.push("MinOverlay".to_string());
         tool_names.push("PatchOrientation".to_string());
         tool_names.push("PercentEqualTo".to_string());
@@ -103,23 +140,35 @@ impl ToolManager {
         tool_names.push("PerimeterAreaRatio".to_string());
         tool_names.push("PickFromList".to_string());
         tool_names.push("PolygonArea".to_string());
+        tool_names.push("PolygonGeometryAttributes".to_string());
         tool_names.push("PolygonLongAxis".to_string());
         tool_names.push("PolygonPerimeter".to_string());
         tool_names.push("PolygonShortAxis".to_string());
         tool_names.push("Polygonize".to_string());
+        tool_names.push("QuadratCount".to_string());
         tool_names.push("RadiusOfGyration".to_string());
+        tool_names.push("RandomPoints".to_string());
         tool_names.push("RasterCellAssignment".to_string());
         tool_names.push("Reclass".to_string());
         tool_names.push("ReclassEqualInterval".to_string());
         tool_names.push("ReclassFromFile".to_string());
+        tool_names.push("RegularGridPoints".to_string());
         tool_names.push("RelatedCircumscribingCircle".to_string());
+        tool_names.push("RipleysK".to_string());
         tool_names.push("ShapeComplexityIndex".to_string());
         // tool_names.push("SibsonInterpolation".to_string());
+        tool_names.push("SimplifyVectors".to_string());
         tool_names.push("SmoothVectors".to_string());
+        tool_names.push("SnapPointsToLines".to_string());
+        tool_names.push("SplitByAttribute".to_string());
+        tool_names.push("SplitByPolygon".to_string());
         tool_names.push("SplitWithLines".to_string());
+        tool_names.push("StratifiedRandomPoints".to_string());
         tool_names.push("SumOverlay".to_string());
         tool_names.push("TINGridding".to_string());
+        tool_names.push("ValidatePolygons".to_string());
         tool_names.push("VectorHexBinning".to_string());
+        tool_names.push("VertexZStatistics".to_string());
         tool_names.push("VoronoiDiagram".to_string());
         tool_names.push("WeightedOverlay".to_string());
         tool_names.push("WeightedSum".to_string());
@@ -129,7 +178,11 @@ impl ToolManager {
         tool_names.push("AverageUpslopeFlowpathLength".to_string());
         tool_names.push("Basins".to_string());
         tool_names.push("BreachDepressions".to_string());
+        tool_names.push("BreachDepressionsLeastCost".to_string());
+        tool_names.push("BreachOrFillDepressions".to_string());
         tool_names.push("BreachSingleCellPits".to_string());
+        tool_names.push("ConditionStreamProfiles".to_string());
+        tool_names.push("CurveNumberRunoff".to_string());
         tool_names.push("D8FlowAccumulation".to_string());
         tool_names.push("D8MassFlux".to_string());
         tool_names.push("D8Pointer".to_string());
@@ -137,8 +190,10 @@ impl ToolManager {
         tool_names.push("DInfFlowAccumulation".to_string());
         tool_names.push("DInfMassFlux".to_string());
         tool_names.push("DInfPointer".to_string());
+        tool_names.push("DistanceToStreamMetrics".to_string());
         tool_names.push("DownslopeDistanceToStream".to_string());
         tool_names.push("DownslopeFlowpathLength".to_string());
+        tool_names.push("EdgeContamination".to_string());
         tool_names.push("ElevationAboveStream".to_string());
         tool_names.push("ElevationAboveStreamEuclidean".to_string());
         tool_names.push("FD8FlowAccumulation".to_string());
@@ -154,6 +209,7 @@ impl ToolManager {
         tool_names.push("FlowLengthDiff".to_string());
         tool_names.push("Hillslopes".to_string());
         tool_names.push("ImpoundmentIndex".to_string());
+        tool_names.push("ImpoundmentStorageCurve".to_string());
         tool_names.push("Isobasins".to_string());
         tool_names.push("JensonSnapPourPoints".to_string());
         tool_names.push("LongestFlowpath".to_string());
@@ -162,31 +218,46 @@ impl ToolManager {
         tool_names.push("RaiseWalls".to_string());
         tool_names.push("Rho8Pointer".to_string());
         tool_names.push("Sink".to_string());
+        tool_names.push("SinkStatistics".to_string());
         tool_names.push("SnapPourPoints".to_string());
         tool_names.push("StochasticDepressionAnalysis".to_string());
         tool_names.push("StrahlerOrderBasins".to_string());
+        tool_names.push("SubbasinParameters".to_string());
         tool_names.push("Subbasins".to_string());
         tool_names.push("TraceDownslopeFlowpaths".to_string());
+        tool_names.push("TraceFlowpathLines".to_string());
         tool_names.push("UnnestBasins".to_string());
+        tool_names.push("UpscaleFlowDirections".to_string());
         tool_names.push("Watershed".to_string());
+        tool_names.push("WeightedFlowAccumulation".to_string());
 
         // image_analysis
         tool_names.push("AdaptiveFilter".to_string());
+        tool_names.push("ApplyLookupTable".to_string());
         tool_names.push("BalanceContrastEnhancement".to_string());
+        tool_names.push("BalancedMosaic".to_string());
         tool_names.push("BilateralFilter".to_string());
+        tool_names.push("ByteContrastStretch".to_string());
         tool_names.push("ChangeVectorAnalysis".to_string());
         tool_names.push("Closing".to_string());
+        tool_names.push("ColourBalance".to_string());
         tool_names.push("ConservativeSmoothingFilter".to_string());
         tool_names.push("CornerDetection".to_string());
         tool_names.push("CorrectVignetting".to_string());
         tool_names.push("CreateColourComposite".to_string());
+        tool_names.push("DarkObjectSubtraction".to_string());
+        tool_names.push("DestripingFilter".to_string());
         tool_names.push("DirectDecorrelationStretch".to_string());
         tool_names.push("DiversityFilter".to_string());
         tool_names.push("DiffOfGaussianFilter".to_string());
         tool_names.push("EdgePreservingMeanFilter".to_string());
         tool_names.push("EmbossFilter".to_string());
+        tool_names.push("ExtractBands".to_string());
         tool_names.push("FastAlmostGaussianFilter".to_string());
+        tool_names.push("FastFourierTransform".to_string());
         tool_names.push("FlipImage".to_string());
+        tool_names.push("FocalStatistics".to_string());
+        tool_names.push("FrequencyDomainFilter".to_string());
         tool_names.push("GammaCorrection".to_string());
         tool_names.push("GaussianContrastStretch".to_string());
         tool_names.push("GaussianFilter".to_string());
@@ -195,11 +266,18 @@ impl ToolManager {
         tool_names.push("HistogramEqualization".to_string());
         tool_names.push("HistogramMatching".to_string());
         tool_names.push("HistogramMatchingTwoImages".to_string());
+        tool_names.push("HsvToRgb".to_string());
         tool_names.push("IhsToRgb".to_string());
+        tool_names.push("ImageCoregistration".to_string());
+        tool_names.push("ImageDifferencing".to_string());
+        tool_names.push("ImageRatio".to_string());
+        tool_names.push("ImageStack".to_string());
         tool_names.push("ImageStackProfile".to_string());
         tool_names.push("IntegralImage".to_string());
+        tool_names.push("InverseFastFourierTransform".to_string());
         tool_names.push("KMeansClustering".to_string());
         tool_names.push("KNearestMeanFilter".to_string());
+        tool_names.push("LandsatToaConversion".to_string());
         tool_names.push("LaplacianFilter".to_string());
         tool_names.push("LaplacianOfGaussianFilter".to_string());
         tool_names.push("LeeFilter".to_string());
@@ -211,7 +289,9 @@ impl ToolManager {
         tool_names.push("MedianFilter".to_string());
         tool_names.push("MinMaxContrastStretch".to_string());
         tool_names.push("MinimumFilter".to_string());
+        tool_names.push("MinorityFilter".to_string());
         tool_names.push("ModifiedKMeansClustering".to_string());
+        tool_names.push("MorphologicalFilter".to_string());
         tool_names.push("Mosaic".to_string());
         tool_names.push("NormalizedDifferenceVegetationIndex".to_string());
         tool_names.push("OlympicFilter".to_string());
@@ -221,11 +301,15 @@ impl ToolManager {
         tool_names.push("PercentileFilter".to_string());
         tool_names.push("PrewittFilter".to_string());
         tool_names.push("RangeFilter".to_string());
+        tool_names.push("RankFilter".to_string());
+        tool_names.push("RegressionChangeDetection".to_string());
         tool_names.push("RemoveSpurs".to_string());
         tool_names.push("Resample".to_string());
+        tool_names.push("RgbToHsv".to_string());
         tool_names.push("RgbToIhs".to_string());
         tool_names.push("RobertsCrossFilter".to_string());
         tool_names.push("ScharrFilter".to_string());
+        tool_names.push("Sentinel2ToaConversion".to_string());
         tool_names.push("SigmoidalContrastStretch".to_string());
         tool_names.push("SobelFilter".to_string());
         tool_names.push("SplitColourComposite".to_string());
@@ -233,6 +317,7 @@ impl ToolManager {
         tool_names.push("StandardDeviationFilter".to_string());
         tool_names.push("ThickenRasterLine".to_string());
         tool_names.push("TophatTransform".to_string());
+        tool_names.push("TopographicCorrection".to_string());
         tool_names.push("TotalFilter".to_string());
         tool_names.push("UnsharpMasking".to_string());
         tool_names.push("UserDefinedWeightsFilter".to_string());
@@ -251,9 +336,13 @@ impl ToolManager {
         tool_names.push("LasToMultipointShapefile".to_string());
         tool_names.push("LasToShapefile".to_string());
         tool_names.push("LidarClassifySubset".to_string());
+        tool_names.push("LidarClip".to_string());
         tool_names.push("LidarColourize".to_string());
         tool_names.push("LidarConstructVectorTIN".to_string());
+        tool_names.push("LidarEigenvalueFeatures".to_string());
         tool_names.push("LidarElevationSlice".to_string());
+        tool_names.push("LidarErase".to_string());
+        tool_names.push("LidarGridStats".to_string());
         tool_names.push("LidarGroundPointFilter".to_string());
         tool_names.push("LidarHexBinning".to_string());
         tool_names.push("LidarHillshade".to_string());
@@ -291,6 +380,7 @@ impl ToolManager {
         tool_names.push("AttributeHistogram".to_string());
         tool_names.push("AttributeScattergram".to_string());
         tool_names.push("Ceil".to_string());
+        tool_names.push("ClassificationAccuracy".to_string());
         tool_names.push("Cos".to_string());
         tool_names.push("Cosh".to_string());
         tool_names.push("CrispnessIndex".to_string());
@@ -303,6 +393,7 @@ impl ToolManager {
         tool_names.push("Exp2".to_string());
         tool_names.push("ExtractRasterStatistics".to_string());
         tool_names.push("Floor".to_string());
+        tool_names.push("GetisOrdGiStar".to_string());
         tool_names.push("GreaterThan".to_string());
         tool_names.push("ImageAutocorrelation".to_string());
         tool_names.push("ImageCorrelation".to_string());
@@ -318,6 +409,7 @@ impl ToolManager {
         tool_names.push("KSTestForNormality".to_string());
         tool_names.push("LessThan".to_string());
         tool_names.push("ListUniqueValues".to_string());
+        tool_names.push("LocalMoransI".to_string());
         tool_names.push("Log10".to_string());
         tool_names.push("Log2".to_string());
         tool_names.push("Ln".to_string());
@@ -335,11 +427,13 @@ impl ToolManager {
         tool_names.push("RandomField".to_string());
         tool_names.push("RandomSample".to_string());
         tool_names.push("RasterHistogram".to_string());
+        tool_names.push("RasterStatisticsReport".to_string());
         tool_names.push("RasterSummaryStats".to_string());
         tool_names.push("Reciprocal".to_string());
         tool_names.push("RescaleValueRange".to_string());
         tool_names.push("RootMeanSquareError".to_string());
         tool_names.push("Round".to_string());
+        tool_names.push("Semivariogram".to_string());
         tool_names.push("Sin".to_string());
         tool_names.push("Sinh".to_string());
         tool_names.push("Square".to_string());
@@ -357,13 +451,17 @@ impl ToolManager {
         tool_names.push("ZScores".to_string());
 
         // stream_network_analysis
+        tool_names.push("AccumulateVectorNetwork".to_string());
         tool_names.push("DistanceToOutlet".to_string());
+        tool_names.push("DrainageDensity".to_string());
+        tool_names.push("ExtractStreamNodes".to_string());
         tool_names.push("ExtractStreams".to_string());
         tool_names.push("ExtractValleys".to_string());
         tool_names.push("FarthestChannelHead".to_string());
         tool_names.push("FindMainStem".to_string());
         tool_names.push("HackStreamOrder".to_string());
         tool_names.push("HortonStreamOrder".to_string());
+        tool_names.push("KnickpointAnalysis".to_string());
         tool_names.push("LengthOfUpstreamChannels".to_string());
         tool_names.push("LongProfile".to_string());
         tool_names.push("LongProfileFromPoints".to_string());
@@ -372,13 +470,17 @@ impl ToolManager {
         tool_names.push("RemoveShortStreams".to_string());
         tool_names.push("ShreveStreamMagnitude".to_string());
         tool_names.push("StrahlerStreamOrder".to_string());
+        tool_names.push("StreamLinkAttributes".to_string());
         tool_names.push("StreamLinkClass".to_string());
         tool_names.push("StreamLinkIdentifier".to_string());
         tool_names.push("StreamLinkLength".to_string());
         tool_names.push("StreamLinkSlope".to_string());
+        tool_names.push("StreamOrdering".to_string());
         tool_names.push("StreamSlopeContinuous".to_string());
         tool_names.push("TopologicalStreamOrder".to_string());
+        tool_names.push("TraceVectorNetwork".to_string());
         tool_names.push("TributaryIdentifier".to_string());
+        tool_names.push("VectorNetworkTopology".to_string());
 
         // terrain_analysis
         tool_names.push("Aspect".to_string());
@@ -391,6 +493,7 @@ impl ToolManager {
         tool_names.push("ElevPercentile".to_string());
         tool_names.push("ElevRelativeToMinMax".to_string());
         tool_names.push("ElevRelativeToWatershedMinMax".to_string());
+        tool_names.push("ExtractProfile".to_string());
         tool_names.push("FeaturePreservingDenoise".to_string());
         tool_names.push("FetchAnalysis".to_string());
         tool_names.push("FillMissingData".to_string());
@@ -398,6 +501,8 @@ impl ToolManager {
         tool_names.push("Hillshade".to_string());
         tool_names.push("HorizonAngle".to_string());
         tool_names.push("HypsometricAnalysis".to_string());
+        tool_names.push("LineOfSight".to_string());
+        tool_names.push("LocalReliefModel".to_string());
         tool_names.push("MaxAnisotropyDev".to_string());
         tool_names.push("MaxAnisotropyDevSignature".to_string());
         tool_names.push("MaxBranchLength".to_string());
@@ -406,6 +511,7 @@ impl ToolManager {
         tool_names.push("MaxElevDevSignature".to_string());
         tool_names.push("MaxElevationDeviation".to_string());
         tool_names.push("MinDownslopeElevChange".to_string());
+        tool_names.push("MultiscaleElevationResidual".to_string());
         tool_names.push("MultiscaleRoughness".to_string());
         tool_names.push("MultiscaleRoughnessSignature".to_string());
         tool_names.push("MultiscaleTopographicPositionImage".to_string());
@@ -425,6 +531,7 @@ impl ToolManager {
         tool_names.push("Slope".to_string());
         tool_names.push("SlopeVsElevationPlot".to_string());
         tool_names.push("StandardDeviationOfSlope".to_string());
+        tool_names.push("SwathProfile".to_string());
         tool_names.push("TangentialCurvature".to_string());
         tool_names.push("TotalCurvature".to_string());
         tool_names.push("Viewshed".to_string());
@@ -444,16 +551,27 @@ impl ToolManager {
     fn get_tool(&self, tool_name: &str) -> Option<Box<WhiteboxTool + 'static>> {
         match tool_name.to_lowercase().replace("_", "").as_ref() {
             // data_tools
+            "addfield" => Some(Box::new(tools::data_tools::AddField::new())),
             "addpointcoordinatestotable" => Some(Box::new(
                 tools::data_tools::AddPointCoordinatesToTable::new(),
             )),
+            "assignprojection" => Some(Box::new(tools::data_tools::AssignProjection::new())),
+            "attributestocsv" => Some(Box::new(tools::data_tools::AttributesToCsv::new())),
+            "calculatefield" => Some(Box::new(tools::data_tools::CalculateField::new())),
             "convertnodatatozero" => Some(Box::new(tools::data_tools::ConvertNodataToZero::new())),
             "convertrasterformat" => Some(Box::new(tools::data_tools::ConvertRasterFormat::new())),
+            "csvpointstoraster" => Some(Box::new(tools::data_tools::CsvPointsToRaster::new())),
+            "csvpointstovector" => Some(Box::new(tools::data_tools::CsvPointsToVector::new())),
+            "deletefield" => Some(Box::new(tools::data_tools::DeleteField::new())),
+            "exportrastertoascii" => Some(Box::new(tools::data_tools::ExportRasterToAscii::new())),
             "exporttabletocsv" => Some(Box::new(tools::data_tools::ExportTableToCsv::new())),
+            "extend" => Some(Box::new(tools::data_tools::Extend::new())),
             "jointables" => Some(Box::new(tools::data_tools::JoinTables::new())),
+            "jointabletovector" => Some(Box::new(tools::data_tools::JoinTableToVector::new())),
             "linestopolygons" => Some(Box::new(tools::data_tools::LinesToPolygons::new())),
             "mergetablewithcsv" => Some(Box::new(tools::data_tools::MergeTableWithCsv::new())),
             "mergevectors" => Some(Box::new(tools::data_tools::MergeVectors::new())),
+            "modifyprojection" => Some(Box::new(tools::data_tools::ModifyProjection::new())),
             "multiparttosinglepart" => {
                 Some(Box::new(tools::data_tools::MultiPartToSinglePart::new()))
             }
@@ -468,7 +586,11 @@ impl ToolManager {
                 tools::data_tools::ReinitializeAttributeTable::new(),
             )),
             "removepolygonholes" => Some(Box::new(tools::data_tools::RemovePolygonHoles::new())),
+            "setextentfromreference" => {
+                Some(Box::new(tools::data_tools::SetExtentFromReference::new()))
+            }
             "setnodatavalue" => Some(Box::new(tools::data_tools::SetNodataValue::new())),
+            "shrink" => Some(Box::new(tools::data_tools::Shrink::new())),
             "singleparttomultipart" => {
                 Some(Box::new(tools::data_tools::SinglePartToMultiPart::new()))
             }
@@ -489,9 +611,11 @@ impl ToolManager {
             "blockminimumgridding" => {
                 Some(Box::new(tools::gis_analysis::BlockMinimumGridding::new()))
             }
+            "boundaryclean" => Some(Box::new(tools::gis_analysis::BoundaryClean::new())),
             "bufferraster" => Some(Box::new(tools::gis_analysis::BufferRaster::new())),
             "centroid" => Some(Box::new(tools::gis_analysis::Centroid::new())),
             "centroidvector" => Some(Box::new(tools::gis_analysis::CentroidVector::new())),
+            "clip" => Some(Box::new(tools::gis_analysis::Clip::new())),
             "cliprastertopolygon" => {
                 Some(Box::new(tools::gis_analysis::ClipRasterToPolygon::new()))
             }
@@ -509,34 +633,51 @@ impl ToolManager {
             "createrectangularvectorgrid" => Some(Box::new(
                 tools::gis_analysis::CreateRectangularVectorGrid::new(),
             )),
+            "drapevectorondem" => Some(Box::new(tools::gis_analysis::DrapeVectorOnDem::new())),
             "edgeproportion" => Some(Box::new(tools::gis_analysis::EdgeProportion::new())),
             "eliminatecoincidentpoints" => Some(Box::new(
                 tools::gis_analysis::EliminateCoincidentPoints::new(),
             )),
             "elongationratio" => Some(Box::new(tools::gis_analysis::ElongationRatio::new())),
+            "erase" => Some(Box::new(tools::gis_analysis::Erase::new())),
             "erasepolygonfromraster" => {
                 Some(Box::new(tools::gis_analysis::ErasePolygonFromRaster::new()))
             }
             "euclideanallocation" => {
                 Some(Box::new(tools::gis_analysis::EuclideanAllocation::new()))
             }
+            "euclideandirection" => Some(Box::new(tools::gis_analysis::EuclideanDirection::new())),
             "euclideandistance" => Some(Box::new(tools::gis_analysis::EuclideanDistance::new())),
             "extendvectorlines" => Some(Box::new(tools::gis_analysis::ExtendVectorLines::new())),
             "extractnodes" => Some(Box::new(tools::gis_analysis::ExtractNodes::new())),
             "extractrastervaluesatpoints" => Some(Box::new(
                 tools::gis_analysis::ExtractRasterValuesAtPoints::new(),
             )),
+            "extractvaluestopoints" => {
+                Some(Box::new(tools::gis_analysis::ExtractValuesToPoints::new()))
+            }
             "findlowestorhighestpoints" => Some(Box::new(
                 tools::gis_analysis::FindLowestOrHighestPoints::new(),
             )),
             "findpatchorclassedgecells" => Some(Box::new(
                 tools::gis_analysis::FindPatchOrClassEdgeCells::new(),
             )),
+            "generalizeclassifiedraster" => {
+                Some(Box::new(tools::gis_analysis::GeneralizeClassifiedRaster::new()))
+            }
+            "geodesicdistance" => Some(Box::new(tools::gis_analysis::GeodesicDistance::new())),
             "highestposition" => Some(Box::new(tools::gis_analysis::HighestPosition::new())),
             "holeproportion" => Some(Box::new(tools::gis_analysis::HoleProportion::new())),
             "idwinterpolation" => Some(Box::new(tools::gis_analysis::IdwInterpolation::new())),
+            "kerneldensity" => Some(Box::new(tools::gis_analysis::KernelDensity::new())),
+            "kriginginterpolation" => {
+                Some(Box::new(tools::gis_analysis::KrigingInterpolation::new()))
+            }
             "layerfootprint" => Some(Box::new(tools::gis_analysis::LayerFootprint::new())),
+            "leastcostcorridor" => Some(Box::new(tools::gis_analysis::LeastCostCorridor::new())),
+            "leastcostpaths" => Some(Box::new(tools::gis_analysis::LeastCostPaths::new())),
             "lineintersections" => Some(Box::new(tools::gis_analysis::LineIntersections::new())),
+            "linelength3d" => Some(Box::new(tools::gis_analysis::LineLength3D::new())),
             "linearityindex" => Some(Box::new(tools::gis_analysis::LinearityIndex::new())),
             "lowestposition" => Some(Box::new(tools::gis_analysis::LowestPosition::new())),
             "maxabsoluteoverlay" => Some(Box::new(tools::gis_analysis::MaxAbsoluteOverlay::new())),
@@ -550,10 +691,16 @@ impl ToolManager {
             "minimumboundingenvelope" => {
                 Some(Box::new(tools::gis_analysis::MinimumBoundingEnvelope::new()))
             }
+            "minimumboundinggeometry" => {
+                Some(Box::new(tools::gis_analysis::MinimumBoundingGeometry::new()))
+            }
             "minimumconvexhull" => Some(Box::new(tools::gis_analysis::MinimumConvexHull::new())),
             "nearestneighbourgridding" => Some(Box::new(
                 tools::gis_analysis::NearestNeighbourGridding::new(),
             )),
+            "nearestneighbourindex" => {
+                Some(Box::new(tools::gis_analysis::NearestNeighbourIndex::new()))
+            }
             "minoverlay" => Some(Box::new(tools::gis_analysis::MinOverlay::new())),
             "patchorientation" => Some(Box::new(tools::gis_analysis::PatchOrientation::new())),
             "percentequalto" => Some(Box::new(tools::gis_analysis::PercentEqualTo::new())),
@@ -562,11 +709,16 @@ impl ToolManager {
             "perimeterarearatio" => Some(Box::new(tools::gis_analysis::PerimeterAreaRatio::new())),
             "pickfromlist" => Some(Box::new(tools::gis_analysis::PickFromList::new())),
             "polygonarea" => Some(Box::new(tools::gis_analysis::PolygonArea::new())),
+            "polygongeometryattributes" => {
+                Some(Box::new(tools::gis_analysis::PolygonGeometryAttributes::new()))
+            }
             "polygonlongaxis" => Some(Box::new(tools::gis_analysis::PolygonLongAxis::new())),
             "polygonperimeter" => Some(Box::new(tools::gis_analysis::PolygonPerimeter::new())),
             "polygonshortaxis" => Some(Box::new(tools::gis_analysis::PolygonShortAxis::new())),
             "polygonize" => Some(Box::new(tools::gis_analysis::Polygonize::new())),
+            "quadratcount" => Some(Box::new(tools::gis_analysis::QuadratCount::new())),
             "radiusofgyration" => Some(Box::new(tools::gis_analysis::RadiusOfGyration::new())),
+            "randompoints" => Some(Box::new(tools::gis_analysis::RandomPoints::new())),
             "rastercellassignment" => {
                 Some(Box::new(tools::gis_analysis::RasterCellAssignment::new()))
             }
@@ -575,20 +727,31 @@ impl ToolManager {
                 Some(Box::new(tools::gis_analysis::ReclassEqualInterval::new()))
             }
             "reclassfromfile" => Some(Box::new(tools::gis_analysis::ReclassFromFile::new())),
+            "regulargridpoints" => Some(Box::new(tools::gis_analysis::RegularGridPoints::new())),
             "relatedcircumscribingcircle" => Some(Box::new(
                 tools::gis_analysis::RelatedCircumscribingCircle::new(),
             )),
+            "ripleysk" => Some(Box::new(tools::gis_analysis::RipleysK::new())),
             "shapecomplexityindex" => {
                 Some(Box::new(tools::gis_analysis::ShapeComplexityIndex::new()))
             }
             // "sibsoninterpolation" => {
             //     Some(Box::new(tools::gis_analysis::SibsonInterpolation::new()))
             // }
+            "simplifyvectors" => Some(Box::new(tools::gis_analysis::SimplifyVectors::new())),
             "smoothvectors" => Some(Box::new(tools::gis_analysis::SmoothVectors::new())),
+            "snappointstolines" => Some(Box::new(tools::gis_analysis::SnapPointsToLines::new())),
+            "splitbyattribute" => Some(Box::new(tools::gis_analysis::SplitByAttribute::new())),
+            "splitbypolygon" => Some(Box::new(tools::gis_analysis::SplitByPolygon::new())),
             "splitwithlines" => Some(Box::new(tools::gis_analysis::SplitWithLines::new())),
+            "stratifiedrandompoints" => {
+                Some(Box::new(tools::gis_analysis::StratifiedRandomPoints::new()))
+            }
             "sumoverlay" => Some(Box::new(tools::gis_analysis::SumOverlay::new())),
             "tingridding" => Some(Box::new(tools::gis_analysis::TINGridding::new())),
+            "validatepolygons" => Some(Box::new(tools::gis_analysis::ValidatePolygons::new())),
             "vectorhexbinning" => Some(Box::new(tools::gis_analysis::VectorHexBinning::new())),
+            "vertexzstatistics" => Some(Box::new(tools::gis_analysis::VertexZStatistics::new())),
             "voronoidiagram" => Some(Box::new(tools::gis_analysis::VoronoiDiagram::new())),
             "weightedoverlay" => Some(Box::new(tools::gis_analysis::WeightedOverlay::new())),
             "weightedsum" => Some(Box::new(tools::gis_analysis::WeightedSum::new())),
@@ -602,9 +765,19 @@ impl ToolManager {
             )),
             "basins" => Some(Box::new(tools::hydro_analysis::Basins::new())),
             "breachdepressions" => Some(Box::new(tools::hydro_analysis::BreachDepressions::new())),
+            "breachdepressionsleastcost" => {
+                Some(Box::new(tools::hydro_analysis::BreachDepressionsLeastCost::new()))
+            }
+            "breachorfilldepressions" => {
+                Some(Box::new(tools::hydro_analysis::BreachOrFillDepressions::new()))
+            }
             "breachsinglecellpits" => {
                 Some(Box::new(tools::hydro_analysis::BreachSingleCellPits::new()))
             }
+            "conditionstreamprofiles" => {
+                Some(Box::new(tools::hydro_analysis::ConditionStreamProfiles::new()))
+            }
+            "curvenumberrunoff" => Some(Box::new(tools::hydro_analysis::CurveNumberRunoff::new())),
             "d8flowaccumulation" => {
                 Some(Box::new(tools::hydro_analysis::D8FlowAccumulation::new()))
             }
@@ -616,12 +789,16 @@ impl ToolManager {
             }
             "dinfmassflux" => Some(Box::new(tools::hydro_analysis::DInfMassFlux::new())),
             "dinfpointer" => Some(Box::new(tools::hydro_analysis::DInfPointer::new())),
+            "distancetostreammetrics" => {
+                Some(Box::new(tools::hydro_analysis::DistanceToStreamMetrics::new()))
+            }
             "downslopedistancetostream" => Some(Box::new(
                 tools::hydro_analysis::DownslopeDistanceToStream::new(),
             )),
             "downslopeflowpathlength" => Some(Box::new(
                 tools::hydro_analysis::DownslopeFlowpathLength::new(),
             )),
+            "edgecontamination" => Some(Box::new(tools::hydro_analysis::EdgeContamination::new())),
             "elevationabovestream" => {
                 Some(Box::new(tools::hydro_analysis::ElevationAboveStream::new()))
             }
@@ -647,6 +824,9 @@ impl ToolManager {
             "flowlengthdiff" => Some(Box::new(tools::hydro_analysis::FlowLengthDiff::new())),
             "hillslopes" => Some(Box::new(tools::hydro_analysis::Hillslopes::new())),
             "impoundmentindex" => Some(Box::new(tools::hydro_analysis::ImpoundmentIndex::new())),
+            "impoundmentstoragecurve" => {
+                Some(Box::new(tools::hydro_analysis::ImpoundmentStorageCurve::new()))
+            }
             "isobasins" => Some(Box::new(tools::hydro_analysis::Isobasins::new())),
             "jensonsnappourpoints" => {
                 Some(Box::new(tools::hydro_analysis::JensonSnapPourPoints::new()))
@@ -661,6 +841,7 @@ impl ToolManager {
             "raisewalls" => Some(Box::new(tools::hydro_analysis::RaiseWalls::new())),
             "rho8pointer" => Some(Box::new(tools::hydro_analysis::Rho8Pointer::new())),
             "sink" => Some(Box::new(tools::hydro_analysis::Sink::new())),
+            "sinkstatistics" => Some(Box::new(tools::hydro_analysis::SinkStatistics::new())),
             "snappourpoints" => Some(Box::new(tools::hydro_analysis::SnapPourPoints::new())),
             "stochasticdepressionanalysis" => Some(Box::new(
                 tools::hydro_analysis::StochasticDepressionAnalysis::new(),
@@ -668,23 +849,41 @@ impl ToolManager {
             "strahlerorderbasins" => {
                 Some(Box::new(tools::hydro_analysis::StrahlerOrderBasins::new()))
             }
+            "subbasinparameters" => {
+                Some(Box::new(tools::hydro_analysis::SubbasinParameters::new()))
+            }
             "subbasins" => Some(Box::new(tools::hydro_analysis::Subbasins::new())),
             "tracedownslopeflowpaths" => Some(Box::new(
                 tools::hydro_analysis::TraceDownslopeFlowpaths::new(),
             )),
+            "traceflowpathlines" => {
+                Some(Box::new(tools::hydro_analysis::TraceFlowpathLines::new()))
+            }
             "unnestbasins" => Some(Box::new(tools::hydro_analysis::UnnestBasins::new())),
+            "upscaleflowdirections" => {
+                Some(Box::new(tools::hydro_analysis::UpscaleFlowDirections::new()))
+            }
             "watershed" => Some(Box::new(tools::hydro_analysis::Watershed::new())),
+            "weightedflowaccumulation" => {
+                Some(Box::new(tools::hydro_analysis::WeightedFlowAccumulation::new()))
+            }
 
             // image_analysis
             "adaptivefilter" => Some(Box::new(tools::image_analysis::AdaptiveFilter::new())),
+            "applylookuptable" => Some(Box::new(tools::image_analysis::ApplyLookupTable::new())),
             "balancecontrastenhancement" => Some(Box::new(
                 tools::image_analysis::BalanceContrastEnhancement::new(),
             )),
+            "balancedmosaic" => Some(Box::new(tools::image_analysis::BalancedMosaic::new())),
             "bilateralfilter" => Some(Box::new(tools::image_analysis::BilateralFilter::new())),
+            "bytecontraststretch" => {
+                Some(Box::new(tools::image_analysis::ByteContrastStretch::new()))
+            }
             "changevectoranalysis" => {
                 Some(Box::new(tools::image_analysis::ChangeVectorAnalysis::new()))
             }
             "closing" => Some(Box::new(tools::image_analysis::Closing::new())),
+            "colourbalance" => Some(Box::new(tools::image_analysis::ColourBalance::new())),
             "cornerdetection" => Some(Box::new(tools::image_analysis::CornerDetection::new())),
             "correctvignetting" => Some(Box::new(tools::image_analysis::CorrectVignetting::new())),
             "conservativesmoothingfilter" => Some(Box::new(
@@ -693,6 +892,10 @@ impl ToolManager {
             "createcolourcomposite" => {
                 Some(Box::new(tools::image_analysis::CreateColourComposite::new()))
             }
+            "darkobjectsubtraction" => {
+                Some(Box::new(tools::image_analysis::DarkObjectSubtraction::new()))
+            }
+            "destripingfilter" => Some(Box::new(tools::image_analysis::DestripingFilter::new())),
             "directdecorrelationstretch" => Some(Box::new(
                 tools::image_analysis::DirectDecorrelationStretch::new(),
             )),
@@ -704,10 +907,18 @@ impl ToolManager {
                 tools::image_analysis::EdgePreservingMeanFilter::new(),
             )),
             "embossfilter" => Some(Box::new(tools::image_analysis::EmbossFilter::new())),
+            "extractbands" => Some(Box::new(tools::image_analysis::ExtractBands::new())),
             "fastalmostgaussianfilter" => Some(Box::new(
                 tools::image_analysis::FastAlmostGaussianFilter::new(),
             )),
+            "fastfouriertransform" => {
+                Some(Box::new(tools::image_analysis::FastFourierTransform::new()))
+            }
             "flipimage" => Some(Box::new(tools::image_analysis::FlipImage::new())),
+            "focalstatistics" => Some(Box::new(tools::image_analysis::FocalStatistics::new())),
+            "frequencydomainfilter" => {
+                Some(Box::new(tools::image_analysis::FrequencyDomainFilter::new()))
+            }
             "gammacorrection" => Some(Box::new(tools::image_analysis::GammaCorrection::new())),
             "gaussiancontraststretch" => Some(Box::new(
                 tools::image_analysis::GaussianContrastStretch::new(),
@@ -724,13 +935,26 @@ impl ToolManager {
             "histogrammatchingtwoimages" => Some(Box::new(
                 tools::image_analysis::HistogramMatchingTwoImages::new(),
             )),
+            "hsvtorgb" => Some(Box::new(tools::image_analysis::HsvToRgb::new())),
             "ihstorgb" => Some(Box::new(tools::image_analysis::IhsToRgb::new())),
+            "imagecoregistration" => {
+                Some(Box::new(tools::image_analysis::ImageCoregistration::new()))
+            }
+            "imagedifferencing" => Some(Box::new(tools::image_analysis::ImageDifferencing::new())),
+            "imageratio" => Some(Box::new(tools::image_analysis::ImageRatio::new())),
+            "imagestack" => Some(Box::new(tools::image_analysis::ImageStack::new())),
             "imagestackprofile" => Some(Box::new(tools::image_analysis::ImageStackProfile::new())),
             "integralimage" => Some(Box::new(tools::image_analysis::IntegralImage::new())),
+            "inversefastfouriertransform" => {
+                Some(Box::new(tools::image_analysis::InverseFastFourierTransform::new()))
+            }
             "kmeansclustering" => Some(Box::new(tools::image_analysis::KMeansClustering::new())),
             "knearestmeanfilter" => {
                 Some(Box::new(tools::image_analysis::KNearestMeanFilter::new()))
             }
+            "landsattoaconversion" => {
+                Some(Box::new(tools::image_analysis::LandsatToaConversion::new()))
+            }
             "laplacianfilter" => Some(Box::new(tools::image_analysis::LaplacianFilter::new())),
             "laplacianofgaussianfilter" => Some(Box::new(
                 tools::image_analysis::LaplacianOfGaussianFilter::new(),
@@ -748,9 +972,13 @@ impl ToolManager {
             "meanfilter" => Some(Box::new(tools::image_analysis::MeanFilter::new())),
             "medianfilter" => Some(Box::new(tools::image_analysis::MedianFilter::new())),
             "minimumfilter" => Some(Box::new(tools::image_analysis::MinimumFilter::new())),
+            "minorityfilter" => Some(Box::new(tools::image_analysis::MinorityFilter::new())),
             "modifiedkmeansclustering" => Some(Box::new(
                 tools::image_analysis::ModifiedKMeansClustering::new(),
             )),
+            "morphologicalfilter" => {
+                Some(Box::new(tools::image_analysis::MorphologicalFilter::new()))
+            }
             "mosaic" => Some(Box::new(tools::image_analysis::Mosaic::new())),
             "normalizeddifferencevegetationindex" => Some(Box::new(
                 tools::image_analysis::NormalizedDifferenceVegetationIndex::new(),
@@ -766,13 +994,21 @@ impl ToolManager {
             "percentilefilter" => Some(Box::new(tools::image_analysis::PercentileFilter::new())),
             "prewittfilter" => Some(Box::new(tools::image_analysis::PrewittFilter::new())),
             "rangefilter" => Some(Box::new(tools::image_analysis::RangeFilter::new())),
+            "rankfilter" => Some(Box::new(tools::image_analysis::RankFilter::new())),
+            "regressionchangedetection" => {
+                Some(Box::new(tools::image_analysis::RegressionChangeDetection::new()))
+            }
             "removespurs" => Some(Box::new(tools::image_analysis::RemoveSpurs::new())),
             "resample" => Some(Box::new(tools::image_analysis::Resample::new())),
+            "rgbtohsv" => Some(Box::new(tools::image_analysis::RgbToHsv::new())),
             "rgbtoihs" => Some(Box::new(tools::image_analysis::RgbToIhs::new())),
             "robertscrossfilter" => {
                 Some(Box::new(tools::image_analysis::RobertsCrossFilter::new()))
             }
             "scharrfilter" => Some(Box::new(tools::image_analysis::ScharrFilter::new())),
+            "sentinel2toaconversion" => {
+                Some(Box::new(tools::image_analysis::Sentinel2ToaConversion::new()))
+            }
             "sigmoidalcontraststretch" => Some(Box::new(
                 tools::image_analysis::SigmoidalContrastStretch::new(),
             )),
@@ -788,6 +1024,9 @@ impl ToolManager {
             )),
             "thickenrasterline" => Some(Box::new(tools::image_analysis::ThickenRasterLine::new())),
             "tophattransform" => Some(Box::new(tools::image_analysis::TophatTransform::new())),
+            "topographiccorrection" => {
+                Some(Box::new(tools::image_analysis::TopographicCorrection::new()))
+            }
             "totalfilter" => Some(Box::new(tools::image_analysis::TotalFilter::new())),
             "unsharpmasking" => Some(Box::new(tools::image_analysis::UnsharpMasking::new())),
             "userdefinedweightsfilter" => Some(Box::new(
@@ -824,13 +1063,19 @@ impl ToolManager {
             "lidarclassifysubset" => {
                 Some(Box::new(tools::lidar_analysis::LidarClassifySubset::new()))
             }
+            "lidarclip" => Some(Box::new(tools::lidar_analysis::LidarClip::new())),
             "lidarcolourize" => Some(Box::new(tools::lidar_analysis::LidarColourize::new())),
             "lidarconstructvectortin" => Some(Box::new(
                 tools::lidar_analysis::LidarConstructVectorTIN::new(),
             )),
+            "lidareigenvaluefeatures" => Some(Box::new(
+                tools::lidar_analysis::LidarEigenvalueFeatures::new(),
+            )),
             "lidarelevationslice" => {
                 Some(Box::new(tools::lidar_analysis::LidarElevationSlice::new()))
             }
+            "lidarerase" => Some(Box::new(tools::lidar_analysis::LidarErase::new())),
+            "lidargridstats" => Some(Box::new(tools::lidar_analysis::LidarGridStats::new())),
             "lidargroundpointfilter" => Some(Box::new(
                 tools::lidar_analysis::LidarGroundPointFilter::new(),
             )),
@@ -894,6 +1139,9 @@ impl ToolManager {
                 tools::math_stat_analysis::AttributeScattergram::new(),
             )),
             "ceil" => Some(Box::new(tools::math_stat_analysis::Ceil::new())),
+            "classificationaccuracy" => {
+                Some(Box::new(tools::math_stat_analysis::ClassificationAccuracy::new()))
+            }
             "cos" => Some(Box::new(tools::math_stat_analysis::Cos::new())),
             "cosh" => Some(Box::new(tools::math_stat_analysis::Cosh::new())),
             "crispnessindex" => Some(Box::new(tools::math_stat_analysis::CrispnessIndex::new())),
@@ -910,6 +1158,7 @@ impl ToolManager {
                 tools::math_stat_analysis::ExtractRasterStatistics::new(),
             )),
             "floor" => Some(Box::new(tools::math_stat_analysis::Floor::new())),
+            "getisordgistar" => Some(Box::new(tools::math_stat_analysis::GetisOrdGiStar::new())),
             "greaterthan" => Some(Box::new(tools::math_stat_analysis::GreaterThan::new())),
             "imageautocorrelation" => Some(Box::new(
                 tools::math_stat_analysis::ImageAutocorrelation::new(),
@@ -933,6 +1182,7 @@ impl ToolManager {
             "listuniquevalues" => {
                 Some(Box::new(tools::math_stat_analysis::ListUniqueValues::new()))
             }
+            "localmoransi" => Some(Box::new(tools::math_stat_analysis::LocalMoransI::new())),
             "log10" => Some(Box::new(tools::math_stat_analysis::Log10::new())),
             "log2" => Some(Box::new(tools::math_stat_analysis::Log2::new())),
             "ln" => Some(Box::new(tools::math_stat_analysis::Ln::new())),
@@ -952,6 +1202,9 @@ impl ToolManager {
             "randomfield" => Some(Box::new(tools::math_stat_analysis::RandomField::new())),
             "randomsample" => Some(Box::new(tools::math_stat_analysis::RandomSample::new())),
             "rasterhistogram" => Some(Box::new(tools::math_stat_analysis::RasterHistogram::new())),
+            "rasterstatisticsreport" => {
+                Some(Box::new(tools::math_stat_analysis::RasterStatisticsReport::new()))
+            }
             "rastersummarystats" => Some(Box::new(
                 tools::math_stat_analysis::RasterSummaryStats::new(),
             )),
@@ -963,6 +1216,7 @@ impl ToolManager {
                 tools::math_stat_analysis::RootMeanSquareError::new(),
             )),
             "round" => Some(Box::new(tools::math_stat_analysis::Round::new())),
+            "semivariogram" => Some(Box::new(tools::math_stat_analysis::Semivariogram::new())),
             "sin" => Some(Box::new(tools::math_stat_analysis::Sin::new())),
             "sinh" => Some(Box::new(tools::math_stat_analysis::Sinh::new())),
             "square" => Some(Box::new(tools::math_stat_analysis::Square::new())),
@@ -984,9 +1238,18 @@ impl ToolManager {
             "zscores" => Some(Box::new(tools::math_stat_analysis::ZScores::new())),
 
             // stream_network_analysis
+            "accumulatevectornetwork" => {
+                Some(Box::new(tools::stream_network_analysis::AccumulateVectorNetwork::new()))
+            }
             "distancetooutlet" => Some(Box::new(
                 tools::stream_network_analysis::DistanceToOutlet::new(),
             )),
+            "drainagedensity" => {
+                Some(Box::new(tools::stream_network_analysis::DrainageDensity::new()))
+            }
+            "extractstreamnodes" => {
+                Some(Box::new(tools::stream_network_analysis::ExtractStreamNodes::new()))
+            }
             "extractstreams" => Some(Box::new(
                 tools::stream_network_analysis::ExtractStreams::new(),
             )),
@@ -1003,6 +1266,9 @@ impl ToolManager {
             "hortonstreamorder" => Some(Box::new(
                 tools::stream_network_analysis::HortonStreamOrder::new(),
             )),
+            "knickpointanalysis" => {
+                Some(Box::new(tools::stream_network_analysis::KnickpointAnalysis::new()))
+            }
             "lengthofupstreamchannels" => Some(Box::new(
                 tools::stream_network_analysis::LengthOfUpstreamChannels::new(),
             )),
@@ -1025,6 +1291,9 @@ impl ToolManager {
             "strahlerstreamorder" => Some(Box::new(
                 tools::stream_network_analysis::StrahlerStreamOrder::new(),
             )),
+            "streamlinkattributes" => {
+                Some(Box::new(tools::stream_network_analysis::StreamLinkAttributes::new()))
+            }
             "streamlinkclass" => Some(Box::new(
                 tools::stream_network_analysis::StreamLinkClass::new(),
             )),
@@ -1037,15 +1306,24 @@ impl ToolManager {
             "streamlinkslope" => Some(Box::new(
                 tools::stream_network_analysis::StreamLinkSlope::new(),
             )),
+            "streamordering" => {
+                Some(Box::new(tools::stream_network_analysis::StreamOrdering::new()))
+            }
             "streamslopecontinuous" => Some(Box::new(
                 tools::stream_network_analysis::StreamSlopeContinuous::new(),
             )),
             "topologicalstreamorder" => Some(Box::new(
                 tools::stream_network_analysis::TopologicalStreamOrder::new(),
             )),
+            "tracevectornetwork" => {
+                Some(Box::new(tools::stream_network_analysis::TraceVectorNetwork::new()))
+            }
             "tributaryidentifier" => Some(Box::new(
                 tools::stream_network_analysis::TributaryIdentifier::new(),
             )),
+            "vectornetworktopology" => Some(Box::new(
+                tools::stream_network_analysis::VectorNetworkTopology::new(),
+            )),
 
             // terrain_analysis
             "aspect" => Some(Box::new(tools::terrain_analysis::Aspect::new())),
@@ -1066,6 +1344,7 @@ impl ToolManager {
             "elevrelativetowatershedminmax" => Some(Box::new(
                 tools::terrain_analysis::ElevRelativeToWatershedMinMax::new(),
             )),
+            "extractprofile" => Some(Box::new(tools::terrain_analysis::ExtractProfile::new())),
             "featurepreservingdenoise" => Some(Box::new(
                 tools::terrain_analysis::FeaturePreservingDenoise::new(),
             )),
@@ -1077,6 +1356,8 @@ impl ToolManager {
             "hypsometricanalysis" => {
                 Some(Box::new(tools::terrain_analysis::HypsometricAnalysis::new()))
             }
+            "lineofsight" => Some(Box::new(tools::terrain_analysis::LineOfSight::new())),
+            "localreliefmodel" => Some(Box::new(tools::terrain_analysis::LocalReliefModel::new())),
             "maxanisotropydev" => Some(Box::new(tools::terrain_analysis::MaxAnisotropyDev::new())),
             "maxanisotropydevsignature" => Some(Box::new(
                 tools::terrain_analysis::MaxAnisotropyDevSignature::new(),
@@ -1097,6 +1378,9 @@ impl ToolManager {
             "mindownslopeelevchange" => Some(Box::new(
                 tools::terrain_analysis::MinDownslopeElevChange::new(),
             )),
+            "multiscaleelevationresidual" => {
+                Some(Box::new(tools::terrain_analysis::MultiscaleElevationResidual::new()))
+            }
             "multiscaleroughness" => {
                 Some(Box::new(tools::terrain_analysis::MultiscaleRoughness::new()))
             }
@@ -1140,6 +1424,7 @@ impl ToolManager {
             "standarddeviationofslope" => Some(Box::new(
                 tools::terrain_analysis::StandardDeviationOfSlope::new(),
             )),
+            "swathprofile" => Some(Box::new(tools::terrain_analysis::SwathProfile::new())),
             "tangentialcurvature" => {
                 Some(Box::new(tools::terrain_analysis::TangentialCurvature::new()))
             }
@@ -1157,8 +1442,23 @@ impl ToolManager {
         //     tool_args_vec.insert(0, format!("--wd={}", working_dir));
         // }
 
+        let mut args = args;
+        let harmonize = harmonize::take_harmonize_flag(&mut args);
         match self.get_tool(tool_name.as_ref()) {
-            Some(tool) => return tool.run(args, &self.working_dir, self.verbose),
+            Some(tool) => {
+                // a wildcard pattern or list of input files runs the tool for each file
+                if let Some(jobs) = batch::get_batch_jobs(self, &tool, &args)? {
+                    return batch::run_batch(self, tool.get_tool_name(), jobs);
+                }
+                if harmonize {
+                    // resample the raster inputs to a common grid, if necessary
+                    let (args, names) = harmonize::harmonize_inputs(self, &tool, &args)?;
+                    let result = tool.run(args, &self.working_dir, self.verbose);
+                    harmonize::remove_harmonized_rasters(&names);
+                    return result;
+                }
+                return tool.run(args, &self.working_dir, self.verbose);
+            }
             None => {
                 return Err(Error::new(
                     ErrorKind::NotFound,
@@ -1244,11 +1544,13 @@ impl ToolManager {
         for val in &self.tool_names {
             let tool = self.get_tool(&val).unwrap();
             let toolbox = tool.get_toolbox();
+            let tags = tool.get_keywords();
             let (nm, des) = get_name_and_description(tool);
             for kw in &keywords {
                 if nm.to_lowercase().contains(&(kw.to_lowercase()))
                     || des.to_lowercase().contains(&(kw.to_lowercase()))
                     || toolbox.to_lowercase().contains(&(kw.to_lowercase()))
+                    || tags.contains(&kw.to_lowercase())
                 {
                     tool_details.push(get_name_and_description(self.get_tool(&val).unwrap()));
                     break;
@@ -1293,6 +1595,66 @@ pub trait WhiteboxTool {
         working_directory: &'a str,
         verbose: bool,
     ) -> Result<(), Error>;
+
+    /// Returns keywords (tags) used to discover the tool. By default these are the
+    /// words of the tool's name and toolbox; tools may override this to add synonyms.
+    fn get_keywords(&self) -> Vec<String> {
+        let mut keywords = split_tool_name(&self.get_tool_name());
+        for w in self.get_toolbox().split(|c: char| c.is_whitespace() || c == '/') {
+            let w = w.to_lowercase();
+            if w.is_empty() || w == "and" || w == "tools" {
+                continue;
+            }
+            if !keywords.contains(&w) {
+                keywords.push(w);
+            }
+        }
+        keywords
+    }
+
+    /// Returns the names of closely related tools. If empty (the default), the tool
+    /// catalog infers related tools from shared keywords.
+    fn get_related_tools(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Splits a CamelCase tool name into lower-case words, keeping acronyms and trailing
+/// digits together, e.g. 'FD8FlowAccumulation' is split into 'fd8', 'flow', and
+/// 'accumulation', and 'DInfPointer' into 'dinf' and 'pointer'.
+fn split_tool_name(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = vec![];
+    let mut word = String::new();
+    for i in 0..chars.len() {
+        let c = chars[i];
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = i + 1 < chars.len() && chars[i + 1].is_lowercase();
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower)
+            {
+                words.push(word.clone());
+                word.clear();
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    // a single capital letter begins an acronym, e.g. the 'D' in 'DInf'
+    let mut ret: Vec<String> = vec![];
+    let mut i = 0;
+    while i < words.len() {
+        if words[i].len() == 1 && i + 1 < words.len() {
+            ret.push(format!("{}{}", words[i], words[i + 1]).to_lowercase());
+            i += 2;
+        } else {
+            ret.push(words[i].to_lowercase());
+            i += 1;
+        }
+    }
+    ret
 }
 
 fn get_help<'a>(wt: Box<WhiteboxTool + 'a>) -> String {
@@ -1351,6 +1713,28 @@ fn get_name_and_description<'a>(wt: Box<WhiteboxTool + 'a>) -> (String, String)
     (wt.get_tool_name(), wt.get_tool_description())
 }
 
+/// Returns the flags of a tool's output (new file) parameters, in the lower-case
+/// single-dash form used when parsing arguments (e.g. '-output').
+fn get_output_parameter_flags<'a>(wt: &Box<WhiteboxTool + 'a>) -> Vec<String> {
+    let mut output_flags = vec![];
+    if let Ok(p) = serde_json::from_str::<serde_json::Value>(&wt.get_tool_parameters()) {
+        if let Some(params) = p["parameters"].as_array() {
+            for param in params {
+                if param["parameter_type"].get("NewFile").is_some() {
+                    if let Some(flags) = param["flags"].as_array() {
+                        for f in flags {
+                            if let Some(f) = f.as_str() {
+                                output_flags.push(f.to_lowercase().replace("--", "-"));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    output_flags
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct ToolParameter {
     name: String,
@@ -1362,6 +1746,15 @@ struct ToolParameter {
 }
 
 impl ToolParameter {
+    /// Returns whether a value must be provided for the parameter. A Boolean parameter
+    /// is never required, since its absence means false.
+    fn is_required(&self) -> bool {
+        match self.parameter_type {
+            ParameterType::Boolean => false,
+            _ => !self.optional && self.default_value.is_none(),
+        }
+    }
+
     pub fn to_string(&self) -> String {
         let v = match serde_json::to_string(&self) {
             Ok(json_str) => json_str,
@@ -1371,6 +1764,281 @@ impl ToolParameter {
     }
 }
 
+/// A tool's command-line arguments, parsed using the tool's parameters.
+///
+/// Arguments may be given either as `--flag=value` or as `--flag value`. Flags are
+/// case-insensitive and may be preceded by one or two dashes. Boolean parameters may
+/// be given without a value. Quoted values, including file names containing spaces,
+/// keep their contents even if they have been split into several arguments.
+///
+/// Values are retrieved using the flags that a tool accepts for a parameter, and are
+/// validated and converted to the required type. For example:
+///
+/// ```ignore
+/// let tool_args = ToolArgs::new(&self.parameters, &args);
+/// if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+///     z_factor = v;
+/// }
+/// ```
+///
+/// A required parameter (i.e. one that is neither optional, nor has a default value,
+/// nor is a Boolean flag) that has not been provided results in an error when its
+/// value is retrieved.
+struct ToolArgs<'a> {
+    parameters: &'a [ToolParameter],
+    values: Vec<(String, Option<String>)>,
+}
+
+impl<'a> ToolArgs<'a> {
+    pub fn new(parameters: &'a [ToolParameter], args: &[String]) -> ToolArgs<'a> {
+        let mut tool_args = ToolArgs {
+            parameters: parameters,
+            values: vec![],
+        };
+        let mut i = 0;
+        while i < args.len() {
+            let (arg, next) = join_quoted_args(args, i);
+            i = next;
+            if !is_flag(&arg) {
+                // a value without a flag is ignored
+                continue;
+            }
+            let mut parts = arg.splitn(2, '=');
+            let flag = normalize_flag(parts.next().unwrap());
+            let value = match parts.next() {
+                Some(v) => Some(unquote(v)),
+                None if i < args.len() => {
+                    // the value, if any, is the next argument
+                    let (next_arg, next) = join_quoted_args(args, i);
+                    let is_value = if tool_args.is_boolean(&flag) {
+                        parse_bool(&unquote(&next_arg)).is_some()
+                    } else {
+                        !is_flag(&next_arg)
+                    };
+                    if is_value {
+                        i = next;
+                        Some(unquote(&next_arg))
+                    } else {
+                        None
+                    }
+                }
+                None => None,
+            };
+            tool_args.values.push((flag, value));
+        }
+        tool_args
+    }
+
+    /// Returns the value of a parameter, if it has been provided.
+    pub fn get_string(&self, flags: &[&str]) -> Result<Option<String>, Error> {
+        match self.get_value(flags)? {
+            Some(Some(v)) => Ok(Some(v.to_string())),
+            Some(None) => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("No value was provided for {}.", self.describe(flags)),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the numeric value of a parameter, if it has been provided.
+    pub fn get_f64(&self, flags: &[&str]) -> Result<Option<f64>, Error> {
+        match self.get_string(flags)? {
+            Some(v) => match v.trim().parse::<f64>() {
+                Ok(n) => Ok(Some(n)),
+                Err(_) => Err(self.invalid_value(flags, &v, "a number")),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the integer value of a parameter, if it has been provided. Any
+    /// fractional part is discarded.
+    pub fn get_isize(&self, flags: &[&str]) -> Result<Option<isize>, Error> {
+        match self.get_string(flags)? {
+            Some(v) => match v.trim().parse::<f64>() {
+                Ok(n) if n.is_finite() => Ok(Some(n as isize)),
+                _ => Err(self.invalid_value(flags, &v, "an integer")),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the non-negative integer value of a parameter, if it has been provided.
+    /// Any fractional part is discarded.
+    pub fn get_usize(&self, flags: &[&str]) -> Result<Option<usize>, Error> {
+        match self.get_string(flags)? {
+            Some(v) => match v.trim().parse::<f64>() {
+                Ok(n) if n.is_finite() && n >= 0f64 => Ok(Some(n as usize)),
+                _ => Err(self.invalid_value(flags, &v, "a non-negative integer")),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the values of a parameter containing a list of integers between 0 and 255,
+    /// e.g. LiDAR point classes, separated by commas or semicolons, if it has been provided.
+    pub fn get_u8_list(&self, flags: &[&str]) -> Result<Option<Vec<u8>>, Error> {
+        match self.get_string(flags)? {
+            Some(v) => {
+                let separator = if v.contains(',') { ',' } else { ';' };
+                let mut list = vec![];
+                for value in v.split(separator) {
+                    if value.trim().is_empty() {
+                        continue;
+                    }
+                    match value.trim().parse::<u8>() {
+                        Ok(n) => list.push(n),
+                        Err(_) => {
+                            return Err(self.invalid_value(
+                                flags,
+                                value.trim(),
+                                "an integer between 0 and 255",
+                            ))
+                        }
+                    }
+                }
+                Ok(Some(list))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns whether a Boolean parameter is set, i.e. it has been provided either
+    /// without a value or with a value of true.
+    pub fn get_flag(&self, flags: &[&str]) -> Result<bool, Error> {
+        match self.get_value(flags)? {
+            Some(Some(v)) => match parse_bool(v) {
+                Some(b) => Ok(b),
+                None => Err(self.invalid_value(flags, v, "true or false")),
+            },
+            Some(None) => Ok(true),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the value of the last occurrence of any of the flags, or of the other
+    /// flags of the corresponding parameter. The value is `Some(None)` for a flag that
+    /// has been provided without a value.
+    fn get_value(&self, flags: &[&str]) -> Result<Option<Option<&str>>, Error> {
+        let mut flags: Vec<String> = flags.iter().map(|f| normalize_flag(f)).collect();
+        let parameter = self.find_parameter(&flags);
+        if let Some(p) = parameter {
+            flags.extend(p.flags.iter().map(|f| normalize_flag(f)));
+        }
+        for &(ref flag, ref value) in self.values.iter().rev() {
+            if flags.contains(flag) {
+                return Ok(Some(value.as_ref().map(|v| v.as_str())));
+            }
+        }
+        match parameter {
+            Some(p) if p.is_required() => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The required parameter {} was not provided.",
+                    describe_parameter(p)
+                ),
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn find_parameter(&self, flags: &[String]) -> Option<&ToolParameter> {
+        self.parameters
+            .iter()
+            .find(|p| p.flags.iter().any(|f| flags.contains(&normalize_flag(f))))
+    }
+
+    fn is_boolean(&self, flag: &str) -> bool {
+        match self.find_parameter(&[flag.to_string()]) {
+            Some(p) => match p.parameter_type {
+                ParameterType::Boolean => true,
+                _ => false,
+            },
+            None => false,
+        }
+    }
+
+    fn describe(&self, flags: &[&str]) -> String {
+        let normalized: Vec<String> = flags.iter().map(|f| normalize_flag(f)).collect();
+        match self.find_parameter(&normalized) {
+            Some(p) => describe_parameter(p),
+            None => flags.last().unwrap_or(&"").to_string(),
+        }
+    }
+
+    fn invalid_value(&self, flags: &[&str], value: &str, expected: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Invalid value '{}' for {}; {} was expected.",
+                value,
+                self.describe(flags),
+                expected
+            ),
+        )
+    }
+}
+
+/// Describes a parameter in error messages, e.g. '--zfactor (Z Conversion Factor)'.
+fn describe_parameter(p: &ToolParameter) -> String {
+    match p.flags.last() {
+        Some(f) => format!("{} ({})", f, p.name),
+        None => p.name.clone(),
+    }
+}
+
+/// Returns the argument starting at `args[i]` and the index of the following argument.
+/// An argument, or the value following its '=', that begins with a quote is joined with
+/// the arguments that follow it until the closing quote.
+fn join_quoted_args(args: &[String], i: usize) -> (String, usize) {
+    let mut arg = args[i].trim().to_string();
+    let value_start = match arg.find('=') {
+        Some(k) if is_flag(&arg) => k + 1,
+        _ => 0,
+    };
+    let quote = match arg[value_start..].chars().next() {
+        Some(c) if c == '"' || c == '\'' => c,
+        _ => return (arg, i + 1),
+    };
+    let mut j = i + 1;
+    while arg[value_start..].matches(quote).count() % 2 == 1 && j < args.len() {
+        arg.push(' ');
+        arg.push_str(args[j].trim());
+        j += 1;
+    }
+    (arg, j)
+}
+
+/// Removes the quotes enclosing a value.
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    for quote in &["\"", "\'"] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return value[1..value.len() - 1].to_string();
+        }
+    }
+    value.trim_matches(|c| c == '"' || c == '\'').to_string()
+}
+
+/// Returns whether an argument is a flag rather than a value, e.g. a negative number.
+fn is_flag(arg: &str) -> bool {
+    let flag = arg.splitn(2, '=').next().unwrap();
+    flag.starts_with('-') && flag.len() > 1 && flag.parse::<f64>().is_err()
+}
+
+fn normalize_flag(flag: &str) -> String {
+    flag.trim_left_matches('-').to_lowercase()
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_ref() {
+        "true" | "t" | "yes" | "1" => Some(true),
+        "false" | "f" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 enum ParameterType {
     Boolean,
@@ -1418,3 +2086,161 @@ enum AttributeType {
     Boolean,
     Date,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{parse_bool, ParameterFileType, ParameterType, ToolArgs, ToolParameter};
+
+    fn parameter(name: &str, flags: &[&str], parameter_type: ParameterType) -> ToolParameter {
+        ToolParameter {
+            name: name.to_owned(),
+            flags: flags.iter().map(|f| f.to_string()).collect(),
+            description: String::new(),
+            parameter_type: parameter_type,
+            default_value: None,
+            optional: false,
+        }
+    }
+
+    fn parameters() -> Vec<ToolParameter> {
+        let mut zfactor = parameter("Z Conversion Factor", &["--zfactor"], ParameterType::Float);
+        zfactor.optional = true;
+        vec![
+            parameter(
+                "Input File",
+                &["-i", "--input"],
+                ParameterType::ExistingFile(ParameterFileType::Raster),
+            ),
+            parameter(
+                "Output File",
+                &["-o", "--output"],
+                ParameterType::NewFile(ParameterFileType::Raster),
+            ),
+            zfactor,
+            parameter("Fill Holes?", &["--fill"], ParameterType::Boolean),
+        ]
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        let params = parameters();
+        // a quoted value split into several arguments by the shell is re-joined
+        let a = args(&["-i=\"my", "DEM.tif\"", "--output", "'out", "file.tif'"]);
+        let tool_args = ToolArgs::new(&params, &a);
+        assert_eq!(
+            tool_args.get_string(&["-i"]).unwrap(),
+            Some("my DEM.tif".to_string())
+        );
+        assert_eq!(
+            tool_args.get_string(&["-o"]).unwrap(),
+            Some("out file.tif".to_string())
+        );
+    }
+
+    #[test]
+    fn test_flag_forms() {
+        let params = parameters();
+        for a in &[
+            args(&["--input=DEM.tif", "--zfactor=2.5"]),
+            args(&["--input", "DEM.tif", "--zfactor", "2.5"]),
+            args(&["-I=DEM.tif", "-ZFACTOR", "2.5"]),
+        ] {
+            let tool_args = ToolArgs::new(&params, a);
+            // any of a parameter's flags retrieves its value
+            assert_eq!(
+                tool_args.get_string(&["-i"]).unwrap(),
+                Some("DEM.tif".to_string())
+            );
+            assert_eq!(
+                tool_args.get_string(&["--input"]).unwrap(),
+                Some("DEM.tif".to_string())
+            );
+            assert_eq!(tool_args.get_f64(&["--zfactor"]).unwrap(), Some(2.5));
+        }
+    }
+
+    #[test]
+    fn test_negative_numbers() {
+        let params = parameters();
+        let a = args(&["--zfactor", "-0.5", "-i", "DEM.tif"]);
+        let tool_args = ToolArgs::new(&params, &a);
+        assert_eq!(tool_args.get_f64(&["--zfactor"]).unwrap(), Some(-0.5));
+        assert_eq!(
+            tool_args.get_string(&["-i"]).unwrap(),
+            Some("DEM.tif".to_string())
+        );
+
+        let a = args(&["--zfactor=-1e3"]);
+        let tool_args = ToolArgs::new(&params, &a);
+        assert_eq!(tool_args.get_f64(&["--zfactor"]).unwrap(), Some(-1000.0));
+    }
+
+    #[test]
+    fn test_invalid_numbers() {
+        let params = parameters();
+        let a = args(&["--zfactor=1.2.3"]);
+        assert!(ToolArgs::new(&params, &a).get_f64(&["--zfactor"]).is_err());
+        let a = args(&["--zfactor", "abc"]);
+        assert!(ToolArgs::new(&params, &a).get_f64(&["--zfactor"]).is_err());
+        let a = args(&["--zfactor=-2"]);
+        assert!(ToolArgs::new(&params, &a)
+            .get_usize(&["--zfactor"])
+            .is_err());
+        // a flag without a value
+        let a = args(&["--zfactor", "-i=DEM.tif"]);
+        assert!(ToolArgs::new(&params, &a).get_f64(&["--zfactor"]).is_err());
+    }
+
+    #[test]
+    fn test_required_parameters() {
+        let params = parameters();
+        let a = args(&["-o=out.tif"]);
+        let tool_args = ToolArgs::new(&params, &a);
+        let err = tool_args.get_string(&["-i"]).unwrap_err();
+        assert!(err.to_string().contains("--input (Input File)"));
+        // optional and Boolean parameters are not required
+        assert_eq!(tool_args.get_f64(&["--zfactor"]).unwrap(), None);
+        assert_eq!(tool_args.get_flag(&["--fill"]).unwrap(), false);
+    }
+
+    #[test]
+    fn test_boolean_parameters() {
+        let params = parameters();
+        let a = args(&["--fill", "DEM.tif"]);
+        // a value that is not a Boolean does not belong to a Boolean flag
+        assert_eq!(
+            ToolArgs::new(&params, &a).get_flag(&["--fill"]).unwrap(),
+            true
+        );
+        for (v, expected) in &[
+            ("--fill=false", false),
+            ("--fill=True", true),
+            ("--fill=0", false),
+        ] {
+            let a = args(&[v]);
+            assert_eq!(
+                ToolArgs::new(&params, &a).get_flag(&["--fill"]).unwrap(),
+                *expected
+            );
+        }
+        let a = args(&["--fill=maybe"]);
+        assert!(ToolArgs::new(&params, &a).get_flag(&["--fill"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        for v in &["true", "T", "yes", "1", " TRUE "] {
+            assert_eq!(parse_bool(v), Some(true));
+        }
+        for v in &["false", "f", "No", "0"] {
+            assert_eq!(parse_bool(v), Some(false));
+        }
+        for v in &["", "2", "on", "y"] {
+            assert_eq!(parse_bool(v), None);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use lidar::LasFile;
+use raster::Raster;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use tools::ToolManager;
+use utils::memory_store;
+use vector::Shapefile;
+
+static RUN_COUNTER: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// An in-memory dataset passed to, or returned from, a tool.
+pub enum Dataset {
+    Raster(Raster),
+    Vector(Shapefile),
+    Lidar(LasFile),
+}
+
+/// Runs a tool using in-memory `Raster`, `Shapefile`, and `LasFile` objects in
+/// place of input and output files, e.g. when embedding tools within another Rust
+/// application. Tools that expose a core function (e.g. `hydro_analysis::d8_pointer`)
+/// may instead be called directly.
+///
+/// Input datasets are bound to the tool's file parameters by flag and output
+/// datasets are requested by flag, e.g.:
+///
+/// ```
+/// let mut outputs = ToolRunner::new("ExtractStreams")
+///     .raster("flow_accum", flow_accum)
+///     .arg("threshold", 100.0)
+///     .flag("zero_background")
+///     .output("output")
+///     .run()?;
+/// let streams = outputs.take_raster("output")?;
+/// ```
+///
+/// Flags may be specified with or without their leading dashes. Calling one of the
+/// dataset methods several times with the same flag passes a list of datasets,
+/// for tools that accept multiple input files. Any ordinary file-name arguments
+/// may still be provided using `arg`.
+///
+/// Internally the datasets are exchanged through `utils::memory_store`, under
+/// `memory://` names that are unique to each run, so tools may be run concurrently
+/// from several threads. The datasets are removed from the store once the tool has run.
+pub struct ToolRunner {
+    tool_name: String,
+    args: Vec<(String, Option<String>)>,
+    inputs: Vec<(String, Dataset)>,
+    outputs: Vec<String>,
+    working_directory: String,
+    verbose: bool,
+}
+
+impl ToolRunner {
+    pub fn new(tool_name: &str) -> ToolRunner {
+        ToolRunner {
+            tool_name: tool_name.to_string(),
+            args: vec![],
+            inputs: vec![],
+            outputs: vec![],
+            working_directory: String::new(),
+            verbose: false,
+        }
+    }
+
+    /// Adds an argument with a value, e.g. `.arg("filter", 11)`.
+    pub fn arg<T: ToString>(mut self, flag: &str, value: T) -> ToolRunner {
+        self.args.push((normalize_flag(flag), Some(value.to_string())));
+        self
+    }
+
+    /// Adds a Boolean argument (a flag without a value), e.g. `.flag("esri_pntr")`.
+    pub fn flag(mut self, flag: &str) -> ToolRunner {
+        self.args.push((normalize_flag(flag), None));
+        self
+    }
+
+    /// Binds an in-memory raster to an input parameter.
+    pub fn raster(mut self, flag: &str, raster: Raster) -> ToolRunner {
+        self.inputs.push((normalize_flag(flag), Dataset::Raster(raster)));
+        self
+    }
+
+    /// Binds an in-memory vector to an input parameter.
+    pub fn vector(mut self, flag: &str, vector: Shapefile) -> ToolRunner {
+        self.inputs.push((normalize_flag(flag), Dataset::Vector(vector)));
+        self
+    }
+
+    /// Binds an in-memory LiDAR file to an input parameter.
+    pub fn lidar(mut self, flag: &str, lidar: LasFile) -> ToolRunner {
+        self.inputs.push((normalize_flag(flag), Dataset::Lidar(lidar)));
+        self
+    }
+
+    /// Requests that the output parameter `flag` be returned in memory rather than written to disk.
+    pub fn output(mut self, flag: &str) -> ToolRunner {
+        self.outputs.push(normalize_flag(flag));
+        self
+    }
+
+    /// Sets the working directory used to resolve any file-name arguments.
+    pub fn working_directory(mut self, working_directory: &str) -> ToolRunner {
+        self.working_directory = working_directory.to_string();
+        self
+    }
+
+    /// Sets whether the tool prints its progress and other output.
+    pub fn verbose(mut self, verbose: bool) -> ToolRunner {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Runs the tool, returning the requested outputs.
+    pub fn run(self) -> Result<ToolOutputs, Error> {
+        let run_id = RUN_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let prefix = format!("wbrun{}x", run_id);
+
+        let mut args: Vec<String> = vec![];
+        let mut input_names: Vec<(String, Vec<String>)> = vec![];
+        for (i, (flag, dataset)) in self.inputs.into_iter().enumerate() {
+            let name = match dataset {
+                Dataset::Raster(r) => {
+                    let name = format!("memory://{}in{}.tif", prefix, i);
+                    memory_store::put_raster(&name, r);
+                    name
+                }
+                Dataset::Vector(v) => {
+                    let name = format!("memory://{}in{}.shp", prefix, i);
+                    memory_store::put_vector(&name, v);
+                    name
+                }
+                Dataset::Lidar(l) => {
+                    let name = format!("memory://{}in{}.las", prefix, i);
+                    memory_store::put_lidar(&name, l);
+                    name
+                }
+            };
+            match input_names.iter().position(|&(ref f, _)| *f == flag) {
+                Some(j) => input_names[j].1.push(name),
+                None => input_names.push((flag, vec![name])),
+            }
+        }
+        for &(ref flag, ref names) in &input_names {
+            args.push(format!("{}={}", flag, names.join(";")));
+        }
+        let mut output_names: Vec<(String, String)> = vec![];
+        for (i, flag) in self.outputs.iter().enumerate() {
+            // no extension; each tool applies its default output format
+            let name = format!("memory://{}out{}", prefix, i);
+            args.push(format!("{}={}", flag, name));
+            output_names.push((flag.clone(), name));
+        }
+        for &(ref flag, ref val) in &self.args {
+            match val {
+                &Some(ref v) => args.push(format!("{}={}", flag, v)),
+                &None => args.push(flag.clone()),
+            }
+        }
+
+        let mut working_directory = self.working_directory.clone();
+        let sep = ::std::path::MAIN_SEPARATOR.to_string();
+        if !working_directory.is_empty() && !working_directory.ends_with(&sep) {
+            working_directory.push_str(&sep);
+        }
+        let result = match ToolManager::new(&working_directory, &self.verbose) {
+            Ok(tm) => tm.run_tool(self.tool_name.clone(), args),
+            Err(e) => Err(e),
+        };
+
+        let mut datasets = HashMap::new();
+        for &(ref flag, ref name) in &output_names {
+            if let Some(r) = memory_store::remove_raster(name) {
+                datasets.insert(flag.clone(), Dataset::Raster(r));
+            } else if let Some(v) = memory_store::remove_vector(name) {
+                datasets.insert(flag.clone(), Dataset::Vector(v));
+            } else if let Some(l) = memory_store::remove_lidar(name) {
+                datasets.insert(flag.clone(), Dataset::Lidar(l));
+            }
+        }
+        // also releases any secondary outputs that a tool derives from an output name
+        memory_store::remove_with_prefix(&prefix);
+
+        result?;
+        Ok(ToolOutputs { datasets: datasets })
+    }
+}
+
+/// The in-memory outputs of a `ToolRunner`, keyed by the output parameter flag.
+pub struct ToolOutputs {
+    datasets: HashMap<String, Dataset>,
+}
+
+impl ToolOutputs {
+    /// Removes and returns the dataset produced for output parameter `flag`, if any.
+    pub fn take(&mut self, flag: &str) -> Option<Dataset> {
+        self.datasets.remove(&normalize_flag(flag))
+    }
+
+    /// Removes and returns the raster produced for output parameter `flag`.
+    pub fn take_raster(&mut self, flag: &str) -> Result<Raster, Error> {
+        match self.take(flag) {
+            Some(Dataset::Raster(r)) => Ok(r),
+            _ => Err(missing_output(flag, "raster")),
+        }
+    }
+
+    /// Removes and returns the vector produced for output parameter `flag`.
+    pub fn take_vector(&mut self, flag: &str) -> Result<Shapefile, Error> {
+        match self.take(flag) {
+            Some(Dataset::Vector(v)) => Ok(v),
+            _ => Err(missing_output(flag, "vector")),
+        }
+    }
+
+    /// Removes and returns the LiDAR file produced for output parameter `flag`.
+    pub fn take_lidar(&mut self, flag: &str) -> Result<LasFile, Error> {
+        match self.take(flag) {
+            Some(Dataset::Lidar(l)) => Ok(l),
+            _ => Err(missing_output(flag, "LiDAR")),
+        }
+    }
+}
+
+fn normalize_flag(flag: &str) -> String {
+    let f = flag.trim();
+    if f.starts_with("-") {
+        f.to_string()
+    } else if f.len() == 1 {
+        format!("-{}", f)
+    } else {
+        format!("--{}", f)
+    }
+}
+
+fn missing_output(flag: &str, data_type: &str) -> Error {
+    Error::new(
+        ErrorKind::NotFound,
+        format!("The tool did not produce a {} output for '{}'.", data_type, flag),
+    )
+}
@@ -0,0 +1,416 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::LineNetwork;
+use tools::*;
+use vector::*;
+
+/// This tool accumulates a numeric attribute (`--field`) downstream through a vector
+/// stream network (`--input`), such that each feature in the output file (`--output`)
+/// is assigned the sum of the attribute value for the feature itself and all of the
+/// features upstream of it. If no field is specified, the length of each feature is
+/// accumulated, giving the total upstream channel length. The accumulated values are
+/// stored in a new attribute field (`--out_field`, default 'ACCUM'), and all of the
+/// input attributes are retained.
+///
+/// Each line feature is assumed to be digitized in the direction of flow. The network
+/// topology is built either by matching feature end-points that are within the `--snap`
+/// distance of one another, or from from-node and to-node identifier fields
+/// (`--from_field` and `--to_field`), in the same way as the `TraceVectorNetwork` tool.
+/// Where a node has more than one outflowing feature, e.g. in braided channels, the
+/// accumulated value is passed to each of the outflowing features. Features that are
+/// part of a flow loop cannot be ordered and are assigned their own value only.
+///
+/// # See Also
+/// `TraceVectorNetwork`, `LengthOfUpstreamChannels`
+pub struct AccumulateVectorNetwork {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl AccumulateVectorNetwork {
+    pub fn new() -> AccumulateVectorNetwork {
+        // public constructor
+        let name = "AccumulateVectorNetwork".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description =
+            "Accumulates a numeric attribute downstream through a vector stream network."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Stream Network File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector stream network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Accumulated Field (optional)".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Numeric field to accumulate; feature length is used if unspecified."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector lines file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Field Name".to_owned(),
+            flags: vec!["--out_field".to_owned()],
+            description: "Name of the output accumulated field.".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: Some("ACCUM".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Node Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which line end-points are considered to be connected."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "From-Node Field (optional)".to_owned(),
+            flags: vec!["--from_field".to_owned()],
+            description: "Optional input field containing from-node identifiers.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "To-Node Field (optional)".to_owned(),
+            flags: vec!["--to_field".to_owned()],
+            description: "Optional input field containing to-node identifiers.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp -o=output.shp --out_field=UP_LENGTH --snap=0.5
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp --field=DISCHARGE -o=output.shp --from_field=FROM_NODE --to_field=TO_NODE", short_exe, name).replace("*", &sep);
+
+        AccumulateVectorNetwork {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for AccumulateVectorNetwork {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut field_name = String::new();
+        let mut output_file = String::new();
+        let mut out_field = String::from("ACCUM");
+        let mut snap_dist = 0f64;
+        let mut from_field = String::new();
+        let mut to_field = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--field"])? {
+            field_name = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_field"])? {
+            out_field = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--snap"])? {
+            snap_dist = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--from_field"])? {
+            from_field = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--to_field"])? {
+            to_field = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if from_field.is_empty() != to_field.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Both the from-node and to-node fields must be specified.",
+            ));
+        }
+        if out_field.is_empty() || out_field.len() > 10 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The output field name must be between 1 and 10 characters long.",
+            ));
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of lines type
+        if input.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polyline base shape type.",
+            ));
+        }
+
+        for field in vec![&field_name, &from_field, &to_field] {
+            if !field.is_empty() && input.attributes.get_field_num(field).is_none() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The field {} does not exist in the attribute table.", field),
+                ));
+            }
+        }
+        if input.attributes.get_field_num(&out_field).is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("The field {} already exists in the attribute table.", out_field),
+            ));
+        }
+
+        // get the values to accumulate
+        let mut values = vec![0f64; input.num_records];
+        for record_num in 0..input.num_records {
+            if !field_name.is_empty() {
+                match input.attributes.get_value(record_num, &field_name) {
+                    FieldData::Int(val) => {
+                        values[record_num] = val as f64;
+                    }
+                    FieldData::Real(val) => {
+                        values[record_num] = val;
+                    }
+                    _ => {
+                        // do nothing; likely due to null value for record.
+                    }
+                }
+            } else {
+                let record = input.get_record(record_num);
+                for part in 0..record.num_parts as usize {
+                    let start_point_in_part = record.parts[part] as usize;
+                    let end_point_in_part = if part < record.num_parts as usize - 1 {
+                        record.parts[part + 1] as usize - 1
+                    } else {
+                        record.num_points as usize - 1
+                    };
+                    for i in start_point_in_part..end_point_in_part {
+                        values[record_num] += record.points[i].distance(&record.points[i + 1]);
+                    }
+                }
+            }
+        }
+
+        // build the network topology
+        let network = if !from_field.is_empty() {
+            let mut from_ids = Vec::with_capacity(input.num_records);
+            let mut to_ids = Vec::with_capacity(input.num_records);
+            for record_num in 0..input.num_records {
+                from_ids.push(format!(
+                    "{}",
+                    input.attributes.get_value(record_num, &from_field)
+                ));
+                to_ids.push(format!(
+                    "{}",
+                    input.attributes.get_value(record_num, &to_field)
+                ));
+            }
+            LineNetwork::from_node_ids(&from_ids, &to_ids)
+        } else {
+            let mut start_points = Vec::with_capacity(input.num_records);
+            let mut end_points = Vec::with_capacity(input.num_records);
+            for record_num in 0..input.num_records {
+                let record = input.get_record(record_num);
+                if record.num_points > 0 {
+                    start_points.push(record.points[0]);
+                    end_points.push(record.points[record.num_points as usize - 1]);
+                } else {
+                    // null shapes are isolated from the network
+                    start_points.push(Point2D::new(f64::NAN, f64::NAN));
+                    end_points.push(Point2D::new(f64::NAN, f64::NAN));
+                }
+            }
+            LineNetwork::from_endpoints(&start_points, &end_points, snap_dist)
+        };
+
+        // accumulate the values from upstream to downstream
+        let order = network.topological_order();
+        let mut accum = values.clone();
+        for &f in &order {
+            for &f2 in network.upstream_neighbours(f) {
+                accum[f] += accum[f2];
+            }
+        }
+        if verbose && order.len() < network.num_features() {
+            report::warning(&format!(
+                "{} features are part of flow loops and could not be accumulated.",
+                network.num_features() - order.len()
+            ));
+        }
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        output.attributes.add_field(&AttributeField::new(
+            &out_field,
+            FieldDataType::Real,
+            14u8,
+            4u8,
+        ));
+
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            output.add_record(record.clone());
+
+            let mut atts = input.attributes.get_record(record_num);
+            atts.push(FieldData::Real(accum[record_num]));
+            output.attributes.add_record(atts, false);
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -155,49 +155,25 @@ impl WhiteboxTool for DistanceToOutlet {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-d8_pntr" || vec[0].to_lowercase() == "--d8_pntr" {
-                if keyval {
-                    d8_file = vec[1].to_string();
-                } else {
-                    d8_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
-                if keyval {
-                    streams_file = vec[1].to_string();
-                } else {
-                    streams_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-esri_pntr"
-                || vec[0].to_lowercase() == "--esri_pntr"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                esri_style = true;
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-            {
-                background_val = 0f64;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            background_val = 0f64;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -216,12 +192,12 @@ impl WhiteboxTool for DistanceToOutlet {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            report::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let pntr_nodata = pntr.configs.nodata;
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -231,9 +207,6 @@ impl WhiteboxTool for DistanceToOutlet {
         let columns = pntr.configs.columns as isize;
         let num_cells = pntr.num_cells();
         let nodata = streams.configs.nodata;
-        if background_val == f64::NEG_INFINITY {
-            background_val = nodata;
-        }
 
         // make sure the input files have the same size
         if streams.configs.rows != pntr.configs.rows
@@ -241,11 +214,15 @@ impl WhiteboxTool for DistanceToOutlet {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        let out_nodata = output.configs.nodata;
+        if background_val == f64::NEG_INFINITY {
+            background_val = out_nodata;
+        }
         let mut stack = Vec::with_capacity((rows * columns) as usize);
 
         // Find outlet cells and add them to stack
@@ -295,7 +272,7 @@ impl WhiteboxTool for DistanceToOutlet {
                     if pntr[(row, col)] != pntr_nodata {
                         output[(row, col)] = background_val;
                     } else {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     }
                     num_solved_cells += 1;
                 }
@@ -303,7 +280,7 @@ impl WhiteboxTool for DistanceToOutlet {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -366,7 +343,7 @@ impl WhiteboxTool for DistanceToOutlet {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -389,19 +366,16 @@ impl WhiteboxTool for DistanceToOutlet {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
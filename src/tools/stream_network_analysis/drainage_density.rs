@@ -0,0 +1,378 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool calculates the drainage density of each sub-basin in a raster of basin
+/// identifiers (`--subbasins`), such as the output of the `Subbasins`, `Basins`, or
+/// `Watershed` tools. Drainage density is the total length of stream channel within a
+/// sub-basin divided by the area of the sub-basin. Channel length is measured along the
+/// D8 flow path (`--d8_pntr`) of the stream cells (`--streams`), and is expressed in
+/// map units per map unit squared (e.g. m/m<sup>2</sup>). Every grid cell within a sub-basin
+/// is assigned the drainage density of that sub-basin in the output raster (`--output`).
+///
+/// The optional `--multiplier` can be used to convert the output to more convenient units;
+/// for example, a multiplier of 1000 converts m/m<sup>2</sup> into km/km<sup>2</sup>.
+///
+/// # See Also
+/// `Subbasins`, `StreamLinkAttributes`, `LengthOfUpstreamChannels`
+pub struct DrainageDensity {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DrainageDensity {
+    pub fn new() -> DrainageDensity {
+        // public constructor
+        let name = "DrainageDensity".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description =
+            "Calculates the drainage density (channel length per unit area) of each sub-basin."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Streams File".to_owned(),
+            flags: vec!["--streams".to_owned()],
+            description: "Input raster streams file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Sub-basins File".to_owned(),
+            flags: vec!["--subbasins".to_owned()],
+            description: "Input raster sub-basin ID file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Multiplier".to_owned(),
+            flags: vec!["--multiplier".to_owned()],
+            description: "Multiplier applied to the drainage density values, e.g. for unit conversion."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --streams=streams.tif --subbasins=subbasins.tif -o=output.tif --multiplier=1000.0", short_exe, name).replace("*", &sep);
+
+        DrainageDensity {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DrainageDensity {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut d8_file = String::new();
+        let mut streams_file = String::new();
+        let mut subbasins_file = String::new();
+        let mut output_file = String::new();
+        let mut multiplier = 1f64;
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--subbasins"])? {
+            subbasins_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--multiplier"])? {
+            multiplier = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !streams_file.contains(&sep) && !streams_file.contains("/") {
+            streams_file = format!("{}{}", working_directory, streams_file);
+        }
+        if !subbasins_file.contains(&sep) && !subbasins_file.contains("/") {
+            subbasins_file = format!("{}{}", working_directory, subbasins_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading pointer data...")
+        };
+        let pntr = Raster::new(&d8_file, "r")?;
+        let pntr_nodata = pntr.configs.nodata;
+        if verbose {
+            report::message("Reading streams data...")
+        };
+        let streams = Raster::new(&streams_file, "r")?;
+        if verbose {
+            report::message("Reading sub-basins data...")
+        };
+        let subbasins = Raster::new(&subbasins_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let streams_nodata = streams.configs.nodata;
+        let nodata = subbasins.configs.nodata;
+        let cell_size_x = pntr.configs.resolution_x;
+        let cell_size_y = pntr.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+        let cell_area = cell_size_x * cell_size_y;
+
+        // make sure the input files have the same size
+        if streams.configs.rows != pntr.configs.rows
+            || streams.configs.columns != pntr.configs.columns
+            || subbasins.configs.rows != pntr.configs.rows
+            || subbasins.configs.columns != pntr.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+        }
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+
+        let min_id = subbasins.configs.minimum.floor();
+        let num_ids = (subbasins.configs.maximum.floor() - min_id) as usize + 1;
+        let mut basin_area = vec![0f64; num_ids];
+        let mut channel_length = vec![0f64; num_ids];
+        let mut id: usize;
+        let mut dir: usize;
+        for row in 0..rows {
+            for col in 0..columns {
+                if subbasins[(row, col)] != nodata {
+                    id = (subbasins[(row, col)].floor() - min_id) as usize;
+                    basin_area[id] += cell_area;
+                    if streams[(row, col)] > 0.0 && streams[(row, col)] != streams_nodata {
+                        dir = pntr[(row, col)] as usize;
+                        if dir > 0 && pntr[(row, col)] != pntr_nodata {
+                            if dir > 128 || pntr_matches[dir] == 999 {
+                                return Err(Error::new(ErrorKind::InvalidInput,
+                                    "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
+                            }
+                            channel_length[id] += grid_lengths[pntr_matches[dir]];
+                        }
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 1 of 2)", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut density = vec![nodata; num_ids];
+        for i in 0..num_ids {
+            if basin_area[i] > 0f64 {
+                density[i] = channel_length[i] / basin_area[i] * multiplier;
+            }
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &subbasins);
+        output.configs.data_type = DataType::F32;
+        for row in 0..rows {
+            for col in 0..columns {
+                if subbasins[(row, col)] != nodata {
+                    id = (subbasins[(row, col)].floor() - min_id) as usize;
+                    output[(row, col)] = density[id];
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 2 of 2)", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "spectrum.plt".to_string();
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input d8 pointer file: {}", d8_file));
+        output.add_metadata_entry(format!("Input streams file: {}", streams_file));
+        output.add_metadata_entry(format!("Input sub-basins file: {}", subbasins_file));
+        output.add_metadata_entry(format!("Multiplier: {}", multiplier));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,472 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+use vector::*;
+
+/// This tool identifies the nodes of a raster stream network, i.e. channel heads, tributary
+/// junctions (confluences), and network outlets, and outputs them as a vector points file
+/// (`--output`). The inputs are a D8 pointer raster (`--d8_pntr`) and a streams raster
+/// (`--streams`), in which stream cells have positive values.
+///
+/// - A *channel head* is a stream cell with no inflowing stream cells.
+/// - A *confluence* is a stream cell with two or more inflowing stream cells.
+/// - An *outlet* is a stream cell that does not flow into another stream cell, e.g. at the
+///   edge of the DEM or where a stream enters a lake or other non-stream area.
+///
+/// The output attribute table contains the node type (`TYPE`, one of 'head', 'confluence',
+/// and 'outlet'), the ID of the stream link that the node belongs to (`LINK_ID`; links are
+/// numbered in the same way as the `StreamOrdering` tool, and a confluence belongs to the
+/// link that begins at the confluence), the number of inflowing stream cells (`NUM_INFLOW`),
+/// and the Strahler order (`STRAHLER`) and Shreve magnitude (`SHREVE`) of the stream at the
+/// node. The outlets are suitable as pour points for the `Watershed` tool, after snapping
+/// with `SnapPourPoints` or `JensonSnapPourPoints`, if needed.
+///
+/// # See Also
+/// `StreamOrdering`, `StreamLinkIdentifier`, `SnapPourPoints`
+pub struct ExtractStreamNodes {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ExtractStreamNodes {
+    pub fn new() -> ExtractStreamNodes {
+        // public constructor
+        let name = "ExtractStreamNodes".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description =
+            "Outputs the channel heads, confluences and outlets of a raster stream network as points."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Streams File".to_owned(),
+            flags: vec!["--streams".to_owned()],
+            description: "Input raster streams file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Node Types".to_owned(),
+            flags: vec!["--node_types".to_owned()],
+            description: "Types of nodes to output; one of 'all' (default), 'heads', 'confluences', and 'outlets'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "all".to_owned(),
+                "heads".to_owned(),
+                "confluences".to_owned(),
+                "outlets".to_owned(),
+            ]),
+            default_value: Some("all".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --streams=streams.tif -o=nodes.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --streams=streams.tif -o=outlets.shp --node_types=outlets --esri_pntr", short_exe, name).replace("*", &sep);
+
+        ExtractStreamNodes {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ExtractStreamNodes {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut d8_file = String::new();
+        let mut streams_file = String::new();
+        let mut output_file = String::new();
+        let mut node_types = String::from("all");
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--node_types"])? {
+            let val = v.to_lowercase();
+            node_types = if val.contains("head") {
+                "head".to_string()
+            } else if val.contains("conf") || val.contains("junc") {
+                "confluence".to_string()
+            } else if val.contains("outlet") {
+                "outlet".to_string()
+            } else {
+                "all".to_string()
+            };
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !streams_file.contains(&sep) && !streams_file.contains("/") {
+            streams_file = format!("{}{}", working_directory, streams_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if verbose {
+            report::message("Reading pointer data...")
+        };
+        let pntr = Raster::new(&d8_file, "r")?;
+        if verbose {
+            report::message("Reading streams data...")
+        };
+        let streams = Raster::new(&streams_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let num_cells = pntr.num_cells();
+
+        // make sure the input files have the same size
+        if streams.configs.rows != pntr.configs.rows
+            || streams.configs.columns != pntr.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        let mut inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+            inflowing_vals = [8f64, 16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64];
+        }
+
+        // calculate the number of inflowing cells
+        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+        let mut num_tributaries: Array2D<i8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut stack = Vec::with_capacity((rows * columns) as usize);
+        let mut num_solved_cells = 0;
+        let mut count: i8;
+        for row in 0..rows {
+            for col in 0..columns {
+                if streams[(row, col)] > 0.0 {
+                    count = 0i8;
+                    for i in 0..8 {
+                        if streams[(row + d_y[i], col + d_x[i])] > 0.0
+                            && pntr[(row + d_y[i], col + d_x[i])] == inflowing_vals[i]
+                        {
+                            count += 1;
+                        }
+                    }
+                    num_inflowing.set_value(row, col, count);
+                    num_tributaries.set_value(row, col, count);
+                    if count == 0 {
+                        // It's a headwater; add it to the stack
+                        stack.push((row, col));
+                    }
+                } else {
+                    num_solved_cells += 1;
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Work downstream through the network from the channel heads, assigning link IDs,
+        // Strahler orders and Shreve magnitudes, and recording the network nodes.
+        let mut strahler: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        let mut num_max_order: Array2D<i8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut shreve: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        let mut link_id: Array2D<i32> = Array2D::new(rows, columns, 0, 0)?;
+        let mut nodes: Vec<(isize, isize, &str)> = vec![];
+        let mut num_links = 0i32;
+        let (mut row_n, mut col_n): (isize, isize);
+        let mut dir: usize;
+        let mut n: i8;
+        let mut order: f64;
+        let mut magnitude: f64;
+        let mut link: i32;
+        while let Some((row, col)) = stack.pop() {
+            n = num_tributaries.get_value(row, col);
+            if n == 0 {
+                strahler.set_value(row, col, 1f64);
+                shreve.set_value(row, col, 1f64);
+                nodes.push((row, col, "head"));
+            } else if n > 1 {
+                if num_max_order.get_value(row, col) > 1 {
+                    order = strahler.get_value(row, col) + 1f64;
+                    strahler.set_value(row, col, order);
+                }
+                nodes.push((row, col, "confluence"));
+            }
+            if n != 1 {
+                num_links += 1;
+                link_id.set_value(row, col, num_links);
+            }
+            link = link_id.get_value(row, col);
+            order = strahler.get_value(row, col);
+            magnitude = shreve.get_value(row, col);
+
+            // find the downstream cell
+            dir = pntr[(row, col)] as usize;
+            if pntr[(row, col)] > 0f64 {
+                if dir > 128 || pntr_matches[dir] == 999 {
+                    return Err(Error::new(ErrorKind::InvalidInput,
+                        "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
+                }
+                row_n = row + d_y[pntr_matches[dir]];
+                col_n = col + d_x[pntr_matches[dir]];
+            } else {
+                row_n = row;
+                col_n = col;
+            }
+            if (row_n != row || col_n != col) && streams[(row_n, col_n)] > 0.0 {
+                if order > strahler.get_value(row_n, col_n) {
+                    strahler.set_value(row_n, col_n, order);
+                    num_max_order.set_value(row_n, col_n, 1);
+                } else if order == strahler.get_value(row_n, col_n) {
+                    num_max_order.increment(row_n, col_n, 1);
+                }
+                shreve.increment(row_n, col_n, magnitude);
+                link_id.set_value(row_n, col_n, link);
+
+                num_inflowing.decrement(row_n, col_n, 1);
+                if num_inflowing.get_value(row_n, col_n) == 0 {
+                    stack.push((row_n, col_n));
+                }
+            } else {
+                nodes.push((row, col, "outlet"));
+            }
+
+            num_solved_cells += 1;
+            if verbose {
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // create output file
+        let mut output = Shapefile::new(&output_file, ShapeType::Point)?;
+        output.projection = streams.configs.coordinate_ref_system_wkt.clone();
+
+        // add the attributes
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("TYPE", FieldDataType::Text, 10u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("LINK_ID", FieldDataType::Int, 7u8, 0u8));
+        output.attributes.add_field(&AttributeField::new(
+            "NUM_INFLOW",
+            FieldDataType::Int,
+            2u8,
+            0u8,
+        ));
+        output.attributes.add_field(&AttributeField::new(
+            "STRAHLER",
+            FieldDataType::Int,
+            4u8,
+            0u8,
+        ));
+        output
+            .attributes
+            .add_field(&AttributeField::new("SHREVE", FieldDataType::Int, 7u8, 0u8));
+
+        let mut fid = 0i32;
+        let (mut num_heads, mut num_confluences, mut num_outlets) = (0, 0, 0);
+        for &(row, col, node_type) in &nodes {
+            match node_type {
+                "head" => num_heads += 1,
+                "confluence" => num_confluences += 1,
+                _ => num_outlets += 1,
+            }
+            if node_types != "all" && node_types != node_type {
+                continue;
+            }
+            fid += 1;
+            output.add_point_record(streams.get_x_from_column(col), streams.get_y_from_row(row));
+            output.attributes.add_record(
+                vec![
+                    FieldData::Int(fid),
+                    FieldData::Text(node_type.to_string()),
+                    FieldData::Int(link_id.get_value(row, col)),
+                    FieldData::Int(num_tributaries.get_value(row, col) as i32),
+                    FieldData::Int(strahler.get_value(row, col) as i32),
+                    FieldData::Int(shreve.get_value(row, col) as i32),
+                ],
+                false,
+            );
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Number of channel heads", &num_heads.to_string());
+            report::metadata("Number of confluences", &num_confluences.to_string());
+            report::metadata("Number of outlets", &num_outlets.to_string());
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 28, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTES: This tool should be updated to incorporate the option for an area-slope based threshold.
@@ -148,46 +148,22 @@ impl WhiteboxTool for ExtractStreams {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-flow_accum" || vec[0].to_lowercase() == "--flow_accum" {
-                if keyval {
-                    flow_accum_file = vec[1].to_string();
-                } else {
-                    flow_accum_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-threshold"
-                || vec[0].to_lowercase() == "--threshold"
-            {
-                if keyval {
-                    fa_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    fa_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-            {
-                background_val = 0f64;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--flow_accum"])? {
+            flow_accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--threshold"])? {
+            fa_threshold = v;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            background_val = 0f64;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,7 +179,7 @@ impl WhiteboxTool for ExtractStreams {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let flow_accum = Arc::new(Raster::new(&flow_accum_file, "r")?);
@@ -213,11 +189,15 @@ impl WhiteboxTool for ExtractStreams {
         let rows = flow_accum.configs.rows as isize;
         let columns = flow_accum.configs.columns as isize;
         let nodata = flow_accum.configs.nodata;
+
+        let mut output = RasterWriter::initialize_using_file(&output_file, &flow_accum);
+        let out_nodata = output.configs.nodata;
         if background_val == f64::NEG_INFINITY {
-            background_val = nodata;
+            background_val = out_nodata;
         }
-
-        let mut output = Raster::initialize_using_file(&output_file, &flow_accum);
+        output.configs.palette = "qual.plt".to_string();
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        output.begin_write()?;
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -227,7 +207,7 @@ impl WhiteboxTool for ExtractStreams {
             thread::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = flow_accum[(row, col)];
                         if z != nodata && z > fa_threshold {
@@ -235,7 +215,7 @@ impl WhiteboxTool for ExtractStreams {
                         } else if z != nodata {
                             data[col as usize] = background_val;
                         } else {
-                            data[col as usize] = nodata;
+                            data[col as usize] = out_nodata;
                         }
                     }
                     tx1.send((row, data)).unwrap();
@@ -245,19 +225,17 @@ impl WhiteboxTool for ExtractStreams {
 
         for row in 0..rows {
             let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
+            output.write_rows(data.0, data.1)?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
-        output.configs.palette = "qual.plt".to_string();
-        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
         output.add_metadata_entry(format!(
             "Created by whitebox_tools\' {} tool",
             self.get_tool_name()
@@ -268,19 +246,16 @@ impl WhiteboxTool for ExtractStreams {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
-        let _ = match output.write() {
+        let _ = match output.finalize() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
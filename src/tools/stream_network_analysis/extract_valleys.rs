@@ -159,64 +159,39 @@ impl WhiteboxTool for ExtractValleys {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--variant"])? {
+            variant = v;
+            if variant.to_lowercase().contains("q") {
+                variant = String::from("lq");
+            } else if variant.to_lowercase().contains("j") {
+                variant = String::from("JandR");
+            } else {
+                //if variant.to_lowercase().contains("p") {
+                variant = String::from("PandD");
             }
-            if vec[0].to_lowercase() == "-dem" || vec[0].to_lowercase() == "--dem" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-variant" || vec[0].to_lowercase() == "--variant" {
-                if keyval {
-                    variant = vec[1].to_string();
-                } else {
-                    variant = args[i + 1].to_string();
-                }
-                if variant.to_lowercase().contains("q") {
-                    variant = String::from("lq");
-                } else if variant.to_lowercase().contains("j") {
-                    variant = String::from("JandR");
-                } else {
-                    //if variant.to_lowercase().contains("p") {
-                    variant = String::from("PandD");
-                }
-            } else if vec[0].to_lowercase() == "-line_thin"
-                || vec[0].to_lowercase() == "--line_thin"
-            {
-                line_thin = true;
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
+        }
+        if tool_args.get_flag(&["--line_thin"])? {
+            line_thin = true;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
 
-                //the filter dimensions must be odd numbers such that there is a middle pixel
-                if (filter_size as f64 / 2f64).floor() == filter_size as f64 / 2f64 {
-                    println!("WARNING: Filter dimensions must be odd numbers. The specified filter dimension has been modified.");
-                    filter_size += 1;
-                }
+            //the filter dimensions must be odd numbers such that there is a middle pixel
+            if (filter_size as f64 / 2f64).floor() == filter_size as f64 / 2f64 {
+                report::warning("Filter dimensions must be odd numbers. The specified filter dimension has been modified.");
+                filter_size += 1;
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -237,8 +212,8 @@ impl WhiteboxTool for ExtractValleys {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
-
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         match &variant as &str {
             "lq" => {
@@ -279,7 +254,7 @@ impl WhiteboxTool for ExtractValleys {
                         }
 
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
                                 z = input[(row, col)];
                                 if z != nodata {
@@ -303,7 +278,7 @@ impl WhiteboxTool for ExtractValleys {
                                         }
                                     }
                                 } else {
-                                    data[col as usize] = nodata;
+                                    data[col as usize] = out_nodata;
                                 }
                             }
                             tx.send((row, data)).unwrap();
@@ -317,7 +292,7 @@ impl WhiteboxTool for ExtractValleys {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress as usize);
                             old_progress = progress;
                         }
                     }
@@ -336,7 +311,7 @@ impl WhiteboxTool for ExtractValleys {
                         let dx = [0, 0, -1, 1];
                         let dy = [-1, 1, 0, 0];
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
                                 z = input[(row, col)];
                                 if z != nodata {
@@ -366,7 +341,7 @@ impl WhiteboxTool for ExtractValleys {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress as usize);
                             old_progress = progress;
                         }
                     }
@@ -400,13 +375,13 @@ impl WhiteboxTool for ExtractValleys {
                             }
                             output.set_value(row + dy[which_cell], col + dx[which_cell], 0f64);
                         } else {
-                            output[(row, col)] = nodata;
+                            output[(row, col)] = out_nodata;
                         }
                     }
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            report::progress("Progress", progress as usize);
                             old_progress = progress;
                         }
                     }
@@ -415,7 +390,7 @@ impl WhiteboxTool for ExtractValleys {
         }
 
         if line_thin {
-            println!("Line thinning operation...");
+            report::message("Line thinning operation...");
             let mut did_something = true;
             let mut loop_num = 0;
             let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -452,7 +427,7 @@ impl WhiteboxTool for ExtractValleys {
                     for row in 0..rows {
                         for col in 0..columns {
                             z = output[(row, col)];
-                            if z > 0.0 && z != nodata {
+                            if z > 0.0 && z != out_nodata {
                                 // fill the neighbours array
                                 for i in 0..8 {
                                     neighbours[i] = output[(row + dy[i], col + dx[i])];
@@ -475,7 +450,10 @@ impl WhiteboxTool for ExtractValleys {
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as i32;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num, progress);
+                            report::progress(
+                                &format!("Loop Number {}", loop_num),
+                                progress as usize,
+                            );
                             old_progress = progress;
                         }
                     }
@@ -497,21 +475,18 @@ impl WhiteboxTool for ExtractValleys {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
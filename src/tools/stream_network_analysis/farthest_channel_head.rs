@@ -156,49 +156,25 @@ impl WhiteboxTool for FarthestChannelHead {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-d8_pntr" || vec[0].to_lowercase() == "--d8_pntr" {
-                if keyval {
-                    d8_file = vec[1].to_string();
-                } else {
-                    d8_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
-                if keyval {
-                    streams_file = vec[1].to_string();
-                } else {
-                    streams_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-esri_pntr"
-                || vec[0].to_lowercase() == "--esri_pntr"
-                || vec[0].to_lowercase() == "--esri_style"
-            {
-                esri_style = true;
-            } else if vec[0].to_lowercase() == "-zero_background"
-                || vec[0].to_lowercase() == "--zero_background"
-            {
-                background_val = 0f64;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            background_val = 0f64;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -217,12 +193,12 @@ impl WhiteboxTool for FarthestChannelHead {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            report::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let pntr_nodata = pntr.configs.nodata;
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -231,10 +207,6 @@ impl WhiteboxTool for FarthestChannelHead {
         let rows = pntr.configs.rows as isize;
         let columns = pntr.configs.columns as isize;
         let num_cells = pntr.num_cells();
-        let nodata = streams.configs.nodata;
-        if background_val == f64::NEG_INFINITY {
-            background_val = nodata;
-        }
         let cell_size_x = streams.configs.resolution_x;
         let cell_size_y = streams.configs.resolution_y;
         let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
@@ -245,11 +217,15 @@ impl WhiteboxTool for FarthestChannelHead {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        let out_nodata = output.configs.nodata;
+        if background_val == f64::NEG_INFINITY {
+            background_val = out_nodata;
+        }
         output.configs.photometric_interp = PhotometricInterpretation::Continuous;
         output.configs.data_type = DataType::F32;
         let mut stack = Vec::with_capacity((rows * columns) as usize);
@@ -295,7 +271,7 @@ impl WhiteboxTool for FarthestChannelHead {
                     if pntr[(row, col)] != pntr_nodata {
                         output[(row, col)] = background_val;
                     } else {
-                        output[(row, col)] = nodata;
+                        output[(row, col)] = out_nodata;
                     }
                     num_solved_cells += 1;
                 }
@@ -303,7 +279,7 @@ impl WhiteboxTool for FarthestChannelHead {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -361,7 +337,7 @@ impl WhiteboxTool for FarthestChannelHead {
                 col_n = col + d_x[c];
 
                 length = output[(row, col)] + grid_lengths[c];
-                if output[(row_n, col_n)] < length || output[(row_n, col_n)] == nodata {
+                if output[(row_n, col_n)] < length || output[(row_n, col_n)] == out_nodata {
                     output[(row_n, col_n)] = length;
                 }
 
@@ -374,7 +350,7 @@ impl WhiteboxTool for FarthestChannelHead {
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -391,19 +367,16 @@ impl WhiteboxTool for FarthestChannelHead {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
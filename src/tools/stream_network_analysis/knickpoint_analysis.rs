@@ -0,0 +1,774 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+use vector::*;
+
+/// This tool calculates the normalized channel steepness index (k<sub>sn</sub>) and
+/// the chi (χ) coordinate for each cell in a stream network, and identifies candidate
+/// knickpoints where k<sub>sn</sub> changes abruptly along the river profile.
+///
+/// The chi coordinate is the upstream integral of (A<sub>0</sub> / A)<sup>θ</sup> along
+/// the flow path, measured from each network outlet, where A is the upslope contributing
+/// area, A<sub>0</sub> is a reference drainage area (`--ref_area`), and θ is the
+/// reference concavity index (`--concavity`, default 0.45). Under steady-state conditions
+/// and uniform uplift and erodibility, elevation is a linear function of χ, with a slope
+/// equal to k<sub>sn</sub> / A<sub>0</sub><sup>θ</sup>. The tool estimates k<sub>sn</sub>
+/// for each stream cell from the change in elevation and χ between the cell and the cell
+/// located `--window` grid cells downstream, which reduces the sensitivity of the estimate
+/// to noise in the DEM.
+///
+/// A stream cell is flagged as a candidate knickpoint when the ratio of the k<sub>sn</sub>
+/// of the reach immediately downstream of the cell to that of the reach immediately upstream
+/// of it (along the trunk stream, i.e. the inflowing stream cell with the largest contributing
+/// area) exceeds `--ratio`, or is less than its reciprocal. Only the cell with the greatest
+/// change within a window of cells along the flow path is retained, so that a single
+/// knickpoint is output for each slope-break. Knickpoints are output as a vector points
+/// file (`--out_knickpoints`) with attributes describing the upstream and downstream
+/// k<sub>sn</sub> values.
+///
+/// The input flow accumulation raster (`--flow_accum`) is interpreted according to
+/// `--accum_type`, which mirrors the output types of the `D8FlowAccumulation` tool. The
+/// pointer, flow accumulation, DEM, and streams rasters must share the same grid. The
+/// DEM should be hydrologically conditioned and the pointer should be derived from it.
+///
+/// # Reference
+/// Perron, J. T., and Royden, L. (2013). An integral approach to bedrock river profile
+/// analysis. *Earth Surface Processes and Landforms*, 38(6), 570-576.
+///
+/// # See Also
+/// `D8Pointer`, `D8FlowAccumulation`, `ExtractStreams`, `LongProfile`
+pub struct KnickpointAnalysis {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl KnickpointAnalysis {
+    pub fn new() -> KnickpointAnalysis {
+        // public constructor
+        let name = "KnickpointAnalysis".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description = "Calculates channel steepness (ksn) and chi for stream cells and identifies candidate knickpoints.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Flow Accumulation File".to_owned(),
+            flags: vec!["--flow_accum".to_owned()],
+            description: "Input raster D8 flow accumulation file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flow Accumulation Type".to_owned(),
+            flags: vec!["--accum_type".to_owned()],
+            description: "Flow accumulation units; one of 'cells', 'specific contributing area', and 'catchment area' (default).".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "cells".to_owned(),
+                "specific contributing area".to_owned(),
+                "catchment area".to_owned(),
+            ]),
+            default_value: Some("catchment area".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Streams File".to_owned(),
+            flags: vec!["--streams".to_owned()],
+            description: "Input raster streams file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Steepness (ksn) File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster normalized channel steepness (ksn) file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Chi File (optional)".to_owned(),
+            flags: vec!["--out_chi".to_owned()],
+            description: "Optional output raster chi file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Knickpoints File (optional)".to_owned(),
+            flags: vec!["--out_knickpoints".to_owned()],
+            description: "Optional output vector points file of candidate knickpoints."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Reference Concavity".to_owned(),
+            flags: vec!["--concavity".to_owned()],
+            description: "Reference concavity index (theta).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.45".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Reference Drainage Area".to_owned(),
+            flags: vec!["--ref_area".to_owned()],
+            description: "Reference drainage area (A0), in map units squared.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Window Size (cells)".to_owned(),
+            flags: vec!["--window".to_owned()],
+            description: "Number of stream cells over which ksn is estimated.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("10".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Knickpoint ksn Ratio Threshold".to_owned(),
+            flags: vec!["--ratio".to_owned()],
+            description: "Minimum ratio of downstream to upstream ksn (or its reciprocal) for a knickpoint.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --flow_accum=accum.tif --dem=DEM.tif --streams=streams.tif -o=ksn.tif --out_chi=chi.tif --out_knickpoints=knickpoints.shp --concavity=0.45 --window=10 --ratio=2.0", short_exe, name).replace("*", &sep);
+
+        KnickpointAnalysis {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for KnickpointAnalysis {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut d8_file = String::new();
+        let mut accum_file = String::new();
+        let mut accum_type = String::from("ca");
+        let mut dem_file = String::new();
+        let mut streams_file = String::new();
+        let mut output_file = String::new();
+        let mut chi_file = String::new();
+        let mut knickpoints_file = String::new();
+        let mut concavity = 0.45f64;
+        let mut ref_area = 1f64;
+        let mut window = 10usize;
+        let mut ratio = 2f64;
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--flow_accum"])? {
+            accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--accum_type"])? {
+            let val = v.to_lowercase();
+            accum_type = if val.contains("cell") {
+                "cells".to_string()
+            } else if val.contains("specific") || val.contains("sca") {
+                "sca".to_string()
+            } else {
+                "ca".to_string()
+            };
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_chi"])? {
+            chi_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_knickpoints"])? {
+            knickpoints_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--concavity"])? {
+            concavity = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--ref_area"])? {
+            ref_area = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--window"])? {
+            window = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--ratio"])? {
+            ratio = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !accum_file.contains(&sep) && !accum_file.contains("/") {
+            accum_file = format!("{}{}", working_directory, accum_file);
+        }
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !streams_file.contains(&sep) && !streams_file.contains("/") {
+            streams_file = format!("{}{}", working_directory, streams_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        let output_chi = !chi_file.is_empty();
+        if output_chi && !chi_file.contains(&sep) && !chi_file.contains("/") {
+            chi_file = format!("{}{}", working_directory, chi_file);
+        }
+        let output_knickpoints = !knickpoints_file.is_empty();
+        if output_knickpoints && !knickpoints_file.contains(&sep) && !knickpoints_file.contains("/")
+        {
+            knickpoints_file = format!("{}{}", working_directory, knickpoints_file);
+        }
+        if window < 1 {
+            window = 1;
+        }
+        if ratio < 1f64 {
+            ratio = 1f64 / ratio;
+        }
+
+        if verbose {
+            report::message("Reading pointer data...")
+        };
+        let pntr = Raster::new(&d8_file, "r")?;
+        if verbose {
+            report::message("Reading flow accumulation data...")
+        };
+        let accum = Raster::new(&accum_file, "r")?;
+        if verbose {
+            report::message("Reading DEM data...")
+        };
+        let dem = Raster::new(&dem_file, "r")?;
+        if verbose {
+            report::message("Reading streams data...")
+        };
+        let streams = Raster::new(&streams_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let streams_nodata = streams.configs.nodata;
+        let dem_nodata = dem.configs.nodata;
+        let nodata = -32768f64;
+
+        // make sure the input files have the same size
+        if streams.configs.rows != pntr.configs.rows
+            || streams.configs.columns != pntr.configs.columns
+            || accum.configs.rows != pntr.configs.rows
+            || accum.configs.columns != pntr.configs.columns
+            || dem.configs.rows != pntr.configs.rows
+            || dem.configs.columns != pntr.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let cell_size_x = pntr.configs.resolution_x;
+        let cell_size_y = pntr.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+        let area_multiplier = if accum_type == "cells" {
+            cell_size_x * cell_size_y
+        } else if accum_type == "sca" {
+            cell_size_x
+        } else {
+            1f64
+        };
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        let mut inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+            inflowing_vals = [8f64, 16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64];
+        }
+
+        let is_stream = |row: isize, col: isize| -> bool {
+            let s = streams.get_value(row, col);
+            s > 0f64 && s != streams_nodata && dem.get_value(row, col) != dem_nodata
+        };
+
+        // Returns the downstream stream cell, if there is one.
+        let downstream = |row: isize, col: isize| -> Option<(isize, isize, usize)> {
+            let p = pntr.get_value(row, col);
+            if p > 0f64 && p <= 128f64 {
+                let dir = pntr_matches[p as usize];
+                if dir < 8 {
+                    let (rn, cn) = (row + d_y[dir], col + d_x[dir]);
+                    if is_stream(rn, cn) {
+                        return Some((rn, cn, dir));
+                    }
+                }
+            }
+            None
+        };
+
+        // Find the outlets, i.e. stream cells without a downstream stream cell, and
+        // calculate chi by working upstream from these.
+        let mut chi: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut stack = vec![];
+        for row in 0..rows {
+            for col in 0..columns {
+                if is_stream(row, col) && downstream(row, col).is_none() {
+                    stack.push((row, col));
+                    chi.set_value(row, col, 0f64);
+                }
+            }
+        }
+
+        let mut num_stream_cells = 0usize;
+        let (mut row_n, mut col_n): (isize, isize);
+        let mut area: f64;
+        let mut chi_down: f64;
+        while let Some((row, col)) = stack.pop() {
+            num_stream_cells += 1;
+            chi_down = chi.get_value(row, col);
+            for n in 0..8 {
+                row_n = row + d_y[n];
+                col_n = col + d_x[n];
+                if is_stream(row_n, col_n) && pntr.get_value(row_n, col_n) == inflowing_vals[n] {
+                    area = accum.get_value(row_n, col_n) * area_multiplier;
+                    if area > 0f64 {
+                        chi.set_value(
+                            row_n,
+                            col_n,
+                            chi_down + (ref_area / area).powf(concavity) * grid_lengths[n],
+                        );
+                    } else {
+                        chi.set_value(row_n, col_n, chi_down);
+                    }
+                    stack.push((row_n, col_n));
+                }
+            }
+        }
+
+        // Calculate ksn for each stream cell over the downstream window.
+        let mut ksn: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let ref_factor = ref_area.powf(concavity);
+        let (mut r, mut c): (isize, isize);
+        let mut delta_chi: f64;
+        let mut delta_z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                if chi.get_value(row, col) != nodata {
+                    r = row;
+                    c = col;
+                    for _ in 0..window {
+                        match downstream(r, c) {
+                            Some((rn, cn, _)) => {
+                                r = rn;
+                                c = cn;
+                            }
+                            None => break,
+                        }
+                    }
+                    delta_chi = chi.get_value(row, col) - chi.get_value(r, c);
+                    if delta_chi > 0f64 {
+                        delta_z = (dem.get_value(row, col) - dem.get_value(r, c)).max(0f64);
+                        ksn.set_value(row, col, delta_z / delta_chi * ref_factor);
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Calculating ksn", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Returns the inflowing stream cell with the largest contributing area.
+        let trunk_upstream = |row: isize, col: isize| -> Option<(isize, isize)> {
+            let mut ret = None;
+            let mut max_area = f64::NEG_INFINITY;
+            for n in 0..8 {
+                let (rn, cn) = (row + d_y[n], col + d_x[n]);
+                if is_stream(rn, cn)
+                    && pntr.get_value(rn, cn) == inflowing_vals[n]
+                    && accum.get_value(rn, cn) > max_area
+                {
+                    max_area = accum.get_value(rn, cn);
+                    ret = Some((rn, cn));
+                }
+            }
+            ret
+        };
+
+        // Identify knickpoints based on the change in ksn across each cell.
+        let mut output_pts = Shapefile::new(&knickpoints_file, ShapeType::Point)?;
+        let mut num_knickpoints = 0usize;
+        if output_knickpoints {
+            let mut change: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+            let mut ksn_up: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+            let (mut k_up, mut k_down): (f64, f64);
+            for row in 0..rows {
+                for col in 0..columns {
+                    k_down = ksn.get_value(row, col);
+                    if k_down != nodata && k_down > 0f64 {
+                        r = row;
+                        c = col;
+                        let mut steps = 0;
+                        while steps < window {
+                            match trunk_upstream(r, c) {
+                                Some((rn, cn)) => {
+                                    r = rn;
+                                    c = cn;
+                                }
+                                None => break,
+                            }
+                            steps += 1;
+                        }
+                        if steps == window {
+                            k_up = ksn.get_value(r, c);
+                            if k_up != nodata && k_up > 0f64 {
+                                ksn_up.set_value(row, col, k_up);
+                                change.set_value(row, col, (k_down / k_up).ln().abs());
+                            }
+                        }
+                    }
+                }
+            }
+
+            output_pts.projection = dem.configs.coordinate_ref_system_wkt.clone();
+            output_pts
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "ELEV",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            output_pts
+                .attributes
+                .add_field(&AttributeField::new("CHI", FieldDataType::Real, 12u8, 4u8));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "KSN_UP",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "KSN_DOWN",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "RATIO",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+
+            let threshold = ratio.ln();
+            let mut value: f64;
+            let mut is_max: bool;
+            for row in 0..rows {
+                for col in 0..columns {
+                    value = change.get_value(row, col);
+                    if value > 0f64 && value >= threshold {
+                        // is this the largest change along the flowpath within the window?
+                        is_max = true;
+                        r = row;
+                        c = col;
+                        for _ in 0..window {
+                            match downstream(r, c) {
+                                Some((rn, cn, _)) => {
+                                    r = rn;
+                                    c = cn;
+                                }
+                                None => break,
+                            }
+                            if change.get_value(r, c) > value {
+                                is_max = false;
+                                break;
+                            }
+                        }
+                        if is_max {
+                            r = row;
+                            c = col;
+                            for _ in 0..window {
+                                match trunk_upstream(r, c) {
+                                    Some((rn, cn)) => {
+                                        r = rn;
+                                        c = cn;
+                                    }
+                                    None => break,
+                                }
+                                if change.get_value(r, c) >= value {
+                                    is_max = false;
+                                    break;
+                                }
+                            }
+                        }
+                        if is_max {
+                            num_knickpoints += 1;
+                            output_pts
+                                .add_point_record(dem.get_x_from_column(col), dem.get_y_from_row(row));
+                            output_pts.attributes.add_record(
+                                vec![
+                                    FieldData::Int(num_knickpoints as i32),
+                                    FieldData::Real(dem.get_value(row, col)),
+                                    FieldData::Real(chi.get_value(row, col)),
+                                    FieldData::Real(ksn_up.get_value(row, col)),
+                                    FieldData::Real(ksn.get_value(row, col)),
+                                    FieldData::Real(
+                                        ksn.get_value(row, col) / ksn_up.get_value(row, col),
+                                    ),
+                                ],
+                                false,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if verbose {
+            report::metadata("Number of stream cells", &num_stream_cells.to_string());
+            if output_knickpoints {
+                report::metadata("Number of knickpoints", &num_knickpoints.to_string());
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.configs.nodata = nodata;
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.configs.palette = "spectrum.plt".to_string();
+        for row in 0..rows {
+            output.set_row_data(row, ksn.get_row_data(row));
+        }
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input d8 pointer file: {}", d8_file));
+        output.add_metadata_entry(format!("Input flow accumulation file: {}", accum_file));
+        output.add_metadata_entry(format!("Input DEM file: {}", dem_file));
+        output.add_metadata_entry(format!("Input streams file: {}", streams_file));
+        output.add_metadata_entry(format!("Reference concavity: {}", concavity));
+        output.add_metadata_entry(format!("Reference area: {}", ref_area));
+        output.add_metadata_entry(format!("Window size: {}", window));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if output_chi {
+            let mut output = Raster::initialize_using_file(&chi_file, &dem);
+            output.configs.nodata = nodata;
+            output.configs.data_type = DataType::F32;
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.configs.palette = "spectrum.plt".to_string();
+            for row in 0..rows {
+                output.set_row_data(row, chi.get_row_data(row));
+            }
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Reference concavity: {}", concavity));
+            output.add_metadata_entry(format!("Reference area: {}", ref_area));
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Chi file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if output_knickpoints {
+            let _ = match output_pts.write() {
+                Ok(_) => if verbose {
+                    report::message("Knickpoints file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
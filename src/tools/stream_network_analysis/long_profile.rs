@@ -163,49 +163,25 @@ impl WhiteboxTool for LongProfile {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-d8_pntr" {
-                d8_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-streams" {
-                streams_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-dem" {
-                dem_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
-                esri_style = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -227,15 +203,15 @@ impl WhiteboxTool for LongProfile {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            report::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
         if verbose {
-            println!("Reading DEM data...")
+            report::message("Reading DEM data...")
         };
         let dem = Raster::new(&dem_file, "r")?;
 
@@ -250,7 +226,7 @@ impl WhiteboxTool for LongProfile {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -258,7 +234,7 @@ impl WhiteboxTool for LongProfile {
         if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
@@ -268,7 +244,7 @@ impl WhiteboxTool for LongProfile {
 
         // find all the stream head cells and add them to a list
         if verbose {
-            println!("Finding channel heads...");
+            report::message("Finding channel heads...");
         }
         let streams = Arc::new(streams);
         let pntr = Arc::new(pntr);
@@ -324,14 +300,14 @@ impl WhiteboxTool for LongProfile {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding channel heads: {}%", progress);
+                    report::progress("Finding channel heads", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Traversing streams...");
+            report::message("Traversing streams...");
         }
         // Now traverse each flowpath starting from each stream head and
         // retrieve the elevation and distance from outlet data.
@@ -427,7 +403,7 @@ impl WhiteboxTool for LongProfile {
             if verbose {
                 progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 1 of 2: {}%", progress);
+                    report::progress("Loop 1 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -485,7 +461,7 @@ impl WhiteboxTool for LongProfile {
             if verbose {
                 progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 2 of 2: {}%", progress);
+                    report::progress("Loop 2 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -551,10 +527,10 @@ impl WhiteboxTool for LongProfile {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -582,7 +558,7 @@ impl WhiteboxTool for LongProfile {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -1,11 +1,15 @@
 // private sub-module defined in other files
+mod accumulate_vector_network;
 mod dist_to_outlet;
+mod drainage_density;
+mod extract_stream_nodes;
 mod extract_streams;
 mod extract_valleys;
 mod farthest_channel_head;
 mod find_main_stem;
 mod hack_order;
 mod horton_order;
+mod knickpoint_analysis;
 mod long_profile;
 mod long_profile_from_points;
 mod raster_streams_to_vector;
@@ -13,23 +17,31 @@ mod rasterize_streams;
 mod remove_short_streams;
 mod shreve_magnitude;
 mod strahler_order;
+mod stream_link_attributes;
 mod stream_link_class;
 mod stream_link_id;
 mod stream_link_length;
 mod stream_link_slope;
+mod stream_ordering;
 mod stream_slope_continuous;
 mod topological_stream_order;
 mod total_length_channels;
+mod trace_vector_network;
 mod tributary_id;
+mod vector_network_topology;
 
 // exports identifiers from private sub-modules in the current module namespace
+pub use self::accumulate_vector_network::AccumulateVectorNetwork;
 pub use self::dist_to_outlet::DistanceToOutlet;
+pub use self::drainage_density::DrainageDensity;
+pub use self::extract_stream_nodes::ExtractStreamNodes;
 pub use self::extract_streams::ExtractStreams;
 pub use self::extract_valleys::ExtractValleys;
 pub use self::farthest_channel_head::FarthestChannelHead;
 pub use self::find_main_stem::FindMainStem;
 pub use self::hack_order::HackStreamOrder;
 pub use self::horton_order::HortonStreamOrder;
+pub use self::knickpoint_analysis::KnickpointAnalysis;
 pub use self::long_profile::LongProfile;
 pub use self::long_profile_from_points::LongProfileFromPoints;
 pub use self::raster_streams_to_vector::RasterStreamsToVector;
@@ -37,11 +49,15 @@ pub use self::rasterize_streams::RasterizeStreams;
 pub use self::remove_short_streams::RemoveShortStreams;
 pub use self::shreve_magnitude::ShreveStreamMagnitude;
 pub use self::strahler_order::StrahlerStreamOrder;
+pub use self::stream_link_attributes::StreamLinkAttributes;
 pub use self::stream_link_class::StreamLinkClass;
 pub use self::stream_link_id::StreamLinkIdentifier;
 pub use self::stream_link_length::StreamLinkLength;
 pub use self::stream_link_slope::StreamLinkSlope;
+pub use self::stream_ordering::StreamOrdering;
 pub use self::stream_slope_continuous::StreamSlopeContinuous;
 pub use self::topological_stream_order::TopologicalStreamOrder;
 pub use self::total_length_channels::LengthOfUpstreamChannels;
+pub use self::trace_vector_network::TraceVectorNetwork;
 pub use self::tributary_id::TributaryIdentifier;
+pub use self::vector_network_topology::VectorNetworkTopology;
@@ -161,45 +161,25 @@ impl WhiteboxTool for RasterizeStreams {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-streams" {
-                streams_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-base" {
-                base_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-nodata" {
-                out_nodata = true;
-            } else if flag_val == "-feature_id" {
-                feature_id = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--base"])? {
+            base_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--nodata"])? {
+            out_nodata = true;
+        }
+        if tool_args.get_flag(&["--feature_id"])? {
+            feature_id = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,12 +198,12 @@ impl WhiteboxTool for RasterizeStreams {
         }
 
         if verbose {
-            println!("Reading streams data...")
+            report::message("Reading streams data...")
         };
         let streams = Shapefile::read(&streams_file)?;
 
         if verbose {
-            println!("Reading base raster data...")
+            report::message("Reading base raster data...")
         };
         let base = Raster::new(&base_file, "r")?;
         let rows = base.configs.rows as isize;
@@ -380,7 +360,7 @@ impl WhiteboxTool for RasterizeStreams {
             if verbose {
                 progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
+                    report::progress("Rasterizing Streams", progress);
                     old_progress = progress;
                 }
             }
@@ -393,7 +373,7 @@ impl WhiteboxTool for RasterizeStreams {
         let dx4 = [1isize, 0isize, -1isize, 0isize];
         let dy4 = [0isize, 1isize, 0isize, -1isize];
         let (mut row_n, mut col_n): (isize, isize);
-        println!("Counting stream cell adjacencies...");
+        report::message("Counting stream cell adjacencies...");
         for row in 0..rows {
             for col in 0..columns {
                 id = output.get_value(row, col);
@@ -420,14 +400,14 @@ impl WhiteboxTool for RasterizeStreams {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if !feature_id {
-            println!("Updating output...");
+            report::message("Updating output...");
             for row in 0..rows {
                 for col in 0..columns {
                     z = output.get_value(row, col);
@@ -439,7 +419,7 @@ impl WhiteboxTool for RasterizeStreams {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        report::progress("Progress", progress);
                         old_progress = progress;
                     }
                 }
@@ -447,9 +427,9 @@ impl WhiteboxTool for RasterizeStreams {
         }
 
         if verbose {
-            println!("Number of stream cells: {}", num_stream_cells);
-            println!("Number of stream collisions: {}", num_link_collisions);
-            println!("Number of stream adjacencies: {}", num_adjacencies);
+            report::metadata("Number of stream cells", &num_stream_cells.to_string());
+            report::metadata("Number of stream collisions", &num_link_collisions.to_string());
+            report::metadata("Number of stream adjacencies", &num_adjacencies.to_string());
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -464,19 +444,16 @@ impl WhiteboxTool for RasterizeStreams {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
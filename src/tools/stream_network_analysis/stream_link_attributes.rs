@@ -0,0 +1,626 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use tools::*;
+
+/// This tool calculates a set of attributes for each link in a stream network and writes
+/// them to a summary CSV table (`--output`). The input stream network is a link ID raster
+/// (`--linkid`), such as the one created by the `StreamLinkIdentifier` tool, in which each
+/// stream link is assigned a unique positive identifier. The following attributes are
+/// calculated for each link:
+///
+/// - `LENGTH`: the length of the link, in map units, measured along the D8 flow path;
+/// - `SLOPE`: the average slope of the link, in percent, calculated from the elevation
+///   range of the link in the DEM (`--dem`) divided by its length;
+/// - `SINUOSITY`: the ratio of the link length to the straight-line distance between its
+///   upstream and downstream ends; and
+/// - `DS_AREA`: the upslope contributing area at the downstream end of the link, in map
+///   units squared, if a flow accumulation raster (`--flow_accum`) is provided. The
+///   `--accum_type` parameter describes the units of this raster, using the same options
+///   as the `D8FlowAccumulation` tool.
+///
+/// Each attribute can optionally be written to a raster, in which every cell of a link is
+/// assigned the link's value (`--out_length`, `--out_slope`, `--out_sinuosity`, and
+/// `--out_area`).
+///
+/// # See Also
+/// `StreamLinkIdentifier`, `StreamLinkLength`, `StreamLinkSlope`, `DrainageDensity`
+pub struct StreamLinkAttributes {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl StreamLinkAttributes {
+    pub fn new() -> StreamLinkAttributes {
+        // public constructor
+        let name = "StreamLinkAttributes".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description = "Calculates the length, slope, sinuosity and contributing area of each link in a stream network.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Stream Link (Tributary) ID File".to_owned(),
+            flags: vec!["--linkid".to_owned()],
+            description: "Input raster streams link ID (or tributary ID) file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Flow Accumulation File (optional)".to_owned(),
+            flags: vec!["--flow_accum".to_owned()],
+            description: "Optional input raster D8 flow accumulation file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Flow Accumulation Type".to_owned(),
+            flags: vec!["--accum_type".to_owned()],
+            description: "Flow accumulation units; one of 'cells', 'specific contributing area', and 'catchment area' (default).".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "cells".to_owned(),
+                "specific contributing area".to_owned(),
+                "catchment area".to_owned(),
+            ]),
+            default_value: Some("catchment area".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV file of link attributes.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Link Length File (optional)".to_owned(),
+            flags: vec!["--out_length".to_owned()],
+            description: "Optional output raster link length file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Link Slope File (optional)".to_owned(),
+            flags: vec!["--out_slope".to_owned()],
+            description: "Optional output raster link slope file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Link Sinuosity File (optional)".to_owned(),
+            flags: vec!["--out_sinuosity".to_owned()],
+            description: "Optional output raster link sinuosity file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Link Contributing Area File (optional)".to_owned(),
+            flags: vec!["--out_area".to_owned()],
+            description: "Optional output raster downstream contributing area file; requires a flow accumulation input.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Should a background value of zero be used?".to_owned(),
+            flags: vec!["--zero_background".to_owned()],
+            description: "Flag indicating whether a background value of zero should be used."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --linkid=streamsID.tif --dem=dem.tif -o=links.csv
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --linkid=streamsID.tif --dem=dem.tif --flow_accum=accum.tif --accum_type=cells -o=links.csv --out_slope=slope.tif --out_sinuosity=sinuosity.tif --zero_background", short_exe, name).replace("*", &sep);
+
+        StreamLinkAttributes {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for StreamLinkAttributes {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut d8_file = String::new();
+        let mut linkid_file = String::new();
+        let mut dem_file = String::new();
+        let mut accum_file = String::new();
+        let mut accum_type = String::from("ca");
+        let mut output_file = String::new();
+        let mut length_file = String::new();
+        let mut slope_file = String::new();
+        let mut sinuosity_file = String::new();
+        let mut area_file = String::new();
+        let mut esri_style = false;
+        let mut background_val = f64::NEG_INFINITY;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--linkid"])? {
+            linkid_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--flow_accum"])? {
+            accum_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--accum_type"])? {
+            let val = v.to_lowercase();
+            accum_type = if val.contains("cell") {
+                "cells".to_string()
+            } else if val.contains("specific") || val.contains("sca") {
+                "sca".to_string()
+            } else {
+                "ca".to_string()
+            };
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_length"])? {
+            length_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_slope"])? {
+            slope_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_sinuosity"])? {
+            sinuosity_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_area"])? {
+            area_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            background_val = 0f64;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let use_accum = !accum_file.is_empty();
+        if !area_file.is_empty() && !use_accum {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A flow accumulation file must be specified to output contributing area.",
+            ));
+        }
+
+        for file in vec![
+            &mut d8_file,
+            &mut linkid_file,
+            &mut dem_file,
+            &mut accum_file,
+            &mut output_file,
+            &mut length_file,
+            &mut slope_file,
+            &mut sinuosity_file,
+            &mut area_file,
+        ] {
+            if !file.is_empty() && !file.contains(&sep) && !file.contains("/") {
+                *file = format!("{}{}", working_directory, file);
+            }
+        }
+
+        if verbose {
+            report::message("Reading pointer data...")
+        };
+        let pntr = Raster::new(&d8_file, "r")?;
+        let pntr_nodata = pntr.configs.nodata;
+        if verbose {
+            report::message("Reading link ID data...")
+        };
+        let streams = Raster::new(&linkid_file, "r")?;
+        if verbose {
+            report::message("Reading DEM data...")
+        };
+        let dem = Raster::new(&dem_file, "r")?;
+        let accum = if use_accum {
+            if verbose {
+                report::message("Reading flow accumulation data...")
+            };
+            Raster::new(&accum_file, "r")?
+        } else {
+            Raster::initialize_using_file(&accum_file, &dem)
+        };
+
+        let start = Instant::now();
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let nodata = streams.configs.nodata;
+        let dem_nodata = dem.configs.nodata;
+        let cell_size_x = streams.configs.resolution_x;
+        let cell_size_y = streams.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+
+        // make sure the input files have the same size
+        if streams.configs.rows != pntr.configs.rows
+            || streams.configs.columns != pntr.configs.columns
+            || streams.configs.rows != dem.configs.rows
+            || streams.configs.columns != dem.configs.columns
+            || (use_accum
+                && (streams.configs.rows != accum.configs.rows
+                    || streams.configs.columns != accum.configs.columns))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let area_multiplier = if accum_type == "cells" {
+            cell_size_x * cell_size_y
+        } else if accum_type == "sca" {
+            cell_size_x
+        } else {
+            1f64
+        };
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        let mut inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+            inflowing_vals = [8f64, 16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64];
+        }
+
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+
+        let max_id = streams.configs.maximum as usize + 1;
+        let mut num_cells = vec![0usize; max_id];
+        let mut link_length = vec![0f64; max_id];
+        let mut min_elev = vec![f64::INFINITY; max_id];
+        let mut max_elev = vec![f64::NEG_INFINITY; max_id];
+        let mut upstream_end = vec![(0f64, 0f64); max_id];
+        let mut downstream_end = vec![(0f64, 0f64); max_id];
+        let mut ds_area = vec![nodata; max_id];
+        let mut current_id: usize;
+        let mut z: f64;
+        let mut dir: usize;
+        let mut is_head: bool;
+        let (mut row_n, mut col_n): (isize, isize);
+        for row in 0..rows {
+            for col in 0..columns {
+                if streams[(row, col)] > 0.0 && streams[(row, col)] != nodata {
+                    current_id = streams[(row, col)] as usize;
+                    num_cells[current_id] += 1;
+                    z = dem[(row, col)];
+                    if z != dem_nodata {
+                        if z < min_elev[current_id] {
+                            min_elev[current_id] = z;
+                        }
+                        if z > max_elev[current_id] {
+                            max_elev[current_id] = z;
+                        }
+                    }
+
+                    // is this the upstream-most cell in the link?
+                    is_head = true;
+                    for i in 0..8 {
+                        if streams[(row + d_y[i], col + d_x[i])] == streams[(row, col)]
+                            && pntr[(row + d_y[i], col + d_x[i])] == inflowing_vals[i]
+                        {
+                            is_head = false;
+                            break;
+                        }
+                    }
+                    if is_head {
+                        upstream_end[current_id] =
+                            (streams.get_x_from_column(col), streams.get_y_from_row(row));
+                    }
+
+                    // is this the downstream-most cell in the link?
+                    dir = pntr[(row, col)] as usize;
+                    if dir > 0 && pntr[(row, col)] != pntr_nodata {
+                        if dir > 128 || pntr_matches[dir] == 999 {
+                            return Err(Error::new(ErrorKind::InvalidInput,
+                                "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
+                        }
+                        link_length[current_id] += grid_lengths[pntr_matches[dir]];
+                        row_n = row + d_y[pntr_matches[dir]];
+                        col_n = col + d_x[pntr_matches[dir]];
+                    } else {
+                        row_n = row;
+                        col_n = col;
+                    }
+                    if streams[(row_n, col_n)] != streams[(row, col)]
+                        || (row_n == row && col_n == col)
+                    {
+                        downstream_end[current_id] =
+                            (streams.get_x_from_column(col_n), streams.get_y_from_row(row_n));
+                        if use_accum && accum[(row, col)] != accum.configs.nodata {
+                            ds_area[current_id] = accum[(row, col)] * area_multiplier;
+                        }
+                    }
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress (Loop 1 of 2)", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut slope = vec![nodata; max_id];
+        let mut sinuosity = vec![nodata; max_id];
+        let mut straight_length: f64;
+        for i in 1..max_id {
+            if num_cells[i] > 0 && link_length[i] > 0f64 {
+                if max_elev[i] >= min_elev[i] {
+                    slope[i] = (max_elev[i] - min_elev[i]) / link_length[i] * 100f64;
+                }
+                straight_length = ((upstream_end[i].0 - downstream_end[i].0).powi(2)
+                    + (upstream_end[i].1 - downstream_end[i].1).powi(2))
+                    .sqrt();
+                if straight_length > 0f64 {
+                    sinuosity[i] = link_length[i] / straight_length;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        // missing values are written as empty fields
+        let format_val = |value: f64, missing: f64| -> String {
+            if value != missing {
+                value.to_string()
+            } else {
+                String::new()
+            }
+        };
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all("LINK_ID,NUM_CELLS,LENGTH,MIN_ELEV,MAX_ELEV,SLOPE,SINUOSITY".as_bytes())?;
+        if use_accum {
+            writer.write_all(",DS_AREA".as_bytes())?;
+        }
+        writer.write_all("\n".as_bytes())?;
+        let mut num_links = 0;
+        for i in 1..max_id {
+            if num_cells[i] > 0 {
+                num_links += 1;
+                let mut s = format!(
+                    "{},{},{},{},{},{},{}",
+                    i,
+                    num_cells[i],
+                    link_length[i],
+                    format_val(min_elev[i], f64::INFINITY),
+                    format_val(max_elev[i], f64::NEG_INFINITY),
+                    format_val(slope[i], nodata),
+                    format_val(sinuosity[i], nodata)
+                );
+                if use_accum {
+                    s.push_str(&format!(",{}", format_val(ds_area[i], nodata)));
+                }
+                s.push_str("\n");
+                writer.write_all(s.as_bytes())?;
+            }
+        }
+        let _ = writer.flush();
+        if verbose {
+            report::metadata("Number of links", &num_links.to_string());
+            report::message("Output file written")
+        };
+
+        let outputs = vec![
+            (&length_file, &link_length, "Link length"),
+            (&slope_file, &slope, "Link slope (%)"),
+            (&sinuosity_file, &sinuosity, "Link sinuosity"),
+            (&area_file, &ds_area, "Downstream contributing area"),
+        ];
+        for &(file, values, label) in &outputs {
+            if file.is_empty() {
+                continue;
+            }
+            let mut output = Raster::initialize_using_file(&file, &streams);
+            let out_nodata = output.configs.nodata;
+            if background_val == f64::NEG_INFINITY {
+                background_val = out_nodata;
+            }
+            output.configs.data_type = DataType::F32;
+            for row in 0..rows {
+                for col in 0..columns {
+                    if streams[(row, col)] > 0.0 && streams[(row, col)] != nodata {
+                        let z = values[streams[(row, col)] as usize];
+                        if z != nodata {
+                            output[(row, col)] = z;
+                        }
+                    } else {
+                        output[(row, col)] = background_val;
+                    }
+                }
+            }
+            if background_val == 0.0f64 {
+                output.configs.palette = "spectrum_black_background.plt".to_string();
+            } else {
+                output.configs.palette = "spectrum.plt".to_string();
+            }
+            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input d8 pointer file: {}", d8_file));
+            output.add_metadata_entry(format!("Input streams ID file: {}", linkid_file));
+            output.add_metadata_entry(format!("Input DEM file: {}", dem_file));
+            output.add_metadata_entry(format!("Link attribute: {}", label));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message(&format!("Saving {} data...", label.to_lowercase()))
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,629 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Array2D;
+use tools::*;
+
+/// This tool assigns the Strahler order, Shreve magnitude, Horton order, and Hack (main
+/// stream) order to each cell of a raster stream network in a single pass through the
+/// network, given a D8 pointer (`--d8_pntr`) and streams raster (`--streams`). One output
+/// raster is written for each ordering scheme that is requested (`--out_strahler`,
+/// `--out_shreve`, `--out_horton`, and `--out_hack`), and at least one output must be
+/// specified.
+///
+/// Optionally, a table of per-link attributes can be written to a CSV file (`--out_links`).
+/// A link is a section of stream between two consecutive confluences, or between a channel
+/// head or network outlet and a confluence. Each row of the table contains the link ID, the
+/// ID of the link immediately downstream (zero for links that end at an outlet), the
+/// Strahler, Shreve, Horton and Hack orders of the link, the link length in map units, and
+/// the number of grid cells in the link.
+///
+/// Tributaries, which are used in the Horton and Hack ordering schemes, are traced upstream
+/// from each confluence along the longest upstream flow path. Network outlets are stream cells
+/// that do not flow into another stream cell.
+///
+/// # See Also
+/// `StrahlerStreamOrder`, `ShreveStreamMagnitude`, `HortonStreamOrder`, `HackStreamOrder`,
+/// `StreamLinkIdentifier`
+pub struct StreamOrdering {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl StreamOrdering {
+    pub fn new() -> StreamOrdering {
+        // public constructor
+        let name = "StreamOrdering".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description = "Assigns Strahler, Shreve, Horton and Hack orders to a stream network and outputs per-link attributes.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input raster D8 pointer file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Streams File".to_owned(),
+            flags: vec!["--streams".to_owned()],
+            description: "Input raster streams file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Strahler Order File (optional)".to_owned(),
+            flags: vec!["--out_strahler".to_owned()],
+            description: "Optional output Strahler stream order raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Shreve Magnitude File (optional)".to_owned(),
+            flags: vec!["--out_shreve".to_owned()],
+            description: "Optional output Shreve stream magnitude raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Horton Order File (optional)".to_owned(),
+            flags: vec!["--out_horton".to_owned()],
+            description: "Optional output Horton stream order raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Hack Order File (optional)".to_owned(),
+            flags: vec!["--out_hack".to_owned()],
+            description: "Optional output Hack stream order raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Link Attributes File (optional)".to_owned(),
+            flags: vec!["--out_links".to_owned()],
+            description: "Optional output CSV file of per-link attributes.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Should a background value of zero be used?".to_owned(),
+            flags: vec!["--zero_background".to_owned()],
+            description: "Flag indicating whether a background value of zero should be used."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --streams=streams.tif --out_strahler=strahler.tif --out_shreve=shreve.tif
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --d8_pntr=D8.tif --streams=streams.tif --out_horton=horton.tif --out_hack=hack.tif --out_links=links.csv --esri_pntr --zero_background", short_exe, name).replace("*", &sep);
+
+        StreamOrdering {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for StreamOrdering {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut d8_file = String::new();
+        let mut streams_file = String::new();
+        let mut strahler_file = String::new();
+        let mut shreve_file = String::new();
+        let mut horton_file = String::new();
+        let mut hack_file = String::new();
+        let mut links_file = String::new();
+        let mut esri_style = false;
+        let mut background_val = f64::NEG_INFINITY;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--d8_pntr"])? {
+            d8_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--streams"])? {
+            streams_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_strahler"])? {
+            strahler_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_shreve"])? {
+            shreve_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_horton"])? {
+            horton_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_hack"])? {
+            hack_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_links"])? {
+            links_file = v;
+        }
+        if tool_args.get_flag(&["--esri_pntr", "--esri_style"])? {
+            esri_style = true;
+        }
+        if tool_args.get_flag(&["--zero_background"])? {
+            background_val = 0f64;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if strahler_file.is_empty()
+            && shreve_file.is_empty()
+            && horton_file.is_empty()
+            && hack_file.is_empty()
+            && links_file.is_empty()
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one output file must be specified.",
+            ));
+        }
+
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !streams_file.contains(&sep) && !streams_file.contains("/") {
+            streams_file = format!("{}{}", working_directory, streams_file);
+        }
+        for file in vec![
+            &mut strahler_file,
+            &mut shreve_file,
+            &mut horton_file,
+            &mut hack_file,
+            &mut links_file,
+        ] {
+            if !file.is_empty() && !file.contains(&sep) && !file.contains("/") {
+                *file = format!("{}{}", working_directory, file);
+            }
+        }
+
+        if verbose {
+            report::message("Reading pointer data...")
+        };
+        let pntr = Raster::new(&d8_file, "r")?;
+        let pntr_nodata = pntr.configs.nodata;
+        if verbose {
+            report::message("Reading streams data...")
+        };
+        let streams = Raster::new(&streams_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = pntr.configs.rows as isize;
+        let columns = pntr.configs.columns as isize;
+        let num_cells = pntr.num_cells();
+        let nodata = streams.configs.nodata;
+        let cell_size_x = streams.configs.resolution_x;
+        let cell_size_y = streams.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+
+        // make sure the input files have the same size
+        if streams.configs.rows != pntr.configs.rows
+            || streams.configs.columns != pntr.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
+            ));
+        }
+
+        let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+        let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+
+        // Create a mapping from the pointer values to cells offsets.
+        // This may seem wasteful, using only 8 of 129 values in the array,
+        // but the mapping method is far faster than calculating z.ln() / ln(2.0).
+        // It's also a good way of allowing for different point styles.
+        let mut pntr_matches: [usize; 129] = [999usize; 129];
+        let mut inflowing_vals = [16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64, 8f64];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 0usize;
+            pntr_matches[2] = 1usize;
+            pntr_matches[4] = 2usize;
+            pntr_matches[8] = 3usize;
+            pntr_matches[16] = 4usize;
+            pntr_matches[32] = 5usize;
+            pntr_matches[64] = 6usize;
+            pntr_matches[128] = 7usize;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in d_x and d_y.
+            pntr_matches[1] = 1usize;
+            pntr_matches[2] = 2usize;
+            pntr_matches[4] = 3usize;
+            pntr_matches[8] = 4usize;
+            pntr_matches[16] = 5usize;
+            pntr_matches[32] = 6usize;
+            pntr_matches[64] = 7usize;
+            pntr_matches[128] = 0usize;
+            inflowing_vals = [8f64, 16f64, 32f64, 64f64, 128f64, 1f64, 2f64, 4f64];
+        }
+
+        // calculate the number of inflowing cells
+        let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+        let mut is_junction: Array2D<u8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut stack = Vec::with_capacity((rows * columns) as usize);
+        let mut num_solved_cells = 0;
+        let mut count: i8;
+        for row in 0..rows {
+            for col in 0..columns {
+                if streams[(row, col)] > 0.0 {
+                    count = 0i8;
+                    for i in 0..8 {
+                        if streams[(row + d_y[i], col + d_x[i])] > 0.0
+                            && pntr[(row + d_y[i], col + d_x[i])] == inflowing_vals[i]
+                        {
+                            count += 1;
+                        }
+                    }
+                    num_inflowing.set_value(row, col, count);
+                    if count == 0 {
+                        // It's a headwater; add it to the stack
+                        stack.push((row, col));
+                    } else if count > 1 {
+                        is_junction.set_value(row, col, 1u8);
+                    }
+                } else {
+                    num_solved_cells += 1;
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Work downstream through the network from the channel heads. By the time that
+        // a cell is removed from the stack, all of its upstream cells have been solved.
+        let mut strahler: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
+        let mut num_max_order: Array2D<i8> = Array2D::new(rows, columns, 0, 0)?;
+        let mut shreve: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
+        let mut link_id: Array2D<i32> = Array2D::new(rows, columns, 0, 0)?;
+        let mut trib_id: Array2D<i32> = Array2D::new(rows, columns, 0, 0)?;
+        let mut trib_length: Array2D<f64> = Array2D::new(rows, columns, -1f64, -1f64)?;
+        let mut trib_max_order = vec![0f64]; // tributary IDs start at 1
+        let mut link_last_cell = vec![(0isize, 0isize)]; // link IDs start at 1
+        let mut link_length = vec![0f64];
+        let mut link_num_cells = vec![0usize];
+        let mut outlets = vec![];
+        let (mut row_n, mut col_n): (isize, isize);
+        let mut dir: usize;
+        let mut c: usize;
+        let mut order: f64;
+        let mut magnitude: f64;
+        let mut length: f64;
+        let (mut link, mut trib): (i32, i32);
+        while let Some((row, col)) = stack.pop() {
+            if trib_length.get_value(row, col) < 0f64 {
+                // channel head
+                strahler.set_value(row, col, 1f64);
+                shreve.set_value(row, col, 1f64);
+                trib_max_order.push(1f64);
+                trib_id.set_value(row, col, (trib_max_order.len() - 1) as i32);
+                trib_length.set_value(row, col, 0f64);
+            } else if num_max_order.get_value(row, col) > 1 {
+                // two or more tributaries of the highest order meet here
+                order = strahler.get_value(row, col) + 1f64;
+                strahler.set_value(row, col, order);
+            }
+            if link_id.get_value(row, col) == 0 {
+                // the first cell of a new link
+                link_last_cell.push((row, col));
+                link_length.push(0f64);
+                link_num_cells.push(0usize);
+                link_id.set_value(row, col, (link_last_cell.len() - 1) as i32);
+            }
+            link = link_id.get_value(row, col);
+            link_last_cell[link as usize] = (row, col);
+            link_num_cells[link as usize] += 1;
+            trib = trib_id.get_value(row, col);
+            order = strahler.get_value(row, col);
+            if trib_max_order[trib as usize] < order {
+                trib_max_order[trib as usize] = order;
+            }
+
+            // find the downstream cell
+            dir = pntr[(row, col)] as usize;
+            if pntr[(row, col)] > 0f64 {
+                if dir > 128 || pntr_matches[dir] == 999 {
+                    return Err(Error::new(ErrorKind::InvalidInput,
+                        "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
+                }
+                c = pntr_matches[dir];
+                row_n = row + d_y[c];
+                col_n = col + d_x[c];
+            } else {
+                c = 0;
+                row_n = -1;
+                col_n = -1;
+            }
+            if row_n >= 0 && streams[(row_n, col_n)] > 0.0 {
+                link_length[link as usize] += grid_lengths[c];
+
+                if order > strahler.get_value(row_n, col_n) {
+                    strahler.set_value(row_n, col_n, order);
+                    num_max_order.set_value(row_n, col_n, 1);
+                } else if order == strahler.get_value(row_n, col_n) {
+                    num_max_order.increment(row_n, col_n, 1);
+                }
+
+                magnitude = shreve.get_value(row, col);
+                shreve.increment(row_n, col_n, magnitude);
+
+                length = trib_length.get_value(row, col) + grid_lengths[c];
+                if trib_length.get_value(row_n, col_n) < length {
+                    trib_length.set_value(row_n, col_n, length);
+                    trib_id.set_value(row_n, col_n, trib);
+                }
+
+                if is_junction.get_value(row_n, col_n) == 0 {
+                    link_id.set_value(row_n, col_n, link);
+                }
+
+                num_inflowing.decrement(row_n, col_n, 1);
+                if num_inflowing.get_value(row_n, col_n) == 0 {
+                    stack.push((row_n, col_n));
+                }
+            } else {
+                outlets.push((row, col));
+            }
+
+            num_solved_cells += 1;
+            if verbose {
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Now work upstream from the outlets to assign the Hack order of each tributary.
+        let mut trib_hack_order = vec![0f64; trib_max_order.len()];
+        let mut trib_n: i32;
+        let mut ho: f64;
+        for &(row, col) in &outlets {
+            trib_hack_order[trib_id.get_value(row, col) as usize] = 1f64;
+        }
+        let mut upstream_stack = outlets.clone();
+        while let Some((row, col)) = upstream_stack.pop() {
+            trib = trib_id.get_value(row, col);
+            ho = trib_hack_order[trib as usize];
+            for i in 0..8 {
+                row_n = row + d_y[i];
+                col_n = col + d_x[i];
+                if streams[(row_n, col_n)] > 0.0 && pntr[(row_n, col_n)] == inflowing_vals[i] {
+                    trib_n = trib_id.get_value(row_n, col_n);
+                    if trib_n != trib {
+                        trib_hack_order[trib_n as usize] = ho + 1f64;
+                    }
+                    upstream_stack.push((row_n, col_n));
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        let outputs = vec![
+            (&strahler_file, "Strahler order"),
+            (&shreve_file, "Shreve magnitude"),
+            (&horton_file, "Horton order"),
+            (&hack_file, "Hack order"),
+        ];
+        for (scheme, &(file, label)) in outputs.iter().enumerate() {
+            if file.is_empty() {
+                continue;
+            }
+            let mut output = Raster::initialize_using_file(&file, &streams);
+            let out_nodata = output.configs.nodata;
+            if background_val == f64::NEG_INFINITY {
+                background_val = out_nodata;
+            }
+            for row in 0..rows {
+                for col in 0..columns {
+                    if streams[(row, col)] > 0.0 {
+                        output[(row, col)] = match scheme {
+                            0 => strahler.get_value(row, col),
+                            1 => shreve.get_value(row, col),
+                            2 => trib_max_order[trib_id.get_value(row, col) as usize],
+                            _ => trib_hack_order[trib_id.get_value(row, col) as usize],
+                        };
+                    } else if pntr[(row, col)] != pntr_nodata {
+                        output[(row, col)] = background_val;
+                    } else {
+                        output[(row, col)] = out_nodata;
+                    }
+                }
+            }
+            output.configs.palette = "qual.plt".to_string();
+            output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input d8 pointer file: {}", d8_file));
+            output.add_metadata_entry(format!("Input streams file: {}", streams_file));
+            output.add_metadata_entry(format!("Ordering scheme: {}", label));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message(&format!("Saving {} data...", label))
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if !links_file.is_empty() {
+            let f = File::create(links_file.clone())?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all(
+                "LINK_ID,DS_LINK,STRAHLER,SHREVE,HORTON,HACK,LENGTH,NUM_CELLS\n".as_bytes(),
+            )?;
+            let mut ds_link: i32;
+            for link in 1..link_last_cell.len() {
+                let (row, col) = link_last_cell[link];
+                ds_link = 0;
+                if pntr[(row, col)] > 0f64 {
+                    c = pntr_matches[pntr[(row, col)] as usize];
+                    row_n = row + d_y[c];
+                    col_n = col + d_x[c];
+                    if streams[(row_n, col_n)] > 0.0 {
+                        ds_link = link_id.get_value(row_n, col_n);
+                    }
+                }
+                trib = trib_id.get_value(row, col);
+                writer.write_all(
+                    format!(
+                        "{},{},{},{},{},{},{},{}\n",
+                        link,
+                        ds_link,
+                        strahler.get_value(row, col),
+                        shreve.get_value(row, col),
+                        trib_max_order[trib as usize],
+                        trib_hack_order[trib as usize],
+                        link_length[link],
+                        link_num_cells[link]
+                    ).as_bytes(),
+                )?;
+            }
+            let _ = writer.flush();
+            if verbose {
+                report::message("Link attributes file written")
+            };
+        }
+
+        if verbose {
+            report::metadata("Number of links", &(link_last_cell.len() - 1).to_string());
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
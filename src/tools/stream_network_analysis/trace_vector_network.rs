@@ -0,0 +1,497 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{LineNetwork, Polyline};
+use tools::*;
+use vector::*;
+
+/// This tool traces upstream, downstream, or in both directions through a vector stream
+/// network (`--input`), starting from a set of selected features, and extracts the
+/// traced features, with their attributes, to a new vector file (`--output`). Each line
+/// feature is assumed to be digitized in the direction of flow, i.e. from its upstream
+/// end to its downstream end, which is the convention used by most hydrography datasets.
+///
+/// The starting features can be specified either as a comma-separated list of feature IDs
+/// (`--fids`; the FID is the one-based record number in the attribute table), or by
+/// a vector points file (`--start_points`), in which case the nearest line to each point
+/// is used. Points that are farther than `--search_dist` from every line are ignored.
+///
+/// The network topology is built by matching the end-points of features. End-points
+/// within the `--snap` distance of one another are considered to be the same node.
+/// Alternatively, if the input file contains from-node and to-node identifier fields
+/// (e.g. NHD-style hydrography), these can be specified with `--from_field` and
+/// `--to_field`, and the topology will be taken from these attributes instead.
+///
+/// The `--direction` may be 'upstream', which extracts the start features and all
+/// features that drain to them, 'downstream', which extracts the flow path from the start
+/// features to the network outlet, or 'connected', which extracts the entire connected
+/// sub-network containing the start features.
+///
+/// # See Also
+/// `AccumulateVectorNetwork`, `TraceDownslopeFlowpaths`
+pub struct TraceVectorNetwork {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl TraceVectorNetwork {
+    pub fn new() -> TraceVectorNetwork {
+        // public constructor
+        let name = "TraceVectorNetwork".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description = "Traces upstream or downstream through a vector stream network and extracts the connected features.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Stream Network File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector stream network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector lines file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Trace Direction".to_owned(),
+            flags: vec!["--direction".to_owned()],
+            description: "Trace direction; one of 'upstream', 'downstream' (default), and 'connected'.".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "upstream".to_owned(),
+                "downstream".to_owned(),
+                "connected".to_owned(),
+            ]),
+            default_value: Some("downstream".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Start Feature IDs (optional)".to_owned(),
+            flags: vec!["--fids".to_owned()],
+            description: "Comma-separated list of starting feature IDs (one-based).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Start Points File (optional)".to_owned(),
+            flags: vec!["--start_points".to_owned()],
+            description: "Optional input vector points file of trace starting locations."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Start Point Search Distance".to_owned(),
+            flags: vec!["--search_dist".to_owned()],
+            description: "Maximum distance between a start point and the nearest line, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Node Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which line end-points are considered to be connected."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "From-Node Field (optional)".to_owned(),
+            flags: vec!["--from_field".to_owned()],
+            description: "Optional input field containing from-node identifiers.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "To-Node Field (optional)".to_owned(),
+            flags: vec!["--to_field".to_owned()],
+            description: "Optional input field containing to-node identifiers.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp -o=upstream.shp --direction=upstream --start_points=gauges.shp --search_dist=50.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp -o=flowpath.shp --direction=downstream --fids='12,45' --from_field=FROM_NODE --to_field=TO_NODE", short_exe, name).replace("*", &sep);
+
+        TraceVectorNetwork {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for TraceVectorNetwork {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut direction = String::from("downstream");
+        let mut fids_str = String::new();
+        let mut points_file = String::new();
+        let mut search_dist = f64::INFINITY;
+        let mut snap_dist = 0f64;
+        let mut from_field = String::new();
+        let mut to_field = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--direction"])? {
+            let val = v.to_lowercase();
+            direction = if val.contains("up") {
+                "upstream".to_string()
+            } else if val.contains("conn") || val.contains("both") {
+                "connected".to_string()
+            } else {
+                "downstream".to_string()
+            };
+        }
+        if let Some(v) = tool_args.get_string(&["--fids"])? {
+            fids_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--start_points"])? {
+            points_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--search_dist"])? {
+            search_dist = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--snap"])? {
+            snap_dist = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--from_field"])? {
+            from_field = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--to_field"])? {
+            to_field = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if fids_str.is_empty() && points_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a list of starting feature IDs or a start points file must be specified.",
+            ));
+        }
+        if from_field.is_empty() != to_field.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Both the from-node and to-node fields must be specified.",
+            ));
+        }
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !points_file.is_empty() && !points_file.contains(&sep) && !points_file.contains("/") {
+            points_file = format!("{}{}", working_directory, points_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of lines type
+        if input.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polyline base shape type.",
+            ));
+        }
+
+        // build the network topology
+        let network = if !from_field.is_empty() {
+            for field in vec![&from_field, &to_field] {
+                if input.attributes.get_field_num(field).is_none() {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The field {} does not exist in the attribute table.", field),
+                    ));
+                }
+            }
+            let mut from_ids = Vec::with_capacity(input.num_records);
+            let mut to_ids = Vec::with_capacity(input.num_records);
+            for record_num in 0..input.num_records {
+                from_ids.push(format!(
+                    "{}",
+                    input.attributes.get_value(record_num, &from_field)
+                ));
+                to_ids.push(format!(
+                    "{}",
+                    input.attributes.get_value(record_num, &to_field)
+                ));
+            }
+            LineNetwork::from_node_ids(&from_ids, &to_ids)
+        } else {
+            let mut start_points = Vec::with_capacity(input.num_records);
+            let mut end_points = Vec::with_capacity(input.num_records);
+            for record_num in 0..input.num_records {
+                let record = input.get_record(record_num);
+                if record.num_points > 0 {
+                    start_points.push(record.points[0]);
+                    end_points.push(record.points[record.num_points as usize - 1]);
+                } else {
+                    // null shapes are isolated from the network
+                    start_points.push(Point2D::new(f64::NAN, f64::NAN));
+                    end_points.push(Point2D::new(f64::NAN, f64::NAN));
+                }
+            }
+            LineNetwork::from_endpoints(&start_points, &end_points, snap_dist)
+        };
+
+        // find the starting features
+        let mut start_features = vec![];
+        if !fids_str.is_empty() {
+            for s in fids_str.split(",") {
+                if s.trim().is_empty() {
+                    continue;
+                }
+                let fid = match s.trim().parse::<usize>() {
+                    Ok(v) => v,
+                    Err(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Error parsing feature ID '{}'.", s.trim()),
+                        ))
+                    }
+                };
+                if fid < 1 || fid > input.num_records {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Feature ID {} is outside the range of the input file.", fid),
+                    ));
+                }
+                start_features.push(fid - 1);
+            }
+        }
+        if !points_file.is_empty() {
+            let points = Shapefile::read(&points_file)?;
+            if points.header.shape_type.base_shape_type() != ShapeType::Point {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The start points data must be of point base shape type.",
+                ));
+            }
+            let mut lines = Vec::with_capacity(input.num_records);
+            for record_num in 0..input.num_records {
+                let record = input.get_record(record_num);
+                let mut parts = vec![];
+                for part in 0..record.num_parts as usize {
+                    let start_point_in_part = record.parts[part] as usize;
+                    let end_point_in_part = if part < record.num_parts as usize - 1 {
+                        record.parts[part + 1] as usize - 1
+                    } else {
+                        record.num_points as usize - 1
+                    };
+                    parts.push(Polyline::new(
+                        &record.points[start_point_in_part..end_point_in_part + 1],
+                        record_num,
+                    ));
+                }
+                lines.push(parts);
+            }
+            let mut min_dist: f64;
+            let mut nearest: Option<usize>;
+            for point_num in 0..points.num_records {
+                let p = points.get_record(point_num).points[0];
+                min_dist = search_dist;
+                nearest = None;
+                for record_num in 0..lines.len() {
+                    for line in &lines[record_num] {
+                        if line.len() > 0 {
+                            let (_, dist) = line.nearest_position(&p);
+                            if dist <= min_dist {
+                                min_dist = dist;
+                                nearest = Some(record_num);
+                            }
+                        }
+                    }
+                }
+                match nearest {
+                    Some(f) => start_features.push(f),
+                    None => {
+                        if verbose {
+                            report::warning(&format!(
+                                "Start point {} is not within the search distance of a line.",
+                                point_num + 1
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        if start_features.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "No starting features were identified.",
+            ));
+        }
+
+        let traced = if direction == "upstream" {
+            network.upstream(&start_features)
+        } else if direction == "connected" {
+            network.connected(&start_features)
+        } else {
+            network.downstream(&start_features)
+        };
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+
+        for i in 0..traced.len() {
+            let record_num = traced[i];
+            let record = input.get_record(record_num);
+            output.add_record(record.clone());
+
+            let atts = input.attributes.get_record(record_num);
+            output.attributes.add_record(atts.clone(), false);
+
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / traced.len() as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::metadata("Number of traced features", &traced.len().to_string());
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,544 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::nearest_point_on_segment;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{BoundingBox, LineNetwork, Point2D, RTree};
+use tools::*;
+use vector::*;
+
+/// This tool builds the node and edge topology of a vector line network (`--input`), such as
+/// a stream or road network, and reports common topological errors. Nodes are identified by
+/// matching the first and last vertices of the line features; end-points within the
+/// `--snap` distance of one another are considered to be the same node, in the same way as
+/// the `TraceVectorNetwork` and `AccumulateVectorNetwork` tools.
+///
+/// The nodes are written to a vector points file (`--nodes`) with the following attributes:
+/// `VALENCE`, the number of feature ends at the node; `NUM_IN` and `NUM_OUT`, the numbers of
+/// features ending and beginning at the node, which for a stream network digitized in the
+/// direction of flow identify channel heads (`NUM_IN` = 0) and outlets (`NUM_OUT` = 0);
+/// `NODE_TYPE`, which is 'dangle' for nodes of valence 1, 'pseudo' for pseudo-nodes of
+/// valence 2, at which two features meet end-to-end and could be merged, and 'junction'
+/// otherwise; and `COMPONENT`, the connected component that the node belongs to. The output
+/// lines file (`--output`) contains the features and their attributes, along with the
+/// identifiers of their from- and to-nodes (`FROM_NODE` and `TO_NODE`) and their component
+/// (`COMPONENT`). Components are numbered from 1; a network consisting of a single component
+/// is fully connected, and additional components indicate disconnected sub-networks.
+///
+/// If `--heal` is specified, small gaps in the network are closed. End-points that are
+/// within the snap distance of one another are moved to a common location, and the dangling
+/// ends of features that are within the snap distance of the interior of another feature,
+/// e.g. tributaries that undershoot, or lie upon, a main channel, are moved onto that feature,
+/// which is split at that location so that the two are connected by a node. Healed outputs
+/// do not retain Z and M values. Note that the dangling ends of a network, e.g. channel heads
+/// and outlets, are never moved unless they are within the snap distance of another feature.
+///
+/// # See Also
+/// `TraceVectorNetwork`, `AccumulateVectorNetwork`, `SnapPointsToLines`, `ExtractNodes`
+pub struct VectorNetworkTopology {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl VectorNetworkTopology {
+    pub fn new() -> VectorNetworkTopology {
+        // public constructor
+        let name = "VectorNetworkTopology".to_string();
+        let toolbox = "Stream Network Analysis".to_string();
+        let description = "Builds the node topology of a vector line network, identifying dangles, pseudo-nodes and disconnected components.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Line Network File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector line network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Lines File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector lines file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Nodes File".to_owned(),
+            flags: vec!["--nodes".to_owned()],
+            description: "Output vector points file of the network nodes.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which feature end-points are considered to be the same node.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Heal gaps?".to_owned(),
+            flags: vec!["--heal".to_owned()],
+            description: "Close gaps in the network that are within the snap distance.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=streams.shp -o=topology.shp --nodes=nodes.shp --snap=0.5 --heal",
+            short_exe, name
+        ).replace("*", &sep);
+
+        VectorNetworkTopology {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for VectorNetworkTopology {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut nodes_file = String::new();
+        let mut snap_dist = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--nodes"])? {
+            nodes_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--snap"])? {
+            snap_dist = v.max(0f64);
+        }
+        let heal = tool_args.get_flag(&["--heal"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !nodes_file.contains(&sep) && !nodes_file.contains("/") {
+            nodes_file = format!("{}{}", working_directory, nodes_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Shapefile::read(&input_file)?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of lines type
+        if input.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polyline base shape type.",
+            ));
+        }
+
+        // the parts of each feature, and the input record that it came from
+        let mut features: Vec<Vec<Vec<Point2D>>> = Vec::with_capacity(input.num_records);
+        let mut source: Vec<usize> = Vec::with_capacity(input.num_records);
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let mut parts = vec![];
+            for part in 0..record.num_parts as usize {
+                let part_start = record.parts[part] as usize;
+                let part_end = if part < record.num_parts as usize - 1 {
+                    record.parts[part + 1] as usize
+                } else {
+                    record.num_points as usize
+                };
+                if part_end > part_start {
+                    parts.push(record.points[part_start..part_end].to_vec());
+                }
+            }
+            features.push(parts);
+            source.push(record_num);
+        }
+
+        let mut network = build_network(&features, snap_dist);
+        let mut num_healed = 0;
+        if heal {
+            // move the end-points of each feature to its nodes
+            for f in 0..features.len() {
+                if !features[f].is_empty() {
+                    features[f][0][0] = network.node_points[network.from_node[f]];
+                    let last = features[f].last_mut().unwrap();
+                    let n = last.len();
+                    last[n - 1] = network.node_points[network.to_node[f]];
+                }
+            }
+
+            // connect the dangling ends that are near the interior of other features
+            let mut segments = vec![];
+            for f in 0..features.len() {
+                for part in 0..features[f].len() {
+                    for i in 0..features[f][part].len().saturating_sub(1) {
+                        let (p1, p2) = (features[f][part][i], features[f][part][i + 1]);
+                        segments.push((BoundingBox::from_two_points(p1, p2), (f, part, i)));
+                    }
+                }
+            }
+            let tree = RTree::new(segments);
+            let mut splits: Vec<Vec<(usize, usize, f64, Point2D)>> = vec![vec![]; features.len()];
+            for node in 0..network.num_nodes {
+                if network.node_valence(node) != 1 || !network.node_points[node].x.is_finite() {
+                    continue;
+                }
+                let p = network.node_points[node];
+                let (f, at_start) = if network.features_leaving(node).len() == 1 {
+                    (network.features_leaving(node)[0], true)
+                } else {
+                    (network.features_entering(node)[0], false)
+                };
+                let nearest = tree.knn_search_by(p.x, p.y, 1, |_, (g, part, i)| {
+                    if g == f {
+                        f64::INFINITY
+                    } else {
+                        p.distance(&nearest_point_on_segment(
+                            &p,
+                            &features[g][part][i],
+                            &features[g][part][i + 1],
+                        ))
+                    }
+                });
+                if let Some(&((g, part, i), dist)) = nearest.first() {
+                    if dist > snap_dist {
+                        continue;
+                    }
+                    let a = features[g][part][i];
+                    let q = nearest_point_on_segment(&p, &a, &features[g][part][i + 1]);
+                    if at_start {
+                        features[f][0][0] = q;
+                    } else {
+                        let last = features[f].last_mut().unwrap();
+                        let n = last.len();
+                        last[n - 1] = q;
+                    }
+                    splits[g].push((part, i, q.distance(&a), q));
+                    num_healed += 1;
+                }
+            }
+
+            // split the features at the locations where dangling ends were connected
+            let mut healed_features = Vec::with_capacity(features.len());
+            let mut healed_source = Vec::with_capacity(features.len());
+            for f in 0..features.len() {
+                if splits[f].is_empty() {
+                    healed_features.push(features[f].clone());
+                    healed_source.push(source[f]);
+                    continue;
+                }
+                for piece in split_feature(&features[f], &mut splits[f]) {
+                    healed_features.push(piece);
+                    healed_source.push(source[f]);
+                }
+            }
+            features = healed_features;
+            source = healed_source;
+            network = build_network(&features, snap_dist);
+        }
+
+        // identify the nodes and components
+        let (component, num_components) = network.components();
+        let mut node_ids = vec![0usize; network.num_nodes];
+        let mut node_components = vec![0usize; network.num_nodes];
+        let mut num_valid_nodes = 0;
+        for node in 0..network.num_nodes {
+            if network.node_points[node].x.is_finite() {
+                num_valid_nodes += 1;
+                node_ids[node] = num_valid_nodes;
+            }
+        }
+        for f in 0..network.num_features() {
+            node_components[network.from_node[f]] = component[f];
+            node_components[network.to_node[f]] = component[f];
+        }
+
+        // output the lines
+        let output_shape_type = if heal {
+            ShapeType::PolyLine
+        } else {
+            input.header.shape_type
+        };
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, output_shape_type, true)?;
+        output
+            .attributes
+            .add_field(&AttributeField::new("FROM_NODE", FieldDataType::Int, 8u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("TO_NODE", FieldDataType::Int, 8u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("COMPONENT", FieldDataType::Int, 8u8, 0u8));
+        for f in 0..features.len() {
+            if heal {
+                let mut geometry = ShapefileGeometry::new(ShapeType::PolyLine);
+                for part in &features[f] {
+                    geometry.add_part(part);
+                }
+                output.add_record(geometry);
+            } else {
+                output.add_record(input.get_record(source[f]).clone());
+            }
+            let mut atts = input.attributes.get_record(source[f]);
+            if features[f].is_empty() {
+                atts.push(FieldData::Null);
+                atts.push(FieldData::Null);
+            } else {
+                atts.push(FieldData::Int(node_ids[network.from_node[f]] as i32));
+                atts.push(FieldData::Int(node_ids[network.to_node[f]] as i32));
+            }
+            atts.push(FieldData::Int(component[f] as i32 + 1i32));
+            output.attributes.add_record(atts, false);
+        }
+
+        // output the nodes
+        let mut nodes = Shapefile::new(&nodes_file, ShapeType::Point)?;
+        nodes.projection = input.projection.clone();
+        nodes
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 8u8, 0u8));
+        nodes
+            .attributes
+            .add_field(&AttributeField::new("VALENCE", FieldDataType::Int, 6u8, 0u8));
+        nodes
+            .attributes
+            .add_field(&AttributeField::new("NUM_IN", FieldDataType::Int, 6u8, 0u8));
+        nodes
+            .attributes
+            .add_field(&AttributeField::new("NUM_OUT", FieldDataType::Int, 6u8, 0u8));
+        nodes
+            .attributes
+            .add_field(&AttributeField::new("NODE_TYPE", FieldDataType::Text, 10u8, 0u8));
+        nodes
+            .attributes
+            .add_field(&AttributeField::new("COMPONENT", FieldDataType::Int, 8u8, 0u8));
+        let (mut num_dangles, mut num_pseudo, mut num_junctions) = (0, 0, 0);
+        for node in 0..network.num_nodes {
+            if node_ids[node] == 0 {
+                continue;
+            }
+            let valence = network.node_valence(node);
+            let node_type = match valence {
+                1 => {
+                    num_dangles += 1;
+                    "dangle"
+                }
+                2 => {
+                    num_pseudo += 1;
+                    "pseudo"
+                }
+                _ => {
+                    num_junctions += 1;
+                    "junction"
+                }
+            };
+            let p = network.node_points[node];
+            nodes.add_point_record(p.x, p.y);
+            nodes.attributes.add_record(
+                vec![
+                    FieldData::Int(node_ids[node] as i32),
+                    FieldData::Int(valence as i32),
+                    FieldData::Int(network.features_entering(node).len() as i32),
+                    FieldData::Int(network.features_leaving(node).len() as i32),
+                    FieldData::Text(node_type.to_string()),
+                    FieldData::Int(node_components[node] as i32 + 1i32),
+                ],
+                false,
+            );
+        }
+
+        report::metadata("Number of features", &features.len().to_string());
+        report::metadata("Number of nodes", &num_valid_nodes.to_string());
+        report::metadata("Dangles (valence 1)", &num_dangles.to_string());
+        report::metadata("Pseudo-nodes (valence 2)", &num_pseudo.to_string());
+        report::metadata("Junctions (valence 3+)", &num_junctions.to_string());
+        report::metadata("Connected components", &num_components.to_string());
+        if heal {
+            report::metadata("Dangling ends connected to other features", &num_healed.to_string());
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        let _ = match nodes.write() {
+            Ok(_) => if verbose {
+                report::message("Nodes file written")
+            },
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds the network from the first and last vertices of the features.
+fn build_network(features: &[Vec<Vec<Point2D>>], snap_dist: f64) -> LineNetwork {
+    let mut start_points = Vec::with_capacity(features.len());
+    let mut end_points = Vec::with_capacity(features.len());
+    for parts in features {
+        if !parts.is_empty() {
+            start_points.push(parts[0][0]);
+            end_points.push(*parts.last().unwrap().last().unwrap());
+        } else {
+            // null shapes are isolated from the network
+            start_points.push(Point2D::new(f64::NAN, f64::NAN));
+            end_points.push(Point2D::new(f64::NAN, f64::NAN));
+        }
+    }
+    LineNetwork::from_endpoints(&start_points, &end_points, snap_dist)
+}
+
+/// Splits a feature at a set of locations, each given by a part, the segment within the part
+/// that it lies on, its distance from the start of the segment, and the location itself.
+/// Locations at the ends of the feature are ignored.
+fn split_feature(
+    parts: &[Vec<Point2D>],
+    splits: &mut Vec<(usize, usize, f64, Point2D)>,
+) -> Vec<Vec<Vec<Point2D>>> {
+    splits.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)).then(a.2.partial_cmp(&b.2).unwrap()));
+    let first = parts[0][0];
+    let last = *parts.last().unwrap().last().unwrap();
+    let mut pieces: Vec<Vec<Vec<Point2D>>> = vec![vec![]];
+    let mut s = 0;
+    for part in 0..parts.len() {
+        let mut current: Vec<Point2D> = vec![];
+        for i in 0..parts[part].len() {
+            if current.last() != Some(&parts[part][i]) {
+                current.push(parts[part][i]);
+            }
+            while s < splits.len() && splits[s].0 == part && splits[s].1 == i {
+                let q = splits[s].3;
+                s += 1;
+                if q == first || q == last {
+                    continue;
+                }
+                if current.last() != Some(&q) {
+                    current.push(q);
+                }
+                if current.len() > 1 {
+                    pieces.last_mut().unwrap().push(current);
+                    pieces.push(vec![]);
+                    current = vec![q];
+                }
+            }
+        }
+        if current.len() > 1 {
+            pieces.last_mut().unwrap().push(current);
+        }
+    }
+    pieces.into_iter().filter(|p| !p.is_empty()).collect()
+}
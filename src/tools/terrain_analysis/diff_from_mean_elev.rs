@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 25, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -10,13 +10,12 @@ use num_cpus;
 use raster::*;
 use std::env;
 use std::f64;
-use std::i32;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
-use structures::Array2D;
+use structures::SummedAreaTable;
 use tools::*;
 
 pub struct DiffFromMeanElev {
@@ -150,56 +149,26 @@ impl WhiteboxTool for DiffFromMeanElev {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-                filter_size_y = filter_size_x;
-            } else if vec[0].to_lowercase() == "-filterx" || vec[0].to_lowercase() == "--filterx" {
-                if keyval {
-                    filter_size_x = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_x = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-filtery" || vec[0].to_lowercase() == "--filtery" {
-                if keyval {
-                    filter_size_y = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size_y = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size_x = v;
+            filter_size_y = filter_size_x;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filterx"])? {
+            filter_size_x = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filtery"])? {
+            filter_size_y = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -232,165 +201,51 @@ impl WhiteboxTool for DiffFromMeanElev {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
-        // first bin the data
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
-        let num_sig_digits = 2;
-        let multiplier = 10f64.powi(num_sig_digits);
-        let min_val = input.configs.minimum;
-        let min_bin = (min_val * multiplier).floor() as i32;
-        let bin_nodata32 = i32::MIN;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
-        let mut binned_data: Array2D<i32> =
-            Array2D::new(rows, columns, bin_nodata32, bin_nodata32)?;
+        // create the integral images
+        let sat = Arc::new(SummedAreaTable::new(rows, columns, false, |row, col| {
+            let z = input.get_value(row, col);
+            if z != nodata {
+                Some(z)
+            } else {
+                None
+            }
+        }));
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let input = input.clone();
-            let rows = rows.clone();
+            let sat = sat.clone();
             let tx1 = tx.clone();
             thread::spawn(move || {
-                let nodata = input.configs.nodata;
-                let columns = input.configs.columns as isize;
+                let (mut y1, mut y2, mut x1, mut x2): (isize, isize, isize, isize);
                 let mut z: f64;
-                let mut val: i32;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![bin_nodata32; columns as usize];
+                    y1 = row - midpoint_y;
+                    y2 = row + midpoint_y;
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input.get_value(row, col);
                         if z != nodata {
-                            val = (z * multiplier).floor() as i32 - min_bin;
-                            data[col as usize] = val;
-                        }
-                    }
-                    tx1.send((row, data)).unwrap();
-                }
-            });
-        }
-
-        for row in 0..rows {
-            let data = rx.recv().unwrap();
-            binned_data.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Binning data: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        // create the integral images
-        let mut integral: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
-        let mut integral_n: Array2D<i32> = Array2D::new(rows, columns, 0, bin_nodata32)?;
-
-        let mut val: f64;
-        let mut sum: f64;
-        let mut sum_n: i32;
-        let mut i_prev: f64;
-        let mut n_prev: i32;
-        for row in 0..rows {
-            sum = 0f64;
-            sum_n = 0;
-            for col in 0..columns {
-                val = binned_data.get_value(row, col) as f64;
-                if val == bin_nodata32 as f64 {
-                    val = 0f64;
-                } else {
-                    sum_n += 1;
-                }
-                sum += val;
-                if row > 0 {
-                    i_prev = integral.get_value(row - 1, col);
-                    n_prev = integral_n.get_value(row - 1, col);
-                    integral.set_value(row, col, sum + i_prev);
-                    integral_n.set_value(row, col, sum_n + n_prev);
-                } else {
-                    integral.set_value(row, col, sum);
-                    integral_n.set_value(row, col, sum_n);
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating integral images: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        let bd = Arc::new(binned_data); // wrap binned_data in an Arc
-        let i = Arc::new(integral); // wrap integral in an Arc
-        let i_n = Arc::new(integral_n); // wrap integral_n in an Arc
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let binned_data = bd.clone();
-            let i = i.clone();
-            let i_n = i_n.clone();
-            let tx1 = tx.clone();
-            thread::spawn(move || {
-                let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
-                let mut n: i32;
-                let mut sum: f64;
-                let mut mean: f64;
-                let mut z: i32;
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    y1 = row - midpoint_y - 1;
-                    if y1 < 0 {
-                        y1 = 0;
-                    }
-                    if y1 >= rows {
-                        y1 = rows - 1;
-                    }
-
-                    y2 = row + midpoint_y;
-                    if y2 < 0 {
-                        y2 = 0;
-                    }
-                    if y2 >= rows {
-                        y2 = rows - 1;
-                    }
-                    let mut data = vec![nodata; columns as usize];
-                    for col in 0..columns {
-                        z = binned_data.get_value(row, col);
-                        if z != bin_nodata32 {
-                            x1 = col - midpoint_x - 1;
-                            if x1 < 0 {
-                                x1 = 0;
-                            }
-                            if x1 >= columns {
-                                x1 = columns - 1;
-                            }
-
+                            x1 = col - midpoint_x;
                             x2 = col + midpoint_x;
-                            if x2 < 0 {
-                                x2 = 0;
-                            }
-                            if x2 >= columns {
-                                x2 = columns - 1;
-                            }
-                            n = i_n.get_value(y2, x2) + i_n.get_value(y1, x1)
-                                - i_n.get_value(y1, x2)
-                                - i_n.get_value(y2, x1);
-                            if n > 0 {
-                                sum = i.get_value(y2, x2) + i.get_value(y1, x1)
-                                    - i.get_value(y1, x2)
-                                    - i.get_value(y2, x1);
-                                mean = sum / n as f64;
-                                data[col as usize] = (z as f64 - mean) / multiplier;
-                            } else {
-                                data[col as usize] = 0f64;
-                            }
+                            data[col as usize] = match sat.mean(y1, x1, y2, x2) {
+                                Some(mean) => z - mean,
+                                None => 0f64,
+                            };
                         }
                     }
 
@@ -405,7 +260,7 @@ impl WhiteboxTool for DiffFromMeanElev {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    report::progress("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -431,22 +286,19 @@ impl WhiteboxTool for DiffFromMeanElev {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -175,63 +175,38 @@ impl WhiteboxTool for DownslopeIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-dem" || flag_val == "-input" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-drop" {
-                drop_val = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-out_type" {
-                out_type = if keyval {
-                    vec[1].to_lowercase()
-                } else {
-                    args[i + 1].to_lowercase()
-                };
-                if out_type.contains("dist") {
-                    out_type = String::from("distance");
-                    out_val = 4;
-                } else if out_type.contains("tan") {
-                    out_type = String::from("tangent");
-                    out_val = 1;
-                } else if out_type.contains("sl") {
-                    out_type = String::from("slope");
-                    out_val = 2;
-                } else if out_type.contains("rad") {
-                    out_type = String::from("radians");
-                    out_val = 3;
-                } else {
-                    out_type = String::from("distance");
-                    out_val = 4;
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--drop"])? {
+            drop_val = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_type"])? {
+            out_type = v.to_lowercase();
+            if out_type.contains("dist") {
+                out_type = String::from("distance");
+                out_val = 4;
+            } else if out_type.contains("tan") {
+                out_type = String::from("tangent");
+                out_val = 1;
+            } else if out_type.contains("sl") {
+                out_type = String::from("slope");
+                out_val = 2;
+            } else if out_type.contains("rad") {
+                out_type = String::from("radians");
+                out_val = 3;
+            } else {
+                out_type = String::from("distance");
+                out_val = 4;
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -247,7 +222,7 @@ impl WhiteboxTool for DownslopeIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -256,6 +231,8 @@ impl WhiteboxTool for DownslopeIndex {
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         let cell_size_x = input.configs.resolution_x;
         let cell_size_y = input.configs.resolution_y;
         let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
@@ -333,7 +310,7 @@ impl WhiteboxTool for DownslopeIndex {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    report::progress("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -364,7 +341,7 @@ impl WhiteboxTool for DownslopeIndex {
                 let mut dir: i8;
                 let mut flag: bool;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input.get_value(row, col);
                         if z != nodata {
@@ -410,7 +387,6 @@ impl WhiteboxTool for DownslopeIndex {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
         output.configs.data_type = DataType::F32;
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
@@ -418,7 +394,7 @@ impl WhiteboxTool for DownslopeIndex {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -438,27 +414,22 @@ impl WhiteboxTool for DownslopeIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
-            DEM needs to be processed to remove topographic depressions and flats prior to
+            report::warning("Interior pit cells were found within the input DEM. It is likely that the \
+            DEM needs to be processed to remove topographic depressions and flats prior to \
             running this tool.");
-            println!("**********************************************************************************");
         }
 
         Ok(())
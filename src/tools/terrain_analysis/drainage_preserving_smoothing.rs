@@ -207,73 +207,36 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-filter" {
-                filter_size = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            } else if flag_val == "-norm_diff" {
-                max_norm_diff = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-num_iter" {
-                num_iter = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            } else if flag_val == "-reduction" {
-                reduction = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-                reduction = reduction.abs();
-            } else if flag_val == "-dfm" {
-                dfm_threshold = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-                dfm_threshold = dfm_threshold.abs();
-            } else if flag_val == "-zfactor" {
-                z_factor = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--norm_diff"])? {
+            max_norm_diff = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_iter"])? {
+            num_iter = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--reduction"])? {
+            reduction = v;
+            reduction = reduction.abs();
+        }
+        if let Some(v) = tool_args.get_f64(&["--dfm"])? {
+            dfm_threshold = v;
+            dfm_threshold = dfm_threshold.abs();
+        }
+        if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+            z_factor = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if filter_size < 3 {
@@ -312,7 +275,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -325,7 +288,10 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = 1.0 / (113200.0 * mid_lat.cos());
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor);
+                report::message(&format!(
+                    "It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.",
+                    z_factor
+                ));
             }
         }
 
@@ -375,7 +341,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning elevations: {}%", progress);
+                    report::progress("Binning elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -510,7 +476,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating topographic position: {}%", progress);
+                    report::progress("Calculating topographic position", progress);
                     old_progress = progress;
                 }
             }
@@ -586,20 +552,14 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    report::progress("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
         }
 
         let t1 = Instant::now();
-        println!(
-            "{}",
-            format!(
-                "Calculating normal vectors: {}",
-                get_formatted_elapsed_time(start)
-            )
-        );
+        report::metadata("Calculating normal vectors", &get_formatted_elapsed_time(start));
 
         //////////////////////////////////////////////////////////
         // Smooth the normal vector field of the fitted planes. //
@@ -698,19 +658,13 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Smoothing normal vectors: {}%", progress);
+                    report::progress("Smoothing normal vectors", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        println!(
-            "{}",
-            format!(
-                "Smoothing normal vectors: {}",
-                get_formatted_elapsed_time(t1)
-            )
-        );
+        report::metadata("Smoothing normal vectors", &get_formatted_elapsed_time(t1));
 
         ///////////////////////////////////////////////////////////////////////////
         // Update the elevations of the DEM based on the smoothed normal vectors //
@@ -730,15 +684,16 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         let (mut xn, mut yn): (isize, isize);
         let mut zn: f64;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         output.set_data_from_raster(&input)?;
-        println!("Updating elevations...");
+        report::message("Updating elevations...");
         for loop_num in 0..num_iter {
-            println!("Iteration {} of {}...", loop_num + 1, num_iter);
+            report::message(&format!("Iteration {} of {}...", loop_num + 1, num_iter));
 
             for row in 0..rows {
                 for col in 0..columns {
                     z = output.get_value(row, col);
-                    if z != nodata {
+                    if z != out_nodata {
                         dfm_value = dfm_data.get_value(row, col);
                         threshold_adj = if dfm_value < 0f64 && dfm_value > dfm_threshold {
                             (max_norm_diff * (1f64 - reduction * dfm_value / dfm_threshold))
@@ -755,7 +710,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             xn = col + dx[n];
                             yn = row + dy[n];
                             zn = output.get_value(yn, xn);
-                            if zn != nodata {
+                            if zn != out_nodata {
                                 //&& exclusions.get_value(yn, xn) == 0f64 {
                                 diff = nv_smooth
                                     .get_value(row, col)
@@ -782,11 +737,13 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Updating DEM elevations (Loop {} of {}): {}%",
-                            loop_num + 1,
-                            num_iter,
-                            progress
+                        report::progress(
+                            &format!(
+                                "Updating DEM elevations (Loop {} of {})",
+                                loop_num + 1,
+                                num_iter
+                            ),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -812,19 +769,16 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
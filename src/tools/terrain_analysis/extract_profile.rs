@@ -0,0 +1,359 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::Polyline;
+use tools::*;
+use vector::*;
+
+/// This tool samples the values of a raster surface (`--surface`) along one or more
+/// vector polylines (`--lines`) at a regular sampling `--interval`, measured in map
+/// units along each line. The distance, x and y coordinates, and raster value of each
+/// sample are written to an output CSV table (`--output`), which is useful for
+/// creating terrain cross-sections and river long-profiles. Optionally, the sample
+/// locations can also be output as a vector points file (`--out_points`). If an
+/// interval is not specified, the grid resolution of the surface is used. Samples
+/// that fall on nodata cells, or outside of the raster, are excluded from the outputs.
+///
+/// Unlike the *Profile* tool, which plots profiles in an HTML report, this tool is
+/// intended to export the underlying profile data for further analysis.
+pub struct ExtractProfile {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ExtractProfile {
+    pub fn new() -> ExtractProfile {
+        // public constructor
+        let name = "ExtractProfile".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Samples a raster surface at regular intervals along vector lines and outputs a profile table."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Line File".to_owned(),
+            flags: vec!["--lines".to_owned()],
+            description: "Input vector line file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Surface File".to_owned(),
+            flags: vec!["--surface".to_owned()],
+            description: "Input raster surface file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Sampling Interval".to_owned(),
+            flags: vec!["--interval".to_owned()],
+            description: "Distance between samples, in map units; defaults to the grid resolution."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Points File (optional)".to_owned(),
+            flags: vec!["--out_points".to_owned()],
+            description: "Optional output vector points file of sample locations.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --lines=profile.shp --surface=dem.tif -o=profile.csv --interval=5.0 --out_points=samples.shp", short_exe, name).replace("*", &sep);
+
+        ExtractProfile {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ExtractProfile {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut lines_file = String::new();
+        let mut surface_file = String::new();
+        let mut output_file = String::new();
+        let mut points_file = String::new();
+        let mut interval = f64::NEG_INFINITY;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--lines"])? {
+            lines_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--surface"])? {
+            surface_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--interval"])? {
+            interval = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_points"])? {
+            points_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !lines_file.contains(&sep) && !lines_file.contains("/") {
+            lines_file = format!("{}{}", working_directory, lines_file);
+        }
+        if !surface_file.contains(&sep) && !surface_file.contains("/") {
+            surface_file = format!("{}{}", working_directory, surface_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        let output_points = !points_file.is_empty();
+        if output_points && !points_file.contains(&sep) && !points_file.contains("/") {
+            points_file = format!("{}{}", working_directory, points_file);
+        }
+
+        if verbose {
+            report::message("Reading line data...")
+        };
+        let lines = Shapefile::read(&lines_file)?;
+
+        if verbose {
+            report::message("Reading surface data...")
+        };
+        let surface = Raster::new(&surface_file, "r")?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of lines type
+        if lines.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polyline base shape type.",
+            ));
+        }
+
+        let nodata = surface.configs.nodata;
+        if interval <= 0f64 {
+            interval = surface.configs.resolution_x;
+        }
+
+        let mut output_pts = Shapefile::new(&points_file, ShapeType::Point)?;
+        if output_points {
+            output_pts.projection = lines.projection.clone();
+            output_pts
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "LINE_ID",
+                FieldDataType::Int,
+                7u8,
+                0u8,
+            ));
+            output_pts
+                .attributes
+                .add_field(&AttributeField::new("PART", FieldDataType::Int, 4u8, 0u8));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "DIST",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            output_pts.attributes.add_field(&AttributeField::new(
+                "VALUE",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+        }
+
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all("LINE_ID,PART,DIST,X,Y,VALUE\n".as_bytes())?;
+
+        let mut start_point_in_part: usize;
+        let mut end_point_in_part: usize;
+        let (mut row, mut col): (isize, isize);
+        let mut z: f64;
+        let mut fid = 1i32;
+        for record_num in 0..lines.num_records {
+            let record = lines.get_record(record_num);
+            for part in 0..record.num_parts as usize {
+                start_point_in_part = record.parts[part] as usize;
+                end_point_in_part = if part < record.num_parts as usize - 1 {
+                    record.parts[part + 1] as usize - 1
+                } else {
+                    record.num_points as usize - 1
+                };
+
+                let line = Polyline::new(
+                    &record.points[start_point_in_part..end_point_in_part + 1],
+                    record_num,
+                );
+                for (dist, p) in line.points_at_interval(interval) {
+                    row = surface.get_row_from_y(p.y);
+                    col = surface.get_column_from_x(p.x);
+                    z = surface.get_value(row, col);
+                    if z != nodata {
+                        writer.write_all(
+                            format!(
+                                "{},{},{},{},{},{}\n",
+                                record_num + 1,
+                                part + 1,
+                                dist,
+                                p.x,
+                                p.y,
+                                z
+                            ).as_bytes(),
+                        )?;
+                        if output_points {
+                            output_pts.add_point_record(p.x, p.y);
+                            output_pts.attributes.add_record(
+                                vec![
+                                    FieldData::Int(fid),
+                                    FieldData::Int(record_num as i32 + 1),
+                                    FieldData::Int(part as i32 + 1),
+                                    FieldData::Real(dist),
+                                    FieldData::Real(z),
+                                ],
+                                false,
+                            );
+                            fid += 1;
+                        }
+                    }
+                }
+            }
+
+            if verbose {
+                progress =
+                    (100.0_f64 * (record_num + 1) as f64 / lines.num_records as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let _ = writer.flush();
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if output_points {
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match output_pts.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -175,59 +175,28 @@ impl WhiteboxTool for FeaturePreservingDenoise {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-filter" {
-                filter_size = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            } else if flag_val == "-norm_diff" {
-                max_norm_diff = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            } else if flag_val == "-num_iter" {
-                num_iter = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap() as usize
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap() as usize
-                };
-            } else if flag_val == "-zfactor" {
-                z_factor = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--norm_diff"])? {
+            max_norm_diff = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_iter"])? {
+            num_iter = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+            z_factor = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         if filter_size < 3 {
@@ -254,7 +223,7 @@ impl WhiteboxTool for FeaturePreservingDenoise {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -269,7 +238,10 @@ impl WhiteboxTool for FeaturePreservingDenoise {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = 1.0 / (113200.0 * mid_lat.cos());
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor);
+                report::message(&format!(
+                    "It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.",
+                    z_factor
+                ));
             }
         }
 
@@ -346,20 +318,14 @@ impl WhiteboxTool for FeaturePreservingDenoise {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    report::progress("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
         }
 
         let t1 = Instant::now();
-        println!(
-            "{}",
-            format!(
-                "Calculating normal vectors: {}",
-                get_formatted_elapsed_time(start)
-            )
-        );
+        report::metadata("Calculating normal vectors", &get_formatted_elapsed_time(start));
 
         //////////////////////////////////////////////////////////
         // Smooth the normal vector field of the fitted planes. //
@@ -442,19 +408,13 @@ impl WhiteboxTool for FeaturePreservingDenoise {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Smoothing normal vectors: {}%", progress);
+                    report::progress("Smoothing normal vectors", progress);
                     old_progress = progress;
                 }
             }
         }
 
-        println!(
-            "{}",
-            format!(
-                "Smoothing normal vectors: {}",
-                get_formatted_elapsed_time(t1)
-            )
-        );
+        report::metadata("Smoothing normal vectors", &get_formatted_elapsed_time(t1));
 
         ///////////////////////////////////////////////////////////////////////////
         // Update the elevations of the DEM based on the smoothed normal vectors //
@@ -472,22 +432,23 @@ impl WhiteboxTool for FeaturePreservingDenoise {
         let (mut xn, mut yn): (isize, isize);
         let mut zn: f64;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         output.set_data_from_raster(&input)?;
-        println!("Updating elevations...");
+        report::message("Updating elevations...");
         for loop_num in 0..num_iter {
-            println!("Iteration {} of {}...", loop_num + 1, num_iter);
+            report::message(&format!("Iteration {} of {}...", loop_num + 1, num_iter));
 
             for row in 0..rows {
                 for col in 0..columns {
                     z = output.get_value(row, col);
-                    if z != nodata {
+                    if z != out_nodata {
                         sum_w = 0f64;
                         z = 0f64;
                         for n in 0..8 {
                             xn = col + dx[n];
                             yn = row + dy[n];
                             zn = output.get_value(yn, xn);
-                            if zn != nodata {
+                            if zn != out_nodata {
                                 diff = nv_smooth
                                     .get_value(row, col)
                                     .angle_between(nv_smooth.get_value(yn, xn));
@@ -513,11 +474,13 @@ impl WhiteboxTool for FeaturePreservingDenoise {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Updating DEM elevations (Loop {} of {}): {}%",
-                            loop_num + 1,
-                            num_iter,
-                            progress
+                        report::progress(
+                            &format!(
+                                "Updating DEM elevations (Loop {} of {})",
+                                loop_num + 1,
+                                num_iter
+                            ),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -1123,19 +1086,16 @@ impl WhiteboxTool for FeaturePreservingDenoise {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
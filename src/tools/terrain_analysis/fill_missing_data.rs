@@ -147,47 +147,22 @@ impl WhiteboxTool for FillMissingData {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-o" || flag_val == "-output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if flag_val == "-filter" {
-                if keyval {
-                    filter_size = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if flag_val == "-weight" {
-                weight = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--weight"])? {
+            weight = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -210,7 +185,7 @@ impl WhiteboxTool for FillMissingData {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -219,6 +194,7 @@ impl WhiteboxTool for FillMissingData {
         let start = Instant::now();
 
         let nodata = input.configs.nodata;
+        let out_nodata = output.configs.nodata;
         let columns = input.configs.columns as isize;
         let rows = input.configs.rows as isize;
         let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -226,7 +202,7 @@ impl WhiteboxTool for FillMissingData {
 
         // Interpolate the data holes. Start by locating all the edge cells.
         if verbose {
-            println!("Interpolating data holes...")
+            report::message("Interpolating data holes...")
         };
         let mut frs: FixedRadiusSearch2D<f64> =
             FixedRadiusSearch2D::new(filter_size as f64, DistanceMetric::Euclidean);
@@ -249,7 +225,7 @@ impl WhiteboxTool for FillMissingData {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding edge cells: {}%", progress);
+                    report::progress("Finding edge cells", progress);
                     old_progress = progress;
                 }
             }
@@ -272,7 +248,7 @@ impl WhiteboxTool for FillMissingData {
                 match weight {
                     x if (x == 1f64 || x == 2f64) => {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
                                 if input[(row, col)] == nodata {
                                     sum_weights = 0f64;
@@ -293,7 +269,7 @@ impl WhiteboxTool for FillMissingData {
                                     if ret.len() > 0 {
                                         data[col as usize] = z;
                                     } else {
-                                        data[col as usize] = nodata;
+                                        data[col as usize] = out_nodata;
                                     }
                                 } else {
                                     data[col as usize] = input[(row, col)];
@@ -304,7 +280,7 @@ impl WhiteboxTool for FillMissingData {
                     }
                     _ => {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
-                            let mut data = vec![nodata; columns as usize];
+                            let mut data = vec![out_nodata; columns as usize];
                             for col in 0..columns {
                                 if input[(row, col)] == nodata {
                                     sum_weights = 0f64;
@@ -325,7 +301,7 @@ impl WhiteboxTool for FillMissingData {
                                     if ret.len() > 0 {
                                         data[col as usize] = z;
                                     } else {
-                                        data[col as usize] = nodata;
+                                        data[col as usize] = out_nodata;
                                     }
                                 } else {
                                     data[col as usize] = input[(row, col)];
@@ -374,7 +350,7 @@ impl WhiteboxTool for FillMissingData {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    report::progress("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -391,19 +367,16 @@ impl WhiteboxTool for FillMissingData {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
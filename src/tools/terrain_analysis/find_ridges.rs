@@ -137,41 +137,19 @@ impl WhiteboxTool for FindRidges {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "-dem"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-line_thin"
-                || vec[0].to_lowercase() == "--line_thin"
-            {
-                line_thin = true;
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if tool_args.get_flag(&["--line_thin"])? {
+            line_thin = true;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -194,6 +172,7 @@ impl WhiteboxTool for FindRidges {
         let nodata = input.configs.nodata;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         output.reinitialize_values(0f64);
 
@@ -210,7 +189,7 @@ impl WhiteboxTool for FindRidges {
                 let (mut zn1, mut zn2): (f64, f64);
 
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input[(row, col)];
                         if z != nodata {
@@ -238,14 +217,14 @@ impl WhiteboxTool for FindRidges {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress as usize);
                     old_progress = progress;
                 }
             }
         }
 
         if line_thin {
-            println!("Line thinning operation...");
+            report::message("Line thinning operation...");
             let mut did_something = true;
             let mut loop_num = 0;
             let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -282,7 +261,7 @@ impl WhiteboxTool for FindRidges {
                     for row in 0..rows {
                         for col in 0..columns {
                             z = output[(row, col)];
-                            if z > 0.0 && z != nodata {
+                            if z > 0.0 && z != out_nodata {
                                 // fill the neighbours array
                                 for i in 0..8 {
                                     neighbours[i] = output[(row + dy[i], col + dx[i])];
@@ -305,7 +284,10 @@ impl WhiteboxTool for FindRidges {
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as i32;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num, progress);
+                            report::progress(
+                                &format!("Loop Number {}", loop_num),
+                                progress as usize,
+                            );
                             old_progress = progress;
                         }
                     }
@@ -323,21 +305,18 @@ impl WhiteboxTool for FindRidges {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
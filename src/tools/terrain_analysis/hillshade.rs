@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 22, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 
@@ -156,56 +156,25 @@ impl WhiteboxTool for Hillshade {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-azimuth" || vec[0].to_lowercase() == "--azimuth" {
-                if keyval {
-                    azimuth = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    azimuth = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-altitude" || vec[0].to_lowercase() == "--altitude"
-            {
-                if keyval {
-                    altitude = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    altitude = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-zfactor" || vec[0].to_lowercase() == "--zfactor" {
-                if keyval {
-                    z_factor = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    z_factor = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--azimuth"])? {
+            azimuth = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--altitude"])? {
+            altitude = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+            z_factor = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -221,7 +190,7 @@ impl WhiteboxTool for Hillshade {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -243,8 +212,10 @@ impl WhiteboxTool for Hillshade {
             }
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let mut output = RasterWriter::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         output.configs.data_type = DataType::I16;
+        output.begin_write()?;
         let rows = input.configs.rows as isize;
 
         let num_procs = num_cpus::get() as isize;
@@ -264,7 +235,7 @@ impl WhiteboxTool for Hillshade {
                 let mut tan_slope: f64;
                 let mut aspect: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         z = input[(row, col)];
                         if z != nodata {
@@ -304,24 +275,23 @@ impl WhiteboxTool for Hillshade {
         }
 
         let mut histo: [f64; 32768] = [0.0; 32768];
-        let nodata = input.configs.nodata;
         let mut num_cells = 0.0;
         for row in 0..rows {
             let data = rx.recv().unwrap();
             let mut bin: usize;
             for col in 0..data.1.len() {
-                if data.1[col] != nodata {
+                if data.1[col] != out_nodata {
                     bin = data.1[col] as usize;
                     histo[bin] += 1.0;
                     num_cells += 1.0;
                 }
             }
-            output.set_row_data(data.0, data.1);
+            output.write_rows(data.0, data.1)?;
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    report::progress("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -367,19 +337,16 @@ impl WhiteboxTool for Hillshade {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
-        let _ = match output.write() {
+        let _ = match output.finalize() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
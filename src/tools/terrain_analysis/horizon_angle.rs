@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: July 7, 2017
-Last Modified: 12/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTES: The tool should have the option to output a distance raster as well.
@@ -19,6 +19,21 @@ use std::sync::Arc;
 use std::thread;
 use tools::*;
 
+/// This tool calculates the horizon angle (Sx), i.e. the maximum slope along a specified azimuth
+/// (0-360 degrees) for each grid cell in an input digital elevation model (DEM), in degrees.
+/// Horizon angle is sometimes referred to as the maximum upwind slope in wind exposure/sheltering
+/// studies. Positive values indicate that the horizon is above the cell (i.e. the cell is
+/// sheltered) and negative values indicate that the cell is exposed in that direction. The
+/// search may optionally be limited to a maximum distance (`--max_dist`), in map units.
+///
+/// Alternatively, if the number of search directions (`--num_directions`) is specified, the
+/// azimuth is ignored and the output is the maximum horizon angle among that many evenly spaced
+/// azimuths, beginning with north. This is the elevation of the highest point of the horizon in
+/// any direction, which is commonly used in solar radiation modelling and the visualization of
+/// archaeological features.
+///
+/// # See Also
+/// `LocalReliefModel`, `Viewshed`, `DirectionalRelief`
 pub struct HorizonAngle {
     name: String,
     description: String,
@@ -74,6 +89,15 @@ impl HorizonAngle {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Number of Search Directions".to_owned(),
+            flags: vec!["--num_directions".to_owned()],
+            description: "Optional number of evenly spaced azimuths over which the maximum horizon angle is calculated; overrides the azimuth.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -85,7 +109,8 @@ impl HorizonAngle {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.tif' -o=output.tif --azimuth=315.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.tif' -o=output.tif --azimuth=315.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='input.tif' -o=output.tif --num_directions=16 --max_dist=500.0", short_exe, name).replace("*", &sep);
 
         HorizonAngle {
             name: name,
@@ -142,6 +167,7 @@ impl WhiteboxTool for HorizonAngle {
         let mut output_file = String::new();
         let mut azimuth = 0.0;
         let mut max_dist = f64::INFINITY;
+        let mut num_directions: Option<usize> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -149,50 +175,31 @@ impl WhiteboxTool for HorizonAngle {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-azimuth" || vec[0].to_lowercase() == "--azimuth" {
-                if keyval {
-                    azimuth = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    azimuth = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-max_dist" || vec[0].to_lowercase() == "--max_dist"
-            {
-                if keyval {
-                    max_dist = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    max_dist = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--azimuth"])? {
+            azimuth = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--max_dist"])? {
+            max_dist = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--num_directions"])? {
+            if v < 1 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The number of search directions must be at least one.",
+                ));
             }
+            num_directions = Some(v as usize);
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -208,178 +215,55 @@ impl WhiteboxTool for HorizonAngle {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
-        if azimuth > 360f64 || azimuth < 0f64 {
-            azimuth = 0.1;
-        }
-        if azimuth == 0f64 {
-            azimuth = 0.1;
-        }
-        if azimuth == 180f64 {
-            azimuth = 179.9;
-        }
-        if azimuth == 360f64 {
-            azimuth = 359.9;
-        }
-        let line_slope: f64;
-        if azimuth < 180f64 {
-            line_slope = (90f64 - azimuth).to_radians().tan();
-        } else {
-            line_slope = (270f64 - azimuth).to_radians().tan();
-        }
-
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
 
         let mut cell_size = (input.configs.resolution_x + input.configs.resolution_y) / 2.0;
         if input.is_in_geographic_coordinates() {
-            let mut mid_lat = (input.configs.north - input.configs.south) / 2.0;
+            let mut mid_lat = (input.configs.north + input.configs.south) / 2.0;
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 cell_size = cell_size * (113200.0 * mid_lat.cos());
             }
         }
 
-        let x_step: isize;
-        let y_step: isize;
-        if azimuth > 0f64 && azimuth <= 90f64 {
-            x_step = 1;
-            y_step = 1;
-        } else if azimuth <= 180f64 {
-            x_step = 1;
-            y_step = -1;
-        } else if azimuth <= 270f64 {
-            x_step = -1;
-            y_step = -1;
-        } else {
-            x_step = -1;
-            y_step = 1;
-        }
+        // the azimuths that are searched; the maximum horizon angle among them is output
+        let searches = Arc::new(match num_directions {
+            Some(n) => (0..n)
+                .map(|i| HorizonSearch::new(360f64 * i as f64 / n as f64))
+                .collect::<Vec<HorizonSearch>>(),
+            None => vec![HorizonSearch::new(azimuth)],
+        });
 
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let input = input.clone();
+            let searches = searches.clone();
             let tx = tx.clone();
             thread::spawn(move || {
                 let mut z: f64;
                 let mut current_val: f64;
-                let mut y_intercept: f64;
                 let mut current_max_val: f64;
-                let a_small_value = -9999999f64;
-                let mut flag: bool;
-                // let mut max_val_dist: f64;
-                let (mut delta_x, mut delta_y): (f64, f64);
-                let (mut x, mut y): (f64, f64);
-                let (mut x1, mut y1): (isize, isize);
-                let (mut x2, mut y2): (isize, isize);
-                let (mut z1, mut z2): (f64, f64);
-                let mut dist: f64;
-                let mut slope: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         current_val = input[(row, col)];
                         if current_val != nodata {
-                            //calculate the y intercept of the line equation
-                            y_intercept = -row as f64 - line_slope * col as f64;
-
-                            //find all of the vertical intersections
-                            current_max_val = a_small_value;
-                            // max_val_dist = a_small_value;
-                            x = col as f64;
-
-                            flag = true;
-                            while flag {
-                                x = x + x_step as f64;
-                                if x < 0.0 || x >= columns as f64 {
-                                    flag = false;
-                                // break;
-                                } else {
-                                    //calculate the Y value
-                                    y = (line_slope * x + y_intercept) * -1f64;
-                                    if y < 0f64 || y >= rows as f64 {
-                                        flag = false;
-                                    // break;
-                                    } else {
-                                        //calculate the distance
-                                        delta_x = (x - col as f64) * cell_size;
-                                        delta_y = (y - row as f64) * cell_size;
-
-                                        dist = (delta_x * delta_x + delta_y * delta_y).sqrt();
-                                        if dist > max_dist {
-                                            flag = false;
-                                        // break;
-                                        } else {
-                                            //estimate z
-                                            y1 = y as isize;
-                                            y2 = y1 + y_step * -1isize;
-                                            z1 = input[(y1, x as isize)];
-                                            z2 = input[(y2, x as isize)];
-                                            z = z1 + (y - y1 as f64) * (z2 - z1);
-                                            //calculate the slope
-                                            slope = (z - current_val) / dist;
-                                            if slope > current_max_val {
-                                                current_max_val = slope;
-                                                // max_val_dist = dist;
-                                                // } else if current_max_val < 0f64 {
-                                                // max_val_dist = dist;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-
-                            //find all of the horizontal intersections
-                            y = -row as f64;
-                            flag = true;
-                            while flag {
-                                y = y + y_step as f64;
-                                if -y < 0f64 || -y >= rows as f64 {
-                                    flag = false;
-                                // break;
-                                } else {
-                                    //calculate the X value
-                                    x = (y - y_intercept) / line_slope;
-                                    if x < 0f64 || x >= columns as f64 {
-                                        flag = false;
-                                    //break;
-                                    } else {
-                                        //calculate the distance
-                                        delta_x = (x - col as f64) * cell_size;
-                                        delta_y = (-y - row as f64) * cell_size;
-                                        dist = (delta_x * delta_x + delta_y * delta_y).sqrt();
-                                        if dist > max_dist {
-                                            flag = false;
-                                        // break;
-                                        } else {
-                                            //estimate z
-                                            x1 = x as isize;
-                                            x2 = x1 + x_step;
-                                            if x2 < 0 || x2 >= columns {
-                                                flag = false;
-                                            // break;
-                                            } else {
-                                                z1 = input[(-y as isize, x1)];
-                                                z2 = input[(y as isize, x2)];
-                                                z = z1 + (x - x1 as f64) * (z2 - z1);
-                                                //calculate the slope
-                                                slope = (z - current_val) / dist;
-                                                if slope > current_max_val {
-                                                    current_max_val = slope;
-                                                // max_val_dist = dist;
-                                                } else if current_max_val < 0f64 {
-                                                    // max_val_dist = dist;
-                                                }
-                                            }
-                                        }
-                                    }
+                            current_max_val = A_SMALL_VALUE;
+                            for search in searches.iter() {
+                                let slope = search.max_slope(&input, row, col, cell_size, max_dist);
+                                if slope > current_max_val {
+                                    current_max_val = slope;
                                 }
                             }
 
@@ -387,17 +271,10 @@ impl WhiteboxTool for HorizonAngle {
                             if z < -89f64 {
                                 z = 0f64;
                             }
-                            if current_max_val != a_small_value {
+                            if current_max_val != A_SMALL_VALUE {
                                 data[col as usize] = z;
-                            // if (saveDistance) {
-                            //     if (z < 0) { max_val_dist = max_val_dist * -1; }
-                            //     outputDist.setValue(row, col, max_val_dist);
-                            // }
                             } else {
-                                data[col as usize] = nodata;
-                                // if (saveDistance) {
-                                //     outputDist.setValue(row, col, noData);
-                                // }
+                                data[col as usize] = out_nodata;
                             }
                         }
                     }
@@ -406,7 +283,7 @@ impl WhiteboxTool for HorizonAngle {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.data_type = DataType::F32;
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
@@ -414,7 +291,7 @@ impl WhiteboxTool for HorizonAngle {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -427,26 +304,186 @@ impl WhiteboxTool for HorizonAngle {
             self.get_tool_name()
         ));
         output.add_metadata_entry(format!("Input file: {}", input_file));
-        output.add_metadata_entry(format!("Azimuth: {}", azimuth));
+        match num_directions {
+            Some(n) => output.add_metadata_entry(format!("Number of search directions: {}", n)),
+            None => output.add_metadata_entry(format!("Azimuth: {}", azimuth)),
+        }
         output.add_metadata_entry(format!("Max dist: {}", max_dist));
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// The value of the maximum slope before any cells have been searched.
+const A_SMALL_VALUE: f64 = -9999999f64;
+
+/// The straight-line search of a DEM in the direction of an azimuth.
+struct HorizonSearch {
+    line_slope: f64,
+    x_step: isize,
+    y_step: isize,
+}
+
+impl HorizonSearch {
+    fn new(azimuth: f64) -> HorizonSearch {
+        let mut azimuth = azimuth;
+        if azimuth > 360f64 || azimuth < 0f64 {
+            azimuth = 0.1;
+        }
+        if azimuth == 0f64 {
+            azimuth = 0.1;
+        }
+        if azimuth == 180f64 {
+            azimuth = 179.9;
+        }
+        if azimuth == 360f64 {
+            azimuth = 359.9;
+        }
+        let line_slope: f64;
+        if azimuth < 180f64 {
+            line_slope = (90f64 - azimuth).to_radians().tan();
+        } else {
+            line_slope = (270f64 - azimuth).to_radians().tan();
+        }
+
+        let x_step: isize;
+        let y_step: isize;
+        if azimuth > 0f64 && azimuth <= 90f64 {
+            x_step = 1;
+            y_step = 1;
+        } else if azimuth <= 180f64 {
+            x_step = 1;
+            y_step = -1;
+        } else if azimuth <= 270f64 {
+            x_step = -1;
+            y_step = -1;
+        } else {
+            x_step = -1;
+            y_step = 1;
+        }
+
+        HorizonSearch {
+            line_slope: line_slope,
+            x_step: x_step,
+            y_step: y_step,
+        }
+    }
+
+    /// Returns the maximum slope from a cell to the DEM along the search line, or
+    /// `A_SMALL_VALUE` if the line leaves the DEM immediately.
+    fn max_slope(
+        &self,
+        input: &Raster,
+        row: isize,
+        col: isize,
+        cell_size: f64,
+        max_dist: f64,
+    ) -> f64 {
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let (line_slope, x_step, y_step) = (self.line_slope, self.x_step, self.y_step);
+        let current_val = input[(row, col)];
+        let mut z: f64;
+        let (mut delta_x, mut delta_y): (f64, f64);
+        let (mut x, mut y): (f64, f64);
+        let (mut x1, mut y1): (isize, isize);
+        let (mut x2, mut y2): (isize, isize);
+        let (mut z1, mut z2): (f64, f64);
+        let mut dist: f64;
+        let mut slope: f64;
+
+        //calculate the y intercept of the line equation
+        let y_intercept = -row as f64 - line_slope * col as f64;
+
+        //find all of the vertical intersections
+        let mut current_max_val = A_SMALL_VALUE;
+        x = col as f64;
+        loop {
+            x = x + x_step as f64;
+            if x < 0.0 || x >= columns as f64 {
+                break;
+            }
+            //calculate the Y value
+            y = (line_slope * x + y_intercept) * -1f64;
+            if y < 0f64 || y >= rows as f64 {
+                break;
+            }
+            //calculate the distance
+            delta_x = (x - col as f64) * cell_size;
+            delta_y = (y - row as f64) * cell_size;
+            dist = (delta_x * delta_x + delta_y * delta_y).sqrt();
+            if dist > max_dist {
+                break;
+            }
+            //estimate z
+            y1 = y as isize;
+            y2 = y1 + y_step * -1isize;
+            z1 = input[(y1, x as isize)];
+            z2 = input[(y2, x as isize)];
+            if z1 == nodata || z2 == nodata {
+                continue;
+            }
+            z = z1 + (y - y1 as f64) * (z2 - z1);
+            //calculate the slope
+            slope = (z - current_val) / dist;
+            if slope > current_max_val {
+                current_max_val = slope;
+            }
+        }
+
+        //find all of the horizontal intersections
+        y = -row as f64;
+        loop {
+            y = y + y_step as f64;
+            if -y < 0f64 || -y >= rows as f64 {
+                break;
+            }
+            //calculate the X value
+            x = (y - y_intercept) / line_slope;
+            if x < 0f64 || x >= columns as f64 {
+                break;
+            }
+            //calculate the distance
+            delta_x = (x - col as f64) * cell_size;
+            delta_y = (-y - row as f64) * cell_size;
+            dist = (delta_x * delta_x + delta_y * delta_y).sqrt();
+            if dist > max_dist {
+                break;
+            }
+            //estimate z
+            x1 = x as isize;
+            x2 = x1 + x_step;
+            if x2 < 0 || x2 >= columns {
+                break;
+            }
+            z1 = input[(-y as isize, x1)];
+            z2 = input[(-y as isize, x2)];
+            if z1 == nodata || z2 == nodata {
+                continue;
+            }
+            z = z1 + (x - x1 as f64) * (z2 - z1);
+            //calculate the slope
+            slope = (z - current_val) / dist;
+            if slope > current_max_val {
+                current_max_val = slope;
+            }
+        }
+
+        current_max_val
+    }
+}
@@ -0,0 +1,517 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use structures::Point2D;
+use tools::*;
+use vector::*;
+
+/// This tool evaluates the lines of sight between pairs of observer (`--observers`) and
+/// target (`--targets`) points over a digital elevation model (`--dem`). Whereas the
+/// `Viewshed` tool maps every cell that is visible from a set of stations, this tool answers
+/// the narrower question of whether particular targets, such as a communications tower or a
+/// proposed building, can be seen from particular locations, and by how much the sight line
+/// clears, or fails to clear, the terrain between them.
+///
+/// By default, the observer and target points are paired in the order of their records,
+/// i.e. the first observer with the first target, and so on, which requires that the two
+/// files contain the same number of points. When the `--all_pairs` flag is specified, every
+/// observer is paired with every target instead. The sight line runs from a height of
+/// `--observer_height` above the ground at the observer to `--target_height` above the
+/// ground at the target, in the z units of the DEM. The terrain is sampled along the sight
+/// line at intervals of half of a grid cell, with bilinear interpolation of the DEM, and the
+/// vertical clearance of the sight line above the terrain is calculated at each sample. The
+/// target is visible if the sight line clears the terrain at every sample between the two
+/// points; otherwise, it is blocked at the first sample, moving away from the observer,
+/// at which the terrain rises above the sight line.
+///
+/// The output vector file (`--output`) contains one 3D polyline (PolyLineZ) for each pair,
+/// which traces the terrain profile beneath the sight line, with the terrain elevations as
+/// z values and the distances from the observer as measures. Its attribute table contains
+/// the observer and target IDs (their record numbers, starting at 1), whether the target
+/// is visible (`VISIBLE`, 1 or 0), the horizontal distance between the points, the minimum
+/// clearance of the sight line (`MIN_CLEAR`, negative where it is blocked), and the
+/// location, distance from the observer, and terrain elevation of the blocking point
+/// (null where the target is visible). The profiles are also written to a CSV file
+/// (`--csv`; by default, the output file name with a '_profiles.csv' suffix), with one
+/// row for each sample, containing its pair, distance, coordinates, terrain and sight-line
+/// elevations, and clearance. Samples on nodata cells are omitted, and pairs whose observer
+/// or target lies outside of the DEM or on nodata are skipped.
+///
+/// # See Also
+/// `Viewshed`, `VisibilityIndex`, `HorizonAngle`
+pub struct LineOfSight {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LineOfSight {
+    pub fn new() -> LineOfSight {
+        // public constructor
+        let name = "LineOfSight".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description = "Evaluates the lines of sight between pairs of observer and target points, reporting visibility and clearance.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Observer Points File".to_owned(),
+            flags: vec!["--observers".to_owned()],
+            description: "Input vector observer points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Target Points File".to_owned(),
+            flags: vec!["--targets".to_owned()],
+            description: "Input vector target points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector Lines File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file of 3D terrain profile lines.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Profiles CSV File".to_owned(),
+            flags: vec!["--csv".to_owned()],
+            description: "Optional output CSV file of the profile samples.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Observer Height (in z units)".to_owned(),
+            flags: vec!["--observer_height".to_owned()],
+            description: "Height of the observer above the ground, in z units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("2.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Target Height (in z units)".to_owned(),
+            flags: vec!["--target_height".to_owned()],
+            description: "Height of the target above the ground, in z units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Pair every observer with every target?".to_owned(),
+            flags: vec!["--all_pairs".to_owned()],
+            description: "Pair every observer with every target, rather than pairing them in record order.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=dem.tif --observers=observers.shp --targets=targets.shp -o=sight_lines.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=dem.tif --observers=houses.shp --targets=tower.shp -o=sight_lines.shp --csv=profiles.csv --observer_height=1.7 --target_height=45.0 --all_pairs", short_exe, name).replace("*", &sep);
+
+        LineOfSight {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LineOfSight {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut dem_file = String::new();
+        let mut observers_file = String::new();
+        let mut targets_file = String::new();
+        let mut output_file = String::new();
+        let mut csv_file = String::new();
+        let mut observer_height = 2f64;
+        let mut target_height = 0f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--dem"])? {
+            dem_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--observers"])? {
+            observers_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--targets"])? {
+            targets_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--csv"])? {
+            csv_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--observer_height"])? {
+            observer_height = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--target_height"])? {
+            target_height = v;
+        }
+        let all_pairs = tool_args.get_flag(&["--all_pairs"])?;
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !observers_file.contains(&sep) && !observers_file.contains("/") {
+            observers_file = format!("{}{}", working_directory, observers_file);
+        }
+        if !targets_file.contains(&sep) && !targets_file.contains("/") {
+            targets_file = format!("{}{}", working_directory, targets_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if csv_file.trim().is_empty() {
+            csv_file = Path::new(&output_file)
+                .with_extension("")
+                .to_string_lossy()
+                .to_string()
+                + "_profiles.csv";
+        } else if !csv_file.contains(&sep) && !csv_file.contains("/") {
+            csv_file = format!("{}{}", working_directory, csv_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let dem = Raster::new(&dem_file, "r")?;
+        let observers = Shapefile::read(&observers_file)?;
+        let targets = Shapefile::read(&targets_file)?;
+
+        let start = Instant::now();
+
+        if observers.header.shape_type.base_shape_type() != ShapeType::Point
+            || targets.header.shape_type.base_shape_type() != ShapeType::Point
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input observer and target files must be of point base shape type.",
+            ));
+        }
+
+        // pair the observers and targets, by record number
+        let mut pairs: Vec<(usize, usize)> = vec![];
+        if all_pairs {
+            for i in 0..observers.num_records {
+                for j in 0..targets.num_records {
+                    pairs.push((i, j));
+                }
+            }
+        } else {
+            if observers.num_records != targets.num_records {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The observer and target files must contain the same number of points, unless every observer is paired with every target (--all_pairs).",
+                ));
+            }
+            for i in 0..observers.num_records {
+                pairs.push((i, i));
+            }
+        }
+
+        let step = dem.configs.resolution_x.min(dem.configs.resolution_y) / 2f64;
+
+        let mut output = Shapefile::new(&output_file, ShapeType::PolyLineZ)?;
+        output.projection = dem.configs.coordinate_ref_system_wkt.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("OBS_ID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("TARGET_ID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("VISIBLE", FieldDataType::Int, 1u8, 0u8));
+        for name in &["DISTANCE", "MIN_CLEAR", "BLOCK_X", "BLOCK_Y", "BLOCK_DIST", "BLOCK_Z"] {
+            output.attributes.add_field(&AttributeField::new(
+                name,
+                FieldDataType::Real,
+                14u8,
+                4u8,
+            ));
+        }
+
+        let f = File::create(&csv_file)?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all(
+            "FID,OBS_ID,TARGET_ID,DISTANCE,X,Y,TERRAIN_Z,SIGHT_Z,CLEARANCE\n".as_bytes(),
+        )?;
+
+        let mut fid = 1i32;
+        let mut num_skipped = 0;
+        let num_pairs = pairs.len();
+        for (pair_num, &(i, j)) in pairs.iter().enumerate() {
+            let p0 = observers.get_record(i).points[0];
+            let p1 = targets.get_record(j).points[0];
+            let (z0, z1) = match (interpolate(&dem, p0.x, p0.y), interpolate(&dem, p1.x, p1.y)) {
+                (Some(z0), Some(z1)) => (z0 + observer_height, z1 + target_height),
+                _ => {
+                    num_skipped += 1;
+                    continue;
+                }
+            };
+            let distance = ((p1.x - p0.x) * (p1.x - p0.x) + (p1.y - p0.y) * (p1.y - p0.y)).sqrt();
+            let num_steps = ((distance / step).ceil() as usize).max(1);
+
+            let mut points = vec![];
+            let mut measures = vec![];
+            let mut z_values = vec![];
+            let mut min_clearance = f64::INFINITY;
+            let mut block: Option<(Point2D, f64, f64)> = None;
+            for k in 0..num_steps + 1 {
+                let t = k as f64 / num_steps as f64;
+                let p = Point2D::new(p0.x + t * (p1.x - p0.x), p0.y + t * (p1.y - p0.y));
+                let z = match interpolate(&dem, p.x, p.y) {
+                    Some(z) => z,
+                    None => continue,
+                };
+                let d = t * distance;
+                let sight_z = z0 + t * (z1 - z0);
+                let clearance = sight_z - z;
+                points.push(p);
+                measures.push(d);
+                z_values.push(z);
+                writer.write_all(
+                    format!(
+                        "{},{},{},{},{},{},{},{},{}\n",
+                        fid,
+                        i + 1,
+                        j + 1,
+                        d,
+                        p.x,
+                        p.y,
+                        z,
+                        sight_z,
+                        clearance
+                    ).as_bytes(),
+                )?;
+                // the end points of the sight line cannot block it
+                if k == 0 || k == num_steps {
+                    continue;
+                }
+                if clearance < min_clearance {
+                    min_clearance = clearance;
+                }
+                if clearance < 0f64 && block.is_none() {
+                    block = Some((p, d, z));
+                }
+            }
+            if !min_clearance.is_finite() {
+                // the points are too close together to be separated by any terrain
+                min_clearance = (z0 - z_values[0]).min(z1 - z_values[z_values.len() - 1]);
+            }
+
+            let mut sfg = ShapefileGeometry::new(ShapeType::PolyLineZ);
+            sfg.add_partz(&points, &measures, &z_values);
+            output.add_record(sfg);
+            let mut record = vec![
+                FieldData::Int(fid),
+                FieldData::Int(i as i32 + 1),
+                FieldData::Int(j as i32 + 1),
+                FieldData::Int(if block.is_none() { 1i32 } else { 0i32 }),
+                FieldData::Real(distance),
+                FieldData::Real(min_clearance),
+            ];
+            match block {
+                Some((p, d, z)) => {
+                    record.push(FieldData::Real(p.x));
+                    record.push(FieldData::Real(p.y));
+                    record.push(FieldData::Real(d));
+                    record.push(FieldData::Real(z));
+                }
+                None => {
+                    for _ in 0..4 {
+                        record.push(FieldData::Null);
+                    }
+                }
+            }
+            output.attributes.add_record(record, false);
+            fid += 1;
+
+            if verbose {
+                progress = (100.0_f64 * (pair_num + 1) as f64 / num_pairs as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        writer.flush()?;
+
+        if num_skipped > 0 {
+            report::warning(&format!(
+                "{} pairs had an observer or target outside of the DEM, or on nodata, and were skipped.",
+                num_skipped
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    report::message("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::message(&format!("Profiles written to {}", csv_file));
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the elevation of a DEM at a location, interpolated bilinearly between the
+/// centres of the surrounding cells, or the value of the containing cell where any of the
+/// surrounding cells are nodata.
+fn interpolate(dem: &Raster, x: f64, y: f64) -> Option<f64> {
+    let nodata = dem.configs.nodata;
+    let row = dem.get_row_from_y(y);
+    let col = dem.get_column_from_x(x);
+    if row < 0 || col < 0 || row >= dem.configs.rows as isize || col >= dem.configs.columns as isize
+    {
+        return None;
+    }
+    let z = dem.get_value(row, col);
+    if z == nodata {
+        return None;
+    }
+    let fx = (x - dem.configs.west) / dem.configs.resolution_x - 0.5;
+    let fy = (dem.configs.north - y) / dem.configs.resolution_y - 0.5;
+    let (c0, r0) = (fx.floor() as isize, fy.floor() as isize);
+    let (tx, ty) = (fx - c0 as f64, fy - r0 as f64);
+    let z00 = dem.get_value(r0, c0);
+    let z01 = dem.get_value(r0, c0 + 1);
+    let z10 = dem.get_value(r0 + 1, c0);
+    let z11 = dem.get_value(r0 + 1, c0 + 1);
+    if z00 == nodata || z01 == nodata || z10 == nodata || z11 == nodata {
+        return Some(z);
+    }
+    Some(
+        z00 * (1f64 - tx) * (1f64 - ty)
+            + z01 * tx * (1f64 - ty)
+            + z10 * (1f64 - tx) * ty
+            + z11 * tx * ty,
+    )
+}
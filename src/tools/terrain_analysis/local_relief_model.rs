@@ -0,0 +1,395 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use num_cpus;
+use raster::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::Array2D;
+use tools::*;
+
+/// This tool calculates a local relief model (LRM) from an input digital elevation model
+/// (DEM). The LRM is the difference between the DEM and a large-scale trend surface, which is
+/// estimated by low-pass filtering the DEM. Subtracting the trend removes the broad landforms
+/// from the DEM, leaving the small-scale features, such as the banks, ditches, mounds and
+/// terraces that are of interest in archaeological prospection (Hesse, 2010). Positive values
+/// indicate locations that are higher than their surroundings and negative values indicate
+/// locations that are lower.
+///
+/// The trend surface is calculated with either a mean (`--method=mean`) or Gaussian
+/// (`--method=gaussian`) filter of a specified size (`--filter`), in grid cells. The Gaussian
+/// filter has a standard deviation of one sixth of the filter size, such that it extends to
+/// three standard deviations on either side of the centre cell. Larger filters retain larger
+/// features in the LRM; the filter should be about twice the width of the largest features of
+/// interest. Nodata cells are excluded from the filter, and the trend near the edges of the DEM
+/// and of nodata areas is therefore estimated from the available cells. The trend surface may
+/// optionally be saved (`--trend`).
+///
+/// # Reference
+/// Hesse R (2010), LiDAR-derived local relief models - a new tool for archaeological
+/// prospection, *Archaeological Prospection*, 17: 67-72.
+///
+/// # See Also
+/// `DevFromMeanElev`, `HorizonAngle`, `MeanFilter`, `GaussianFilter`
+pub struct LocalReliefModel {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LocalReliefModel {
+    pub fn new() -> LocalReliefModel {
+        // public constructor
+        let name = "LocalReliefModel".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Calculates a local relief model, the difference between a DEM and its low-pass filtered trend surface."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output local relief model raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Trend Surface File (optional)".to_owned(),
+            flags: vec!["--trend".to_owned()],
+            description: "Optional output trend surface raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Size (cells)".to_owned(),
+            flags: vec!["--filter".to_owned()],
+            description: "Size of the low-pass filter, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("51".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Filter Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Low-pass filter method; options include 'mean' and 'gaussian'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "mean".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("mean".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=dem.tif -o=lrm.tif --filter=41 --method=gaussian --trend=trend.tif",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LocalReliefModel {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LocalReliefModel {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut trend_file = String::new();
+        let mut filter_size = 51usize;
+        let mut method = String::from("mean");
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--trend"])? {
+            trend_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--filter"])? {
+            if v < 3 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The filter size must be at least three cells.",
+                ));
+            }
+            filter_size = v as usize;
+        }
+        if let Some(v) = tool_args.get_string(&["--method"])? {
+            method = v.to_lowercase();
+        }
+        if filter_size % 2 == 0 {
+            filter_size += 1;
+        }
+        let half = (filter_size / 2) as isize;
+        let weights: Vec<f64> = if method.contains("gauss") {
+            let sigma = filter_size as f64 / 6f64;
+            (-half..half + 1)
+                .map(|i| (-((i * i) as f64) / (2f64 * sigma * sigma)).exp())
+                .collect()
+        } else if method.contains("mean") {
+            vec![1f64; filter_size]
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized filter method; options include 'mean' and 'gaussian'.",
+            ));
+        };
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !trend_file.is_empty() && !trend_file.contains(&sep) && !trend_file.contains("/") {
+            trend_file = format!("{}{}", working_directory, trend_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+
+        // The filter is normalized by the weights of the valid cells within it, such that
+        // nodata cells and cells beyond the edges of the DEM are excluded. Each of the
+        // weighted sums is calculated as a horizontal pass followed by a vertical pass.
+        let mut sum_zw: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        let mut sum_w: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != nodata {
+                    sum_zw.set_value(row, col, z);
+                    sum_w.set_value(row, col, 1f64);
+                }
+            }
+        }
+        let weights = Arc::new(weights);
+        if verbose {
+            report::message("Filtering the DEM...");
+        }
+        let sum_zw = separable_pass(
+            Arc::new(separable_pass(Arc::new(sum_zw), weights.clone(), true)?),
+            weights.clone(),
+            false,
+        )?;
+        let sum_w = separable_pass(
+            Arc::new(separable_pass(Arc::new(sum_w), weights.clone(), true)?),
+            weights.clone(),
+            false,
+        )?;
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.data_type = DataType::F32;
+        let mut trend = if trend_file.is_empty() {
+            None
+        } else {
+            let mut r = Raster::initialize_using_file(&trend_file, &input);
+            r.configs.data_type = DataType::F32;
+            Some(r)
+        };
+        let mut w: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                w = sum_w.get_value(row, col);
+                if z != nodata && w > 0f64 {
+                    let t = sum_zw.get_value(row, col) / w;
+                    output.set_value(row, col, z - t);
+                    if let Some(ref mut r) = trend {
+                        r.set_value(row, col, t);
+                    }
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "blue_white_red.plt".to_string();
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Filter size: {}", filter_size));
+        output.add_metadata_entry(format!("Filter method: {}", method));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => if verbose {
+                report::message("Output file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if let Some(mut r) = trend {
+            r.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            r.add_metadata_entry(format!("Input file: {}", input_file));
+            r.add_metadata_entry(format!("Filter size: {}", filter_size));
+            r.add_metadata_entry(format!("Filter method: {}", method));
+            let _ = match r.write() {
+                Ok(_) => if verbose {
+                    report::message("Trend surface file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Convolves the rows (`horizontal`) or columns of a grid with a symmetric, one-dimensional
+/// kernel in parallel. Cells beyond the edges of the grid are treated as zero.
+fn separable_pass(
+    input: Arc<Array2D<f64>>,
+    weights: Arc<Vec<f64>>,
+    horizontal: bool,
+) -> Result<Array2D<f64>, Error> {
+    let rows = input.rows();
+    let columns = input.columns();
+    let half = (weights.len() / 2) as isize;
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let weights = weights.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let (mut x, mut y): (isize, isize);
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data = vec![0f64; columns as usize];
+                for col in 0..columns {
+                    let mut sum = 0f64;
+                    for i in -half..half + 1 {
+                        if horizontal {
+                            x = col + i;
+                            y = row;
+                        } else {
+                            x = col;
+                            y = row + i;
+                        }
+                        if x >= 0 && x < columns && y >= 0 && y < rows {
+                            sum += weights[(i + half) as usize] * input.get_value(y, x);
+                        }
+                    }
+                    data[col as usize] = sum;
+                }
+                tx.send((row, data)).unwrap();
+            }
+        });
+    }
+
+    let mut output: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64)?;
+    for _ in 0..rows {
+        let (row, data) = rx.recv().unwrap();
+        output.set_row_data(row, data);
+    }
+    Ok(output)
+}
@@ -165,68 +165,37 @@ impl WhiteboxTool for MaxAnisotropyDev {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_mag"])? {
+            output_mag_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_scale"])? {
+            output_scale_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--min_scale"])? {
+            min_scale = v;
+            if min_scale < 3 {
+                min_scale = 3;
             }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_mag" || flag_val == "-out_mag" {
-                output_mag_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_scale" {
-                output_scale_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-min_scale" {
-                min_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-                if min_scale < 3 {
-                    min_scale = 3;
-                }
-            } else if flag_val == "-max_scale" {
-                max_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-                if max_scale < 5 {
-                    max_scale = 5;
-                }
-            } else if flag_val == "-step" {
-                step = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-                if step < 1 {
-                    step = 1;
-                }
+        }
+        if let Some(v) = tool_args.get_isize(&["--max_scale"])? {
+            max_scale = v;
+            if max_scale < 5 {
+                max_scale = 5;
+            }
+        }
+        if let Some(v) = tool_args.get_isize(&["--step"])? {
+            step = v;
+            if step < 1 {
+                step = 1;
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -245,7 +214,7 @@ impl WhiteboxTool for MaxAnisotropyDev {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -295,7 +264,7 @@ impl WhiteboxTool for MaxAnisotropyDev {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    report::progress("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -309,6 +278,7 @@ impl WhiteboxTool for MaxAnisotropyDev {
 
         let mut output_mag = Raster::initialize_using_file(&output_mag_file, &input);
         let mut output_scale = Raster::initialize_using_file(&output_scale_file, &input);
+        let out_nodata = output_mag.configs.nodata;
 
         // num_loops can be determined exactly ahead of time.
         let num_loops = (min_scale..max_scale + 1).fold(0, |acc, x| {
@@ -593,7 +563,7 @@ impl WhiteboxTool for MaxAnisotropyDev {
                     z2 = data[col as usize];
                     if z2 != nodata {
                         z1 = output_mag[(row, col)];
-                        if z1 != nodata {
+                        if z1 != out_nodata {
                             if z2 * z2 > z1 * z1 {
                                 output_mag[(row, col)] = z2;
                                 output_scale[(row, col)] = midpoint as f64;
@@ -607,9 +577,9 @@ impl WhiteboxTool for MaxAnisotropyDev {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (Loop {} of {}): {}%",
-                            loop_num, num_loops, progress
+                        report::progress(
+                            &format!("Progress (Loop {} of {})", loop_num, num_loops),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -630,12 +600,12 @@ impl WhiteboxTool for MaxAnisotropyDev {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            report::message("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -653,22 +623,19 @@ impl WhiteboxTool for MaxAnisotropyDev {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            report::message("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -169,57 +169,28 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-points" {
-                points_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-min_scale" {
-                min_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-                if min_scale < 1 {
-                    min_scale = 1;
-                }
-            } else if flag_val == "-max_scale" {
-                max_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-            } else if flag_val == "-step" {
-                step = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--points"])? {
+            points_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--min_scale"])? {
+            min_scale = v;
+            if min_scale < 1 {
+                min_scale = 1;
             }
         }
+        if let Some(v) = tool_args.get_isize(&["--max_scale"])? {
+            max_scale = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--step"])? {
+            step = v;
+        }
 
         if max_scale < min_scale {
             let ms = min_scale;
@@ -236,9 +207,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -257,7 +226,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            report::message("Reading DEM data...")
         };
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
@@ -266,7 +235,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         let nodata = input.configs.nodata;
 
         if verbose {
-            println!("Reading points data...")
+            report::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -297,7 +266,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
+                    report::progress("Finding site row/column values", progress);
                     old_progress = progress;
                 }
             }
@@ -343,7 +312,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    report::progress("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -353,7 +322,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         for midpoint in (min_scale..max_scale).filter(|s| (s - min_scale) % step == 0) {
             // .step_by(step) { once step_by is stabilized
             if verbose {
-                println!("Filter Size {} / {}", midpoint, max_scale);
+                report::message(&format!("Filter Size {} / {}", midpoint, max_scale));
             }
             middle_pane_radius = (midpoint * 2 + 1) / 6;
 
@@ -573,10 +542,10 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -655,7 +624,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -169,57 +169,28 @@ impl WhiteboxTool for MaxElevDevSignature {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-points" {
-                points_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-min_scale" {
-                min_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-                if min_scale < 1 {
-                    min_scale = 1;
-                }
-            } else if flag_val == "-max_scale" {
-                max_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-            } else if flag_val == "-step" {
-                step = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--points"])? {
+            points_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--min_scale"])? {
+            min_scale = v;
+            if min_scale < 1 {
+                min_scale = 1;
             }
         }
+        if let Some(v) = tool_args.get_isize(&["--max_scale"])? {
+            max_scale = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--step"])? {
+            step = v;
+        }
 
         if max_scale < min_scale {
             let ms = min_scale;
@@ -236,9 +207,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -257,7 +226,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
@@ -267,7 +236,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         let nodata = input.configs.nodata;
 
         if verbose {
-            println!("Reading points data...")
+            report::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -298,7 +267,7 @@ impl WhiteboxTool for MaxElevDevSignature {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
+                    report::progress("Finding site row/column values", progress);
                     old_progress = progress;
                 }
             }
@@ -344,7 +313,7 @@ impl WhiteboxTool for MaxElevDevSignature {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    report::progress("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -403,10 +372,10 @@ impl WhiteboxTool for MaxElevDevSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -463,10 +432,10 @@ impl WhiteboxTool for MaxElevDevSignature {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -493,7 +462,7 @@ impl WhiteboxTool for MaxElevDevSignature {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                report::message(&format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -166,76 +166,37 @@ impl WhiteboxTool for MaxElevationDeviation {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_mag"])? {
+            output_mag_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_scale"])? {
+            output_scale_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--min_scale"])? {
+            min_scale = v;
+            if min_scale < 1 {
+                min_scale = 1;
             }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_mag" || vec[0].to_lowercase() == "--out_mag" {
-                if keyval {
-                    output_mag_file = vec[1].to_string();
-                } else {
-                    output_mag_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-out_scale"
-                || vec[0].to_lowercase() == "--out_scale"
-            {
-                if keyval {
-                    output_scale_file = vec[1].to_string();
-                } else {
-                    output_scale_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-min_scale"
-                || vec[0].to_lowercase() == "--min_scale"
-            {
-                if keyval {
-                    min_scale = vec[1].to_string().parse::<isize>().unwrap();
-                } else {
-                    min_scale = args[i + 1].to_string().parse::<isize>().unwrap();
-                }
-                if min_scale < 1 {
-                    min_scale = 1;
-                }
-            } else if vec[0].to_lowercase() == "-max_scale"
-                || vec[0].to_lowercase() == "--max_scale"
-            {
-                if keyval {
-                    max_scale = vec[1].to_string().parse::<isize>().unwrap();
-                } else {
-                    max_scale = args[i + 1].to_string().parse::<isize>().unwrap();
-                }
-                if max_scale < 5 {
-                    max_scale = 5;
-                }
-            } else if vec[0].to_lowercase() == "-step" || vec[0].to_lowercase() == "--step" {
-                if keyval {
-                    step = vec[1].to_string().parse::<isize>().unwrap();
-                } else {
-                    step = args[i + 1].to_string().parse::<isize>().unwrap();
-                }
-                if step < 1 {
-                    step = 1;
-                }
+        }
+        if let Some(v) = tool_args.get_isize(&["--max_scale"])? {
+            max_scale = v;
+            if max_scale < 5 {
+                max_scale = 5;
+            }
+        }
+        if let Some(v) = tool_args.get_isize(&["--step"])? {
+            step = v;
+            if step < 1 {
+                step = 1;
             }
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -254,7 +215,7 @@ impl WhiteboxTool for MaxElevationDeviation {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let start = Instant::now();
@@ -303,7 +264,7 @@ impl WhiteboxTool for MaxElevationDeviation {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    report::progress("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -317,6 +278,7 @@ impl WhiteboxTool for MaxElevationDeviation {
 
         let mut output_mag = Raster::initialize_using_file(&output_mag_file, &input);
         let mut output_scale = Raster::initialize_using_file(&output_scale_file, &input);
+        let out_nodata = output_mag.configs.nodata;
 
         let num_loops = (max_scale - min_scale) / step;
         let mut loop_num = 0;
@@ -395,7 +357,7 @@ impl WhiteboxTool for MaxElevationDeviation {
                     z2 = data[col as usize];
                     if z2 != nodata {
                         z1 = output_mag[(row, col)];
-                        if z1 != nodata {
+                        if z1 != out_nodata {
                             if z2 * z2 > z1 * z1 {
                                 output_mag[(row, col)] = z2;
                                 output_scale[(row, col)] = midpoint as f64;
@@ -409,9 +371,9 @@ impl WhiteboxTool for MaxElevationDeviation {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (Loop {} of {}): {}%",
-                            loop_num, num_loops, progress
+                        report::progress(
+                            &format!("Progress (Loop {} of {})", loop_num, num_loops),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -434,12 +396,12 @@ impl WhiteboxTool for MaxElevationDeviation {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            report::message("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -457,22 +419,19 @@ impl WhiteboxTool for MaxElevationDeviation {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            report::message("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
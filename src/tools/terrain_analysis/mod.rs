@@ -9,6 +9,7 @@ mod elev_above_pit;
 mod elev_percentile;
 mod elev_relative_to_min_max;
 mod elev_relative_to_watershed_min_max;
+mod extract_profile;
 mod feature_preserving_denoise;
 mod fetch_analysis;
 mod fill_missing_data;
@@ -16,6 +17,8 @@ mod find_ridges;
 mod hillshade;
 mod horizon_angle;
 mod hypsometric_analysis;
+mod line_of_sight;
+mod local_relief_model;
 mod max_anisotropy_dev;
 mod max_anisotropy_dev_signature;
 mod max_branch_length;
@@ -24,6 +27,7 @@ mod max_downslope_elev_change;
 mod max_elev_dev_signature;
 mod max_elev_deviation;
 mod min_downslope_elev_change;
+mod multiscale_elevation_residual;
 mod multiscale_roughness;
 mod multiscale_roughness_signature;
 mod multiscale_topographic_position_image;
@@ -43,6 +47,7 @@ mod sediment_transport_index;
 mod slope;
 mod slope_vs_elev_plot;
 mod standard_deviation_of_slope;
+mod swath_profile;
 mod tan_curvature;
 mod total_curvature;
 mod viewshed;
@@ -50,7 +55,7 @@ mod visibility_index;
 mod wetness_index;
 
 // exports identifiers from private sub-modules in the current module namespace
-pub use self::aspect::Aspect;
+pub use self::aspect::{aspect, Aspect};
 pub use self::dev_from_mean_elev::DevFromMeanElev;
 pub use self::diff_from_mean_elev::DiffFromMeanElev;
 pub use self::directional_relief::DirectionalRelief;
@@ -60,6 +65,7 @@ pub use self::elev_above_pit::ElevAbovePit;
 pub use self::elev_percentile::ElevPercentile;
 pub use self::elev_relative_to_min_max::ElevRelativeToMinMax;
 pub use self::elev_relative_to_watershed_min_max::ElevRelativeToWatershedMinMax;
+pub use self::extract_profile::ExtractProfile;
 pub use self::feature_preserving_denoise::FeaturePreservingDenoise;
 pub use self::fetch_analysis::FetchAnalysis;
 pub use self::fill_missing_data::FillMissingData;
@@ -67,6 +73,8 @@ pub use self::find_ridges::FindRidges;
 pub use self::hillshade::Hillshade;
 pub use self::horizon_angle::HorizonAngle;
 pub use self::hypsometric_analysis::HypsometricAnalysis;
+pub use self::line_of_sight::LineOfSight;
+pub use self::local_relief_model::LocalReliefModel;
 pub use self::max_anisotropy_dev::MaxAnisotropyDev;
 pub use self::max_anisotropy_dev_signature::MaxAnisotropyDevSignature;
 pub use self::max_branch_length::MaxBranchLength;
@@ -75,6 +83,7 @@ pub use self::max_downslope_elev_change::MaxDownslopeElevChange;
 pub use self::max_elev_dev_signature::MaxElevDevSignature;
 pub use self::max_elev_deviation::MaxElevationDeviation;
 pub use self::min_downslope_elev_change::MinDownslopeElevChange;
+pub use self::multiscale_elevation_residual::MultiscaleElevationResidual;
 pub use self::multiscale_roughness::MultiscaleRoughness;
 pub use self::multiscale_roughness_signature::MultiscaleRoughnessSignature;
 pub use self::multiscale_topographic_position_image::MultiscaleTopographicPositionImage;
@@ -91,9 +100,10 @@ pub use self::relative_topographic_position::RelativeTopographicPosition;
 pub use self::remove_off_terrain_objects::RemoveOffTerrainObjects;
 pub use self::ruggedness_index::RuggednessIndex;
 pub use self::sediment_transport_index::SedimentTransportIndex;
-pub use self::slope::Slope;
+pub use self::slope::{slope, Slope};
 pub use self::slope_vs_elev_plot::SlopeVsElevationPlot;
 pub use self::standard_deviation_of_slope::StandardDeviationOfSlope;
+pub use self::swath_profile::SwathProfile;
 pub use self::tan_curvature::TangentialCurvature;
 pub use self::total_curvature::TotalCurvature;
 pub use self::viewshed::Viewshed;
@@ -0,0 +1,600 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use algorithms::{rank_filter, RankStatistic, NO_BIN};
+use num_cpus;
+use raster::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use structures::SummedAreaTable;
+use tools::*;
+use vector::{ShapeType, Shapefile};
+
+/// The number of elevation bins used to calculate elevation percentiles.
+const NUM_BINS: usize = 1024;
+
+/// This tool calculates a local topographic position index, or elevation residual, for
+/// each grid cell in a DEM (`--dem`) over a geometric series of spatial scales, and
+/// reports the scale at which each cell's residual is most extreme. The scales are the
+/// radii, in grid cells, of square neighbourhoods; the series begins at `--min_scale`
+/// and each subsequent radius is `--scale_factor` times the previous radius, for a total
+/// of `--num_steps` scales. A geometric series samples the finer scales more densely than
+/// the broader ones, which reflects the way that topographic position typically varies
+/// with scale.
+///
+/// Two residuals are available (`--residual`). The deviation from mean elevation (`dev`)
+/// is the difference between a cell's elevation and the mean elevation of its
+/// neighbourhood, divided by the standard deviation of the neighbourhood's elevations.
+/// The elevation percentile (`percentile`) is the percentage of the neighbourhood's cells
+/// that are lower than the centre cell. Both residuals are calculated in constant time
+/// per cell regardless of the neighbourhood size, using integral images and histograms
+/// respectively, such that very broad scales can be examined. Elevation percentiles are
+/// calculated from the elevations binned into 1024 classes.
+///
+/// The tool produces two scale-optimized rasters: the magnitude raster (`--out_mag`)
+/// contains the residual with the greatest departure from a neutral topographic position
+/// (a DEV of zero or a percentile of 50) across the scales, and the scale raster
+/// (`--out_scale`) contains the neighbourhood radius at which it occurs. In addition, if
+/// a vector points file of sample locations (`--points`) is specified, the residuals of
+/// each point at all of the scales, i.e. the points' scale signatures, are saved to a
+/// CSV file (`--out_signatures`), with one line per point and scale. These signatures
+/// can be used to select the scales that are relevant to an analysis.
+///
+/// # See Also
+/// `MaxElevationDeviation`, `MaxElevDevSignature`, `DevFromMeanElev`, `ElevPercentile`
+pub struct MultiscaleElevationResidual {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl MultiscaleElevationResidual {
+    pub fn new() -> MultiscaleElevationResidual {
+        // public constructor
+        let name = "MultiscaleElevationResidual".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Calculates elevation residuals over a geometric series of scales, with optional scale signatures for sample points."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Residual Magnitude File".to_owned(),
+            flags: vec!["--out_mag".to_owned()],
+            description: "Output raster scale-optimized residual magnitude file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Residual Scale File".to_owned(),
+            flags: vec!["--out_scale".to_owned()],
+            description: "Output raster scale-optimized residual scale file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Residual Type".to_owned(),
+            flags: vec!["--residual".to_owned()],
+            description: "Elevation residual; options are 'dev' (deviation from mean elevation) and 'percentile' (elevation percentile).".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "dev".to_owned(),
+                "percentile".to_owned(),
+            ]),
+            default_value: Some("dev".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Search Neighbourhood Radius (grid cells)".to_owned(),
+            flags: vec!["--min_scale".to_owned()],
+            description: "Minimum search neighbourhood radius in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Steps".to_owned(),
+            flags: vec!["--num_steps".to_owned()],
+            description: "Number of scales in the geometric series.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("10".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Scale Factor".to_owned(),
+            flags: vec!["--scale_factor".to_owned()],
+            description: "Ratio of each neighbourhood radius to the previous radius (> 1.0)."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.5".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Sample Points File (optional)".to_owned(),
+            flags: vec!["--points".to_owned()],
+            description: "Input vector points file of sample locations.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Scale Signatures File (optional)".to_owned(),
+            flags: vec!["--out_signatures".to_owned()],
+            description: "Output CSV file of the sample points' scale signatures.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --out_mag=mag.tif --out_scale=scale.tif --min_scale=1 --num_steps=12 --scale_factor=1.5
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --out_mag=mag.tif --out_scale=scale.tif --residual=percentile --points=sites.shp --out_signatures=signatures.csv",
+            short_exe, name
+        ).replace("*", &sep);
+
+        MultiscaleElevationResidual {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for MultiscaleElevationResidual {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_mag_file = String::new();
+        let mut output_scale_file = String::new();
+        let mut residual = "dev".to_string();
+        let mut min_scale = 1isize;
+        let mut num_steps = 10usize;
+        let mut scale_factor = 1.5f64;
+        let mut points_file = String::new();
+        let mut signatures_file = String::new();
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_mag"])? {
+            output_mag_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_scale"])? {
+            output_scale_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--residual"])? {
+            residual = v.to_lowercase();
+            if residual.contains("perc") {
+                residual = "percentile".to_string();
+            } else if residual.contains("dev") {
+                residual = "dev".to_string();
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The residual must be 'dev' or 'percentile'.",
+                ));
+            }
+        }
+        if let Some(v) = tool_args.get_isize(&["--min_scale"])? {
+            min_scale = v.max(1);
+        }
+        if let Some(v) = tool_args.get_usize(&["--num_steps"])? {
+            num_steps = v.max(1);
+        }
+        if let Some(v) = tool_args.get_f64(&["--scale_factor"])? {
+            scale_factor = v;
+            if scale_factor <= 1f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The scale factor must be greater than 1.0.",
+                ));
+            }
+        }
+        if let Some(v) = tool_args.get_string(&["--points"])? {
+            points_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_signatures"])? {
+            signatures_file = v;
+        }
+        if points_file.is_empty() != signatures_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The --points and --out_signatures parameters must be specified together.",
+            ));
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_mag_file.contains(&sep) && !output_mag_file.contains("/") {
+            output_mag_file = format!("{}{}", working_directory, output_mag_file);
+        }
+        if !output_scale_file.contains(&sep) && !output_scale_file.contains("/") {
+            output_scale_file = format!("{}{}", working_directory, output_scale_file);
+        }
+        if !points_file.is_empty() && !points_file.contains(&sep) && !points_file.contains("/")
+        {
+            points_file = format!("{}{}", working_directory, points_file);
+        }
+        if !signatures_file.is_empty()
+            && !signatures_file.contains(&sep)
+            && !signatures_file.contains("/")
+        {
+            signatures_file = format!("{}{}", working_directory, signatures_file);
+        }
+
+        if verbose {
+            report::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+
+        // the geometric series of neighbourhood radii
+        let mut scales: Vec<isize> = vec![];
+        let mut radius = min_scale as f64;
+        for _ in 0..num_steps {
+            let mut scale = radius.round() as isize;
+            if let Some(&last) = scales.last() {
+                if scale <= last {
+                    scale = last + 1;
+                }
+            }
+            if scale > rows.max(columns) {
+                break; // the neighbourhood covers the entire DEM
+            }
+            scales.push(scale);
+            radius *= scale_factor;
+        }
+        if scales.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The minimum scale exceeds the size of the DEM.",
+            ));
+        }
+
+        // read the sample points and find the grid cells that contain them
+        let mut sites: Vec<(usize, f64, f64)> = vec![];
+        let mut sites_by_row: HashMap<isize, Vec<(usize, isize)>> = HashMap::new();
+        if !points_file.is_empty() {
+            if verbose {
+                report::message("Reading points data...")
+            };
+            let points = Shapefile::read(&points_file)?;
+            if points.header.shape_type.base_shape_type() != ShapeType::Point {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input vector data must be of point base shape type.",
+                ));
+            }
+            for record_num in 0..points.num_records {
+                let record = points.get_record(record_num);
+                let (x, y) = (record.points[0].x, record.points[0].y);
+                let row = input.get_row_from_y(y);
+                let col = input.get_column_from_x(x);
+                if row >= 0 && col >= 0 && row < rows && col < columns {
+                    sites_by_row
+                        .entry(row)
+                        .or_insert(vec![])
+                        .push((sites.len(), col));
+                    sites.push((record_num + 1, x, y));
+                } else if verbose {
+                    report::warning(&format!("Point {} lies outside of the DEM.", record_num + 1));
+                }
+            }
+        }
+        let mut signatures = vec![vec![nodata; scales.len()]; sites.len()];
+
+        let valid = |row: isize, col: isize| {
+            let z = input.get_value(row, col);
+            if z != nodata {
+                Some(z)
+            } else {
+                None
+            }
+        };
+        let sat = Arc::new(SummedAreaTable::new(
+            rows,
+            columns,
+            residual == "dev",
+            valid,
+        ));
+
+        // elevation percentiles are calculated from binned elevations
+        let mut bins = vec![];
+        if residual == "percentile" {
+            let min_val = input.configs.minimum;
+            let bin_width = (input.configs.maximum - min_val) / NUM_BINS as f64;
+            bins = vec![NO_BIN; (rows * columns) as usize];
+            for row in 0..rows {
+                for col in 0..columns {
+                    let z = input.get_value(row, col);
+                    if z != nodata {
+                        bins[(row * columns + col) as usize] = if bin_width > 0f64 {
+                            (((z - min_val) / bin_width) as usize).min(NUM_BINS - 1) as u32
+                        } else {
+                            0
+                        };
+                    }
+                }
+            }
+        }
+        let bins = Arc::new(bins);
+
+        let mut output_mag = Raster::initialize_using_file(&output_mag_file, &input);
+        output_mag.configs.data_type = DataType::F32;
+        let mut output_scale = Raster::initialize_using_file(&output_scale_file, &input);
+        let out_nodata = output_mag.configs.nodata;
+        output_scale.configs.data_type = DataType::I16;
+        let neutral = if residual == "dev" { 0f64 } else { 50f64 };
+
+        let num_procs = num_cpus::get() as isize;
+        let strip_size = (rows + num_procs - 1) / num_procs;
+        for (k, &scale) in scales.iter().enumerate() {
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let input = input.clone();
+                let sat = sat.clone();
+                let bins = bins.clone();
+                let residual = residual.clone();
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    if residual == "dev" {
+                        let mut z: f64;
+                        for row in (0..rows).filter(|r| r % num_procs == tid) {
+                            let mut data = vec![nodata; columns as usize];
+                            for col in 0..columns {
+                                z = input.get_value(row, col);
+                                if z != nodata {
+                                    let (y1, x1) = (row - scale, col - scale);
+                                    let (y2, x2) = (row + scale, col + scale);
+                                    let mean = sat.mean(y1, x1, y2, x2);
+                                    data[col as usize] = match (mean, sat.std_dev(y1, x1, y2, x2)) {
+                                        (Some(mean), Some(s)) if s > 0f64 => (z - mean) / s,
+                                        _ => 0f64,
+                                    };
+                                }
+                            }
+                            tx.send((row, data)).unwrap();
+                        }
+                    } else {
+                        let first_row = (tid * strip_size).min(rows) as usize;
+                        let last_row = ((tid + 1) * strip_size).min(rows) as usize;
+                        rank_filter(
+                            &bins,
+                            rows as usize,
+                            columns as usize,
+                            scale as usize,
+                            scale as usize,
+                            NUM_BINS,
+                            first_row..last_row,
+                            RankStatistic::CountBelow,
+                            |row, below| {
+                                let row = row as isize;
+                                let mut data = vec![nodata; columns as usize];
+                                for col in 0..columns {
+                                    if below[col as usize] != NO_BIN {
+                                        let n = sat.count(
+                                            row - scale,
+                                            col - scale,
+                                            row + scale,
+                                            col + scale,
+                                        );
+                                        data[col as usize] =
+                                            100f64 * below[col as usize] as f64 / n as f64;
+                                    }
+                                }
+                                tx.send((row, data)).unwrap();
+                            },
+                        );
+                    }
+                });
+            }
+
+            let mut z: f64;
+            for r in 0..rows {
+                let (row, data) = rx.recv().unwrap();
+                for col in 0..columns {
+                    z = data[col as usize];
+                    if z != nodata {
+                        let current = output_mag.get_value(row, col);
+                        if current == out_nodata
+                            || (z - neutral).abs() > (current - neutral).abs()
+                        {
+                            output_mag.set_value(row, col, z);
+                            output_scale.set_value(row, col, scale as f64);
+                        }
+                    }
+                }
+                if let Some(row_sites) = sites_by_row.get(&row) {
+                    for &(site, col) in row_sites {
+                        signatures[site][k] = data[col as usize];
+                    }
+                }
+                if verbose {
+                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        report::progress(
+                            &format!("Progress (Loop {} of {})", k + 1, scales.len()),
+                            progress,
+                        );
+                        old_progress = progress;
+                    }
+                }
+            }
+        }
+
+        if !signatures_file.is_empty() {
+            let f = File::create(signatures_file.clone())?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all("FID,X,Y,SCALE,RESIDUAL\n".as_bytes())?;
+            for (site, &(fid, x, y)) in sites.iter().enumerate() {
+                for (k, &scale) in scales.iter().enumerate() {
+                    let value = signatures[site][k];
+                    writer.write_all(
+                        format!(
+                            "{},{},{},{},{}\n",
+                            fid,
+                            x,
+                            y,
+                            scale,
+                            if value != nodata {
+                                value.to_string()
+                            } else {
+                                String::new()
+                            }
+                        ).as_bytes(),
+                    )?;
+                }
+            }
+            writer.flush()?;
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if residual == "dev" {
+            output_mag.configs.display_min = -3.0;
+            output_mag.configs.display_max = 3.0;
+        } else {
+            output_mag.configs.display_min = 0.0;
+            output_mag.configs.display_max = 100.0;
+        }
+        output_mag.configs.palette = "blue_white_red.plt".to_string();
+        output_scale.configs.palette = "spectrum.plt".to_string();
+        let scale_list = scales
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        for output in [&mut output_mag, &mut output_scale].iter_mut() {
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            output.add_metadata_entry(format!("Residual: {}", residual));
+            output.add_metadata_entry(format!("Neighbourhood radii: {}", scale_list));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        if verbose {
+            report::message("Saving data...")
+        };
+        let _ = match output_mag.write() {
+            Ok(_) => if verbose {
+                report::message("Output magnitude file written")
+            },
+            Err(e) => return Err(e),
+        };
+        let _ = match output_scale.write() {
+            Ok(_) => if verbose {
+                report::message("Output scale file written")
+            },
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
@@ -167,57 +167,28 @@ impl WhiteboxTool for MultiscaleRoughness {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_mag" {
-                output_mag_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-out_scale" {
-                output_scale_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-min_scale" {
-                min_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-                if min_scale < 1 {
-                    min_scale = 1;
-                }
-            } else if flag_val == "-max_scale" {
-                max_scale = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
-            } else if flag_val == "-step" {
-                step = if keyval {
-                    vec[1].to_string().parse::<isize>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<isize>().unwrap()
-                };
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_mag"])? {
+            output_mag_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_scale"])? {
+            output_scale_file = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--min_scale"])? {
+            min_scale = v;
+            if min_scale < 1 {
+                min_scale = 1;
             }
         }
+        if let Some(v) = tool_args.get_isize(&["--max_scale"])? {
+            max_scale = v;
+        }
+        if let Some(v) = tool_args.get_isize(&["--step"])? {
+            step = v;
+        }
 
         if max_scale < min_scale {
             let ms = min_scale;
@@ -234,9 +205,7 @@ impl WhiteboxTool for MultiscaleRoughness {
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,7 +224,7 @@ impl WhiteboxTool for MultiscaleRoughness {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let start = Instant::now();
@@ -344,7 +313,7 @@ impl WhiteboxTool for MultiscaleRoughness {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    report::progress("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
@@ -385,7 +354,7 @@ impl WhiteboxTool for MultiscaleRoughness {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    report::progress("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -405,7 +374,7 @@ impl WhiteboxTool for MultiscaleRoughness {
             // .step_by(step) { once step_by is stabilized
             // loop_num += 1;
 
-            println!("Loop {} / {}", midpoint - min_scale, max_scale - min_scale);
+            report::message(&format!("Loop {} / {}", midpoint - min_scale, max_scale - min_scale));
 
             ////////////////////////////////////////////////////////////////////////////
             // Use the integral image to smooth the DEM at a scale of the filter size //
@@ -582,7 +551,9 @@ impl WhiteboxTool for MultiscaleRoughness {
             for _ in 0..rows {
                 let (row, data) = rx.recv().unwrap();
                 for col in 0..columns {
-                    if data[col as usize] > output_mag.get_value(row, col) {
+                    if data[col as usize] != nodata
+                        && data[col as usize] > output_mag.get_value(row, col)
+                    {
                         output_mag.set_value(row, col, data[col as usize]);
                         output_scale.set_value(row, col, midpoint as f64);
                     }
@@ -603,12 +574,12 @@ impl WhiteboxTool for MultiscaleRoughness {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            report::message("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -626,22 +597,19 @@ impl WhiteboxTool for MultiscaleRoughness {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            report::message("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    report::message("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -154,54 +154,25 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-broad" || vec[0].to_lowercase() == "--broad" {
-                if keyval {
-                    input1_file = vec[1].to_string();
-                } else {
-                    input1_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-meso" || vec[0].to_lowercase() == "--meso" {
-                if keyval {
-                    input2_file = vec[1].to_string();
-                } else {
-                    input2_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-local" || vec[0].to_lowercase() == "--local" {
-                if keyval {
-                    input3_file = vec[1].to_string();
-                } else {
-                    input3_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-lightness"
-                || vec[0].to_lowercase() == "--lightness"
-            {
-                if keyval {
-                    cutoff = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    cutoff = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--broad"])? {
+            input1_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--meso"])? {
+            input2_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--local"])? {
+            input3_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--lightness"])? {
+            cutoff = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -223,15 +194,15 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         }
 
         if verbose {
-            println!("Reading broad-scale DEV data...")
+            report::message("Reading broad-scale DEV data...")
         };
         let input_r = Arc::new(Raster::new(&input1_file, "r")?);
         if verbose {
-            println!("Reading meso-scale DEV data...")
+            report::message("Reading meso-scale DEV data...")
         };
         let input_g = Arc::new(Raster::new(&input2_file, "r")?);
         if verbose {
-            println!("Reading local-scale DEV data...")
+            report::message("Reading local-scale DEV data...")
         };
         let input_b = Arc::new(Raster::new(&input3_file, "r")?);
 
@@ -255,7 +226,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
         if input_r.configs.rows != input_b.configs.rows
@@ -263,10 +234,13 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
+        let mut output = Raster::initialize_using_file(&output_file, &input_r);
+        let out_nodata = output.configs.nodata;
+
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
@@ -280,7 +254,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
                 let mut blue_val: f64;
                 let (mut red, mut green, mut blue): (u32, u32, u32);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata_r; columns as usize];
+                    let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         red_val = input_r[(row, col)];
                         green_val = input_g[(row, col)];
@@ -329,7 +303,6 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &input_r);
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::I32;
         for row in 0..rows {
@@ -338,7 +311,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -355,19 +328,16 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
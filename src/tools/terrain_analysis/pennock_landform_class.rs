@@ -170,61 +170,28 @@ impl WhiteboxTool for PennockLandformClass {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-zfactor" || vec[0].to_lowercase() == "--zfactor" {
-                if keyval {
-                    z_factor = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    z_factor = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-slope" || vec[0].to_lowercase() == "--slope" {
-                if keyval {
-                    slope_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    slope_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-prof" || vec[0].to_lowercase() == "--prof" {
-                if keyval {
-                    prof_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    prof_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            } else if vec[0].to_lowercase() == "-plan" || vec[0].to_lowercase() == "--plan" {
-                if keyval {
-                    plan_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    plan_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+            z_factor = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--slope"])? {
+            slope_threshold = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--prof"])? {
+            prof_threshold = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--plan"])? {
+            plan_threshold = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -240,7 +207,7 @@ impl WhiteboxTool for PennockLandformClass {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -389,7 +356,7 @@ impl WhiteboxTool for PennockLandformClass {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -416,30 +383,27 @@ impl WhiteboxTool for PennockLandformClass {
         output.add_metadata_entry(format!("6      Divergent Backslope"));
         output.add_metadata_entry(format!("7      Level"));
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!("CLASSIFICATION KEY");
-            println!("Value  Class");
-            println!("1      Convergent Footslope");
-            println!("2      Divergent Footslope");
-            println!("3      Convergent Shoulder");
-            println!("4      Divergent Shoulder");
-            println!("5      Convergent Backslope");
-            println!("6      Divergent Backslope");
-            println!("7      Level");
-
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::message("CLASSIFICATION KEY");
+            report::message("Value  Class");
+            report::message("1      Convergent Footslope");
+            report::message("2      Divergent Footslope");
+            report::message("3      Convergent Shoulder");
+            report::message("4      Divergent Shoulder");
+            report::message("5      Convergent Backslope");
+            report::message("6      Divergent Backslope");
+            report::message("7      Level");
+
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
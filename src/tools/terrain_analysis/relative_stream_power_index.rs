@@ -144,47 +144,22 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-sca" || vec[0].to_lowercase() == "--sca" {
-                if keyval {
-                    sca_file = vec[1].to_string();
-                } else {
-                    sca_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-slope" || vec[0].to_lowercase() == "--slope" {
-                if keyval {
-                    slope_file = vec[1].to_string();
-                } else {
-                    slope_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-exponent" || vec[0].to_lowercase() == "--exponent"
-            {
-                if keyval {
-                    sca_exponent = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    sca_exponent = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--sca"])? {
+            sca_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--slope"])? {
+            slope_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--exponent"])? {
+            sca_exponent = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,7 +178,7 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let sca = Arc::new(Raster::new(&sca_file, "r")?);
         let slope = Arc::new(Raster::new(&slope_file, "r")?);
@@ -218,10 +193,13 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
         if sca.configs.rows != slope.configs.rows || sca.configs.columns != slope.configs.columns {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                "The input files must have the same number of rows and columns and spatial extent.",
+                "The input files must have the same number of rows and columns and spatial extent; the Resample tool (--base) may be used to align them.",
             ));
         }
 
+        let mut output = Raster::initialize_using_file(&output_file, &sca);
+        let out_nodata = output.configs.nodata;
+
         // calculate the number of downslope cells
         let num_procs = num_cpus::get() as isize;
         let (tx, rx) = mpsc::channel();
@@ -233,7 +211,7 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
                 let mut sca_val: f64;
                 let mut slope_val: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data: Vec<f64> = vec![sca_nodata; columns as usize];
+                    let mut data: Vec<f64> = vec![out_nodata; columns as usize];
                     for col in 0..columns {
                         sca_val = sca[(row, col)];
                         slope_val = slope[(row, col)];
@@ -247,7 +225,6 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
             });
         }
 
-        let mut output = Raster::initialize_using_file(&output_file, &sca);
         for r in 0..rows {
             let (row, data) = rx.recv().unwrap();
             output.set_row_data(row, data);
@@ -255,7 +232,7 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    report::progress("Progress", progress);
                     old_progress = progress;
                 }
             }
@@ -276,24 +253,21 @@ impl WhiteboxTool for RelativeStreamPowerIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
 
         if sca.configs.maximum < 100.0 {
-            println!("WARNING: The input SCA data layer contained only low values. It is likely that it has been
+            report::warning("The input SCA data layer contained only low values. It is likely that it has been \
             log-transformed. This tool requires non-transformed SCA as an input.")
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
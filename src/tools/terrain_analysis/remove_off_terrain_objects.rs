@@ -138,55 +138,27 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         let mut output_file = String::new();
         let mut filter_size = 11usize;
         let mut slope_threshold = 15f64;
-        let mut keyval: bool;
         if args.len() == 0 {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-                if keyval {
-                    filter_size = vec[1].to_string().parse::<f32>().unwrap() as usize;
-                } else {
-                    filter_size = args[i + 1].to_string().parse::<f32>().unwrap() as usize;
-                }
-            } else if vec[0].to_lowercase() == "-slope" || vec[0].to_lowercase() == "--slope" {
-                if keyval {
-                    slope_threshold = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    slope_threshold = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_usize(&["--filter"])? {
+            filter_size = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--slope"])? {
+            slope_threshold = v;
         }
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -211,7 +183,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -241,7 +213,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         {
             // This additional scope is simply to ensure that erosion is cleaned up at the end of the white tophat transform.
             if verbose {
-                println!("Performing tophat transform...")
+                report::message("Performing tophat transform...")
             };
             let mut erosion: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
             for row in 0..rows {
@@ -296,7 +268,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing erosion: {}%", progress);
+                        report::progress("Performing erosion", progress);
                         old_progress = progress;
                     }
                 }
@@ -349,7 +321,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing dilation: {}%", progress);
+                        report::progress("Performing dilation", progress);
                         old_progress = progress;
                     }
                 }
@@ -358,7 +330,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Back-fill the shallow hills using region growing
         if verbose {
-            println!("Backfilling hills...")
+            report::message("Backfilling hills...")
         };
         let initial_value = f64::NEG_INFINITY;
         let mut out: Array2D<f64> = Array2D::new(rows, columns, initial_value, nodata)?;
@@ -384,7 +356,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding seed cells: {}%", progress);
+                    report::progress("Finding seed cells", progress);
                     old_progress = progress;
                 }
             }
@@ -413,7 +385,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Interpolate the data holes. Start by locating all the edge cells.
         if verbose {
-            println!("Interpolating data holes...")
+            report::message("Interpolating data holes...")
         };
         let mut frs: FixedRadiusSearch2D<f64> = FixedRadiusSearch2D::new(
             filter_size as f64 / 1.5f64,
@@ -440,7 +412,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding OTO edge cells: {}%", progress);
+                    report::progress("Finding OTO edge cells", progress);
                     old_progress = progress;
                 }
             }
@@ -478,7 +450,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Interpolating data holes: {}%", progress);
+                    report::progress("Interpolating data holes", progress);
                     old_progress = progress;
                 }
             }
@@ -488,18 +460,19 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Finally, output the new raster
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        let out_nodata = output.configs.nodata;
         for row in 0..rows {
             for col in 0..columns {
                 if out[(row, col)] != initial_value && input.get_value(row, col) != nodata {
                     output.set_value(row, col, out[(row, col)]);
                 } else {
-                    output.set_value(row, col, nodata);
+                    output.set_value(row, col, out_nodata);
                 }
             }
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Outputing data: {}%", progress);
+                    report::progress("Outputing data", progress);
                     old_progress = progress;
                 }
             }
@@ -514,19 +487,16 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         ///////////////////////////////////////////////////////////////////////////////////////////////
@@ -139,50 +139,23 @@ impl WhiteboxTool for Slope {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            if vec[0].to_lowercase() == "-i"
-                || vec[0].to_lowercase() == "--input"
-                || vec[0].to_lowercase() == "--dem"
-            {
-                if keyval {
-                    input_file = vec[1].to_string();
-                } else {
-                    input_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-                if keyval {
-                    output_file = vec[1].to_string();
-                } else {
-                    output_file = args[i + 1].to_string();
-                }
-            } else if vec[0].to_lowercase() == "-zfactor" || vec[0].to_lowercase() == "--zfactor" {
-                if keyval {
-                    z_factor = vec[1].to_string().parse::<f64>().unwrap();
-                } else {
-                    z_factor = args[i + 1].to_string().parse::<f64>().unwrap();
-                }
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--zfactor"])? {
+            z_factor = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
 
-        let mut progress: usize;
-        let mut old_progress: usize = 1;
-
         if !input_file.contains(&sep) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
@@ -191,79 +164,17 @@ impl WhiteboxTool for Slope {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
-        let eight_grid_res = input.configs.resolution_x * 8.0;
-
-        if input.is_in_geographic_coordinates() {
-            // calculate a new z-conversion factor
-            let mut mid_lat = (input.configs.north - input.configs.south) / 2.0;
-            if mid_lat <= 90.0 && mid_lat >= -90.0 {
-                mid_lat = mid_lat.to_radians();
-                z_factor = 1.0 / (113200.0 * mid_lat.cos());
-            }
-        }
-
-        let mut output = Raster::initialize_using_file(&output_file, &input);
-        let rows = input.configs.rows as isize;
-
-        let num_procs = num_cpus::get() as isize;
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let input = input.clone();
-            let tx1 = tx.clone();
-            thread::spawn(move || {
-                let nodata = input.configs.nodata;
-                let columns = input.configs.columns as isize;
-                let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
-                let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
-                let mut n: [f64; 8] = [0.0; 8];
-                let mut z: f64;
-                let (mut fx, mut fy): (f64, f64);
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = vec![nodata; columns as usize];
-                    for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != nodata {
-                            for c in 0..8 {
-                                n[c] = input[(row + d_y[c], col + d_x[c])];
-                                if n[c] != nodata {
-                                    n[c] = n[c] * z_factor;
-                                } else {
-                                    n[c] = z * z_factor;
-                                }
-                            }
-                            // calculate slope
-                            fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
-                            fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
-                            data[col as usize] = (fx * fx + fy * fy).sqrt().atan().to_degrees();
-                        }
-                    }
-                    tx1.send((row, data)).unwrap();
-                }
-            });
-        }
-
-        for row in 0..rows {
-            let data = rx.recv().unwrap();
-            output.set_row_data(data.0, data.1);
-
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
+        let mut output = slope(input, z_factor, verbose);
+        output.set_file_name(&output_file);
 
         let elapsed_time = get_formatted_elapsed_time(start);
-        output.configs.palette = "spectrum_soft.plt".to_string();
         output.add_metadata_entry(format!(
             "Created by whitebox_tools\' {} tool",
             self.get_tool_name()
@@ -273,21 +184,95 @@ impl WhiteboxTool for Slope {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
     }
 }
+
+/// Calculates the slope gradient, in degrees, of each grid cell of a DEM. Elevations are
+/// multiplied by `z_factor`, unless the DEM is in geographic coordinates, in which case
+/// the factor is estimated from the latitude of the DEM.
+pub fn slope(input: Arc<Raster>, z_factor: f64, verbose: bool) -> Raster {
+    let mut z_factor = z_factor;
+    let mut progress: usize;
+    let mut old_progress: usize = 1;
+
+    let eight_grid_res = input.configs.resolution_x * 8.0;
+
+    if input.is_in_geographic_coordinates() {
+        // calculate a new z-conversion factor
+        let mut mid_lat = (input.configs.north - input.configs.south) / 2.0;
+        if mid_lat <= 90.0 && mid_lat >= -90.0 {
+            mid_lat = mid_lat.to_radians();
+            z_factor = 1.0 / (113200.0 * mid_lat.cos());
+        }
+    }
+
+    let mut output = Raster::initialize_using_raster(&input);
+    let out_nodata = output.configs.nodata;
+    let rows = input.configs.rows as isize;
+
+    let num_procs = num_cpus::get() as isize;
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let input = input.clone();
+        let tx1 = tx.clone();
+        thread::spawn(move || {
+            let nodata = input.configs.nodata;
+            let columns = input.configs.columns as isize;
+            let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+            let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+            let mut n: [f64; 8] = [0.0; 8];
+            let mut z: f64;
+            let (mut fx, mut fy): (f64, f64);
+            for row in (0..rows).filter(|r| r % num_procs == tid) {
+                let mut data = vec![out_nodata; columns as usize];
+                for col in 0..columns {
+                    z = input[(row, col)];
+                    if z != nodata {
+                        for c in 0..8 {
+                            n[c] = input[(row + d_y[c], col + d_x[c])];
+                            if n[c] != nodata {
+                                n[c] = n[c] * z_factor;
+                            } else {
+                                n[c] = z * z_factor;
+                            }
+                        }
+                        // calculate slope
+                        fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
+                        fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
+                        data[col as usize] = (fx * fx + fy * fy).sqrt().atan().to_degrees();
+                    }
+                }
+                tx1.send((row, data)).unwrap();
+            }
+        });
+    }
+
+    for row in 0..rows {
+        let data = rx.recv().unwrap();
+        output.set_row_data(data.0, data.1);
+
+        if verbose {
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                report::progress("Performing analysis", progress);
+                old_progress = progress;
+            }
+        }
+    }
+    output.configs.palette = "spectrum_soft.plt".to_string();
+
+    output
+}
@@ -144,41 +144,19 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-inputs" {
-                input_files_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-watershed" {
-                watershed_files_str = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--inputs"])? {
+            input_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--watershed"])? {
+            watershed_files_str = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -310,7 +288,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Slope analysis: {}%", progress);
+                            report::progress("Slope analysis", progress);
                             old_progress = progress;
                         }
                     }
@@ -341,7 +319,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
+                            report::progress("Binning the data", progress);
                             old_progress = progress;
                         }
                     }
@@ -453,7 +431,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Slope analysis: {}%", progress);
+                            report::progress("Slope analysis", progress);
                             old_progress = progress;
                         }
                     }
@@ -475,7 +453,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 let watershed_min = watershed.configs.minimum;
                 let watershed_max = watershed.configs.maximum;
                 let num_watersheds = (watershed_max - watershed_min) as usize + 1;
-                println!("Num. Watersheds {}", num_watersheds);
+                report::message(&format!("Num. Watersheds {}", num_watersheds));
 
                 // get the number of watersheds, and the min and max elev for each watershed
                 let mut min_elevs = vec![f64::INFINITY; num_watersheds];
@@ -502,7 +480,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Processing watershed data: {}%", progress);
+                            report::progress("Processing watershed data", progress);
                             old_progress = progress;
                         }
                     }
@@ -555,7 +533,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
+                            report::progress("Binning the data", progress);
                             old_progress = progress;
                         }
                     }
@@ -614,10 +592,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            report::message(&format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -645,7 +623,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            report::message(&format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -0,0 +1,443 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use raster::*;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use structures::{Array2D, Point2D, Polyline};
+use tools::*;
+use vector::*;
+
+/// This tool extracts a swath profile from a raster surface (`--surface`), typically
+/// a digital elevation model (DEM). Swath profiles summarize the distribution of
+/// elevations within a corridor of a specified `--width` centred on a vector
+/// centerline (`--lines`), and are commonly used in tectonic geomorphology to
+/// characterize regional topography along a transect.
+///
+/// Each grid cell within the corridor is projected onto the nearest centerline and
+/// assigned to a bin based on its distance along the line. Bins are `--bin_size` map
+/// units long (the default is the grid resolution). For each bin, the minimum, first
+/// quartile, median, mean, third quartile, maximum, and standard deviation of the
+/// surface values are written to the output CSV file (`--output`). When the input
+/// file contains multiple line features, each feature (and part) is treated as a
+/// separate swath centerline and is identified by the `LINE_ID` and `PART` columns.
+///
+/// Optionally, a raster (`--out_raster`) can be output in which each cell within a
+/// swath is assigned its bin number, which is useful for visualizing the extent of
+/// the swath and the bin arrangement.
+///
+/// # See Also
+/// `ExtractProfile`, `Profile`
+pub struct SwathProfile {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SwathProfile {
+    pub fn new() -> SwathProfile {
+        // public constructor
+        let name = "SwathProfile".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Calculates binned surface statistics within a corridor along a vector centerline."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Line File".to_owned(),
+            flags: vec!["--lines".to_owned()],
+            description: "Input vector centerline file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Surface File".to_owned(),
+            flags: vec!["--surface".to_owned()],
+            description: "Input raster surface file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Swath Width".to_owned(),
+            flags: vec!["--width".to_owned()],
+            description: "Total width of the swath corridor, in map units.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Bin Size".to_owned(),
+            flags: vec!["--bin_size".to_owned()],
+            description: "Length of bins along the centerline, in map units; defaults to the grid resolution.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Swath Raster File (optional)".to_owned(),
+            flags: vec!["--out_raster".to_owned()],
+            description: "Optional output raster file of swath bin numbers.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --lines=transect.shp --surface=dem.tif -o=swath.csv --width=5000.0 --bin_size=100.0 --out_raster=swath.tif", short_exe, name).replace("*", &sep);
+
+        SwathProfile {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SwathProfile {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        let mut s = String::from("{\"parameters\": [");
+        for i in 0..self.parameters.len() {
+            if i < self.parameters.len() - 1 {
+                s.push_str(&(self.parameters[i].to_string()));
+                s.push_str(",");
+            } else {
+                s.push_str(&(self.parameters[i].to_string()));
+            }
+        }
+        s.push_str("]}");
+        s
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+    ) -> Result<(), Error> {
+        let mut lines_file = String::new();
+        let mut surface_file = String::new();
+        let mut output_file = String::new();
+        let mut raster_file = String::new();
+        let mut width = 0f64;
+        let mut bin_size = f64::NEG_INFINITY;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no paramters.",
+            ));
+        }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["--lines"])? {
+            lines_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--surface"])? {
+            surface_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--width"])? {
+            width = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--bin_size"])? {
+            bin_size = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--out_raster"])? {
+            raster_file = v;
+        }
+
+        if verbose {
+            report::welcome(&self.get_tool_name());
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !lines_file.contains(&sep) && !lines_file.contains("/") {
+            lines_file = format!("{}{}", working_directory, lines_file);
+        }
+        if !surface_file.contains(&sep) && !surface_file.contains("/") {
+            surface_file = format!("{}{}", working_directory, surface_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        let output_raster = !raster_file.is_empty();
+        if output_raster && !raster_file.contains(&sep) && !raster_file.contains("/") {
+            raster_file = format!("{}{}", working_directory, raster_file);
+        }
+
+        if width <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The swath width must be greater than zero.",
+            ));
+        }
+        let half_width = width / 2f64;
+
+        if verbose {
+            report::message("Reading line data...")
+        };
+        let lines = Shapefile::read(&lines_file)?;
+
+        if verbose {
+            report::message("Reading surface data...")
+        };
+        let surface = Raster::new(&surface_file, "r")?;
+
+        let start = Instant::now();
+
+        // make sure the input vector file is of lines type
+        if lines.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polyline base shape type.",
+            ));
+        }
+
+        let rows = surface.configs.rows as isize;
+        let columns = surface.configs.columns as isize;
+        let nodata = surface.configs.nodata;
+        if bin_size <= 0f64 {
+            bin_size = surface.configs.resolution_x;
+        }
+
+        // Gather the centerlines; each part of each record is a separate swath.
+        let mut centerlines: Vec<Polyline> = vec![];
+        let mut line_ids: Vec<(usize, usize)> = vec![];
+        let mut start_point_in_part: usize;
+        let mut end_point_in_part: usize;
+        for record_num in 0..lines.num_records {
+            let record = lines.get_record(record_num);
+            for part in 0..record.num_parts as usize {
+                start_point_in_part = record.parts[part] as usize;
+                end_point_in_part = if part < record.num_parts as usize - 1 {
+                    record.parts[part + 1] as usize - 1
+                } else {
+                    record.num_points as usize - 1
+                };
+                centerlines.push(Polyline::new(
+                    &record.points[start_point_in_part..end_point_in_part + 1],
+                    record_num,
+                ));
+                line_ids.push((record_num + 1, part + 1));
+            }
+        }
+
+        // Assign each cell within a swath to its nearest centerline.
+        let mut min_dist: Array2D<f64> = Array2D::new(rows, columns, f64::INFINITY, -1f64)?;
+        let mut line_num: Array2D<i32> = Array2D::new(rows, columns, -1i32, -1i32)?;
+        let mut bin: Array2D<i32> = Array2D::new(rows, columns, -1i32, -1i32)?;
+        let (mut row_st, mut row_end, mut col_st, mut col_end): (isize, isize, isize, isize);
+        let mut z: f64;
+        let mut p: Point2D;
+        for i in 0..centerlines.len() {
+            let mut bb = centerlines[i].get_bounding_box();
+            bb.expand_by(half_width);
+            row_st = surface.get_row_from_y(bb.max_y).max(0);
+            row_end = surface.get_row_from_y(bb.min_y).min(rows - 1);
+            col_st = surface.get_column_from_x(bb.min_x).max(0);
+            col_end = surface.get_column_from_x(bb.max_x).min(columns - 1);
+            for row in row_st..row_end + 1 {
+                for col in col_st..col_end + 1 {
+                    z = surface.get_value(row, col);
+                    if z != nodata {
+                        p = Point2D::new(surface.get_x_from_column(col), surface.get_y_from_row(row));
+                        let (along, dist) = centerlines[i].nearest_position(&p);
+                        if dist <= half_width && dist < min_dist.get_value(row, col) {
+                            min_dist.set_value(row, col, dist);
+                            line_num.set_value(row, col, i as i32);
+                            bin.set_value(row, col, (along / bin_size).floor() as i32);
+                        }
+                    }
+                }
+            }
+
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / centerlines.len() as f64) as usize;
+                if progress != old_progress {
+                    report::progress("Progress", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Collect the surface values within each bin of each swath.
+        let mut bin_values: Vec<Vec<Vec<f64>>> = Vec::with_capacity(centerlines.len());
+        for i in 0..centerlines.len() {
+            let num_bins = (centerlines[i].length() / bin_size).floor() as usize + 1;
+            bin_values.push(vec![vec![]; num_bins]);
+        }
+        let (mut n, mut b): (i32, i32);
+        for row in 0..rows {
+            for col in 0..columns {
+                n = line_num.get_value(row, col);
+                if n >= 0 {
+                    b = bin.get_value(row, col);
+                    bin_values[n as usize][b as usize].push(surface.get_value(row, col));
+                }
+            }
+        }
+
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all(
+            "LINE_ID,PART,BIN,DIST_START,DIST_MID,N,MIN,Q1,MEDIAN,MEAN,Q3,MAX,STDEV\n".as_bytes(),
+        )?;
+        let (mut mean, mut stdev): (f64, f64);
+        for i in 0..centerlines.len() {
+            for j in 0..bin_values[i].len() {
+                let values = &mut bin_values[i][j];
+                if values.len() == 0 {
+                    continue;
+                }
+                values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                mean = values.iter().sum::<f64>() / values.len() as f64;
+                stdev = (values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>()
+                    / values.len() as f64)
+                    .sqrt();
+                writer.write_all(
+                    format!(
+                        "{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                        line_ids[i].0,
+                        line_ids[i].1,
+                        j + 1,
+                        j as f64 * bin_size,
+                        (j as f64 + 0.5) * bin_size,
+                        values.len(),
+                        values[0],
+                        percentile(&values, 25f64),
+                        percentile(&values, 50f64),
+                        mean,
+                        percentile(&values, 75f64),
+                        values[values.len() - 1],
+                        stdev
+                    ).as_bytes(),
+                )?;
+            }
+        }
+
+        let _ = writer.flush();
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if output_raster {
+            let mut output = Raster::initialize_using_file(&raster_file, &surface);
+            output.configs.nodata = -32768f64;
+            output.configs.data_type = DataType::I32;
+            output.reinitialize_values(-32768f64);
+            for row in 0..rows {
+                for col in 0..columns {
+                    b = bin.get_value(row, col);
+                    if b >= 0 {
+                        output.set_value(row, col, (b + 1) as f64);
+                    }
+                }
+            }
+            output.configs.palette = "qual.plt".to_string();
+            output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input lines file: {}", lines_file));
+            output.add_metadata_entry(format!("Input surface file: {}", surface_file));
+            output.add_metadata_entry(format!("Swath width: {}", width));
+            output.add_metadata_entry(format!("Bin size: {}", bin_size));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+            if verbose {
+                report::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => if verbose {
+                    report::message("Output file written")
+                },
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the percentile of a sorted list of values using linear interpolation.
+fn percentile(sorted_values: &[f64], percent: f64) -> f64 {
+    let n = sorted_values.len();
+    if n == 1 {
+        return sorted_values[0];
+    }
+    let rank = percent / 100f64 * (n - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted_values[lower] + (rank - lower as f64) * (sorted_values[upper] - sorted_values[lower])
+}
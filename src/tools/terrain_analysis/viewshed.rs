@@ -159,47 +159,22 @@ impl WhiteboxTool for Viewshed {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-stations" || flag_val == "-station" {
-                stations_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-height" {
-                height = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap()
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["--stations", "--station"])? {
+            stations_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--height"])? {
+            height = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let num_procs = num_cpus::get() as isize;
@@ -220,14 +195,14 @@ impl WhiteboxTool for Viewshed {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let dem = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         if height < 0f64 {
-            println!("Warning: Input station height cannot be less than zero.");
+            report::warning("Input station height cannot be less than zero.");
             height = 0f64;
         }
 
@@ -280,7 +255,7 @@ impl WhiteboxTool for Viewshed {
                 progress =
                     (100.0_f64 * record_num as f64 / (stations.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating view stations: {}%", progress);
+                    report::progress("Locating view stations", progress);
                     old_progress = progress;
                 }
             }
@@ -294,7 +269,7 @@ impl WhiteboxTool for Viewshed {
         let num_stn = station_x.len();
         while !station_x.is_empty() {
             stn_num += 1;
-            println!("Station {} of {}", stn_num, num_stn);
+            report::message(&format!("Station {} of {}", stn_num, num_stn));
 
             stn_x = station_x.pop().unwrap();
             stn_col = dem.get_column_from_x(stn_x);
@@ -348,9 +323,12 @@ impl WhiteboxTool for Viewshed {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Calculating view angle (Station {} of {}): {}%",
-                            stn_num, num_stn, progress
+                        report::progress(
+                            &format!(
+                                "Calculating view angle (Station {} of {})",
+                                stn_num, num_stn
+                            ),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -650,9 +628,9 @@ impl WhiteboxTool for Viewshed {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Creating output: (Station {} of {}): {}%",
-                            stn_num, num_stn, progress
+                        report::progress(
+                            &format!("Creating output: (Station {} of {})", stn_num, num_stn),
+                            progress,
                         );
                         old_progress = progress;
                     }
@@ -669,19 +647,16 @@ impl WhiteboxTool for Viewshed {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
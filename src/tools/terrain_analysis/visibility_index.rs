@@ -166,47 +166,22 @@ impl WhiteboxTool for VisibilityIndex {
                 "Tool run with no paramters.",
             ));
         }
-        for i in 0..args.len() {
-            let mut arg = args[i].replace("\"", "");
-            arg = arg.replace("\'", "");
-            let cmd = arg.split("="); // in case an equals sign was used
-            let vec = cmd.collect::<Vec<&str>>();
-            let mut keyval = false;
-            if vec.len() > 1 {
-                keyval = true;
-            }
-            let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
-                input_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-o" || flag_val == "-output" {
-                output_file = if keyval {
-                    vec[1].to_string()
-                } else {
-                    args[i + 1].to_string()
-                };
-            } else if flag_val == "-height" {
-                height = if keyval {
-                    vec[1].to_string().parse::<f32>().unwrap()
-                } else {
-                    args[i + 1].to_string().parse::<f32>().unwrap()
-                };
-            } else if flag_val == "-res_factor" {
-                res_factor = if keyval {
-                    vec[1].to_string().parse::<f64>().unwrap() as isize
-                } else {
-                    args[i + 1].to_string().parse::<f64>().unwrap() as isize
-                };
-            }
+        let tool_args = ToolArgs::new(&self.parameters, &args);
+        if let Some(v) = tool_args.get_string(&["-i", "--input", "--dem"])? {
+            input_file = v;
+        }
+        if let Some(v) = tool_args.get_string(&["-o", "--output"])? {
+            output_file = v;
+        }
+        if let Some(v) = tool_args.get_f64(&["--height"])? {
+            height = v as f32;
+        }
+        if let Some(v) = tool_args.get_isize(&["--res_factor"])? {
+            res_factor = v;
         }
 
         if verbose {
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
-            println!("* Welcome to {} *", self.get_tool_name());
-            println!("***************{}", "*".repeat(self.get_tool_name().len()));
+            report::welcome(&self.get_tool_name());
         }
 
         let num_procs = num_cpus::get() as isize;
@@ -224,14 +199,14 @@ impl WhiteboxTool for VisibilityIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            report::message("Reading data...")
         };
         let dem = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         if height < 0f32 {
-            println!("Warning: Input station height cannot be less than zero.");
+            report::warning("Input station height cannot be less than zero.");
             height = 0f32;
         }
 
@@ -247,7 +222,7 @@ impl WhiteboxTool for VisibilityIndex {
         let nodata = dem.configs.nodata;
 
         if verbose {
-            println!("Performing analysis. Please be patient...")
+            report::message("Performing analysis. Please be patient...")
         };
 
         let num_cells_tested =
@@ -595,7 +570,7 @@ impl WhiteboxTool for VisibilityIndex {
                                 let progress = (100.0_f64 * *num_cells_completed as f64
                                     / (num_cells_tested - 1f64))
                                     as usize;
-                                println!("Progress (Loop 1 of 2): {}%", progress);
+                                report::progress("Progress (Loop 1 of 2)", progress);
                             }
                         }
                     }
@@ -605,7 +580,7 @@ impl WhiteboxTool for VisibilityIndex {
                 if verbose {
                     let progress = (100.0_f64 * *num_cells_completed as f64
                         / (num_cells_tested - 1f64)) as usize;
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    report::progress("Progress (Loop 1 of 2)", progress);
                 }
                 tx.send(return_data).unwrap();
             });
@@ -635,7 +610,7 @@ impl WhiteboxTool for VisibilityIndex {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    report::progress("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -650,19 +625,16 @@ impl WhiteboxTool for VisibilityIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            report::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                report::message("Output file written")
             },
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            report::metadata("Elapsed Time (excluding I/O)", &elapsed_time);
         }
 
         Ok(())
@@ -0,0 +1,363 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! Executes a workflow, i.e. a directed acyclic graph (DAG) of tools, from a JSON file.
+//!
+//! A workflow file contains a list of steps, each naming a tool and its arguments.
+//! Argument names are the tool's parameter flags, with or without leading dashes.
+//! Boolean `true` values are passed as flags without a value and `false` values are
+//! omitted. An optional `working_directory` is used when the `--wd` flag is not
+//! specified.
+//!
+//! ```json
+//! {
+//!   "working_directory": "/path/to/data/",
+//!   "steps": [
+//!     { "id": "fill", "tool": "FillDepressions", "args": { "dem": "DEM.tif", "output": "memory://filled" } },
+//!     { "id": "pntr", "tool": "D8Pointer", "args": { "dem": "memory://filled", "output": "memory://pntr" } },
+//!     { "id": "accum", "tool": "D8FlowAccumulation", "args": { "dem": "memory://filled", "output": "memory://accum" } },
+//!     { "id": "streams", "tool": "ExtractStreams", "args": { "flow_accum": "memory://accum", "output": "streams.tif", "threshold": 100 } }
+//!   ]
+//! }
+//! ```
+//!
+//! Datasets whose names use the `memory://` scheme are intermediates held in memory
+//! (see `utils::memory_store`) rather than written to disk. A step that writes a
+//! dataset to one of its output (new file) parameters is the producer of that
+//! dataset and any step that reads it depends upon the producer; this applies to
+//! files on disk as well as in-memory datasets. The steps are
+//! executed in a topological order of these dependencies, preserving the order of
+//! the file where possible, and each intermediate is released from memory after
+//! its last consumer has run. Rasters, vectors (Shapefiles), and LiDAR (LAS) files may
+//! all be held in memory.
+
+use serde_json;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::time::Instant;
+use tools::{get_output_parameter_flags, unquote, ToolManager};
+use utils::get_formatted_elapsed_time;
+use utils::memory_store;
+use utils::report;
+
+struct WorkflowStep {
+    id: String,
+    tool: String,
+    args: Vec<(String, Option<String>)>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl ToolManager {
+    /// Runs the workflow contained within the JSON file `file_name`.
+    pub fn run_workflow(&self, file_name: String) -> Result<(), Error> {
+        let start = Instant::now();
+        let sep = path::MAIN_SEPARATOR.to_string();
+
+        let mut file_name = file_name;
+        if !file_name.contains(&sep) && !file_name.contains("/") {
+            file_name = format!("{}{}", self.working_dir, file_name);
+        }
+        let mut contents = String::new();
+        File::open(&file_name)?.read_to_string(&mut contents)?;
+        let workflow: Value = match serde_json::from_str(&contents) {
+            Ok(v) => v,
+            Err(e) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Error parsing workflow file: {}", e),
+                ))
+            }
+        };
+
+        let mut working_dir = self.working_dir.clone();
+        if working_dir.trim() == sep || working_dir.trim().is_empty() {
+            if let Some(wd) = workflow["working_directory"].as_str() {
+                working_dir = wd.to_string();
+                if !working_dir.ends_with(&sep) {
+                    working_dir.push_str(&sep);
+                }
+            }
+        }
+        let tm = ToolManager::new(&working_dir, &self.verbose)?;
+
+        let steps = match workflow["steps"].as_array() {
+            Some(a) => {
+                let mut steps = Vec::with_capacity(a.len());
+                for i in 0..a.len() {
+                    steps.push(tm.parse_workflow_step(&a[i], i)?);
+                }
+                steps
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The workflow file does not contain a 'steps' array.",
+                ))
+            }
+        };
+        let num_steps = steps.len();
+
+        // find the producer of each dataset
+        let mut producers: HashMap<String, usize> = HashMap::new();
+        for i in 0..num_steps {
+            for d in &steps[i].outputs {
+                if let Some(j) = producers.insert(d.clone(), i) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Dataset '{}' is produced by more than one step ('{}' and '{}').",
+                            d, steps[j].id, steps[i].id
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // build the dependency graph
+        let mut dependents: Vec<Vec<usize>> = vec![vec![]; num_steps];
+        let mut num_dependencies = vec![0usize; num_steps];
+        for i in 0..num_steps {
+            let mut deps: Vec<usize> = vec![];
+            for d in &steps[i].inputs {
+                match producers.get(d) {
+                    Some(&j) => {
+                        if j == i {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                format!("Step '{}' both reads and writes '{}'.", steps[i].id, d),
+                            ));
+                        }
+                        if !deps.contains(&j) {
+                            deps.push(j);
+                        }
+                    }
+                    None => {
+                        if memory_store::is_memory_dataset(d) {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                format!(
+                                    "In-memory dataset '{}' used by step '{}' is not produced by any step.",
+                                    d, steps[i].id
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+            num_dependencies[i] = deps.len();
+            for j in deps {
+                dependents[j].push(i);
+            }
+        }
+
+        // topological sort; ties are broken by the order of the steps in the file
+        let mut order: Vec<usize> = Vec::with_capacity(num_steps);
+        let mut scheduled = vec![false; num_steps];
+        while order.len() < num_steps {
+            let next = (0..num_steps).find(|&i| !scheduled[i] && num_dependencies[i] == 0);
+            match next {
+                Some(i) => {
+                    scheduled[i] = true;
+                    order.push(i);
+                    for &j in &dependents[i] {
+                        num_dependencies[j] -= 1;
+                    }
+                }
+                None => {
+                    let ids: Vec<String> = (0..num_steps)
+                        .filter(|&i| !scheduled[i])
+                        .map(|i| steps[i].id.clone())
+                        .collect();
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "The workflow contains a cycle involving steps: {}",
+                            ids.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // the position in the run order after which each dataset may be released
+        let mut last_use: HashMap<String, usize> = HashMap::new();
+        for k in 0..num_steps {
+            let i = order[k];
+            for d in &steps[i].outputs {
+                last_use.entry(d.clone()).or_insert(k);
+            }
+            for d in &steps[i].inputs {
+                last_use.insert(d.clone(), k);
+            }
+        }
+        if self.verbose {
+            for d in producers.keys() {
+                if memory_store::is_memory_dataset(d)
+                    && steps.iter().all(|s| !s.inputs.contains(d))
+                {
+                    report::warning(&format!(
+                        "In-memory dataset '{}' is not used by any step and will be discarded.",
+                        d
+                    ));
+                }
+            }
+        }
+
+        let memory_datasets: Vec<String> = producers
+            .keys()
+            .filter(|d| memory_store::is_memory_dataset(d))
+            .cloned()
+            .collect();
+        let release_all = || {
+            for d in &memory_datasets {
+                memory_store::remove(d);
+            }
+        };
+        release_all();
+        for k in 0..num_steps {
+            let step = &steps[order[k]];
+            if self.verbose {
+                report::message(&format!(
+                    "\n[Workflow step {} of {}: {} ({})]",
+                    k + 1,
+                    num_steps,
+                    step.id,
+                    step.tool
+                ));
+            }
+            let args: Vec<String> = step
+                .args
+                .iter()
+                .map(|&(ref flag, ref val)| match val {
+                    &Some(ref v) => format!("{}={}", flag, v),
+                    &None => flag.clone(),
+                })
+                .collect();
+            let step_start = Instant::now();
+            if let Err(e) = tm.run_tool(step.tool.clone(), args) {
+                release_all();
+                return Err(Error::new(
+                    e.kind(),
+                    format!("Workflow step '{}' ({}) failed: {}", step.id, step.tool, e),
+                ));
+            }
+            for d in step.outputs.iter().chain(step.inputs.iter()) {
+                if memory_store::is_memory_dataset(d) && last_use.get(d) == Some(&k) {
+                    memory_store::remove(d);
+                }
+            }
+            if self.verbose {
+                report::message(&format!(
+                    "Step '{}' completed in {}",
+                    step.id,
+                    get_formatted_elapsed_time(step_start)
+                ));
+            }
+        }
+        release_all();
+
+        if self.verbose {
+            report::message(&format!(
+                "\nWorkflow completed ({} steps). Elapsed Time: {}",
+                num_steps,
+                get_formatted_elapsed_time(start)
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn parse_workflow_step(&self, value: &Value, index: usize) -> Result<WorkflowStep, Error> {
+        let sep = path::MAIN_SEPARATOR.to_string();
+        let id = match value["id"].as_str() {
+            Some(s) => s.to_string(),
+            None => format!("step{}", index + 1),
+        };
+        let tool_name = match value["tool"].as_str() {
+            Some(s) => s.to_string(),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Workflow step '{}' does not specify a tool.", id),
+                ))
+            }
+        };
+
+        // identify the tool's output (new file) parameters
+        let tool = match self.get_tool(&tool_name) {
+            Some(t) => t,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("Unrecognized tool name {} in step '{}'.", tool_name, id),
+                ))
+            }
+        };
+        let output_flags = get_output_parameter_flags(&tool);
+
+        let mut step = WorkflowStep {
+            id: id.clone(),
+            tool: tool_name,
+            args: vec![],
+            inputs: vec![],
+            outputs: vec![],
+        };
+        let args = match value["args"].as_object() {
+            Some(a) => a,
+            None => return Ok(step),
+        };
+        for (key, val) in args {
+            let flag = if key.starts_with("-") {
+                key.clone()
+            } else {
+                format!("--{}", key)
+            };
+            let v = match val {
+                &Value::String(ref s) => s.clone(),
+                &Value::Number(ref n) => n.to_string(),
+                &Value::Bool(true) => {
+                    step.args.push((flag, None));
+                    continue;
+                }
+                &Value::Bool(false) | &Value::Null => continue,
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Unsupported value for argument '{}' in step '{}'.", key, id),
+                    ))
+                }
+            };
+            // Datasets are identified by their names for in-memory datasets and by the
+            // full path for files. File lists may contain several datasets.
+            let is_output = output_flags.contains(&flag.to_lowercase().replace("--", "-"));
+            for f in v.split(|c| c == ',' || c == ';') {
+                let f = unquote(f);
+                if f.is_empty() {
+                    continue;
+                }
+                let name = if !f.contains(&sep) && !f.contains("/") {
+                    format!("{}{}", self.working_dir, f)
+                } else {
+                    f
+                };
+                if is_output {
+                    step.outputs.push(name);
+                } else {
+                    step.inputs.push(name);
+                }
+            }
+            step.args.push((flag, Some(v)));
+        }
+        Ok(step)
+    }
+}
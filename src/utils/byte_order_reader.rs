@@ -1,14 +1,16 @@
 // extern crate byteorder;
 use byteorder::{BigEndian, ByteOrder, LittleEndian};
 
-pub struct ByteOrderReader {
+/// Reads values of a given byte order from a buffer of bytes, which is usually a `Vec<u8>`
+/// but may be any type that can be viewed as a byte slice, e.g. a `utils::MemoryMap`.
+pub struct ByteOrderReader<B: AsRef<[u8]> = Vec<u8>> {
     pub byte_order: Endianness,
-    pub buffer: Vec<u8>,
+    pub buffer: B,
     pub pos: usize,
 }
 
-impl ByteOrderReader {
-    pub fn new(buffer: Vec<u8>, byte_order: Endianness) -> ByteOrderReader {
+impl<B: AsRef<[u8]>> ByteOrderReader<B> {
+    pub fn new(buffer: B, byte_order: Endianness) -> ByteOrderReader<B> {
         ByteOrderReader {
             buffer: buffer,
             byte_order: byte_order,
@@ -25,29 +27,30 @@ impl ByteOrderReader {
     }
 
     pub fn len(&mut self) -> usize {
-        self.buffer.len()
+        self.buffer.as_ref().len()
     }
 
     pub fn read_utf8(&mut self, length: usize) -> String {
-        let val = String::from_utf8_lossy(&self.buffer[self.pos..self.pos + length]).to_string();
+        let bytes = &self.buffer.as_ref()[self.pos..self.pos + length];
+        let val = String::from_utf8_lossy(bytes).to_string();
         self.pos += length;
         val
     }
 
     pub fn read_u8(&mut self) -> u8 {
         // There's really no need for endian issues when reading single bytes.
-        let val = self.buffer[self.pos];
+        let val = self.buffer.as_ref()[self.pos];
         self.pos += 1;
         val
     }
 
     pub fn peek_u8(&mut self) -> u8 {
-        let val = self.buffer[self.pos];
+        let val = self.buffer.as_ref()[self.pos];
         val
     }
 
     pub fn read_u16(&mut self) -> u16 {
-        let buf = &self.buffer[self.pos..self.pos + 2];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 2];
         self.pos += 2;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_u16(buf)
@@ -57,7 +60,7 @@ impl ByteOrderReader {
     }
 
     pub fn read_u32(&mut self) -> u32 {
-        let buf = &self.buffer[self.pos..self.pos + 4];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 4];
         self.pos += 4;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_u32(buf)
@@ -67,7 +70,7 @@ impl ByteOrderReader {
     }
 
     pub fn read_u64(&mut self) -> u64 {
-        let buf = &self.buffer[self.pos..self.pos + 8];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 8];
         self.pos += 8;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_u64(buf)
@@ -78,13 +81,13 @@ impl ByteOrderReader {
 
     pub fn read_i8(&mut self) -> i8 {
         // There's really no need for endian issues when reading single bytes.
-        let val = self.buffer[self.pos] as i8;
+        let val = self.buffer.as_ref()[self.pos] as i8;
         self.pos += 1;
         val
     }
 
     pub fn read_i16(&mut self) -> i16 {
-        let buf = &self.buffer[self.pos..self.pos + 2];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 2];
         self.pos += 2;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_i16(buf)
@@ -94,7 +97,7 @@ impl ByteOrderReader {
     }
 
     pub fn read_i32(&mut self) -> i32 {
-        let buf = &self.buffer[self.pos..self.pos + 4];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 4];
         self.pos += 4;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_i32(buf)
@@ -104,7 +107,7 @@ impl ByteOrderReader {
     }
 
     pub fn read_i64(&mut self) -> i64 {
-        let buf = &self.buffer[self.pos..self.pos + 8];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 8];
         self.pos += 8;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_i64(buf)
@@ -114,7 +117,7 @@ impl ByteOrderReader {
     }
 
     pub fn read_f32(&mut self) -> f32 {
-        let buf = &self.buffer[self.pos..self.pos + 4];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 4];
         self.pos += 4;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_f32(buf)
@@ -124,7 +127,7 @@ impl ByteOrderReader {
     }
 
     pub fn read_f64(&mut self) -> f64 {
-        let buf = &self.buffer[self.pos..self.pos + 8];
+        let buf = &self.buffer.as_ref()[self.pos..self.pos + 8];
         self.pos += 8;
         if self.byte_order == Endianness::LittleEndian {
             LittleEndian::read_f64(buf)
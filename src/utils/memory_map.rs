@@ -0,0 +1,130 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+use std::fs::File;
+use std::io::Error;
+use std::ops::Deref;
+
+/// A read-only memory map of an entire file. The pages of a mapped file are read on
+/// demand and are shared, through the operating system's page cache, with every other
+/// process that maps or reads the same file, rather than being copied into the memory
+/// of each process. Memory mapping is currently only supported on Unix-like systems;
+/// elsewhere, `open` returns an error of kind `Other`.
+pub struct MemoryMap {
+    ptr: *const u8,
+    len: usize,
+}
+
+// The mapping is read-only and is never modified once created.
+unsafe impl Send for MemoryMap {}
+unsafe impl Sync for MemoryMap {}
+
+impl MemoryMap {
+    /// Maps the file `file_name` into memory.
+    #[cfg(unix)]
+    pub fn open(file_name: &str) -> Result<MemoryMap, Error> {
+        use libc;
+        use std::os::unix::io::AsRawFd;
+        use std::ptr;
+
+        let f = File::open(file_name)?;
+        let len = f.metadata()?.len() as usize;
+        if len == 0 {
+            // a zero-length mapping is invalid
+            return Ok(MemoryMap {
+                ptr: ptr::null(),
+                len: 0,
+            });
+        }
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len as libc::size_t,
+                libc::PROT_READ,
+                libc::MAP_SHARED,
+                f.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(Error::last_os_error());
+        }
+        // the mapping remains valid once the file is closed
+        Ok(MemoryMap {
+            ptr: ptr as *const u8,
+            len: len,
+        })
+    }
+
+    /// Maps the file `file_name` into memory.
+    #[cfg(not(unix))]
+    pub fn open(file_name: &str) -> Result<MemoryMap, Error> {
+        let _ = File::open(file_name)?;
+        Err(Error::new(
+            ::std::io::ErrorKind::Other,
+            "Memory-mapped files are not supported on this platform.",
+        ))
+    }
+
+    /// Returns the length of the mapped file, in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the contents of the mapped file as a slice of `T`, beginning `offset` bytes
+    /// into the file and with `count` elements, or None if the slice would extend beyond
+    /// the end of the file or the position of its first element is not a multiple of the
+    /// alignment of `T`. `T` must be a plain-old-data type for which any bit pattern is
+    /// a valid value (e.g. `f64`).
+    pub fn get_slice<T: Copy>(&self, offset: usize, count: usize) -> Option<&[T]> {
+        let size = count.checked_mul(::std::mem::size_of::<T>())?;
+        if offset.checked_add(size)? > self.len {
+            return None;
+        }
+        if count == 0 {
+            return Some(&[]);
+        }
+        let ptr = unsafe { self.ptr.offset(offset as isize) };
+        if ptr as usize % ::std::mem::align_of::<T>() != 0 {
+            return None;
+        }
+        Some(unsafe { ::std::slice::from_raw_parts(ptr as *const T, count) })
+    }
+}
+
+impl Deref for MemoryMap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { ::std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl AsRef<[u8]> for MemoryMap {
+    fn as_ref(&self) -> &[u8] {
+        &self[..]
+    }
+}
+
+impl Drop for MemoryMap {
+    #[cfg(unix)]
+    fn drop(&mut self) {
+        use libc;
+        if self.len > 0 {
+            unsafe {
+                libc::munmap(self.ptr as *mut libc::c_void, self.len as libc::size_t);
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn drop(&mut self) {}
+}
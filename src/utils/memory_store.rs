@@ -0,0 +1,173 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! A process-wide store for datasets that are held in memory rather than written to disk.
+//!
+//! A dataset is held in memory when its file name uses the `memory://` scheme, e.g.
+//! `memory://flow_dir`. Datasets are keyed by the full name following the scheme,
+//! including any extension, such that `memory://flow_dir` and `memory://flow_dir.tif` are
+//! different datasets. Writing such a `Raster`, `Shapefile`, or `LasFile` places it into
+//! the store and reading it retrieves it, which allows the output of one tool to be passed
+//! directly to another tool within a single process (e.g. a workflow or a `ToolRunner`).
+//! Each data type is kept in a separate namespace.
+//!
+//! Reading a dataset never removes it from the store; a raster that is read shares its
+//! cell values with the stored raster (see `RasterData`) until either of them is modified,
+//! and vectors and LiDAR files are copied. Datasets remain in the store until they are
+//! explicitly removed, e.g. once the last step of a workflow that uses them has run.
+
+use lidar::LasFile;
+use raster::Raster;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::sync::{Mutex, MutexGuard, Once, ONCE_INIT};
+use vector::Shapefile;
+
+/// The scheme that identifies the file name of an in-memory dataset.
+pub const MEMORY_SCHEME: &str = "memory://";
+
+#[derive(Default)]
+struct MemoryStore {
+    rasters: HashMap<String, Raster>,
+    vectors: HashMap<String, Shapefile>,
+    lidar: HashMap<String, LasFile>,
+}
+
+static INIT: Once = ONCE_INIT;
+static mut STORE: *const Mutex<MemoryStore> = 0 as *const _;
+
+fn store() -> MutexGuard<'static, MemoryStore> {
+    let s = unsafe {
+        INIT.call_once(|| {
+            STORE = Box::into_raw(Box::new(Mutex::new(MemoryStore::default())));
+        });
+        &*STORE
+    };
+    match s.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(), // a panicking tool leaves the maps intact
+    }
+}
+
+/// Returns true if `file_name` refers to an in-memory dataset.
+pub fn is_memory_dataset(file_name: &str) -> bool {
+    file_name.starts_with(MEMORY_SCHEME)
+}
+
+/// Returns the store key associated with an in-memory dataset file name, i.e. the full
+/// name following the `memory://` scheme.
+pub fn memory_dataset_key(file_name: &str) -> String {
+    if is_memory_dataset(file_name) {
+        file_name[MEMORY_SCHEME.len()..].to_string()
+    } else {
+        file_name.to_string()
+    }
+}
+
+fn not_found(file_name: &str) -> Error {
+    Error::new(
+        ErrorKind::NotFound,
+        format!(
+            "In-memory dataset '{}{}' does not exist.",
+            MEMORY_SCHEME,
+            memory_dataset_key(file_name)
+        ),
+    )
+}
+
+/// Places `raster` into the store, replacing any existing raster of the same name.
+pub fn put_raster(file_name: &str, raster: Raster) {
+    store()
+        .rasters
+        .insert(memory_dataset_key(file_name), raster);
+}
+
+/// Retrieves an in-memory raster, which shares its cell values with the stored raster.
+pub fn get_raster(file_name: &str) -> Result<Raster, Error> {
+    match store().rasters.get(&memory_dataset_key(file_name)) {
+        Some(r) => Ok(r.clone()),
+        None => Err(not_found(file_name)),
+    }
+}
+
+/// Removes a raster from the store and returns it.
+pub fn remove_raster(file_name: &str) -> Option<Raster> {
+    store().rasters.remove(&memory_dataset_key(file_name))
+}
+
+/// Places `vector` into the store, replacing any existing vector of the same name.
+pub fn put_vector(file_name: &str, vector: Shapefile) {
+    store()
+        .vectors
+        .insert(memory_dataset_key(file_name), vector);
+}
+
+/// Retrieves a copy of an in-memory vector.
+pub fn get_vector(file_name: &str) -> Result<Shapefile, Error> {
+    match store().vectors.get(&memory_dataset_key(file_name)) {
+        Some(v) => Ok(v.clone()),
+        None => Err(not_found(file_name)),
+    }
+}
+
+/// Removes a vector from the store and returns it.
+pub fn remove_vector(file_name: &str) -> Option<Shapefile> {
+    store().vectors.remove(&memory_dataset_key(file_name))
+}
+
+/// Places `lidar` into the store, replacing any existing LiDAR file of the same name.
+pub fn put_lidar(file_name: &str, lidar: LasFile) {
+    store()
+        .lidar
+        .insert(memory_dataset_key(file_name), lidar);
+}
+
+/// Retrieves a copy of an in-memory LiDAR file.
+pub fn get_lidar(file_name: &str) -> Result<LasFile, Error> {
+    match store().lidar.get(&memory_dataset_key(file_name)) {
+        Some(l) => Ok(l.clone()),
+        None => Err(not_found(file_name)),
+    }
+}
+
+/// Removes a LiDAR file from the store and returns it.
+pub fn remove_lidar(file_name: &str) -> Option<LasFile> {
+    store().lidar.remove(&memory_dataset_key(file_name))
+}
+
+/// Returns true if the store contains a dataset of any type with the given name.
+pub fn contains(file_name: &str) -> bool {
+    let key = memory_dataset_key(file_name);
+    let s = store();
+    s.rasters.contains_key(&key) || s.vectors.contains_key(&key) || s.lidar.contains_key(&key)
+}
+
+/// Removes any datasets with the given name from the store, freeing their memory.
+pub fn remove(file_name: &str) {
+    let key = memory_dataset_key(file_name);
+    let mut s = store();
+    s.rasters.remove(&key);
+    s.vectors.remove(&key);
+    s.lidar.remove(&key);
+}
+
+/// Removes all datasets whose keys (the names following the scheme) begin with `prefix`.
+pub fn remove_with_prefix(prefix: &str) {
+    let mut s = store();
+    s.rasters.retain(|k, _| !k.starts_with(prefix));
+    s.vectors.retain(|k, _| !k.starts_with(prefix));
+    s.lidar.retain(|k, _| !k.starts_with(prefix));
+}
+
+/// Removes all datasets from the store.
+pub fn clear() {
+    let mut s = store();
+    s.rasters.clear();
+    s.vectors.clear();
+    s.lidar.clear();
+}
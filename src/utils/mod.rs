@@ -1,10 +1,15 @@
 // private sub-module defined in other files
 mod byte_order_reader;
 mod byte_order_writer;
+mod memory_map;
+pub mod memory_store;
+pub mod report;
+pub mod settings;
 
 // exports identifiers from private sub-modules in the current module namespace
 pub use self::byte_order_reader::ByteOrderReader;
 pub use self::byte_order_reader::Endianness;
+pub use self::memory_map::MemoryMap;
 // pub use self::byte_order_writer::ByteOrderWriter;
 
 use std::time::Instant;
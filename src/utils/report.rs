@@ -0,0 +1,108 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! Reporting of tool progress, warnings, metadata, and other messages.
+//!
+//! Tools report through these functions rather than printing directly, such that the
+//! same reports can be written either as text or as JSON lines. The text format, which
+//! is the default, prints each report to stdout as tools always have, e.g.
+//! `Performing analysis: 45%`. In the JSON format (`--json`), each report is instead
+//! written to stdout as a JSON object on a single line, with a `type` field:
+//!
+//! | type       | fields                                                      |
+//! | ---------- | ----------------------------------------------------------- |
+//! | `progress` | `progress` (0-100), `label` (e.g. "Progress (Loop 1 of 2)") |
+//! | `warning`  | `message`                                                   |
+//! | `metadata` | `name`, `value` (e.g. "Elapsed Time (excluding I/O)")       |
+//! | `message`  | `message` (any other output, e.g. "Saving data...")         |
+//!
+//! The format is a process-wide setting, and applies to all tools run in the process.
+
+use serde_json::{Map, Value};
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+
+static JSON_FORMAT: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// Sets whether reports are written as JSON lines rather than as text.
+pub fn set_json_format(json: bool) {
+    JSON_FORMAT.store(json, Ordering::SeqCst);
+}
+
+/// Returns true if reports are written as JSON lines.
+pub fn is_json_format() -> bool {
+    JSON_FORMAT.load(Ordering::SeqCst)
+}
+
+/// Reports the start of a tool. This is a banner in the text format and is omitted
+/// from the JSON format, in which the caller reports the start of the run.
+pub fn welcome(tool_name: &str) {
+    if !is_json_format() {
+        println!("***************{}", "*".repeat(tool_name.len()));
+        println!("* Welcome to {} *", tool_name);
+        println!("***************{}", "*".repeat(tool_name.len()));
+    }
+}
+
+/// Reports the progress (0-100) of a stage of processing, e.g. "Performing analysis".
+pub fn progress(label: &str, progress: usize) {
+    if is_json_format() {
+        emit(vec![
+            ("type", Value::from("progress")),
+            ("progress", Value::from(progress)),
+            ("label", Value::from(label)),
+        ]);
+    } else {
+        println!("{}: {}%", label, progress);
+    }
+}
+
+/// Reports a warning about the inputs or the results of a tool.
+pub fn warning(message: &str) {
+    if is_json_format() {
+        emit(vec![
+            ("type", Value::from("warning")),
+            ("message", Value::from(message)),
+        ]);
+    } else {
+        println!("Warning: {}", message);
+    }
+}
+
+/// Reports a named value describing a tool's inputs or results, e.g. the elapsed time.
+pub fn metadata(name: &str, value: &str) {
+    if is_json_format() {
+        emit(vec![
+            ("type", Value::from("metadata")),
+            ("name", Value::from(name)),
+            ("value", Value::from(value)),
+        ]);
+    } else {
+        println!("{}: {}", name, value);
+    }
+}
+
+/// Reports any other output of a tool, e.g. a stage of processing or a results table.
+pub fn message(message: &str) {
+    if is_json_format() {
+        emit(vec![
+            ("type", Value::from("message")),
+            ("message", Value::from(message)),
+        ]);
+    } else {
+        println!("{}", message);
+    }
+}
+
+/// Writes a JSON object with the given fields as a single line on stdout.
+pub fn emit(fields: Vec<(&str, Value)>) {
+    let mut m = Map::new();
+    for (k, v) in fields {
+        m.insert(k.to_string(), v);
+    }
+    println!("{}", Value::Object(m));
+}
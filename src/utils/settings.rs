@@ -0,0 +1,285 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: WhiteboxTools contributors
+Created: 15/10/2026
+Last Modified: 15/10/2026
+License: MIT
+*/
+
+//! Process-wide settings providing default values for tool outputs.
+//!
+//! The whitebox_tools executable reads the settings from a `settings.json` file located
+//! in the same directory as the executable, if it exists, e.g.:
+//!
+//! ```json
+//! {
+//!   "default_raster_format": "tif",
+//!   "compress_rasters": true,
+//!   "default_nodata": -32768.0,
+//!   "clip_display_min_max": true,
+//!   "ascii_precision": 3,
+//!   "write_prj_files": true,
+//!   "write_world_files": false,
+//!   "memory_map_rasters": false
+//! }
+//! ```
+//!
+//! Any of the values may be omitted. Individual values may also be overridden for a
+//! single run using `--config`, either with a list of key-value pairs, e.g.
+//! `--config="compress_rasters=true;default_nodata=-9999"`, or with the name of
+//! another settings file, e.g. `--config=my_settings.json`.
+
+use serde_json;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, Once, ONCE_INIT};
+
+/// The name of the settings file read by the whitebox_tools executable.
+pub const SETTINGS_FILE_NAME: &'static str = "settings.json";
+
+/// The raster file extensions that may be used as the default output format.
+const RASTER_FORMATS: [&'static str; 12] = [
+    "tif", "tiff", "dep", "tas", "flt", "rdc", "rst", "sdat", "sgrd", "grd", "asc", "txt",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The file extension given to output rasters whose file name has no extension.
+    pub default_raster_format: String,
+    /// Whether GeoTIFF outputs are compressed (LZW).
+    pub compress_rasters: bool,
+    /// The nodata value of output rasters whose data type can store it; when unspecified, or
+    /// for other outputs, each tool's own value is used.
+    pub default_nodata: Option<f64>,
+    /// Whether tools may clip the display minimum and maximum of their outputs to exclude
+    /// the distribution tails; when false, the display range is the full data range.
+    pub clip_display_min_max: bool,
+    /// The number of decimal places of floating-point values written to ASCII raster
+    /// formats; when unspecified, values are written with as many digits as are needed
+    /// to represent them exactly.
+    pub ascii_precision: Option<usize>,
+    /// Whether output rasters with a known coordinate reference system are accompanied
+    /// by a .prj file containing its WKT definition.
+    pub write_prj_files: bool,
+    /// Whether output rasters are accompanied by a .wld world file describing their
+    /// georeferencing.
+    pub write_world_files: bool,
+    /// Whether input rasters stored as uncompressed 64-bit floating-point GeoTIFF or
+    /// Whitebox files are mapped into memory rather than read, such that concurrently
+    /// running tools reading the same files share the operating system's page cache.
+    /// Mapped files must not be overwritten while a tool is reading them.
+    pub memory_map_rasters: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            default_raster_format: "tif".to_string(),
+            compress_rasters: false,
+            default_nodata: None,
+            clip_display_min_max: true,
+            ascii_precision: None,
+            write_prj_files: false,
+            write_world_files: false,
+            memory_map_rasters: false,
+        }
+    }
+}
+
+impl Settings {
+    /// Reads settings from a JSON file. Values that are absent from the file take
+    /// their default values.
+    pub fn from_file(file_name: &str) -> Result<Settings, Error> {
+        let mut settings = Settings::default();
+        settings.merge_file(file_name)?;
+        Ok(settings)
+    }
+
+    /// Applies a `--config` value, which is either a settings file name or a list of
+    /// `key=value` pairs separated by semicolons or commas.
+    pub fn apply_overrides(&mut self, config: &str) -> Result<(), Error> {
+        let config = config.trim();
+        if config.to_lowercase().ends_with(".json") && !config.contains("=") {
+            return self.merge_file(config);
+        }
+        for pair in config.split(|c| c == ';' || c == ',') {
+            let pair = pair.trim();
+            if pair.is_empty() {
+                continue;
+            }
+            let mut parts = pair.splitn(2, '=');
+            let key = parts.next().unwrap().trim();
+            match parts.next() {
+                Some(value) => self.set_value(key, value.trim())?,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Config setting '{}' has no value (expected key=value).", pair),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the value of a single setting from its text representation.
+    pub fn set_value(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let invalid = || {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid value '{}' for setting '{}'.", value, key),
+            )
+        };
+        match key.to_lowercase().as_ref() {
+            "default_raster_format" => {
+                let ext = value.trim_left_matches('.').to_lowercase();
+                if !RASTER_FORMATS.contains(&ext.as_ref()) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Unsupported default raster format '{}'; use one of: {}.",
+                            value,
+                            RASTER_FORMATS.join(", ")
+                        ),
+                    ));
+                }
+                self.default_raster_format = ext;
+            }
+            "compress_rasters" => {
+                self.compress_rasters = parse_bool(value).ok_or_else(invalid)?;
+            }
+            "default_nodata" => {
+                self.default_nodata = match value.to_lowercase().as_ref() {
+                    "" | "none" | "null" => None,
+                    v => Some(v.parse::<f64>().map_err(|_| invalid())?),
+                };
+            }
+            "clip_display_min_max" => {
+                self.clip_display_min_max = parse_bool(value).ok_or_else(invalid)?;
+            }
+            "ascii_precision" => {
+                self.ascii_precision = match value.to_lowercase().as_ref() {
+                    "" | "none" | "null" => None,
+                    v => Some(v.parse::<usize>().map_err(|_| invalid())?),
+                };
+            }
+            "write_prj_files" => {
+                self.write_prj_files = parse_bool(value).ok_or_else(invalid)?;
+            }
+            "write_world_files" => {
+                self.write_world_files = parse_bool(value).ok_or_else(invalid)?;
+            }
+            "memory_map_rasters" => {
+                self.memory_map_rasters = parse_bool(value).ok_or_else(invalid)?;
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unrecognized setting '{}'.", key),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the settings as a `--config` value, i.e. a list of key-value pairs.
+    pub fn to_config_string(&self) -> String {
+        format!(
+            "default_raster_format={};compress_rasters={};default_nodata={};clip_display_min_max={};ascii_precision={};write_prj_files={};write_world_files={};memory_map_rasters={}",
+            self.default_raster_format,
+            self.compress_rasters,
+            match self.default_nodata {
+                Some(v) => v.to_string(),
+                None => "none".to_string(),
+            },
+            self.clip_display_min_max,
+            match self.ascii_precision {
+                Some(v) => v.to_string(),
+                None => "none".to_string(),
+            },
+            self.write_prj_files,
+            self.write_world_files,
+            self.memory_map_rasters
+        )
+    }
+
+    /// Overrides the settings with those values that are contained in a JSON file.
+    fn merge_file(&mut self, file_name: &str) -> Result<(), Error> {
+        let mut f = File::open(file_name)?;
+        let mut contents = String::new();
+        f.read_to_string(&mut contents)?;
+        let v: serde_json::Value = match serde_json::from_str(&contents) {
+            Ok(v) => v,
+            Err(e) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Error parsing settings file {}: {}", file_name, e),
+                ))
+            }
+        };
+        let map = match v.as_object() {
+            Some(m) => m,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("The settings file {} does not contain a JSON object.", file_name),
+                ))
+            }
+        };
+        for (key, value) in map {
+            let s = match value {
+                &serde_json::Value::String(ref s) => s.clone(),
+                &serde_json::Value::Null => "none".to_string(),
+                v => v.to_string(),
+            };
+            self.set_value(key, &s)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_ref() {
+        "true" | "t" | "yes" | "1" => Some(true),
+        "false" | "f" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+static INIT: Once = ONCE_INIT;
+static mut SETTINGS: *const Mutex<Settings> = 0 as *const _;
+
+fn settings() -> MutexGuard<'static, Settings> {
+    let s = unsafe {
+        INIT.call_once(|| {
+            SETTINGS = Box::into_raw(Box::new(Mutex::new(Settings::default())));
+        });
+        &*SETTINGS
+    };
+    match s.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Returns a copy of the current settings.
+pub fn get_settings() -> Settings {
+    settings().clone()
+}
+
+/// Replaces the current settings.
+pub fn set_settings(new_settings: Settings) {
+    *settings() = new_settings;
+}
+
+/// Loads the settings file contained within `directory`, if there is one.
+pub fn load_settings_file(directory: &Path) -> Result<(), Error> {
+    let file_name = directory.join(SETTINGS_FILE_NAME);
+    if file_name.exists() {
+        set_settings(Settings::from_file(&file_name.to_string_lossy())?);
+    }
+    Ok(())
+}
@@ -2,7 +2,7 @@
 This file is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 10/04/2018
-Last Modified: 12/04/2018
+Last Modified: 15/10/2026
 License: MIT
 
 NOTE: Structures and functions for handling the Shapefile attribute table info 
@@ -11,6 +11,11 @@ contained with the associated .dbf file.
 
 use std::collections::HashMap;
 use std::fmt;
+use std::fs;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::Error;
+use utils::{ByteOrderReader, Endianness};
 
 #[derive(Debug, Default, Clone)]
 pub struct AttributeHeader {
@@ -175,6 +180,142 @@ pub struct ShapefileAttributes {
 }
 
 impl ShapefileAttributes {
+    /// Reads an attribute table from a dBase (.dbf) file.
+    pub fn read(dbf_file: &str) -> Result<ShapefileAttributes, Error> {
+        let mut attributes = ShapefileAttributes::default();
+
+        // read the header
+        let mut f = File::open(dbf_file)?;
+        let metadata = fs::metadata(dbf_file)?;
+        let file_size: usize = metadata.len() as usize;
+        let mut buffer = vec![0; file_size];
+
+        // read the file's bytes into a buffer
+        f.read(&mut buffer)?;
+
+        let mut bor = ByteOrderReader::new(buffer, Endianness::LittleEndian);
+
+        attributes.header.version = bor.read_u8();
+        attributes.header.year = 1900u32 + bor.read_u8() as u32;
+        attributes.header.month = bor.read_u8();
+        attributes.header.day = bor.read_u8();
+        attributes.header.num_records = bor.read_u32();
+        attributes.header.bytes_in_header = bor.read_u16();
+        attributes.header.bytes_in_record = bor.read_u16();
+        // reserved bytes
+        bor.pos += 2;
+        attributes.header.incomplete_tansaction = bor.read_u8();
+        attributes.header.encryption_flag = bor.read_u8();
+        // skip free record thread for LAN only
+        bor.pos += 4;
+        // reserved for multi-user dBASE in dBASE III+
+        bor.pos += 8;
+        attributes.header.mdx_flag = bor.read_u8();
+        attributes.header.language_driver_id = bor.read_u8();
+        // reserved bytes
+        bor.pos += 2;
+
+        // read the field data
+        attributes.fields = vec![];
+        let mut flag = true;
+        while flag {
+            let name = bor.read_utf8(11).replace(char::from(0), "");
+            let field_type = char::from(bor.read_u8());
+            bor.pos += 4;
+            let field_length = bor.read_u8();
+            let decimal_count = bor.read_u8();
+            // Skip reserved bytes multi-user dBASE.
+            bor.pos += 2;
+            let _work_area_id = bor.read_u8();
+            // Skip reserved bytes multi-user dBASE.
+            bor.pos += 2;
+            let _set_field_flag = bor.read_u8();
+            // Skip reserved bytes.
+            bor.pos += 7;
+            let _index_field_flag = bor.read_u8();
+
+            let field_data = AttributeField {
+                name: name.clone(),
+                field_type: field_type,
+                field_length: field_length,
+                decimal_count: decimal_count,
+            };
+            // let field_data = AttributeField::new(
+            //     &name,
+            //     field_type,
+            //     field_length,
+            //     decimal_count,
+            //     work_area_id,
+            //     set_field_flag,
+            //     index_field_flag,
+            // );
+
+            attributes.add_field(&field_data); //.fields.push(field_data);
+
+            // Checks for end of field descriptor array (0x0d). Valid .dbf files
+            // will have this flag.
+            if bor.peek_u8() == 0x0d {
+                flag = false;
+                // break;
+            }
+        }
+
+        // attributes.get_field_hashmap();
+
+        attributes.header.num_fields = attributes.fields.len() as u32;
+
+        bor.pos += 1;
+
+        let mut d: bool;
+        let mut str_rep: String;
+        for _ in 0..attributes.header.num_records {
+            d = bor.read_u8() as u32 == 0x2A;
+            let mut r: Vec<FieldData> = vec![];
+            for j in 0..attributes.header.num_fields {
+                str_rep = bor
+                    .read_utf8(attributes.fields[j as usize].field_length as usize)
+                    .replace(char::from(0), "")
+                    .replace("*", "")
+                    .trim()
+                    .to_string();
+                if str_rep.replace(" ", "").replace("?", "").is_empty() {
+                    r.push(FieldData::Null);
+                } else {
+                    match attributes.fields[j as usize].field_type {
+                        'N' | 'F' | 'I' | 'O' => {
+                            if attributes.fields[j as usize].decimal_count == 0 {
+                                r.push(FieldData::Int(str_rep.parse::<i32>().unwrap()));
+                            } else {
+                                r.push(FieldData::Real(str_rep.parse::<f64>().unwrap()));
+                            }
+                        }
+                        'D' => {
+                            r.push(FieldData::Date(DateData {
+                                year: str_rep[0..4].parse::<u16>().unwrap(),
+                                month: str_rep[4..6].parse::<u8>().unwrap(),
+                                day: str_rep[6..8].parse::<u8>().unwrap(),
+                            }));
+                        }
+                        'L' => {
+                            if str_rep.to_lowercase().contains("t") {
+                                r.push(FieldData::Bool(true));
+                            } else {
+                                r.push(FieldData::Bool(false));
+                            }
+                        }
+                        _ => {
+                            // treat it like a string
+                            r.push(FieldData::Text(str_rep.clone()));
+                        }
+                    }
+                }
+            }
+            attributes.add_record(r, d);
+        }
+
+        Ok(attributes)
+    }
+
     /// Adds a field to the table
     pub fn add_field<'a>(&mut self, field: &'a AttributeField) {
         self.fields.push(field.clone());
@@ -207,6 +348,19 @@ impl ShapefileAttributes {
         self.get_field_hashmap();
     }
 
+    /// Removes a field, and its values, from the table
+    pub fn remove_field(&mut self, index: usize) {
+        if index >= self.fields.len() {
+            panic!("Error: Specified field does not appear in attribute table.");
+        }
+        self.fields.remove(index);
+        self.header.num_fields -= 1;
+        for record_index in 0..self.data.len() {
+            self.data[record_index].remove(index);
+        }
+        self.get_field_hashmap();
+    }
+
     /// Returns a field from the table
     pub fn get_field<'a>(&'a self, index: usize) -> &'a AttributeField {
         &self.fields[index]
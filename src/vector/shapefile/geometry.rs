@@ -2,7 +2,7 @@
 This file is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 10/04/2018
-Last Modified: 30/09/2018
+Last Modified: 15/10/2026
 License: MIT
 */
 use algorithms::point_in_poly;
@@ -229,6 +229,10 @@ impl ShapefileGeometry {
     /// Returns the length of the ShapefileGeometry, including the header, in bytes.
     pub fn get_length(&self) -> i32 {
         let mut ret = 4; //12; // 12 bytes in the record number, content length, and shape type
+        if self.num_points == 0 {
+            // written as a null shape
+            return ret;
+        }
 
         ret += match self.shape_type {
             ShapeType::Null => 0i32,
@@ -2,7 +2,7 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 21, 2017
-Last Modified: 17/10/2018
+Last Modified: 15/10/2026
 License: MIT
 
 Notes: The logic behind working with the ESRI Shapefile format.
@@ -28,6 +28,8 @@ use std::io::{BufReader, BufWriter, Error, ErrorKind};
 use std::path::Path;
 use std::str;
 use structures::Point2D;
+use utils::memory_store;
+use utils::report;
 use utils::{ByteOrderReader, Endianness};
 
 /// `ShapefileHeader` stores the header variables of a ShapeFile header.
@@ -115,6 +117,12 @@ pub struct Shapefile {
 
 impl Shapefile {
     pub fn read<'a>(file_name: &'a str) -> Result<Shapefile, Error> {
+        if memory_store::is_memory_dataset(file_name) {
+            let mut sf = memory_store::get_vector(file_name)?;
+            sf.file_name = file_name.to_string();
+            sf.file_mode = "r".to_string();
+            return Ok(sf);
+        }
         let mut sf = Shapefile {
             file_name: file_name.to_string(),
             file_mode: "r".to_string(),
@@ -125,12 +133,7 @@ impl Shapefile {
     }
 
     pub fn new<'a>(file_name: &'a str, file_type: ShapeType) -> Result<Shapefile, Error> {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .shp
-            format!("{}.shp", file_name)
-        };
+        let new_file_name = get_output_file_name(file_name);
         let mut sf = Shapefile {
             file_name: new_file_name.to_string(),
             file_mode: "w".to_string(),
@@ -140,6 +143,13 @@ impl Shapefile {
         Ok(sf)
     }
 
+    /// Sets the name of the file to which the vector is written, e.g. that of a vector
+    /// returned by one of the tools' core functions. The extension defaults to `.shp`.
+    pub fn set_file_name(&mut self, file_name: &str) {
+        self.file_name = get_output_file_name(file_name);
+        self.file_mode = "w".to_string();
+    }
+
     pub fn get_total_num_parts(&self) -> usize {
         let mut ret = 0;
         for a in 0..self.num_records {
@@ -162,12 +172,7 @@ impl Shapefile {
         shape_type: ShapeType,
         copy_fields: bool,
     ) -> Result<Shapefile, Error> {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .shp
-            format!("{}.shp", file_name)
-        };
+        let new_file_name = get_output_file_name(file_name);
 
         let mut sf = Shapefile {
             file_name: new_file_name,
@@ -273,261 +278,102 @@ impl Shapefile {
         self.header.m_max = bor.read_f64();
 
         // Read the data
-        bor.byte_order = Endianness::LittleEndian;
-        match self.header.shape_type {
-            ShapeType::Point => {
-                while bor.pos < file_size {
-                    bor.pos += 12; // Don't need to read the header and shapeType. It's not going to change.
-                    let sfg = ShapefileGeometry {
-                        shape_type: ShapeType::Point,
-                        num_points: 1i32,
-                        points: vec![Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        }],
-                        ..Default::default()
-                    };
-                    self.records.push(sfg);
-                }
-            }
-
-            ShapeType::PolyLine | ShapeType::Polygon => {
-                while bor.pos < file_size {
-                    bor.pos += 8;
-                    let mut sfg = ShapefileGeometry {
-                        shape_type: ShapeType::from_int(bor.read_i32()),
-                        x_min: bor.read_f64(),
-                        y_min: bor.read_f64(),
-                        x_max: bor.read_f64(),
-                        y_max: bor.read_f64(),
-                        num_parts: bor.read_i32(),
-                        num_points: bor.read_i32(),
-                        ..Default::default()
-                    };
-
-                    for _ in 0..sfg.num_parts {
-                        sfg.parts.push(bor.read_i32());
-                    }
-
-                    for _ in 0..sfg.num_points {
-                        sfg.points.push(Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        });
-                    }
-
-                    self.records.push(sfg);
+        if self.header.shape_type == ShapeType::Null {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Unrecognized ShapeType.",
+            ));
+        }
+        let shape_type = self.header.shape_type;
+        let base_shape_type = shape_type.base_shape_type();
+        let dimension = shape_type.dimension();
+        while bor.pos + 12 <= file_size {
+            // the record header is BigEndian
+            bor.byte_order = Endianness::BigEndian;
+            bor.pos += 4; // record number
+            let content_length = 2 * bor.read_i32() as usize; // from 16-bit words to bytes
+            let record_end = bor.pos + content_length;
+            bor.byte_order = Endianness::LittleEndian;
+            let mut sfg = ShapefileGeometry::new(shape_type);
+            if bor.read_i32() == 0 {
+                // a null shape, which is read as a geometry without any points
+                self.records.push(sfg);
+                bor.pos = record_end;
+                continue;
+            }
+
+            if base_shape_type == ShapeType::Point {
+                let p = Point2D {
+                    x: bor.read_f64(),
+                    y: bor.read_f64(),
+                };
+                sfg.add_point(p);
+                if dimension == ShapeTypeDimension::Z {
+                    let z = bor.read_f64();
+                    sfg.z_array.push(z);
+                    sfg.z_min = z;
+                    sfg.z_max = z;
                 }
-            }
-
-            ShapeType::MultiPoint => {
-                while bor.pos < file_size {
-                    bor.pos += 8;
-                    let mut sfg = ShapefileGeometry {
-                        shape_type: ShapeType::from_int(bor.read_i32()),
-                        x_min: bor.read_f64(),
-                        y_min: bor.read_f64(),
-                        x_max: bor.read_f64(),
-                        y_max: bor.read_f64(),
-                        num_points: bor.read_i32(),
-                        ..Default::default()
+                if dimension != ShapeTypeDimension::XY {
+                    // measures are optional in PointZ records
+                    let m = if bor.pos + 8 <= record_end {
+                        bor.read_f64()
+                    } else {
+                        0f64
                     };
-
-                    for _ in 0..sfg.num_points {
-                        sfg.points.push(Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        });
-                    }
-
-                    self.records.push(sfg);
+                    sfg.m_array.push(m);
+                    sfg.m_min = m;
+                    sfg.m_max = m;
                 }
-            }
-
-            ShapeType::PointZ => {
-                while bor.pos < file_size {
-                    bor.pos += 12; // Don't need to read the header and shapeType. It's not going to change.
-                    let sfg = ShapefileGeometry {
-                        shape_type: ShapeType::Point,
-                        num_points: 1i32,
-                        points: vec![Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        }],
-                        z_array: vec![bor.read_f64()],
-                        m_array: vec![bor.read_f64()],
-                        ..Default::default()
-                    };
-                    self.records.push(sfg);
+            } else {
+                sfg.x_min = bor.read_f64();
+                sfg.y_min = bor.read_f64();
+                sfg.x_max = bor.read_f64();
+                sfg.y_max = bor.read_f64();
+                if base_shape_type != ShapeType::MultiPoint {
+                    sfg.num_parts = bor.read_i32();
                 }
-            }
-
-            ShapeType::PolyLineZ | ShapeType::PolygonZ => {
-                while bor.pos < file_size {
-                    bor.pos += 8;
-                    let mut sfg = ShapefileGeometry {
-                        shape_type: ShapeType::from_int(bor.read_i32()),
-                        x_min: bor.read_f64(),
-                        y_min: bor.read_f64(),
-                        x_max: bor.read_f64(),
-                        y_max: bor.read_f64(),
-                        num_parts: bor.read_i32(),
-                        num_points: bor.read_i32(),
-                        ..Default::default()
-                    };
+                sfg.num_points = bor.read_i32();
 
+                if base_shape_type != ShapeType::MultiPoint {
                     for _ in 0..sfg.num_parts {
                         sfg.parts.push(bor.read_i32());
                     }
-
-                    for _ in 0..sfg.num_points {
-                        sfg.points.push(Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        });
-                    }
-
-                    sfg.z_min = bor.read_f64();
-                    sfg.z_max = bor.read_f64();
-                    for _ in 0..sfg.num_points {
-                        sfg.z_array.push(bor.read_f64());
-                    }
-
-                    sfg.m_min = bor.read_f64();
-                    sfg.m_max = bor.read_f64();
-                    for _ in 0..sfg.num_points {
-                        sfg.m_array.push(bor.read_f64());
-                    }
-
-                    self.records.push(sfg);
                 }
-            }
 
-            ShapeType::MultiPointZ => {
-                while bor.pos < file_size {
-                    bor.pos += 8;
-                    let mut sfg = ShapefileGeometry {
-                        shape_type: ShapeType::from_int(bor.read_i32()),
-                        x_min: bor.read_f64(),
-                        y_min: bor.read_f64(),
-                        x_max: bor.read_f64(),
-                        y_max: bor.read_f64(),
-                        num_points: bor.read_i32(),
-                        ..Default::default()
-                    };
-
-                    for _ in 0..sfg.num_points {
-                        sfg.points.push(Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        });
-                    }
+                for _ in 0..sfg.num_points {
+                    sfg.points.push(Point2D {
+                        x: bor.read_f64(),
+                        y: bor.read_f64(),
+                    });
+                }
 
+                if dimension == ShapeTypeDimension::Z {
                     sfg.z_min = bor.read_f64();
                     sfg.z_max = bor.read_f64();
                     for _ in 0..sfg.num_points {
                         sfg.z_array.push(bor.read_f64());
                     }
-
-                    sfg.m_min = bor.read_f64();
-                    sfg.m_max = bor.read_f64();
-                    for _ in 0..sfg.num_points {
-                        sfg.m_array.push(bor.read_f64());
-                    }
-
-                    self.records.push(sfg);
                 }
-            }
 
-            ShapeType::PointM => {
-                while bor.pos < file_size {
-                    bor.pos += 12; // Don't need to read the header and shapeType. It's not going to change.
-                    let sfg = ShapefileGeometry {
-                        shape_type: ShapeType::Point,
-                        num_points: 1i32,
-                        points: vec![Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        }],
-                        m_array: vec![bor.read_f64()],
-                        ..Default::default()
-                    };
-                    self.records.push(sfg);
-                }
-            }
-
-            ShapeType::PolyLineM | ShapeType::PolygonM => {
-                while bor.pos < file_size {
-                    bor.pos += 8;
-                    let mut sfg = ShapefileGeometry {
-                        shape_type: ShapeType::from_int(bor.read_i32()),
-                        x_min: bor.read_f64(),
-                        y_min: bor.read_f64(),
-                        x_max: bor.read_f64(),
-                        y_max: bor.read_f64(),
-                        num_parts: bor.read_i32(),
-                        num_points: bor.read_i32(),
-                        ..Default::default()
-                    };
-
-                    for _ in 0..sfg.num_parts {
-                        sfg.parts.push(bor.read_i32());
-                    }
-
-                    for _ in 0..sfg.num_points {
-                        sfg.points.push(Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        });
-                    }
-
-                    sfg.m_min = bor.read_f64();
-                    sfg.m_max = bor.read_f64();
-                    for _ in 0..sfg.num_points {
-                        sfg.m_array.push(bor.read_f64());
-                    }
-
-                    self.records.push(sfg);
-                }
-            }
-
-            ShapeType::MultiPointM => {
-                while bor.pos < file_size {
-                    bor.pos += 8;
-
-                    let mut sfg = ShapefileGeometry {
-                        shape_type: ShapeType::from_int(bor.read_i32()),
-                        x_min: bor.read_f64(),
-                        y_min: bor.read_f64(),
-                        x_max: bor.read_f64(),
-                        y_max: bor.read_f64(),
-                        num_points: bor.read_i32(),
-                        ..Default::default()
-                    };
-
-                    for _ in 0..sfg.num_points {
-                        sfg.points.push(Point2D {
-                            x: bor.read_f64(),
-                            y: bor.read_f64(),
-                        });
-                    }
-
-                    sfg.m_min = bor.read_f64();
-                    sfg.m_max = bor.read_f64();
-                    for _ in 0..sfg.num_points {
-                        sfg.m_array.push(bor.read_f64());
+                if dimension != ShapeTypeDimension::XY {
+                    // measures are optional in Z-type records
+                    if bor.pos + 16 + 8 * sfg.num_points as usize <= record_end {
+                        sfg.m_min = bor.read_f64();
+                        sfg.m_max = bor.read_f64();
+                        for _ in 0..sfg.num_points {
+                            sfg.m_array.push(bor.read_f64());
+                        }
+                    } else {
+                        sfg.m_min = 0f64;
+                        sfg.m_max = 0f64;
+                        sfg.m_array = vec![0f64; sfg.num_points as usize];
                     }
-
-                    self.records.push(sfg);
                 }
             }
 
-            _ => {
-                return Err(Error::new(
-                    ErrorKind::InvalidInput,
-                    "Unrecognized ShapeType.",
-                ));
-            }
+            self.records.push(sfg);
+            bor.pos = record_end;
         }
 
         self.num_records = self.records.len();
@@ -544,142 +390,15 @@ impl Shapefile {
                     self.projection.push_str(&format!("{}\n", line_unwrapped));
                 }
             }
-            Err(_) => println!("Warning: Projection file not located."),
+            Err(_) => report::warning("Projection file not located."),
         }
 
         ///////////////////////////////
         // Read the attributes table //
         ///////////////////////////////
 
-        // read the header
         let dbf_file = self.file_name.replace(".shp", ".dbf");
-        let mut f = File::open(dbf_file.clone()).unwrap();
-        let metadata = fs::metadata(dbf_file.clone()).unwrap();
-        let file_size: usize = metadata.len() as usize;
-        let mut buffer = vec![0; file_size];
-
-        // read the file's bytes into a buffer
-        f.read(&mut buffer).unwrap();
-
-        let mut bor = ByteOrderReader::new(buffer, Endianness::LittleEndian);
-
-        self.attributes.header.version = bor.read_u8();
-        self.attributes.header.year = 1900u32 + bor.read_u8() as u32;
-        self.attributes.header.month = bor.read_u8();
-        self.attributes.header.day = bor.read_u8();
-        self.attributes.header.num_records = bor.read_u32();
-        self.attributes.header.bytes_in_header = bor.read_u16();
-        self.attributes.header.bytes_in_record = bor.read_u16();
-        // reserved bytes
-        bor.pos += 2;
-        self.attributes.header.incomplete_tansaction = bor.read_u8();
-        self.attributes.header.encryption_flag = bor.read_u8();
-        // skip free record thread for LAN only
-        bor.pos += 4;
-        // reserved for multi-user dBASE in dBASE III+
-        bor.pos += 8;
-        self.attributes.header.mdx_flag = bor.read_u8();
-        self.attributes.header.language_driver_id = bor.read_u8();
-        // reserved bytes
-        bor.pos += 2;
-
-        // read the field data
-        self.attributes.fields = vec![];
-        let mut flag = true;
-        while flag {
-            let name = bor.read_utf8(11).replace(char::from(0), "");
-            let field_type = char::from(bor.read_u8());
-            bor.pos += 4;
-            let field_length = bor.read_u8();
-            let decimal_count = bor.read_u8();
-            // Skip reserved bytes multi-user dBASE.
-            bor.pos += 2;
-            let _work_area_id = bor.read_u8();
-            // Skip reserved bytes multi-user dBASE.
-            bor.pos += 2;
-            let _set_field_flag = bor.read_u8();
-            // Skip reserved bytes.
-            bor.pos += 7;
-            let _index_field_flag = bor.read_u8();
-
-            let field_data = AttributeField {
-                name: name.clone(),
-                field_type: field_type,
-                field_length: field_length,
-                decimal_count: decimal_count,
-            };
-            // let field_data = AttributeField::new(
-            //     &name,
-            //     field_type,
-            //     field_length,
-            //     decimal_count,
-            //     work_area_id,
-            //     set_field_flag,
-            //     index_field_flag,
-            // );
-
-            self.attributes.add_field(&field_data); //.fields.push(field_data);
-
-            // Checks for end of field descriptor array (0x0d). Valid .dbf files
-            // will have this flag.
-            if bor.peek_u8() == 0x0d {
-                flag = false;
-                // break;
-            }
-        }
-
-        // self.attributes.get_field_hashmap();
-
-        self.attributes.header.num_fields = self.attributes.fields.len() as u32;
-
-        bor.pos += 1;
-
-        let mut d: bool;
-        let mut str_rep: String;
-        for _ in 0..self.attributes.header.num_records {
-            d = bor.read_u8() as u32 == 0x2A;
-            let mut r: Vec<FieldData> = vec![];
-            for j in 0..self.attributes.header.num_fields {
-                str_rep = bor
-                    .read_utf8(self.attributes.fields[j as usize].field_length as usize)
-                    .replace(char::from(0), "")
-                    .replace("*", "")
-                    .trim()
-                    .to_string();
-                if str_rep.replace(" ", "").replace("?", "").is_empty() {
-                    r.push(FieldData::Null);
-                } else {
-                    match self.attributes.fields[j as usize].field_type {
-                        'N' | 'F' | 'I' | 'O' => {
-                            if self.attributes.fields[j as usize].decimal_count == 0 {
-                                r.push(FieldData::Int(str_rep.parse::<i32>().unwrap()));
-                            } else {
-                                r.push(FieldData::Real(str_rep.parse::<f64>().unwrap()));
-                            }
-                        }
-                        'D' => {
-                            r.push(FieldData::Date(DateData {
-                                year: str_rep[0..4].parse::<u16>().unwrap(),
-                                month: str_rep[4..6].parse::<u8>().unwrap(),
-                                day: str_rep[6..8].parse::<u8>().unwrap(),
-                            }));
-                        }
-                        'L' => {
-                            if str_rep.to_lowercase().contains("t") {
-                                r.push(FieldData::Bool(true));
-                            } else {
-                                r.push(FieldData::Bool(false));
-                            }
-                        }
-                        _ => {
-                            // treat it like a string
-                            r.push(FieldData::Text(str_rep.clone()));
-                        }
-                    }
-                }
-            }
-            self.attributes.add_record(r, d);
-        }
+        self.attributes = ShapefileAttributes::read(&dbf_file)?;
 
         Ok(())
     }
@@ -700,6 +419,19 @@ impl Shapefile {
             ));
         }
 
+        if self.file_name.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Cannot write a vector without a file name (see set_file_name).",
+            ));
+        }
+
+        if memory_store::is_memory_dataset(&self.file_name) {
+            self.calculate_extent();
+            memory_store::put_vector(&self.file_name, self.clone());
+            return Ok(());
+        }
+
         /////////////////////////////////////////
         // Write the geometry data (.shp file) //
         /////////////////////////////////////////
@@ -742,254 +474,68 @@ impl Shapefile {
         writer.write_f64::<LittleEndian>(self.header.m_max)?;
 
         // Write the geometries
-        match self.header.shape_type {
-            ShapeType::Null => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(0i32)?; // Shape type
-                }
-            }
-            ShapeType::Point => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(1i32)?; // Shape type
-                    writer.write_f64::<LittleEndian>(self.records[i].points[0].x)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].points[0].y)?;
+        let shape_type = self.header.shape_type;
+        let base_shape_type = shape_type.base_shape_type();
+        let dimension = shape_type.dimension();
+        for i in 0..self.num_records {
+            let record = &self.records[i];
+            writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
+            writer.write_i32::<BigEndian>(record.get_length() / 2)?; // Content length in 16-bit words
+            if shape_type == ShapeType::Null || record.num_points == 0 {
+                writer.write_i32::<LittleEndian>(0i32)?; // Null shape type
+                continue;
+            }
+            writer.write_i32::<LittleEndian>(shape_type.to_int())?; // Shape type
+
+            if base_shape_type == ShapeType::Point {
+                writer.write_f64::<LittleEndian>(record.points[0].x)?;
+                writer.write_f64::<LittleEndian>(record.points[0].y)?;
+            } else {
+                // extent
+                writer.write_f64::<LittleEndian>(record.x_min)?;
+                writer.write_f64::<LittleEndian>(record.y_min)?;
+                writer.write_f64::<LittleEndian>(record.x_max)?;
+                writer.write_f64::<LittleEndian>(record.y_max)?;
+
+                if base_shape_type != ShapeType::MultiPoint {
+                    writer.write_i32::<LittleEndian>(record.num_parts)?; // Num parts
                 }
-            }
-
-            ShapeType::PolyLine | ShapeType::Polygon => {
-                let st = if self.header.shape_type == ShapeType::PolyLine {
-                    3i32
-                } else {
-                    5i32
-                };
-
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(st)?; // Shape type
+                writer.write_i32::<LittleEndian>(record.num_points)?; // Num points
 
-                    // extent
-                    writer.write_f64::<LittleEndian>(self.records[i].x_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].x_max)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_max)?;
-
-                    writer.write_i32::<LittleEndian>(self.records[i].num_parts)?; // Num parts
-                    writer.write_i32::<LittleEndian>(self.records[i].num_points)?; // Num points
-
-                    // parts
-                    for part in &self.records[i].parts {
+                // parts
+                if base_shape_type != ShapeType::MultiPoint {
+                    for part in &record.parts {
                         writer.write_i32::<LittleEndian>(*part)?;
                     }
-
-                    // points
-                    for pt in &self.records[i].points {
-                        writer.write_f64::<LittleEndian>(pt.x)?;
-                        writer.write_f64::<LittleEndian>(pt.y)?;
-                    }
                 }
-            }
-
-            ShapeType::MultiPoint => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(8i32)?; // Shape type
 
-                    // extent
-                    writer.write_f64::<LittleEndian>(self.records[i].x_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].x_max)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_max)?;
-
-                    writer.write_i32::<LittleEndian>(self.records[i].num_points)?; // Num points
-
-                    // points
-                    for pt in &self.records[i].points {
-                        writer.write_f64::<LittleEndian>(pt.x)?;
-                        writer.write_f64::<LittleEndian>(pt.y)?;
-                    }
+                // points
+                for pt in &record.points {
+                    writer.write_f64::<LittleEndian>(pt.x)?;
+                    writer.write_f64::<LittleEndian>(pt.y)?;
                 }
             }
 
-            ShapeType::PointZ => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(11i32)?; // Shape type
-                    writer.write_f64::<LittleEndian>(self.records[i].points[0].x)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].points[0].y)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].z_array[0])?;
-                    writer.write_f64::<LittleEndian>(self.records[i].m_array[0])?;
-                }
+            // z and measure data; missing values, e.g. in records added without them, are zero
+            let mut value_arrays = vec![];
+            if dimension == ShapeTypeDimension::Z {
+                value_arrays.push(&record.z_array);
             }
-
-            ShapeType::PolyLineZ | ShapeType::PolygonZ => {
-                let st = if self.header.shape_type == ShapeType::PolyLine {
-                    13i32
-                } else {
-                    15i32
-                };
-
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(st)?; // Shape type
-
-                    // extent
-                    writer.write_f64::<LittleEndian>(self.records[i].x_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].x_max)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_max)?;
-
-                    writer.write_i32::<LittleEndian>(self.records[i].num_parts)?; // Num parts
-                    writer.write_i32::<LittleEndian>(self.records[i].num_points)?; // Num points
-
-                    // parts
-                    for part in &self.records[i].parts {
-                        writer.write_i32::<LittleEndian>(*part)?;
-                    }
-
-                    // points
-                    for pt in &self.records[i].points {
-                        writer.write_f64::<LittleEndian>(pt.x)?;
-                        writer.write_f64::<LittleEndian>(pt.y)?;
-                    }
-
-                    // z data
-                    writer.write_f64::<LittleEndian>(self.records[i].z_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].z_max)?;
-                    for z in &self.records[i].z_array {
-                        writer.write_f64::<LittleEndian>(*z)?;
-                    }
-
-                    // measure data
-                    writer.write_f64::<LittleEndian>(self.records[i].m_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].m_max)?;
-                    for m in &self.records[i].m_array {
-                        writer.write_f64::<LittleEndian>(*m)?;
-                    }
-                }
+            if dimension != ShapeTypeDimension::XY {
+                value_arrays.push(&record.m_array);
             }
-
-            ShapeType::MultiPointZ => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(18i32)?; // Shape type
-
-                    // extent
-                    writer.write_f64::<LittleEndian>(self.records[i].x_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].x_max)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_max)?;
-
-                    writer.write_i32::<LittleEndian>(self.records[i].num_points)?; // Num points
-
-                    // points
-                    for pt in &self.records[i].points {
-                        writer.write_f64::<LittleEndian>(pt.x)?;
-                        writer.write_f64::<LittleEndian>(pt.y)?;
-                    }
-
-                    // z data
-                    writer.write_f64::<LittleEndian>(self.records[i].z_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].z_max)?;
-                    for z in &self.records[i].z_array {
-                        writer.write_f64::<LittleEndian>(*z)?;
-                    }
-
-                    // measure data
-                    writer.write_f64::<LittleEndian>(self.records[i].m_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].m_max)?;
-                    for m in &self.records[i].m_array {
-                        writer.write_f64::<LittleEndian>(*m)?;
-                    }
+            for values in value_arrays {
+                let values: Vec<f64> = (0..record.num_points as usize)
+                    .map(|k| *values.get(k).unwrap_or(&0f64))
+                    .collect();
+                if base_shape_type != ShapeType::Point {
+                    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+                    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                    writer.write_f64::<LittleEndian>(min)?;
+                    writer.write_f64::<LittleEndian>(max)?;
                 }
-            }
-
-            ShapeType::PointM => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(21i32)?; // Shape type
-                    writer.write_f64::<LittleEndian>(self.records[i].points[0].x)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].points[0].y)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].m_array[0])?;
-                }
-            }
-
-            ShapeType::PolyLineM | ShapeType::PolygonM => {
-                let st = if self.header.shape_type == ShapeType::PolyLine {
-                    23i32
-                } else {
-                    25i32
-                };
-
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(st)?; // Shape type
-
-                    // extent
-                    writer.write_f64::<LittleEndian>(self.records[i].x_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].x_max)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_max)?;
-
-                    writer.write_i32::<LittleEndian>(self.records[i].num_parts)?; // Num parts
-                    writer.write_i32::<LittleEndian>(self.records[i].num_points)?; // Num points
-
-                    // parts
-                    for part in &self.records[i].parts {
-                        writer.write_i32::<LittleEndian>(*part)?;
-                    }
-
-                    // points
-                    for pt in &self.records[i].points {
-                        writer.write_f64::<LittleEndian>(pt.x)?;
-                        writer.write_f64::<LittleEndian>(pt.y)?;
-                    }
-
-                    // measure data
-                    writer.write_f64::<LittleEndian>(self.records[i].m_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].m_max)?;
-                    for m in &self.records[i].m_array {
-                        writer.write_f64::<LittleEndian>(*m)?;
-                    }
-                }
-            }
-
-            ShapeType::MultiPointM => {
-                for i in 0..self.num_records {
-                    writer.write_i32::<BigEndian>(i as i32 + 1i32)?; // Record number
-                    writer.write_i32::<BigEndian>(self.records[i].get_length() / 2)?; // Content length in 16-bit words
-                    writer.write_i32::<LittleEndian>(28i32)?; // Shape type
-
-                    // extent
-                    writer.write_f64::<LittleEndian>(self.records[i].x_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].x_max)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].y_max)?;
-
-                    writer.write_i32::<LittleEndian>(self.records[i].num_points)?; // Num points
-
-                    // points
-                    for pt in &self.records[i].points {
-                        writer.write_f64::<LittleEndian>(pt.x)?;
-                        writer.write_f64::<LittleEndian>(pt.y)?;
-                    }
-
-                    // measure data
-                    writer.write_f64::<LittleEndian>(self.records[i].m_min)?;
-                    writer.write_f64::<LittleEndian>(self.records[i].m_max)?;
-                    for m in &self.records[i].m_array {
-                        writer.write_f64::<LittleEndian>(*m)?;
-                    }
+                for v in values {
+                    writer.write_f64::<LittleEndian>(v)?;
                 }
             }
         }
@@ -1155,18 +701,10 @@ impl Shapefile {
                     //     }
                     // }
                     FieldData::Real(v) => {
+                        // formatted directly, since the integer part of values between -1
+                        // and 0 would otherwise lose its sign
                         let dc = self.attributes.fields[j as usize].decimal_count as usize;
-                        let s = v.to_string();
-                        let d = v.trunc().to_string();
-                        let mut c = if s.len() > d.len() {
-                            s[d.len() + 1..s.len()].to_string()
-                        } else {
-                            String::new()
-                        };
-                        if c.len() > dc {
-                            c = c[0..dc].to_string();
-                        }
-                        let b = format!("{}.{}", d, c);
+                        let b = format!("{:.*}", dc, v);
                         if b.len() < fl {
                             let mut spcs: String = vec![' '; fl - b.len()].into_iter().collect();
                             spcs.push_str(&b);
@@ -1323,5 +861,30 @@ impl Shapefile {
                 }
             }
         }
+
+        // records without z or measure values, or without any points, are not included
+        if !self.header.z_min.is_finite() || !self.header.z_max.is_finite() {
+            self.header.z_min = 0f64;
+            self.header.z_max = 0f64;
+        }
+        if !self.header.m_min.is_finite() || !self.header.m_max.is_finite() {
+            self.header.m_min = 0f64;
+            self.header.m_max = 0f64;
+        }
+    }
+}
+
+/// Returns `file_name` with a `.shp` extension appended if it has none. Vectors without
+/// a file name, e.g. those returned by the tools' core functions, are left unnamed.
+fn get_output_file_name(file_name: &str) -> String {
+    // in-memory datasets are identified by their full names and have no file format
+    if file_name.is_empty()
+        || file_name.contains(".")
+        || memory_store::is_memory_dataset(file_name)
+    {
+        file_name.to_string()
+    } else {
+        // likely no extension provided; default to .shp
+        format!("{}.shp", file_name)
     }
 }